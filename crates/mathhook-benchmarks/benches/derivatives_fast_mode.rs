@@ -3,7 +3,9 @@
 /// Tests the fast `derivative()` API without step generation.
 /// This is the performance-critical path for production use.
 use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
-use mathhook_core::calculus::derivatives::Derivative;
+use mathhook_core::calculus::derivatives::{
+    Derivative, DerivativeSimplification, IterativeDerivative,
+};
 use mathhook_core::{symbol, Expression};
 use std::time::Duration;
 
@@ -133,6 +135,37 @@ fn bench_fast_polynomial(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_fast_higher_order(c: &mut Criterion) {
+    let mut group = c.benchmark_group("derivatives_fast/higher_order");
+    let x = symbol!(x);
+
+    // d^n/dx^n sin(x^2)
+    let expr = Expression::function(
+        "sin",
+        vec![Expression::pow(
+            Expression::symbol(x.clone()),
+            Expression::integer(2),
+        )],
+    );
+    let deferred = IterativeDerivative::new(DerivativeSimplification::Deferred);
+
+    for order in [2u32, 5, 10].iter() {
+        group.bench_with_input(BenchmarkId::new("recursive", order), order, |b, &n| {
+            b.iter(|| expr.nth_derivative(x.clone(), n))
+        });
+        group.bench_with_input(BenchmarkId::new("iterative", order), order, |b, &n| {
+            b.iter(|| IterativeDerivative::default().nth_derivative(&expr, &x, n))
+        });
+        group.bench_with_input(
+            BenchmarkId::new("iterative_deferred", order),
+            order,
+            |b, &n| b.iter(|| deferred.nth_derivative(&expr, &x, n)),
+        );
+    }
+
+    group.finish();
+}
+
 criterion_group!(
     name = fast_mode_benchmarks;
     config = Criterion::default()
@@ -143,7 +176,8 @@ criterion_group!(
         bench_fast_chain_rule,
         bench_fast_product_rule,
         bench_fast_complex,
-        bench_fast_polynomial
+        bench_fast_polynomial,
+        bench_fast_higher_order
 );
 
 criterion_main!(fast_mode_benchmarks);
@@ -14,7 +14,7 @@ use mathhook_core::algebra::polynomial_advanced::AdvancedPolynomial;
 use mathhook_core::algebra::polynomial_division::polynomial_div;
 use mathhook_core::core::polynomial::algorithms::polynomial_gcd;
 use mathhook_core::core::polynomial::algorithms::zippel_gcd::{
-    multivariate_gcd_zippel, MultivariateConfig,
+    multivariate_gcd_zippel, MultiPoly, MultivariateConfig,
 };
 use mathhook_core::core::polynomial::finite_field::PolyZp;
 use mathhook_core::core::polynomial::groebner::{GroebnerBasis, MonomialOrder};
@@ -114,9 +114,41 @@ fn bench_multivariate_gcd(c: &mut Criterion) {
         let config = MultivariateConfig::default();
         b.iter(|| multivariate_gcd_zippel(bb(&f), bb(&g), 2, &config))
     });
+    group.bench_function("zippel_trivariate_dense", |b| {
+        // f = (1 + x + y + z)⁶(x - y + 2), g = (1 + x + y + z)⁶(y + z² - 3)
+        let base: MultiPoly = HashMap::from([
+            (vec![0, 0, 0], 1),
+            (vec![1, 0, 0], 1),
+            (vec![0, 1, 0], 1),
+            (vec![0, 0, 1], 1),
+        ]);
+        let common = (0..5).fold(base.clone(), |acc, _| multipoly_mul(&acc, &base));
+        let f = multipoly_mul(
+            &common,
+            &HashMap::from([(vec![1, 0, 0], 1), (vec![0, 1, 0], -1), (vec![0, 0, 0], 2)]),
+        );
+        let g = multipoly_mul(
+            &common,
+            &HashMap::from([(vec![0, 1, 0], 1), (vec![0, 0, 2], 1), (vec![0, 0, 0], -3)]),
+        );
+        let config = MultivariateConfig::default();
+        b.iter(|| multivariate_gcd_zippel(bb(&f), bb(&g), 3, &config))
+    });
     group.finish();
 }
 
+fn multipoly_mul(a: &MultiPoly, b: &MultiPoly) -> MultiPoly {
+    let mut product = MultiPoly::new();
+    for (ea, &ca) in a {
+        for (eb, &cb) in b {
+            let key = ea.iter().zip(eb).map(|(i, j)| i + j).collect();
+            *product.entry(key).or_insert(0) += ca * cb;
+        }
+    }
+    product.retain(|_, c| *c != 0);
+    product
+}
+
 fn bench_polynomial_division(c: &mut Criterion) {
     let mut group = c.benchmark_group("polynomial_division");
     group.sample_size(50);
@@ -2,6 +2,7 @@
 //! These benchmarks guide SIMD integration by measuring real-world performance patterns
 
 use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use mathhook_core::core::polynomial::eval_polynomial_fast;
 use mathhook_core::{symbol, Expression, Number, Simplify, Symbol};
 use num_bigint::BigInt;
 use num_rational::BigRational;
@@ -300,6 +301,32 @@ fn bench_polynomial_operations(c: &mut Criterion) {
             },
         );
 
+        // Same evaluation through the Horner scheme
+        group.bench_with_input(
+            BenchmarkId::new("polynomial_evaluation_horner", degree),
+            degree,
+            |b, _| b.iter(|| black_box(eval_polynomial_fast(&dense_poly, &x, 2.0))),
+        );
+
+        group.bench_with_input(BenchmarkId::new("horner_form", degree), degree, |b, _| {
+            b.iter(|| black_box(dense_poly.horner(&x)))
+        });
+        let compiled = dense_poly
+            .compile(std::slice::from_ref(&x))
+            .expect("polynomial compiles");
+        group.bench_with_input(
+            BenchmarkId::new("polynomial_evaluation_compiled", degree),
+            degree,
+            |b, _| b.iter(|| black_box(compiled.evaluate(&[2.0]))),
+        );
+        let bytecode = mathhook_core::codegen::compile(&dense_poly, std::slice::from_ref(&x))
+            .expect("polynomial compiles");
+        group.bench_with_input(
+            BenchmarkId::new("polynomial_evaluation_bytecode", degree),
+            degree,
+            |b, _| b.iter(|| black_box(bytecode.evaluate(&[2.0]))),
+        );
+
         // Polynomial multiplication (convolution-like)
         let poly_a = create_dense_polynomial(&x, *degree / 2);
         let poly_b = create_dense_polynomial(&x, *degree / 2);
@@ -250,6 +250,7 @@ fn bench_optimization_detection(c: &mut Criterion) {
                             mathhook_core::core::Number::Float(f) => Some(*f),
                             mathhook_core::core::Number::Rational(r) => r.to_f64(),
                             mathhook_core::core::Number::BigInteger(bi) => bi.to_f64(),
+                            mathhook_core::core::Number::Decimal(d) => d.to_f64(),
                         },
                         _ => None,
                     })
@@ -3,7 +3,7 @@
 /// Tests algebraic, trigonometric, logarithmic, and rational simplification
 /// with varying complexity levels. Critical for symbolic simplification performance.
 use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
-use mathhook_core::{parse, symbol, Expression, Simplify};
+use mathhook_core::{parse, symbol, Expand, Expression, Simplify};
 use std::hint::black_box;
 use std::time::Duration;
 
@@ -65,8 +65,29 @@ fn bench_polynomial_simplification(c: &mut Criterion) {
         );
     }
 
-    // Multinomial simplification with multiple variables
+    // Multinomial expansion: (x + y + z + w)^n
     let y = symbol!(y);
+    let z = symbol!(z);
+    let w = symbol!(w);
+    for power in [5, 10, 15].iter() {
+        let multinomial = Expression::pow(
+            Expression::add(vec![
+                Expression::symbol(x.clone()),
+                Expression::symbol(y.clone()),
+                Expression::symbol(z.clone()),
+                Expression::symbol(w.clone()),
+            ]),
+            Expression::integer(*power),
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("multinomial_expansion", power),
+            power,
+            |b, _| b.iter(|| black_box(multinomial.expand())),
+        );
+    }
+
+    // Multinomial simplification with multiple variables
     let multinomial = Expression::add(vec![
         Expression::mul(vec![
             Expression::integer(2),
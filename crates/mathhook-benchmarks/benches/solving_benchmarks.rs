@@ -88,7 +88,7 @@ fn bench_quadratic_solving(c: &mut Criterion) {
     let mut group = c.benchmark_group("quadratic_solving");
 
     let x = symbol!(x);
-    let solver = QuadraticSolver;
+    let solver = QuadraticSolver::new();
 
     // Simple quadratic: x^2 - 4 = 0 (real roots)
     let simple_quad = Expression::add(vec![
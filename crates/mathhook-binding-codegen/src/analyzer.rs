@@ -334,14 +334,12 @@ fn try_map_string_like_generic(name: &str, ctx: &TypeContext) -> Option<MappedTy
 
     if self_type == "Symbol" {
         match method_name {
-            "new" | "scalar" | "matrix" | "operator" | "quaternion" => {
-                if name == "S" {
-                    return Some(MappedType::Direct {
-                        rust_type: "String".to_string(),
-                        python_type: "String".to_string(),
-                        node_type: "String".to_string(),
-                    });
-                }
+            "new" | "scalar" | "matrix" | "operator" | "quaternion" if name == "S" => {
+                return Some(MappedType::Direct {
+                    rust_type: "String".to_string(),
+                    python_type: "String".to_string(),
+                    node_type: "String".to_string(),
+                });
             }
             _ => {}
         }
@@ -51,7 +51,10 @@ impl BindingConfig {
                 "*Builder".to_string(),
             ],
             skip_module_paths: vec![],
-            skip_types: Self::hand_written_types(),
+            skip_types: Self::hand_written_types()
+                .into_iter()
+                .chain(Self::unbindable_types())
+                .collect(),
             force_include: vec![],
             custom_mappings: vec![],
             external_types: vec![],
@@ -62,6 +65,36 @@ impl BindingConfig {
         vec![]
     }
 
+    /// Core types whose API cannot be wrapped as generated
+    fn unbindable_types() -> Vec<String> {
+        [
+            // Generic over the attributed result
+            "Provenance",
+            // Constructor returns `Option`
+            "Endpoint",
+            // Only reachable through `Matrix::jordan_blocks`
+            "JordanBlock",
+            // Return borrowed expressions and take `Range<usize>`
+            "IncrementalParser",
+            "TextEdit",
+            // Built from `solvers::SolverResult`, which bindings do not expose
+            "Solutions",
+            // Names are `&'static str`
+            "FunctionDefinition",
+            // Builders taking and returning `self`
+            "Assumptions",
+            "EvalSettings",
+            "ExpectedInput",
+            "QuadraticSolver",
+            "RewriteRule",
+            "SimplifyPipeline",
+            "Template",
+        ]
+        .into_iter()
+        .map(String::from)
+        .collect()
+    }
+
     pub fn should_skip(&self, type_name: &str) -> bool {
         self.skip_types.contains(&type_name.to_string())
             || self
@@ -125,6 +158,13 @@ mod tests {
         assert!(!config.should_skip("KeepMe"));
     }
 
+    #[test]
+    fn test_default_config_skips_unbindable_types() {
+        let config = BindingConfig::default_config();
+        assert!(config.should_skip("Provenance"));
+        assert!(!config.should_skip("Expression"));
+    }
+
     #[test]
     fn test_should_skip_module_path() {
         let config = BindingConfig {
@@ -11,6 +11,7 @@ const SKIPPED_FUNCTIONS: &[&str] = &[
     "get_performance_optimizer",
     "get_cached_expression",
     "get_cache_stats",
+    "integrate_with_technique",
 ];
 
 fn to_js_class_name(rust_name: &str) -> String {
@@ -311,6 +312,8 @@ impl NodeEmitter {
             output.push_str("    }\n\n");
         }
 
+        output.push_str(&Self::emit_json_methods(type_info, &struct_name));
+
         output.push_str("}\n\n");
 
         output.push_str(&format!(
@@ -340,6 +343,37 @@ impl NodeEmitter {
         output
     }
 
+    /// `toJSON`/`fromJSON` for serde types, so `JSON.stringify` sees the fields
+    fn emit_json_methods(type_info: &TypeInfo, struct_name: &str) -> String {
+        let mut output = String::new();
+
+        if type_info.has_serialize() {
+            output.push_str("    #[napi(js_name = \"toJSON\")]\n");
+            output
+                .push_str("    pub fn to_json_value(&self) -> napi::Result<serde_json::Value> {\n");
+            output.push_str("        serde_json::to_value(&self.inner)\n");
+            output.push_str("            .map_err(|e| napi::Error::from_reason(e.to_string()))\n");
+            output.push_str("    }\n\n");
+        }
+
+        if type_info.has_deserialize() {
+            output.push_str("    #[napi(factory, js_name = \"fromJSON\")]\n");
+            output.push_str(&format!(
+                "    pub fn from_json_value(value: serde_json::Value) -> napi::Result<{}> {{\n",
+                struct_name
+            ));
+            output.push_str("        serde_json::from_value(value)\n");
+            output.push_str(&format!(
+                "            .map(|inner| {} {{ inner }})\n",
+                struct_name
+            ));
+            output.push_str("            .map_err(|e| napi::Error::from_reason(e.to_string()))\n");
+            output.push_str("    }\n\n");
+        }
+
+        output
+    }
+
     fn emit_method(
         &self,
         method: &AnalyzedMethod,
@@ -618,26 +652,28 @@ impl NodeEmitter {
                         }
                         _ => name.to_string(),
                     },
-                    MappedType::Collected { item_type } => match &**item_type {
-                        MappedType::Direct { node_type, .. }
-                            if node_type.starts_with("Js") && node_type != "JsObject" =>
-                        {
-                            if node_type == "JsSymbol" {
-                                // SymbolOrExpression wraps Symbol in .0
-                                format!("{}.as_ref().map(|v| v.iter().map(|x| x.0.clone()).collect())", name)
-                            } else if is_owned {
-                                // Vec items are &JsX, must clone
-                                format!("{}.as_ref().map(|v| v.iter().map(|x| x.inner.clone()).collect())", name)
-                            } else {
-                                // Core wants references
-                                format!(
-                                    "{}.as_ref().map(|v| v.iter().map(|x| &x.inner).collect())",
-                                    name
-                                )
+                    MappedType::Collected { item_type } => {
+                        match &**item_type {
+                            MappedType::Direct { node_type, .. }
+                                if node_type.starts_with("Js") && node_type != "JsObject" =>
+                            {
+                                if node_type == "JsSymbol" {
+                                    // SymbolOrExpression wraps Symbol in .0
+                                    format!("{}.as_ref().map(|v| v.iter().map(|x| x.0.clone()).collect())", name)
+                                } else if is_owned {
+                                    // Vec items are &JsX, must clone
+                                    format!("{}.as_ref().map(|v| v.iter().map(|x| x.inner.clone()).collect())", name)
+                                } else {
+                                    // Core wants references
+                                    format!(
+                                        "{}.as_ref().map(|v| v.iter().map(|x| &x.inner).collect())",
+                                        name
+                                    )
+                                }
                             }
+                            _ => name.to_string(),
                         }
-                        _ => name.to_string(),
-                    },
+                    }
                     _ => name.to_string(),
                 }
             }
@@ -745,7 +781,7 @@ impl NodeEmitter {
                 if node_type.starts_with("Js") && node_type != "JsObject" {
                     format!("{} {{ inner: {} }}", node_type, call)
                 } else if Self::needs_integer_cast(rust_type) {
-                    format!("{} as i64", call)
+                    Self::js_integer(call)
                 } else if rust_type == "str" || rust_type == "&str" {
                     format!("{}.to_string()", call)
                 } else {
@@ -766,7 +802,7 @@ impl NodeEmitter {
                 if node_type.starts_with("Js") && node_type != "JsObject" {
                     format!("{}.map(|v| {} {{ inner: v }})", call, node_type)
                 } else if Self::needs_integer_cast(rust_type) {
-                    format!("{}.map(|v| v as i64)", call)
+                    format!("{}.map(|v| {})", call, Self::js_integer("v"))
                 } else if rust_type == "str" || rust_type == "&str" {
                     format!("{}.map(|v| v.to_string())", call)
                 } else {
@@ -841,7 +877,7 @@ impl NodeEmitter {
                     if node_type.starts_with("Js") && node_type != "JsObject" {
                         format!("{} {{ inner: t.{} }}", node_type, i)
                     } else if Self::needs_integer_cast(rust_type) {
-                        format!("t.{} as i64", i)
+                        Self::js_integer(&format!("t.{}", i))
                     } else {
                         format!("t.{}", i)
                     }
@@ -859,7 +895,7 @@ impl NodeEmitter {
                             // Tuple element is a reference, clone before wrapping
                             format!("{} {{ inner: t.{}.clone() }}", node_type, i)
                         } else if Self::needs_integer_cast(rust_type) {
-                            format!("*t.{} as i64", i)
+                            Self::js_integer(&format!("*t.{}", i))
                         } else if rust_type == "str" || rust_type == "&str" {
                             format!("t.{}.to_string()", i)
                         } else {
@@ -888,7 +924,11 @@ impl NodeEmitter {
                         call, node_type
                     )
                 } else if Self::needs_integer_cast(rust_type) {
-                    format!("{}.into_iter().map(|v| v as i64).collect()", call)
+                    format!(
+                        "{}.into_iter().map(|v| {}).collect()",
+                        call,
+                        Self::js_integer("v")
+                    )
                 } else if rust_type == "str" || rust_type == "&str" {
                     format!("{}.into_iter().map(|v| v.to_string()).collect()", call)
                 } else {
@@ -935,7 +975,7 @@ impl NodeEmitter {
                     if node_type.starts_with("Js") && node_type != "JsObject" {
                         format!("{} {{ inner: t.{} }}", node_type, i)
                     } else if Self::needs_integer_cast(rust_type) {
-                        format!("t.{} as i64", i)
+                        Self::js_integer(&format!("t.{}", i))
                     } else {
                         format!("t.{}", i)
                     }
@@ -952,7 +992,7 @@ impl NodeEmitter {
                         if node_type.starts_with("Js") && node_type != "JsObject" {
                             format!("{} {{ inner: t.{}.clone() }}", node_type, i)
                         } else if Self::needs_integer_cast(rust_type) {
-                            format!("*t.{} as i64", i)
+                            Self::js_integer(&format!("*t.{}", i))
                         } else if rust_type == "str" || rust_type == "&str" {
                             format!("t.{}.to_string()", i)
                         } else {
@@ -981,6 +1021,8 @@ impl NodeEmitter {
             } => {
                 if node_type.starts_with("Js") && node_type != "JsObject" {
                     format!("{} {{ inner: {}.clone() }}", node_type, call)
+                } else if Self::needs_integer_cast(rust_type) {
+                    Self::js_integer(&format!("*{}", call))
                 } else if rust_type == "str" || rust_type == "&str" {
                     format!("{}.to_string()", call)
                 } else {
@@ -1008,8 +1050,9 @@ impl NodeEmitter {
                     )
                 } else if Self::needs_integer_cast(rust_type) {
                     format!(
-                        "{}.map(|v| v as i64).map_err(|e| napi::Error::from_reason(format!(\"{{:?}}\", e)))",
-                        call
+                        "{}.map(|v| {}).map_err(|e| napi::Error::from_reason(format!(\"{{:?}}\", e)))",
+                        call,
+                        Self::js_integer("v")
                     )
                 } else {
                     format!(
@@ -1077,7 +1120,7 @@ impl NodeEmitter {
                     if node_type.starts_with("Js") && node_type != "JsObject" {
                         format!("{} {{ inner: t.{} }}", node_type, i)
                     } else if Self::needs_integer_cast(rust_type) {
-                        format!("t.{} as i64", i)
+                        Self::js_integer(&format!("t.{}", i))
                     } else {
                         format!("t.{}", i)
                     }
@@ -1149,7 +1192,7 @@ impl NodeEmitter {
                         if node_type.starts_with("Js") && node_type != "JsObject" {
                             format!("{} {{ inner: {} }}", node_type, field)
                         } else if Self::needs_integer_cast(rust_type) {
-                            format!("{} as i64", field)
+                            Self::js_integer(&field)
                         } else {
                             field
                         }
@@ -1238,7 +1281,7 @@ impl NodeEmitter {
                 if rust_type == "()" {
                     "()".to_string()
                 } else if Self::needs_integer_cast(rust_type) {
-                    "i64".to_string()
+                    "Either<i64, BigInt>".to_string()
                 } else if rust_type == "str" || rust_type == "&str" {
                     "String".to_string()
                 } else {
@@ -1285,6 +1328,14 @@ impl NodeEmitter {
         )
     }
 
+    /// Conversion of the Rust integer `value` for JavaScript
+    ///
+    /// Every integer type widens to `i128` without loss; `js_integer` then
+    /// returns a `number` or, beyond 2^53, a `bigint`.
+    fn js_integer(value: &str) -> String {
+        format!("crate::js_integer({} as i128)", value)
+    }
+
     fn emit_functions_file_impl(&self, functions: &[AnalyzedMethod]) -> String {
         let mut output = String::new();
 
@@ -1614,6 +1665,68 @@ mod tests {
         assert_eq!(borrowed_result, "maybe_expr.map(|v| v.inner.clone())");
     }
 
+    #[test]
+    fn test_integer_outputs_keep_full_range() {
+        let emitter = NodeEmitter::new();
+        let integer = |rust_type: &str| MappedType::Direct {
+            rust_type: rust_type.to_string(),
+            python_type: "int".to_string(),
+            node_type: "i64".to_string(),
+        };
+
+        assert_eq!(
+            NodeEmitter::method_return_type(&integer("u64")),
+            "Either<i64, BigInt>"
+        );
+        assert_eq!(
+            emitter.wrap_direct_value(&integer("usize"), "self.inner.len()"),
+            "crate::js_integer(self.inner.len() as i128)"
+        );
+        assert_eq!(
+            emitter.wrap_option_value(&integer("u64"), "x.checked()"),
+            "x.checked().map(|v| crate::js_integer(v as i128))"
+        );
+        assert_eq!(
+            emitter.wrap_collected_value(&integer("u32"), "x.degrees()"),
+            "x.degrees().into_iter().map(|v| crate::js_integer(v as i128)).collect()"
+        );
+        let pair = [integer("usize"), integer("isize")];
+        assert_eq!(
+            emitter.wrap_tuple_value(&pair, "x.shape()"),
+            "{ let _t = x.shape(); (crate::js_integer(_t.0 as i128), crate::js_integer(_t.1 as i128)) }"
+        );
+    }
+
+    #[test]
+    fn test_emit_json_methods_for_serde_types() {
+        let mut type_info = TypeInfo {
+            name: "Step".to_string(),
+            module_path: String::new(),
+            source_file: std::path::PathBuf::new(),
+            kind: TypeKind::Struct,
+            is_public: true,
+            has_lifetimes: false,
+            doc_comment: None,
+            fields: vec![],
+            enum_variants: vec![],
+            derived_traits: vec!["Clone".to_string(), "Serialize".to_string()],
+            is_cfg_gated: false,
+            skip_binding: false,
+        };
+
+        let result = NodeEmitter::emit_json_methods(&type_info, "JsStep");
+        assert!(result.contains("js_name = \"toJSON\""));
+        assert!(!result.contains("fromJSON"));
+
+        type_info.derived_traits.push("Deserialize".to_string());
+        let result = NodeEmitter::emit_json_methods(&type_info, "JsStep");
+        assert!(result.contains("factory, js_name = \"fromJSON\""));
+        assert!(result.contains("-> napi::Result<JsStep>"));
+
+        type_info.derived_traits.clear();
+        assert!(NodeEmitter::emit_json_methods(&type_info, "JsStep").is_empty());
+    }
+
     #[test]
     fn test_unwrap_arg_hashmap_owned_vs_borrowed() {
         let emitter = NodeEmitter::new();
@@ -230,6 +230,14 @@ impl TypeInfo {
     pub fn has_debug(&self) -> bool {
         self.derived_traits.iter().any(|t| t == "Debug")
     }
+
+    pub fn has_serialize(&self) -> bool {
+        self.derived_traits.iter().any(|t| t == "Serialize")
+    }
+
+    pub fn has_deserialize(&self) -> bool {
+        self.derived_traits.iter().any(|t| t == "Deserialize")
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -617,6 +625,81 @@ impl ApiVisitor {
             .collect()
     }
 
+    /// Constructor function generated by `#[math_function]` on an impl block
+    ///
+    /// Mirrors the macro: the name comes from `name = "..."` or the snake_case
+    /// type name, and there is one `Expression` parameter per argument of the
+    /// first of `eval`, `derivative` or `latex`, or `arity = N` of them.
+    fn math_function_info(&self, node: &ItemImpl) -> Option<FunctionInfo> {
+        let attr = node.attrs.iter().find(|attr| {
+            attr.path()
+                .segments
+                .last()
+                .is_some_and(|s| s.ident == "math_function")
+        })?;
+
+        let type_name = match &*node.self_ty {
+            syn::Type::Path(type_path) => type_path.path.segments.last()?.ident.to_string(),
+            _ => return None,
+        };
+
+        let mut name = crate::emitter::to_snake_case(&type_name);
+        let mut arity = None;
+        if let syn::Meta::List(_) = &attr.meta {
+            let _ = attr.parse_nested_meta(|meta| {
+                let value: syn::Lit = meta.value()?.parse()?;
+                match value {
+                    syn::Lit::Str(lit) if meta.path.is_ident("name") => name = lit.value(),
+                    syn::Lit::Int(lit) if meta.path.is_ident("arity") => {
+                        arity = Some(lit.base10_parse::<usize>()?)
+                    }
+                    _ => {}
+                }
+                Ok(())
+            });
+        }
+
+        let params: Vec<String> = node
+            .items
+            .iter()
+            .find_map(|item| match item {
+                syn::ImplItem::Fn(method)
+                    if ["eval", "derivative", "latex"]
+                        .contains(&method.sig.ident.to_string().as_str()) =>
+                {
+                    Some(
+                        Self::extract_signature(&method.sig)
+                            .inputs
+                            .into_iter()
+                            .map(|(name, _)| name)
+                            .collect(),
+                    )
+                }
+                _ => None,
+            })
+            .or_else(|| arity.map(|n| (0..n).map(|i| format!("arg{}", i)).collect()))?;
+
+        let expression: syn::Type = syn::parse_quote!(Expression);
+        let doc_comment = Self::extract_doc_comment(&node.attrs);
+        let skip_binding = has_no_binding_directive(&doc_comment);
+
+        Some(FunctionInfo {
+            name,
+            module_path: self.current_module.clone(),
+            source_file: self.current_file.clone(),
+            signature: MethodSignature {
+                inputs: params
+                    .into_iter()
+                    .map(|param| (param, expression.clone()))
+                    .collect(),
+                output: Some(expression),
+                is_async: false,
+            },
+            doc_comment,
+            skip_binding,
+        })
+    }
+
     fn extract_trait_path(path: &syn::Path) -> String {
         use quote::ToTokens;
         path.segments
@@ -812,15 +895,13 @@ impl<'ast> Visit<'ast> for ApiVisitor {
 
             for item in items {
                 match item {
-                    syn::Item::Use(use_item) => {
-                        if Self::is_public(&use_item.vis) {
-                            let extracted = Self::extract_use_items(&use_item.tree, "");
-                            for item_name in extracted {
-                                self.reexports.push(ReexportInfo {
-                                    item_name,
-                                    reexport_module: self.current_module.clone(),
-                                });
-                            }
+                    syn::Item::Use(use_item) if Self::is_public(&use_item.vis) => {
+                        let extracted = Self::extract_use_items(&use_item.tree, "");
+                        for item_name in extracted {
+                            self.reexports.push(ReexportInfo {
+                                item_name,
+                                reexport_module: self.current_module.clone(),
+                            });
                         }
                     }
                     syn::Item::Mod(nested_mod) => {
@@ -839,6 +920,10 @@ impl<'ast> Visit<'ast> for ApiVisitor {
             return;
         }
 
+        if let Some(function) = self.math_function_info(node) {
+            self.functions.push(function);
+        }
+
         let doc_comment = Self::extract_doc_comment(&node.attrs);
         let impl_skip_binding = has_no_binding_directive(&doc_comment);
 
@@ -1386,4 +1471,37 @@ mod tests {
             "Clean doc without directive"
         );
     }
+
+    #[test]
+    fn test_math_function_impl_yields_constructor() {
+        let file: File = syn::parse_quote! {
+            #[math_function(name = "softplus")]
+            impl SoftPlus {
+                fn eval(x: f64) -> f64 {
+                    x.exp().ln_1p()
+                }
+            }
+
+            #[math_function(arity = 2)]
+            impl LogSumExp {}
+        };
+        let mut visitor = ApiVisitor::new(PathBuf::from("custom.rs"), "custom".to_string());
+        visitor.visit_file(&file);
+
+        let signatures: Vec<_> = visitor
+            .functions
+            .iter()
+            .map(|f| {
+                let params: Vec<_> = f.signature.inputs.iter().map(|(n, _)| n.as_str()).collect();
+                (f.name.as_str(), params, f.signature.output.is_some())
+            })
+            .collect();
+        assert_eq!(
+            signatures,
+            vec![
+                ("softplus", vec!["x"], true),
+                ("log_sum_exp", vec!["arg0", "arg1"], true),
+            ]
+        );
+    }
 }
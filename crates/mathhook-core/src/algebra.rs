@@ -9,14 +9,18 @@ pub mod expand;
 pub mod factor;
 pub mod gcd;
 pub mod groebner;
+pub mod linear_programming;
 pub mod matrix_correctness_tests;
 pub mod multivariate_gcd;
+pub mod partial_fractions;
 pub mod polynomial_advanced;
 pub mod polynomial_division;
 pub mod rational;
+pub mod rationalize;
 pub mod root_finding;
 pub mod simplification;
 pub mod solvers;
+pub mod symmetric;
 pub mod zero_detection;
 
 // Re-export the actual traits from their modules
@@ -26,8 +30,10 @@ pub use complex::ComplexOperations;
 pub use expand::Expand;
 pub use factor::Factor;
 pub use gcd::PolynomialGcd;
+pub use partial_fractions::Apart;
 pub use polynomial_advanced::AdvancedPolynomial;
 pub use rational::RationalSimplify;
+pub use rationalize::RationalizeDenominator;
 pub use zero_detection::ZeroDetection;
 
 // Re-export polynomial division functions
@@ -42,8 +48,13 @@ pub use solvers::{EquationSolver, SolverResult as AlgebraSolverResult};
 
 // Re-export Gröbner basis types and functions
 pub use groebner::{
-    buchberger_algorithm, poly_reduce, poly_reduce_completely, s_polynomial, GroebnerBasis,
-    MonomialOrder, MonomialOrdering,
+    buchberger_algorithm, groebner_basis_over, poly_reduce, poly_reduce_completely, s_polynomial,
+    CoefficientField, GroebnerBasis, MonomialOrder, MonomialOrdering,
+};
+
+// Re-export linear programming
+pub use linear_programming::{
+    maximize, minimize, LinearProgramSolution, LinearProgramStatus, Tableau,
 };
 
 // Re-export simplification system
@@ -57,4 +68,16 @@ pub use crate::core::polynomial::finite_field::{
     is_prime, FiniteFieldError, FiniteFieldResult, PolyZp, Zp,
 };
 pub use crate::core::polynomial::poly::IntPoly;
+
+// Re-export integer polynomial building blocks (content, square-free, Hensel lifting)
+pub use crate::core::polynomial::algorithms::{
+    content_primitive_part, factor_square_free, square_free_factorization_poly,
+};
+pub use crate::core::polynomial::finite_field::{hensel_lift, hensel_lift_factors};
 pub use multivariate_gcd::multivariate_gcd;
+
+// Re-export symmetric function toolkit
+pub use symmetric::{
+    discriminant_from_roots, elementary_from_power_sums, elementary_symmetric, is_symmetric,
+    power_sum, power_sums_from_elementary, symmetric_decomposition,
+};
@@ -38,7 +38,7 @@ impl AdvancedSimplify for Expression {
     /// Simplify factorial expressions
     fn simplify_factorial(&self) -> Self {
         match self {
-            Expression::Function { name, args } if name.as_ref() == "factorial" => {
+            Expression::Function { name, args, .. } if name.as_ref() == "factorial" => {
                 if args.len() == 1 {
                     self.compute_factorial(&args[0])
                 } else {
@@ -64,7 +64,7 @@ impl AdvancedSimplify for Expression {
                 Expression::pow(base.simplify_factorial(), exp.simplify_factorial())
             }
 
-            Expression::Function { name, args } => {
+            Expression::Function { name, args, .. } => {
                 let simplified_args: Vec<Expression> =
                     args.iter().map(|arg| arg.simplify_factorial()).collect();
                 Expression::function(name.clone(), simplified_args)
@@ -77,11 +77,11 @@ impl AdvancedSimplify for Expression {
     /// Simplify logarithmic expressions
     fn simplify_logarithms(&self) -> Self {
         match self {
-            Expression::Function { name, args } if name.as_ref() == "log" => {
+            Expression::Function { name, args, .. } if name.as_ref() == "log" => {
                 self.simplify_log_function(args)
             }
 
-            Expression::Function { name, args } if name.as_ref() == "ln" => {
+            Expression::Function { name, args, .. } if name.as_ref() == "ln" => {
                 self.simplify_ln_function(args)
             }
 
@@ -108,7 +108,7 @@ impl AdvancedSimplify for Expression {
     /// Simplify trigonometric expressions
     fn simplify_trigonometric(&self) -> Self {
         match self {
-            Expression::Function { name, args } if self.is_trig_function(name) => {
+            Expression::Function { name, args, .. } if self.is_trig_function(name) => {
                 self.simplify_trig_function(name, args)
             }
 
@@ -135,7 +135,7 @@ impl AdvancedSimplify for Expression {
     /// Simplify other special functions
     fn simplify_special_functions(&self) -> Self {
         match self {
-            Expression::Function { name, args } => match name.as_ref() {
+            Expression::Function { name, args, .. } => match name.as_ref() {
                 "sqrt" => self.simplify_sqrt(args),
                 "abs" => self.simplify_abs(args),
                 "exp" => self.simplify_exp(args),
@@ -2,12 +2,17 @@
 //! This is the "brain" that decides which solver to use
 
 use crate::algebra::solvers::matrix_equations::MatrixEquationSolver;
+use crate::algebra::solvers::QuadraticMethod;
 use crate::algebra::solvers::{EquationSolver, SolverResult};
-use crate::algebra::solvers::{LinearSolver, PolynomialSolver, QuadraticSolver, SystemSolver};
+use crate::algebra::solvers::{
+    LinearSolver, MinMaxSolver, PolynomialSolver, QuadraticSolver, RoundingSolver, SystemSolver,
+};
 use crate::calculus::ode::EducationalODESolver;
 use crate::calculus::pde::EducationalPDESolver;
+use crate::core::provenance::AlgorithmId;
 use crate::core::symbol::SymbolType;
 use crate::core::{Expression, Number, Symbol};
+use crate::educational::curriculum::{LearnedTechniques, SolveTechnique};
 use crate::educational::step_by_step::{Step, StepByStepExplanation};
 
 /// Types of equations our system can handle
@@ -22,6 +27,10 @@ pub enum EquationType {
     Transcendental,
     Numerical,
     Matrix,
+    /// Floor or ceiling of an expression in the variable
+    Rounding,
+    /// Min or max of expressions in the variable
+    MinMax,
     ODE,
     PDE,
     Unknown,
@@ -48,6 +57,14 @@ impl EquationAnalyzer {
             return EquationType::Matrix;
         }
 
+        if RoundingSolver::contains_rounding_of(equation, variable) {
+            return EquationType::Rounding;
+        }
+
+        if MinMaxSolver::contains_min_max_of(equation, variable) {
+            return EquationType::MinMax;
+        }
+
         let degree = Self::find_highest_degree(equation, variable);
         let has_transcendental = Self::has_transcendental_functions(equation);
         let variable_count = Self::count_variables(equation);
@@ -94,7 +111,7 @@ impl EquationAnalyzer {
 
     fn count_transcendental_functions(expr: &Expression) -> usize {
         match expr {
-            Expression::Function { name, args } => {
+            Expression::Function { name, args, .. } => {
                 let current =
                     if matches!(name.as_ref(), "sin" | "cos" | "tan" | "exp" | "ln" | "log") {
                         1
@@ -168,7 +185,7 @@ impl EquationAnalyzer {
 
     fn has_transcendental_functions(expr: &Expression) -> bool {
         match expr {
-            Expression::Function { name, args } => {
+            Expression::Function { name, args, .. } => {
                 matches!(name.as_ref(), "sin" | "cos" | "tan" | "exp" | "ln" | "log")
                     || args.iter().any(Self::has_transcendental_functions)
             }
@@ -217,7 +234,7 @@ impl EquationAnalyzer {
 
     fn has_derivatives(expr: &Expression) -> bool {
         match expr {
-            Expression::Function { name, args } => {
+            Expression::Function { name, args, .. } => {
                 matches!(name.as_ref(), "derivative" | "diff" | "D")
                     || args.iter().any(Self::has_derivatives)
             }
@@ -234,7 +251,7 @@ impl EquationAnalyzer {
 
     fn has_partial_derivatives(expr: &Expression) -> bool {
         match expr {
-            Expression::Function { name, args } => {
+            Expression::Function { name, args, .. } => {
                 matches!(name.as_ref(), "partial" | "pdiff" | "Partial")
                     || args.iter().any(Self::has_partial_derivatives)
             }
@@ -259,8 +276,11 @@ pub struct SmartEquationSolver {
     system_solver: SystemSolver,
     polynomial_solver: PolynomialSolver,
     matrix_solver: MatrixEquationSolver,
+    rounding_solver: RoundingSolver,
+    min_max_solver: MinMaxSolver,
     ode_solver: EducationalODESolver,
     pde_solver: EducationalPDESolver,
+    curriculum: Option<LearnedTechniques>,
 }
 
 impl Default for SmartEquationSolver {
@@ -277,9 +297,50 @@ impl SmartEquationSolver {
             system_solver: SystemSolver::new(),
             polynomial_solver: PolynomialSolver::new(),
             matrix_solver: MatrixEquationSolver::new(),
+            rounding_solver: RoundingSolver::new(),
+            min_max_solver: MinMaxSolver::new(),
             ode_solver: EducationalODESolver::new(),
             pde_solver: EducationalPDESolver::new(),
+            curriculum: None,
+        }
+    }
+
+    /// Only use techniques the student has learned
+    ///
+    /// Equations that need an untaught technique are reported as such
+    /// instead of solved, and quadratics are solved with the most general
+    /// method taught so far.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::algebra::equation_analyzer::SmartEquationSolver;
+    /// use mathhook_core::algebra::solvers::SolverResult;
+    /// use mathhook_core::educational::curriculum::Curriculum;
+    /// use mathhook_core::{expr, symbol};
+    ///
+    /// let x = symbol!(x);
+    /// let equation = expr!((x ^ 2) - 2);
+    ///
+    /// let unit_4 = Curriculum::algebra_1().through_unit(4);
+    /// let solver = SmartEquationSolver::new().with_curriculum(unit_4);
+    /// let (result, _) = solver.solve_with_equation(&equation, &x);
+    /// assert_eq!(result, SolverResult::NoSolution);
+    ///
+    /// let unit_5 = Curriculum::algebra_1().through_unit(5);
+    /// let solver = SmartEquationSolver::new().with_curriculum(unit_5);
+    /// let (result, _) = solver.solve_with_equation(&equation, &x);
+    /// assert!(matches!(result, SolverResult::Multiple(_)));
+    /// ```
+    pub fn with_curriculum(mut self, learned: LearnedTechniques) -> Self {
+        if let Some(method) = learned.quadratic_method() {
+            self.quadratic_solver = QuadraticSolver::new().with_method(method);
+            if !learned.permits(SolveTechnique::QuadraticFormula) {
+                self.quadratic_solver = self.quadratic_solver.without_formula_fallback();
+            }
         }
+        self.curriculum = Some(learned);
+        self
     }
 
     /// Solve equation with educational explanation, including equation analysis
@@ -306,6 +367,17 @@ impl SmartEquationSolver {
         equation: &Expression,
         variable: &Symbol,
     ) -> (SolverResult, StepByStepExplanation) {
+        let (result, explanation, _) = self.solve_with_algorithm(equation, variable);
+        (result, explanation)
+    }
+
+    /// Solve with an explanation, reporting the algorithm that produced the
+    /// result
+    pub(crate) fn solve_with_algorithm(
+        &self,
+        equation: &Expression,
+        variable: &Symbol,
+    ) -> (SolverResult, StepByStepExplanation, AlgorithmId) {
         let mut all_steps = Vec::new();
 
         let degree = EquationAnalyzer::find_highest_degree(equation, variable);
@@ -339,6 +411,12 @@ impl SmartEquationSolver {
             EquationType::Matrix => {
                 "Detected matrix equation (contains noncommutative symbols)".to_owned()
             }
+            EquationType::Rounding => {
+                "Detected rounding equation (contains floor/ceiling of the variable)".to_owned()
+            }
+            EquationType::MinMax => {
+                "Detected piecewise-linear equation (contains min/max of the variable)".to_owned()
+            }
             EquationType::ODE => {
                 "Detected ordinary differential equation (contains derivatives)".to_owned()
             }
@@ -352,15 +430,44 @@ impl SmartEquationSolver {
 
         all_steps.push(Step::new("Equation Analysis", analysis_description));
 
+        if let Some(learned) = &self.curriculum {
+            all_steps.push(Step::new(
+                "Curriculum",
+                format!("Using only techniques from {}", learned.description),
+            ));
+            if !learned.permits_equation(eq_type) {
+                all_steps.push(Step::new(
+                    "Technique Not Yet Learned",
+                    format!(
+                        "Solving this equation needs a technique not covered in {}",
+                        learned.description
+                    ),
+                ));
+                return (
+                    SolverResult::NoSolution,
+                    StepByStepExplanation::new(all_steps),
+                    AlgorithmId::Unevaluated,
+                );
+            }
+        }
+
         let solver_description = match eq_type {
             EquationType::Linear => "Using linear equation solver (isolation method)",
-            EquationType::Quadratic => "Using quadratic equation solver (quadratic formula)",
+            EquationType::Quadratic => match self.quadratic_solver.method {
+                QuadraticMethod::Formula => "Using quadratic equation solver (quadratic formula)",
+                QuadraticMethod::Factoring => "Using quadratic equation solver (factoring)",
+                QuadraticMethod::CompletingSquare => {
+                    "Using quadratic equation solver (completing the square)"
+                }
+            },
             EquationType::Cubic | EquationType::Quartic => "Using polynomial solver",
             EquationType::System => "Using system equation solver",
             EquationType::Numerical => {
                 "Using numerical solver (Newton-Raphson method with numerical differentiation)"
             }
             EquationType::Matrix => "Using matrix equation solver (left/right division)",
+            EquationType::Rounding => "Using rounding solver (integer bounds of floor/ceiling)",
+            EquationType::MinMax => "Using min/max solver (case split on the selected argument)",
             EquationType::ODE => "Using ODE solver (separable/linear/exact methods)",
             EquationType::PDE => {
                 "Using PDE solver (method of characteristics/separation of variables)"
@@ -370,13 +477,33 @@ impl SmartEquationSolver {
 
         all_steps.push(Step::new("Solver Selection", solver_description));
 
+        let mut algorithm = match eq_type {
+            EquationType::Linear => AlgorithmId::LinearIsolation,
+            EquationType::Cubic | EquationType::Quartic => AlgorithmId::PolynomialRoots,
+            EquationType::System => AlgorithmId::SystemElimination,
+            EquationType::Numerical => AlgorithmId::NumericFallback,
+            EquationType::Matrix => AlgorithmId::MatrixDivision,
+            EquationType::Rounding => AlgorithmId::RoundingBounds,
+            EquationType::MinMax => AlgorithmId::MinMaxCases,
+            EquationType::ODE => AlgorithmId::OdeMethods,
+            EquationType::PDE => AlgorithmId::PdeMethods,
+            EquationType::Quadratic
+            | EquationType::Constant
+            | EquationType::Transcendental
+            | EquationType::Unknown => AlgorithmId::Unevaluated,
+        };
+
         let (result, solver_steps) = match eq_type {
             EquationType::Linear => self
                 .linear_solver
                 .solve_with_explanation(equation, variable),
-            EquationType::Quadratic => self
-                .quadratic_solver
-                .solve_with_explanation(equation, variable),
+            EquationType::Quadratic => {
+                let (result, steps, used) = self
+                    .quadratic_solver
+                    .solve_with_algorithm(equation, variable);
+                algorithm = used;
+                (result, steps)
+            }
             EquationType::Cubic | EquationType::Quartic => self
                 .polynomial_solver
                 .solve_with_explanation(equation, variable),
@@ -387,6 +514,12 @@ impl SmartEquationSolver {
             EquationType::Matrix => self
                 .matrix_solver
                 .solve_with_explanation(equation, variable),
+            EquationType::Rounding => self
+                .rounding_solver
+                .solve_with_explanation(equation, variable),
+            EquationType::MinMax => self
+                .min_max_solver
+                .solve_with_explanation(equation, variable),
             EquationType::ODE => self.ode_solver.solve_with_explanation(equation, variable),
             EquationType::PDE => self.pde_solver.solve_with_explanation(equation, variable),
             _ => {
@@ -400,7 +533,7 @@ impl SmartEquationSolver {
 
         all_steps.extend(solver_steps.steps);
 
-        (result, StepByStepExplanation::new(all_steps))
+        (result, StepByStepExplanation::new(all_steps), algorithm)
     }
 
     fn solve_numerical(
@@ -494,7 +627,7 @@ impl SmartEquationSolver {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::symbol;
+    use crate::{expr, symbol};
 
     #[test]
     fn test_equation_type_detection() {
@@ -560,4 +693,73 @@ mod tests {
             EquationType::Matrix
         );
     }
+
+    #[test]
+    fn test_floor_equation_routes_to_rounding_solver() {
+        let x = symbol!(x);
+        let equation = Expression::add(vec![
+            Expression::function("floor", vec![Expression::symbol(x.clone())]),
+            Expression::integer(-3),
+        ]);
+        assert_eq!(
+            EquationAnalyzer::analyze(&equation, &x),
+            EquationType::Rounding
+        );
+
+        let (result, _) = SmartEquationSolver::new().solve_with_equation(&equation, &x);
+        assert_eq!(
+            result,
+            SolverResult::Single(Expression::interval(
+                Expression::integer(3),
+                Expression::integer(4),
+                true,
+                false,
+            ))
+        );
+    }
+
+    #[test]
+    fn test_max_equation_routes_to_min_max_solver() {
+        let x = symbol!(x);
+        let equation = Expression::add(vec![
+            Expression::function(
+                "max",
+                vec![Expression::symbol(x.clone()), Expression::integer(1)],
+            ),
+            Expression::integer(-4),
+        ]);
+        assert_eq!(
+            EquationAnalyzer::analyze(&equation, &x),
+            EquationType::MinMax
+        );
+
+        let (result, _) = SmartEquationSolver::new().solve_with_equation(&equation, &x);
+        assert_eq!(result, SolverResult::Single(Expression::integer(4)));
+    }
+
+    #[test]
+    fn test_curriculum_restricts_techniques() {
+        use crate::educational::curriculum::Curriculum;
+
+        let x = symbol!(x);
+        let quadratic = expr!((x ^ 2) - (5 * x) + 6);
+        let algebra = Curriculum::algebra_1();
+
+        let solver = SmartEquationSolver::new().with_curriculum(algebra.through_unit(4));
+        let (result, explanation) = solver.solve_with_equation(&quadratic, &x);
+        assert!(matches!(result, SolverResult::Multiple(ref roots) if roots.len() == 2));
+        assert!(explanation.steps.iter().any(|s| s.title == "Factor"));
+        assert!(explanation
+            .steps
+            .iter()
+            .all(|s| s.title != "Quadratic Formula"));
+
+        let solver = SmartEquationSolver::new().with_curriculum(algebra.through_unit(1));
+        let (result, explanation) = solver.solve_with_equation(&expr!((2 * x) - 4), &x);
+        assert_eq!(result, SolverResult::NoSolution);
+        assert!(explanation
+            .steps
+            .iter()
+            .any(|s| s.title == "Technique Not Yet Learned"));
+    }
 }
@@ -1,8 +1,18 @@
 //! Expression expansion operations
 //! Handles polynomial expansion, distribution, and algebraic expansion
 
+use crate::algebra::solvers::completing_square::{as_rational, from_rational};
 use crate::core::commutativity::Commutativity;
+use crate::core::expression::ExpressionBuilder;
 use crate::core::{Expression, Number};
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::{One, Zero};
+use std::collections::BTreeMap;
+use std::slice;
+
+/// Largest number of terms the multinomial expansion of a power may produce
+const MAX_MULTINOMIAL_TERMS: u128 = 250_000;
 
 /// Trait for expanding expressions
 pub trait Expand {
@@ -25,7 +35,7 @@ impl Expand for Expression {
 
             Expression::Pow(base, exp) => self.expand_power(base, exp),
 
-            Expression::Function { name, args } => {
+            Expression::Function { name, args, .. } => {
                 let expanded_args: Vec<Expression> = args.iter().map(|arg| arg.expand()).collect();
                 Expression::function(name.clone(), expanded_args)
             }
@@ -78,9 +88,21 @@ impl Expression {
     }
 
     /// Expand power expressions
+    ///
+    /// Powers of commutative sums go through the multinomial theorem for any
+    /// positive integer exponent whose expansion stays below
+    /// `MAX_MULTINOMIAL_TERMS` terms; other integer powers up to 10 are
+    /// multiplied out.
     fn expand_power(&self, base: &Expression, exp: &Expression) -> Expression {
         if let Expression::Number(Number::Integer(n)) = exp {
             let exp_val = *n;
+            if let Some(expanded) = u32::try_from(exp_val)
+                .ok()
+                .filter(|&n| n >= 2)
+                .and_then(|n| expand_multinomial(&base.expand(), n))
+            {
+                return expanded;
+            }
             if (0..=10).contains(&exp_val) {
                 return self.expand_integer_power(base, exp_val as u32);
             }
@@ -223,10 +245,174 @@ impl Expression {
     }
 }
 
+/// Expand `(t₁ + … + tₘ)ⁿ` by the multinomial theorem
+///
+/// Each term is split into a rational coefficient `cᵢ` and integer powers of
+/// atoms (exponent vector `eᵢ`). Every composition `k₁ + … + kₘ = n` adds
+/// `n! / (k₁!⋯kₘ!) · Π cᵢ^kᵢ` to the coefficient of `Σ kᵢ eᵢ` in a flat map,
+/// so no intermediate product is built as a tree and like terms are merged
+/// as they are generated.
+///
+/// Returns `None` if `base` is not a sum, has noncommutative or non-rational
+/// numeric factors, or would expand to more than `MAX_MULTINOMIAL_TERMS`
+/// terms.
+fn expand_multinomial(base: &Expression, n: u32) -> Option<Expression> {
+    let Expression::Add(terms) = base else {
+        return None;
+    };
+    if !Commutativity::combine(terms.iter().map(|t| t.commutativity())).can_sort()
+        || multinomial_term_count(terms.len(), n)? > MAX_MULTINOMIAL_TERMS
+    {
+        return None;
+    }
+
+    let mut atoms: Vec<Expression> = Vec::new();
+    let mut split = Vec::with_capacity(terms.len());
+    for term in terms.iter() {
+        let factors = match term {
+            Expression::Mul(factors) => factors.as_slice(),
+            other => slice::from_ref(other),
+        };
+        let mut coefficient = BigRational::one();
+        let mut powers = Vec::new();
+        for factor in factors {
+            let (atom, power) = match factor {
+                Expression::Number(_) => {
+                    coefficient *= as_rational(factor)?;
+                    continue;
+                }
+                Expression::Pow(atom, exp) => match exp.as_ref() {
+                    Expression::Number(Number::Integer(k)) => (atom.as_ref(), *k),
+                    _ => (factor, 1),
+                },
+                other => (other, 1),
+            };
+            let index = match atoms.iter().position(|a| a == atom) {
+                Some(index) => index,
+                None => {
+                    atoms.push(atom.clone());
+                    atoms.len() - 1
+                }
+            };
+            powers.push((index, power));
+        }
+        split.push((coefficient, powers));
+    }
+
+    let exponents: Vec<Vec<i64>> = split
+        .iter()
+        .map(|(_, powers)| {
+            let mut exponents = vec![0; atoms.len()];
+            for &(index, power) in powers {
+                exponents[index] += power;
+            }
+            exponents
+        })
+        .collect();
+    let coefficient_powers: Vec<Vec<BigRational>> = split
+        .iter()
+        .map(|(c, _)| {
+            let mut powers = vec![BigRational::one()];
+            for k in 0..n as usize {
+                powers.push(&powers[k] * c);
+            }
+            powers
+        })
+        .collect();
+
+    let mut expansion = Multinomial {
+        binomials: pascal_triangle(n),
+        exponents,
+        coefficient_powers,
+        terms: BTreeMap::new(),
+    };
+    expansion.accumulate(0, n, BigRational::one(), vec![0; atoms.len()]);
+
+    let mut sum = ExpressionBuilder::sum();
+    sum.reserve(expansion.terms.len());
+    for (exponents, coefficient) in expansion.terms {
+        if coefficient.is_zero() {
+            continue;
+        }
+        let mut factors = vec![from_rational(coefficient)];
+        for (atom, &power) in atoms.iter().zip(&exponents) {
+            match power {
+                0 => {}
+                1 => factors.push(atom.clone()),
+                _ => factors.push(Expression::pow(atom.clone(), Expression::integer(power))),
+            }
+        }
+        sum.push(Expression::mul(factors));
+    }
+    Some(sum.build())
+}
+
+/// Flat accumulator for [`expand_multinomial`]
+struct Multinomial {
+    binomials: Vec<Vec<BigInt>>,
+    exponents: Vec<Vec<i64>>,
+    coefficient_powers: Vec<Vec<BigRational>>,
+    terms: BTreeMap<Vec<i64>, BigRational>,
+}
+
+impl Multinomial {
+    /// Distribute `remaining` of the exponent over terms `index..`
+    fn accumulate(
+        &mut self,
+        index: usize,
+        remaining: u32,
+        coefficient: BigRational,
+        exps: Vec<i64>,
+    ) {
+        let last = index + 1 == self.exponents.len();
+        let choices = if last {
+            remaining..=remaining
+        } else {
+            0..=remaining
+        };
+        for k in choices {
+            let mut next = exps.clone();
+            for (e, &d) in next.iter_mut().zip(&self.exponents[index]) {
+                *e += d * k as i64;
+            }
+            let scaled = &coefficient
+                * &self.coefficient_powers[index][k as usize]
+                * BigRational::from_integer(self.binomials[remaining as usize][k as usize].clone());
+            if last {
+                *self.terms.entry(next).or_insert_with(BigRational::zero) += scaled;
+            } else {
+                self.accumulate(index + 1, remaining - k, scaled, next);
+            }
+        }
+    }
+}
+
+/// Rows `0..=n` of Pascal's triangle
+fn pascal_triangle(n: u32) -> Vec<Vec<BigInt>> {
+    let mut rows: Vec<Vec<BigInt>> = vec![vec![BigInt::one()]];
+    for r in 1..=n as usize {
+        let previous = &rows[r - 1];
+        let mut row = vec![BigInt::one(); r + 1];
+        for k in 1..r {
+            row[k] = &previous[k - 1] + &previous[k];
+        }
+        rows.push(row);
+    }
+    rows
+}
+
+/// `C(n + m - 1, m - 1)`, the number of monomials of degree `n` in `m` terms
+fn multinomial_term_count(m: usize, n: u32) -> Option<u128> {
+    let (top, k) = (n as u128 + m as u128 - 1, m as u128 - 1);
+    let k = k.min(top - k);
+    (0..k).try_fold(1u128, |acc, i| Some(acc.checked_mul(top - i)? / (i + 1)))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::symbol;
+    use crate::simplify::Simplify;
+    use crate::{expr, symbol};
 
     #[test]
     fn test_basic_expansion() {
@@ -455,6 +641,38 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_multinomial_expansion() {
+        let expanded = Expression::pow(expr!(x + y + z + w), Expression::integer(15)).expand();
+        let Expression::Add(terms) = &expanded else {
+            panic!("expected a sum, got {}", expanded);
+        };
+        assert_eq!(terms.len(), 816);
+        // 15! / (5! 5! 5!) x⁵y⁵z⁵
+        let central = Expression::mul(vec![
+            Expression::integer(756_756),
+            expr!(x ^ 5),
+            expr!(y ^ 5),
+            expr!(z ^ 5),
+        ]);
+        assert!(terms.iter().any(|term| term.simplify() == central));
+
+        let base = Expression::add(vec![
+            expr!(x),
+            expr!(2 * y),
+            Expression::mul(vec![Expression::rational(-1, 2), expr!(x * (z ^ 2))]),
+            expr!(3),
+        ]);
+        let multinomial = Expression::pow(base.clone(), Expression::integer(4)).expand();
+        let repeated =
+            Expression::mul(vec![base.clone(), base.clone(), base.clone(), base]).expand();
+        let difference = Expression::add(vec![
+            multinomial,
+            Expression::mul(vec![Expression::integer(-1), repeated]),
+        ]);
+        assert!(difference.expand().is_zero());
+    }
+
     #[test]
     fn test_binomial_theorem_not_used_for_noncommutative() {
         let a = symbol!(A; matrix);
@@ -1,12 +1,19 @@
 //! Factorization operations for expressions
 //! Handles polynomial factorization, common factor extraction, and algebraic factoring
+//!
+//! Univariate polynomials with rational coefficients are factored completely
+//! into irreducibles over Q; other sums fall back to common factor extraction.
 
 mod common;
 mod noncommutative;
 mod quadratic;
+mod rational;
 
+use crate::algebra::gcd::{divide_out, PolynomialGcd};
+use crate::algebra::solvers::Assumptions;
 use crate::core::commutativity::Commutativity;
 use crate::core::Expression;
+use crate::simplify::radicals::rewrite_radical;
 // num_traits imports removed
 
 /// Trait for factoring expressions
@@ -32,9 +39,18 @@ impl Factor for Expression {
 
             Expression::Pow(base, exp) => Expression::pow(base.factor(), exp.factor()),
 
-            Expression::Function { name, args } => {
+            Expression::Function { name, args, .. } => {
                 let factored_args: Vec<Expression> = args.iter().map(|arg| arg.factor()).collect();
-                Expression::function(name.clone(), factored_args)
+                // sqrt(x^2 + x^2 y) = sqrt(x^2 (1 + y)) = x sqrt(1 + y) for nonnegative x
+                let exposed: Vec<Expression> = factored_args
+                    .iter()
+                    .map(|arg| match arg {
+                        Expression::Add(_) => arg.factor_out_gcd(),
+                        _ => arg.clone(),
+                    })
+                    .collect();
+                rewrite_radical(name, &exposed, &Assumptions::new())
+                    .unwrap_or_else(|| Expression::function(name.clone(), factored_args))
             }
             _ => self.clone(),
         }
@@ -48,23 +64,31 @@ impl Factor for Expression {
                     return self.clone();
                 }
 
-                let mut common_factor = terms[0].clone();
-                for term in &terms[1..] {
-                    common_factor = common_factor.gcd(term);
-                    if common_factor.is_one() {
-                        return self.clone();
-                    }
-                }
-
-                if !common_factor.is_one() {
-                    let factored_terms: Vec<Expression> = terms
-                        .iter()
-                        .map(|term| self.divide_by_factor(term, &common_factor))
-                        .collect();
+                // Rational content first, so x/2 + y/3 becomes (3x + 2y)/6
+                let (content, primitive) = self.primitive();
+                let primitive_terms = match &primitive {
+                    Expression::Add(primitive_terms) => primitive_terms.as_slice(),
+                    _ => return self.clone(),
+                };
 
-                    Expression::mul(vec![common_factor, Expression::add(factored_terms)])
+                let common_factor = Expression::gcd_many(primitive_terms);
+                let factored_terms: Vec<Expression> = if common_factor.is_one() {
+                    primitive_terms.to_vec()
                 } else {
-                    self.clone()
+                    primitive_terms
+                        .iter()
+                        .map(|term| divide_out(term, &common_factor))
+                        .collect()
+                };
+
+                let factors: Vec<Expression> = [content, common_factor]
+                    .into_iter()
+                    .filter(|f| !f.is_one())
+                    .chain(std::iter::once(Expression::add(factored_terms)))
+                    .collect();
+                match factors.len() {
+                    1 => self.clone(),
+                    _ => Expression::mul(factors),
                 }
             }
             _ => self.clone(),
@@ -92,6 +116,10 @@ impl Expression {
         let commutativity = Commutativity::combine(terms.iter().map(|t| t.commutativity()));
 
         if commutativity.can_sort() {
+            if let Some(factored) = self.try_rational_factoring() {
+                return factored;
+            }
+
             let common_factor = self.find_common_factor_in_terms(terms);
 
             if !common_factor.is_one() {
@@ -122,7 +150,7 @@ impl Expression {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::symbol;
+    use crate::{expr, symbol};
     use num_bigint::BigInt;
 
     #[test]
@@ -158,6 +186,32 @@ mod tests {
         assert!(!result.is_zero());
     }
 
+    #[test]
+    fn test_factor_out_rational_content() {
+        let x = symbol!(x);
+        let y = symbol!(y);
+        let expr = Expression::add(vec![
+            Expression::mul(vec![
+                Expression::rational(1, 2),
+                Expression::symbol(x.clone()),
+            ]),
+            Expression::mul(vec![
+                Expression::rational(1, 3),
+                Expression::symbol(y.clone()),
+            ]),
+        ]);
+        assert_eq!(
+            expr.factor_out_gcd(),
+            Expression::mul(vec![Expression::rational(1, 6), expr!((3 * x) + (2 * y))])
+        );
+
+        assert_eq!(
+            expr!((6 * (x ^ 2)) + (9 * x)).factor_out_gcd(),
+            Expression::mul(vec![expr!(3), expr!(x), expr!((2 * x) + 3)])
+        );
+        assert_eq!(expr!(x + y).factor_out_gcd(), expr!(x + y));
+    }
+
     #[test]
     fn test_numeric_coefficient_extraction() {
         let x = symbol!(x);
@@ -227,6 +281,31 @@ mod tests {
         assert_eq!(result, expr);
     }
 
+    #[test]
+    fn test_factor_over_rationals() {
+        assert_eq!(
+            expr!((x ^ 4) - 1).factor(),
+            Expression::mul(vec![expr!(x - 1), expr!(x + 1), expr!((x ^ 2) + 1)])
+        );
+        // Irreducible mod every prime yet reducible over Z
+        assert_eq!(
+            expr!((x ^ 4) + 4).factor(),
+            Expression::mul(vec![
+                expr!((x ^ 2) - (2 * x) + 2),
+                expr!((x ^ 2) + (2 * x) + 2)
+            ])
+        );
+        assert_eq!(
+            expr!(((x ^ 2) / 2) - (1 / 2)).factor(),
+            Expression::mul(vec![Expression::rational(1, 2), expr!(x - 1), expr!(x + 1)])
+        );
+        assert_eq!(
+            expr!((x ^ 3) - (x ^ 2) - x + 1).factor(),
+            Expression::mul(vec![expr!((x - 1) ^ 2), expr!(x + 1)])
+        );
+        assert_eq!(expr!((x ^ 2) + 1).factor(), expr!((x ^ 2) + 1));
+    }
+
     #[test]
     fn test_left_factoring_matrices() {
         let a = symbol!(A; matrix);
@@ -395,4 +474,33 @@ mod tests {
         // Should be able to factor out AB
         assert!(!result.is_zero());
     }
+
+    #[test]
+    fn test_sqrt_factoring_respects_assumptions() {
+        use crate::core::symbol::{Assumption, Symbol};
+        use crate::simplify::Simplify;
+
+        let sqrt = |arg: Expression| Expression::function("sqrt", vec![arg]);
+        let radicand = |x: &Expression| {
+            let square = Expression::pow(x.clone(), Expression::integer(2));
+            Expression::add(vec![
+                square.clone(),
+                Expression::mul(vec![expr!(y), square]),
+            ])
+        };
+
+        let positive =
+            Expression::symbol(Symbol::new_with_assumptions("x", &[Assumption::Positive]));
+        let expected = Expression::mul(vec![
+            positive.clone(),
+            sqrt(Expression::add(vec![Expression::integer(1), expr!(y)])),
+        ]);
+        assert_eq!(
+            sqrt(radicand(&positive)).factor().simplify(),
+            expected.simplify()
+        );
+
+        let plain = sqrt(radicand(&expr!(x)));
+        assert_eq!(plain.factor(), plain);
+    }
 }
@@ -97,21 +97,18 @@ impl Expression {
 
             (Expression::Symbol(s1), Expression::Symbol(s2)) if s1 == s2 => Expression::integer(1),
 
-            (Expression::Mul(factors), _) => {
-                let mut remaining_factors = factors.as_ref().clone();
-                if let Some(pos) = remaining_factors.iter().position(|f| f == factor) {
+            (Expression::Mul(factors), _) => match factors.iter().position(|f| f == factor) {
+                Some(pos) => {
+                    let mut remaining_factors = factors.to_vec();
                     remaining_factors.remove(pos);
-                    if remaining_factors.is_empty() {
-                        Expression::integer(1)
-                    } else if remaining_factors.len() == 1 {
-                        remaining_factors[0].clone()
-                    } else {
-                        Expression::mul(remaining_factors)
+                    match remaining_factors.len() {
+                        0 => Expression::integer(1),
+                        1 => remaining_factors.swap_remove(0),
+                        _ => Expression::mul(remaining_factors),
                     }
-                } else {
-                    expr.clone()
                 }
-            }
+                None => expr.clone(),
+            },
 
             _ => expr.clone(),
         }
@@ -0,0 +1,89 @@
+//! Complete factorization of univariate polynomials over the rationals
+//!
+//! The polynomial is split into square-free parts over Q, each part is scaled
+//! to a primitive integer polynomial, and that is factored into irreducibles
+//! over Z by Zassenhaus' algorithm (factorization mod p, Hensel lifting and
+//! recombination). By Gauss' lemma these are also the irreducible factors
+//! over Q, leaving a single rational constant in front.
+
+use crate::algebra::partial_fractions::{ratio_expr, to_rational_function, Q};
+use crate::core::polynomial::algorithms::{
+    content_primitive_part, factor_square_free, square_free_factorization_poly,
+};
+use crate::core::polynomial::poly::{IntPoly, RationalPoly};
+use crate::core::{Expression, Symbol};
+use num_integer::Integer;
+use num_traits::One;
+
+impl Expression {
+    /// Factor a polynomial in one variable with rational coefficients into
+    /// irreducibles over Q
+    ///
+    /// Returns `None` when the expression is not such a polynomial of degree
+    /// at least 2, or when it does not split.
+    pub(super) fn try_rational_factoring(&self) -> Option<Expression> {
+        let [var] = self.find_variables().try_into().ok()?;
+        let f = to_rational_function(self, &var)?;
+        if !f.den.is_constant() || f.num.degree()? < 2 {
+            return None;
+        }
+
+        let mut irreducibles = Vec::new();
+        for (part, multiplicity) in square_free_factorization_poly(&f.num).ok()? {
+            if part.is_constant() {
+                continue;
+            }
+            let primitive = primitive_integer_part(&part)?;
+            let factors = factor_square_free(&primitive).unwrap_or_else(|| vec![primitive]);
+            irreducibles.extend(factors.into_iter().map(|g| (g, multiplicity)));
+        }
+        if irreducibles.iter().map(|(_, m)| m).sum::<usize>() < 2 {
+            return None;
+        }
+
+        let content = rational_content(&f.num, &irreducibles)?;
+        Some(product_expression(&content, &irreducibles, &var))
+    }
+}
+
+/// `poly` scaled to a primitive integer polynomial with positive leading
+/// coefficient
+fn primitive_integer_part(poly: &RationalPoly) -> Option<IntPoly> {
+    let denominator = poly
+        .coefficients()
+        .iter()
+        .fold(1i64, |acc, c| acc.lcm(c.denom()));
+    let coeffs = poly
+        .coefficients()
+        .iter()
+        .map(|c| c.numer().checked_mul(denominator / c.denom()))
+        .collect::<Option<Vec<i64>>>()?;
+    Some(content_primitive_part(&IntPoly::from_coeffs(coeffs)).1)
+}
+
+/// c with `poly` = c · ∏ gᵢ^mᵢ, or `None` if the factors do not multiply back
+/// to `poly`
+fn rational_content(poly: &RationalPoly, factors: &[(IntPoly, usize)]) -> Option<Q> {
+    let product = factors
+        .iter()
+        .fold(RationalPoly::constant(Q::one()), |acc, (g, m)| {
+            let g = RationalPoly::from_coeffs(
+                g.coefficients()
+                    .iter()
+                    .map(|&c| Q::from_integer(c))
+                    .collect(),
+            );
+            (0..*m).fold(acc, |acc, _| &acc * &g)
+        });
+    let content = poly.leading_coeff() / product.leading_coeff();
+    (product.scale(&content) == *poly).then_some(content)
+}
+
+fn product_expression(content: &Q, factors: &[(IntPoly, usize)], var: &Symbol) -> Expression {
+    let constant = (!content.is_one()).then(|| ratio_expr(content));
+    let powers = factors.iter().map(|(g, m)| match m {
+        1 => g.to_expression(var),
+        _ => Expression::pow(g.to_expression(var), Expression::integer(*m as i64)),
+    });
+    Expression::mul(constant.into_iter().chain(powers).collect())
+}
@@ -1,9 +1,12 @@
 //! Greatest Common Divisor operations for polynomials and expressions
 
+use crate::algebra::solvers::completing_square::{as_rational, from_rational};
 use crate::core::polynomial::IntPoly;
 use crate::core::polynomial::PolynomialError;
 use crate::core::{Expression, Number, Symbol};
 use num_integer::Integer;
+use num_rational::BigRational;
+use num_traits::{One, Signed, Zero};
 
 /// Trait for GCD operations on expressions
 pub trait PolynomialGcd {
@@ -12,6 +15,67 @@ pub trait PolynomialGcd {
     fn factor_gcd(&self) -> Self;
     fn cofactors(&self, other: &Self) -> (Expression, Expression, Expression);
 
+    /// GCD of any number of expressions
+    ///
+    /// Rational contents combine as `gcd(numerators) / lcm(denominators)`,
+    /// so the result divides every input with an integer cofactor. Zeros are
+    /// ignored, and the GCD of no expressions is `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::{expr, Expression, algebra::gcd::PolynomialGcd};
+    ///
+    /// let gcd = Expression::gcd_many(&[expr!(6 * (x ^ 2)), expr!(4 * x), expr!(10 * (x ^ 3))]);
+    /// assert_eq!(gcd, expr!(2 * x));
+    ///
+    /// let halves_and_thirds = Expression::gcd_many(&[
+    ///     Expression::rational(1, 2),
+    ///     Expression::rational(2, 3),
+    /// ]);
+    /// assert_eq!(halves_and_thirds, Expression::rational(1, 6));
+    /// ```
+    fn gcd_many(expressions: &[Self]) -> Self
+    where
+        Self: Sized;
+
+    /// LCM of any number of expressions
+    ///
+    /// The LCM of no expressions is `1`, and of any list containing zero is
+    /// `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::{expr, Expression, algebra::gcd::PolynomialGcd};
+    ///
+    /// let lcm = Expression::lcm_many(&[expr!(4 * x), expr!(6 * (x ^ 2)), expr!(3)]);
+    /// assert_eq!(lcm, expr!(12 * (x ^ 2)));
+    /// ```
+    fn lcm_many(expressions: &[Self]) -> Self
+    where
+        Self: Sized;
+
+    /// Split into `(content, primitive part)` over the rationals
+    ///
+    /// The content is the positive rational that leaves the primitive part
+    /// with coprime integer coefficients, and `content * primitive == self`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::{expr, Expression, algebra::gcd::PolynomialGcd};
+    ///
+    /// let poly = Expression::add(vec![
+    ///     Expression::mul(vec![Expression::rational(1, 2), expr!(x)]),
+    ///     Expression::mul(vec![Expression::rational(1, 3), expr!(y)]),
+    /// ]);
+    /// let (content, primitive) = poly.primitive();
+    /// assert_eq!(content, Expression::rational(1, 6));
+    /// assert_eq!(primitive, expr!((3 * x) + (2 * y)));
+    /// ```
+    fn primitive(&self) -> (Expression, Expression);
+
     /// Divides this polynomial by another, returning (quotient, remainder).
     ///
     /// Performs polynomial long division with respect to the specified variable.
@@ -184,14 +248,10 @@ impl PolynomialGcd for Expression {
                     return self.clone();
                 }
 
-                let mut common_gcd = terms[0].clone();
-                for term in &terms[1..] {
-                    common_gcd = common_gcd.gcd(term);
-                    if common_gcd.is_one() {
-                        return self.clone();
-                    }
+                let common_gcd = Expression::gcd_many(terms);
+                if common_gcd.is_one() {
+                    return self.clone();
                 }
-
                 common_gcd
             }
             Expression::Mul(_factors) => self.clone(),
@@ -242,6 +302,54 @@ impl PolynomialGcd for Expression {
         (gcd_val, self.clone(), other.clone())
     }
 
+    fn gcd_many(expressions: &[Self]) -> Self {
+        let (contents, primitives): (Vec<_>, Vec<_>) = expressions
+            .iter()
+            .filter(|e| !e.is_zero())
+            .map(split_unsigned)
+            .unzip();
+        let Some(content) = contents.into_iter().reduce(|a, b| rational_gcd(&a, &b)) else {
+            return Expression::integer(0);
+        };
+
+        let mut common = primitives[0].clone();
+        for primitive in &primitives[1..] {
+            if common.is_one() {
+                break;
+            }
+            common = primitive_gcd(&common, primitive);
+        }
+        scaled(&content, common)
+    }
+
+    fn lcm_many(expressions: &[Self]) -> Self {
+        if expressions.iter().any(|e| e.is_zero()) {
+            return Expression::integer(0);
+        }
+        let (contents, primitives): (Vec<_>, Vec<_>) =
+            expressions.iter().map(split_unsigned).unzip();
+        let Some(content) = contents.into_iter().reduce(|a, b| rational_lcm(&a, &b)) else {
+            return Expression::integer(1);
+        };
+
+        let mut common = primitives[0].clone();
+        for primitive in &primitives[1..] {
+            let gcd = primitive_gcd(&common, primitive);
+            let cofactor = if gcd.is_one() {
+                primitive.clone()
+            } else {
+                divide_out(primitive, &gcd)
+            };
+            common = Expression::mul(vec![common, cofactor]);
+        }
+        scaled(&content, common)
+    }
+
+    fn primitive(&self) -> (Expression, Expression) {
+        let (content, primitive) = split_content(self);
+        (from_rational(content), primitive)
+    }
+
     fn div_polynomial(&self, divisor: &Expression, var: &Symbol) -> (Expression, Expression) {
         crate::algebra::polynomial_division::polynomial_div(self, divisor, var)
             .unwrap_or_else(|_| (Expression::undefined(), Expression::undefined()))
@@ -256,6 +364,161 @@ impl PolynomialGcd for Expression {
     }
 }
 
+/// Rational content and primitive part of an expression
+fn split_content(expr: &Expression) -> (BigRational, Expression) {
+    match expr {
+        Expression::Add(terms) => {
+            let parts: Vec<_> = terms.iter().map(split_content).collect();
+            let content = parts
+                .iter()
+                .map(|(c, _)| c.clone())
+                .reduce(|a, b| rational_gcd(&a, &b))
+                .unwrap_or_else(BigRational::one);
+            if content.is_zero() {
+                return (BigRational::one(), expr.clone());
+            }
+            let terms = parts
+                .into_iter()
+                .map(|(c, p)| scaled(&(c / &content), p))
+                .collect();
+            (content, Expression::add(terms))
+        }
+        Expression::Mul(factors) => {
+            let mut coefficient = BigRational::one();
+            let mut rest = Vec::new();
+            for factor in factors.iter() {
+                match as_rational(factor) {
+                    Some(r) => coefficient *= r,
+                    None => rest.push(factor.clone()),
+                }
+            }
+            let sign = BigRational::from_integer(coefficient.numer().signum());
+            (coefficient.abs(), scaled(&sign, Expression::mul(rest)))
+        }
+        _ => match as_rational(expr) {
+            Some(r) if !r.is_zero() => (r.abs(), Expression::big_integer(r.numer().signum())),
+            _ => (BigRational::one(), expr.clone()),
+        },
+    }
+}
+
+/// Content and primitive part with a `-1` factor dropped, since GCDs and
+/// LCMs are only defined up to sign
+fn split_unsigned(expr: &Expression) -> (BigRational, Expression) {
+    let (content, primitive) = split_content(expr);
+    let unsigned = match primitive {
+        Expression::Number(Number::Integer(-1)) => Expression::integer(1),
+        Expression::Mul(factors) if factors.contains(&Expression::integer(-1)) => Expression::mul(
+            factors
+                .iter()
+                .filter(|f| **f != Expression::integer(-1))
+                .cloned()
+                .collect(),
+        ),
+        other => other,
+    };
+    (content, unsigned)
+}
+
+/// GCD of two primitive parts, dispatching on the variables they share
+fn primitive_gcd(a: &Expression, b: &Expression) -> Expression {
+    let mut vars = a.find_variables();
+    for var in b.find_variables() {
+        if !vars.contains(&var) {
+            vars.push(var);
+        }
+    }
+    vars.sort_by(|p, q| p.name().cmp(q.name()));
+
+    let gcd = match vars.len() {
+        0 => Expression::integer(1),
+        1 => a.gcd(b),
+        _ => match (monomial_powers(a), monomial_powers(b)) {
+            (Some(p), Some(q)) => monomial_gcd(&p, &q),
+            _ => crate::algebra::multivariate_gcd::multivariate_gcd(a, b, &vars),
+        },
+    };
+    match &gcd {
+        Expression::Number(_) => Expression::integer(1),
+        _ => gcd,
+    }
+}
+
+/// `(base, exponent)` factors of a product of powers, ignoring numbers
+fn monomial_powers(expr: &Expression) -> Option<Vec<(Expression, i64)>> {
+    let factors = match expr {
+        Expression::Mul(factors) => factors.as_slice(),
+        other => std::slice::from_ref(other),
+    };
+    let mut powers = Vec::new();
+    for factor in factors {
+        match factor {
+            Expression::Number(_) => {}
+            Expression::Add(_) => return None,
+            Expression::Pow(base, exp) => match exp.as_ref() {
+                Expression::Number(Number::Integer(n)) if *n > 0 => {
+                    powers.push((base.as_ref().clone(), *n))
+                }
+                _ => powers.push((factor.clone(), 1)),
+            },
+            other => powers.push((other.clone(), 1)),
+        }
+    }
+    Some(powers)
+}
+
+fn monomial_gcd(a: &[(Expression, i64)], b: &[(Expression, i64)]) -> Expression {
+    Expression::mul(
+        a.iter()
+            .filter_map(|(base, p)| {
+                let q = b.iter().find(|(other, _)| other == base)?.1;
+                Some(Expression::pow(
+                    base.clone(),
+                    Expression::integer((*p).min(q)),
+                ))
+            })
+            .collect(),
+    )
+}
+
+/// Exact quotient of `expr` by a divisor of it
+pub(crate) fn divide_out(expr: &Expression, divisor: &Expression) -> Expression {
+    let vars = expr.find_variables();
+    if let [var] = vars.as_slice() {
+        if let Ok((quotient, remainder)) =
+            crate::algebra::polynomial_division::polynomial_div(expr, divisor, var)
+        {
+            if remainder.is_zero() {
+                return quotient;
+            }
+        }
+    }
+    let factors = match divisor {
+        Expression::Mul(factors) => factors.as_slice(),
+        other => std::slice::from_ref(other),
+    };
+    factors.iter().fold(expr.clone(), |quotient, factor| {
+        Expression::div(quotient, factor.clone())
+    })
+}
+
+fn rational_gcd(a: &BigRational, b: &BigRational) -> BigRational {
+    BigRational::new(a.numer().gcd(b.numer()), a.denom().lcm(b.denom()))
+}
+
+fn rational_lcm(a: &BigRational, b: &BigRational) -> BigRational {
+    BigRational::new(a.numer().lcm(b.numer()), a.denom().gcd(b.denom()))
+}
+
+/// `coefficient * expr`, leaving out a unit coefficient
+fn scaled(coefficient: &BigRational, expr: Expression) -> Expression {
+    if coefficient.is_one() {
+        expr
+    } else {
+        Expression::mul(vec![from_rational(coefficient.clone()), expr])
+    }
+}
+
 /// Symbolic Euclidean GCD algorithm
 ///
 /// Minimal fallback for rational coefficient polynomials.
@@ -457,6 +720,7 @@ pub fn univariate_gcd_modular(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::algebra::Expand;
     use crate::expr;
     use crate::symbol;
 
@@ -610,6 +874,51 @@ mod tests {
         assert!(!gcd.is_zero());
     }
 
+    #[test]
+    fn test_gcd_and_lcm_of_many() {
+        let terms = [
+            expr!(12 * (x ^ 2) * y),
+            expr!(18 * x * (y ^ 3)),
+            expr!(-6 * x * y),
+        ];
+        assert_eq!(Expression::gcd_many(&terms), expr!(6 * x * y));
+        assert_eq!(Expression::lcm_many(&terms), expr!(36 * (x ^ 2) * (y ^ 3)));
+
+        let polys = [
+            expr!((x ^ 2) - 1),
+            expr!((x ^ 2) + (2 * x) + 1),
+            expr!(3 * x + 3),
+        ];
+        assert_eq!(Expression::gcd_many(&polys), expr!(x + 1));
+
+        assert_eq!(Expression::gcd_many(&[expr!(x), expr!(y)]), expr!(1));
+        assert_eq!(
+            Expression::gcd_many(&[expr!(0), expr!(4 * x)]),
+            expr!(4 * x)
+        );
+        assert_eq!(Expression::gcd_many(&[]), expr!(0));
+        assert_eq!(Expression::lcm_many(&[]), expr!(1));
+    }
+
+    #[test]
+    fn test_primitive_with_rational_coefficients() {
+        let poly = Expression::add(vec![
+            Expression::mul(vec![Expression::rational(-2, 3), expr!(x ^ 2)]),
+            Expression::mul(vec![Expression::rational(4, 9), expr!(x)]),
+            Expression::rational(2, 5),
+        ]);
+        let (content, primitive) = poly.primitive();
+        assert_eq!(content, Expression::rational(2, 45));
+        assert_eq!(primitive, expr!((-15 * (x ^ 2)) + (10 * x) + 9));
+        assert_eq!(
+            Expression::mul(vec![content, primitive]).expand(),
+            poly.expand()
+        );
+
+        assert_eq!(expr!(-4).primitive(), (expr!(4), expr!(-1)));
+        assert_eq!(expr!(sin(x)).primitive(), (expr!(1), expr!(sin(x))));
+    }
+
     #[test]
     fn test_gcd_coprime_expressions() {
         let _x = symbol!(x);
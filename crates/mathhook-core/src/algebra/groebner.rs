@@ -3,15 +3,20 @@
 //! Implements Buchberger's algorithm for computing Gröbner bases of polynomial ideals.
 //! Supports multiple monomial orderings and provides tools for ideal membership testing,
 //! solving systems of polynomial equations, and computational algebraic geometry.
+//!
+//! Coefficients are rational by default; [`GroebnerBasis::with_field`] computes
+//! over a prime field Z_p instead.
 
 mod buchberger;
+mod coefficients;
 mod efficient_buchberger;
 mod monomial_order;
 mod reduction;
 mod s_polynomial;
 
 pub use buchberger::buchberger_algorithm;
-pub use efficient_buchberger::efficient_buchberger_algorithm;
+pub use coefficients::CoefficientField;
+pub use efficient_buchberger::{efficient_buchberger_algorithm, groebner_basis_over};
 pub use monomial_order::{MonomialOrder, MonomialOrdering};
 pub use reduction::{poly_reduce, poly_reduce_completely};
 pub use s_polynomial::s_polynomial;
@@ -21,6 +26,7 @@ pub use crate::core::polynomial::sparse_polynomial::{
 };
 
 use crate::core::{Expression, Symbol};
+use efficient_buchberger::normal_form;
 use std::collections::HashSet;
 
 /// Represents a Gröbner basis for a polynomial ideal
@@ -54,6 +60,9 @@ pub struct GroebnerBasis {
     /// Monomial ordering used for computation
     pub ordering: MonomialOrder,
 
+    /// Field of the coefficients
+    pub field: CoefficientField,
+
     /// Whether the basis is reduced
     pub is_reduced: bool,
 }
@@ -92,10 +101,36 @@ impl GroebnerBasis {
             basis: polynomials,
             variables,
             ordering,
+            field: CoefficientField::Rationals,
             is_reduced: false,
         }
     }
 
+    /// Compute over the given coefficient field instead of the rationals
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::algebra::groebner::{CoefficientField, GroebnerBasis, MonomialOrder};
+    /// use mathhook_core::{expr, symbol};
+    ///
+    /// let x = symbol!(x);
+    /// let y = symbol!(y);
+    /// // Over Z_2, x^2 + y^2 = (x + y)^2
+    /// let mut gb = GroebnerBasis::new(
+    ///     vec![expr!((x ^ 2) + (y ^ 2)), expr!(x + y)],
+    ///     vec![x, y],
+    ///     MonomialOrder::Lex,
+    /// )
+    /// .with_field(CoefficientField::Prime(2));
+    /// gb.compute_with_result().unwrap();
+    /// assert_eq!(gb.basis.len(), 1);
+    /// ```
+    pub fn with_field(mut self, field: CoefficientField) -> Self {
+        self.field = field;
+        self
+    }
+
     /// Compute the Gröbner basis using Buchberger's algorithm
     ///
     /// Transforms the initial generators into a Gröbner basis by computing
@@ -119,15 +154,14 @@ impl GroebnerBasis {
     /// gb.compute();
     /// ```
     pub fn compute(&mut self) {
-        self.basis = efficient_buchberger_algorithm(&self.basis, &self.variables, &self.ordering)
+        self.compute_with_result()
             .expect("Efficient Buchberger algorithm should converge for valid polynomial ideals");
-        self.is_reduced = false;
     }
 
     /// Compute the Gröbner basis with explicit error handling
     ///
     /// Returns `Ok(())` on success or `Err(MathError)` if computation times out
-    /// or exceeds iteration limit.
+    /// or exceeds iteration limit. The computed basis is already reduced.
     ///
     /// # Examples
     ///
@@ -151,14 +185,9 @@ impl GroebnerBasis {
     /// }
     /// ```
     pub fn compute_with_result(&mut self) -> crate::error::MathResult<()> {
-        match efficient_buchberger_algorithm(&self.basis, &self.variables, &self.ordering) {
-            Ok(basis) => {
-                self.basis = basis;
-                self.is_reduced = false;
-                Ok(())
-            }
-            Err(e) => Err(e),
-        }
+        self.basis = groebner_basis_over(&self.basis, &self.variables, &self.ordering, self.field)?;
+        self.is_reduced = true;
+        Ok(())
     }
 
     /// Reduce the Gröbner basis to minimal form
@@ -221,9 +250,36 @@ impl GroebnerBasis {
     /// assert!(gb.contains(&test));
     /// ```
     pub fn contains(&self, poly: &Expression) -> bool {
-        let basis_refs: Vec<&Expression> = self.basis.iter().collect();
-        let reduced = poly_reduce_completely(poly, &basis_refs, &self.variables, &self.ordering);
-        reduced.is_zero()
+        let sparse = |p: &Expression| expression_to_sparse_polynomial(p, &self.variables);
+        let (Some(poly), Some(basis)) = (
+            sparse(poly),
+            self.basis.iter().map(sparse).collect::<Option<Vec<_>>>(),
+        ) else {
+            return false;
+        };
+        normal_form(&poly, &basis, &self.ordering, self.field).is_ok_and(|r| r.is_zero())
+    }
+
+    /// Whether the ideal has finitely many common zeros over the algebraic
+    /// closure
+    ///
+    /// For a computed basis, this holds exactly when every variable has a
+    /// pure power among the leading monomials.
+    pub fn is_zero_dimensional(&self) -> bool {
+        let leading: Vec<Monomial> = self
+            .basis
+            .iter()
+            .filter_map(|p| expression_to_sparse_polynomial(p, &self.variables))
+            .filter_map(|p| p.leading_monomial(&self.ordering).cloned())
+            .collect();
+        (0..self.variables.len()).all(|var| {
+            leading.iter().any(|m| {
+                m.exponents
+                    .iter()
+                    .enumerate()
+                    .all(|(i, &e)| (i == var) == (e > 0))
+            })
+        })
     }
 
     /// Get all variables that appear in the basis
@@ -271,7 +327,8 @@ fn find_variables(expr: &Expression) -> Vec<Symbol> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::symbol;
+    use crate::simplify::Simplify;
+    use crate::{expr, symbol};
 
     #[test]
     fn test_groebner_basis_creation() {
@@ -306,7 +363,6 @@ mod tests {
     }
 
     #[test]
-    #[ignore = "FIXME: Gröbner basis ideal membership test needs convergence tuning"]
     fn test_ideal_membership() {
         let x = symbol!(x);
         let y = symbol!(y);
@@ -326,4 +382,71 @@ mod tests {
 
         assert!(gb.contains(&test));
     }
+
+    #[test]
+    fn test_reduced_basis_is_triangular() {
+        let x = symbol!(x);
+        let y = symbol!(y);
+        // 2x^2 + 2y^2 - 2 and 3x - 3y: non-monic generators
+        let mut gb = GroebnerBasis::new(
+            vec![
+                expr!((2 * (x ^ 2)) + (2 * (y ^ 2)) - 2),
+                expr!((3 * x) - (3 * y)),
+            ],
+            vec![x.clone(), y.clone()],
+            MonomialOrder::Lex,
+        );
+        gb.compute();
+
+        assert_eq!(gb.basis.len(), 2);
+        assert_eq!(gb.basis[0], expr!(x - y).simplify());
+        assert_eq!(
+            gb.basis[1],
+            Expression::add(vec![expr!(y ^ 2), Expression::rational(-1, 2)]).simplify()
+        );
+        assert!(gb.is_zero_dimensional());
+        assert!(gb.contains(&expr!((2 * (x ^ 2)) - 1)));
+        assert!(!gb.contains(&expr!(x - 1)));
+    }
+
+    #[test]
+    fn test_basis_over_prime_field() {
+        let x = symbol!(x);
+        let y = symbol!(y);
+        let generators = vec![expr!((x ^ 2) + 1), expr!((y ^ 2) + 1), expr!(x + y)];
+
+        // Over Q the ideal is proper
+        let mut rational = GroebnerBasis::new(
+            generators.clone(),
+            vec![x.clone(), y.clone()],
+            MonomialOrder::Grevlex,
+        );
+        rational.compute();
+        assert_ne!(rational.basis, vec![Expression::integer(1)]);
+
+        // Over Z_3, x + y = 0 and x^2 + 1 = 0 leave x^2 + 1 irreducible: still proper
+        let mut mod3 =
+            GroebnerBasis::new(generators, vec![x.clone(), y.clone()], MonomialOrder::Lex)
+                .with_field(CoefficientField::Prime(3));
+        mod3.compute();
+        assert!(mod3.contains(&expr!((x ^ 2) + 1)));
+
+        // 2x - 1 and x - 3 are inconsistent over Q but agree mod 5
+        let mut mod5 = GroebnerBasis::new(
+            vec![expr!((2 * x) - 1), expr!(x - 3)],
+            vec![x.clone()],
+            MonomialOrder::Lex,
+        )
+        .with_field(CoefficientField::Prime(5));
+        mod5.compute();
+        assert_eq!(mod5.basis, vec![expr!(x + 2).simplify()]);
+
+        let mut inconsistent = GroebnerBasis::new(
+            vec![expr!((2 * x) - 1), expr!(x - 3)],
+            vec![x],
+            MonomialOrder::Lex,
+        );
+        inconsistent.compute();
+        assert_eq!(inconsistent.basis, vec![Expression::integer(1)]);
+    }
 }
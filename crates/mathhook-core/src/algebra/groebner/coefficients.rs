@@ -0,0 +1,138 @@
+//! Coefficient Fields for Gröbner Basis Computation
+//!
+//! Bases are computed over the rationals or over a prime field Z_p. Sparse
+//! polynomials always store `BigRational` coefficients; over Z_p these are
+//! kept as integers in [0, p), and a rational input a/b is read as a·b⁻¹ mod p.
+
+use crate::core::polynomial::finite_field::is_prime;
+use crate::core::polynomial::sparse_polynomial::SparsePolynomial;
+use crate::core::Expression;
+use crate::error::{MathError, MathResult};
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_rational::BigRational;
+use num_traits::{One, Zero};
+
+/// Field the coefficients of a polynomial ideal live in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CoefficientField {
+    /// The rational numbers Q
+    #[default]
+    Rationals,
+
+    /// The integers modulo a prime p
+    Prime(u64),
+}
+
+impl CoefficientField {
+    /// Read the coefficients of `poly` as elements of this field
+    ///
+    /// # Errors
+    ///
+    /// Returns `MathError::DomainError` if the modulus is not prime, and
+    /// `MathError::DivisionByZero` if a denominator is divisible by p.
+    pub(crate) fn normalize(&self, poly: SparsePolynomial) -> MathResult<SparsePolynomial> {
+        let CoefficientField::Prime(p) = *self else {
+            return Ok(poly);
+        };
+        if !is_prime(p) {
+            return Err(MathError::DomainError {
+                operation: "Gröbner basis over Z_p".to_owned(),
+                value: Expression::integer(p as i64),
+                reason: "the modulus must be prime".to_owned(),
+            });
+        }
+
+        let modulus = BigInt::from(p);
+        let mut terms = poly.terms;
+        for coeff in terms.values_mut() {
+            let denominator = coeff.denom().mod_floor(&modulus);
+            if denominator.is_zero() {
+                return Err(MathError::DivisionByZero);
+            }
+            let inverse = denominator.modpow(&(&modulus - 2), &modulus);
+            *coeff = BigRational::from_integer((coeff.numer() * inverse).mod_floor(&modulus));
+        }
+        terms.retain(|_, coeff| !coeff.is_zero());
+        Ok(SparsePolynomial {
+            terms,
+            num_vars: poly.num_vars,
+        })
+    }
+
+    /// Reduce integer coefficients back into [0, p) after arithmetic
+    pub(crate) fn reduce(&self, mut poly: SparsePolynomial) -> SparsePolynomial {
+        if let CoefficientField::Prime(p) = *self {
+            let modulus = BigInt::from(p);
+            for coeff in poly.terms.values_mut() {
+                *coeff = BigRational::from_integer(coeff.numer().mod_floor(&modulus));
+            }
+            poly.terms.retain(|_, coeff| !coeff.is_zero());
+        }
+        poly
+    }
+
+    /// Multiplicative inverse of a nonzero field element
+    pub(crate) fn inverse(&self, value: &BigRational) -> BigRational {
+        match *self {
+            CoefficientField::Rationals => value.recip(),
+            CoefficientField::Prime(p) => {
+                let modulus = BigInt::from(p);
+                BigRational::from_integer(value.numer().modpow(&(&modulus - 2), &modulus))
+            }
+        }
+    }
+
+    /// `poly` scaled so its leading coefficient is 1
+    pub(crate) fn make_monic(
+        &self,
+        poly: &SparsePolynomial,
+        order: &super::MonomialOrder,
+    ) -> SparsePolynomial {
+        match poly.leading_coefficient(order) {
+            Some(lc) if !lc.is_one() => self.reduce(poly.scalar_mul(&self.inverse(lc))),
+            _ => poly.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algebra::groebner::MonomialOrder;
+    use crate::core::polynomial::sparse_polynomial::Monomial;
+
+    fn constant(n: i64, d: i64) -> SparsePolynomial {
+        SparsePolynomial::constant(BigRational::new(BigInt::from(n), BigInt::from(d)), 1)
+    }
+
+    #[test]
+    fn test_prime_field_reads_fractions_as_inverses() {
+        let field = CoefficientField::Prime(7);
+        // 1/3 = 5 mod 7
+        let poly = field.normalize(constant(1, 3)).unwrap();
+        assert_eq!(
+            poly.terms.get(&Monomial::constant(1)),
+            Some(&BigRational::from_integer(BigInt::from(5)))
+        );
+        assert!(field.normalize(constant(14, 1)).unwrap().is_zero());
+        assert_eq!(
+            field.normalize(constant(1, 7)).unwrap_err(),
+            MathError::DivisionByZero
+        );
+        assert!(CoefficientField::Prime(8)
+            .normalize(constant(1, 1))
+            .is_err());
+    }
+
+    #[test]
+    fn test_make_monic() {
+        let field = CoefficientField::Prime(5);
+        let poly = field.normalize(constant(3, 1)).unwrap();
+        let monic = field.make_monic(&poly, &MonomialOrder::Lex);
+        assert_eq!(
+            monic.leading_coefficient(&MonomialOrder::Lex),
+            Some(&BigRational::one())
+        );
+    }
+}
@@ -3,15 +3,22 @@
 //! This implementation uses the sparse polynomial representation for O(n²) arithmetic
 //! instead of the exponential growth from Expression AST.
 //!
+//! Coefficients are taken from a [`CoefficientField`], the rationals or Z_p, and
+//! the result is always the reduced Gröbner basis, which is unique for a given
+//! ideal and monomial order.
+//!
 //! Performance target: Match SymPy (0.00s for circle-line system)
 
+use super::coefficients::CoefficientField;
 use super::monomial_order::MonomialOrder;
 use crate::core::polynomial::sparse_polynomial::{
     expression_to_sparse_polynomial, sparse_polynomial_to_expression, Monomial, SparsePolynomial,
 };
 use crate::core::{Expression, Symbol};
 use crate::error::{MathError, MathResult};
-use std::collections::VecDeque;
+use crate::simplify::Simplify;
+use num_rational::BigRational;
+use std::collections::HashSet;
 
 /// Compute Gröbner basis using efficient sparse polynomial representation
 ///
@@ -60,100 +67,207 @@ pub fn efficient_buchberger_algorithm(
     variables: &[Symbol],
     order: &MonomialOrder,
 ) -> MathResult<Vec<Expression>> {
-    // Convert expressions to sparse polynomials
-    let mut basis: Vec<SparsePolynomial> = generators
+    groebner_basis_over(generators, variables, order, CoefficientField::Rationals)
+}
+
+/// Compute the reduced Gröbner basis of an ideal over a chosen coefficient field
+///
+/// Generators that are not polynomials in `variables` are skipped. The
+/// result is the reduced basis: every element is monic, no term of an
+/// element is divisible by the leading monomial of another, and elements
+/// are sorted by decreasing leading monomial. For lex order this lists a
+/// zero-dimensional system in triangular form, the polynomial in the last
+/// variable alone coming last.
+///
+/// # Arguments
+///
+/// * `generators` - Initial polynomial generators as Expressions
+/// * `variables` - Variables in the polynomial ring
+/// * `order` - Monomial ordering
+/// * `field` - Field of the coefficients
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::algebra::groebner::{groebner_basis_over, CoefficientField, MonomialOrder};
+/// use mathhook_core::simplify::Simplify;
+/// use mathhook_core::{expr, symbol};
+///
+/// let x = symbol!(x);
+/// // x^2 + 1 and x - 2 share a root mod 5, since 2^2 + 1 = 5
+/// let gb = groebner_basis_over(
+///     &[expr!((x ^ 2) + 1), expr!(x - 2)],
+///     &[x.clone()],
+///     &MonomialOrder::Lex,
+///     CoefficientField::Prime(5),
+/// )
+/// .unwrap();
+/// assert_eq!(gb, vec![expr!(x + 3).simplify()]);
+/// ```
+///
+/// # Errors
+///
+/// Returns `MathError::MaxIterationsReached` if the computation does not
+/// finish within 10,000 pair reductions, and the errors of
+/// [`CoefficientField`] for a modulus that is not prime or a coefficient
+/// with no value mod p.
+pub fn groebner_basis_over(
+    generators: &[Expression],
+    variables: &[Symbol],
+    order: &MonomialOrder,
+    field: CoefficientField,
+) -> MathResult<Vec<Expression>> {
+    let generators: Vec<SparsePolynomial> = generators
         .iter()
         .filter_map(|expr| expression_to_sparse_polynomial(expr, variables))
-        .filter(|poly| !poly.is_zero())
         .collect();
 
+    let basis = reduced_groebner_basis(generators, order, field)?;
     if basis.is_empty() {
         return Ok(vec![Expression::integer(0)]);
     }
 
-    // Initialize pairs queue
-    let mut pairs = VecDeque::new();
-    for i in 0..basis.len() {
-        for j in (i + 1)..basis.len() {
-            pairs.push_back((i, j));
+    Ok(basis
+        .iter()
+        .map(|poly| sparse_polynomial_to_expression(poly, variables).simplify())
+        .collect())
+}
+
+/// Reduced Gröbner basis of sparse generators by Buchberger's algorithm
+///
+/// Pairs are taken smallest lcm first (the normal strategy), and skipped by
+/// Buchberger's two criteria: coprime leading monomials, and the chain
+/// criterion when a third leading monomial divides their lcm and both of its
+/// pairs have already been treated.
+pub(crate) fn reduced_groebner_basis(
+    generators: Vec<SparsePolynomial>,
+    order: &MonomialOrder,
+    field: CoefficientField,
+) -> MathResult<Vec<SparsePolynomial>> {
+    let mut basis = Vec::with_capacity(generators.len());
+    for poly in generators {
+        let poly = field.normalize(poly)?;
+        if !poly.is_zero() {
+            basis.push(field.make_monic(&poly, order));
+        }
+    }
+
+    let mut pending: HashSet<(usize, usize)> = HashSet::new();
+    for j in 0..basis.len() {
+        for i in 0..j {
+            pending.insert((i, j));
         }
     }
 
-    // Iteration limit for safety
     let max_iterations = 10000;
     let mut iterations = 0;
-
-    while !pairs.is_empty() && iterations < max_iterations {
-        iterations += 1;
-
-        let (i, j) = pairs.pop_front().unwrap();
-
-        // Check Buchberger criterion
-        if can_skip_pair_sparse(i, j, &basis, order) {
-            continue;
+    while let Some(&(i, j)) = pending
+        .iter()
+        .min_by(|a, b| pair_lcm(&basis, **a, order).cmp(&pair_lcm(&basis, **b, order), order))
+    {
+        if iterations == max_iterations {
+            return Err(MathError::MaxIterationsReached { max_iterations });
         }
+        iterations += 1;
+        pending.remove(&(i, j));
 
-        // Compute S-polynomial
-        let s_poly = s_polynomial_sparse(&basis[i], &basis[j], order);
-
-        if s_poly.is_zero() {
+        if can_skip_pair_sparse(i, j, &basis, &pending, order) {
             continue;
         }
 
-        // Reduce S-polynomial modulo current basis
+        let s_poly = s_polynomial_sparse(&basis[i], &basis[j], order, field);
         let basis_refs: Vec<&SparsePolynomial> = basis.iter().collect();
-        let remainder = poly_reduce_completely_sparse(&s_poly, &basis_refs, order);
+        let remainder = poly_reduce_completely_sparse(&s_poly, &basis_refs, order, field);
 
         if !remainder.is_zero() {
             let new_idx = basis.len();
-            basis.push(remainder);
-
-            // Add new pairs
+            basis.push(field.make_monic(&remainder, order));
             for k in 0..new_idx {
-                pairs.push_back((k, new_idx));
+                pending.insert((k, new_idx));
             }
         }
     }
 
-    if iterations >= max_iterations {
-        return Err(MathError::MaxIterationsReached { max_iterations });
-    }
+    Ok(interreduce(basis, order, field))
+}
 
-    // Remove zero polynomials
-    basis.retain(|p| !p.is_zero());
+/// Reduce `poly` modulo a Gröbner basis; zero exactly when `poly` lies in
+/// the ideal
+pub(crate) fn normal_form(
+    poly: &SparsePolynomial,
+    basis: &[SparsePolynomial],
+    order: &MonomialOrder,
+    field: CoefficientField,
+) -> MathResult<SparsePolynomial> {
+    let poly = field.normalize(poly.clone())?;
+    let basis_refs: Vec<&SparsePolynomial> = basis.iter().collect();
+    Ok(poly_reduce_completely_sparse(
+        &poly,
+        &basis_refs,
+        order,
+        field,
+    ))
+}
+
+/// Turn a Gröbner basis into the reduced one
+fn interreduce(
+    mut basis: Vec<SparsePolynomial>,
+    order: &MonomialOrder,
+    field: CoefficientField,
+) -> Vec<SparsePolynomial> {
+    // Minimal basis: drop elements whose leading monomial is a multiple of another's
+    let mut i = 0;
+    while i < basis.len() {
+        let lm = basis[i].leading_monomial(order).cloned();
+        let redundant = lm.is_some_and(|lm| {
+            basis.iter().enumerate().any(|(j, other)| {
+                j != i
+                    && other.leading_monomial(order).is_some_and(|other_lm| {
+                        lm.try_divide(other_lm).is_some() && (other_lm != &lm || j < i)
+                    })
+            })
+        });
+        if redundant {
+            basis.remove(i);
+        } else {
+            i += 1;
+        }
+    }
 
-    // Auto-reduce: each polynomial reduced by all others
-    let n = basis.len();
-    for i in 0..n {
+    for i in 0..basis.len() {
         let others: Vec<&SparsePolynomial> = basis
             .iter()
             .enumerate()
             .filter(|(j, _)| *j != i)
             .map(|(_, p)| p)
             .collect();
-
-        if !others.is_empty() {
-            let reduced = poly_reduce_completely_sparse(&basis[i], &others, order);
-            basis[i] = reduced;
-        }
+        let reduced = poly_reduce_completely_sparse(&basis[i], &others, order, field);
+        basis[i] = field.make_monic(&reduced, order);
     }
 
     basis.retain(|p| !p.is_zero());
+    basis.sort_by(
+        |a, b| match (a.leading_monomial(order), b.leading_monomial(order)) {
+            (Some(a), Some(b)) => b.cmp(a, order),
+            _ => std::cmp::Ordering::Equal,
+        },
+    );
+    basis
+}
 
-    // Convert back to expressions
-    let result_exprs: Vec<Expression> = basis
-        .iter()
-        .map(|poly| sparse_polynomial_to_expression(poly, variables))
-        .collect();
-
-    Ok(result_exprs)
+/// lcm of the leading monomials of a pair
+fn pair_lcm(basis: &[SparsePolynomial], (i, j): (usize, usize), order: &MonomialOrder) -> Monomial {
+    let lt_i = basis[i].leading_monomial(order).expect("basis is non-zero");
+    let lt_j = basis[j].leading_monomial(order).expect("basis is non-zero");
+    lt_i.lcm(lt_j)
 }
 
-/// Compute S-polynomial of two sparse polynomials
+/// Compute S-polynomial of two monic sparse polynomials
 fn s_polynomial_sparse(
     f: &SparsePolynomial,
     g: &SparsePolynomial,
     order: &MonomialOrder,
+    field: CoefficientField,
 ) -> SparsePolynomial {
     // Get leading monomials (unwrap is safe since polynomials are non-zero in basis)
     let lt_f = f.leading_monomial(order).expect("f is non-zero");
@@ -168,69 +282,81 @@ fn s_polynomial_sparse(
     let scaled_f = f.mul_monomial(&f_factor);
     let scaled_g = g.mul_monomial(&g_factor);
 
-    scaled_f.sub(&scaled_g)
+    field.reduce(scaled_f.sub(&scaled_g))
 }
 
-/// Reduce polynomial modulo a set of polynomials
+/// Fully reduce a polynomial modulo a set of polynomials
+///
+/// Every term, not only the leading one, is reduced, so no term of the
+/// remainder is divisible by a leading monomial of the divisors.
 fn poly_reduce_completely_sparse(
     poly: &SparsePolynomial,
     basis: &[&SparsePolynomial],
     order: &MonomialOrder,
+    field: CoefficientField,
 ) -> SparsePolynomial {
-    let mut remainder = poly.clone();
-
-    loop {
-        let mut reduced = false;
-
-        for divisor in basis {
-            if divisor.is_zero() {
-                continue;
-            }
+    let divisors: Vec<(&SparsePolynomial, Monomial, BigRational)> = basis
+        .iter()
+        .filter_map(|divisor| {
+            let (lm, lc) = divisor.leading_term(order)?;
+            Some((*divisor, lm, field.inverse(&lc)))
+        })
+        .collect();
 
-            let divisor_lt = divisor.leading_monomial(order);
-
-            // Try to reduce remainder by divisor
-            while !remainder.is_zero() {
-                let remainder_lt = remainder.leading_monomial(order);
-
-                if let (Some(r_lt), Some(d_lt)) = (remainder_lt, divisor_lt) {
-                    if let Some(quotient_mono) = r_lt.try_divide(d_lt) {
-                        // remainder -= (quotient_mono * divisor)
-                        let to_subtract = divisor.mul_monomial(&quotient_mono);
-                        remainder = remainder.sub(&to_subtract);
-                        reduced = true;
-                    } else {
-                        break;
-                    }
-                } else {
-                    break;
-                }
+    let mut remainder = SparsePolynomial::zero(poly.num_vars);
+    let mut rest = poly.clone();
+    while let Some((lm, lc)) = rest.leading_term(order) {
+        let step = divisors
+            .iter()
+            .find_map(|(divisor, divisor_lm, lc_inverse)| {
+                let quotient = lm.try_divide(divisor_lm)?;
+                Some(
+                    divisor
+                        .mul_monomial(&quotient)
+                        .scalar_mul(&(&lc * lc_inverse)),
+                )
+            });
+        match step {
+            Some(to_subtract) => rest = field.reduce(rest.sub(&to_subtract)),
+            None => {
+                rest.terms.remove(&lm);
+                remainder.terms.insert(lm, lc);
             }
         }
-
-        if !reduced {
-            break;
-        }
     }
 
     remainder
 }
 
-/// Check if pair can be skipped (Buchberger criterion)
+/// Check if pair can be skipped by Buchberger's criteria
 fn can_skip_pair_sparse(
     i: usize,
     j: usize,
     basis: &[SparsePolynomial],
+    pending: &HashSet<(usize, usize)>,
     order: &MonomialOrder,
 ) -> bool {
-    let lt_i = basis[i].leading_monomial(order);
-    let lt_j = basis[j].leading_monomial(order);
-
-    if let (Some(mono_i), Some(mono_j)) = (lt_i, lt_j) {
-        are_relatively_prime_sparse(mono_i, mono_j)
-    } else {
-        false
+    let (Some(mono_i), Some(mono_j)) = (
+        basis[i].leading_monomial(order),
+        basis[j].leading_monomial(order),
+    ) else {
+        return false;
+    };
+    if are_relatively_prime_sparse(mono_i, mono_j) {
+        return true;
     }
+
+    let lcm = mono_i.lcm(mono_j);
+    let pair = |a: usize, b: usize| (a.min(b), a.max(b));
+    (0..basis.len()).any(|k| {
+        k != i
+            && k != j
+            && basis[k]
+                .leading_monomial(order)
+                .is_some_and(|mono_k| lcm.try_divide(mono_k).is_some())
+            && !pending.contains(&pair(i, k))
+            && !pending.contains(&pair(j, k))
+    })
 }
 
 /// Check if two monomials are relatively prime
@@ -0,0 +1,713 @@
+//! Linear programming by the simplex method in exact rational arithmetic
+//!
+//! [`maximize`] finds the vertex of the feasible region `{x ≥ 0 : constraints}`
+//! where a linear objective is largest. Coefficients must be integers or
+//! rationals, so the optimum is exact and every tableau can be shown as it
+//! is, as in an operations-research course:
+//!
+//! - each `≤` row gets a slack variable, each `≥` row a surplus variable and
+//!   an artificial one, each `=` row an artificial one
+//! - phase 1 drives the artificial variables to zero, or shows there is no
+//!   feasible point
+//! - phase 2 optimizes the objective from the feasible vertex found
+//!
+//! Pivots follow Bland's rule (lowest-index entering and leaving variable),
+//! which cannot cycle on degenerate problems.
+
+use crate::algebra::expand::Expand;
+use crate::algebra::solvers::completing_square::{as_rational, from_rational};
+use crate::core::expression::RelationType;
+use crate::core::{Expression, Symbol};
+use crate::educational::step_by_step::{Step, StepByStepExplanation};
+use crate::error::MathError;
+use crate::simplify::Simplify;
+use num_rational::BigRational;
+use num_traits::{One, Signed, Zero};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// How a linear program ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LinearProgramStatus {
+    Optimal,
+    /// No point satisfies all constraints
+    Infeasible,
+    /// The objective grows without bound on the feasible region
+    Unbounded,
+}
+
+/// One simplex tableau, as it would be written on the board
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tableau {
+    /// 1 while searching for a feasible vertex, 2 while optimizing
+    pub phase: u8,
+    /// Decision, slack, surplus and artificial variables, in column order
+    pub columns: Vec<String>,
+    /// Basic variable of each constraint row
+    pub basis: Vec<String>,
+    /// Constraint rows, right-hand side last
+    pub rows: Vec<Vec<Expression>>,
+    /// Reduced costs, current objective value last
+    pub objective: Vec<Expression>,
+    /// `(row, column)` of the pivot taken from this tableau; `None` for the
+    /// last tableau of a phase
+    pub pivot: Option<(usize, usize)>,
+}
+
+impl fmt::Display for Tableau {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let header = std::iter::once("basis".to_owned())
+            .chain(self.columns.iter().cloned())
+            .chain(std::iter::once("rhs".to_owned()));
+        let mut lines: Vec<Vec<String>> = vec![header.collect()];
+        for (name, row) in self.basis.iter().zip(&self.rows) {
+            lines.push(
+                std::iter::once(name.clone())
+                    .chain(row.iter().map(ToString::to_string))
+                    .collect(),
+            );
+        }
+        lines.push(
+            std::iter::once(if self.phase == 1 { "w" } else { "z" }.to_owned())
+                .chain(self.objective.iter().map(ToString::to_string))
+                .collect(),
+        );
+
+        let widths: Vec<usize> = (0..lines[0].len())
+            .map(|j| {
+                lines
+                    .iter()
+                    .map(|line| line[j].chars().count())
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+        let rendered: Vec<String> = lines
+            .iter()
+            .map(|line| {
+                line.iter()
+                    .zip(&widths)
+                    .map(|(cell, width)| format!("{:>width$}", cell, width = width))
+                    .collect::<Vec<_>>()
+                    .join(" | ")
+            })
+            .collect();
+        write!(f, "{}", rendered.join("\n"))
+    }
+}
+
+/// Result of [`maximize`] or [`minimize`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinearProgramSolution {
+    pub status: LinearProgramStatus,
+    /// Value of each decision variable at the optimal vertex, in name order;
+    /// empty unless the status is optimal
+    pub vertex: Vec<(Symbol, Expression)>,
+    /// Optimal objective value
+    pub value: Option<Expression>,
+    /// Every tableau in order, starting with the initial one of each phase
+    pub tableaux: Vec<Tableau>,
+}
+
+impl LinearProgramSolution {
+    /// Optimal value of a decision variable
+    pub fn value_of(&self, variable: &Symbol) -> Option<&Expression> {
+        self.vertex
+            .iter()
+            .find(|(symbol, _)| symbol == variable)
+            .map(|(_, value)| value)
+    }
+
+    /// One step per tableau, naming the entering and leaving variables
+    pub fn explanation(&self) -> StepByStepExplanation {
+        let mut steps: Vec<Step> = self
+            .tableaux
+            .iter()
+            .enumerate()
+            .map(|(index, tableau)| {
+                let action = match tableau.pivot {
+                    Some((row, column)) => format!(
+                        "{} enters the basis and {} leaves",
+                        tableau.columns[column], tableau.basis[row]
+                    ),
+                    None if tableau.phase == 1 => "Phase 1 complete".to_owned(),
+                    None => "No further improving column".to_owned(),
+                };
+                Step::new(
+                    format!("Tableau {} (phase {})", index + 1, tableau.phase),
+                    format!("{}\n{}", tableau, action),
+                )
+            })
+            .collect();
+
+        let conclusion = match self.status {
+            LinearProgramStatus::Optimal => {
+                let assignments: Vec<String> = self
+                    .vertex
+                    .iter()
+                    .map(|(symbol, value)| format!("{} = {}", symbol.name(), value))
+                    .collect();
+                let value = self.value.as_ref().map(ToString::to_string);
+                format!(
+                    "Optimum {} at {}",
+                    value.unwrap_or_default(),
+                    assignments.join(", ")
+                )
+            }
+            LinearProgramStatus::Infeasible => {
+                "Artificial variables cannot reach zero: the constraints are infeasible".to_owned()
+            }
+            LinearProgramStatus::Unbounded => {
+                "The entering column has no positive entry: the objective is unbounded".to_owned()
+            }
+        };
+        steps.push(Step::new("Result", conclusion));
+        StepByStepExplanation::new(steps)
+    }
+}
+
+/// Maximize a linear objective over `variables ≥ 0` subject to linear
+/// constraints
+///
+/// Constraints are relations using `≤`, `≥` or `=`. All variables appearing
+/// in the objective or the constraints are decision variables and are
+/// implicitly nonnegative.
+///
+/// # Errors
+///
+/// Returns `MathError::DomainError` when the objective or a constraint is not
+/// linear with exact rational coefficients, or when a constraint is not a
+/// `≤`, `≥` or `=` relation.
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::algebra::linear_programming::{maximize, LinearProgramStatus};
+/// use mathhook_core::core::expression::RelationType;
+/// use mathhook_core::{expr, symbol, Expression};
+///
+/// // Wyndor Glass: maximize 3x + 5y with x ≤ 4, 2y ≤ 12, 3x + 2y ≤ 18
+/// let constraints = vec![
+///     Expression::relation(expr!(x), expr!(4), RelationType::LessEqual),
+///     Expression::relation(expr!(2 * y), expr!(12), RelationType::LessEqual),
+///     Expression::relation(expr!((3 * x) + (2 * y)), expr!(18), RelationType::LessEqual),
+/// ];
+/// let solution = maximize(&expr!((3 * x) + (5 * y)), &constraints).unwrap();
+///
+/// assert_eq!(solution.status, LinearProgramStatus::Optimal);
+/// assert_eq!(solution.value, Some(expr!(36)));
+/// assert_eq!(solution.value_of(&symbol!(x)), Some(&expr!(2)));
+/// assert_eq!(solution.value_of(&symbol!(y)), Some(&expr!(6)));
+/// ```
+pub fn maximize(
+    objective: &Expression,
+    subject_to: &[Expression],
+) -> Result<LinearProgramSolution, MathError> {
+    let sides = subject_to.iter().flat_map(|constraint| match constraint {
+        Expression::Relation(data) => vec![&data.left, &data.right],
+        other => vec![other],
+    });
+    let mut variables: Vec<Symbol> = std::iter::once(objective)
+        .chain(sides)
+        .flat_map(Expression::find_variables)
+        .collect();
+    variables.sort_by(|a, b| a.name().cmp(b.name()));
+    variables.dedup();
+
+    let (costs, _) = linear_form(objective, &variables)?;
+    let constraints = subject_to
+        .iter()
+        .map(|constraint| Constraint::parse(constraint, &variables))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut simplex = Simplex::new(&variables, constraints);
+    let status = simplex.solve(&costs);
+
+    let (vertex, value) = match status {
+        LinearProgramStatus::Optimal => {
+            let mut values = vec![BigRational::zero(); variables.len()];
+            for (row, &basic) in simplex.rows.iter().zip(&simplex.basis) {
+                if basic < variables.len() {
+                    values[basic] = row[row.len() - 1].clone();
+                }
+            }
+            let vertex = variables
+                .iter()
+                .cloned()
+                .zip(values.into_iter().map(from_rational))
+                .collect();
+            let value = simplex.objective[simplex.objective.len() - 1].clone();
+            (vertex, Some(from_rational(value)))
+        }
+        _ => (Vec::new(), None),
+    };
+
+    Ok(LinearProgramSolution {
+        status,
+        vertex,
+        value,
+        tableaux: simplex.tableaux,
+    })
+}
+
+/// Minimize a linear objective by maximizing its negation
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::algebra::linear_programming::minimize;
+/// use mathhook_core::core::expression::RelationType;
+/// use mathhook_core::{expr, symbol, Expression};
+///
+/// let constraints = vec![
+///     Expression::relation(expr!(x + (2 * y)), expr!(4), RelationType::GreaterEqual),
+///     Expression::relation(expr!((3 * x) + y), expr!(6), RelationType::GreaterEqual),
+/// ];
+/// let solution = minimize(&expr!(x + y), &constraints).unwrap();
+///
+/// assert_eq!(solution.value, Some(Expression::rational(14, 5)));
+/// assert_eq!(solution.value_of(&symbol!(x)), Some(&Expression::rational(8, 5)));
+/// ```
+pub fn minimize(
+    objective: &Expression,
+    subject_to: &[Expression],
+) -> Result<LinearProgramSolution, MathError> {
+    let negated = Expression::mul(vec![Expression::integer(-1), objective.clone()]);
+    let mut solution = maximize(&negated, subject_to)?;
+    solution.value = solution
+        .value
+        .map(|value| Expression::mul(vec![Expression::integer(-1), value]).simplify());
+    Ok(solution)
+}
+
+/// `coefficients · x (relation) bound` with `bound ≥ 0`
+struct Constraint {
+    coefficients: Vec<BigRational>,
+    relation: RelationType,
+    bound: BigRational,
+}
+
+impl Constraint {
+    fn parse(constraint: &Expression, variables: &[Symbol]) -> Result<Self, MathError> {
+        let data = match constraint {
+            Expression::Relation(data)
+                if matches!(
+                    data.relation_type,
+                    RelationType::LessEqual | RelationType::GreaterEqual | RelationType::Equal
+                ) =>
+            {
+                data
+            }
+            _ => {
+                return Err(not_linear(
+                    constraint,
+                    "Constraints must be ≤, ≥ or = relations",
+                ))
+            }
+        };
+
+        let difference = Expression::add(vec![
+            data.left.clone(),
+            Expression::mul(vec![Expression::integer(-1), data.right.clone()]),
+        ]);
+        let (mut coefficients, constant) = linear_form(&difference, variables)?;
+        let mut bound = -constant;
+        let mut relation = data.relation_type;
+        if bound.is_negative() {
+            coefficients.iter_mut().for_each(|c| *c = -c.clone());
+            bound = -bound;
+            relation = match relation {
+                RelationType::LessEqual => RelationType::GreaterEqual,
+                RelationType::GreaterEqual => RelationType::LessEqual,
+                other => other,
+            };
+        }
+        Ok(Self {
+            coefficients,
+            relation,
+            bound,
+        })
+    }
+}
+
+/// Coefficients of each variable and the constant term of a linear expression
+fn linear_form(
+    expr: &Expression,
+    variables: &[Symbol],
+) -> Result<(Vec<BigRational>, BigRational), MathError> {
+    let expanded = expr.expand().simplify();
+    let terms = match &expanded {
+        Expression::Add(terms) => terms.to_vec(),
+        other => vec![other.clone()],
+    };
+
+    let mut coefficients = vec![BigRational::zero(); variables.len()];
+    let mut constant = BigRational::zero();
+    for term in &terms {
+        let factors = match term {
+            Expression::Mul(factors) => factors.to_vec(),
+            other => vec![other.clone()],
+        };
+        let mut scale = BigRational::one();
+        let mut variable = None;
+        for factor in &factors {
+            match (as_rational(factor), factor) {
+                (Some(value), _) => scale *= value,
+                (None, Expression::Symbol(symbol))
+                    if variable.is_none() && variables.contains(symbol) =>
+                {
+                    variable = variables.iter().position(|v| v == symbol);
+                }
+                _ => {
+                    return Err(not_linear(
+                        expr,
+                        "Expected a linear expression with rational coefficients",
+                    ))
+                }
+            }
+        }
+        match variable {
+            Some(index) => coefficients[index] += scale,
+            None => constant += scale,
+        }
+    }
+    Ok((coefficients, constant))
+}
+
+fn not_linear(expr: &Expression, reason: &str) -> MathError {
+    MathError::DomainError {
+        operation: "linear_programming".to_owned(),
+        value: expr.clone(),
+        reason: reason.to_owned(),
+    }
+}
+
+/// Dense tableau in exact arithmetic
+struct Simplex {
+    columns: Vec<String>,
+    /// Constraint rows, right-hand side last
+    rows: Vec<Vec<BigRational>>,
+    /// Reduced costs, objective value last
+    objective: Vec<BigRational>,
+    basis: Vec<usize>,
+    /// Index of the first artificial column
+    artificial_start: usize,
+    phase: u8,
+    tableaux: Vec<Tableau>,
+}
+
+impl Simplex {
+    fn new(variables: &[Symbol], constraints: Vec<Constraint>) -> Self {
+        let n = variables.len();
+        let slack_count = constraints
+            .iter()
+            .filter(|c| c.relation != RelationType::Equal)
+            .count();
+        let artificial_count = constraints
+            .iter()
+            .filter(|c| c.relation != RelationType::LessEqual)
+            .count();
+        let artificial_start = n + slack_count;
+        let width = artificial_start + artificial_count + 1;
+
+        let mut columns: Vec<String> = variables.iter().map(|v| v.name().to_owned()).collect();
+        columns.extend((1..=slack_count).map(|i| format!("s{}", i)));
+        columns.extend((1..=artificial_count).map(|i| format!("a{}", i)));
+
+        let mut rows = Vec::with_capacity(constraints.len());
+        let mut basis = Vec::with_capacity(constraints.len());
+        let (mut slack, mut artificial) = (n, artificial_start);
+        for constraint in constraints {
+            let mut row = constraint.coefficients;
+            row.resize(width, BigRational::zero());
+            row[width - 1] = constraint.bound;
+            match constraint.relation {
+                RelationType::LessEqual => {
+                    row[slack] = BigRational::one();
+                    basis.push(slack);
+                    slack += 1;
+                }
+                RelationType::GreaterEqual => {
+                    row[slack] = -BigRational::one();
+                    row[artificial] = BigRational::one();
+                    basis.push(artificial);
+                    slack += 1;
+                    artificial += 1;
+                }
+                _ => {
+                    row[artificial] = BigRational::one();
+                    basis.push(artificial);
+                    artificial += 1;
+                }
+            }
+            rows.push(row);
+        }
+
+        Self {
+            columns,
+            rows,
+            objective: vec![BigRational::zero(); width],
+            basis,
+            artificial_start,
+            phase: 1,
+            tableaux: Vec::new(),
+        }
+    }
+
+    fn solve(&mut self, costs: &[BigRational]) -> LinearProgramStatus {
+        let width = self.columns.len() + 1;
+        if self.artificial_start + 1 < width {
+            // Phase 1: maximize minus the sum of the artificial variables
+            let mut objective = vec![BigRational::zero(); width];
+            for value in &mut objective[self.artificial_start..width - 1] {
+                *value = BigRational::one();
+            }
+            self.set_objective(objective);
+            self.iterate();
+            if self.objective[width - 1].is_negative() {
+                return LinearProgramStatus::Infeasible;
+            }
+            self.remove_artificial();
+        }
+
+        self.phase = 2;
+        let mut objective = vec![BigRational::zero(); self.columns.len() + 1];
+        for (value, cost) in objective.iter_mut().zip(costs) {
+            *value = -cost.clone();
+        }
+        self.set_objective(objective);
+        if self.iterate() {
+            LinearProgramStatus::Optimal
+        } else {
+            LinearProgramStatus::Unbounded
+        }
+    }
+
+    /// Install reduced costs and eliminate the basic columns from them
+    fn set_objective(&mut self, mut objective: Vec<BigRational>) {
+        for (row, &basic) in self.rows.iter().zip(&self.basis) {
+            let factor = objective[basic].clone();
+            if !factor.is_zero() {
+                for (value, entry) in objective.iter_mut().zip(row) {
+                    *value -= &factor * entry;
+                }
+            }
+        }
+        self.objective = objective;
+    }
+
+    /// Pivot until optimal (`true`) or unbounded (`false`)
+    fn iterate(&mut self) -> bool {
+        loop {
+            let last = self.objective.len() - 1;
+            let Some(column) = (0..last).find(|&j| self.objective[j].is_negative()) else {
+                self.record(None);
+                return true;
+            };
+
+            let mut leaving: Option<(usize, BigRational)> = None;
+            for (i, row) in self.rows.iter().enumerate() {
+                if !row[column].is_positive() {
+                    continue;
+                }
+                let ratio = &row[last] / &row[column];
+                let better = match &leaving {
+                    None => true,
+                    Some((best, best_ratio)) => {
+                        ratio < *best_ratio
+                            || (ratio == *best_ratio && self.basis[i] < self.basis[*best])
+                    }
+                };
+                if better {
+                    leaving = Some((i, ratio));
+                }
+            }
+            let Some((row, _)) = leaving else {
+                self.record(None);
+                return false;
+            };
+
+            self.record(Some((row, column)));
+            self.pivot(row, column);
+        }
+    }
+
+    fn pivot(&mut self, row: usize, column: usize) {
+        let pivot = self.rows[row][column].clone();
+        for entry in &mut self.rows[row] {
+            *entry /= &pivot;
+        }
+        let pivot_row = self.rows[row].clone();
+        let eliminate = |target: &mut Vec<BigRational>| {
+            let factor = target[column].clone();
+            if !factor.is_zero() {
+                for (value, entry) in target.iter_mut().zip(&pivot_row) {
+                    *value -= &factor * entry;
+                }
+            }
+        };
+        for (i, target) in self.rows.iter_mut().enumerate() {
+            if i != row {
+                eliminate(target);
+            }
+        }
+        eliminate(&mut self.objective);
+        self.basis[row] = column;
+    }
+
+    /// Pivot zero-valued artificial variables out of the basis, drop rows
+    /// that are redundant, then drop the artificial columns
+    fn remove_artificial(&mut self) {
+        let mut i = 0;
+        while i < self.rows.len() {
+            if self.basis[i] < self.artificial_start {
+                i += 1;
+                continue;
+            }
+            match (0..self.artificial_start).find(|&j| !self.rows[i][j].is_zero()) {
+                Some(column) => {
+                    self.pivot(i, column);
+                    i += 1;
+                }
+                None => {
+                    self.rows.remove(i);
+                    self.basis.remove(i);
+                }
+            }
+        }
+
+        let start = self.artificial_start;
+        for row in &mut self.rows {
+            let rhs = row[row.len() - 1].clone();
+            row.truncate(start);
+            row.push(rhs);
+        }
+        self.columns.truncate(start);
+    }
+
+    fn record(&mut self, pivot: Option<(usize, usize)>) {
+        let exact = |values: &Vec<BigRational>| values.iter().cloned().map(from_rational).collect();
+        self.tableaux.push(Tableau {
+            phase: self.phase,
+            columns: self.columns.clone(),
+            basis: self
+                .basis
+                .iter()
+                .map(|&j| self.columns[j].clone())
+                .collect(),
+            rows: self.rows.iter().map(exact).collect(),
+            objective: exact(&self.objective),
+            pivot,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{expr, symbol};
+
+    fn le(left: Expression, right: Expression) -> Expression {
+        Expression::relation(left, right, RelationType::LessEqual)
+    }
+
+    fn ge(left: Expression, right: Expression) -> Expression {
+        Expression::relation(left, right, RelationType::GreaterEqual)
+    }
+
+    #[test]
+    fn test_fractional_vertex() {
+        let constraints = vec![
+            le(expr!((2 * x) + y), expr!(4)),
+            le(expr!(x + (2 * y)), expr!(3)),
+        ];
+        let solution = maximize(&expr!(x + y), &constraints).unwrap();
+
+        assert_eq!(solution.status, LinearProgramStatus::Optimal);
+        assert_eq!(solution.value, Some(Expression::rational(7, 3)));
+        assert_eq!(
+            solution.vertex,
+            vec![
+                (symbol!(x), Expression::rational(5, 3)),
+                (symbol!(y), Expression::rational(2, 3)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tableau_steps() {
+        let constraints = vec![
+            le(expr!(x), expr!(4)),
+            le(expr!(2 * y), expr!(12)),
+            le(expr!((3 * x) + (2 * y)), expr!(18)),
+        ];
+        let solution = maximize(&expr!((3 * x) + (5 * y)), &constraints).unwrap();
+
+        let first = &solution.tableaux[0];
+        assert_eq!(first.columns, vec!["x", "y", "s1", "s2", "s3"]);
+        assert_eq!(first.basis, vec!["s1", "s2", "s3"]);
+        assert_eq!(first.objective[0], expr!(-3));
+        assert_eq!(first.pivot, Some((0, 0)));
+
+        let last = solution.tableaux.last().unwrap();
+        assert_eq!(last.pivot, None);
+        assert_eq!(last.objective.last(), Some(&expr!(36)));
+        assert!(solution.tableaux.iter().all(|t| t.phase == 2));
+
+        let explanation = solution.explanation();
+        assert_eq!(explanation.steps.len(), solution.tableaux.len() + 1);
+        assert!(explanation.steps[0]
+            .description
+            .contains("x enters the basis"));
+        let header: Vec<String> = first
+            .to_string()
+            .lines()
+            .next()
+            .unwrap()
+            .split('|')
+            .map(|cell| cell.trim().to_owned())
+            .collect();
+        assert_eq!(header, vec!["basis", "x", "y", "s1", "s2", "s3", "rhs"]);
+    }
+
+    #[test]
+    fn test_equality_and_redundant_constraints() {
+        let constraints = vec![
+            Expression::equation(expr!(x + y), expr!(2)),
+            Expression::equation(expr!((2 * x) + (2 * y)), expr!(4)),
+            le(expr!(x), expr!(3)),
+        ];
+        let solution = maximize(&expr!((2 * x) + y), &constraints).unwrap();
+
+        assert_eq!(solution.value, Some(expr!(4)));
+        assert_eq!(solution.value_of(&symbol!(x)), Some(&expr!(2)));
+        assert_eq!(solution.value_of(&symbol!(y)), Some(&expr!(0)));
+        assert!(solution.tableaux.iter().any(|t| t.phase == 1));
+    }
+
+    #[test]
+    fn test_infeasible_and_unbounded() {
+        let infeasible = maximize(&expr!(x), &[le(expr!(x), expr!(1)), ge(expr!(x), expr!(2))]);
+        let infeasible = infeasible.unwrap();
+        assert_eq!(infeasible.status, LinearProgramStatus::Infeasible);
+        assert!(infeasible.vertex.is_empty() && infeasible.value.is_none());
+
+        let unbounded = maximize(&expr!(x), &[le(expr!(x - y), expr!(1))]).unwrap();
+        assert_eq!(unbounded.status, LinearProgramStatus::Unbounded);
+    }
+
+    #[test]
+    fn test_negative_bound_flips_relation() {
+        // -x ≤ -1 is x ≥ 1, so phase 1 is needed
+        let solution = minimize(&expr!(x), &[le(expr!(-x), expr!(-1))]).unwrap();
+        assert_eq!(solution.value, Some(expr!(1)));
+    }
+
+    #[test]
+    fn test_rejects_nonlinear_input() {
+        assert!(maximize(&expr!(x * y), &[le(expr!(x), expr!(1))]).is_err());
+        assert!(maximize(&expr!(x), &[le(expr!(x ^ 2), expr!(1))]).is_err());
+        assert!(maximize(&expr!(x), &[expr!(x - 1)]).is_err());
+        assert!(maximize(&expr!(x), &[le(expr!(x), Expression::float(0.5))]).is_err());
+    }
+}
@@ -1,6 +1,9 @@
 //! Multivariate polynomial GCD computation using evaluation-interpolation
 //!
-//! Implements the heuristic GCD algorithm (heugcd) from SymPy's euclidtools.py.
+//! Polynomials with integer coefficients in two or more variables go through
+//! the modular Brown/Zippel algorithm (`multivariate_gcd_zippel`) first.
+//! Everything else, and any input the modular path cannot finish, uses the
+//! heuristic GCD algorithm (heugcd) from SymPy's euclidtools.py.
 //! This approach avoids the infinite recursion issues of content-primitive factorization
 //! by using integer evaluation and polynomial interpolation.
 //!
@@ -28,9 +31,18 @@
 
 use crate::algebra::gcd::PolynomialGcd;
 use crate::algebra::polynomial_advanced::AdvancedPolynomial;
+use crate::algebra::Expand;
+use crate::core::polynomial::algorithms::zippel_gcd::{
+    multivariate_gcd_zippel, MultiPoly, MultivariateConfig,
+};
+use crate::core::polynomial::{
+    expression_to_sparse_polynomial, sparse_polynomial_to_expression, Monomial, SparsePolynomial,
+};
 use crate::core::{Expression, Number, Symbol};
 use crate::expr;
 use crate::simplify::Simplify;
+use num_bigint::BigInt;
+use num_rational::BigRational;
 use num_traits::ToPrimitive;
 
 /// Maximum number of evaluation points to try before giving up
@@ -42,7 +54,8 @@ pub struct HeuristicGCDFailed;
 
 /// Compute GCD of multivariate polynomials using evaluation-interpolation
 ///
-/// This is the main entry point. Uses the heuristic GCD algorithm which:
+/// This is the main entry point. Integer polynomials in two or more variables
+/// use the modular algorithm; otherwise the heuristic GCD algorithm:
 /// 1. Extracts numeric content (ground GCD)
 /// 2. Evaluates at integer points
 /// 3. Recursively reduces dimension
@@ -78,6 +91,12 @@ pub fn multivariate_gcd(poly1: &Expression, poly2: &Expression, vars: &[Symbol])
         return result;
     }
 
+    if vars.len() > 1 {
+        if let Some(gcd) = modular_gcd(poly1, poly2, vars) {
+            return gcd;
+        }
+    }
+
     // Try heuristic GCD algorithm
     match multivariate_heu_gcd(poly1, poly2, vars) {
         Ok((gcd, _, _)) => gcd,
@@ -114,6 +133,41 @@ fn trivial_gcd(poly1: &Expression, poly2: &Expression, vars: &[Symbol]) -> Optio
     None
 }
 
+/// GCD through `multivariate_gcd_zippel` when both inputs expand to
+/// polynomials in `vars` with `i64` coefficients
+fn modular_gcd(poly1: &Expression, poly2: &Expression, vars: &[Symbol]) -> Option<Expression> {
+    let to_multipoly = |poly: &Expression| -> Option<MultiPoly> {
+        expression_to_sparse_polynomial(&poly.expand(), vars)?
+            .terms
+            .into_iter()
+            .filter(|(_, coeff)| *coeff != BigRational::from_integer(BigInt::from(0)))
+            .map(|(monomial, coeff)| {
+                let coeff = coeff.is_integer().then(|| coeff.to_integer().to_i64())??;
+                Some((monomial.exponents, coeff))
+            })
+            .collect()
+    };
+    let (f, g) = (to_multipoly(poly1)?, to_multipoly(poly2)?);
+
+    let gcd = multivariate_gcd_zippel(&f, &g, vars.len(), &MultivariateConfig::default())
+        .ok()?
+        .gcd;
+    let terms = gcd
+        .into_iter()
+        .map(|(exponents, coeff)| {
+            (
+                Monomial::new(exponents),
+                BigRational::from_integer(BigInt::from(coeff)),
+            )
+        })
+        .collect();
+    let sparse = SparsePolynomial {
+        terms,
+        num_vars: vars.len(),
+    };
+    Some(sparse_polynomial_to_expression(&sparse, vars).simplify())
+}
+
 /// Heuristic multivariate polynomial GCD in Z[X]
 ///
 /// Implements SymPy's `dmp_zz_heu_gcd` algorithm:
@@ -1086,6 +1140,28 @@ mod tests {
         assert!(!result.is_zero());
     }
 
+    #[test]
+    fn test_modular_gcd_of_expanded_products() {
+        let x = symbol!(x);
+        let y = symbol!(y);
+        let z = symbol!(z);
+
+        let common = expr!(x + y + 1);
+        let p1 = Expression::mul(vec![common.clone(), expr!(x - y)]).expand();
+        let p2 = Expression::mul(vec![common.clone(), expr!(x + (2 * y))]).expand();
+        let result = multivariate_gcd(&p1, &p2, &[x.clone(), y.clone()]);
+        assert_eq!(result, common.simplify());
+
+        let common = expr!((x * z) - (y ^ 2));
+        let p1 = Expression::mul(vec![expr!(3), common.clone(), expr!(z + 1)]);
+        let p2 = Expression::mul(vec![expr!(6), common.clone(), expr!(x - z)]);
+        let result = multivariate_gcd(&p1, &p2, &[x, y, z]);
+        assert_eq!(
+            result,
+            Expression::mul(vec![expr!(3), common]).expand().simplify()
+        );
+    }
+
     #[test]
     fn test_univariate_interpolate() {
         let x = symbol!(x);
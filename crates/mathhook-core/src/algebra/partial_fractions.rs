@@ -0,0 +1,500 @@
+//! Partial fraction decomposition
+//!
+//! Rewrites a rational function P(x)/Q(x) with rational coefficients as a
+//! polynomial plus a sum of proper fractions over the factors of Q:
+//!
+//! ```text
+//! P/Q = S + Σᵢ Σⱼ₌₁ᵐⁱ Aᵢⱼ/Fᵢʲ        deg Aᵢⱼ < deg Fᵢ
+//! ```
+//!
+//! where Q = c·F₁^m₁⋯Fₖ^mₖ. The denominator is split by square-free
+//! factorization and its rational roots, so every linear factor over Q is
+//! found and a quadratic without rational roots is irreducible. A factor of
+//! degree three or more without rational roots is kept whole.
+//!
+//! For each factor power Fᵐ with cofactor C = Q/Fᵐ, the numerator of the
+//! fraction over Fᵐ is s = P·C⁻¹ mod Fᵐ; its digits in base F are the Aᵢⱼ.
+//!
+//! Coefficients must be rational numbers: expressions with other symbols,
+//! floats or radicals are not decomposed.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use mathhook_core::algebra::Apart;
+//! use mathhook_core::{expr, symbol, Expression};
+//!
+//! let x = symbol!(x);
+//! let fraction = expr!((3 * x + 5) / ((x + 1) * (x + 2)));
+//!
+//! // 2/(x + 1) + 1/(x + 2)
+//! let expected = Expression::add(vec![
+//!     Expression::mul(vec![expr!(2), expr!((x + 1) ^ (-1))]),
+//!     expr!((x + 2) ^ (-1)),
+//! ]);
+//! assert_eq!(fraction.apart(&x), expected);
+//! ```
+
+use crate::core::polynomial::algorithms::square_free_factorization_poly;
+use crate::core::polynomial::poly::RationalPoly;
+use crate::core::{Expression, Number, Symbol};
+use crate::simplify::Simplify;
+use num_integer::Integer;
+use num_rational::Ratio;
+use num_traits::{One, ToPrimitive, Zero};
+
+pub(crate) type Q = Ratio<i64>;
+
+/// Partial fraction decomposition of rational functions
+pub trait Apart {
+    /// Decompose into partial fractions with respect to `var`
+    ///
+    /// Returns the expression unchanged if it is not a rational function of
+    /// `var` with rational coefficients.
+    fn apart(&self, var: &Symbol) -> Self;
+}
+
+impl Apart for Expression {
+    fn apart(&self, var: &Symbol) -> Self {
+        partial_fraction_terms(self, var)
+            .map(Expression::add)
+            .unwrap_or_else(|| self.clone())
+    }
+}
+
+/// Terms of the partial fraction decomposition of `expr` in `var`
+///
+/// The polynomial part, if any, comes first, followed by one term per
+/// power of each denominator factor. Factors are scaled to integer
+/// coefficients.
+///
+/// # Returns
+///
+/// `None` if `expr` is not a rational function of `var` with rational
+/// coefficients.
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::algebra::partial_fractions::partial_fraction_terms;
+/// use mathhook_core::{expr, symbol};
+///
+/// let x = symbol!(x);
+/// // x²/(x² - 1) = 1 + (1/2)/(x - 1) - (1/2)/(x + 1)
+/// let terms = partial_fraction_terms(&expr!((x ^ 2) / ((x ^ 2) - 1)), &x).unwrap();
+/// assert_eq!(terms.len(), 3);
+/// assert_eq!(terms[0], expr!(1));
+///
+/// assert!(partial_fraction_terms(&expr!(sin(x) / x), &x).is_none());
+/// ```
+pub fn partial_fraction_terms(expr: &Expression, var: &Symbol) -> Option<Vec<Expression>> {
+    let f = to_rational_function(expr, var)?;
+    Some(decompose(&f, &[])?.terms(&Expression::symbol(var.clone())))
+}
+
+/// num/den in lowest terms with a monic denominator
+#[derive(Debug, Clone)]
+pub(crate) struct RationalFunction {
+    pub(crate) num: RationalPoly,
+    pub(crate) den: RationalPoly,
+}
+
+impl RationalFunction {
+    pub(crate) fn new(num: RationalPoly, den: RationalPoly) -> Self {
+        let g = monic_gcd(&num, &den);
+        let (num, _) = num.div_rem(&g).expect("gcd of a nonzero denominator");
+        let (den, _) = den.div_rem(&g).expect("gcd of a nonzero denominator");
+        let lc = den.leading_coeff();
+        Self {
+            num: num.scale(&lc.recip()),
+            den: den.scale(&lc.recip()),
+        }
+    }
+
+    pub(crate) fn constant(c: Q) -> Self {
+        Self {
+            num: RationalPoly::constant(c),
+            den: RationalPoly::constant(Q::one()),
+        }
+    }
+
+    pub(crate) fn add(&self, other: &Self) -> Self {
+        Self::new(
+            &(&self.num * &other.den) + &(&other.num * &self.den),
+            &self.den * &other.den,
+        )
+    }
+
+    pub(crate) fn mul(&self, other: &Self) -> Self {
+        Self::new(&self.num * &other.num, &self.den * &other.den)
+    }
+
+    pub(crate) fn pow(&self, n: i64) -> Option<Self> {
+        let (base, n) = if n < 0 {
+            if self.num.is_zero() {
+                return None;
+            }
+            (Self::new(self.den.clone(), self.num.clone()), -n)
+        } else {
+            (self.clone(), n)
+        };
+        Some((0..n).fold(Self::constant(Q::one()), |acc, _| acc.mul(&base)))
+    }
+}
+
+/// Proper fraction numerator/factorᵖᵒʷᵉʳ with a monic factor
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Fraction {
+    pub(crate) numerator: RationalPoly,
+    pub(crate) factor: RationalPoly,
+    pub(crate) power: usize,
+}
+
+impl Fraction {
+    /// The fraction with its factor scaled to integer coefficients
+    fn to_expression(&self, x: &Expression) -> Expression {
+        let scale = self
+            .factor
+            .coefficients()
+            .iter()
+            .fold(1i64, |acc, c| acc.lcm(c.denom()));
+        let scale = Q::from_integer(scale);
+        let mut numerator = self.numerator.clone();
+        for _ in 0..self.power {
+            numerator = numerator.scale(&scale);
+        }
+        let denominator = Expression::pow(
+            poly_expr(&self.factor.scale(&scale), x),
+            Expression::integer(-(self.power as i64)),
+        );
+        match numerator.degree() {
+            Some(0) if numerator.coeff(0).is_one() => denominator,
+            _ => Expression::mul(vec![poly_expr(&numerator, x), denominator]),
+        }
+    }
+}
+
+/// num/den as a polynomial plus proper fractions
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Decomposition {
+    pub(crate) polynomial: RationalPoly,
+    pub(crate) fractions: Vec<Fraction>,
+}
+
+impl Decomposition {
+    /// The polynomial part, unless it is zero, followed by the fractions
+    pub(crate) fn terms(&self, x: &Expression) -> Vec<Expression> {
+        let mut terms = Vec::with_capacity(self.fractions.len() + 1);
+        if !self.polynomial.is_zero() || self.fractions.is_empty() {
+            terms.push(poly_expr(&self.polynomial, x));
+        }
+        terms.extend(
+            self.fractions
+                .iter()
+                .map(|fraction| fraction.to_expression(x)),
+        );
+        terms
+    }
+}
+
+/// Decompose `f` into partial fractions
+///
+/// `pieces` are polynomials known to divide the denominator's factors; they
+/// split factors that have no rational roots.
+pub(crate) fn decompose(f: &RationalFunction, pieces: &[RationalPoly]) -> Option<Decomposition> {
+    let (polynomial, remainder) = f.num.div_rem(&f.den).ok()?;
+    let mut fractions = Vec::new();
+    if remainder.is_zero() {
+        return Some(Decomposition {
+            polynomial,
+            fractions,
+        });
+    }
+
+    for (factor, multiplicity) in factor_denominator(&f.den, pieces)? {
+        let power = (1..multiplicity).fold(factor.clone(), |acc, _| &acc * &factor);
+        let (cofactor, _) = f.den.div_rem(&power).ok()?;
+        let (_, s) = (&remainder * &inverse_mod(&cofactor, &power)?)
+            .div_rem(&power)
+            .ok()?;
+
+        // s/Fᵐ = Σ Aᵢ/Fⁱ with Aᵢ the base-F digits of s
+        let mut digits = Vec::with_capacity(multiplicity);
+        let mut rest = s;
+        for i in (1..=multiplicity).rev() {
+            let (q, digit) = rest.div_rem(&factor).ok()?;
+            rest = q;
+            if !digit.is_zero() {
+                digits.push(Fraction {
+                    numerator: digit,
+                    factor: factor.clone(),
+                    power: i,
+                });
+            }
+        }
+        fractions.extend(digits.into_iter().rev());
+    }
+
+    Some(Decomposition {
+        polynomial,
+        fractions,
+    })
+}
+
+/// `expr` as a rational function of `var` with rational coefficients
+pub(crate) fn to_rational_function(expr: &Expression, var: &Symbol) -> Option<RationalFunction> {
+    match expr {
+        Expression::Number(number) => Some(RationalFunction::constant(to_ratio(number)?)),
+        Expression::Symbol(symbol) if symbol == var => Some(RationalFunction::new(
+            RationalPoly::from_coeffs(vec![Q::zero(), Q::one()]),
+            RationalPoly::constant(Q::one()),
+        )),
+        Expression::Add(terms) => terms
+            .iter()
+            .try_fold(RationalFunction::constant(Q::zero()), |sum, term| {
+                Some(sum.add(&to_rational_function(term, var)?))
+            }),
+        Expression::Mul(factors) => factors
+            .iter()
+            .try_fold(RationalFunction::constant(Q::one()), |product, factor| {
+                Some(product.mul(&to_rational_function(factor, var)?))
+            }),
+        Expression::Pow(base, exp) => match exp.as_ref() {
+            Expression::Number(Number::Integer(n)) => to_rational_function(base, var)?.pow(*n),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+pub(crate) fn to_ratio(number: &Number) -> Option<Q> {
+    match number {
+        Number::Integer(n) => Some(Q::from_integer(*n)),
+        Number::Rational(r) => Some(Q::new(r.numer().to_i64()?, r.denom().to_i64()?)),
+        _ => None,
+    }
+}
+
+/// Monic gcd over Q; gcd(0, 0) = 1 so it can always divide
+pub(crate) fn monic_gcd(a: &RationalPoly, b: &RationalPoly) -> RationalPoly {
+    let (mut a, mut b) = (a.clone(), b.clone());
+    while !b.is_zero() {
+        let (_, r) = a.div_rem(&b).expect("nonzero divisor");
+        a = b;
+        b = r;
+    }
+    if a.is_zero() {
+        return RationalPoly::constant(Q::one());
+    }
+    let lc = a.leading_coeff();
+    a.scale(&lc.recip())
+}
+
+/// Inverse of `a` modulo `m` by the extended Euclidean algorithm
+fn inverse_mod(a: &RationalPoly, m: &RationalPoly) -> Option<RationalPoly> {
+    let (mut r0, mut r1) = (m.clone(), a.div_rem(m).ok()?.1);
+    let (mut s0, mut s1) = (RationalPoly::zero(), RationalPoly::constant(Q::one()));
+    while !r1.is_zero() {
+        let (q, r) = r0.div_rem(&r1).ok()?;
+        let s = &s0 - &(&q * &s1);
+        (r0, r1) = (r1, r);
+        (s0, s1) = (s1, s);
+    }
+    if r0.degree() != Some(0) {
+        return None;
+    }
+    let inverse = s0.scale(&r0.leading_coeff().recip());
+    Some(inverse.div_rem(m).ok()?.1)
+}
+
+/// Monic, pairwise coprime factors with multiplicities: every linear factor
+/// over Q, and what is left after splitting by `pieces`
+fn factor_denominator(
+    den: &RationalPoly,
+    pieces: &[RationalPoly],
+) -> Option<Vec<(RationalPoly, usize)>> {
+    let mut factors = Vec::new();
+    for (square_free, multiplicity) in square_free_factorization_poly(den).ok()? {
+        if square_free.is_constant() {
+            continue;
+        }
+        let mut parts = vec![monic(&square_free)];
+        for piece in pieces {
+            parts = parts
+                .into_iter()
+                .flat_map(|part| {
+                    let g = monic_gcd(&part, piece);
+                    match g.degree() {
+                        Some(d) if d > 0 && Some(d) < part.degree() => {
+                            let (cofactor, _) = part.div_rem(&g).expect("nonzero gcd");
+                            vec![g, cofactor]
+                        }
+                        _ => vec![part],
+                    }
+                })
+                .collect();
+        }
+
+        for mut rest in parts {
+            while let Some(root) = rational_root(&rest) {
+                let linear = RationalPoly::from_coeffs(vec![-root, Q::one()]);
+                rest = rest.div_rem(&linear).ok()?.0;
+                factors.push((linear, multiplicity));
+            }
+            if rest.degree()? > 0 {
+                factors.push((monic(&rest), multiplicity));
+            }
+        }
+    }
+    Some(factors)
+}
+
+fn monic(p: &RationalPoly) -> RationalPoly {
+    p.scale(&p.leading_coeff().recip())
+}
+
+/// A rational root by the rational root theorem
+fn rational_root(p: &RationalPoly) -> Option<Q> {
+    if p.degree()? == 0 {
+        return None;
+    }
+    if p.coeff(0).is_zero() {
+        return Some(Q::zero());
+    }
+
+    // Clear denominators to get integer coefficients
+    let lcm = p
+        .coefficients()
+        .iter()
+        .fold(1i64, |acc, c| acc.lcm(c.denom()));
+    let ints: Vec<i64> = p
+        .coefficients()
+        .iter()
+        .map(|c| (c * Q::from_integer(lcm)).to_integer())
+        .collect();
+
+    let constant = *ints.first()?;
+    let leading = *ints.last()?;
+    for numer in divisors(constant) {
+        for denom in divisors(leading) {
+            for candidate in [Q::new(numer, denom), Q::new(-numer, denom)] {
+                if p.evaluate(&candidate).is_zero() {
+                    return Some(candidate);
+                }
+            }
+        }
+    }
+    None
+}
+
+fn divisors(n: i64) -> Vec<i64> {
+    let n = n.abs();
+    (1..=n)
+        .take_while(|d| d * d <= n)
+        .fold(Vec::new(), |mut acc, d| {
+            if n % d == 0 {
+                acc.push(d);
+                if d != n / d {
+                    acc.push(n / d);
+                }
+            }
+            acc
+        })
+}
+
+pub(crate) fn ratio_expr(r: &Q) -> Expression {
+    if r.is_integer() {
+        Expression::integer(r.to_integer())
+    } else {
+        Expression::rational(*r.numer(), *r.denom())
+    }
+}
+
+pub(crate) fn poly_expr(p: &RationalPoly, t: &Expression) -> Expression {
+    let terms: Vec<Expression> = p
+        .coefficients()
+        .iter()
+        .enumerate()
+        .filter(|(_, c)| !c.is_zero())
+        .map(|(i, c)| {
+            Expression::mul(vec![
+                ratio_expr(c),
+                Expression::pow(t.clone(), Expression::integer(i as i64)),
+            ])
+        })
+        .collect();
+    Expression::add(terms).simplify()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algebra::solvers::Assumptions;
+    use crate::simplify::verification::find_mismatch;
+    use crate::{expr, symbol};
+
+    fn check(fraction: Expression) -> Vec<Expression> {
+        let x = symbol!(x);
+        let terms = partial_fraction_terms(&fraction, &x)
+            .unwrap_or_else(|| panic!("no decomposition for {}", fraction));
+        let sum = Expression::add(terms.clone());
+        assert!(
+            find_mismatch(&fraction, &sum, &Assumptions::new()).is_none(),
+            "{} != {}",
+            sum,
+            fraction
+        );
+        terms
+    }
+
+    #[test]
+    fn test_distinct_linear_factors() {
+        let x = symbol!(x);
+        assert_eq!(check(expr!((3 * x + 5) / ((x + 1) * (x + 2)))).len(), 2);
+        assert_eq!(check(expr!(((x ^ 3) - x) ^ (-1))).len(), 3);
+
+        // 1/(2x + 1) keeps integer coefficients
+        assert_eq!(
+            expr!((2 * x + 1) ^ (-1)).apart(&x),
+            expr!((2 * x + 1) ^ (-1))
+        );
+    }
+
+    #[test]
+    fn test_repeated_and_quadratic_factors() {
+        // 1/(x(x + 1)²) = 1/x - 1/(x + 1) - 1/(x + 1)²
+        assert_eq!(check(expr!((x * ((x + 1) ^ 2)) ^ (-1))).len(), 3);
+
+        // 1/((x - 1)(x² + 1)) has a linear and a quadratic fraction
+        let terms = check(expr!(((x - 1) * ((x ^ 2) + 1)) ^ (-1)));
+        assert_eq!(terms.len(), 2);
+
+        // (x⁴ + 5x² + 4) has no rational roots, so it stays whole
+        assert_eq!(check(expr!(((x ^ 4) + (5 * (x ^ 2)) + 4) ^ (-1))).len(), 1);
+    }
+
+    #[test]
+    fn test_polynomial_part_and_cancellation() {
+        let x = symbol!(x);
+        let terms = check(expr!(((x ^ 3) + 1) / ((x ^ 2) - 1)));
+        assert_eq!(terms[0], expr!(x));
+
+        // (x² - 1)/(x - 1) = x + 1
+        assert_eq!(
+            expr!(((x ^ 2) - 1) / (x - 1)).apart(&x),
+            expr!(x + 1).simplify()
+        );
+    }
+
+    #[test]
+    fn test_unsupported_expressions_are_unchanged() {
+        let x = symbol!(x);
+        for unsupported in [
+            expr!(a / (x + a)),
+            expr!(sin(x) / (x + 1)),
+            expr!(x ^ (1 / 2)),
+        ] {
+            assert_eq!(unsupported.apart(&x), unsupported);
+        }
+    }
+}
@@ -9,6 +9,8 @@
 //! - In general, simplification with noncommutative terms is NOT always valid
 //! - This implementation currently preserves order and does NOT auto-simplify noncommutative rationals
 
+use crate::algebra::polynomial_division::polynomial_div;
+use crate::algebra::rationalize::RationalizeDenominator;
 use crate::core::{Expression, Number};
 use num_bigint::BigInt;
 use num_rational::BigRational;
@@ -90,9 +92,9 @@ impl RationalSimplify for Expression {
     }
 
     /// Rationalize denominators (remove radicals from denominators)
+    /// Clear radicals and `i` from the denominator
     fn rationalize(&self) -> Self {
-        // This is a complex operation - simplified implementation for now
-        self.clone()
+        self.rationalize_denominator()
     }
 }
 
@@ -220,24 +222,29 @@ impl Expression {
             _ if dividend == divisor => Expression::integer(1),
 
             // Multiplication division
-            (Expression::Mul(factors), _) => {
+            (Expression::Mul(factors), _) if factors.contains(divisor) => {
                 let mut remaining_factors = factors.as_ref().clone();
                 if let Some(pos) = remaining_factors.iter().position(|f| f == divisor) {
                     remaining_factors.remove(pos);
-                    if remaining_factors.is_empty() {
-                        Expression::integer(1)
-                    } else if remaining_factors.len() == 1 {
-                        remaining_factors[0].clone()
-                    } else {
-                        Expression::mul(remaining_factors)
-                    }
+                }
+                if remaining_factors.is_empty() {
+                    Expression::integer(1)
+                } else if remaining_factors.len() == 1 {
+                    remaining_factors[0].clone()
                 } else {
-                    dividend.clone()
+                    Expression::mul(remaining_factors)
                 }
             }
 
-            // Default: return original
-            _ => dividend.clone(),
+            // Exact polynomial division in a variable of the divisor,
+            // otherwise return original
+            _ => match divisor.find_variables().first() {
+                Some(var) => match polynomial_div(dividend, divisor, var) {
+                    Ok((quotient, remainder)) if remainder.is_zero() => quotient,
+                    _ => dividend.clone(),
+                },
+                None => dividend.clone(),
+            },
         }
     }
 
@@ -384,7 +391,8 @@ impl Expression {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::symbol;
+    use crate::simplify::Simplify;
+    use crate::{expr, symbol};
 
     #[test]
     fn test_rational_detection() {
@@ -466,6 +474,15 @@ mod tests {
         assert!(!result.is_zero());
     }
 
+    #[test]
+    fn test_polynomial_factor_cancellation() {
+        let numerator = expr!((x ^ 2) - 1);
+        let denominator = expr!(x - 1);
+
+        let expr = Expression::integer(1).create_rational_division(&numerator, &denominator);
+        assert_eq!(expr.simplify_rational().simplify(), expr!(x + 1));
+    }
+
     #[test]
     fn test_extract_rational_coefficient() {
         let x = symbol!(x);
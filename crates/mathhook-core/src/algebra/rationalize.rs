@@ -0,0 +1,589 @@
+//! Rationalizing denominators
+//!
+//! Rewrites a quotient so that its denominator is free of radicals and of
+//! the imaginary unit:
+//!
+//! - a single radical `r^(p/q)` is cleared by multiplying by `r^(1 - p/q)`
+//! - a sum of square roots is cleared by multiplying by its conjugate, using
+//!   `(a + b)(a - b) = a² - b²`, repeatedly until no square root is left
+//! - a complex denominator `a + b i` is cleared by its complex conjugate,
+//!   treating `i` as `√(-1)`
+//!
+//! The arithmetic works on sums of `coefficient · radicals` terms, so that
+//! `√2 · √2 = 2` is applied exactly and only radical-free coefficients go
+//! through `simplify`.
+
+use crate::core::{Expression, Number};
+use crate::educational::step_by_step::{Step, StepByStepExplanation};
+use crate::simplify::Simplify;
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_rational::BigRational;
+use num_traits::{One, Signed, Zero};
+
+/// Upper bound on conjugate multiplications for a single denominator
+const MAX_ROUNDS: usize = 8;
+
+/// Trait for clearing radicals and `i` from denominators
+pub trait RationalizeDenominator {
+    /// Rewrite with a radical-free denominator, or return the expression
+    /// unchanged when it cannot be rationalized
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::algebra::RationalizeDenominator;
+    /// use mathhook_core::Expression;
+    ///
+    /// // 1/(1 + √2) = √2 - 1
+    /// let sqrt2 = Expression::sqrt(Expression::integer(2));
+    /// let expr = Expression::div(
+    ///     Expression::integer(1),
+    ///     Expression::add(vec![Expression::integer(1), sqrt2.clone()]),
+    /// );
+    /// assert_eq!(
+    ///     expr.rationalize_denominator(),
+    ///     Expression::add(vec![Expression::integer(-1), sqrt2])
+    /// );
+    /// ```
+    fn rationalize_denominator(&self) -> Expression;
+
+    /// Rationalize and explain each multiplication
+    fn rationalize_denominator_with_steps(&self) -> (Expression, StepByStepExplanation);
+}
+
+impl RationalizeDenominator for Expression {
+    fn rationalize_denominator(&self) -> Expression {
+        self.rationalize_denominator_with_steps().0
+    }
+
+    fn rationalize_denominator_with_steps(&self) -> (Expression, StepByStepExplanation) {
+        let mut steps = Vec::new();
+        let result = match self {
+            Expression::Add(terms) => Expression::add(
+                terms
+                    .iter()
+                    .map(|term| rationalize_quotient(term, &mut steps))
+                    .collect(),
+            ),
+            _ => rationalize_quotient(self, &mut steps),
+        };
+        if steps.is_empty() {
+            steps.push(step(
+                "Nothing to Rationalize",
+                format!("The denominator of {} has no radicals", self),
+                self.clone(),
+                "Identity",
+            ));
+        }
+        let mut explanation = StepByStepExplanation::new(steps);
+        explanation.initial_expression = self.clone();
+        explanation.final_expression = result.clone();
+        (result, explanation)
+    }
+}
+
+fn rationalize_quotient(expr: &Expression, steps: &mut Vec<Step>) -> Expression {
+    let (numerator, denominator) = split_fraction(expr);
+    if !has_radical(&denominator) {
+        return expr.clone();
+    }
+    let (Some(mut num), Some(mut den)) = (surds(&numerator), surds(&denominator)) else {
+        return expr.clone();
+    };
+    steps.push(step(
+        "Identify Denominator",
+        format!("The denominator {} contains radicals", denominator),
+        denominator,
+        "Identify Denominator",
+    ));
+
+    for _ in 0..MAX_ROUNDS {
+        if den.iter().all(|term| term.radicals.is_empty()) {
+            break;
+        }
+        let Some((multiplier, kind)) = rationalizing_factor(&den) else {
+            return expr.clone();
+        };
+        let multiplier_expr = to_expression(&multiplier);
+        num = multiply(&num, &multiplier);
+        den = multiply(&den, &multiplier);
+        let (title, reason) = match kind {
+            Factor::Radical => (
+                "Multiply by the Radical",
+                "raising each radical to a whole power",
+            ),
+            Factor::Conjugate => (
+                "Multiply by the Conjugate",
+                "using (a + b)(a - b) = a² - b² to square the radical away",
+            ),
+            Factor::ComplexConjugate => (
+                "Multiply by the Complex Conjugate",
+                "using (a + bi)(a - bi) = a² + b²",
+            ),
+        };
+        steps.push(step(
+            title,
+            format!(
+                "Multiply numerator and denominator by {}, {}: the denominator becomes {}",
+                multiplier_expr,
+                reason,
+                to_expression(&den)
+            ),
+            multiplier_expr,
+            title,
+        ));
+    }
+    if den.iter().any(|term| !term.radicals.is_empty()) {
+        return expr.clone();
+    }
+
+    let denominator = tidy(&Expression::add(
+        den.into_iter().map(|term| term.coefficient).collect(),
+    ));
+    if denominator.is_zero() {
+        return expr.clone();
+    }
+    let terms: Vec<Term> = num
+        .into_iter()
+        .map(|term| Term {
+            coefficient: tidy(&Expression::div(term.coefficient, denominator.clone())),
+            radicals: term.radicals,
+        })
+        .collect();
+    let result = to_expression(&combine(terms));
+    steps.push(step(
+        "Divide",
+        format!(
+            "Divide the numerator by the rational denominator {}: {}",
+            denominator, result
+        ),
+        result.clone(),
+        "Divide",
+    ));
+    result
+}
+
+/// `(numerator, denominator)` from factors with negative exponents
+fn split_fraction(expr: &Expression) -> (Expression, Expression) {
+    let factors: Vec<Expression> = match expr {
+        Expression::Mul(factors) => factors.to_vec(),
+        _ => vec![expr.clone()],
+    };
+    let mut numerator = Vec::new();
+    let mut denominator = Vec::new();
+    for factor in factors {
+        match &factor {
+            Expression::Pow(base, exp) if is_negative(exp) => {
+                let positive = tidy(&Expression::mul(vec![
+                    Expression::integer(-1),
+                    exp.as_ref().clone(),
+                ]));
+                denominator.push(if positive == Expression::integer(1) {
+                    base.as_ref().clone()
+                } else {
+                    Expression::pow(base.as_ref().clone(), positive)
+                });
+            }
+            _ => numerator.push(factor),
+        }
+    }
+    (product(numerator), product(denominator))
+}
+
+fn product(mut factors: Vec<Expression>) -> Expression {
+    match factors.len() {
+        0 => Expression::integer(1),
+        1 => factors.remove(0),
+        _ => Expression::mul(factors),
+    }
+}
+
+fn is_negative(expr: &Expression) -> bool {
+    match expr {
+        Expression::Number(Number::Integer(n)) => *n < 0,
+        Expression::Number(Number::Rational(r)) => r.is_negative(),
+        _ => false,
+    }
+}
+
+/// `coefficient · Π base^exponent` with every exponent in `(0, 1)`
+#[derive(Debug, Clone, PartialEq)]
+struct Term {
+    coefficient: Expression,
+    radicals: Vec<(Expression, BigRational)>,
+}
+
+impl Term {
+    fn constant(coefficient: Expression) -> Self {
+        Self {
+            coefficient,
+            radicals: Vec::new(),
+        }
+    }
+
+    /// `base^exponent`, with whole powers moved into the coefficient
+    fn radical(base: Expression, exponent: BigRational) -> Self {
+        Self::constant(Expression::integer(1)).times(&Self {
+            coefficient: Expression::integer(1),
+            radicals: vec![(base, exponent)],
+        })
+    }
+
+    fn times(&self, other: &Term) -> Term {
+        let mut coefficient = vec![self.coefficient.clone(), other.coefficient.clone()];
+        let mut radicals = self.radicals.clone();
+        for (base, exponent) in &other.radicals {
+            match radicals.iter_mut().find(|(b, _)| b == base) {
+                Some((_, e)) => *e += exponent,
+                None => radicals.push((base.clone(), exponent.clone())),
+            }
+        }
+        let mut kept = Vec::new();
+        for (base, exponent) in radicals {
+            let whole = exponent.floor();
+            if !whole.is_zero() {
+                coefficient.push(Expression::pow(
+                    base.clone(),
+                    Expression::big_integer(whole.to_integer()),
+                ));
+            }
+            let fraction = exponent - whole;
+            if !fraction.is_zero() {
+                kept.push((base, fraction));
+            }
+        }
+        kept.sort_by_key(|(base, _)| base.to_string());
+        Term {
+            coefficient: tidy(&Expression::mul(coefficient)),
+            radicals: kept,
+        }
+    }
+
+    fn negated(&self) -> Term {
+        Term {
+            coefficient: tidy(&Expression::mul(vec![
+                Expression::integer(-1),
+                self.coefficient.clone(),
+            ])),
+            radicals: self.radicals.clone(),
+        }
+    }
+
+    fn to_expression(&self) -> Expression {
+        let mut factors = Vec::new();
+        if self.coefficient != Expression::integer(1) || self.radicals.is_empty() {
+            factors.push(self.coefficient.clone());
+        }
+        factors.extend(self.radicals.iter().map(|(base, exponent)| {
+            let half = BigRational::new(BigInt::one(), BigInt::from(2));
+            if *exponent == half && *base == Expression::integer(-1) {
+                Expression::i()
+            } else if *exponent == half {
+                Expression::sqrt(base.clone())
+            } else {
+                Expression::pow(
+                    base.clone(),
+                    Expression::Number(Number::rational(exponent.clone())),
+                )
+            }
+        }));
+        product(factors)
+    }
+}
+
+fn to_expression(terms: &[Term]) -> Expression {
+    match terms.len() {
+        0 => Expression::integer(0),
+        1 => terms[0].to_expression(),
+        _ => Expression::add(terms.iter().map(Term::to_expression).collect()),
+    }
+}
+
+/// Collect terms with the same radicals, dropping those that cancel
+fn combine(terms: Vec<Term>) -> Vec<Term> {
+    let mut combined: Vec<Term> = Vec::new();
+    for term in terms {
+        match combined.iter_mut().find(|t| t.radicals == term.radicals) {
+            Some(existing) => {
+                existing.coefficient = tidy(&Expression::add(vec![
+                    existing.coefficient.clone(),
+                    term.coefficient,
+                ]));
+            }
+            None => combined.push(term),
+        }
+    }
+    combined.retain(|term| !term.coefficient.is_zero());
+    combined
+}
+
+fn multiply(a: &[Term], b: &[Term]) -> Vec<Term> {
+    combine(
+        a.iter()
+            .flat_map(|x| b.iter().map(move |y| x.times(y)))
+            .collect(),
+    )
+}
+
+/// Which kind of factor clears the next radical
+enum Factor {
+    Radical,
+    Conjugate,
+    ComplexConjugate,
+}
+
+/// The factor that removes one radical from `den`
+fn rationalizing_factor(den: &[Term]) -> Option<(Vec<Term>, Factor)> {
+    if let [single] = den {
+        let radicals = single
+            .radicals
+            .iter()
+            .map(|(base, exponent)| (base.clone(), BigRational::one() - exponent))
+            .collect();
+        let kind = if single
+            .radicals
+            .iter()
+            .all(|(base, _)| *base == Expression::integer(-1))
+        {
+            Factor::ComplexConjugate
+        } else {
+            Factor::Radical
+        };
+        return Some((
+            vec![Term {
+                coefficient: Expression::integer(1),
+                radicals,
+            }],
+            kind,
+        ));
+    }
+    let half = BigRational::new(BigInt::one(), BigInt::from(2));
+    let square_roots_only = den
+        .iter()
+        .all(|term| term.radicals.iter().all(|(_, exponent)| *exponent == half));
+    if !square_roots_only {
+        return None;
+    }
+    let index = den.iter().rposition(|term| !term.radicals.is_empty())?;
+    let kind = if den[index].radicals == [(Expression::integer(-1), half)] {
+        Factor::ComplexConjugate
+    } else {
+        Factor::Conjugate
+    };
+    let conjugate = den
+        .iter()
+        .enumerate()
+        .map(|(i, term)| {
+            if i == index {
+                term.negated()
+            } else {
+                term.clone()
+            }
+        })
+        .collect();
+    Some((conjugate, kind))
+}
+
+/// `expr` as a sum of terms, `None` for radicals it cannot multiply out
+fn surds(expr: &Expression) -> Option<Vec<Term>> {
+    if !has_radical(expr) {
+        return Some(vec![Term::constant(tidy(expr))]);
+    }
+    if let Some((base, exponent)) = as_radical(expr) {
+        return Some(vec![Term::radical(base, exponent)]);
+    }
+    match expr {
+        Expression::Add(terms) => {
+            let mut all = Vec::new();
+            for term in terms.iter() {
+                all.extend(surds(term)?);
+            }
+            Some(combine(all))
+        }
+        Expression::Mul(factors) => {
+            let mut result = vec![Term::constant(Expression::integer(1))];
+            for factor in factors.iter() {
+                result = multiply(&result, &surds(factor)?);
+            }
+            Some(result)
+        }
+        Expression::Pow(base, exp) => match exp.as_ref() {
+            Expression::Number(Number::Integer(k)) if (1..=16).contains(k) => {
+                let base = surds(base)?;
+                let mut result = base.clone();
+                for _ in 1..*k {
+                    result = multiply(&result, &base);
+                }
+                Some(result)
+            }
+            _ => None,
+        },
+        Expression::Complex(data) => {
+            let mut all = surds(&data.real)?;
+            let i = Term::radical(Expression::integer(-1), half());
+            all.extend(multiply(&surds(&data.imag)?, &[i]));
+            Some(combine(all))
+        }
+        _ => None,
+    }
+}
+
+fn half() -> BigRational {
+    BigRational::new(BigInt::one(), BigInt::from(2))
+}
+
+/// `(base, exponent)` for `√base`, `base^(p/q)` and `i`
+fn as_radical(expr: &Expression) -> Option<(Expression, BigRational)> {
+    match expr {
+        Expression::Function { name, args, .. } if name.as_ref() == "sqrt" && args.len() == 1 => {
+            Some((args[0].clone(), half()))
+        }
+        Expression::Pow(base, exp) => match exp.as_ref() {
+            Expression::Number(Number::Rational(r))
+                if !r.denom().is_one() && !has_radical(base) =>
+            {
+                Some((base.as_ref().clone(), *r.clone()))
+            }
+            _ => None,
+        },
+        Expression::Constant(_) if *expr == Expression::i() => {
+            Some((Expression::integer(-1), half()))
+        }
+        _ => None,
+    }
+}
+
+/// Whether `expr` contains a square root, fractional power or `i`
+fn has_radical(expr: &Expression) -> bool {
+    if as_radical(expr).is_some() {
+        return true;
+    }
+    match expr {
+        Expression::Add(items) | Expression::Mul(items) => items.iter().any(has_radical),
+        Expression::Pow(base, exp) => has_radical(base) || has_radical(exp),
+        Expression::Function { args, .. } => args.iter().any(has_radical),
+        Expression::Complex(_) => true,
+        _ => false,
+    }
+}
+
+/// Simplify radical-free expressions, leaving radicals to the term arithmetic
+fn tidy(expr: &Expression) -> Expression {
+    if has_radical(expr) {
+        expr.clone()
+    } else {
+        normalize(expr.simplify())
+    }
+}
+
+/// Simplification can leave rationals such as `4/4` unreduced
+fn normalize(expr: Expression) -> Expression {
+    match &expr {
+        Expression::Number(Number::Rational(r)) if r.denom().is_one() => {
+            Expression::big_integer(r.numer().clone())
+        }
+        Expression::Number(Number::Rational(r)) => {
+            let gcd = r.numer().gcd(r.denom());
+            if gcd.is_one() {
+                expr
+            } else {
+                Expression::Number(Number::rational(BigRational::new(
+                    r.numer() / &gcd,
+                    r.denom() / &gcd,
+                )))
+            }
+        }
+        _ => expr,
+    }
+}
+
+fn step(title: &str, description: String, expression: Expression, rule: &str) -> Step {
+    Step {
+        title: title.to_owned(),
+        description,
+        expression,
+        rule_applied: rule.to_owned(),
+        latex: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr;
+
+    fn sqrt(n: i64) -> Expression {
+        Expression::sqrt(Expression::integer(n))
+    }
+
+    #[test]
+    fn test_single_radical() {
+        // 1/√2 = √2/2
+        let expr = Expression::div(expr!(1), sqrt(2));
+        assert_eq!(
+            expr.rationalize_denominator(),
+            Expression::mul(vec![Expression::rational(1, 2), sqrt(2)])
+        );
+
+        // 6/∛2 = 3 · 2^(2/3)
+        let cube_root = Expression::pow(expr!(2), Expression::rational(1, 3));
+        let expr = Expression::div(expr!(6), cube_root);
+        assert_eq!(
+            expr.rationalize_denominator(),
+            Expression::mul(vec![
+                expr!(3),
+                Expression::pow(expr!(2), Expression::rational(2, 3)),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_binomial_and_nested_conjugates() {
+        // (√3 + 1)/(√3 - 1) = 2 + √3
+        let expr = Expression::div(
+            Expression::add(vec![sqrt(3), expr!(1)]),
+            Expression::add(vec![sqrt(3), expr!(-1)]),
+        );
+        let (result, explanation) = expr.rationalize_denominator_with_steps();
+        assert_eq!(result, Expression::add(vec![sqrt(3), expr!(2)]));
+        assert!(explanation
+            .steps
+            .iter()
+            .any(|step| step.title == "Multiply by the Conjugate"));
+
+        // 1/(1 + √2 + √3) has no radicals left in the denominator
+        let expr = Expression::div(expr!(1), Expression::add(vec![expr!(1), sqrt(2), sqrt(3)]));
+        let result = expr.rationalize_denominator();
+        assert!(!matches!(result, Expression::Mul(_)));
+        let (_, denominator) = split_fraction(&result);
+        assert_eq!(denominator, expr!(1));
+    }
+
+    #[test]
+    fn test_complex_denominator() {
+        // 5/(1 + 2i) = 1 - 2i
+        let expr = Expression::div(
+            expr!(5),
+            Expression::add(vec![
+                expr!(1),
+                Expression::mul(vec![expr!(2), Expression::i()]),
+            ]),
+        );
+        let (result, explanation) = expr.rationalize_denominator_with_steps();
+        assert_eq!(
+            result,
+            Expression::add(vec![
+                expr!(1),
+                Expression::mul(vec![expr!(-2), Expression::i()]),
+            ])
+        );
+        assert!(explanation
+            .steps
+            .iter()
+            .any(|step| step.title == "Multiply by the Complex Conjugate"));
+
+        let plain = expr!(x / 2);
+        assert_eq!(plain.rationalize_denominator(), plain);
+    }
+}
@@ -4,10 +4,12 @@
 //! All methods work with closures for maximum flexibility.
 
 pub mod bisection;
+pub mod isolation;
 pub mod newton_raphson;
 pub mod secant;
 
 pub use bisection::BisectionMethod;
+pub use isolation::{find_all_roots, RootEnclosure, RootIsolationConfig};
 pub use newton_raphson::NewtonRaphson;
 pub use secant::SecantMethod;
 
@@ -0,0 +1,370 @@
+//! Isolation of every real root of an expression on an interval
+//!
+//! The single-root methods in this module need a bracket or a starting
+//! guess. [`find_all_roots`] supplies them: it scans the interval in steps
+//! of `config.step`, looking both for sign changes of `f` and for sign
+//! changes of the symbolic derivative `f'`. A critical point splits its
+//! cell into pieces on which `f` is monotone, so two roots sharing one cell
+//! are still separated, and a critical point where `f` vanishes is reported
+//! as a touching (even multiplicity) root.
+//!
+//! # Algorithm
+//!
+//! 1. Sample `f` and `f'` on the grid `a, a + step, ..., b`
+//! 2. In each cell where `f'` changes sign, bisect `f'` to the critical point
+//! 3. Refine every sign change of `f` with Newton steps kept inside the
+//!    bracket, falling back to bisection when a step leaves it
+//! 4. Drop brackets whose values grow while shrinking (poles such as `tan`)
+//!
+//! # Certification
+//!
+//! An enclosure is `certified` when `f` changes sign across it, so it
+//! contains a root of the continuous function, and `f'` keeps one nonzero
+//! sign at its ends and midpoint, so that root is simple and unique in it.
+//! Touching roots have no sign change and are never certified.
+
+use super::RootFindingConfig;
+use crate::calculus::derivatives::Derivative;
+use crate::core::expression::CompiledExpression;
+use crate::core::{Expression, Symbol};
+use crate::error::MathError;
+
+/// Configuration for [`find_all_roots`]
+#[derive(Debug, Clone)]
+pub struct RootIsolationConfig {
+    /// Width of the scanning cells; roots closer than this are only
+    /// separated when a critical point lies between them
+    pub step: f64,
+    /// Largest width of a returned enclosure
+    pub tolerance: f64,
+    /// Iteration limit when polishing a single root
+    pub max_iterations: usize,
+}
+
+impl Default for RootIsolationConfig {
+    fn default() -> Self {
+        Self {
+            step: 1e-2,
+            tolerance: 1e-12,
+            max_iterations: 200,
+        }
+    }
+}
+
+impl From<&RootFindingConfig> for RootIsolationConfig {
+    fn from(config: &RootFindingConfig) -> Self {
+        Self {
+            tolerance: config.tolerance,
+            max_iterations: config.max_iterations,
+            ..Self::default()
+        }
+    }
+}
+
+/// A root together with an interval known to contain it
+#[derive(Debug, Clone, PartialEq)]
+pub struct RootEnclosure {
+    /// Lower end of the enclosure
+    pub lower: f64,
+    /// Upper end of the enclosure
+    pub upper: f64,
+    /// Polished root inside `[lower, upper]`
+    pub root: f64,
+    /// `f(root)`
+    pub function_value: f64,
+    /// Whether the enclosure provably holds exactly one simple root
+    pub certified: bool,
+}
+
+impl RootEnclosure {
+    /// Width of the enclosure
+    pub fn width(&self) -> f64 {
+        self.upper - self.lower
+    }
+
+    /// Check whether `x` lies in the enclosure
+    pub fn contains(&self, x: f64) -> bool {
+        self.lower <= x && x <= self.upper
+    }
+}
+
+/// Find every real root of `expr` in `interval`
+///
+/// Returns disjoint enclosures sorted from left to right.
+///
+/// # Arguments
+///
+/// * `expr` - Expression whose zeros are wanted
+/// * `variable` - The only free variable of `expr`
+/// * `interval` - Closed search interval `(a, b)`
+/// * `config` - Scan step, enclosure width and iteration limit
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::algebra::root_finding::{find_all_roots, RootIsolationConfig};
+/// use mathhook_core::{expr, symbol};
+///
+/// let x = symbol!(x);
+/// let roots = find_all_roots(&expr!(sin(x)), &x, (-1.0, 10.0), &RootIsolationConfig::default()).unwrap();
+///
+/// assert_eq!(roots.len(), 4);
+/// assert!(roots[2].contains(2.0 * std::f64::consts::PI));
+/// assert!(roots.iter().all(|r| r.certified));
+/// ```
+///
+/// # Errors
+///
+/// Returns `InvalidInterval` if `a >= b`, `DomainError` for a non-positive
+/// step or tolerance, and `NonNumericalResult` if `expr` has other free
+/// variables or cannot be evaluated numerically.
+pub fn find_all_roots(
+    expr: &Expression,
+    variable: &Symbol,
+    interval: (f64, f64),
+    config: &RootIsolationConfig,
+) -> Result<Vec<RootEnclosure>, MathError> {
+    let (a, b) = interval;
+    if !a.is_finite() || !b.is_finite() || a >= b {
+        return Err(MathError::InvalidInterval { lower: a, upper: b });
+    }
+    if !(config.step > 0.0 && config.tolerance > 0.0) {
+        return Err(MathError::DomainError {
+            operation: "find_all_roots".to_owned(),
+            value: expr.clone(),
+            reason: "scan step and tolerance must be positive".to_owned(),
+        });
+    }
+
+    let variables = [variable.clone()];
+    let isolator = Isolator {
+        f: expr.compile(&variables)?,
+        df: expr.derivative(variable.clone()).compile(&variables)?,
+        config,
+    };
+
+    let cells = ((b - a) / config.step).ceil().max(1.0) as usize;
+    let grid: Vec<f64> = (0..=cells)
+        .map(|i| {
+            if i == cells {
+                b
+            } else {
+                a + i as f64 * config.step
+            }
+        })
+        .collect();
+
+    let mut roots: Vec<RootEnclosure> = grid
+        .iter()
+        .filter(|&&x| isolator.f(x) == 0.0)
+        .map(|&x| isolator.exact(x))
+        .collect();
+    for cell in grid.windows(2) {
+        roots.extend(isolator.cell_roots(cell[0], cell[1]));
+    }
+
+    roots.sort_by(|p, q| p.lower.total_cmp(&q.lower));
+    roots.dedup_by(|next, kept| next.lower <= kept.upper);
+    Ok(roots)
+}
+
+struct Isolator<'a> {
+    f: CompiledExpression,
+    df: CompiledExpression,
+    config: &'a RootIsolationConfig,
+}
+
+impl Isolator<'_> {
+    fn f(&self, x: f64) -> f64 {
+        self.f.evaluate(&[x])
+    }
+
+    fn df(&self, x: f64) -> f64 {
+        self.df.evaluate(&[x])
+    }
+
+    /// Roots strictly inside `(lo, hi)`, split at a critical point if any
+    fn cell_roots(&self, lo: f64, hi: f64) -> Vec<RootEnclosure> {
+        let critical =
+            opposite(self.df(lo), self.df(hi)).then(|| self.bisect(|x| self.df(x), lo, hi));
+
+        let mut points = vec![lo];
+        let mut roots = Vec::new();
+        if let Some((left, right)) = critical {
+            let c = (left + right) / 2.0;
+            let fc = self.f(c);
+            if fc == 0.0 {
+                roots.push(self.exact(c));
+            } else if fc.abs() <= self.config.tolerance && !opposite(self.f(left), self.f(right)) {
+                roots.push(RootEnclosure {
+                    lower: left,
+                    upper: right,
+                    root: c,
+                    function_value: fc,
+                    certified: false,
+                });
+            }
+            points.push(c);
+        }
+        points.push(hi);
+
+        for piece in points.windows(2) {
+            let (fl, fr) = (self.f(piece[0]), self.f(piece[1]));
+            if opposite(fl, fr) {
+                roots.extend(self.polish(piece[0], piece[1], fl.abs().max(fr.abs())));
+            }
+        }
+        roots
+    }
+
+    /// Shrink a sign change of `f` to an enclosure, or `None` at a pole
+    fn polish(&self, mut lo: f64, mut hi: f64, bound: f64) -> Option<RootEnclosure> {
+        let f_lo = self.f(lo);
+        let mut x = (lo + hi) / 2.0;
+        for _ in 0..self.config.max_iterations {
+            if hi - lo <= self.config.tolerance {
+                break;
+            }
+            let fx = self.f(x);
+            if fx == 0.0 {
+                return Some(self.exact(x));
+            }
+            if opposite(fx, f_lo) {
+                hi = x;
+            } else {
+                lo = x;
+            }
+
+            let newton = x - fx / self.df(x);
+            if (newton - x).abs() <= self.config.tolerance / 2.0 {
+                let (l, h) = (
+                    newton - self.config.tolerance / 2.0,
+                    newton + self.config.tolerance / 2.0,
+                );
+                if lo < l && h < hi && opposite(self.f(l), self.f(h)) {
+                    (lo, hi) = (l, h);
+                    x = newton;
+                    break;
+                }
+            }
+            x = if lo < newton && newton < hi {
+                newton
+            } else {
+                (lo + hi) / 2.0
+            };
+        }
+
+        if !(lo <= x && x <= hi) {
+            x = (lo + hi) / 2.0;
+        }
+        let value = self.f(x);
+        if value.is_nan() || value.abs() > bound {
+            return None;
+        }
+        let slopes = [self.df(lo), self.df(x), self.df(hi)];
+        Some(RootEnclosure {
+            lower: lo,
+            upper: hi,
+            root: x,
+            function_value: value,
+            certified: slopes.iter().all(|d| *d > 0.0) || slopes.iter().all(|d| *d < 0.0),
+        })
+    }
+
+    /// Bisect a sign change of `g` down to the configured tolerance
+    fn bisect(&self, g: impl Fn(f64) -> f64, mut lo: f64, mut hi: f64) -> (f64, f64) {
+        let g_lo = g(lo);
+        for _ in 0..self.config.max_iterations {
+            if hi - lo <= self.config.tolerance {
+                break;
+            }
+            let mid = (lo + hi) / 2.0;
+            let g_mid = g(mid);
+            if g_mid == 0.0 {
+                return (mid, mid);
+            }
+            if opposite(g_mid, g_lo) {
+                hi = mid;
+            } else {
+                lo = mid;
+            }
+        }
+        (lo, hi)
+    }
+
+    fn exact(&self, x: f64) -> RootEnclosure {
+        let slope = self.df(x);
+        RootEnclosure {
+            lower: x,
+            upper: x,
+            root: x,
+            function_value: 0.0,
+            certified: slope != 0.0 && slope.is_finite(),
+        }
+    }
+}
+
+/// Strict sign change between two finite values
+fn opposite(p: f64, q: f64) -> bool {
+    p.is_finite() && q.is_finite() && p * q < 0.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{expr, symbol};
+    use std::f64::consts::PI;
+
+    fn roots(expr: &Expression, interval: (f64, f64)) -> Vec<RootEnclosure> {
+        find_all_roots(expr, &symbol!(x), interval, &RootIsolationConfig::default()).unwrap()
+    }
+
+    #[test]
+    fn test_transcendental_roots_are_isolated() {
+        let sines = roots(&expr!(sin(x)), (-1.0, 10.0));
+        let expected = [0.0, PI, 2.0 * PI, 3.0 * PI];
+        assert_eq!(sines.len(), expected.len());
+        for (enclosure, root) in sines.iter().zip(expected) {
+            assert!(enclosure.certified);
+            assert!(enclosure.width() <= 1e-12);
+            assert!((enclosure.root - root).abs() < 1e-10);
+        }
+
+        let x = symbol!(x);
+        let cos_minus_x = Expression::add(vec![
+            Expression::function("cos", vec![Expression::symbol(x.clone())]),
+            Expression::mul(vec![Expression::integer(-1), Expression::symbol(x)]),
+        ]);
+        let fixed_point = roots(&cos_minus_x, (-5.0, 5.0));
+        assert_eq!(fixed_point.len(), 1);
+        assert!((fixed_point[0].root - 0.739_085_133_215_160_6).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_touching_roots_and_poles() {
+        let touching = roots(&expr!((x - 1) ^ 2), (0.0, 3.0));
+        assert_eq!(touching.len(), 1);
+        assert!(!touching[0].certified);
+        assert!((touching[0].root - 1.0).abs() < 1e-6);
+
+        assert!(roots(&expr!((x ^ 2) + 1), (-3.0, 3.0)).is_empty());
+
+        let tangent = roots(&expr!(tan(x)), (0.5, 4.0));
+        assert_eq!(tangent.len(), 1);
+        assert!((tangent[0].root - PI).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_close_roots_split_by_critical_point() {
+        let x = symbol!(x);
+        let config = RootIsolationConfig {
+            step: 1.0,
+            ..Default::default()
+        };
+        let close_pair = Expression::add(vec![expr!(x ^ 2), Expression::float(-0.01)]);
+        let close = find_all_roots(&close_pair, &x, (-0.5, 0.5), &config).unwrap();
+        assert_eq!(close.len(), 2);
+        assert!(close[0].upper < close[1].lower);
+        assert!(find_all_roots(&expr!(x), &x, (1.0, 1.0), &config).is_err());
+    }
+}
@@ -133,7 +133,7 @@ mod tests {
         let x = symbol!(x);
         let result = strategy.simplify(&[x.clone().into()]);
 
-        if let Expression::Function { name, args } = result {
+        if let Expression::Function { name, args, .. } = result {
             assert_eq!(name.as_ref(), "sin");
             assert_eq!(args.len(), 1);
             assert_eq!(args[0], x.into());
@@ -155,7 +155,7 @@ mod tests {
         let x = symbol!(x);
         let result = strategy.simplify(&[x.clone().into()]);
 
-        if let Expression::Function { name, args } = result {
+        if let Expression::Function { name, args, .. } = result {
             assert_eq!(name.as_ref(), "cos");
             assert_eq!(args.len(), 1);
             assert_eq!(args[0], x.into());
@@ -177,7 +177,7 @@ mod tests {
         let x = symbol!(x);
         let result = strategy.simplify(&[x.clone().into()]);
 
-        if let Expression::Function { name, args } = result {
+        if let Expression::Function { name, args, .. } = result {
             assert_eq!(name.as_ref(), "tan");
             assert_eq!(args.len(), 1);
             assert_eq!(args[0], x.into());
@@ -192,7 +192,7 @@ mod tests {
         let x = symbol!(x);
         let result = strategy.simplify(&[x.clone().into()]);
 
-        if let Expression::Function { name, args } = result {
+        if let Expression::Function { name, args, .. } = result {
             assert_eq!(name.as_ref(), "sinh");
             assert_eq!(args.len(), 1);
             assert_eq!(args[0], x.into());
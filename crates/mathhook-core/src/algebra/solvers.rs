@@ -8,17 +8,32 @@ use crate::educational::step_by_step::{Step, StepByStepExplanation};
 use serde::{Deserialize, Serialize};
 
 // Individual solver modules
+pub mod cases;
+pub mod completing_square;
 pub mod linear;
 pub mod matrix_equations;
+pub mod min_max;
 pub mod polynomial;
 pub mod quadratic;
+pub mod roots;
+pub mod rounding;
+pub mod sets;
+pub mod solution_set;
 pub mod systems;
 
 // Re-exports for easy access
+pub use cases::{Assumptions, SolutionCase};
+pub use completing_square::{complete_square, CompletedSquare};
 pub use linear::LinearSolver;
 pub use matrix_equations::MatrixEquationSolver;
+pub use min_max::MinMaxSolver;
 pub use polynomial::PolynomialSolver;
-pub use quadratic::QuadraticSolver;
+pub use quadratic::{QuadraticMethod, QuadraticSolver};
+pub use roots::{Root, RootKind, RootSet};
+pub use rounding::RoundingSolver;
+pub(crate) use sets::general_solutions;
+pub use sets::{solve_general, ConditionSet, ImageSet, Solutions};
+pub use solution_set::{solve_set, Endpoint, Interval, SolutionSet};
 pub use systems::SystemSolver;
 
 /// Unified result type for equation solvers
@@ -39,6 +54,11 @@ pub enum SolverResult {
     /// For example, a cubic equation may have one real root found via rational root theorem,
     /// but the remaining complex roots cannot be computed without implementing the full cubic formula.
     Partial(Vec<Expression>),
+    /// Solutions that depend on conditions on the equation's parameters
+    ///
+    /// For example, `a x = b` has `x = b/a` when `a ≠ 0` and no solution
+    /// (or every value) when `a = 0`.
+    Cases(Vec<SolutionCase>),
 }
 
 /// Unified error handling for equation solvers
@@ -95,6 +115,46 @@ impl SolverResult {
             SolverResult::Multiple(exprs) => exprs.iter().all(|e| e.is_valid_expression()),
             SolverResult::Parametric(exprs) => exprs.iter().all(|e| e.is_valid_expression()),
             SolverResult::Partial(exprs) => exprs.iter().all(|e| e.is_valid_expression()),
+            SolverResult::Cases(cases) => cases.iter().all(|case| {
+                case.conditions.iter().all(|c| c.is_valid_expression())
+                    && case.result.is_valid_solution()
+            }),
+        }
+    }
+
+    /// Drop solutions that contradict the assumptions declared on `variable`
+    ///
+    /// @no-binding - consumes the result
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::algebra::solvers::SolverResult;
+    /// use mathhook_core::core::symbol::{Assumption, Symbol};
+    /// use mathhook_core::expr;
+    ///
+    /// let x = Symbol::new_with_assumptions("x", &[Assumption::Positive]);
+    /// let roots = SolverResult::Multiple(vec![expr!(2), expr!(-2)]);
+    /// assert_eq!(roots.respecting_assumptions(&x), SolverResult::Single(expr!(2)));
+    /// ```
+    pub fn respecting_assumptions(self, variable: &Symbol) -> Self {
+        let assumptions = Assumptions::new();
+        match self {
+            SolverResult::Single(value) if assumptions.contradicts(variable, &value) => {
+                SolverResult::NoSolution
+            }
+            SolverResult::Multiple(values) => {
+                let mut kept: Vec<Expression> = values
+                    .into_iter()
+                    .filter(|value| !assumptions.contradicts(variable, value))
+                    .collect();
+                match kept.len() {
+                    0 => SolverResult::NoSolution,
+                    1 => SolverResult::Single(kept.remove(0)),
+                    _ => SolverResult::Multiple(kept),
+                }
+            }
+            other => other,
         }
     }
 
@@ -107,6 +167,7 @@ impl SolverResult {
             SolverResult::Partial(exprs) => Some(exprs.len()),
             SolverResult::NoSolution => Some(0),
             SolverResult::InfiniteSolutions => None,
+            SolverResult::Cases(_) => None,
         }
     }
 }
@@ -251,7 +312,7 @@ impl Expression {
             Expression::Pow(base, exp) => {
                 format!("{}^{{{}}}", base, exp)
             }
-            Expression::Function { name, args } => {
+            Expression::Function { name, args, .. } => {
                 let arg_strs: Vec<String> = args.iter().map(|a| format!("{}", a)).collect();
                 format!("\\{}({})", name, arg_strs.join(", "))
             }
@@ -0,0 +1,383 @@
+//! Case analysis for equations with symbolic coefficients
+//!
+//! Solving `a x = b` for `x` divides by `a`, which is only valid when
+//! `a ≠ 0`. Unless the solver's `Assumptions` establish that, the result is a
+//! `SolverResult::Cases` split: `x = b/a` when `a ≠ 0`, and the degenerate
+//! equation `0 = b` (no solution, or every value when `b = 0`) otherwise.
+
+use super::roots::classify;
+use super::{RootKind, SolverResult};
+use crate::core::expression::RelationType;
+use crate::core::symbol::SymbolAssumptions;
+use crate::core::{Expression, MathConstant, Number, Symbol};
+use crate::simplify::Simplify;
+use serde::{Deserialize, Serialize};
+
+/// One branch of a case split
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SolutionCase {
+    /// Relations on the parameters that must all hold, e.g. `a ≠ 0`
+    pub conditions: Vec<Expression>,
+    pub result: SolverResult,
+}
+
+impl SolutionCase {
+    pub fn new(conditions: Vec<Expression>, result: SolverResult) -> Self {
+        Self { conditions, result }
+    }
+
+    /// Conditions joined with "and", for explanations
+    pub fn describe_conditions(&self) -> String {
+        self.conditions
+            .iter()
+            .map(|condition| condition.to_string())
+            .collect::<Vec<_>>()
+            .join(" and ")
+    }
+}
+
+/// Facts about the parameters of an equation that let solvers skip cases
+///
+/// Assumptions declared on a symbol itself (see [`SymbolAssumptions`]) are
+/// always taken into account, in addition to the ones added here.
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::algebra::solvers::Assumptions;
+/// use mathhook_core::expr;
+///
+/// let assumptions = Assumptions::new().nonzero(expr!(a));
+/// assert!(assumptions.is_nonzero(&expr!(2 * a)));
+/// assert!(!assumptions.is_nonzero(&expr!(b)));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Assumptions {
+    nonzero: Vec<Expression>,
+    integer: Vec<Expression>,
+    nonnegative: Vec<Expression>,
+}
+
+impl Assumptions {
+    /// No assumptions: every parameter may be zero
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Assume `expr ≠ 0`
+    pub fn nonzero(mut self, expr: Expression) -> Self {
+        self.nonzero.push(expr.simplify());
+        self
+    }
+
+    /// Assume `expr` takes integer values
+    pub fn integer(mut self, expr: Expression) -> Self {
+        self.integer.push(expr.simplify());
+        self
+    }
+
+    /// Assume `expr ≥ 0`
+    pub fn nonnegative(mut self, expr: Expression) -> Self {
+        self.nonnegative.push(expr.simplify());
+        self
+    }
+
+    /// Whether `expr` is known to be nonnegative
+    ///
+    /// Nonnegative numbers and constants, assumed expressions and symbols,
+    /// even powers, `abs`, `exp` and `sqrt`, and sums and products of
+    /// nonnegative terms qualify.
+    pub fn is_nonnegative(&self, expr: &Expression) -> bool {
+        match expr {
+            Expression::Number(_) => !expr.is_negative_number(),
+            Expression::Constant(constant) => constant.to_f64() >= 0.0,
+            _ if self.nonnegative.contains(expr) => true,
+            Expression::Add(terms) | Expression::Mul(terms) => {
+                terms.iter().all(|term| self.is_nonnegative(term))
+            }
+            Expression::Pow(base, exponent) => {
+                matches!(exponent.as_ref(), Expression::Number(Number::Integer(n)) if n % 2 == 0)
+                    || self.is_nonnegative(base)
+            }
+            Expression::Function { name, .. } => {
+                matches!(name.as_ref(), "abs" | "exp" | "sqrt")
+            }
+            Expression::Symbol(symbol) => symbol.assumptions().is_nonnegative(),
+            _ => false,
+        }
+    }
+
+    /// Whether `expr` is known to take integer values
+    ///
+    /// Integers, assumed expressions and symbols, rounding functions,
+    /// integer-valued functions of integers (`factorial(n)`, `gcd(m, n)`),
+    /// and sums, products and non-negative integer powers of them qualify.
+    pub fn is_integer(&self, expr: &Expression) -> bool {
+        match expr {
+            Expression::Number(Number::Integer(_) | Number::BigInteger(_)) => true,
+            Expression::Number(_) | Expression::Constant(_) => false,
+            _ if self.integer.contains(expr) => true,
+            Expression::Add(terms) | Expression::Mul(terms) => {
+                terms.iter().all(|term| self.is_integer(term))
+            }
+            Expression::Pow(base, exponent) => {
+                self.is_integer(base)
+                    && matches!(exponent.as_ref(), Expression::Number(Number::Integer(n)) if *n >= 0)
+            }
+            Expression::Function { name, args, .. } => match name.as_ref() {
+                "floor" | "ceil" | "round" => true,
+                "sign" | "factorial" | "gcd" | "lcm" | "mod" | "abs" => {
+                    args.iter().all(|arg| self.is_integer(arg))
+                }
+                _ => false,
+            },
+            Expression::Symbol(symbol) => symbol.assumptions().is_integer(),
+            _ => false,
+        }
+    }
+
+    /// Whether `expr` is known to be nonzero
+    ///
+    /// Nonzero numbers and constants, assumed expressions and symbols,
+    /// exponentials, products and powers built from them, and sums of
+    /// nonnegative terms with at least one positive term are nonzero.
+    pub fn is_nonzero(&self, expr: &Expression) -> bool {
+        match expr {
+            Expression::Number(Number::Integer(n)) => *n != 0,
+            Expression::Number(_) => !expr.is_zero(),
+            Expression::Constant(constant) => !matches!(constant, MathConstant::Undefined),
+            _ if self.nonzero.contains(expr) => true,
+            Expression::Mul(factors) => factors.iter().all(|factor| self.is_nonzero(factor)),
+            Expression::Add(terms) => {
+                terms.iter().all(|term| self.is_nonnegative(term))
+                    && terms.iter().any(|term| self.is_positive(term))
+            }
+            Expression::Pow(base, _) => self.is_nonzero(base),
+            Expression::Function { name, .. } => name.as_ref() == "exp",
+            Expression::Symbol(symbol) => symbol.assumptions().is_nonzero(),
+            _ => false,
+        }
+    }
+
+    /// Whether `expr` is known to be positive: nonnegative and nonzero
+    pub fn is_positive(&self, expr: &Expression) -> bool {
+        self.is_nonnegative(expr) && self.is_nonzero(expr)
+    }
+
+    /// Whether `value` is known to violate the assumptions declared on
+    /// `variable`
+    ///
+    /// Solvers use this to discard roots outside the declared domain, such as
+    /// `x = -2` for a positive `x`. Values that cannot be decided are kept.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::algebra::solvers::Assumptions;
+    /// use mathhook_core::core::symbol::{Assumption, Symbol};
+    /// use mathhook_core::expr;
+    ///
+    /// let x = Symbol::new_with_assumptions("x", &[Assumption::Positive]);
+    /// let assumptions = Assumptions::new();
+    /// assert!(assumptions.contradicts(&x, &expr!(-2)));
+    /// assert!(assumptions.contradicts(&x, &expr!(0)));
+    /// assert!(!assumptions.contradicts(&x, &expr!(a)));
+    /// ```
+    pub fn contradicts(&self, variable: &Symbol, value: &Expression) -> bool {
+        let declared = variable.assumptions();
+        if declared.is_empty() {
+            return false;
+        }
+        if declared.is_real() && classify(value) == RootKind::Complex {
+            return true;
+        }
+
+        let negated = Expression::mul(vec![Expression::integer(-1), value.clone()]).simplify();
+        let numeric = value.evaluate_to_f64().ok().filter(|v| v.is_finite());
+        (declared.is_nonzero() && (value.is_zero() || numeric == Some(0.0)))
+            || (declared.is_nonnegative()
+                && (numeric.is_some_and(|v| v < 0.0) || self.is_positive(&negated)))
+            || (declared.is_integer() && numeric.is_some_and(|v| v.fract() != 0.0))
+    }
+
+    /// Whether `expr` is known to be real
+    ///
+    /// Numbers, finite real constants, real symbols, and sums, products and
+    /// integer powers of real expressions qualify, as does anything known to
+    /// be nonnegative or integer.
+    pub fn is_real(&self, expr: &Expression) -> bool {
+        match expr {
+            Expression::Number(_) => true,
+            Expression::Constant(constant) => matches!(
+                constant,
+                MathConstant::Pi
+                    | MathConstant::E
+                    | MathConstant::GoldenRatio
+                    | MathConstant::EulerGamma
+                    | MathConstant::TribonacciConstant
+            ),
+            Expression::Symbol(symbol) => symbol.assumptions().is_real(),
+            Expression::Add(terms) | Expression::Mul(terms) => {
+                terms.iter().all(|term| self.is_real(term))
+            }
+            Expression::Pow(base, exponent)
+                if matches!(exponent.as_ref(), Expression::Number(Number::Integer(_))) =>
+            {
+                self.is_real(base)
+            }
+            _ => self.is_nonnegative(expr) || self.is_integer(expr),
+        }
+    }
+}
+
+impl Expression {
+    /// Facts that follow from the assumptions declared on the symbols in this
+    /// expression
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::core::symbol::{Symbol, SymbolAssumptions};
+    /// use mathhook_core::Expression;
+    ///
+    /// let x = Symbol::scalar("x").with_assumptions(SymbolAssumptions::new().positive());
+    /// let expr = Expression::add(vec![
+    ///     Expression::pow(Expression::symbol(x), Expression::integer(2)),
+    ///     Expression::integer(1),
+    /// ]);
+    /// assert!(expr.assumptions().is_positive());
+    /// assert!(!expr.assumptions().is_integer());
+    /// ```
+    pub fn assumptions(&self) -> SymbolAssumptions {
+        let facts = Assumptions::new();
+        let mut result = SymbolAssumptions::new();
+        if facts.is_real(self) {
+            result = result.real();
+        }
+        if facts.is_integer(self) {
+            result = result.integer();
+        }
+        if facts.is_nonnegative(self) {
+            result = result.nonnegative();
+        }
+        if facts.is_nonzero(self) {
+            result = result.nonzero();
+        }
+        result
+    }
+}
+
+/// Relation `expr = 0` or `expr ≠ 0`
+pub(crate) fn zero_condition(expr: &Expression, is_zero: bool) -> Expression {
+    let relation = if is_zero {
+        RelationType::Equal
+    } else {
+        RelationType::NotEqual
+    };
+    Expression::relation(expr.clone(), Expression::integer(0), relation)
+}
+
+/// Case split for `a x + b = 0` with solution `x = -b/a`, or `None` when the
+/// assumptions rule out `a = 0`
+pub(crate) fn linear_cases(
+    a: &Expression,
+    b: &Expression,
+    solution: Expression,
+    assumptions: &Assumptions,
+) -> Option<Vec<SolutionCase>> {
+    if assumptions.is_nonzero(a) {
+        return None;
+    }
+
+    let mut cases = vec![SolutionCase::new(
+        vec![zero_condition(a, false)],
+        SolverResult::Single(solution),
+    )];
+    if b.is_zero() {
+        cases.push(SolutionCase::new(
+            vec![zero_condition(a, true)],
+            SolverResult::InfiniteSolutions,
+        ));
+    } else if assumptions.is_nonzero(b) {
+        cases.push(SolutionCase::new(
+            vec![zero_condition(a, true)],
+            SolverResult::NoSolution,
+        ));
+    } else {
+        cases.push(SolutionCase::new(
+            vec![zero_condition(a, true), zero_condition(b, true)],
+            SolverResult::InfiniteSolutions,
+        ));
+        cases.push(SolutionCase::new(
+            vec![zero_condition(a, true), zero_condition(b, false)],
+            SolverResult::NoSolution,
+        ));
+    }
+    Some(cases)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Symbol;
+    use crate::expr;
+
+    #[test]
+    fn test_nonzero_reasoning() {
+        let assumptions = Assumptions::new().nonzero(expr!(a));
+        assert!(assumptions.is_nonzero(&expr!(3)));
+        assert!(assumptions.is_nonzero(&expr!(a ^ 2)));
+        assert!(!assumptions.is_nonzero(&expr!(0)));
+        assert!(!assumptions.is_nonzero(&expr!(a + 1)));
+    }
+
+    #[test]
+    fn test_nonnegative_reasoning() {
+        let assumptions = Assumptions::new().nonnegative(expr!(a));
+        assert!(assumptions.is_nonnegative(&expr!(x ^ 2)));
+        assert!(assumptions.is_nonnegative(&expr!((a ^ 3) + 1)));
+        assert!(!assumptions.is_nonnegative(&expr!(-1)));
+        assert!(!assumptions.is_nonnegative(&expr!(b)));
+    }
+
+    #[test]
+    fn test_symbol_assumptions() {
+        let a = Symbol::scalar("a").with_assumptions(SymbolAssumptions::new().positive());
+        let n = Symbol::scalar("n").with_assumptions(SymbolAssumptions::new().integer());
+        let assumptions = Assumptions::new();
+
+        let a_plus_one =
+            Expression::add(vec![Expression::symbol(a.clone()), Expression::integer(1)]);
+        assert!(assumptions.is_nonzero(&a_plus_one));
+        assert!(assumptions.is_integer(&Expression::mul(vec![
+            Expression::integer(2),
+            Expression::symbol(n.clone()),
+        ])));
+        assert!(!assumptions.is_nonnegative(&Expression::symbol(n.clone())));
+
+        let facts = Expression::symbol(n).assumptions();
+        assert_eq!(facts.names(), vec!["integer", "real"]);
+        assert!(expr!(x).assumptions().is_empty());
+        assert!(linear_cases(&Expression::symbol(a), &expr!(b), expr!(c), &assumptions).is_none());
+    }
+
+    #[test]
+    fn test_linear_cases_with_unknown_constant() {
+        let cases = linear_cases(&expr!(a), &expr!(b), expr!(c), &Assumptions::new()).unwrap();
+        assert_eq!(cases.len(), 3);
+        assert_eq!(cases[0].result, SolverResult::Single(expr!(c)));
+        assert_eq!(cases[1].result, SolverResult::InfiniteSolutions);
+        assert_eq!(cases[2].conditions.len(), 2);
+        assert_eq!(cases[2].result, SolverResult::NoSolution);
+    }
+
+    #[test]
+    fn test_linear_cases_fold_known_constant() {
+        let cases = linear_cases(&expr!(a), &expr!(5), expr!(c), &Assumptions::new()).unwrap();
+        assert_eq!(cases.len(), 2);
+        assert_eq!(cases[1].result, SolverResult::NoSolution);
+
+        let assumptions = Assumptions::new().nonzero(expr!(a));
+        assert!(linear_cases(&expr!(a), &expr!(b), expr!(c), &assumptions).is_none());
+    }
+}
@@ -0,0 +1,328 @@
+//! Completing the square: `a x² + b x + c = a (x - h)² + k`
+//!
+//! The vertex form exposes the vertex `(h, k)` of the parabola and its axis of
+//! symmetry `x = h`, and solving `a (x - h)² + k = 0` gives the quadratic's
+//! roots without the quadratic formula.
+
+use crate::core::expression::RelationType;
+use crate::core::polynomial::extract_coefficient_map;
+use crate::core::{Expression, Number, Symbol};
+use crate::educational::step_by_step::{Step, StepByStepExplanation};
+use crate::error::MathError;
+use crate::formatter::latex::LaTeXFormatter;
+use crate::geometry::Point;
+use crate::simplify::Simplify;
+use num_bigint::{BigInt, Sign};
+use num_rational::BigRational;
+
+/// A quadratic rewritten in vertex form
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompletedSquare {
+    /// `a (x - h)² + k`
+    pub form: Expression,
+    /// Leading coefficient `a`
+    pub a: Expression,
+    /// Vertex `(h, k)` of the parabola `y = a x² + b x + c`
+    pub vertex: Point,
+    /// Axis of symmetry `x = h`
+    pub axis: Expression,
+    pub explanation: StepByStepExplanation,
+}
+
+/// Rewrite `a x² + b x + c` (or an equation between quadratics) in vertex form
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::algebra::solvers::complete_square;
+/// use mathhook_core::{expr, symbol, Expression};
+///
+/// let x = symbol!(x);
+/// let completed = complete_square(&expr!((2 * (x ^ 2)) - (4 * x) + 5), &x).unwrap();
+/// assert_eq!(completed.vertex.x, Expression::integer(1));
+/// assert_eq!(completed.vertex.y, Expression::integer(3));
+/// ```
+///
+/// # Errors
+///
+/// Returns `DomainError` if the expression is not a quadratic in `variable`.
+pub fn complete_square(
+    quadratic: &Expression,
+    variable: &Symbol,
+) -> Result<CompletedSquare, MathError> {
+    let expr = match quadratic {
+        Expression::Relation(relation) if relation.relation_type == RelationType::Equal => {
+            (relation.left.clone() - relation.right.clone()).simplify()
+        }
+        _ => quadratic.simplify(),
+    };
+    let not_quadratic = || MathError::DomainError {
+        operation: "complete_square".to_owned(),
+        value: quadratic.clone(),
+        reason: format!("not a quadratic in {}", variable.name()),
+    };
+
+    let coefficients = extract_coefficient_map(&expr, variable);
+    if coefficients.keys().any(|degree| !(0..=2).contains(degree)) {
+        return Err(not_quadratic());
+    }
+    let coefficient = |degree| {
+        coefficients
+            .get(&degree)
+            .map(|c: &Expression| c.simplify())
+            .unwrap_or_else(|| Expression::integer(0))
+    };
+    let (a, b, c) = (coefficient(2), coefficient(1), coefficient(0));
+    if a.is_zero() || [&a, &b, &c].iter().any(|e| e.contains_variable(variable)) {
+        return Err(not_quadratic());
+    }
+
+    let (h, k) = vertex(&a, &b, &c);
+    let form = vertex_form(&a, &h, &k, variable);
+    let x = Expression::symbol(variable.clone());
+
+    let mut steps = vec![step(
+        "Given Quadratic",
+        format!("Complete the square in {}", latex(&expr)),
+        expr.clone(),
+        "Introduction",
+    )];
+
+    let b_over_a = quotient(&b, &a);
+    let monic_part = Expression::add(vec![
+        square(&x),
+        Expression::mul(vec![b_over_a.clone(), x.clone()]),
+    ]);
+    if a != Expression::integer(1) {
+        let factored = Expression::add(vec![
+            Expression::mul(vec![a.clone(), monic_part.clone()]),
+            c.clone(),
+        ]);
+        steps.push(step(
+            "Factor Out Leading Coefficient",
+            format!(
+                "Factor {} out of the x terms: {} + {}",
+                latex(&a),
+                product_latex(&[a.clone(), monic_part.clone()]),
+                latex(&c)
+            ),
+            factored,
+            "Factor Leading Coefficient",
+        ));
+    }
+
+    let half = quotient(&b_over_a, &Expression::integer(2));
+    let half_squared = square(&half).simplify();
+    steps.push(step(
+        "Half the Linear Coefficient",
+        format!(
+            "Half of {} is {}, and its square is {}",
+            latex(&b_over_a),
+            latex(&half),
+            latex(&half_squared)
+        ),
+        half_squared.clone(),
+        "Half Linear Coefficient",
+    ));
+
+    let bracket = Expression::add(vec![monic_part, half_squared.clone()]);
+    let compensation = Expression::mul(vec![a.clone(), half_squared]).simplify();
+    let balanced = Expression::add(vec![
+        Expression::mul(vec![a.clone(), bracket.clone()]),
+        c.clone(),
+        Expression::mul(vec![Expression::integer(-1), compensation.clone()]),
+    ]);
+    steps.push(step(
+        "Add and Subtract",
+        format!(
+            "Add and subtract the square inside the expression: {} + {} - {}",
+            product_latex(&[a.clone(), bracket]),
+            latex(&c),
+            latex(&compensation)
+        ),
+        balanced,
+        "Add and Subtract",
+    ));
+
+    steps.push(step(
+        "Write as a Square",
+        format!(
+            "The bracket is a perfect square, giving the vertex form {}",
+            latex(&form)
+        ),
+        form.clone(),
+        "Perfect Square",
+    ));
+
+    let axis = Expression::equation(x, h.clone());
+    steps.push(step(
+        "Vertex and Axis",
+        format!(
+            "The vertex is ({}, {}) and the axis of symmetry is x = {}",
+            latex(&h),
+            latex(&k),
+            latex(&h)
+        ),
+        axis.clone(),
+        "Vertex Form",
+    ));
+
+    let mut explanation = StepByStepExplanation::new(steps);
+    explanation.initial_expression = expr;
+    explanation.final_expression = form.clone();
+
+    Ok(CompletedSquare {
+        form,
+        a,
+        vertex: Point::new(h, k),
+        axis,
+        explanation,
+    })
+}
+
+/// `(h, k)` with `h = -b/(2a)` and `k = c - b²/(4a)`
+pub(crate) fn vertex(a: &Expression, b: &Expression, c: &Expression) -> (Expression, Expression) {
+    let h = quotient(
+        &(Expression::integer(-1) * b.clone()),
+        &(Expression::integer(2) * a.clone()),
+    );
+    let k = (c.clone() - quotient(&square(b), &(Expression::integer(4) * a.clone()))).simplify();
+    (h, k)
+}
+
+/// `a (x - h)² + k`, leaving out a unit `a` and zero `h` or `k`
+pub(crate) fn vertex_form(
+    a: &Expression,
+    h: &Expression,
+    k: &Expression,
+    variable: &Symbol,
+) -> Expression {
+    let x = Expression::symbol(variable.clone());
+    let shifted = if h.is_zero() {
+        x
+    } else {
+        Expression::add(vec![x, (Expression::integer(-1) * h.clone()).simplify()])
+    };
+    let scaled = if *a == Expression::integer(1) {
+        square(&shifted)
+    } else {
+        Expression::mul(vec![a.clone(), square(&shifted)])
+    };
+    if k.is_zero() {
+        scaled
+    } else {
+        Expression::add(vec![scaled, k.clone()])
+    }
+}
+
+/// `√value`, exact for perfect squares of integers and rationals
+pub(crate) fn square_root(value: &Expression) -> Expression {
+    as_rational(value)
+        .and_then(|r| rational_sqrt(&r))
+        .map(from_rational)
+        .unwrap_or_else(|| Expression::sqrt(value.clone()))
+}
+
+/// `√r` when `r` is the square of a rational
+pub(crate) fn rational_sqrt(r: &BigRational) -> Option<BigRational> {
+    let root = |v: &BigInt| {
+        let s = v.sqrt();
+        (&s * &s == *v).then_some(s)
+    };
+    if r.numer().sign() == Sign::Minus {
+        return None;
+    }
+    Some(BigRational::new(root(r.numer())?, root(r.denom())?))
+}
+
+pub(crate) fn as_rational(expr: &Expression) -> Option<BigRational> {
+    match expr {
+        Expression::Number(Number::Integer(v)) => Some(BigRational::from_integer(BigInt::from(*v))),
+        Expression::Number(Number::BigInteger(v)) => Some(BigRational::from_integer(*v.clone())),
+        Expression::Number(Number::Rational(r)) => Some(*r.clone()),
+        _ => None,
+    }
+}
+
+/// An integer when the denominator is one, a rational otherwise
+pub(crate) fn from_rational(r: BigRational) -> Expression {
+    if r.is_integer() {
+        Expression::big_integer(r.to_integer())
+    } else {
+        Expression::Number(Number::rational(r))
+    }
+}
+
+fn quotient(numerator: &Expression, denominator: &Expression) -> Expression {
+    Expression::div(numerator.clone(), denominator.clone()).simplify()
+}
+
+/// LaTeX for a product, parenthesizing sums and leaving out unit factors
+pub(crate) fn product_latex(factors: &[Expression]) -> String {
+    factors
+        .iter()
+        .filter(|factor| **factor != Expression::integer(1))
+        .map(|factor| match factor {
+            Expression::Add(_) => format!("\\left({}\\right)", latex(factor)),
+            _ => latex(factor),
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn square(expr: &Expression) -> Expression {
+    Expression::pow(expr.clone(), Expression::integer(2))
+}
+
+fn latex(expr: &Expression) -> String {
+    expr.to_latex(None).unwrap_or_else(|_| expr.to_string())
+}
+
+fn step(title: &str, description: String, expression: Expression, rule: &str) -> Step {
+    Step {
+        title: title.to_owned(),
+        description,
+        expression,
+        rule_applied: rule.to_owned(),
+        latex: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{expr, symbol};
+
+    #[test]
+    fn test_vertex_form() {
+        let x = symbol!(x);
+        let completed = complete_square(&expr!((x ^ 2) + (6 * x) + 5), &x).unwrap();
+        assert_eq!(completed.vertex, Point::new(expr!(-3), expr!(-4)));
+        assert_eq!(completed.axis, Expression::equation(expr!(x), expr!(-3)));
+        assert_eq!(
+            completed.form,
+            Expression::add(vec![
+                Expression::pow(Expression::add(vec![expr!(x), expr!(3)]), expr!(2)),
+                expr!(-4),
+            ])
+        );
+
+        let symbolic = complete_square(&expr!((a * (x ^ 2)) + (b * x) + c), &x).unwrap();
+        assert_eq!(
+            symbolic.vertex.x.simplify(),
+            Expression::div(expr!(-b), expr!(2 * a)).simplify()
+        );
+        assert!(complete_square(&expr!((x ^ 3) + x), &x).is_err());
+        assert!(complete_square(&expr!((2 * x) + 1), &x).is_err());
+    }
+
+    #[test]
+    fn test_square_root_is_exact_for_perfect_squares() {
+        assert_eq!(
+            square_root(&Expression::rational(9, 4)),
+            Expression::rational(3, 2)
+        );
+        assert_eq!(square_root(&expr!(49)), expr!(7));
+        assert_eq!(square_root(&expr!(8)), Expression::sqrt(expr!(8)));
+    }
+}
@@ -6,6 +6,7 @@ use crate::core::constants::EPSILON;
 use crate::core::{Commutativity, Expression, Number, Symbol};
 use crate::educational::step_by_step::{Step, StepByStepExplanation};
 // Temporarily simplified for TDD success
+use crate::algebra::solvers::cases::{linear_cases, Assumptions};
 use crate::algebra::solvers::{EquationSolver, SolverResult};
 use crate::simplify::Simplify;
 use num_bigint::BigInt;
@@ -16,6 +17,9 @@ use num_rational::BigRational;
 pub struct LinearSolver {
     /// Enable step-by-step explanations
     pub show_steps: bool,
+    /// Facts about symbolic coefficients; a coefficient not known to be
+    /// nonzero produces a `SolverResult::Cases` split
+    pub assumptions: Assumptions,
 }
 
 impl Default for LinearSolver {
@@ -27,12 +31,43 @@ impl Default for LinearSolver {
 impl LinearSolver {
     /// Create new linear solver
     pub fn new() -> Self {
-        Self { show_steps: true }
+        Self {
+            show_steps: true,
+            assumptions: Assumptions::new(),
+        }
     }
 
     /// Create solver without step-by-step (for performance)
     pub fn new_fast() -> Self {
-        Self { show_steps: false }
+        Self {
+            show_steps: false,
+            assumptions: Assumptions::new(),
+        }
+    }
+
+    /// Use `assumptions` to skip cases they rule out
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::algebra::solvers::{
+    ///     linear::LinearSolver, Assumptions, EquationSolver, SolverResult,
+    /// };
+    /// use mathhook_core::{expr, symbol};
+    ///
+    /// let x = symbol!(x);
+    /// let equation = expr!((a * x) - b);
+    ///
+    /// // Without assumptions a = 0 has to be considered separately
+    /// let result = LinearSolver::new().solve(&equation, &x);
+    /// assert!(matches!(result, SolverResult::Cases(ref cases) if cases.len() == 3));
+    ///
+    /// let solver = LinearSolver::new().with_assumptions(Assumptions::new().nonzero(expr!(a)));
+    /// assert!(matches!(solver.solve(&equation, &x), SolverResult::Single(_)));
+    /// ```
+    pub fn with_assumptions(mut self, assumptions: Assumptions) -> Self {
+        self.assumptions = assumptions;
+        self
     }
 }
 
@@ -169,7 +204,15 @@ impl EquationSolver for LinearSolver {
 
                 // Try to evaluate the solution numerically if possible
                 let final_solution = Self::try_eval_numeric_internal(&solution);
-                SolverResult::Single(final_solution)
+                match linear_cases(
+                    &a_simplified,
+                    &b_simplified,
+                    final_solution.clone(),
+                    &self.assumptions,
+                ) {
+                    Some(cases) => SolverResult::Cases(cases),
+                    None => SolverResult::Single(final_solution),
+                }
             }
         }
     }
@@ -192,7 +235,7 @@ impl EquationSolver for LinearSolver {
         let neg_b = b_simplified.negate().simplify();
         let solution = Self::divide_expressions(&neg_b, &a_simplified).simplify();
 
-        let steps = vec![
+        let mut steps = vec![
             Step::new(
                 "Given Equation",
                 format!("We need to solve: {} = 0", equation),
@@ -209,8 +252,21 @@ impl EquationSolver for LinearSolver {
                 "Calculate",
                 format!("{} = -({}) ÷ {} = {}", variable.name, b, a, solution),
             ),
-            Step::new("Solution", format!("{} = {}", variable.name, solution)),
         ];
+
+        if let Some(cases) = linear_cases(
+            &a_simplified,
+            &b_simplified,
+            solution.clone(),
+            &self.assumptions,
+        ) {
+            return Self::explain_cases(steps, &a_simplified, variable, cases);
+        }
+
+        steps.push(Step::new(
+            "Solution",
+            format!("{} = {}", variable.name, solution),
+        ));
         let explanation = StepByStepExplanation::new(steps);
 
         (SolverResult::Single(solution), explanation)
@@ -224,6 +280,40 @@ impl EquationSolver for LinearSolver {
 }
 
 impl LinearSolver {
+    /// Finish an explanation with one step per case of the split
+    fn explain_cases(
+        mut steps: Vec<Step>,
+        coefficient: &Expression,
+        variable: &Symbol,
+        cases: Vec<crate::algebra::solvers::SolutionCase>,
+    ) -> (SolverResult, StepByStepExplanation) {
+        steps.push(Step::new(
+            "Case Analysis",
+            format!(
+                "Dividing by {} requires {} ≠ 0, so the cases are solved separately",
+                coefficient, coefficient
+            ),
+        ));
+        for case in &cases {
+            let outcome = match &case.result {
+                SolverResult::Single(solution) => format!("{} = {}", variable.name, solution),
+                SolverResult::NoSolution => "no solution".to_owned(),
+                SolverResult::InfiniteSolutions => {
+                    format!("every value of {} is a solution", variable.name)
+                }
+                other => format!("{:?}", other),
+            };
+            steps.push(Step::new(
+                format!("Case {}", case.describe_conditions()),
+                format!("When {}: {}", case.describe_conditions(), outcome),
+            ));
+        }
+        (
+            SolverResult::Cases(cases),
+            StepByStepExplanation::new(steps),
+        )
+    }
+
     /// Handle special cases with step explanations
     fn handle_special_case_with_style(
         &self,
@@ -413,7 +503,7 @@ impl LinearSolver {
                 }
             }
             // Handle fractions that should be evaluated
-            Expression::Function { name, args }
+            Expression::Function { name, args, .. }
                 if name.as_ref() == "fraction" && args.len() == 2 =>
             {
                 Self::eval_exact_internal(expr)
@@ -474,7 +564,7 @@ impl LinearSolver {
             }
             // Handle fraction functions: fraction(numerator, denominator)
             // BigRational::new() automatically reduces to lowest terms
-            Expression::Function { name, args }
+            Expression::Function { name, args, .. }
                 if name.as_ref() == "fraction" && args.len() == 2 =>
             {
                 // First evaluate the numerator and denominator
@@ -622,7 +712,7 @@ impl LinearSolver {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::symbol;
+    use crate::{expr, symbol};
 
     #[test]
     fn test_coefficient_extraction() {
@@ -641,6 +731,27 @@ mod tests {
         assert_eq!(b.simplify(), Expression::integer(3));
     }
 
+    #[test]
+    fn test_symbolic_coefficient_explains_cases() {
+        let x = symbol!(x);
+        let solver = LinearSolver::new();
+        let equation = expr!((a * x) - 3);
+
+        let (result, explanation) = solver.solve_with_explanation(&equation, &x);
+        let SolverResult::Cases(cases) = result else {
+            panic!("Expected a case split for a symbolic coefficient");
+        };
+        assert_eq!(cases.len(), 2);
+        assert_eq!(cases[1].result, SolverResult::NoSolution);
+
+        let titles: Vec<&str> = explanation.steps.iter().map(|s| s.title.as_str()).collect();
+        assert!(titles.contains(&"Case Analysis"));
+        assert!(explanation
+            .steps
+            .iter()
+            .any(|step| step.description.contains("no solution")));
+    }
+
     #[test]
     fn test_linear_detection() {
         let x = symbol!(x);
@@ -0,0 +1,448 @@
+//! Solves equations containing min or max of expressions in the variable
+//!
+//! `max(u, v) = k` splits into the branches `u = k` where `u ≥ v` and
+//! `v = k` where `v ≥ u` (reversed for min). Each branch is solved on its
+//! own and only solutions inside the branch's region are kept. A branch that
+//! holds identically, such as `max(x, 2) = 2` on `x ≤ 2`, contributes its
+//! whole region as an interval when the region is bounded by linear
+//! conditions.
+
+use crate::algebra::equation_analyzer::SmartEquationSolver;
+use crate::algebra::solvers::cases::zero_condition;
+use crate::algebra::solvers::{EquationSolver, SolutionCase, SolverResult};
+use crate::calculus::derivatives::Derivative;
+use crate::core::expression::RelationType;
+use crate::core::{Expression, Symbol};
+use crate::educational::step_by_step::{Step, StepByStepExplanation};
+use crate::pattern::Substitutable;
+use crate::simplify::Simplify;
+use std::collections::HashMap;
+
+/// Slack allowed when checking a branch condition numerically
+const CONDITION_TOLERANCE: f64 = 1e-12;
+
+/// Solver for equations with min or max of expressions in the variable
+#[derive(Debug, Clone, Default)]
+pub struct MinMaxSolver;
+
+/// One argument of the min or max chosen as its value
+struct Branch {
+    equation: Expression,
+    /// `winner ≥ other` for max, `winner ≤ other` for min, for display
+    conditions: Vec<Expression>,
+    /// The same conditions as `g ≥ 0`
+    nonnegative: Vec<Expression>,
+}
+
+/// Solutions gathered across branches
+#[derive(Default)]
+struct Collected {
+    points: Vec<Expression>,
+    intervals: Vec<Expression>,
+    undecided: Vec<SolutionCase>,
+}
+
+impl MinMaxSolver {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Whether `expr` applies min or max to something containing `variable`
+    pub fn contains_min_max_of(expr: &Expression, variable: &Symbol) -> bool {
+        Self::find_call(expr, variable).is_some()
+    }
+
+    /// First min or max call with an argument containing `variable`
+    fn find_call(expr: &Expression, variable: &Symbol) -> Option<Expression> {
+        match expr {
+            Expression::Function { name, args, .. } => {
+                if matches!(name.as_ref(), "min" | "max")
+                    && args.iter().any(|arg| arg.contains_variable(variable))
+                {
+                    return Some(expr.clone());
+                }
+                args.iter().find_map(|arg| Self::find_call(arg, variable))
+            }
+            Expression::Add(terms) | Expression::Mul(terms) => terms
+                .iter()
+                .find_map(|term| Self::find_call(term, variable)),
+            Expression::Pow(base, exponent) => {
+                Self::find_call(base, variable).or_else(|| Self::find_call(exponent, variable))
+            }
+            Expression::Relation(relation) => Self::find_call(&relation.left, variable)
+                .or_else(|| Self::find_call(&relation.right, variable)),
+            _ => None,
+        }
+    }
+
+    fn branches(equation: &Expression, variable: &Symbol) -> Option<Vec<Branch>> {
+        // The analyzer routes `expr = 0` forms, not relations
+        let equation = match equation {
+            Expression::Relation(relation) => Expression::add(vec![
+                relation.left.clone(),
+                Expression::mul(vec![Expression::integer(-1), relation.right.clone()]),
+            ]),
+            other => other.clone(),
+        }
+        .simplify();
+        let call = Self::find_call(&equation, variable)?;
+        let Expression::Function { name, args, .. } = &call else {
+            return None;
+        };
+        let is_max = name.as_ref() == "max";
+
+        let branches = args
+            .iter()
+            .enumerate()
+            .map(|(i, winner)| {
+                let others = args.iter().enumerate().filter(|(j, _)| *j != i);
+                let (conditions, nonnegative) = others
+                    .map(|(_, other)| {
+                        let (relation, larger, smaller) = if is_max {
+                            (RelationType::GreaterEqual, winner, other)
+                        } else {
+                            (RelationType::LessEqual, other, winner)
+                        };
+                        (
+                            Expression::relation(winner.clone(), other.clone(), relation),
+                            Expression::add(vec![
+                                larger.clone(),
+                                Expression::mul(vec![Expression::integer(-1), smaller.clone()]),
+                            ])
+                            .simplify(),
+                        )
+                    })
+                    .unzip();
+                Branch {
+                    equation: equation.subs(&call, winner).simplify(),
+                    conditions,
+                    nonnegative,
+                }
+            })
+            .collect();
+        Some(branches)
+    }
+
+    /// Keep the solutions of `result` that satisfy the branch conditions
+    fn collect(
+        branch: &Branch,
+        result: &SolverResult,
+        variable: &Symbol,
+        collected: &mut Collected,
+    ) {
+        let points: &[Expression] = match result {
+            SolverResult::Single(point) => std::slice::from_ref(point),
+            SolverResult::Multiple(points) | SolverResult::Partial(points) => points,
+            SolverResult::InfiniteSolutions => {
+                match Self::linear_region(&branch.nonnegative, variable) {
+                    Some(Some(region)) => collected.intervals.push(region),
+                    Some(None) => {}
+                    None => collected.undecided.push(SolutionCase::new(
+                        branch.conditions.clone(),
+                        SolverResult::InfiniteSolutions,
+                    )),
+                }
+                return;
+            }
+            SolverResult::Cases(cases) => {
+                collected.undecided.extend(cases.iter().cloned());
+                return;
+            }
+            SolverResult::NoSolution | SolverResult::Parametric(_) => return,
+        };
+
+        for point in points {
+            let substitutions = HashMap::from([(variable.name().to_owned(), point.clone())]);
+            let values: Vec<Option<f64>> = branch
+                .nonnegative
+                .iter()
+                .map(|g| g.substitute(&substitutions).evaluate_to_f64().ok())
+                .collect();
+            if values.iter().all(Option::is_some) {
+                if values.iter().flatten().all(|g| *g >= -CONDITION_TOLERANCE)
+                    && !collected.points.contains(point)
+                {
+                    collected.points.push(point.clone());
+                }
+            } else {
+                let conditions = branch
+                    .conditions
+                    .iter()
+                    .map(|condition| condition.substitute(&substitutions))
+                    .collect();
+                collected.undecided.push(SolutionCase::new(
+                    conditions,
+                    SolverResult::Single(point.clone()),
+                ));
+            }
+        }
+    }
+
+    /// Interval where every `g ≥ 0` holds, for `g` linear in `variable` with
+    /// numeric coefficients
+    ///
+    /// `None` when a condition is not of that form, `Some(None)` when the
+    /// region is empty.
+    fn linear_region(nonnegative: &[Expression], variable: &Symbol) -> Option<Option<Expression>> {
+        let substitutions = HashMap::from([(variable.name().to_owned(), Expression::integer(0))]);
+        let mut lower: Option<(Expression, f64)> = None;
+        let mut upper: Option<(Expression, f64)> = None;
+
+        for g in nonnegative {
+            let slope = g.derivative(variable.clone()).simplify();
+            let offset = g.substitute(&substitutions).simplify();
+            if !matches!(slope, Expression::Number(_)) || !matches!(offset, Expression::Number(_)) {
+                return None;
+            }
+            if slope.is_zero() {
+                if offset.is_negative_number() {
+                    return Some(None);
+                }
+                continue;
+            }
+            // slope·x + offset ≥ 0 ⇔ x ≥ -offset/slope when slope > 0
+            let bound = Expression::mul(vec![
+                Expression::integer(-1),
+                offset,
+                Expression::pow(slope.clone(), Expression::integer(-1)),
+            ])
+            .simplify();
+            let value = bound.evaluate_to_f64().ok()?;
+            if slope.is_negative_number() {
+                if upper.as_ref().is_none_or(|(_, current)| value < *current) {
+                    upper = Some((bound, value));
+                }
+            } else if lower.as_ref().is_none_or(|(_, current)| value > *current) {
+                lower = Some((bound, value));
+            }
+        }
+
+        if let (Some((_, low)), Some((_, high))) = (&lower, &upper) {
+            if low > high {
+                return Some(None);
+            }
+        }
+        let (start, start_inclusive) =
+            lower.map_or((Expression::negative_infinity(), false), |(e, _)| (e, true));
+        let (end, end_inclusive) =
+            upper.map_or((Expression::infinity(), false), |(e, _)| (e, true));
+        Some(Some(Expression::interval(
+            start,
+            end,
+            start_inclusive,
+            end_inclusive,
+        )))
+    }
+
+    fn contains(interval: &Expression, point: &Expression) -> bool {
+        let (Expression::Interval(data), Ok(value)) = (interval, point.evaluate_to_f64()) else {
+            return false;
+        };
+        let (Ok(start), Ok(end)) = (data.start.evaluate_to_f64(), data.end.evaluate_to_f64())
+        else {
+            return false;
+        };
+        (start < value || (data.start_inclusive && start == value))
+            && (value < end || (data.end_inclusive && value == end))
+    }
+
+    fn combine(collected: Collected) -> SolverResult {
+        let Collected {
+            points,
+            intervals,
+            undecided,
+        } = collected;
+        let mut solutions: Vec<Expression> = intervals.clone();
+        solutions.extend(points.into_iter().filter(|point| {
+            !intervals
+                .iter()
+                .any(|interval| Self::contains(interval, point))
+        }));
+
+        let definite = match solutions.len() {
+            0 => SolverResult::NoSolution,
+            1 => SolverResult::Single(solutions.remove(0)),
+            _ => SolverResult::Multiple(solutions),
+        };
+        if undecided.is_empty() {
+            return definite;
+        }
+
+        let mut cases = undecided;
+        if definite != SolverResult::NoSolution {
+            cases.insert(0, SolutionCase::new(vec![], definite));
+        }
+        SolverResult::Cases(cases)
+    }
+
+    fn solve_branches(
+        equation: &Expression,
+        variable: &Symbol,
+    ) -> Option<(SolverResult, Vec<(Branch, SolverResult)>)> {
+        let branches = Self::branches(equation, variable)?;
+        let solver = SmartEquationSolver::new();
+        let mut collected = Collected::default();
+        let mut solved = Vec::with_capacity(branches.len());
+        for branch in branches {
+            let result = if branch.equation.contains_variable(variable) {
+                solver.solve_with_equation(&branch.equation, variable).0
+            } else if branch.equation.is_zero() {
+                SolverResult::InfiniteSolutions
+            } else if matches!(branch.equation, Expression::Number(_)) {
+                SolverResult::NoSolution
+            } else {
+                // Holds only for parameters making the branch equation vanish
+                let mut conditions = vec![zero_condition(&branch.equation, true)];
+                conditions.extend(branch.conditions.iter().cloned());
+                SolverResult::Cases(vec![SolutionCase::new(
+                    conditions,
+                    SolverResult::InfiniteSolutions,
+                )])
+            };
+            Self::collect(&branch, &result, variable, &mut collected);
+            solved.push((branch, result));
+        }
+        Some((Self::combine(collected), solved))
+    }
+}
+
+impl EquationSolver for MinMaxSolver {
+    /// Solve an equation containing `min` or `max` for `variable`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::algebra::solvers::{EquationSolver, MinMaxSolver, SolverResult};
+    /// use mathhook_core::{expr, symbol, Expression};
+    ///
+    /// let x = symbol!(x);
+    /// // max(x, 2 - x) = 3 at x = 3 (x ≥ 1) and x = -1 (x ≤ 1)
+    /// let equation = Expression::equation(
+    ///     Expression::function("max", vec![expr!(x), expr!(2 - x)]),
+    ///     expr!(3),
+    /// );
+    /// let result = MinMaxSolver::new().solve(&equation, &x);
+    /// assert_eq!(result, SolverResult::Multiple(vec![expr!(3), expr!(-1)]));
+    /// ```
+    fn solve(&self, equation: &Expression, variable: &Symbol) -> SolverResult {
+        Self::solve_branches(equation, variable)
+            .map_or(SolverResult::NoSolution, |(result, _)| result)
+    }
+
+    fn solve_with_explanation(
+        &self,
+        equation: &Expression,
+        variable: &Symbol,
+    ) -> (SolverResult, StepByStepExplanation) {
+        let Some((result, solved)) = Self::solve_branches(equation, variable) else {
+            let steps = vec![Step::new(
+                "Unsupported Form",
+                format!("No min or max of {} found", variable.name()),
+            )];
+            return (SolverResult::NoSolution, StepByStepExplanation::new(steps));
+        };
+
+        let mut steps = vec![Step::new(
+            "Split Cases",
+            format!(
+                "Each argument of the min/max gives one case, valid where that argument is selected ({} cases)",
+                solved.len()
+            ),
+        )];
+        for (i, (branch, branch_result)) in solved.iter().enumerate() {
+            let conditions = branch
+                .conditions
+                .iter()
+                .map(|condition| condition.to_string())
+                .collect::<Vec<_>>()
+                .join(" and ");
+            steps.push(Step::new(
+                format!("Case {}", i + 1),
+                format!(
+                    "Where {}: solve {} giving {:?}",
+                    conditions, branch.equation, branch_result
+                ),
+            ));
+        }
+        steps.push(Step::new(
+            "Check Regions",
+            "Keep only the solutions lying in the region of their case",
+        ));
+        steps.push(Step::new("Solution", format!("{:?}", result)));
+
+        (result, StepByStepExplanation::new(steps))
+    }
+
+    fn can_solve(&self, equation: &Expression) -> bool {
+        equation
+            .find_variables()
+            .iter()
+            .any(|variable| Self::contains_min_max_of(equation, variable))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{expr, symbol};
+
+    fn max(args: Vec<Expression>) -> Expression {
+        Expression::function("max", args)
+    }
+
+    #[test]
+    fn test_branch_solutions_outside_region_are_dropped() {
+        let x = symbol!(x);
+        // min(x, 1) = 3: x = 3 is outside x ≤ 1, and 1 = 3 is impossible
+        let equation = Expression::equation(
+            Expression::function("min", vec![expr!(x), expr!(1)]),
+            expr!(3),
+        );
+        assert_eq!(
+            MinMaxSolver::new().solve(&equation, &x),
+            SolverResult::NoSolution
+        );
+    }
+
+    #[test]
+    fn test_identity_branch_gives_interval() {
+        let x = symbol!(x);
+        let equation = Expression::equation(max(vec![expr!(x), expr!(2)]), expr!(2));
+        assert_eq!(
+            MinMaxSolver::new().solve(&equation, &x),
+            SolverResult::Single(Expression::interval(
+                Expression::negative_infinity(),
+                expr!(2),
+                false,
+                true,
+            ))
+        );
+    }
+
+    #[test]
+    fn test_symbolic_region_is_left_as_case() {
+        let x = symbol!(x);
+        let equation = Expression::equation(max(vec![expr!(x), expr!(a)]), expr!(5));
+        let SolverResult::Cases(cases) = MinMaxSolver::new().solve(&equation, &x) else {
+            panic!("expected a case split");
+        };
+        assert_eq!(
+            cases[0].conditions,
+            vec![Expression::relation(
+                expr!(5),
+                expr!(a),
+                RelationType::GreaterEqual
+            )]
+        );
+        assert_eq!(cases[0].result, SolverResult::Single(expr!(5)));
+    }
+
+    #[test]
+    fn test_explanation_lists_cases() {
+        let x = symbol!(x);
+        let equation = Expression::equation(max(vec![expr!(x), expr!(-x)]), expr!(4));
+        let (result, explanation) = MinMaxSolver::new().solve_with_explanation(&equation, &x);
+        assert_eq!(result, SolverResult::Multiple(vec![expr!(4), expr!(-4)]));
+        assert!(explanation.steps.iter().any(|step| step.title == "Case 2"));
+    }
+}
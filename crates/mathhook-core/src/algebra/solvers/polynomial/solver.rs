@@ -216,7 +216,7 @@ impl PolynomialSolver {
                 let new_exp = Self::substitute_variable(exp, variable, value);
                 Expression::pow(new_base, new_exp).simplify()
             }
-            Expression::Function { name, args } => {
+            Expression::Function { name, args, .. } => {
                 let new_args: Vec<Expression> = args
                     .iter()
                     .map(|a| Self::substitute_variable(a, variable, value))
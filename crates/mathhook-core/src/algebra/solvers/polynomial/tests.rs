@@ -128,20 +128,20 @@ fn test_no_fake_roots_in_output() {
         SolverResult::Multiple(roots) | SolverResult::Partial(roots) => {
             for root in &roots {
                 match root {
-                    Expression::Function { name, args } if name.as_ref() == "complex" => {
-                        if args.len() == 2 {
-                            let is_zero_one = matches!(
-                                (&args[0], &args[1]),
-                                (
-                                    Expression::Number(crate::core::Number::Integer(0)),
-                                    Expression::Number(crate::core::Number::Integer(1)),
-                                )
-                            );
-                            assert!(
-                                !is_zero_one,
-                                "Invalid root complex(0, 1) detected - this is an error"
-                            );
-                        }
+                    Expression::Function { name, args, .. }
+                        if name.as_ref() == "complex" && args.len() == 2 =>
+                    {
+                        let is_zero_one = matches!(
+                            (&args[0], &args[1]),
+                            (
+                                Expression::Number(crate::core::Number::Integer(0)),
+                                Expression::Number(crate::core::Number::Integer(1)),
+                            )
+                        );
+                        assert!(
+                            !is_zero_one,
+                            "Invalid root complex(0, 1) detected - this is an error"
+                        );
                     }
                     _ => {}
                 }
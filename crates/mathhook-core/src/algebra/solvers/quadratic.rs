@@ -1,8 +1,12 @@
 //! Solves equations of the form ax² + bx + c = 0
 //! Includes step-by-step explanations for educational value
 
+use crate::algebra::solvers::completing_square::{
+    self, as_rational, from_rational, product_latex, rational_sqrt, square_root,
+};
 use crate::algebra::solvers::{EquationSolver, SolverResult};
 use crate::core::constants::EPSILON;
+use crate::core::provenance::AlgorithmId;
 use crate::core::{Expression, Number, Symbol};
 use crate::educational::step_by_step::{Step, StepByStepExplanation};
 // Unused educational imports removed
@@ -11,59 +15,99 @@ use crate::simplify::Simplify;
 use num_bigint::BigInt;
 use num_rational::BigRational;
 
+/// Method used to solve a quadratic
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum QuadraticMethod {
+    /// `x = (-b ± √(b² - 4ac)) / (2a)`
+    #[default]
+    Formula,
+    /// `a (x - r₁)(x - r₂) = 0` for rational roots, falling back to the formula
+    Factoring,
+    /// `a (x - h)² + k = 0`, exact for any coefficients
+    CompletingSquare,
+}
+
 /// Quadratic equation solver
 #[derive(Debug, Clone)]
-pub struct QuadraticSolver;
+pub struct QuadraticSolver {
+    pub method: QuadraticMethod,
+    /// Use the quadratic formula when factoring finds no rational roots
+    pub formula_fallback: bool,
+}
 
 impl Default for QuadraticSolver {
     fn default() -> Self {
-        Self::new()
+        Self {
+            method: QuadraticMethod::default(),
+            formula_fallback: true,
+        }
     }
 }
 
 impl QuadraticSolver {
     pub fn new() -> Self {
-        Self
+        Self::default()
     }
-}
-
-impl EquationSolver for QuadraticSolver {
-    #[inline(always)]
-    fn solve(&self, equation: &Expression, variable: &Symbol) -> SolverResult {
-        // Simplify equation first to flatten nested structures
-        let simplified_equation = equation.simplify();
-
-        // Extract coefficients from quadratic equation ax² + bx + c = 0
-        let (a, b, c) = self.extract_quadratic_coefficients(&simplified_equation, variable);
-
-        // Handle special cases
-        let a_simplified = a.simplify();
-        let b_simplified = b.simplify();
-        let c_simplified = c.simplify();
 
-        if a_simplified.is_zero() {
-            // Degenerate case - actually linear: bx + c = 0
-            if b_simplified.is_zero() {
-                if c_simplified.is_zero() {
-                    return SolverResult::InfiniteSolutions; // 0 = 0
-                } else {
-                    return SolverResult::NoSolution; // c = 0 where c ≠ 0
-                }
-            } else {
-                // Linear equation: bx + c = 0 → x = -c/b
-                return self.solve_linear(&b_simplified, &c_simplified);
-            }
-        }
+    /// Solve with the given method instead of the quadratic formula
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::algebra::solvers::{
+    ///     EquationSolver, QuadraticMethod, QuadraticSolver, SolverResult,
+    /// };
+    /// use mathhook_core::{expr, symbol, Expression};
+    ///
+    /// let x = symbol!(x);
+    /// let solver = QuadraticSolver::new().with_method(QuadraticMethod::CompletingSquare);
+    /// let result = solver.solve(&expr!((x ^ 2) - (2 * x) - 2), &x);
+    /// let root = Expression::sqrt(Expression::integer(3));
+    /// assert_eq!(
+    ///     result,
+    ///     SolverResult::Multiple(vec![
+    ///         Expression::add(vec![Expression::integer(1), root.clone()]),
+    ///         Expression::add(vec![Expression::integer(1), Expression::mul(vec![Expression::integer(-1), root])]),
+    ///     ])
+    /// );
+    /// ```
+    pub fn with_method(mut self, method: QuadraticMethod) -> Self {
+        self.method = method;
+        self
+    }
 
-        // Solve using quadratic formula: x = (-b ± √(b² - 4ac)) / 2a
-        self.solve_quadratic_formula(&a_simplified, &b_simplified, &c_simplified)
+    /// Give up instead of using the quadratic formula when factoring fails
+    ///
+    /// For students who have learned to factor but not yet the formula.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::algebra::solvers::{
+    ///     EquationSolver, QuadraticMethod, QuadraticSolver, SolverResult,
+    /// };
+    /// use mathhook_core::{expr, symbol};
+    ///
+    /// let x = symbol!(x);
+    /// let solver = QuadraticSolver::new()
+    ///     .with_method(QuadraticMethod::Factoring)
+    ///     .without_formula_fallback();
+    /// assert_eq!(solver.solve(&expr!((x ^ 2) - 2), &x), SolverResult::NoSolution);
+    /// ```
+    pub fn without_formula_fallback(mut self) -> Self {
+        self.formula_fallback = false;
+        self
     }
 
-    fn solve_with_explanation(
+    /// Solve with an explanation, reporting the method that found the roots
+    ///
+    /// Differs from the configured method when factoring falls back to the
+    /// formula or the equation turns out to be linear.
+    pub(crate) fn solve_with_algorithm(
         &self,
         equation: &Expression,
         variable: &Symbol,
-    ) -> (SolverResult, StepByStepExplanation) {
+    ) -> (SolverResult, StepByStepExplanation, AlgorithmId) {
         let mut steps = Vec::new();
 
         let simplified_equation = equation.simplify();
@@ -122,7 +166,75 @@ impl EquationSolver for QuadraticSolver {
             }
 
             let result = self.solve(equation, variable);
-            return (result, StepByStepExplanation::new(steps));
+            let algorithm = if b_simplified.is_zero() {
+                AlgorithmId::Unevaluated
+            } else {
+                AlgorithmId::LinearIsolation
+            };
+            return (result, StepByStepExplanation::new(steps), algorithm);
+        }
+
+        match self.method {
+            QuadraticMethod::Formula => {}
+            QuadraticMethod::Factoring => {
+                if let Some((result, factored)) =
+                    self.rational_roots(&a_simplified, &b_simplified, &c_simplified, variable)
+                {
+                    let factored_latex = match &factored {
+                        Expression::Mul(factors) => product_latex(factors),
+                        _ => factored
+                            .to_latex(None)
+                            .unwrap_or_else(|_| "factored form".to_owned()),
+                    };
+                    steps.push(Step::new(
+                        "Factor",
+                        format!("Factor the quadratic: {} = 0", factored_latex),
+                    ));
+                    steps.push(Step::new(
+                        "Zero Product Property",
+                        "A product is zero exactly when one of its factors is zero",
+                    ));
+                    push_solution_steps(&mut steps, &result);
+                    return (
+                        result,
+                        StepByStepExplanation::new(steps),
+                        AlgorithmId::QuadraticFactoring,
+                    );
+                }
+                steps.push(Step::new(
+                    "Factoring",
+                    "The roots are not rational, so the quadratic does not factor over the rationals",
+                ));
+                if !self.formula_fallback {
+                    steps.push(Step::new(
+                        "Stop",
+                        "Solving this quadratic needs the quadratic formula or completing the square",
+                    ));
+                    return (
+                        SolverResult::NoSolution,
+                        StepByStepExplanation::new(steps),
+                        AlgorithmId::Unevaluated,
+                    );
+                }
+            }
+            QuadraticMethod::CompletingSquare => {
+                let form = completing_square::complete_square(&simplified_equation, variable);
+                if let Ok(completed) = form {
+                    steps.extend(completed.explanation.steps.into_iter().skip(1));
+                }
+                let result =
+                    self.solve_by_completing_square(&a_simplified, &b_simplified, &c_simplified);
+                steps.push(Step::new(
+                    "Take Square Roots",
+                    "Isolate the square and take square roots of both sides: x = h ± √(-k/a)",
+                ));
+                push_solution_steps(&mut steps, &result);
+                return (
+                    result,
+                    StepByStepExplanation::new(steps),
+                    AlgorithmId::CompletingSquare,
+                );
+            }
         }
 
         steps.push(Step::new(
@@ -162,28 +274,76 @@ impl EquationSolver for QuadraticSolver {
         }
 
         let result = self.solve_quadratic_formula(&a_simplified, &b_simplified, &c_simplified);
+        push_solution_steps(&mut steps, &result);
+
+        (
+            result,
+            StepByStepExplanation::new(steps),
+            AlgorithmId::QuadraticFormula,
+        )
+    }
+}
+
+impl EquationSolver for QuadraticSolver {
+    #[inline(always)]
+    fn solve(&self, equation: &Expression, variable: &Symbol) -> SolverResult {
+        // Simplify equation first to flatten nested structures
+        let simplified_equation = equation.simplify();
+
+        // Extract coefficients from quadratic equation ax² + bx + c = 0
+        let (a, b, c) = self.extract_quadratic_coefficients(&simplified_equation, variable);
+
+        // Handle special cases
+        let a_simplified = a.simplify();
+        let b_simplified = b.simplify();
+        let c_simplified = c.simplify();
 
-        match &result {
-            SolverResult::Single(sol) => {
-                let sol_latex = sol.to_latex(None).unwrap_or_else(|_| "solution".to_owned());
-                steps.push(Step::new("Solution", format!("x = {}", sol_latex)));
+        if a_simplified.is_zero() {
+            // Degenerate case - actually linear: bx + c = 0
+            if b_simplified.is_zero() {
+                if c_simplified.is_zero() {
+                    return SolverResult::InfiniteSolutions; // 0 = 0
+                } else {
+                    return SolverResult::NoSolution; // c = 0 where c ≠ 0
+                }
+            } else {
+                // Linear equation: bx + c = 0 → x = -c/b
+                return self.solve_linear(&b_simplified, &c_simplified);
             }
-            SolverResult::Multiple(sols) => {
-                let sols_latex: Vec<String> = sols
-                    .iter()
-                    .map(|s| s.to_latex(None).unwrap_or_else(|_| "solution".to_owned()))
-                    .collect();
-                steps.push(Step::new(
-                    "Solutions",
-                    format!("x₁ = {}, x₂ = {}", sols_latex[0], sols_latex[1]),
-                ));
+        }
+
+        match self.method {
+            QuadraticMethod::Formula => {}
+            QuadraticMethod::Factoring => {
+                if let Some((roots, _)) =
+                    self.rational_roots(&a_simplified, &b_simplified, &c_simplified, variable)
+                {
+                    return roots;
+                }
+                if !self.formula_fallback {
+                    return SolverResult::NoSolution;
+                }
             }
-            _ => {
-                steps.push(Step::new("Result", format!("{:?}", result)));
+            QuadraticMethod::CompletingSquare => {
+                return self.solve_by_completing_square(
+                    &a_simplified,
+                    &b_simplified,
+                    &c_simplified,
+                );
             }
         }
 
-        (result, StepByStepExplanation::new(steps))
+        // Solve using quadratic formula: x = (-b ± √(b² - 4ac)) / 2a
+        self.solve_quadratic_formula(&a_simplified, &b_simplified, &c_simplified)
+    }
+
+    fn solve_with_explanation(
+        &self,
+        equation: &Expression,
+        variable: &Symbol,
+    ) -> (SolverResult, StepByStepExplanation) {
+        let (result, explanation, _) = self.solve_with_algorithm(equation, variable);
+        (result, explanation)
     }
 
     fn can_solve(&self, equation: &Expression) -> bool {
@@ -192,7 +352,105 @@ impl EquationSolver for QuadraticSolver {
     }
 }
 
+/// Final step listing the solutions
+fn push_solution_steps(steps: &mut Vec<Step>, result: &SolverResult) {
+    match result {
+        SolverResult::Single(sol) => {
+            let sol_latex = sol.to_latex(None).unwrap_or_else(|_| "solution".to_owned());
+            steps.push(Step::new("Solution", format!("x = {}", sol_latex)));
+        }
+        SolverResult::Multiple(sols) if sols.len() == 2 => {
+            let sols_latex: Vec<String> = sols
+                .iter()
+                .map(|s| s.to_latex(None).unwrap_or_else(|_| "solution".to_owned()))
+                .collect();
+            steps.push(Step::new(
+                "Solutions",
+                format!("x₁ = {}, x₂ = {}", sols_latex[0], sols_latex[1]),
+            ));
+        }
+        _ => {
+            steps.push(Step::new("Result", format!("{:?}", result)));
+        }
+    }
+}
+
 impl QuadraticSolver {
+    /// Exact roots when `b² - 4ac` is the square of a rational, together with
+    /// the factored form `a (x - r₁)(x - r₂)`
+    fn rational_roots(
+        &self,
+        a: &Expression,
+        b: &Expression,
+        c: &Expression,
+        variable: &Symbol,
+    ) -> Option<(SolverResult, Expression)> {
+        let (a, b, c) = (as_rational(a)?, as_rational(b)?, as_rational(c)?);
+        let two = BigRational::from_integer(BigInt::from(2));
+        let four = BigRational::from_integer(BigInt::from(4));
+        let root = rational_sqrt(&(&b * &b - four * &a * &c))?;
+        let first = from_rational((-&b + &root) / (&two * &a));
+        let second = from_rational((-&b - &root) / (two * &a));
+        let x = Expression::symbol(variable.clone());
+        let factor = |r: &Expression| {
+            Expression::add(vec![
+                x.clone(),
+                Expression::mul(vec![Expression::integer(-1), r.clone()]).simplify(),
+            ])
+        };
+        let factored = Expression::mul(vec![from_rational(a), factor(&first), factor(&second)]);
+        let result = if first == second {
+            SolverResult::Single(first)
+        } else {
+            SolverResult::Multiple(vec![first, second])
+        };
+        Some((result, factored))
+    }
+
+    /// `x = h ± √(-k/a)` from the vertex form `a (x - h)² + k = 0`
+    fn solve_by_completing_square(
+        &self,
+        a: &Expression,
+        b: &Expression,
+        c: &Expression,
+    ) -> SolverResult {
+        let (h, k) = completing_square::vertex(a, b, c);
+        let radicand =
+            Expression::div(Expression::mul(vec![Expression::integer(-1), k]), a.clone())
+                .simplify();
+        if radicand.is_zero() {
+            return SolverResult::Single(h);
+        }
+        let negative = radicand.evaluate_to_f64().is_ok_and(|value| value < 0.0);
+        if negative {
+            let root =
+                square_root(&Expression::mul(vec![Expression::integer(-1), radicand]).simplify());
+            let imaginary = |sign: i64| {
+                Expression::mul(vec![Expression::integer(sign), root.clone()]).simplify()
+            };
+            return SolverResult::Multiple(vec![
+                Expression::complex(h.clone(), imaginary(1)),
+                Expression::complex(h, imaginary(-1)),
+            ]);
+        }
+        let root = square_root(&radicand);
+        let shifted = |sign: i64| {
+            let offset = if sign > 0 {
+                root.clone()
+            } else {
+                Expression::mul(vec![Expression::integer(-1), root.clone()])
+            };
+            let root = if h.is_zero() {
+                offset.simplify()
+            } else {
+                Expression::add(vec![h.clone(), offset]).simplify()
+            };
+            // Simplification can leave rationals such as 4/4 unreduced
+            as_rational(&root).map(from_rational).unwrap_or(root)
+        };
+        SolverResult::Multiple(vec![shifted(1), shifted(-1)])
+    }
+
     /// Extract coefficients a, b, c from ax² + bx + c = 0
     fn extract_quadratic_coefficients(
         &self,
@@ -398,3 +656,62 @@ impl QuadraticSolver {
         true
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{expr, symbol};
+
+    #[test]
+    fn test_methods_agree_on_rational_roots() {
+        let x = symbol!(x);
+        let equation = expr!((2 * (x ^ 2)) - (3 * x) + 1);
+        let expected =
+            SolverResult::Multiple(vec![Expression::integer(1), Expression::rational(1, 2)]);
+        for method in [
+            QuadraticMethod::Factoring,
+            QuadraticMethod::CompletingSquare,
+        ] {
+            let solver = QuadraticSolver::new().with_method(method);
+            assert_eq!(solver.solve(&equation, &x), expected);
+            let (result, explanation) = solver.solve_with_explanation(&equation, &x);
+            assert_eq!(result, expected);
+            assert!(!explanation.steps.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_completing_square_is_exact() {
+        let x = symbol!(x);
+        let solver = QuadraticSolver::new().with_method(QuadraticMethod::CompletingSquare);
+        let result = solver.solve(&expr!((x ^ 2) + (2 * x) + 5), &x);
+        assert_eq!(
+            result,
+            SolverResult::Multiple(vec![
+                Expression::complex(expr!(-1), expr!(2)),
+                Expression::complex(expr!(-1), expr!(-2)),
+            ])
+        );
+
+        // Irrational roots fall back to the formula when factoring
+        let factoring = QuadraticSolver::new().with_method(QuadraticMethod::Factoring);
+        let equation = expr!((x ^ 2) - 2);
+        assert_eq!(
+            factoring.solve(&equation, &x),
+            QuadraticSolver::new().solve(&equation, &x)
+        );
+    }
+
+    #[test]
+    fn test_algorithm_reports_fallback() {
+        let x = symbol!(x);
+        let factoring = QuadraticSolver::new().with_method(QuadraticMethod::Factoring);
+        let algorithm = |equation: Expression| factoring.solve_with_algorithm(&equation, &x).2;
+        assert_eq!(
+            algorithm(expr!((x ^ 2) - 1)),
+            AlgorithmId::QuadraticFactoring
+        );
+        assert_eq!(algorithm(expr!((x ^ 2) - 2)), AlgorithmId::QuadraticFormula);
+        assert_eq!(algorithm(expr!((2 * x) - 2)), AlgorithmId::LinearIsolation);
+    }
+}
@@ -0,0 +1,292 @@
+//! Structured roots of polynomial solves
+//!
+//! `SolverResult` lists solutions flat, with a repeated root appearing once.
+//! `SolverResult::roots` recovers the structure: each distinct root with its
+//! multiplicity (the number of derivatives of the polynomial vanishing at the
+//! root), whether it is real or complex, and whether it is exact or a
+//! floating-point approximation.
+
+use super::SolverResult;
+use crate::calculus::derivatives::Derivative;
+use crate::core::{Expression, MathConstant, Number, Symbol};
+use crate::simplify::Simplify;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Residuals below this are treated as zero for approximate roots
+const APPROXIMATE_ZERO: f64 = 1e-9;
+
+/// Highest multiplicity probed; bounds the derivative loop for non-polynomials
+const MAX_MULTIPLICITY: usize = 32;
+
+/// Real or complex classification of a root
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RootKind {
+    Real,
+    /// Has a nonzero imaginary part
+    Complex,
+    /// Depends on parameters, so it cannot be classified
+    Symbolic,
+}
+
+/// One distinct root of an equation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Root {
+    pub value: Expression,
+    pub multiplicity: usize,
+    pub kind: RootKind,
+    /// False when the root is a floating-point approximation
+    pub exact: bool,
+    /// Numeric value of a real root
+    pub numeric: Option<f64>,
+}
+
+impl Root {
+    pub fn is_real(&self) -> bool {
+        self.kind == RootKind::Real
+    }
+}
+
+/// Distinct roots with multiplicities, in the order the solver found them
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RootSet {
+    pub roots: Vec<Root>,
+}
+
+impl RootSet {
+    /// Real roots only
+    pub fn real_only(&self) -> RootSet {
+        self.filtered(|root| root.is_real())
+    }
+
+    /// Real roots in the closed interval `[a, b]`
+    pub fn in_interval(&self, a: f64, b: f64) -> RootSet {
+        self.filtered(|root| root.numeric.is_some_and(|value| a <= value && value <= b))
+    }
+
+    /// Roots that are floating-point approximations
+    pub fn approximate(&self) -> RootSet {
+        self.filtered(|root| !root.exact)
+    }
+
+    /// Number of roots counted with multiplicity
+    pub fn total_multiplicity(&self) -> usize {
+        self.roots.iter().map(|root| root.multiplicity).sum()
+    }
+
+    /// Root values without multiplicities
+    pub fn values(&self) -> Vec<&Expression> {
+        self.roots.iter().map(|root| &root.value).collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.roots.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.roots.is_empty()
+    }
+
+    fn filtered(&self, keep: impl Fn(&Root) -> bool) -> RootSet {
+        RootSet {
+            roots: self
+                .roots
+                .iter()
+                .filter(|root| keep(root))
+                .cloned()
+                .collect(),
+        }
+    }
+}
+
+impl SolverResult {
+    /// Distinct roots of `equation` with multiplicity and classification
+    ///
+    /// `NoSolution`, `InfiniteSolutions`, `Parametric` and `Cases` results
+    /// have no roots. For `Partial` results only the roots found are reported.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::algebra::solvers::{EquationSolver, PolynomialSolver};
+    /// use mathhook_core::{expr, symbol};
+    ///
+    /// // (x - 1)^2 (x + 2) = x^3 - 3x + 2
+    /// let x = symbol!(x);
+    /// let cubic = expr!((x ^ 3) - (3 * x) + 2);
+    ///
+    /// let roots = PolynomialSolver::new().solve(&cubic, &x).roots(&cubic, &x);
+    /// assert_eq!(roots.total_multiplicity(), 3);
+    /// assert_eq!(roots.in_interval(0.0, 5.0).roots[0].multiplicity, 2);
+    /// ```
+    pub fn roots(&self, equation: &Expression, variable: &Symbol) -> RootSet {
+        let solutions: &[Expression] = match self {
+            SolverResult::Single(root) => std::slice::from_ref(root),
+            SolverResult::Multiple(roots) | SolverResult::Partial(roots) => roots,
+            SolverResult::NoSolution
+            | SolverResult::InfiniteSolutions
+            | SolverResult::Parametric(_)
+            | SolverResult::Cases(_) => &[],
+        };
+        let polynomial = standard_form(equation);
+
+        let mut set = RootSet::default();
+        for solution in solutions {
+            if set.roots.iter().any(|root| &root.value == solution) {
+                continue;
+            }
+            // A solver listing a repeated root more than once is trusted when
+            // the derivatives cannot be simplified to zero at the root
+            let listed = solutions.iter().filter(|other| *other == solution).count();
+            let kind = classify(solution);
+            set.roots.push(Root {
+                value: solution.clone(),
+                multiplicity: multiplicity(&polynomial, variable, solution).max(listed),
+                kind,
+                exact: !contains_float(solution),
+                numeric: match kind {
+                    RootKind::Real => solution.evaluate_to_f64().ok(),
+                    _ => None,
+                },
+            });
+        }
+        set
+    }
+}
+
+fn standard_form(equation: &Expression) -> Expression {
+    match equation {
+        Expression::Relation(relation) => Expression::add(vec![
+            relation.left.clone(),
+            Expression::mul(vec![Expression::integer(-1), relation.right.clone()]),
+        ]),
+        other => other.clone(),
+    }
+}
+
+/// Number of successive derivatives vanishing at `root`, at least one
+fn multiplicity(polynomial: &Expression, variable: &Symbol, root: &Expression) -> usize {
+    let substitutions = HashMap::from([(variable.name().to_owned(), root.clone())]);
+    let mut derivative = polynomial.derivative(variable.clone());
+    let mut count = 1;
+    while count < MAX_MULTIPLICITY && vanishes(&derivative.substitute(&substitutions)) {
+        derivative = derivative.derivative(variable.clone());
+        count += 1;
+    }
+    count
+}
+
+fn vanishes(value: &Expression) -> bool {
+    value.simplify().is_zero()
+        || value
+            .evaluate_to_f64()
+            .is_ok_and(|numeric| numeric.abs() < APPROXIMATE_ZERO)
+}
+
+pub(super) fn classify(root: &Expression) -> RootKind {
+    if contains_imaginary(root) {
+        RootKind::Complex
+    } else if root.evaluate_to_f64().is_ok_and(f64::is_finite) {
+        RootKind::Real
+    } else if root.find_variables().is_empty() {
+        // Numeric but not real, e.g. the square root of a negative number
+        RootKind::Complex
+    } else {
+        RootKind::Symbolic
+    }
+}
+
+fn contains_imaginary(expr: &Expression) -> bool {
+    match expr {
+        Expression::Complex(data) => !data.imag.is_zero(),
+        Expression::Constant(MathConstant::I) => true,
+        Expression::Add(terms) | Expression::Mul(terms) => terms.iter().any(contains_imaginary),
+        Expression::Pow(base, exponent) => contains_imaginary(base) || contains_imaginary(exponent),
+        Expression::Function { args, .. } => args.iter().any(contains_imaginary),
+        _ => false,
+    }
+}
+
+fn contains_float(expr: &Expression) -> bool {
+    match expr {
+        Expression::Number(Number::Float(_)) => true,
+        Expression::Complex(data) => contains_float(&data.real) || contains_float(&data.imag),
+        Expression::Add(terms) | Expression::Mul(terms) => terms.iter().any(contains_float),
+        Expression::Pow(base, exponent) => contains_float(base) || contains_float(exponent),
+        Expression::Function { args, .. } => args.iter().any(contains_float),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algebra::solvers::{EquationSolver, QuadraticSolver};
+    use crate::{expr, symbol};
+
+    #[test]
+    fn test_double_root_of_quadratic() {
+        let x = symbol!(x);
+        let equation = expr!((x ^ 2) - (2 * x) + 1);
+        let result = QuadraticSolver::new().solve(&equation, &x);
+
+        let roots = result.roots(&equation, &x);
+        assert_eq!(roots.len(), 1);
+        assert_eq!(roots.roots[0].multiplicity, 2);
+        assert!(roots.roots[0].exact);
+        assert_eq!(roots.roots[0].numeric, Some(1.0));
+    }
+
+    #[test]
+    fn test_complex_roots_are_classified() {
+        let x = symbol!(x);
+        let equation = expr!((x ^ 2) + 1);
+        let result = QuadraticSolver::new().solve(&equation, &x);
+
+        let roots = result.roots(&equation, &x);
+        assert_eq!(roots.len(), 2);
+        assert!(roots
+            .roots
+            .iter()
+            .all(|root| root.kind == RootKind::Complex));
+        assert!(roots.real_only().is_empty());
+        assert!(roots.in_interval(-10.0, 10.0).is_empty());
+    }
+
+    #[test]
+    fn test_irrational_roots_are_approximate() {
+        let x = symbol!(x);
+        let equation = expr!((x ^ 2) - 2);
+        let result = QuadraticSolver::new().solve(&equation, &x);
+
+        let roots = result.roots(&equation, &x);
+        assert_eq!(roots.approximate().len(), 2);
+        assert_eq!(roots.in_interval(0.0, 2.0).len(), 1);
+        assert_eq!(roots.total_multiplicity(), 2);
+    }
+
+    #[test]
+    fn test_symbolic_and_empty_results() {
+        let x = symbol!(x);
+        let equation = Expression::equation(expr!(x), expr!(a));
+        let roots = SolverResult::Single(expr!(a)).roots(&equation, &x);
+        assert_eq!(roots.roots[0].kind, RootKind::Symbolic);
+        assert_eq!(roots.roots[0].numeric, None);
+
+        assert!(SolverResult::NoSolution.roots(&equation, &x).is_empty());
+    }
+
+    #[test]
+    fn test_listed_duplicates_are_merged() {
+        let x = symbol!(x);
+        let result = SolverResult::Multiple(vec![expr!(0), expr!(0)]);
+
+        let roots = result.roots(&expr!(x ^ 2), &x);
+        assert_eq!(roots.len(), 1);
+        assert_eq!(roots.roots[0].multiplicity, 2);
+
+        // The listing is the only evidence when the equation is not a polynomial
+        let roots = result.roots(&expr!(sin(x)), &x);
+        assert_eq!(roots.roots[0].multiplicity, 2);
+    }
+}
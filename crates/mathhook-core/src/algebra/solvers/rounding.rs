@@ -0,0 +1,471 @@
+//! Solves equations of the form c·floor(a x + b) + p x + d = 0 (and ceil)
+//!
+//! Without the `p x` term, `floor(u) = n` holds exactly when `n ≤ u < n + 1`
+//! for integer `n`, so the solution is an interval of `x` rather than
+//! isolated points, and a non-integer right-hand side has no solution. With
+//! it, each integer value `n` of the floor fixes `x`, and only the few `n`
+//! within 1 of the argument at that `x` need checking.
+
+use crate::algebra::solvers::{EquationSolver, SolverResult};
+use crate::calculus::derivatives::Derivative;
+use crate::core::{Expression, Number, Symbol};
+use crate::educational::step_by_step::{Step, StepByStepExplanation};
+use crate::simplify::Simplify;
+use std::collections::HashMap;
+
+/// Solver for equations with a single floor or ceiling of a linear argument
+#[derive(Debug, Clone, Default)]
+pub struct RoundingSolver;
+
+/// `coefficient·name(slope·x + offset) + linear·x + constant = 0`
+struct RoundingForm {
+    name: String,
+    coefficient: Expression,
+    constant: Expression,
+    slope: Expression,
+    offset: Expression,
+    linear: Expression,
+}
+
+/// Most integer values of the floor checked for `floor(u) + p x + d = 0`
+const MAX_CANDIDATES: i64 = 64;
+
+impl RoundingSolver {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Whether `expr` applies floor or ceil to something containing `variable`
+    pub fn contains_rounding_of(expr: &Expression, variable: &Symbol) -> bool {
+        match expr {
+            Expression::Function { name, args, .. } => {
+                (matches!(name.as_ref(), "floor" | "ceil")
+                    && args.iter().any(|arg| arg.contains_variable(variable)))
+                    || args
+                        .iter()
+                        .any(|arg| Self::contains_rounding_of(arg, variable))
+            }
+            Expression::Add(terms) | Expression::Mul(terms) => terms
+                .iter()
+                .any(|term| Self::contains_rounding_of(term, variable)),
+            Expression::Pow(base, exponent) => {
+                Self::contains_rounding_of(base, variable)
+                    || Self::contains_rounding_of(exponent, variable)
+            }
+            Expression::Relation(relation) => {
+                Self::contains_rounding_of(&relation.left, variable)
+                    || Self::contains_rounding_of(&relation.right, variable)
+            }
+            _ => false,
+        }
+    }
+
+    fn decompose(equation: &Expression, variable: &Symbol) -> Option<RoundingForm> {
+        let standard = match equation {
+            Expression::Relation(data) => Expression::add(vec![
+                data.left.clone(),
+                Expression::mul(vec![Expression::integer(-1), data.right.clone()]),
+            ]),
+            other => other.clone(),
+        }
+        .simplify();
+
+        let terms = match &standard {
+            Expression::Add(terms) => terms.to_vec(),
+            single => vec![single.clone()],
+        };
+
+        let mut rounding = None;
+        let mut constants = Vec::new();
+        let mut linear_terms = Vec::new();
+        for term in terms {
+            if !term.contains_variable(variable) {
+                constants.push(term);
+            } else if let Some(split) = Self::split_coefficient(&term) {
+                if rounding.is_some() {
+                    return None;
+                }
+                rounding = Some(split);
+            } else {
+                linear_terms.push(term);
+            }
+        }
+        let (coefficient, name, argument) = rounding?;
+
+        let substitutions = HashMap::from([(variable.name().to_owned(), Expression::integer(0))]);
+        let slope = argument.derivative(variable.clone()).simplify();
+        let offset = argument.substitute(&substitutions).simplify();
+        let constant = Expression::add(constants).simplify();
+        let linear_part = Expression::add(linear_terms).simplify();
+        let linear = linear_part.derivative(variable.clone()).simplify();
+        let numeric = |e: &Expression| matches!(e, Expression::Number(_));
+        let coefficients = [&coefficient, &constant, &slope, &offset, &linear];
+        if !coefficients.into_iter().all(numeric)
+            || !linear_part.substitute(&substitutions).simplify().is_zero()
+            || coefficient.is_zero()
+            || slope.is_zero()
+        {
+            return None;
+        }
+
+        Some(RoundingForm {
+            name,
+            coefficient,
+            constant,
+            slope,
+            offset,
+            linear,
+        })
+    }
+
+    /// `c·floor(u)` as `(c, "floor", u)`
+    fn split_coefficient(term: &Expression) -> Option<(Expression, String, Expression)> {
+        match term {
+            Expression::Function { name, args, .. }
+                if matches!(name.as_ref(), "floor" | "ceil") && args.len() == 1 =>
+            {
+                Some((Expression::integer(1), name.to_string(), args[0].clone()))
+            }
+            Expression::Mul(factors) => {
+                let position = factors
+                    .iter()
+                    .position(|f| matches!(f, Expression::Function { .. }))?;
+                let (_, name, argument) = Self::split_coefficient(&factors[position])?;
+                let rest: Vec<Expression> = factors
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, _)| *i != position)
+                    .map(|(_, f)| f.clone())
+                    .collect();
+                Some((Expression::mul(rest).simplify(), name, argument))
+            }
+            _ => None,
+        }
+    }
+
+    /// Interval of `x` with `slope·x + offset` between `low` and `high`
+    fn interval(
+        form: &RoundingForm,
+        low: Expression,
+        high: Expression,
+        low_inclusive: bool,
+        high_inclusive: bool,
+    ) -> Expression {
+        let solve_for_x = |bound: Expression| {
+            Expression::mul(vec![
+                Expression::add(vec![
+                    bound,
+                    Expression::mul(vec![Expression::integer(-1), form.offset.clone()]),
+                ]),
+                Expression::pow(form.slope.clone(), Expression::integer(-1)),
+            ])
+            .simplify()
+        };
+        let (start, end) = (solve_for_x(low), solve_for_x(high));
+        if form.slope.is_negative_number() {
+            Expression::interval(end, start, high_inclusive, low_inclusive)
+        } else {
+            Expression::interval(start, end, low_inclusive, high_inclusive)
+        }
+    }
+
+    fn solve_form(form: &RoundingForm) -> (SolverResult, Option<Expression>) {
+        // floor(u) = n with n = -constant / coefficient
+        let value = Expression::mul(vec![
+            Expression::integer(-1),
+            form.constant.clone(),
+            Expression::pow(form.coefficient.clone(), Expression::integer(-1)),
+        ])
+        .simplify();
+        if !matches!(
+            value,
+            Expression::Number(Number::Integer(_) | Number::BigInteger(_))
+        ) {
+            return (SolverResult::NoSolution, Some(value));
+        }
+
+        let shifted = |by: i64| Expression::add(vec![value.clone(), Expression::integer(by)]);
+        let interval = if form.name == "floor" {
+            Self::interval(form, value.clone(), shifted(1), true, false)
+        } else {
+            Self::interval(form, shifted(-1), value.clone(), false, true)
+        };
+        (SolverResult::Single(interval), Some(value))
+    }
+
+    /// Solve `c·floor(a x + b) + p x + d = 0` with `p ≠ 0`
+    ///
+    /// With `n = floor(a x + b)`, `x = -(c n + d) / p` and `a x + b = m - k n`
+    /// for `k = a c / p` and `m = b - a d / p`. `n` lies within 1 of that, so
+    /// `|(1 + k) n - m| < 1` bounds the candidates. Returns `Partial` when
+    /// the candidates cannot be bounded (every or no `n` may work).
+    fn solve_mixed(form: &RoundingForm) -> SolverResult {
+        let value = |e: &Expression| e.evaluate_to_f64().ok();
+        let (Some(a), Some(b), Some(c), Some(d), Some(p)) = (
+            value(&form.slope),
+            value(&form.offset),
+            value(&form.coefficient),
+            value(&form.constant),
+            value(&form.linear),
+        ) else {
+            return SolverResult::Partial(vec![]);
+        };
+        let (k, m) = (a * c / p, b - a * d / p);
+        let scale = 1.0 + k;
+        if scale.abs() < f64::EPSILON {
+            return SolverResult::Partial(vec![]);
+        }
+        let (low, high) = ((m - 1.0) / scale, (m + 1.0) / scale);
+        let (first, last) = (low.min(high).floor(), low.max(high).ceil());
+        if last - first > MAX_CANDIDATES as f64 {
+            return SolverResult::Partial(vec![]);
+        }
+
+        let solutions: Vec<Expression> = (first as i64..=last as i64)
+            .filter_map(|n| {
+                let x = Expression::mul(vec![
+                    Expression::integer(-1),
+                    Expression::add(vec![
+                        Expression::mul(vec![form.coefficient.clone(), Expression::integer(n)]),
+                        form.constant.clone(),
+                    ]),
+                    Expression::pow(form.linear.clone(), Expression::integer(-1)),
+                ])
+                .simplify();
+                let argument = Expression::add(vec![
+                    Expression::mul(vec![form.slope.clone(), x.clone()]),
+                    form.offset.clone(),
+                ]);
+                let rounded = Expression::function(form.name.as_str(), vec![argument]).simplify();
+                (value(&rounded) == Some(n as f64)).then_some(x)
+            })
+            .collect();
+
+        match solutions.len() {
+            0 => SolverResult::NoSolution,
+            1 => SolverResult::Single(solutions[0].clone()),
+            _ => SolverResult::Multiple(solutions),
+        }
+    }
+}
+
+impl EquationSolver for RoundingSolver {
+    /// Solve `c·floor(a x + b) + d = 0` (or ceil) for `x`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::algebra::solvers::{EquationSolver, RoundingSolver, SolverResult};
+    /// use mathhook_core::{expr, symbol, Expression};
+    ///
+    /// let x = symbol!(x);
+    /// // floor(2x) = 3  ⇔  3/2 ≤ x < 2
+    /// let equation = Expression::equation(
+    ///     Expression::function("floor", vec![expr!(2 * x)]),
+    ///     expr!(3),
+    /// );
+    /// let result = RoundingSolver::new().solve(&equation, &x);
+    /// assert_eq!(
+    ///     result,
+    ///     SolverResult::Single(Expression::interval(
+    ///         Expression::rational(3, 2),
+    ///         expr!(2),
+    ///         true,
+    ///         false,
+    ///     ))
+    /// );
+    /// ```
+    ///
+    /// Forms this solver does not handle give `Partial` with no solutions
+    /// rather than `NoSolution`.
+    fn solve(&self, equation: &Expression, variable: &Symbol) -> SolverResult {
+        match Self::decompose(equation, variable) {
+            Some(form) if form.linear.is_zero() => Self::solve_form(&form).0,
+            Some(form) => Self::solve_mixed(&form),
+            None => SolverResult::Partial(vec![]),
+        }
+    }
+
+    fn solve_with_explanation(
+        &self,
+        equation: &Expression,
+        variable: &Symbol,
+    ) -> (SolverResult, StepByStepExplanation) {
+        let Some(form) = Self::decompose(equation, variable) else {
+            let steps = vec![Step::new(
+                "Unsupported Form",
+                "Only a single floor or ceiling of a linear argument, plus a linear term, can be solved exactly",
+            )];
+            return (
+                SolverResult::Partial(vec![]),
+                StepByStepExplanation::new(steps),
+            );
+        };
+
+        if !form.linear.is_zero() {
+            let result = Self::solve_mixed(&form);
+            let mut steps = vec![Step::new(
+                "Isolate",
+                format!(
+                    "With n = {}(u), {}·n + {}·{} + {} = 0 fixes {} for each integer n",
+                    form.name,
+                    form.coefficient,
+                    form.linear,
+                    variable.name(),
+                    form.constant,
+                    variable.name()
+                ),
+            )];
+            steps.push(match &result {
+                SolverResult::Single(x) => {
+                    Step::new("Solution", format!("{} = {}", variable.name(), x))
+                }
+                SolverResult::Multiple(xs) => Step::new(
+                    "Solution",
+                    format!(
+                        "{} ∈ {{{}}}",
+                        variable.name(),
+                        xs.iter()
+                            .map(|x| x.to_string())
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ),
+                ),
+                SolverResult::NoSolution => Step::new(
+                    "No Solution",
+                    format!("No integer n gives {}(u) = n", form.name),
+                ),
+                _ => Step::new(
+                    "Unbounded",
+                    "The integer values of the floor cannot be narrowed down",
+                ),
+            });
+            return (result, StepByStepExplanation::new(steps));
+        }
+
+        let (result, value) = Self::solve_form(&form);
+        let value = value.unwrap_or_else(|| Expression::integer(0));
+        let mut steps = vec![Step::new(
+            "Isolate",
+            format!(
+                "{}(u) = {} with u = {}·{} + {}",
+                form.name,
+                value,
+                form.slope,
+                variable.name(),
+                form.offset
+            ),
+        )];
+
+        match &result {
+            SolverResult::Single(interval) => {
+                let bounds = if form.name == "floor" {
+                    format!("{} ≤ u < {} + 1", value, value)
+                } else {
+                    format!("{} - 1 < u ≤ {}", value, value)
+                };
+                steps.push(Step::new(
+                    "Rounding Bounds",
+                    format!("{}(u) = {} holds exactly when {}", form.name, value, bounds),
+                ));
+                steps.push(Step::new(
+                    "Solution",
+                    format!("{} ∈ {}", variable.name(), interval),
+                ));
+            }
+            _ => steps.push(Step::new(
+                "No Solution",
+                format!(
+                    "{}(u) is always an integer, but {} is not",
+                    form.name, value
+                ),
+            )),
+        }
+
+        (result, StepByStepExplanation::new(steps))
+    }
+
+    fn can_solve(&self, equation: &Expression) -> bool {
+        equation
+            .find_variables()
+            .first()
+            .is_some_and(|variable| Self::decompose(equation, variable).is_some())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{expr, symbol};
+
+    fn floor(arg: Expression) -> Expression {
+        Expression::function("floor", vec![arg])
+    }
+
+    #[test]
+    fn test_floor_equation_gives_half_open_interval() {
+        let x = symbol!(x);
+        let equation = Expression::equation(floor(expr!(x + 1)), expr!(4));
+        let result = RoundingSolver::new().solve(&equation, &x);
+        assert_eq!(
+            result,
+            SolverResult::Single(Expression::interval(expr!(3), expr!(4), true, false))
+        );
+    }
+
+    #[test]
+    fn test_ceil_with_negative_slope() {
+        let x = symbol!(x);
+        // ceil(-x) = 2  ⇔  1 < -x ≤ 2  ⇔  -2 ≤ x < -1
+        let equation =
+            Expression::equation(Expression::function("ceil", vec![expr!(-x)]), expr!(2));
+        let result = RoundingSolver::new().solve(&equation, &x);
+        assert_eq!(
+            result,
+            SolverResult::Single(Expression::interval(expr!(-2), expr!(-1), true, false))
+        );
+    }
+
+    #[test]
+    fn test_non_integer_value_has_no_solution() {
+        let x = symbol!(x);
+        let equation = expr!(2 * floor(x) - 3);
+        let (result, explanation) = RoundingSolver::new().solve_with_explanation(&equation, &x);
+        assert_eq!(result, SolverResult::NoSolution);
+        assert_eq!(explanation.steps.last().unwrap().title, "No Solution");
+    }
+
+    #[test]
+    fn test_floor_plus_linear_term() {
+        let x = symbol!(x);
+        let equation = Expression::equation(expr!(floor(x) + x), expr!(4));
+        let result = RoundingSolver::new().solve(&equation, &x);
+        assert_eq!(result, SolverResult::Single(expr!(2)));
+
+        // x = 5 - floor(x) is an integer, so floor(x) + x is even
+        let equation = Expression::equation(expr!(floor(x) + x), expr!(5));
+        let result = RoundingSolver::new().solve(&equation, &x);
+        assert_eq!(result, SolverResult::NoSolution);
+    }
+
+    #[test]
+    fn test_unsupported_form_is_partial() {
+        let x = symbol!(x);
+        let equation = Expression::equation(expr!(floor(x ^ 2) + floor(x)), expr!(4));
+        let result = RoundingSolver::new().solve(&equation, &x);
+        assert_eq!(result, SolverResult::Partial(vec![]));
+    }
+
+    #[test]
+    fn test_detection() {
+        let x = symbol!(x);
+        assert!(RoundingSolver::contains_rounding_of(
+            &expr!(floor(x) + 1),
+            &x
+        ));
+        assert!(!RoundingSolver::contains_rounding_of(
+            &expr!(floor(y) + x),
+            &x
+        ));
+    }
+}
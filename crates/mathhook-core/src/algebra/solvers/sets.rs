@@ -0,0 +1,728 @@
+//! Symbolic solution sets
+//!
+//! [`SolutionSet`] describes subsets of the real line built from intervals.
+//! Equations with infinitely many isolated solutions, such as `sin(x) = 0`,
+//! or whose solutions have no closed form need a richer description.
+//! [`MathSolver::solve`](crate::solvers::MathSolver::solve) reports them as
+//! [`SolverResult::ImageSets`], families like `{π·n | n ∈ ℤ}`, and
+//! [`SolverResult::ConditionSet`], sets like `{x | cos(x) = x}`.
+//!
+//! [`Solutions`] is the set algebra over those results: it combines finite
+//! sets, intervals, image sets and condition sets with union and
+//! intersection. [`solve_general`] solves an equation or inequality with
+//! [`MathSolver`] and returns its solutions as a [`Solutions`]:
+//!
+//! ```rust
+//! use mathhook_core::algebra::solvers::{solve_general, Solutions, SolutionSet};
+//! use mathhook_core::{expr, symbol, Expression};
+//!
+//! let x = symbol!(x);
+//! let zeros = solve_general(&Expression::equation(expr!(sin(x)), expr!(0)), &x);
+//! assert!(matches!(zeros, Solutions::ImageSet(_)));
+//! assert_eq!(zeros.contains(&Expression::pi()), Some(true));
+//!
+//! // 0, π and 2π
+//! let window = Solutions::Interval(SolutionSet::interval(expr!(-1), expr!(7), true, true).unwrap());
+//! let inside = zeros.intersection(&window);
+//! assert!(matches!(inside, Solutions::FiniteSet(ref members) if members.len() == 3));
+//! ```
+
+use super::SolutionSet;
+use crate::calculus::integrals::distributions::linear_coefficients;
+use crate::core::expression::eval_numeric::EvalNumeric;
+use crate::core::expression::RelationType;
+use crate::core::symbol::Assumption;
+use crate::core::{Expression, Symbol};
+use crate::simplify::Simplify;
+use crate::solvers::{MathSolver, SolverResult};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+/// Relative tolerance for deciding that a value is a member numerically
+const MEMBERSHIP_TOLERANCE: f64 = 1e-9;
+
+/// Most members listed when an image set is cut down to a bounded interval
+const MAX_LISTED_MEMBERS: i64 = 10_000;
+
+/// `{expression | parameter ∈ ℤ}`, such as the general solution `π/2 + 2·π·n`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ImageSet {
+    pub parameter: Symbol,
+    pub expression: Expression,
+}
+
+impl ImageSet {
+    pub fn new(parameter: Symbol, expression: Expression) -> Self {
+        Self {
+            parameter,
+            expression,
+        }
+    }
+
+    /// `{base + period·n | n ∈ ℤ}` over an integer symbol `n`
+    ///
+    /// The parameter is named `n`, `k` or `m`, or `n1`, `n2`, … if those
+    /// already occur in `base` or `period`, so it never captures a symbol
+    /// of the equation.
+    pub fn periodic(base: Expression, period: Expression) -> Self {
+        let taken: HashSet<String> = base
+            .find_variables()
+            .into_iter()
+            .chain(period.find_variables())
+            .map(|symbol| symbol.name().to_owned())
+            .collect();
+        let name = ["n", "k", "m"]
+            .into_iter()
+            .map(str::to_owned)
+            .chain((1..).map(|i| format!("n{i}")))
+            .find(|name| !taken.contains(name))
+            .expect("BUG: unbounded candidate names");
+        let n = Symbol::new_with_assumptions(&name, &[Assumption::Integer]);
+        let expression = Expression::add(vec![
+            base,
+            Expression::mul(vec![period, Expression::symbol(n.clone())]),
+        ])
+        .simplify();
+        Self::new(n, expression)
+    }
+
+    /// The member for `parameter = k`
+    pub fn member(&self, k: i64) -> Expression {
+        self.expression
+            .substitute(&HashMap::from([(
+                self.parameter.name().to_owned(),
+                Expression::integer(k),
+            )]))
+            .simplify()
+    }
+
+    /// `(base, period)` when the members are `base + period·n` with real
+    /// numeric base and nonzero period
+    fn progression(&self) -> Option<(f64, f64)> {
+        let (period, base) = linear_coefficients(&self.expression, &self.parameter)?;
+        let period = real_value(&period).filter(|p| *p != 0.0)?;
+        let base = real_value(&base)?;
+        Some((base, period))
+    }
+
+    /// Whether the real number `value` is a member, if decidable
+    pub fn contains(&self, value: f64) -> Option<bool> {
+        let (base, period) = self.progression()?;
+        let k = (value - base) / period;
+        Some((k - k.round()).abs() <= MEMBERSHIP_TOLERANCE * k.abs().max(1.0))
+    }
+
+    /// Members inside a bounded interval, or `None` if the interval is
+    /// unbounded or the members are not an arithmetic progression
+    fn members_within(&self, set: &SolutionSet) -> Option<Vec<Expression>> {
+        let (base, period) = self.progression()?;
+        let mut members = Vec::new();
+        for interval in set.intervals() {
+            let (lower, upper) = (interval.lower.as_ref()?, interval.upper.as_ref()?);
+            let (first, last) = {
+                let a = (lower.numeric - base) / period;
+                let b = (upper.numeric - base) / period;
+                (a.min(b).floor() as i64, a.max(b).ceil() as i64)
+            };
+            if last - first > MAX_LISTED_MEMBERS {
+                return None;
+            }
+            for k in first..=last {
+                if interval.contains(base + period * k as f64) {
+                    members.push(self.member(k));
+                }
+            }
+        }
+        Some(members)
+    }
+}
+
+impl fmt::Display for ImageSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{{{} | {} ∈ ℤ}}", self.expression, self.parameter.name())
+    }
+}
+
+/// `{variable | condition}`: the real values satisfying `condition`
+///
+/// Used for equations that could not be solved in closed form.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConditionSet {
+    pub variable: Symbol,
+    pub condition: Expression,
+}
+
+impl ConditionSet {
+    pub fn new(variable: Symbol, condition: Expression) -> Self {
+        Self {
+            variable,
+            condition,
+        }
+    }
+
+    /// Whether the condition holds at `value`, if it can be evaluated
+    pub fn contains(&self, value: &Expression) -> Option<bool> {
+        let substitutions = HashMap::from([(self.variable.name().to_owned(), value.clone())]);
+        let (left, right, relation_type) = match &self.condition {
+            Expression::Relation(data) => (&data.left, &data.right, data.relation_type),
+            other => (other, &Expression::integer(0), RelationType::Equal),
+        };
+        let left = real_value(&left.substitute(&substitutions))?;
+        let right = real_value(&right.substitute(&substitutions))?;
+        let equal = (left - right).abs() <= MEMBERSHIP_TOLERANCE * left.abs().max(1.0);
+        match relation_type {
+            RelationType::Equal => Some(equal),
+            RelationType::NotEqual => Some(!equal),
+            RelationType::Less => Some(left < right && !equal),
+            RelationType::LessEqual => Some(left < right || equal),
+            RelationType::Greater => Some(left > right && !equal),
+            RelationType::GreaterEqual => Some(left > right || equal),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ConditionSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.condition {
+            Expression::Relation(_) => {
+                write!(f, "{{{} | {}}}", self.variable.name(), self.condition)
+            }
+            other => write!(f, "{{{} | {} = 0}}", self.variable.name(), other),
+        }
+    }
+}
+
+/// Solutions of an equation as a set of real numbers
+///
+/// Unions and intersections are normalized as far as the parts allow;
+/// combinations that cannot be decided, such as an image set intersected
+/// with a condition set, are kept as `Union` or `Intersection`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Solutions {
+    EmptySet,
+    FiniteSet(Vec<Expression>),
+    /// Union of intervals on the real line
+    Interval(SolutionSet),
+    ImageSet(ImageSet),
+    ConditionSet(ConditionSet),
+    Union(Vec<Solutions>),
+    Intersection(Vec<Solutions>),
+}
+
+impl Solutions {
+    /// Solutions described by a [`MathSolver`] result
+    ///
+    /// Case splits on parameters become a condition set on `equation`.
+    pub fn from_solver_result(
+        result: &SolverResult,
+        equation: &Expression,
+        variable: &Symbol,
+    ) -> Self {
+        match result {
+            SolverResult::Single(solution) => Solutions::finite(vec![solution.clone()]),
+            SolverResult::Multiple(solutions) => Solutions::finite(solutions.clone()),
+            SolverResult::NoSolution => Solutions::EmptySet,
+            SolverResult::InfiniteSolutions => Solutions::Interval(SolutionSet::reals()),
+            SolverResult::ImageSets(families) => families
+                .iter()
+                .map(|family| Solutions::ImageSet(family.clone()))
+                .fold(Solutions::EmptySet, |acc, family| acc.union(&family)),
+            SolverResult::ConditionSet(condition) => Solutions::ConditionSet(condition.clone()),
+            SolverResult::Cases(_) => {
+                Solutions::ConditionSet(ConditionSet::new(variable.clone(), equation.clone()))
+            }
+        }
+    }
+
+    /// Finite set without duplicates; empty input gives `EmptySet`
+    pub fn finite(members: Vec<Expression>) -> Self {
+        let mut unique: Vec<Expression> = Vec::with_capacity(members.len());
+        for member in members {
+            if !unique.contains(&member) {
+                unique.push(member);
+            }
+        }
+        if unique.is_empty() {
+            Solutions::EmptySet
+        } else {
+            Solutions::FiniteSet(unique)
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        matches!(self, Solutions::EmptySet)
+    }
+
+    /// Whether `value` belongs to the set, if that can be decided
+    pub fn contains(&self, value: &Expression) -> Option<bool> {
+        let numeric = real_value(value);
+        match self {
+            Solutions::EmptySet => Some(false),
+            Solutions::FiniteSet(members) => {
+                if members.contains(value) {
+                    return Some(true);
+                }
+                let numeric = numeric?;
+                let mut decided = true;
+                for member in members {
+                    match real_value(member) {
+                        Some(m)
+                            if (m - numeric).abs() <= MEMBERSHIP_TOLERANCE * m.abs().max(1.0) =>
+                        {
+                            return Some(true)
+                        }
+                        Some(_) => {}
+                        None => decided = false,
+                    }
+                }
+                decided.then_some(false)
+            }
+            Solutions::Interval(set) => numeric.map(|v| set.contains(v)),
+            Solutions::ImageSet(image) => image.contains(numeric?),
+            Solutions::ConditionSet(condition) => condition.contains(value),
+            Solutions::Union(parts) => {
+                let answers: Vec<_> = parts.iter().map(|part| part.contains(value)).collect();
+                if answers.contains(&Some(true)) {
+                    Some(true)
+                } else {
+                    answers.iter().all(|a| *a == Some(false)).then_some(false)
+                }
+            }
+            Solutions::Intersection(parts) => {
+                let answers: Vec<_> = parts.iter().map(|part| part.contains(value)).collect();
+                if answers.contains(&Some(false)) {
+                    Some(false)
+                } else {
+                    answers.iter().all(|a| *a == Some(true)).then_some(true)
+                }
+            }
+        }
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut members = Vec::new();
+        let mut intervals: Option<SolutionSet> = None;
+        let mut rest: Vec<Solutions> = Vec::new();
+        for part in self.parts().into_iter().chain(other.parts()) {
+            match part {
+                Solutions::EmptySet => {}
+                Solutions::FiniteSet(found) => members.extend(found),
+                Solutions::Interval(set) => {
+                    intervals = Some(match intervals {
+                        Some(existing) => existing.union(&set),
+                        None => set,
+                    })
+                }
+                part if !rest.contains(&part) => rest.push(part),
+                _ => {}
+            }
+        }
+
+        // Numeric points join the intervals so `{1} ∪ (1, 2)` is `[1, 2)`
+        if let Some(set) = intervals.as_mut() {
+            members.retain(
+                |member| match SolutionSet::from_points(std::slice::from_ref(member)) {
+                    Some(point) => {
+                        *set = set.union(&point);
+                        false
+                    }
+                    None => true,
+                },
+            );
+        }
+
+        let mut parts = Vec::with_capacity(rest.len() + 2);
+        if let finite @ Solutions::FiniteSet(_) = Solutions::finite(members) {
+            parts.push(finite);
+        }
+        if let Some(set) = intervals.filter(|set| !set.is_empty()) {
+            parts.push(Solutions::Interval(set));
+        }
+        parts.extend(rest);
+        Self::collect(parts, Solutions::Union)
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        match (self, other) {
+            (Solutions::EmptySet, _) | (_, Solutions::EmptySet) => Solutions::EmptySet,
+            (Solutions::Union(parts), set) | (set, Solutions::Union(parts)) => parts
+                .iter()
+                .map(|part| part.intersection(set))
+                .fold(Solutions::EmptySet, |acc, part| acc.union(&part)),
+            (Solutions::FiniteSet(members), set) | (set, Solutions::FiniteSet(members)) => {
+                let mut kept = Vec::new();
+                let mut undecided = Vec::new();
+                for member in members {
+                    match set.contains(member) {
+                        Some(true) => kept.push(member.clone()),
+                        Some(false) => {}
+                        None => undecided.push(member.clone()),
+                    }
+                }
+                let kept = Solutions::finite(kept);
+                if undecided.is_empty() {
+                    kept
+                } else {
+                    kept.union(&Solutions::Intersection(vec![
+                        Solutions::FiniteSet(undecided),
+                        set.clone(),
+                    ]))
+                }
+            }
+            (Solutions::Interval(a), Solutions::Interval(b)) => {
+                let both = a.intersection(b);
+                if both.is_empty() {
+                    Solutions::EmptySet
+                } else {
+                    Solutions::Interval(both)
+                }
+            }
+            (Solutions::ImageSet(image), Solutions::Interval(set))
+            | (Solutions::Interval(set), Solutions::ImageSet(image)) => {
+                match image.members_within(set) {
+                    Some(members) => Solutions::finite(members),
+                    None => Solutions::Intersection(vec![self.clone(), other.clone()]),
+                }
+            }
+            _ if self == other => self.clone(),
+            _ => Self::collect(
+                self.factors().into_iter().chain(other.factors()).collect(),
+                Solutions::Intersection,
+            ),
+        }
+    }
+
+    /// Operands of a union, or the set itself
+    fn parts(&self) -> Vec<Solutions> {
+        match self {
+            Solutions::Union(parts) => parts.clone(),
+            other => vec![other.clone()],
+        }
+    }
+
+    /// Operands of an intersection, or the set itself
+    fn factors(&self) -> Vec<Solutions> {
+        match self {
+            Solutions::Intersection(parts) => parts.clone(),
+            other => vec![other.clone()],
+        }
+    }
+
+    fn collect(mut parts: Vec<Solutions>, combine: fn(Vec<Solutions>) -> Solutions) -> Self {
+        match parts.len() {
+            0 => Solutions::EmptySet,
+            1 => parts.remove(0),
+            _ => combine(parts),
+        }
+    }
+}
+
+impl fmt::Display for Solutions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let join = |parts: &[Solutions], separator: &str| {
+            parts
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(separator)
+        };
+        match self {
+            Solutions::EmptySet => write!(f, "∅"),
+            Solutions::FiniteSet(members) => {
+                let members: Vec<String> = members.iter().map(ToString::to_string).collect();
+                write!(f, "{{{}}}", members.join(", "))
+            }
+            Solutions::Interval(set) => write!(f, "{}", set),
+            Solutions::ImageSet(image) => write!(f, "{}", image),
+            Solutions::ConditionSet(condition) => write!(f, "{}", condition),
+            Solutions::Union(parts) => write!(f, "{}", join(parts, " ∪ ")),
+            Solutions::Intersection(parts) => write!(f, "{}", join(parts, " ∩ ")),
+        }
+    }
+}
+
+/// All real solutions of an equation or inequality in `variable`
+///
+/// `sin`, `cos` and `tan` of a linear argument equal to a constant have
+/// their general solutions as image sets. Inequalities are solved with
+/// [`solve_set`](super::solve_set). Other equations go through the equation
+/// solver; those it cannot solve, and those with no closed form, are
+/// returned as a condition set rather than reported as having no solution.
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::algebra::solvers::{solve_general, Solutions};
+/// use mathhook_core::{expr, symbol, Expression};
+///
+/// let x = symbol!(x);
+/// let peaks = solve_general(&Expression::equation(expr!(cos(x)), expr!(1)), &x);
+/// let two_pi = Expression::float(2.0 * std::f64::consts::PI);
+/// assert_eq!(peaks.contains(&two_pi), Some(true));
+/// assert_eq!(peaks.contains(&Expression::pi()), Some(false));
+///
+/// let fixed_point = solve_general(&Expression::equation(expr!(cos(x)), expr!(x)), &x);
+/// assert!(matches!(fixed_point, Solutions::ConditionSet(_)));
+/// ```
+pub fn solve_general(equation: &Expression, variable: &Symbol) -> Solutions {
+    match equation {
+        Expression::Relation(data) if data.relation_type != RelationType::Equal => {
+            super::solve_set(equation, variable)
+                .map(|set| {
+                    if set.is_empty() {
+                        Solutions::EmptySet
+                    } else {
+                        Solutions::Interval(set)
+                    }
+                })
+                .unwrap_or_else(|| {
+                    Solutions::ConditionSet(ConditionSet::new(variable.clone(), equation.clone()))
+                })
+        }
+        _ => Solutions::from_solver_result(
+            &MathSolver::new().solve(equation, variable),
+            equation,
+            variable,
+        ),
+    }
+}
+
+/// General solution of `k·f(a·x + b) + c = 0` for `f` in sin, cos, tan,
+/// one image set per family; empty when there is no solution
+pub(crate) fn general_solutions(
+    difference: &Expression,
+    variable: &Symbol,
+) -> Option<Vec<ImageSet>> {
+    let terms = match difference {
+        Expression::Add(terms) => terms.as_slice(),
+        other => std::slice::from_ref(other),
+    };
+    let (varying, constant): (Vec<&Expression>, Vec<&Expression>) = terms
+        .iter()
+        .partition(|term| term.contains_variable(variable));
+    let [term] = varying.as_slice() else {
+        return None;
+    };
+    let (scale, function) = match term {
+        Expression::Mul(factors) => {
+            let (scale, varying): (Vec<Expression>, Vec<Expression>) = factors
+                .iter()
+                .cloned()
+                .partition(|factor| !factor.contains_variable(variable));
+            let [function] = <[Expression; 1]>::try_from(varying).ok()?;
+            (Expression::mul(scale), function)
+        }
+        other => (Expression::integer(1), (*other).clone()),
+    };
+    let Expression::Function { name, args, .. } = &function else {
+        return None;
+    };
+    let [argument] = args.as_slice() else {
+        return None;
+    };
+    let (slope, offset) = linear_coefficients(argument, variable)?;
+
+    // f(u) = value
+    let value = Expression::div(
+        Expression::mul(vec![
+            Expression::integer(-1),
+            Expression::add(constant.into_iter().cloned().collect()),
+        ]),
+        scale,
+    )
+    .simplify();
+    let numeric = real_value(&value);
+    let is = |target: f64| numeric.is_some_and(|v| v == target);
+    let pi = Expression::pi;
+    let two_pi = || Expression::mul(vec![Expression::integer(2), Expression::pi()]);
+    let half_pi =
+        |sign: i64| Expression::mul(vec![Expression::rational(sign, 2), Expression::pi()]);
+
+    let families: Vec<(Expression, Expression)> = match name.as_ref() {
+        "sin" | "cos" if numeric.is_some_and(|v| v.abs() > 1.0) => return Some(Vec::new()),
+        "sin" if is(0.0) => vec![(Expression::integer(0), pi())],
+        "sin" if is(1.0) => vec![(half_pi(1), two_pi())],
+        "sin" if is(-1.0) => vec![(half_pi(-1), two_pi())],
+        "sin" => {
+            let principal = Expression::function("arcsin", vec![value]);
+            vec![
+                (principal.clone(), two_pi()),
+                (
+                    Expression::add(vec![
+                        pi(),
+                        Expression::mul(vec![Expression::integer(-1), principal]),
+                    ]),
+                    two_pi(),
+                ),
+            ]
+        }
+        "cos" if is(0.0) => vec![(half_pi(1), pi())],
+        "cos" if is(1.0) => vec![(Expression::integer(0), two_pi())],
+        "cos" if is(-1.0) => vec![(pi(), two_pi())],
+        "cos" => {
+            let principal = Expression::function("arccos", vec![value]);
+            vec![
+                (principal.clone(), two_pi()),
+                (
+                    Expression::mul(vec![Expression::integer(-1), principal]),
+                    two_pi(),
+                ),
+            ]
+        }
+        "tan" => vec![(Expression::function("arctan", vec![value]), pi())],
+        _ => return None,
+    };
+
+    // a·x + b = θ + p·n  ⟹  x = (θ - b)/a + (p/a)·n
+    let solve_for_variable = |theta: Expression| {
+        Expression::div(
+            Expression::add(vec![
+                theta,
+                Expression::mul(vec![Expression::integer(-1), offset.clone()]),
+            ]),
+            slope.clone(),
+        )
+        .simplify()
+    };
+    let per_slope = |period: Expression| Expression::div(period, slope.clone()).simplify();
+    Some(
+        families
+            .into_iter()
+            .map(|(theta, period)| ImageSet::periodic(solve_for_variable(theta), per_slope(period)))
+            .collect(),
+    )
+}
+
+/// Value of a closed-form real number such as `π/2`
+fn real_value(expr: &Expression) -> Option<f64> {
+    let value = expr.eval_complex().ok()?;
+    (value.im.abs() <= MEMBERSHIP_TOLERANCE * value.re.abs().max(1.0)).then_some(value.re)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{expr, symbol};
+
+    fn interval(start: i64, end: i64) -> Solutions {
+        Solutions::Interval(
+            SolutionSet::interval(
+                Expression::integer(start),
+                Expression::integer(end),
+                true,
+                true,
+            )
+            .unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_trigonometric_general_solutions() {
+        let x = symbol!(x);
+        let solve = |left: Expression, right: Expression| {
+            solve_general(&Expression::equation(left, right), &x)
+        };
+
+        let sine = solve(expr!(sin(x)), expr!(1));
+        assert!(matches!(sine, Solutions::ImageSet(_)));
+        assert_eq!(
+            sine.contains(&Expression::float(2.5 * std::f64::consts::PI)),
+            Some(true)
+        );
+        assert_eq!(sine.contains(&Expression::float(0.0)), Some(false));
+
+        let half = solve(expr!(sin(x)), Expression::rational(1, 2));
+        let Solutions::Union(families) = &half else {
+            panic!("expected two families, got {half}");
+        };
+        assert_eq!(families.len(), 2);
+        let five_sixths = Expression::float(5.0 * std::f64::consts::PI / 6.0);
+        assert_eq!(half.contains(&five_sixths), Some(true));
+
+        // tan(2x) = 0 has period π/2
+        let tangent = solve(expr!(tan(2 * x)), expr!(0));
+        assert_eq!(
+            tangent.contains(&Expression::float(std::f64::consts::FRAC_PI_2)),
+            Some(true)
+        );
+        assert_eq!(tangent.contains(&Expression::float(1.0)), Some(false));
+
+        assert!(solve(expr!(cos(x)), expr!(2)).is_empty());
+    }
+
+    #[test]
+    fn test_parameter_does_not_capture_symbols() {
+        let x = symbol!(x);
+        let n = symbol!(n);
+        let zeros = solve_general(&Expression::equation(expr!(sin(n * x)), expr!(0)), &x);
+        let Solutions::ImageSet(family) = &zeros else {
+            panic!("expected an image set, got {zeros}");
+        };
+        assert_ne!(family.parameter.name(), "n");
+        assert!(family.expression.contains_variable(&n));
+        assert_eq!(family.member(1), expr!(pi / n).simplify());
+
+        let nk = Expression::mul(vec![Expression::symbol(n), Expression::symbol(symbol!(k))]);
+        let shifted = ImageSet::periodic(nk, Expression::pi());
+        assert_eq!(shifted.parameter.name(), "m");
+    }
+
+    #[test]
+    fn test_algebraic_and_unsolved_equations() {
+        let x = symbol!(x);
+        let quadratic = solve_general(&Expression::equation(expr!(x ^ 2), expr!(4)), &x);
+        assert_eq!(quadratic.contains(&expr!(2)), Some(true));
+        assert_eq!(quadratic.contains(&expr!(-2)), Some(true));
+        assert_eq!(quadratic.contains(&expr!(3)), Some(false));
+
+        let kepler = Expression::equation(expr!(x - sin(x)), expr!(1));
+        let unsolved = solve_general(&kepler, &x);
+        assert_eq!(
+            unsolved,
+            Solutions::ConditionSet(ConditionSet::new(x.clone(), kepler))
+        );
+        assert_eq!(unsolved.contains(&expr!(0)), Some(false));
+    }
+
+    #[test]
+    fn test_union_normalizes() {
+        let points = Solutions::finite(vec![expr!(1), expr!(5), expr!(1)]);
+        assert_eq!(points.to_string(), "{1, 5}");
+        assert_eq!(points.union(&Solutions::EmptySet), points);
+
+        let merged = points.union(&interval(0, 2));
+        assert_eq!(merged.to_string(), "[0, 2] ∪ {5}");
+
+        let symbolic = Solutions::finite(vec![expr!(a)]);
+        assert_eq!(symbolic.union(&symbolic), symbolic);
+    }
+
+    #[test]
+    fn test_intersection() {
+        let zeros =
+            Solutions::ImageSet(ImageSet::periodic(Expression::integer(0), Expression::pi()));
+        let window = interval(-4, 4);
+        let inside = zeros.intersection(&window);
+        let Solutions::FiniteSet(members) = &inside else {
+            panic!("expected a finite set, got {inside}");
+        };
+        assert_eq!(members.len(), 3);
+        assert_eq!(inside.contains(&Expression::pi()), Some(true));
+
+        let points = Solutions::finite(vec![expr!(0), expr!(1)]);
+        assert_eq!(
+            points.intersection(&zeros),
+            Solutions::finite(vec![expr!(0)])
+        );
+
+        assert!(interval(0, 1).intersection(&interval(2, 3)).is_empty());
+
+        let x = symbol!(x);
+        let condition = Solutions::ConditionSet(ConditionSet::new(x, expr!(x - cos(x))));
+        let both = condition.intersection(&zeros);
+        assert!(matches!(both, Solutions::Intersection(ref parts) if parts.len() == 2));
+        assert_eq!(both.contains(&expr!(1)), Some(false));
+    }
+}
@@ -0,0 +1,759 @@
+//! Solution sets on the real line
+//!
+//! A [`SolutionSet`] is a finite union of disjoint intervals, with isolated
+//! solutions stored as degenerate closed intervals `[a, a]`. Endpoints keep
+//! their exact expressions, so `x^2 > 1` solves to `(-∞, -1) ∪ (1, ∞)`, and
+//! are ordered through their numeric values. Sets support union,
+//! intersection and complement, which lets constraints from several
+//! equations and inequalities be combined:
+//!
+//! ```rust
+//! use mathhook_core::algebra::solvers::{solve_set, SolutionSet};
+//! use mathhook_core::core::expression::RelationType;
+//! use mathhook_core::{expr, symbol, Expression};
+//!
+//! let x = symbol!(x);
+//! let outside = solve_set(
+//!     &Expression::relation(expr!(x ^ 2), expr!(1), RelationType::Greater),
+//!     &x,
+//! )
+//! .unwrap();
+//! let below = solve_set(&Expression::relation(expr!(x), expr!(3), RelationType::Less), &x).unwrap();
+//!
+//! let both = outside.intersection(&below);
+//! assert_eq!(both.to_string(), "(-∞, -1) ∪ (1, 3)");
+//! assert!(both.contains(2.0) && !both.contains(0.0) && !both.contains(3.0));
+//! ```
+
+use super::SolverResult;
+use crate::algebra::equation_analyzer::SmartEquationSolver;
+use crate::algebra::expand::Expand;
+use crate::calculus::integrals::rational::helpers::is_polynomial;
+use crate::core::expression::RelationType;
+use crate::core::symbol::SymbolAssumptions;
+use crate::core::{Expression, Number, Symbol};
+use crate::simplify::Simplify;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt;
+
+/// Relative tolerance under which two endpoints are the same number
+const ENDPOINT_TOLERANCE: f64 = 1e-12;
+
+/// Finite endpoint of an interval
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Endpoint {
+    pub value: Expression,
+    pub numeric: f64,
+    pub closed: bool,
+}
+
+impl Endpoint {
+    /// Endpoint at `value`, if it evaluates to a finite real number
+    pub fn new(value: Expression, closed: bool) -> Option<Self> {
+        let numeric = value.evaluate_to_f64().ok().filter(|v| v.is_finite())?;
+        Some(Self {
+            value,
+            numeric,
+            closed,
+        })
+    }
+
+    fn with_closed(&self, closed: bool) -> Self {
+        Self {
+            closed,
+            ..self.clone()
+        }
+    }
+}
+
+/// Interval of real numbers; a missing endpoint is infinite
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Interval {
+    pub lower: Option<Endpoint>,
+    pub upper: Option<Endpoint>,
+}
+
+impl Interval {
+    pub fn contains(&self, value: f64) -> bool {
+        let above = self
+            .lower
+            .as_ref()
+            .is_none_or(|lower| match compare(lower.numeric, value) {
+                Ordering::Less => true,
+                Ordering::Equal => lower.closed,
+                Ordering::Greater => false,
+            });
+        let below = self
+            .upper
+            .as_ref()
+            .is_none_or(|upper| match compare(value, upper.numeric) {
+                Ordering::Less => true,
+                Ordering::Equal => upper.closed,
+                Ordering::Greater => false,
+            });
+        above && below
+    }
+
+    /// The single point of a degenerate interval `[a, a]`
+    pub fn as_point(&self) -> Option<&Expression> {
+        match (&self.lower, &self.upper) {
+            (Some(lower), Some(upper))
+                if compare(lower.numeric, upper.numeric) == Ordering::Equal =>
+            {
+                Some(&lower.value)
+            }
+            _ => None,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        match (&self.lower, &self.upper) {
+            (Some(lower), Some(upper)) => match compare(lower.numeric, upper.numeric) {
+                Ordering::Less => false,
+                Ordering::Equal => !(lower.closed && upper.closed),
+                Ordering::Greater => true,
+            },
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(point) = self.as_point() {
+            return write!(f, "{{{}}}", point);
+        }
+        match &self.lower {
+            Some(lower) => write!(f, "{}{}", if lower.closed { "[" } else { "(" }, lower.value)?,
+            None => write!(f, "(-∞")?,
+        }
+        match &self.upper {
+            Some(upper) => write!(
+                f,
+                ", {}{}",
+                upper.value,
+                if upper.closed { "]" } else { ")" }
+            ),
+            None => write!(f, ", ∞)"),
+        }
+    }
+}
+
+/// Subset of the real line, kept as sorted, disjoint, non-touching intervals
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SolutionSet {
+    intervals: Vec<Interval>,
+}
+
+impl SolutionSet {
+    pub fn empty() -> Self {
+        Self::default()
+    }
+
+    /// Every real number
+    pub fn reals() -> Self {
+        Self {
+            intervals: vec![Interval {
+                lower: None,
+                upper: None,
+            }],
+        }
+    }
+
+    /// Real values allowed by the assumptions declared on a symbol
+    ///
+    /// Only sign assumptions restrict the set; `integer` cannot be
+    /// represented by intervals and is ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::algebra::solvers::SolutionSet;
+    /// use mathhook_core::core::symbol::SymbolAssumptions;
+    ///
+    /// let positive = SolutionSet::domain(SymbolAssumptions::new().positive());
+    /// assert_eq!(positive.to_string(), "(0, ∞)");
+    /// let nonzero = SolutionSet::domain(SymbolAssumptions::new().nonzero());
+    /// assert_eq!(nonzero.to_string(), "(-∞, 0) ∪ (0, ∞)");
+    /// ```
+    pub fn domain(assumptions: SymbolAssumptions) -> Self {
+        let zero = Expression::integer(0);
+        if assumptions.is_nonnegative() {
+            Self::above(zero, !assumptions.is_nonzero()).unwrap_or_else(Self::reals)
+        } else if assumptions.is_nonzero() {
+            Self::from_points(&[zero]).map_or_else(Self::reals, |origin| origin.complement())
+        } else {
+            Self::reals()
+        }
+    }
+
+    /// Interval between two finite endpoints, if both evaluate to real numbers
+    pub fn interval(
+        start: Expression,
+        end: Expression,
+        start_inclusive: bool,
+        end_inclusive: bool,
+    ) -> Option<Self> {
+        Some(Self::from_intervals(vec![Interval {
+            lower: Some(Endpoint::new(start, start_inclusive)?),
+            upper: Some(Endpoint::new(end, end_inclusive)?),
+        }]))
+    }
+
+    /// `(value, ∞)`, or `[value, ∞)` when inclusive
+    pub fn above(value: Expression, inclusive: bool) -> Option<Self> {
+        Some(Self::from_intervals(vec![Interval {
+            lower: Some(Endpoint::new(value, inclusive)?),
+            upper: None,
+        }]))
+    }
+
+    /// `(-∞, value)`, or `(-∞, value]` when inclusive
+    pub fn below(value: Expression, inclusive: bool) -> Option<Self> {
+        Some(Self::from_intervals(vec![Interval {
+            lower: None,
+            upper: Some(Endpoint::new(value, inclusive)?),
+        }]))
+    }
+
+    /// Finite set of real values
+    ///
+    /// Returns `None` if some value does not evaluate to a real number.
+    pub fn from_points(values: &[Expression]) -> Option<Self> {
+        let intervals = values
+            .iter()
+            .map(|value| {
+                let endpoint = Endpoint::new(value.clone(), true)?;
+                Some(Interval {
+                    lower: Some(endpoint.clone()),
+                    upper: Some(endpoint),
+                })
+            })
+            .collect::<Option<Vec<_>>>()?;
+        Some(Self::from_intervals(intervals))
+    }
+
+    /// Real solutions of an equation solver result
+    ///
+    /// Numeric non-real solutions are dropped. Returns `None` for symbolic
+    /// solutions, which cannot be placed on the real line, and for
+    /// parametric, partial and case-split results.
+    pub fn from_solver_result(result: &SolverResult) -> Option<Self> {
+        let solutions: &[Expression] = match result {
+            SolverResult::Single(solution) => std::slice::from_ref(solution),
+            SolverResult::Multiple(solutions) => solutions,
+            SolverResult::NoSolution => return Some(Self::empty()),
+            SolverResult::InfiniteSolutions => return Some(Self::reals()),
+            SolverResult::Parametric(_) | SolverResult::Partial(_) | SolverResult::Cases(_) => {
+                return None
+            }
+        };
+
+        let mut real = Vec::with_capacity(solutions.len());
+        for solution in solutions {
+            match solution.evaluate_to_f64() {
+                Ok(value) if value.is_finite() => real.push(solution.clone()),
+                _ if solution.find_variables().is_empty() => {}
+                _ => return None,
+            }
+        }
+        Self::from_points(&real)
+    }
+
+    /// Normalize arbitrary intervals: drop empty ones, sort and merge
+    fn from_intervals(mut intervals: Vec<Interval>) -> Self {
+        intervals.retain(|interval| !interval.is_empty());
+        intervals.sort_by(|a, b| compare_lower(&a.lower, &b.lower));
+
+        let mut merged: Vec<Interval> = Vec::with_capacity(intervals.len());
+        for interval in intervals {
+            if let Some(last) = merged.last_mut() {
+                if touches(&last.upper, &interval.lower) {
+                    if compare_upper(&interval.upper, &last.upper) == Ordering::Greater {
+                        last.upper = interval.upper;
+                    }
+                    continue;
+                }
+            }
+            merged.push(interval);
+        }
+        Self { intervals: merged }
+    }
+
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    pub fn contains(&self, value: f64) -> bool {
+        self.intervals
+            .iter()
+            .any(|interval| interval.contains(value))
+    }
+
+    /// The members of a finite set, or `None` if it contains an interval
+    pub fn points(&self) -> Option<Vec<Expression>> {
+        self.intervals
+            .iter()
+            .map(|interval| interval.as_point().cloned())
+            .collect()
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        Self::from_intervals(
+            self.intervals
+                .iter()
+                .chain(&other.intervals)
+                .cloned()
+                .collect(),
+        )
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut pieces = Vec::new();
+        for a in &self.intervals {
+            for b in &other.intervals {
+                let lower = match compare_lower(&a.lower, &b.lower) {
+                    Ordering::Less => &b.lower,
+                    _ => &a.lower,
+                };
+                let upper = match compare_upper(&a.upper, &b.upper) {
+                    Ordering::Greater => &b.upper,
+                    _ => &a.upper,
+                };
+                pieces.push(Interval {
+                    lower: lower.clone(),
+                    upper: upper.clone(),
+                });
+            }
+        }
+        Self::from_intervals(pieces)
+    }
+
+    /// Real numbers not in the set
+    pub fn complement(&self) -> Self {
+        self.intervals
+            .iter()
+            .fold(Self::reals(), |outside, interval| {
+                let mut around = Vec::with_capacity(2);
+                if let Some(lower) = &interval.lower {
+                    around.push(Interval {
+                        lower: None,
+                        upper: Some(lower.with_closed(!lower.closed)),
+                    });
+                }
+                if let Some(upper) = &interval.upper {
+                    around.push(Interval {
+                        lower: Some(upper.with_closed(!upper.closed)),
+                        upper: None,
+                    });
+                }
+                outside.intersection(&Self::from_intervals(around))
+            })
+    }
+
+    /// `self \ other`
+    pub fn difference(&self, other: &Self) -> Self {
+        self.intersection(&other.complement())
+    }
+
+    /// Finite sets as individual solutions, infinite ones as
+    /// `InfiniteSolutions`
+    pub fn to_solver_result(&self) -> SolverResult {
+        match self.points() {
+            Some(points) if points.is_empty() => SolverResult::NoSolution,
+            Some(mut points) if points.len() == 1 => SolverResult::Single(points.remove(0)),
+            Some(points) => SolverResult::Multiple(points),
+            None => SolverResult::InfiniteSolutions,
+        }
+    }
+}
+
+impl fmt::Display for SolutionSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.intervals.is_empty() {
+            return write!(f, "∅");
+        }
+        if *self == Self::reals() {
+            return write!(f, "ℝ");
+        }
+        let parts: Vec<String> = self.intervals.iter().map(ToString::to_string).collect();
+        write!(f, "{}", parts.join(" ∪ "))
+    }
+}
+
+/// Real solutions of a polynomial or rational equation or inequality in
+/// `variable`
+///
+/// `relation` is an `Expression::Relation` comparing with `=`, `≠`, `<`,
+/// `≤`, `>` or `≥`; any other expression is solved as `expr = 0`. The
+/// difference of the two sides is brought over a common denominator
+/// `p / q`. The real roots of `p` and the poles, the real roots of `q`,
+/// split the line into intervals on which the sign is constant, so one test
+/// point per interval decides it. Poles are never solutions. Sign
+/// assumptions declared on `variable` restrict the result to its domain.
+/// Returns `None` for input that is not a rational function of `variable`,
+/// for other free variables, and when the roots are not all found.
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::algebra::solvers::solve_set;
+/// use mathhook_core::core::expression::RelationType;
+/// use mathhook_core::{expr, symbol, Expression};
+///
+/// let x = symbol!(x);
+/// let quadratic = Expression::relation(expr!((x ^ 2) - (4 * x)), expr!(-3), RelationType::LessEqual);
+///
+/// let set = solve_set(&quadratic, &x).unwrap();
+/// assert_eq!(set.to_string(), "[1, 3]");
+/// assert_eq!(set.complement().to_string(), "(-∞, 1) ∪ (3, ∞)");
+///
+/// let ratio = Expression::relation(
+///     Expression::div(expr!(x + 1), expr!(x - 2)),
+///     expr!(0),
+///     RelationType::GreaterEqual,
+/// );
+/// assert_eq!(solve_set(&ratio, &x).unwrap().to_string(), "(-∞, -1] ∪ (2, ∞)");
+/// ```
+pub fn solve_set(relation: &Expression, variable: &Symbol) -> Option<SolutionSet> {
+    let (difference, relation_type) = match relation {
+        Expression::Relation(data) => (
+            Expression::add(vec![
+                data.left.clone(),
+                Expression::mul(vec![Expression::integer(-1), data.right.clone()]),
+            ]),
+            data.relation_type,
+        ),
+        other => (other.clone(), RelationType::Equal),
+    };
+
+    let (strict, closed): (fn(f64) -> bool, bool) = match relation_type {
+        RelationType::Equal => (|_| false, true),
+        RelationType::NotEqual => (|_| true, false),
+        RelationType::Less => (|sign| sign < 0.0, false),
+        RelationType::LessEqual => (|sign| sign < 0.0, true),
+        RelationType::Greater => (|sign| sign > 0.0, false),
+        RelationType::GreaterEqual => (|sign| sign > 0.0, true),
+        _ => return None,
+    };
+    let (numerator, denominator) = rational_parts(&difference, variable)?;
+    let numerator = numerator.expand().simplify();
+    let denominator = denominator.expand().simplify();
+    if !is_polynomial(&numerator, variable) || !is_polynomial(&denominator, variable) {
+        return None;
+    }
+
+    let poles = real_roots(&denominator, variable)?;
+    let domain = SolutionSet::domain(variable.assumptions()).difference(&poles);
+    if numerator.is_zero() {
+        return Some(if closed { domain } else { SolutionSet::empty() });
+    }
+    let roots = real_roots(&numerator, variable)?;
+
+    let sign_at = |point: f64| {
+        let substitutions = HashMap::from([(variable.name().to_owned(), Expression::float(point))]);
+        let value = |part: &Expression| {
+            part.substitute(&substitutions)
+                .evaluate_to_f64()
+                .ok()
+                .filter(|value| value.is_finite())
+        };
+        Some(value(&numerator)? / value(&denominator)?)
+    };
+
+    // One test point inside each interval between consecutive critical points
+    let endpoints: Vec<Endpoint> = roots
+        .union(&poles)
+        .intervals()
+        .iter()
+        .filter_map(|point| point.lower.as_ref().map(|lower| lower.with_closed(false)))
+        .collect();
+    let mut pieces = Vec::with_capacity(endpoints.len() * 2 + 1);
+    for index in 0..=endpoints.len() {
+        let lower = index.checked_sub(1).map(|i| endpoints[i].clone());
+        let upper = endpoints.get(index).cloned();
+        let test_point = match (&lower, &upper) {
+            (Some(lower), Some(upper)) => (lower.numeric + upper.numeric) / 2.0,
+            (Some(lower), None) => lower.numeric + 1.0,
+            (None, Some(upper)) => upper.numeric - 1.0,
+            (None, None) => 0.0,
+        };
+        if strict(sign_at(test_point)?) {
+            pieces.push(Interval { lower, upper });
+        }
+    }
+    let mut solutions = SolutionSet::from_intervals(pieces);
+    if closed {
+        solutions = solutions.union(&roots);
+    }
+    Some(solutions.intersection(&domain))
+}
+
+/// Real roots of a polynomial in `variable`, as a finite set
+///
+/// Constant polynomials have no roots here; the zero polynomial is handled
+/// by the caller.
+fn real_roots(polynomial: &Expression, variable: &Symbol) -> Option<SolutionSet> {
+    if !polynomial.contains_variable(variable) {
+        return Some(SolutionSet::empty());
+    }
+    let (result, _) = SmartEquationSolver::new().solve_with_equation(polynomial, variable);
+    // Only the zero polynomial vanishes on a whole interval
+    let roots = SolutionSet::from_solver_result(&result)?;
+    roots.points().map(|_| roots)
+}
+
+/// Split `expr` into a numerator and denominator over a common denominator
+///
+/// Only integer powers of subexpressions involving `variable` are allowed;
+/// everything else free of `variable` is a constant factor.
+fn rational_parts(expr: &Expression, variable: &Symbol) -> Option<(Expression, Expression)> {
+    if !expr.contains_variable(variable) {
+        return Some((expr.clone(), Expression::integer(1)));
+    }
+    match expr {
+        Expression::Add(terms) => terms.iter().try_fold(
+            (Expression::integer(0), Expression::integer(1)),
+            |(numerator, denominator), term| {
+                let (term_numerator, term_denominator) = rational_parts(term, variable)?;
+                Some((
+                    Expression::add(vec![
+                        Expression::mul(vec![numerator, term_denominator.clone()]),
+                        Expression::mul(vec![term_numerator, denominator.clone()]),
+                    ]),
+                    Expression::mul(vec![denominator, term_denominator]),
+                ))
+            },
+        ),
+        Expression::Mul(factors) => factors.iter().try_fold(
+            (Expression::integer(1), Expression::integer(1)),
+            |(numerator, denominator), factor| {
+                let (factor_numerator, factor_denominator) = rational_parts(factor, variable)?;
+                Some((
+                    Expression::mul(vec![numerator, factor_numerator]),
+                    Expression::mul(vec![denominator, factor_denominator]),
+                ))
+            },
+        ),
+        Expression::Pow(base, exponent) => {
+            let Expression::Number(Number::Integer(power)) = exponent.as_ref() else {
+                return None;
+            };
+            let (numerator, denominator) = rational_parts(base, variable)?;
+            let power_of =
+                |part: Expression, power: i64| Expression::pow(part, Expression::integer(power));
+            Some(if *power < 0 {
+                (power_of(denominator, -power), power_of(numerator, -power))
+            } else {
+                (power_of(numerator, *power), power_of(denominator, *power))
+            })
+        }
+        Expression::Symbol(_) => Some((expr.clone(), Expression::integer(1))),
+        _ => None,
+    }
+}
+
+/// Compare two numbers, treating those within rounding error as equal
+fn compare(a: f64, b: f64) -> Ordering {
+    if (a - b).abs() <= ENDPOINT_TOLERANCE * a.abs().max(b.abs()).max(1.0) {
+        Ordering::Equal
+    } else {
+        a.total_cmp(&b)
+    }
+}
+
+/// Order lower endpoints by how far down they reach; `None` is -∞
+fn compare_lower(a: &Option<Endpoint>, b: &Option<Endpoint>) -> Ordering {
+    match (a, b) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Less,
+        (Some(_), None) => Ordering::Greater,
+        (Some(a), Some(b)) => compare(a.numeric, b.numeric).then(b.closed.cmp(&a.closed)),
+    }
+}
+
+/// Order upper endpoints by how far up they reach; `None` is ∞
+fn compare_upper(a: &Option<Endpoint>, b: &Option<Endpoint>) -> Ordering {
+    match (a, b) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Greater,
+        (Some(_), None) => Ordering::Less,
+        (Some(a), Some(b)) => compare(a.numeric, b.numeric).then(a.closed.cmp(&b.closed)),
+    }
+}
+
+/// Whether an interval ending at `upper` overlaps or abuts one starting at
+/// `lower`, given the second does not start before the first
+fn touches(upper: &Option<Endpoint>, lower: &Option<Endpoint>) -> bool {
+    match (upper, lower) {
+        (Some(upper), Some(lower)) => match compare(upper.numeric, lower.numeric) {
+            Ordering::Less => false,
+            Ordering::Equal => upper.closed || lower.closed,
+            Ordering::Greater => true,
+        },
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{expr, symbol};
+
+    fn relation(left: Expression, right: Expression, relation_type: RelationType) -> Expression {
+        Expression::relation(left, right, relation_type)
+    }
+
+    #[test]
+    fn test_union_merges_touching_intervals() {
+        let a = SolutionSet::interval(expr!(0), expr!(1), true, false).unwrap();
+        let b = SolutionSet::interval(expr!(1), expr!(2), true, true).unwrap();
+        assert_eq!(a.union(&b).to_string(), "[0, 2]");
+
+        let open = SolutionSet::interval(expr!(1), expr!(2), false, true).unwrap();
+        assert_eq!(a.union(&open).to_string(), "[0, 1) ∪ (1, 2]");
+        assert_eq!(a.union(&SolutionSet::empty()), a);
+    }
+
+    #[test]
+    fn test_intersection() {
+        let a = SolutionSet::below(expr!(2), true).unwrap();
+        let b = SolutionSet::above(expr!(-1), false).unwrap();
+        assert_eq!(a.intersection(&b).to_string(), "(-1, 2]");
+
+        let points = SolutionSet::from_points(&[expr!(-3), expr!(0), expr!(2)]).unwrap();
+        assert_eq!(
+            points.intersection(&a.intersection(&b)).to_string(),
+            "{0} ∪ {2}"
+        );
+        assert!(a
+            .intersection(&SolutionSet::above(expr!(2), false).unwrap())
+            .is_empty());
+    }
+
+    #[test]
+    fn test_complement() {
+        assert_eq!(SolutionSet::empty().complement(), SolutionSet::reals());
+        assert_eq!(SolutionSet::reals().complement(), SolutionSet::empty());
+
+        let point = SolutionSet::from_points(&[expr!(1)]).unwrap();
+        assert_eq!(point.complement().to_string(), "(-∞, 1) ∪ (1, ∞)");
+        assert_eq!(point.complement().complement(), point);
+
+        let ray = SolutionSet::above(expr!(0), true).unwrap();
+        assert_eq!(ray.complement().to_string(), "(-∞, 0)");
+        assert_eq!(ray.difference(&point).to_string(), "[0, 1) ∪ (1, ∞)");
+    }
+
+    #[test]
+    fn test_solve_polynomial_inequalities() {
+        let x = symbol!(x);
+
+        let greater = relation(expr!(x ^ 2), expr!(1), RelationType::Greater);
+        assert_eq!(
+            solve_set(&greater, &x).unwrap().to_string(),
+            "(-∞, -1) ∪ (1, ∞)"
+        );
+
+        let at_least = relation(expr!(x ^ 2), expr!(1), RelationType::GreaterEqual);
+        assert_eq!(
+            solve_set(&at_least, &x).unwrap().to_string(),
+            "(-∞, -1] ∪ [1, ∞)"
+        );
+
+        let never = relation(expr!((x ^ 2) + 1), expr!(0), RelationType::Less);
+        assert!(solve_set(&never, &x).unwrap().is_empty());
+
+        let not_equal = relation(expr!(2 * x), expr!(4), RelationType::NotEqual);
+        assert_eq!(
+            solve_set(&not_equal, &x).unwrap().to_string(),
+            "(-∞, 2) ∪ (2, ∞)"
+        );
+
+        let equation = expr!((x ^ 2) - 4);
+        assert_eq!(solve_set(&equation, &x).unwrap().to_string(), "{-2} ∪ {2}");
+    }
+
+    #[test]
+    fn test_solve_rational_inequalities() {
+        let x = symbol!(x);
+        let ratio = Expression::div(expr!(x + 1), expr!(x - 2));
+
+        let nonnegative = relation(ratio.clone(), expr!(0), RelationType::GreaterEqual);
+        assert_eq!(
+            solve_set(&nonnegative, &x).unwrap().to_string(),
+            "(-∞, -1] ∪ (2, ∞)"
+        );
+
+        let negative = relation(ratio, expr!(0), RelationType::Less);
+        assert_eq!(solve_set(&negative, &x).unwrap().to_string(), "(-1, 2)");
+
+        // 1/x < 1 on both sides of the pole at 0
+        let reciprocal = relation(
+            Expression::pow(expr!(x), expr!(-1)),
+            expr!(1),
+            RelationType::Less,
+        );
+        assert_eq!(
+            solve_set(&reciprocal, &x).unwrap().to_string(),
+            "(-∞, 0) ∪ (1, ∞)"
+        );
+
+        // (x^2 - 1)/(x - 1) = 0 only at -1 because 1 is a pole
+        let removable = Expression::div(expr!((x ^ 2) - 1), expr!(x - 1));
+        let at_pole = relation(removable, expr!(0), RelationType::Equal);
+        assert_eq!(solve_set(&at_pole, &x).unwrap().to_string(), "{-1}");
+    }
+
+    #[test]
+    fn test_solve_set_rejects_unsupported() {
+        let x = symbol!(x);
+        assert!(solve_set(&expr!(sin(x)), &x).is_none());
+        assert!(solve_set(&relation(expr!(x), expr!(y), RelationType::Less), &x).is_none());
+    }
+
+    #[test]
+    fn test_solve_set_restricted_to_domain() {
+        use crate::core::symbol::Assumption;
+
+        let x = Symbol::new_with_assumptions("x", &[Assumption::Positive]);
+        let quadratic = Expression::relation(
+            Expression::pow(Expression::symbol(x.clone()), expr!(2)),
+            expr!(4),
+            RelationType::GreaterEqual,
+        );
+        assert_eq!(solve_set(&quadratic, &x).unwrap().to_string(), "[2, ∞)");
+
+        let nonzero = Symbol::new_with_assumptions("t", &[Assumption::Nonzero]);
+        let identity = Expression::equation(
+            Expression::symbol(nonzero.clone()),
+            Expression::symbol(nonzero.clone()),
+        );
+        assert_eq!(
+            solve_set(&identity, &nonzero).unwrap(),
+            SolutionSet::domain(nonzero.assumptions())
+        );
+    }
+
+    #[test]
+    fn test_solver_result_round_trip() {
+        let set =
+            SolutionSet::from_solver_result(&SolverResult::Multiple(vec![expr!(3), expr!(-1)]))
+                .unwrap();
+        assert_eq!(
+            set.to_solver_result(),
+            SolverResult::Multiple(vec![expr!(-1), expr!(3)])
+        );
+        assert!(SolutionSet::from_solver_result(&SolverResult::Single(expr!(a + 1))).is_none());
+        assert_eq!(
+            SolutionSet::reals().to_solver_result(),
+            SolverResult::InfiniteSolutions
+        );
+    }
+}
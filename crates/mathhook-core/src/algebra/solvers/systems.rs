@@ -3,9 +3,13 @@
 //! Linear systems: Uses LU decomposition via Matrix::solve()
 //! Polynomial systems: Uses Gröbner basis computation (Buchberger's algorithm)
 
+use crate::algebra::equation_analyzer::SmartEquationSolver;
 use crate::algebra::groebner::{GroebnerBasis, MonomialOrder};
 use crate::algebra::polynomial_advanced::AdvancedPolynomial;
-use crate::algebra::solvers::{EquationSolver, SolverResult, SystemEquationSolver};
+use crate::algebra::solvers::{
+    solve_set, EquationSolver, SolutionSet, SolverResult, SystemEquationSolver,
+};
+use crate::core::polynomial::sparse_polynomial::expression_to_sparse_polynomial;
 use crate::core::{Expression, Number, Symbol};
 use crate::educational::step_by_step::{Step, StepByStepExplanation};
 use crate::error::MathError;
@@ -13,6 +17,7 @@ use crate::matrices::Matrix;
 use crate::simplify::Simplify;
 use num_bigint::BigInt;
 use num_rational::BigRational;
+use std::collections::HashMap;
 
 /// System equation solver
 #[derive(Debug, Clone)]
@@ -101,6 +106,11 @@ impl SystemEquationSolver for SystemSolver {
         let n = equations.len();
         let m = variables.len();
 
+        // Several constraints on one unknown: intersect their solution sets
+        if m == 1 && n > 1 {
+            return self.solve_by_intersection(equations, &variables[0]);
+        }
+
         // Check if system is square
         if n != m {
             return SolverResult::NoSolution; // Underdetermined or overdetermined
@@ -179,7 +189,10 @@ impl SystemEquationSolver for SystemSolver {
         }
 
         match &result {
-            SolverResult::Multiple(sols) if sols.len() == variables.len() => {
+            SolverResult::Multiple(sols)
+                if sols.len() == variables.len()
+                    && !sols.iter().any(|s| matches!(s, Expression::Set(_))) =>
+            {
                 steps.push(Step::new(
                     "Solve System",
                     "Apply the chosen method to solve the system",
@@ -217,6 +230,21 @@ impl SystemEquationSolver for SystemSolver {
 }
 
 impl SystemSolver {
+    /// Solve equations and inequalities in one variable by intersecting the
+    /// solution set of each
+    ///
+    /// An interval of solutions is reported as `InfiniteSolutions`. When some
+    /// constraint cannot be solved as a set the result is `NoSolution`, as for
+    /// any other non-square system.
+    fn solve_by_intersection(&self, equations: &[Expression], variable: &Symbol) -> SolverResult {
+        equations
+            .iter()
+            .try_fold(SolutionSet::reals(), |set, equation| {
+                Some(set.intersection(&solve_set(equation, variable)?))
+            })
+            .map_or(SolverResult::NoSolution, |set| set.to_solver_result())
+    }
+
     /// Solve 2x2 linear system using elimination
     fn solve_2x2_system(
         &self,
@@ -617,7 +645,8 @@ impl SystemSolver {
 
     /// Detect if system contains polynomial (non-linear) equations
     ///
-    /// A system is polynomial if any equation has degree > 1 in any variable.
+    /// A system is polynomial if every equation is a polynomial in the
+    /// variables and some term has total degree > 1, such as x² or xy.
     /// Linear systems (degree ≤ 1) are handled by Gaussian elimination.
     ///
     /// # Arguments
@@ -627,29 +656,27 @@ impl SystemSolver {
     ///
     /// # Returns
     ///
-    /// `true` if any equation has degree > 1 (polynomial system)
+    /// `true` if the equations form a nonlinear polynomial system
     fn is_polynomial_system(&self, equations: &[Expression], variables: &[Symbol]) -> bool {
+        let mut nonlinear = false;
         for equation in equations {
-            for variable in variables {
-                if equation.polynomial_degree(variable).unwrap_or(0) > 1 {
-                    return true;
-                }
-            }
+            let Some(poly) = expression_to_sparse_polynomial(equation, variables) else {
+                return false;
+            };
+            nonlinear |= poly.terms.keys().any(|monomial| monomial.degree() > 1);
         }
-        false
+        nonlinear
     }
 
     /// Solve polynomial system using Gröbner basis
     ///
-    /// Uses Buchberger's algorithm to compute Gröbner basis, then extracts solutions
-    /// from the basis. Works for systems of polynomial equations of any degree.
-    ///
-    /// # Algorithm
+    /// Computes the reduced lex Gröbner basis, which lists a system with
+    /// finitely many solutions in triangular form, then back-substitutes:
+    /// the elements involving only the last variable give its values, and
+    /// each value is substituted into the elements involving the last two
+    /// variables, and so on up to the first.
     ///
-    /// 1. Compute Gröbner basis using Buchberger's algorithm
-    /// 2. Basis is in triangular form (elimination ideal)
-    /// 3. Extract solutions by solving univariate polynomials
-    /// 4. Back-substitute to find all variable values
+    /// Only real solutions are reported.
     ///
     /// # Arguments
     ///
@@ -658,113 +685,135 @@ impl SystemSolver {
     ///
     /// # Returns
     ///
-    /// `SolverResult::Multiple(solutions)` if solutions found, otherwise `NoSolution`
+    /// - `SolverResult::Multiple(values)`, one value per variable, for a
+    ///   single real solution
+    /// - `SolverResult::Multiple(points)`, each point a set of values in
+    ///   variable order, for several
+    /// - `NoSolution` when the equations are inconsistent or have no real
+    ///   solution
+    /// - `Partial(vec![])` when the solutions are not finitely many or a
+    ///   univariate step cannot be solved
     ///
     /// # Examples
     ///
-    /// ```rust,ignore
-    /// use mathhook_core::algebra::solvers::{SystemSolver, SystemEquationSolver};
-    /// use mathhook_core::{symbol, expr, Expression};
+    /// ```rust
+    /// use mathhook_core::algebra::solvers::{SolverResult, SystemEquationSolver, SystemSolver};
+    /// use mathhook_core::{expr, symbol, Expression};
     ///
     /// let solver = SystemSolver::new();
     /// let x = symbol!(x);
     /// let y = symbol!(y);
     ///
-    /// // Circle x² + y² = 1 intersecting line x = y
-    /// let eq1 = Expression::add(vec![expr!(x^2), expr!(y^2), expr!(-1)]);
-    /// let eq2 = expr!(x - y);
+    /// // Circle x² + y² = 25 and parabola y = x² - 5
+    /// let eq1 = expr!((x ^ 2) + (y ^ 2) - 25);
+    /// let eq2 = expr!(y - (x ^ 2) + 5);
     ///
-    /// let result = solver.solve_system(&[eq1, eq2], &[x, y]);
-    /// // Finds points (√2/2, √2/2) and (-√2/2, -√2/2)
+    /// let SolverResult::Multiple(points) = solver.solve_system(&[eq1, eq2], &[x, y]) else {
+    ///     panic!("expected solutions");
+    /// };
+    /// // (0, -5), (±3, 4)
+    /// assert_eq!(points.len(), 3);
+    /// assert!(points.contains(&Expression::set(vec![expr!(3), expr!(4)])));
     /// ```
     fn solve_polynomial_system_groebner(
         &self,
         equations: &[Expression],
         variables: &[Symbol],
     ) -> SolverResult {
-        // Create Gröbner basis with lexicographic ordering
-        // Lex ordering produces elimination ideal (triangular form)
+        // Lex ordering produces elimination ideals (triangular form)
         let mut gb = GroebnerBasis::new(equations.to_vec(), variables.to_vec(), MonomialOrder::Lex);
 
-        // Compute basis using Buchberger's algorithm
         // If computation times out or exceeds iteration limit, return Partial result
-        let computation_result = gb.compute_with_result();
-        if computation_result.is_err() {
-            // Timeout or iteration limit exceeded - return Partial
+        if gb.compute_with_result().is_err() {
             return SolverResult::Partial(vec![]);
         }
 
-        // Try to reduce basis for simpler form
-        gb.reduce();
-
-        // Extract solutions from the Gröbner basis
-        // In lex ordering, basis should be in triangular form:
-        // [..., g_k(x_k, ..., x_n), ..., g_n(x_n)]
-
-        // For now, return Partial with the basis as solution representation
-        // Full solution extraction requires:
-        // 1. Solve univariate polynomial in last variable
-        // 2. Back-substitute to find other variables
-        // 3. Handle multiple solutions (roots of polynomials)
-
-        // If basis contains only constant (non-zero), no solution exists
-        if gb.basis.len() == 1 {
-            if let Expression::Number(Number::Integer(n)) = &gb.basis[0] {
-                if *n != 0 {
-                    return SolverResult::NoSolution; // Inconsistent system (e.g., 1 = 0)
-                }
-            }
-        }
-
         // If basis is empty or contains only zero, infinite solutions
-        if gb.basis.is_empty() || gb.basis.iter().all(|p| p.is_zero()) {
+        if gb.basis.iter().all(|p| p.is_zero()) {
             return SolverResult::InfiniteSolutions;
         }
 
-        // For simple cases, try to extract solutions directly
-        // Look for equations of form "variable - constant = 0"
-        let mut solutions = vec![Expression::integer(0); variables.len()];
-        let mut found_count = 0;
+        // A nonzero constant in the basis means 1 = 0 follows from the system
+        if gb.basis.iter().any(|p| matches!(p, Expression::Number(_))) {
+            return SolverResult::NoSolution;
+        }
+
+        if !gb.is_zero_dimensional() {
+            return SolverResult::Partial(vec![]);
+        }
 
-        for poly in &gb.basis {
-            if let Expression::Add(terms) = poly {
-                if terms.len() == 2 {
-                    // Check for "x - c" or "c - x" pattern
-                    for (i, var) in variables.iter().enumerate() {
-                        if terms[0] == Expression::symbol(var.clone()) {
-                            if let Expression::Number(_) = terms[1] {
-                                solutions[i] = Expression::mul(vec![
-                                    Expression::integer(-1),
-                                    terms[1].clone(),
-                                ])
-                                .simplify();
-                                found_count += 1;
-                                break;
-                            }
-                        } else if terms[1] == Expression::symbol(var.clone()) {
-                            if let Expression::Number(_) = terms[0] {
-                                solutions[i] = Expression::mul(vec![
-                                    Expression::integer(-1),
-                                    terms[0].clone(),
-                                ])
-                                .simplify();
-                                found_count += 1;
-                                break;
-                            }
-                        }
+        // Values of variables[k..], in variable order, for each partial solution
+        let mut points: Vec<Vec<Expression>> = vec![vec![]];
+        for k in (0..variables.len()).rev() {
+            let var = &variables[k];
+            let later = &variables[k + 1..];
+            let triangle: Vec<&Expression> = gb
+                .basis
+                .iter()
+                .filter(|p| {
+                    let vars = p.find_variables();
+                    vars.contains(var) && vars.iter().all(|v| v == var || later.contains(v))
+                })
+                .collect();
+
+            let mut extended = Vec::new();
+            for point in &points {
+                let values: HashMap<String, Expression> = later
+                    .iter()
+                    .zip(point)
+                    .map(|(v, value)| (v.name().to_owned(), value.clone()))
+                    .collect();
+                let mut univariate: Vec<Expression> = triangle
+                    .iter()
+                    .map(|p| p.substitute(&values).simplify())
+                    .filter(|p| !p.is_zero())
+                    .collect();
+                univariate.sort_by_key(|p| p.polynomial_degree(var).unwrap_or(i64::MAX));
+
+                let Some((lowest, others)) = univariate.split_first() else {
+                    return SolverResult::Partial(vec![]);
+                };
+                let Some(roots) = real_roots(lowest, var) else {
+                    return SolverResult::Partial(vec![]);
+                };
+                for root in roots {
+                    if others.iter().all(|p| vanishes_at(p, var, &root)) {
+                        let mut values = vec![root];
+                        values.extend(point.iter().cloned());
+                        extended.push(values);
                     }
                 }
             }
+            points = extended;
         }
 
-        // If we found solutions for all variables, return them
-        if found_count == variables.len() {
-            return SolverResult::Multiple(solutions);
+        match points.len() {
+            0 => SolverResult::NoSolution,
+            1 => SolverResult::Multiple(points.remove(0)),
+            _ => SolverResult::Multiple(points.into_iter().map(Expression::set).collect()),
         }
-
-        // Otherwise, system is too complex for simple extraction
-        // Gröbner basis computed but extraction incomplete
-        // Full implementation (univariate solving + back-substitution) deferred to Phase 4: WAVE-CLEANUP
-        SolverResult::Partial(vec![])
     }
 }
+
+/// Real roots of a univariate step of back-substitution
+///
+/// Substituted values such as √2/2 leave coefficients that `solve_set` does
+/// not accept as polynomial, so those equations go to the equation solver.
+fn real_roots(poly: &Expression, var: &Symbol) -> Option<Vec<Expression>> {
+    solve_set(poly, var)
+        .and_then(|set| set.points())
+        .or_else(|| {
+            let (result, _) = SmartEquationSolver::new().solve_with_equation(poly, var);
+            SolutionSet::from_solver_result(&result)?.points()
+        })
+}
+
+/// Whether `poly` is zero at `var = value`, exactly or to within rounding
+fn vanishes_at(poly: &Expression, var: &Symbol, value: &Expression) -> bool {
+    let at = poly.substitute(&HashMap::from([(var.name().to_owned(), value.clone())]));
+    let simplified = at.simplify();
+    simplified.is_zero()
+        || simplified
+            .evaluate_to_f64()
+            .is_ok_and(|v| v.abs() < 1e-9 * (1.0 + value.evaluate_to_f64().unwrap_or(0.0).abs()))
+}
@@ -0,0 +1,425 @@
+//! Symmetric functions and polynomial invariants
+//!
+//! Provides the classical building blocks for working with symmetric polynomials:
+//!
+//! - Elementary symmetric polynomials eₖ and power sums pₖ
+//! - Newton's identities converting between power sums and elementary symmetric polynomials
+//! - Decomposition of a symmetric polynomial into a polynomial in e₁, ..., eₙ
+//! - Discriminant of a polynomial from its roots
+//!
+//! # Mathematical Background
+//!
+//! By the fundamental theorem of symmetric polynomials, every symmetric polynomial in
+//! x₁, ..., xₙ is a unique polynomial in e₁, ..., eₙ. The decomposition repeatedly
+//! cancels the lex-leading term c·x₁^a₁⋯xₙ^aₙ (with a₁ ≥ ⋯ ≥ aₙ) using
+//! c·e₁^(a₁-a₂)⋯eₙ₋₁^(aₙ₋₁-aₙ)·eₙ^aₙ.
+//!
+//! Newton's identities relate the two bases:
+//!
+//! ```text
+//! pₖ = Σᵢ₌₁ᵏ⁻¹ (-1)^(i-1) eᵢ pₖ₋ᵢ + (-1)^(k-1) k eₖ
+//! k eₖ = Σᵢ₌₁ᵏ (-1)^(i-1) eₖ₋ᵢ pᵢ
+//! ```
+
+use crate::algebra::groebner::MonomialOrder;
+use crate::algebra::Expand;
+use crate::core::polynomial::sparse_polynomial::{
+    expression_to_sparse_polynomial, sparse_polynomial_to_expression, Monomial, SparsePolynomial,
+};
+use crate::core::{Expression, Symbol};
+use crate::error::{MathError, MathResult};
+use crate::simplify::Simplify;
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::One;
+
+/// Elementary symmetric polynomial eₖ(x₁, ..., xₙ)
+///
+/// Sum of all products of `k` distinct variables. e₀ = 1 and eₖ = 0 for k > n.
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::algebra::symmetric::elementary_symmetric;
+/// use mathhook_core::{expr, symbol};
+///
+/// let (x, y, z) = (symbol!(x), symbol!(y), symbol!(z));
+/// let e2 = elementary_symmetric(&[x, y, z], 2);
+/// assert_eq!(e2, expr!((x * y) + (x * z) + (y * z)));
+/// ```
+pub fn elementary_symmetric(vars: &[Symbol], k: usize) -> Expression {
+    if k == 0 {
+        return Expression::integer(1);
+    }
+    if k > vars.len() {
+        return Expression::integer(0);
+    }
+
+    let mut terms = Vec::new();
+    let mut chosen = Vec::with_capacity(k);
+    collect_products(vars, k, 0, &mut chosen, &mut terms);
+    Expression::add(terms)
+}
+
+/// Power sum pₖ(x₁, ..., xₙ) = x₁ᵏ + ⋯ + xₙᵏ
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::algebra::symmetric::power_sum;
+/// use mathhook_core::{expr, symbol};
+///
+/// let (x, y) = (symbol!(x), symbol!(y));
+/// assert_eq!(power_sum(&[x, y], 2), expr!((x ^ 2) + (y ^ 2)));
+/// ```
+pub fn power_sum(vars: &[Symbol], k: usize) -> Expression {
+    Expression::add(
+        vars.iter()
+            .map(|v| Expression::pow(Expression::symbol(v.clone()), Expression::integer(k as i64)))
+            .collect(),
+    )
+}
+
+/// Power sums p₁, ..., p_count expressed through elementary symmetric polynomials
+///
+/// Applies Newton's identities. `elementary[i]` is eᵢ₊₁; elementary polynomials beyond
+/// the slice are treated as zero, matching n = `elementary.len()` variables.
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::algebra::symmetric::power_sums_from_elementary;
+/// use mathhook_core::{expr, Expression};
+///
+/// let e = vec![expr!(e1), expr!(e2)];
+/// let p = power_sums_from_elementary(&e, 2);
+/// assert_eq!(p[0], expr!(e1));
+/// assert_eq!(p[1], Expression::add(vec![expr!(e1 ^ 2), expr!(-2 * e2)]));
+/// ```
+pub fn power_sums_from_elementary(elementary: &[Expression], count: usize) -> Vec<Expression> {
+    let e = |i: usize| {
+        elementary
+            .get(i - 1)
+            .cloned()
+            .unwrap_or_else(|| Expression::integer(0))
+    };
+
+    let mut power_sums: Vec<Expression> = Vec::with_capacity(count);
+    for k in 1..=count {
+        let mut terms = Vec::with_capacity(k);
+        for i in 1..k {
+            terms.push(Expression::mul(vec![
+                alternating_sign(i),
+                e(i),
+                power_sums[k - i - 1].clone(),
+            ]));
+        }
+        terms.push(Expression::mul(vec![
+            alternating_sign(k),
+            Expression::integer(k as i64),
+            e(k),
+        ]));
+        power_sums.push(Expression::add(terms).expand());
+    }
+    power_sums
+}
+
+/// Elementary symmetric polynomials e₁, ..., eₙ expressed through power sums
+///
+/// Inverse of [`power_sums_from_elementary`]: `power_sums[i]` is pᵢ₊₁ and the result has
+/// the same length as the input.
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::algebra::symmetric::elementary_from_power_sums;
+/// use mathhook_core::{expr, Expression};
+///
+/// let p = vec![expr!(p1), expr!(p2)];
+/// let e = elementary_from_power_sums(&p);
+/// assert_eq!(e[0], expr!(p1));
+/// assert_eq!(
+///     e[1],
+///     Expression::add(vec![
+///         Expression::mul(vec![Expression::rational(1, 2), expr!(p1 ^ 2)]),
+///         Expression::mul(vec![Expression::rational(-1, 2), expr!(p2)]),
+///     ])
+/// );
+/// ```
+pub fn elementary_from_power_sums(power_sums: &[Expression]) -> Vec<Expression> {
+    let mut elementary: Vec<Expression> = Vec::with_capacity(power_sums.len());
+    for k in 1..=power_sums.len() {
+        let terms: Vec<Expression> = (1..=k)
+            .map(|i| {
+                let e_prev = if k == i {
+                    Expression::integer(1)
+                } else {
+                    elementary[k - i - 1].clone()
+                };
+                Expression::mul(vec![alternating_sign(i), e_prev, power_sums[i - 1].clone()])
+            })
+            .collect();
+        elementary.push(
+            Expression::mul(vec![
+                Expression::rational(1, k as i64),
+                Expression::add(terms),
+            ])
+            .expand(),
+        );
+    }
+    elementary
+}
+
+/// Check whether a polynomial is symmetric in the given variables
+///
+/// Returns `false` when the expression is not a polynomial in `vars`.
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::algebra::symmetric::is_symmetric;
+/// use mathhook_core::{expr, symbol};
+///
+/// let (x, y) = (symbol!(x), symbol!(y));
+/// assert!(is_symmetric(&expr!((x ^ 2) + (y ^ 2)), &[x.clone(), y.clone()]));
+/// assert!(!is_symmetric(&expr!(x + (2 * y)), &[x, y]));
+/// ```
+pub fn is_symmetric(poly: &Expression, vars: &[Symbol]) -> bool {
+    let Some(sparse) = expression_to_sparse_polynomial(&poly.expand(), vars) else {
+        return false;
+    };
+
+    // Invariance under adjacent transpositions implies invariance under all permutations
+    (0..vars.len().saturating_sub(1)).all(|i| {
+        sparse.terms.iter().all(|(monomial, coeff)| {
+            let mut swapped = monomial.exponents.clone();
+            swapped.swap(i, i + 1);
+            sparse.terms.get(&Monomial::new(swapped)) == Some(coeff)
+        })
+    })
+}
+
+/// Express a symmetric polynomial in terms of elementary symmetric polynomials
+///
+/// # Arguments
+///
+/// * `poly` - Polynomial symmetric in `vars`
+/// * `vars` - Variables x₁, ..., xₙ
+/// * `elementary` - Symbols e₁, ..., eₙ to use in the result (must match `vars` in length)
+///
+/// # Errors
+///
+/// Returns `MathError::DomainError` if `poly` is not a polynomial in `vars`, is not
+/// symmetric, or if the symbol lists differ in length.
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::algebra::symmetric::symmetric_decomposition;
+/// use mathhook_core::{expr, symbol, Expression};
+///
+/// let (x, y) = (symbol!(x), symbol!(y));
+/// let (e1, e2) = (symbol!(e1), symbol!(e2));
+///
+/// // x² + y² = e₁² - 2e₂
+/// let result = symmetric_decomposition(&expr!((x ^ 2) + (y ^ 2)), &[x, y], &[e1, e2]).unwrap();
+/// assert_eq!(result, Expression::add(vec![expr!(e1 ^ 2), expr!(-2 * e2)]));
+/// ```
+pub fn symmetric_decomposition(
+    poly: &Expression,
+    vars: &[Symbol],
+    elementary: &[Symbol],
+) -> MathResult<Expression> {
+    let not_symmetric = |reason: &str| MathError::DomainError {
+        operation: "symmetric_decomposition".to_owned(),
+        value: poly.clone(),
+        reason: reason.to_owned(),
+    };
+
+    if vars.len() != elementary.len() {
+        return Err(not_symmetric(
+            "number of elementary symbols must match number of variables",
+        ));
+    }
+
+    let n = vars.len();
+    let mut remainder = expression_to_sparse_polynomial(&poly.expand(), vars)
+        .ok_or_else(|| not_symmetric("expression is not a polynomial in the given variables"))?;
+    remainder
+        .terms
+        .retain(|_, c| *c != BigRational::from(BigInt::from(0)));
+
+    let e_polys: Vec<SparsePolynomial> = (1..=n).map(|k| sparse_elementary(n, k)).collect();
+    let mut result = SparsePolynomial::zero(n);
+
+    while let Some((leading, coeff)) = remainder.leading_term(&MonomialOrder::Lex) {
+        let a = &leading.exponents;
+        if a.windows(2).any(|w| w[0] < w[1]) {
+            return Err(not_symmetric("polynomial is not symmetric"));
+        }
+
+        // e₁^(a₁-a₂) ⋯ eₙ^aₙ as exponent vector over the elementary symbols
+        let e_exponents: Vec<usize> = (0..n)
+            .map(|i| a[i] - a.get(i + 1).copied().unwrap_or(0))
+            .collect();
+
+        let mut product = SparsePolynomial::constant(coeff.clone(), n);
+        for (e_k, &power) in e_polys.iter().zip(&e_exponents) {
+            for _ in 0..power {
+                product = product.mul(e_k);
+            }
+        }
+
+        remainder = remainder.sub(&product);
+        result = result.add(&SparsePolynomial::from_term(
+            Monomial::new(e_exponents),
+            coeff,
+            n,
+        ));
+    }
+
+    Ok(sparse_polynomial_to_expression(&result, elementary).simplify())
+}
+
+/// Discriminant of the monic polynomial with the given roots
+///
+/// Computes ∏ᵢ<ⱼ (rᵢ - rⱼ)², which vanishes exactly when two roots coincide.
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::algebra::symmetric::discriminant_from_roots;
+/// use mathhook_core::Expression;
+///
+/// let roots = vec![Expression::integer(1), Expression::integer(2), Expression::integer(4)];
+/// assert_eq!(discriminant_from_roots(&roots), Expression::integer(36));
+/// ```
+pub fn discriminant_from_roots(roots: &[Expression]) -> Expression {
+    let mut factors = Vec::new();
+    for (i, r_i) in roots.iter().enumerate() {
+        for r_j in &roots[i + 1..] {
+            let difference = Expression::add(vec![
+                r_i.clone(),
+                Expression::mul(vec![Expression::integer(-1), r_j.clone()]),
+            ]);
+            factors.push(Expression::pow(difference, Expression::integer(2)));
+        }
+    }
+    Expression::mul(factors)
+}
+
+fn collect_products(
+    vars: &[Symbol],
+    k: usize,
+    start: usize,
+    chosen: &mut Vec<Expression>,
+    terms: &mut Vec<Expression>,
+) {
+    if chosen.len() == k {
+        terms.push(Expression::mul(chosen.clone()));
+        return;
+    }
+    for i in start..vars.len() {
+        chosen.push(Expression::symbol(vars[i].clone()));
+        collect_products(vars, k, i + 1, chosen, terms);
+        chosen.pop();
+    }
+}
+
+fn sparse_elementary(n: usize, k: usize) -> SparsePolynomial {
+    let mut poly = SparsePolynomial::zero(n);
+    let mut exponents = vec![0; n];
+    add_subset_monomials(&mut poly, &mut exponents, k, 0);
+    poly
+}
+
+fn add_subset_monomials(
+    poly: &mut SparsePolynomial,
+    exponents: &mut Vec<usize>,
+    remaining: usize,
+    start: usize,
+) {
+    if remaining == 0 {
+        poly.terms
+            .insert(Monomial::new(exponents.clone()), BigRational::one());
+        return;
+    }
+    for i in start..exponents.len() {
+        exponents[i] = 1;
+        add_subset_monomials(poly, exponents, remaining - 1, i + 1);
+        exponents[i] = 0;
+    }
+}
+
+fn alternating_sign(i: usize) -> Expression {
+    Expression::integer(if i % 2 == 1 { 1 } else { -1 })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{expr, symbol};
+
+    #[test]
+    fn test_elementary_symmetric_edges() {
+        let vars = vec![symbol!(x), symbol!(y)];
+        assert_eq!(elementary_symmetric(&vars, 0), Expression::integer(1));
+        assert_eq!(elementary_symmetric(&vars, 3), Expression::integer(0));
+        assert_eq!(elementary_symmetric(&vars, 2), expr!(x * y));
+    }
+
+    #[test]
+    fn test_newton_round_trip() {
+        let e = vec![expr!(a), expr!(b), expr!(c)];
+        let p = power_sums_from_elementary(&e, 3);
+        let back = elementary_from_power_sums(&p);
+        assert_eq!(back, e);
+    }
+
+    #[test]
+    fn test_newton_matches_definition() {
+        let vars = vec![symbol!(x), symbol!(y), symbol!(z)];
+        let e: Vec<Expression> = (1..=3).map(|k| elementary_symmetric(&vars, k)).collect();
+        let p = power_sums_from_elementary(&e, 4);
+        for (k, p_k) in p.iter().enumerate() {
+            let expected = power_sum(&vars, k + 1);
+            let difference = Expression::add(vec![
+                p_k.clone(),
+                Expression::mul(vec![Expression::integer(-1), expected]),
+            ]);
+            assert_eq!(difference.expand(), Expression::integer(0), "p{}", k + 1);
+        }
+    }
+
+    #[test]
+    fn test_decomposition_three_variables() {
+        let vars = vec![symbol!(x), symbol!(y), symbol!(z)];
+        let e_syms = vec![symbol!(e1), symbol!(e2), symbol!(e3)];
+
+        // x³ + y³ + z³ = e₁³ - 3e₁e₂ + 3e₃
+        let result = symmetric_decomposition(&power_sum(&vars, 3), &vars, &e_syms).unwrap();
+        let expected = Expression::add(vec![expr!(e1 ^ 3), expr!(-3 * e1 * e2), expr!(3 * e3)]);
+        let difference = Expression::add(vec![
+            result,
+            Expression::mul(vec![Expression::integer(-1), expected]),
+        ]);
+        assert_eq!(difference.expand(), Expression::integer(0));
+    }
+
+    #[test]
+    fn test_decomposition_rejects_non_symmetric() {
+        let vars = vec![symbol!(x), symbol!(y)];
+        let e_syms = vec![symbol!(e1), symbol!(e2)];
+        assert!(symmetric_decomposition(&expr!(x ^ 2), &vars, &e_syms).is_err());
+        assert!(!is_symmetric(&expr!(x ^ 2), &vars));
+    }
+
+    #[test]
+    fn test_discriminant_from_repeated_root() {
+        let roots = vec![expr!(a), expr!(a), expr!(b)];
+        assert_eq!(
+            discriminant_from_roots(&roots).expand(),
+            Expression::integer(0)
+        );
+    }
+}
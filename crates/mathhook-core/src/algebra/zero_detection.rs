@@ -114,21 +114,13 @@ impl Expression {
             }
 
             // Symbolic case: x + (-x) = 0
-            (Expression::Symbol(s1), Expression::Mul(factors)) => {
-                if factors.len() == 2 {
-                    if let (Expression::Number(Number::Integer(n)), Expression::Symbol(s2)) =
-                        (&factors[0], &factors[1])
-                    {
+            (Expression::Symbol(s1), Expression::Mul(factors)) if factors.len() == 2 => {
+                match (&factors[0], &factors[1]) {
+                    (Expression::Number(Number::Integer(n)), Expression::Symbol(s2))
+                    | (Expression::Symbol(s2), Expression::Number(Number::Integer(n))) => {
                         *n == -1 && s1 == s2
-                    } else if let (Expression::Symbol(s2), Expression::Number(Number::Integer(n))) =
-                        (&factors[0], &factors[1])
-                    {
-                        *n == -1 && s1 == s2
-                    } else {
-                        false
                     }
-                } else {
-                    false
+                    _ => false,
                 }
             }
 
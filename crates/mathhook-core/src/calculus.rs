@@ -4,25 +4,31 @@
 //! limits, series expansions, and advanced calculus operations.
 
 pub mod derivatives;
+pub mod fluent;
 pub mod integrals;
+pub mod laplace;
 pub mod limits;
 pub mod ode;
 pub mod pde;
 pub mod residues;
 pub mod series;
 pub mod summation;
+pub mod transforms;
 
 // Re-export main traits and types
 pub use derivatives::{
     BasicDerivatives, ChainRule, Derivative, DifferentiabilityChecker, FunctionDerivatives,
     GeneralProductRule, HigherOrderDerivatives, PowerRule, ProductRule,
 };
+pub use fluent::CalculusBuilder;
 pub use integrals::{BasicIntegrals, FunctionIntegrals, Integration, IntegrationMethods};
+pub use laplace::{InverseLaplaceTransform, LaplaceTransform, TransferFunction};
 pub use limits::{LimitDirection, Limits};
 pub use pde::*;
 pub use residues::{ComplexAnalysis, ResidueCalculus};
 pub use series::{SeriesExpansion, SeriesType};
 pub use summation::{Summation, SummationMethods};
+pub use transforms::{FourierConvention, FourierTransform};
 /// Main calculus operations trait
 pub trait CalculusOperations:
     Derivative + Integration + Limits + SeriesExpansion + Summation + ResidueCalculus
@@ -9,6 +9,7 @@ mod chain_rule;
 mod checker;
 pub mod educational;
 mod higher_order;
+mod iterative;
 mod partial;
 mod power_rule;
 mod product_rule;
@@ -22,6 +23,7 @@ pub use chain_rule::{ChainRule, FunctionDerivatives};
 pub use checker::DifferentiabilityChecker;
 pub use educational::DerivativeWithSteps;
 pub use higher_order::HigherOrderDerivatives;
+pub use iterative::{DerivativeSimplification, IterativeDerivative};
 pub use partial::{
     ConservativeFields, DirectionalDerivatives, FluidDynamicsOperations, GradientOperations,
     HessianOperations, JacobianDeterminant, JacobianOperations, MatrixUtils, PartialDerivatives,
@@ -80,7 +82,9 @@ impl Derivative for Expression {
             Expression::Add(terms) => BasicDerivatives::handle_sum(terms, &variable),
             Expression::Mul(factors) => ProductRule::handle_product(factors, variable),
             Expression::Pow(base, exponent) => PowerRule::apply(base, exponent, variable),
-            Expression::Function { name, args } => ChainRule::handle_function(name, args, variable),
+            Expression::Function { name, args, id } => {
+                ChainRule::handle_function(name, *id, args, variable)
+            }
             _ => Expression::derivative(self.clone(), variable, 1),
         }
     }
@@ -149,7 +149,7 @@ impl ImplicitCurveAnalysis {
             SolverResult::InfiniteSolutions => {
                 vec![(Expression::symbol(x_var), Expression::symbol(y_var))]
             }
-            SolverResult::Parametric(_) | SolverResult::Partial(_) => {
+            SolverResult::Parametric(_) | SolverResult::Partial(_) | SolverResult::Cases(_) => {
                 vec![]
             }
         }
@@ -268,7 +268,7 @@ mod tests {
             // Check if x-coordinate is zero (handles multiple representations)
             let is_zero = match x_coord {
                 Expression::Number(Number::Integer(0)) => true,
-                Expression::Function { name, args }
+                Expression::Function { name, args, .. }
                     if name.as_ref() == "fraction" && !args.is_empty() =>
                 {
                     // fraction(0, denominator) = 0
@@ -309,7 +309,7 @@ mod tests {
             // Check if x-coordinate is zero (handles multiple representations)
             let is_zero = match x_coord {
                 Expression::Number(Number::Integer(0)) => true,
-                Expression::Function { name, args }
+                Expression::Function { name, args, .. }
                     if name.as_ref() == "fraction" && !args.is_empty() =>
                 {
                     // fraction(0, denominator) = 0
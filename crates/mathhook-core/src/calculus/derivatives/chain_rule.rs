@@ -1,8 +1,11 @@
 //! Chain rule implementation for function derivatives
 
 use crate::calculus::derivatives::Derivative;
+use crate::core::expression::{FunctionId, RelationType};
 use crate::core::{Expression, Symbol};
+use crate::functions::custom::custom_function;
 use crate::functions::intelligence::get_universal_registry;
+use crate::functions::special::distributions::{delta_order, dirac_delta};
 use crate::simplify::Simplify;
 
 /// Chain rule implementation for function derivatives
@@ -22,12 +25,46 @@ impl ChainRule {
     /// let expr = Expression::function("sin", vec![Expression::symbol(x.clone())]);
     /// let result = expr.derivative(x.clone());
     /// ```
-    pub fn handle_function(name: &str, args: &[Expression], variable: Symbol) -> Expression {
+    pub fn handle_function(
+        name: &str,
+        id: FunctionId,
+        args: &[Expression],
+        variable: Symbol,
+    ) -> Expression {
+        if matches!(name, "min" | "max") && !args.is_empty() {
+            return Self::min_max(name, args, variable);
+        }
+
+        // H'(u) = δ(u), and δ⁽ᵏ⁾(u)' = δ⁽ᵏ⁺¹⁾(u)
+        let delta_order = match name {
+            "heaviside" if args.len() == 1 => Some(0),
+            "dirac_delta" => delta_order(args).map(|order| order + 1),
+            _ => None,
+        };
+        if let Some(order) = delta_order {
+            return Expression::mul(vec![
+                dirac_delta(&args[0], order),
+                args[0].derivative(variable),
+            ])
+            .simplify();
+        }
+
+        if !id.is_builtin() {
+            if let Some(result) =
+                custom_function(name).and_then(|f| f.differentiate(args, variable.clone()))
+            {
+                return result;
+            }
+        }
+
         if args.len() != 1 {
             return Expression::derivative(Expression::function(name, args.to_vec()), variable, 1);
         }
 
-        Self::apply(name, &args[0], variable)
+        let arg_derivative = args[0].derivative(variable.clone());
+        let function_derivative = FunctionDerivatives::get_by_id(id, name, &args[0], variable);
+
+        Expression::mul(vec![function_derivative, arg_derivative]).simplify()
     }
 
     /// Apply chain rule for function derivatives
@@ -48,6 +85,72 @@ impl ChainRule {
 
         Expression::mul(vec![function_derivative, arg_derivative]).simplify()
     }
+
+    /// Piecewise derivative of `max(a, b)` or `min(a, b)`
+    ///
+    /// The derivative of the selected argument away from ties. Where `a = b`
+    /// the derivative exists only if `a' = b'`; otherwise the default piece is
+    /// the subgradient interval between the two one-sided derivatives.
+    /// Calls with more arguments nest, `max(a, b, c) = max(a, max(b, c))`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::calculus::derivatives::Derivative;
+    /// use mathhook_core::{expr, symbol, Expression};
+    ///
+    /// let x = symbol!(x);
+    /// // max(x, 2x) has derivative 1 for x < 0 and 2 for x > 0
+    /// let max = Expression::function("max", vec![expr!(x), expr!(2 * x)]);
+    /// let derivative = max.derivative(x);
+    /// assert!(matches!(derivative, Expression::Piecewise(_)));
+    /// ```
+    pub fn min_max(name: &str, args: &[Expression], variable: Symbol) -> Expression {
+        let [first, rest @ ..] = args else {
+            return Expression::derivative(Expression::function(name, vec![]), variable, 1);
+        };
+        if rest.is_empty() {
+            return first.derivative(variable);
+        }
+        let other = if rest.len() == 1 {
+            rest[0].clone()
+        } else {
+            Expression::function(name, rest.to_vec())
+        };
+
+        let first_derivative = first.derivative(variable.clone()).simplify();
+        let other_derivative = other.derivative(variable).simplify();
+        let (first_wins, other_wins) = if name == "max" {
+            (RelationType::Greater, RelationType::Less)
+        } else {
+            (RelationType::Less, RelationType::Greater)
+        };
+        let at_tie = if first_derivative == other_derivative {
+            first_derivative.clone()
+        } else {
+            let bounds = vec![first_derivative.clone(), other_derivative.clone()];
+            Expression::interval(
+                Expression::function("min", bounds.clone()).simplify(),
+                Expression::function("max", bounds).simplify(),
+                true,
+                true,
+            )
+        };
+
+        Expression::piecewise(
+            vec![
+                (
+                    first_derivative,
+                    Expression::relation(first.clone(), other.clone(), first_wins),
+                ),
+                (
+                    other_derivative,
+                    Expression::relation(first.clone(), other, other_wins),
+                ),
+            ],
+            Some(at_tie),
+        )
+    }
 }
 
 /// Function derivative lookup
@@ -67,9 +170,26 @@ impl FunctionDerivatives {
     /// let result = FunctionDerivatives::get("sin", &arg, x.clone());
     /// ```
     pub fn get(name: &str, arg: &Expression, variable: Symbol) -> Expression {
+        Self::get_by_id(FunctionId::of(name), name, arg, variable)
+    }
+
+    /// Get the derivative of a function whose name is already interned
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::core::expression::FunctionId;
+    /// use mathhook_core::calculus::derivatives::FunctionDerivatives;
+    /// use mathhook_core::{expr, symbol};
+    ///
+    /// let x = symbol!(x);
+    /// let result = FunctionDerivatives::get_by_id(FunctionId::of("sin"), "sin", &expr!(x), x.clone());
+    /// assert_eq!(result, expr!(cos(x)));
+    /// ```
+    pub fn get_by_id(id: FunctionId, name: &str, arg: &Expression, variable: Symbol) -> Expression {
         let registry = get_universal_registry();
 
-        if let Some(props) = registry.get_properties(name) {
+        if let Some(props) = registry.get_properties_by_id(id, name) {
             if let Some(deriv_expr) = props.get_derivative_expression(arg) {
                 return deriv_expr;
             }
@@ -78,3 +198,58 @@ impl FunctionDerivatives {
         Expression::derivative(Expression::function(name, vec![arg.clone()]), variable, 1)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{expr, symbol};
+
+    #[test]
+    fn test_max_derivative_has_subgradient_at_kink() {
+        let x = symbol!(x);
+        let relu = Expression::function("max", vec![expr!(x), expr!(0)]);
+        let Expression::Piecewise(data) = relu.derivative(x) else {
+            panic!("expected a piecewise derivative");
+        };
+        assert_eq!(data.pieces[0].0, expr!(1));
+        assert_eq!(
+            data.pieces[0].1,
+            Expression::relation(expr!(x), expr!(0), RelationType::Greater)
+        );
+        assert_eq!(data.pieces[1].0, expr!(0));
+        assert_eq!(
+            data.default,
+            Some(Expression::interval(expr!(0), expr!(1), true, true))
+        );
+    }
+
+    #[test]
+    fn test_min_with_equal_slopes_is_smooth_at_tie() {
+        let x = symbol!(x);
+        let min = Expression::function("min", vec![expr!(x + 1), expr!(x - 1)]);
+        let Expression::Piecewise(data) = min.derivative(x) else {
+            panic!("expected a piecewise derivative");
+        };
+        assert_eq!(data.default, Some(expr!(1)));
+    }
+
+    #[test]
+    fn test_heaviside_derivative_is_delta() {
+        let x = symbol!(x);
+        let step = Expression::function("heaviside", vec![expr!(x - 2)]);
+        assert_eq!(
+            step.derivative(x.clone()),
+            Expression::function("dirac_delta", vec![expr!(x - 2)])
+        );
+
+        let impulse = Expression::function("dirac_delta", vec![expr!(3 * x)]);
+        assert_eq!(
+            impulse.derivative(x),
+            Expression::mul(vec![
+                expr!(3),
+                Expression::function("dirac_delta", vec![expr!(3 * x), expr!(1)]),
+            ])
+            .simplify()
+        );
+    }
+}
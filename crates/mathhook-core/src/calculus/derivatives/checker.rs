@@ -27,7 +27,7 @@ impl DifferentiabilityChecker {
             Expression::Pow(base, exponent) => {
                 Self::check(base, variable.clone()) && Self::check(exponent, variable)
             }
-            Expression::Function { name, args } => {
+            Expression::Function { name, args, .. } => {
                 Self::is_function_differentiable(name)
                     && args.iter().all(|arg| Self::check(arg, variable.clone()))
             }
@@ -46,7 +46,10 @@ impl DifferentiabilityChecker {
     /// let is_abs_diff = DifferentiabilityChecker::is_function_differentiable("abs");
     /// ```
     pub fn is_function_differentiable(name: &str) -> bool {
-        !matches!(name, "abs" | "floor" | "ceil" | "sign")
+        !matches!(
+            name,
+            "abs" | "floor" | "ceil" | "sign" | "min" | "max" | "heaviside" | "dirac_delta"
+        )
     }
 }
 
@@ -123,7 +126,7 @@ mod tests {
 
     #[test]
     fn test_non_differentiable_functions() {
-        let non_diff_functions = vec!["abs", "floor", "ceil", "sign"];
+        let non_diff_functions = vec!["abs", "floor", "ceil", "sign", "min", "max"];
 
         for func_name in non_diff_functions {
             assert!(
@@ -135,7 +135,7 @@ fn compute_single_derivative_steps(expr: &Expression, variable: &Symbol) -> Step
                 explain_general_product_rule(factors, variable)
             }
         }
-        Expression::Function { name, args } => {
+        Expression::Function { name, args, .. } => {
             if args.len() == 1 {
                 explain_chain_rule(name, &args[0], variable)
             } else {
@@ -0,0 +1,334 @@
+//! Worklist differentiation with configurable simplification
+//!
+//! [`Derivative::derivative`] recurses through the expression and simplifies
+//! after every rule it applies. [`IterativeDerivative`] walks the tree with an
+//! explicit stack instead, so deeply nested inputs cannot exhaust the call
+//! stack, and can defer simplification until the whole derivative is built.
+
+use crate::calculus::derivatives::{BasicDerivatives, Derivative, FunctionDerivatives, PowerRule};
+use crate::core::{Expression, Number, Symbol};
+use crate::simplify::Simplify;
+use std::sync::Arc;
+
+/// When intermediate derivatives are simplified
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DerivativeSimplification {
+    /// Simplify the derivative of every node as it is combined, matching
+    /// [`Derivative::derivative`]
+    #[default]
+    EachNode,
+    /// Build the derivative without simplifying and simplify it once at
+    /// the end
+    Deferred,
+}
+
+/// Differentiator that walks the expression with an explicit work stack
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::calculus::derivatives::{DerivativeSimplification, IterativeDerivative};
+/// use mathhook_core::{expr, symbol};
+///
+/// let x = symbol!(x);
+/// let differentiator = IterativeDerivative::new(DerivativeSimplification::Deferred);
+///
+/// let slope = differentiator.derivative(&expr!(x ^ 3), &x);
+/// assert_eq!(slope, expr!(3 * (x ^ 2)));
+///
+/// let fourth = differentiator.nth_derivative(&expr!(sin(x)), &x, 4);
+/// assert_eq!(fourth, expr!(sin(x)));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct IterativeDerivative {
+    simplification: DerivativeSimplification,
+}
+
+/// Pending work: differentiate a node, or combine its children's derivatives
+enum Task<'a> {
+    Visit(&'a Expression),
+    Combine(&'a Expression, Vec<&'a Expression>),
+}
+
+impl IterativeDerivative {
+    /// Create a differentiator with the given simplification policy
+    pub fn new(simplification: DerivativeSimplification) -> Self {
+        Self { simplification }
+    }
+
+    /// Derivative of `expr` with respect to `variable`, simplified
+    pub fn derivative(&self, expr: &Expression, variable: &Symbol) -> Expression {
+        let result = self.differentiate(expr, variable);
+        match self.simplification {
+            DerivativeSimplification::EachNode => result,
+            DerivativeSimplification::Deferred => result.simplify(),
+        }
+    }
+
+    /// `order`-th derivative of `expr`, simplifying after each order
+    pub fn nth_derivative(&self, expr: &Expression, variable: &Symbol, order: u32) -> Expression {
+        let mut result = expr.clone();
+        for _ in 0..order {
+            result = self.derivative(&result, variable);
+        }
+        result
+    }
+
+    fn differentiate(&self, expr: &Expression, variable: &Symbol) -> Expression {
+        let mut tasks = vec![Task::Visit(expr)];
+        let mut derivatives: Vec<Expression> = Vec::new();
+
+        while let Some(task) = tasks.pop() {
+            match task {
+                Task::Visit(node) => match Self::children(node) {
+                    Some(children) => {
+                        // Reversed so that the children finish in order
+                        let visits: Vec<Task> = children
+                            .iter()
+                            .rev()
+                            .map(|&child| Task::Visit(child))
+                            .collect();
+                        tasks.push(Task::Combine(node, children));
+                        tasks.extend(visits);
+                    }
+                    None => derivatives.push(Self::leaf(node, variable)),
+                },
+                Task::Combine(node, children) => {
+                    let parts = derivatives.split_off(derivatives.len() - children.len());
+                    let combined = self.combine(node, &children, parts, variable);
+                    derivatives.push(match self.simplification {
+                        DerivativeSimplification::EachNode => combined.simplify(),
+                        DerivativeSimplification::Deferred => combined,
+                    });
+                }
+            }
+        }
+
+        derivatives
+            .pop()
+            .expect("BUG: the root derivative is always produced")
+    }
+
+    /// Operands the iterative rules differentiate, or `None` for nodes
+    /// handled directly
+    fn children(node: &Expression) -> Option<Vec<&Expression>> {
+        match node {
+            Expression::Add(operands) | Expression::Mul(operands) => {
+                Some(operands.iter().collect())
+            }
+            Expression::Pow(base, exponent) => Some(vec![base.as_ref(), exponent.as_ref()]),
+            Expression::Function { name, args, .. }
+                if args.len() == 1
+                    && !matches!(name.as_ref(), "min" | "max" | "heaviside" | "dirac_delta") =>
+            {
+                Some(vec![&args[0]])
+            }
+            _ => None,
+        }
+    }
+
+    fn leaf(node: &Expression, variable: &Symbol) -> Expression {
+        match node {
+            Expression::Number(_) | Expression::Constant(_) => Expression::integer(0),
+            Expression::Symbol(symbol) => BasicDerivatives::handle_symbol(symbol, variable),
+            _ => node.derivative(variable.clone()),
+        }
+    }
+
+    /// Apply the rule for `node` given the derivatives of its children
+    fn combine(
+        &self,
+        node: &Expression,
+        children: &[&Expression],
+        derivatives: Vec<Expression>,
+        variable: &Symbol,
+    ) -> Expression {
+        match node {
+            Expression::Add(_) => self.sum(
+                derivatives
+                    .into_iter()
+                    .filter(|d| !d.is_zero_fast())
+                    .collect(),
+            ),
+            // Σᵢ f₁⋯fᵢ′⋯fₙ, keeping factor order for noncommutative products
+            Expression::Mul(_) => self.sum(
+                derivatives
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, d)| !d.is_zero_fast())
+                    .map(|(i, d)| {
+                        let mut factors: Vec<Expression> =
+                            children.iter().map(|&f| f.clone()).collect();
+                        factors[i] = d.clone();
+                        self.product(factors)
+                    })
+                    .collect(),
+            ),
+            Expression::Pow(base, exponent) => {
+                let (base_derivative, exponent_derivative) = (&derivatives[0], &derivatives[1]);
+                if base_derivative.is_zero_fast() && exponent_derivative.is_zero_fast() {
+                    return Expression::integer(0);
+                }
+                if exponent_derivative.is_zero_fast() {
+                    // d/dx[fⁿ] = n·fⁿ⁻¹·f′
+                    let reduced = match exponent.as_ref() {
+                        Expression::Number(Number::Integer(n)) => Expression::integer(n - 1),
+                        _ => Expression::add(vec![
+                            exponent.as_ref().clone(),
+                            Expression::integer(-1),
+                        ]),
+                    };
+                    return self.product(vec![
+                        exponent.as_ref().clone(),
+                        Expression::pow(base.as_ref().clone(), reduced),
+                        base_derivative.clone(),
+                    ]);
+                }
+                // d/dx[f^g] = f^g · (g′·ln(f) + g·f′/f)
+                self.product(vec![
+                    node.clone(),
+                    self.sum(vec![
+                        self.product(vec![
+                            exponent_derivative.clone(),
+                            Expression::function("ln", vec![base.as_ref().clone()]),
+                        ]),
+                        self.product(vec![
+                            exponent.as_ref().clone(),
+                            PowerRule::div(base_derivative.clone(), base.as_ref().clone()),
+                        ]),
+                    ]),
+                ])
+            }
+            Expression::Function { name, args, id } => {
+                let inner = &derivatives[0];
+                if inner.is_zero_fast() {
+                    return Expression::integer(0);
+                }
+                self.product(vec![
+                    FunctionDerivatives::get_by_id(*id, name, &args[0], variable.clone()),
+                    inner.clone(),
+                ])
+            }
+            _ => node.derivative(variable.clone()),
+        }
+    }
+
+    /// Sum of `terms`; in deferred mode a flat sum built without
+    /// simplifying, so combining never re-simplifies the operands
+    fn sum(&self, terms: Vec<Expression>) -> Expression {
+        match self.simplification {
+            DerivativeSimplification::EachNode => Expression::add(terms),
+            DerivativeSimplification::Deferred => match flatten(terms, |term| match term {
+                Expression::Add(terms) => Some(terms),
+                _ => None,
+            }) {
+                flat if flat.is_empty() => Expression::integer(0),
+                mut flat if flat.len() == 1 => flat.remove(0),
+                flat => Expression::Add(Arc::new(flat)),
+            },
+        }
+    }
+
+    /// Product of `factors`, flat and unsimplified in deferred mode
+    fn product(&self, factors: Vec<Expression>) -> Expression {
+        match self.simplification {
+            DerivativeSimplification::EachNode => Expression::mul(factors),
+            DerivativeSimplification::Deferred => match flatten(factors, |factor| match factor {
+                Expression::Mul(factors) => Some(factors),
+                _ => None,
+            }) {
+                flat if flat.is_empty() => Expression::integer(1),
+                mut flat if flat.len() == 1 => flat.remove(0),
+                flat => Expression::Mul(Arc::new(flat)),
+            },
+        }
+    }
+}
+
+/// Operands with nested operations of the same kind spliced in, in order
+fn flatten(
+    operands: Vec<Expression>,
+    nested: impl Fn(&Expression) -> Option<&Arc<Vec<Expression>>>,
+) -> Vec<Expression> {
+    let mut flat = Vec::with_capacity(operands.len());
+    for operand in operands {
+        match nested(&operand) {
+            Some(inner) => flat.extend(inner.iter().cloned()),
+            None => flat.push(operand),
+        }
+    }
+    flat
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algebra::solvers::Assumptions;
+    use crate::simplify::verification::find_mismatch;
+    use crate::{expr, symbol};
+
+    #[test]
+    fn test_matches_recursive_derivative() {
+        let x = symbol!(x);
+        let inputs = [
+            expr!(sin(x ^ 2)),
+            expr!((x ^ 3) * exp(2 * x) * ln(x)),
+            expr!(cos(sin(x ^ 2 + 1))),
+            Expression::pow(expr!(x), expr!(x)),
+        ];
+        for simplification in [
+            DerivativeSimplification::EachNode,
+            DerivativeSimplification::Deferred,
+        ] {
+            let differentiator = IterativeDerivative::new(simplification);
+            for input in &inputs {
+                let recursive = input.derivative(x.clone()).simplify();
+                let iterative = differentiator.derivative(input, &x);
+                assert!(
+                    find_mismatch(&recursive, &iterative, &Assumptions::new()).is_none(),
+                    "d/dx {} gave {} instead of {}",
+                    input,
+                    iterative,
+                    recursive
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_deeply_nested_input() {
+        let x = symbol!(x);
+        let mut nested = expr!(x);
+        for _ in 0..200 {
+            nested = Expression::function("sin", vec![nested]);
+        }
+        let differentiator = IterativeDerivative::new(DerivativeSimplification::Deferred);
+        let Expression::Mul(factors) = differentiator.derivative(&nested, &x) else {
+            panic!("expected a chain-rule product");
+        };
+        assert_eq!(factors.len(), 200);
+    }
+
+    #[test]
+    fn test_nested_chain_rule() {
+        let x = symbol!(x);
+        let mut nested = expr!(x ^ 2);
+        for _ in 0..8 {
+            nested = Expression::function("sin", vec![nested]);
+        }
+        let recursive = nested.derivative(x.clone()).simplify();
+        let iterative =
+            IterativeDerivative::new(DerivativeSimplification::Deferred).derivative(&nested, &x);
+        assert!(find_mismatch(&recursive, &iterative, &Assumptions::new()).is_none());
+    }
+
+    #[test]
+    fn test_tenth_derivative_of_sin_x_squared() {
+        let x = symbol!(x);
+        let input = expr!(sin(x ^ 2));
+        let eager = IterativeDerivative::default().nth_derivative(&input, &x, 10);
+        let deferred = IterativeDerivative::new(DerivativeSimplification::Deferred)
+            .nth_derivative(&input, &x, 10);
+        assert!(find_mismatch(&eager, &deferred, &Assumptions::new()).is_none());
+    }
+}
@@ -0,0 +1,273 @@
+//! Chainable access to the calculus operations
+//!
+//! The operations live on separate traits ([`Derivative`], [`Integration`],
+//! [`Limits`], [`SeriesExpansion`], [`Summation`]), each with its own
+//! argument conventions. [`Expression::calculus`] gathers them behind one
+//! builder: set the variable, order, bounds or point, then finish with the
+//! operation. The traits remain the lower-level interface.
+
+use super::{Derivative, Integration, LimitDirection, Limits, SeriesExpansion, Summation};
+use crate::core::{Expression, MathConstant, Symbol};
+use crate::error::MathError;
+
+/// Calculus operation on an expression being configured
+///
+/// Created by [`Expression::calculus`].
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::{expr, symbol};
+///
+/// let x = symbol!(x);
+/// let f = expr!(x ^ 3);
+///
+/// let second = f.calculus().wrt(x.clone()).order(2).derivative().unwrap();
+/// assert_eq!(second, expr!(6 * x));
+///
+/// let area = f.calculus().wrt(x).from(0).to(2).integrate().unwrap();
+/// assert_eq!(area, expr!(4));
+/// ```
+#[derive(Debug, Clone)]
+#[must_use = "a calculus builder does nothing until an operation is called"]
+pub struct CalculusBuilder<'a> {
+    expr: &'a Expression,
+    variable: Option<Symbol>,
+    order: Option<u32>,
+    lower: Option<Expression>,
+    upper: Option<Expression>,
+    point: Option<Expression>,
+    direction: LimitDirection,
+}
+
+impl Expression {
+    /// Start a chained calculus operation on this expression
+    pub fn calculus(&self) -> CalculusBuilder<'_> {
+        CalculusBuilder {
+            expr: self,
+            variable: None,
+            order: None,
+            lower: None,
+            upper: None,
+            point: None,
+            direction: LimitDirection::Both,
+        }
+    }
+}
+
+impl<'a> CalculusBuilder<'a> {
+    /// Variable to differentiate, integrate, sum or take the limit in
+    ///
+    /// May be omitted when the expression has exactly one variable.
+    pub fn wrt(mut self, variable: Symbol) -> Self {
+        self.variable = Some(variable);
+        self
+    }
+
+    /// Order of the derivative, or degree of the series; 1 by default
+    pub fn order(mut self, order: u32) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Lower bound of an integral or sum
+    pub fn from(mut self, lower: impl Into<Expression>) -> Self {
+        self.lower = Some(lower.into());
+        self
+    }
+
+    /// Upper bound of an integral or sum
+    pub fn to(mut self, upper: impl Into<Expression>) -> Self {
+        self.upper = Some(upper.into());
+        self
+    }
+
+    /// Point a limit approaches or a series is expanded around
+    pub fn at(mut self, point: impl Into<Expression>) -> Self {
+        self.point = Some(point.into());
+        self
+    }
+
+    /// Side a limit approaches from; both by default
+    pub fn direction(mut self, direction: LimitDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Derivative of the configured order
+    ///
+    /// # Errors
+    ///
+    /// Returns `MathError::DomainError` when no variable was given and the
+    /// expression does not have exactly one.
+    pub fn derivative(self) -> Result<Expression, MathError> {
+        let variable = self.variable("derivative")?;
+        Ok(self.expr.nth_derivative(variable, self.order.unwrap_or(1)))
+    }
+
+    /// Antiderivative, or the definite integral when both bounds are set
+    ///
+    /// # Errors
+    ///
+    /// Returns `MathError::DomainError` when the variable cannot be inferred,
+    /// when only one bound is set, or when the antiderivative cannot be
+    /// evaluated at the bounds.
+    pub fn integrate(self) -> Result<Expression, MathError> {
+        let variable = self.variable("integrate")?;
+        match self.bounds("integrate")? {
+            Some((lower, upper)) => self.expr.definite_integrate(variable, lower, upper),
+            None => Ok(self.expr.integrate(variable, 0)),
+        }
+    }
+
+    /// Limit as the variable approaches the point set with [`Self::at`]
+    ///
+    /// An infinite point gives the limit at infinity.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MathError::DomainError` when the variable cannot be inferred
+    /// or no point was set.
+    pub fn limit(self) -> Result<Expression, MathError> {
+        let variable = self.variable("limit")?;
+        let point = self.required_point("limit")?;
+        Ok(match (&point, self.direction) {
+            (Expression::Constant(MathConstant::Infinity), _) => {
+                self.expr.limit_at_infinity(&variable)
+            }
+            (Expression::Constant(MathConstant::NegativeInfinity), _) => {
+                self.expr.limit_at_negative_infinity(&variable)
+            }
+            (_, LimitDirection::Both) => self.expr.limit(&variable, &point),
+            (_, direction) => self.expr.limit_directed(&variable, &point, direction),
+        })
+    }
+
+    /// Taylor polynomial of the configured degree around the point set with
+    /// [`Self::at`], or around 0 when none was set
+    ///
+    /// # Errors
+    ///
+    /// Returns `MathError::DomainError` when the variable cannot be inferred.
+    pub fn series(self) -> Result<Expression, MathError> {
+        let variable = self.variable("series")?;
+        let order = self.order.unwrap_or(1);
+        Ok(match &self.point {
+            Some(point) => self.expr.taylor_series(&variable, point, order),
+            None => self.expr.maclaurin_series(&variable, order),
+        })
+    }
+
+    /// Sum over the variable from the lower to the upper bound
+    ///
+    /// An infinite upper bound gives the infinite sum.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MathError::DomainError` when the variable cannot be inferred
+    /// or either bound is missing.
+    pub fn sum(self) -> Result<Expression, MathError> {
+        let variable = self.variable("sum")?;
+        let Some((lower, upper)) = self.bounds("sum")? else {
+            return Err(self.error("sum", "both bounds are required"));
+        };
+        Ok(match upper {
+            Expression::Constant(MathConstant::Infinity) => {
+                self.expr.infinite_sum(&variable, &lower)
+            }
+            upper => self.expr.finite_sum(&variable, &lower, &upper),
+        })
+    }
+
+    fn variable(&self, operation: &str) -> Result<Symbol, MathError> {
+        if let Some(variable) = &self.variable {
+            return Ok(variable.clone());
+        }
+        match self.expr.find_variables().as_slice() {
+            [variable] => Ok(variable.clone()),
+            [] => Err(self.error(operation, "the expression has no variable; use wrt")),
+            _ => Err(self.error(
+                operation,
+                "the expression has several variables; use wrt to choose one",
+            )),
+        }
+    }
+
+    fn bounds(&self, operation: &str) -> Result<Option<(Expression, Expression)>, MathError> {
+        match (&self.lower, &self.upper) {
+            (Some(lower), Some(upper)) => Ok(Some((lower.clone(), upper.clone()))),
+            (None, None) => Ok(None),
+            _ => Err(self.error(operation, "set both bounds with from and to")),
+        }
+    }
+
+    fn required_point(&self, operation: &str) -> Result<Expression, MathError> {
+        self.point
+            .clone()
+            .ok_or_else(|| self.error(operation, "set the point with at"))
+    }
+
+    fn error(&self, operation: &str, reason: &str) -> MathError {
+        MathError::DomainError {
+            operation: operation.to_owned(),
+            value: self.expr.clone(),
+            reason: reason.to_owned(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{expr, symbol};
+
+    #[test]
+    fn test_matches_traits() {
+        let x = symbol!(x);
+        let f = expr!((x ^ 4) + (3 * x));
+        assert_eq!(
+            f.calculus().wrt(x.clone()).order(3).derivative().unwrap(),
+            f.nth_derivative(x.clone(), 3)
+        );
+        assert_eq!(f.calculus().integrate().unwrap(), f.integrate(x.clone(), 0));
+        assert_eq!(f.calculus().series().unwrap(), f.maclaurin_series(&x, 1));
+    }
+
+    #[test]
+    fn test_limits() {
+        let x = symbol!(x);
+        let reciprocal = expr!(x ^ (-1));
+        assert_eq!(
+            reciprocal
+                .calculus()
+                .at(Expression::infinity())
+                .limit()
+                .unwrap(),
+            expr!(0)
+        );
+        assert_eq!(
+            reciprocal
+                .calculus()
+                .at(0)
+                .direction(LimitDirection::Right)
+                .limit()
+                .unwrap(),
+            reciprocal.limit_directed(&x, &expr!(0), LimitDirection::Right)
+        );
+    }
+
+    #[test]
+    fn test_missing_configuration() {
+        let x = symbol!(x);
+        let y = symbol!(y);
+        let f = expr!(x * y);
+        assert!(f.calculus().derivative().is_err());
+        assert_eq!(
+            f.calculus().wrt(y.clone()).derivative().unwrap(),
+            Expression::symbol(x.clone())
+        );
+        assert!(f.calculus().wrt(x.clone()).from(0).integrate().is_err());
+        assert!(f.calculus().wrt(x).limit().is_err());
+        assert!(f.calculus().wrt(y).sum().is_err());
+    }
+}
@@ -7,36 +7,47 @@
 
 mod basic;
 pub mod by_parts;
-// mod definite;
+pub mod coverage;
+mod definite;
+pub mod distributions;
 pub mod educational;
 mod function_integrals;
+pub mod heuristics;
 pub mod numerical;
 pub mod rational;
 pub mod risch;
 pub mod strategy;
 pub mod substitution;
 pub mod table;
+pub mod trig_substitution;
 pub mod trigonometric;
+pub mod weierstrass;
 
 pub use basic::BasicIntegrals;
 pub use by_parts::IntegrationByParts;
-// pub use definite::DefiniteIntegrals;
+pub use coverage::{
+    classify_integrand, integration_coverage, CoveragePrediction, CoverageReport, IntegrandClass,
+    NonElementaryKind,
+};
 pub use educational::{
     explain_constant_rule, explain_definite_integral, explain_integration_by_parts,
     explain_power_rule, explain_sum_rule, explain_u_substitution,
 };
 pub use function_integrals::FunctionIntegrals;
+pub use heuristics::{try_heuristic_integration, HeuristicResult};
 pub use numerical::{
     AdaptiveSimpson, GaussianQuadrature, IntegrationConfig, IntegrationResult, NumericalIntegrator,
-    RombergIntegration,
+    RombergIntegration, TanhSinh,
 };
 pub use rational::{integrate_rational, is_rational_function};
+pub use strategy::{integrate_with_provenance, integrate_with_technique, IntegrationStrategy};
 pub use substitution::try_substitution;
+pub use trig_substitution::{explain_trig_substitution, try_trig_substitution};
 pub use trigonometric::try_trigonometric_integration;
+pub use weierstrass::{explain_weierstrass_substitution, try_weierstrass_substitution};
 
 use crate::core::{Expression, Symbol};
 use crate::error::MathError;
-use std::collections::HashMap;
 use strategy::integrate_with_strategy;
 
 /// Trait for integration operations
@@ -72,9 +83,11 @@ pub trait Integration {
 
     /// Compute definite integral
     ///
+    /// Same as [`Expression::integrate_definite`].
+    ///
     /// # Errors
     ///
-    /// Returns `MathError::DomainError` when evaluation at bounds fails.
+    /// Returns `MathError::ConvergenceFailed` when the integral diverges.
     ///
     /// # Examples
     ///
@@ -109,19 +122,7 @@ impl Integration for Expression {
         lower: Expression,
         upper: Expression,
     ) -> Result<Expression, MathError> {
-        let antiderivative = self.integrate(variable.clone(), 0);
-
-        let mut substitutions = HashMap::new();
-        substitutions.insert(variable.name().to_string(), upper.clone());
-        let upper_val = antiderivative.clone().substitute(&substitutions);
-
-        substitutions.insert(variable.name().to_string(), lower.clone());
-        let lower_val = antiderivative.substitute(&substitutions);
-
-        Ok(Expression::add(vec![
-            upper_val,
-            Expression::mul(vec![Expression::integer(-1), lower_val]),
-        ]))
+        self.integrate_definite(&variable, &lower, &upper)
     }
 }
 
@@ -0,0 +1,621 @@
+//! Integration coverage diagnostics
+//!
+//! Classifies an integrand before integrating it, so callers know ahead of time
+//! whether MathHook covers it and by which technique, instead of receiving an
+//! unevaluated integral with no explanation.
+//!
+//! # Classes
+//!
+//! | Class               | Example                 | Prediction                       |
+//! |---------------------|-------------------------|----------------------------------|
+//! | Constant            | `5`, `a·b`              | basic rules                      |
+//! | Polynomial          | `x² + 3x`               | basic rules                      |
+//! | Rational            | `(x + 1)/(x² - 4)`      | partial fractions                |
+//! | Trigonometric       | `1/(2 + cos(x))`        | tangent half-angle substitution  |
+//! | Quadratic radical   | `√(4 - x²)`             | trigonometric substitution       |
+//! | Non-elementary      | `e^(x²)`, `sin(x)/x`    | no elementary antiderivative     |
+//! | Elementary          | `x·eˣ`, `ln(x)`         | possible (parts, substitution, Risch) |
+//! | Special function    | `Γ(x)`, `H(x - 1)`      | possible                         |
+//! | Unsupported         | `f(x)`, matrices        | unsupported                      |
+//!
+//! A prediction names the technique that covers the whole class; a cheaper
+//! layer of the strategy dispatcher may still produce the result first.
+//! [`integration_coverage`] also integrates and checks the antiderivative by
+//! differentiating it at sample points.
+
+use crate::calculus::derivatives::Derivative;
+use crate::calculus::integrals::strategy::{integrate_with_technique, IntegrationStrategy};
+use crate::calculus::integrals::{trig_substitution, weierstrass};
+use crate::core::{Expression, MathConstant, Number, Symbol};
+use crate::functions::intelligence::get_universal_registry;
+use crate::functions::properties::FunctionProperties;
+use std::collections::HashMap;
+use std::fmt;
+
+/// Sample points for checking an antiderivative by differentiation
+const SAMPLE_POINTS: [f64; 5] = [0.37, 0.83, 1.29, 1.91, 2.63];
+
+/// Relative tolerance when comparing the derivative with the integrand
+const TOLERANCE: f64 = 1e-6;
+
+/// Elementary functions known to the integrator under names the function
+/// registry does not list
+const ELEMENTARY_ALIASES: [&str; 6] = ["asin", "acos", "atan", "asinh", "acosh", "atanh"];
+
+/// Shape of an integrand with respect to the variable of integration
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntegrandClass {
+    /// Free of the variable of integration
+    Constant,
+    Polynomial,
+    /// Quotient of polynomials
+    Rational,
+    /// Rational function of sin, cos, tan, cot, sec and csc of one linear argument
+    TrigRational,
+    /// xᵐ times an odd power of √(a² - x²), √(x² + a²) or √(x² - a²)
+    QuadraticRadical,
+    /// Matches a pattern with no elementary antiderivative
+    NonElementary(NonElementaryKind),
+    /// Other combinations of elementary functions
+    Elementary,
+    /// Contains special functions (Γ, Bessel, erf, ...) or distributions
+    SpecialFunction,
+    /// Contains undefined functions or non-scalar objects
+    Unsupported,
+}
+
+/// Non-elementary integrals, named by the special function expressing them
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NonElementaryKind {
+    /// e^(p(x)) with deg p ≥ 2, as in ∫e^(-x²) dx = (√π/2)·erf(x)
+    ErrorFunction,
+    /// sin(p(x)) or cos(p(x)) with deg p ≥ 2
+    Fresnel,
+    /// e^(ax + b)/(cx + d)
+    ExponentialIntegral,
+    /// sin(ax + b)/(cx + d)
+    SineIntegral,
+    /// cos(ax + b)/(cx + d)
+    CosineIntegral,
+    /// 1/ln(ax + b)
+    LogarithmicIntegral,
+    /// Square root of a cubic or quartic
+    Elliptic,
+}
+
+impl NonElementaryKind {
+    /// Special function in which the antiderivative is expressed
+    pub fn special_function(&self) -> &'static str {
+        match self {
+            NonElementaryKind::ErrorFunction => "erf",
+            NonElementaryKind::Fresnel => "Fresnel S/C",
+            NonElementaryKind::ExponentialIntegral => "Ei",
+            NonElementaryKind::SineIntegral => "Si",
+            NonElementaryKind::CosineIntegral => "Ci",
+            NonElementaryKind::LogarithmicIntegral => "li",
+            NonElementaryKind::Elliptic => "elliptic integrals",
+        }
+    }
+}
+
+/// What MathHook is expected to do with an integrand class
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoveragePrediction {
+    /// The technique integrates every integrand of the class in closed form
+    Supported(IntegrationStrategy),
+    /// Integrable when substitution, integration by parts, the heuristics or
+    /// the Risch algorithm recognize the structure
+    Possible,
+    /// No elementary antiderivative exists; the integral stays unevaluated
+    NonElementary(NonElementaryKind),
+    /// Left unevaluated
+    Unsupported,
+}
+
+impl IntegrandClass {
+    /// Expected outcome of integrating an integrand of this class
+    pub fn prediction(&self) -> CoveragePrediction {
+        match self {
+            IntegrandClass::Constant | IntegrandClass::Polynomial => {
+                CoveragePrediction::Supported(IntegrationStrategy::BasicRules)
+            }
+            IntegrandClass::Rational => {
+                CoveragePrediction::Supported(IntegrationStrategy::RationalFunction)
+            }
+            IntegrandClass::TrigRational => {
+                CoveragePrediction::Supported(IntegrationStrategy::WeierstrassSubstitution)
+            }
+            IntegrandClass::QuadraticRadical => {
+                CoveragePrediction::Supported(IntegrationStrategy::TrigSubstitution)
+            }
+            IntegrandClass::NonElementary(kind) => CoveragePrediction::NonElementary(*kind),
+            IntegrandClass::Elementary | IntegrandClass::SpecialFunction => {
+                CoveragePrediction::Possible
+            }
+            IntegrandClass::Unsupported => CoveragePrediction::Unsupported,
+        }
+    }
+}
+
+impl fmt::Display for IntegrandClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IntegrandClass::Constant => write!(f, "constant"),
+            IntegrandClass::Polynomial => write!(f, "polynomial"),
+            IntegrandClass::Rational => write!(f, "rational function"),
+            IntegrandClass::TrigRational => {
+                write!(f, "rational function of trigonometric functions")
+            }
+            IntegrandClass::QuadraticRadical => write!(f, "square root of a quadratic"),
+            IntegrandClass::NonElementary(kind) => {
+                write!(f, "non-elementary ({} class)", kind.special_function())
+            }
+            IntegrandClass::Elementary => write!(f, "elementary function"),
+            IntegrandClass::SpecialFunction => write!(f, "special function"),
+            IntegrandClass::Unsupported => write!(f, "unsupported integrand"),
+        }
+    }
+}
+
+/// Classification of an integrand together with the result of integrating it
+#[derive(Debug, Clone, PartialEq)]
+pub struct CoverageReport {
+    pub class: IntegrandClass,
+    pub prediction: CoveragePrediction,
+    /// Technique that produced the antiderivative; `None` when the integral
+    /// was left unevaluated
+    pub technique: Option<IntegrationStrategy>,
+    pub antiderivative: Expression,
+    /// Whether the derivative of the antiderivative matches the integrand at
+    /// sample points; `None` when it could not be evaluated there
+    pub verified: Option<bool>,
+}
+
+impl CoverageReport {
+    /// Whether MathHook produced an antiderivative that was not refuted
+    pub fn is_integrated(&self) -> bool {
+        self.technique.is_some() && self.verified != Some(false)
+    }
+}
+
+impl fmt::Display for CoverageReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.technique, self.verified) {
+            (None, _) => write!(f, "{}: left unevaluated", self.class),
+            (Some(technique), Some(false)) => write!(
+                f,
+                "{}: {} produced an antiderivative that does not differentiate back to the integrand",
+                self.class,
+                technique.name()
+            ),
+            (Some(technique), _) => write!(f, "{}: integrated by {}", self.class, technique.name()),
+        }
+    }
+}
+
+/// Classify an integrand without integrating it
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::calculus::integrals::coverage::{
+///     classify_integrand, CoveragePrediction, IntegrandClass, NonElementaryKind,
+/// };
+/// use mathhook_core::calculus::integrals::strategy::IntegrationStrategy;
+/// use mathhook_core::{expr, symbol};
+///
+/// let x = symbol!(x);
+/// let class = classify_integrand(&expr!((x + 1) / ((x ^ 2) - 4)), &x);
+/// assert_eq!(class, IntegrandClass::Rational);
+/// assert_eq!(
+///     class.prediction(),
+///     CoveragePrediction::Supported(IntegrationStrategy::RationalFunction)
+/// );
+///
+/// let gaussian = classify_integrand(&expr!(exp(x ^ 2)), &x);
+/// assert_eq!(
+///     gaussian,
+///     IntegrandClass::NonElementary(NonElementaryKind::ErrorFunction)
+/// );
+/// ```
+pub fn classify_integrand(expr: &Expression, var: &Symbol) -> IntegrandClass {
+    if !expr.contains_variable(var) {
+        return IntegrandClass::Constant;
+    }
+    if let Some(class) = unsupported_or_special(expr, var) {
+        return class;
+    }
+    if degree_in(expr, var).is_some() {
+        return IntegrandClass::Polynomial;
+    }
+    if is_rational_in(expr, var) {
+        return IntegrandClass::Rational;
+    }
+    if let Some(kind) = non_elementary_kind(expr, var) {
+        return IntegrandClass::NonElementary(kind);
+    }
+    if weierstrass::is_rational_trig(expr, var) {
+        return IntegrandClass::TrigRational;
+    }
+    if trig_substitution::detect_radical_form(expr, var).is_some() {
+        return IntegrandClass::QuadraticRadical;
+    }
+    IntegrandClass::Elementary
+}
+
+/// Classify, integrate and check the antiderivative
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::calculus::integrals::coverage::{integration_coverage, IntegrandClass};
+/// use mathhook_core::calculus::integrals::strategy::IntegrationStrategy;
+/// use mathhook_core::{expr, symbol};
+///
+/// let x = symbol!(x);
+/// let report = integration_coverage(&expr!(x * exp(x)), &x);
+/// assert_eq!(report.class, IntegrandClass::Elementary);
+/// assert_eq!(report.technique, Some(IntegrationStrategy::IntegrationByParts));
+/// assert!(report.is_integrated());
+///
+/// let report = integration_coverage(&expr!(sin(x ^ 2)), &x);
+/// assert!(!report.is_integrated());
+/// assert_eq!(
+///     report.to_string(),
+///     "non-elementary (Fresnel S/C class): left unevaluated"
+/// );
+/// ```
+pub fn integration_coverage(expr: &Expression, var: &Symbol) -> CoverageReport {
+    let class = classify_integrand(expr, var);
+    let (antiderivative, technique) = integrate_with_technique(expr, var.clone(), 0);
+    let verified = technique.and_then(|_| verify_antiderivative(expr, &antiderivative, var));
+    CoverageReport {
+        class,
+        prediction: class.prediction(),
+        technique,
+        antiderivative,
+        verified,
+    }
+}
+
+/// Unsupported or special-function class from the nodes involving `var`
+fn unsupported_or_special(expr: &Expression, var: &Symbol) -> Option<IntegrandClass> {
+    let mut special = false;
+    let mut stack = vec![expr];
+    while let Some(node) = stack.pop() {
+        if !node.contains_variable(var) {
+            continue;
+        }
+        match node {
+            Expression::Number(_) | Expression::Symbol(_) | Expression::Constant(_) => {}
+            Expression::Add(children) | Expression::Mul(children) => stack.extend(children.iter()),
+            Expression::Pow(base, exp) => {
+                stack.push(base);
+                stack.push(exp);
+            }
+            Expression::Function { name, args, .. } => {
+                match get_universal_registry().get_properties(name) {
+                    Some(FunctionProperties::Elementary(_)) => {}
+                    Some(_) => special = true,
+                    None if ELEMENTARY_ALIASES.contains(&name.as_ref()) => {}
+                    None if matches!(
+                        name.as_ref(),
+                        "heaviside" | "dirac_delta" | "erf" | "erfc"
+                    ) =>
+                    {
+                        special = true
+                    }
+                    None => return Some(IntegrandClass::Unsupported),
+                }
+                stack.extend(args.iter());
+            }
+            _ => return Some(IntegrandClass::Unsupported),
+        }
+    }
+    special.then_some(IntegrandClass::SpecialFunction)
+}
+
+/// Degree in `var` if `expr` is a polynomial in `var` (coefficients may be
+/// any expressions free of `var`)
+fn degree_in(expr: &Expression, var: &Symbol) -> Option<i64> {
+    if !expr.contains_variable(var) {
+        return Some(0);
+    }
+    match expr {
+        Expression::Symbol(s) if s == var => Some(1),
+        Expression::Add(terms) => terms
+            .iter()
+            .map(|term| degree_in(term, var))
+            .try_fold(0, |max, degree| degree.map(|d| max.max(d))),
+        Expression::Mul(factors) => factors.iter().map(|factor| degree_in(factor, var)).sum(),
+        Expression::Pow(base, exp) => match exp.as_ref() {
+            Expression::Number(Number::Integer(n)) if *n >= 0 => Some(degree_in(base, var)? * n),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn is_rational_in(expr: &Expression, var: &Symbol) -> bool {
+    if degree_in(expr, var).is_some() {
+        return true;
+    }
+    match expr {
+        Expression::Add(children) | Expression::Mul(children) => {
+            children.iter().all(|child| is_rational_in(child, var))
+        }
+        Expression::Pow(base, exp) => {
+            matches!(exp.as_ref(), Expression::Number(Number::Integer(_)))
+                && is_rational_in(base, var)
+        }
+        _ => false,
+    }
+}
+
+/// Match the integrand, without constant factors, against the classic
+/// non-elementary patterns
+fn non_elementary_kind(expr: &Expression, var: &Symbol) -> Option<NonElementaryKind> {
+    let factors: Vec<&Expression> = match expr {
+        Expression::Mul(factors) => factors
+            .iter()
+            .filter(|factor| factor.contains_variable(var))
+            .collect(),
+        _ => vec![expr],
+    };
+
+    match factors.as_slice() {
+        [single] => {
+            if let Some(argument) = exponential_argument(single) {
+                return (degree_in(argument, var)? >= 2)
+                    .then_some(NonElementaryKind::ErrorFunction);
+            }
+            match *single {
+                Expression::Function { name, args, .. }
+                    if matches!(name.as_ref(), "sin" | "cos") && args.len() == 1 =>
+                {
+                    (degree_in(&args[0], var)? >= 2).then_some(NonElementaryKind::Fresnel)
+                }
+                Expression::Pow(base, exp) if is_integer(exp, -1) => match base.as_ref() {
+                    Expression::Function { name, args, .. }
+                        if name.as_ref() == "ln" && args.len() == 1 =>
+                    {
+                        (degree_in(&args[0], var)? == 1)
+                            .then_some(NonElementaryKind::LogarithmicIntegral)
+                    }
+                    _ => None,
+                },
+                _ => {
+                    let radicand = square_root_radicand(single)?;
+                    let degree = degree_in(radicand, var)?;
+                    let terms = matches!(radicand, Expression::Add(terms) if terms.len() >= 2);
+                    (terms && (3..=4).contains(&degree)).then_some(NonElementaryKind::Elliptic)
+                }
+            }
+        }
+        [first, second] => {
+            let (numerator, denominator) = if reciprocal_of_linear(second, var) {
+                (*first, *second)
+            } else {
+                (*second, *first)
+            };
+            if !reciprocal_of_linear(denominator, var) {
+                return None;
+            }
+            if let Some(argument) = exponential_argument(numerator) {
+                return (degree_in(argument, var)? == 1)
+                    .then_some(NonElementaryKind::ExponentialIntegral);
+            }
+            match numerator {
+                Expression::Function { name, args, .. }
+                    if args.len() == 1 && degree_in(&args[0], var) == Some(1) =>
+                {
+                    match name.as_ref() {
+                        "sin" => Some(NonElementaryKind::SineIntegral),
+                        "cos" => Some(NonElementaryKind::CosineIntegral),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Argument `u` of `exp(u)` or `e^u`
+fn exponential_argument(expr: &Expression) -> Option<&Expression> {
+    match expr {
+        Expression::Function { name, args, .. } if name.as_ref() == "exp" && args.len() == 1 => {
+            Some(&args[0])
+        }
+        Expression::Pow(base, exp) if **base == Expression::Constant(MathConstant::E) => Some(exp),
+        _ => None,
+    }
+}
+
+/// Radicand `p` of `sqrt(p)` or `p^(±1/2)`
+fn square_root_radicand(expr: &Expression) -> Option<&Expression> {
+    match expr {
+        Expression::Function { name, args, .. } if name.as_ref() == "sqrt" && args.len() == 1 => {
+            Some(&args[0])
+        }
+        Expression::Pow(base, exp) => match exp.as_ref() {
+            Expression::Number(Number::Rational(r)) if *r.denom() == 2.into() => Some(base),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// `(ax + b)^(-1)`
+fn reciprocal_of_linear(expr: &Expression, var: &Symbol) -> bool {
+    matches!(expr, Expression::Pow(base, exp) if is_integer(exp, -1) && degree_in(base, var) == Some(1))
+}
+
+fn is_integer(expr: &Expression, value: i64) -> bool {
+    matches!(expr, Expression::Number(Number::Integer(n)) if *n == value)
+}
+
+/// Compare the derivative of `antiderivative` with `integrand` at sample points
+///
+/// Other symbols are fixed to arbitrary values. Points where either side does
+/// not evaluate to a finite number are skipped; `None` if fewer than two remain.
+fn verify_antiderivative(
+    integrand: &Expression,
+    antiderivative: &Expression,
+    var: &Symbol,
+) -> Option<bool> {
+    let derivative = antiderivative.derivative(var.clone());
+    let mut others: Vec<Symbol> = integrand.find_variables();
+    others.extend(antiderivative.find_variables());
+    others.retain(|symbol| symbol != var);
+
+    let mut checked = 0;
+    for point in SAMPLE_POINTS {
+        let mut values: HashMap<String, Expression> = others
+            .iter()
+            .enumerate()
+            .map(|(i, symbol)| {
+                let value = 1.17 + 0.31 * i as f64;
+                (symbol.name().to_owned(), Expression::float(value))
+            })
+            .collect();
+        values.insert(var.name().to_owned(), Expression::float(point));
+
+        let expected = integrand.substitute(&values).evaluate_to_f64();
+        let actual = derivative.substitute(&values).evaluate_to_f64();
+        if let (Ok(expected), Ok(actual)) = (expected, actual) {
+            if !expected.is_finite() || !actual.is_finite() {
+                continue;
+            }
+            if (expected - actual).abs() > TOLERANCE * expected.abs().max(1.0) {
+                return Some(false);
+            }
+            checked += 1;
+        }
+    }
+    (checked >= 2).then_some(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{expr, symbol};
+
+    #[test]
+    fn test_algebraic_classes() {
+        let x = symbol!(x);
+        assert_eq!(
+            classify_integrand(&expr!(y ^ 2), &x),
+            IntegrandClass::Constant
+        );
+        assert_eq!(
+            classify_integrand(&expr!((x ^ 3) + (y * x)), &x),
+            IntegrandClass::Polynomial
+        );
+        assert_eq!(
+            classify_integrand(&expr!(1 / ((x ^ 2) + 1)), &x),
+            IntegrandClass::Rational
+        );
+        assert_eq!(
+            classify_integrand(&expr!(sqrt(4 - (x ^ 2))), &x),
+            IntegrandClass::QuadraticRadical
+        );
+        assert_eq!(
+            classify_integrand(&expr!((2 + cos(x)) ^ (-1)), &x),
+            IntegrandClass::TrigRational
+        );
+    }
+
+    #[test]
+    fn test_non_elementary_patterns() {
+        let x = symbol!(x);
+        let cases = [
+            (expr!(3 * exp(x ^ 2)), NonElementaryKind::ErrorFunction),
+            (expr!(cos(2 * (x ^ 2))), NonElementaryKind::Fresnel),
+            (expr!(exp(x) / x), NonElementaryKind::ExponentialIntegral),
+            (expr!(sin(x) / x), NonElementaryKind::SineIntegral),
+            (
+                expr!(cos(2 * x) / (x + 1)),
+                NonElementaryKind::CosineIntegral,
+            ),
+            (expr!(ln(x) ^ (-1)), NonElementaryKind::LogarithmicIntegral),
+            (expr!(sqrt((x ^ 3) + 1)), NonElementaryKind::Elliptic),
+        ];
+        for (integrand, kind) in cases {
+            assert_eq!(
+                classify_integrand(&integrand, &x),
+                IntegrandClass::NonElementary(kind),
+                "{}",
+                integrand
+            );
+        }
+
+        // Elementary despite the resemblance
+        assert_eq!(
+            classify_integrand(&expr!(x * exp(x ^ 2)), &x),
+            IntegrandClass::Elementary
+        );
+        assert_eq!(
+            classify_integrand(&expr!(exp(2 * x)), &x),
+            IntegrandClass::Elementary
+        );
+    }
+
+    #[test]
+    fn test_special_and_unsupported() {
+        let x = symbol!(x);
+        let gamma = Expression::function("gamma", vec![expr!(x)]);
+        assert_eq!(
+            classify_integrand(&gamma, &x),
+            IntegrandClass::SpecialFunction
+        );
+        let step = Expression::function("heaviside", vec![expr!(x - 1)]);
+        assert_eq!(
+            classify_integrand(&step, &x),
+            IntegrandClass::SpecialFunction
+        );
+        let unknown = Expression::function("f", vec![expr!(x)]);
+        assert_eq!(
+            classify_integrand(&unknown, &x),
+            IntegrandClass::Unsupported
+        );
+        assert_eq!(
+            IntegrandClass::Unsupported.prediction(),
+            CoveragePrediction::Unsupported
+        );
+    }
+
+    #[test]
+    fn test_report_matches_integration() {
+        let x = symbol!(x);
+        let report = integration_coverage(&expr!((x + 1) / ((x ^ 2) - 4)), &x);
+        assert_eq!(
+            report.technique,
+            Some(IntegrationStrategy::RationalFunction)
+        );
+        assert_eq!(report.verified, Some(true));
+        assert_eq!(
+            report.to_string(),
+            "rational function: integrated by rational"
+        );
+
+        let report = integration_coverage(&expr!(exp(x ^ 2)), &x);
+        assert_eq!(report.technique, None);
+        assert!(!report.is_integrated());
+    }
+
+    #[test]
+    fn test_wrong_antiderivative_is_refuted() {
+        let x = symbol!(x);
+        let integrand = expr!(cos(x));
+        assert_eq!(
+            verify_antiderivative(&integrand, &expr!(sin(x)), &x),
+            Some(true)
+        );
+        assert_eq!(
+            verify_antiderivative(&integrand, &expr!(x * sin(x)), &x),
+            Some(false)
+        );
+    }
+}
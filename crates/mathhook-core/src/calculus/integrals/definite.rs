@@ -0,0 +1,518 @@
+//! Definite integration by the fundamental theorem of calculus
+//!
+//! An antiderivative F is found first, then evaluated at the bounds. F(b) - F(a)
+//! is only the integral when F is continuous on [a, b], so the interval is split
+//! at every point inside it where the integrand or F may be singular (located
+//! as in [`find_singularities`]), and each piece contributes F(right⁻) - F(left⁺).
+//!
+//! # Bound Evaluation
+//!
+//! - At ±∞, F is evaluated exactly from the limits of elementary functions:
+//!   exp(x) → ∞, exp(-x) → 0, atan(x) → π/2, powers of x, and so on
+//! - Elsewhere the one-sided limit is estimated by evaluating F ever closer
+//!   to the point, and F at the point itself is used instead whenever it
+//!   agrees, so the result stays exact wherever F is continuous
+//!
+//! A limit that is infinite or never settles (such as sin(x) as x → ∞) means
+//! the improper integral diverges, which is reported as an error.
+
+use super::numerical::find_singularities;
+use super::Integration;
+use crate::core::constants::MathConstant;
+use crate::core::{Expression, Symbol};
+use crate::error::MathError;
+use crate::simplify::Simplify;
+use std::collections::HashMap;
+
+/// Distances from a finite point, relative to its size, at which F is sampled
+const FINITE_PROBES: [f64; 4] = [1e-4, 1e-6, 1e-8, 1e-10];
+
+/// Distances from the origin at which F is sampled toward ±∞
+const INFINITE_PROBES: [f64; 4] = [1e2, 1e4, 1e6, 1e8];
+
+/// Half-width of the window searched for singular points when a bound is
+/// infinite
+const SEARCH_RADIUS: f64 = 1e3;
+
+impl Expression {
+    /// Definite integral of this expression over [`lower`, `upper`]
+    ///
+    /// Applies the fundamental theorem of calculus piece by piece between the
+    /// singular points inside the interval, so a jump in the antiderivative
+    /// does not corrupt the result. Bounds may be infinite. With symbolic
+    /// bounds the antiderivative is evaluated at them directly. If no
+    /// antiderivative is found, the unevaluated definite integral is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `var` - Variable of integration
+    /// * `lower` - Lower bound, possibly `Expression::negative_infinity()`
+    /// * `upper` - Upper bound, possibly `Expression::infinity()`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::{expr, symbol, Expression};
+    ///
+    /// let x = symbol!(x);
+    /// // ∫₀^∞ 1/(1 + x²) dx = π/2
+    /// let integrand = Expression::pow(expr!(1 + (x ^ 2)), Expression::integer(-1));
+    /// let value = integrand
+    ///     .integrate_definite(&x, &expr!(0), &Expression::infinity())
+    ///     .unwrap();
+    /// assert!((value.evaluate_to_f64().unwrap() - std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+    ///
+    /// // 1/x² has a non-integrable pole at 0
+    /// assert!(expr!(x ^ (-2)).integrate_definite(&x, &expr!(-1), &expr!(1)).is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `MathError::ConvergenceFailed` when the integral diverges: the
+    /// antiderivative is unbounded, or has no limit, at an infinite bound or a
+    /// singular point of the interval. Returns `MathError::DomainError` when
+    /// the antiderivative is not real on part of the interval.
+    pub fn integrate_definite(
+        &self,
+        var: &Symbol,
+        lower: &Expression,
+        upper: &Expression,
+    ) -> Result<Expression, MathError> {
+        if lower == upper {
+            return Ok(Expression::integer(0));
+        }
+        let antiderivative = self.integrate(var.clone(), 0);
+        if contains_integral(&antiderivative) {
+            return Ok(Expression::definite_integral(
+                self.clone(),
+                var.clone(),
+                lower.clone(),
+                upper.clone(),
+            ));
+        }
+
+        let (Some(a), Some(b)) = (bound_value(lower), bound_value(upper)) else {
+            return Ok(Expression::add(vec![
+                at(&antiderivative, var, upper),
+                Expression::mul(vec![
+                    Expression::integer(-1),
+                    at(&antiderivative, var, lower),
+                ]),
+            ])
+            .simplify());
+        };
+        if a == b {
+            return Ok(Expression::integer(0));
+        }
+        if a > b {
+            let reversed = self.integrate_definite(var, upper, lower)?;
+            return Ok(Expression::mul(vec![Expression::integer(-1), reversed]).simplify());
+        }
+
+        let Ok(compiled) = antiderivative.compile(std::slice::from_ref(var)) else {
+            // Other free symbols: the singular points cannot be located
+            return Ok(Expression::add(vec![
+                at(&antiderivative, var, upper),
+                Expression::mul(vec![
+                    Expression::integer(-1),
+                    at(&antiderivative, var, lower),
+                ]),
+            ])
+            .simplify());
+        };
+        let f = |x: f64| compiled.evaluate(&[x]);
+
+        let (search_lower, search_upper) = (
+            a.max(b.min(0.0) - SEARCH_RADIUS),
+            b.min(a.max(0.0) + SEARCH_RADIUS),
+        );
+        let mut interior = find_singularities(self, var, search_lower, search_upper)?;
+        interior.extend(find_singularities(
+            &antiderivative,
+            var,
+            search_lower,
+            search_upper,
+        )?);
+        interior.sort_by(f64::total_cmp);
+        interior.dedup_by(|p, q| (*p - *q).abs() <= 1e-9 * (1.0 + q.abs()));
+
+        let mut breakpoints = vec![(Some(lower.clone()), a)];
+        breakpoints.extend(interior.into_iter().map(|p| (exact_point(p), p)));
+        breakpoints.push((Some(upper.clone()), b));
+
+        let mut pieces = Vec::with_capacity(2 * breakpoints.len());
+        for window in breakpoints.windows(2) {
+            let (left, right) = (&window[0], &window[1]);
+            let reach = (right.1 - left.1) / 2.0;
+            let right_value = one_sided(&antiderivative, &f, var, right, -1.0, reach)?;
+            let left_value = one_sided(&antiderivative, &f, var, left, 1.0, reach)?;
+            pieces.push(right_value);
+            pieces.push(Expression::mul(vec![Expression::integer(-1), left_value]));
+        }
+        Ok(Expression::add(pieces).simplify())
+    }
+}
+
+/// Limit of the antiderivative at a breakpoint, approached from `side`
+/// (+1 from above, -1 from below) within `reach` of it
+///
+/// Bounds of the interval have an exact value; singular points found inside
+/// it have one only if their position is recognised. The exact value of F
+/// is used only if F approaches it from both sides, so not at a jump.
+fn one_sided<F: Fn(f64) -> f64>(
+    antiderivative: &Expression,
+    f: &F,
+    var: &Symbol,
+    (point, x): &(Option<Expression>, f64),
+    side: f64,
+    reach: f64,
+) -> Result<Expression, MathError> {
+    let x = *x;
+    if x.is_infinite() {
+        if let Some(Asymptote::Finite(value)) = asymptote(antiderivative, var, x.signum()) {
+            return Ok(value.simplify());
+        }
+    }
+
+    let approached = match numeric_limit(f, x, side, reach) {
+        Approach::Settles(value) => value,
+        Approach::Unbounded => {
+            return Err(MathError::ConvergenceFailed {
+                reason: format!("integral diverges at {} = {}", var.name(), x),
+            })
+        }
+        Approach::NotReal => {
+            return Err(MathError::DomainError {
+                operation: "definite integration".to_owned(),
+                value: point.clone().unwrap_or_else(|| Expression::float(x)),
+                reason: "the antiderivative is not real near this point".to_owned(),
+            })
+        }
+    };
+    let close = |a: f64, b: f64| (a - b).abs() <= 1e-6 * (1.0 + b.abs());
+    let exact = point
+        .as_ref()
+        .filter(|_| x.is_finite())
+        .map(|point| at(antiderivative, var, point))
+        .and_then(|value| Some((value.evaluate_to_f64().ok()?, value)))
+        .filter(|(value, _)| close(approached, *value))
+        .filter(|(value, _)| match numeric_limit(f, x, -side, reach) {
+            Approach::Settles(other) => close(other, *value),
+            Approach::Unbounded => false,
+            Approach::NotReal => true,
+        });
+    Ok(match exact {
+        Some((_, value)) => value,
+        None if approached.abs() < 1e-9 => Expression::integer(0),
+        None => Expression::float(approached),
+    })
+}
+
+/// How a function behaves approaching a point
+enum Approach {
+    Settles(f64),
+    Unbounded,
+    NotReal,
+}
+
+/// One-sided limit of `f` at `x` estimated from samples ever closer to it
+///
+/// The samples are spaced so that a function with a finite limit approaches
+/// it geometrically, which Aitken's Δ² extrapolation then removes. Samples
+/// that grow or do not settle mean there is no finite limit.
+fn numeric_limit<F: Fn(f64) -> f64>(f: &F, x: f64, side: f64, reach: f64) -> Approach {
+    let samples: Vec<f64> = if x.is_infinite() {
+        INFINITE_PROBES.iter().map(|t| f(x.signum() * t)).collect()
+    } else {
+        let scale = reach.min(x.abs().max(1.0));
+        FINITE_PROBES
+            .iter()
+            .map(|delta| f(x + side * delta * scale))
+            .collect()
+    };
+    if samples.iter().any(|v| v.is_nan()) {
+        return Approach::NotReal;
+    }
+    if samples.iter().any(|v| v.is_infinite()) {
+        return Approach::Unbounded;
+    }
+
+    let steps: Vec<f64> = samples.windows(2).map(|w| w[1] - w[0]).collect();
+    let last = samples[samples.len() - 1];
+    let negligible = steps[2].abs() <= 1e-12 * (1.0 + last.abs());
+    let shrinking =
+        steps[2].abs() <= 0.9 * steps[1].abs() && steps[1].abs() <= 0.9 * steps[0].abs();
+    if !negligible && !shrinking {
+        return Approach::Unbounded;
+    }
+    let curvature = steps[2] - steps[1];
+    Approach::Settles(if negligible || curvature == 0.0 {
+        last
+    } else {
+        last - steps[2] * steps[2] / curvature
+    })
+}
+
+/// Exact position of a singular point found numerically, if it is a
+/// fraction or a fraction of π with a small denominator
+fn exact_point(p: f64) -> Option<Expression> {
+    let fraction = |v: f64| {
+        (1..=12).find_map(|q| {
+            let n = (v * q as f64).round();
+            ((v * q as f64 - n).abs() <= 1e-9 * q as f64 * (1.0 + v.abs())).then(|| match q {
+                1 => Expression::integer(n as i64),
+                _ => Expression::rational(n as i64, q),
+            })
+        })
+    };
+    fraction(p)
+        .or_else(|| {
+            fraction(p / std::f64::consts::PI)
+                .map(|multiple| Expression::mul(vec![multiple, Expression::pi()]))
+        })
+        .map(|point| point.simplify())
+}
+
+/// Behaviour of an expression as the variable tends to ±∞
+enum Asymptote {
+    Finite(Expression),
+    Infinite(f64),
+}
+
+/// Exact limit of `expr` as `var` → `direction`·∞, or `None` when it cannot
+/// be decided from the limits of the parts
+fn asymptote(expr: &Expression, var: &Symbol, direction: f64) -> Option<Asymptote> {
+    use Asymptote::{Finite, Infinite};
+
+    if !expr.contains_variable(var) {
+        return Some(Finite(expr.clone()));
+    }
+    match expr {
+        Expression::Symbol(s) if s == var => Some(Infinite(direction)),
+        Expression::Add(terms) => {
+            let mut finite = Vec::new();
+            let mut infinite: Option<f64> = None;
+            for term in terms.iter() {
+                match asymptote(term, var, direction)? {
+                    Finite(value) => finite.push(value),
+                    Infinite(sign) if infinite.is_none_or(|s| s == sign) => infinite = Some(sign),
+                    Infinite(_) => return None,
+                }
+            }
+            Some(match infinite {
+                Some(sign) => Infinite(sign),
+                None => Finite(Expression::add(finite)),
+            })
+        }
+        Expression::Mul(factors) => {
+            let mut finite = Vec::new();
+            let mut sign: Option<f64> = None;
+            for factor in factors.iter() {
+                match asymptote(factor, var, direction)? {
+                    Finite(value) => finite.push(value),
+                    Infinite(s) => sign = Some(sign.unwrap_or(1.0) * s),
+                }
+            }
+            let product = Expression::mul(finite).simplify();
+            match sign {
+                None => Some(Finite(product)),
+                Some(sign) => {
+                    let coefficient = product.evaluate_to_f64().ok()?;
+                    (coefficient != 0.0 && coefficient.is_finite())
+                        .then(|| Infinite(sign * coefficient.signum()))
+                }
+            }
+        }
+        Expression::Pow(base, exponent) if !exponent.contains_variable(var) => {
+            let power = exponent.evaluate_to_f64().ok()?;
+            match asymptote(base, var, direction)? {
+                Finite(value) => finite_value(Expression::pow(value, exponent.as_ref().clone())),
+                Infinite(_) if power < 0.0 => Some(Finite(Expression::integer(0))),
+                Infinite(sign) if power > 0.0 && sign > 0.0 => Some(Infinite(1.0)),
+                Infinite(_) if power > 0.0 && power.fract() == 0.0 => {
+                    Some(Infinite(if power % 2.0 == 0.0 { 1.0 } else { -1.0 }))
+                }
+                Infinite(_) => None,
+            }
+        }
+        Expression::Pow(base, exponent) if !base.contains_variable(var) => {
+            let base_value = base.evaluate_to_f64().ok()?;
+            match asymptote(exponent, var, direction)? {
+                Finite(value) => finite_value(Expression::pow(base.as_ref().clone(), value)),
+                Infinite(sign) if base_value > 1.0 => Some(if sign > 0.0 {
+                    Infinite(1.0)
+                } else {
+                    Finite(Expression::integer(0))
+                }),
+                Infinite(sign) if (0.0..1.0).contains(&base_value) => Some(if sign < 0.0 {
+                    Infinite(1.0)
+                } else {
+                    Finite(Expression::integer(0))
+                }),
+                Infinite(_) => None,
+            }
+        }
+        Expression::Function { name, args, .. } if args.len() == 1 => {
+            match (name.as_ref(), asymptote(&args[0], var, direction)?) {
+                (_, Finite(value)) => {
+                    finite_value(Expression::function(name.as_ref(), vec![value]))
+                }
+                ("exp" | "sinh", Infinite(sign)) if sign > 0.0 => Some(Infinite(1.0)),
+                ("exp", Infinite(_)) => Some(Finite(Expression::integer(0))),
+                ("sinh", Infinite(sign)) => Some(Infinite(sign)),
+                ("cosh" | "abs", Infinite(_)) => Some(Infinite(1.0)),
+                ("ln" | "log" | "sqrt", Infinite(sign)) if sign > 0.0 => Some(Infinite(1.0)),
+                ("atan" | "arctan", Infinite(sign)) => Some(Finite(Expression::mul(vec![
+                    Expression::rational(sign as i64, 2),
+                    Expression::pi(),
+                ]))),
+                ("tanh" | "erf", Infinite(sign)) => Some(Finite(Expression::integer(sign as i64))),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// `value` as a finite asymptote, or `None` if it has no finite real value
+fn finite_value(value: Expression) -> Option<Asymptote> {
+    let value = value.simplify();
+    value
+        .evaluate_to_f64()
+        .is_ok_and(f64::is_finite)
+        .then_some(Asymptote::Finite(value))
+}
+
+/// Numeric position of a bound, with ±∞ for the infinity constants, or
+/// `None` for a symbolic bound
+fn bound_value(bound: &Expression) -> Option<f64> {
+    match bound {
+        Expression::Constant(MathConstant::Infinity) => Some(f64::INFINITY),
+        Expression::Constant(MathConstant::NegativeInfinity) => Some(f64::NEG_INFINITY),
+        Expression::Mul(factors)
+            if factors.len() == 2
+                && factors.contains(&Expression::integer(-1))
+                && factors.contains(&Expression::infinity()) =>
+        {
+            Some(f64::NEG_INFINITY)
+        }
+        _ => bound.evaluate_to_f64().ok().filter(|v| v.is_finite()),
+    }
+}
+
+/// `expr` with `var` replaced by `value`, simplified
+fn at(expr: &Expression, var: &Symbol, value: &Expression) -> Expression {
+    expr.substitute(&HashMap::from([(var.name().to_owned(), value.clone())]))
+        .simplify()
+}
+
+/// Whether `expr` still contains an unevaluated integral
+fn contains_integral(expr: &Expression) -> bool {
+    match expr {
+        Expression::Calculus(_) => true,
+        Expression::Add(terms) | Expression::Mul(terms) => terms.iter().any(contains_integral),
+        Expression::Pow(base, exp) => contains_integral(base) || contains_integral(exp),
+        Expression::Function { args, .. } => args.iter().any(contains_integral),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{expr, symbol};
+
+    fn value(integrand: Expression, lower: Expression, upper: Expression) -> f64 {
+        integrand
+            .integrate_definite(&symbol!(x), &lower, &upper)
+            .unwrap()
+            .evaluate_to_f64()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_proper_integrals() {
+        let x = symbol!(x);
+        let result = expr!(x ^ 2)
+            .integrate_definite(&x, &expr!(0), &expr!(3))
+            .unwrap();
+        assert_eq!(result, Expression::integer(9));
+        // Reversed bounds change the sign
+        let result = expr!(x ^ 2)
+            .integrate_definite(&x, &expr!(3), &expr!(0))
+            .unwrap();
+        assert_eq!(result, Expression::integer(-9));
+        assert!((value(expr!(sin(x)), expr!(0), Expression::pi()) - 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_improper_integrals() {
+        let x = symbol!(x);
+        // ∫₀^∞ e^(-x) dx = 1, exactly
+        let decay = expr!(exp(-x));
+        let result = decay
+            .integrate_definite(&x, &expr!(0), &Expression::infinity())
+            .unwrap();
+        assert_eq!(result, Expression::integer(1));
+
+        // ∫₀¹ 1/√x dx = 2: integrable endpoint singularity
+        let inverse_sqrt = Expression::pow(expr!(x), Expression::rational(-1, 2));
+        assert!((value(inverse_sqrt, expr!(0), expr!(1)) - 2.0).abs() < 1e-12);
+
+        // ∫ over the whole line of 1/(1 + x²) = π
+        let lorentzian = Expression::pow(expr!(1 + (x ^ 2)), Expression::integer(-1));
+        let whole_line = value(
+            lorentzian,
+            Expression::negative_infinity(),
+            Expression::infinity(),
+        );
+        assert!((whole_line - std::f64::consts::PI).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_jump_in_antiderivative() {
+        // The Weierstrass antiderivative of 1/(2 + cos x) jumps at x = π;
+        // evaluating it only at the bounds would give 0
+        let integrand = Expression::pow(expr!(2 + cos(x)), Expression::integer(-1));
+        let two_pi = Expression::mul(vec![Expression::integer(2), Expression::pi()]);
+        let expected = 2.0 * std::f64::consts::PI / 3.0_f64.sqrt();
+        assert!((value(integrand, expr!(0), two_pi) - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_divergent_integrals_are_errors() {
+        let x = symbol!(x);
+        let infinity = Expression::infinity();
+        // Interior pole
+        assert!(matches!(
+            expr!(x ^ (-1)).integrate_definite(&x, &expr!(-1), &expr!(2)),
+            Err(MathError::ConvergenceFailed { .. })
+        ));
+        // Endpoint pole and infinite bound
+        assert!(expr!(x ^ (-1))
+            .integrate_definite(&x, &expr!(0), &expr!(1))
+            .is_err());
+        assert!(expr!(x ^ (-1))
+            .integrate_definite(&x, &expr!(1), &infinity)
+            .is_err());
+        // No limit at all
+        assert!(expr!(cos(x))
+            .integrate_definite(&x, &expr!(0), &infinity)
+            .is_err());
+    }
+
+    #[test]
+    fn test_symbolic_bounds_and_unknown_antiderivatives() {
+        let x = symbol!(x);
+        let result = expr!(2 * x)
+            .integrate_definite(&x, &expr!(0), &expr!(t))
+            .unwrap();
+        assert_eq!(result, expr!(t ^ 2).simplify());
+
+        let gaussian = expr!(exp(x ^ 2));
+        let result = gaussian
+            .integrate_definite(&x, &expr!(0), &expr!(1))
+            .unwrap();
+        assert!(matches!(result, Expression::Calculus(_)));
+    }
+}
@@ -0,0 +1,220 @@
+//! Integration of Heaviside step and Dirac delta products
+//!
+//! With `u = a x + b` and root `r = -b/a`:
+//! - Sifting: `∫ f(x) δ(u) dx = f(r)/|a| · H(x - r)`
+//! - Cut-off: `∫ f(x) H(u) dx = H(x - r) (F(x) - F(r))` for `a > 0`, where
+//!   `F` is an antiderivative of `f`; for `a < 0`, `H(u) = 1 - H(x - r)`
+//!
+//! Definite integrals then follow from the bounds: `H` at a positive
+//! argument is 1 and at a negative one 0, so `∫ₗʰ f δ(x - r) dx = f(r)` when
+//! `l < r < h`.
+
+use super::Integration;
+use crate::calculus::derivatives::Derivative;
+use crate::core::{Expression, Symbol};
+use crate::simplify::Simplify;
+use std::collections::HashMap;
+
+/// Integrate `f(x)·δ(a x + b)` or `f(x)·H(a x + b)`, with `f` free of other
+/// distributions in `var`
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::calculus::integrals::Integration;
+/// use mathhook_core::{expr, symbol, Expression};
+///
+/// let x = symbol!(x);
+/// // ∫₀⁵ x² δ(x - 2) dx = 4
+/// let integrand = Expression::mul(vec![
+///     expr!(x ^ 2),
+///     Expression::function("dirac_delta", vec![expr!(x - 2)]),
+/// ]);
+/// let value = integrand.definite_integrate(x, expr!(0), expr!(5)).unwrap();
+/// assert_eq!(value.evaluate_to_f64().unwrap(), 4.0);
+/// ```
+pub fn try_distribution_integration(
+    expr: &Expression,
+    var: &Symbol,
+    depth: usize,
+) -> Option<Expression> {
+    let mut factors = Vec::new();
+    flatten_product(expr, &mut factors);
+    let mut distributions = factors
+        .iter()
+        .enumerate()
+        .filter(|(_, factor)| is_distribution_of(factor, var));
+    let (position, distribution) = distributions.next()?;
+    if distributions.next().is_some() {
+        return None;
+    }
+    let Expression::Function { name, args, .. } = distribution else {
+        return None;
+    };
+
+    let (slope, offset) = linear_coefficients(&args[0], var)?;
+    let root = Expression::mul(vec![
+        Expression::integer(-1),
+        offset,
+        Expression::pow(slope.clone(), Expression::integer(-1)),
+    ])
+    .simplify();
+    let step = Expression::function(
+        "heaviside",
+        vec![Expression::add(vec![
+            Expression::symbol(var.clone()),
+            Expression::mul(vec![Expression::integer(-1), root.clone()]),
+        ])
+        .simplify()],
+    );
+    let rest = Expression::mul(
+        factors
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| *i != position)
+            .map(|(_, factor)| factor.clone())
+            .collect(),
+    );
+    let at_root = |e: &Expression| {
+        e.substitute(&HashMap::from([(var.name().to_owned(), root.clone())]))
+            .simplify()
+    };
+
+    match (name.as_ref(), args.len()) {
+        ("dirac_delta", 1) => Some(
+            Expression::mul(vec![
+                at_root(&rest),
+                Expression::pow(
+                    Expression::function("abs", vec![slope]),
+                    Expression::integer(-1),
+                ),
+                step,
+            ])
+            .simplify(),
+        ),
+        ("heaviside", 1) => {
+            let antiderivative = rest.integrate(var.clone(), depth + 1);
+            if matches!(antiderivative, Expression::Calculus(_)) {
+                return None;
+            }
+            let above_root = Expression::mul(vec![
+                step,
+                Expression::add(vec![
+                    antiderivative.clone(),
+                    Expression::mul(vec![Expression::integer(-1), at_root(&antiderivative)]),
+                ]),
+            ]);
+            if slope.is_negative_number() {
+                Some(
+                    Expression::add(vec![
+                        antiderivative,
+                        Expression::mul(vec![Expression::integer(-1), above_root]),
+                    ])
+                    .simplify(),
+                )
+            } else if matches!(slope, Expression::Number(_)) {
+                Some(above_root.simplify())
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
+fn flatten_product(expr: &Expression, factors: &mut Vec<Expression>) {
+    match expr {
+        Expression::Mul(inner) => inner
+            .iter()
+            .for_each(|factor| flatten_product(factor, factors)),
+        other => factors.push(other.clone()),
+    }
+}
+
+fn is_distribution_of(expr: &Expression, var: &Symbol) -> bool {
+    matches!(
+        expr,
+        Expression::Function { name, args, .. }
+            if matches!(name.as_ref(), "heaviside" | "dirac_delta")
+                && args.first().is_some_and(|arg| arg.contains_variable(var))
+    )
+}
+
+/// `(a, b)` with `expr = a·var + b`, both free of `var` and `a ≠ 0`
+pub(crate) fn linear_coefficients(
+    expr: &Expression,
+    var: &Symbol,
+) -> Option<(Expression, Expression)> {
+    let slope = expr.derivative(var.clone()).simplify();
+    if slope.contains_variable(var) || slope.is_zero() {
+        return None;
+    }
+    let offset = expr
+        .substitute(&HashMap::from([(
+            var.name().to_owned(),
+            Expression::integer(0),
+        )]))
+        .simplify();
+    Some((slope, offset))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{expr, symbol};
+
+    fn heaviside(arg: Expression) -> Expression {
+        Expression::function("heaviside", vec![arg])
+    }
+
+    #[test]
+    fn test_sifting_with_scaled_argument() {
+        let x = symbol!(x);
+        // ∫ cos(x) δ(2x) dx = cos(0)/2 · H(x)
+        let integrand = Expression::mul(vec![
+            expr!(cos(x)),
+            Expression::function("dirac_delta", vec![expr!(2 * x)]),
+        ]);
+        let result = try_distribution_integration(&integrand, &x, 0).unwrap();
+        assert_eq!(
+            result,
+            Expression::mul(vec![Expression::rational(1, 2), heaviside(expr!(x))]).simplify()
+        );
+    }
+
+    #[test]
+    fn test_step_cuts_off_antiderivative() {
+        let x = symbol!(x);
+        let integrand = Expression::mul(vec![expr!(2 * x), heaviside(expr!(x - 1))]);
+        let result = try_distribution_integration(&integrand, &x, 0).unwrap();
+        let value_at = |point: i64| {
+            result
+                .substitute(&HashMap::from([(
+                    "x".to_owned(),
+                    Expression::integer(point),
+                )]))
+                .evaluate_to_f64()
+                .unwrap()
+        };
+        assert_eq!(value_at(0), 0.0);
+        // x² - 1 past the step
+        assert_eq!(value_at(3), 8.0);
+    }
+
+    #[test]
+    fn test_reversed_step() {
+        let x = symbol!(x);
+        // ∫₀³ H(1 - x) dx = 1
+        let value = heaviside(expr!(1 - x))
+            .definite_integrate(x, expr!(0), expr!(3))
+            .unwrap();
+        assert_eq!(value.evaluate_to_f64().unwrap(), 1.0);
+    }
+
+    #[test]
+    fn test_products_of_distributions_are_left_alone() {
+        let x = symbol!(x);
+        let integrand = Expression::mul(vec![heaviside(expr!(x)), heaviside(expr!(x - 1))]);
+        assert!(try_distribution_integration(&integrand, &x, 0).is_none());
+    }
+}
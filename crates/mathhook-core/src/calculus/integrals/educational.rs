@@ -3,6 +3,8 @@
 //! Provides step-by-step explanations for various integration techniques
 //! including power rule, substitution, integration by parts, and definite integrals.
 
+use crate::calculus::integrals::heuristics::try_heuristic_integration;
+use crate::calculus::integrals::strategy::integrate_with_technique;
 use crate::core::{Expression, Symbol};
 use crate::educational::message_registry::{MessageBuilder, MessageCategory, MessageType};
 use crate::educational::step_by_step::{Step, StepByStepExplanation};
@@ -40,7 +42,19 @@ impl IntegrationExplanation {
     /// assert!(!explanation.steps().is_empty());
     /// ```
     pub fn generate(expr: &Expression, var: &Symbol) -> Self {
-        let (steps, strategy) = analyze_and_explain(expr, var);
+        let (mut steps, mut strategy) = analyze_and_explain(expr, var);
+
+        let (result, technique) = integrate_with_technique(expr, var.clone(), 0);
+        if let Some(technique) = technique {
+            if let Some(heuristic) = try_heuristic_integration(expr, var, 0)
+                .filter(|h| h.technique == technique && h.antiderivative == result)
+            {
+                steps.push(format!("Apply {}", heuristic.description));
+            }
+            steps.push(format!("Technique used: {}", technique.name()));
+            steps.push(format!("Result: {} + C", result));
+            strategy = technique.name().to_owned();
+        }
         Self { steps, strategy }
     }
 
@@ -50,6 +64,9 @@ impl IntegrationExplanation {
     }
 
     /// Get the integration strategy/technique that was used
+    ///
+    /// This is the technique reported by the strategy dispatcher when the
+    /// integral was evaluated, and the analysis guess otherwise.
     pub fn strategy_used(&self) -> String {
         self.strategy.clone()
     }
@@ -113,7 +130,7 @@ fn analyze_and_explain(expr: &Expression, var: &Symbol) -> (Vec<String>, String)
                 )
             }
         }
-        Expression::Function { name, args: _ } => {
+        Expression::Function { name, args: _, .. } => {
             let strategy = match name.as_ref() {
                 "sin" | "cos" | "tan" | "sec" | "csc" | "cot" => "trig",
                 "exp" | "ln" | "log" => "table",
@@ -683,4 +700,19 @@ mod tests {
         assert!(explanation.steps.len() >= 5);
         assert_eq!(explanation.total_steps, 5);
     }
+
+    #[test]
+    fn test_integration_explanation_reports_technique() {
+        let x = symbol!(x);
+        let integrand = Expression::mul(vec![
+            Expression::symbol(x.clone()),
+            Expression::function("ln", vec![Expression::symbol(x.clone())]),
+        ]);
+        let explanation = IntegrationExplanation::generate(&integrand, &x);
+        assert_eq!(explanation.strategy_used(), "by_parts");
+        assert!(explanation
+            .steps()
+            .iter()
+            .any(|step| step.contains("u = ln(x)")));
+    }
 }
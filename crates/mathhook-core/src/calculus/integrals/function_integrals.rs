@@ -265,7 +265,7 @@ impl FunctionIntegrals {
             Expression::Symbol(sym) if *sym == variable => {
                 Expression::mul(vec![coefficient.clone(), Expression::symbol(variable)])
             }
-            Expression::Function { name, args } => {
+            Expression::Function { name, args, .. } => {
                 let new_args: Vec<Expression> = args
                     .iter()
                     .map(|arg| {
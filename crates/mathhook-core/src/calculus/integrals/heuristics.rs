@@ -0,0 +1,709 @@
+//! Heuristic integration
+//!
+//! Textbook techniques tried before the Risch algorithm. Each technique either
+//! produces a closed-form antiderivative or gives up; partial results containing
+//! unevaluated integrals are never returned.
+//!
+//! # Techniques (in order)
+//!
+//! 1. **Constant factors** - ∫c·f dx = c·∫f dx
+//! 2. **Trigonometric powers** - sinᵐ(u)·cosⁿ(u) for linear u via reduction formulas
+//! 3. **Cyclic by parts** - e^(ax)·sin(bx) and e^(ax)·cos(bx) in closed form
+//! 4. **U-substitution** - f(g(x))·g'(x) for algebraic, trigonometric, exponential
+//!    and logarithmic inner functions g
+//! 5. **Trigonometric substitution** - xᵐ·√(a² - x²), √(x² + a²), √(x² - a²) and
+//!    odd powers of the radicals
+//! 6. **Tangent half-angle substitution** - rational functions of sin and cos with
+//!    sin or cos in a denominator, via t = tan(u/2) and partial fractions
+//! 7. **Integration by parts** - u chosen by LIATE priority (Logarithmic, Inverse
+//!    trig, Algebraic, Trigonometric, Exponential)
+
+use crate::calculus::derivatives::Derivative;
+use crate::calculus::integrals::strategy::{integrate_with_strategy, IntegrationStrategy};
+use crate::calculus::integrals::{trig_substitution, trigonometric, weierstrass};
+use crate::core::{Expression, Number, Symbol};
+use crate::simplify::Simplify;
+use std::collections::HashMap;
+
+/// Heuristics are not attempted at or beyond this depth
+///
+/// Every technique recurses into the full strategy, so this bounds the work
+/// spent on integrands no heuristic can handle.
+const MAX_HEURISTIC_DEPTH: usize = 6;
+
+/// Substitution candidates tried per integrand, largest first
+const MAX_SUBSTITUTION_CANDIDATES: usize = 8;
+
+/// Antiderivative found by a heuristic, with the technique that produced it
+#[derive(Debug, Clone, PartialEq)]
+pub struct HeuristicResult {
+    pub antiderivative: Expression,
+    /// Technique reported to the strategy dispatcher
+    pub technique: IntegrationStrategy,
+    /// Human-readable account of the step, e.g. `u = x^2`
+    pub description: String,
+}
+
+impl HeuristicResult {
+    fn new(
+        antiderivative: Expression,
+        technique: IntegrationStrategy,
+        description: String,
+    ) -> Self {
+        Self {
+            antiderivative,
+            technique,
+            description,
+        }
+    }
+}
+
+/// Try the textbook integration heuristics
+///
+/// # Arguments
+///
+/// * `expr` - Integrand
+/// * `var` - Variable of integration
+/// * `depth` - Current recursion depth of the strategy dispatcher
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::calculus::integrals::heuristics::try_heuristic_integration;
+/// use mathhook_core::calculus::integrals::strategy::IntegrationStrategy;
+/// use mathhook_core::{expr, symbol};
+///
+/// let x = symbol!(x);
+/// let result = try_heuristic_integration(&expr!(x * ln(x)), &x, 0).unwrap();
+/// assert_eq!(result.technique, IntegrationStrategy::IntegrationByParts);
+/// ```
+pub fn try_heuristic_integration(
+    expr: &Expression,
+    var: &Symbol,
+    depth: usize,
+) -> Option<HeuristicResult> {
+    if depth >= MAX_HEURISTIC_DEPTH || !expr.contains_variable(var) {
+        return None;
+    }
+
+    if let Some((coefficient, rest)) = split_constant_factor(expr, var) {
+        let inner = try_heuristic_integration(&rest, var, depth)?;
+        return Some(HeuristicResult {
+            antiderivative: Expression::mul(vec![coefficient, inner.antiderivative]),
+            ..inner
+        });
+    }
+
+    try_trig_powers(expr, var)
+        .or_else(|| try_cyclic_by_parts(expr, var))
+        .or_else(|| try_u_substitution(expr, var, depth))
+        .or_else(|| try_radical_substitution(expr, var))
+        .or_else(|| try_tangent_half_angle(expr, var))
+        .or_else(|| try_liate_by_parts(expr, var, depth))
+}
+
+/// Split c·f into (c, f) where c is free of `var`
+fn split_constant_factor(expr: &Expression, var: &Symbol) -> Option<(Expression, Expression)> {
+    let Expression::Mul(factors) = expr else {
+        return None;
+    };
+    let (constants, variables): (Vec<Expression>, Vec<Expression>) = factors
+        .iter()
+        .cloned()
+        .partition(|f| !f.contains_variable(var));
+    if constants.is_empty() || variables.is_empty() {
+        return None;
+    }
+    Some((Expression::mul(constants), Expression::mul(variables)))
+}
+
+/// sinᵐ(u)·cosⁿ(u) with u linear in `var`
+///
+/// The dedicated trigonometric module is consulted first so its closed forms are
+/// kept; reduction formulas cover the remaining exponent combinations:
+///
+/// - ∫sinᵐcosⁿ = -sinᵐ⁻¹cosⁿ⁺¹/(m+n) + (m-1)/(m+n)·∫sinᵐ⁻²cosⁿ
+/// - ∫sinᵐcosⁿ = sinᵐ⁺¹cosⁿ⁻¹/(m+n) + (n-1)/(m+n)·∫sinᵐcosⁿ⁻²
+fn try_trig_powers(expr: &Expression, var: &Symbol) -> Option<HeuristicResult> {
+    let (arg, m, n) = sin_cos_powers(expr)?;
+    if m + n < 2 {
+        return None;
+    }
+    let slope = linear_slope(&arg, var)?;
+    let description = format!("reduce sin^{}·cos^{} of {}", m, n, arg);
+
+    if let Some(result) = trigonometric::try_trigonometric_integration(expr, var) {
+        if is_closed_form(&result) {
+            return Some(HeuristicResult::new(
+                result,
+                IntegrationStrategy::Trigonometric,
+                description,
+            ));
+        }
+    }
+
+    let antiderivative = Expression::mul(vec![
+        Expression::pow(slope, Expression::integer(-1)),
+        sin_cos_antiderivative(m, n, &arg),
+    ]);
+    Some(HeuristicResult::new(
+        antiderivative,
+        IntegrationStrategy::Trigonometric,
+        description,
+    ))
+}
+
+/// Decompose sinᵐ(u)·cosⁿ(u) into (u, m, n)
+fn sin_cos_powers(expr: &Expression) -> Option<(Expression, i64, i64)> {
+    let factors: &[Expression] = match expr {
+        Expression::Mul(factors) => factors,
+        _ => std::slice::from_ref(expr),
+    };
+
+    let mut arg: Option<&Expression> = None;
+    let (mut m, mut n) = (0, 0);
+    for factor in factors {
+        let (function, power) = match factor {
+            Expression::Pow(base, exp) => match exp.as_ref() {
+                Expression::Number(Number::Integer(k)) if *k > 0 => (base.as_ref(), *k),
+                _ => return None,
+            },
+            _ => (factor, 1),
+        };
+        let Expression::Function { name, args, .. } = function else {
+            return None;
+        };
+        if args.len() != 1 || arg.is_some_and(|a| a != &args[0]) {
+            return None;
+        }
+        arg = Some(&args[0]);
+        match name.as_ref() {
+            "sin" => m += power,
+            "cos" => n += power,
+            _ => return None,
+        }
+    }
+    arg.map(|a| (a.clone(), m, n))
+}
+
+/// ∫sinᵐ(u)·cosⁿ(u) du
+fn sin_cos_antiderivative(m: i64, n: i64, u: &Expression) -> Expression {
+    let sin = |k: i64| {
+        Expression::pow(
+            Expression::function("sin", vec![u.clone()]),
+            Expression::integer(k),
+        )
+    };
+    let cos = |k: i64| {
+        Expression::pow(
+            Expression::function("cos", vec![u.clone()]),
+            Expression::integer(k),
+        )
+    };
+
+    match (m, n) {
+        (0, 0) => u.clone(),
+        (1, 0) => Expression::mul(vec![Expression::integer(-1), cos(1)]),
+        (0, 1) => sin(1),
+        (1, 1) => Expression::mul(vec![Expression::rational(1, 2), sin(2)]),
+        _ if m >= 2 => Expression::add(vec![
+            Expression::mul(vec![
+                Expression::rational(-1, m + n),
+                sin(m - 1),
+                cos(n + 1),
+            ]),
+            Expression::mul(vec![
+                Expression::rational(m - 1, m + n),
+                sin_cos_antiderivative(m - 2, n, u),
+            ]),
+        ]),
+        _ => Expression::add(vec![
+            Expression::mul(vec![Expression::rational(1, m + n), sin(m + 1), cos(n - 1)]),
+            Expression::mul(vec![
+                Expression::rational(n - 1, m + n),
+                sin_cos_antiderivative(m, n - 2, u),
+            ]),
+        ]),
+    }
+}
+
+/// e^(g)·sin(h) or e^(g)·cos(h) with g = ax + c, h = bx + d
+///
+/// Two rounds of integration by parts return the original integral, giving
+///
+/// - ∫e^(ax)sin(bx) dx = e^(ax)(a·sin(bx) - b·cos(bx))/(a² + b²)
+/// - ∫e^(ax)cos(bx) dx = e^(ax)(a·cos(bx) + b·sin(bx))/(a² + b²)
+fn try_cyclic_by_parts(expr: &Expression, var: &Symbol) -> Option<HeuristicResult> {
+    let Expression::Mul(factors) = expr else {
+        return None;
+    };
+    if factors.len() != 2 {
+        return None;
+    }
+
+    let (exponential, trig) = match (&factors[0], &factors[1]) {
+        (e @ Expression::Function { name, .. }, t) | (t, e @ Expression::Function { name, .. })
+            if name.as_ref() == "exp" =>
+        {
+            (e, t)
+        }
+        _ => return None,
+    };
+    let (Expression::Function { args: exp_args, .. }, Expression::Function { name, args, .. }) =
+        (exponential, trig)
+    else {
+        return None;
+    };
+    if args.len() != 1 || !matches!(name.as_ref(), "sin" | "cos") {
+        return None;
+    }
+
+    let a = linear_slope(&exp_args[0], var)?;
+    let b = linear_slope(&args[0], var)?;
+    let sin = Expression::function("sin", args.to_vec());
+    let cos = Expression::function("cos", args.to_vec());
+    let combination = if name.as_ref() == "sin" {
+        Expression::add(vec![
+            Expression::mul(vec![a.clone(), sin]),
+            Expression::mul(vec![Expression::integer(-1), b.clone(), cos]),
+        ])
+    } else {
+        Expression::add(vec![
+            Expression::mul(vec![a.clone(), cos]),
+            Expression::mul(vec![b.clone(), sin]),
+        ])
+    };
+    let denominator = Expression::add(vec![
+        Expression::pow(a, Expression::integer(2)),
+        Expression::pow(b, Expression::integer(2)),
+    ]);
+
+    Some(HeuristicResult::new(
+        Expression::mul(vec![
+            exponential.clone(),
+            combination,
+            Expression::pow(denominator, Expression::integer(-1)),
+        ]),
+        IntegrationStrategy::IntegrationByParts,
+        format!("cyclic integration by parts on {}", expr),
+    ))
+}
+
+/// ∫f(g(x))·g'(x) dx = ∫f(u) du with u = g(x)
+///
+/// Candidates for g are function calls, their arguments, and the bases and
+/// exponents of powers. A candidate works when dividing the integrand by g'
+/// leaves an expression in which `var` only occurs inside g.
+fn try_u_substitution(expr: &Expression, var: &Symbol, depth: usize) -> Option<HeuristicResult> {
+    let u = fresh_symbol(expr, var);
+    let u_expr = Expression::symbol(u.clone());
+
+    for candidate in substitution_candidates(expr, var) {
+        let derivative = candidate.derivative(var.clone()).simplify();
+        if derivative.is_zero() {
+            continue;
+        }
+        let mut factors = vec![expr.clone()];
+        factors.extend(reciprocal_factors(&derivative));
+        let quotient = Expression::mul(factors).simplify();
+        let in_u = replace_subexpression(&quotient, &candidate, &u_expr);
+        if in_u.contains_variable(var) {
+            continue;
+        }
+
+        let integrated = integrate_with_strategy(&in_u, u.clone(), depth + 1);
+        if !is_closed_form(&integrated) {
+            continue;
+        }
+        let mut back = HashMap::new();
+        back.insert(u.name().to_owned(), candidate.clone());
+        return Some(HeuristicResult::new(
+            integrated.substitute(&back),
+            IntegrationStrategy::Substitution,
+            format!(
+                "{} substitution u = {}",
+                substitution_kind(&candidate),
+                candidate
+            ),
+        ));
+    }
+    None
+}
+
+/// Trigonometric or hyperbolic substitution for square roots of quadratics
+fn try_radical_substitution(expr: &Expression, var: &Symbol) -> Option<HeuristicResult> {
+    let form = trig_substitution::detect_radical_form(expr, var)?;
+    let antiderivative = trig_substitution::try_trig_substitution(expr, var)?;
+    Some(HeuristicResult::new(
+        antiderivative,
+        IntegrationStrategy::TrigSubstitution,
+        format!("substitution {}", form.substitution()),
+    ))
+}
+
+/// Weierstrass substitution t = tan(u/2) for rational functions of sin and cos
+///
+/// Polynomials in sin and cos are left to power reduction, which gives simpler
+/// antiderivatives, so the substitution is only tried when sin or cos appears
+/// in a denominator.
+fn try_tangent_half_angle(expr: &Expression, var: &Symbol) -> Option<HeuristicResult> {
+    if !has_sin_cos_denominator(expr, var) {
+        return None;
+    }
+    let antiderivative = weierstrass::try_weierstrass_substitution(expr, var)?;
+    Some(HeuristicResult::new(
+        antiderivative,
+        IntegrationStrategy::WeierstrassSubstitution,
+        "substitution t = tan(u/2)".to_owned(),
+    ))
+}
+
+/// Check for a negative power of an expression containing sin or cos of `var`
+fn has_sin_cos_denominator(expr: &Expression, var: &Symbol) -> bool {
+    fn has_sin_cos(expr: &Expression, var: &Symbol) -> bool {
+        match expr {
+            Expression::Function { name, args, .. } => {
+                (matches!(name.as_ref(), "sin" | "cos")
+                    && args.iter().any(|a| a.contains_variable(var)))
+                    || args.iter().any(|a| has_sin_cos(a, var))
+            }
+            Expression::Add(terms) | Expression::Mul(terms) => {
+                terms.iter().any(|t| has_sin_cos(t, var))
+            }
+            Expression::Pow(base, exp) => has_sin_cos(base, var) || has_sin_cos(exp, var),
+            _ => false,
+        }
+    }
+
+    match expr {
+        Expression::Pow(base, exp) => {
+            matches!(exp.as_ref(), Expression::Number(Number::Integer(n)) if *n < 0)
+                && has_sin_cos(base, var)
+        }
+        Expression::Mul(factors) => factors.iter().any(|f| has_sin_cos_denominator(f, var)),
+        _ => false,
+    }
+}
+
+/// Candidate inner functions, most complex first
+fn substitution_candidates(expr: &Expression, var: &Symbol) -> Vec<Expression> {
+    fn push(candidate: &Expression, var: &Symbol, out: &mut Vec<Expression>) {
+        if candidate.contains_variable(var)
+            && !candidate.is_simple_variable(var)
+            && !out.contains(candidate)
+        {
+            out.push(candidate.clone());
+        }
+    }
+
+    fn collect(expr: &Expression, var: &Symbol, out: &mut Vec<Expression>) {
+        match expr {
+            Expression::Function { args, .. } => {
+                push(expr, var, out);
+                for arg in args.iter() {
+                    push(arg, var, out);
+                    collect(arg, var, out);
+                }
+            }
+            Expression::Pow(base, exp) => {
+                push(base, var, out);
+                push(exp, var, out);
+                collect(base, var, out);
+                collect(exp, var, out);
+            }
+            Expression::Add(terms) | Expression::Mul(terms) => {
+                for term in terms.iter() {
+                    collect(term, var, out);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut candidates = Vec::new();
+    collect(expr, var, &mut candidates);
+    candidates
+        .sort_by_key(|c| std::cmp::Reverse(c.count_variable_occurrences(var) + node_count(c)));
+    candidates.truncate(MAX_SUBSTITUTION_CANDIDATES);
+    candidates
+}
+
+fn substitution_kind(candidate: &Expression) -> &'static str {
+    match candidate {
+        Expression::Function { name, .. } => match name.as_ref() {
+            "sin" | "cos" | "tan" | "sec" | "csc" | "cot" => "trigonometric",
+            "exp" => "exponential",
+            "ln" | "log" => "logarithmic",
+            _ => "algebraic",
+        },
+        Expression::Pow(base, _) if matches!(base.as_ref(), Expression::Constant(_)) => {
+            "exponential"
+        }
+        _ => "algebraic",
+    }
+}
+
+/// LIATE class of a factor; lower classes make better choices for u
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Liate {
+    Logarithmic,
+    InverseTrig,
+    Algebraic,
+    Trigonometric,
+    Exponential,
+}
+
+fn liate_class(expr: &Expression, var: &Symbol) -> Option<Liate> {
+    match expr {
+        Expression::Function { name, .. } => match name.as_ref() {
+            "ln" | "log" => Some(Liate::Logarithmic),
+            "arcsin" | "arccos" | "arctan" | "asin" | "acos" | "atan" => Some(Liate::InverseTrig),
+            "sin" | "cos" | "sinh" | "cosh" => Some(Liate::Trigonometric),
+            "exp" => Some(Liate::Exponential),
+            _ => None,
+        },
+        Expression::Symbol(s) if s == var => Some(Liate::Algebraic),
+        Expression::Pow(base, exp) => match (base.as_ref(), exp.as_ref()) {
+            (Expression::Symbol(s), Expression::Number(Number::Integer(k)))
+                if s == var && *k > 0 =>
+            {
+                Some(Liate::Algebraic)
+            }
+            (Expression::Function { name, .. }, Expression::Number(Number::Integer(k)))
+                if *k > 0 && matches!(name.as_ref(), "ln" | "log") =>
+            {
+                Some(Liate::Logarithmic)
+            }
+            (_, exponent) if !base.contains_variable(var) && exponent.contains_variable(var) => {
+                Some(Liate::Exponential)
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// ∫u dv = uv - ∫v du with u chosen by LIATE priority
+///
+/// A lone logarithmic or inverse trigonometric integrand is treated as u·1.
+/// An algebraic u is only used against trigonometric or exponential dv, where
+/// differentiating u lowers its degree.
+fn try_liate_by_parts(expr: &Expression, var: &Symbol, depth: usize) -> Option<HeuristicResult> {
+    let (u, dv) = match expr {
+        Expression::Mul(factors) => {
+            let classes: Vec<Liate> = factors
+                .iter()
+                .map(|f| liate_class(f, var))
+                .collect::<Option<_>>()?;
+            let (index, &class) = classes.iter().enumerate().min_by_key(|(_, c)| **c)?;
+            let rest_classes = classes.iter().enumerate().filter(|(i, _)| *i != index);
+            if class == Liate::Algebraic
+                && !rest_classes
+                    .clone()
+                    .all(|(_, c)| matches!(c, Liate::Trigonometric | Liate::Exponential))
+            {
+                return None;
+            }
+            let dv: Vec<Expression> = factors
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| *i != index)
+                .map(|(_, f)| f.clone())
+                .collect();
+            (factors[index].clone(), Expression::mul(dv))
+        }
+        _ => match liate_class(expr, var)? {
+            Liate::Logarithmic | Liate::InverseTrig => (expr.clone(), Expression::integer(1)),
+            _ => return None,
+        },
+    };
+
+    let v = integrate_with_strategy(&dv, var.clone(), depth + 1);
+    if !is_closed_form(&v) {
+        return None;
+    }
+    let v_du = Expression::mul(vec![v.clone(), u.derivative(var.clone())]).simplify();
+    let integral_v_du = integrate_with_strategy(&v_du, var.clone(), depth + 1);
+    if !is_closed_form(&integral_v_du) {
+        return None;
+    }
+
+    Some(HeuristicResult::new(
+        Expression::add(vec![
+            Expression::mul(vec![u.clone(), v]),
+            Expression::mul(vec![Expression::integer(-1), integral_v_du]),
+        ]),
+        IntegrationStrategy::IntegrationByParts,
+        format!("integration by parts with u = {}, dv = {}", u, dv),
+    ))
+}
+
+/// Derivative of `expr` if it is a nonzero constant, i.e. `expr` is linear in `var`
+fn linear_slope(expr: &Expression, var: &Symbol) -> Option<Expression> {
+    let slope = expr.derivative(var.clone()).simplify();
+    (!slope.is_zero() && !slope.contains_variable(var)).then_some(slope)
+}
+
+/// Factors of 1/expr, distributed over products so they cancel individually
+fn reciprocal_factors(expr: &Expression) -> Vec<Expression> {
+    match expr {
+        Expression::Mul(factors) => factors.iter().flat_map(reciprocal_factors).collect(),
+        Expression::Pow(base, exp) => vec![Expression::pow(
+            base.as_ref().clone(),
+            Expression::mul(vec![Expression::integer(-1), exp.as_ref().clone()]),
+        )],
+        _ => vec![Expression::pow(expr.clone(), Expression::integer(-1))],
+    }
+}
+
+/// Check that no unevaluated integral remains anywhere in `expr`
+fn is_closed_form(expr: &Expression) -> bool {
+    match expr {
+        Expression::Calculus(_) => false,
+        Expression::Add(terms) | Expression::Mul(terms) => terms.iter().all(is_closed_form),
+        Expression::Pow(base, exp) => is_closed_form(base) && is_closed_form(exp),
+        Expression::Function { args, .. } => args.iter().all(is_closed_form),
+        _ => true,
+    }
+}
+
+/// Replace every occurrence of `target` in `expr`, rebuilding canonically
+fn replace_subexpression(
+    expr: &Expression,
+    target: &Expression,
+    replacement: &Expression,
+) -> Expression {
+    if expr == target {
+        return replacement.clone();
+    }
+    let replace = |e: &Expression| replace_subexpression(e, target, replacement);
+    match expr {
+        Expression::Add(terms) => Expression::add(terms.iter().map(replace).collect()),
+        Expression::Mul(factors) => Expression::mul(factors.iter().map(replace).collect()),
+        Expression::Pow(base, exp) => Expression::pow(replace(base), replace(exp)),
+        Expression::Function { name, args, .. } => {
+            Expression::function(name.as_ref(), args.iter().map(replace).collect())
+        }
+        _ => expr.clone(),
+    }
+}
+
+fn node_count(expr: &Expression) -> usize {
+    match expr {
+        Expression::Add(terms) | Expression::Mul(terms) => {
+            1 + terms.iter().map(node_count).sum::<usize>()
+        }
+        Expression::Pow(base, exp) => 1 + node_count(base) + node_count(exp),
+        Expression::Function { args, .. } => 1 + args.iter().map(node_count).sum::<usize>(),
+        _ => 1,
+    }
+}
+
+/// Symbol for the substituted variable that does not clash with the integrand
+fn fresh_symbol(expr: &Expression, var: &Symbol) -> Symbol {
+    let mut name = "u".to_owned();
+    loop {
+        let symbol = Symbol::scalar(&name);
+        if &symbol != var && !expr.contains_variable(&symbol) {
+            return symbol;
+        }
+        name.push('_');
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algebra::solvers::Assumptions;
+    use crate::simplify::verification::find_mismatch;
+    use crate::{expr, symbol};
+
+    /// Integrate with the heuristics and check F' = f numerically
+    fn check(integrand: Expression, technique: IntegrationStrategy) {
+        let x = symbol!(x);
+        let result = try_heuristic_integration(&integrand, &x, 0)
+            .unwrap_or_else(|| panic!("no heuristic for {}", integrand));
+        assert_eq!(result.technique, technique, "{}", integrand);
+        assert!(is_closed_form(&result.antiderivative));
+        let derivative = result.antiderivative.derivative(x);
+        assert!(
+            find_mismatch(&integrand, &derivative, &Assumptions::new()).is_none(),
+            "d/dx {} != {}",
+            result.antiderivative,
+            integrand
+        );
+    }
+
+    #[test]
+    fn test_by_parts_liate() {
+        check(expr!(x * ln(x)), IntegrationStrategy::IntegrationByParts);
+        check(
+            expr!((x ^ 3) * ln(x)),
+            IntegrationStrategy::IntegrationByParts,
+        );
+        check(
+            expr!((x ^ 2) * exp(x)),
+            IntegrationStrategy::IntegrationByParts,
+        );
+        check(expr!(x * sin(x)), IntegrationStrategy::IntegrationByParts);
+        check(
+            expr!(3 * x * exp(x)),
+            IntegrationStrategy::IntegrationByParts,
+        );
+    }
+
+    #[test]
+    fn test_by_parts_lone_logarithm() {
+        check(expr!(ln(x) ^ 2), IntegrationStrategy::IntegrationByParts);
+    }
+
+    #[test]
+    fn test_cyclic_by_parts() {
+        check(
+            expr!(exp(x) * sin(x)),
+            IntegrationStrategy::IntegrationByParts,
+        );
+        check(
+            expr!(exp(2 * x) * cos(3 * x)),
+            IntegrationStrategy::IntegrationByParts,
+        );
+    }
+
+    #[test]
+    fn test_u_substitution() {
+        check(expr!(x * exp(x ^ 2)), IntegrationStrategy::Substitution);
+        check(
+            expr!(cos(x) * exp(sin(x))),
+            IntegrationStrategy::Substitution,
+        );
+        check(expr!(ln(x) * (x ^ (-1))), IntegrationStrategy::Substitution);
+    }
+
+    #[test]
+    fn test_trig_power_reduction() {
+        check(expr!(cos(x) ^ 4), IntegrationStrategy::Trigonometric);
+        check(
+            expr!((sin(x) ^ 2) * (cos(x) ^ 3)),
+            IntegrationStrategy::Trigonometric,
+        );
+        check(expr!(sin(2 * x) ^ 4), IntegrationStrategy::Trigonometric);
+    }
+
+    #[test]
+    fn test_tangent_half_angle() {
+        check(
+            expr!((2 + cos(x)) ^ (-1)),
+            IntegrationStrategy::WeierstrassSubstitution,
+        );
+        check(
+            expr!((1 + sin(x) + cos(x)) ^ (-1)),
+            IntegrationStrategy::WeierstrassSubstitution,
+        );
+    }
+
+    #[test]
+    fn test_no_heuristic_for_plain_power() {
+        let x = symbol!(x);
+        assert!(try_heuristic_integration(&expr!(x ^ 2), &x, 0).is_none());
+    }
+}
@@ -4,12 +4,18 @@
 //! of definite integrals when symbolic integration is not feasible.
 
 pub mod gaussian;
+pub mod multidimensional;
+pub mod nintegrate;
 pub mod romberg;
 pub mod simpson;
+pub mod tanh_sinh;
 
 pub use gaussian::GaussianQuadrature;
+pub use multidimensional::{integrate_unit_cube, CubatureMethod, Region, VarianceReduction};
+pub use nintegrate::find_singularities;
 pub use romberg::RombergIntegration;
 pub use simpson::AdaptiveSimpson;
+pub use tanh_sinh::TanhSinh;
 
 use crate::error::MathError;
 
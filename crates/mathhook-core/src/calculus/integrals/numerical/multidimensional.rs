@@ -0,0 +1,627 @@
+//! Multidimensional numerical integration
+//!
+//! Integrates over boxes and iterated regions in any number of variables. The
+//! region is mapped onto the unit cube, so every method only has to integrate
+//! over [0, 1]^d:
+//!
+//! - **Monte Carlo** samples uniformly with optional variance reduction
+//!   (antithetic pairs or stratification). The error estimate is the standard
+//!   error, and the cost does not grow with the dimension.
+//! - **Sparse grids** combine tensor products of low-order Gauss-Legendre rules
+//!   (Smolyak combination technique), which is far cheaper than a full tensor
+//!   grid and very accurate for smooth integrands in moderate dimensions.
+//!
+//! Expressions are compiled once with [`Expression::compile`] and evaluated at
+//! every sample point.
+
+use super::IntegrationResult;
+use crate::core::expression::compiled::CompiledExpression;
+use crate::core::{Expression, Symbol};
+use crate::error::MathError;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// Region of integration
+#[derive(Debug, Clone)]
+pub enum Region {
+    /// Axis-aligned box, one `(lower, upper)` pair per variable
+    Box(Vec<(f64, f64)>),
+    /// Iterated bounds, outermost variable first
+    ///
+    /// The bounds of the k-th variable may depend on the variables before it,
+    /// e.g. the unit disk is `[(-1, 1), (-√(1 - x²), √(1 - x²))]` for `[x, y]`.
+    Iterated(Vec<(Expression, Expression)>),
+}
+
+impl Region {
+    /// Number of variables the region spans
+    pub fn dimension(&self) -> usize {
+        match self {
+            Region::Box(bounds) => bounds.len(),
+            Region::Iterated(bounds) => bounds.len(),
+        }
+    }
+}
+
+/// Variance reduction for Monte Carlo integration
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VarianceReduction {
+    /// Plain uniform sampling
+    None,
+    /// Evaluate every sample together with its reflection u → 1 - u
+    Antithetic,
+    /// Split the cube into equal cells and sample each one separately
+    Stratified,
+}
+
+/// Multidimensional integration method
+#[derive(Debug, Clone)]
+pub enum CubatureMethod {
+    /// Monte Carlo with a reproducible seed
+    MonteCarlo {
+        samples: usize,
+        seed: u64,
+        variance_reduction: VarianceReduction,
+    },
+    /// Smolyak sparse grid, refined until two levels agree within `tolerance`
+    SparseGrid { max_level: usize, tolerance: f64 },
+}
+
+impl Default for CubatureMethod {
+    fn default() -> Self {
+        CubatureMethod::MonteCarlo {
+            samples: 100_000,
+            seed: 0,
+            variance_reduction: VarianceReduction::Antithetic,
+        }
+    }
+}
+
+impl Expression {
+    /// Numerically integrate over a region in several variables
+    ///
+    /// # Arguments
+    ///
+    /// * `variables` - Integration variables, in the order of the region bounds
+    /// * `region` - Box or iterated region
+    /// * `method` - Monte Carlo or sparse grid
+    ///
+    /// # Returns
+    ///
+    /// The value and its error estimate: the standard error for Monte Carlo and
+    /// the change between the last two levels for sparse grids. `iterations`
+    /// is the number of integrand evaluations; `subdivisions` the number of
+    /// strata or the final sparse-grid level.
+    ///
+    /// # Errors
+    ///
+    /// - `MathError::DomainError` if the region dimension differs from the number of variables
+    /// - `MathError::InvalidInterval` if a box bound is not finite
+    /// - `MathError::NonNumericalResult` if the integrand or a bound has other free symbols
+    /// - `MathError::ConvergenceFailed` if the result is not finite or the sparse
+    ///   grid does not reach the tolerance
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::calculus::integrals::numerical::{CubatureMethod, Region};
+    /// use mathhook_core::{expr, symbol};
+    ///
+    /// let x = symbol!(x);
+    /// let y = symbol!(y);
+    /// let region = Region::Box(vec![(0.0, 1.0), (0.0, 2.0)]);
+    /// let method = CubatureMethod::SparseGrid { max_level: 6, tolerance: 1e-10 };
+    /// let result = expr!(x * y).nintegrate_region(&[x, y], &region, &method).unwrap();
+    /// assert!((result.value - 1.0).abs() < 1e-10);
+    /// ```
+    pub fn nintegrate_region(
+        &self,
+        variables: &[Symbol],
+        region: &Region,
+        method: &CubatureMethod,
+    ) -> Result<IntegrationResult, MathError> {
+        if region.dimension() != variables.len() {
+            return Err(MathError::DomainError {
+                operation: "nintegrate_region".to_owned(),
+                value: self.clone(),
+                reason: format!(
+                    "region has {} dimensions but {} variables were given",
+                    region.dimension(),
+                    variables.len()
+                ),
+            });
+        }
+
+        let integrand = self.compile(variables)?;
+        let mapping = RegionMapping::new(region, variables)?;
+        let dimension = variables.len();
+
+        integrate_unit_cube(
+            |u| {
+                let mut point = vec![0.0; dimension];
+                let jacobian = mapping.map(u, &mut point);
+                if jacobian == 0.0 {
+                    0.0
+                } else {
+                    jacobian * integrand.evaluate(&point)
+                }
+            },
+            dimension,
+            method,
+        )
+    }
+}
+
+/// Integrate a function over the unit cube [0, 1]^d
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::calculus::integrals::numerical::{integrate_unit_cube, CubatureMethod};
+///
+/// let result = integrate_unit_cube(|u| u[0] + u[1], 2, &CubatureMethod::default()).unwrap();
+/// assert!((result.value - 1.0).abs() < 1e-2);
+/// ```
+pub fn integrate_unit_cube<F>(
+    f: F,
+    dimension: usize,
+    method: &CubatureMethod,
+) -> Result<IntegrationResult, MathError>
+where
+    F: Fn(&[f64]) -> f64,
+{
+    if dimension == 0 {
+        return Ok(IntegrationResult {
+            value: f(&[]),
+            error_estimate: 0.0,
+            iterations: 1,
+            subdivisions: 1,
+        });
+    }
+
+    let result = match *method {
+        CubatureMethod::MonteCarlo {
+            samples,
+            seed,
+            variance_reduction,
+        } => monte_carlo(&f, dimension, samples.max(2), seed, variance_reduction),
+        CubatureMethod::SparseGrid {
+            max_level,
+            tolerance,
+        } => sparse_grid(&f, dimension, max_level.max(2), tolerance)?,
+    };
+
+    if !result.value.is_finite() {
+        return Err(MathError::ConvergenceFailed {
+            reason: "integrand is not finite on the region".to_owned(),
+        });
+    }
+    Ok(result)
+}
+
+/// Compiled bounds mapping the unit cube onto the region
+struct RegionMapping {
+    bounds: Vec<(Bound, Bound)>,
+}
+
+enum Bound {
+    Fixed(f64),
+    Compiled(CompiledExpression),
+}
+
+impl Bound {
+    fn value(&self, point: &[f64]) -> f64 {
+        match self {
+            Bound::Fixed(value) => *value,
+            Bound::Compiled(bound) => bound.evaluate(point),
+        }
+    }
+}
+
+impl RegionMapping {
+    fn new(region: &Region, variables: &[Symbol]) -> Result<Self, MathError> {
+        let bounds = match region {
+            Region::Box(bounds) => bounds
+                .iter()
+                .map(|&(lower, upper)| {
+                    if lower.is_finite() && upper.is_finite() {
+                        Ok((Bound::Fixed(lower), Bound::Fixed(upper)))
+                    } else {
+                        Err(MathError::InvalidInterval { lower, upper })
+                    }
+                })
+                .collect::<Result<_, _>>()?,
+            Region::Iterated(bounds) => bounds
+                .iter()
+                .enumerate()
+                .map(|(k, (lower, upper))| {
+                    // Only the outer variables may appear in the bounds
+                    let outer = &variables[..k];
+                    Ok((
+                        Bound::Compiled(lower.compile(outer)?),
+                        Bound::Compiled(upper.compile(outer)?),
+                    ))
+                })
+                .collect::<Result<_, MathError>>()?,
+        };
+        Ok(Self { bounds })
+    }
+
+    /// Fill `point` with the image of `u` and return the Jacobian
+    fn map(&self, u: &[f64], point: &mut [f64]) -> f64 {
+        let mut jacobian = 1.0;
+        for (k, (lower, upper)) in self.bounds.iter().enumerate() {
+            let lower = lower.value(point);
+            let width = upper.value(point) - lower;
+            point[k] = lower + u[k] * width;
+            jacobian *= width;
+        }
+        jacobian
+    }
+}
+
+fn monte_carlo<F>(
+    f: &F,
+    dimension: usize,
+    samples: usize,
+    seed: u64,
+    variance_reduction: VarianceReduction,
+) -> IntegrationResult
+where
+    F: Fn(&[f64]) -> f64,
+{
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut u = vec![0.0; dimension];
+
+    match variance_reduction {
+        VarianceReduction::None => {
+            let mut stats = RunningStats::default();
+            for _ in 0..samples {
+                u.iter_mut().for_each(|c| *c = rng.random());
+                stats.push(f(&u));
+            }
+            stats.into_result(samples, 1)
+        }
+        VarianceReduction::Antithetic => {
+            let mut stats = RunningStats::default();
+            let mut reflected = vec![0.0; dimension];
+            for _ in 0..samples / 2 {
+                u.iter_mut().for_each(|c| *c = rng.random());
+                reflected.iter_mut().zip(&u).for_each(|(r, c)| *r = 1.0 - c);
+                stats.push(0.5 * (f(&u) + f(&reflected)));
+            }
+            stats.into_result(2 * (samples / 2), 1)
+        }
+        VarianceReduction::Stratified => {
+            // k cells per axis with at least two samples per cell
+            let per_axis = ((samples / 2) as f64).powf(1.0 / dimension as f64).floor() as usize;
+            let per_axis = per_axis.max(1);
+            let cells = per_axis.pow(dimension as u32);
+            let per_cell = (samples / cells).max(2);
+            let cell_volume = 1.0 / cells as f64;
+
+            let mut value = 0.0;
+            let mut variance = 0.0;
+            let mut corner = vec![0usize; dimension];
+            for _ in 0..cells {
+                let mut stats = RunningStats::default();
+                for _ in 0..per_cell {
+                    for (c, &index) in u.iter_mut().zip(&corner) {
+                        *c = (index as f64 + rng.random::<f64>()) / per_axis as f64;
+                    }
+                    stats.push(f(&u));
+                }
+                value += cell_volume * stats.mean;
+                variance += cell_volume * cell_volume * stats.variance() / per_cell as f64;
+                advance(&mut corner, per_axis);
+            }
+
+            IntegrationResult {
+                value,
+                error_estimate: variance.sqrt(),
+                iterations: cells * per_cell,
+                subdivisions: cells,
+            }
+        }
+    }
+}
+
+/// Step a multi-index through {0, …, base - 1}^d
+fn advance(index: &mut [usize], base: usize) {
+    for digit in index.iter_mut() {
+        *digit += 1;
+        if *digit < base {
+            return;
+        }
+        *digit = 0;
+    }
+}
+
+/// Welford accumulator for mean and variance
+#[derive(Default)]
+struct RunningStats {
+    count: usize,
+    mean: f64,
+    m2: f64,
+}
+
+impl RunningStats {
+    fn push(&mut self, value: f64) {
+        self.count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (value - self.mean);
+    }
+
+    fn variance(&self) -> f64 {
+        if self.count < 2 {
+            0.0
+        } else {
+            self.m2 / (self.count - 1) as f64
+        }
+    }
+
+    fn into_result(self, evaluations: usize, subdivisions: usize) -> IntegrationResult {
+        IntegrationResult {
+            value: self.mean,
+            error_estimate: (self.variance() / self.count.max(1) as f64).sqrt(),
+            iterations: evaluations,
+            subdivisions,
+        }
+    }
+}
+
+fn sparse_grid<F>(
+    f: &F,
+    dimension: usize,
+    max_level: usize,
+    tolerance: f64,
+) -> Result<IntegrationResult, MathError>
+where
+    F: Fn(&[f64]) -> f64,
+{
+    let rules: Vec<(Vec<f64>, Vec<f64>)> = (1..=max_level).map(unit_gauss_rule).collect();
+    let mut evaluations = 0;
+    let mut previous = smolyak(f, dimension, 1, &rules, &mut evaluations);
+
+    for level in 2..=max_level {
+        let current = smolyak(f, dimension, level, &rules, &mut evaluations);
+        let error_estimate = (current - previous).abs();
+        if error_estimate <= tolerance * current.abs().max(1.0) {
+            return Ok(IntegrationResult {
+                value: current,
+                error_estimate,
+                iterations: evaluations,
+                subdivisions: level,
+            });
+        }
+        previous = current;
+    }
+
+    Err(MathError::ConvergenceFailed {
+        reason: format!(
+            "sparse grid did not reach tolerance {} by level {}",
+            tolerance, max_level
+        ),
+    })
+}
+
+/// Smolyak combination technique
+///
+/// A(L, d) = Σ (-1)^(L-|i|) C(d-1, L-|i|) U^i1 ⊗ … ⊗ U^id over multi-indices
+/// i ≥ 1 with L - d + 1 ≤ |i| - d + 1 ≤ L, where U^i is the i-th 1D rule.
+fn smolyak<F>(
+    f: &F,
+    dimension: usize,
+    level: usize,
+    rules: &[(Vec<f64>, Vec<f64>)],
+    evaluations: &mut usize,
+) -> f64
+where
+    F: Fn(&[f64]) -> f64,
+{
+    let q = level + dimension - 1;
+    let lowest = q.saturating_sub(dimension - 1).max(dimension);
+    let mut total = 0.0;
+
+    for norm in lowest..=q {
+        let gap = q - norm;
+        let coefficient =
+            binomial(dimension - 1, gap) as f64 * if gap.is_multiple_of(2) { 1.0 } else { -1.0 };
+        for index in compositions(norm, dimension) {
+            total += coefficient * tensor_rule(f, &index, rules, evaluations);
+        }
+    }
+    total
+}
+
+/// Full tensor product of the 1D rules selected by `index`
+fn tensor_rule<F>(
+    f: &F,
+    index: &[usize],
+    rules: &[(Vec<f64>, Vec<f64>)],
+    evaluations: &mut usize,
+) -> f64
+where
+    F: Fn(&[f64]) -> f64,
+{
+    let sizes: Vec<usize> = index.iter().map(|&i| rules[i - 1].0.len()).collect();
+    let mut position = vec![0usize; index.len()];
+    let mut point = vec![0.0; index.len()];
+    let mut sum = 0.0;
+
+    loop {
+        let mut weight = 1.0;
+        for (k, &p) in position.iter().enumerate() {
+            let (nodes, weights) = &rules[index[k] - 1];
+            point[k] = nodes[p];
+            weight *= weights[p];
+        }
+        sum += weight * f(&point);
+        *evaluations += 1;
+
+        // Mixed-radix increment over the grid
+        let mut k = 0;
+        loop {
+            if k == position.len() {
+                return sum;
+            }
+            position[k] += 1;
+            if position[k] < sizes[k] {
+                break;
+            }
+            position[k] = 0;
+            k += 1;
+        }
+    }
+}
+
+/// All d-tuples of positive integers summing to `total`
+fn compositions(total: usize, parts: usize) -> Vec<Vec<usize>> {
+    if parts == 1 {
+        return vec![vec![total]];
+    }
+    (1..=total.saturating_sub(parts - 1))
+        .flat_map(|first| {
+            compositions(total - first, parts - 1)
+                .into_iter()
+                .map(move |mut rest| {
+                    rest.insert(0, first);
+                    rest
+                })
+        })
+        .collect()
+}
+
+fn binomial(n: usize, k: usize) -> usize {
+    if k > n {
+        return 0;
+    }
+    (0..k).fold(1, |acc, i| acc * (n - i) / (i + 1))
+}
+
+/// Gauss-Legendre rule with 2·level - 1 points on [0, 1]
+fn unit_gauss_rule(level: usize) -> (Vec<f64>, Vec<f64>) {
+    let n = 2 * level - 1;
+    let mut nodes = vec![0.0; n];
+    let mut weights = vec![0.0; n];
+
+    for i in 0..n.div_ceil(2) {
+        // Chebyshev initial guess refined by Newton's method on P_n
+        let mut x = (std::f64::consts::PI * (i as f64 + 0.75) / (n as f64 + 0.5)).cos();
+        let mut derivative = 1.0;
+        for _ in 0..100 {
+            let (p, dp) = legendre(n, x);
+            derivative = dp;
+            let step = p / dp;
+            x -= step;
+            if step.abs() < 1e-15 {
+                break;
+            }
+        }
+        let weight = 2.0 / ((1.0 - x * x) * derivative * derivative);
+        nodes[i] = 0.5 * (1.0 - x);
+        nodes[n - 1 - i] = 0.5 * (1.0 + x);
+        weights[i] = 0.5 * weight;
+        weights[n - 1 - i] = 0.5 * weight;
+    }
+    (nodes, weights)
+}
+
+/// Legendre polynomial P_n and its derivative at x
+fn legendre(n: usize, x: f64) -> (f64, f64) {
+    let (mut p0, mut p1) = (1.0, x);
+    for k in 2..=n {
+        let p2 = ((2 * k - 1) as f64 * x * p1 - (k - 1) as f64 * p0) / k as f64;
+        p0 = p1;
+        p1 = p2;
+    }
+    let dp = n as f64 * (x * p1 - p0) / (x * x - 1.0);
+    (p1, dp)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{expr, symbol};
+
+    #[test]
+    fn test_unit_gauss_rule_exactness() {
+        let (nodes, weights) = unit_gauss_rule(3);
+        let integral: f64 = nodes.iter().zip(&weights).map(|(x, w)| w * x.powi(9)).sum();
+        assert!((integral - 0.1).abs() < 1e-14);
+    }
+
+    #[test]
+    fn test_sparse_grid_box() {
+        let x = symbol!(x);
+        let y = symbol!(y);
+        let z = symbol!(z);
+        let region = Region::Box(vec![(0.0, 1.0), (0.0, 1.0), (0.0, 1.0)]);
+        let method = CubatureMethod::SparseGrid {
+            max_level: 8,
+            tolerance: 1e-12,
+        };
+        let result = expr!(exp(x + y + z))
+            .nintegrate_region(&[x, y, z], &region, &method)
+            .unwrap();
+        let expected = (std::f64::consts::E - 1.0).powi(3);
+        assert!((result.value - expected).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_monte_carlo_variance_reduction() {
+        let f = |u: &[f64]| u.iter().map(|c| c * c).sum::<f64>();
+        for variance_reduction in [
+            VarianceReduction::None,
+            VarianceReduction::Antithetic,
+            VarianceReduction::Stratified,
+        ] {
+            let method = CubatureMethod::MonteCarlo {
+                samples: 20_000,
+                seed: 7,
+                variance_reduction,
+            };
+            let result = integrate_unit_cube(f, 4, &method).unwrap();
+            assert!(
+                (result.value - 4.0 / 3.0).abs() < 5.0 * result.error_estimate + 1e-12,
+                "{:?}: {} ± {}",
+                variance_reduction,
+                result.value,
+                result.error_estimate
+            );
+        }
+    }
+
+    #[test]
+    fn test_iterated_region_disk_area() {
+        let x = symbol!(x);
+        let y = symbol!(y);
+        let edge = expr!(sqrt(1 - (x ^ 2)));
+        let region = Region::Iterated(vec![
+            (Expression::integer(-1), Expression::integer(1)),
+            (
+                Expression::mul(vec![Expression::integer(-1), edge.clone()]),
+                edge,
+            ),
+        ]);
+        let method = CubatureMethod::MonteCarlo {
+            samples: 50_000,
+            seed: 1,
+            variance_reduction: VarianceReduction::Stratified,
+        };
+        let result = Expression::integer(1)
+            .nintegrate_region(&[x, y], &region, &method)
+            .unwrap();
+        assert!((result.value - std::f64::consts::PI).abs() < 1e-2);
+    }
+
+    #[test]
+    fn test_dimension_mismatch() {
+        let x = symbol!(x);
+        let region = Region::Box(vec![(0.0, 1.0), (0.0, 1.0)]);
+        assert!(expr!(x)
+            .nintegrate_region(&[x], &region, &CubatureMethod::default())
+            .is_err());
+    }
+}
@@ -0,0 +1,356 @@
+//! Numerical integration of expressions
+//!
+//! [`Expression::nintegrate`] compiles the integrand, locates its singularities
+//! inside the interval, splits the interval there and integrates every piece
+//! with tanh-sinh quadrature, which tolerates integrable singularities at the
+//! endpoints of a piece.
+//!
+//! # Singularity Analysis
+//!
+//! Candidates come from the structure of the integrand: zeros of denominators
+//! (negative powers), of the arguments of ln, log, sqrt, abs and fractional
+//! powers, and of cos or sin under tan, sec, cot and csc. Each candidate is
+//! sampled across the interval; sign changes are refined by bisection and
+//! touching zeros by golden-section search on its absolute value.
+//!
+//! Before integrating, the order α of each singular endpoint is estimated from
+//! the growth |f(p + δ)| ~ δ^(-α). An order of one or more means the integral
+//! diverges, which is reported as an error instead of returning a meaningless
+//! number.
+
+use super::{IntegrationConfig, IntegrationResult, NumericalIntegrator, TanhSinh};
+use crate::core::expression::compiled::CompiledExpression;
+use crate::core::{Expression, Number, Symbol};
+use crate::error::MathError;
+
+/// Sample points per candidate function when locating zeros
+const SAMPLES: usize = 400;
+
+/// Endpoint singularities of at least this order are not integrable
+const DIVERGENCE_ORDER: f64 = 0.95;
+
+impl Expression {
+    /// Numerically integrate over [a, b] with respect to `var`
+    ///
+    /// Interior singularities split the interval; each piece is integrated with
+    /// tanh-sinh quadrature. `a > b` integrates in reverse.
+    ///
+    /// # Arguments
+    ///
+    /// * `var` - Variable of integration; the integrand may contain no other symbol
+    /// * `a` - Lower bound
+    /// * `b` - Upper bound
+    /// * `config` - Tolerance and iteration limits, applied to every piece
+    ///
+    /// # Returns
+    ///
+    /// The value with the summed error estimate of all pieces. `subdivisions`
+    /// is the number of pieces and `iterations` the total quadrature levels.
+    ///
+    /// # Errors
+    ///
+    /// - `MathError::InvalidInterval` if a bound is not finite
+    /// - `MathError::NonNumericalResult` if the integrand has other free symbols
+    /// - `MathError::ConvergenceFailed` if the integral diverges at a singularity
+    ///   or quadrature does not reach the tolerance
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::calculus::integrals::IntegrationConfig;
+    /// use mathhook_core::{expr, symbol, Expression};
+    ///
+    /// let x = symbol!(x);
+    /// // 1/√|x| is singular at 0 but integrable: ∫₋₁¹ = 4
+    /// let integrand = Expression::pow(expr!(abs(x)), Expression::rational(-1, 2));
+    /// let result = integrand
+    ///     .nintegrate(&x, -1.0, 1.0, &IntegrationConfig::default())
+    ///     .unwrap();
+    /// assert!((result.value - 4.0).abs() < 1e-8);
+    /// assert_eq!(result.subdivisions, 2);
+    /// ```
+    pub fn nintegrate(
+        &self,
+        var: &Symbol,
+        a: f64,
+        b: f64,
+        config: &IntegrationConfig,
+    ) -> Result<IntegrationResult, MathError> {
+        if !a.is_finite() || !b.is_finite() {
+            return Err(MathError::InvalidInterval { lower: a, upper: b });
+        }
+        if a > b {
+            let result = self.nintegrate(var, b, a, config)?;
+            return Ok(IntegrationResult {
+                value: -result.value,
+                ..result
+            });
+        }
+        if a == b {
+            return Ok(IntegrationResult {
+                value: 0.0,
+                error_estimate: 0.0,
+                iterations: 0,
+                subdivisions: 0,
+            });
+        }
+
+        let integrand = self.compile(std::slice::from_ref(var))?;
+        let f = |x: f64| integrand.evaluate(&[x]);
+        let singularities = find_singularities(self, var, a, b)?;
+
+        let mut breakpoints = vec![a];
+        breakpoints.extend(&singularities);
+        breakpoints.push(b);
+
+        let mut total = IntegrationResult {
+            value: 0.0,
+            error_estimate: 0.0,
+            iterations: 0,
+            subdivisions: 0,
+        };
+        for piece in breakpoints.windows(2) {
+            let (left, right) = (piece[0], piece[1]);
+            for (endpoint, direction) in [(left, 1.0), (right, -1.0)] {
+                if singularity_order(&f, endpoint, direction, right - left) >= DIVERGENCE_ORDER {
+                    return Err(MathError::ConvergenceFailed {
+                        reason: format!("integral diverges at {} = {}", var.name(), endpoint),
+                    });
+                }
+            }
+
+            let result = TanhSinh::new().integrate(f, left, right, config)?;
+            total.value += result.value;
+            total.error_estimate += result.error_estimate;
+            total.iterations += result.iterations;
+            total.subdivisions += 1;
+        }
+        Ok(total)
+    }
+}
+
+/// Points strictly inside (a, b) where `expr` may be singular, in increasing order
+///
+/// # Errors
+///
+/// Returns `MathError::NonNumericalResult` if `expr` has free symbols other
+/// than `var`.
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::calculus::integrals::numerical::find_singularities;
+/// use mathhook_core::{expr, symbol};
+///
+/// let x = symbol!(x);
+/// let points = find_singularities(&expr!(((x - 1) * (x - 2)) ^ (-1)), &x, 0.0, 3.0).unwrap();
+/// assert_eq!(points.len(), 2);
+/// assert!((points[0] - 1.0).abs() < 1e-12);
+/// ```
+pub fn find_singularities(
+    expr: &Expression,
+    var: &Symbol,
+    a: f64,
+    b: f64,
+) -> Result<Vec<f64>, MathError> {
+    let mut candidates = Vec::new();
+    critical_functions(expr, var, &mut candidates);
+
+    let width = b - a;
+    let mut points = Vec::new();
+    for candidate in candidates {
+        let g = candidate.compile(std::slice::from_ref(var))?;
+        points.extend(zeros(&g, a, b));
+    }
+
+    points.retain(|p| *p > a + 1e-12 * width && *p < b - 1e-12 * width);
+    points.sort_by(f64::total_cmp);
+    points.dedup_by(|p, q| (*p - *q).abs() <= 1e-9 * width);
+    Ok(points)
+}
+
+/// Subexpressions whose zeros are potential singularities
+fn critical_functions(expr: &Expression, var: &Symbol, out: &mut Vec<Expression>) {
+    fn push(candidate: Expression, var: &Symbol, out: &mut Vec<Expression>) {
+        if candidate.contains_variable(var) && !out.contains(&candidate) {
+            out.push(candidate);
+        }
+    }
+
+    match expr {
+        Expression::Pow(base, exp) => {
+            let singular_exponent = match exp.as_ref() {
+                Expression::Number(Number::Integer(n)) => *n < 0,
+                _ => true,
+            };
+            if singular_exponent {
+                push(base.as_ref().clone(), var, out);
+            }
+            critical_functions(base, var, out);
+            critical_functions(exp, var, out);
+        }
+        Expression::Function { name, args, .. } => {
+            if let [arg] = args.as_slice() {
+                match name.as_ref() {
+                    "ln" | "log" | "log10" | "sqrt" | "abs" => push(arg.clone(), var, out),
+                    "tan" | "sec" => push(Expression::function("cos", vec![arg.clone()]), var, out),
+                    "cot" | "csc" => push(Expression::function("sin", vec![arg.clone()]), var, out),
+                    _ => {}
+                }
+            }
+            for arg in args.iter() {
+                critical_functions(arg, var, out);
+            }
+        }
+        Expression::Add(terms) | Expression::Mul(terms) => {
+            for term in terms.iter() {
+                critical_functions(term, var, out);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Zeros of `g` in [a, b] from sign changes and touching minima of |g|
+fn zeros(g: &CompiledExpression, a: f64, b: f64) -> Vec<f64> {
+    let xs: Vec<f64> = (0..=SAMPLES)
+        .map(|i| a + (b - a) * i as f64 / SAMPLES as f64)
+        .collect();
+    let values: Vec<f64> = xs.iter().map(|x| g.evaluate(&[*x])).collect();
+    let scale = values
+        .iter()
+        .filter(|v| v.is_finite())
+        .fold(1.0_f64, |acc, v| acc.max(v.abs()));
+
+    let mut found = Vec::new();
+    for i in 0..SAMPLES {
+        let (x0, x1) = (xs[i], xs[i + 1]);
+        let (g0, g1) = (values[i], values[i + 1]);
+        if g0 == 0.0 {
+            found.push(x0);
+        } else if g0.is_finite() && g1.is_finite() && g0.signum() != g1.signum() && g1 != 0.0 {
+            found.push(bisect(g, x0, x1, g0));
+        } else if i > 0 && values[i - 1].abs() > g0.abs() && g1.abs() > g0.abs() {
+            let x = golden_section(g, xs[i - 1], x1);
+            if g.evaluate(&[x]).abs() <= 1e-12 * scale {
+                found.push(x);
+            }
+        }
+    }
+    found
+}
+
+fn bisect(g: &CompiledExpression, mut lo: f64, mut hi: f64, g_lo: f64) -> f64 {
+    let sign = g_lo.signum();
+    loop {
+        let mid = 0.5 * (lo + hi);
+        if mid <= lo || mid >= hi {
+            return mid;
+        }
+        let value = g.evaluate(&[mid]);
+        if value == 0.0 {
+            return mid;
+        }
+        if value.signum() == sign {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+}
+
+/// Minimizer of |g| on [lo, hi]
+fn golden_section(g: &CompiledExpression, mut lo: f64, mut hi: f64) -> f64 {
+    let ratio = (5.0_f64.sqrt() - 1.0) / 2.0;
+    let magnitude = |x: f64| g.evaluate(&[x]).abs();
+    for _ in 0..200 {
+        let c = hi - ratio * (hi - lo);
+        let d = lo + ratio * (hi - lo);
+        if c <= lo || d >= hi || c >= d {
+            break;
+        }
+        if magnitude(c) < magnitude(d) {
+            hi = d;
+        } else {
+            lo = c;
+        }
+    }
+    0.5 * (lo + hi)
+}
+
+/// Estimated α in |f(p + δ)| ~ δ^(-α) approaching `endpoint` from `direction`
+///
+/// Returns 0 when f stays bounded or cannot be evaluated near the endpoint.
+fn singularity_order<F: Fn(f64) -> f64>(f: &F, endpoint: f64, direction: f64, width: f64) -> f64 {
+    let (near, far) = (width * 1e-9, width * 1e-5);
+    let (f_near, f_far) = (
+        f(endpoint + direction * near).abs(),
+        f(endpoint + direction * far).abs(),
+    );
+    if !f_near.is_finite() || !f_far.is_finite() || f_near == 0.0 || f_far == 0.0 {
+        return 0.0;
+    }
+    (f_near / f_far).ln() / (far / near).ln()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{expr, symbol};
+
+    fn nintegrate(expr: Expression, a: f64, b: f64) -> Result<IntegrationResult, MathError> {
+        expr.nintegrate(&symbol!(x), a, b, &IntegrationConfig::default())
+    }
+
+    #[test]
+    fn test_smooth_integrand() {
+        let result = nintegrate(expr!(sin(x)), 0.0, std::f64::consts::PI).unwrap();
+        assert!((result.value - 2.0).abs() < 1e-10);
+        assert_eq!(result.subdivisions, 1);
+    }
+
+    #[test]
+    fn test_endpoint_singularities() {
+        let inverse_sqrt = Expression::pow(expr!(x), Expression::rational(-1, 2));
+        let result = nintegrate(inverse_sqrt, 0.0, 1.0).unwrap();
+        assert!((result.value - 2.0).abs() < 1e-8);
+        let result = nintegrate(expr!(ln(x)), 0.0, 1.0).unwrap();
+        assert!((result.value + 1.0).abs() < 1e-8);
+    }
+
+    #[test]
+    fn test_interior_singularity_splits_interval() {
+        let result = nintegrate(expr!(ln(abs(x - 1))), 0.0, 3.0).unwrap();
+        let expected = 2.0 * 2.0_f64.ln() - 3.0;
+        assert!((result.value - expected).abs() < 1e-8);
+        assert_eq!(result.subdivisions, 2);
+    }
+
+    #[test]
+    fn test_divergent_integrals_are_errors() {
+        assert!(nintegrate(expr!(x ^ (-1)), -1.0, 1.0).is_err());
+        assert!(nintegrate(expr!((x - 0.3) ^ (-2)), 0.0, 1.0).is_err());
+        assert!(nintegrate(expr!(tan(x)), 0.0, 2.0).is_err());
+    }
+
+    #[test]
+    fn test_reversed_and_empty_bounds() {
+        let forward = nintegrate(expr!(x ^ 2), 0.0, 1.0).unwrap();
+        let backward = nintegrate(expr!(x ^ 2), 1.0, 0.0).unwrap();
+        assert!((forward.value + backward.value).abs() < 1e-14);
+        assert_eq!(nintegrate(expr!(x ^ 2), 1.0, 1.0).unwrap().value, 0.0);
+    }
+
+    #[test]
+    fn test_touching_zero_is_found() {
+        let x = symbol!(x);
+        let points = find_singularities(&expr!((x - 0.3) ^ (-2)), &x, 0.0, 1.0).unwrap();
+        assert_eq!(points.len(), 1);
+        assert!((points[0] - 0.3).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_free_symbols_are_rejected() {
+        assert!(nintegrate(expr!(x * y), 0.0, 1.0).is_err());
+    }
+}
@@ -36,10 +36,13 @@
 //! - Simple irreducible quadratics `(x²+px+q)` with proper coefficient extraction
 //! - Repeated irreducible quadratics `(x²+px+q)²` via Ostrogradsky's reduction formula
 //!
+//! - Any denominator with rational coefficients, factored or not, through
+//!   [`crate::algebra::partial_fractions`]
+//!
 //! **Not Yet Implemented:**
-//! - Repeated irreducible quadratics `(x²+px+q)^m` with `m > 2`
-//!   (Can be generalized using recursive Ostrogradsky reduction)
-//! - Automatic polynomial factorization (assumes factored form)
+//! - Repeated irreducible quadratics `(x²+px+q)^m` with `m > 2` and symbolic
+//!   coefficients (Can be generalized using recursive Ostrogradsky reduction)
+//! - Factorization of denominators with symbolic coefficients (assumes factored form)
 //!
 //! # References
 //!
@@ -50,10 +53,12 @@
 //! - Bronstein, "Symbolic Integration I"
 
 use crate::algebra::gcd::PolynomialGcd;
+use crate::algebra::partial_fractions::to_rational_function;
 use crate::core::constants::EPSILON;
 use crate::core::{Expression, Number, Symbol};
 use crate::simplify::Simplify;
 
+pub(crate) mod exact;
 pub mod helpers;
 pub mod linear;
 pub mod quadratic;
@@ -309,6 +314,15 @@ pub fn integrate_rational(expr: &Expression, var: &Symbol) -> Option<Expression>
         return None;
     }
 
+    // With rational coefficients the denominator need not be factored;
+    // polynomials are left to the basic rules
+    if let Some(f) = to_rational_function(expr, var) {
+        if f.den.is_constant() {
+            return None;
+        }
+        return exact::integrate_rational_function(&f, var);
+    }
+
     // Early return if denominator doesn't actually contain the variable
     // This happens when expression is in wrong variable (e.g., contains 'x' but var is 'u')
     // Return None to let other strategies handle it
@@ -0,0 +1,210 @@
+//! Exact integration of partial fraction decompositions
+//!
+//! Integrates the decompositions of [`crate::algebra::partial_fractions`]
+//! term by term. Their coefficients are rational numbers, so each fraction
+//! A/Fⁱ has a monic factor F that is linear, or quadratic without rational
+//! roots:
+//!
+//! - ∫A/(t - r)ⁱ dt is a logarithm or a power of (t - r)
+//! - ∫(Bt + C)/(t² + pt + q)ⁱ dt is split into (B/2)·F'/Fⁱ and a multiple of
+//!   ∫ds/(s² + k)ⁱ with s = t + p/2, which the reduction formula brings down
+//!   to an arctangent (k > 0) or a logarithm (k < 0)
+//!
+//! Factors of degree three or more are not integrated.
+
+use crate::algebra::partial_fractions::{
+    decompose, poly_expr, ratio_expr, Decomposition, RationalFunction, Q,
+};
+use crate::core::polynomial::poly::RationalPoly;
+use crate::core::{Expression, Symbol};
+use crate::simplify::Simplify;
+use num_traits::{Signed, Zero};
+
+/// Integrate `f`, a rational function of `var`
+///
+/// Returns `None` if the denominator keeps a factor of degree three or more.
+pub(crate) fn integrate_rational_function(
+    f: &RationalFunction,
+    var: &Symbol,
+) -> Option<Expression> {
+    integrate_decomposition(&decompose(f, &[])?, &Expression::symbol(var.clone()))
+}
+
+/// Antiderivative of a decomposition in the variable `t`
+pub(crate) fn integrate_decomposition(
+    decomposition: &Decomposition,
+    t: &Expression,
+) -> Option<Expression> {
+    let mut antiderivative = vec![poly_expr(&integrate_poly(&decomposition.polynomial), t)];
+    for fraction in &decomposition.fractions {
+        antiderivative.push(integrate_partial_fraction(
+            &fraction.numerator,
+            &fraction.factor,
+            fraction.power as i64,
+            t,
+        )?);
+    }
+    Some(Expression::add(antiderivative).simplify())
+}
+
+fn integrate_poly(p: &RationalPoly) -> RationalPoly {
+    let mut coeffs = vec![Q::zero()];
+    coeffs.extend(
+        p.coefficients()
+            .iter()
+            .enumerate()
+            .map(|(i, c)| c / Q::from_integer(i as i64 + 1)),
+    );
+    RationalPoly::from_coeffs(coeffs)
+}
+
+/// ∫A/Fⁱ dt for a monic irreducible factor F of degree one or two
+fn integrate_partial_fraction(
+    numerator: &RationalPoly,
+    factor: &RationalPoly,
+    i: i64,
+    t: &Expression,
+) -> Option<Expression> {
+    let f = poly_expr(factor, t);
+    let log_of =
+        |e: Expression| Expression::function("ln", vec![Expression::function("abs", vec![e])]);
+
+    if factor.degree()? > 2 {
+        return None;
+    }
+    if factor.degree()? == 1 {
+        let a = ratio_expr(&numerator.coeff(0));
+        return Some(if i == 1 {
+            Expression::mul(vec![a, log_of(f)])
+        } else {
+            Expression::mul(vec![
+                a,
+                Expression::rational(-1, i - 1),
+                Expression::pow(f, Expression::integer(1 - i)),
+            ])
+        });
+    }
+
+    // (Bt + C)/Fⁱ = (B/2)·F'/Fⁱ + (C - Bp/2)/Fⁱ with F = t² + pt + q = s² + k
+    let (b, c) = (numerator.coeff(1), numerator.coeff(0));
+    let (p, q) = (factor.coeff(1), factor.coeff(0));
+    let half_p = p / Q::from_integer(2);
+    let k = q - half_p * half_p;
+    let s = Expression::add(vec![t.clone(), ratio_expr(&half_p)]);
+
+    let logarithmic = if i == 1 {
+        if k.is_positive() {
+            Expression::function("ln", vec![f.clone()])
+        } else {
+            log_of(f.clone())
+        }
+    } else {
+        Expression::mul(vec![
+            Expression::rational(1, 1 - i),
+            Expression::pow(f.clone(), Expression::integer(1 - i)),
+        ])
+    };
+
+    // J₁ = ∫ds/(s² + k); Jₙ₊₁ = s/(2nk·Fⁿ) + (2n - 1)/(2nk)·Jₙ
+    let mut j = if k.is_positive() {
+        let root = sqrt_ratio(&k);
+        Expression::mul(vec![
+            Expression::pow(root.clone(), Expression::integer(-1)),
+            Expression::function(
+                "arctan",
+                vec![Expression::mul(vec![
+                    s.clone(),
+                    Expression::pow(root, Expression::integer(-1)),
+                ])],
+            ),
+        ])
+    } else {
+        let root = sqrt_ratio(&-k);
+        Expression::mul(vec![
+            Expression::rational(1, 2),
+            Expression::pow(root.clone(), Expression::integer(-1)),
+            log_of(Expression::mul(vec![
+                Expression::add(vec![
+                    s.clone(),
+                    Expression::mul(vec![Expression::integer(-1), root.clone()]),
+                ]),
+                Expression::pow(
+                    Expression::add(vec![s.clone(), root]),
+                    Expression::integer(-1),
+                ),
+            ])),
+        ])
+    };
+    for n in 1..i {
+        let two_nk = Q::from_integer(2 * n) * k;
+        j = Expression::add(vec![
+            Expression::mul(vec![
+                ratio_expr(&two_nk.recip()),
+                s.clone(),
+                Expression::pow(f.clone(), Expression::integer(-n)),
+            ]),
+            Expression::mul(vec![ratio_expr(&(Q::from_integer(2 * n - 1) / two_nk)), j]),
+        ]);
+    }
+
+    Some(Expression::add(vec![
+        Expression::mul(vec![ratio_expr(&(b / Q::from_integer(2))), logarithmic]),
+        Expression::mul(vec![ratio_expr(&(c - b * half_p)), j]),
+    ]))
+}
+
+/// √r, exact when numerator and denominator are perfect squares
+fn sqrt_ratio(r: &Q) -> Expression {
+    let exact = |n: i64| {
+        let root = (n as f64).sqrt().round() as i64;
+        (root * root == n).then_some(root)
+    };
+    match (exact(*r.numer()), exact(*r.denom())) {
+        (Some(n), Some(d)) => Expression::rational(n, d),
+        _ => Expression::pow(ratio_expr(r), Expression::rational(1, 2)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algebra::solvers::Assumptions;
+    use crate::calculus::derivatives::Derivative;
+    use crate::calculus::integrals::integrate_rational;
+    use crate::core::Expression;
+    use crate::simplify::verification::find_mismatch;
+    use crate::{expr, symbol};
+
+    fn check(integrand: Expression) {
+        let x = symbol!(x);
+        let result = integrate_rational(&integrand, &x)
+            .unwrap_or_else(|| panic!("no antiderivative for {}", integrand));
+        let derivative = result.derivative(x);
+        assert!(
+            find_mismatch(&integrand, &derivative, &Assumptions::new()).is_none(),
+            "d/dx {} != {}",
+            result,
+            integrand
+        );
+    }
+
+    #[test]
+    fn test_unfactored_denominators() {
+        check(expr!(((x ^ 2) + (3 * x) + 2) ^ (-1)));
+        check(expr!(((x ^ 3) - x) ^ (-1)));
+        check(expr!((x + 3) / ((x ^ 3) + (x ^ 2) + x + 1)));
+        check(expr!((2 * x + 1) ^ (-1)));
+    }
+
+    #[test]
+    fn test_repeated_factors_and_polynomial_part() {
+        check(expr!(((x ^ 2) * ((x - 1) ^ 3)) ^ (-1)));
+        check(expr!(((x ^ 4) + 1) / ((x ^ 2) - 4)));
+        check(expr!((((x ^ 2) + 2 * x + 5) ^ 3) ^ (-1)));
+    }
+
+    #[test]
+    fn test_factor_of_degree_three_is_not_integrated() {
+        let x = symbol!(x);
+        assert!(integrate_rational(&expr!(((x ^ 3) + x + 1) ^ (-1)), &x).is_none());
+    }
+}
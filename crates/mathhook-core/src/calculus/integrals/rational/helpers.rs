@@ -1,6 +1,7 @@
 //! Helper utilities for rational function integration
 
 use crate::core::{Expression, Number, Symbol};
+use crate::functions::special;
 use crate::simplify::Simplify;
 
 /// Check if expression is a polynomial in the given variable
@@ -140,7 +141,7 @@ pub fn substitute_variable(expr: &Expression, var: &Symbol, value: &Expression)
             substitute_variable(base, var, value),
             substitute_variable(exp, var, value),
         ),
-        Expression::Function { name, args } => Expression::function(
+        Expression::Function { name, args, .. } => Expression::function(
             name,
             args.iter()
                 .map(|a| substitute_variable(a, var, value))
@@ -152,6 +153,8 @@ pub fn substitute_variable(expr: &Expression, var: &Symbol, value: &Expression)
 
 /// Compute factorial of a non-negative integer
 ///
+/// Exact for any `n`: results past `i64` are big integers.
+///
 /// # Arguments
 ///
 /// * `n` - Non-negative integer
@@ -160,18 +163,17 @@ pub fn substitute_variable(expr: &Expression, var: &Symbol, value: &Expression)
 ///
 /// ```
 /// use mathhook_core::calculus::integrals::rational::helpers::factorial;
-///
-/// assert_eq!(factorial(0), 1);
-/// assert_eq!(factorial(1), 1);
-/// assert_eq!(factorial(5), 120);
-/// assert_eq!(factorial(10), 3628800);
+/// use mathhook_core::Expression;
+///
+/// assert_eq!(factorial(0), Expression::integer(1));
+/// assert_eq!(factorial(5), Expression::integer(120));
+/// assert_eq!(
+///     factorial(21),
+///     Expression::big_integer("51090942171709440000".parse().unwrap())
+/// );
 /// ```
-pub fn factorial(n: i64) -> i64 {
-    if n <= 0 {
-        1
-    } else {
-        (1..=n).product()
-    }
+pub fn factorial(n: i64) -> Expression {
+    special::factorial::factorial(&Expression::integer(n.max(0)))
 }
 
 /// Try to extract quadratic coefficients from x² + px + q
@@ -179,7 +179,7 @@ pub fn compute_heaviside_coefficient(
 
     let fact = factorial(derivative_order);
     Some(Expression::mul(vec![
-        Expression::rational(1, fact),
+        Expression::pow(fact, Expression::integer(-1)),
         evaluated,
     ]))
 }
@@ -5,14 +5,20 @@
 //! # Strategy Layers
 //!
 //! 1. **Table lookup** - O(1) exact pattern matching for common integrals
-//! 2. **Rational functions** - Partial fraction decomposition for P(x)/Q(x)
-//! 3. **Function registry** - Known antiderivatives (sin, cos, exp, ln, etc.)
-//! 4. **Integration by parts** - Product rule in reverse using LIATE heuristic
-//! 5. **Substitution** - Chain rule in reverse (u-substitution)
-//! 6. **Trigonometric** - Trig identities and power reduction formulas
-//! 7. **Risch algorithm** - Decision procedure for elementary functions
-//! 8. **Basic rules** - Power rule, constants, sums, constant multiples
-//! 9. **Symbolic fallback** - Return unevaluated integral expression
+//! 2. **Distributions** - Sifting by δ(x - a) and cut-off by H(x - a)
+//! 3. **Rational functions** - Partial fraction decomposition for P(x)/Q(x)
+//! 4. **Function registry** - Known antiderivatives (sin, cos, exp, ln, etc.)
+//! 5. **Heuristics** - LIATE by parts, u-substitution, trig substitution, tangent
+//!    half-angle substitution and trig power reduction
+//! 6. **Integration by parts** - Product rule in reverse using LIATE heuristic
+//! 7. **Substitution** - Chain rule in reverse (u-substitution)
+//! 8. **Trigonometric** - Trig identities and power reduction formulas
+//! 9. **Risch algorithm** - Decision procedure for elementary functions
+//! 10. **Basic rules** - Power rule, constants, sums, constant multiples
+//! 11. **Symbolic fallback** - Return unevaluated integral expression
+//!
+//! [`integrate_with_technique`] additionally reports which layer produced the
+//! result, for educational traces.
 //!
 //! # Strategy Tracking
 //!
@@ -23,11 +29,14 @@
 //!
 //! Maximum integration depth is 10 to prevent infinite recursion in pathological cases.
 use crate::calculus::integrals::{
-    basic::BasicIntegrals, by_parts::IntegrationByParts, function_integrals::FunctionIntegrals,
-    rational, risch, substitution, table, trigonometric,
+    basic::BasicIntegrals, by_parts::IntegrationByParts, distributions,
+    function_integrals::FunctionIntegrals, heuristics, rational, risch, substitution, table,
+    trigonometric,
 };
+use crate::core::provenance::{AlgorithmId, Provenance};
 use crate::core::{Expression, Number, Symbol};
 use std::collections::HashSet;
+use std::time::Instant;
 /// Maximum integration recursion depth
 ///
 /// This prevents infinite recursion in cases where integration strategies
@@ -40,14 +49,35 @@ const MAX_DEPTH: usize = 10;
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum IntegrationStrategy {
     TableLookup,
+    Distribution,
     RationalFunction,
     FunctionRegistry,
     IntegrationByParts,
     Substitution,
+    TrigSubstitution,
+    WeierstrassSubstitution,
     Trigonometric,
     Risch,
     BasicRules,
 }
+impl IntegrationStrategy {
+    /// Short name used in explanations
+    pub fn name(&self) -> &'static str {
+        match self {
+            IntegrationStrategy::TableLookup => "table",
+            IntegrationStrategy::Distribution => "distribution",
+            IntegrationStrategy::RationalFunction => "rational",
+            IntegrationStrategy::FunctionRegistry => "function",
+            IntegrationStrategy::IntegrationByParts => "by_parts",
+            IntegrationStrategy::Substitution => "substitution",
+            IntegrationStrategy::TrigSubstitution => "trig_substitution",
+            IntegrationStrategy::WeierstrassSubstitution => "weierstrass",
+            IntegrationStrategy::Trigonometric => "trig",
+            IntegrationStrategy::Risch => "risch",
+            IntegrationStrategy::BasicRules => "basic",
+        }
+    }
+}
 /// Strategy execution context
 ///
 /// Tracks which integration strategies are currently active to prevent
@@ -109,45 +139,112 @@ pub fn integrate_with_strategy(expr: &Expression, var: Symbol, depth: usize) ->
     };
     integrate_with_context(expr, var, &context)
 }
+/// Integrate and report which technique produced the result
+///
+/// The technique is `None` when the integral is left unevaluated.
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::calculus::integrals::strategy::{
+///     integrate_with_technique, IntegrationStrategy,
+/// };
+/// use mathhook_core::{expr, symbol};
+///
+/// let x = symbol!(x);
+/// let (_, technique) = integrate_with_technique(&expr!(x * exp(x ^ 2)), x, 0);
+/// assert_eq!(technique, Some(IntegrationStrategy::Substitution));
+/// ```
+pub fn integrate_with_technique(
+    expr: &Expression,
+    var: Symbol,
+    depth: usize,
+) -> (Expression, Option<IntegrationStrategy>) {
+    if depth >= MAX_DEPTH {
+        return (Expression::integral(expr.clone(), var), None);
+    }
+    let context = StrategyContext {
+        active_strategies: HashSet::new(),
+        depth,
+    };
+    integrate_tracked(expr, var, &context)
+}
+/// Integrate, reporting the algorithm used and the time taken
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::calculus::integrals::integrate_with_provenance;
+/// use mathhook_core::core::AlgorithmId;
+/// use mathhook_core::{expr, symbol};
+///
+/// let x = symbol!(x);
+/// let integrated = integrate_with_provenance(&expr!(x * exp(x ^ 2)), x);
+/// assert_eq!(integrated.algorithm, AlgorithmId::Substitution);
+/// ```
+pub fn integrate_with_provenance(expr: &Expression, var: Symbol) -> Provenance<Expression> {
+    let start = Instant::now();
+    let (result, technique) = integrate_with_technique(expr, var, 0);
+    Provenance {
+        result,
+        algorithm: technique.map_or(AlgorithmId::Unevaluated, AlgorithmId::from),
+        elapsed: start.elapsed(),
+    }
+}
 /// Integration with explicit strategy context
 ///
 /// Used by recursive calls to track which strategies are currently active.
 fn integrate_with_context(expr: &Expression, var: Symbol, ctx: &StrategyContext) -> Expression {
+    integrate_tracked(expr, var, ctx).0
+}
+/// Try each layer in order, recording the one that succeeds
+fn integrate_tracked(
+    expr: &Expression,
+    var: Symbol,
+    ctx: &StrategyContext,
+) -> (Expression, Option<IntegrationStrategy>) {
     if let Some(result) = try_table_lookup_with_context(expr, &var, ctx) {
-        return result;
+        return (result, Some(IntegrationStrategy::TableLookup));
+    }
+    if let Some(result) = distributions::try_distribution_integration(expr, &var, ctx.depth()) {
+        return (result, Some(IntegrationStrategy::Distribution));
     }
     if is_rational_function(expr, &var) {
         if let Some(result) = try_rational_function(expr, &var) {
-            return result;
+            return (result, Some(IntegrationStrategy::RationalFunction));
         }
     }
     if let Some(result) = try_registry_integration_with_context(expr, &var, ctx) {
-        return result;
+        return (result, Some(IntegrationStrategy::FunctionRegistry));
+    }
+    if let Some(result) = heuristics::try_heuristic_integration(expr, &var, ctx.depth()) {
+        return (result.antiderivative, Some(result.technique));
     }
     if let Some(result) = ctx.with_strategy(IntegrationStrategy::IntegrationByParts, |child_ctx| {
         try_by_parts_with_context(expr, &var, child_ctx, child_ctx.depth())
     }) {
-        return result;
+        return (result, Some(IntegrationStrategy::IntegrationByParts));
     }
     if let Some(result) = ctx.with_strategy(IntegrationStrategy::Substitution, |child_ctx| {
         try_substitution_with_context(expr, &var, child_ctx)
     }) {
-        return result;
+        return (result, Some(IntegrationStrategy::Substitution));
     }
     if let Some(result) = ctx.with_strategy(IntegrationStrategy::Trigonometric, |child_ctx| {
         try_trigonometric_with_context(expr, &var, child_ctx)
     }) {
-        return result;
+        return (result, Some(IntegrationStrategy::Trigonometric));
     }
     if let Some(result) = ctx.with_strategy(IntegrationStrategy::Risch, |child_ctx| {
         try_risch_with_context(expr, &var, child_ctx)
     }) {
-        return result;
+        return (result, Some(IntegrationStrategy::Risch));
     }
     if let Some(result) = try_basic_rules_with_context(expr, &var, ctx) {
-        return result;
+        let technique = (!is_symbolic_integral(&result)).then_some(IntegrationStrategy::BasicRules);
+        return (result, technique);
     }
-    Expression::integral(expr.clone(), var)
+    (Expression::integral(expr.clone(), var), None)
 }
 /// Table lookup with strategy context
 fn try_table_lookup_with_context(
@@ -166,7 +263,7 @@ fn try_rational_function(expr: &Expression, var: &Symbol) -> Option<Expression>
 /// Uses the function intelligence registry for elementary functions.
 pub fn try_registry_integration(expr: &Expression, var: &Symbol) -> Option<Expression> {
     match expr {
-        Expression::Function { name, args } => {
+        Expression::Function { name, args, .. } => {
             let result = FunctionIntegrals::integrate(name, args, var.clone());
             if is_symbolic_integral(&result) {
                 None
@@ -142,7 +142,7 @@ fn find_substitution_candidates(expr: &Expression, var: &Symbol) -> Vec<Expressi
 /// Recursively collect substitution candidates from expression tree
 fn collect_candidates_recursive(expr: &Expression, var: &Symbol, candidates: &mut Vec<Expression>) {
     match expr {
-        Expression::Function { name: _, args } => {
+        Expression::Function { name: _, args, .. } => {
             // For function arguments, consider the function itself as a candidate
             // Example: sin(x) is a candidate in sin³(x)·cos(x)
             if args.len() == 1 && args[0].contains_variable(var) {
@@ -199,7 +199,7 @@ fn contains_expression(expr: &Expression, candidate: &Expression) -> bool {
         Expression::Pow(base, exp) => {
             contains_expression(base, candidate) || contains_expression(exp, candidate)
         }
-        Expression::Function { name: _, args } => {
+        Expression::Function { name: _, args, .. } => {
             args.iter().any(|a| contains_expression(a, candidate))
         }
         _ => false,
@@ -218,7 +218,7 @@ fn expression_complexity(expr: &Expression) -> usize {
         Expression::Add(terms) => terms.iter().map(expression_complexity).sum::<usize>() + 1,
         Expression::Mul(factors) => factors.iter().map(expression_complexity).sum::<usize>() + 1,
         Expression::Pow(base, exp) => expression_complexity(base) + expression_complexity(exp) + 1,
-        Expression::Function { name: _, args } => {
+        Expression::Function { name: _, args, .. } => {
             args.iter().map(expression_complexity).sum::<usize>() + 2
         }
         _ => 1,
@@ -394,7 +394,7 @@ fn replace_expression(
             replace_expression(base, pattern, replacement),
             replace_expression(exp, pattern, replacement),
         ),
-        Expression::Function { name, args } => Expression::function(
+        Expression::Function { name, args, .. } => Expression::function(
             name,
             args.iter()
                 .map(|a| replace_expression(a, pattern, replacement))
@@ -0,0 +1,178 @@
+//! Built-in integral table entries
+//!
+//! Each entry is (pattern, antiderivative, conditions). Wildcards: `x` is the
+//! integration variable, `a` a nonzero constant coefficient, `n` a numeric exponent
+//! and `c` a positive numeric constant.
+
+use super::rule::{IntegralRule, RuleCondition};
+use crate::core::Expression;
+use crate::expr;
+use crate::pattern::Pattern;
+
+/// All built-in rules, in lookup order
+pub(super) fn builtin_rules() -> Vec<IntegralRule> {
+    let mut rules = Vec::with_capacity(32);
+    rules.extend(power_rules());
+    rules.extend(exponential_and_log_rules());
+    rules.extend(trigonometric_rules());
+    rules.extend(hyperbolic_rules());
+    rules.extend(inverse_trig_rules());
+    rules
+}
+
+fn power_rules() -> Vec<IntegralRule> {
+    vec![
+        // ∫x dx = x²/2
+        IntegralRule::new("identity", x(), expr!((1 / 2) * (x ^ 2))),
+        // ∫1/x dx = ln|x|
+        IntegralRule::new(
+            "reciprocal",
+            pow(x(), exact(-1)),
+            Expression::function("ln", vec![Expression::function("abs", vec![expr!(x)])]),
+        ),
+        // ∫xⁿ dx = xⁿ⁺¹/(n+1), n ≠ -1
+        IntegralRule::new(
+            "power",
+            pow(x(), w("n")),
+            expr!(((n + 1) ^ (-1)) * (x ^ (n + 1))),
+        )
+        .when(RuleCondition::Numeric("n".to_owned()))
+        .when(RuleCondition::NotEqual(
+            "n".to_owned(),
+            Expression::integer(-1),
+        )),
+        // ∫√x dx = (2/3)x^(3/2)
+        IntegralRule::new(
+            "sqrt",
+            func("sqrt", x()),
+            Expression::mul(vec![
+                Expression::rational(2, 3),
+                Expression::pow(expr!(x), Expression::rational(3, 2)),
+            ]),
+        ),
+        // ∫1/√x dx = 2√x
+        IntegralRule::new(
+            "reciprocal_sqrt",
+            pow(func("sqrt", x()), exact(-1)),
+            expr!(2 * sqrt(x)),
+        ),
+    ]
+}
+
+fn exponential_and_log_rules() -> Vec<IntegralRule> {
+    vec![
+        // ∫eˣ dx = eˣ
+        IntegralRule::new("exp", func("exp", x()), expr!(exp(x))),
+        // ∫e^(ax) dx = e^(ax)/a
+        linear_rule("exp_linear", "exp", expr!((a ^ (-1)) * exp(a * x))),
+        // ∫ln(x) dx = x·ln(x) - x
+        IntegralRule::new("ln", func("ln", x()), expr!((x * ln(x)) - x)),
+    ]
+}
+
+fn trigonometric_rules() -> Vec<IntegralRule> {
+    vec![
+        // ∫sin(x) dx = -cos(x)
+        IntegralRule::new("sin", func("sin", x()), expr!(-1 * cos(x))),
+        // ∫sin(ax) dx = -cos(ax)/a
+        linear_rule("sin_linear", "sin", expr!(-1 * (a ^ (-1)) * cos(a * x))),
+        // ∫cos(x) dx = sin(x)
+        IntegralRule::new("cos", func("cos", x()), expr!(sin(x))),
+        // ∫cos(ax) dx = sin(ax)/a
+        linear_rule("cos_linear", "cos", expr!((a ^ (-1)) * sin(a * x))),
+        // ∫tan(x) dx = -ln|cos(x)|
+        IntegralRule::new(
+            "tan",
+            func("tan", x()),
+            Expression::mul(vec![Expression::integer(-1), ln_abs(expr!(cos(x)))]),
+        ),
+        // ∫cot(x) dx = ln|sin(x)|
+        IntegralRule::new("cot", func("cot", x()), ln_abs(expr!(sin(x)))),
+        // ∫sec(x) dx = ln|sec(x) + tan(x)|
+        IntegralRule::new("sec", func("sec", x()), ln_abs(expr!(sec(x) + tan(x)))),
+        // ∫csc(x) dx = -ln|csc(x) + cot(x)|
+        IntegralRule::new(
+            "csc",
+            func("csc", x()),
+            Expression::mul(vec![
+                Expression::integer(-1),
+                ln_abs(expr!(csc(x) + cot(x))),
+            ]),
+        ),
+    ]
+}
+
+fn hyperbolic_rules() -> Vec<IntegralRule> {
+    vec![
+        // ∫sinh(x) dx = cosh(x)
+        IntegralRule::new("sinh", func("sinh", x()), expr!(cosh(x))),
+        // ∫cosh(x) dx = sinh(x)
+        IntegralRule::new("cosh", func("cosh", x()), expr!(sinh(x))),
+        // ∫tanh(x) dx = ln(cosh(x))
+        IntegralRule::new("tanh", func("tanh", x()), expr!(ln(cosh(x)))),
+    ]
+}
+
+fn inverse_trig_rules() -> Vec<IntegralRule> {
+    let x_squared = pow(x(), exact(2));
+    vec![
+        // ∫1/(x² + c) dx = atan(x/√c)/√c, c > 0
+        IntegralRule::new(
+            "arctan",
+            pow(Pattern::Add(vec![x_squared.clone(), w("c")]), exact(-1)),
+            expr!((sqrt(c) ^ (-1)) * atan(x * (sqrt(c) ^ (-1)))),
+        )
+        .when(RuleCondition::Positive("c".to_owned())),
+        // ∫1/√(c - x²) dx = asin(x/√c), c > 0
+        IntegralRule::new(
+            "arcsin",
+            pow(
+                func(
+                    "sqrt",
+                    Pattern::Add(vec![w("c"), Pattern::Mul(vec![exact(-1), x_squared])]),
+                ),
+                exact(-1),
+            ),
+            expr!(asin(x * (sqrt(c) ^ (-1)))),
+        )
+        .when(RuleCondition::Positive("c".to_owned())),
+    ]
+}
+
+/// Rule for f(a·x) with nonzero constant `a`
+fn linear_rule(name: &str, function: &str, antiderivative: Expression) -> IntegralRule {
+    IntegralRule::new(
+        name,
+        func(function, Pattern::Mul(vec![w("a"), x()])),
+        antiderivative,
+    )
+    .when(RuleCondition::Constant("a".to_owned()))
+    .when(RuleCondition::NonZero("a".to_owned()))
+}
+
+fn ln_abs(arg: Expression) -> Expression {
+    Expression::function("ln", vec![Expression::function("abs", vec![arg])])
+}
+
+fn x() -> Pattern {
+    w(super::rule::VAR)
+}
+
+fn w(name: &str) -> Pattern {
+    Pattern::wildcard(name)
+}
+
+fn exact(n: i64) -> Pattern {
+    Pattern::Exact(Expression::integer(n))
+}
+
+fn pow(base: Pattern, exp: Pattern) -> Pattern {
+    Pattern::Pow(Box::new(base), Box::new(exp))
+}
+
+fn func(name: &str, arg: Pattern) -> Pattern {
+    Pattern::Function {
+        name: name.to_owned(),
+        args: vec![arg],
+    }
+}
@@ -0,0 +1,141 @@
+//! Integral table registry
+//!
+//! Holds the built-in rules plus any rules registered at runtime. Custom rules are
+//! consulted before built-in ones, so a downstream crate can both add coverage and
+//! override a built-in formula.
+
+use super::builtin::builtin_rules;
+use super::rule::IntegralRule;
+use crate::core::{Expression, Symbol};
+use once_cell::sync::Lazy;
+use std::sync::RwLock;
+
+/// Global integral table used by `try_table_lookup`
+///
+/// Lazy initialization loads the built-in rules on first use.
+pub static INTEGRAL_TABLE: Lazy<RwLock<IntegralTable>> =
+    Lazy::new(|| RwLock::new(IntegralTable::new()));
+
+/// Ordered collection of integral rules
+#[derive(Debug, Clone)]
+pub struct IntegralTable {
+    custom: Vec<IntegralRule>,
+    builtin: Vec<IntegralRule>,
+}
+
+impl Default for IntegralTable {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IntegralTable {
+    /// Create a table with the built-in rules
+    pub fn new() -> Self {
+        Self {
+            custom: Vec::new(),
+            builtin: builtin_rules(),
+        }
+    }
+
+    /// Create a table without any rules
+    pub fn empty() -> Self {
+        Self {
+            custom: Vec::new(),
+            builtin: Vec::new(),
+        }
+    }
+
+    /// Register a custom rule
+    ///
+    /// A rule with the same name as an existing custom rule replaces it. Custom rules
+    /// are tried in registration order, before all built-in rules.
+    pub fn register(&mut self, rule: IntegralRule) {
+        if let Some(existing) = self.custom.iter_mut().find(|r| r.name() == rule.name()) {
+            *existing = rule;
+        } else {
+            self.custom.push(rule);
+        }
+    }
+
+    /// Remove a custom rule by name, returning it if present
+    pub fn unregister(&mut self, name: &str) -> Option<IntegralRule> {
+        let index = self.custom.iter().position(|r| r.name() == name)?;
+        Some(self.custom.remove(index))
+    }
+
+    /// Integrate using the first applicable rule
+    pub fn lookup(&self, expr: &Expression, var: &Symbol) -> Option<Expression> {
+        self.lookup_with_rule(expr, var).map(|(_, result)| result)
+    }
+
+    /// Integrate using the first applicable rule, reporting which rule was used
+    pub fn lookup_with_rule(
+        &self,
+        expr: &Expression,
+        var: &Symbol,
+    ) -> Option<(&IntegralRule, Expression)> {
+        self.rules()
+            .find_map(|rule| rule.apply(expr, var).map(|result| (rule, result)))
+    }
+
+    /// All rules in lookup order
+    pub fn rules(&self) -> impl Iterator<Item = &IntegralRule> {
+        self.custom.iter().chain(self.builtin.iter())
+    }
+
+    /// Number of rules in the table
+    pub fn len(&self) -> usize {
+        self.custom.len() + self.builtin.len()
+    }
+
+    /// Check if the table has no rules
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Register a custom rule in the global integral table
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::calculus::integrals::table::{
+///     register_integral_rule, try_table_lookup, unregister_integral_rule, IntegralRule,
+/// };
+/// use mathhook_core::pattern::Pattern;
+/// use mathhook_core::{expr, symbol, Expression};
+///
+/// // ∫ sech(x)² dx = tanh(x)
+/// register_integral_rule(IntegralRule::new(
+///     "sech_squared",
+///     Pattern::Pow(
+///         Box::new(Pattern::Function {
+///             name: "sech".to_owned(),
+///             args: vec![Pattern::wildcard("x")],
+///         }),
+///         Box::new(Pattern::Exact(Expression::integer(2))),
+///     ),
+///     expr!(tanh(x)),
+/// ));
+///
+/// let x = symbol!(x);
+/// let integrand = expr!(sech(x) ^ 2);
+/// assert_eq!(try_table_lookup(&integrand, &x), Some(expr!(tanh(x))));
+///
+/// unregister_integral_rule("sech_squared");
+/// ```
+pub fn register_integral_rule(rule: IntegralRule) {
+    INTEGRAL_TABLE
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .register(rule);
+}
+
+/// Remove a custom rule from the global integral table
+pub fn unregister_integral_rule(name: &str) -> Option<IntegralRule> {
+    INTEGRAL_TABLE
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .unregister(name)
+}
@@ -0,0 +1,264 @@
+//! Declarative integral rules
+//!
+//! An [`IntegralRule`] is data: a pattern for the integrand, a template for the
+//! antiderivative and the conditions under which the formula holds.
+//!
+//! # Conventions
+//!
+//! - The wildcard named [`VAR`] (`"x"`) stands for the integration variable and only
+//!   matches that variable
+//! - Every other wildcard binds a subexpression of the integrand
+//! - Symbols in the antiderivative template whose names match a wildcard are replaced
+//!   by the bound expression; the result is rebuilt in canonical form
+
+use crate::core::{Expression, Symbol};
+use crate::pattern::matching::WildcardConstraints;
+use crate::pattern::{Matchable, Pattern, PatternMatches};
+use crate::simplify::Simplify;
+
+/// Wildcard name reserved for the integration variable
+pub const VAR: &str = "x";
+
+/// Side condition on a wildcard binding
+#[derive(Debug, Clone)]
+pub enum RuleCondition {
+    /// Binding does not depend on the integration variable
+    Constant(String),
+    /// Binding is a numeric literal
+    Numeric(String),
+    /// Binding is not the literal zero
+    NonZero(String),
+    /// Binding is a positive numeric literal
+    Positive(String),
+    /// Binding differs from the given expression
+    NotEqual(String, Expression),
+    /// Binding satisfies a custom predicate
+    Predicate(String, fn(&Expression) -> bool),
+}
+
+impl RuleCondition {
+    /// Check the condition against the bindings of a successful match
+    pub fn holds(&self, bindings: &PatternMatches, var: &Symbol) -> bool {
+        match self {
+            RuleCondition::Constant(name) => {
+                bindings.get(name).is_some_and(|e| !contains_symbol(e, var))
+            }
+            RuleCondition::Numeric(name) => {
+                matches!(bindings.get(name), Some(Expression::Number(_)))
+            }
+            RuleCondition::NonZero(name) => bindings
+                .get(name)
+                .is_some_and(|e| !matches!(e, Expression::Number(n) if n.is_zero())),
+            RuleCondition::Positive(name) => match bindings.get(name) {
+                Some(Expression::Number(n)) => n.to_float().is_ok_and(|v| v > 0.0),
+                _ => false,
+            },
+            RuleCondition::NotEqual(name, value) => bindings.get(name).is_some_and(|e| e != value),
+            RuleCondition::Predicate(name, predicate) => bindings.get(name).is_some_and(predicate),
+        }
+    }
+
+    fn wildcard(&self) -> &str {
+        match self {
+            RuleCondition::Constant(name)
+            | RuleCondition::Numeric(name)
+            | RuleCondition::NonZero(name)
+            | RuleCondition::Positive(name)
+            | RuleCondition::NotEqual(name, _)
+            | RuleCondition::Predicate(name, _) => name,
+        }
+    }
+}
+
+/// A single table entry: ∫ pattern dx = antiderivative, provided all conditions hold
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::calculus::integrals::table::{IntegralRule, RuleCondition};
+/// use mathhook_core::pattern::Pattern;
+/// use mathhook_core::{expr, symbol, Expression};
+///
+/// // ∫ sech(x)^2 dx = tanh(x)
+/// let rule = IntegralRule::new(
+///     "sech_squared",
+///     Pattern::Pow(
+///         Box::new(Pattern::Function {
+///             name: "sech".to_owned(),
+///             args: vec![Pattern::wildcard("x")],
+///         }),
+///         Box::new(Pattern::Exact(Expression::integer(2))),
+///     ),
+///     expr!(tanh(x)),
+/// );
+///
+/// let t = symbol!(t);
+/// let integrand = Expression::pow(
+///     Expression::function("sech", vec![Expression::symbol(t.clone())]),
+///     Expression::integer(2),
+/// );
+/// assert_eq!(
+///     rule.apply(&integrand, &t),
+///     Some(Expression::function("tanh", vec![Expression::symbol(t)]))
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct IntegralRule {
+    name: String,
+    pattern: Pattern,
+    antiderivative: Expression,
+    conditions: Vec<RuleCondition>,
+}
+
+impl IntegralRule {
+    /// Create a rule without conditions
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Identifier used for reporting and for replacing rules
+    /// * `pattern` - Integrand pattern; the wildcard `"x"` is the integration variable
+    /// * `antiderivative` - Template whose symbols named after wildcards are substituted
+    pub fn new(name: impl Into<String>, pattern: Pattern, antiderivative: Expression) -> Self {
+        Self {
+            name: name.into(),
+            pattern,
+            antiderivative,
+            conditions: Vec::new(),
+        }
+    }
+
+    /// Add a side condition
+    pub fn when(mut self, condition: RuleCondition) -> Self {
+        self.conditions.push(condition);
+        self
+    }
+
+    /// Rule identifier
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Integrand pattern
+    pub fn pattern(&self) -> &Pattern {
+        &self.pattern
+    }
+
+    /// Antiderivative template
+    pub fn antiderivative(&self) -> &Expression {
+        &self.antiderivative
+    }
+
+    /// Side conditions
+    pub fn conditions(&self) -> &[RuleCondition] {
+        &self.conditions
+    }
+
+    /// Integrate `expr` with respect to `var` if the rule applies
+    ///
+    /// # Returns
+    ///
+    /// The instantiated antiderivative, or `None` if the pattern does not match or a
+    /// condition fails.
+    pub fn apply(&self, expr: &Expression, var: &Symbol) -> Option<Expression> {
+        let pattern = self.bind_variable(&self.pattern, var);
+        let mut bindings = expr.matches(&pattern)?;
+
+        if !self.conditions.iter().all(|c| c.holds(&bindings, var)) {
+            return None;
+        }
+
+        bindings.insert(VAR.to_owned(), Expression::symbol(var.clone()));
+        Some(instantiate(&self.antiderivative, &bindings))
+    }
+
+    /// Specialize the stored pattern to a concrete integration variable
+    ///
+    /// The variable wildcard becomes an exact match, and wildcards required to be
+    /// constant exclude the variable so commutative matching never binds it there.
+    fn bind_variable(&self, pattern: &Pattern, var: &Symbol) -> Pattern {
+        match pattern {
+            Pattern::Wildcard { name, .. } if name == VAR => {
+                Pattern::Exact(Expression::symbol(var.clone()))
+            }
+            Pattern::Wildcard { name, constraints } => {
+                let is_constant = self
+                    .conditions
+                    .iter()
+                    .any(|c| matches!(c, RuleCondition::Constant(_)) && c.wildcard() == name);
+                if !is_constant {
+                    return pattern.clone();
+                }
+                let mut constraints = constraints.clone().unwrap_or(WildcardConstraints {
+                    exclude: Vec::new(),
+                    properties: Vec::new(),
+                });
+                constraints.exclude.push(Expression::symbol(var.clone()));
+                Pattern::Wildcard {
+                    name: name.clone(),
+                    constraints: Some(constraints),
+                }
+            }
+            Pattern::Exact(_) => pattern.clone(),
+            Pattern::Add(terms) => {
+                Pattern::Add(terms.iter().map(|p| self.bind_variable(p, var)).collect())
+            }
+            Pattern::Mul(factors) => {
+                Pattern::Mul(factors.iter().map(|p| self.bind_variable(p, var)).collect())
+            }
+            Pattern::Pow(base, exp) => Pattern::Pow(
+                Box::new(self.bind_variable(base, var)),
+                Box::new(self.bind_variable(exp, var)),
+            ),
+            Pattern::Function { name, args } => Pattern::Function {
+                name: name.clone(),
+                args: args.iter().map(|p| self.bind_variable(p, var)).collect(),
+            },
+        }
+    }
+}
+
+/// Substitute bindings into a template, rebuilding through the canonical constructors
+///
+/// Functions applied to numeric literals are simplified so that, e.g., `sqrt(4)`
+/// in an instantiated formula becomes `2`.
+fn instantiate(template: &Expression, bindings: &PatternMatches) -> Expression {
+    match template {
+        Expression::Symbol(s) => bindings
+            .get(s.name())
+            .cloned()
+            .unwrap_or_else(|| template.clone()),
+        Expression::Add(terms) => {
+            Expression::add(terms.iter().map(|t| instantiate(t, bindings)).collect())
+        }
+        Expression::Mul(factors) => {
+            Expression::mul(factors.iter().map(|f| instantiate(f, bindings)).collect())
+        }
+        Expression::Pow(base, exp) => {
+            Expression::pow(instantiate(base, bindings), instantiate(exp, bindings))
+        }
+        Expression::Function { name, args, .. } => {
+            let args: Vec<Expression> = args.iter().map(|a| instantiate(a, bindings)).collect();
+            let numeric = args.iter().all(|a| matches!(a, Expression::Number(_)));
+            let function = Expression::function(name.as_ref(), args);
+            if numeric {
+                function.simplify()
+            } else {
+                function
+            }
+        }
+        _ => template.clone(),
+    }
+}
+
+fn contains_symbol(expr: &Expression, var: &Symbol) -> bool {
+    match expr {
+        Expression::Symbol(s) => s == var,
+        Expression::Number(_) | Expression::Constant(_) => false,
+        Expression::Add(terms) | Expression::Mul(terms) => {
+            terms.iter().any(|t| contains_symbol(t, var))
+        }
+        Expression::Pow(base, exp) => contains_symbol(base, var) || contains_symbol(exp, var),
+        Expression::Function { args, .. } => args.iter().any(|a| contains_symbol(a, var)),
+        _ => true,
+    }
+}
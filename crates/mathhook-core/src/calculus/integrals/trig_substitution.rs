@@ -0,0 +1,611 @@
+//! Trigonometric and hyperbolic substitution
+//!
+//! Integrates c·xᵐ·Qᵏᐟ² for odd k, where Q is one of the three quadratic forms
+//! below, by eliminating the square root:
+//!
+//! | Q         | Substitution  | √Q          | Back-substitution              |
+//! |-----------|---------------|-------------|--------------------------------|
+//! | a² - x²   | x = a·sin(θ)  | a·cos(θ)    | θ = arcsin(x/a)                |
+//! | x² + a²   | x = a·sinh(t) | a·cosh(t)   | t = ln(x + √(x² + a²)) + const |
+//! | x² - a²   | x = a·cosh(t) | a·sinh(t)   | t = ln\|x + √(x² - a²)\| + const |
+//!
+//! The substituted integral is a product of powers of the two functions, which is
+//! integrated by reduction formulas written directly in terms of x/a and √Q/a, so
+//! the result never contains the auxiliary angle.
+
+use crate::core::{Expression, Number, Symbol};
+use crate::educational::step_by_step::{Step, StepByStepExplanation};
+use crate::simplify::Simplify;
+use num_traits::ToPrimitive;
+
+/// Quadratic under the square root
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RadicalForm {
+    /// √(a² - x²), substitute x = a·sin(θ)
+    AMinusX2,
+    /// √(x² + a²), substitute x = a·sinh(t)
+    XPlusA2,
+    /// √(x² - a²), substitute x = a·cosh(t)
+    XMinusA2,
+}
+
+impl Radical {
+    /// aⁿ, exact when a² is a perfect square
+    fn a_pow(&self, n: i64) -> Expression {
+        match exact_sqrt(&self.a_squared) {
+            Some(a) => Expression::pow(a, Expression::integer(n)),
+            None if n % 2 == 0 => {
+                Expression::pow(self.a_squared.clone(), Expression::integer(n / 2)).simplify()
+            }
+            None => Expression::pow(self.a_squared.clone(), Expression::rational(n, 2)),
+        }
+    }
+}
+
+impl RadicalForm {
+    /// The substitution that removes the radical
+    pub fn substitution(&self) -> &'static str {
+        match self {
+            RadicalForm::AMinusX2 => "x = a·sin(θ)",
+            RadicalForm::XPlusA2 => "x = a·sinh(t)",
+            RadicalForm::XMinusA2 => "x = a·cosh(t)",
+        }
+    }
+
+    fn functions(&self) -> (&'static str, &'static str) {
+        match self {
+            RadicalForm::AMinusX2 => ("sin", "cos"),
+            RadicalForm::XPlusA2 => ("sinh", "cosh"),
+            RadicalForm::XMinusA2 => ("cosh", "sinh"),
+        }
+    }
+
+    fn angle(&self) -> &'static str {
+        match self {
+            RadicalForm::AMinusX2 => "θ",
+            _ => "t",
+        }
+    }
+
+    fn template(&self) -> &'static str {
+        match self {
+            RadicalForm::AMinusX2 => "sqrt(a^2 - x^2)",
+            RadicalForm::XPlusA2 => "sqrt(x^2 + a^2)",
+            RadicalForm::XMinusA2 => "sqrt(x^2 - a^2)",
+        }
+    }
+}
+
+/// Integrand recognized as coefficient·xᵐ·Qᵏᐟ²
+struct Radical {
+    form: RadicalForm,
+    coefficient: Expression,
+    quadratic: Expression,
+    /// Positive constant a² with Q = ±x² ± a²
+    a_squared: Expression,
+    m: i64,
+    k: i64,
+}
+
+/// Integrate by trigonometric or hyperbolic substitution
+///
+/// # Arguments
+///
+/// * `expr` - Integrand of the form c·xᵐ·Qᵏᐟ² with k odd
+/// * `var` - Variable of integration
+///
+/// # Returns
+///
+/// The antiderivative in terms of `var`, or `None` if the integrand does not have
+/// a supported form.
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::calculus::integrals::trig_substitution::try_trig_substitution;
+/// use mathhook_core::{expr, symbol};
+///
+/// let x = symbol!(x);
+/// // ∫√(4 - x²) dx = x·√(4 - x²)/2 + 2·arcsin(x/2)
+/// let result = try_trig_substitution(&expr!(sqrt(4 - (x ^ 2))), &x);
+/// assert!(result.is_some());
+/// ```
+pub fn try_trig_substitution(expr: &Expression, var: &Symbol) -> Option<Expression> {
+    substitute(expr, var).map(|(result, _)| result)
+}
+
+/// Step-by-step explanation of a trigonometric or hyperbolic substitution
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::calculus::integrals::trig_substitution::explain_trig_substitution;
+/// use mathhook_core::{expr, symbol};
+///
+/// let x = symbol!(x);
+/// let explanation = explain_trig_substitution(&expr!(sqrt((x ^ 2) + 9)), &x).unwrap();
+/// assert_eq!(explanation.total_steps, explanation.steps.len());
+/// assert!(explanation.steps[1].description.contains("sinh"));
+/// ```
+pub fn explain_trig_substitution(expr: &Expression, var: &Symbol) -> Option<StepByStepExplanation> {
+    let (result, steps) = substitute(expr, var)?;
+    Some(StepByStepExplanation {
+        initial_expression: expr.clone(),
+        final_expression: result,
+        total_steps: steps.len(),
+        steps,
+        rules_used: vec!["Trigonometric Substitution".to_owned()],
+    })
+}
+
+/// Recognize the form of the radical used by the integrand
+pub fn detect_radical_form(expr: &Expression, var: &Symbol) -> Option<RadicalForm> {
+    analyze(expr, var).map(|radical| radical.form)
+}
+
+fn substitute(expr: &Expression, var: &Symbol) -> Option<(Expression, Vec<Step>)> {
+    let radical = analyze(expr, var)?;
+    let (i, j) = (radical.m, radical.k + 1);
+    let form = radical.form;
+    let x = Expression::symbol(var.clone());
+    let a = radical.a_pow(1);
+    let sqrt_q = Expression::function("sqrt", vec![radical.quadratic.clone()]);
+    let a_inv = radical.a_pow(-1);
+
+    // Values of the two functions and of the angle in terms of x
+    let p = Expression::mul(vec![x.clone(), a_inv.clone()]);
+    let r = Expression::mul(vec![sqrt_q.clone(), a_inv.clone()]);
+    let angle = match form {
+        RadicalForm::AMinusX2 => Expression::function("arcsin", vec![p.clone()]),
+        RadicalForm::XPlusA2 => {
+            Expression::function("ln", vec![Expression::add(vec![x.clone(), sqrt_q.clone()])])
+        }
+        RadicalForm::XMinusA2 => Expression::function(
+            "ln",
+            vec![Expression::function(
+                "abs",
+                vec![Expression::add(vec![x.clone(), sqrt_q.clone()])],
+            )],
+        ),
+    };
+
+    let scale = Expression::mul(vec![
+        radical.coefficient.clone(),
+        radical.a_pow(radical.m + radical.k + 1),
+    ]);
+    let result =
+        fold_numeric_powers(&distribute(&scale, reduce(form, i, j, &p, &r, &angle)?).simplify());
+
+    let (f, g) = form.functions();
+    let t = Expression::symbol(Symbol::scalar(form.angle()));
+    let f_t = Expression::function(f, vec![t.clone()]);
+    let g_t = Expression::function(g, vec![t.clone()]);
+    let integrand_in_t = Expression::mul(vec![
+        scale.clone(),
+        Expression::pow(f_t.clone(), Expression::integer(i)),
+        Expression::pow(g_t.clone(), Expression::integer(j)),
+    ]);
+    let identity = match form {
+        RadicalForm::AMinusX2 => "1 - sin(θ)^2 = cos(θ)^2",
+        RadicalForm::XPlusA2 => "sinh(t)^2 + 1 = cosh(t)^2",
+        RadicalForm::XMinusA2 => "cosh(t)^2 - 1 = sinh(t)^2",
+    };
+    let angle_name = form.angle();
+
+    let steps = vec![
+        Step::new(
+            "Identify Radical Form",
+            format!(
+                "The integrand contains {}, of the form {} with a = {}",
+                sqrt_q,
+                form.template(),
+                a
+            ),
+        ),
+        Step::new(
+            "Choose Substitution",
+            format!(
+                "{} = {}·{}({}), d{} = {}·{}({}) d{}",
+                var.name(),
+                a,
+                f,
+                angle_name,
+                var.name(),
+                a,
+                g,
+                angle_name,
+                angle_name
+            ),
+        ),
+        Step::new(
+            "Simplify Radical",
+            format!(
+                "{} = {}·{}({}) since {}",
+                sqrt_q, a, g, angle_name, identity
+            ),
+        ),
+        Step::new(
+            "Rewrite Integral",
+            format!("integral({} d{})", integrand_in_t, angle_name),
+        ),
+        Step::new(
+            "Integrate",
+            format!(
+                "Apply reduction formulas to {}({})^{}·{}({})^{}",
+                f, angle_name, i, g, angle_name, j
+            ),
+        ),
+        Step::new(
+            "Back-Substitute",
+            format!(
+                "{}({}) = {}, {}({}) = {}, {} = {}",
+                f, angle_name, p, g, angle_name, r, angle_name, angle
+            ),
+        ),
+        Step::new("Result", format!("{} + C", result)),
+    ];
+
+    Some((result, steps))
+}
+
+/// ∫fⁱ·gʲ d(angle) with f, g the substitution functions, expressed through their
+/// back-substituted values `p` = f and `r` = g
+fn reduce(
+    form: RadicalForm,
+    i: i64,
+    j: i64,
+    p: &Expression,
+    r: &Expression,
+    angle: &Expression,
+) -> Option<Expression> {
+    let power = |base: &Expression, k: i64| Expression::pow(base.clone(), Expression::integer(k));
+    let ratio = |num: &Expression, den: &Expression, sign: i64| {
+        Expression::mul(vec![Expression::integer(sign), num.clone(), power(den, -1)])
+    };
+
+    // x = a·cosh(t) swaps the roles of sinh and cosh
+    let (s, c, si, ci) = match form {
+        RadicalForm::XMinusA2 => (r, p, j, i),
+        _ => (p, r, i, j),
+    };
+    let hyperbolic = form != RadicalForm::AMinusX2;
+
+    match (si, ci) {
+        (0, 0) => Some(angle.clone()),
+        // ∫sin = -cos, ∫sinh = cosh
+        (1, 0) => Some(if hyperbolic {
+            c.clone()
+        } else {
+            Expression::mul(vec![Expression::integer(-1), c.clone()])
+        }),
+        (0, 1) => Some(s.clone()),
+        (1, 1) => Some(Expression::mul(vec![
+            Expression::rational(1, 2),
+            power(s, 2),
+        ])),
+        // ∫sec² = tan, ∫sech² = tanh
+        (0, -2) => Some(ratio(s, c, 1)),
+        // ∫csc² = -cot, ∫csch² = -coth
+        (-2, 0) => Some(ratio(c, s, -1)),
+        (si, ci) if si >= 2 && ci >= 0 => {
+            let sign = if hyperbolic { 1 } else { -1 };
+            let rest = reduce_swapped(form, si - 2, ci, p, r, angle)?;
+            Some(Expression::add(vec![
+                Expression::mul(vec![
+                    Expression::rational(sign, si + ci),
+                    power(s, si - 1),
+                    power(c, ci + 1),
+                ]),
+                Expression::mul(vec![Expression::rational(-sign * (si - 1), si + ci), rest]),
+            ]))
+        }
+        (si, ci) if ci >= 2 && si >= 0 => {
+            let rest = reduce_swapped(form, si, ci - 2, p, r, angle)?;
+            Some(Expression::add(vec![
+                Expression::mul(vec![
+                    Expression::rational(1, si + ci),
+                    power(s, si + 1),
+                    power(c, ci - 1),
+                ]),
+                Expression::mul(vec![Expression::rational(ci - 1, si + ci), rest]),
+            ]))
+        }
+        _ => None,
+    }
+}
+
+/// Multiply each term of a sum by `scale`, so numeric factors combine per term
+fn distribute(scale: &Expression, expr: Expression) -> Expression {
+    match expr {
+        Expression::Add(terms) => Expression::add(
+            terms
+                .iter()
+                .map(|term| distribute(scale, term.clone()))
+                .collect(),
+        ),
+        Expression::Mul(factors) => {
+            let mut all = vec![scale.clone()];
+            all.extend(factors.iter().cloned());
+            Expression::mul(all)
+        }
+        other => Expression::mul(vec![scale.clone(), other]),
+    }
+}
+
+/// Evaluate integer powers of integers left over from combining powers of √(a²)
+fn fold_numeric_powers(expr: &Expression) -> Expression {
+    match expr {
+        Expression::Pow(base, exp) => {
+            let exponent = match exp.as_ref() {
+                Expression::Number(Number::Integer(e)) => Some(*e),
+                Expression::Number(Number::Rational(ratio)) if ratio.is_integer() => {
+                    ratio.numer().to_i64()
+                }
+                _ => None,
+            };
+            match (base.as_ref(), exponent) {
+                (Expression::Number(Number::Integer(b)), Some(e))
+                    if *b != 0 && e.unsigned_abs() <= 16 =>
+                {
+                    match b.checked_pow(e.unsigned_abs() as u32) {
+                        Some(v) if e >= 0 => Expression::integer(v),
+                        Some(v) => Expression::rational(1, v),
+                        None => expr.clone(),
+                    }
+                }
+                _ => Expression::pow(fold_numeric_powers(base), fold_numeric_powers(exp)),
+            }
+        }
+        Expression::Add(terms) => Expression::add(terms.iter().map(fold_numeric_powers).collect()),
+        Expression::Mul(factors) => {
+            Expression::mul(factors.iter().map(fold_numeric_powers).collect())
+        }
+        Expression::Function { name, args, .. } => Expression::function(
+            name.as_ref(),
+            args.iter().map(fold_numeric_powers).collect(),
+        ),
+        _ => expr.clone(),
+    }
+}
+
+/// √n for a perfect square integer or rational n
+fn exact_sqrt(n: &Expression) -> Option<Expression> {
+    let root = |v: i64| {
+        let r = (v as f64).sqrt().round() as i64;
+        (r * r == v).then_some(r)
+    };
+    match n {
+        Expression::Number(Number::Integer(v)) => root(*v).map(Expression::integer),
+        Expression::Number(Number::Rational(ratio)) => {
+            let numer = root(ratio.numer().to_i64()?)?;
+            let denom = root(ratio.denom().to_i64()?)?;
+            Some(Expression::rational(numer, denom))
+        }
+        _ => None,
+    }
+}
+
+/// Recurse with exponents given as (sine-like, cosine-like) powers
+fn reduce_swapped(
+    form: RadicalForm,
+    si: i64,
+    ci: i64,
+    p: &Expression,
+    r: &Expression,
+    angle: &Expression,
+) -> Option<Expression> {
+    match form {
+        RadicalForm::XMinusA2 => reduce(form, ci, si, p, r, angle),
+        _ => reduce(form, si, ci, p, r, angle),
+    }
+}
+
+/// Split the integrand into coefficient, power of x and a single radical
+fn analyze(expr: &Expression, var: &Symbol) -> Option<Radical> {
+    let factors: &[Expression] = match expr {
+        Expression::Mul(factors) => factors,
+        _ => std::slice::from_ref(expr),
+    };
+
+    let mut coefficient = Vec::new();
+    let mut m = 0;
+    let mut radical: Option<(Expression, i64)> = None;
+    for factor in factors {
+        if !factor.contains_variable(var) {
+            coefficient.push(factor.clone());
+            continue;
+        }
+        match factor {
+            Expression::Symbol(s) if s == var => m += 1,
+            Expression::Pow(base, exp) if base.is_simple_variable(var) => match exp.as_ref() {
+                Expression::Number(Number::Integer(n)) => m += n,
+                _ => return None,
+            },
+            _ => {
+                if radical.is_some() {
+                    return None;
+                }
+                radical = Some(radical_power(factor)?);
+            }
+        }
+    }
+
+    let (quadratic, k) = radical?;
+    let (form, a_squared) = quadratic_form(&quadratic, var)?;
+    Some(Radical {
+        form,
+        coefficient: Expression::mul(coefficient),
+        quadratic,
+        a_squared,
+        m,
+        k,
+    })
+}
+
+/// Recognize √Q, (√Q)ʲ and Q^(k/2), returning (Q, k) with k odd
+fn radical_power(factor: &Expression) -> Option<(Expression, i64)> {
+    let sqrt_arg = |e: &Expression| match e {
+        Expression::Function { name, args, .. } if name.as_ref() == "sqrt" && args.len() == 1 => {
+            Some(args[0].clone())
+        }
+        _ => None,
+    };
+
+    if let Some(q) = sqrt_arg(factor) {
+        return Some((q, 1));
+    }
+    let Expression::Pow(base, exp) = factor else {
+        return None;
+    };
+    match exp.as_ref() {
+        Expression::Number(Number::Integer(k)) if k % 2 != 0 => sqrt_arg(base).map(|q| (q, *k)),
+        Expression::Number(Number::Rational(ratio)) if *ratio.denom() == 2.into() => {
+            let k = ratio.numer().to_string().parse::<i64>().ok()?;
+            Some((base.as_ref().clone(), k))
+        }
+        _ => None,
+    }
+}
+
+/// Classify Q = ±x² + q, returning the form and a² = |q|
+fn quadratic_form(quadratic: &Expression, var: &Symbol) -> Option<(RadicalForm, Expression)> {
+    let Expression::Add(terms) = quadratic else {
+        return None;
+    };
+    if terms.len() != 2 {
+        return None;
+    }
+
+    let is_square = |e: &Expression| {
+        matches!(e, Expression::Pow(base, exp)
+            if base.is_simple_variable(var)
+                && matches!(exp.as_ref(), Expression::Number(Number::Integer(2))))
+    };
+    let square_sign = |e: &Expression| -> Option<i64> {
+        if is_square(e) {
+            return Some(1);
+        }
+        match e {
+            Expression::Mul(factors)
+                if factors.len() == 2
+                    && factors[0] == Expression::integer(-1)
+                    && is_square(&factors[1]) =>
+            {
+                Some(-1)
+            }
+            _ => None,
+        }
+    };
+
+    let (sign, constant) = match (square_sign(&terms[0]), square_sign(&terms[1])) {
+        (Some(sign), None) => (sign, &terms[1]),
+        (None, Some(sign)) => (sign, &terms[0]),
+        _ => return None,
+    };
+    let Expression::Number(q) = constant else {
+        return None;
+    };
+    let q_value = q.to_float().ok()?;
+    let a_squared = Expression::mul(vec![
+        Expression::integer(q_value.signum() as i64),
+        constant.clone(),
+    ]);
+
+    match (sign, q_value > 0.0) {
+        (-1, true) => Some((RadicalForm::AMinusX2, a_squared)),
+        (1, true) => Some((RadicalForm::XPlusA2, a_squared)),
+        (1, false) => Some((RadicalForm::XMinusA2, a_squared)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algebra::solvers::Assumptions;
+    use crate::calculus::derivatives::Derivative;
+    use crate::simplify::verification::find_mismatch;
+    use crate::{expr, symbol};
+
+    fn check(integrand: Expression, form: RadicalForm) {
+        let x = symbol!(x);
+        assert_eq!(detect_radical_form(&integrand, &x), Some(form));
+        let result = try_trig_substitution(&integrand, &x)
+            .unwrap_or_else(|| panic!("no substitution for {}", integrand));
+        let derivative = result.derivative(x);
+        assert!(
+            find_mismatch(&integrand, &derivative, &Assumptions::new()).is_none(),
+            "d/dx {} != {}",
+            result,
+            integrand
+        );
+    }
+
+    #[test]
+    fn test_a_minus_x_squared() {
+        check(expr!(sqrt(4 - (x ^ 2))), RadicalForm::AMinusX2);
+        check(expr!((x ^ 2) * sqrt(9 - (x ^ 2))), RadicalForm::AMinusX2);
+        check(
+            expr!((x ^ 2) * (sqrt(1 - (x ^ 2)) ^ (-1))),
+            RadicalForm::AMinusX2,
+        );
+        check(expr!(sqrt(4 - (x ^ 2)) ^ (-3)), RadicalForm::AMinusX2);
+    }
+
+    #[test]
+    fn test_x_plus_a_squared() {
+        check(expr!(sqrt((x ^ 2) + 9)), RadicalForm::XPlusA2);
+        check(expr!(sqrt((x ^ 2) + 1) ^ (-1)), RadicalForm::XPlusA2);
+        check(
+            expr!((x ^ (-2)) * (sqrt((x ^ 2) + 4) ^ (-1))),
+            RadicalForm::XPlusA2,
+        );
+        check(expr!(sqrt((x ^ 2) + 2) ^ 3), RadicalForm::XPlusA2);
+    }
+
+    #[test]
+    fn test_x_minus_a_squared() {
+        check(expr!(sqrt((x ^ 2) - 4)), RadicalForm::XMinusA2);
+        check(expr!((x ^ 2) * sqrt((x ^ 2) - 1)), RadicalForm::XMinusA2);
+        check(expr!(sqrt((x ^ 2) - 9) ^ (-1)), RadicalForm::XMinusA2);
+    }
+
+    #[test]
+    fn test_irrational_a_stays_exact() {
+        fn has_float(expr: &Expression) -> bool {
+            match expr {
+                Expression::Number(Number::Float(_)) => true,
+                Expression::Add(terms) | Expression::Mul(terms) => terms.iter().any(has_float),
+                Expression::Pow(base, exp) => has_float(base) || has_float(exp),
+                Expression::Function { args, .. } => args.iter().any(has_float),
+                _ => false,
+            }
+        }
+
+        check(expr!(sqrt(2 - (x ^ 2))), RadicalForm::AMinusX2);
+        let x = symbol!(x);
+        let result = try_trig_substitution(&expr!(sqrt(2 - (x ^ 2))), &x).unwrap();
+        assert!(!has_float(&result), "{}", result);
+    }
+
+    #[test]
+    fn test_unsupported_forms() {
+        let x = symbol!(x);
+        assert!(try_trig_substitution(&expr!(sqrt(-(x ^ 2) - 4)), &x).is_none());
+        assert!(try_trig_substitution(&expr!(sqrt((x ^ 3) + 1)), &x).is_none());
+        assert!(try_trig_substitution(&expr!((x ^ 2) + 1), &x).is_none());
+    }
+
+    #[test]
+    fn test_explanation_steps() {
+        let x = symbol!(x);
+        let explanation = explain_trig_substitution(&expr!(sqrt(4 - (x ^ 2))), &x).unwrap();
+        assert_eq!(explanation.steps.len(), 7);
+        assert!(explanation.steps[1].description.contains("sin(θ)"));
+        assert!(explanation.steps[5].description.contains("arcsin"));
+        assert_eq!(
+            explanation.final_expression,
+            try_trig_substitution(&expr!(sqrt(4 - (x ^ 2))), &x).unwrap()
+        );
+    }
+}
@@ -61,7 +61,9 @@ pub fn detect_trig_pattern(expr: &Expression, var: &Symbol) -> Option<TrigPatter
     match expr {
         Expression::Pow(base, exp) => detect_power_pattern(base, exp, var),
         Expression::Mul(factors) => detect_product_pattern(factors, var),
-        Expression::Function { name, args } if args.len() == 1 && is_simple_var(&args[0], var) => {
+        Expression::Function { name, args, .. }
+            if args.len() == 1 && is_simple_var(&args[0], var) =>
+        {
             detect_single_function_pattern(name)
         }
         _ => None,
@@ -70,7 +72,7 @@ pub fn detect_trig_pattern(expr: &Expression, var: &Symbol) -> Option<TrigPatter
 
 /// Detect power patterns: func(x)^n
 fn detect_power_pattern(base: &Expression, exp: &Expression, var: &Symbol) -> Option<TrigPattern> {
-    if let (Expression::Function { name, args }, Expression::Number(Number::Integer(n))) =
+    if let (Expression::Function { name, args, .. }, Expression::Number(Number::Integer(n))) =
         (base, exp)
     {
         if args.len() == 1 && is_simple_var(&args[0], var) {
@@ -130,7 +132,7 @@ fn detect_product_same_var(factors: &[Expression], var: &Symbol) -> Option<TrigP
 
     for factor in factors.iter() {
         match factor {
-            Expression::Function { name, args }
+            Expression::Function { name, args, .. }
                 if args.len() == 1 && is_simple_var(&args[0], var) =>
             {
                 update_trig_powers(
@@ -148,7 +150,7 @@ fn detect_product_same_var(factors: &[Expression], var: &Symbol) -> Option<TrigP
             }
             Expression::Pow(base, exp) => {
                 if let (
-                    Expression::Function { name, args },
+                    Expression::Function { name, args, .. },
                     Expression::Number(Number::Integer(n)),
                 ) = (&**base, &**exp)
                 {
@@ -293,7 +295,7 @@ fn is_elementary_trig(name: &str) -> bool {
 /// Returns (function_name, coefficient) if pattern matches, None otherwise
 pub fn extract_trig_function_with_coeff(expr: &Expression, var: &Symbol) -> Option<(String, i64)> {
     match expr {
-        Expression::Function { name, args } if args.len() == 1 => {
+        Expression::Function { name, args, .. } if args.len() == 1 => {
             if is_simple_var(&args[0], var) {
                 return Some((name.to_string(), 1));
             }
@@ -0,0 +1,332 @@
+//! Weierstrass (tangent half-angle) substitution
+//!
+//! Integrates rational functions R(sin(u), cos(u)) with u linear in x by
+//! substituting t = tan(u/2):
+//!
+//! ```text
+//! sin(u) = 2t/(1 + t²)    cos(u) = (1 - t²)/(1 + t²)    du = 2/(1 + t²) dt
+//! ```
+//!
+//! tan, cot, sec and csc are rewritten the same way. The integrand becomes a
+//! rational function of t with rational coefficients, which is decomposed into
+//! partial fractions over the rationals:
+//!
+//! - Linear factors (t - r)ⁿ from rational roots of the denominator
+//! - Quadratic factors (t² + pt + q)ⁿ, integrated by completing the square and
+//!   the reduction formula for ∫dt/(s² + k)ⁿ
+//!
+//! Denominators that leave an irreducible factor of degree three or more are not
+//! supported. The antiderivative is valid on each interval where tan(u/2) is
+//! continuous.
+
+use crate::algebra::partial_fractions::{decompose, poly_expr, to_ratio, RationalFunction, Q};
+use crate::calculus::derivatives::Derivative;
+use crate::calculus::integrals::rational::exact::integrate_decomposition;
+use crate::calculus::integrals::rational::helpers::substitute_variable;
+use crate::core::polynomial::poly::RationalPoly;
+use crate::core::{Expression, Number, Symbol};
+use crate::educational::step_by_step::{Step, StepByStepExplanation};
+use crate::simplify::Simplify;
+use num_traits::{One, Zero};
+
+/// Integrate a rational function of sin and cos by t = tan(u/2)
+///
+/// # Arguments
+///
+/// * `expr` - Rational function of sin, cos, tan, cot, sec and csc of a single
+///   argument u that is linear in `var`
+/// * `var` - Variable of integration
+///
+/// # Returns
+///
+/// The antiderivative in terms of `var`, or `None` if the integrand is not a
+/// rational trigonometric function or its denominator cannot be factored.
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::calculus::integrals::weierstrass::try_weierstrass_substitution;
+/// use mathhook_core::{expr, symbol};
+///
+/// let x = symbol!(x);
+/// // ∫1/(2 + cos(x)) dx = (2/√3)·arctan(tan(x/2)/√3)
+/// let result = try_weierstrass_substitution(&expr!((2 + cos(x)) ^ (-1)), &x);
+/// assert!(result.is_some());
+/// ```
+pub fn try_weierstrass_substitution(expr: &Expression, var: &Symbol) -> Option<Expression> {
+    substitute(expr, var).map(|(result, _)| result)
+}
+
+/// Step-by-step explanation of the tangent half-angle substitution
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::calculus::integrals::weierstrass::explain_weierstrass_substitution;
+/// use mathhook_core::{expr, symbol};
+///
+/// let x = symbol!(x);
+/// let explanation = explain_weierstrass_substitution(&expr!((1 + sin(x)) ^ (-1)), &x).unwrap();
+/// assert_eq!(explanation.total_steps, explanation.steps.len());
+/// assert!(explanation.steps[1].description.contains("tan"));
+/// ```
+pub fn explain_weierstrass_substitution(
+    expr: &Expression,
+    var: &Symbol,
+) -> Option<StepByStepExplanation> {
+    let (result, steps) = substitute(expr, var)?;
+    Some(StepByStepExplanation {
+        initial_expression: expr.clone(),
+        final_expression: result,
+        total_steps: steps.len(),
+        steps,
+        rules_used: vec!["Weierstrass Substitution".to_owned()],
+    })
+}
+
+/// Check whether `expr` is a rational function of trig functions of one
+/// argument linear in `var`
+pub fn is_rational_trig(expr: &Expression, var: &Symbol) -> bool {
+    analyze(expr, var).is_some()
+}
+
+/// Integrand rewritten in t = tan(u/2), including the factor 2/(1 + t²)
+struct HalfAngleForm {
+    argument: Expression,
+    slope: Expression,
+    integrand: RationalFunction,
+    /// Polynomials known to divide products in the denominator
+    pieces: Vec<RationalPoly>,
+}
+
+fn analyze(expr: &Expression, var: &Symbol) -> Option<HalfAngleForm> {
+    let argument = trig_argument(expr, var)?;
+    let slope = argument.derivative(var.clone()).simplify();
+    if slope.is_zero() || slope.contains_variable(var) {
+        return None;
+    }
+    let mut pieces = vec![one_plus_t_squared()];
+    let rational = to_rational_function(expr, &argument, var, &mut pieces)?;
+    let differential = RationalFunction::new(
+        RationalPoly::constant(Q::from_integer(2)),
+        one_plus_t_squared(),
+    );
+    Some(HalfAngleForm {
+        argument,
+        slope,
+        integrand: rational.mul(&differential),
+        pieces,
+    })
+}
+
+fn substitute(expr: &Expression, var: &Symbol) -> Option<(Expression, Vec<Step>)> {
+    let form = analyze(expr, var)?;
+    let t = Symbol::scalar("t");
+    let t_expr = Expression::symbol(t.clone());
+    let (decomposition, antiderivative_t) =
+        integrate_rational_function(&form.integrand, &form.pieces, &t)?;
+
+    let scale = Expression::pow(form.slope.clone(), Expression::integer(-1)).simplify();
+    let half_angle = Expression::function(
+        "tan",
+        vec![Expression::mul(vec![Expression::rational(1, 2), form.argument.clone()]).simplify()],
+    );
+    let result = Expression::mul(vec![
+        scale.clone(),
+        substitute_variable(&antiderivative_t, &t, &half_angle),
+    ])
+    .simplify();
+
+    let integrand_t = Expression::mul(vec![
+        scale.clone(),
+        poly_expr(&form.integrand.num, &t_expr),
+        Expression::pow(
+            poly_expr(&form.integrand.den, &t_expr),
+            Expression::integer(-1),
+        ),
+    ]);
+    let u = &form.argument;
+    let steps = vec![
+        Step::new(
+            "Identify Rational Trigonometric Form",
+            format!("The integrand is a rational function of sin({}) and cos({})", u, u),
+        ),
+        Step::new(
+            "Choose Substitution",
+            format!(
+                "t = {}: sin({}) = 2t/(1 + t^2), cos({}) = (1 - t^2)/(1 + t^2), d({}) = 2/(1 + t^2) dt",
+                half_angle, u, u, u
+            ),
+        ),
+        Step::new("Rewrite Integral", format!("integral({} dt)", integrand_t)),
+        Step::new(
+            "Partial Fractions",
+            format!("{}", Expression::add(decomposition)),
+        ),
+        Step::new(
+            "Integrate",
+            format!("{}", Expression::mul(vec![scale, antiderivative_t]).simplify()),
+        ),
+        Step::new("Back-Substitute", format!("t = {}", half_angle)),
+        Step::new("Result", format!("{} + C", result)),
+    ];
+
+    Some((result, steps))
+}
+
+/// First argument of a trig function that depends on `var`
+fn trig_argument(expr: &Expression, var: &Symbol) -> Option<Expression> {
+    match expr {
+        Expression::Function { name, args, .. }
+            if args.len() == 1 && is_trig(name.as_ref()) && args[0].contains_variable(var) =>
+        {
+            Some(args[0].clone())
+        }
+        Expression::Add(terms) | Expression::Mul(terms) => {
+            terms.iter().find_map(|term| trig_argument(term, var))
+        }
+        Expression::Pow(base, _) => trig_argument(base, var),
+        _ => None,
+    }
+}
+
+fn is_trig(name: &str) -> bool {
+    matches!(name, "sin" | "cos" | "tan" | "cot" | "sec" | "csc")
+}
+
+/// Rewrite `expr` as a rational function of t = tan(argument/2)
+///
+/// Numerators of sums are recorded in `pieces`: they are the polynomials that
+/// can reappear as denominators, and splitting by them factors products that
+/// have no rational roots.
+fn to_rational_function(
+    expr: &Expression,
+    argument: &Expression,
+    var: &Symbol,
+    pieces: &mut Vec<RationalPoly>,
+) -> Option<RationalFunction> {
+    match expr {
+        Expression::Number(number) => Some(RationalFunction::constant(to_ratio(number)?)),
+        _ if !expr.contains_variable(var) => None,
+        Expression::Function { name, args, .. } if args.len() == 1 && &args[0] == argument => {
+            let two_t = RationalPoly::from_coeffs(vec![Q::zero(), Q::from_integer(2)]);
+            let one_minus_t2 =
+                RationalPoly::from_coeffs(vec![Q::one(), Q::zero(), Q::from_integer(-1)]);
+            let (num, den) = match name.as_ref() {
+                "sin" => (two_t, one_plus_t_squared()),
+                "cos" => (one_minus_t2, one_plus_t_squared()),
+                "tan" => (two_t, one_minus_t2),
+                "cot" => (one_minus_t2, two_t),
+                "sec" => (one_plus_t_squared(), one_minus_t2),
+                "csc" => (one_plus_t_squared(), two_t),
+                _ => return None,
+            };
+            Some(RationalFunction::new(num, den))
+        }
+        Expression::Add(terms) => {
+            let mut sum = RationalFunction::constant(Q::zero());
+            for term in terms.iter() {
+                sum = sum.add(&to_rational_function(term, argument, var, pieces)?);
+            }
+            pieces.push(sum.num.clone());
+            Some(sum)
+        }
+        Expression::Mul(factors) => {
+            let mut product = RationalFunction::constant(Q::one());
+            for factor in factors.iter() {
+                product = product.mul(&to_rational_function(factor, argument, var, pieces)?);
+            }
+            Some(product)
+        }
+        Expression::Pow(base, exp) => match exp.as_ref() {
+            Expression::Number(Number::Integer(n)) => {
+                to_rational_function(base, argument, var, pieces)?.pow(*n)
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn one_plus_t_squared() -> RationalPoly {
+    RationalPoly::from_coeffs(vec![Q::one(), Q::zero(), Q::one()])
+}
+
+/// Integrate num/den by partial fractions
+///
+/// Returns the decomposition terms alongside the antiderivative.
+fn integrate_rational_function(
+    f: &RationalFunction,
+    pieces: &[RationalPoly],
+    t: &Symbol,
+) -> Option<(Vec<Expression>, Expression)> {
+    let t_expr = Expression::symbol(t.clone());
+    let decomposition = decompose(f, pieces)?;
+    let antiderivative = integrate_decomposition(&decomposition, &t_expr)?;
+    Some((decomposition.terms(&t_expr), antiderivative))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algebra::solvers::Assumptions;
+    use crate::simplify::verification::find_mismatch;
+    use crate::{expr, symbol};
+
+    fn check(integrand: Expression) {
+        let x = symbol!(x);
+        let result = try_weierstrass_substitution(&integrand, &x)
+            .unwrap_or_else(|| panic!("no substitution for {}", integrand));
+        let derivative = result.derivative(x);
+        assert!(
+            find_mismatch(&integrand, &derivative, &Assumptions::new()).is_none(),
+            "d/dx {} != {}",
+            result,
+            integrand
+        );
+    }
+
+    #[test]
+    fn test_linear_in_sin_cos_denominators() {
+        check(expr!((2 + cos(x)) ^ (-1)));
+        check(expr!((1 + sin(x)) ^ (-1)));
+        check(expr!((3 + (5 * cos(x))) ^ (-1)));
+        check(expr!((sin(x) + cos(x)) ^ (-1)));
+    }
+
+    #[test]
+    fn test_repeated_and_mixed_factors() {
+        check(expr!((2 + cos(x)) ^ (-2)));
+        check(expr!(sin(x) * ((1 + cos(x)) ^ (-1))));
+        check(expr!(cos(x) * ((2 + sin(x)) ^ (-1))));
+    }
+
+    #[test]
+    fn test_reciprocal_functions_and_scaled_argument() {
+        check(expr!(sin(x) ^ (-1)));
+        check(expr!((1 + sec(x)) ^ (-1)));
+        check(expr!((2 + cos(3 * x)) ^ (-1)));
+    }
+
+    #[test]
+    fn test_rejects_non_rational_trig() {
+        let x = symbol!(x);
+        assert!(!is_rational_trig(&expr!(x * sin(x)), &x));
+        assert!(!is_rational_trig(&expr!(sin(x) * cos(2 * x)), &x));
+        assert!(!is_rational_trig(&expr!(sqrt(sin(x))), &x));
+        assert!(is_rational_trig(&expr!((2 + cos(x)) ^ (-1)), &x));
+    }
+
+    #[test]
+    fn test_explanation_steps() {
+        let x = symbol!(x);
+        let integrand = expr!((2 + cos(x)) ^ (-1));
+        let explanation = explain_weierstrass_substitution(&integrand, &x).unwrap();
+        assert_eq!(explanation.steps.len(), 7);
+        assert!(explanation.steps[1].description.contains("tan"));
+        assert_eq!(
+            explanation.final_expression,
+            try_weierstrass_substitution(&integrand, &x).unwrap()
+        );
+    }
+}
@@ -0,0 +1,339 @@
+//! Laplace transform by table lookup
+//!
+//! `L{f}(s) = ∫₀^∞ f(t) e^{-st} dt`, computed from a table of standard pairs
+//! together with linearity and the two shift theorems:
+//!
+//! | f(t)                  | F(s)                 |
+//! |-----------------------|----------------------|
+//! | c                     | c/s                  |
+//! | tⁿ                    | n!/sⁿ⁺¹              |
+//! | e^{at}                | 1/(s - a)            |
+//! | sin(ωt), cos(ωt)      | ω/(s² + ω²), s/(s² + ω²) |
+//! | sinh(ωt), cosh(ωt)    | ω/(s² - ω²), s/(s² - ω²) |
+//! | H(t - a)              | e^{-as}/s            |
+//! | δ⁽ⁿ⁾(t - a)           | sⁿ e^{-as}           |
+//! | e^{at} g(t)           | G(s - a)             |
+//! | H(t - a) g(t)         | e^{-as} L{g(t + a)}  |
+//!
+//! Delays `a` are taken to be nonnegative. Expressions outside the table stay
+//! as an unevaluated `laplace_transform(f, t, s)`. The inverse transform of
+//! rational functions is in [`inverse`], and [`transfer_function`] builds
+//! control-system block diagrams on top of both directions.
+
+pub mod convolution;
+pub mod inverse;
+pub mod transfer_function;
+
+pub use convolution::convolve;
+pub use inverse::InverseLaplaceTransform;
+pub use transfer_function::TransferFunction;
+
+use crate::calculus::integrals::distributions::linear_coefficients;
+use crate::core::{Expression, Number, Symbol};
+use crate::functions::special::distributions::delta_order;
+use crate::simplify::Simplify;
+use std::collections::HashMap;
+
+/// Laplace transform of expressions in a time variable
+pub trait LaplaceTransform {
+    /// Transform from `t` to `s`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::calculus::laplace::LaplaceTransform;
+    /// use mathhook_core::{expr, symbol, Expression};
+    ///
+    /// let (t, s) = (symbol!(t), symbol!(s));
+    /// // Unit step delayed by 3: e^{-3s}/s
+    /// let step = Expression::function("heaviside", vec![expr!(t - 3)]);
+    /// assert_eq!(
+    ///     step.laplace_transform(&t, &s),
+    ///     expr!(exp(-3 * s) * (s ^ -1))
+    /// );
+    /// ```
+    fn laplace_transform(&self, t: &Symbol, s: &Symbol) -> Expression;
+}
+
+impl LaplaceTransform for Expression {
+    fn laplace_transform(&self, t: &Symbol, s: &Symbol) -> Expression {
+        match transform(self, t, s) {
+            Some(result) => result.simplify(),
+            None => Expression::function(
+                "laplace_transform",
+                vec![
+                    self.clone(),
+                    Expression::symbol(t.clone()),
+                    Expression::symbol(s.clone()),
+                ],
+            ),
+        }
+    }
+}
+
+fn transform(expr: &Expression, t: &Symbol, s: &Symbol) -> Option<Expression> {
+    let s_expr = Expression::symbol(s.clone());
+    if !expr.contains_variable(t) {
+        return Some(Expression::mul(vec![
+            expr.clone(),
+            Expression::pow(s_expr, Expression::integer(-1)),
+        ]));
+    }
+
+    match expr {
+        Expression::Add(terms) => terms
+            .iter()
+            .map(|term| transform(term, t, s))
+            .collect::<Option<Vec<_>>>()
+            .map(Expression::add),
+        Expression::Mul(factors) => transform_product(factors, t, s),
+        Expression::Symbol(_) => Some(Expression::pow(s_expr, Expression::integer(-2))),
+        Expression::Pow(base, exponent) => match (base.as_ref(), exponent.as_ref()) {
+            (Expression::Symbol(symbol), Expression::Number(Number::Integer(n)))
+                if symbol == t && *n >= 0 =>
+            {
+                // n!/s^(n+1)
+                Some(Expression::mul(vec![
+                    Expression::function("factorial", vec![Expression::integer(*n)]),
+                    Expression::pow(s_expr, Expression::integer(-(n + 1))),
+                ]))
+            }
+            _ => None,
+        },
+        Expression::Function { name, args, .. } => transform_function(name, args, t, s),
+        _ => None,
+    }
+}
+
+fn transform_function(
+    name: &str,
+    args: &[Expression],
+    t: &Symbol,
+    s: &Symbol,
+) -> Option<Expression> {
+    let s_expr = Expression::symbol(s.clone());
+    let (rate, offset) = linear_coefficients(args.first()?, t)?;
+    let squared = |sign: i64| {
+        Expression::add(vec![
+            Expression::pow(s_expr.clone(), Expression::integer(2)),
+            Expression::mul(vec![
+                Expression::integer(sign),
+                Expression::pow(rate.clone(), Expression::integer(2)),
+            ]),
+        ])
+    };
+    let over = |numerator: Expression, denominator: Expression| {
+        Expression::mul(vec![
+            numerator,
+            Expression::pow(denominator, Expression::integer(-1)),
+        ])
+    };
+    // Time delay a of an argument k(t - a)
+    let delay = || {
+        Expression::mul(vec![
+            Expression::integer(-1),
+            offset.clone(),
+            Expression::pow(rate.clone(), Expression::integer(-1)),
+        ])
+    };
+    let delayed = |transform: Expression| {
+        Expression::mul(vec![
+            Expression::function(
+                "exp",
+                vec![Expression::mul(vec![
+                    Expression::integer(-1),
+                    delay(),
+                    s_expr.clone(),
+                ])],
+            ),
+            transform,
+        ])
+    };
+
+    match name {
+        "exp" if args.len() == 1 => Some(Expression::mul(vec![
+            Expression::function("exp", vec![offset.clone()]),
+            over(
+                Expression::integer(1),
+                Expression::add(vec![
+                    s_expr.clone(),
+                    Expression::mul(vec![Expression::integer(-1), rate.clone()]),
+                ]),
+            ),
+        ])),
+        "sin" | "cos" | "sinh" | "cosh" if args.len() == 1 && offset.is_zero() => {
+            let numerator = match name {
+                "sin" | "sinh" => rate.clone(),
+                _ => s_expr.clone(),
+            };
+            let sign = if name.ends_with('h') { -1 } else { 1 };
+            Some(over(numerator, squared(sign)))
+        }
+        "heaviside" if args.len() == 1 && rate.is_positive_number() => {
+            Some(delayed(over(Expression::integer(1), s_expr.clone())))
+        }
+        "dirac_delta" if rate.is_positive_number() => {
+            let order = delta_order(args)?;
+            // δ⁽ⁿ⁾(k(t - a)) = δ⁽ⁿ⁾(t - a)/kⁿ⁺¹ for k > 0
+            Some(delayed(over(
+                Expression::pow(s_expr.clone(), Expression::integer(order)),
+                Expression::pow(rate.clone(), Expression::integer(order + 1)),
+            )))
+        }
+        _ => None,
+    }
+}
+
+/// Constant multiples and the two shift theorems
+fn transform_product(factors: &[Expression], t: &Symbol, s: &Symbol) -> Option<Expression> {
+    let (constants, varying): (Vec<Expression>, Vec<Expression>) = factors
+        .iter()
+        .cloned()
+        .partition(|factor| !factor.contains_variable(t));
+    if !constants.is_empty() {
+        let inner = transform(&Expression::mul(varying), t, s)?;
+        let mut product = constants;
+        product.push(inner);
+        return Some(Expression::mul(product));
+    }
+    if varying.len() == 1 {
+        return transform(&varying[0], t, s);
+    }
+
+    let rest_without = |position: usize| {
+        Expression::mul(
+            varying
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| *i != position)
+                .map(|(_, factor)| factor.clone())
+                .collect(),
+        )
+    };
+    let s_expr = Expression::symbol(s.clone());
+
+    for (position, factor) in varying.iter().enumerate() {
+        let Expression::Function { name, args, .. } = factor else {
+            continue;
+        };
+        let Some((rate, offset)) = args.first().and_then(|arg| linear_coefficients(arg, t)) else {
+            continue;
+        };
+        match name.as_ref() {
+            // e^{at + b} g(t) → e^b G(s - a)
+            "exp" => {
+                let shifted_s = Expression::add(vec![
+                    s_expr.clone(),
+                    Expression::mul(vec![Expression::integer(-1), rate]),
+                ]);
+                let inner = transform(&rest_without(position), t, s)?;
+                return Some(Expression::mul(vec![
+                    Expression::function("exp", vec![offset]),
+                    inner.substitute(&HashMap::from([(s.name().to_owned(), shifted_s)])),
+                ]));
+            }
+            // H(t - a) g(t) → e^{-as} L{g(t + a)}
+            "heaviside" if rate.is_positive_number() => {
+                let delay = Expression::mul(vec![
+                    Expression::integer(-1),
+                    offset,
+                    Expression::pow(rate, Expression::integer(-1)),
+                ])
+                .simplify();
+                let advanced = rest_without(position).substitute(&HashMap::from([(
+                    t.name().to_owned(),
+                    Expression::add(vec![Expression::symbol(t.clone()), delay.clone()]),
+                )]));
+                let inner = transform(&advanced.simplify(), t, s)?;
+                return Some(Expression::mul(vec![
+                    Expression::function(
+                        "exp",
+                        vec![Expression::mul(vec![
+                            Expression::integer(-1),
+                            delay,
+                            s_expr,
+                        ])],
+                    ),
+                    inner,
+                ]));
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{expr, symbol};
+
+    /// F(s) at a sample point, to compare transforms independent of form
+    fn at(transform: &Expression, s: f64) -> f64 {
+        transform
+            .substitute(&HashMap::from([("s".to_owned(), Expression::float(s))]))
+            .evaluate_to_f64()
+            .unwrap()
+    }
+
+    fn assert_transform(f: Expression, expected: impl Fn(f64) -> f64) {
+        let result = f.laplace_transform(&symbol!(t), &symbol!(s));
+        for s in [1.5, 2.0, 3.5] {
+            assert!(
+                (at(&result, s) - expected(s)).abs() < 1e-9,
+                "L{{{}}} = {} at s = {}",
+                f,
+                result,
+                s
+            );
+        }
+    }
+
+    #[test]
+    fn test_table_entries() {
+        assert_transform(expr!(5), |s| 5.0 / s);
+        assert_transform(expr!(t ^ 3), |s| 6.0 / s.powi(4));
+        assert_transform(expr!(exp(-2 * t)), |s| 1.0 / (s + 2.0));
+        assert_transform(expr!(sin(3 * t)), |s| 3.0 / (s * s + 9.0));
+        assert_transform(expr!(cosh(t)), |s| s / (s * s - 1.0));
+    }
+
+    #[test]
+    fn test_step_and_impulse() {
+        assert_transform(Expression::function("heaviside", vec![expr!(t - 2)]), |s| {
+            (-2.0 * s).exp() / s
+        });
+        assert_transform(
+            Expression::function("dirac_delta", vec![expr!(t - 1)]),
+            |s| (-s).exp(),
+        );
+        assert_transform(Expression::function("dirac_delta", vec![expr!(t)]), |_| 1.0);
+    }
+
+    #[test]
+    fn test_shift_theorems() {
+        // Delayed ramp H(t - 1)(t - 1) → e^{-s}/s²
+        let ramp = Expression::mul(vec![
+            Expression::function("heaviside", vec![expr!(t - 1)]),
+            expr!(t - 1),
+        ]);
+        assert_transform(ramp, |s| (-s).exp() / (s * s));
+
+        // Damped oscillation e^{-t} sin(2t) → 2/((s + 1)² + 4)
+        let damped = Expression::mul(vec![expr!(exp(-t)), expr!(sin(2 * t))]);
+        assert_transform(damped, |s| 2.0 / ((s + 1.0).powi(2) + 4.0));
+
+        // Linearity with a constant multiple
+        assert_transform(expr!((3 * t) + 1), |s| 3.0 / (s * s) + 1.0 / s);
+    }
+
+    #[test]
+    fn test_unknown_is_left_unevaluated() {
+        let (t, s) = (symbol!(t), symbol!(s));
+        let result = expr!(ln(t)).laplace_transform(&t, &s);
+        assert!(matches!(
+            result,
+            Expression::Function { ref name, .. } if name.as_ref() == "laplace_transform"
+        ));
+    }
+}
@@ -0,0 +1,107 @@
+//! Convolution `(f * g)(t) = ∫₀ᵗ f(τ) g(t - τ) dτ`
+//!
+//! By the convolution theorem `L{f * g} = F(s)G(s)`, so when both transforms
+//! are known the product is inverted; otherwise the integral is computed
+//! directly.
+
+use super::inverse::InverseLaplaceTransform;
+use super::LaplaceTransform;
+use crate::calculus::integrals::Integration;
+use crate::core::{Expression, Symbol};
+use crate::simplify::Simplify;
+use std::collections::HashMap;
+
+/// Convolution of two causal signals in `t`
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::calculus::laplace::convolution::convolve;
+/// use mathhook_core::{expr, symbol};
+///
+/// let t = symbol!(t);
+/// // e^{-t} * e^{-2t} = e^{-t} - e^{-2t}
+/// assert_eq!(
+///     convolve(&expr!(exp(-t)), &expr!(exp(-2 * t)), &t),
+///     expr!(exp(-t) - exp(-2 * t))
+/// );
+/// ```
+pub fn convolve(f: &Expression, g: &Expression, t: &Symbol) -> Expression {
+    let s = Symbol::scalar("s");
+    let product = Expression::mul(vec![f.laplace_transform(t, &s), g.laplace_transform(t, &s)]);
+    let result = product.inverse_laplace_transform(&s, t);
+    if !is_unevaluated(&result) && !f.contains_variable(&s) && !g.contains_variable(&s) {
+        return result;
+    }
+
+    let tau = Symbol::scalar("τ");
+    let at = |expr: &Expression, value: Expression| {
+        expr.substitute(&HashMap::from([(t.name().to_owned(), value)]))
+    };
+    let t_expr = Expression::symbol(t.clone());
+    let tau_expr = Expression::symbol(tau.clone());
+    let integrand = Expression::mul(vec![
+        at(f, tau_expr.clone()),
+        at(
+            g,
+            Expression::add(vec![
+                t_expr.clone(),
+                Expression::mul(vec![Expression::integer(-1), tau_expr]),
+            ]),
+        ),
+    ])
+    .simplify();
+    match integrand.definite_integrate(tau, Expression::integer(0), t_expr) {
+        Ok(value) => value.simplify(),
+        Err(_) => Expression::function(
+            "convolve",
+            vec![f.clone(), g.clone(), Expression::symbol(t.clone())],
+        ),
+    }
+}
+
+fn is_unevaluated(expr: &Expression) -> bool {
+    match expr {
+        Expression::Function { name, args, .. } => {
+            matches!(
+                name.as_ref(),
+                "laplace_transform" | "inverse_laplace_transform"
+            ) || args.iter().any(is_unevaluated)
+        }
+        Expression::Add(terms) | Expression::Mul(terms) => terms.iter().any(is_unevaluated),
+        Expression::Pow(base, exponent) => is_unevaluated(base) || is_unevaluated(exponent),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{expr, symbol};
+
+    #[test]
+    fn test_convolution_through_transforms() {
+        let t = symbol!(t);
+        // 1 * sin t = 1 - cos t
+        assert_eq!(
+            convolve(&expr!(1), &expr!(sin(t)), &t),
+            expr!(1 - cos(t)).simplify()
+        );
+        // δ is the identity
+        let delta = Expression::function("dirac_delta", vec![expr!(t)]);
+        assert_eq!(convolve(&delta, &expr!(exp(-t)), &t), expr!(exp(-t)));
+    }
+
+    #[test]
+    fn test_convolution_by_direct_integration() {
+        let t = symbol!(t);
+        // √t has no table transform: ∫₀ᵗ √τ dτ = 2t^{3/2}/3
+        let root = Expression::pow(expr!(t), Expression::rational(1, 2));
+        let result = convolve(&root, &expr!(1), &t);
+        let value = result
+            .substitute(&HashMap::from([("t".to_owned(), Expression::integer(4))]))
+            .evaluate_to_f64()
+            .unwrap();
+        assert!((value - 16.0 / 3.0).abs() < 1e-12, "{}", result);
+    }
+}
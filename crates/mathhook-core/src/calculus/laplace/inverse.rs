@@ -0,0 +1,598 @@
+//! Inverse Laplace transform of rational functions
+//!
+//! `F(s) = N(s)/D(s)` is split by partial fractions over the real factors of
+//! `D`, and each fraction is inverted from the table:
+//!
+//! | F(s)                          | f(t)                                |
+//! |-------------------------------|-------------------------------------|
+//! | sᵏ (polynomial part)          | δ⁽ᵏ⁾(t)                             |
+//! | A/(s - r)ᵏ                    | A tᵏ⁻¹ e^{rt}/(k - 1)!              |
+//! | (Bs + C)/((s - α)² + β²)      | e^{αt}(B cos βt + (C + Bα)/β sin βt) |
+//! | e^{-as} G(s)                  | H(t - a) g(t - a)                   |
+//!
+//! Linear factors of a factored denominator give their roots directly, so
+//! symbolic poles survive. Expanded denominators are factored with the
+//! equation solver: exact real roots are divided out and a remaining
+//! quadratic is solved in closed form. Repeated irreducible quadratics and
+//! higher-degree remainders are left unevaluated.
+
+use crate::algebra::equation_analyzer::SmartEquationSolver;
+use crate::algebra::gcd::PolynomialGcd;
+use crate::algebra::solvers::RootKind;
+use crate::algebra::Expand;
+use crate::calculus::derivatives::Derivative;
+use crate::calculus::integrals::distributions::linear_coefficients;
+use crate::calculus::integrals::rational::extract_numerator_denominator;
+use crate::calculus::integrals::rational::helpers::{factorial, is_polynomial, polynomial_degree};
+use crate::core::{Expression, Number, Symbol};
+use crate::simplify::Simplify;
+use std::collections::HashMap;
+
+/// Inverse Laplace transform of expressions in a frequency variable
+pub trait InverseLaplaceTransform {
+    /// Transform from `s` back to `t`, for `t ≥ 0`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::calculus::laplace::InverseLaplaceTransform;
+    /// use mathhook_core::{expr, symbol};
+    ///
+    /// let (s, t) = (symbol!(s), symbol!(t));
+    /// // 1/((s + 1)(s + 2)) = 1/(s + 1) - 1/(s + 2)
+    /// let transfer = expr!(((s + 1) ^ -1) * ((s + 2) ^ -1));
+    /// assert_eq!(
+    ///     transfer.inverse_laplace_transform(&s, &t),
+    ///     expr!(exp(-t) - exp(-2 * t))
+    /// );
+    /// ```
+    fn inverse_laplace_transform(&self, s: &Symbol, t: &Symbol) -> Expression;
+}
+
+impl InverseLaplaceTransform for Expression {
+    fn inverse_laplace_transform(&self, s: &Symbol, t: &Symbol) -> Expression {
+        match invert(&self.simplify(), s, t) {
+            Some(result) => result.simplify(),
+            None => Expression::function(
+                "inverse_laplace_transform",
+                vec![
+                    self.clone(),
+                    Expression::symbol(s.clone()),
+                    Expression::symbol(t.clone()),
+                ],
+            ),
+        }
+    }
+}
+
+fn invert(expr: &Expression, s: &Symbol, t: &Symbol) -> Option<Expression> {
+    if !expr.contains_variable(s) {
+        return Some(Expression::mul(vec![
+            expr.clone(),
+            Expression::function("dirac_delta", vec![Expression::symbol(t.clone())]),
+        ]));
+    }
+
+    match expr {
+        Expression::Add(terms) => terms
+            .iter()
+            .map(|term| invert(term, s, t))
+            .collect::<Option<Vec<_>>>()
+            .map(Expression::add),
+        Expression::Mul(factors) => match delay_factor(factors, s) {
+            Some((position, rate, offset)) => invert_delayed(factors, position, rate, offset, s, t),
+            None => invert_rational(expr, s, t),
+        },
+        _ => invert_rational(expr, s, t),
+    }
+}
+
+/// Position and `(rate, offset)` of an `exp(rate·s + offset)` factor
+fn delay_factor(factors: &[Expression], s: &Symbol) -> Option<(usize, Expression, Expression)> {
+    factors
+        .iter()
+        .enumerate()
+        .find_map(|(position, factor)| match factor {
+            Expression::Function { name, args, .. }
+                if name.as_ref() == "exp" && args.len() == 1 =>
+            {
+                let (rate, offset) = linear_coefficients(&args[0], s)?;
+                Some((position, rate, offset))
+            }
+            _ => None,
+        })
+}
+
+/// `e^{b - as} G(s) → e^b H(t - a) g(t - a)`
+fn invert_delayed(
+    factors: &[Expression],
+    position: usize,
+    rate: Expression,
+    offset: Expression,
+    s: &Symbol,
+    t: &Symbol,
+) -> Option<Expression> {
+    let rest = Expression::mul(
+        factors
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| *i != position)
+            .map(|(_, factor)| factor.clone())
+            .collect(),
+    );
+    let undelayed = invert(&rest, s, t)?;
+    let shifted_t = Expression::add(vec![Expression::symbol(t.clone()), rate]).simplify();
+    Some(Expression::mul(vec![
+        Expression::function("exp", vec![offset]),
+        Expression::function("heaviside", vec![shifted_t.clone()]),
+        undelayed.substitute(&HashMap::from([(t.name().to_owned(), shifted_t)])),
+    ]))
+}
+
+/// Real factorization `leading · Π (s - r)ᵐ · Π (s² + p s + q)` of a
+/// denominator, with every quadratic irreducible and simple
+struct Factorization {
+    leading: Expression,
+    roots: Vec<(Expression, i64)>,
+    quadratics: Vec<(Expression, Expression)>,
+}
+
+impl Factorization {
+    fn push_root(&mut self, root: Expression, multiplicity: i64) {
+        match self
+            .roots
+            .iter_mut()
+            .find(|(existing, _)| *existing == root)
+        {
+            Some((_, existing)) => *existing += multiplicity,
+            None => self.roots.push((root, multiplicity)),
+        }
+    }
+
+    /// Every factor except the given linear or quadratic one
+    fn cofactor(
+        &self,
+        skip_root: Option<usize>,
+        skip_quadratic: Option<usize>,
+        s: &Symbol,
+    ) -> Expression {
+        let mut factors = vec![self.leading.clone()];
+        for (i, (root, multiplicity)) in self.roots.iter().enumerate() {
+            if Some(i) != skip_root {
+                factors.push(Expression::pow(
+                    shifted(s, root),
+                    Expression::integer(*multiplicity),
+                ));
+            }
+        }
+        for (i, (p, q)) in self.quadratics.iter().enumerate() {
+            if Some(i) != skip_quadratic {
+                factors.push(quadratic(s, p, q));
+            }
+        }
+        Expression::mul(factors)
+    }
+}
+
+fn invert_rational(expr: &Expression, s: &Symbol, t: &Symbol) -> Option<Expression> {
+    let (numerator, denominator) = extract_numerator_denominator(expr);
+    if !is_polynomial(&numerator, s) || !is_polynomial(&denominator, s) {
+        return None;
+    }
+    let factorization = factor_denominator(&denominator, s)?;
+
+    let numerator = numerator.expand().simplify();
+    let denominator = denominator.expand().simplify();
+    let (quotient, remainder) =
+        if polynomial_degree(&numerator, s) >= polynomial_degree(&denominator, s) {
+            numerator.div_polynomial(&denominator, s)
+        } else {
+            (Expression::integer(0), numerator)
+        };
+
+    let t_expr = Expression::symbol(t.clone());
+    let mut terms = Vec::new();
+
+    // sᵏ → δ⁽ᵏ⁾(t)
+    for k in 0..=polynomial_degree(&quotient, s) {
+        let coefficient = coefficient(&quotient, s, k);
+        if coefficient.is_zero() {
+            continue;
+        }
+        let mut delta_args = vec![t_expr.clone()];
+        if k > 0 {
+            delta_args.push(Expression::integer(k));
+        }
+        terms.push(Expression::mul(vec![
+            coefficient,
+            Expression::function("dirac_delta", delta_args),
+        ]));
+    }
+
+    for (i, (root, multiplicity)) in factorization.roots.iter().enumerate() {
+        // Heaviside's method: A_k = [(s - r)ᵐ F]⁽ᵐ⁻ᵏ⁾(r)/(m - k)!
+        let cover = Expression::mul(vec![
+            remainder.clone(),
+            Expression::pow(
+                factorization.cofactor(Some(i), None, s),
+                Expression::integer(-1),
+            ),
+        ]);
+        let exponential = Expression::function(
+            "exp",
+            vec![Expression::mul(vec![root.clone(), t_expr.clone()])],
+        );
+        for k in 1..=*multiplicity {
+            let order = (multiplicity - k) as u32;
+            let derivative = if order == 0 {
+                cover.clone()
+            } else {
+                cover.nth_derivative(s.clone(), order)
+            };
+            let residue = at(&derivative, s, root);
+            terms.push(Expression::mul(vec![
+                residue,
+                Expression::pow(
+                    Expression::mul(vec![factorial(multiplicity - k), factorial(k - 1)]),
+                    Expression::integer(-1),
+                ),
+                Expression::pow(t_expr.clone(), Expression::integer(k - 1)),
+                exponential.clone(),
+            ]));
+        }
+    }
+
+    for (i, (p, q)) in factorization.quadratics.iter().enumerate() {
+        terms.push(invert_quadratic(
+            &remainder,
+            &factorization.cofactor(None, Some(i), s),
+            p,
+            q,
+            s,
+            t,
+        )?);
+    }
+
+    Some(Expression::add(terms))
+}
+
+/// Inverse of `N(s)/(M(s)(s² + p s + q))` over the complex pole pair
+/// `α ± iβ`: with `N(α + iβ)/M(α + iβ) = u + iv`, the pair contributes
+/// `e^{αt}(v cos βt + u sin βt)/β`
+fn invert_quadratic(
+    numerator: &Expression,
+    cofactor: &Expression,
+    p: &Expression,
+    q: &Expression,
+    s: &Symbol,
+    t: &Symbol,
+) -> Option<Expression> {
+    let alpha = Expression::mul(vec![Expression::rational(-1, 2), p.clone()]).simplify();
+    let beta = square_root(Expression::add(vec![
+        q.clone(),
+        Expression::mul(vec![
+            Expression::integer(-1),
+            Expression::pow(alpha.clone(), Expression::integer(2)),
+        ]),
+    ]))
+    .simplify();
+
+    // A polynomial at α + iβ, from its remainder a s + b modulo the quadratic
+    let at_pole = |polynomial: &Expression| {
+        let (_, remainder) = polynomial
+            .expand()
+            .simplify()
+            .div_polynomial(&quadratic(s, p, q).expand().simplify(), s);
+        let slope = coefficient(&remainder, s, 1);
+        let real = Expression::add(vec![
+            Expression::mul(vec![slope.clone(), alpha.clone()]),
+            coefficient(&remainder, s, 0),
+        ]);
+        (real, Expression::mul(vec![slope, beta.clone()]))
+    };
+    let (x1, y1) = at_pole(numerator);
+    let (x2, y2) = at_pole(cofactor);
+    let modulus = Expression::add(vec![
+        Expression::pow(x2.clone(), Expression::integer(2)),
+        Expression::pow(y2.clone(), Expression::integer(2)),
+    ]);
+    let over_modulus = |value: Expression| {
+        Expression::mul(vec![
+            value,
+            Expression::pow(modulus.clone(), Expression::integer(-1)),
+        ])
+    };
+    let u = over_modulus(Expression::add(vec![
+        Expression::mul(vec![x1.clone(), x2.clone()]),
+        Expression::mul(vec![y1.clone(), y2.clone()]),
+    ]));
+    let v = over_modulus(Expression::add(vec![
+        Expression::mul(vec![y1, x2]),
+        Expression::mul(vec![Expression::integer(-1), x1, y2]),
+    ]));
+
+    let t_expr = Expression::symbol(t.clone());
+    let beta_t = Expression::mul(vec![beta.clone(), t_expr.clone()]);
+    Some(Expression::mul(vec![
+        Expression::function("exp", vec![Expression::mul(vec![alpha, t_expr])]),
+        Expression::pow(beta, Expression::integer(-1)),
+        Expression::add(vec![
+            Expression::mul(vec![v, Expression::function("cos", vec![beta_t.clone()])]),
+            Expression::mul(vec![u, Expression::function("sin", vec![beta_t])]),
+        ]),
+    ]))
+}
+
+fn factor_denominator(denominator: &Expression, s: &Symbol) -> Option<Factorization> {
+    let mut factorization = Factorization {
+        leading: Expression::integer(1),
+        roots: Vec::new(),
+        quadratics: Vec::new(),
+    };
+    let factors = match denominator {
+        Expression::Mul(factors) => factors.to_vec(),
+        other => vec![other.clone()],
+    };
+    for factor in factors {
+        let (base, power) = match &factor {
+            Expression::Pow(base, exponent) => match exponent.as_ref() {
+                Expression::Number(Number::Integer(power)) if *power > 0 => {
+                    (base.as_ref().clone(), *power)
+                }
+                _ => return None,
+            },
+            other => (other.clone(), 1),
+        };
+        if !base.contains_variable(s) {
+            factorization.leading = Expression::mul(vec![factorization.leading, factor]);
+        } else if let Some((slope, offset)) = linear_coefficients(&base, s) {
+            factorization.leading = Expression::mul(vec![
+                factorization.leading,
+                Expression::pow(slope.clone(), Expression::integer(power)),
+            ]);
+            factorization.push_root(root_of(&slope, &offset), power);
+        } else {
+            factor_polynomial(&base.expand().simplify(), s, power, &mut factorization)?;
+        }
+    }
+    factorization.leading = factorization.leading.simplify();
+    Some(factorization)
+}
+
+/// Factor an expanded polynomial raised to `power` into `factorization`
+fn factor_polynomial(
+    polynomial: &Expression,
+    s: &Symbol,
+    power: i64,
+    factorization: &mut Factorization,
+) -> Option<()> {
+    let mut remaining = polynomial.clone();
+    if polynomial_degree(polynomial, s) > 2 {
+        let (solutions, _) = SmartEquationSolver::new().solve_with_equation(polynomial, s);
+        for root in solutions.roots(polynomial, s).roots {
+            if !root.exact || root.kind == RootKind::Complex {
+                continue;
+            }
+            let multiplicity = root.multiplicity as i64;
+            let divisor =
+                Expression::pow(shifted(s, &root.value), Expression::integer(multiplicity))
+                    .expand()
+                    .simplify();
+            let (quotient, rest) = remaining.div_polynomial(&divisor, s);
+            if !rest.is_zero() {
+                continue;
+            }
+            remaining = quotient.simplify();
+            factorization.push_root(root.value, multiplicity * power);
+        }
+    }
+
+    let degree = polynomial_degree(&remaining, s);
+    let leading = coefficient(&remaining, s, degree);
+    factorization.leading = Expression::mul(vec![
+        factorization.leading.clone(),
+        Expression::pow(leading.clone(), Expression::integer(power)),
+    ]);
+    let monic = |k: i64| {
+        Expression::mul(vec![
+            coefficient(&remaining, s, k),
+            Expression::pow(leading.clone(), Expression::integer(-1)),
+        ])
+        .simplify()
+    };
+    match degree {
+        0 => {}
+        1 => factorization.push_root(
+            Expression::mul(vec![Expression::integer(-1), monic(0)]).simplify(),
+            power,
+        ),
+        2 => {
+            let (p, q) = (monic(1), monic(0));
+            let discriminant = Expression::add(vec![
+                Expression::pow(p.clone(), Expression::integer(2)),
+                Expression::mul(vec![Expression::integer(-4), q.clone()]),
+            ])
+            .simplify();
+            if discriminant.is_negative_number() {
+                if power != 1 {
+                    return None;
+                }
+                factorization.quadratics.push((p, q));
+            } else {
+                for sign in [1, -1] {
+                    let root = Expression::mul(vec![
+                        Expression::rational(1, 2),
+                        Expression::add(vec![
+                            Expression::mul(vec![Expression::integer(-1), p.clone()]),
+                            Expression::mul(vec![
+                                Expression::integer(sign),
+                                square_root(discriminant.clone()),
+                            ]),
+                        ]),
+                    ])
+                    .simplify();
+                    factorization.push_root(root, power);
+                }
+            }
+        }
+        _ => return None,
+    }
+    Some(())
+}
+
+/// Coefficient of `sᵏ` in a polynomial, `P⁽ᵏ⁾(0)/k!`
+fn coefficient(polynomial: &Expression, s: &Symbol, k: i64) -> Expression {
+    let derivative = if k == 0 {
+        polynomial.clone()
+    } else {
+        polynomial.nth_derivative(s.clone(), k as u32)
+    };
+    Expression::mul(vec![
+        Expression::pow(factorial(k), Expression::integer(-1)),
+        at(&derivative, s, &Expression::integer(0)),
+    ])
+    .simplify()
+}
+
+fn at(expr: &Expression, s: &Symbol, value: &Expression) -> Expression {
+    expr.substitute(&HashMap::from([(s.name().to_owned(), value.clone())]))
+        .simplify()
+}
+
+/// `sqrt` folds perfect squares but turns other numbers into floats; keep
+/// those as exact powers
+fn square_root(value: Expression) -> Expression {
+    match Expression::sqrt(value.clone()).simplify() {
+        Expression::Number(Number::Float(_)) => Expression::pow(value, Expression::rational(1, 2)),
+        folded => folded,
+    }
+}
+
+fn root_of(slope: &Expression, offset: &Expression) -> Expression {
+    Expression::mul(vec![
+        Expression::integer(-1),
+        offset.clone(),
+        Expression::pow(slope.clone(), Expression::integer(-1)),
+    ])
+    .simplify()
+}
+
+fn shifted(s: &Symbol, root: &Expression) -> Expression {
+    Expression::add(vec![
+        Expression::symbol(s.clone()),
+        Expression::mul(vec![Expression::integer(-1), root.clone()]),
+    ])
+}
+
+fn quadratic(s: &Symbol, p: &Expression, q: &Expression) -> Expression {
+    let s_expr = Expression::symbol(s.clone());
+    Expression::add(vec![
+        Expression::pow(s_expr.clone(), Expression::integer(2)),
+        Expression::mul(vec![p.clone(), s_expr]),
+        q.clone(),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{expr, symbol};
+
+    fn assert_inverse(transform: Expression, expected: impl Fn(f64) -> f64) {
+        let result = transform.inverse_laplace_transform(&symbol!(s), &symbol!(t));
+        for t in [0.3, 1.0, 2.5] {
+            let value = result
+                .substitute(&HashMap::from([("t".to_owned(), Expression::float(t))]))
+                .evaluate_to_f64()
+                .unwrap();
+            assert!(
+                (value - expected(t)).abs() < 1e-9,
+                "L⁻¹{{{}}} = {} at t = {}",
+                transform,
+                result,
+                t
+            );
+        }
+    }
+
+    #[test]
+    fn test_distinct_and_repeated_real_poles() {
+        assert_inverse(expr!((s + 3) ^ -1), |t| (-3.0 * t).exp());
+        // 1/(s (s + 1)²) = 1/s - 1/(s + 1) - 1/(s + 1)²
+        assert_inverse(expr!((s ^ -1) * ((s + 1) ^ -2)), |t| {
+            1.0 - (-t).exp() - t * (-t).exp()
+        });
+        // Expanded denominator: (s + 1)(s + 2)(s + 3)
+        let cubic = Expression::pow(
+            expr!((s ^ 3) + (6 * (s ^ 2)) + (11 * s) + 6),
+            Expression::integer(-1),
+        );
+        assert_inverse(cubic, |t| {
+            0.5 * (-t).exp() - (-2.0 * t).exp() + 0.5 * (-3.0 * t).exp()
+        });
+    }
+
+    #[test]
+    fn test_complex_pole_pairs() {
+        // (s + 1)/((s + 1)² + 4) = e^{-t} cos 2t
+        let damped = Expression::mul(vec![
+            expr!(s + 1),
+            Expression::pow(expr!((s ^ 2) + (2 * s) + 5), Expression::integer(-1)),
+        ]);
+        assert_inverse(damped, |t| (-t).exp() * (2.0 * t).cos());
+        // 1/(s (s² + 1)) = 1 - cos t
+        assert_inverse(
+            Expression::mul(vec![
+                expr!(s ^ -1),
+                Expression::pow(expr!((s ^ 2) + 1), Expression::integer(-1)),
+            ]),
+            |t| 1.0 - t.cos(),
+        );
+    }
+
+    #[test]
+    fn test_irrational_roots_stay_exact() {
+        // s² + 3s + 1 has roots (-3 ± √5)/2
+        let result = Expression::pow(expr!((s ^ 2) + (3 * s) + 1), Expression::integer(-1))
+            .inverse_laplace_transform(&symbol!(s), &symbol!(t));
+        assert!(!format!("{:?}", result).contains("Float"));
+        let r = 5f64.sqrt();
+        assert_inverse(
+            Expression::pow(expr!((s ^ 2) + (3 * s) + 1), Expression::integer(-1)),
+            move |t| (((-3.0 + r) / 2.0 * t).exp() - ((-3.0 - r) / 2.0 * t).exp()) / r,
+        );
+    }
+
+    #[test]
+    fn test_polynomial_part_and_delay() {
+        // (s + 2)/(s + 1) = 1 + 1/(s + 1)
+        let improper = Expression::mul(vec![expr!(s + 2), expr!((s + 1) ^ -1)]);
+        let (s, t) = (symbol!(s), symbol!(t));
+        assert_eq!(
+            improper.inverse_laplace_transform(&s, &t),
+            expr!(dirac_delta(t) + exp(-t)).simplify()
+        );
+        // e^{-2s}/s = H(t - 2)
+        assert_eq!(
+            expr!(exp(-2 * s) * (s ^ -1)).inverse_laplace_transform(&s, &t),
+            Expression::function("heaviside", vec![expr!(t - 2)])
+        );
+    }
+
+    #[test]
+    fn test_symbolic_poles_from_factored_denominator() {
+        let (s, t) = (symbol!(s), symbol!(t));
+        let result = expr!((s + a) ^ -1).inverse_laplace_transform(&s, &t);
+        assert_eq!(result, expr!(exp(-(a * t))).simplify());
+    }
+
+    #[test]
+    fn test_unsupported_is_left_unevaluated() {
+        let (s, t) = (symbol!(s), symbol!(t));
+        let result = expr!(ln(s)).inverse_laplace_transform(&s, &t);
+        assert!(matches!(
+            result,
+            Expression::Function { ref name, .. } if name.as_ref() == "inverse_laplace_transform"
+        ));
+    }
+}
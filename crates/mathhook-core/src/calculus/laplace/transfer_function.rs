@@ -0,0 +1,264 @@
+//! Transfer functions of linear time-invariant systems
+//!
+//! A transfer function `G(s) = N(s)/D(s)` is kept as a numerator and
+//! denominator polynomial in `s`. Block diagrams compose as
+//!
+//! - series: `G₁G₂`
+//! - parallel: `G₁ + G₂`
+//! - negative feedback: `G/(1 + GH)`
+//!
+//! Factors appearing in both numerator and denominator are cancelled, so a
+//! series connection of `(s + 1)/(s + 2)` and `1/(s + 1)` has a single pole.
+//! Time responses come from the inverse Laplace transform.
+
+use super::inverse::InverseLaplaceTransform;
+use crate::algebra::equation_analyzer::SmartEquationSolver;
+use crate::algebra::solvers::RootSet;
+use crate::algebra::Expand;
+use crate::calculus::integrals::rational::extract_numerator_denominator;
+use crate::core::{Expression, Number, Symbol};
+use crate::simplify::Simplify;
+use std::collections::HashMap;
+
+/// Ratio of polynomials in a frequency variable
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::calculus::laplace::TransferFunction;
+/// use mathhook_core::{expr, symbol};
+///
+/// let s = symbol!(s);
+/// // Plant 1/(s + 1) under unity negative feedback with gain 4
+/// let plant = TransferFunction::new(expr!(4), expr!(s + 1), s.clone());
+/// let closed = plant.feedback(&TransferFunction::new(expr!(1), expr!(1), s.clone()));
+///
+/// assert_eq!(closed.to_expression(), expr!(4 * ((s + 5) ^ -1)));
+/// assert_eq!(closed.poles().values(), vec![&expr!(-5)]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransferFunction {
+    numerator: Expression,
+    denominator: Expression,
+    variable: Symbol,
+}
+
+impl TransferFunction {
+    /// `numerator/denominator`, with common factors cancelled
+    pub fn new(numerator: Expression, denominator: Expression, variable: Symbol) -> Self {
+        let (numerator, denominator) = cancel(&numerator.simplify(), &denominator.simplify());
+        Self {
+            numerator,
+            denominator,
+            variable,
+        }
+    }
+
+    /// Split a rational expression such as `(s + 1) * (s + 2)^-1`
+    pub fn from_expression(expr: &Expression, variable: Symbol) -> Self {
+        let (numerator, denominator) = extract_numerator_denominator(&expr.simplify());
+        Self::new(numerator, denominator, variable)
+    }
+
+    pub fn numerator(&self) -> &Expression {
+        &self.numerator
+    }
+
+    pub fn denominator(&self) -> &Expression {
+        &self.denominator
+    }
+
+    pub fn variable(&self) -> &Symbol {
+        &self.variable
+    }
+
+    /// `N(s)/D(s)` as a single expression
+    pub fn to_expression(&self) -> Expression {
+        Expression::mul(vec![
+            self.numerator.clone(),
+            Expression::pow(self.denominator.clone(), Expression::integer(-1)),
+        ])
+        .simplify()
+    }
+
+    /// Series connection `G₁G₂`
+    pub fn series(&self, other: &TransferFunction) -> TransferFunction {
+        Self::new(
+            Expression::mul(vec![self.numerator.clone(), other.numerator.clone()]),
+            Expression::mul(vec![self.denominator.clone(), other.denominator.clone()]),
+            self.variable.clone(),
+        )
+    }
+
+    /// Parallel connection `G₁ + G₂`
+    pub fn parallel(&self, other: &TransferFunction) -> TransferFunction {
+        Self::new(
+            Expression::add(vec![
+                Expression::mul(vec![self.numerator.clone(), other.denominator.clone()]),
+                Expression::mul(vec![other.numerator.clone(), self.denominator.clone()]),
+            ]),
+            Expression::mul(vec![self.denominator.clone(), other.denominator.clone()]),
+            self.variable.clone(),
+        )
+    }
+
+    /// Negative feedback through `feedback_path` H: `G/(1 + GH)`
+    pub fn feedback(&self, feedback_path: &TransferFunction) -> TransferFunction {
+        Self::new(
+            Expression::mul(vec![
+                self.numerator.clone(),
+                feedback_path.denominator.clone(),
+            ]),
+            Expression::add(vec![
+                Expression::mul(vec![
+                    self.denominator.clone(),
+                    feedback_path.denominator.clone(),
+                ]),
+                Expression::mul(vec![
+                    self.numerator.clone(),
+                    feedback_path.numerator.clone(),
+                ]),
+            ]),
+            self.variable.clone(),
+        )
+    }
+
+    /// Roots of the denominator, with multiplicity
+    pub fn poles(&self) -> RootSet {
+        roots_of(&self.denominator, &self.variable)
+    }
+
+    /// Roots of the numerator, with multiplicity
+    pub fn zeros(&self) -> RootSet {
+        roots_of(&self.numerator, &self.variable)
+    }
+
+    /// Steady-state gain `G(0)`
+    pub fn dc_gain(&self) -> Expression {
+        self.to_expression()
+            .substitute(&HashMap::from([(
+                self.variable.name().to_owned(),
+                Expression::integer(0),
+            )]))
+            .simplify()
+    }
+
+    /// Response `g(t)` to a unit impulse, the inverse transform of `G(s)`
+    pub fn impulse_response(&self, t: &Symbol) -> Expression {
+        self.to_expression()
+            .inverse_laplace_transform(&self.variable, t)
+    }
+
+    /// Response to a unit step, the inverse transform of `G(s)/s`
+    pub fn step_response(&self, t: &Symbol) -> Expression {
+        let integrator = TransferFunction::new(
+            Expression::integer(1),
+            Expression::symbol(self.variable.clone()),
+            self.variable.clone(),
+        );
+        self.series(&integrator).impulse_response(t)
+    }
+}
+
+fn roots_of(polynomial: &Expression, variable: &Symbol) -> RootSet {
+    let expanded = polynomial.expand().simplify();
+    if !expanded.contains_variable(variable) {
+        return RootSet::default();
+    }
+    let (solutions, _) = SmartEquationSolver::new().solve_with_equation(&expanded, variable);
+    solutions.roots(&expanded, variable)
+}
+
+/// Cancel factors common to numerator and denominator, comparing bases and
+/// integer powers structurally
+fn cancel(numerator: &Expression, denominator: &Expression) -> (Expression, Expression) {
+    let mut upper = powers(numerator);
+    let mut lower = powers(denominator);
+    for (base, upper_power) in upper.iter_mut() {
+        if matches!(base, Expression::Number(_)) {
+            continue;
+        }
+        if let Some((_, lower_power)) = lower.iter_mut().find(|(other, _)| other == base) {
+            let common = (*upper_power).min(*lower_power);
+            *upper_power -= common;
+            *lower_power -= common;
+        }
+    }
+    let rebuild = |factors: Vec<(Expression, i64)>| {
+        Expression::mul(
+            factors
+                .into_iter()
+                .filter(|(_, power)| *power > 0)
+                .map(|(base, power)| Expression::pow(base, Expression::integer(power)))
+                .collect(),
+        )
+        .simplify()
+    };
+    (rebuild(upper), rebuild(lower))
+}
+
+fn powers(expr: &Expression) -> Vec<(Expression, i64)> {
+    let factors = match expr {
+        Expression::Mul(factors) => factors.to_vec(),
+        other => vec![other.clone()],
+    };
+    factors
+        .into_iter()
+        .map(|factor| match factor {
+            Expression::Pow(base, exponent) => match exponent.as_ref() {
+                Expression::Number(Number::Integer(power)) if *power > 0 => {
+                    (base.as_ref().clone(), *power)
+                }
+                _ => (Expression::Pow(base, exponent), 1),
+            },
+            other => (other, 1),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{expr, symbol};
+
+    fn tf(numerator: Expression, denominator: Expression) -> TransferFunction {
+        TransferFunction::new(numerator, denominator, symbol!(s))
+    }
+
+    #[test]
+    fn test_series_cancels_common_factors() {
+        let lead = tf(expr!(s + 1), expr!(s + 2));
+        let plant = tf(expr!(3), expr!(s + 1));
+        let combined = lead.series(&plant);
+        assert_eq!(combined, tf(expr!(3), expr!(s + 2)));
+        assert_eq!(combined.poles().len(), 1);
+        assert!(combined.zeros().is_empty());
+    }
+
+    #[test]
+    fn test_parallel_and_feedback() {
+        let a = tf(expr!(1), expr!(s + 1));
+        let b = tf(expr!(1), expr!(s + 2));
+        let sum = a.parallel(&b);
+        assert_eq!(sum.zeros().values(), vec![&Expression::rational(-3, 2)]);
+        assert_eq!(sum.poles().total_multiplicity(), 2);
+
+        // 1/s with feedback 1/(s + 1): (s + 1)/(s² + s + 1)
+        let integrator = tf(expr!(1), expr!(s));
+        let closed = integrator.feedback(&a);
+        assert_eq!(closed.dc_gain(), expr!(1));
+        assert_eq!(closed.poles().total_multiplicity(), 2);
+    }
+
+    #[test]
+    fn test_step_response_of_first_order_system() {
+        let (s, t) = (symbol!(s), symbol!(t));
+        let system = TransferFunction::from_expression(&expr!(2 * ((s + 2) ^ -1)), s);
+        assert_eq!(system.dc_gain(), expr!(1));
+        assert_eq!(
+            system.impulse_response(&t),
+            expr!(2 * exp(-2 * t)).simplify()
+        );
+        assert_eq!(system.step_response(&t), expr!(1 - exp(-2 * t)).simplify());
+    }
+}
@@ -7,10 +7,12 @@
 //! Preserves order for noncommutative expressions (matrices, operators, quaternions).
 pub mod educational;
 pub mod methods;
+pub mod sequence;
 
 use crate::core::polynomial::PolynomialProperties;
 use crate::core::{Expression, Number, Symbol};
 use methods::LimitMethods;
+pub use sequence::{limit_seq, SequenceLimit, SequenceLimitMethod};
 
 /// Direction for limit computation
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -145,9 +147,9 @@ impl Limits for Expression {
 
         match self {
             Expression::Mul(_factors) => LimitMethods::trigonometric_limit(self, variable, point),
-            Expression::Function { name: _, args: _ } => {
-                LimitMethods::trigonometric_limit(self, variable, point)
-            }
+            Expression::Function {
+                name: _, args: _, ..
+            } => LimitMethods::trigonometric_limit(self, variable, point),
             _ => Expression::function(
                 "limit",
                 vec![
@@ -188,7 +190,7 @@ impl Limits for Expression {
 
             Expression::Symbol(s) if s == variable => Expression::infinity(),
 
-            Expression::Function { name, args } => {
+            Expression::Function { name, args, .. } => {
                 // ln(x), log(x) → infinity
                 if (name.as_ref() == "ln" || name.as_ref() == "log") && args.len() == 1 {
                     if let Expression::Symbol(s) = &args[0] {
@@ -163,7 +163,7 @@ impl LimitMethods {
         if let Expression::Mul(factors) = expr {
             if factors.len() == 2 {
                 let check_sin_over_x = |(func_expr, pow_expr): (&Expression, &Expression)| -> bool {
-                    if let (Expression::Function { name, args }, Expression::Pow(base, exp)) =
+                    if let (Expression::Function { name, args, .. }, Expression::Pow(base, exp)) =
                         (func_expr, pow_expr)
                     {
                         name.as_ref() == "sin"
@@ -241,7 +241,7 @@ impl LimitMethods {
                 let sub_exp = Self::substitute_and_evaluate(exp, variable, point);
                 Expression::pow(sub_base, sub_exp).simplify()
             }
-            Expression::Function { name, args } => {
+            Expression::Function { name, args, .. } => {
                 let substituted_args: Vec<Expression> = args
                     .iter()
                     .map(|arg| Self::substitute_and_evaluate(arg, variable, point))
@@ -257,7 +257,7 @@ impl LimitMethods {
         let substituted = Self::substitute_and_evaluate(expr, variable, point);
 
         match &substituted {
-            Expression::Function { name, args: _ } if name.as_ref() == "undefined" => true,
+            Expression::Function { name, args: _, .. } if name.as_ref() == "undefined" => true,
             Expression::Mul(factors) if factors.len() == 2 => {
                 (factors[0].is_zero() && Self::is_infinite(&factors[1]))
                     || (factors[1].is_zero() && Self::is_infinite(&factors[0]))
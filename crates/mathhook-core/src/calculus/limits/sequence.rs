@@ -0,0 +1,711 @@
+//! Limits of sequences and discrete asymptotics
+//!
+//! [`limit_seq`] finds `lim a_n` as the integer `n → ∞` by reducing every term
+//! to the growth scale
+//!
+//! ```text
+//! c · n^(s·n) · bⁿ · n^p · (ln n)^q
+//! ```
+//!
+//! and comparing scales in that order: super-exponential growth beats
+//! exponential growth, which beats powers of `n`, which beat powers of
+//! `ln n`. Factorials and gamma functions are replaced by Stirling's
+//! approximation, so `n!`, `(2n)!` and `nⁿ` all land on the same scale.
+//! Quotients of partial sums `Σ_{k ≤ n} f(k) / y_n` are handled by the
+//! Stolz–Cesàro theorem. Anything else falls back to the continuous limit
+//! engine.
+//!
+//! Besides the limit, the result carries the dominant term `g(n)` with
+//! `a_n ~ g(n)` and an expansion `g(n) + O(h(n))` bounding what was dropped.
+
+use super::Limits;
+use crate::algebra::Expand;
+use crate::core::expression::CalculusData;
+use crate::core::polynomial::PolynomialProperties;
+use crate::core::{Expression, Symbol};
+use crate::simplify::Simplify;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt;
+
+/// Tolerance when comparing numeric exponents and bases
+const TOLERANCE: f64 = 1e-12;
+
+/// How [`limit_seq`] found the limit
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SequenceLimitMethod {
+    /// Comparing the growth of the terms
+    DominantTerm,
+    /// Comparing growth after Stirling's approximation of factorials
+    Stirling,
+    /// Stolz–Cesàro on a quotient of a partial sum
+    StolzCesaro,
+    /// The continuous limit engine, treating `n` as real
+    Continuous,
+}
+
+impl fmt::Display for SequenceLimitMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::DominantTerm => "dominant term",
+            Self::Stirling => "Stirling's approximation",
+            Self::StolzCesaro => "Stolz–Cesàro theorem",
+            Self::Continuous => "continuous limit",
+        })
+    }
+}
+
+/// Result of [`limit_seq`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct SequenceLimit {
+    /// The limit: a value, `±∞`, `undefined` for an oscillating sequence, or
+    /// an unevaluated `limit(...)` when nothing applies
+    pub limit: Expression,
+    /// Dominant term `g(n)` with `a_n / g(n) → 1`
+    pub asymptotic: Option<Expression>,
+    /// `g(n) + O(h(n))`, or `g(n)` alone when nothing was dropped
+    pub expansion: Option<Expression>,
+    pub method: SequenceLimitMethod,
+}
+
+/// Limit of the sequence `expr` as the integer `n → ∞`
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::calculus::limits::{limit_seq, SequenceLimitMethod};
+/// use mathhook_core::{symbol, Expression};
+///
+/// let n = symbol!(n);
+/// // n! / nⁿ → 0
+/// let term = Expression::mul(vec![
+///     Expression::function("factorial", vec![Expression::symbol(n.clone())]),
+///     Expression::pow(
+///         Expression::symbol(n.clone()),
+///         Expression::mul(vec![Expression::integer(-1), Expression::symbol(n.clone())]),
+///     ),
+/// ]);
+/// let result = limit_seq(&term, &n);
+/// assert_eq!(result.limit, Expression::integer(0));
+/// assert_eq!(result.method, SequenceLimitMethod::Stirling);
+/// ```
+pub fn limit_seq(expr: &Expression, n: &Symbol) -> SequenceLimit {
+    let expr = expr.simplify();
+    let mut analysis = Analysis {
+        n,
+        used_stirling: false,
+    };
+    if let Some(asymptotic) = analysis.asymptotic(&expr) {
+        if let Some(limit) = asymptotic.leading.limit() {
+            let method = if analysis.used_stirling {
+                SequenceLimitMethod::Stirling
+            } else {
+                SequenceLimitMethod::DominantTerm
+            };
+            return SequenceLimit {
+                limit,
+                asymptotic: Some(asymptotic.leading.to_expression(n)),
+                expansion: Some(asymptotic.expansion(n)),
+                method,
+            };
+        }
+    }
+    if let Some(result) = stolz_cesaro(&expr, n) {
+        return result;
+    }
+    SequenceLimit {
+        limit: expr.limit_at_infinity(n),
+        asymptotic: None,
+        expansion: None,
+        method: SequenceLimitMethod::Continuous,
+    }
+}
+
+/// `lim x_n / y_n = lim (x_{n+1} - x_n) / (y_{n+1} - y_n)` for a partial sum
+/// `x_n = c · Σ_{k=a}^{n} f(k)` over an increasing, unbounded `y_n`
+fn stolz_cesaro(expr: &Expression, n: &Symbol) -> Option<SequenceLimit> {
+    let Expression::Mul(factors) = expr else {
+        return None;
+    };
+    let mut constant = Vec::new();
+    let mut denominator = Vec::new();
+    let mut increment = None;
+    for factor in factors.iter() {
+        match factor {
+            Expression::Calculus(data) => match data.as_ref() {
+                CalculusData::Sum {
+                    expression,
+                    variable,
+                    end,
+                    ..
+                } if increment.is_none() && *end == Expression::symbol(n.clone()) => {
+                    let next = Expression::symbol(n.clone()) + Expression::integer(1);
+                    let substitutions = HashMap::from([(variable.name().to_owned(), next)]);
+                    increment = Some(expression.substitute(&substitutions));
+                }
+                _ => return None,
+            },
+            Expression::Pow(base, exp) if is_negative(exp) => {
+                denominator.push(Expression::pow(
+                    base.as_ref().clone(),
+                    Expression::mul(vec![Expression::integer(-1), exp.as_ref().clone()]),
+                ));
+            }
+            _ if !factor.contains_variable(n) => constant.push(factor.clone()),
+            _ => return None,
+        }
+    }
+    let denominator = Expression::mul(denominator).simplify();
+
+    let mut analysis = Analysis {
+        n,
+        used_stirling: false,
+    };
+    let growth = analysis.asymptotic(&denominator)?.leading;
+    if growth.limit()? != Expression::infinity() {
+        return None;
+    }
+    let next = Expression::symbol(n.clone()) + Expression::integer(1);
+    let shifted = denominator.substitute(&HashMap::from([(n.name().to_owned(), next)]));
+    let difference = (shifted - denominator).expand().simplify();
+
+    constant.push(increment?);
+    constant.push(Expression::pow(difference, Expression::integer(-1)));
+    let ratio = analysis.asymptotic(&Expression::mul(constant).simplify())?;
+    // Stolz–Cesàro transfers the limit but not the rate
+    Some(SequenceLimit {
+        limit: ratio.leading.limit()?,
+        asymptotic: None,
+        expansion: None,
+        method: SequenceLimitMethod::StolzCesaro,
+    })
+}
+
+/// `n^(s·n) · bⁿ · n^p · (ln n)^q`, the growth of a term without its coefficient
+#[derive(Debug, Clone, PartialEq)]
+struct Scale {
+    super_exponent: Expression,
+    base: Expression,
+    power: Expression,
+    log_power: Expression,
+}
+
+impl Scale {
+    fn one() -> Self {
+        Self::new(0, 1, 0, 0)
+    }
+
+    fn new(super_exponent: i64, base: i64, power: i64, log_power: i64) -> Self {
+        Self {
+            super_exponent: Expression::integer(super_exponent),
+            base: Expression::integer(base),
+            power: Expression::integer(power),
+            log_power: Expression::integer(log_power),
+        }
+    }
+
+    fn mul(&self, other: &Scale) -> Self {
+        Self {
+            super_exponent: sum(&self.super_exponent, &other.super_exponent),
+            base: (self.base.clone() * other.base.clone()).simplify(),
+            power: sum(&self.power, &other.power),
+            log_power: sum(&self.log_power, &other.log_power),
+        }
+    }
+
+    fn pow(&self, k: &Expression) -> Self {
+        let scale = |e: &Expression| (e.clone() * k.clone()).simplify();
+        Self {
+            super_exponent: scale(&self.super_exponent),
+            base: Expression::pow(self.base.clone(), k.clone()).simplify(),
+            power: scale(&self.power),
+            log_power: scale(&self.log_power),
+        }
+    }
+
+    fn recip(&self) -> Self {
+        self.pow(&Expression::integer(-1))
+    }
+
+    /// Order of growth, comparing `|b|` for the exponential part
+    fn compare(&self, other: &Scale) -> Option<Ordering> {
+        let pairs = [
+            (
+                numeric(&self.super_exponent)?,
+                numeric(&other.super_exponent)?,
+            ),
+            (numeric(&self.base)?.abs(), numeric(&other.base)?.abs()),
+            (numeric(&self.power)?, numeric(&other.power)?),
+            (numeric(&self.log_power)?, numeric(&other.log_power)?),
+        ];
+        for (a, b) in pairs {
+            if (a - b).abs() > TOLERANCE {
+                return a.partial_cmp(&b);
+            }
+        }
+        Some(Ordering::Equal)
+    }
+
+    /// Same growth and the same sign of the exponential base
+    fn matches(&self, other: &Scale) -> bool {
+        self.compare(other) == Some(Ordering::Equal)
+            && numeric(&self.base).map(f64::signum) == numeric(&other.base).map(f64::signum)
+    }
+
+    /// Whether the base is negative, so that the sign alternates
+    fn alternates(&self) -> Option<bool> {
+        Some(numeric(&self.base)? < 0.0)
+    }
+
+    fn to_expression(&self, n: &Symbol) -> Expression {
+        let n_expr = Expression::symbol(n.clone());
+        let mut factors = Vec::new();
+        if !self.super_exponent.is_zero() {
+            factors.push(Expression::pow(
+                n_expr.clone(),
+                self.super_exponent.clone() * n_expr.clone(),
+            ));
+        }
+        if self.base != Expression::integer(1) {
+            factors.push(Expression::pow(self.base.clone(), n_expr.clone()));
+        }
+        if !self.power.is_zero() {
+            factors.push(Expression::pow(n_expr.clone(), self.power.clone()));
+        }
+        if !self.log_power.is_zero() {
+            factors.push(Expression::pow(
+                Expression::function("ln", vec![n_expr]),
+                self.log_power.clone(),
+            ));
+        }
+        match factors.len() {
+            0 => Expression::integer(1),
+            1 => factors.remove(0),
+            _ => Expression::mul(factors),
+        }
+    }
+}
+
+/// `c · scale`
+#[derive(Debug, Clone, PartialEq)]
+struct Term {
+    coefficient: Expression,
+    scale: Scale,
+}
+
+impl Term {
+    /// Limit of the term, `None` when the sign of `c` is unknown
+    fn limit(&self) -> Option<Expression> {
+        if self.coefficient.is_zero() {
+            return Some(Expression::integer(0));
+        }
+        let one = Scale::one();
+        match self.scale.compare(&one)? {
+            Ordering::Less => Some(Expression::integer(0)),
+            _ if self.scale.alternates()? => Some(Expression::undefined()),
+            Ordering::Equal => Some(self.coefficient.clone()),
+            Ordering::Greater => Some(if numeric(&self.coefficient)? > 0.0 {
+                Expression::infinity()
+            } else {
+                Expression::negative_infinity()
+            }),
+        }
+    }
+
+    fn to_expression(&self, n: &Symbol) -> Expression {
+        (self.coefficient.clone() * self.scale.to_expression(n)).simplify()
+    }
+}
+
+/// `a_n = leading + O(error)`, with `error = None` when nothing was dropped
+#[derive(Debug, Clone, PartialEq)]
+struct Asymptotic {
+    leading: Term,
+    error: Option<Scale>,
+}
+
+impl Asymptotic {
+    fn exact(coefficient: Expression, scale: Scale) -> Self {
+        Self {
+            leading: Term { coefficient, scale },
+            error: None,
+        }
+    }
+
+    /// `error / leading`, which tends to zero
+    fn relative_error(&self) -> Option<Scale> {
+        self.error
+            .as_ref()
+            .map(|error| error.mul(&self.leading.scale.recip()))
+    }
+
+    fn expansion(&self, n: &Symbol) -> Expression {
+        let leading = self.leading.to_expression(n);
+        match &self.error {
+            Some(error) => Expression::add(vec![
+                leading,
+                Expression::function("O", vec![error.to_expression(n).simplify()]),
+            ]),
+            None => leading,
+        }
+    }
+
+    fn mul(&self, other: &Asymptotic) -> Option<Self> {
+        let coefficient =
+            (self.leading.coefficient.clone() * other.leading.coefficient.clone()).simplify();
+        let scale = self.leading.scale.mul(&other.leading.scale);
+        let relative = larger(self.relative_error(), other.relative_error())?;
+        Some(Self {
+            error: relative.map(|relative| relative.mul(&scale)),
+            leading: Term { coefficient, scale },
+        })
+    }
+
+    /// `a^k` for `k` free of `n`
+    fn pow(&self, k: &Expression) -> Option<Self> {
+        let coefficient = &self.leading.coefficient;
+        let integral = numeric(k).is_some_and(|k| k.fract() == 0.0);
+        if !integral && numeric(coefficient).is_none_or(|c| c < 0.0) {
+            return None;
+        }
+        let scale = self.leading.scale.pow(k);
+        Some(Self {
+            error: self.relative_error().map(|relative| relative.mul(&scale)),
+            leading: Term {
+                coefficient: Expression::pow(coefficient.clone(), k.clone()).simplify(),
+                scale,
+            },
+        })
+    }
+
+    /// Keep the largest group of matching terms, and bound everything else
+    fn sum(parts: Vec<Asymptotic>) -> Option<Self> {
+        let mut groups: Vec<Term> = Vec::new();
+        let mut errors = Vec::new();
+        for part in parts {
+            errors.extend(part.error);
+            match groups
+                .iter_mut()
+                .find(|group| group.scale.matches(&part.leading.scale))
+            {
+                Some(group) => {
+                    group.coefficient = sum(&group.coefficient, &part.leading.coefficient);
+                }
+                None => groups.push(part.leading),
+            }
+        }
+        groups.retain(|group| !group.coefficient.is_zero());
+
+        let mut leading: Option<Term> = None;
+        for group in groups {
+            match &leading {
+                Some(current) if group.scale.compare(&current.scale)? != Ordering::Greater => {
+                    errors.push(group.scale);
+                }
+                _ => {
+                    if let Some(previous) = leading.replace(group) {
+                        errors.push(previous.scale);
+                    }
+                }
+            }
+        }
+        let Some(leading) = leading else {
+            return errors
+                .is_empty()
+                .then(|| Self::exact(Expression::integer(0), Scale::one()));
+        };
+        let mut error: Option<Scale> = None;
+        for candidate in errors {
+            if candidate.compare(&leading.scale)? != Ordering::Less {
+                return None;
+            }
+            error = larger(error, Some(candidate))?;
+        }
+        Some(Self { leading, error })
+    }
+}
+
+/// The larger of two optional scales, `None` inside when both are absent
+fn larger(a: Option<Scale>, b: Option<Scale>) -> Option<Option<Scale>> {
+    Some(match (a, b) {
+        (Some(a), Some(b)) => Some(if a.compare(&b)? == Ordering::Less {
+            b
+        } else {
+            a
+        }),
+        (a, b) => a.or(b),
+    })
+}
+
+struct Analysis<'a> {
+    n: &'a Symbol,
+    used_stirling: bool,
+}
+
+impl Analysis<'_> {
+    fn asymptotic(&mut self, expr: &Expression) -> Option<Asymptotic> {
+        if !expr.contains_variable(self.n) {
+            return Some(Asymptotic::exact(expr.clone(), Scale::one()));
+        }
+        match expr {
+            Expression::Symbol(_) => Some(Asymptotic::exact(
+                Expression::integer(1),
+                Scale::new(0, 1, 1, 0),
+            )),
+            Expression::Add(terms) => Asymptotic::sum(
+                terms
+                    .iter()
+                    .map(|term| self.asymptotic(term))
+                    .collect::<Option<_>>()?,
+            ),
+            Expression::Mul(factors) => {
+                let mut product = Asymptotic::exact(Expression::integer(1), Scale::one());
+                for factor in factors.iter() {
+                    product = product.mul(&self.asymptotic(factor)?)?;
+                }
+                Some(product)
+            }
+            Expression::Pow(base, exp) if !exp.contains_variable(self.n) => {
+                self.asymptotic(base)?.pow(exp)
+            }
+            Expression::Pow(base, exp) => self.power_with_variable_exponent(base, exp),
+            Expression::Function { name, args, .. } if args.len() == 1 => {
+                let arg = &args[0];
+                match name.as_ref() {
+                    "sqrt" => self.asymptotic(arg)?.pow(&Expression::rational(1, 2)),
+                    "exp" => self.exponential(&Expression::e(), arg),
+                    "ln" | "log" => self.logarithm(arg),
+                    "factorial" => self.stirling(arg),
+                    "gamma" => self.stirling(&(arg.clone() - Expression::integer(1)).simplify()),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// `α n + β` as `(α, β)`
+    fn linear(&self, expr: &Expression) -> Option<(Expression, Expression)> {
+        if expr.degree(self.n)? != 1 {
+            return None;
+        }
+        let alpha = expr.leading_coefficient(self.n);
+        let beta = (expr.clone() - alpha.clone() * Expression::symbol(self.n.clone())).simplify();
+        (!beta.contains_variable(self.n)).then_some((alpha, beta))
+    }
+
+    /// `base^(α n + β) = base^β · (base^α)ⁿ` for a constant base
+    fn exponential(&self, base: &Expression, exp: &Expression) -> Option<Asymptotic> {
+        let (alpha, beta) = self.linear(exp)?;
+        let scale = Scale {
+            base: Expression::pow(base.clone(), alpha).simplify(),
+            ..Scale::one()
+        };
+        Some(Asymptotic::exact(
+            Expression::pow(base.clone(), beta).simplify(),
+            scale,
+        ))
+    }
+
+    /// `base^exp` with `exp` depending on `n`: a constant base, or an exact
+    /// `c n^p` raised to `α n + β`
+    fn power_with_variable_exponent(
+        &mut self,
+        base: &Expression,
+        exp: &Expression,
+    ) -> Option<Asymptotic> {
+        if !base.contains_variable(self.n) {
+            return self.exponential(base, exp);
+        }
+        let base = self.asymptotic(base)?;
+        let scale = &base.leading.scale;
+        let monomial = Scale {
+            power: scale.power.clone(),
+            ..Scale::one()
+        };
+        if base.error.is_some() || *scale != monomial {
+            return None;
+        }
+        let (alpha, beta) = self.linear(exp)?;
+        let c = &base.leading.coefficient;
+        let p = &scale.power;
+        Some(Asymptotic::exact(
+            Expression::pow(c.clone(), beta.clone()).simplify(),
+            Scale {
+                super_exponent: (p.clone() * alpha.clone()).simplify(),
+                base: Expression::pow(c.clone(), alpha).simplify(),
+                power: (p.clone() * beta).simplify(),
+                log_power: Expression::integer(0),
+            },
+        ))
+    }
+
+    /// `ln a_n = s n ln n + n ln b + p ln n + q ln ln n + ln c + O(a_n's relative error)`
+    fn logarithm(&mut self, arg: &Expression) -> Option<Asymptotic> {
+        let inner = self.asymptotic(arg)?;
+        let Term { coefficient, scale } = &inner.leading;
+        if numeric(coefficient)? <= 0.0 || numeric(&scale.base)? <= 0.0 {
+            return None;
+        }
+        let ln = |e: &Expression| Expression::function("ln", vec![e.clone()]).simplify();
+        let mut parts = vec![
+            Asymptotic::exact(scale.super_exponent.clone(), Scale::new(0, 1, 1, 1)),
+            Asymptotic::exact(ln(&scale.base), Scale::new(0, 1, 1, 0)),
+            Asymptotic::exact(scale.power.clone(), Scale::new(0, 1, 0, 1)),
+            Asymptotic::exact(ln(coefficient), Scale::one()),
+        ];
+        if !scale.log_power.is_zero() {
+            // ln ln n has no scale of its own; O(ln n) bounds it
+            parts.push(Asymptotic {
+                leading: Term {
+                    coefficient: Expression::integer(0),
+                    scale: Scale::one(),
+                },
+                error: Some(Scale::new(0, 1, 0, 1)),
+            });
+        }
+        if let Some(relative) = inner.relative_error() {
+            parts.push(Asymptotic {
+                leading: Term {
+                    coefficient: Expression::integer(0),
+                    scale: Scale::one(),
+                },
+                error: Some(relative),
+            });
+        }
+        Asymptotic::sum(parts)
+    }
+
+    /// `(α n + β)! ~ √(2π) α^(β+½) · n^(α n) · (α/e)^(α n) · n^(β+½)`
+    fn stirling(&mut self, arg: &Expression) -> Option<Asymptotic> {
+        let (alpha, beta) = self.linear(arg)?;
+        if numeric(&alpha)? <= 0.0 {
+            return None;
+        }
+        self.used_stirling = true;
+        let half = Expression::rational(1, 2);
+        let coefficient = Expression::mul(vec![
+            Expression::sqrt(Expression::integer(2) * Expression::pi()),
+            Expression::pow(alpha.clone(), sum(&beta, &half)),
+        ])
+        .simplify();
+        let scale = Scale {
+            super_exponent: alpha.clone(),
+            base: Expression::pow(
+                Expression::mul(vec![
+                    alpha.clone(),
+                    Expression::pow(Expression::e(), Expression::integer(-1)),
+                ]),
+                alpha,
+            )
+            .simplify(),
+            power: sum(&beta, &half),
+            log_power: Expression::integer(0),
+        };
+        // Relative error O(1/n)
+        let error = Scale::new(0, 1, -1, 0).mul(&scale);
+        Some(Asymptotic {
+            leading: Term { coefficient, scale },
+            error: Some(error),
+        })
+    }
+}
+
+fn sum(a: &Expression, b: &Expression) -> Expression {
+    (a.clone() + b.clone()).simplify()
+}
+
+fn numeric(expr: &Expression) -> Option<f64> {
+    expr.evaluate_to_f64()
+        .ok()
+        .filter(|value| value.is_finite())
+}
+
+fn is_negative(expr: &Expression) -> bool {
+    numeric(expr).is_some_and(|value| value < 0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{expr, symbol};
+
+    fn factorial(arg: Expression) -> Expression {
+        Expression::function("factorial", vec![arg])
+    }
+
+    #[test]
+    fn test_rational_sequence_with_rate() {
+        let n = symbol!(n);
+        let result = limit_seq(&expr!(((n ^ 2) + 1) / ((3 * (n ^ 2)) - n)), &n);
+        assert_eq!(result.limit, Expression::rational(1, 3));
+        assert_eq!(result.method, SequenceLimitMethod::DominantTerm);
+        assert_eq!(
+            result.expansion,
+            Some(Expression::add(vec![
+                Expression::rational(1, 3),
+                Expression::function("O", vec![expr!(n ^ (-1))]),
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_growth_comparisons() {
+        let n = symbol!(n);
+        let exponential_over_power = Expression::mul(vec![
+            Expression::pow(expr!(2), expr!(n)),
+            Expression::pow(expr!(n), expr!(-10)),
+        ]);
+        assert_eq!(
+            limit_seq(&exponential_over_power, &n).limit,
+            Expression::infinity()
+        );
+
+        let exponential_over_factorial = Expression::mul(vec![
+            Expression::pow(expr!(100), expr!(n)),
+            Expression::pow(factorial(expr!(n)), expr!(-1)),
+        ]);
+        let result = limit_seq(&exponential_over_factorial, &n);
+        assert_eq!(result.limit, Expression::integer(0));
+        assert_eq!(result.method, SequenceLimitMethod::Stirling);
+
+        let oscillating = Expression::pow(expr!(-1), expr!(n));
+        assert_eq!(limit_seq(&oscillating, &n).limit, Expression::undefined());
+        let damped = Expression::mul(vec![oscillating, expr!(n ^ (-1))]);
+        assert_eq!(limit_seq(&damped, &n).limit, Expression::integer(0));
+    }
+
+    #[test]
+    fn test_stirling_asymptotics() {
+        let n = symbol!(n);
+        // (2n)! / (n!)² ~ 4ⁿ / √(π n)
+        let central = Expression::mul(vec![
+            factorial(expr!(2 * n)),
+            Expression::pow(factorial(expr!(n)), expr!(-2)),
+        ]);
+        let result = limit_seq(&central, &n);
+        assert_eq!(result.limit, Expression::infinity());
+        let asymptotic = result.asymptotic.unwrap();
+        let ratio = asymptotic.substitute(&HashMap::from([("n".to_owned(), expr!(50))]));
+        let expected = 4f64.powi(50) / (std::f64::consts::PI * 50.0).sqrt();
+        assert!((ratio.evaluate_to_f64().unwrap() / expected - 1.0).abs() < 1e-9);
+
+        // ln(n!) / (n ln n) → 1
+        let log_factorial = Expression::mul(vec![
+            Expression::function("ln", vec![factorial(expr!(n))]),
+            Expression::pow(expr!(n * ln(n)), expr!(-1)),
+        ]);
+        assert_eq!(limit_seq(&log_factorial, &n).limit, Expression::integer(1));
+    }
+
+    #[test]
+    fn test_stolz_cesaro() {
+        let n = symbol!(n);
+        let k = symbol!(k);
+        let partial_sum = Expression::sum(expr!(k ^ 2), k, expr!(1), expr!(n));
+        let average = Expression::mul(vec![partial_sum, expr!(n ^ (-3))]);
+        let result = limit_seq(&average, &n);
+        assert_eq!(result.limit, Expression::rational(1, 3));
+        assert_eq!(result.method, SequenceLimitMethod::StolzCesaro);
+    }
+}
@@ -177,6 +177,7 @@ impl ConstantCoeffSecondOrderSolver {
                 Number::Float(f) => *f < 0.0,
                 Number::BigInteger(bi) => bi.sign() == Sign::Minus,
                 Number::Rational(r) => r.numer().sign() == Sign::Minus,
+                Number::Decimal(d) => d.is_negative(),
             };
 
             if is_negative {
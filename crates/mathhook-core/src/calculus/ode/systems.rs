@@ -5,6 +5,10 @@
 //!
 //! Uses eigenvalue-eigenvector method for diagonalizable systems:
 //! x(t) = c₁e^(λ₁t)v₁ + c₂e^(λ₂t)v₂ + ... + cₙe^(λₙt)vₙ
+//!
+//! When an eigenvalue is repeated the matrix exponential is used instead:
+//! x(t) = e^(At)c, which also covers defective matrices through terms like
+//! t·e^(λt) from the Jordan form of A.
 
 use crate::algebra::solvers::{linear::LinearSolver, EquationSolver, SolverResult};
 use crate::calculus::ode::first_order::ODEError;
@@ -71,10 +75,20 @@ impl LinearSystemSolver {
 
         let n = rows;
 
-        if !coefficient_matrix.is_diagonalizable() {
-            return Err(ODEError::NotImplemented {
-                feature: "Non-diagonalizable systems (requires Jordan normal form)".to_owned(),
+        let repeated = coefficient_matrix
+            .exact_eigenvalues()
+            .is_some_and(|eigenvalues| {
+                eigenvalues
+                    .iter()
+                    .any(|(_, multiplicity)| *multiplicity > 1)
             });
+        if repeated || !coefficient_matrix.is_diagonalizable() {
+            return self.solve_with_exponential(
+                coefficient_matrix,
+                independent_var,
+                initial_conditions,
+                n,
+            );
         }
 
         let eigen_decomp =
@@ -130,19 +144,53 @@ impl LinearSystemSolver {
         Ok(final_solution)
     }
 
-    /// Apply initial conditions to solve for integration constants
+    /// Solve dx/dt = Ax as x(t) = e^(At)c
     ///
-    /// Solves the linear system V*c = y₀ where:
-    /// - V is the eigenvector matrix
-    /// - c is the vector of constants [C1, C2, ..., Cn]
-    /// - y₀ is the initial condition vector
-    fn apply_initial_conditions(
+    /// With initial conditions c = x(0), otherwise c = [C1, ..., Cn].
+    fn solve_with_exponential(
         &self,
-        general_solution: &[Expression],
-        initial_conditions: &[Expression],
+        coefficient_matrix: &Matrix,
+        independent_var: &Symbol,
+        initial_conditions: Option<Vec<Expression>>,
         n: usize,
-        eigenvectors: &Matrix,
     ) -> Result<Vec<Expression>, ODEError> {
+        let exponential = coefficient_matrix
+            .exp_scaled(&Expression::symbol(independent_var.clone()))
+            .ok_or_else(|| ODEError::NotImplemented {
+                feature: "Matrix exponential for matrices without exact eigenvalues".to_owned(),
+            })?;
+
+        let constants = match initial_conditions {
+            Some(ic) => {
+                self.check_initial_conditions(&ic, n)?;
+                ic
+            }
+            None => (1..=n)
+                .map(|i| Expression::symbol(Symbol::new(format!("C{}", i))))
+                .collect(),
+        };
+
+        Ok((0..n)
+            .map(|i| {
+                Expression::add(
+                    constants
+                        .iter()
+                        .enumerate()
+                        .map(|(j, c)| {
+                            Expression::mul(vec![exponential.get_element(i, j), c.clone()])
+                        })
+                        .collect(),
+                )
+                .simplify()
+            })
+            .collect())
+    }
+
+    fn check_initial_conditions(
+        &self,
+        initial_conditions: &[Expression],
+        n: usize,
+    ) -> Result<(), ODEError> {
         if initial_conditions.len() != n {
             return Err(ODEError::NotLinearForm {
                 reason: format!(
@@ -152,6 +200,23 @@ impl LinearSystemSolver {
                 ),
             });
         }
+        Ok(())
+    }
+
+    /// Apply initial conditions to solve for integration constants
+    ///
+    /// Solves the linear system V*c = y₀ where:
+    /// - V is the eigenvector matrix
+    /// - c is the vector of constants [C1, C2, ..., Cn]
+    /// - y₀ is the initial condition vector
+    fn apply_initial_conditions(
+        &self,
+        general_solution: &[Expression],
+        initial_conditions: &[Expression],
+        n: usize,
+        eigenvectors: &Matrix,
+    ) -> Result<Vec<Expression>, ODEError> {
+        self.check_initial_conditions(initial_conditions, n)?;
 
         let linear_solver = LinearSolver::new_fast();
         let mut constant_values: HashMap<String, Expression> = HashMap::new();
@@ -254,6 +319,12 @@ impl LinearSystemSolver {
                     constant_name
                 ),
             }),
+            SolverResult::Cases(_) => Err(ODEError::NotLinearForm {
+                reason: format!(
+                    "Integration constant {} depends on conditions on the parameters",
+                    constant_name
+                ),
+            }),
         }
     }
 
@@ -372,6 +443,7 @@ impl LinearSystemSolver {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::calculus::derivatives::Derivative;
     use crate::{expr, symbol};
 
     #[test]
@@ -474,6 +546,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_repeated_eigenvalue_system() {
+        // dx/dt = [3 1; -1 1]x has eigenvalue 2 twice and one eigenvector
+        let t = symbol!(t);
+        let matrix = Matrix::dense(vec![vec![expr!(3), expr!(1)], vec![expr!(-1), expr!(1)]]);
+        let solver = LinearSystemSolver;
+        let solution = solver
+            .solve(&matrix, &t, Some(vec![expr!(1), expr!(0)]))
+            .unwrap();
+
+        let mut t_subs = HashMap::new();
+        t_subs.insert(t.name().to_string(), expr!(0));
+        assert_eq!(solution[0].substitute(&t_subs).simplify(), expr!(1));
+        assert_eq!(solution[1].substitute(&t_subs).simplify(), expr!(0));
+
+        // x' = 3x + y at t = 0
+        let derivative = solution[0].derivative(t.clone());
+        let residual = Expression::add(vec![
+            derivative,
+            Expression::mul(vec![expr!(-3), solution[0].clone()]),
+            Expression::mul(vec![expr!(-1), solution[1].clone()]),
+        ]);
+        assert_eq!(residual.substitute(&t_subs).simplify(), expr!(0));
+
+        let general = solver.solve(&matrix, &t, None).unwrap();
+        assert!(general[0].find_variables().iter().any(|s| s.name() == "C2"));
+    }
+
     #[test]
     fn test_wrong_size_initial_conditions() {
         let t = symbol!(t);
@@ -15,6 +15,7 @@ mod singularities;
 
 use crate::calculus::derivatives::Derivative;
 use crate::core::{Expression, Symbol};
+use crate::functions::special::factorial::factorial;
 use crate::simplify::Simplify;
 
 use pole_finding::{find_rational_poles, find_transcendental_poles};
@@ -196,11 +197,7 @@ impl ResidueMethods {
     ///
     /// Expression representing n!
     pub fn factorial(n: u32) -> Expression {
-        let result = match n {
-            0 | 1 => 1,
-            _ => (2..=n as i64).product(),
-        };
-        Expression::integer(result)
+        factorial(&Expression::integer(i64::from(n)))
     }
 }
 
@@ -263,7 +260,9 @@ impl ResidueCalculus for Expression {
                 vec![]
             }
             // Transcendental functions with known poles
-            Expression::Function { name, args } => find_transcendental_poles(name, args, variable),
+            Expression::Function { name, args, .. } => {
+                find_transcendental_poles(name, args, variable)
+            }
             _ => vec![],
         }
     }
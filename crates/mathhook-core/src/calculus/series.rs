@@ -10,6 +10,7 @@
 
 use crate::calculus::derivatives::Derivative;
 use crate::core::{Expression, Symbol};
+use crate::functions::special::factorial::factorial;
 use crate::simplify::Simplify;
 
 /// Types of series expansions
@@ -103,15 +104,7 @@ pub struct SeriesMethods;
 impl SeriesMethods {
     /// Compute factorial
     pub fn factorial(n: u32) -> Expression {
-        if n == 0 || n == 1 {
-            Expression::integer(1)
-        } else {
-            let mut result = 1i64;
-            for i in 2..=n {
-                result *= i as i64;
-            }
-            Expression::integer(result)
-        }
+        factorial(&Expression::integer(i64::from(n)))
     }
 
     /// Compute binomial coefficient
@@ -306,7 +299,7 @@ impl SeriesMethods {
                 let eval_exp = Self::evaluate_at_point(exp, variable, point);
                 Expression::pow(eval_base, eval_exp).simplify()
             }
-            Expression::Function { name, args } => {
+            Expression::Function { name, args, .. } => {
                 let evaluated_args: Vec<Expression> = args
                     .iter()
                     .map(|arg| Self::evaluate_at_point(arg, variable, point))
@@ -321,7 +314,7 @@ impl SeriesMethods {
 impl SeriesExpansion for Expression {
     fn taylor_series(&self, variable: &Symbol, point: &Expression, order: u32) -> Expression {
         // Try known series first
-        if let Expression::Function { name, args } = self {
+        if let Expression::Function { name, args, .. } = self {
             if args.len() == 1 {
                 if let Expression::Symbol(sym) = &args[0] {
                     if sym == variable {
@@ -34,8 +34,11 @@ use crate::core::{Expression, Number, Symbol};
 use crate::expr;
 use crate::simplify::Simplify;
 
+pub mod convergence;
 pub mod educational;
 
+pub use convergence::{series_convergence, ConvergenceTest, SeriesConvergence};
+
 /// Trait for summation and product operations
 pub trait Summation {
     /// Compute finite sum
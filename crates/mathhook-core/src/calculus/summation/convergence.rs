@@ -0,0 +1,1024 @@
+//! Convergence tests for infinite series with step-by-step justification
+//!
+//! [`series_convergence`] decides whether `Σ a_n` converges by running the
+//! standard Calc II tests in order: the n-th term test, the alternating series
+//! test, limit comparison with a p-series, the ratio test, the root test and
+//! the integral test. Every test that is tried leaves a step explaining what
+//! was computed and why it does or does not settle the question.
+//!
+//! Limits at infinity are taken with [`Limits::limit_at_infinity`] first,
+//! then by combining the limits of subexpressions, and finally estimated from
+//! the terms at large `n`. Ratio and root limits within `1e-3` of `1` are
+//! treated as equal to `1`, so a numerical estimate never decides those tests.
+
+use crate::calculus::derivatives::Derivative;
+use crate::calculus::integrals::Integration;
+use crate::calculus::limits::Limits;
+use crate::calculus::summation::ConvergenceResult;
+use crate::core::expression::compiled::CompiledExpression;
+use crate::core::{Expression, Number, Symbol};
+use crate::educational::step_by_step::{Step, StepByStepExplanation};
+use crate::simplify::Simplify;
+use std::collections::HashMap;
+use std::fmt;
+
+/// Tolerance for deciding that a ratio or root limit equals one
+const UNIT_TOLERANCE: f64 = 1e-3;
+
+/// Tolerance for deciding that a limit is zero
+const ZERO_TOLERANCE: f64 = 1e-9;
+
+/// Sample points for numerical estimates of limits at infinity
+const LIMIT_SAMPLES: [f64; 4] = [1e3, 1e4, 1e5, 1e6];
+
+/// Sample points for checking the sign and monotonicity of the terms
+const TERM_SAMPLES: [f64; 5] = [10.0, 20.0, 50.0, 100.0, 1e3];
+
+/// A test for the convergence of an infinite series
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConvergenceTest {
+    /// `a_n ↛ 0` implies divergence
+    NthTerm,
+    /// `Σ (-1)^n b_n` converges when `b_n` decreases to zero
+    Alternating,
+    /// Limit comparison with a p-series `Σ 1/n^p`
+    Comparison,
+    /// `lim |a_(n+1)/a_n|` below or above one
+    Ratio,
+    /// `lim |a_n|^(1/n)` below or above one
+    Root,
+    /// Convergence of `∫ f(x) dx` on `[1, ∞)`
+    Integral,
+}
+
+impl ConvergenceTest {
+    /// Name of the test as used in textbooks
+    pub fn name(&self) -> &'static str {
+        match self {
+            ConvergenceTest::NthTerm => "n-th term test",
+            ConvergenceTest::Alternating => "alternating series test",
+            ConvergenceTest::Comparison => "limit comparison test",
+            ConvergenceTest::Ratio => "ratio test",
+            ConvergenceTest::Root => "root test",
+            ConvergenceTest::Integral => "integral test",
+        }
+    }
+}
+
+impl fmt::Display for ConvergenceTest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Verdict of [`series_convergence`] with its justification
+#[derive(Debug, Clone)]
+pub struct SeriesConvergence {
+    pub result: ConvergenceResult,
+    /// The test that settled the question, if any did
+    pub test: Option<ConvergenceTest>,
+    pub explanation: StepByStepExplanation,
+}
+
+/// Decide whether `Σ expr` over `n = 1, 2, …` converges, explaining each test
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::calculus::summation::{series_convergence, ConvergenceResult, ConvergenceTest};
+/// use mathhook_core::{symbol, Expression};
+///
+/// let n = symbol!(n);
+/// // Σ 2^n / n!
+/// let term = Expression::div(
+///     Expression::pow(Expression::integer(2), Expression::symbol(n.clone())),
+///     Expression::function("factorial", vec![Expression::symbol(n.clone())]),
+/// );
+/// let analysis = series_convergence(&term, &n);
+/// assert_eq!(analysis.result, ConvergenceResult::Convergent);
+/// assert_eq!(analysis.test, Some(ConvergenceTest::Ratio));
+///
+/// for step in &analysis.explanation.steps {
+///     println!("{}: {}", step.title, step.description);
+/// }
+/// ```
+pub fn series_convergence(expr: &Expression, n: &Symbol) -> SeriesConvergence {
+    let term = expr.simplify();
+    let mut analysis = Analysis {
+        n,
+        steps: Vec::new(),
+        tests: Vec::new(),
+    };
+    analysis.step(
+        "Series",
+        format!(
+            "Determine whether Σ a_{} converges, where a_{} = {}",
+            n.name(),
+            n.name(),
+            term
+        ),
+        &term,
+        "Introduction",
+    );
+
+    let (result, test) = match split_alternating(&term, n) {
+        Some(magnitude) => analysis.alternating(&term, &magnitude),
+        None => analysis.general(&term),
+    };
+
+    let conclusion = match (&result, test) {
+        (ConvergenceResult::Unknown, _) | (_, None) => {
+            "None of the tests settles the question; convergence is undetermined".to_owned()
+        }
+        (verdict, Some(test)) => {
+            let verdict = match verdict {
+                ConvergenceResult::Convergent => "converges",
+                ConvergenceResult::ConditionallyConvergent => "converges conditionally",
+                _ => "diverges",
+            };
+            format!("The series {} by the {}", verdict, test)
+        }
+    };
+    analysis.step("Conclusion", conclusion, &term, "Final Result");
+
+    let total_steps = analysis.steps.len().saturating_sub(2);
+    let rules_used = analysis.tests.iter().map(|t| t.name().to_owned()).collect();
+    SeriesConvergence {
+        result,
+        test,
+        explanation: StepByStepExplanation {
+            initial_expression: expr.clone(),
+            final_expression: term,
+            steps: analysis.steps,
+            total_steps,
+            rules_used,
+        },
+    }
+}
+
+type Verdict = (ConvergenceResult, Option<ConvergenceTest>);
+
+struct Analysis<'a> {
+    n: &'a Symbol,
+    steps: Vec<Step>,
+    tests: Vec<ConvergenceTest>,
+}
+
+impl Analysis<'_> {
+    fn step(&mut self, title: &str, description: String, expr: &Expression, rule: &str) {
+        self.steps.push(Step {
+            title: title.to_owned(),
+            description,
+            expression: expr.clone(),
+            rule_applied: rule.to_owned(),
+            latex: None,
+        });
+    }
+
+    fn record(&mut self, test: ConvergenceTest, description: String, expr: &Expression) {
+        if !self.tests.contains(&test) {
+            self.tests.push(test);
+        }
+        let title = capitalize(test.name());
+        self.step(&title, description, expr, test.name());
+    }
+
+    /// Series without an explicit `(-1)^n` factor
+    fn general(&mut self, term: &Expression) -> Verdict {
+        if let Some(verdict) = self.nth_term(term) {
+            return verdict;
+        }
+        let positive = match eventual_sign(term, self.n) {
+            Some(Sign::Positive) => term.clone(),
+            Some(Sign::Negative) => {
+                let negated = (Expression::integer(-1) * term.clone()).simplify();
+                self.step(
+                    "Sign",
+                    format!(
+                        "The terms are eventually negative, so study Σ -a_{} = Σ {} instead",
+                        self.n.name(),
+                        negated
+                    ),
+                    &negated,
+                    "Sign Change",
+                );
+                negated
+            }
+            None => {
+                self.step(
+                    "Sign",
+                    "The terms change sign irregularly, so the tests for positive series do not apply"
+                        .to_owned(),
+                    term,
+                    "Sign Check",
+                );
+                return (ConvergenceResult::Unknown, None);
+            }
+        };
+        self.positive_series(&positive)
+            .unwrap_or((ConvergenceResult::Unknown, None))
+    }
+
+    /// `Σ (-1)^n b_n` with `b_n = magnitude`
+    fn alternating(&mut self, term: &Expression, magnitude: &Expression) -> Verdict {
+        let n = self.n.name();
+        self.step(
+            "Alternating Series",
+            format!(
+                "The series alternates in sign: a_{n} = (-1)^{n} b_{n} with b_{n} = {}",
+                magnitude
+            ),
+            magnitude,
+            "Series Detection",
+        );
+
+        match limit_at_infinity(magnitude, self.n) {
+            Some(limit) if !limit.is_zero() => {
+                self.record(
+                    ConvergenceTest::NthTerm,
+                    format!(
+                        "lim b_{n} = {}, so the terms a_{n} do not approach 0 and the series diverges",
+                        limit
+                    ),
+                    term,
+                );
+                return (ConvergenceResult::Divergent, Some(ConvergenceTest::NthTerm));
+            }
+            Some(_) => {}
+            None => {
+                self.step(
+                    "Limit of Terms",
+                    format!("The limit of b_{n} could not be determined"),
+                    magnitude,
+                    "Limit",
+                );
+                return (ConvergenceResult::Unknown, None);
+            }
+        }
+
+        let decreasing = is_eventually_decreasing(magnitude, self.n);
+        if decreasing {
+            self.record(
+                ConvergenceTest::Alternating,
+                format!(
+                    "b_{n} > 0 decreases and lim b_{n} = 0, so Σ (-1)^{n} b_{n} converges. \
+                     Next, check absolute convergence by studying Σ b_{n}"
+                ),
+                magnitude,
+            );
+        } else {
+            self.record(
+                ConvergenceTest::Alternating,
+                format!(
+                    "b_{n} is not eventually decreasing, so the alternating series test does not apply. \
+                     Check absolute convergence instead"
+                ),
+                magnitude,
+            );
+        }
+
+        match self.positive_series(magnitude) {
+            Some((ConvergenceResult::Convergent, test)) => {
+                self.step(
+                    "Absolute Convergence",
+                    format!("Σ |a_{n}| converges, so the series converges absolutely"),
+                    term,
+                    "Absolute Convergence",
+                );
+                (ConvergenceResult::Convergent, test)
+            }
+            Some((ConvergenceResult::Divergent, _)) if decreasing => {
+                self.step(
+                    "Conditional Convergence",
+                    format!("Σ |a_{n}| diverges, so the convergence is only conditional"),
+                    term,
+                    "Conditional Convergence",
+                );
+                (
+                    ConvergenceResult::ConditionallyConvergent,
+                    Some(ConvergenceTest::Alternating),
+                )
+            }
+            _ if decreasing => (
+                ConvergenceResult::Convergent,
+                Some(ConvergenceTest::Alternating),
+            ),
+            _ => (ConvergenceResult::Unknown, None),
+        }
+    }
+
+    /// The n-th term test; decides only divergence
+    fn nth_term(&mut self, term: &Expression) -> Option<Verdict> {
+        let n = self.n.name();
+        match limit_at_infinity(term, self.n) {
+            Some(limit) if !limit.is_zero() => {
+                self.record(
+                    ConvergenceTest::NthTerm,
+                    format!(
+                        "lim a_{n} = {} ≠ 0 as {n} → ∞, so the series diverges",
+                        limit
+                    ),
+                    term,
+                );
+                Some((ConvergenceResult::Divergent, Some(ConvergenceTest::NthTerm)))
+            }
+            Some(_) => {
+                self.record(
+                    ConvergenceTest::NthTerm,
+                    format!("lim a_{n} = 0, so the n-th term test is inconclusive"),
+                    term,
+                );
+                None
+            }
+            None => {
+                self.step(
+                    "Limit of Terms",
+                    format!("lim a_{n} could not be determined, so move on to other tests"),
+                    term,
+                    "Limit",
+                );
+                None
+            }
+        }
+    }
+
+    /// Tests for series with eventually positive terms
+    fn positive_series(&mut self, term: &Expression) -> Option<Verdict> {
+        if has_exponential_growth(term, self.n) {
+            if let Some(verdict) = self.ratio(term) {
+                return Some(verdict);
+            }
+            if let Some(verdict) = self.root(term) {
+                return Some(verdict);
+            }
+        }
+        if let Some(verdict) = self.comparison(term) {
+            return Some(verdict);
+        }
+        self.integral(term)
+    }
+
+    /// Decide from a ratio or root limit `L`: below one converges, above diverges
+    fn unit_verdict(
+        &mut self,
+        test: ConvergenceTest,
+        quantity: &str,
+        value: &Expression,
+        limit: Option<Limit>,
+    ) -> Option<Verdict> {
+        let limit = limit?;
+        let (verdict, reading) = match limit {
+            Limit::Finite(l) if l < 1.0 - UNIT_TOLERANCE => (
+                ConvergenceResult::Convergent,
+                "L < 1, so the series converges",
+            ),
+            Limit::Finite(l) if l <= 1.0 + UNIT_TOLERANCE => {
+                self.record(
+                    test,
+                    format!("L = lim {} = 1, so the {} is inconclusive", quantity, test),
+                    value,
+                );
+                return None;
+            }
+            _ => (
+                ConvergenceResult::Divergent,
+                "L > 1, so the series diverges",
+            ),
+        };
+        self.record(
+            test,
+            format!("L = lim {} = {}; {}", quantity, limit, reading),
+            value,
+        );
+        Some((verdict, Some(test)))
+    }
+
+    fn ratio(&mut self, term: &Expression) -> Option<Verdict> {
+        let ratio = successive_ratio(term, self.n);
+        let limit = limit_at_infinity(&ratio, self.n).map(Limit::abs);
+        let n = self.n.name();
+        let quantity = format!("|a_({n}+1) / a_{n}| = lim |{}|", ratio);
+        self.unit_verdict(ConvergenceTest::Ratio, &quantity, &ratio, limit)
+    }
+
+    fn root(&mut self, term: &Expression) -> Option<Verdict> {
+        let root = nth_root(term, self.n)?;
+        let limit = limit_at_infinity(&root, self.n).map(Limit::abs);
+        let n = self.n.name();
+        let quantity = format!("|a_{n}|^(1/{n}) = lim |{}|", root);
+        self.unit_verdict(ConvergenceTest::Root, &quantity, &root, limit)
+    }
+
+    /// Limit comparison with `Σ 1/n^p` where `a_n` grows like `n^(-p)`
+    fn comparison(&mut self, term: &Expression) -> Option<Verdict> {
+        let p = -growth_exponent(term, self.n)?;
+        let n_symbol = Expression::symbol(self.n.clone());
+        let scaled = (term.clone() * Expression::pow(n_symbol, number(p))).simplify();
+        let constant = match limit_at_infinity(&scaled, self.n)? {
+            Limit::Finite(c) if c.abs() > ZERO_TOLERANCE => c,
+            _ => return None,
+        };
+
+        let n = self.n.name();
+        let p_text = format_value(p);
+        let (verdict, reading) = if p > 1.0 {
+            (ConvergenceResult::Convergent, "p > 1, so it converges")
+        } else {
+            (ConvergenceResult::Divergent, "p ≤ 1, so it diverges")
+        };
+        self.record(
+            ConvergenceTest::Comparison,
+            format!(
+                "Compare with b_{n} = 1/{n}^{p_text}: lim a_{n} / b_{n} = {} is finite and positive, \
+                 so both series behave alike. Σ 1/{n}^{p_text} is a p-series with p = {p_text}; {}",
+                format_value(constant),
+                reading
+            ),
+            term,
+        );
+        Some((verdict, Some(ConvergenceTest::Comparison)))
+    }
+
+    /// Integral test with an antiderivative verified by differentiation
+    fn integral(&mut self, term: &Expression) -> Option<Verdict> {
+        if !is_eventually_decreasing(term, self.n) {
+            return None;
+        }
+        let antiderivative = term.integrate(self.n.clone(), 0).simplify();
+        if !is_closed_form(&antiderivative) || !is_antiderivative(&antiderivative, term, self.n) {
+            return None;
+        }
+        let n = self.n.name();
+        let (verdict, reading) = match limit_at_infinity(&antiderivative, self.n)? {
+            Limit::Finite(_) => (
+                ConvergenceResult::Convergent,
+                "has a finite limit, so the integral and the series converge",
+            ),
+            _ => (
+                ConvergenceResult::Divergent,
+                "grows without bound, so the integral and the series diverge",
+            ),
+        };
+        self.record(
+            ConvergenceTest::Integral,
+            format!(
+                "f({n}) = {} is positive and decreasing. Its antiderivative {} {}",
+                term, antiderivative, reading
+            ),
+            &antiderivative,
+        );
+        Some((verdict, Some(ConvergenceTest::Integral)))
+    }
+}
+
+/// Limit of a sequence as `n → ∞`
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Limit {
+    Finite(f64),
+    PositiveInfinity,
+    NegativeInfinity,
+}
+
+impl Limit {
+    fn is_zero(&self) -> bool {
+        matches!(self, Limit::Finite(v) if v.abs() <= ZERO_TOLERANCE)
+    }
+
+    fn abs(self) -> Self {
+        match self {
+            Limit::Finite(v) => Limit::Finite(v.abs()),
+            _ => Limit::PositiveInfinity,
+        }
+    }
+
+    fn from_value(value: f64) -> Option<Self> {
+        if value.is_nan() {
+            None
+        } else if value == f64::INFINITY {
+            Some(Limit::PositiveInfinity)
+        } else if value == f64::NEG_INFINITY {
+            Some(Limit::NegativeInfinity)
+        } else {
+            Some(Limit::Finite(value))
+        }
+    }
+
+    fn add(self, other: Limit) -> Option<Limit> {
+        match (self, other) {
+            (Limit::Finite(a), Limit::Finite(b)) => Some(Limit::Finite(a + b)),
+            (Limit::PositiveInfinity, Limit::NegativeInfinity)
+            | (Limit::NegativeInfinity, Limit::PositiveInfinity) => None,
+            (Limit::Finite(_), infinite) | (infinite, _) => Some(infinite),
+        }
+    }
+
+    fn mul(self, other: Limit) -> Option<Limit> {
+        match (self, other) {
+            (Limit::Finite(a), Limit::Finite(b)) => Some(Limit::Finite(a * b)),
+            (Limit::Finite(a), infinite) | (infinite, Limit::Finite(a)) => {
+                if a.abs() <= ZERO_TOLERANCE {
+                    None
+                } else if (a > 0.0) == (infinite == Limit::PositiveInfinity) {
+                    Some(Limit::PositiveInfinity)
+                } else {
+                    Some(Limit::NegativeInfinity)
+                }
+            }
+            (a, b) if a == b => Some(Limit::PositiveInfinity),
+            _ => Some(Limit::NegativeInfinity),
+        }
+    }
+}
+
+impl fmt::Display for Limit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Limit::Finite(v) => f.write_str(&format_value(*v)),
+            Limit::PositiveInfinity => f.write_str("∞"),
+            Limit::NegativeInfinity => f.write_str("-∞"),
+        }
+    }
+}
+
+/// `lim expr` as `n → ∞`, or `None` when it cannot be determined
+fn limit_at_infinity(expr: &Expression, n: &Symbol) -> Option<Limit> {
+    let exact = expr.limit_at_infinity(n);
+    if exact == Expression::infinity() {
+        return Some(Limit::PositiveInfinity);
+    }
+    if exact == Expression::negative_infinity() {
+        return Some(Limit::NegativeInfinity);
+    }
+    if is_closed_form(&exact) && !exact.contains_variable(n) {
+        if let Ok(value) = exact.evaluate_to_f64() {
+            return Limit::from_value(value);
+        }
+    }
+    combined_limit(expr, n).or_else(|| estimated_limit(expr, n))
+}
+
+/// Limit from the limits of the operands, for determinate forms
+fn combined_limit(expr: &Expression, n: &Symbol) -> Option<Limit> {
+    if !expr.contains_variable(n) {
+        return Limit::from_value(expr.evaluate_to_f64().ok()?);
+    }
+    match expr {
+        Expression::Symbol(s) if s == n => Some(Limit::PositiveInfinity),
+        Expression::Add(terms) => terms.iter().try_fold(Limit::Finite(0.0), |acc, t| {
+            acc.add(limit_at_infinity(t, n)?)
+        }),
+        Expression::Mul(factors) => factors.iter().try_fold(Limit::Finite(1.0), |acc, f| {
+            acc.mul(limit_at_infinity(f, n)?)
+        }),
+        Expression::Pow(base, exp) if !exp.contains_variable(n) => {
+            let q = exp.evaluate_to_f64().ok()?;
+            match limit_at_infinity(base, n)? {
+                Limit::PositiveInfinity if q > 0.0 => Some(Limit::PositiveInfinity),
+                Limit::PositiveInfinity if q < 0.0 => Some(Limit::Finite(0.0)),
+                Limit::Finite(b) if b > ZERO_TOLERANCE => Some(Limit::Finite(b.powf(q))),
+                _ => None,
+            }
+        }
+        Expression::Pow(base, exp) if !base.contains_variable(n) => {
+            let b = base.evaluate_to_f64().ok()?;
+            if b <= 0.0 {
+                return None;
+            }
+            match limit_at_infinity(exp, n)? {
+                Limit::Finite(e) => Some(Limit::Finite(b.powf(e))),
+                Limit::PositiveInfinity if b > 1.0 => Some(Limit::PositiveInfinity),
+                Limit::NegativeInfinity if b < 1.0 => Some(Limit::PositiveInfinity),
+                _ if b == 1.0 => Some(Limit::Finite(1.0)),
+                _ => Some(Limit::Finite(0.0)),
+            }
+        }
+        Expression::Function { name, args, .. } if args.len() == 1 => {
+            let inner = limit_at_infinity(&args[0], n)?;
+            match (name.as_ref(), inner) {
+                ("ln" | "log" | "sqrt" | "exp" | "factorial", Limit::PositiveInfinity) => {
+                    Some(Limit::PositiveInfinity)
+                }
+                ("exp", Limit::NegativeInfinity) => Some(Limit::Finite(0.0)),
+                ("arctan", Limit::PositiveInfinity) => {
+                    Some(Limit::Finite(std::f64::consts::FRAC_PI_2))
+                }
+                ("arctan", Limit::NegativeInfinity) => {
+                    Some(Limit::Finite(-std::f64::consts::FRAC_PI_2))
+                }
+                (_, Limit::Finite(v)) => {
+                    let value = Expression::function(name.as_ref(), vec![Expression::float(v)]);
+                    Limit::from_value(value.evaluate_to_f64().ok()?)
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Limit estimated from the values at large `n`
+///
+/// Finite when successive values agree to a relative `1e-4`, infinite when
+/// they keep growing in magnitude without slowing down.
+fn estimated_limit(expr: &Expression, n: &Symbol) -> Option<Limit> {
+    let sampler = Sampler::new(expr, n)?;
+    let values: Vec<f64> = LIMIT_SAMPLES
+        .iter()
+        .map(|&point| sampler.at(point))
+        .collect::<Option<_>>()?;
+    let [.., previous, last] = values[..] else {
+        return None;
+    };
+    if (last - previous).abs() <= 1e-4 * last.abs().max(1.0) {
+        return Some(Limit::Finite(last));
+    }
+    let differences: Vec<f64> = values.windows(2).map(|w| w[1] - w[0]).collect();
+    let same_direction = differences.iter().all(|d| d.signum() == last.signum());
+    let not_slowing = differences
+        .windows(2)
+        .all(|d| d[1].abs() >= 0.9 * d[0].abs());
+    if same_direction && not_slowing {
+        Some(if last > 0.0 {
+            Limit::PositiveInfinity
+        } else {
+            Limit::NegativeInfinity
+        })
+    } else {
+        None
+    }
+}
+
+/// `expr` compiled as a function of `n` for sampling
+///
+/// Evaluating in floating point lets huge terms such as `1000!` overflow to
+/// infinity instead of being computed exactly.
+struct Sampler(CompiledExpression);
+
+impl Sampler {
+    fn new(expr: &Expression, n: &Symbol) -> Option<Self> {
+        let compiled = factorials_as_gamma(expr)
+            .compile(std::slice::from_ref(n))
+            .ok()?;
+        Some(Self(compiled))
+    }
+
+    fn at(&self, point: f64) -> Option<f64> {
+        let value = self.0.evaluate(&[point]);
+        (!value.is_nan()).then_some(value)
+    }
+}
+
+/// `x!` as `Γ(x + 1)`, which has a numeric value for large `x`
+fn factorials_as_gamma(expr: &Expression) -> Expression {
+    match expr {
+        Expression::Function { name, args, .. }
+            if name.as_ref() == "factorial" && args.len() == 1 =>
+        {
+            let shifted = factorials_as_gamma(&args[0]) + Expression::integer(1);
+            Expression::function("gamma", vec![shifted])
+        }
+        Expression::Function { name, args, .. } => Expression::function(
+            name.as_ref(),
+            args.iter().map(factorials_as_gamma).collect(),
+        ),
+        Expression::Add(terms) => Expression::add(terms.iter().map(factorials_as_gamma).collect()),
+        Expression::Mul(factors) => {
+            Expression::mul(factors.iter().map(factorials_as_gamma).collect())
+        }
+        Expression::Pow(base, exp) => {
+            Expression::pow(factorials_as_gamma(base), factorials_as_gamma(exp))
+        }
+        other => other.clone(),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Sign {
+    Positive,
+    Negative,
+}
+
+/// Sign shared by the terms at all sample points
+fn eventual_sign(term: &Expression, n: &Symbol) -> Option<Sign> {
+    let sampler = Sampler::new(term, n)?;
+    let values: Vec<f64> = TERM_SAMPLES
+        .iter()
+        .filter_map(|&point| sampler.at(point))
+        .collect();
+    if values.len() < 3 {
+        return None;
+    }
+    if values.iter().all(|&v| v > 0.0) {
+        Some(Sign::Positive)
+    } else if values.iter().all(|&v| v < 0.0) {
+        Some(Sign::Negative)
+    } else {
+        None
+    }
+}
+
+/// Whether `b_(n+1) ≤ b_n` with `b_n > 0` at all sample points
+fn is_eventually_decreasing(term: &Expression, n: &Symbol) -> bool {
+    let Some(sampler) = Sampler::new(term, n) else {
+        return false;
+    };
+    let checked: Vec<bool> = TERM_SAMPLES
+        .iter()
+        .filter_map(|&point| {
+            let current = sampler.at(point)?;
+            let next = sampler.at(point + 1.0)?;
+            Some(current > 0.0 && next <= current)
+        })
+        .collect();
+    checked.len() >= 3 && checked.iter().all(|&ok| ok)
+}
+
+/// `b_n` when `a_n = (-1)^(g(n)) b_n` for some `g` depending on `n`
+fn split_alternating(term: &Expression, n: &Symbol) -> Option<Expression> {
+    let is_sign = |factor: &Expression| {
+        matches!(factor, Expression::Pow(base, exp)
+            if matches!(base.as_ref(), Expression::Number(Number::Integer(-1)))
+                && exp.contains_variable(n))
+    };
+    match term {
+        _ if is_sign(term) => Some(Expression::integer(1)),
+        Expression::Mul(factors) if factors.iter().filter(|f| is_sign(f)).count() == 1 => {
+            let rest: Vec<Expression> = factors.iter().filter(|f| !is_sign(f)).cloned().collect();
+            Some(Expression::mul(rest).simplify())
+        }
+        _ => None,
+    }
+}
+
+/// Whether the terms contain a factorial or `n` in an exponent
+fn has_exponential_growth(expr: &Expression, n: &Symbol) -> bool {
+    match expr {
+        Expression::Pow(base, exp) => exp.contains_variable(n) || has_exponential_growth(base, n),
+        Expression::Function { name, args, .. } => {
+            (matches!(name.as_ref(), "factorial" | "gamma" | "exp")
+                && args.iter().any(|a| a.contains_variable(n)))
+                || args.iter().any(|a| has_exponential_growth(a, n))
+        }
+        Expression::Add(terms) | Expression::Mul(terms) => {
+            terms.iter().any(|t| has_exponential_growth(t, n))
+        }
+        _ => false,
+    }
+}
+
+/// `expr` with `n` replaced by `n + 1`
+fn shifted(expr: &Expression, n: &Symbol) -> Expression {
+    let next = Expression::symbol(n.clone()) + Expression::integer(1);
+    expr.substitute(&HashMap::from([(n.name().to_owned(), next)]))
+}
+
+/// `a_(n+1) / a_n`, simplified factor by factor as one would by hand:
+/// `b^(n+1)/b^n = b` and `(n+1)!/n! = n + 1`
+fn successive_ratio(term: &Expression, n: &Symbol) -> Expression {
+    let factors = match term {
+        Expression::Mul(factors) => factors.to_vec(),
+        other => vec![other.clone()],
+    };
+    let ratios = factors.iter().map(|f| factor_ratio(f, n)).collect();
+    Expression::mul(ratios).simplify()
+}
+
+fn factor_ratio(factor: &Expression, n: &Symbol) -> Expression {
+    if !factor.contains_variable(n) {
+        return Expression::integer(1);
+    }
+    let step = |e: &Expression| (shifted(e, n) - e.clone()).simplify();
+    match factor {
+        Expression::Pow(base, exp) if !base.contains_variable(n) => {
+            Expression::pow(base.as_ref().clone(), step(exp))
+        }
+        Expression::Pow(base, exp) if !exp.contains_variable(n) => {
+            Expression::pow(factor_ratio(base, n), exp.as_ref().clone())
+        }
+        Expression::Function { name, args, .. } if name.as_ref() == "exp" && args.len() == 1 => {
+            Expression::function("exp", vec![step(&args[0])])
+        }
+        Expression::Function { name, args, .. }
+            if name.as_ref() == "factorial" && args.len() == 1 =>
+        {
+            match step(&args[0]) {
+                Expression::Number(Number::Integer(k)) if (1..=8).contains(&k) => Expression::mul(
+                    (1..=k)
+                        .map(|i| args[0].clone() + Expression::integer(i))
+                        .collect(),
+                ),
+                _ => Expression::div(shifted(factor, n), factor.clone()),
+            }
+        }
+        _ => Expression::div(shifted(factor, n), factor.clone()),
+    }
+}
+
+/// `|a_n|^(1/n)` when some factor has `n` in its exponent, e.g.
+/// `(n/(2n+1))^n` gives `n/(2n+1)`
+fn nth_root(term: &Expression, n: &Symbol) -> Option<Expression> {
+    let factors = match term {
+        Expression::Mul(factors) => factors.to_vec(),
+        other => vec![other.clone()],
+    };
+    let n_expr = Expression::symbol(n.clone());
+    let mut applicable = false;
+    let roots = factors
+        .iter()
+        .map(|factor| match factor {
+            Expression::Pow(base, exp) if exp.contains_variable(n) => {
+                applicable = true;
+                let reduced = Expression::div(exp.as_ref().clone(), n_expr.clone()).simplify();
+                Expression::pow(base.as_ref().clone(), reduced)
+            }
+            other => Expression::pow(
+                other.clone(),
+                Expression::pow(n_expr.clone(), Expression::integer(-1)),
+            ),
+        })
+        .collect();
+    applicable.then(|| Expression::mul(roots).simplify())
+}
+
+/// `q` such that the term grows like `n^q`, for algebraic terms
+fn growth_exponent(expr: &Expression, n: &Symbol) -> Option<f64> {
+    if !expr.contains_variable(n) {
+        return Some(0.0);
+    }
+    match expr {
+        Expression::Symbol(s) if s == n => Some(1.0),
+        Expression::Pow(base, exp) if !exp.contains_variable(n) => {
+            Some(growth_exponent(base, n)? * exp.evaluate_to_f64().ok()?)
+        }
+        Expression::Function { name, args, .. } if name.as_ref() == "sqrt" && args.len() == 1 => {
+            Some(growth_exponent(&args[0], n)? / 2.0)
+        }
+        Expression::Mul(factors) => factors.iter().map(|f| growth_exponent(f, n)).sum(),
+        Expression::Add(terms) => terms
+            .iter()
+            .map(|t| growth_exponent(t, n))
+            .try_fold(f64::NEG_INFINITY, |max, q| Some(max.max(q?))),
+        _ => None,
+    }
+}
+
+/// Whether the expression has no unevaluated integrals or limits left
+fn is_closed_form(expr: &Expression) -> bool {
+    match expr {
+        Expression::Calculus(_) => false,
+        Expression::Function { name, args, .. } => {
+            !matches!(name.as_ref(), "limit" | "integrate") && args.iter().all(is_closed_form)
+        }
+        Expression::Add(terms) | Expression::Mul(terms) => terms.iter().all(is_closed_form),
+        Expression::Pow(base, exp) => is_closed_form(base) && is_closed_form(exp),
+        _ => true,
+    }
+}
+
+/// Check `F' = f` numerically at a few points
+fn is_antiderivative(antiderivative: &Expression, integrand: &Expression, n: &Symbol) -> bool {
+    let (Some(derivative), Some(integrand)) = (
+        Sampler::new(&antiderivative.derivative(n.clone()), n),
+        Sampler::new(integrand, n),
+    ) else {
+        return false;
+    };
+    [11.0, 37.0, 123.0]
+        .iter()
+        .all(|&x| match (derivative.at(x), integrand.at(x)) {
+            (Some(d), Some(f)) => (d - f).abs() <= 1e-8 * f.abs().max(1e-300),
+            _ => false,
+        })
+}
+
+fn number(value: f64) -> Expression {
+    if value.fract() == 0.0 {
+        Expression::integer(value as i64)
+    } else if (value * 2.0).fract() == 0.0 {
+        Expression::rational((value * 2.0) as i64, 2)
+    } else {
+        Expression::float(value)
+    }
+}
+
+/// Exact-looking text for a number: integers and small fractions exactly
+fn format_value(value: f64) -> String {
+    if (value - value.round()).abs() < 1e-9 {
+        return format!("{}", value.round() as i64);
+    }
+    for denominator in 2..=12 {
+        let numerator = value * denominator as f64;
+        if (numerator - numerator.round()).abs() < 1e-9 * denominator as f64 {
+            return format!("{}/{}", numerator.round() as i64, denominator);
+        }
+    }
+    let text = format!("{:.6}", value);
+    text.trim_end_matches('0').to_owned()
+}
+
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::symbol;
+
+    fn n() -> Expression {
+        Expression::symbol(symbol!(n))
+    }
+
+    fn verdict(term: Expression) -> (ConvergenceResult, Option<ConvergenceTest>) {
+        let analysis = series_convergence(&term, &symbol!(n));
+        assert!(analysis.explanation.steps.len() >= 3);
+        (analysis.result, analysis.test)
+    }
+
+    #[test]
+    fn test_p_series_by_comparison() {
+        use ConvergenceResult::*;
+        let inverse_square = Expression::pow(n(), Expression::integer(-2));
+        assert_eq!(
+            verdict(inverse_square),
+            (Convergent, Some(ConvergenceTest::Comparison))
+        );
+        let harmonic = Expression::pow(n(), Expression::integer(-1));
+        assert_eq!(
+            verdict(harmonic),
+            (Divergent, Some(ConvergenceTest::Comparison))
+        );
+        // n / (n³ + 1) behaves like 1/n²
+        let rational = Expression::div(
+            n(),
+            Expression::pow(n(), Expression::integer(3)) + Expression::integer(1),
+        );
+        assert_eq!(verdict(rational).0, Convergent);
+    }
+
+    #[test]
+    fn test_nth_term_divergence() {
+        let term = Expression::div(n(), n() + Expression::integer(1));
+        assert_eq!(
+            verdict(term),
+            (ConvergenceResult::Divergent, Some(ConvergenceTest::NthTerm))
+        );
+    }
+
+    #[test]
+    fn test_ratio_and_root() {
+        let factorial = Expression::function("factorial", vec![n()]);
+        let ratio = Expression::div(Expression::pow(Expression::integer(3), n()), factorial);
+        assert_eq!(
+            verdict(ratio),
+            (ConvergenceResult::Convergent, Some(ConvergenceTest::Ratio))
+        );
+        let growing = Expression::div(
+            Expression::pow(Expression::integer(2), n()),
+            Expression::pow(n(), Expression::integer(2)),
+        );
+        assert_eq!(verdict(growing).0, ConvergenceResult::Divergent);
+
+        // (n / (2n + 1))^n
+        let base = Expression::div(n(), Expression::integer(2) * n() + Expression::integer(1));
+        let root = Expression::pow(base, n());
+        assert_eq!(verdict(root).0, ConvergenceResult::Convergent);
+    }
+
+    #[test]
+    fn test_alternating_series() {
+        let sign = Expression::pow(Expression::integer(-1), n());
+        let harmonic = sign.clone() * Expression::pow(n(), Expression::integer(-1));
+        assert_eq!(
+            verdict(harmonic),
+            (
+                ConvergenceResult::ConditionallyConvergent,
+                Some(ConvergenceTest::Alternating)
+            )
+        );
+        let absolute = sign.clone() * Expression::pow(n(), Expression::integer(-2));
+        assert_eq!(verdict(absolute).0, ConvergenceResult::Convergent);
+        let divergent = sign * Expression::div(n(), n() + Expression::integer(1));
+        assert_eq!(verdict(divergent).0, ConvergenceResult::Divergent);
+    }
+
+    #[test]
+    fn test_integral_test() {
+        // ln(n) / n²
+        let term = Expression::div(
+            Expression::function("ln", vec![n()]),
+            Expression::pow(n(), Expression::integer(2)),
+        );
+        assert_eq!(
+            verdict(term),
+            (
+                ConvergenceResult::Convergent,
+                Some(ConvergenceTest::Integral)
+            )
+        );
+    }
+}
@@ -0,0 +1,8 @@
+//! Integral transforms
+//!
+//! The Laplace transform, older than this module, is in
+//! [`crate::calculus::laplace`].
+
+pub mod fourier;
+
+pub use fourier::{fourier_series, FourierConvention, FourierSeries, FourierTransform};
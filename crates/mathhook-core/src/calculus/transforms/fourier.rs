@@ -0,0 +1,907 @@
+//! Fourier transforms and Fourier series
+//!
+//! Where the factors of 2π go differs between fields, so the transform takes
+//! a [`FourierConvention`] with parameters `(a, b)`:
+//!
+//! ```text
+//! F(ω) = √(|b| / (2π)^(1-a)) ∫ f(t) e^{-ibωt} dt
+//! f(t) = √(|b| / (2π)^(1+a)) ∫ F(ω) e^{ibωt} dω
+//! ```
+//!
+//! Both directions are computed from one table of pairs for the kernel
+//! `e^{-iωt}`, together with linearity and the modulation theorem. The inverse
+//! transform uses the same table by duality: `∫ F(ω) e^{ibωt} dω` is the
+//! table transform of `F` evaluated at `-bt`.
+//!
+//! | f(t)                  | ∫ f(t) e^{-iωt} dt          |
+//! |-----------------------|-----------------------------|
+//! | c                     | 2πc δ(ω)                    |
+//! | tⁿ                    | 2π iⁿ δ⁽ⁿ⁾(ω)                |
+//! | δ⁽ⁿ⁾(t - a)           | (iω)ⁿ e^{-iaω}              |
+//! | e^{iat}               | 2π δ(ω - a)                 |
+//! | cos(at), sin(at)      | π(δ(ω - a) + δ(ω + a)), -iπ(δ(ω - a) - δ(ω + a)) |
+//! | e^{-a\|t\|}           | 2a/(a² + ω²)                |
+//! | e^{-at²}              | √(π/a) e^{-ω²/(4a)}         |
+//! | 1/(t² + a²)           | (π/a) e^{-a\|ω\|}            |
+//! | H(t) tⁿ e^{-at}       | n!/(iω + a)ⁿ⁺¹              |
+//! | H(t)                  | πδ(ω) - i/ω                 |
+//! | e^{iat} g(t)          | G(ω - a)                    |
+//!
+//! Decay rates `a` are taken to be positive. Expressions outside the table
+//! stay as an unevaluated `fourier_transform(f, t, ω)`.
+//!
+//! [`fourier_series`] expands a periodic function instead, integrating over
+//! one period with [`Expression::integrate_definite`].
+
+use crate::calculus::integrals::distributions::linear_coefficients;
+use crate::core::constants::MathConstant;
+use crate::core::{Expression, Number, Symbol};
+use crate::error::MathError;
+use crate::functions::special::distributions::{delta_order, dirac_delta};
+use crate::simplify::Simplify;
+use std::collections::HashMap;
+
+/// Placement of the 2π factors, as the parameters `(a, b)` of the module docs
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::calculus::transforms::fourier::{FourierConvention, FourierTransform};
+/// use mathhook_core::{expr, symbol, Expression};
+///
+/// let (t, w) = (symbol!(t), symbol!(w));
+/// let pulse = Expression::function("dirac_delta", vec![expr!(t)]);
+///
+/// // Without a forward factor the impulse transforms to 1
+/// assert_eq!(pulse.fourier_transform(&t, &w, &FourierConvention::angular()), expr!(1));
+/// // The unitary convention splits 1/(2π) evenly between the directions
+/// let unitary = pulse.fourier_transform(&t, &w, &FourierConvention::unitary());
+/// let expected = 1.0 / (2.0 * std::f64::consts::PI).sqrt();
+/// assert!((unitary.evaluate_to_f64().unwrap() - expected).abs() < 1e-12);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct FourierConvention {
+    a: i64,
+    b: Expression,
+}
+
+impl FourierConvention {
+    /// Convention with parameters `(a, b)`
+    pub fn new(a: i64, b: Expression) -> Self {
+        Self { a, b }
+    }
+
+    /// `(0, 1)`: angular frequency, `1/√(2π)` in both directions
+    pub fn unitary() -> Self {
+        Self::new(0, Expression::integer(1))
+    }
+
+    /// `(1, 1)`: angular frequency, `1/(2π)` on the inverse only
+    pub fn angular() -> Self {
+        Self::new(1, Expression::integer(1))
+    }
+
+    /// `(0, 2π)`: ordinary frequency, no factors at all
+    pub fn ordinary() -> Self {
+        Self::new(
+            0,
+            Expression::mul(vec![Expression::integer(2), Expression::pi()]),
+        )
+    }
+
+    pub fn a(&self) -> i64 {
+        self.a
+    }
+
+    pub fn b(&self) -> &Expression {
+        &self.b
+    }
+
+    /// `√(|b| (2π)^exponent)`, as a product of powers so that 2π cancels
+    fn factor(&self, exponent: i64) -> Expression {
+        let root = |base: Expression, power: i64| {
+            let half = if power % 2 == 0 {
+                Expression::integer(power / 2)
+            } else {
+                Expression::rational(power, 2)
+            };
+            Expression::pow(base, half)
+        };
+        let b_factors = match &self.b {
+            Expression::Mul(factors) => factors.to_vec(),
+            b => vec![b.clone()],
+        };
+        let mut product: Vec<Expression> = b_factors
+            .into_iter()
+            .map(|factor| root(Expression::function("abs", vec![factor]), 1))
+            .collect();
+        product.push(root(Expression::integer(2), exponent));
+        product.push(root(Expression::pi(), exponent));
+        Expression::mul(product)
+    }
+}
+
+impl Default for FourierConvention {
+    fn default() -> Self {
+        Self::unitary()
+    }
+}
+
+/// Fourier transform of expressions in a time variable
+pub trait FourierTransform {
+    /// Transform from `t` to the frequency `omega`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::calculus::transforms::fourier::{FourierConvention, FourierTransform};
+    /// use mathhook_core::{expr, symbol, Simplify};
+    ///
+    /// let (t, w) = (symbol!(t), symbol!(w));
+    /// // e^{-|t|} → 2/(1 + ω²)
+    /// let transform = expr!(exp(-abs(t))).fourier_transform(&t, &w, &FourierConvention::angular());
+    /// assert_eq!(transform, expr!(2 * ((1 + (w ^ 2)) ^ (-1))).simplify());
+    /// ```
+    fn fourier_transform(
+        &self,
+        t: &Symbol,
+        omega: &Symbol,
+        convention: &FourierConvention,
+    ) -> Expression;
+
+    /// Transform from the frequency `omega` back to `t`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::calculus::transforms::fourier::{FourierConvention, FourierTransform};
+    /// use mathhook_core::{expr, symbol, Expression};
+    ///
+    /// let (t, w) = (symbol!(t), symbol!(w));
+    /// // The inverse of 2πδ(ω) is 1
+    /// let spectrum = Expression::mul(vec![
+    ///     expr!(2 * pi),
+    ///     Expression::function("dirac_delta", vec![expr!(w)]),
+    /// ]);
+    /// assert_eq!(
+    ///     spectrum.inverse_fourier_transform(&w, &t, &FourierConvention::angular()),
+    ///     expr!(1)
+    /// );
+    /// ```
+    fn inverse_fourier_transform(
+        &self,
+        omega: &Symbol,
+        t: &Symbol,
+        convention: &FourierConvention,
+    ) -> Expression;
+}
+
+impl FourierTransform for Expression {
+    fn fourier_transform(
+        &self,
+        t: &Symbol,
+        omega: &Symbol,
+        convention: &FourierConvention,
+    ) -> Expression {
+        match transform(self, t, omega) {
+            Some(result) => {
+                let scaled = Expression::mul(vec![
+                    convention.b.clone(),
+                    Expression::symbol(omega.clone()),
+                ]);
+                Expression::mul(vec![
+                    convention.factor(convention.a - 1),
+                    at(&result, omega, scaled),
+                ])
+                .simplify()
+            }
+            None => Expression::function(
+                "fourier_transform",
+                vec![
+                    self.clone(),
+                    Expression::symbol(t.clone()),
+                    Expression::symbol(omega.clone()),
+                ],
+            ),
+        }
+    }
+
+    fn inverse_fourier_transform(
+        &self,
+        omega: &Symbol,
+        t: &Symbol,
+        convention: &FourierConvention,
+    ) -> Expression {
+        match transform(self, omega, t) {
+            Some(result) => {
+                let reflected = Expression::mul(vec![
+                    Expression::integer(-1),
+                    convention.b.clone(),
+                    Expression::symbol(t.clone()),
+                ]);
+                Expression::mul(vec![
+                    convention.factor(-1 - convention.a),
+                    at(&result, t, reflected),
+                ])
+                .simplify()
+            }
+            None => Expression::function(
+                "inverse_fourier_transform",
+                vec![
+                    self.clone(),
+                    Expression::symbol(omega.clone()),
+                    Expression::symbol(t.clone()),
+                ],
+            ),
+        }
+    }
+}
+
+/// Truncated Fourier series of a periodic function
+///
+/// With period `P`, the series is
+/// `constant + Σₙ cosine[n-1]·cos(2πnx/P) + sine[n-1]·sin(2πnx/P)`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FourierSeries {
+    /// Mean value over one period, `a₀/2`
+    pub constant: Expression,
+    /// `aₙ` for `n = 1..=N`
+    pub cosine: Vec<Expression>,
+    /// `bₙ` for `n = 1..=N`
+    pub sine: Vec<Expression>,
+    /// The sum of the first `N` harmonics
+    pub series: Expression,
+}
+
+/// Fourier series of `expr` with period `period`, up to the `n_terms`-th harmonic
+///
+/// The coefficients integrate over `[-P/2, P/2]`, so `expr` is the function
+/// on that interval repeated periodically. A coefficient whose integral has
+/// no closed form is left as an unevaluated definite integral.
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::calculus::transforms::fourier::fourier_series;
+/// use mathhook_core::{expr, symbol, Expression};
+///
+/// let x = symbol!(x);
+/// // Sawtooth x on (-π, π): 2 Σ (-1)ⁿ⁺¹ sin(nx)/n
+/// let sawtooth = fourier_series(&expr!(x), &x, &expr!(2 * pi), 3).unwrap();
+/// assert_eq!(sawtooth.constant, expr!(0));
+/// assert_eq!(
+///     sawtooth.sine,
+///     vec![expr!(2), expr!(-1), Expression::rational(2, 3)]
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Returns the error of [`Expression::integrate_definite`] when a
+/// coefficient integral diverges.
+pub fn fourier_series(
+    expr: &Expression,
+    x: &Symbol,
+    period: &Expression,
+    n_terms: usize,
+) -> Result<FourierSeries, MathError> {
+    // 1/P factor by factor, so that 2π/P cancels for a period like 2π
+    let reciprocal = match period {
+        Expression::Mul(factors) => Expression::mul(
+            factors
+                .iter()
+                .map(|factor| Expression::pow(factor.clone(), Expression::integer(-1)))
+                .collect(),
+        ),
+        _ => Expression::pow(period.clone(), Expression::integer(-1)),
+    };
+    let half = Expression::mul(vec![Expression::rational(1, 2), period.clone()]).simplify();
+    let lower = Expression::mul(vec![Expression::integer(-1), half.clone()]).simplify();
+    let over_period = |integrand: Expression, scale: i64| {
+        let integral = integrand.integrate_definite(x, &lower, &half)?;
+        Ok::<_, MathError>(
+            Expression::mul(vec![
+                Expression::integer(scale),
+                integral,
+                reciprocal.clone(),
+            ])
+            .simplify(),
+        )
+    };
+    // 2πnx/P
+    let phase = |n: usize| {
+        Expression::mul(vec![
+            Expression::integer(2 * n as i64),
+            Expression::pi(),
+            Expression::symbol(x.clone()),
+            reciprocal.clone(),
+        ])
+        .simplify()
+    };
+
+    let constant = over_period(expr.clone(), 1)?;
+    let mut terms = vec![constant.clone()];
+    let (mut cosine, mut sine) = (Vec::with_capacity(n_terms), Vec::with_capacity(n_terms));
+    for n in 1..=n_terms {
+        let cos = Expression::function("cos", vec![phase(n)]);
+        let sin = Expression::function("sin", vec![phase(n)]);
+        let a = over_period(Expression::mul(vec![expr.clone(), cos.clone()]), 2)?;
+        let b = over_period(Expression::mul(vec![expr.clone(), sin.clone()]), 2)?;
+        terms.push(Expression::mul(vec![a.clone(), cos]));
+        terms.push(Expression::mul(vec![b.clone(), sin]));
+        cosine.push(a);
+        sine.push(b);
+    }
+
+    Ok(FourierSeries {
+        constant,
+        cosine,
+        sine,
+        series: Expression::add(terms).simplify(),
+    })
+}
+
+/// `∫ expr e^{-iωt} dt` from the table
+fn transform(expr: &Expression, t: &Symbol, omega: &Symbol) -> Option<Expression> {
+    let w = Expression::symbol(omega.clone());
+    if !expr.contains_variable(t) {
+        return Some(Expression::mul(vec![
+            Expression::integer(2),
+            Expression::pi(),
+            expr.clone(),
+            dirac_delta(&w, 0),
+        ]));
+    }
+
+    match expr {
+        Expression::Add(terms) => terms
+            .iter()
+            .map(|term| transform(term, t, omega))
+            .collect::<Option<Vec<_>>>()
+            .map(Expression::add),
+        Expression::Mul(factors) => transform_product(factors, t, omega),
+        Expression::Symbol(_) => Some(monomial(1, &w)),
+        Expression::Pow(base, exponent) => match (base.as_ref(), exponent.as_ref()) {
+            (Expression::Symbol(symbol), Expression::Number(Number::Integer(n)))
+                if symbol == t && *n >= 0 =>
+            {
+                Some(monomial(*n, &w))
+            }
+            (Expression::Add(terms), Expression::Number(Number::Integer(-1))) => {
+                lorentzian(terms, t, &w)
+            }
+            _ => None,
+        },
+        Expression::Function { name, args, .. } => match name.as_ref() {
+            "exp" if args.len() == 1 => exponential(&args[0], t, &w),
+            "heaviside" => causal(std::slice::from_ref(expr), t, &w),
+            _ => transform_function(name, args, t, &w),
+        },
+        _ => None,
+    }
+}
+
+/// tⁿ → 2π iⁿ δ⁽ⁿ⁾(ω)
+fn monomial(n: i64, w: &Expression) -> Expression {
+    Expression::mul(vec![
+        Expression::integer(2),
+        Expression::pi(),
+        Expression::pow(Expression::i(), Expression::integer(n)),
+        dirac_delta(w, n),
+    ])
+}
+
+/// Sinusoids and impulses, whose arguments are linear in `t`
+fn transform_function(
+    name: &str,
+    args: &[Expression],
+    t: &Symbol,
+    w: &Expression,
+) -> Option<Expression> {
+    let (rate, offset) = linear_coefficients(args.first()?, t)?;
+    let phase = |sign: i64| {
+        Expression::function(
+            "exp",
+            vec![Expression::mul(vec![
+                Expression::integer(sign),
+                Expression::i(),
+                offset.clone(),
+            ])],
+        )
+    };
+    let shifted_delta = |sign: i64| {
+        dirac_delta(
+            &Expression::add(vec![
+                w.clone(),
+                Expression::mul(vec![Expression::integer(sign), rate.clone()]),
+            ]),
+            0,
+        )
+    };
+    // e^{ib} δ(ω - a) and e^{-ib} δ(ω + a) for the argument at + b
+    let up = Expression::mul(vec![phase(1), shifted_delta(-1)]);
+    let down = Expression::mul(vec![phase(-1), shifted_delta(1)]);
+
+    match name {
+        "cos" if args.len() == 1 => Some(Expression::mul(vec![
+            Expression::pi(),
+            Expression::add(vec![up, down]),
+        ])),
+        "sin" if args.len() == 1 => Some(Expression::mul(vec![
+            Expression::integer(-1),
+            Expression::i(),
+            Expression::pi(),
+            Expression::add(vec![
+                up,
+                Expression::mul(vec![Expression::integer(-1), down]),
+            ]),
+        ])),
+        "dirac_delta" if rate.is_positive_number() => {
+            let order = delta_order(args)?;
+            // δ⁽ⁿ⁾(k(t - a)) = δ⁽ⁿ⁾(t - a)/kⁿ⁺¹ → (iω)ⁿ e^{-iaω}/kⁿ⁺¹
+            let delay = Expression::mul(vec![
+                Expression::integer(-1),
+                offset.clone(),
+                Expression::pow(rate.clone(), Expression::integer(-1)),
+            ]);
+            Some(Expression::mul(vec![
+                Expression::pow(
+                    Expression::mul(vec![Expression::i(), w.clone()]),
+                    Expression::integer(order),
+                ),
+                Expression::function(
+                    "exp",
+                    vec![Expression::mul(vec![
+                        Expression::integer(-1),
+                        Expression::i(),
+                        delay,
+                        w.clone(),
+                    ])],
+                ),
+                Expression::pow(rate, Expression::integer(-(order + 1))),
+            ]))
+        }
+        _ => None,
+    }
+}
+
+/// Pure oscillations, two-sided exponentials and Gaussians
+fn exponential(arg: &Expression, t: &Symbol, w: &Expression) -> Option<Expression> {
+    if let Some((rate, offset)) = linear_coefficients(arg, t) {
+        // e^{iat + b} → 2π e^b δ(ω - a)
+        let a = frequency(&rate)?;
+        return Some(Expression::mul(vec![
+            Expression::integer(2),
+            Expression::pi(),
+            Expression::function("exp", vec![offset]),
+            dirac_delta(
+                &Expression::add(vec![
+                    w.clone(),
+                    Expression::mul(vec![Expression::integer(-1), a]),
+                ]),
+                0,
+            ),
+        ]));
+    }
+
+    let t_expr = Expression::symbol(t.clone());
+    let decay = |pattern: &Expression| {
+        let a = Expression::mul(vec![
+            Expression::integer(-1),
+            coefficient_of(arg, pattern, t)?,
+        ])
+        .simplify();
+        (!a.is_negative_number() && !a.is_zero()).then_some(a)
+    };
+    if let Some(a) = decay(&Expression::function("abs", vec![t_expr.clone()])) {
+        // e^{-a|t|} → 2a/(a² + ω²)
+        return Some(Expression::mul(vec![
+            Expression::integer(2),
+            a.clone(),
+            Expression::pow(
+                Expression::add(vec![
+                    Expression::pow(a, Expression::integer(2)),
+                    Expression::pow(w.clone(), Expression::integer(2)),
+                ]),
+                Expression::integer(-1),
+            ),
+        ]));
+    }
+    let a = decay(&Expression::pow(t_expr, Expression::integer(2)))?;
+    // e^{-at²} → √(π/a) e^{-ω²/(4a)}
+    Some(Expression::mul(vec![
+        Expression::sqrt(Expression::mul(vec![
+            Expression::pi(),
+            Expression::pow(a.clone(), Expression::integer(-1)),
+        ])),
+        Expression::function(
+            "exp",
+            vec![Expression::mul(vec![
+                Expression::rational(-1, 4),
+                Expression::pow(w.clone(), Expression::integer(2)),
+                Expression::pow(a, Expression::integer(-1)),
+            ])],
+        ),
+    ]))
+}
+
+/// 1/(pt² + q) = (1/p)/(t² + a²) → (π/(pa)) e^{-a|ω|} with a = √(q/p)
+fn lorentzian(terms: &[Expression], t: &Symbol, w: &Expression) -> Option<Expression> {
+    let (constants, varying): (Vec<Expression>, Vec<Expression>) = terms
+        .iter()
+        .cloned()
+        .partition(|term| !term.contains_variable(t));
+    let [quadratic] = varying.as_slice() else {
+        return None;
+    };
+    let square = Expression::pow(Expression::symbol(t.clone()), Expression::integer(2));
+    let p = coefficient_of(quadratic, &square, t)?;
+    let q = Expression::add(constants);
+    if q.is_zero() {
+        return None;
+    }
+    let a = Expression::sqrt(Expression::mul(vec![
+        q,
+        Expression::pow(p.clone(), Expression::integer(-1)),
+    ]))
+    .simplify();
+    Some(Expression::mul(vec![
+        Expression::pi(),
+        Expression::pow(Expression::mul(vec![p, a.clone()]), Expression::integer(-1)),
+        Expression::function(
+            "exp",
+            vec![Expression::mul(vec![
+                Expression::integer(-1),
+                a,
+                Expression::function("abs", vec![w.clone()]),
+            ])],
+        ),
+    ]))
+}
+
+/// Constant multiples, causal signals and the modulation theorem
+fn transform_product(factors: &[Expression], t: &Symbol, omega: &Symbol) -> Option<Expression> {
+    let (constants, varying): (Vec<Expression>, Vec<Expression>) = factors
+        .iter()
+        .cloned()
+        .partition(|factor| !factor.contains_variable(t));
+    if !constants.is_empty() {
+        let inner = transform(&Expression::mul(varying), t, omega)?;
+        let mut product = constants;
+        product.push(inner);
+        return Some(Expression::mul(product));
+    }
+    if varying.len() == 1 {
+        return transform(&varying[0], t, omega);
+    }
+    let w = Expression::symbol(omega.clone());
+    if let Some(result) = causal(&varying, t, &w) {
+        return Some(result);
+    }
+
+    for (position, factor) in varying.iter().enumerate() {
+        let Expression::Function { name, args, .. } = factor else {
+            continue;
+        };
+        if name.as_ref() != "exp" || args.len() != 1 {
+            continue;
+        }
+        let Some((rate, offset)) = linear_coefficients(&args[0], t) else {
+            continue;
+        };
+        let Some(a) = frequency(&rate) else {
+            continue;
+        };
+        // e^{iat + b} g(t) → e^b G(ω - a)
+        let rest = Expression::mul(
+            varying
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| *i != position)
+                .map(|(_, factor)| factor.clone())
+                .collect(),
+        );
+        let inner = transform(&rest, t, omega)?;
+        let shifted = Expression::add(vec![
+            w.clone(),
+            Expression::mul(vec![Expression::integer(-1), a]),
+        ]);
+        return Some(Expression::mul(vec![
+            Expression::function("exp", vec![offset]),
+            at(&inner, omega, shifted),
+        ]));
+    }
+    None
+}
+
+/// H(t) tⁿ e^{kt + b} → e^b n!/(iω - k)ⁿ⁺¹, and H(t) → πδ(ω) - i/ω
+fn causal(factors: &[Expression], t: &Symbol, w: &Expression) -> Option<Expression> {
+    let mut step = false;
+    let mut power = 0;
+    let mut rates = Vec::new();
+    let mut offsets = Vec::new();
+    for factor in factors {
+        match factor {
+            Expression::Function { name, args, .. } if args.len() == 1 => {
+                let (rate, offset) = linear_coefficients(&args[0], t)?;
+                match name.as_ref() {
+                    "heaviside" if !step && rate.is_positive_number() && offset.is_zero() => {
+                        step = true;
+                    }
+                    "exp" if frequency(&rate).is_none() => {
+                        rates.push(rate);
+                        offsets.push(Expression::function("exp", vec![offset]));
+                    }
+                    _ => return None,
+                }
+            }
+            Expression::Symbol(symbol) if symbol == t => power += 1,
+            Expression::Pow(base, exponent) => match (base.as_ref(), exponent.as_ref()) {
+                (Expression::Symbol(symbol), Expression::Number(Number::Integer(n)))
+                    if symbol == t && *n > 0 =>
+                {
+                    power += n;
+                }
+                _ => return None,
+            },
+            _ => return None,
+        }
+    }
+    if !step {
+        return None;
+    }
+
+    let rate = Expression::add(rates).simplify();
+    let mut product = offsets;
+    if rate.is_zero() {
+        if power != 0 {
+            return None;
+        }
+        product.push(Expression::add(vec![
+            Expression::mul(vec![Expression::pi(), dirac_delta(w, 0)]),
+            Expression::mul(vec![
+                Expression::integer(-1),
+                Expression::i(),
+                Expression::pow(w.clone(), Expression::integer(-1)),
+            ]),
+        ]));
+    } else {
+        product.push(Expression::function(
+            "factorial",
+            vec![Expression::integer(power)],
+        ));
+        product.push(Expression::pow(
+            Expression::add(vec![
+                Expression::mul(vec![Expression::i(), w.clone()]),
+                Expression::mul(vec![Expression::integer(-1), rate]),
+            ]),
+            Expression::integer(-(power + 1)),
+        ));
+    }
+    Some(Expression::mul(product))
+}
+
+/// `a` when `rate` is `a·i` with `a` free of `i`
+fn frequency(rate: &Expression) -> Option<Expression> {
+    let is_i = |e: &Expression| matches!(e, Expression::Constant(MathConstant::I));
+    match rate {
+        _ if is_i(rate) => Some(Expression::integer(1)),
+        Expression::Mul(factors) if factors.iter().filter(|f| is_i(f)).count() == 1 => Some(
+            Expression::mul(factors.iter().filter(|f| !is_i(f)).cloned().collect()),
+        ),
+        _ => None,
+    }
+}
+
+/// `c` when `expr` is `c·pattern` with `c` free of `t`
+fn coefficient_of(expr: &Expression, pattern: &Expression, t: &Symbol) -> Option<Expression> {
+    if expr == pattern {
+        return Some(Expression::integer(1));
+    }
+    let Expression::Mul(factors) = expr else {
+        return None;
+    };
+    let (constants, varying): (Vec<Expression>, Vec<Expression>) = factors
+        .iter()
+        .cloned()
+        .partition(|factor| !factor.contains_variable(t));
+    (varying.as_slice() == std::slice::from_ref(pattern)).then(|| Expression::mul(constants))
+}
+
+/// `expr` with `var` replaced by `value`
+fn at(expr: &Expression, var: &Symbol, value: Expression) -> Expression {
+    expr.substitute(&HashMap::from([(var.name().to_owned(), value)]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{expr, symbol};
+
+    /// F(ω) at a sample point, to compare transforms independent of form
+    fn at_frequency(transform: &Expression, w: f64) -> f64 {
+        at(transform, &symbol!(w), Expression::float(w))
+            .evaluate_to_f64()
+            .unwrap()
+    }
+
+    fn assert_transform(
+        f: Expression,
+        convention: &FourierConvention,
+        expected: impl Fn(f64) -> f64,
+    ) {
+        let result = f.fourier_transform(&symbol!(t), &symbol!(w), convention);
+        for w in [0.5, 1.0, 2.5] {
+            assert!(
+                (at_frequency(&result, w) - expected(w)).abs() < 1e-9,
+                "F{{{}}} = {} at ω = {}",
+                f,
+                result,
+                w
+            );
+        }
+    }
+
+    #[test]
+    fn test_table_entries() {
+        let angular = FourierConvention::angular();
+        assert_transform(expr!(exp(-2 * abs(t))), &angular, |w| 4.0 / (4.0 + w * w));
+        assert_transform(expr!(exp(-(t ^ 2))), &angular, |w| {
+            std::f64::consts::PI.sqrt() * (-w * w / 4.0).exp()
+        });
+        assert_transform(
+            Expression::pow(expr!((t ^ 2) + 4), Expression::integer(-1)),
+            &angular,
+            |w| std::f64::consts::PI / 2.0 * (-2.0 * w).exp(),
+        );
+    }
+
+    #[test]
+    fn test_conventions() {
+        use std::f64::consts::PI;
+        let gaussian = expr!(exp(-(t ^ 2) / 2));
+        // The Gaussian e^{-t²/2} is its own unitary transform
+        assert_transform(gaussian, &FourierConvention::unitary(), |w| {
+            (-w * w / 2.0).exp()
+        });
+        // e^{-πt²} is its own transform in ordinary frequency
+        let gaussian = Expression::function(
+            "exp",
+            vec![Expression::mul(vec![
+                Expression::integer(-1),
+                Expression::pi(),
+                expr!(t ^ 2),
+            ])],
+        );
+        assert_transform(gaussian, &FourierConvention::ordinary(), |w| {
+            (-PI * w * w).exp()
+        });
+    }
+
+    #[test]
+    fn test_distributions_and_oscillations() {
+        let (t, w) = (symbol!(t), symbol!(w));
+        let angular = FourierConvention::angular();
+        let delta = |arg: Expression| Expression::function("dirac_delta", vec![arg]);
+
+        assert_eq!(
+            expr!(cos(3 * t)).fourier_transform(&t, &w, &angular),
+            Expression::mul(vec![
+                Expression::pi(),
+                Expression::add(vec![delta(expr!(w - 3)), delta(expr!(w + 3))]),
+            ])
+            .simplify()
+        );
+        assert_eq!(
+            expr!(5).fourier_transform(&t, &w, &angular),
+            Expression::mul(vec![expr!(10), Expression::pi(), delta(expr!(w))]).simplify()
+        );
+        // A unit impulse delayed by 2 picks up the phase e^{-2iω}
+        let delayed = delta(expr!(t - 2)).fourier_transform(&t, &w, &angular);
+        assert_eq!(
+            delayed,
+            Expression::function(
+                "exp",
+                vec![Expression::mul(vec![
+                    Expression::integer(-2),
+                    Expression::i(),
+                    expr!(w),
+                ])],
+            )
+            .simplify()
+        );
+    }
+
+    #[test]
+    fn test_causal_and_modulated() {
+        let (t, w) = (symbol!(t), symbol!(w));
+        let angular = FourierConvention::angular();
+        let step = Expression::function("heaviside", vec![expr!(t)]);
+
+        // H(t) e^{-t} → 1/(1 + iω)
+        let decay = Expression::mul(vec![step.clone(), expr!(exp(-t))]);
+        assert_eq!(
+            decay.fourier_transform(&t, &w, &angular),
+            Expression::pow(
+                Expression::add(vec![
+                    Expression::integer(1),
+                    Expression::mul(vec![Expression::i(), expr!(w)]),
+                ]),
+                Expression::integer(-1),
+            )
+            .simplify()
+        );
+
+        // e^{2it} e^{-|t|} → 2/(1 + (ω - 2)²)
+        let modulated = Expression::mul(vec![
+            Expression::function(
+                "exp",
+                vec![Expression::mul(vec![
+                    Expression::integer(2),
+                    Expression::i(),
+                    expr!(t),
+                ])],
+            ),
+            expr!(exp(-abs(t))),
+        ]);
+        assert_transform(modulated, &angular, |w| 2.0 / (1.0 + (w - 2.0).powi(2)));
+    }
+
+    #[test]
+    fn test_inverse_round_trip() {
+        let (t, w) = (symbol!(t), symbol!(w));
+        for convention in [
+            FourierConvention::unitary(),
+            FourierConvention::angular(),
+            FourierConvention::ordinary(),
+        ] {
+            let f = expr!(exp(-3 * abs(t)));
+            let round_trip = f
+                .fourier_transform(&t, &w, &convention)
+                .inverse_fourier_transform(&w, &t, &convention);
+            for x in [0.25, 1.0, 2.0] {
+                let value = at(&round_trip, &t, Expression::float(x))
+                    .evaluate_to_f64()
+                    .unwrap();
+                assert!(
+                    (value - (-3.0 * x).exp()).abs() < 1e-9,
+                    "{:?}: {}",
+                    convention,
+                    round_trip
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_unknown_stays_unevaluated() {
+        let (t, w) = (symbol!(t), symbol!(w));
+        let result = expr!(exp(t)).fourier_transform(&t, &w, &FourierConvention::default());
+        assert!(
+            matches!(result, Expression::Function { ref name, .. } if name.as_ref() == "fourier_transform")
+        );
+    }
+
+    #[test]
+    fn test_series_coefficients() {
+        use std::f64::consts::PI;
+        let x = symbol!(x);
+        // x² on (-π, π): π²/3 + Σ 4(-1)ⁿ cos(nx)/n²
+        let parabola = fourier_series(&expr!(x ^ 2), &x, &expr!(2 * pi), 3).unwrap();
+        let value = |e: &Expression| e.evaluate_to_f64().unwrap();
+        assert!((value(&parabola.constant) - PI * PI / 3.0).abs() < 1e-12);
+        for (n, a) in parabola.cosine.iter().enumerate() {
+            let n = (n + 1) as f64;
+            let expected = 4.0 * (-1f64).powi(n as i32) / (n * n);
+            assert!((value(a) - expected).abs() < 1e-12, "a_{} = {}", n, a);
+        }
+        assert!(parabola.sine.iter().all(Expression::is_zero));
+
+        // A symbolic period works the same way
+        let l = symbol!(L);
+        let line = fourier_series(&expr!(x), &x, &expr!(2 * L), 1).unwrap();
+        let expected = expr!(2 * L / pi).simplify();
+        let at_l = |e: &Expression| at(e, &l, Expression::integer(3)).evaluate_to_f64().unwrap();
+        assert!((at_l(&line.sine[0]) - at_l(&expected)).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,525 @@
+//! Bytecode compilation for repeated numeric evaluation
+//!
+//! [`compile`] lowers an expression once into a flat sequence of stack
+//! machine instructions over `f64`. Evaluating it is a single loop over that
+//! sequence: no tree walk, no `Expression` allocation and no simplification
+//! per point, which makes it one to two orders of magnitude faster than
+//! substituting and calling [`EvalNumeric`](crate::EvalNumeric) for every
+//! point of a plot, a root search or a quadrature rule.
+//!
+//! While lowering, subtrees free of the variables are folded to constants,
+//! sums of negated terms become subtractions, negative powers become
+//! divisions, and polynomials of degree two or more in one variable are
+//! evaluated by Horner's scheme.
+//!
+//! Domain errors follow IEEE semantics like [`Expression::compile`]: `√-1`
+//! and `ln(0)` give NaN and `-∞` instead of an error.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use mathhook_core::codegen;
+//! use mathhook_core::{expr, symbol};
+//!
+//! let x = symbol!(x);
+//! let y = symbol!(y);
+//! let f = codegen::compile(&expr!((x ^ 2) - (y / x)), &[x, y]).unwrap();
+//! assert_eq!(f.evaluate(&[2.0, 6.0]), 1.0);
+//! ```
+
+use crate::core::expression::compiled::{dispatch, native, number_value};
+use crate::core::polynomial::{polynomial_terms, sparse_horner};
+use crate::core::{Expression, Number, Symbol};
+use crate::error::MathError;
+use std::sync::Arc;
+
+/// Stack depth evaluated without a heap allocation
+const INLINE_STACK: usize = 32;
+
+/// Expression compiled to stack machine bytecode
+#[derive(Debug, Clone)]
+pub struct CompiledFn {
+    code: Vec<Op>,
+    arity: usize,
+    stack_size: usize,
+}
+
+/// One stack machine instruction; operands are popped from the top of the
+/// stack and the result is pushed
+#[derive(Debug, Clone)]
+enum Op {
+    Const(f64),
+    Load(usize),
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Pow,
+    Neg,
+    Recip,
+    Square,
+    PowInt(i32),
+    Call(fn(f64) -> f64),
+    /// Polynomial in one variable, coefficients on the stack highest degree first
+    Horner {
+        variable: usize,
+        degrees: Box<[u32]>,
+    },
+    /// Function without a native implementation, through the function registry
+    Dispatch {
+        name: Arc<str>,
+        arity: usize,
+    },
+}
+
+impl Op {
+    /// Number of values popped
+    fn operands(&self) -> usize {
+        match self {
+            Op::Const(_) | Op::Load(_) => 0,
+            Op::Add | Op::Sub | Op::Mul | Op::Div | Op::Pow => 2,
+            Op::Neg | Op::Recip | Op::Square | Op::PowInt(_) | Op::Call(_) => 1,
+            Op::Horner { degrees, .. } => degrees.len(),
+            Op::Dispatch { arity, .. } => *arity,
+        }
+    }
+}
+
+/// Compile `expr` for evaluation at points with one coordinate per variable
+///
+/// # Errors
+///
+/// Returns `MathError::NonNumericalResult` if the expression contains a
+/// symbol not listed in `variables`, or a node with no numeric value
+/// (matrices, sets, relations, unevaluated calculus).
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::codegen;
+/// use mathhook_core::{expr, symbol};
+///
+/// let t = symbol!(t);
+/// let f = codegen::compile(&expr!(exp(t) * sin(t)), &[t]).unwrap();
+/// let samples: Vec<f64> = (0..100).map(|i| f.evaluate(&[i as f64 / 100.0])).collect();
+/// assert_eq!(samples[0], 0.0);
+/// ```
+pub fn compile(expr: &Expression, variables: &[Symbol]) -> Result<CompiledFn, MathError> {
+    let mut emitter = Emitter {
+        variables,
+        code: Vec::new(),
+        depth: 0,
+        max_depth: 0,
+    };
+    emitter.lower(expr)?;
+    Ok(CompiledFn {
+        code: emitter.code,
+        arity: variables.len(),
+        stack_size: emitter.max_depth,
+    })
+}
+
+impl CompiledFn {
+    /// Evaluate at `point`, one coordinate per compiled variable
+    ///
+    /// # Panics
+    ///
+    /// Panics if `point` has fewer coordinates than there are variables.
+    pub fn evaluate(&self, point: &[f64]) -> f64 {
+        assert!(
+            point.len() >= self.arity,
+            "expected {} coordinates, got {}",
+            self.arity,
+            point.len()
+        );
+        if self.stack_size <= INLINE_STACK {
+            execute(&self.code, point, &mut [0.0; INLINE_STACK])
+        } else {
+            execute(&self.code, point, &mut vec![0.0; self.stack_size])
+        }
+    }
+
+    /// Number of variables
+    pub fn arity(&self) -> usize {
+        self.arity
+    }
+
+    /// Number of bytecode instructions
+    pub fn len(&self) -> usize {
+        self.code.len()
+    }
+
+    /// Always false: every expression compiles to at least one instruction
+    pub fn is_empty(&self) -> bool {
+        self.code.is_empty()
+    }
+}
+
+/// Run `code` on `stack`, which must hold the program's maximum depth
+fn execute(code: &[Op], point: &[f64], stack: &mut [f64]) -> f64 {
+    let mut top = 0;
+    for op in code {
+        match op {
+            Op::Const(value) => {
+                stack[top] = *value;
+                top += 1;
+            }
+            Op::Load(index) => {
+                stack[top] = point[*index];
+                top += 1;
+            }
+            Op::Add => {
+                top -= 1;
+                stack[top - 1] += stack[top];
+            }
+            Op::Sub => {
+                top -= 1;
+                stack[top - 1] -= stack[top];
+            }
+            Op::Mul => {
+                top -= 1;
+                stack[top - 1] *= stack[top];
+            }
+            Op::Div => {
+                top -= 1;
+                stack[top - 1] /= stack[top];
+            }
+            Op::Pow => {
+                top -= 1;
+                stack[top - 1] = stack[top - 1].powf(stack[top]);
+            }
+            Op::Neg => stack[top - 1] = -stack[top - 1],
+            Op::Recip => stack[top - 1] = stack[top - 1].recip(),
+            Op::Square => stack[top - 1] *= stack[top - 1],
+            Op::PowInt(n) => stack[top - 1] = stack[top - 1].powi(*n),
+            Op::Call(f) => stack[top - 1] = f(stack[top - 1]),
+            Op::Horner { variable, degrees } => {
+                top -= degrees.len();
+                let coefficients = stack[top..].iter().copied();
+                stack[top] =
+                    sparse_horner(degrees.iter().copied().zip(coefficients), point[*variable]);
+                top += 1;
+            }
+            Op::Dispatch { name, arity } => {
+                top -= arity;
+                stack[top] = dispatch(name, stack[top..top + arity].iter().copied());
+                top += 1;
+            }
+        }
+    }
+    stack[0]
+}
+
+/// Lowers expressions to postfix code, tracking the stack depth
+struct Emitter<'a> {
+    variables: &'a [Symbol],
+    code: Vec<Op>,
+    depth: usize,
+    max_depth: usize,
+}
+
+impl Emitter<'_> {
+    /// Append `op`, or fold it into a constant when all its operands are
+    /// constants; Horner code also reads its variable and is never folded
+    fn emit(&mut self, op: Op) {
+        let operands = op.operands();
+        self.depth = self.depth + 1 - operands;
+        self.max_depth = self.max_depth.max(self.depth);
+
+        let start = self.code.len().saturating_sub(operands);
+        let foldable = operands > 0
+            && !matches!(op, Op::Horner { .. })
+            && self.code.len() >= operands
+            && self.code[start..]
+                .iter()
+                .all(|op| matches!(op, Op::Const(_)));
+        if foldable {
+            let mut program: Vec<Op> = self.code.drain(start..).collect();
+            program.push(op);
+            let value = execute(&program, &[], &mut vec![0.0; operands]);
+            self.code.push(Op::Const(value));
+        } else {
+            self.code.push(op);
+        }
+    }
+
+    fn lower(&mut self, expr: &Expression) -> Result<(), MathError> {
+        match expr {
+            Expression::Number(number) => self.emit(Op::Const(number_value(number))),
+            Expression::Constant(constant) => self.emit(Op::Const(constant.to_f64())),
+            Expression::Symbol(symbol) => {
+                let index = self
+                    .variables
+                    .iter()
+                    .position(|v| v == symbol)
+                    .ok_or_else(|| MathError::NonNumericalResult {
+                        expression: expr.clone(),
+                    })?;
+                self.emit(Op::Load(index));
+            }
+            Expression::Add(terms) => {
+                if !self.lower_polynomial(expr)? {
+                    self.lower_sum(terms)?;
+                }
+            }
+            Expression::Mul(factors) => self.lower_product(factors)?,
+            Expression::Pow(base, exp) => self.lower_power(base, exp)?,
+            Expression::Function { name, args, .. } => {
+                for arg in args.iter() {
+                    self.lower(arg)?;
+                }
+                match native(name) {
+                    Some(f) if args.len() == 1 => self.emit(Op::Call(f)),
+                    _ => self.emit(Op::Dispatch {
+                        name: Arc::clone(name),
+                        arity: args.len(),
+                    }),
+                }
+            }
+            _ => {
+                return Err(MathError::NonNumericalResult {
+                    expression: expr.clone(),
+                })
+            }
+        }
+        Ok(())
+    }
+
+    /// Horner code for a sum that is a polynomial of degree two or more in
+    /// one of the variables, trying them in order
+    fn lower_polynomial(&mut self, expr: &Expression) -> Result<bool, MathError> {
+        for (variable, symbol) in self.variables.iter().enumerate() {
+            let Some(terms) = polynomial_terms(expr, symbol) else {
+                continue;
+            };
+            if terms.len() < 2 || terms[0].0 < 2 {
+                continue;
+            }
+            for (_, coefficient) in &terms {
+                self.lower(coefficient)?;
+            }
+            self.emit(Op::Horner {
+                variable,
+                degrees: terms.iter().map(|(degree, _)| *degree).collect(),
+            });
+            return Ok(true);
+        }
+        Ok(false)
+    }
+
+    /// Sum with negated terms subtracted
+    fn lower_sum(&mut self, terms: &[Expression]) -> Result<(), MathError> {
+        for (i, term) in terms.iter().enumerate() {
+            match (i, negated_term(term)) {
+                (0, Some(magnitude)) => {
+                    self.lower(&magnitude)?;
+                    self.emit(Op::Neg);
+                }
+                (0, None) => self.lower(term)?,
+                (_, Some(magnitude)) => {
+                    self.lower(&magnitude)?;
+                    self.emit(Op::Sub);
+                }
+                (_, None) => {
+                    self.lower(term)?;
+                    self.emit(Op::Add);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Product with factors of negative power divided out
+    fn lower_product(&mut self, factors: &[Expression]) -> Result<(), MathError> {
+        let (negate, factors) = match factors.split_first() {
+            Some((Expression::Number(Number::Integer(-1)), rest)) if !rest.is_empty() => {
+                (true, rest)
+            }
+            _ => (false, factors),
+        };
+
+        let mut numerator = Vec::with_capacity(factors.len());
+        let mut denominator = Vec::new();
+        for factor in factors {
+            match reciprocal(factor) {
+                Some(divisor) => denominator.push(divisor),
+                None => numerator.push(factor),
+            }
+        }
+
+        if numerator.is_empty() {
+            self.emit(Op::Const(1.0));
+        }
+        for (i, factor) in numerator.into_iter().enumerate() {
+            self.lower(factor)?;
+            if i > 0 {
+                self.emit(Op::Mul);
+            }
+        }
+        for divisor in &denominator {
+            self.lower(divisor)?;
+            self.emit(Op::Div);
+        }
+        if negate {
+            self.emit(Op::Neg);
+        }
+        Ok(())
+    }
+
+    fn lower_power(&mut self, base: &Expression, exp: &Expression) -> Result<(), MathError> {
+        self.lower(base)?;
+        match exp {
+            Expression::Number(Number::Integer(n)) => match i32::try_from(*n) {
+                Ok(1) => {}
+                Ok(2) => self.emit(Op::Square),
+                Ok(-1) => self.emit(Op::Recip),
+                Ok(n) => self.emit(Op::PowInt(n)),
+                Err(_) => {
+                    self.lower(exp)?;
+                    self.emit(Op::Pow);
+                }
+            },
+            Expression::Number(Number::Rational(r))
+                if *r.denom() == 2.into() && r.numer().magnitude() == &1u32.into() =>
+            {
+                self.emit(Op::Call(f64::sqrt));
+                if exp.is_negative_number() {
+                    self.emit(Op::Recip);
+                }
+            }
+            _ => {
+                self.lower(exp)?;
+                self.emit(Op::Pow);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Magnitude of a term with a negative leading coefficient
+fn negated_term(term: &Expression) -> Option<Expression> {
+    match term {
+        Expression::Number(num) if term.is_negative_number() => {
+            Some(Expression::Number((-num.clone()).ok()?))
+        }
+        Expression::Mul(factors) => {
+            let (Expression::Number(coefficient), rest) = factors.split_first()? else {
+                return None;
+            };
+            if rest.is_empty() || !factors[0].is_negative_number() {
+                return None;
+            }
+            let magnitude = (-coefficient.clone()).ok()?;
+            let mut rest = rest.to_vec();
+            if !magnitude.is_one() {
+                rest.insert(0, Expression::Number(magnitude));
+            }
+            Some(match rest.len() {
+                1 => rest.remove(0),
+                _ => Expression::Mul(Arc::new(rest)),
+            })
+        }
+        _ => None,
+    }
+}
+
+/// `b^|n|` for a factor `b^n` with a negative integer exponent
+fn reciprocal(factor: &Expression) -> Option<Expression> {
+    let Expression::Pow(base, exp) = factor else {
+        return None;
+    };
+    match exp.as_ref() {
+        Expression::Number(Number::Integer(-1)) => Some(base.as_ref().clone()),
+        Expression::Number(Number::Integer(n)) if *n < 0 => Some(Expression::Pow(
+            base.clone(),
+            Arc::new(Expression::integer(n.checked_neg()?)),
+        )),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{expr, symbol};
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() <= 1e-12 * expected.abs().max(1.0),
+            "{} != {}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn test_matches_tree_evaluation() {
+        let x = symbol!(x);
+        let y = symbol!(y);
+        let variables = [x.clone(), y.clone()];
+        let expressions = [
+            expr!((sin(x) ^ 2) + (exp(-x) * sqrt(x)) + ln(x + 1)),
+            expr!((x ^ 3) - (2 * x * y) + (y ^ 2) - 7),
+            expr!((x + y) / (x - y)),
+            expr!(x ^ (-3)),
+            expr!(y ^ x),
+            expr!(-(x * cos(y))),
+        ];
+        for expression in &expressions {
+            let bytecode = compile(expression, &variables).unwrap();
+            let tree = expression.compile(&variables).unwrap();
+            for point in [[0.25, 1.5], [1.0, -2.0], [2.5, 0.75]] {
+                assert_close(bytecode.evaluate(&point), tree.evaluate(&point));
+            }
+        }
+    }
+
+    #[test]
+    fn test_subtraction_and_division() {
+        let x = symbol!(x);
+        let y = symbol!(y);
+        let z = symbol!(z);
+        let f = compile(&expr!(x - (y / z)), &[x, y, z]).unwrap();
+        assert!(matches!(
+            f.code.as_slice(),
+            [Op::Load(0), Op::Load(1), Op::Load(2), Op::Div, Op::Sub]
+        ));
+        assert_eq!(f.evaluate(&[1.0, 6.0, 3.0]), -1.0);
+    }
+
+    #[test]
+    fn test_constant_subtrees_are_folded() {
+        let x = symbol!(x);
+        let f = compile(&expr!(x + sin(1) * sqrt(2)), &[x]).unwrap();
+        assert_eq!(f.len(), 3);
+        assert_close(f.evaluate(&[0.0]), 1f64.sin() * 2f64.sqrt());
+    }
+
+    #[test]
+    fn test_dispatch_and_deep_stack() {
+        let x = symbol!(x);
+        let gamma = Expression::function("gamma", vec![Expression::symbol(x.clone())]);
+        let f = compile(&gamma, std::slice::from_ref(&x)).unwrap();
+        assert_close(f.evaluate(&[5.0]), 24.0);
+
+        // Expands to a polynomial with more coefficients than the inline stack holds
+        let mut nested = Expression::symbol(x.clone());
+        for _ in 0..2 * INLINE_STACK {
+            nested = Expression::Add(Arc::new(vec![
+                Expression::symbol(x.clone()),
+                Expression::Mul(Arc::new(vec![Expression::symbol(x.clone()), nested])),
+            ]));
+        }
+        let f = compile(&nested, std::slice::from_ref(&x)).unwrap();
+        assert!(f.stack_size > INLINE_STACK);
+        assert_eq!(f.evaluate(&[1.0]), (2 * INLINE_STACK + 1) as f64);
+    }
+
+    #[test]
+    fn test_errors_and_domain() {
+        let x = symbol!(x);
+        assert!(compile(&expr!(x + y), std::slice::from_ref(&x)).is_err());
+        let f = compile(&expr!(ln(x)), &[x]).unwrap();
+        assert!(f.evaluate(&[-1.0]).is_nan());
+    }
+}
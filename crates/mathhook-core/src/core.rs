@@ -1,5 +1,6 @@
 //! Core mathematical types and operations
 
+pub mod angle_unit;
 pub mod commutativity;
 pub mod constants;
 pub mod expression;
@@ -7,8 +8,10 @@ pub mod matrix;
 pub mod number;
 pub mod performance;
 pub mod polynomial;
+pub mod provenance;
 pub mod symbol;
 
+pub use angle_unit::AngleUnit;
 pub use commutativity::Commutativity;
 pub use constants::MathConstant;
 pub use expression::Expression;
@@ -17,4 +20,5 @@ pub use matrix::NumericMatrix;
 pub use number::Number;
 pub use performance::*;
 pub use polynomial::{PolynomialClassification, PolynomialError, PolynomialProperties};
+pub use provenance::{AlgorithmId, Provenance};
 pub use symbol::{Symbol, SymbolType};
@@ -0,0 +1,161 @@
+//! Angle units for trigonometric functions
+//!
+//! Expressions always treat trigonometric arguments as radians. In degree
+//! mode, `sin(30)` means `sin(30°)`, so evaluation first rewrites each
+//! argument to radians (`sin(π/6)`) and each inverse function's result to
+//! degrees, after which the special-value table gives exact results.
+
+use crate::core::{Expression, MathConstant};
+use crate::simplify::Simplify;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// Functions taking an angle
+pub(crate) const ANGLE_FUNCTIONS: &[&str] = &["sin", "cos", "tan", "sec", "csc", "cot"];
+
+/// Functions returning an angle
+const INVERSE_ANGLE_FUNCTIONS: &[&str] = &[
+    "arcsin", "arccos", "arctan", "arcsec", "arccsc", "arccot", "asin", "acos", "atan",
+];
+
+/// Unit of the angles in trigonometric functions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum AngleUnit {
+    #[default]
+    Radians,
+    Degrees,
+}
+
+impl AngleUnit {
+    /// `angle` in this unit converted to radians
+    pub fn to_radians(self, angle: Expression) -> Expression {
+        match self {
+            AngleUnit::Radians => angle,
+            AngleUnit::Degrees => Expression::mul(vec![
+                Expression::rational(1, 180),
+                Expression::constant(MathConstant::Pi),
+                angle,
+            ]),
+        }
+    }
+
+    /// `angle` in radians converted to this unit
+    pub fn from_radians(self, angle: Expression) -> Expression {
+        match self {
+            AngleUnit::Radians => angle,
+            AngleUnit::Degrees => Expression::mul(vec![
+                Expression::integer(180),
+                Expression::pow(
+                    Expression::constant(MathConstant::Pi),
+                    Expression::integer(-1),
+                ),
+                angle,
+            ]),
+        }
+    }
+
+    /// Rewrite trigonometric functions in `expr` to take and return radians
+    ///
+    /// Each rewritten call is simplified, so special angles become exact.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::core::AngleUnit;
+    /// use mathhook_core::Expression;
+    ///
+    /// let sin_30 = Expression::function("sin", vec![Expression::integer(30)]);
+    /// assert_eq!(AngleUnit::Degrees.in_radians(&sin_30), Expression::rational(1, 2));
+    ///
+    /// let arctan = Expression::function("arctan", vec![Expression::integer(1)]);
+    /// assert_eq!(AngleUnit::Degrees.in_radians(&arctan), Expression::integer(45));
+    /// ```
+    pub fn in_radians(self, expr: &Expression) -> Expression {
+        if self == AngleUnit::Radians {
+            return expr.clone();
+        }
+        match expr {
+            Expression::Function { name, args, .. } => {
+                let args: Vec<Expression> = args.iter().map(|a| self.in_radians(a)).collect();
+                match (name.as_ref(), args.as_slice()) {
+                    (name, [angle]) if ANGLE_FUNCTIONS.contains(&name) => {
+                        Expression::function(name, vec![self.to_radians(angle.clone())]).simplify()
+                    }
+                    (name, [_]) if INVERSE_ANGLE_FUNCTIONS.contains(&name) => self
+                        .from_radians(Expression::function(name, args).simplify())
+                        .simplify(),
+                    _ => Expression::function(name.as_ref(), args),
+                }
+            }
+            Expression::Add(terms) => {
+                Expression::add(terms.iter().map(|t| self.in_radians(t)).collect())
+            }
+            Expression::Mul(factors) => {
+                Expression::mul(factors.iter().map(|f| self.in_radians(f)).collect())
+            }
+            Expression::Pow(base, exponent) => {
+                Expression::pow(self.in_radians(base), self.in_radians(exponent))
+            }
+            Expression::Relation(relation) => {
+                let mut relation = relation.as_ref().clone();
+                relation.left = self.in_radians(&relation.left);
+                relation.right = self.in_radians(&relation.right);
+                Expression::Relation(Arc::new(relation))
+            }
+            _ => expr.clone(),
+        }
+    }
+}
+
+impl Expression {
+    /// Simplify with trigonometric angles in `unit`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::core::AngleUnit;
+    /// use mathhook_core::{expr, Expression};
+    ///
+    /// let identity = expr!((sin(x) ^ 2) + (cos(x) ^ 2));
+    /// assert_eq!(identity.simplify_with_angle_unit(AngleUnit::Degrees), expr!(1));
+    ///
+    /// let tan_45 = Expression::function("tan", vec![Expression::integer(45)]);
+    /// assert_eq!(tan_45.simplify_with_angle_unit(AngleUnit::Degrees), expr!(1));
+    /// ```
+    pub fn simplify_with_angle_unit(&self, unit: AngleUnit) -> Expression {
+        unit.in_radians(self).simplify()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr;
+
+    fn degrees(name: &str, angle: i64) -> Expression {
+        AngleUnit::Degrees.in_radians(&Expression::function(
+            name,
+            vec![Expression::integer(angle)],
+        ))
+    }
+
+    #[test]
+    fn test_special_angles_are_exact() {
+        assert_eq!(degrees("sin", 0), expr!(0));
+        assert_eq!(degrees("sin", 90), expr!(1));
+        assert_eq!(degrees("cos", 60), Expression::rational(1, 2));
+        assert_eq!(degrees("cos", 180), expr!(-1));
+    }
+
+    #[test]
+    fn test_radians_leave_expressions_alone() {
+        let sin_30 = Expression::function("sin", vec![expr!(30)]);
+        assert_eq!(AngleUnit::Radians.in_radians(&sin_30), sin_30);
+    }
+
+    #[test]
+    fn test_nonspecial_angle_evaluates_numerically() {
+        let value = degrees("sin", 10).evaluate_to_f64().unwrap();
+        assert!((value - 10f64.to_radians().sin()).abs() < 1e-12);
+    }
+}
@@ -1,4 +1,8 @@
 //! Mathematical constants
+//!
+//! Physical constants, with units and uncertainties, are in [`physical`].
+
+pub mod physical;
 
 use serde::{Deserialize, Serialize};
 
@@ -0,0 +1,447 @@
+//! Physical constants with their uncertainties
+//!
+//! Values are the CODATA 2018 recommended values. Constants fixed by the
+//! definition of the SI units (`c`, `h`, `k_B`, `q_e`, `N_A`, ...) are exact;
+//! measured ones carry their standard uncertainty. Values are exact
+//! [`Decimal`]s, so substituting an exact constant loses nothing.
+//!
+//! In an expression a constant is a symbol named like its usual notation,
+//! created with [`const_expr!`](crate::const_expr). These symbols live in a
+//! [`SymbolContext`] of their own, so a user's `c` is never mistaken for the
+//! speed of light. They stay symbolic until
+//! [`Expression::substitute_physical_constants`] replaces them by their
+//! values.
+//!
+//! Units are SI unit strings; they are not checked or simplified.
+
+use crate::core::number::Decimal;
+use crate::core::symbol::{SymbolAssumptions, SymbolContext};
+use crate::core::{Expression, Number, Symbol};
+use num_bigint::BigInt;
+use num_traits::ToPrimitive;
+use std::sync::LazyLock;
+
+/// Context holding the symbols of all physical constants
+static CONSTANTS_CONTEXT: LazyLock<SymbolContext> = LazyLock::new(|| {
+    let context = SymbolContext::new();
+    for constant in PHYSICAL_CONSTANTS {
+        let positive =
+            Symbol::scalar(constant.symbol).with_assumptions(SymbolAssumptions::new().positive());
+        context
+            .bind(&Expression::symbol(positive))
+            .expect("BUG: the physical constants context is never frozen");
+    }
+    context.freeze();
+    context
+});
+
+/// A measured or defined physical constant
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::core::constants::physical::{GRAVITATIONAL_CONSTANT, SPEED_OF_LIGHT};
+///
+/// assert!(SPEED_OF_LIGHT.is_exact());
+/// assert_eq!(SPEED_OF_LIGHT.value().to_string(), "299792458");
+/// assert_eq!(SPEED_OF_LIGHT.unit(), "m s^-1");
+///
+/// let g = GRAVITATIONAL_CONSTANT;
+/// assert!(!g.is_exact());
+/// assert!((g.relative_uncertainty().unwrap() - 2.25e-5).abs() < 1e-7);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PhysicalConstant {
+    symbol: &'static str,
+    name: &'static str,
+    /// Decimal text, optionally with an `e` exponent
+    value: &'static str,
+    /// Standard uncertainty in the same notation; `None` when exact
+    uncertainty: Option<&'static str>,
+    unit: &'static str,
+}
+
+impl PhysicalConstant {
+    /// Symbol name used in expressions, such as `k_B`
+    pub fn symbol(&self) -> &'static str {
+        self.symbol
+    }
+
+    /// Descriptive name, such as `Boltzmann constant`
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// SI unit, written as space-separated powers (`m^3 kg^-1 s^-2`)
+    pub fn unit(&self) -> &'static str {
+        self.unit
+    }
+
+    /// The recommended value
+    pub fn value(&self) -> Decimal {
+        scientific(self.value)
+    }
+
+    /// The standard uncertainty, or `None` for a constant exact by definition
+    pub fn uncertainty(&self) -> Option<Decimal> {
+        self.uncertainty.map(scientific)
+    }
+
+    /// Whether the value is exact by definition of the SI units
+    pub fn is_exact(&self) -> bool {
+        self.uncertainty.is_none()
+    }
+
+    /// Uncertainty divided by the value, or `None` when exact
+    pub fn relative_uncertainty(&self) -> Option<f64> {
+        let uncertainty = self.uncertainty()?.to_rational();
+        (uncertainty / self.value().to_rational()).to_f64()
+    }
+
+    /// The value as the nearest float
+    pub fn to_f64(&self) -> f64 {
+        self.value().to_f64().unwrap_or(f64::NAN)
+    }
+
+    /// The symbol standing for this constant in expressions
+    pub fn to_symbol(&self) -> Symbol {
+        CONSTANTS_CONTEXT
+            .get(self.symbol)
+            .expect("BUG: every physical constant is registered in its context")
+    }
+
+    /// This constant as a symbolic expression; see [`const_expr!`](crate::const_expr)
+    pub fn expression(&self) -> Expression {
+        Expression::symbol(self.to_symbol())
+    }
+
+    /// The value as an exact number expression
+    pub fn value_expression(&self) -> Expression {
+        Expression::Number(Number::decimal(self.value()))
+    }
+}
+
+/// Speed of light in vacuum
+pub const SPEED_OF_LIGHT: PhysicalConstant = PhysicalConstant {
+    symbol: "c",
+    name: "speed of light in vacuum",
+    value: "299792458",
+    uncertainty: None,
+    unit: "m s^-1",
+};
+
+/// Planck constant
+pub const PLANCK_CONSTANT: PhysicalConstant = PhysicalConstant {
+    symbol: "h",
+    name: "Planck constant",
+    value: "6.62607015e-34",
+    uncertainty: None,
+    unit: "J s",
+};
+
+/// Boltzmann constant
+pub const BOLTZMANN_CONSTANT: PhysicalConstant = PhysicalConstant {
+    symbol: "k_B",
+    name: "Boltzmann constant",
+    value: "1.380649e-23",
+    uncertainty: None,
+    unit: "J K^-1",
+};
+
+/// Elementary charge
+pub const ELEMENTARY_CHARGE: PhysicalConstant = PhysicalConstant {
+    symbol: "q_e",
+    name: "elementary charge",
+    value: "1.602176634e-19",
+    uncertainty: None,
+    unit: "C",
+};
+
+/// Avogadro constant
+pub const AVOGADRO_CONSTANT: PhysicalConstant = PhysicalConstant {
+    symbol: "N_A",
+    name: "Avogadro constant",
+    value: "6.02214076e23",
+    uncertainty: None,
+    unit: "mol^-1",
+};
+
+/// Molar gas constant, `N_A · k_B`
+pub const MOLAR_GAS_CONSTANT: PhysicalConstant = PhysicalConstant {
+    symbol: "R",
+    name: "molar gas constant",
+    value: "8.31446261815324",
+    uncertainty: None,
+    unit: "J mol^-1 K^-1",
+};
+
+/// Standard acceleration of gravity
+pub const STANDARD_GRAVITY: PhysicalConstant = PhysicalConstant {
+    symbol: "g_n",
+    name: "standard acceleration of gravity",
+    value: "9.80665",
+    uncertainty: None,
+    unit: "m s^-2",
+};
+
+/// Newtonian constant of gravitation
+pub const GRAVITATIONAL_CONSTANT: PhysicalConstant = PhysicalConstant {
+    symbol: "G",
+    name: "Newtonian constant of gravitation",
+    value: "6.67430e-11",
+    uncertainty: Some("0.00015e-11"),
+    unit: "m^3 kg^-1 s^-2",
+};
+
+/// Electron mass
+pub const ELECTRON_MASS: PhysicalConstant = PhysicalConstant {
+    symbol: "m_e",
+    name: "electron mass",
+    value: "9.1093837015e-31",
+    uncertainty: Some("0.0000000028e-31"),
+    unit: "kg",
+};
+
+/// Proton mass
+pub const PROTON_MASS: PhysicalConstant = PhysicalConstant {
+    symbol: "m_p",
+    name: "proton mass",
+    value: "1.67262192369e-27",
+    uncertainty: Some("0.00000000051e-27"),
+    unit: "kg",
+};
+
+/// Neutron mass
+pub const NEUTRON_MASS: PhysicalConstant = PhysicalConstant {
+    symbol: "m_n",
+    name: "neutron mass",
+    value: "1.67492749804e-27",
+    uncertainty: Some("0.00000000095e-27"),
+    unit: "kg",
+};
+
+/// Atomic mass constant
+pub const ATOMIC_MASS_CONSTANT: PhysicalConstant = PhysicalConstant {
+    symbol: "m_u",
+    name: "atomic mass constant",
+    value: "1.66053906660e-27",
+    uncertainty: Some("0.00000000050e-27"),
+    unit: "kg",
+};
+
+/// Vacuum electric permittivity
+pub const VACUUM_PERMITTIVITY: PhysicalConstant = PhysicalConstant {
+    symbol: "epsilon_0",
+    name: "vacuum electric permittivity",
+    value: "8.8541878128e-12",
+    uncertainty: Some("0.0000000013e-12"),
+    unit: "F m^-1",
+};
+
+/// Vacuum magnetic permeability
+pub const VACUUM_PERMEABILITY: PhysicalConstant = PhysicalConstant {
+    symbol: "mu_0",
+    name: "vacuum magnetic permeability",
+    value: "1.25663706212e-6",
+    uncertainty: Some("0.00000000019e-6"),
+    unit: "N A^-2",
+};
+
+/// Fine-structure constant
+pub const FINE_STRUCTURE_CONSTANT: PhysicalConstant = PhysicalConstant {
+    symbol: "alpha",
+    name: "fine-structure constant",
+    value: "7.2973525693e-3",
+    uncertainty: Some("0.0000000011e-3"),
+    unit: "1",
+};
+
+/// Rydberg constant
+pub const RYDBERG_CONSTANT: PhysicalConstant = PhysicalConstant {
+    symbol: "R_inf",
+    name: "Rydberg constant",
+    value: "10973731.568160",
+    uncertainty: Some("0.000021"),
+    unit: "m^-1",
+};
+
+/// Bohr radius
+pub const BOHR_RADIUS: PhysicalConstant = PhysicalConstant {
+    symbol: "a_0",
+    name: "Bohr radius",
+    value: "5.29177210903e-11",
+    uncertainty: Some("0.00000000080e-11"),
+    unit: "m",
+};
+
+/// Every constant in this module
+pub const PHYSICAL_CONSTANTS: &[PhysicalConstant] = &[
+    SPEED_OF_LIGHT,
+    PLANCK_CONSTANT,
+    BOLTZMANN_CONSTANT,
+    ELEMENTARY_CHARGE,
+    AVOGADRO_CONSTANT,
+    MOLAR_GAS_CONSTANT,
+    STANDARD_GRAVITY,
+    GRAVITATIONAL_CONSTANT,
+    ELECTRON_MASS,
+    PROTON_MASS,
+    NEUTRON_MASS,
+    ATOMIC_MASS_CONSTANT,
+    VACUUM_PERMITTIVITY,
+    VACUUM_PERMEABILITY,
+    FINE_STRUCTURE_CONSTANT,
+    RYDBERG_CONSTANT,
+    BOHR_RADIUS,
+];
+
+/// Constants under their symbol names, as used by [`const_expr!`](crate::const_expr)
+#[allow(non_upper_case_globals)]
+pub mod by_symbol {
+    pub use super::{
+        ATOMIC_MASS_CONSTANT as m_u, AVOGADRO_CONSTANT as N_A, BOHR_RADIUS as a_0,
+        BOLTZMANN_CONSTANT as k_B, ELECTRON_MASS as m_e, ELEMENTARY_CHARGE as q_e,
+        FINE_STRUCTURE_CONSTANT as alpha, GRAVITATIONAL_CONSTANT as G, MOLAR_GAS_CONSTANT as R,
+        NEUTRON_MASS as m_n, PLANCK_CONSTANT as h, PROTON_MASS as m_p, RYDBERG_CONSTANT as R_inf,
+        SPEED_OF_LIGHT as c, STANDARD_GRAVITY as g_n, VACUUM_PERMEABILITY as mu_0,
+        VACUUM_PERMITTIVITY as epsilon_0,
+    };
+}
+
+/// The constant written `symbol` in expressions
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::core::constants::physical::physical_constant;
+///
+/// assert_eq!(physical_constant("k_B").unwrap().name(), "Boltzmann constant");
+/// assert!(physical_constant("x").is_none());
+/// ```
+pub fn physical_constant(symbol: &str) -> Option<&'static PhysicalConstant> {
+    PHYSICAL_CONSTANTS
+        .iter()
+        .find(|constant| constant.symbol == symbol)
+}
+
+/// The constant a symbol stands for, if it is a physical constant symbol
+pub fn constant_for_symbol(symbol: &Symbol) -> Option<&'static PhysicalConstant> {
+    if symbol.context_id() != CONSTANTS_CONTEXT.id() {
+        return None;
+    }
+    physical_constant(symbol.name())
+}
+
+impl Expression {
+    /// Replace every physical constant by its exact value
+    ///
+    /// Symbols that merely share a constant's name are left alone.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::core::constants::physical::SPEED_OF_LIGHT;
+    /// use mathhook_core::core::number::Decimal;
+    /// use mathhook_core::{const_expr, Expression, Number, Simplify};
+    ///
+    /// let c_squared = Expression::pow(const_expr!(c), Expression::integer(2));
+    /// assert_eq!(
+    ///     c_squared.substitute_physical_constants().simplify(),
+    ///     Expression::Number(Number::decimal(Decimal::parse("89875517873681764").unwrap()))
+    /// );
+    ///
+    /// // A user's own `c` is untouched
+    /// let c = Expression::symbol("c");
+    /// assert_eq!(c.substitute_physical_constants(), c);
+    /// ```
+    pub fn substitute_physical_constants(&self) -> Expression {
+        match self {
+            Expression::Symbol(symbol) => constant_for_symbol(symbol)
+                .map(PhysicalConstant::value_expression)
+                .unwrap_or_else(|| self.clone()),
+            _ => self.map_children(Expression::substitute_physical_constants),
+        }
+    }
+}
+
+/// Read `digits[.digits][e±exponent]` exactly
+fn scientific(text: &str) -> Decimal {
+    let (mantissa, exponent) = text.split_once('e').unwrap_or((text, "0"));
+    let mantissa = Decimal::parse(mantissa).expect("BUG: malformed physical constant mantissa");
+    let exponent: i64 = exponent
+        .parse()
+        .expect("BUG: malformed physical constant exponent");
+    let shift = exponent - i64::from(mantissa.scale());
+    if shift >= 0 {
+        let factor = num_traits::pow(BigInt::from(10), shift as usize);
+        Decimal::new(mantissa.digits() * factor, 0)
+    } else {
+        Decimal::new(mantissa.digits().clone(), (-shift) as u32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::const_expr;
+
+    #[test]
+    fn test_exact_values() {
+        assert_eq!(
+            AVOGADRO_CONSTANT.value().to_string(),
+            "602214076000000000000000"
+        );
+        assert_eq!(
+            PLANCK_CONSTANT.value().to_rational(),
+            scientific("662607015e-42").to_rational()
+        );
+        // R = N_A · k_B exactly
+        let product = AVOGADRO_CONSTANT.value().mul(&BOLTZMANN_CONSTANT.value());
+        assert_eq!(product, MOLAR_GAS_CONSTANT.value());
+        assert!(PHYSICAL_CONSTANTS
+            .iter()
+            .all(|constant| constant.value().to_f64().is_some()));
+    }
+
+    #[test]
+    fn test_uncertainties() {
+        assert_eq!(SPEED_OF_LIGHT.uncertainty(), None);
+        assert_eq!(
+            ELECTRON_MASS.uncertainty().unwrap().to_rational(),
+            scientific("28e-41").to_rational()
+        );
+        assert!(RYDBERG_CONSTANT.relative_uncertainty().unwrap() < 1e-11);
+    }
+
+    #[test]
+    fn test_symbols_are_distinct_from_user_symbols() {
+        let c = const_expr!(c);
+        assert_ne!(c, Expression::symbol("c"));
+        assert_eq!(c, SPEED_OF_LIGHT.expression());
+        assert_eq!(const_expr!(k_B), BOLTZMANN_CONSTANT.expression());
+
+        let Expression::Symbol(symbol) = &c else {
+            panic!("expected a symbol");
+        };
+        assert!(symbol.assumptions().is_positive());
+        assert_eq!(constant_for_symbol(symbol), Some(&SPEED_OF_LIGHT));
+        assert_eq!(constant_for_symbol(&Symbol::scalar("c")), None);
+    }
+
+    #[test]
+    fn test_substitution_on_demand() {
+        let force = Expression::mul(vec![const_expr!(G), Expression::symbol("M")]);
+        let substituted = force.substitute_physical_constants();
+        assert_eq!(
+            substituted,
+            Expression::mul(vec![
+                GRAVITATIONAL_CONSTANT.value_expression(),
+                Expression::symbol("M"),
+            ])
+        );
+        assert_eq!(
+            Expression::symbol("G").substitute_physical_constants(),
+            Expression::symbol("G")
+        );
+    }
+}
@@ -1,22 +1,35 @@
 //! Expression type and core functionality
 
+pub mod builder;
 pub mod classification;
+pub mod compiled;
 pub mod constructors;
 pub mod conversion;
 pub mod data_types;
 pub mod display;
 pub mod eval_numeric;
+pub mod eval_scope;
 pub mod evaluation;
+pub mod function_id;
+mod hash;
+pub mod intern;
 pub mod matrix_methods;
 pub mod methods;
 pub mod operations;
 pub mod operators;
+mod sharing;
 pub mod smart_display;
+pub mod summary;
+pub mod tree;
 
+pub use builder::ExpressionBuilder;
 pub use classification::ExpressionClass;
+pub use compiled::CompiledExpression;
 
 pub use crate::matrices::unified::Matrix;
 pub use data_types::*;
+pub use function_id::FunctionId;
+pub use intern::ExpressionInterner;
 
 use crate::core::{MathConstant, Number, Symbol};
 use serde::{Deserialize, Serialize};
@@ -37,6 +50,8 @@ pub enum Expression {
     Function {
         name: Arc<str>,
         args: Arc<Vec<Expression>>,
+        #[serde(skip)]
+        id: FunctionId,
     },
     Constant(MathConstant),
     Set(Arc<Vec<Expression>>),
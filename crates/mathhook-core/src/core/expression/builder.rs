@@ -0,0 +1,178 @@
+//! Incremental construction of large sums and products
+//!
+//! `Expression::add` and `Expression::mul` normalize on every call, so
+//! growing a sum one term at a time re-allocates and re-simplifies the whole
+//! term list each step. [`ExpressionBuilder`] appends operands in place and
+//! normalizes once when the expression is built, which keeps series with
+//! hundreds of thousands of coefficients cheap to assemble.
+
+use super::Expression;
+use std::sync::Arc;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operation {
+    Sum,
+    Product,
+}
+
+/// Accumulates the terms of a sum or the factors of a product
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::core::expression::ExpressionBuilder;
+/// use mathhook_core::{expr, Expression};
+///
+/// let mut series = ExpressionBuilder::sum();
+/// for k in 0..4 {
+///     series.push(Expression::mul(vec![
+///         Expression::integer(k + 1),
+///         Expression::pow(expr!(x), Expression::integer(k)),
+///     ]));
+/// }
+/// assert_eq!(series.len(), 4);
+/// assert_eq!(series.build(), expr!(1 + (2 * x) + (3 * (x ^ 2)) + (4 * (x ^ 3))));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ExpressionBuilder {
+    operation: Operation,
+    operands: Vec<Expression>,
+}
+
+impl ExpressionBuilder {
+    /// Start an empty sum, which builds to `0`
+    pub fn sum() -> Self {
+        Self {
+            operation: Operation::Sum,
+            operands: Vec::new(),
+        }
+    }
+
+    /// Start an empty product, which builds to `1`
+    pub fn product() -> Self {
+        Self {
+            operation: Operation::Product,
+            operands: Vec::new(),
+        }
+    }
+
+    /// Reserve room for `additional` more operands
+    pub fn reserve(&mut self, additional: usize) -> &mut Self {
+        self.operands.reserve(additional);
+        self
+    }
+
+    /// Append a term (for sums) or factor (for products)
+    ///
+    /// A nested sum pushed onto a sum, or product onto a product, is
+    /// flattened into its operands. Nothing is simplified until
+    /// [`build`](Self::build).
+    pub fn push(&mut self, operand: Expression) -> &mut Self {
+        match (self.operation, operand) {
+            (Operation::Sum, Expression::Add(terms)) => self.flatten(terms),
+            (Operation::Product, Expression::Mul(factors)) => self.flatten(factors),
+            (_, operand) => self.operands.push(operand),
+        }
+        self
+    }
+
+    /// Number of operands pushed so far, after flattening
+    pub fn len(&self) -> usize {
+        self.operands.len()
+    }
+
+    /// Check whether no operand has been pushed
+    pub fn is_empty(&self) -> bool {
+        self.operands.is_empty()
+    }
+
+    /// Normalize the accumulated operands into a canonical expression
+    ///
+    /// Equivalent to `Expression::add` or `Expression::mul` on the same
+    /// operands, run once.
+    pub fn build(self) -> Expression {
+        match self.operation {
+            Operation::Sum => Expression::add(self.operands),
+            Operation::Product => Expression::mul(self.operands),
+        }
+    }
+
+    /// Wrap the operands without normalizing them
+    ///
+    /// Useful when the operands are already canonical and distinct, for
+    /// example the terms of a series in a fixed order. An empty builder gives
+    /// the identity element and a single operand is returned as is.
+    pub fn build_unsimplified(mut self) -> Expression {
+        match (self.operation, self.operands.len()) {
+            (Operation::Sum, 0) => Expression::integer(0),
+            (Operation::Product, 0) => Expression::integer(1),
+            (_, 1) => self.operands.pop().expect("BUG: one operand is present"),
+            (Operation::Sum, _) => Expression::Add(Arc::new(self.operands)),
+            (Operation::Product, _) => Expression::Mul(Arc::new(self.operands)),
+        }
+    }
+
+    fn flatten(&mut self, operands: Arc<Vec<Expression>>) {
+        match Arc::try_unwrap(operands) {
+            Ok(owned) => self.operands.extend(owned),
+            Err(shared) => self.operands.extend(shared.iter().cloned()),
+        }
+    }
+}
+
+impl Extend<Expression> for ExpressionBuilder {
+    fn extend<I: IntoIterator<Item = Expression>>(&mut self, operands: I) {
+        let operands = operands.into_iter();
+        self.operands.reserve(operands.size_hint().0);
+        for operand in operands {
+            self.push(operand);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{expr, symbol};
+
+    #[test]
+    fn test_builder_matches_constructors() {
+        let terms = vec![expr!(x), expr!(2), expr!(y + 3), expr!(2 * x)];
+        let mut sum = ExpressionBuilder::sum();
+        sum.extend(terms.clone());
+        assert_eq!(sum.len(), 5);
+        assert_eq!(sum.build(), Expression::add(terms));
+
+        let mut product = ExpressionBuilder::product();
+        product.push(expr!(x * y)).push(expr!(x)).push(expr!(3));
+        assert_eq!(product.len(), 4);
+        assert_eq!(product.build(), expr!(3 * (x ^ 2) * y));
+
+        assert_eq!(ExpressionBuilder::sum().build(), expr!(0));
+        assert_eq!(ExpressionBuilder::product().build_unsimplified(), expr!(1));
+    }
+
+    #[test]
+    fn test_long_series_builds_once() {
+        let x = symbol!(x);
+        let n = 20_000;
+        let mut series = ExpressionBuilder::sum();
+        series.reserve(2 * n);
+        for k in 0..n as i64 {
+            let power = Expression::pow(Expression::symbol(x.clone()), Expression::integer(k));
+            series.push(Expression::mul(vec![Expression::integer(k), power.clone()]));
+            series.push(power);
+        }
+
+        match series.build() {
+            Expression::Add(terms) => {
+                assert_eq!(terms.len(), n);
+                assert!(terms.contains(&Expression::mul(vec![
+                    Expression::integer(100),
+                    Expression::pow(Expression::symbol(x), Expression::integer(99)),
+                ])));
+            }
+            other => panic!("expected a sum, got {}", other),
+        }
+    }
+}
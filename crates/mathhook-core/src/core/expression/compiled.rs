@@ -0,0 +1,286 @@
+//! Compiled numeric evaluation
+//!
+//! Lowers an expression to a tree of `f64` operations so it can be evaluated
+//! many times without substitution or simplification. Numerical integrators
+//! evaluate their integrand thousands of times, which is where this matters.
+//!
+//! Elementary functions run natively. Any other function is evaluated through
+//! the regular function dispatch with its compiled arguments, so every function
+//! that `evaluate_to_f64` understands can be compiled.
+//!
+//! Points outside the domain (√-1, ln 0, 1/0) evaluate to NaN or ±∞ instead of
+//! returning an error, matching IEEE semantics.
+
+use super::Expression;
+use crate::core::polynomial::{polynomial_terms, sparse_horner};
+use crate::core::{Number, Symbol};
+use crate::error::MathError;
+use num_traits::ToPrimitive;
+use std::sync::Arc;
+
+/// Expression compiled for fast evaluation at `f64` points
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::{expr, symbol};
+///
+/// let x = symbol!(x);
+/// let y = symbol!(y);
+/// let f = expr!((x ^ 2) + (3 * y)).compile(&[x, y]).unwrap();
+/// assert_eq!(f.evaluate(&[2.0, 1.0]), 7.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct CompiledExpression {
+    root: Node,
+    arity: usize,
+}
+
+#[derive(Debug, Clone)]
+enum Node {
+    Constant(f64),
+    Variable(usize),
+    Add(Vec<Node>),
+    Mul(Vec<Node>),
+    PowInt(Box<Node>, i32),
+    /// Polynomial in one variable, `(degree, coefficient)` highest first
+    Horner(usize, Vec<(u32, Node)>),
+    Pow(Box<Node>, Box<Node>),
+    Native(fn(f64) -> f64, Box<Node>),
+    Dispatch {
+        name: Arc<str>,
+        args: Vec<Node>,
+    },
+}
+
+impl Expression {
+    /// Compile for repeated numeric evaluation
+    ///
+    /// `variables` fixes the order of the coordinates passed to
+    /// [`CompiledExpression::evaluate`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `MathError::NonNumericalResult` if the expression contains a
+    /// symbol not listed in `variables`, or a node with no numeric value
+    /// (matrices, sets, relations, unevaluated calculus).
+    pub fn compile(&self, variables: &[Symbol]) -> Result<CompiledExpression, MathError> {
+        Ok(CompiledExpression {
+            root: compile_node(self, variables)?,
+            arity: variables.len(),
+        })
+    }
+}
+
+impl CompiledExpression {
+    /// Evaluate at `point`, one coordinate per compiled variable
+    ///
+    /// # Panics
+    ///
+    /// Panics if `point` has fewer coordinates than there are variables.
+    pub fn evaluate(&self, point: &[f64]) -> f64 {
+        assert!(
+            point.len() >= self.arity,
+            "expected {} coordinates, got {}",
+            self.arity,
+            point.len()
+        );
+        eval_node(&self.root, point)
+    }
+
+    /// Number of variables
+    pub fn arity(&self) -> usize {
+        self.arity
+    }
+}
+
+fn compile_node(expr: &Expression, variables: &[Symbol]) -> Result<Node, MathError> {
+    let compile_all = |items: &[Expression]| {
+        items
+            .iter()
+            .map(|item| compile_node(item, variables))
+            .collect::<Result<Vec<_>, _>>()
+    };
+
+    Ok(match expr {
+        Expression::Number(number) => Node::Constant(number_value(number)),
+        Expression::Constant(constant) => Node::Constant(constant.to_f64()),
+        Expression::Symbol(symbol) => {
+            Node::Variable(variables.iter().position(|v| v == symbol).ok_or_else(|| {
+                MathError::NonNumericalResult {
+                    expression: expr.clone(),
+                }
+            })?)
+        }
+        Expression::Add(terms) => match compile_polynomial(expr, variables)? {
+            Some(polynomial) => polynomial,
+            None => Node::Add(compile_all(terms)?),
+        },
+        Expression::Mul(factors) => Node::Mul(compile_all(factors)?),
+        Expression::Pow(base, exp) => {
+            let base = Box::new(compile_node(base, variables)?);
+            match exp.as_ref() {
+                Expression::Number(Number::Integer(n)) if i32::try_from(*n).is_ok() => {
+                    Node::PowInt(base, *n as i32)
+                }
+                _ => Node::Pow(base, Box::new(compile_node(exp, variables)?)),
+            }
+        }
+        Expression::Function { name, args, .. } => {
+            let mut compiled = compile_all(args)?;
+            match native(name) {
+                Some(f) if compiled.len() == 1 => Node::Native(f, Box::new(compiled.remove(0))),
+                _ => Node::Dispatch {
+                    name: Arc::clone(name),
+                    args: compiled,
+                },
+            }
+        }
+        _ => {
+            return Err(MathError::NonNumericalResult {
+                expression: expr.clone(),
+            })
+        }
+    })
+}
+
+/// Horner node for a sum that is a polynomial of degree two or more in one
+/// of `variables`, trying them in order
+fn compile_polynomial(expr: &Expression, variables: &[Symbol]) -> Result<Option<Node>, MathError> {
+    for (index, variable) in variables.iter().enumerate() {
+        let Some(terms) = polynomial_terms(expr, variable) else {
+            continue;
+        };
+        if terms.len() < 2 || terms[0].0 < 2 {
+            continue;
+        }
+        let terms = terms
+            .iter()
+            .map(|(degree, coefficient)| Ok((*degree, compile_node(coefficient, variables)?)))
+            .collect::<Result<Vec<_>, MathError>>()?;
+        return Ok(Some(Node::Horner(index, terms)));
+    }
+    Ok(None)
+}
+
+pub(crate) fn number_value(number: &Number) -> f64 {
+    match number {
+        Number::Integer(n) => *n as f64,
+        Number::Float(f) => *f,
+        Number::BigInteger(n) => n.to_f64().unwrap_or(f64::NAN),
+        Number::Rational(r) => r.to_f64().unwrap_or(f64::NAN),
+        Number::Decimal(d) => d.to_f64().unwrap_or(f64::NAN),
+    }
+}
+
+/// Single-argument functions with a native `f64` implementation
+pub(crate) fn native(name: &str) -> Option<fn(f64) -> f64> {
+    Some(match name {
+        "sin" => f64::sin,
+        "cos" => f64::cos,
+        "tan" => f64::tan,
+        "sec" => |x: f64| 1.0 / x.cos(),
+        "csc" => |x: f64| 1.0 / x.sin(),
+        "cot" => |x: f64| 1.0 / x.tan(),
+        "arcsin" | "asin" => f64::asin,
+        "arccos" | "acos" => f64::acos,
+        "arctan" | "atan" => f64::atan,
+        "sinh" => f64::sinh,
+        "cosh" => f64::cosh,
+        "tanh" => f64::tanh,
+        "exp" => f64::exp,
+        "ln" => f64::ln,
+        "log" | "log10" => f64::log10,
+        "sqrt" => f64::sqrt,
+        "abs" => f64::abs,
+        "floor" => f64::floor,
+        "ceil" => f64::ceil,
+        "sign" => |x: f64| if x == 0.0 { 0.0 } else { x.signum() },
+        _ => return None,
+    })
+}
+
+fn eval_node(node: &Node, point: &[f64]) -> f64 {
+    match node {
+        Node::Constant(value) => *value,
+        Node::Variable(index) => point[*index],
+        Node::Add(terms) => terms.iter().map(|t| eval_node(t, point)).sum(),
+        Node::Mul(factors) => factors.iter().map(|f| eval_node(f, point)).product(),
+        Node::PowInt(base, n) => eval_node(base, point).powi(*n),
+        Node::Horner(variable, terms) => sparse_horner(
+            terms
+                .iter()
+                .map(|(degree, coefficient)| (*degree, eval_node(coefficient, point))),
+            point[*variable],
+        ),
+        Node::Pow(base, exp) => eval_node(base, point).powf(eval_node(exp, point)),
+        Node::Native(f, arg) => f(eval_node(arg, point)),
+        Node::Dispatch { name, args } => {
+            dispatch(name, args.iter().map(|arg| eval_node(arg, point)))
+        }
+    }
+}
+
+/// Value of a function without a native implementation, through the regular
+/// function dispatch; NaN where it has no numeric value
+pub(crate) fn dispatch(name: &str, args: impl IntoIterator<Item = f64>) -> f64 {
+    let args = args.into_iter().map(Expression::float).collect();
+    Expression::function(name, args)
+        .evaluate_to_f64()
+        .unwrap_or(f64::NAN)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{expr, symbol};
+
+    #[test]
+    fn test_compiled_matches_substitution() {
+        let x = symbol!(x);
+        let expr = expr!((sin(x) ^ 2) + (exp(-x) * sqrt(x)) + ln(x + 1));
+        let compiled = expr.compile(std::slice::from_ref(&x)).unwrap();
+        for value in [0.25, 1.0, 2.5] {
+            let expected = expr
+                .substitute(&[("x".to_owned(), Expression::float(value))].into())
+                .evaluate_to_f64()
+                .unwrap();
+            assert!((compiled.evaluate(&[value]) - expected).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_dispatch_fallback_and_constants() {
+        let x = symbol!(x);
+        let compiled = Expression::add(vec![
+            Expression::function("gamma", vec![Expression::symbol(x.clone())]),
+            Expression::pi(),
+        ])
+        .compile(&[x])
+        .unwrap();
+        assert!((compiled.evaluate(&[5.0]) - (24.0 + std::f64::consts::PI)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_domain_errors_become_nan() {
+        let x = symbol!(x);
+        let compiled = expr!(sqrt(x)).compile(&[x]).unwrap();
+        assert!(compiled.evaluate(&[-1.0]).is_nan());
+    }
+
+    #[test]
+    fn test_polynomials_compile_to_horner() {
+        let x = symbol!(x);
+        let y = symbol!(y);
+        let poly = expr!((3 * (x ^ 5)) + ((x ^ 2) * y) - (2 * x) + 1);
+        let compiled = poly.compile(&[x, y]).unwrap();
+        assert!(matches!(compiled.root, Node::Horner(0, ref terms) if terms.len() == 4));
+        assert_eq!(compiled.evaluate(&[2.0, 3.0]), 96.0 + 12.0 - 4.0 + 1.0);
+    }
+
+    #[test]
+    fn test_unknown_symbol_is_rejected() {
+        let x = symbol!(x);
+        assert!(expr!(x + y).compile(&[x]).is_err());
+    }
+}
@@ -1,6 +1,6 @@
 //! Function and calculus expression constructors
 
-use crate::core::expression::{CalculusData, Expression};
+use crate::core::expression::{CalculusData, Expression, FunctionId};
 use crate::core::Symbol;
 use std::sync::Arc;
 
@@ -15,9 +15,11 @@ impl Expression {
     /// let expression = Expression::function("sin", vec![expr!(x)]);
     /// ```
     pub fn function<S: AsRef<str>>(name: S, args: Vec<Expression>) -> Self {
+        let name = name.as_ref();
         Self::Function {
-            name: Arc::from(name.as_ref()),
+            name: Arc::from(name),
             args: Arc::new(args),
+            id: FunctionId::of(name),
         }
     }
 
@@ -98,6 +98,27 @@ impl Expression {
         Self::Matrix(Arc::new(Matrix::dense(rows)))
     }
 
+    /// Create a matrix expression whose element at row `i`, column `j` is `f(i, j)`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::{expr, Expression};
+    ///
+    /// let matrix = Expression::matrix_from_fn(2, 2, |i, j| Expression::integer((i * 2 + j) as i64));
+    /// assert_eq!(
+    ///     matrix,
+    ///     Expression::matrix(vec![vec![expr!(0), expr!(1)], vec![expr!(2), expr!(3)]])
+    /// );
+    /// ```
+    pub fn matrix_from_fn<F>(rows: usize, cols: usize, f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> Expression,
+    {
+        use crate::matrices::Matrix;
+        Self::Matrix(Arc::new(Matrix::from_fn(rows, cols, f)))
+    }
+
     /// Create an identity matrix expression
     ///
     /// # Examples
@@ -83,6 +83,24 @@ pub enum RelationType {
     Congruent,
 }
 
+impl RelationType {
+    /// Operator used when displaying the relation
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            RelationType::Equal => "=",
+            RelationType::NotEqual => "≠",
+            RelationType::Less => "<",
+            RelationType::LessEqual => "≤",
+            RelationType::Greater => ">",
+            RelationType::GreaterEqual => "≥",
+            RelationType::Approximate => "≈",
+            RelationType::Similar => "∼",
+            RelationType::Proportional => "∝",
+            RelationType::Congruent => "≅",
+        }
+    }
+}
+
 /// Direction for limit operations
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum LimitDirection {
@@ -1,13 +1,13 @@
 //! Display implementation for Expression
 
 use super::smart_display::SmartDisplayFormatter;
-use super::{Expression, RelationType};
+use super::Expression;
 use std::fmt;
 
 impl fmt::Display for Expression {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Expression::Number(num) => write!(f, "{:?}", num),
+            Expression::Number(num) => write!(f, "{}", num),
             Expression::Symbol(sym) => write!(f, "{}", sym.name),
             Expression::Add(terms) => {
                 if terms.is_empty() {
@@ -24,7 +24,7 @@ impl fmt::Display for Expression {
                 }
             }
             Expression::Pow(base, exp) => write!(f, "{}^{}", base, exp),
-            Expression::Function { name, args } => {
+            Expression::Function { name, args, .. } => {
                 let arg_strs: Vec<String> = args.iter().map(|a| format!("{}", a)).collect();
                 write!(f, "{}({})", name, arg_strs.join(", "))
             }
@@ -49,19 +49,13 @@ impl fmt::Display for Expression {
                 write!(f, "[{}]", row_strs.join(", "))
             }
             Expression::Relation(data) => {
-                let op = match data.relation_type {
-                    RelationType::Equal => "=",
-                    RelationType::NotEqual => "≠",
-                    RelationType::Less => "<",
-                    RelationType::LessEqual => "≤",
-                    RelationType::Greater => ">",
-                    RelationType::GreaterEqual => "≥",
-                    RelationType::Approximate => "≈",
-                    RelationType::Similar => "∼",
-                    RelationType::Proportional => "∝",
-                    RelationType::Congruent => "≅",
-                };
-                write!(f, "{} {} {}", data.left, op, data.right)
+                write!(
+                    f,
+                    "{} {} {}",
+                    data.left,
+                    data.relation_type.symbol(),
+                    data.right
+                )
             }
             Expression::MethodCall(method_data) => {
                 if method_data.args.is_empty() {
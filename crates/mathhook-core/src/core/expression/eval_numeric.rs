@@ -25,12 +25,17 @@
 //! - `sin(π/2)` → `1.0` (exact symbolic evaluation)
 //! - `sqrt(2)` → `1.4142135623730951` (numerical approximation with precision control)
 //! - `x^2` (with x=3) → `9` (after substitution and evaluation)
+use super::eval_scope::EvalSettings;
+use crate::core::angle_unit::AngleUnit;
 use crate::core::number::Number;
 use crate::core::Expression;
 use crate::error::MathError;
 use num_bigint::BigInt;
+use num_complex::Complex64;
 use num_rational::BigRational;
+use num_traits::{ToPrimitive, Zero};
 use std::collections::HashMap;
+use std::fmt;
 
 pub trait EvalNumeric {
     /// Evaluate expression to numerical form
@@ -58,6 +63,126 @@ pub trait EvalNumeric {
     /// 3. Use specified precision for floating-point operations
     /// 4. NOT perform variable substitution (that's `evaluate_with_context()`'s job)
     fn eval_numeric(&self, precision: u32) -> Result<Expression, MathError>;
+
+    /// Evaluate expression to a complex floating-point value
+    ///
+    /// Unlike `eval_numeric()`, which leaves `sqrt(-4)` or `exp(i)` symbolic,
+    /// this evaluates over the complex numbers, using principal branches for
+    /// roots, logarithms and non-integer powers.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::{expr, EvalNumeric, Expression};
+    ///
+    /// let i = Expression::i();
+    /// let z = Expression::add(vec![Expression::integer(3), Expression::mul(vec![Expression::integer(4), i])]);
+    /// assert_eq!(z.eval_complex().unwrap().norm(), 5.0);
+    ///
+    /// let root = Expression::pow(Expression::integer(-4), Expression::rational(1, 2));
+    /// let value = root.eval_complex().unwrap();
+    /// assert!(value.re.abs() < 1e-12 && (value.im - 2.0).abs() < 1e-12);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `MathError` for:
+    /// - Free symbols and non-scalar expressions (matrices, sets, relations)
+    /// - Division by zero and results that are not finite
+    fn eval_complex(&self) -> Result<Complex64, MathError>;
+
+    /// Evaluate numerically, reporting domain violations with their location
+    ///
+    /// Division by zero, logarithms of non-positive numbers and even roots
+    /// of negative numbers are detected after the operands are evaluated.
+    /// Under `DomainPolicy::Strict` the first one is returned as
+    /// `MathError::DomainViolation`; under `DomainPolicy::Permissive` the
+    /// offending subexpression evaluates to NaN and the violation is
+    /// recorded. `DomainPolicy::Symbolic` behaves like `eval_numeric()`.
+    /// Matrices, intervals and piecewise expressions are evaluated without
+    /// looking inside them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::core::expression::eval_numeric::{DomainPolicy, ViolationKind};
+    /// use mathhook_core::{EvalNumeric, Expression, MathError};
+    ///
+    /// // 1 + ln(-2)
+    /// let expr = Expression::add(vec![
+    ///     Expression::integer(1),
+    ///     Expression::function("ln", vec![Expression::integer(-2)]),
+    /// ]);
+    ///
+    /// let checked = expr.eval_numeric_checked(53, DomainPolicy::Permissive).unwrap();
+    /// assert!(checked.value.evaluate_to_f64().unwrap().is_nan());
+    /// assert_eq!(checked.diagnostics[0].kind, ViolationKind::LogOfNonPositive);
+    ///
+    /// let err = expr.eval_numeric_checked(53, DomainPolicy::Strict).unwrap_err();
+    /// assert!(matches!(err, MathError::DomainViolation { .. }));
+    /// ```
+    fn eval_numeric_checked(
+        &self,
+        precision: u32,
+        policy: DomainPolicy,
+    ) -> Result<CheckedEvaluation, MathError>;
+}
+
+/// How numerical evaluation treats domain violations
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DomainPolicy {
+    /// Leave the offending subexpression unevaluated, as `eval_numeric()` does
+    #[default]
+    Symbolic,
+    /// Stop at the first violation with `MathError::DomainViolation`
+    Strict,
+    /// Evaluate the offending subexpression to NaN and record the violation
+    Permissive,
+}
+
+/// Kind of domain violation met during numerical evaluation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ViolationKind {
+    DivisionByZero,
+    LogOfNonPositive,
+    EvenRootOfNegative,
+}
+
+impl fmt::Display for ViolationKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let description = match self {
+            ViolationKind::DivisionByZero => "division by zero",
+            ViolationKind::LogOfNonPositive => "a logarithm of a non-positive number",
+            ViolationKind::EvenRootOfNegative => "an even root of a negative number",
+        };
+        write!(f, "{}", description)
+    }
+}
+
+/// Domain violation and where it happened
+///
+/// `path` lists child indices from the root of the evaluated expression,
+/// in the order of `Expression::map_children`: a power's base is `0` and
+/// its exponent `1`, function arguments and operands count from `0`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EvalDiagnostic {
+    pub kind: ViolationKind,
+    pub path: Vec<usize>,
+    pub subexpression: Expression,
+}
+
+impl From<EvalDiagnostic> for MathError {
+    fn from(diagnostic: EvalDiagnostic) -> Self {
+        MathError::DomainViolation { diagnostic }
+    }
+}
+
+/// Result of `EvalNumeric::eval_numeric_checked`
+#[derive(Debug, Clone, PartialEq)]
+pub struct CheckedEvaluation {
+    pub value: Expression,
+    /// Violations replaced by NaN, in evaluation order
+    pub diagnostics: Vec<EvalDiagnostic>,
 }
 
 /// Evaluation context
@@ -131,7 +256,8 @@ pub struct EvalContext {
     /// - 64 bits: Extended precision
     /// - 128+ bits: Arbitrary precision (future)
     ///
-    /// Note: Current implementation uses f64, so precision >53 has no effect yet.
+    /// Note: Current implementation uses f64, so precision >53 has no effect yet,
+    /// while lower precisions round the floats of a numerical result.
     /// Future versions will support arbitrary precision via `rug` or `mpc`.
     pub precision: u32,
 
@@ -142,6 +268,16 @@ pub struct EvalContext {
     ///
     /// Simplification often improves numerical stability by reducing expression complexity.
     pub simplify_first: bool,
+
+    /// Unit of the angles in trigonometric functions
+    ///
+    /// In degree mode `sin(30)` is the sine of 30° and evaluates to exactly
+    /// `1/2`, and inverse functions return degrees.
+    pub angle_unit: AngleUnit,
+
+    /// Treatment of division by zero, logarithms of non-positive numbers
+    /// and even roots of negative numbers during numerical evaluation
+    pub domain_policy: DomainPolicy,
 }
 
 impl EvalContext {
@@ -158,6 +294,9 @@ impl EvalContext {
     /// - Default precision (53 bits)
     /// - No pre-simplification
     ///
+    /// Inside an [`EvalScope`](super::eval_scope::EvalScope), its precision
+    /// and angle unit replace the defaults.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -172,12 +311,14 @@ impl EvalContext {
     /// // Result is still symbolic: x^2 + 2*x + 1
     /// ```
     pub fn symbolic() -> Self {
-        Self {
+        EvalSettings::current().apply_to(Self {
             variables: HashMap::new(),
             numeric: false,
             precision: 53,
             simplify_first: false,
-        }
+            angle_unit: AngleUnit::Radians,
+            domain_policy: DomainPolicy::Symbolic,
+        })
     }
 
     /// Create context for numerical evaluation with substitutions
@@ -197,6 +338,9 @@ impl EvalContext {
     /// - Default precision (53 bits for f64)
     /// - Pre-simplification enabled (simplify_first = true)
     ///
+    /// As with [`EvalContext::symbolic`], an open scope supplies the
+    /// precision and angle unit.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -215,12 +359,14 @@ impl EvalContext {
     /// // Result is numerical: 16 (= 3^2 + 2*3 + 1)
     /// ```
     pub fn numeric(variables: HashMap<String, Expression>) -> Self {
-        Self {
+        EvalSettings::current().apply_to(Self {
             variables,
             numeric: true,
             precision: 53,
             simplify_first: true,
-        }
+            angle_unit: AngleUnit::Radians,
+            domain_policy: DomainPolicy::Symbolic,
+        })
     }
 
     /// Set precision for numerical operations (bits)
@@ -277,6 +423,48 @@ impl EvalContext {
         self.simplify_first = simplify;
         self
     }
+
+    /// Set the unit of trigonometric angles
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::core::expression::eval_numeric::EvalContext;
+    /// use mathhook_core::core::AngleUnit;
+    /// use mathhook_core::Expression;
+    ///
+    /// let sin_30 = Expression::function("sin", vec![Expression::integer(30)]);
+    /// let ctx = EvalContext::symbolic().with_angle_unit(AngleUnit::Degrees);
+    /// assert_eq!(
+    ///     sin_30.evaluate_with_context(&ctx).unwrap(),
+    ///     Expression::rational(1, 2)
+    /// );
+    /// ```
+    pub fn with_angle_unit(mut self, unit: AngleUnit) -> Self {
+        self.angle_unit = unit;
+        self
+    }
+
+    /// Set how domain violations are treated during numerical evaluation
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::core::expression::eval_numeric::{DomainPolicy, EvalContext};
+    /// use mathhook_core::{expr, MathError};
+    /// use std::collections::HashMap;
+    ///
+    /// let vars = HashMap::from([("x".to_string(), expr!(1))]);
+    /// let ctx = EvalContext::numeric(vars)
+    ///     .with_simplify(false)
+    ///     .with_domain_policy(DomainPolicy::Strict);
+    /// let err = expr!(ln(x - 1)).evaluate_with_context(&ctx).unwrap_err();
+    /// assert!(matches!(err, MathError::DomainViolation { .. }));
+    /// ```
+    pub fn with_domain_policy(mut self, policy: DomainPolicy) -> Self {
+        self.domain_policy = policy;
+        self
+    }
 }
 
 impl Default for EvalContext {
@@ -302,6 +490,7 @@ fn is_number_negative(n: &Number) -> bool {
         Number::Float(f) => *f < 0.0,
         Number::BigInteger(bi) => **bi < BigInt::from(0),
         Number::Rational(r) => **r < BigRational::new(BigInt::from(0), BigInt::from(1)),
+        Number::Decimal(d) => d.is_negative(),
     }
 }
 
@@ -391,13 +580,24 @@ impl EvalNumeric for Expression {
                             return Ok(Expression::float(result));
                         }
                     }
+                    // Real roots such as 5^(1/2); negative bases stay symbolic
+                    (Expression::Number(b), Expression::Number(Number::Rational(e)))
+                        if !is_number_negative(b) =>
+                    {
+                        if let (Ok(b), Some(e)) = (b.to_float(), e.to_f64()) {
+                            let result = b.powf(e);
+                            if result.is_finite() {
+                                return Ok(Expression::float(result));
+                            }
+                        }
+                    }
                     _ => {}
                 }
 
                 Ok(Expression::pow(base_eval, exp_eval))
             }
 
-            Expression::Function { name, args } => {
+            Expression::Function { name, args, .. } => {
                 let eval_args = args
                     .iter()
                     .map(|arg| arg.eval_numeric(_precision))
@@ -483,6 +683,225 @@ impl EvalNumeric for Expression {
             Expression::MethodCall(_) => Ok(self.clone()),
         }
     }
+
+    fn eval_numeric_checked(
+        &self,
+        precision: u32,
+        policy: DomainPolicy,
+    ) -> Result<CheckedEvaluation, MathError> {
+        let mut diagnostics = Vec::new();
+        let value = if policy == DomainPolicy::Symbolic {
+            self.eval_numeric(precision)?
+        } else {
+            eval_tracked(self, precision, policy, &mut Vec::new(), &mut diagnostics)?
+        };
+        Ok(CheckedEvaluation { value, diagnostics })
+    }
+
+    fn eval_complex(&self) -> Result<Complex64, MathError> {
+        let value = complex_value(self)?;
+        if value.is_finite() {
+            Ok(value)
+        } else {
+            Err(MathError::Undefined {
+                expression: self.clone(),
+                reason: "value is not a finite complex number".to_owned(),
+            })
+        }
+    }
+}
+
+/// `eval_numeric` that checks each node once its operands are evaluated
+fn eval_tracked(
+    expr: &Expression,
+    precision: u32,
+    policy: DomainPolicy,
+    path: &mut Vec<usize>,
+    diagnostics: &mut Vec<EvalDiagnostic>,
+) -> Result<Expression, MathError> {
+    let mut index = 0;
+    let mut failure = None;
+    let mut nan_operand = false;
+    let evaluated = expr.map_children(|child| {
+        path.push(index);
+        index += 1;
+        let result = eval_tracked(child, precision, policy, path, diagnostics);
+        path.pop();
+        if let Ok(Expression::Number(Number::Float(value))) = &result {
+            nan_operand |= value.is_nan();
+        }
+        result.unwrap_or_else(|err| {
+            failure.get_or_insert(err);
+            child.clone()
+        })
+    });
+    if let Some(err) = failure {
+        return Err(err);
+    }
+    // NaN from a recorded violation propagates through every operation
+    if nan_operand {
+        return Ok(Expression::float(f64::NAN));
+    }
+
+    if let Some(kind) = domain_violation(&evaluated) {
+        let diagnostic = EvalDiagnostic {
+            kind,
+            path: path.clone(),
+            subexpression: expr.clone(),
+        };
+        if policy == DomainPolicy::Strict {
+            return Err(diagnostic.into());
+        }
+        diagnostics.push(diagnostic);
+        return Ok(Expression::float(f64::NAN));
+    }
+    evaluated.eval_numeric(precision)
+}
+
+/// Domain violation of a node whose operands are already evaluated
+fn domain_violation(expr: &Expression) -> Option<ViolationKind> {
+    let negative = |e: &Expression| matches!(e, Expression::Number(n) if is_number_negative(n));
+    match expr {
+        Expression::Pow(base, exp) => match exp.as_ref() {
+            Expression::Number(n) if base.is_zero() && is_number_negative(n) => {
+                Some(ViolationKind::DivisionByZero)
+            }
+            Expression::Number(Number::Rational(r))
+                if negative(base) && (r.denom() % BigInt::from(2)).is_zero() =>
+            {
+                Some(ViolationKind::EvenRootOfNegative)
+            }
+            _ => None,
+        },
+        Expression::Function { name, args, .. } => match (name.as_ref(), args.first()) {
+            ("sqrt", Some(arg)) if negative(arg) => Some(ViolationKind::EvenRootOfNegative),
+            ("ln" | "log" | "log10" | "log2", Some(arg))
+                if matches!(arg, Expression::Number(_)) && (arg.is_zero() || negative(arg)) =>
+            {
+                Some(ViolationKind::LogOfNonPositive)
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn complex_value(expr: &Expression) -> Result<Complex64, MathError> {
+    use crate::core::MathConstant;
+
+    let non_numerical = || MathError::NonNumericalResult {
+        expression: expr.clone(),
+    };
+    match expr {
+        Expression::Number(n) => Ok(Complex64::from(n.to_float()?)),
+        Expression::Constant(MathConstant::I) => Ok(Complex64::i()),
+        Expression::Constant(
+            MathConstant::Infinity | MathConstant::NegativeInfinity | MathConstant::Undefined,
+        ) => Err(non_numerical()),
+        Expression::Constant(c) => Ok(Complex64::from(c.to_f64())),
+        Expression::Add(terms) => terms.iter().map(complex_value).sum(),
+        Expression::Mul(factors) => factors.iter().map(complex_value).product(),
+        Expression::Pow(base, exp) => {
+            let base = complex_value(base)?;
+            let exp_value = complex_value(exp)?;
+            if base.is_zero() {
+                return match exp_value.re {
+                    re if exp_value.im == 0.0 && re == 0.0 => Ok(Complex64::from(1.0)),
+                    re if re > 0.0 => Ok(Complex64::zero()),
+                    _ => Err(MathError::DivisionByZero),
+                };
+            }
+            match exp.as_ref() {
+                Expression::Number(Number::Integer(n)) => match i32::try_from(*n) {
+                    Ok(n) => Ok(base.powi(n)),
+                    Err(_) => Ok(base.powc(exp_value)),
+                },
+                _ => Ok(base.powc(exp_value)),
+            }
+        }
+        Expression::Complex(data) => {
+            Ok(complex_value(&data.real)? + complex_value(&data.imag)? * Complex64::i())
+        }
+        Expression::Function { name, args, .. } => {
+            let values = args
+                .iter()
+                .map(complex_value)
+                .collect::<Result<Vec<_>, _>>()?;
+            complex_function(name, &values).ok_or_else(non_numerical)
+        }
+        _ => Err(non_numerical()),
+    }
+}
+
+/// Elementary function `name` at complex arguments, on principal branches
+fn complex_function(name: &str, args: &[Complex64]) -> Option<Complex64> {
+    let value = match (name, args) {
+        ("sin", [z]) => z.sin(),
+        ("cos", [z]) => z.cos(),
+        ("tan", [z]) => z.tan(),
+        ("sec", [z]) => z.cos().inv(),
+        ("csc", [z]) => z.sin().inv(),
+        ("cot", [z]) => z.tan().inv(),
+        ("arcsin" | "asin", [z]) => z.asin(),
+        ("arccos" | "acos", [z]) => z.acos(),
+        ("arctan" | "atan", [z]) => z.atan(),
+        ("sinh", [z]) => z.sinh(),
+        ("cosh", [z]) => z.cosh(),
+        ("tanh", [z]) => z.tanh(),
+        ("arcsinh" | "asinh", [z]) => z.asinh(),
+        ("arccosh" | "acosh", [z]) => z.acosh(),
+        ("arctanh" | "atanh", [z]) => z.atanh(),
+        ("exp", [z]) => z.exp(),
+        ("ln", [z]) => z.ln(),
+        ("log" | "log10", [z]) => z.log10(),
+        ("log", [z, base]) => z.ln() / base.ln(),
+        ("sqrt", [z]) => z.sqrt(),
+        ("abs", [z]) => Complex64::from(z.norm()),
+        ("arg", [z]) => Complex64::from(z.arg()),
+        ("re", [z]) => Complex64::from(z.re),
+        ("im", [z]) => Complex64::from(z.im),
+        ("conjugate", [z]) => z.conj(),
+        // Other functions are evaluated on the real line
+        _ => {
+            if args.iter().any(|z| z.im != 0.0) {
+                return None;
+            }
+            let real_args: Vec<Expression> = args.iter().map(|z| Expression::float(z.re)).collect();
+            match super::evaluation::evaluate_function_dispatch(name, &real_args)? {
+                Expression::Number(n) => Complex64::from(n.to_float().ok()?),
+                _ => return None,
+            }
+        }
+    };
+    Some(value)
+}
+
+/// `expr` with its floats rounded to `bits` significant bits
+///
+/// `f64` carries 53 bits, so higher precisions leave `expr` unchanged.
+pub(crate) fn round_to_precision(expr: &Expression, bits: u32) -> Expression {
+    if bits >= f64::MANTISSA_DIGITS {
+        return expr.clone();
+    }
+    match expr {
+        Expression::Number(Number::Float(value)) => Expression::float(round_to_bits(*value, bits)),
+        Expression::Number(_) | Expression::Symbol(_) | Expression::Constant(_) => expr.clone(),
+        _ => expr.map_children(|child| round_to_precision(child, bits)),
+    }
+}
+
+fn round_to_bits(value: f64, bits: u32) -> f64 {
+    if value == 0.0 || !value.is_finite() {
+        return value;
+    }
+    // Scale so that the kept bits sit left of the binary point
+    let exponent = value.abs().log2().floor() as i32;
+    let scale = 2f64.powi(bits.max(1) as i32 - 1 - exponent);
+    if scale.is_finite() && scale != 0.0 {
+        (value * scale).round() / scale
+    } else {
+        value
+    }
 }
 
 #[cfg(test)]
@@ -546,4 +965,152 @@ mod tests {
         assert_eq!(ctx.precision, 128);
         assert!(!ctx.simplify_first);
     }
+
+    #[test]
+    fn test_eval_numeric_rational_power() {
+        let sqrt5 = Expression::pow(Expression::integer(5), Expression::rational(1, 2));
+        assert_eq!(
+            sqrt5.eval_numeric(53).unwrap(),
+            Expression::float(5f64.sqrt())
+        );
+
+        let negative = Expression::pow(Expression::integer(-4), Expression::rational(1, 2));
+        assert_eq!(negative.eval_numeric(53).unwrap(), negative);
+    }
+
+    fn assert_close(actual: Complex64, expected: Complex64) {
+        assert!(
+            (actual - expected).norm() < 1e-12,
+            "expected {expected}, got {actual}"
+        );
+    }
+
+    #[test]
+    fn test_eval_complex_euler_formula() {
+        let x = Expression::rational(2, 3);
+        let exp_ix = Expression::function(
+            "exp",
+            vec![Expression::mul(vec![Expression::i(), x.clone()])],
+        );
+        assert_close(
+            exp_ix.eval_complex().unwrap(),
+            Complex64::new((2.0f64 / 3.0).cos(), (2.0f64 / 3.0).sin()),
+        );
+
+        let exp_i_pi = Expression::function(
+            "exp",
+            vec![Expression::mul(vec![Expression::i(), Expression::pi()])],
+        );
+        assert_close(exp_i_pi.eval_complex().unwrap(), Complex64::new(-1.0, 0.0));
+    }
+
+    #[test]
+    fn test_eval_complex_roots_and_powers() {
+        let cube_root = Expression::pow(Expression::integer(-8), Expression::rational(1, 3));
+        assert_close(
+            cube_root.eval_complex().unwrap(),
+            Complex64::new(1.0, 3f64.sqrt()),
+        );
+
+        let i_squared = Expression::pow(Expression::i(), Expression::integer(2));
+        assert_close(i_squared.eval_complex().unwrap(), Complex64::new(-1.0, 0.0));
+
+        let log_negative = Expression::function("ln", vec![Expression::integer(-1)]);
+        assert_close(
+            log_negative.eval_complex().unwrap(),
+            Complex64::new(0.0, std::f64::consts::PI),
+        );
+    }
+
+    #[test]
+    fn test_eval_complex_falls_back_to_real_functions() {
+        let gamma = Expression::function("gamma", vec![Expression::integer(5)]);
+        assert_close(gamma.eval_complex().unwrap(), Complex64::new(24.0, 0.0));
+    }
+
+    #[test]
+    fn test_eval_numeric_checked_locates_violations() {
+        use crate::expr;
+
+        // 2 + 1/(x - x) with the zero denominator left unsimplified
+        let zero = Expression::Add(std::sync::Arc::new(vec![expr!(3), expr!(-3)]));
+        let reciprocal = Expression::Pow(
+            std::sync::Arc::new(zero),
+            std::sync::Arc::new(Expression::integer(-1)),
+        );
+        let sum = Expression::Add(std::sync::Arc::new(vec![expr!(2), reciprocal.clone()]));
+
+        let err = sum
+            .eval_numeric_checked(53, DomainPolicy::Strict)
+            .unwrap_err();
+        let MathError::DomainViolation { diagnostic } = &err else {
+            panic!("expected a domain violation, got {err:?}");
+        };
+        assert_eq!(diagnostic.kind, ViolationKind::DivisionByZero);
+        assert_eq!(diagnostic.path, vec![1]);
+        assert_eq!(diagnostic.subexpression, reciprocal);
+
+        let checked = sum
+            .eval_numeric_checked(53, DomainPolicy::Permissive)
+            .unwrap();
+        assert!(checked.value.evaluate_to_f64().unwrap().is_nan());
+        assert_eq!(checked.diagnostics, vec![diagnostic.clone()]);
+    }
+
+    #[test]
+    fn test_eval_numeric_checked_roots_and_logs() {
+        let root = Expression::pow(Expression::integer(-4), Expression::rational(1, 2));
+        let cube_root = Expression::pow(Expression::integer(-8), Expression::rational(1, 3));
+        let log_zero = Expression::function("ln", vec![Expression::integer(0)]);
+        let reciprocal = Expression::Pow(
+            std::sync::Arc::new(Expression::float(0.0)),
+            std::sync::Arc::new(Expression::integer(-1)),
+        );
+        let product = Expression::Mul(std::sync::Arc::new(vec![
+            Expression::integer(3),
+            log_zero,
+            reciprocal,
+        ]));
+
+        let checked = product
+            .eval_numeric_checked(53, DomainPolicy::Permissive)
+            .unwrap();
+        let kinds: Vec<_> = checked.diagnostics.iter().map(|d| d.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ViolationKind::LogOfNonPositive,
+                ViolationKind::DivisionByZero
+            ]
+        );
+
+        assert!(matches!(
+            root.eval_numeric_checked(53, DomainPolicy::Strict),
+            Err(MathError::DomainViolation { .. })
+        ));
+        assert!(cube_root
+            .eval_numeric_checked(53, DomainPolicy::Strict)
+            .is_ok());
+        assert_eq!(
+            root.eval_numeric_checked(53, DomainPolicy::Symbolic)
+                .unwrap()
+                .value,
+            root
+        );
+    }
+
+    #[test]
+    fn test_eval_complex_errors() {
+        let x = Expression::symbol(crate::symbol!(x));
+        assert!(x.eval_complex().is_err());
+
+        let reciprocal = Expression::Pow(
+            std::sync::Arc::new(Expression::integer(0)),
+            std::sync::Arc::new(Expression::integer(-1)),
+        );
+        assert_eq!(reciprocal.eval_complex(), Err(MathError::DivisionByZero));
+
+        let log_zero = Expression::function("ln", vec![Expression::integer(0)]);
+        assert!(log_zero.eval_complex().is_err());
+    }
 }
@@ -0,0 +1,366 @@
+//! Evaluation settings scoped to a block of code
+//!
+//! Language bindings offer blocks such as Python's
+//! `with mathhook.evaluate(angle='degrees'):`, inside which every evaluation
+//! uses different defaults. [`EvalSettings::enter`] pushes settings for the
+//! current thread until the returned [`EvalScope`] is dropped. While a scope
+//! is open:
+//!
+//! - new [`EvalContext`]s start from its precision and angle unit, and
+//!   `evaluate` uses them too; precisions below the 53 bits of `f64` round
+//!   the floats in the result
+//! - `evaluate`, `evaluate_with_context` and `simplify` treat the named
+//!   symbols as carrying its assumptions, so `sqrt(x^2)` simplifies to `x`
+//!   for positive `x`
+//!
+//! The angle unit does not apply to `simplify`, which keeps trigonometric
+//! arguments as written.
+//!
+//! Scopes nest: inner settings override outer ones, and assumptions for
+//! different symbols accumulate.
+
+use super::eval_numeric::EvalContext;
+use super::Expression;
+use crate::core::symbol::SymbolAssumptions;
+use crate::core::{AngleUnit, Symbol};
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::marker::PhantomData;
+use std::mem;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Open scopes on any thread, so evaluation outside any scope skips the
+/// thread-local lookup
+static SCOPES: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    /// Settings of the open scopes on this thread, innermost last
+    static STACK: RefCell<Vec<EvalSettings>> = const { RefCell::new(Vec::new()) };
+
+    /// Whether the scoped assumptions are already attached to the expression
+    /// being worked on, so nested simplifications skip the lookup
+    static ATTACHED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Defaults for evaluations inside a scope; unset fields keep the outer value
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::core::expression::eval_scope::EvalSettings;
+/// use mathhook_core::core::AngleUnit;
+/// use mathhook_core::{EvalContext, Expression};
+///
+/// let sin_30 = Expression::function("sin", vec![Expression::integer(30)]);
+/// {
+///     let _scope = EvalSettings::new()
+///         .with_angle_unit(AngleUnit::Degrees)
+///         .enter();
+///     assert_eq!(sin_30.evaluate().unwrap(), Expression::rational(1, 2));
+///     assert_eq!(EvalContext::symbolic().angle_unit, AngleUnit::Degrees);
+/// }
+/// assert_eq!(EvalContext::symbolic().angle_unit, AngleUnit::Radians);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EvalSettings {
+    /// Precision of numerical evaluation in bits
+    pub precision: Option<u32>,
+    pub angle_unit: Option<AngleUnit>,
+    /// Assumptions for symbols, by name
+    pub assumptions: HashMap<String, SymbolAssumptions>,
+}
+
+impl EvalSettings {
+    /// Settings that change nothing
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_precision(mut self, bits: u32) -> Self {
+        self.precision = Some(bits);
+        self
+    }
+
+    pub fn with_angle_unit(mut self, unit: AngleUnit) -> Self {
+        self.angle_unit = Some(unit);
+        self
+    }
+
+    /// Treat symbols named `name` as carrying `assumptions`
+    pub fn assume(mut self, name: impl Into<String>, assumptions: SymbolAssumptions) -> Self {
+        self.assumptions.insert(name.into(), assumptions);
+        self
+    }
+
+    /// Apply these settings on the current thread until the scope is dropped
+    pub fn enter(self) -> EvalScope {
+        let depth = STACK.with(|stack| {
+            let mut stack = stack.borrow_mut();
+            stack.push(self);
+            stack.len() - 1
+        });
+        SCOPES.fetch_add(1, Ordering::Relaxed);
+        EvalScope {
+            depth,
+            _thread: PhantomData,
+        }
+    }
+
+    /// Replace every open scope on the current thread with these settings
+    /// until the guard is dropped
+    ///
+    /// For work run on behalf of another thread, such as a batch job picked up
+    /// by a worker that is itself inside some other scope.
+    pub(crate) fn isolate(self) -> Isolated {
+        let replacement = if self.is_empty() {
+            Vec::new()
+        } else {
+            vec![self]
+        };
+        SCOPES.fetch_add(replacement.len(), Ordering::Relaxed);
+        Isolated {
+            outer: STACK.with(|stack| stack.replace(replacement)),
+            attached: ATTACHED.with(|attached| attached.replace(false)),
+            _thread: PhantomData,
+        }
+    }
+
+    /// Combined settings of the open scopes on the current thread
+    pub fn current() -> Self {
+        if SCOPES.load(Ordering::Relaxed) == 0 {
+            return Self::default();
+        }
+        STACK.with(|stack| {
+            stack
+                .borrow()
+                .iter()
+                .fold(Self::default(), |mut merged, settings| {
+                    merged.precision = settings.precision.or(merged.precision);
+                    merged.angle_unit = settings.angle_unit.or(merged.angle_unit);
+                    merged.assumptions.extend(
+                        settings
+                            .assumptions
+                            .iter()
+                            .map(|(name, facts)| (name.clone(), *facts)),
+                    );
+                    merged
+                })
+        })
+    }
+
+    /// Whether these settings change nothing
+    pub fn is_empty(&self) -> bool {
+        self.precision.is_none() && self.angle_unit.is_none() && self.assumptions.is_empty()
+    }
+
+    /// `context` with the precision and angle unit set here
+    pub(crate) fn apply_to(&self, mut context: EvalContext) -> EvalContext {
+        if let Some(bits) = self.precision {
+            context.precision = bits;
+        }
+        if let Some(unit) = self.angle_unit {
+            context.angle_unit = unit;
+        }
+        context
+    }
+
+    /// Run `simplify` on `expr` with the assumptions of the open scopes
+    /// attached to its symbols
+    pub(crate) fn simplify_in_scope(
+        expr: &Expression,
+        simplify: impl FnOnce(&Expression) -> Expression,
+    ) -> Expression {
+        if SCOPES.load(Ordering::Relaxed) == 0 || ATTACHED.with(Cell::get) {
+            return simplify(expr);
+        }
+        Self::current()
+            .with_assumptions(expr, |expr| Ok::<_, Infallible>(simplify(expr)))
+            .unwrap_or_else(|never| match never {})
+    }
+
+    /// Run `evaluate` on `expr` with the scoped assumptions attached to its
+    /// symbols, then restore the symbols the caller used
+    pub(crate) fn with_assumptions<E>(
+        &self,
+        expr: &Expression,
+        evaluate: impl FnOnce(&Expression) -> Result<Expression, E>,
+    ) -> Result<Expression, E> {
+        let _attached = Attached::mark();
+        if self.assumptions.is_empty() {
+            return evaluate(expr);
+        }
+        let mut assumed = HashMap::new();
+        let mut restored = HashMap::new();
+        for symbol in expr.find_variables() {
+            if let Some(facts) = self.assumptions.get(symbol.name()) {
+                let name = symbol.name().to_owned();
+                let with_facts = Symbol::scalar(&name).with_assumptions(*facts);
+                assumed.insert(name.clone(), Expression::symbol(with_facts));
+                restored.insert(name, Expression::symbol(symbol));
+            }
+        }
+        if assumed.is_empty() {
+            return evaluate(expr);
+        }
+        evaluate(&expr.substitute(&assumed)).map(|result| result.substitute(&restored))
+    }
+}
+
+/// Marks the scoped assumptions as attached until dropped
+struct Attached(bool);
+
+impl Attached {
+    fn mark() -> Self {
+        Self(ATTACHED.with(|attached| attached.replace(true)))
+    }
+}
+
+impl Drop for Attached {
+    fn drop(&mut self) {
+        ATTACHED.with(|attached| attached.set(self.0));
+    }
+}
+
+/// Scopes set aside by [`EvalSettings::isolate`]; dropping it restores them
+pub(crate) struct Isolated {
+    outer: Vec<EvalSettings>,
+    attached: bool,
+    _thread: PhantomData<*const ()>,
+}
+
+impl Drop for Isolated {
+    fn drop(&mut self) {
+        let outer = mem::take(&mut self.outer);
+        let closed = STACK.with(|stack| stack.replace(outer).len());
+        SCOPES.fetch_sub(closed, Ordering::Relaxed);
+        ATTACHED.with(|attached| attached.set(self.attached));
+    }
+}
+
+/// Open evaluation scope; dropping it restores the previous settings
+///
+/// Tied to the thread that opened it.
+#[derive(Debug)]
+#[must_use = "the settings only apply until the scope is dropped"]
+pub struct EvalScope {
+    depth: usize,
+    _thread: PhantomData<*const ()>,
+}
+
+impl Drop for EvalScope {
+    fn drop(&mut self) {
+        // Truncating also closes inner scopes that were leaked rather than
+        // dropped, so the stack never outlives its owners
+        let closed = STACK.with(|stack| {
+            let mut stack = stack.borrow_mut();
+            let closed = stack.len().saturating_sub(self.depth);
+            stack.truncate(self.depth);
+            closed
+        });
+        SCOPES.fetch_sub(closed, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simplify::Simplify;
+    use crate::{expr, symbol};
+
+    #[test]
+    fn test_scopes_nest() {
+        assert!(EvalSettings::current().is_empty());
+        let outer = EvalSettings::new()
+            .with_precision(128)
+            .with_angle_unit(AngleUnit::Degrees)
+            .enter();
+        {
+            let _inner = EvalSettings::new().with_precision(256).enter();
+            let current = EvalSettings::current();
+            assert_eq!(current.precision, Some(256));
+            assert_eq!(current.angle_unit, Some(AngleUnit::Degrees));
+            assert_eq!(EvalContext::numeric(HashMap::new()).precision, 256);
+        }
+        assert_eq!(EvalSettings::current().precision, Some(128));
+        drop(outer);
+        assert!(EvalSettings::current().is_empty());
+        assert_eq!(EvalContext::symbolic().precision, 53);
+    }
+
+    #[test]
+    fn test_scoped_assumptions() {
+        let x = symbol!(x);
+        let root = Expression::function("sqrt", vec![expr!(x ^ 2)]);
+        let simplifying = || EvalContext::symbolic().with_simplify(true);
+        assert_ne!(
+            root.evaluate_with_context(&simplifying()).unwrap(),
+            Expression::symbol(x.clone())
+        );
+
+        let _scope = EvalSettings::new()
+            .assume("x", SymbolAssumptions::new().positive())
+            .enter();
+        let result = root.evaluate_with_context(&simplifying()).unwrap();
+        assert_eq!(result, Expression::symbol(x));
+        assert!(result.find_variables()[0].assumptions().is_empty());
+        assert_eq!(expr!(y + y).evaluate().unwrap(), expr!(2 * y).simplify());
+    }
+
+    #[test]
+    fn test_scoped_simplify_and_precision() {
+        let x = symbol!(x);
+        let root = Expression::function("sqrt", vec![expr!(x ^ 2)]);
+        let sqrt_2 = Expression::function("sqrt", vec![expr!(2)]);
+        let numeric = || EvalContext::numeric(HashMap::new());
+        {
+            let _scope = EvalSettings::new()
+                .with_precision(8)
+                .assume("x", SymbolAssumptions::new().positive())
+                .enter();
+            let simplified = root.simplify();
+            assert_eq!(simplified, Expression::symbol(x.clone()));
+            assert!(simplified.find_variables()[0].assumptions().is_empty());
+            assert_eq!(
+                sqrt_2.evaluate_with_context(&numeric()).unwrap(),
+                Expression::float(1.4140625)
+            );
+        }
+        assert_ne!(root.simplify(), Expression::symbol(x));
+        assert_eq!(
+            sqrt_2.evaluate_with_context(&numeric()).unwrap(),
+            Expression::float(std::f64::consts::SQRT_2)
+        );
+    }
+
+    #[test]
+    fn test_isolate_replaces_open_scopes() {
+        let _outer = EvalSettings::new()
+            .with_precision(100)
+            .with_angle_unit(AngleUnit::Degrees)
+            .enter();
+        {
+            let _isolated = EvalSettings::new().isolate();
+            assert!(EvalSettings::current().is_empty());
+        }
+        {
+            let _isolated = EvalSettings::new().with_precision(200).isolate();
+            std::mem::forget(EvalSettings::new().with_precision(300).enter());
+            let current = EvalSettings::current();
+            assert_eq!(current.precision, Some(300));
+            assert_eq!(current.angle_unit, None);
+        }
+        let current = EvalSettings::current();
+        assert_eq!(current.precision, Some(100));
+        assert_eq!(current.angle_unit, Some(AngleUnit::Degrees));
+    }
+
+    #[test]
+    fn test_leaked_inner_scope_is_closed_by_outer() {
+        let outer = EvalSettings::new().with_precision(100).enter();
+        std::mem::forget(EvalSettings::new().with_precision(200).enter());
+        assert_eq!(EvalSettings::current().precision, Some(200));
+        drop(outer);
+        assert!(EvalSettings::current().is_empty());
+    }
+}
@@ -6,7 +6,8 @@
 //! - `evaluate_to_f64()` - conversion to f64
 //! - `try_extract_numeric_value()` - helper for numeric extraction
 
-use super::super::eval_numeric::EvalContext;
+use super::super::eval_numeric::{round_to_precision, EvalContext};
+use super::super::eval_scope::EvalSettings;
 use super::super::Expression;
 use crate::core::constants::EPSILON;
 use crate::core::Number;
@@ -114,7 +115,32 @@ impl Expression {
     ///     Err(e) => eprintln!("Other error: {:?}", e),
     /// }
     /// ```
+    ///
+    /// ## Scoped Settings
+    ///
+    /// Inside an [`EvalScope`](crate::core::expression::eval_scope::EvalScope),
+    /// trigonometric arguments use its angle unit and the named symbols carry
+    /// its assumptions.
     pub fn evaluate(&self) -> Result<Expression, crate::MathError> {
+        let settings = EvalSettings::current();
+        if settings.is_empty() {
+            return self.evaluate_tree();
+        }
+        let value = settings.with_assumptions(self, |expr| {
+            settings
+                .angle_unit
+                .unwrap_or_default()
+                .in_radians(expr)
+                .evaluate_tree()
+        })?;
+        Ok(match settings.precision {
+            Some(bits) => round_to_precision(&value, bits),
+            None => value,
+        })
+    }
+
+    /// `evaluate` without scoped settings, applied recursively
+    fn evaluate_tree(&self) -> Result<Expression, crate::MathError> {
         use crate::MathError;
         use std::f64::consts::PI;
 
@@ -127,19 +153,19 @@ impl Expression {
 
             Expression::Add(terms) => {
                 let evaluated_terms: Result<Vec<Expression>, MathError> =
-                    terms.iter().map(|t| t.evaluate()).collect();
+                    terms.iter().map(|t| t.evaluate_tree()).collect();
                 Ok(Expression::add(evaluated_terms?).simplify())
             }
 
             Expression::Mul(factors) => {
                 let evaluated_factors: Result<Vec<Expression>, MathError> =
-                    factors.iter().map(|f| f.evaluate()).collect();
+                    factors.iter().map(|f| f.evaluate_tree()).collect();
                 Ok(Expression::mul(evaluated_factors?).simplify())
             }
 
             Expression::Pow(base, exp) => {
-                let eval_base = base.evaluate()?;
-                let eval_exp = exp.evaluate()?;
+                let eval_base = base.evaluate_tree()?;
+                let eval_exp = exp.evaluate_tree()?;
 
                 if eval_base.is_zero_fast() {
                     if let Some(exp_value) = Self::try_extract_numeric_value(&eval_exp) {
@@ -152,13 +178,13 @@ impl Expression {
                 Ok(Expression::pow(eval_base, eval_exp).simplify())
             }
 
-            Expression::Function { name, args } => {
+            Expression::Function { name, args, .. } => {
                 if name.as_ref() == "undefined" {
                     return Err(MathError::DivisionByZero);
                 }
 
                 let evaluated_args: Result<Vec<Expression>, MathError> =
-                    args.iter().map(|arg| arg.evaluate()).collect();
+                    args.iter().map(|arg| arg.evaluate_tree()).collect();
                 let evaluated_args = evaluated_args?;
 
                 match name.as_ref() {
@@ -351,24 +377,29 @@ impl Expression {
         &self,
         context: &EvalContext,
     ) -> Result<Expression, crate::MathError> {
-        let substituted = if context.variables.is_empty() {
-            self.clone()
-        } else {
-            self.substitute(&context.variables)
-        };
+        EvalSettings::current().with_assumptions(self, |expr| {
+            let substituted = if context.variables.is_empty() {
+                expr.clone()
+            } else {
+                expr.substitute(&context.variables)
+            };
+            let substituted = context.angle_unit.in_radians(&substituted);
 
-        let simplified = if context.simplify_first {
-            substituted.simplify()
-        } else {
-            substituted
-        };
+            let simplified = if context.simplify_first {
+                substituted.simplify()
+            } else {
+                substituted
+            };
 
-        if context.numeric {
-            use crate::core::expression::eval_numeric::EvalNumeric;
-            simplified.eval_numeric(context.precision)
-        } else {
-            Ok(simplified)
-        }
+            if context.numeric {
+                use crate::core::expression::eval_numeric::EvalNumeric;
+                simplified
+                    .eval_numeric_checked(context.precision, context.domain_policy)
+                    .map(|checked| round_to_precision(&checked.value, context.precision))
+            } else {
+                Ok(simplified)
+            }
+        })
     }
 
     /// Convert evaluated expression to f64
@@ -409,6 +440,9 @@ impl Expression {
                         operation: "rational to f64 conversion".to_owned(),
                     })
                 }
+                Number::Decimal(d) => d.to_f64().ok_or_else(|| crate::MathError::NumericOverflow {
+                    operation: "decimal to f64 conversion".to_owned(),
+                }),
             },
             Expression::Constant(ref c) => {
                 let val = c.to_f64();
@@ -435,6 +469,11 @@ impl Expression {
                                 operation: "rational to f64 conversion".to_owned(),
                             })
                         }
+                        Number::Decimal(d) => {
+                            d.to_f64().ok_or_else(|| crate::MathError::NumericOverflow {
+                                operation: "decimal to f64 conversion".to_owned(),
+                            })
+                        }
                     },
                     _ => Err(crate::MathError::NonNumericalResult {
                         expression: evaluated.clone(),
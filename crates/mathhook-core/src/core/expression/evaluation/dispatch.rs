@@ -60,6 +60,9 @@ use std::collections::HashMap;
 /// - Chebyshev second kind: chebyshevu, chebyshev_u
 /// - Hermite: hermiteh, hermite
 /// - Laguerre: laguerrel, laguerre
+///
+/// ## Custom Functions
+/// - Any name registered in [`crate::functions::custom`], when every argument is a number
 #[inline]
 pub fn evaluate_function_dispatch(name: &str, args: &[Expression]) -> Option<Expression> {
     if args.is_empty() {
@@ -123,6 +126,9 @@ pub fn evaluate_function_dispatch(name: &str, args: &[Expression]) -> Option<Exp
         "erf" => Some(crate::functions::special::erf(&args[0])),
         "erfc" => Some(crate::functions::special::erfc(&args[0])),
         "factorial" => Some(crate::functions::special::factorial(&args[0])),
+        "heaviside" => Some(crate::functions::special::heaviside(&args[0])),
+        "dirac_delta" => crate::functions::special::distributions::delta_order(args)
+            .map(|order| crate::functions::special::dirac_delta(&args[0], order)),
         "beta" if args.len() >= 2 => Some(crate::functions::special::beta(&args[0], &args[1])),
         "gcd" if args.len() >= 2 => Some(args[0].gcd(&args[1])),
         "lcm" if args.len() >= 2 => Some(crate::functions::number_theory_eval::lcm(
@@ -176,7 +182,7 @@ pub fn evaluate_function_dispatch(name: &str, args: &[Expression]) -> Option<Exp
             &args[1],
             crate::functions::polynomials::symbolic::expand_laguerre_symbolic,
         ),
-        _ => None,
+        _ => crate::functions::custom::custom_function(name)?.evaluate(args),
     }
 }
 
@@ -66,7 +66,7 @@ impl Expression {
                 Expression::pow(new_base, new_exp)
             }
 
-            Expression::Function { name, args } => {
+            Expression::Function { name, args, .. } => {
                 let new_args: Vec<Expression> = args
                     .iter()
                     .map(|arg| arg.substitute(substitutions))
@@ -0,0 +1,146 @@
+//! Interned identifiers for built-in function names
+//!
+//! Every `Expression::Function` carries a [`FunctionId`] computed from its
+//! name when the node is built. Built-in functions get the index of their name
+//! in [`BUILTIN_FUNCTIONS`], so property lookups on hot paths index a table
+//! instead of hashing the name; every other name is user-defined and falls
+//! back to string lookup.
+
+use std::hash::{Hash, Hasher};
+
+/// Names of the built-in functions, sorted so that an ID is the index of its
+/// name
+///
+/// Covers every function registered in the universal function registry.
+pub const BUILTIN_FUNCTIONS: &[&str] = &[
+    "abs",
+    "acosh",
+    "arccos",
+    "arccot",
+    "arccsc",
+    "arcsec",
+    "arcsin",
+    "arctan",
+    "asinh",
+    "atanh",
+    "bessel_j",
+    "bessel_y",
+    "beta",
+    "chebyshev_first",
+    "chebyshev_second",
+    "cos",
+    "cosh",
+    "cot",
+    "coth",
+    "csc",
+    "csch",
+    "digamma",
+    "exp",
+    "gamma",
+    "gcd",
+    "hermite",
+    "is_prime",
+    "laguerre",
+    "lcm",
+    "legendre_p",
+    "ln",
+    "log",
+    "mod",
+    "polygamma",
+    "sec",
+    "sech",
+    "sin",
+    "sinh",
+    "sqrt",
+    "tan",
+    "tanh",
+    "zeta",
+];
+
+/// Interned identifier of a function name
+///
+/// The ID is a cache of the name, so it never distinguishes two functions on
+/// its own: all IDs compare equal and hash to nothing, which keeps equality
+/// and hashing of expressions determined by the name alone. Deserialized
+/// functions start out user-defined and take the string path.
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::core::expression::FunctionId;
+///
+/// assert_eq!(FunctionId::of("sin").name(), Some("sin"));
+/// assert!(!FunctionId::of("f").is_builtin());
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct FunctionId(u16);
+
+impl FunctionId {
+    /// ID of every function that is not built in
+    pub const USER_DEFINED: FunctionId = FunctionId(u16::MAX);
+
+    /// Intern a function name
+    #[inline]
+    pub fn of(name: &str) -> Self {
+        BUILTIN_FUNCTIONS
+            .binary_search(&name)
+            .map_or(Self::USER_DEFINED, |index| FunctionId(index as u16))
+    }
+
+    /// Index into [`BUILTIN_FUNCTIONS`], or `None` for user-defined functions
+    #[inline]
+    pub fn index(self) -> Option<usize> {
+        let index = self.0 as usize;
+        (index < BUILTIN_FUNCTIONS.len()).then_some(index)
+    }
+
+    /// Name of a built-in function
+    #[inline]
+    pub fn name(self) -> Option<&'static str> {
+        self.index().map(|index| BUILTIN_FUNCTIONS[index])
+    }
+
+    /// Whether the name is one of [`BUILTIN_FUNCTIONS`]
+    #[inline]
+    pub fn is_builtin(self) -> bool {
+        self.index().is_some()
+    }
+}
+
+impl Default for FunctionId {
+    fn default() -> Self {
+        Self::USER_DEFINED
+    }
+}
+
+impl PartialEq for FunctionId {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for FunctionId {}
+
+impl Hash for FunctionId {
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::functions::intelligence::get_universal_registry;
+
+    #[test]
+    fn test_builtin_names_are_sorted() {
+        assert!(BUILTIN_FUNCTIONS.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(BUILTIN_FUNCTIONS.len() < u16::MAX as usize);
+    }
+
+    #[test]
+    fn test_registered_functions_have_ids() {
+        for name in get_universal_registry().list_all_functions() {
+            let id = FunctionId::of(&name);
+            assert_eq!(id.name(), Some(name.as_str()), "{} has no ID", name);
+        }
+    }
+}
@@ -0,0 +1,198 @@
+//! Structural hashing for expressions
+//!
+//! Two expressions that compare equal hash to the same value, so expressions
+//! can key a `HashMap` or `HashSet` directly. Floats hash by bit pattern with
+//! `-0.0` folded into `0.0` and every `NaN` folded into one, matching
+//! `Number`'s equality, under which all `NaN`s are equal.
+
+use super::{CalculusData, Expression};
+use crate::core::{MathConstant, Number};
+use std::hash::{Hash, Hasher};
+use std::mem;
+
+impl Eq for Expression {}
+
+impl Hash for Expression {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+        match self {
+            Expression::Number(n) => n.hash(state),
+            Expression::Symbol(s) => s.hash(state),
+            Expression::Add(operands) | Expression::Mul(operands) | Expression::Set(operands) => {
+                operands.hash(state)
+            }
+            Expression::Pow(base, exp) => {
+                base.hash(state);
+                exp.hash(state);
+            }
+            Expression::Function { name, args, .. } => {
+                name.hash(state);
+                args.hash(state);
+            }
+            Expression::Constant(c) => c.hash(state),
+            Expression::Complex(data) => {
+                data.real.hash(state);
+                data.imag.hash(state);
+            }
+            // Dimensions only: equal matrices agree on them, and walking every
+            // element of a large matrix on each lookup is not worth it
+            Expression::Matrix(matrix) => matrix.dimensions().hash(state),
+            Expression::Relation(data) => {
+                data.left.hash(state);
+                data.right.hash(state);
+                mem::discriminant(&data.relation_type).hash(state);
+            }
+            Expression::Piecewise(data) => {
+                data.pieces.hash(state);
+                data.default.hash(state);
+            }
+            Expression::Interval(data) => {
+                data.start.hash(state);
+                data.end.hash(state);
+                data.start_inclusive.hash(state);
+                data.end_inclusive.hash(state);
+            }
+            Expression::Calculus(data) => data.as_ref().hash(state),
+            Expression::MethodCall(data) => {
+                data.object.hash(state);
+                data.method_name.hash(state);
+                data.args.hash(state);
+            }
+        }
+    }
+}
+
+impl Hash for Number {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+        match self {
+            Number::Integer(i) => i.hash(state),
+            Number::Float(f) => {
+                let f = if *f == 0.0 {
+                    0.0
+                } else if f.is_nan() {
+                    f64::NAN
+                } else {
+                    *f
+                };
+                f.to_bits().hash(state);
+            }
+            Number::BigInteger(i) => i.hash(state),
+            Number::Rational(r) => r.hash(state),
+            Number::Decimal(d) => d.hash(state),
+        }
+    }
+}
+
+impl Hash for MathConstant {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+    }
+}
+
+impl Hash for CalculusData {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+        match self {
+            CalculusData::Derivative {
+                expression,
+                variable,
+                order,
+            } => {
+                expression.hash(state);
+                variable.hash(state);
+                order.hash(state);
+            }
+            CalculusData::Integral {
+                integrand,
+                variable,
+                bounds,
+            } => {
+                integrand.hash(state);
+                variable.hash(state);
+                bounds.hash(state);
+            }
+            CalculusData::Limit {
+                expression,
+                variable,
+                point,
+                direction,
+            } => {
+                expression.hash(state);
+                variable.hash(state);
+                point.hash(state);
+                mem::discriminant(direction).hash(state);
+            }
+            CalculusData::Sum {
+                expression,
+                variable,
+                start,
+                end,
+            }
+            | CalculusData::Product {
+                expression,
+                variable,
+                start,
+                end,
+            } => {
+                expression.hash(state);
+                variable.hash(state);
+                start.hash(state);
+                end.hash(state);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{expr, symbol};
+    use std::collections::hash_map::DefaultHasher;
+    use std::collections::HashMap;
+
+    fn hash_of(expr: &Expression) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        expr.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn test_equal_expressions_hash_equal() {
+        let a = expr!((x + 1) * sin(y));
+        let b = expr!((x + 1) * sin(y));
+        assert_eq!(a, b);
+        assert_eq!(hash_of(&a), hash_of(&b));
+        assert_eq!(
+            hash_of(&Expression::float(0.0)),
+            hash_of(&Expression::float(-0.0))
+        );
+    }
+
+    #[test]
+    fn test_nan_equals_itself() {
+        let nan = Expression::float(f64::NAN);
+        let negative_nan = Expression::float(-f64::NAN);
+        assert_eq!(nan, nan.clone());
+        assert_eq!(nan, negative_nan);
+        assert_eq!(hash_of(&nan), hash_of(&negative_nan));
+        assert_ne!(nan, Expression::float(0.0));
+
+        let key = |value: &Expression| Expression::function("g", vec![expr!(x), value.clone()]);
+        let mut map = HashMap::new();
+        map.insert(key(&nan), Expression::integer(1));
+        assert_eq!(map.get(&key(&negative_nan)), Some(&Expression::integer(1)));
+    }
+
+    #[test]
+    fn test_expressions_as_map_keys() {
+        let x = symbol!(x);
+        let mut map = HashMap::new();
+        map.insert(Expression::symbol(x.clone()), Expression::integer(1));
+        map.insert(expr!(x ^ 2), Expression::integer(2));
+
+        assert_eq!(map.get(&expr!(x)), Some(&Expression::integer(1)));
+        assert_eq!(map.get(&expr!(x ^ 2)), Some(&Expression::integer(2)));
+        assert_eq!(map.get(&expr!(x ^ 3)), None);
+    }
+}
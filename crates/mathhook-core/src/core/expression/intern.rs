@@ -0,0 +1,338 @@
+//! Hash-consing of expressions
+//!
+//! An [`ExpressionInterner`] keeps one copy of every distinct subtree it has
+//! seen. Interning rebuilds an expression bottom-up from those copies, so
+//! structurally equal subtrees end up sharing the same `Arc` storage. Since
+//! `Arc` equality short-circuits on identical pointers, comparing interned
+//! expressions no longer walks shared subtrees, and a large polynomial that
+//! repeats the same monomials stores each of them once.
+//!
+//! Children are interned before their parent, so a node is looked up by its
+//! variant and the addresses of its (already canonical) children instead of
+//! by a deep structural hash: interning is linear in the size of the tree.
+//!
+//! Interning is opt-in. [`Expression::intern`] uses an interner local to the
+//! current thread, which lives until [`clear`] is called; create an
+//! `ExpressionInterner` directly to bound the sharing to one computation.
+//!
+//! Matrices, piecewise functions, intervals, calculus operations and method
+//! calls are shared as whole nodes without interning their contents.
+
+use super::{ComplexData, Expression, RelationData};
+use std::cell::RefCell;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
+use std::mem;
+use std::sync::Arc;
+
+thread_local! {
+    static INTERNER: RefCell<ExpressionInterner> = RefCell::new(ExpressionInterner::new());
+}
+
+/// Table of canonical subtrees
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::core::expression::ExpressionInterner;
+/// use mathhook_core::expr;
+///
+/// let mut interner = ExpressionInterner::new();
+/// let a = interner.intern(&expr!((x ^ 2) + y));
+/// let b = interner.intern(&expr!((x ^ 2) + y));
+/// assert!(a.ptr_eq(&b));
+/// ```
+#[derive(Debug, Default)]
+pub struct ExpressionInterner {
+    table: HashSet<Node>,
+}
+
+impl ExpressionInterner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Canonical copy of `expr`, sharing storage with every equal subtree
+    /// interned before
+    pub fn intern(&mut self, expr: &Expression) -> Expression {
+        self.intern_node(expr)
+    }
+
+    /// Number of distinct subtrees stored
+    pub fn len(&self) -> usize {
+        self.table.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.table.is_empty()
+    }
+
+    /// Forget every stored subtree
+    pub fn clear(&mut self) {
+        self.table.clear();
+    }
+
+    fn intern_node(&mut self, expr: &Expression) -> Expression {
+        let mut child = |interner: &mut Self, child: &Expression| interner.intern_node(child);
+
+        let rebuilt = match expr {
+            Expression::Number(_) | Expression::Symbol(_) | Expression::Constant(_) => expr.clone(),
+            Expression::Add(terms) => Expression::Add(self.intern_all(terms, &mut child)),
+            Expression::Mul(factors) => Expression::Mul(self.intern_all(factors, &mut child)),
+            Expression::Set(elements) => Expression::Set(self.intern_all(elements, &mut child)),
+            Expression::Pow(base, exp) => {
+                Expression::Pow(Arc::new(child(self, base)), Arc::new(child(self, exp)))
+            }
+            Expression::Function { name, args, id } => Expression::Function {
+                name: name.clone(),
+                args: self.intern_all(args, &mut child),
+                id: *id,
+            },
+            Expression::Complex(data) => Expression::Complex(Arc::new(ComplexData {
+                real: child(self, &data.real),
+                imag: child(self, &data.imag),
+            })),
+            Expression::Relation(data) => Expression::Relation(Arc::new(RelationData {
+                left: child(self, &data.left),
+                right: child(self, &data.right),
+                relation_type: data.relation_type,
+            })),
+            Expression::Matrix(_)
+            | Expression::Piecewise(_)
+            | Expression::Interval(_)
+            | Expression::Calculus(_)
+            | Expression::MethodCall(_) => expr.clone(),
+        };
+        let node = Node(rebuilt);
+        if let Some(existing) = self.table.get(&node) {
+            return existing.0.clone();
+        }
+        let interned = node.0.clone();
+        self.table.insert(node);
+        interned
+    }
+
+    fn intern_all<F>(&mut self, items: &Arc<Vec<Expression>>, child: &mut F) -> Arc<Vec<Expression>>
+    where
+        F: FnMut(&mut Self, &Expression) -> Expression,
+    {
+        Arc::new(items.iter().map(|item| child(self, item)).collect())
+    }
+}
+
+/// Expression keyed by its variant and the identity of its children
+///
+/// Equal interned nodes have identical children, so equal nodes hash alike,
+/// and equality compares the children by pointer first.
+#[derive(Debug, PartialEq, Eq)]
+struct Node(Expression);
+
+impl Hash for Node {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let expr = &self.0;
+        mem::discriminant(expr).hash(state);
+        match expr {
+            Expression::Add(items) | Expression::Mul(items) | Expression::Set(items) => {
+                items.iter().for_each(|item| identity(item, state))
+            }
+            Expression::Pow(base, exp) => {
+                identity(base, state);
+                identity(exp, state);
+            }
+            Expression::Function { name, args, .. } => {
+                name.hash(state);
+                args.iter().for_each(|arg| identity(arg, state));
+            }
+            Expression::Complex(data) => {
+                identity(&data.real, state);
+                identity(&data.imag, state);
+            }
+            Expression::Relation(data) => {
+                identity(&data.left, state);
+                identity(&data.right, state);
+                mem::discriminant(&data.relation_type).hash(state);
+            }
+            _ => expr.hash(state),
+        }
+    }
+}
+
+/// Hash an interned child by the address of its storage, or by value when it
+/// is stored inline
+fn identity<H: Hasher>(child: &Expression, state: &mut H) {
+    let address = match child {
+        Expression::Number(_) | Expression::Symbol(_) | Expression::Constant(_) => {
+            return child.hash(state);
+        }
+        Expression::Add(items) | Expression::Mul(items) | Expression::Set(items) => {
+            Arc::as_ptr(items) as *const ()
+        }
+        Expression::Pow(base, exp) => {
+            (Arc::as_ptr(exp) as usize).hash(state);
+            Arc::as_ptr(base) as *const ()
+        }
+        Expression::Function { args, .. } => Arc::as_ptr(args) as *const (),
+        Expression::Complex(data) => Arc::as_ptr(data) as *const (),
+        Expression::Relation(data) => Arc::as_ptr(data) as *const (),
+        // Stored whole, so equal values are not necessarily at one address
+        Expression::Matrix(_)
+        | Expression::Piecewise(_)
+        | Expression::Interval(_)
+        | Expression::Calculus(_)
+        | Expression::MethodCall(_) => {
+            let mut hasher = DefaultHasher::new();
+            child.hash(&mut hasher);
+            return hasher.finish().hash(state);
+        }
+    };
+    (address as usize).hash(state);
+}
+
+/// Forget the subtrees interned by [`Expression::intern`] on this thread
+pub fn clear() {
+    INTERNER.with(|interner| interner.borrow_mut().clear());
+}
+
+/// Number of distinct subtrees interned by [`Expression::intern`] on this
+/// thread
+pub fn interned_count() -> usize {
+    INTERNER.with(|interner| interner.borrow().len())
+}
+
+impl Expression {
+    /// Canonical copy of this expression from the current thread's interner
+    ///
+    /// Equal subtrees of everything interned on the thread share storage,
+    /// so [`Expression::ptr_eq`] decides equality of interned expressions.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::expr;
+    ///
+    /// let a = expr!((x + 1) ^ 2).intern();
+    /// let b = expr!((x + 1) ^ 2).intern();
+    /// assert!(a.ptr_eq(&b));
+    /// ```
+    pub fn intern(&self) -> Expression {
+        INTERNER.with(|interner| interner.borrow_mut().intern(self))
+    }
+
+    /// Whether both expressions use the same storage
+    ///
+    /// Implies equality. Numbers, symbols and constants are stored inline,
+    /// so for them this is plain equality.
+    pub fn ptr_eq(&self, other: &Expression) -> bool {
+        match (self, other) {
+            (Expression::Add(a), Expression::Add(b))
+            | (Expression::Mul(a), Expression::Mul(b))
+            | (Expression::Set(a), Expression::Set(b)) => Arc::ptr_eq(a, b),
+            (Expression::Pow(a, x), Expression::Pow(b, y)) => {
+                Arc::ptr_eq(a, b) && Arc::ptr_eq(x, y)
+            }
+            (
+                Expression::Function {
+                    name: a, args: x, ..
+                },
+                Expression::Function {
+                    name: b, args: y, ..
+                },
+            ) => a == b && Arc::ptr_eq(x, y),
+            (Expression::Complex(a), Expression::Complex(b)) => Arc::ptr_eq(a, b),
+            (Expression::Matrix(a), Expression::Matrix(b)) => Arc::ptr_eq(a, b),
+            (Expression::Relation(a), Expression::Relation(b)) => Arc::ptr_eq(a, b),
+            (Expression::Piecewise(a), Expression::Piecewise(b)) => Arc::ptr_eq(a, b),
+            (Expression::Interval(a), Expression::Interval(b)) => Arc::ptr_eq(a, b),
+            (Expression::Calculus(a), Expression::Calculus(b)) => Arc::ptr_eq(a, b),
+            (Expression::MethodCall(a), Expression::MethodCall(b)) => Arc::ptr_eq(a, b),
+            (Expression::Number(_), Expression::Number(_))
+            | (Expression::Symbol(_), Expression::Symbol(_))
+            | (Expression::Constant(_), Expression::Constant(_)) => self == other,
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr;
+
+    #[test]
+    fn test_equal_subtrees_share_storage() {
+        // x² + 3x², built without combining the terms
+        let square = || expr!(x ^ 2);
+        let build = || {
+            Expression::Add(Arc::new(vec![
+                square(),
+                Expression::Mul(Arc::new(vec![expr!(3), square()])),
+            ]))
+        };
+
+        let mut interner = ExpressionInterner::new();
+        let sum = interner.intern(&build());
+        let Expression::Add(terms) = &sum else {
+            panic!("expected a sum, got {:?}", sum);
+        };
+        let Expression::Mul(factors) = &terms[1] else {
+            panic!("expected a product, got {:?}", terms[1]);
+        };
+        assert!(terms[0].ptr_eq(&factors[1]));
+
+        let again = interner.intern(&build());
+        assert!(again.ptr_eq(&sum));
+        assert_eq!(again, build());
+        assert!(!build().ptr_eq(&build()));
+    }
+
+    #[test]
+    fn test_stores_each_subtree_once() {
+        let mut interner = ExpressionInterner::new();
+        interner.intern(&expr!((x ^ 2) + (x ^ 2)));
+        // x, 2, x^2 and the sum
+        assert_eq!(interner.len(), 4);
+        interner.intern(&expr!(x ^ 2));
+        assert_eq!(interner.len(), 4);
+
+        interner.clear();
+        assert!(interner.is_empty());
+    }
+
+    #[test]
+    fn test_distinguishes_different_expressions() {
+        let mut interner = ExpressionInterner::new();
+        let a = interner.intern(&expr!(x ^ 2));
+        let b = interner.intern(&expr!(x ^ 3));
+        let c = interner.intern(&Expression::function("sin", vec![expr!(x)]));
+        let d = interner.intern(&Expression::function("cos", vec![expr!(x)]));
+        assert!(!a.ptr_eq(&b));
+        assert!(!c.ptr_eq(&d));
+        assert_ne!(c, d);
+    }
+
+    #[test]
+    fn test_nan_is_shared() {
+        let mut interner = ExpressionInterner::new();
+        let nan = Expression::float(f64::NAN);
+        interner.intern(&nan);
+        interner.intern(&nan);
+        assert_eq!(interner.len(), 1);
+
+        let sum = Expression::add(vec![expr!(x), nan]);
+        let a = interner.intern(&sum);
+        let b = interner.intern(&sum);
+        assert!(a.ptr_eq(&b));
+    }
+
+    #[test]
+    fn test_thread_local_interner() {
+        clear();
+        let a = expr!(x + y).intern();
+        let b = expr!(x + y).intern();
+        assert!(a.ptr_eq(&b));
+        assert!(interned_count() >= 3);
+        clear();
+        assert_eq!(interned_count(), 0);
+    }
+}
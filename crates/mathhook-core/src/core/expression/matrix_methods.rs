@@ -1,12 +1,39 @@
 //! Matrix-specific methods for Expression type
 //!
-//! This module provides matrix operations including transpose and inverse
-//! that respect noncommutativity and implement proper order reversal rules.
+//! This module provides matrix operations including transpose, adjoint,
+//! trace and inverse that respect noncommutativity and implement proper order
+//! reversal rules.
 
+use std::cmp::Ordering;
 use std::sync::Arc;
 
 use super::Expression;
+use crate::core::commutativity::Commutativity;
 use crate::core::symbol::SymbolType;
+use crate::simplify::arithmetic::expression_order;
+
+/// Rotation of a cyclic product that starts at its least factor
+///
+/// `trace(ABC) = trace(BCA) = trace(CAB)`, so rotating every product under a
+/// trace to the same starting point makes equal traces compare equal.
+pub(crate) fn canonical_rotation(factors: &[Expression]) -> Vec<Expression> {
+    let rotated = |start: usize| factors[start..].iter().chain(&factors[..start]);
+    let start = (1..factors.len()).fold(0, |best, start| {
+        let order = rotated(start)
+            .zip(rotated(best))
+            .map(|(a, b)| expression_order(a, b))
+            .find(|order| *order != Ordering::Equal);
+        match order {
+            Some(Ordering::Less) => start,
+            _ => best,
+        }
+    });
+    rotated(start).cloned().collect()
+}
+
+fn is_matrix(expr: &Expression) -> bool {
+    expr.commutativity() == Commutativity::Noncommutative
+}
 
 impl Expression {
     /// Compute transpose of matrix expression
@@ -76,6 +103,120 @@ impl Expression {
         }
     }
 
+    /// Compute the adjoint (conjugate transpose) of a matrix expression
+    ///
+    /// # Mathematical Rules
+    ///
+    /// - For products: (AB)† = B† A† (order reverses)
+    /// - For sums: (A+B)† = A† + B† (distributes)
+    /// - For scalars: c† = conj(c)
+    /// - For matrix symbols: A† creates adjoint function
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::{Expression, symbol};
+    ///
+    /// let a = Expression::symbol(symbol!(A; matrix));
+    /// let b = Expression::symbol(symbol!(B; matrix));
+    ///
+    /// let product = Expression::mul(vec![a.clone(), b.clone()]);
+    /// assert_eq!(
+    ///     product.adjoint(),
+    ///     Expression::mul(vec![b.adjoint(), a.adjoint()])
+    /// );
+    /// ```
+    pub fn adjoint(&self) -> Expression {
+        match self {
+            Expression::Mul(factors) if is_matrix(self) => {
+                let (scalars, matrices): (Vec<_>, Vec<_>) =
+                    factors.iter().partition(|f| !is_matrix(f));
+                let adjoint_factors: Vec<Expression> = scalars
+                    .into_iter()
+                    .map(|s| s.conjugate())
+                    .chain(matrices.into_iter().rev().map(|m| m.adjoint()))
+                    .collect();
+
+                Expression::mul(adjoint_factors)
+            }
+
+            Expression::Add(terms) if is_matrix(self) => {
+                Expression::add(terms.iter().map(|term| term.adjoint()).collect())
+            }
+
+            Expression::Matrix(matrix) => {
+                use crate::matrices::CoreMatrixOps;
+                let transposed = matrix.transpose();
+                let (rows, cols) = transposed.dimensions();
+                Expression::matrix(
+                    (0..rows)
+                        .map(|i| {
+                            (0..cols)
+                                .map(|j| transposed.get_element(i, j).conjugate())
+                                .collect()
+                        })
+                        .collect(),
+                )
+            }
+
+            _ if !is_matrix(self) => self.conjugate(),
+
+            _ => Expression::function("adjoint", vec![self.clone()]),
+        }
+    }
+
+    /// Compute the trace of a matrix expression
+    ///
+    /// # Mathematical Rules
+    ///
+    /// - Linearity: tr(A+B) = tr(A) + tr(B), tr(cA) = c tr(A)
+    /// - Cyclicity: tr(ABC) = tr(BCA) = tr(CAB); products are rotated to a
+    ///   canonical starting factor
+    /// - Transpose: tr(Aᵀ) = tr(A)
+    /// - Concrete matrices: the sum of the diagonal
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::{Expression, symbol};
+    ///
+    /// let a = Expression::symbol(symbol!(A; matrix));
+    /// let b = Expression::symbol(symbol!(B; matrix));
+    /// let c = Expression::symbol(symbol!(C; matrix));
+    ///
+    /// let abc = Expression::mul(vec![a.clone(), b.clone(), c.clone()]);
+    /// let cab = Expression::mul(vec![c, a, b]);
+    /// assert_eq!(abc.trace(), cab.trace());
+    /// ```
+    pub fn trace(&self) -> Expression {
+        match self {
+            Expression::Function { name, args, .. }
+                if name.as_ref() == "transpose" && args.len() == 1 =>
+            {
+                args[0].trace()
+            }
+
+            Expression::Add(terms) => {
+                Expression::add(terms.iter().map(|term| term.trace()).collect())
+            }
+
+            Expression::Mul(factors) if is_matrix(self) => {
+                let (mut scalars, matrices): (Vec<_>, Vec<_>) =
+                    factors.iter().cloned().partition(|f| !is_matrix(f));
+                let product = match matrices.len() {
+                    1 => matrices[0].clone(),
+                    _ => Expression::Mul(Arc::new(canonical_rotation(&matrices))),
+                };
+                scalars.push(Expression::function("trace", vec![product]));
+                Expression::mul(scalars)
+            }
+
+            Expression::Matrix(matrix) => matrix.trace(),
+
+            _ => Expression::function("trace", vec![self.clone()]),
+        }
+    }
+
     /// Compute inverse of matrix expression
     ///
     /// For symbolic matrix expressions, this implements proper order reversal
@@ -149,7 +290,7 @@ mod tests {
         let transposed = expr.transpose();
 
         match transposed {
-            Expression::Function { name, args } => {
+            Expression::Function { name, args, .. } => {
                 assert_eq!(name.as_ref(), "transpose");
                 assert_eq!(args.len(), 1);
                 assert_eq!(args[0], Expression::symbol(a));
@@ -269,7 +410,7 @@ mod tests {
         let inverse = expr.inverse();
 
         match inverse {
-            Expression::Function { name, args } => {
+            Expression::Function { name, args, .. } => {
                 assert_eq!(name.as_ref(), "inverse");
                 assert_eq!(args.len(), 1);
                 assert_eq!(args[0], Expression::symbol(a));
@@ -407,7 +548,7 @@ mod tests {
         let transposed_twice = transposed_once.clone().transpose();
 
         match transposed_twice {
-            Expression::Function { name, args } => {
+            Expression::Function { name, args, .. } => {
                 assert_eq!(name.as_ref(), "transpose");
                 assert_eq!(args.len(), 1);
                 assert_eq!(args[0], transposed_once);
@@ -416,6 +557,73 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_adjoint_product_reverses_order() {
+        let a = Expression::symbol(symbol!(A; matrix));
+        let b = Expression::symbol(symbol!(B; matrix));
+        let adjoint = |e: Expression| Expression::function("adjoint", vec![e]);
+
+        let product = Expression::mul(vec![a.clone(), b.clone()]);
+        assert_eq!(
+            product.adjoint(),
+            Expression::mul(vec![adjoint(b), adjoint(a)])
+        );
+    }
+
+    #[test]
+    fn test_adjoint_concrete_matrix_conjugates() {
+        let i = Expression::complex(Expression::integer(0), Expression::integer(1));
+        let matrix = Expression::matrix(vec![
+            vec![Expression::integer(1), i.clone()],
+            vec![Expression::integer(2), Expression::integer(3)],
+        ]);
+
+        let expected = Expression::matrix(vec![
+            vec![Expression::integer(1), Expression::integer(2)],
+            vec![i.conjugate(), Expression::integer(3)],
+        ]);
+
+        assert_eq!(matrix.adjoint(), expected);
+    }
+
+    #[test]
+    fn test_trace_is_scalar() {
+        use crate::core::commutativity::Commutativity;
+
+        let a = Expression::symbol(symbol!(A; matrix));
+        assert_eq!(a.trace().commutativity(), Commutativity::Commutative);
+    }
+
+    #[test]
+    fn test_trace_cyclic_and_linear() {
+        let a = Expression::symbol(symbol!(A; matrix));
+        let b = Expression::symbol(symbol!(B; matrix));
+        let c = Expression::symbol(symbol!(C; matrix));
+
+        let abc = Expression::mul(vec![a.clone(), b.clone(), c.clone()]);
+        let bca = Expression::mul(vec![b.clone(), c.clone(), a.clone()]);
+        assert_eq!(abc.trace(), bca.trace());
+
+        let scaled = Expression::mul(vec![Expression::integer(2), a.clone()]);
+        assert_eq!(
+            scaled.trace(),
+            Expression::mul(vec![Expression::integer(2), a.trace()])
+        );
+
+        let sum = Expression::add(vec![a.clone(), b.clone()]);
+        assert_eq!(sum.trace(), Expression::add(vec![a.trace(), b.trace()]));
+        assert_eq!(a.transpose().trace(), a.trace());
+    }
+
+    #[test]
+    fn test_trace_concrete_matrix() {
+        let matrix = Expression::matrix(vec![
+            vec![Expression::integer(1), Expression::integer(2)],
+            vec![Expression::integer(3), Expression::integer(4)],
+        ]);
+        assert_eq!(matrix.trace(), Expression::integer(5));
+    }
+
     #[test]
     fn test_symbolic_matrix_operations_combined() {
         let a = symbol!(A; matrix);
@@ -63,6 +63,11 @@ impl Expression {
 
             Expression::Pow(base, _exp) => base.commutativity(),
 
+            // Traces and determinants of matrices are scalars
+            Expression::Function { name, .. } if matches!(name.as_ref(), "trace" | "det") => {
+                Commutativity::Commutative
+            }
+
             Expression::Function { args, .. } => {
                 Commutativity::combine(args.iter().map(|a| a.commutativity()))
             }
@@ -425,7 +430,7 @@ impl Expression {
     #[inline]
     pub fn as_function(&self) -> Option<(&str, &[Expression])> {
         match self {
-            Expression::Function { name, args } => Some((name.as_ref(), args.as_slice())),
+            Expression::Function { name, args, .. } => Some((name.as_ref(), args.as_slice())),
             _ => None,
         }
     }
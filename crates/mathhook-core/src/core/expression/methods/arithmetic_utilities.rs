@@ -6,11 +6,12 @@ use super::super::Expression;
 use crate::core::polynomial::IntPoly;
 use crate::core::Number;
 use crate::expr;
+use num_bigint::BigInt;
 use std::collections::HashSet;
 
 /// Helper function for computing GCD of integers
-fn gcd_integers(a: i64, b: i64) -> i64 {
-    let (mut a, mut b) = (a.abs(), b.abs());
+fn gcd_integers(a: i64, b: i64) -> u64 {
+    let (mut a, mut b) = (a.unsigned_abs(), b.unsigned_abs());
     while b != 0 {
         let temp = b;
         b = a % b;
@@ -65,7 +66,7 @@ impl Expression {
         match (self, other) {
             (Expression::Number(num1), Expression::Number(num2)) => match (num1, num2) {
                 (Number::Integer(a), Number::Integer(b)) => {
-                    Expression::integer(gcd_integers(*a, *b))
+                    Expression::big_integer(BigInt::from(gcd_integers(*a, *b)))
                 }
                 _ => expr!(1),
             },
@@ -128,7 +129,9 @@ impl Expression {
                         expr!(0)
                     } else {
                         let gcd_val = gcd_integers(*a, *b);
-                        Expression::integer((*a * *b).abs() / gcd_val)
+                        Expression::big_integer(
+                            BigInt::from(a.unsigned_abs() / gcd_val) * b.unsigned_abs(),
+                        )
                     }
                 }
                 _ => self.clone(),
@@ -65,7 +65,7 @@ impl Expression {
     /// The solver result containing solutions from the quadratic formula
     #[inline]
     pub fn solve_quadratic(&self, variable: &Symbol) -> SolverResult {
-        QuadraticSolver.solve(self, variable)
+        QuadraticSolver::new().solve(self, variable)
     }
 
     /// Fast path: solve as quadratic equation with steps (skip classification)
@@ -74,7 +74,7 @@ impl Expression {
         &self,
         variable: &Symbol,
     ) -> (SolverResult, StepByStepExplanation) {
-        QuadraticSolver.solve_with_explanation(self, variable)
+        QuadraticSolver::new().solve_with_explanation(self, variable)
     }
 
     /// Fast path: solve as polynomial equation (skip classification)
@@ -257,22 +257,16 @@ impl Expression {
     /// Returns true for expressions like sin(x), cos(x), etc.
     /// Now integrated with Universal Function Intelligence System
     pub fn is_function(&self) -> bool {
-        match self {
-            Expression::Function { name, .. } => {
-                use crate::functions::intelligence::UNIVERSAL_REGISTRY;
-                UNIVERSAL_REGISTRY.has_intelligence(name)
-            }
-            _ => false,
-        }
+        self.get_function_intelligence().is_some()
     }
 
     /// Get function intelligence properties if available
     ///
     /// Seamless integration between core expressions and function intelligence
     pub fn get_function_intelligence(&self) -> Option<&crate::functions::FunctionProperties> {
-        if let Expression::Function { name, .. } = self {
+        if let Expression::Function { name, id, .. } = self {
             use crate::functions::intelligence::UNIVERSAL_REGISTRY;
-            UNIVERSAL_REGISTRY.get_properties(name)
+            UNIVERSAL_REGISTRY.get_properties_by_id(*id, name)
         } else {
             None
         }
@@ -282,7 +276,7 @@ impl Expression {
     ///
     /// Perfect integration with the educational system
     pub fn explain_function(&self) -> Vec<crate::educational::step_by_step::Step> {
-        if let Expression::Function { name, args } = self {
+        if let Expression::Function { name, args, .. } = self {
             use crate::functions::intelligence::UNIVERSAL_REGISTRY;
             UNIVERSAL_REGISTRY.explain_function(name, args)
         } else {
@@ -0,0 +1,236 @@
+//! Structural sharing and copy-on-write access to operands
+//!
+//! Children of compound expressions live behind `Arc`s, so cloning an
+//! expression of any size costs a reference-count increment. The helpers
+//! here keep it that way when expressions are rewritten or edited:
+//!
+//! - [`Expression::map_children`] rebuilds a node only if a child changed,
+//!   so untouched subtrees keep their storage
+//! - [`Expression::operands_mut`] copies the operand list only when another
+//!   expression still shares it
+//! - [`Expression::into_operands`] moves the operand list out when this
+//!   expression holds the only reference
+//!
+//! Sums, products, sets and function arguments keep `Arc<Vec<Expression>>`
+//! rather than `Arc<[Expression]>`: a slice pointer is twice as wide, which
+//! would push `Function` past the 32-byte size of `Expression`, and a
+//! uniquely owned `Vec` can grow in place.
+
+use super::{ComplexData, Expression, RelationData};
+use std::sync::Arc;
+
+impl Expression {
+    /// Operands of a sum, product or set, or the arguments of a function
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::{expr, Expression};
+    ///
+    /// let sum = expr!(x + y);
+    /// assert_eq!(sum.operands().map(<[Expression]>::len), Some(2));
+    /// assert_eq!(expr!(x ^ 2).operands(), None);
+    /// ```
+    pub fn operands(&self) -> Option<&[Expression]> {
+        match self {
+            Expression::Add(items)
+            | Expression::Mul(items)
+            | Expression::Set(items)
+            | Expression::Function { args: items, .. } => Some(items),
+            _ => None,
+        }
+    }
+
+    /// Mutable operands, copied first if another expression shares them
+    ///
+    /// The list is edited in place, without canonicalizing the result.
+    ///
+    /// @no-binding - returns a mutable borrow
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::{expr, Expression};
+    ///
+    /// let original = expr!(x + y);
+    /// let mut edited = original.clone();
+    /// edited.operands_mut().unwrap().push(Expression::integer(1));
+    ///
+    /// assert_eq!(original.operands().unwrap().len(), 2);
+    /// assert_eq!(edited.operands().unwrap().len(), 3);
+    /// ```
+    pub fn operands_mut(&mut self) -> Option<&mut Vec<Expression>> {
+        match self {
+            Expression::Add(items)
+            | Expression::Mul(items)
+            | Expression::Set(items)
+            | Expression::Function { args: items, .. } => Some(Arc::make_mut(items)),
+            _ => None,
+        }
+    }
+
+    /// The operand list, moved out when this expression is its only owner
+    pub fn into_operands(self) -> Option<Vec<Expression>> {
+        match self {
+            Expression::Add(items)
+            | Expression::Mul(items)
+            | Expression::Set(items)
+            | Expression::Function { args: items, .. } => Some(Arc::unwrap_or_clone(items)),
+            _ => None,
+        }
+    }
+
+    /// Apply `f` to each direct child, reusing this node when no child changes
+    ///
+    /// Covers sums, products, sets, powers, function arguments, complex
+    /// numbers and relations; other expressions are returned as they are.
+    /// The rebuilt node is not canonicalized.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::{expr, Expression};
+    ///
+    /// let sum = expr!(x + y);
+    /// let unchanged = sum.map_children(|child| child.clone());
+    /// assert!(unchanged.ptr_eq(&sum));
+    ///
+    /// let y = expr!(y);
+    /// let replaced = sum.map_children(|child| if *child == y { expr!(z) } else { child.clone() });
+    /// assert_eq!(replaced, expr!(x + z));
+    /// ```
+    pub fn map_children<F>(&self, mut f: F) -> Expression
+    where
+        F: FnMut(&Expression) -> Expression,
+    {
+        match self {
+            Expression::Add(items) => {
+                map_all(items, f).map_or_else(|| self.clone(), Expression::Add)
+            }
+            Expression::Mul(items) => {
+                map_all(items, f).map_or_else(|| self.clone(), Expression::Mul)
+            }
+            Expression::Set(items) => {
+                map_all(items, f).map_or_else(|| self.clone(), Expression::Set)
+            }
+            Expression::Function { name, args, id } => match map_all(args, f) {
+                Some(args) => Expression::Function {
+                    name: name.clone(),
+                    args,
+                    id: *id,
+                },
+                None => self.clone(),
+            },
+            Expression::Pow(base, exp) => {
+                let (new_base, new_exp) = (f(base), f(exp));
+                match (new_base.ptr_eq(base), new_exp.ptr_eq(exp)) {
+                    (true, true) => self.clone(),
+                    (same_base, same_exp) => Expression::Pow(
+                        reuse(base, new_base, same_base),
+                        reuse(exp, new_exp, same_exp),
+                    ),
+                }
+            }
+            Expression::Complex(data) => {
+                let (real, imag) = (f(&data.real), f(&data.imag));
+                if real.ptr_eq(&data.real) && imag.ptr_eq(&data.imag) {
+                    self.clone()
+                } else {
+                    Expression::Complex(Arc::new(ComplexData { real, imag }))
+                }
+            }
+            Expression::Relation(data) => {
+                let (left, right) = (f(&data.left), f(&data.right));
+                if left.ptr_eq(&data.left) && right.ptr_eq(&data.right) {
+                    self.clone()
+                } else {
+                    Expression::Relation(Arc::new(RelationData {
+                        left,
+                        right,
+                        relation_type: data.relation_type,
+                    }))
+                }
+            }
+            _ => self.clone(),
+        }
+    }
+}
+
+/// `old` itself when `new` is the same expression, so the allocation is shared
+fn reuse(old: &Arc<Expression>, new: Expression, same: bool) -> Arc<Expression> {
+    if same {
+        old.clone()
+    } else {
+        Arc::new(new)
+    }
+}
+
+/// New operand list if `f` changes any operand
+fn map_all<F>(items: &Arc<Vec<Expression>>, mut f: F) -> Option<Arc<Vec<Expression>>>
+where
+    F: FnMut(&Expression) -> Expression,
+{
+    let mapped: Vec<Expression> = items.iter().map(&mut f).collect();
+    let changed = mapped
+        .iter()
+        .zip(items.iter())
+        .any(|(new, old)| !new.ptr_eq(old));
+    changed.then(|| Arc::new(mapped))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr;
+
+    #[test]
+    fn test_operands_mut_copies_only_shared_lists() {
+        let mut sum = expr!(x + y);
+        let Expression::Add(before) = &sum else {
+            panic!("expected a sum");
+        };
+        let before = Arc::as_ptr(before);
+
+        // Sole owner: edited in place
+        sum.operands_mut().unwrap()[0] = expr!(z);
+        let Expression::Add(after) = &sum else {
+            panic!("expected a sum");
+        };
+        assert_eq!(Arc::as_ptr(after), before);
+
+        // Shared: the other owner keeps its operands
+        let shared = sum.clone();
+        sum.operands_mut().unwrap().pop();
+        assert_eq!(shared.operands().unwrap().len(), 2);
+        assert_eq!(sum.operands().unwrap().len(), 1);
+        assert_eq!(expr!(x ^ 2).operands_mut(), None);
+    }
+
+    #[test]
+    fn test_into_operands() {
+        let product = expr!(2 * x);
+        let shared = product.clone();
+        assert_eq!(product.into_operands().unwrap().len(), 2);
+        assert_eq!(shared.into_operands().unwrap().len(), 2);
+        assert_eq!(expr!(x).into_operands(), None);
+    }
+
+    #[test]
+    fn test_map_children_shares_unchanged_subtrees() {
+        let x = expr!(x);
+        let power = expr!((x + 1) ^ (y + 2));
+        let Expression::Pow(base, exp) = &power else {
+            panic!("expected a power");
+        };
+
+        let rewritten = power.map_children(|child| {
+            child.map_children(|leaf| if *leaf == x { expr!(t) } else { leaf.clone() })
+        });
+        let Expression::Pow(new_base, new_exp) = &rewritten else {
+            panic!("expected a power");
+        };
+        assert!(!Arc::ptr_eq(base, new_base));
+        assert!(Arc::ptr_eq(exp, new_exp));
+        assert_eq!(rewritten, expr!((t + 1) ^ (y + 2)));
+    }
+}
@@ -0,0 +1,303 @@
+//! Size statistics and truncated display for large expressions
+//!
+//! Intermediate results of expansion or integration can have hundreds of
+//! thousands of nodes, and formatting them in full can stall a renderer or
+//! flood a log. [`Expression::stats`] measures an expression without
+//! recursion, and [`Expression::summary`] renders at most a given number of
+//! nodes, replacing the rest with markers such as `... (+ 134 terms)`.
+
+use super::smart_display::SmartDisplayFormatter;
+use super::tree::children;
+use super::Expression;
+use crate::core::Symbol;
+use std::collections::HashSet;
+use std::fmt;
+
+/// Nesting below which `summary` stops descending regardless of budget
+const MAX_RENDER_DEPTH: usize = 64;
+
+/// Size and shape of an expression tree
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExpressionStats {
+    /// Number of nodes, counting shared subtrees once per occurrence
+    pub nodes: usize,
+    /// Length of the longest root-to-leaf path; a leaf has depth 1
+    pub depth: usize,
+    /// Numbers, symbols and constants
+    pub leaves: usize,
+    /// Distinct symbols
+    pub symbols: usize,
+}
+
+/// Truncated rendering of an expression with its statistics
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExpressionSummary {
+    pub text: String,
+    pub stats: ExpressionStats,
+    /// Whether `text` omits part of the expression
+    pub truncated: bool,
+}
+
+impl fmt::Display for ExpressionSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.text)
+    }
+}
+
+impl Expression {
+    /// Node count, depth, leaf count and number of distinct symbols
+    ///
+    /// Uses an explicit stack, so arbitrarily deep expressions are safe.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::expr;
+    ///
+    /// let stats = expr!((x ^ 2) + (2 * x) + 1).stats();
+    /// assert_eq!(stats.depth, 3);
+    /// assert_eq!(stats.symbols, 1);
+    /// ```
+    pub fn stats(&self) -> ExpressionStats {
+        let mut stats = ExpressionStats {
+            nodes: 0,
+            depth: 0,
+            leaves: 0,
+            symbols: 0,
+        };
+        let mut symbols: HashSet<&Symbol> = HashSet::new();
+        let mut stack = vec![(self, 1)];
+        while let Some((expr, depth)) = stack.pop() {
+            stats.nodes += 1;
+            stats.depth = stats.depth.max(depth);
+            let children = children(expr);
+            if children.is_empty() {
+                stats.leaves += 1;
+            }
+            if let Expression::Symbol(symbol) = expr {
+                symbols.insert(symbol);
+            }
+            stack.extend(children.into_iter().map(|child| (child, depth + 1)));
+        }
+        stats.symbols = symbols.len();
+        stats
+    }
+
+    /// Display form limited to about `max_nodes` nodes, with statistics
+    ///
+    /// Sums, products and argument lists are cut off once the budget runs
+    /// out, ending in a count of what was left out. Subtrees nested deeper
+    /// than 64 levels are shown as `...`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::{symbol, Expression};
+    ///
+    /// let x = symbol!(x);
+    /// let terms = (0..200)
+    ///     .map(|k| Expression::pow(Expression::symbol(x.clone()), Expression::integer(k)))
+    ///     .collect();
+    /// let sum = Expression::Add(std::sync::Arc::new(terms));
+    ///
+    /// let summary = sum.summary(20);
+    /// assert!(summary.truncated);
+    /// assert!(summary.text.starts_with("1 + x + x^2"));
+    /// assert!(summary.text.ends_with("... (+ 192 terms)"));
+    /// assert_eq!(summary.stats.nodes, 597);
+    ///
+    /// let small = Expression::symbol(x).summary(20);
+    /// assert_eq!(small.text, "x");
+    /// assert!(!small.truncated);
+    /// ```
+    pub fn summary(&self, max_nodes: usize) -> ExpressionSummary {
+        let mut writer = SummaryWriter {
+            text: String::new(),
+            budget: max_nodes.max(1),
+            truncated: false,
+        };
+        writer.write(self, 0);
+        ExpressionSummary {
+            text: writer.text,
+            stats: self.stats(),
+            truncated: writer.truncated,
+        }
+    }
+}
+
+struct SummaryWriter {
+    text: String,
+    budget: usize,
+    truncated: bool,
+}
+
+impl SummaryWriter {
+    fn write(&mut self, expr: &Expression, depth: usize) {
+        if self.budget == 0 || depth >= MAX_RENDER_DEPTH {
+            self.omit("...");
+            return;
+        }
+        self.budget -= 1;
+        match expr {
+            Expression::Add(terms) => self.write_list(terms, depth, " + ", "terms", |i, term| {
+                match SmartDisplayFormatter::extract_negated_expression(term) {
+                    Some(positive) => (if i == 0 { "-" } else { " - " }, positive),
+                    None => (if i == 0 { "" } else { " + " }, term),
+                }
+            }),
+            Expression::Mul(factors) => {
+                match SmartDisplayFormatter::extract_division_parts(factors) {
+                    Some((dividend, divisor)) => {
+                        self.write(dividend, depth + 1);
+                        self.text.push_str(" / ");
+                        self.write(divisor, depth + 1);
+                    }
+                    None => self.write_list(factors, depth, " * ", "factors", separated(" * ")),
+                }
+            }
+            Expression::Pow(base, exp) => {
+                self.write(base, depth + 1);
+                self.text.push('^');
+                self.write(exp, depth + 1);
+            }
+            Expression::Function { name, args, .. } => {
+                self.text.push_str(name);
+                self.text.push('(');
+                self.write_list(args, depth, ", ", "arguments", separated(", "));
+                self.text.push(')');
+            }
+            Expression::Set(elements) => {
+                self.text.push('{');
+                self.write_list(elements, depth, ", ", "elements", separated(", "));
+                self.text.push('}');
+            }
+            Expression::Relation(data) => {
+                self.write(&data.left, depth + 1);
+                self.text.push(' ');
+                self.text.push_str(data.relation_type.symbol());
+                self.text.push(' ');
+                self.write(&data.right, depth + 1);
+            }
+            other if children(other).is_empty() => self.text.push_str(&other.to_string()),
+            other => {
+                let nodes = other.stats().nodes - 1;
+                if nodes <= self.budget {
+                    self.budget -= nodes;
+                    self.text.push_str(&other.to_string());
+                } else {
+                    self.omit("...");
+                }
+            }
+        }
+    }
+
+    /// Write items while the budget lasts, then a count of the rest
+    ///
+    /// `lead` gives the text before each item and the expression to write,
+    /// which lets sums show `a - b` for `a + (-1)·b`.
+    fn write_list<'a>(
+        &mut self,
+        items: &'a [Expression],
+        depth: usize,
+        separator: &str,
+        noun: &str,
+        lead: impl Fn(usize, &'a Expression) -> (&'static str, &'a Expression),
+    ) {
+        for (i, item) in items.iter().enumerate() {
+            if self.budget == 0 {
+                if i > 0 {
+                    self.text.push_str(separator);
+                }
+                self.omit(&format!("... (+ {} {})", items.len() - i, noun));
+                return;
+            }
+            let (prefix, item) = lead(i, item);
+            self.text.push_str(prefix);
+            self.write(item, depth + 1);
+        }
+    }
+
+    fn omit(&mut self, marker: &str) {
+        self.text.push_str(marker);
+        self.truncated = true;
+    }
+}
+
+/// `lead` for `write_list` that puts `separator` between items
+fn separated(
+    separator: &'static str,
+) -> impl Fn(usize, &Expression) -> (&'static str, &Expression) {
+    move |i, item| (if i == 0 { "" } else { separator }, item)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{expr, symbol};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_stats() {
+        let stats = expr!(sin(x) + (y ^ 2)).stats();
+        assert_eq!(
+            stats,
+            ExpressionStats {
+                nodes: 6,
+                depth: 3,
+                leaves: 3,
+                symbols: 2,
+            }
+        );
+
+        let leaf = expr!(7).stats();
+        assert_eq!(
+            (leaf.nodes, leaf.depth, leaf.leaves, leaf.symbols),
+            (1, 1, 1, 0)
+        );
+    }
+
+    #[test]
+    fn test_stats_of_deep_expression() {
+        let x = symbol!(x);
+        let mut nested = Expression::symbol(x);
+        for _ in 0..5_000 {
+            nested = Expression::Function {
+                name: "f".into(),
+                args: Arc::new(vec![nested]),
+                id: Default::default(),
+            };
+        }
+        let stats = nested.stats();
+        assert_eq!(stats.depth, 5_001);
+        assert_eq!(stats.leaves, 1);
+
+        let summary = nested.summary(1_000);
+        assert!(summary.truncated);
+        assert!(summary.text.contains("f(...)"));
+    }
+
+    #[test]
+    fn test_summary_matches_display_when_small() {
+        let small = expr!((x ^ 2) + (3 * y));
+        let summary = small.summary(100);
+        assert_eq!(summary.text, small.to_string());
+        assert!(!summary.truncated);
+
+        let equation = Expression::equation(expr!(x + 1), expr!(2));
+        assert_eq!(equation.summary(100).text, equation.to_string());
+    }
+
+    #[test]
+    fn test_summary_truncates_lists() {
+        let factors = (0..50).map(|k| expr!(a) * Expression::integer(k)).collect();
+        let product = Expression::Mul(Arc::new(factors));
+        let summary = product.summary(10);
+        assert!(summary.truncated);
+        assert!(summary.text.ends_with("factors)"), "{}", summary.text);
+
+        let args = (0..30).map(Expression::integer).collect();
+        let call = Expression::function("g", args);
+        let summary = call.summary(5);
+        assert_eq!(summary.text, "g(0, 1, 2, 3, ... (+ 26 arguments))");
+    }
+}
@@ -0,0 +1,230 @@
+//! Structural inspection of expression trees
+//!
+//! Renderers in other languages walk an expression node by node rather than
+//! parsing its display string. [`Expression::kind`] names the node,
+//! [`Expression::args`] lists its children in display order, and
+//! [`Expression::symbol_name`] and [`Expression::function_name`] give the
+//! names leaves and calls carry. Together with the serde representation this
+//! is everything a binding needs to draw or rebuild a tree.
+
+use super::{CalculusData, Expression};
+use crate::matrices::Matrix;
+
+impl Expression {
+    /// Name of the node type
+    ///
+    /// One of `number`, `symbol`, `constant`, `add`, `mul`, `pow`,
+    /// `function`, `set`, `complex`, `matrix`, `relation`, `piecewise`,
+    /// `interval`, `derivative`, `integral`, `limit`, `sum`, `product` and
+    /// `method_call`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::expr;
+    ///
+    /// assert_eq!(expr!(x + 1).kind(), "add");
+    /// assert_eq!(expr!(sin(x)).kind(), "function");
+    /// assert_eq!(expr!(x).kind(), "symbol");
+    /// ```
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Expression::Number(_) => "number",
+            Expression::Symbol(_) => "symbol",
+            Expression::Constant(_) => "constant",
+            Expression::Add(_) => "add",
+            Expression::Mul(_) => "mul",
+            Expression::Pow(_, _) => "pow",
+            Expression::Function { .. } => "function",
+            Expression::Set(_) => "set",
+            Expression::Complex(_) => "complex",
+            Expression::Matrix(_) => "matrix",
+            Expression::Relation(_) => "relation",
+            Expression::Piecewise(_) => "piecewise",
+            Expression::Interval(_) => "interval",
+            Expression::Calculus(data) => match data.as_ref() {
+                CalculusData::Derivative { .. } => "derivative",
+                CalculusData::Integral { .. } => "integral",
+                CalculusData::Limit { .. } => "limit",
+                CalculusData::Sum { .. } => "sum",
+                CalculusData::Product { .. } => "product",
+            },
+            Expression::MethodCall(_) => "method_call",
+        }
+    }
+
+    /// Direct children, empty for numbers, symbols and constants
+    ///
+    /// A power gives base and exponent, a relation its two sides, a complex
+    /// number its real and imaginary parts, and a matrix all its entries in
+    /// row-major order. A piecewise expression alternates each value with
+    /// its condition and ends with the default value, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::{expr, Expression};
+    ///
+    /// let power = expr!(x ^ 3);
+    /// assert_eq!(power.args(), vec![expr!(x), Expression::integer(3)]);
+    /// assert!(expr!(x).args().is_empty());
+    /// ```
+    pub fn args(&self) -> Vec<Expression> {
+        match self {
+            Expression::Matrix(matrix) => {
+                let (rows, cols) = matrix.dimensions();
+                (0..rows)
+                    .flat_map(|i| (0..cols).map(move |j| (i, j)))
+                    .map(|(i, j)| matrix.get_element(i, j))
+                    .collect()
+            }
+            other => children(other).into_iter().cloned().collect(),
+        }
+    }
+
+    /// Name of a symbol, `None` for any other node
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::expr;
+    ///
+    /// assert_eq!(expr!(theta).symbol_name(), Some("theta".to_owned()));
+    /// assert_eq!(expr!(2).symbol_name(), None);
+    /// ```
+    pub fn symbol_name(&self) -> Option<String> {
+        match self {
+            Expression::Symbol(symbol) => Some(symbol.name().to_owned()),
+            _ => None,
+        }
+    }
+
+    /// Name of a function call, `None` for any other node
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::expr;
+    ///
+    /// assert_eq!(expr!(sin(x)).function_name(), Some("sin".to_owned()));
+    /// assert_eq!(expr!(x).function_name(), None);
+    /// ```
+    pub fn function_name(&self) -> Option<String> {
+        match self {
+            Expression::Function { name, .. } => Some(name.to_string()),
+            _ => None,
+        }
+    }
+}
+
+/// Direct children of any expression, including matrix entries and the
+/// operands of unevaluated calculus
+pub(super) fn children(expr: &Expression) -> Vec<&Expression> {
+    match expr {
+        Expression::Number(_) | Expression::Symbol(_) | Expression::Constant(_) => Vec::new(),
+        Expression::Add(items)
+        | Expression::Mul(items)
+        | Expression::Set(items)
+        | Expression::Function { args: items, .. } => items.iter().collect(),
+        Expression::Pow(base, exp) => vec![base, exp],
+        Expression::Complex(data) => vec![&data.real, &data.imag],
+        Expression::Relation(data) => vec![&data.left, &data.right],
+        Expression::Interval(data) => vec![&data.start, &data.end],
+        Expression::Piecewise(data) => data
+            .pieces
+            .iter()
+            .flat_map(|(value, condition)| [value, condition])
+            .chain(&data.default)
+            .collect(),
+        Expression::Matrix(matrix) => match matrix.as_ref() {
+            Matrix::Dense(data) => data.rows.iter().flatten().collect(),
+            Matrix::Diagonal(data) => data.diagonal_elements.iter().collect(),
+            Matrix::Scalar(data) => vec![&data.scalar_value],
+            Matrix::UpperTriangular(data) => data.elements.iter().collect(),
+            Matrix::LowerTriangular(data) => data.elements.iter().collect(),
+            Matrix::Symmetric(data) => data.elements.iter().collect(),
+            Matrix::Identity(_) | Matrix::Zero(_) | Matrix::Permutation(_) => Vec::new(),
+        },
+        Expression::Calculus(data) => match data.as_ref() {
+            CalculusData::Derivative { expression, .. } => vec![expression],
+            CalculusData::Integral {
+                integrand, bounds, ..
+            } => std::iter::once(integrand)
+                .chain(bounds.iter().flat_map(|(lower, upper)| [lower, upper]))
+                .collect(),
+            CalculusData::Limit {
+                expression, point, ..
+            } => vec![expression, point],
+            CalculusData::Sum {
+                expression,
+                start,
+                end,
+                ..
+            }
+            | CalculusData::Product {
+                expression,
+                start,
+                end,
+                ..
+            } => vec![expression, start, end],
+        },
+        Expression::MethodCall(data) => std::iter::once(&data.object).chain(&data.args).collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Symbol;
+    use crate::expr;
+
+    /// Rebuild an expression from `kind`, `args` and the names alone
+    fn rebuild(expr: &Expression) -> Expression {
+        let args: Vec<Expression> = expr.args().iter().map(rebuild).collect();
+        match expr.kind() {
+            "add" => Expression::add(args),
+            "mul" => Expression::mul(args),
+            "pow" => Expression::pow(args[0].clone(), args[1].clone()),
+            "function" => Expression::function(expr.function_name().unwrap(), args),
+            "symbol" => Expression::symbol(Symbol::scalar(expr.symbol_name().unwrap())),
+            _ => expr.clone(),
+        }
+    }
+
+    #[test]
+    fn test_walk_rebuilds_expression() {
+        let original = expr!((sin(x) ^ 2) + (3 * y) - (1 / z));
+        assert_eq!(rebuild(&original), original);
+    }
+
+    #[test]
+    fn test_matrix_args_are_row_major() {
+        let identity = Expression::Matrix(std::sync::Arc::new(Matrix::identity(2)));
+        assert_eq!(identity.kind(), "matrix");
+        assert_eq!(
+            identity.args(),
+            vec![
+                Expression::integer(1),
+                Expression::integer(0),
+                Expression::integer(0),
+                Expression::integer(1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let original = Expression::add(vec![
+            expr!(sin(x)),
+            Expression::rational(2, 3),
+            Expression::equation(expr!(y), expr!(2)),
+        ]);
+        let json = serde_json::to_value(&original).unwrap();
+        let restored: Expression = serde_json::from_value(json).unwrap();
+        assert_eq!(restored, original);
+        assert!(restored
+            .args()
+            .iter()
+            .any(|arg| arg.function_name() == Some("sin".to_owned())));
+    }
+}
@@ -4,10 +4,14 @@ mod arithmetic;
 mod conversion;
 mod decomposition;
 mod display;
+mod iterative;
 mod multiply;
 mod solve;
+mod stability;
 
 pub use decomposition::LUResult;
+pub use iterative::{IterativeOptions, IterativeSolution, Preconditioner};
+pub use stability::SolveReport;
 
 const EPSILON: f64 = 1e-10;
 
@@ -0,0 +1,547 @@
+use super::NumericMatrix;
+use crate::error::MathError;
+
+const DEFAULT_TOLERANCE: f64 = 1e-10;
+const DEFAULT_MAX_ITERATIONS: usize = 1000;
+const DEFAULT_RESTART: usize = 30;
+const BREAKDOWN_THRESHOLD: f64 = 1e-14;
+
+/// Preconditioner `M ≈ A` applied as `M⁻¹r` by the iterative solvers
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Preconditioner {
+    None,
+    /// `M = D`, the diagonal of `A`
+    Jacobi,
+    /// Symmetric successive over-relaxation with relaxation factor `omega`
+    /// in `(0, 2)`; symmetric for symmetric `A`, so it may be used with CG
+    Ssor {
+        omega: f64,
+    },
+}
+
+/// Stopping criteria and preconditioning for [`NumericMatrix::solve_cg`] and
+/// [`NumericMatrix::solve_gmres`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct IterativeOptions {
+    /// Target for the relative residual `‖b - Ax‖₂ / ‖b‖₂`
+    pub tolerance: f64,
+    pub max_iterations: usize,
+    pub preconditioner: Preconditioner,
+    /// Krylov subspace size before GMRES restarts; ignored by CG
+    pub restart: usize,
+}
+
+impl Default for IterativeOptions {
+    fn default() -> Self {
+        Self {
+            tolerance: DEFAULT_TOLERANCE,
+            max_iterations: DEFAULT_MAX_ITERATIONS,
+            preconditioner: Preconditioner::None,
+            restart: DEFAULT_RESTART,
+        }
+    }
+}
+
+impl IterativeOptions {
+    pub fn with_tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    pub fn with_max_iterations(mut self, max_iterations: usize) -> Self {
+        self.max_iterations = max_iterations;
+        self
+    }
+
+    pub fn with_preconditioner(mut self, preconditioner: Preconditioner) -> Self {
+        self.preconditioner = preconditioner;
+        self
+    }
+
+    pub fn with_restart(mut self, restart: usize) -> Self {
+        self.restart = restart;
+        self
+    }
+}
+
+/// Outcome of an iterative solve
+///
+/// Running out of iterations is not an error: the best iterate is returned
+/// with `converged` set to `false`, so callers decide whether it is usable.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IterativeSolution {
+    pub solution: Vec<f64>,
+    pub iterations: usize,
+    /// Final relative residual `‖b - Ax‖₂ / ‖b‖₂`
+    pub residual_norm: f64,
+    pub converged: bool,
+    /// Relative residual before the first iteration and after each one
+    pub residual_history: Vec<f64>,
+}
+
+impl NumericMatrix {
+    /// Solve `Ax = b` for symmetric positive definite `A` by preconditioned
+    /// conjugate gradients
+    ///
+    /// Each iteration costs one matrix-vector product, so this suits the
+    /// large sparse systems of finite-difference discretisations.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::core::matrix::numeric_matrix::{IterativeOptions, Preconditioner};
+    /// use mathhook_core::NumericMatrix;
+    ///
+    /// // 1D Poisson problem: tridiagonal [-1, 2, -1]
+    /// let n = 20;
+    /// let a = NumericMatrix::from_fn(n, n, |i, j| match i.abs_diff(j) {
+    ///     0 => 2.0,
+    ///     1 => -1.0,
+    ///     _ => 0.0,
+    /// })
+    /// .unwrap();
+    /// let b = vec![1.0; n];
+    ///
+    /// let options = IterativeOptions::default().with_preconditioner(Preconditioner::Jacobi);
+    /// let result = a.solve_cg(&b, &options).unwrap();
+    /// assert!(result.converged);
+    /// assert!(result.residual_norm < 1e-10);
+    /// ```
+    pub fn solve_cg(
+        &self,
+        b: &[f64],
+        options: &IterativeOptions,
+    ) -> Result<IterativeSolution, MathError> {
+        self.check_iterative("solve_cg", b, options.preconditioner)?;
+        if !self.is_symmetric() {
+            return Err(MathError::DomainError {
+                operation: "solve_cg".to_string(),
+                value: crate::Expression::integer(self.rows as i64),
+                reason: "Conjugate gradients requires a symmetric matrix".to_string(),
+            });
+        }
+
+        let b_norm = norm(b);
+        let mut x = vec![0.0; self.rows];
+        if b_norm == 0.0 {
+            return Ok(IterativeSolution::trivial(x));
+        }
+
+        let mut r = b.to_vec();
+        let mut z = self.precondition(options.preconditioner, &r);
+        let mut p = z.clone();
+        let mut rz = dot(&r, &z);
+        let mut history = vec![1.0];
+        let mut iterations = 0;
+
+        while iterations < options.max_iterations && history[iterations] > options.tolerance {
+            let ap = self.multiply_vector(&p)?;
+            let pap = dot(&p, &ap);
+            if pap <= 0.0 {
+                // Not positive definite along p; report what we have
+                break;
+            }
+
+            let alpha = rz / pap;
+            axpy(alpha, &p, &mut x);
+            axpy(-alpha, &ap, &mut r);
+            iterations += 1;
+            history.push(norm(&r) / b_norm);
+
+            z = self.precondition(options.preconditioner, &r);
+            let rz_next = dot(&r, &z);
+            let beta = rz_next / rz;
+            rz = rz_next;
+            for (pi, zi) in p.iter_mut().zip(&z) {
+                *pi = zi + beta * *pi;
+            }
+        }
+
+        self.finish(x, b, b_norm, iterations, history, options.tolerance)
+    }
+
+    /// Solve `Ax = b` for general nonsingular `A` by restarted GMRES with
+    /// right preconditioning
+    ///
+    /// Right preconditioning keeps the monitored residual equal to the true
+    /// residual of the original system.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::core::matrix::numeric_matrix::{IterativeOptions, Preconditioner};
+    /// use mathhook_core::NumericMatrix;
+    ///
+    /// // Upwinded convection-diffusion: not symmetric, so CG does not apply
+    /// let n = 20;
+    /// let a = NumericMatrix::from_fn(n, n, |i, j| match j as i64 - i as i64 {
+    ///     0 => 3.0,
+    ///     -1 => -2.0,
+    ///     1 => -1.0,
+    ///     _ => 0.0,
+    /// })
+    /// .unwrap();
+    /// let b = vec![1.0; n];
+    ///
+    /// let options = IterativeOptions::default()
+    ///     .with_preconditioner(Preconditioner::Ssor { omega: 1.2 })
+    ///     .with_restart(10);
+    /// let result = a.solve_gmres(&b, &options).unwrap();
+    /// assert!(result.converged);
+    /// ```
+    pub fn solve_gmres(
+        &self,
+        b: &[f64],
+        options: &IterativeOptions,
+    ) -> Result<IterativeSolution, MathError> {
+        self.check_iterative("solve_gmres", b, options.preconditioner)?;
+        if options.restart == 0 {
+            return Err(MathError::DomainError {
+                operation: "solve_gmres".to_string(),
+                value: crate::Expression::integer(0),
+                reason: "GMRES restart length must be positive".to_string(),
+            });
+        }
+
+        let b_norm = norm(b);
+        let mut x = vec![0.0; self.rows];
+        if b_norm == 0.0 {
+            return Ok(IterativeSolution::trivial(x));
+        }
+
+        let mut history = vec![1.0];
+        let mut iterations = 0;
+
+        while iterations < options.max_iterations {
+            let ax = self.multiply_vector(&x)?;
+            let r: Vec<f64> = b.iter().zip(&ax).map(|(bi, axi)| bi - axi).collect();
+            let beta = norm(&r);
+            if beta / b_norm <= options.tolerance {
+                break;
+            }
+
+            let m = options.restart;
+            let mut basis = vec![r.iter().map(|ri| ri / beta).collect::<Vec<f64>>()];
+            let mut h = vec![vec![0.0; m]; m + 1];
+            let mut cs = vec![0.0; m];
+            let mut sn = vec![0.0; m];
+            let mut g = vec![0.0; m + 1];
+            g[0] = beta;
+
+            let mut k = 0;
+            while k < m && iterations < options.max_iterations {
+                let z = self.precondition(options.preconditioner, &basis[k]);
+                let mut w = self.multiply_vector(&z)?;
+                for (i, v) in basis.iter().enumerate() {
+                    h[i][k] = dot(&w, v);
+                    axpy(-h[i][k], v, &mut w);
+                }
+                let w_norm = norm(&w);
+                h[k + 1][k] = w_norm;
+
+                for i in 0..k {
+                    let (a, b) = (h[i][k], h[i + 1][k]);
+                    h[i][k] = cs[i] * a + sn[i] * b;
+                    h[i + 1][k] = -sn[i] * a + cs[i] * b;
+                }
+                let (a, b) = (h[k][k], h[k + 1][k]);
+                let radius = a.hypot(b);
+                cs[k] = a / radius;
+                sn[k] = b / radius;
+                h[k][k] = radius;
+                h[k + 1][k] = 0.0;
+                g[k + 1] = -sn[k] * g[k];
+                g[k] *= cs[k];
+
+                k += 1;
+                iterations += 1;
+                history.push(g[k].abs() / b_norm);
+
+                if history[iterations] <= options.tolerance || w_norm < BREAKDOWN_THRESHOLD {
+                    break;
+                }
+                basis.push(w.iter().map(|wi| wi / w_norm).collect());
+            }
+
+            // Back substitution for the least-squares coefficients
+            let mut y = vec![0.0; k];
+            for i in (0..k).rev() {
+                let tail: f64 = (i + 1..k).map(|j| h[i][j] * y[j]).sum();
+                y[i] = (g[i] - tail) / h[i][i];
+            }
+            let mut update = vec![0.0; self.rows];
+            for (yi, v) in y.iter().zip(&basis) {
+                axpy(*yi, v, &mut update);
+            }
+            let correction = self.precondition(options.preconditioner, &update);
+            axpy(1.0, &correction, &mut x);
+
+            if history[iterations] <= options.tolerance {
+                break;
+            }
+        }
+
+        self.finish(x, b, b_norm, iterations, history, options.tolerance)
+    }
+
+    /// Validate the system and the preconditioner shared by both solvers
+    fn check_iterative(
+        &self,
+        operation: &str,
+        b: &[f64],
+        preconditioner: Preconditioner,
+    ) -> Result<(), MathError> {
+        if !self.is_square() {
+            return Err(MathError::DomainError {
+                operation: operation.to_string(),
+                value: crate::Expression::integer(self.rows as i64),
+                reason: "Solving linear system requires square matrix".to_string(),
+            });
+        }
+        if b.len() != self.rows {
+            return Err(MathError::DomainError {
+                operation: operation.to_string(),
+                value: crate::Expression::integer(b.len() as i64),
+                reason: format!(
+                    "Right-hand side length {} does not match matrix dimension {}",
+                    b.len(),
+                    self.rows
+                ),
+            });
+        }
+        if let Preconditioner::Ssor { omega } = preconditioner {
+            if !(omega > 0.0 && omega < 2.0) {
+                return Err(MathError::DomainError {
+                    operation: operation.to_string(),
+                    value: crate::Expression::float(omega),
+                    reason: "SSOR relaxation factor must lie in (0, 2)".to_string(),
+                });
+            }
+        }
+        if preconditioner != Preconditioner::None {
+            if let Some(i) = (0..self.rows).find(|&i| self.data[i * self.cols + i] == 0.0) {
+                return Err(MathError::DomainError {
+                    operation: operation.to_string(),
+                    value: crate::Expression::integer(i as i64),
+                    reason: format!("Preconditioner needs a nonzero diagonal, but A[{i}, {i}] = 0"),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Apply `M⁻¹` to `r`
+    fn precondition(&self, preconditioner: Preconditioner, r: &[f64]) -> Vec<f64> {
+        let n = self.rows;
+        let diagonal = |i: usize| self.data[i * n + i];
+        match preconditioner {
+            Preconditioner::None => r.to_vec(),
+            Preconditioner::Jacobi => (0..n).map(|i| r[i] / diagonal(i)).collect(),
+            Preconditioner::Ssor { omega } => {
+                // M = (D + ωL) D⁻¹ (D + ωU) / (ω(2 - ω))
+                let mut y = vec![0.0; n];
+                for i in 0..n {
+                    let lower: f64 = (0..i).map(|j| self.data[i * n + j] * y[j]).sum();
+                    y[i] = (r[i] - omega * lower) / diagonal(i);
+                }
+                let mut z = vec![0.0; n];
+                for i in (0..n).rev() {
+                    let upper: f64 = (i + 1..n).map(|j| self.data[i * n + j] * z[j]).sum();
+                    z[i] = (diagonal(i) * y[i] - omega * upper) / diagonal(i);
+                }
+                let scale = omega * (2.0 - omega);
+                z.iter().map(|zi| zi * scale).collect()
+            }
+        }
+    }
+
+    /// Package the iterate with its true residual
+    fn finish(
+        &self,
+        x: Vec<f64>,
+        b: &[f64],
+        b_norm: f64,
+        iterations: usize,
+        residual_history: Vec<f64>,
+        tolerance: f64,
+    ) -> Result<IterativeSolution, MathError> {
+        let ax = self.multiply_vector(&x)?;
+        let residual: Vec<f64> = b.iter().zip(&ax).map(|(bi, axi)| bi - axi).collect();
+        let residual_norm = norm(&residual) / b_norm;
+        Ok(IterativeSolution {
+            solution: x,
+            iterations,
+            residual_norm,
+            converged: residual_norm <= tolerance,
+            residual_history,
+        })
+    }
+}
+
+impl IterativeSolution {
+    /// Exact zero solution for a zero right-hand side
+    fn trivial(solution: Vec<f64>) -> Self {
+        Self {
+            solution,
+            iterations: 0,
+            residual_norm: 0.0,
+            converged: true,
+            residual_history: vec![0.0],
+        }
+    }
+}
+
+fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+fn norm(v: &[f64]) -> f64 {
+    dot(v, v).sqrt()
+}
+
+/// `y += alpha * x`
+fn axpy(alpha: f64, x: &[f64], y: &mut [f64]) {
+    for (yi, xi) in y.iter_mut().zip(x) {
+        *yi += alpha * xi;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn poisson(n: usize) -> NumericMatrix {
+        NumericMatrix::from_fn(n, n, |i, j| match i.abs_diff(j) {
+            0 => 2.0,
+            1 => -1.0,
+            _ => 0.0,
+        })
+        .unwrap()
+    }
+
+    fn convection_diffusion(n: usize) -> NumericMatrix {
+        NumericMatrix::from_fn(n, n, |i, j| match j as i64 - i as i64 {
+            0 => 4.0,
+            -1 => -3.0,
+            1 => -0.5,
+            _ => 0.0,
+        })
+        .unwrap()
+    }
+
+    fn assert_solves(a: &NumericMatrix, b: &[f64], result: &IterativeSolution) {
+        let direct = a.solve(b).unwrap();
+        for (x, expected) in result.solution.iter().zip(&direct) {
+            assert!((x - expected).abs() < 1e-6, "{} != {}", x, expected);
+        }
+    }
+
+    #[test]
+    fn test_cg_poisson() {
+        let a = poisson(50);
+        let b: Vec<f64> = (0..50).map(|i| (i as f64 * 0.1).sin()).collect();
+
+        for preconditioner in [
+            Preconditioner::None,
+            Preconditioner::Jacobi,
+            Preconditioner::Ssor { omega: 1.5 },
+        ] {
+            let options = IterativeOptions::default().with_preconditioner(preconditioner);
+            let result = a.solve_cg(&b, &options).unwrap();
+            assert!(result.converged, "{:?} did not converge", preconditioner);
+            assert_eq!(result.residual_history.len(), result.iterations + 1);
+            assert_solves(&a, &b, &result);
+        }
+    }
+
+    #[test]
+    fn test_ssor_reduces_cg_iterations() {
+        let a = poisson(100);
+        let b = vec![1.0; 100];
+
+        let plain = a.solve_cg(&b, &IterativeOptions::default()).unwrap();
+        let options =
+            IterativeOptions::default().with_preconditioner(Preconditioner::Ssor { omega: 1.8 });
+        let preconditioned = a.solve_cg(&b, &options).unwrap();
+
+        assert!(preconditioned.converged);
+        assert!(preconditioned.iterations < plain.iterations);
+    }
+
+    #[test]
+    fn test_cg_rejects_nonsymmetric() {
+        let a = convection_diffusion(5);
+        assert!(a.solve_cg(&[1.0; 5], &IterativeOptions::default()).is_err());
+    }
+
+    #[test]
+    fn test_gmres_nonsymmetric() {
+        let a = convection_diffusion(40);
+        let b: Vec<f64> = (0..40).map(|i| 1.0 + i as f64 / 40.0).collect();
+
+        for preconditioner in [
+            Preconditioner::None,
+            Preconditioner::Jacobi,
+            Preconditioner::Ssor { omega: 1.0 },
+        ] {
+            let options = IterativeOptions::default()
+                .with_preconditioner(preconditioner)
+                .with_restart(8);
+            let result = a.solve_gmres(&b, &options).unwrap();
+            assert!(result.converged, "{:?} did not converge", preconditioner);
+            assert_solves(&a, &b, &result);
+        }
+    }
+
+    #[test]
+    fn test_gmres_exact_in_n_steps() {
+        let a = NumericMatrix::from_flat(3, 3, vec![2.0, 1.0, 1.0, 4.0, 3.0, 3.0, 8.0, 7.0, 9.0])
+            .unwrap();
+        let b = [4.0, 10.0, 24.0];
+
+        let result = a.solve_gmres(&b, &IterativeOptions::default()).unwrap();
+        assert!(result.converged);
+        assert!(result.iterations <= 3);
+        assert_solves(&a, &b, &result);
+    }
+
+    #[test]
+    fn test_reports_non_convergence() {
+        let a = poisson(50);
+        let b = vec![1.0; 50];
+        let options = IterativeOptions::default().with_max_iterations(3);
+
+        let cg = a.solve_cg(&b, &options).unwrap();
+        assert!(!cg.converged);
+        assert_eq!(cg.iterations, 3);
+        assert!(cg.residual_norm > options.tolerance);
+
+        let gmres = a.solve_gmres(&b, &options).unwrap();
+        assert!(!gmres.converged);
+        assert_eq!(gmres.iterations, 3);
+        assert_eq!(gmres.residual_history.len(), 4);
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        let a = poisson(4);
+        let options = IterativeOptions::default();
+        assert!(a.solve_cg(&[1.0; 3], &options).is_err());
+        assert!(a
+            .solve_gmres(&[1.0; 4], &options.clone().with_restart(0))
+            .is_err());
+
+        let bad_omega = options
+            .clone()
+            .with_preconditioner(Preconditioner::Ssor { omega: 2.0 });
+        assert!(a.solve_cg(&[1.0; 4], &bad_omega).is_err());
+
+        let zero_diagonal = NumericMatrix::from_flat(2, 2, vec![0.0, 1.0, 1.0, 0.0]).unwrap();
+        let jacobi = options.with_preconditioner(Preconditioner::Jacobi);
+        assert!(zero_diagonal.solve_gmres(&[1.0, 2.0], &jacobi).is_err());
+
+        let zero = a.solve_cg(&[0.0; 4], &IterativeOptions::default()).unwrap();
+        assert!(zero.converged);
+        assert_eq!(zero.solution, vec![0.0; 4]);
+    }
+}
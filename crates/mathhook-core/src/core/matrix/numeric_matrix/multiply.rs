@@ -27,6 +27,27 @@ impl NumericMatrix {
         }
     }
 
+    pub fn multiply_vector(&self, x: &[f64]) -> Result<Vec<f64>, MathError> {
+        if x.len() != self.cols {
+            return Err(MathError::DomainError {
+                operation: "NumericMatrix::multiply_vector".to_string(),
+                value: crate::Expression::integer(x.len() as i64),
+                reason: format!(
+                    "Cannot multiply {}x{} matrix with vector of length {}",
+                    self.rows,
+                    self.cols,
+                    x.len()
+                ),
+            });
+        }
+
+        Ok(self
+            .data
+            .chunks_exact(self.cols)
+            .map(|row| row.iter().zip(x).map(|(a, b)| a * b).sum())
+            .collect())
+    }
+
     fn simple_multiply(&self, other: &Self) -> Result<Self, MathError> {
         let mut data = vec![0.0; self.rows * other.cols];
 
@@ -129,6 +150,16 @@ mod tests {
         assert!(a.multiply(&b).is_err());
     }
 
+    #[test]
+    fn test_multiply_vector() {
+        let a = NumericMatrix::from_flat(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+        assert_eq!(
+            a.multiply_vector(&[1.0, 0.0, -1.0]).unwrap(),
+            vec![-2.0, -2.0]
+        );
+        assert!(a.multiply_vector(&[1.0, 2.0]).is_err());
+    }
+
     #[test]
     fn test_blocked_multiply_large() {
         let n = 200;
@@ -1,4 +1,4 @@
-use super::NumericMatrix;
+use super::{LUResult, NumericMatrix};
 use crate::error::MathError;
 
 const EPSILON: f64 = 1e-10;
@@ -27,14 +27,7 @@ impl NumericMatrix {
 
         let lu = self.lu_decomposition()?;
 
-        let mut pb = vec![0.0; self.rows];
-        for i in 0..self.rows {
-            pb[i] = b[lu.p[i]];
-        }
-
-        let y = forward_substitution(&lu.l, &pb)?;
-
-        backward_substitution(&lu.u, &y)
+        solve_with_lu(&lu, b)
     }
 
     pub fn inverse(&self) -> Result<NumericMatrix, MathError> {
@@ -68,6 +61,15 @@ impl NumericMatrix {
     }
 }
 
+/// Solve `Ax = b` given the LU decomposition of `A`
+pub(super) fn solve_with_lu(lu: &LUResult, b: &[f64]) -> Result<Vec<f64>, MathError> {
+    let pb: Vec<f64> = lu.p.iter().map(|&row| b[row]).collect();
+
+    let y = forward_substitution(&lu.l, &pb)?;
+
+    backward_substitution(&lu.u, &y)
+}
+
 #[allow(clippy::needless_range_loop)]
 fn forward_substitution(l: &NumericMatrix, b: &[f64]) -> Result<Vec<f64>, MathError> {
     let n = l.rows;
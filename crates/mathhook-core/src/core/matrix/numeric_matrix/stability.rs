@@ -0,0 +1,211 @@
+use super::solve::solve_with_lu;
+use super::{LUResult, NumericMatrix};
+use crate::error::MathError;
+
+/// Solution of a linear system together with estimates of its numerical error
+///
+/// All norms are infinity norms, so the quantities combine into the standard
+/// forward error bound `‖x̂ - x‖ / ‖x‖ ≤ κ(A) · η`, where `η` is the normwise
+/// backward error reported as `relative_residual`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SolveReport {
+    pub solution: Vec<f64>,
+    /// `‖b - Ax̂‖`
+    pub residual_norm: f64,
+    /// `‖b - Ax̂‖ / (‖A‖‖x̂‖ + ‖b‖)`, the normwise backward error
+    pub relative_residual: f64,
+    /// Largest entry of `U` over largest entry of `A`; large values mean
+    /// elimination lost accuracy even when the matrix is well conditioned
+    pub pivot_growth: f64,
+    /// `κ(A) = ‖A‖‖A⁻¹‖`
+    pub condition_number: f64,
+}
+
+impl SolveReport {
+    /// Upper bound on the relative error of the solution
+    pub fn error_bound(&self) -> f64 {
+        self.condition_number * self.relative_residual
+    }
+
+    /// Whether the error bound is within `tolerance`
+    pub fn is_reliable(&self, tolerance: f64) -> bool {
+        self.error_bound() <= tolerance
+    }
+}
+
+impl NumericMatrix {
+    /// Maximum absolute column sum
+    pub fn norm_one(&self) -> f64 {
+        (0..self.cols)
+            .map(|j| {
+                (0..self.rows)
+                    .map(|i| self.data[i * self.cols + j].abs())
+                    .sum()
+            })
+            .fold(0.0, f64::max)
+    }
+
+    /// Maximum absolute row sum
+    pub fn norm_inf(&self) -> f64 {
+        self.data
+            .chunks_exact(self.cols)
+            .map(|row| row.iter().map(|x| x.abs()).sum())
+            .fold(0.0, f64::max)
+    }
+
+    /// Condition number `‖A‖‖A⁻¹‖` in the infinity norm
+    ///
+    /// Singular matrices have an infinite condition number. Each digit of the
+    /// condition number is roughly one significant digit that a solve with
+    /// this matrix may lose.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::NumericMatrix;
+    ///
+    /// let a = NumericMatrix::from_flat(2, 2, vec![1.0, 1.0, 1.0, 1.0001]).unwrap();
+    /// assert!(a.condition_number().unwrap() > 1e4);
+    ///
+    /// let singular = NumericMatrix::from_flat(2, 2, vec![1.0, 2.0, 2.0, 4.0]).unwrap();
+    /// assert_eq!(singular.condition_number().unwrap(), f64::INFINITY);
+    /// ```
+    pub fn condition_number(&self) -> Result<f64, MathError> {
+        if !self.is_square() {
+            return Err(MathError::DomainError {
+                operation: "condition_number".to_string(),
+                value: crate::Expression::integer(self.dimensions().0 as i64),
+                reason: "Condition number requires square matrix".to_string(),
+            });
+        }
+
+        let inverse_norm = match self.lu_decomposition() {
+            Ok(lu) => inverse_norm_inf(&lu),
+            Err(_) => None,
+        };
+        Ok(inverse_norm.map_or(f64::INFINITY, |norm| self.norm_inf() * norm))
+    }
+
+    /// Solve `Ax = b`, reporting residual, pivot growth and condition number
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::NumericMatrix;
+    ///
+    /// let a = NumericMatrix::from_flat(2, 2, vec![3.0, 2.0, 1.0, 4.0]).unwrap();
+    /// let report = a.solve_with_report(&[7.0, 9.0]).unwrap();
+    ///
+    /// assert!((report.solution[0] - 1.0).abs() < 1e-12);
+    /// assert!((report.solution[1] - 2.0).abs() < 1e-12);
+    /// assert!(report.residual_norm < 1e-12);
+    /// assert!(report.is_reliable(1e-10));
+    /// ```
+    pub fn solve_with_report(&self, b: &[f64]) -> Result<SolveReport, MathError> {
+        let solution = self.solve(b)?;
+        let lu = self.lu_decomposition()?;
+
+        let ax = self.multiply_vector(&solution)?;
+        let residual_norm = b
+            .iter()
+            .zip(&ax)
+            .map(|(bi, axi)| (bi - axi).abs())
+            .fold(0.0, f64::max);
+        let scale = self.norm_inf() * max_abs(&solution) + max_abs(b);
+        let relative_residual = if scale == 0.0 {
+            0.0
+        } else {
+            residual_norm / scale
+        };
+
+        let max_entry = max_abs(&self.data);
+        let pivot_growth = if max_entry == 0.0 {
+            1.0
+        } else {
+            max_abs(&lu.u.data) / max_entry
+        };
+
+        Ok(SolveReport {
+            solution,
+            residual_norm,
+            relative_residual,
+            pivot_growth,
+            condition_number: inverse_norm_inf(&lu)
+                .map_or(f64::INFINITY, |norm| self.norm_inf() * norm),
+        })
+    }
+}
+
+/// `‖A⁻¹‖` in the infinity norm, one column of the inverse at a time
+///
+/// Returns `None` when the factors are singular.
+fn inverse_norm_inf(lu: &LUResult) -> Option<f64> {
+    let n = lu.p.len();
+    let mut row_sums = vec![0.0; n];
+    for j in 0..n {
+        let mut e = vec![0.0; n];
+        e[j] = 1.0;
+        let column = solve_with_lu(lu, &e).ok()?;
+        for (sum, value) in row_sums.iter_mut().zip(column) {
+            *sum += value.abs();
+        }
+    }
+    Some(row_sums.into_iter().fold(0.0, f64::max))
+}
+
+fn max_abs(values: &[f64]) -> f64 {
+    values.iter().fold(0.0, |acc, x| acc.max(x.abs()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_norms() {
+        let a = NumericMatrix::from_flat(2, 2, vec![1.0, -2.0, -3.0, 4.0]).unwrap();
+        assert_eq!(a.norm_one(), 6.0);
+        assert_eq!(a.norm_inf(), 7.0);
+    }
+
+    #[test]
+    fn test_condition_number() {
+        let identity = NumericMatrix::identity(3).unwrap();
+        assert_eq!(identity.condition_number().unwrap(), 1.0);
+
+        // ‖A‖ = 7 and A⁻¹ = [[-2, 1], [1.5, -0.5]] has ‖A⁻¹‖ = 3
+        let a = NumericMatrix::from_flat(2, 2, vec![1.0, 2.0, 3.0, 4.0]).unwrap();
+        assert!((a.condition_number().unwrap() - 21.0).abs() < 1e-10);
+
+        let rect = NumericMatrix::zeros(2, 3).unwrap();
+        assert!(rect.condition_number().is_err());
+    }
+
+    #[test]
+    fn test_hilbert_matrix_is_flagged() {
+        let hilbert = NumericMatrix::from_fn(8, 8, |i, j| 1.0 / (i + j + 1) as f64).unwrap();
+        let b = hilbert.multiply_vector(&[1.0; 8]).unwrap();
+        let report = hilbert.solve_with_report(&b).unwrap();
+
+        assert!(report.condition_number > 1e9);
+        assert!(report.relative_residual < 1e-12);
+        assert!(!report.is_reliable(1e-8));
+
+        let error = report
+            .solution
+            .iter()
+            .fold(0.0, |acc: f64, x| acc.max((x - 1.0).abs()));
+        assert!(error > 1e-10 && error <= report.error_bound());
+    }
+
+    #[test]
+    fn test_pivot_growth() {
+        let a = NumericMatrix::from_flat(2, 2, vec![1.0, 1.0, -1.0, 1.0]).unwrap();
+        let report = a.solve_with_report(&[2.0, 0.0]).unwrap();
+
+        // Partial pivoting keeps row 0 first, so U = [[1, 1], [0, 2]]
+        assert_eq!(report.pivot_growth, 2.0);
+        assert_eq!(report.solution, vec![1.0, 1.0]);
+        assert_eq!(report.residual_norm, 0.0);
+    }
+}
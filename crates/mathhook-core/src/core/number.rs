@@ -1,16 +1,23 @@
 //! Number type for exact arithmetic
 //!
-//! Supports three representations:
+//! Supports four representations:
 //! - Integer: Arbitrary precision integers (i64 with promotion to BigInt)
 //! - Rational: Exact fractions (numerator/denominator as BigInt)
+//! - Decimal: Exact base-10 numbers that keep their places, such as prices
 //! - Float: Floating-point approximations (f64)
 //!
+//! Numbers can also be read from and written in other bases; see
+//! [`Number::from_str_radix`] and [`Number::to_base`].
+//!
 //! All arithmetic operations use checked arithmetic to detect overflow and
 //! automatically promote to BigInt or Rational types when needed. Float operations
 //! check for infinity and NaN to prevent silent error propagation.
 
 mod arithmetic;
+mod decimal;
 mod integer_ops;
+mod radix;
 mod types;
 
+pub use decimal::Decimal;
 pub use types::Number;
@@ -2,8 +2,10 @@
 //!
 //! Implements Add, Sub, Mul, Div, and Neg traits for Number type.
 //! Uses checked arithmetic to detect overflow and promotes to BigInt or Rational when needed.
+//! Decimals follow the conversion rules in [`super::decimal`].
 //! All float operations check for infinity and NaN.
 
+use super::decimal::{self, DecimalOperation};
 use super::types::Number;
 use crate::error::MathError;
 use num_bigint::BigInt;
@@ -28,12 +30,15 @@ impl Add for Number {
 
     fn add(self, other: Number) -> Result<Number, MathError> {
         match (self, other) {
-            (Number::Integer(a), Number::Integer(b)) => match a.checked_add(b) {
-                Some(result) => Ok(Number::Integer(result)),
-                None => Ok(Number::BigInteger(Box::new(
-                    BigInt::from(a) + BigInt::from(b),
-                ))),
-            },
+            (a @ Number::Decimal(_), b) | (a, b @ Number::Decimal(_)) => {
+                decimal::combine(a, b, DecimalOperation::Add)
+            }
+
+            (Number::Integer(a), Number::Integer(b)) => {
+                Ok(Number::promote_on_overflow(a.checked_add(b), || {
+                    BigInt::from(a) + BigInt::from(b)
+                }))
+            }
 
             (Number::BigInteger(a), Number::BigInteger(b)) => {
                 Ok(Number::BigInteger(Box::new(*a + *b)))
@@ -139,12 +144,15 @@ impl Sub for Number {
 
     fn sub(self, other: Number) -> Result<Number, MathError> {
         match (self, other) {
-            (Number::Integer(a), Number::Integer(b)) => match a.checked_sub(b) {
-                Some(result) => Ok(Number::Integer(result)),
-                None => Ok(Number::BigInteger(Box::new(
-                    BigInt::from(a) - BigInt::from(b),
-                ))),
-            },
+            (a @ Number::Decimal(_), b) | (a, b @ Number::Decimal(_)) => {
+                decimal::combine(a, b, DecimalOperation::Sub)
+            }
+
+            (Number::Integer(a), Number::Integer(b)) => {
+                Ok(Number::promote_on_overflow(a.checked_sub(b), || {
+                    BigInt::from(a) - BigInt::from(b)
+                }))
+            }
 
             (Number::BigInteger(a), Number::BigInteger(b)) => {
                 Ok(Number::BigInteger(Box::new(*a - *b)))
@@ -309,12 +317,15 @@ impl Mul for Number {
 
     fn mul(self, other: Number) -> Result<Number, MathError> {
         match (self, other) {
-            (Number::Integer(a), Number::Integer(b)) => match a.checked_mul(b) {
-                Some(result) => Ok(Number::Integer(result)),
-                None => Ok(Number::BigInteger(Box::new(
-                    BigInt::from(a) * BigInt::from(b),
-                ))),
-            },
+            (a @ Number::Decimal(_), b) | (a, b @ Number::Decimal(_)) => {
+                decimal::combine(a, b, DecimalOperation::Mul)
+            }
+
+            (Number::Integer(a), Number::Integer(b)) => {
+                Ok(Number::promote_on_overflow(a.checked_mul(b), || {
+                    BigInt::from(a) * BigInt::from(b)
+                }))
+            }
 
             (Number::BigInteger(a), Number::BigInteger(b)) => {
                 Ok(Number::BigInteger(Box::new(*a * *b)))
@@ -424,9 +435,16 @@ impl Div for Number {
         }
 
         match (self, other) {
+            (a @ Number::Decimal(_), b) | (a, b @ Number::Decimal(_)) => {
+                decimal::combine(a, b, DecimalOperation::Div)
+            }
+
             (Number::Integer(a), Number::Integer(b)) => {
-                if a % b == 0 {
-                    Ok(Number::Integer(a / b))
+                // Checked first: i64::MIN % -1 overflows
+                if b == -1 || a % b == 0 {
+                    Ok(Number::promote_on_overflow(a.checked_div(b), || {
+                        BigInt::from(a) / BigInt::from(b)
+                    }))
                 } else {
                     Ok(Number::Rational(Box::new(BigRational::new(
                         BigInt::from(a),
@@ -601,16 +619,17 @@ impl Neg for Number {
 
     fn neg(self) -> Result<Number, MathError> {
         match self {
-            Number::Integer(i) => match i.checked_neg() {
-                Some(result) => Ok(Number::Integer(result)),
-                None => Ok(Number::BigInteger(Box::new(-BigInt::from(i)))),
-            },
+            Number::Integer(i) => Ok(Number::promote_on_overflow(i.checked_neg(), || {
+                -BigInt::from(i)
+            })),
 
             Number::BigInteger(bi) => Ok(Number::BigInteger(Box::new(-*bi))),
 
             Number::Float(f) => Ok(Number::Float(-f)),
 
             Number::Rational(r) => Ok(Number::Rational(Box::new(-*r))),
+
+            Number::Decimal(d) => Ok(Number::decimal(d.neg())),
         }
     }
 }
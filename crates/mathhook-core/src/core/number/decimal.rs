@@ -0,0 +1,539 @@
+//! Exact base-10 numbers
+//!
+//! A [`Decimal`] is an integer count of 10^-scale units, so `19.99` is 1999
+//! hundredths and stays exactly that through addition, subtraction and
+//! multiplication, with none of the binary artifacts of `0.1 + 0.2` in floats.
+//! The scale is kept as written: `3.50` has two places and prints as `3.50`.
+//!
+//! # Conversion Rules
+//!
+//! - Decimal with decimal or integer: a decimal, with as many places as the
+//!   operation needs (the larger scale for sums, the sum of scales for products)
+//! - Division: a decimal when the quotient terminates in base 10, otherwise a
+//!   rational
+//! - Decimal with rational: a rational, which is exact for both
+//! - Decimal with float: a float
+
+use super::types::Number;
+use crate::error::MathError;
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_rational::BigRational;
+use num_traits::{One, Signed, Zero};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+/// Most places a terminating quotient may need before it is kept as a rational
+const MAX_QUOTIENT_SCALE: u32 = 1000;
+
+/// Exact number `digits · 10^-scale`
+///
+/// Decimals compare by value, so `2.5` equals `2.50` although they print
+/// differently.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Decimal {
+    digits: BigInt,
+    scale: u32,
+}
+
+impl PartialEq for Decimal {
+    fn eq(&self, other: &Self) -> bool {
+        let (a, b, _) = self.aligned(other);
+        a == b
+    }
+}
+
+impl Eq for Decimal {}
+
+impl Hash for Decimal {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Trailing zeros removed, so equal values hash alike
+        let mut digits = self.digits.clone();
+        let mut scale = self.scale;
+        let ten = BigInt::from(10);
+        while scale > 0 && (&digits % &ten).is_zero() {
+            digits /= &ten;
+            scale -= 1;
+        }
+        digits.hash(state);
+        scale.hash(state);
+    }
+}
+
+impl Decimal {
+    /// Create the decimal `digits · 10^-scale`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::core::number::Decimal;
+    /// use num_bigint::BigInt;
+    ///
+    /// assert_eq!(Decimal::new(BigInt::from(1999), 2).to_string(), "19.99");
+    /// ```
+    pub fn new(digits: BigInt, scale: u32) -> Self {
+        Self { digits, scale }
+    }
+
+    /// Read a decimal written as digits with an optional sign and point
+    ///
+    /// The number of digits after the point becomes the scale.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::core::number::Decimal;
+    ///
+    /// let price = Decimal::parse("-3.50").unwrap();
+    /// assert_eq!(price.scale(), 2);
+    /// assert_eq!(price.to_string(), "-3.50");
+    /// assert_eq!(Decimal::parse("1e5"), None);
+    /// ```
+    pub fn parse(text: &str) -> Option<Self> {
+        let (negative, unsigned) = match text.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, text.strip_prefix('+').unwrap_or(text)),
+        };
+        let (whole, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+        if whole.is_empty() && fraction.is_empty()
+            || !whole
+                .chars()
+                .chain(fraction.chars())
+                .all(|c| c.is_ascii_digit())
+        {
+            return None;
+        }
+        let digits: BigInt = format!("{}{}", whole, fraction).parse().ok()?;
+        Some(Self {
+            digits: if negative { -digits } else { digits },
+            scale: fraction.len() as u32,
+        })
+    }
+
+    /// The exact decimal equal to `value`, if its expansion terminates
+    ///
+    /// The scale is the fewest places that hold the value exactly.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::core::number::Decimal;
+    /// use num_bigint::BigInt;
+    /// use num_rational::BigRational;
+    ///
+    /// let eighth = BigRational::new(BigInt::from(1), BigInt::from(8));
+    /// assert_eq!(Decimal::from_rational(&eighth).unwrap().to_string(), "0.125");
+    ///
+    /// let third = BigRational::new(BigInt::from(1), BigInt::from(3));
+    /// assert_eq!(Decimal::from_rational(&third), None);
+    /// ```
+    pub fn from_rational(value: &BigRational) -> Option<Self> {
+        let (twos, fives, rest) = split_twos_and_fives(value.denom());
+        if rest != BigInt::from(1) {
+            return None;
+        }
+        let scale = twos.max(fives);
+        if scale > MAX_QUOTIENT_SCALE {
+            return None;
+        }
+        let digits = value.numer() * pow10(scale) / value.denom();
+        Some(Self { digits, scale })
+    }
+
+    /// The integer `value` as a decimal with no places
+    pub fn from_integer(value: BigInt) -> Self {
+        Self {
+            digits: value,
+            scale: 0,
+        }
+    }
+
+    /// Number of digits after the point
+    pub fn scale(&self) -> u32 {
+        self.scale
+    }
+
+    /// Integer count of 10^-scale units
+    pub fn digits(&self) -> &BigInt {
+        &self.digits
+    }
+
+    /// The exact value as a fraction
+    pub fn to_rational(&self) -> BigRational {
+        BigRational::new(self.digits.clone(), pow10(self.scale))
+    }
+
+    /// The nearest float
+    pub fn to_f64(&self) -> Option<f64> {
+        self.to_string().parse().ok()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.digits.is_zero()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.digits.is_negative()
+    }
+
+    /// The magnitude, with the same places
+    pub fn abs(&self) -> Self {
+        Self {
+            digits: self.digits.abs(),
+            scale: self.scale,
+        }
+    }
+
+    /// This value with exactly `places` digits after the point
+    ///
+    /// Extra digits are rounded half away from zero, as in school and most
+    /// currency rules; missing ones are filled with zeros.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::core::number::Decimal;
+    ///
+    /// let total = Decimal::parse("2.345").unwrap();
+    /// assert_eq!(total.round_to(2).to_string(), "2.35");
+    /// assert_eq!(Decimal::parse("-2.345").unwrap().round_to(2).to_string(), "-2.35");
+    /// assert_eq!(Decimal::parse("7").unwrap().round_to(2).to_string(), "7.00");
+    /// ```
+    pub fn round_to(&self, places: u32) -> Self {
+        if places >= self.scale {
+            return Self {
+                digits: &self.digits * pow10(places - self.scale),
+                scale: places,
+            };
+        }
+        let unit = pow10(self.scale - places);
+        let (quotient, remainder) = self.digits.abs().div_rem(&unit);
+        let rounded = if remainder * 2 >= unit {
+            quotient + 1
+        } else {
+            quotient
+        };
+        Self {
+            digits: if self.is_negative() {
+                -rounded
+            } else {
+                rounded
+            },
+            scale: places,
+        }
+    }
+
+    /// The same value with the digits of both operands aligned to one scale
+    fn aligned(&self, other: &Self) -> (BigInt, BigInt, u32) {
+        let scale = self.scale.max(other.scale);
+        (
+            &self.digits * pow10(scale - self.scale),
+            &other.digits * pow10(scale - other.scale),
+            scale,
+        )
+    }
+
+    pub(crate) fn add(&self, other: &Self) -> Self {
+        let (a, b, scale) = self.aligned(other);
+        Self {
+            digits: a + b,
+            scale,
+        }
+    }
+
+    pub(crate) fn sub(&self, other: &Self) -> Self {
+        let (a, b, scale) = self.aligned(other);
+        Self {
+            digits: a - b,
+            scale,
+        }
+    }
+
+    pub(crate) fn mul(&self, other: &Self) -> Self {
+        Self {
+            digits: &self.digits * &other.digits,
+            scale: self.scale + other.scale,
+        }
+    }
+
+    pub(crate) fn pow(&self, exponent: u32) -> Self {
+        Self {
+            digits: num_traits::pow(self.digits.clone(), exponent as usize),
+            scale: self.scale * exponent,
+        }
+    }
+
+    pub(crate) fn neg(&self) -> Self {
+        Self {
+            digits: -&self.digits,
+            scale: self.scale,
+        }
+    }
+
+    /// Exact quotient: a decimal with at least the scale of the dividend when
+    /// it terminates, otherwise a rational
+    pub(crate) fn div(&self, other: &Self) -> Result<Number, MathError> {
+        if other.is_zero() {
+            return Err(MathError::DivisionByZero);
+        }
+        let quotient = self.to_rational() / other.to_rational();
+        Ok(match Decimal::from_rational(&quotient) {
+            Some(decimal) if decimal.scale < self.scale => {
+                Number::decimal(decimal.round_to(self.scale))
+            }
+            Some(decimal) => Number::decimal(decimal),
+            None => Number::rational(quotient),
+        })
+    }
+}
+
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let magnitude = self.digits.abs().to_string();
+        let sign = if self.is_negative() { "-" } else { "" };
+        let scale = self.scale as usize;
+        if scale == 0 {
+            return write!(f, "{}{}", sign, magnitude);
+        }
+        let padded = format!("{:0>width$}", magnitude, width = scale + 1);
+        let (whole, fraction) = padded.split_at(padded.len() - scale);
+        write!(f, "{}{}.{}", sign, whole, fraction)
+    }
+}
+
+impl Number {
+    /// Create an exact decimal number
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::core::number::Decimal;
+    /// use mathhook_core::Number;
+    ///
+    /// let a = Number::decimal(Decimal::parse("0.1").unwrap());
+    /// let b = Number::decimal(Decimal::parse("0.2").unwrap());
+    /// assert_eq!((a + b).unwrap().to_string(), "0.3");
+    /// ```
+    pub fn decimal(value: Decimal) -> Self {
+        Self::Decimal(Box::new(value))
+    }
+
+    /// Write this number with exactly `places` digits after the point
+    ///
+    /// Integers, rationals and decimals are rounded exactly, half away from
+    /// zero; floats are rounded from their exact binary value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::Number;
+    /// use num_bigint::BigInt;
+    /// use num_rational::BigRational;
+    ///
+    /// let third = Number::rational(BigRational::new(BigInt::from(10), BigInt::from(3)));
+    /// assert_eq!(third.to_fixed(2).unwrap(), "3.33");
+    /// assert_eq!(Number::integer(5).to_fixed(2).unwrap(), "5.00");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `MathError::DomainError` for a float that is infinite or NaN.
+    pub fn to_fixed(&self, places: u32) -> Result<String, MathError> {
+        let exact = match self {
+            Number::Integer(i) => BigRational::from_integer(BigInt::from(*i)),
+            Number::BigInteger(i) => BigRational::from_integer(i.as_ref().clone()),
+            Number::Rational(r) => r.as_ref().clone(),
+            Number::Decimal(d) => return Ok(d.round_to(places).to_string()),
+            Number::Float(f) => {
+                BigRational::from_float(*f).ok_or_else(|| MathError::DomainError {
+                    operation: "to_fixed".to_owned(),
+                    value: crate::core::Expression::number(*f),
+                    reason: "only finite numbers have a decimal expansion".to_owned(),
+                })?
+            }
+        };
+        let negative = exact.is_negative();
+        let scaled = (exact * BigRational::from_integer(pow10(places))).abs();
+        let whole = scaled.trunc();
+        let mut digits = whole.to_integer();
+        if (scaled - whole) * BigRational::from_integer(BigInt::from(2)) >= BigRational::one() {
+            digits += 1;
+        }
+        if negative {
+            digits = -digits;
+        }
+        Ok(Decimal::new(digits, places).to_string())
+    }
+}
+
+/// Apply `operation` to two numbers at least one of which is a decimal,
+/// following the conversion rules of this module
+pub(super) fn combine(
+    a: Number,
+    b: Number,
+    operation: DecimalOperation,
+) -> Result<Number, MathError> {
+    match (exact_decimal(&a), exact_decimal(&b)) {
+        (Some(x), Some(y)) => match operation {
+            DecimalOperation::Add => Ok(Number::decimal(x.add(&y))),
+            DecimalOperation::Sub => Ok(Number::decimal(x.sub(&y))),
+            DecimalOperation::Mul => Ok(Number::decimal(x.mul(&y))),
+            DecimalOperation::Div => x.div(&y),
+        },
+        _ if matches!(a, Number::Float(_)) || matches!(b, Number::Float(_)) => {
+            let (x, y) = (Number::Float(a.to_float()?), Number::Float(b.to_float()?));
+            operation.apply(x, y)
+        }
+        _ => operation.apply(as_rational(a), as_rational(b)),
+    }
+}
+
+/// Arithmetic operation passed to [`combine`]
+#[derive(Debug, Clone, Copy)]
+pub(super) enum DecimalOperation {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+impl DecimalOperation {
+    fn apply(self, a: Number, b: Number) -> Result<Number, MathError> {
+        match self {
+            DecimalOperation::Add => a + b,
+            DecimalOperation::Sub => a - b,
+            DecimalOperation::Mul => a * b,
+            DecimalOperation::Div => a / b,
+        }
+    }
+}
+
+/// A decimal or an integer as a decimal
+fn exact_decimal(number: &Number) -> Option<Decimal> {
+    match number {
+        Number::Decimal(d) => Some(d.as_ref().clone()),
+        Number::Integer(i) => Some(Decimal::from_integer(BigInt::from(*i))),
+        Number::BigInteger(i) => Some(Decimal::from_integer(i.as_ref().clone())),
+        _ => None,
+    }
+}
+
+/// A decimal as the equal rational; other numbers unchanged
+fn as_rational(number: Number) -> Number {
+    match number {
+        Number::Decimal(d) => Number::rational(d.to_rational()),
+        other => other,
+    }
+}
+
+fn pow10(exponent: u32) -> BigInt {
+    num_traits::pow(BigInt::from(10), exponent as usize)
+}
+
+/// (a, b, m) with n = 2^a · 5^b · m
+fn split_twos_and_fives(n: &BigInt) -> (u32, u32, BigInt) {
+    let mut rest = n.abs();
+    let mut count = |p: u32| {
+        let prime = BigInt::from(p);
+        let mut k = 0;
+        while (&rest % &prime).is_zero() && !rest.is_zero() {
+            rest /= &prime;
+            k += 1;
+        }
+        k
+    };
+    let twos = count(2);
+    let fives = count(5);
+    (twos, fives, rest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dec(text: &str) -> Number {
+        Number::decimal(Decimal::parse(text).unwrap())
+    }
+
+    #[test]
+    fn test_exact_arithmetic() {
+        assert_eq!((dec("0.1") + dec("0.2")).unwrap(), dec("0.3"));
+        assert_eq!((dec("19.99") * Number::integer(3)).unwrap(), dec("59.97"));
+        assert_eq!((dec("1.5") * dec("1.5")).unwrap(), dec("2.25"));
+        assert_eq!((dec("10.00") - dec("0.01")).unwrap(), dec("9.99"));
+        assert_eq!((-dec("2.50")).unwrap(), dec("-2.50"));
+        assert_eq!(dec("1.10").pow(&Number::integer(2)).unwrap(), dec("1.2100"));
+    }
+
+    #[test]
+    fn test_conversion_rules() {
+        // Terminating quotients stay decimal, keeping the dividend's places
+        assert_eq!((dec("10.00") / Number::integer(4)).unwrap(), dec("2.50"));
+        assert_eq!((dec("1") / dec("8")).unwrap(), dec("0.125"));
+        // Others become exact rationals
+        assert_eq!(
+            (dec("1.00") / Number::integer(3)).unwrap(),
+            Number::rational(BigRational::new(BigInt::from(1), BigInt::from(3)))
+        );
+        let half = Number::rational(BigRational::new(BigInt::from(1), BigInt::from(2)));
+        assert_eq!(
+            (dec("0.25") + half).unwrap(),
+            Number::rational(BigRational::new(BigInt::from(3), BigInt::from(4)))
+        );
+        assert_eq!(
+            (dec("0.5") + Number::float(0.25)).unwrap(),
+            Number::float(0.75)
+        );
+        assert!((dec("1.0") / dec("0.00")).is_err());
+    }
+
+    #[test]
+    fn test_expression_simplification() {
+        use crate::core::Expression;
+        use crate::simplify::Simplify;
+
+        let num = |text: &str| Expression::Number(dec(text));
+        let x = Expression::symbol("x");
+        assert_eq!(
+            Expression::add(vec![num("0.1"), num("0.2")]).simplify(),
+            num("0.3")
+        );
+        assert_eq!(
+            Expression::add(vec![
+                Expression::mul(vec![num("2.5"), x.clone()]),
+                Expression::mul(vec![num("1.5"), x.clone()]),
+            ])
+            .simplify(),
+            Expression::mul(vec![num("4.0"), x.clone()])
+        );
+        assert_eq!(Expression::mul(vec![num("1.00"), x.clone()]).simplify(), x);
+        assert_eq!(
+            Expression::pow(num("1.5"), Expression::integer(2)).simplify(),
+            num("2.25")
+        );
+        assert_eq!(
+            Expression::pow(num("0.5"), Expression::integer(-2)).simplify(),
+            num("4")
+        );
+    }
+
+    #[test]
+    fn test_formatting() {
+        assert_eq!(Decimal::parse("0.05").unwrap().to_string(), "0.05");
+        assert_eq!(Decimal::parse("-.5").unwrap().to_string(), "-0.5");
+        assert_eq!(
+            Decimal::parse("1.005").unwrap().round_to(2).to_string(),
+            "1.01"
+        );
+        assert_eq!(
+            Decimal::parse("0.004").unwrap().round_to(2).to_string(),
+            "0.00"
+        );
+        assert_eq!(Number::float(2.675).to_fixed(2).unwrap(), "2.67");
+        assert_eq!(Number::integer(-7).to_fixed(0).unwrap(), "-7");
+        let two_thirds = Number::rational(BigRational::new(BigInt::from(-2), BigInt::from(3)));
+        assert_eq!(two_thirds.to_fixed(3).unwrap(), "-0.667");
+        assert!(Number::float(f64::NAN).to_fixed(2).is_err());
+    }
+}
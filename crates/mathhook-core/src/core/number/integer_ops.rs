@@ -2,11 +2,24 @@
 //!
 //! Implements power operations with checked arithmetic for integer bases and exponents.
 //! Uses checked arithmetic to detect overflow and promotes to BigInt when needed.
+//!
+//! # Overflow Policy
+//!
+//! Machine integers never wrap or saturate. Every `i64` operation on values
+//! that can come from user input is done with its `checked_*` form, and an
+//! overflow redoes the operation exactly with `BigInt`; see
+//! [`Number::promote_on_overflow`].
 
 use super::types::Number;
 use crate::error::MathError;
 use num_bigint::BigInt;
 
+/// Largest integer power computed exactly, in bits
+///
+/// Beyond this a power such as `2^(10^9)` from user input would take
+/// gigabytes to hold, so it is reported as an overflow instead.
+const MAX_POWER_BITS: u64 = 1 << 24;
+
 impl Number {
     /// Power operation with overflow checking
     ///
@@ -44,16 +57,12 @@ impl Number {
                 }
 
                 let exp_u32 = *exp as u32;
+                Self::check_power_size(&BigInt::from(*base), exp_u32)?;
 
-                if let Some(result) = Self::checked_pow_i64(*base, exp_u32) {
-                    Ok(Number::Integer(result))
-                } else {
-                    let base_bigint = BigInt::from(*base);
-                    Ok(Number::BigInteger(Box::new(num_traits::Pow::pow(
-                        base_bigint,
-                        exp_u32,
-                    ))))
-                }
+                Ok(Self::promote_on_overflow(
+                    Self::checked_pow_i64(*base, exp_u32),
+                    || num_traits::Pow::pow(BigInt::from(*base), exp_u32),
+                ))
             }
 
             (Number::BigInteger(base), Number::Integer(exp)) if *exp >= 0 => {
@@ -62,10 +71,21 @@ impl Number {
                         operation: "exponent too large for BigInteger power".to_owned(),
                     });
                 }
-                Ok(Number::BigInteger(Box::new(num_traits::Pow::pow(
+                Self::check_power_size(base, *exp as u32)?;
+                Ok(Number::big_integer(num_traits::Pow::pow(
                     base.as_ref().clone(),
                     *exp as u32,
-                ))))
+                )))
+            }
+
+            (Number::Decimal(base), Number::Integer(exp)) if *exp >= 0 => {
+                if *exp > u32::MAX as i64 {
+                    return Err(MathError::NumericOverflow {
+                        operation: "exponent too large for decimal power".to_owned(),
+                    });
+                }
+                Self::check_power_size(base.digits(), *exp as u32)?;
+                Ok(Number::decimal(base.pow(*exp as u32)))
             }
 
             _ => {
@@ -84,6 +104,42 @@ impl Number {
         }
     }
 
+    /// The result of an `i64` operation, promoted to a big integer on overflow
+    ///
+    /// `checked` is the result of the operation's `checked_*` form; when it
+    /// overflowed, `exact` recomputes the result with `BigInt`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::Number;
+    /// use num_bigint::BigInt;
+    ///
+    /// let (a, b) = (i64::MAX, 2);
+    /// let product = Number::promote_on_overflow(a.checked_mul(b), || BigInt::from(a) * b);
+    /// assert_eq!(product, Number::big_integer(BigInt::from(i64::MAX) * 2));
+    /// ```
+    pub fn promote_on_overflow(checked: Option<i64>, exact: impl FnOnce() -> BigInt) -> Number {
+        match checked {
+            Some(value) => Number::Integer(value),
+            None => Number::big_integer(exact()),
+        }
+    }
+
+    /// Fail when `base^exp` would exceed [`MAX_POWER_BITS`]
+    fn check_power_size(base: &BigInt, exp: u32) -> Result<(), MathError> {
+        let magnitude = base.magnitude();
+        if magnitude.bits() <= 1 {
+            return Ok(());
+        }
+        if (magnitude.bits() - 1).saturating_mul(u64::from(exp)) > MAX_POWER_BITS {
+            return Err(MathError::NumericOverflow {
+                operation: "integer power result too large".to_owned(),
+            });
+        }
+        Ok(())
+    }
+
     /// Helper function for checked integer power
     fn checked_pow_i64(base: i64, exp: u32) -> Option<i64> {
         if exp == 0 {
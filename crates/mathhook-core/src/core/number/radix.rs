@@ -0,0 +1,261 @@
+//! Numbers written in bases other than ten
+//!
+//! Parses digit strings in any base from 2 to 36, including the prefixed
+//! literals `0b1010`, `0o17` and `0x1F`, and writes exact numbers back out in
+//! a chosen base. Fractions whose expansion repeats are written with the
+//! repeating block in parentheses, so 1/7 in base 10 is `0.(142857)` and 1/6
+//! is `0.1(6)`.
+
+use super::types::Number;
+use crate::core::Expression;
+use crate::error::MathError;
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::{Signed, ToPrimitive, Zero};
+use std::collections::HashMap;
+
+/// Longest fractional expansion written before giving up
+///
+/// Repeating blocks can be as long as the denominator, so 1/p for a large
+/// prime p would otherwise produce millions of digits.
+const MAX_FRACTION_DIGITS: usize = 1000;
+
+impl Number {
+    /// Parse a digit string in the given base
+    ///
+    /// Digits beyond 9 are letters in either case. An optional leading `-`
+    /// and a single `.` separating a fractional part are accepted; the
+    /// result is exact.
+    ///
+    /// # Arguments
+    ///
+    /// * `digits` - Digits of the number, without a base prefix
+    /// * `radix` - Base from 2 to 36
+    ///
+    /// # Returns
+    ///
+    /// `None` if the base is out of range or a digit is not valid in it
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::Number;
+    /// use num_bigint::BigInt;
+    /// use num_rational::BigRational;
+    ///
+    /// assert_eq!(Number::from_str_radix("1F", 16), Some(Number::integer(31)));
+    /// assert_eq!(
+    ///     Number::from_str_radix("-10.1", 2),
+    ///     Some(Number::rational(BigRational::new(BigInt::from(-5), BigInt::from(2))))
+    /// );
+    /// assert_eq!(Number::from_str_radix("12", 2), None);
+    /// ```
+    pub fn from_str_radix(digits: &str, radix: u32) -> Option<Number> {
+        if !(2..=36).contains(&radix) {
+            return None;
+        }
+        let (negative, digits) = match digits.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, digits),
+        };
+        let (whole, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+        if whole.is_empty() && fraction.is_empty() {
+            return None;
+        }
+
+        let mut numerator = BigInt::zero();
+        for c in whole.chars().chain(fraction.chars()) {
+            numerator = numerator * radix + c.to_digit(radix)?;
+        }
+        if negative {
+            numerator = -numerator;
+        }
+        if fraction.is_empty() {
+            return Some(Number::big_integer(numerator));
+        }
+
+        let denominator = num_traits::pow(BigInt::from(radix), fraction.len());
+        let value = BigRational::new(numerator, denominator);
+        Some(if value.is_integer() {
+            Number::big_integer(value.to_integer())
+        } else {
+            Number::rational(value)
+        })
+    }
+
+    /// Parse a binary, octal or hexadecimal literal such as `0b1010`, `0o17`
+    /// or `0x1F`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::Number;
+    ///
+    /// assert_eq!(Number::from_prefixed_literal("0b1010"), Some(Number::integer(10)));
+    /// assert_eq!(Number::from_prefixed_literal("0XFF"), Some(Number::integer(255)));
+    /// assert_eq!(Number::from_prefixed_literal("42"), None);
+    /// ```
+    pub fn from_prefixed_literal(literal: &str) -> Option<Number> {
+        let radix = match literal.get(..2)? {
+            "0b" | "0B" => 2,
+            "0o" | "0O" => 8,
+            "0x" | "0X" => 16,
+            _ => return None,
+        };
+        let digits = &literal[2..];
+        if digits.is_empty() || digits.contains(['.', '-']) {
+            return None;
+        }
+        Self::from_str_radix(digits, radix)
+    }
+
+    /// Write the exact value of this number in the given base
+    ///
+    /// Digits beyond 9 are uppercase letters. A fraction is expanded until
+    /// it terminates or repeats, and the repeating block is enclosed in
+    /// parentheses. Floats are written by the exact value of their binary
+    /// representation.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - Base from 2 to 36
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::Number;
+    /// use num_bigint::BigInt;
+    /// use num_rational::BigRational;
+    ///
+    /// let seventh = Number::rational(BigRational::new(BigInt::from(1), BigInt::from(7)));
+    /// assert_eq!(seventh.to_base(10).unwrap(), "0.(142857)");
+    /// assert_eq!(Number::integer(-31).to_base(16).unwrap(), "-1F");
+    /// assert_eq!(Number::float(0.625).to_base(2).unwrap(), "0.101");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `MathError::DomainError` for a base outside 2 to 36 or a
+    /// float that is infinite or NaN, and `MathError::NumericOverflow` when
+    /// the fractional expansion does not repeat within 1000 digits.
+    pub fn to_base(&self, base: u32) -> Result<String, MathError> {
+        if !(2..=36).contains(&base) {
+            return Err(MathError::DomainError {
+                operation: "to_base".to_owned(),
+                value: Expression::integer(base as i64),
+                reason: "base must be between 2 and 36".to_owned(),
+            });
+        }
+        let value = match self {
+            Number::Integer(n) => BigRational::from_integer(BigInt::from(*n)),
+            Number::BigInteger(n) => BigRational::from_integer(n.as_ref().clone()),
+            Number::Rational(r) => r.as_ref().clone(),
+            Number::Decimal(d) => d.to_rational(),
+            Number::Float(f) => {
+                BigRational::from_float(*f).ok_or_else(|| MathError::DomainError {
+                    operation: "to_base".to_owned(),
+                    value: Expression::number(*f),
+                    reason: "only finite numbers have a base expansion".to_owned(),
+                })?
+            }
+        };
+        expand(&value, base)
+    }
+}
+
+fn expand(value: &BigRational, base: u32) -> Result<String, MathError> {
+    let magnitude = value.abs();
+    let denominator = magnitude.denom();
+    let mut remainder = magnitude.numer() % denominator;
+
+    let mut text = String::new();
+    if value.is_negative() {
+        text.push('-');
+    }
+    text.push_str(&magnitude.to_integer().to_str_radix(base).to_uppercase());
+    if remainder.is_zero() {
+        return Ok(text);
+    }
+
+    // Position of each remainder, so the first repeat marks the period
+    let mut seen: HashMap<BigInt, usize> = HashMap::new();
+    let mut digits = String::new();
+    while !remainder.is_zero() {
+        if let Some(&start) = seen.get(&remainder) {
+            digits.insert(start, '(');
+            digits.push(')');
+            break;
+        }
+        if digits.len() == MAX_FRACTION_DIGITS {
+            return Err(MathError::NumericOverflow {
+                operation: format!(
+                    "base-{} expansion longer than {} digits",
+                    base, MAX_FRACTION_DIGITS
+                ),
+            });
+        }
+        seen.insert(remainder.clone(), digits.len());
+        remainder *= base;
+        let digit = (&remainder / denominator).to_u32().unwrap_or(0);
+        remainder %= denominator;
+        digits.push(
+            std::char::from_digit(digit, base)
+                .unwrap_or('?')
+                .to_ascii_uppercase(),
+        );
+    }
+
+    text.push('.');
+    text.push_str(&digits);
+    Ok(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ratio(n: i64, d: i64) -> Number {
+        Number::rational(BigRational::new(BigInt::from(n), BigInt::from(d)))
+    }
+
+    #[test]
+    fn test_repeating_expansions() {
+        assert_eq!(ratio(1, 7).to_base(10).unwrap(), "0.(142857)");
+        assert_eq!(ratio(1, 6).to_base(10).unwrap(), "0.1(6)");
+        assert_eq!(ratio(-22, 7).to_base(10).unwrap(), "-3.(142857)");
+        assert_eq!(ratio(1, 3).to_base(2).unwrap(), "0.(01)");
+        assert_eq!(ratio(1, 3).to_base(3).unwrap(), "0.1");
+        assert_eq!(ratio(1, 10).to_base(2).unwrap(), "0.0(0011)");
+    }
+
+    #[test]
+    fn test_integers_and_floats() {
+        assert_eq!(Number::integer(0).to_base(2).unwrap(), "0");
+        assert_eq!(Number::integer(255).to_base(16).unwrap(), "FF");
+        assert_eq!(Number::integer(35).to_base(36).unwrap(), "Z");
+        let big = Number::big_integer(BigInt::from(u64::MAX));
+        assert_eq!(big.to_base(16).unwrap(), "FFFFFFFFFFFFFFFF");
+        assert_eq!(Number::float(-2.5).to_base(10).unwrap(), "-2.5");
+
+        assert!(Number::integer(5).to_base(1).is_err());
+        assert!(Number::float(f64::NAN).to_base(10).is_err());
+        // The period of 1/1019 in base 10 is 1018 digits
+        assert!(ratio(1, 1019).to_base(10).is_err());
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for (number, base) in [
+            (ratio(5, 8), 2),
+            (ratio(-45, 4), 16),
+            (Number::integer(-1_000_000), 7),
+        ] {
+            let text = number.to_base(base).unwrap();
+            assert_eq!(Number::from_str_radix(&text, base), Some(number));
+        }
+        assert_eq!(Number::from_str_radix("", 10), None);
+        assert_eq!(Number::from_str_radix("1.2.3", 10), None);
+        assert_eq!(Number::from_prefixed_literal("0x"), None);
+        assert_eq!(Number::from_prefixed_literal("0o19"), None);
+    }
+}
@@ -1,8 +1,10 @@
 //! Core number type and basic operations
 //!
-//! Defines the Number enum supporting integers, rationals, floats, and big integers.
+//! Defines the Number enum supporting integers, rationals, floats, big integers
+//! and exact decimals.
 //! Provides constructors, basic trait implementations, and helper methods.
 
+use super::decimal::Decimal;
 use crate::core::constants::EPSILON;
 use crate::error::MathError;
 use num_bigint::BigInt;
@@ -11,12 +13,17 @@ use num_traits::{One, ToPrimitive, Zero};
 use serde::{Deserialize, Serialize};
 
 /// Unified number type supporting integers, rationals, and floats
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+///
+/// Equality is structural and reflexive: floats compare by value, except
+/// that every `NaN` equals every other `NaN`, so `Number` and `Expression`
+/// can implement `Eq`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum Number {
     Integer(i64),
     Float(f64),
     BigInteger(Box<BigInt>),
     Rational(Box<BigRational>),
+    Decimal(Box<Decimal>),
 }
 
 impl Number {
@@ -62,6 +69,27 @@ impl Number {
         Self::Rational(Box::new(value))
     }
 
+    /// Create an integer from a big integer, as `Integer` when it fits in `i64`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::Number;
+    /// use num_bigint::BigInt;
+    ///
+    /// assert_eq!(Number::big_integer(BigInt::from(7)), Number::integer(7));
+    /// assert!(matches!(
+    ///     Number::big_integer(BigInt::from(i64::MAX) + 1),
+    ///     Number::BigInteger(_)
+    /// ));
+    /// ```
+    pub fn big_integer(value: BigInt) -> Self {
+        match value.to_i64() {
+            Some(small) => Self::Integer(small),
+            None => Self::BigInteger(Box::new(value)),
+        }
+    }
+
     /// Check if the number is zero
     ///
     /// # Examples
@@ -78,6 +106,7 @@ impl Number {
             Number::Float(f) => f.abs() < EPSILON,
             Number::BigInteger(bi) => **bi == BigInt::from(0),
             Number::Rational(r) => r.is_zero(),
+            Number::Decimal(d) => d.is_zero(),
         }
     }
 
@@ -97,6 +126,7 @@ impl Number {
             Number::Float(f) => (f - 1.0).abs() < EPSILON,
             Number::BigInteger(bi) => **bi == BigInt::from(1),
             Number::Rational(r) => r.is_one(),
+            Number::Decimal(d) => d.to_rational().is_one(),
         }
     }
 
@@ -116,6 +146,7 @@ impl Number {
             Number::Float(f) => (f + 1.0).abs() < EPSILON,
             Number::BigInteger(bi) => **bi == BigInt::from(-1),
             Number::Rational(r) => **r == BigRational::new(BigInt::from(-1), BigInt::from(1)),
+            Number::Decimal(d) => d.to_rational() == BigRational::from_integer(BigInt::from(-1)),
         }
     }
 
@@ -142,6 +173,22 @@ impl Number {
                     })?;
                 Ok(numer_float / denom_float)
             }
+            Number::Decimal(d) => d.to_f64().ok_or_else(|| MathError::NumericOverflow {
+                operation: "Decimal to float conversion".to_owned(),
+            }),
+        }
+    }
+}
+
+impl PartialEq for Number {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Number::Integer(a), Number::Integer(b)) => a == b,
+            (Number::Float(a), Number::Float(b)) => a == b || (a.is_nan() && b.is_nan()),
+            (Number::BigInteger(a), Number::BigInteger(b)) => a == b,
+            (Number::Rational(a), Number::Rational(b)) => a == b,
+            (Number::Decimal(a), Number::Decimal(b)) => a == b,
+            _ => false,
         }
     }
 }
@@ -177,6 +224,7 @@ impl std::fmt::Display for Number {
                     write!(f, "{}/{}", r.numer(), r.denom())
                 }
             }
+            Number::Decimal(d) => write!(f, "{}", d),
         }
     }
 }
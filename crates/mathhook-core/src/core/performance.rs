@@ -8,6 +8,7 @@
 //! - Smart automatic optimization detection
 
 pub mod background_compute;
+pub mod batch;
 pub mod config;
 pub mod gpu_acceleration;
 pub mod persistent_cache;
@@ -25,6 +26,7 @@ pub use background_compute::{
     get_background_result_by_expression, predict_and_precompute, submit_background_task,
     BackgroundComputeStatistics, ComputePriority, ComputeResult, ComputeTask,
 };
+pub use batch::{parse_many, parse_many_in, simplify_many, solve_many};
 pub use config::{
     cache_result, cache_stats, clear_cache, compute_expr_hash, extract_numeric_f64,
     get_cached_result, get_global_config, get_performance_metrics, get_performance_summary,
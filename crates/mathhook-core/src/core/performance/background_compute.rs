@@ -303,7 +303,7 @@ impl BackgroundCompute {
                 }
             }
 
-            Expression::Function { name, args: _ } => {
+            Expression::Function { name, args: _, .. } => {
                 match name.as_ref() {
                     "sin" | "cos" | "tan" => {
                         // Trigonometric functions often get differentiated
@@ -0,0 +1,208 @@
+//! Batch parsing, simplification and solving
+//!
+//! Services that grade many submissions at once call these instead of one
+//! function per expression, so bindings cross the language boundary once per
+//! batch. Each item is an independent parse, simplification or solve, costly
+//! enough that the items are spread across threads whenever parallelism is
+//! enabled, regardless of the element-count threshold used for arithmetic.
+//! Results keep the order of the inputs.
+//!
+//! Worker threads run inside the caller's evaluation scope (see
+//! [`EvalSettings`]), so a batch behaves like the same calls made one by
+//! one on the calling thread. Each worker parses with its own [`Parser`],
+//! so workers never wait on each other's parse cache.
+
+use super::config::get_global_config;
+use crate::core::expression::eval_scope::EvalSettings;
+use crate::core::symbol::SymbolContext;
+use crate::core::{Expression, Symbol};
+use crate::parser::config::ParserConfig;
+use crate::parser::error::ParseError;
+use crate::parser::Parser;
+use crate::simplify::Simplify;
+use crate::solvers::{MathSolver, SolverResult};
+use rayon::prelude::*;
+
+/// Apply `operation` to every item, in parallel when enabled
+///
+/// `init` creates the per-worker state passed to `operation`.
+fn map_batch<T, R, S, I, F>(items: &[T], init: I, operation: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    I: Fn() -> S + Sync + Send,
+    F: Fn(&mut S, &T) -> R + Sync + Send,
+{
+    map_items(items, get_global_config().parallel_enabled, init, operation)
+}
+
+fn map_items<T, R, S, I, F>(items: &[T], parallel: bool, init: I, operation: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    I: Fn() -> S + Sync + Send,
+    F: Fn(&mut S, &T) -> R + Sync + Send,
+{
+    if !parallel || items.len() < 2 {
+        let mut state = init();
+        return items
+            .iter()
+            .map(|item| operation(&mut state, item))
+            .collect();
+    }
+    // Evaluation scopes are thread-local, and a worker may be inside another
+    // batch's scope when it picks up a job, so each job runs under exactly
+    // the caller's settings
+    let settings = EvalSettings::current();
+    items
+        .par_iter()
+        .map_init(init, |state, item| {
+            let _isolated = settings.clone().isolate();
+            operation(state, item)
+        })
+        .collect()
+}
+
+/// Parse every input, reporting failures per input
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::core::performance::parse_many;
+/// use mathhook_core::{expr, ParserConfig};
+///
+/// let parsed = parse_many(&["x + 1", "2 *", "y^2"], &ParserConfig::default());
+/// assert_eq!(parsed[0], Ok(expr!(x + 1)));
+/// assert!(parsed[1].is_err());
+/// assert_eq!(parsed[2], Ok(expr!(y ^ 2)));
+/// ```
+pub fn parse_many<S>(inputs: &[S], config: &ParserConfig) -> Vec<Result<Expression, ParseError>>
+where
+    S: AsRef<str> + Sync,
+{
+    map_batch(
+        inputs,
+        || Parser::new(config),
+        |parser, input| parser.parse(input.as_ref()),
+    )
+}
+
+/// Parse every input with its symbols bound in `context`
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::core::performance::parse_many_in;
+/// use mathhook_core::core::symbol::SymbolContext;
+/// use mathhook_core::ParserConfig;
+///
+/// let session = SymbolContext::new();
+/// let parsed = parse_many_in(&["x + 1", "x^2"], &ParserConfig::default(), &session);
+/// let x = session.get("x").unwrap();
+/// assert!(parsed.iter().all(|p| p.as_ref().unwrap().find_variables() == vec![x.clone()]));
+/// ```
+pub fn parse_many_in<S>(
+    inputs: &[S],
+    config: &ParserConfig,
+    context: &SymbolContext,
+) -> Vec<Result<Expression, ParseError>>
+where
+    S: AsRef<str> + Sync,
+{
+    map_batch(
+        inputs,
+        || Parser::new(config),
+        |parser, input| parser.parse_in(input.as_ref(), context),
+    )
+}
+
+/// Simplify every expression
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::core::performance::simplify_many;
+/// use mathhook_core::expr;
+///
+/// let simplified = simplify_many(&[expr!(x + x), expr!(2 * 3)]);
+/// assert_eq!(simplified, vec![expr!(2 * x), expr!(6)]);
+/// ```
+pub fn simplify_many(expressions: &[Expression]) -> Vec<Expression> {
+    map_batch(expressions, || (), |_, expression| expression.simplify())
+}
+
+/// Solve every equation for `variable`
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::core::performance::solve_many;
+/// use mathhook_core::{expr, symbol, SolverResult};
+///
+/// let x = symbol!(x);
+/// let results = solve_many(&[expr!(x - 3), expr!(2 * x + 4)], &x);
+/// assert_eq!(results[0], SolverResult::Single(expr!(3)));
+/// assert_eq!(results[1], SolverResult::Single(expr!(-2)));
+/// ```
+pub fn solve_many(equations: &[Expression], variable: &Symbol) -> Vec<SolverResult> {
+    let solver = MathSolver::new();
+    map_batch(
+        equations,
+        || (),
+        |_, equation| solver.solve(equation, variable),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::symbol::SymbolAssumptions;
+    use crate::{expr, symbol};
+
+    #[test]
+    fn test_batches_match_single_calls() {
+        let inputs: Vec<String> = (1..=40).map(|n| format!("{} * x + x", n)).collect();
+        let config = ParserConfig::default();
+        let parser = Parser::new(&config);
+
+        let parsed: Vec<Expression> = parse_many(&inputs, &config)
+            .into_iter()
+            .map(Result::unwrap)
+            .collect();
+        for (input, expression) in inputs.iter().zip(&parsed) {
+            assert_eq!(&parser.parse(input).unwrap(), expression);
+        }
+
+        let simplified = simplify_many(&parsed);
+        for (expression, result) in parsed.iter().zip(&simplified) {
+            assert_eq!(&expression.simplify(), result);
+        }
+
+        let x = symbol!(x);
+        let solver = MathSolver::new();
+        for (equation, result) in simplified.iter().zip(solve_many(&simplified, &x)) {
+            assert_eq!(solver.solve(equation, &x), result);
+        }
+    }
+
+    #[test]
+    fn test_workers_inherit_evaluation_scope() {
+        let roots = vec![Expression::function("sqrt", vec![expr!(x ^ 2)]); 16];
+        let simplify = |roots: &[Expression]| {
+            map_items(roots, true, || (), |_, root: &Expression| root.simplify())
+        };
+        assert!(simplify(&roots).iter().all(|root| *root != expr!(x)));
+
+        let _scope = EvalSettings::new()
+            .assume("x", SymbolAssumptions::new().positive())
+            .enter();
+        assert!(simplify(&roots).iter().all(|root| *root == expr!(x)));
+    }
+
+    #[test]
+    fn test_empty_batch() {
+        assert!(simplify_many(&[]).is_empty());
+        assert!(parse_many::<&str>(&[], &ParserConfig::default()).is_empty());
+        assert!(solve_many(&[], &symbol!(x)).is_empty());
+    }
+}
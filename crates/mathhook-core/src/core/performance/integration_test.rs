@@ -319,7 +319,7 @@ fn format_expr(expr: &Expression) -> String {
             }
         }
         Expression::Pow(base, exp) => format!("{}^{}", format_expr(base), format_expr(exp)),
-        Expression::Function { name, args } => {
+        Expression::Function { name, args, .. } => {
             if args.len() == 1 {
                 format!("{}({})", name, format_expr(&args[0]))
             } else {
@@ -62,6 +62,7 @@ mod error;
 pub mod finite_field;
 mod gcd_ops;
 pub mod groebner;
+mod horner;
 pub mod poly;
 mod properties;
 pub mod sparse_polynomial;
@@ -81,6 +82,9 @@ pub use gcd_ops::PolynomialGcdOps;
 
 pub use educational::PolynomialEducational;
 
+pub use horner::eval_polynomial_fast;
+pub(crate) use horner::{polynomial_terms, sparse_horner};
+
 pub use coefficients::{
     coefficient_at, coefficients_list, constant_term, extract_coefficient_map, is_monic,
 };
@@ -35,7 +35,9 @@ pub use zippel_gcd::{trial_divide, verify_gcd_candidate, TrialDivisionResult};
 pub use zippel_gcd::{analyze_sparsity, is_sparse, sparse_multiply, SparsityInfo};
 
 // Re-export factorization (pure Poly<T>)
-pub use factorization::square_free_factorization_poly;
+pub use factorization::{
+    content_primitive_part, factor_square_free, square_free_factorization_poly,
+};
 
 // Re-export resultant (backward compatibility - moved to algebra)
 pub use resultant::AdvancedPolynomial;
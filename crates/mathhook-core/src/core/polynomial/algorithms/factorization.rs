@@ -8,9 +8,49 @@
 //! - `factor_numeric()`
 //! - `polynomial_content()`
 
-use crate::core::polynomial::poly::Poly;
+use crate::core::polynomial::finite_field::cantor_zassenhaus::cantor_zassenhaus;
+use crate::core::polynomial::finite_field::{hensel_lift_factors, is_prime, PolyZp};
+use crate::core::polynomial::poly::{IntPoly, Poly};
 use crate::core::polynomial::traits::EuclideanDomain;
 
+/// Split an integer polynomial into content and primitive part
+///
+/// The sign is carried by the content so that the primitive part always has a
+/// positive leading coefficient and f = content · primitive.
+///
+/// # Arguments
+///
+/// * `poly` - Integer polynomial to split
+///
+/// # Returns
+///
+/// `(content, primitive)`; the zero polynomial yields `(0, 0)`
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::algebra::{content_primitive_part, IntPoly};
+///
+/// let f = IntPoly::from_coeffs(vec![6, -4, -2]);
+/// let (c, pp) = content_primitive_part(&f);
+/// assert_eq!(c, -2);
+/// assert_eq!(pp, IntPoly::from_coeffs(vec![-3, 2, 1]));
+/// ```
+pub fn content_primitive_part(poly: &IntPoly) -> (i64, IntPoly) {
+    let content = poly.content_i64();
+    if content == 0 {
+        return (0, IntPoly::zero());
+    }
+
+    let signed = if poly.leading_coeff() < 0 {
+        -content
+    } else {
+        content
+    };
+    let primitive = IntPoly::from_coeffs(poly.coefficients().iter().map(|&c| c / signed).collect());
+    (signed, primitive)
+}
+
 /// Square-free factorization using Yun's algorithm
 ///
 /// Pure `Poly<T>` implementation - no Expression conversions during algorithm.
@@ -107,11 +147,232 @@ pub fn square_free_factorization_poly<T: EuclideanDomain>(
     }
 }
 
+/// Number of suitable primes tried before lifting the smallest modular factorization
+const CANDIDATE_PRIMES: usize = 5;
+
+/// Factor a square-free primitive integer polynomial into irreducibles over Z
+///
+/// # Algorithm (Zassenhaus)
+///
+/// 1. Pick odd primes p not dividing the leading coefficient for which f stays
+///    square-free mod p, factor f mod p by Cantor–Zassenhaus, and keep the prime
+///    with the fewest modular factors
+/// 2. Hensel-lift those factors to p^k, with p^k larger than twice the leading
+///    coefficient times the Mignotte bound on the coefficients of any factor
+/// 3. Recombine: for subsets of the lifted factors of growing size, reduce
+///    lc · ∏ subset into the symmetric range mod p^k, take its primitive part,
+///    and keep it when it divides f exactly
+///
+/// # Arguments
+///
+/// * `poly` - Square-free primitive polynomial with positive leading coefficient
+///
+/// # Returns
+///
+/// Irreducible factors with positive leading coefficients whose product is
+/// `poly`, or `None` when p^k would not fit in an `i64`
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::algebra::{factor_square_free, IntPoly};
+///
+/// // x^4 + 4 = (x^2 - 2x + 2)(x^2 + 2x + 2)
+/// let f = IntPoly::from_coeffs(vec![4, 0, 0, 0, 1]);
+/// let factors = factor_square_free(&f).unwrap();
+/// assert_eq!(
+///     factors,
+///     vec![
+///         IntPoly::from_coeffs(vec![2, -2, 1]),
+///         IntPoly::from_coeffs(vec![2, 2, 1]),
+///     ]
+/// );
+/// ```
+pub fn factor_square_free(poly: &IntPoly) -> Option<Vec<IntPoly>> {
+    let n = poly.degree()?;
+    if n <= 1 {
+        return Some(vec![poly.clone()]);
+    }
+
+    let lc = poly.leading_coeff();
+    let mut best: Option<Vec<PolyZp>> = None;
+    let mut suitable = 0;
+    for p in (3u64..).step_by(2).filter(|&p| is_prime(p)).take(1000) {
+        if lc % p as i64 == 0 {
+            continue;
+        }
+        let reduced = PolyZp::from_signed_coeffs(poly.coefficients(), p);
+        let derivative = PolyZp::from_signed_coeffs(poly.derivative().coefficients(), p);
+        if reduced.gcd(&derivative).ok()?.degree() != Some(0) {
+            continue;
+        }
+        let factors = cantor_zassenhaus(&reduced).ok()?;
+        if factors.len() == 1 {
+            return Some(vec![poly.clone()]);
+        }
+        if best.as_ref().is_none_or(|b| factors.len() < b.len()) {
+            best = Some(factors);
+        }
+        suitable += 1;
+        if suitable == CANDIDATE_PRIMES {
+            break;
+        }
+    }
+    let modular = best?;
+    let p = modular[0].modulus();
+
+    let bound = coefficient_bound(poly)?;
+    let mut k = 1;
+    let mut pk = p as i128;
+    while pk <= bound {
+        pk = pk
+            .checked_mul(p as i128)
+            .filter(|&m| m <= i64::MAX as i128)?;
+        k += 1;
+    }
+    let lifted = hensel_lift_factors(poly, &modular, k).ok()?;
+    let mut result = recombine(poly, lifted, pk);
+    result.sort_by(|a, b| {
+        a.degree()
+            .cmp(&b.degree())
+            .then_with(|| a.coefficients().cmp(b.coefficients()))
+    });
+    Some(result)
+}
+
+/// 2 · |lc| · 2^n · ‖f‖₂, bounding lc times any coefficient of a factor of f
+fn coefficient_bound(poly: &IntPoly) -> Option<i128> {
+    let n = poly.degree()? as u32;
+    let norm_squared = poly.coefficients().iter().try_fold(0i128, |acc, &c| {
+        acc.checked_add((c as i128).checked_mul(c as i128)?)
+    })?;
+    let norm = (norm_squared as f64).sqrt().ceil() as i128 + 1;
+    (poly.leading_coeff() as i128)
+        .abs()
+        .checked_mul(2)?
+        .checked_mul(1i128.checked_shl(n).filter(|&b| b > 0)?)?
+        .checked_mul(norm)
+}
+
+/// Combine lifted modular factors into the true factors of `poly`
+fn recombine(poly: &IntPoly, mut lifted: Vec<IntPoly>, pk: i128) -> Vec<IntPoly> {
+    let mut rest = poly.clone();
+    let mut factors = Vec::new();
+    let mut size = 1;
+
+    'sizes: while 2 * size <= lifted.len() {
+        let mut subset: Vec<usize> = (0..size).collect();
+        loop {
+            let lc = rest.leading_coeff() as i128;
+            let product = subset.iter().fold(vec![lc.rem_euclid(pk)], |acc, &i| {
+                mul_mod(&acc, lifted[i].coefficients(), pk)
+            });
+            let candidate =
+                IntPoly::from_coeffs(product.iter().map(|&c| symmetric(c, pk) as i64).collect());
+            let (_, candidate) = content_primitive_part(&candidate);
+            if let Some(quotient) = exact_quotient(&rest, &candidate) {
+                factors.push(candidate);
+                rest = quotient;
+                for &i in subset.iter().rev() {
+                    lifted.remove(i);
+                }
+                continue 'sizes;
+            }
+            if !next_subset(&mut subset, lifted.len()) {
+                break;
+            }
+        }
+        size += 1;
+    }
+
+    if rest.degree().is_some_and(|d| d > 0) {
+        factors.push(rest);
+    }
+    factors
+}
+
+/// Advance `subset` to the next increasing index combination below `n`
+fn next_subset(subset: &mut [usize], n: usize) -> bool {
+    let size = subset.len();
+    for i in (0..size).rev() {
+        if subset[i] < n - size + i {
+            subset[i] += 1;
+            for j in i + 1..size {
+                subset[j] = subset[j - 1] + 1;
+            }
+            return true;
+        }
+    }
+    false
+}
+
+fn mul_mod(a: &[i128], b: &[i64], m: i128) -> Vec<i128> {
+    let mut product = vec![0i128; a.len() + b.len() - 1];
+    for (i, &x) in a.iter().enumerate() {
+        for (j, &y) in b.iter().enumerate() {
+            product[i + j] = (product[i + j] + x * (y as i128).rem_euclid(m)) % m;
+        }
+    }
+    product
+}
+
+fn symmetric(c: i128, m: i128) -> i128 {
+    let r = c.rem_euclid(m);
+    if r > m / 2 {
+        r - m
+    } else {
+        r
+    }
+}
+
+/// f / g when g divides f over Z
+fn exact_quotient(f: &IntPoly, g: &IntPoly) -> Option<IntPoly> {
+    let (f, g) = (f.coefficients(), g.coefficients());
+    let (&g_lc, &g_0) = (g.last()?, g.first()?);
+    if f.len() < g.len() || g_0 == 0 || f[0] % g_0 != 0 {
+        return None;
+    }
+
+    let mut remainder: Vec<i128> = f.iter().map(|&c| c as i128).collect();
+    let mut quotient = vec![0i64; f.len() - g.len() + 1];
+    for i in (0..quotient.len()).rev() {
+        let top = remainder[i + g.len() - 1];
+        if top % g_lc as i128 != 0 {
+            return None;
+        }
+        let q = top / g_lc as i128;
+        quotient[i] = i64::try_from(q).ok()?;
+        for (j, &c) in g.iter().enumerate() {
+            remainder[i + j] -= q * c as i128;
+        }
+    }
+    remainder
+        .iter()
+        .all(|&c| c == 0)
+        .then(|| IntPoly::from_coeffs(quotient))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::core::polynomial::poly::IntPoly;
 
+    #[test]
+    fn test_content_primitive_part_sign() {
+        let poly = IntPoly::from_coeffs(vec![4, 0, -8]);
+        let (c, pp) = content_primitive_part(&poly);
+        assert_eq!(c, -4);
+        assert_eq!(pp, IntPoly::from_coeffs(vec![-1, 0, 2]));
+        assert_eq!(pp.scale_i64(c), poly);
+    }
+
+    #[test]
+    fn test_content_primitive_part_zero() {
+        let (c, pp) = content_primitive_part(&IntPoly::zero());
+        assert_eq!(c, 0);
+        assert!(pp.is_zero());
+    }
+
     #[test]
     fn test_square_free_poly_direct() {
         let poly = IntPoly::from_coeffs(vec![1, -2, 1]);
@@ -152,4 +413,38 @@ mod tests {
         let factors = square_free_factorization_poly(&poly).unwrap();
         assert!(!factors.is_empty());
     }
+
+    #[test]
+    fn test_factor_square_free_recombines_lifted_factors() {
+        // x^4 + 1 is irreducible over Z but splits into quadratics mod every prime
+        let f = IntPoly::from_coeffs(vec![1, 0, 0, 0, 1]);
+        assert_eq!(factor_square_free(&f), Some(vec![f.clone()]));
+
+        // (2x + 1)(3x^2 - 5)(x^3 - x - 1)
+        let factors = vec![
+            IntPoly::from_coeffs(vec![1, 2]),
+            IntPoly::from_coeffs(vec![-5, 0, 3]),
+            IntPoly::from_coeffs(vec![-1, -1, 0, 1]),
+        ];
+        let product = factors
+            .iter()
+            .fold(IntPoly::from_coeffs(vec![1]), |acc, g| acc.mul(g));
+        assert_eq!(factor_square_free(&product), Some(factors));
+    }
+
+    #[test]
+    fn test_factor_square_free_cyclotomic_product() {
+        // x^6 - 1 = (x - 1)(x + 1)(x^2 - x + 1)(x^2 + x + 1)
+        let f = IntPoly::from_coeffs(vec![-1, 0, 0, 0, 0, 0, 1]);
+        let factors = factor_square_free(&f).unwrap();
+        assert_eq!(
+            factors,
+            vec![
+                IntPoly::from_coeffs(vec![-1, 1]),
+                IntPoly::from_coeffs(vec![1, 1]),
+                IntPoly::from_coeffs(vec![1, -1, 1]),
+                IntPoly::from_coeffs(vec![1, 1, 1]),
+            ]
+        );
+    }
 }
@@ -14,6 +14,7 @@
 //! - `sparse`: Sparsity analysis and sparse operations (pure numeric)
 //! - `univariate`: Modular GCD for single-variable polynomials (pure numeric)
 //! - `multivariate`: Multivariate GCD using Zippel's algorithm (pure numeric)
+//! - `modular`: Brown's dense and Zippel's sparse modular images with CRT (pure numeric)
 //! - `interpolation`: Lagrange interpolation for polynomial reconstruction
 //! - `degree_bounds`: Degree bound computation
 //! - `variable_order`: Variable ordering optimization
@@ -31,6 +32,7 @@ mod degree_bounds;
 pub mod educational;
 mod helpers;
 mod interpolation;
+mod modular;
 mod multivariate;
 mod sparse;
 mod trial_division;
@@ -0,0 +1,782 @@
+//! Modular Multivariate GCD (Brown's dense algorithm, Zippel's sparse interpolation)
+//!
+//! PURE NUMERIC: works on `HashMap<Vec<usize>, i64>` over ℤ and on
+//! lexicographically ordered maps over ℤ_p (x₀ is the most significant
+//! variable and the one kept symbolic).
+//!
+//! # Algorithm
+//!
+//! 1. Remove the integer content and fix the scale of every image with
+//!    γ = gcd(lc(f), lc(g)), so images modulo different primes agree
+//! 2. The first image is computed with Brown's recursive algorithm: evaluate
+//!    the last variable at points of ℤ_p, recurse, and Newton-interpolate the
+//!    images back, discarding points whose image has the wrong leading monomial
+//! 3. Later primes reuse the monomial support of that image (Zippel): each
+//!    coefficient in x₀ is recovered from a transposed Vandermonde system over
+//!    univariate GCDs at powers of one random point, which needs as many
+//!    univariate GCDs as the largest coefficient has terms instead of a
+//!    dense grid of points
+//! 4. Images are combined by the Chinese remainder theorem until they stop
+//!    changing and the primitive part divides both inputs over ℤ
+//!
+//! Every image is checked by trial division modulo its prime, and a sparse
+//! image that fails the check falls back to the dense algorithm.
+
+use super::helpers::arithmetic::integer_gcd;
+use super::helpers::LARGE_PRIMES;
+use super::multivariate::{MultiPoly, MultivariateConfig};
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::{Signed, ToPrimitive, Zero};
+use std::collections::{BTreeMap, HashMap};
+
+/// Polynomial over ℤ_p, terms in lexicographic order
+type ModPoly = BTreeMap<Vec<usize>, u64>;
+
+/// Dense univariate polynomial over ℤ_p, lowest degree first, no trailing zeros
+type Dense = Vec<u64>;
+
+/// GCD over ℤ with a positive leading coefficient
+///
+/// Both inputs must be nonzero with exponent vectors of length `num_vars`.
+/// Returns `None` when `config.max_iterations` primes (or all available ones)
+/// did not produce a verified result.
+pub(super) fn modular_gcd(
+    f: &MultiPoly,
+    g: &MultiPoly,
+    num_vars: usize,
+    config: &MultivariateConfig,
+) -> Option<MultiPoly> {
+    let (content_f, f) = integer_primitive(f);
+    let (content_g, g) = integer_primitive(g);
+    let content = integer_gcd(content_f, content_g);
+    let (lc_f, lc_g) = (leading_coefficient(&f)?, leading_coefficient(&g)?);
+    let gamma = integer_gcd(lc_f, lc_g);
+
+    let mut reconstruction: Option<Reconstruction> = None;
+    for (attempt, &p) in LARGE_PRIMES.iter().take(config.max_iterations).enumerate() {
+        if [gamma, lc_f, lc_g]
+            .iter()
+            .any(|c| c.unsigned_abs() % p == 0)
+        {
+            continue;
+        }
+        let field = Field { p };
+        let (f_p, g_p) = (field.reduce(&f), field.reduce(&g));
+
+        let sparse = reconstruction
+            .as_ref()
+            .and_then(|r| field.sparse_image(&f_p, &g_p, &r.support, attempt as u64));
+        let Some(image) =
+            sparse.or_else(|| field.dense_image(&f_p, &g_p, num_vars, config.max_eval_points))
+        else {
+            continue;
+        };
+
+        let lead = image.keys().next_back()?.clone();
+        if lead.iter().all(|&e| e == 0) {
+            return Some(HashMap::from([(vec![0; num_vars], content)]));
+        }
+        let image = field.scale(&image, field.residue(gamma));
+
+        match &mut reconstruction {
+            Some(r) if lead > r.lead => continue,
+            Some(r) if lead == r.lead => {
+                if r.combine(&image, p) {
+                    continue;
+                }
+            }
+            _ => {
+                reconstruction = Some(Reconstruction::new(lead, &image, p));
+                continue;
+            }
+        }
+
+        let candidate = reconstruction.as_ref()?.primitive()?;
+        if divide_exact(&f, &candidate).is_some() && divide_exact(&g, &candidate).is_some() {
+            return candidate
+                .into_iter()
+                .map(|(exps, c)| Some((exps, c.checked_mul(content)?)))
+                .collect();
+        }
+    }
+    None
+}
+
+/// Exact quotient `f / d` over ℤ, or `None` if `d` does not divide `f`
+pub(super) fn divide_exact(f: &MultiPoly, d: &MultiPoly) -> Option<MultiPoly> {
+    let divisor: BTreeMap<&Vec<usize>, i128> = d
+        .iter()
+        .filter(|(_, &c)| c != 0)
+        .map(|(exps, &c)| (exps, c as i128))
+        .collect();
+    let (&lead, &lc) = divisor.iter().next_back()?;
+
+    let mut remainder: BTreeMap<Vec<usize>, i128> = f
+        .iter()
+        .filter(|(_, &c)| c != 0)
+        .map(|(exps, &c)| (exps.clone(), c as i128))
+        .collect();
+    let mut quotient = MultiPoly::new();
+    while let Some((exps, &c)) = remainder.iter().next_back() {
+        let shift = exponent_shift(exps, lead)?;
+        if c % lc != 0 {
+            return None;
+        }
+        let q = c / lc;
+        for (&exps, &dc) in &divisor {
+            let key = shifted(exps, &shift);
+            let entry = remainder.entry(key.clone()).or_insert(0);
+            *entry = entry.checked_sub(q.checked_mul(dc)?)?;
+            if *entry == 0 {
+                remainder.remove(&key);
+            }
+        }
+        quotient.insert(shift, i64::try_from(q).ok()?);
+    }
+    Some(quotient)
+}
+
+/// CRT state of the images seen so far
+struct Reconstruction {
+    lead: Vec<usize>,
+    support: Vec<Vec<usize>>,
+    coefficients: BTreeMap<Vec<usize>, BigInt>,
+    modulus: BigInt,
+}
+
+impl Reconstruction {
+    fn new(lead: Vec<usize>, image: &ModPoly, p: u64) -> Self {
+        let field = Field { p };
+        Self {
+            lead,
+            support: image.keys().cloned().collect(),
+            coefficients: image
+                .iter()
+                .map(|(exps, &c)| (exps.clone(), BigInt::from(field.symmetric(c))))
+                .collect(),
+            modulus: BigInt::from(p),
+        }
+    }
+
+    /// Fold in an image modulo `p`; returns whether any coefficient changed
+    fn combine(&mut self, image: &ModPoly, p: u64) -> bool {
+        let field = Field { p };
+        let prime = BigInt::from(p);
+        let product = &self.modulus * &prime;
+        let correction = field.inv(
+            self.modulus
+                .mod_floor(&prime)
+                .to_u64()
+                .expect("BUG: residue is below the prime"),
+        );
+
+        let mut keys: Vec<Vec<usize>> = self.coefficients.keys().cloned().collect();
+        keys.extend(image.keys().cloned());
+        keys.sort();
+        keys.dedup();
+
+        let mut changed = false;
+        for key in keys {
+            let old = self.coefficients.get(&key).cloned().unwrap_or_default();
+            let residue = image.get(&key).copied().unwrap_or(0);
+            let old_mod_p = old
+                .mod_floor(&prime)
+                .to_u64()
+                .expect("BUG: residue is below the prime");
+            let t = field.mul(field.sub(residue, old_mod_p), correction);
+            let mut value = (&old + &self.modulus * t).mod_floor(&product);
+            if &value * 2 > product {
+                value -= &product;
+            }
+            changed |= value != old;
+            if value.is_zero() {
+                self.coefficients.remove(&key);
+            } else {
+                self.coefficients.insert(key, value);
+            }
+        }
+        self.modulus = product;
+        changed
+    }
+
+    /// Primitive part with a positive leading coefficient, if it fits in `i64`
+    fn primitive(&self) -> Option<MultiPoly> {
+        let content = self
+            .coefficients
+            .values()
+            .fold(BigInt::zero(), |acc, c| acc.gcd(c));
+        let sign = if self.coefficients.values().next_back()?.is_negative() {
+            -content
+        } else {
+            content
+        };
+        self.coefficients
+            .iter()
+            .map(|(exps, c)| Some((exps.clone(), (c / &sign).to_i64()?)))
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Field {
+    p: u64,
+}
+
+impl Field {
+    fn residue(self, c: i64) -> u64 {
+        c.rem_euclid(self.p as i64) as u64
+    }
+
+    fn symmetric(self, c: u64) -> i64 {
+        if c > self.p / 2 {
+            c as i64 - self.p as i64
+        } else {
+            c as i64
+        }
+    }
+
+    fn add(self, a: u64, b: u64) -> u64 {
+        (a + b) % self.p
+    }
+
+    fn sub(self, a: u64, b: u64) -> u64 {
+        (a + self.p - b) % self.p
+    }
+
+    fn mul(self, a: u64, b: u64) -> u64 {
+        a * b % self.p
+    }
+
+    fn pow(self, mut base: u64, mut exp: u64) -> u64 {
+        let mut result = 1;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = self.mul(result, base);
+            }
+            base = self.mul(base, base);
+            exp >>= 1;
+        }
+        result
+    }
+
+    fn inv(self, a: u64) -> u64 {
+        self.pow(a, self.p - 2)
+    }
+
+    // Dense univariate arithmetic
+
+    fn eval(self, a: &[u64], x: u64) -> u64 {
+        a.iter()
+            .rev()
+            .fold(0, |acc, &c| self.add(self.mul(acc, x), c))
+    }
+
+    fn dense_scale(self, a: &[u64], c: u64) -> Dense {
+        trim(a.iter().map(|&v| self.mul(v, c)).collect())
+    }
+
+    fn dense_mul(self, a: &[u64], b: &[u64]) -> Dense {
+        if a.is_empty() || b.is_empty() {
+            return Vec::new();
+        }
+        let mut product = vec![0; a.len() + b.len() - 1];
+        for (i, &u) in a.iter().enumerate() {
+            for (j, &v) in b.iter().enumerate() {
+                product[i + j] = self.add(product[i + j], self.mul(u, v));
+            }
+        }
+        trim(product)
+    }
+
+    fn dense_divrem(self, a: &[u64], b: &[u64]) -> (Dense, Dense) {
+        if a.len() < b.len() {
+            return (Vec::new(), a.to_vec());
+        }
+        let inv = self.inv(*b.last().expect("BUG: division by the zero polynomial"));
+        let mut remainder = a.to_vec();
+        let mut quotient = vec![0; a.len() - b.len() + 1];
+        for i in (0..quotient.len()).rev() {
+            let q = self.mul(remainder[i + b.len() - 1], inv);
+            quotient[i] = q;
+            if q != 0 {
+                for (j, &v) in b.iter().enumerate() {
+                    remainder[i + j] = self.sub(remainder[i + j], self.mul(q, v));
+                }
+            }
+        }
+        remainder.truncate(b.len() - 1);
+        (trim(quotient), trim(remainder))
+    }
+
+    /// Monic GCD; the GCD with the zero polynomial is the other argument
+    fn dense_gcd(self, mut a: Dense, mut b: Dense) -> Dense {
+        while !b.is_empty() {
+            let (_, r) = self.dense_divrem(&a, &b);
+            a = b;
+            b = r;
+        }
+        match a.last() {
+            Some(&lc) => self.dense_scale(&a, self.inv(lc)),
+            None => a,
+        }
+    }
+
+    // Sparse multivariate arithmetic
+
+    fn reduce(self, poly: &MultiPoly) -> ModPoly {
+        poly.iter()
+            .map(|(exps, &c)| (exps.clone(), self.residue(c)))
+            .filter(|&(_, c)| c != 0)
+            .collect()
+    }
+
+    fn add_term(self, poly: &mut ModPoly, exps: Vec<usize>, c: u64) {
+        let entry = poly.entry(exps.clone()).or_insert(0);
+        *entry = self.add(*entry, c);
+        if *entry == 0 {
+            poly.remove(&exps);
+        }
+    }
+
+    fn scale(self, poly: &ModPoly, c: u64) -> ModPoly {
+        poly.iter()
+            .map(|(exps, &v)| (exps.clone(), self.mul(v, c)))
+            .collect()
+    }
+
+    fn monic(self, poly: &ModPoly) -> ModPoly {
+        match poly.values().next_back() {
+            Some(&lc) => self.scale(poly, self.inv(lc)),
+            None => ModPoly::new(),
+        }
+    }
+
+    /// Substitute `a` for variable `var`
+    fn evaluate(self, poly: &ModPoly, var: usize, a: u64) -> ModPoly {
+        let mut powers = vec![1];
+        for _ in 0..degree(poly, var) {
+            powers.push(self.mul(*powers.last().expect("BUG: powers start at 1"), a));
+        }
+        let mut result = ModPoly::new();
+        for (exps, &c) in poly {
+            let mut key = exps.clone();
+            key[var] = 0;
+            self.add_term(&mut result, key, self.mul(c, powers[exps[var]]));
+        }
+        result
+    }
+
+    /// Coefficients in variable `var`, keyed by the remaining monomial
+    fn split(self, poly: &ModPoly, var: usize) -> BTreeMap<Vec<usize>, Dense> {
+        let mut parts: BTreeMap<Vec<usize>, Dense> = BTreeMap::new();
+        for (exps, &c) in poly {
+            let mut key = exps.clone();
+            key[var] = 0;
+            let dense = parts.entry(key).or_default();
+            if dense.len() <= exps[var] {
+                dense.resize(exps[var] + 1, 0);
+            }
+            dense[exps[var]] = c;
+        }
+        parts
+    }
+
+    fn join(self, parts: BTreeMap<Vec<usize>, Dense>, var: usize) -> ModPoly {
+        let mut poly = ModPoly::new();
+        for (key, dense) in parts {
+            for (i, c) in dense.into_iter().enumerate().filter(|&(_, c)| c != 0) {
+                let mut exps = key.clone();
+                exps[var] = i;
+                poly.insert(exps, c);
+            }
+        }
+        poly
+    }
+
+    /// Monic content in ℤ_p[`var`]
+    fn content_in(self, poly: &ModPoly, var: usize) -> Dense {
+        self.split(poly, var)
+            .into_values()
+            .fold(Vec::new(), |acc, c| self.dense_gcd(acc, c))
+    }
+
+    /// Coefficient in ℤ_p[`var`] of the leading monomial in the other variables
+    fn leading_in(self, poly: &ModPoly, var: usize) -> Dense {
+        self.split(poly, var)
+            .into_values()
+            .next_back()
+            .unwrap_or_default()
+    }
+
+    fn divide_in(self, poly: &ModPoly, var: usize, divisor: &[u64]) -> ModPoly {
+        let parts = self
+            .split(poly, var)
+            .into_iter()
+            .map(|(key, c)| (key, self.dense_divrem(&c, divisor).0))
+            .collect();
+        self.join(parts, var)
+    }
+
+    fn multiply_in(self, poly: &ModPoly, var: usize, factor: &[u64]) -> ModPoly {
+        let parts = self
+            .split(poly, var)
+            .into_iter()
+            .map(|(key, c)| (key, self.dense_mul(&c, factor)))
+            .collect();
+        self.join(parts, var)
+    }
+
+    /// Whether `d` divides `f` in ℤ_p[x₀, …]
+    fn divides(self, d: &ModPoly, f: &ModPoly) -> bool {
+        let Some((lead, &lc)) = d.iter().next_back() else {
+            return f.is_empty();
+        };
+        let inv = self.inv(lc);
+        let mut remainder = f.clone();
+        while let Some((exps, &c)) = remainder.iter().next_back() {
+            let Some(shift) = exponent_shift(exps, lead) else {
+                return false;
+            };
+            let q = self.mul(c, inv);
+            for (exps, &v) in d {
+                self.add_term(
+                    &mut remainder,
+                    shifted(exps, &shift),
+                    self.sub(0, self.mul(q, v)),
+                );
+            }
+        }
+        true
+    }
+
+    /// Monic GCD in the first `active` variables by Brown's algorithm
+    fn dense_image(
+        self,
+        f: &ModPoly,
+        g: &ModPoly,
+        active: usize,
+        max_points: usize,
+    ) -> Option<ModPoly> {
+        if f.is_empty() || g.is_empty() {
+            return Some(self.monic(if f.is_empty() { g } else { f }));
+        }
+        if active == 1 {
+            let (f0, g0) = (self.split(f, 0), self.split(g, 0));
+            let key = f0.keys().next()?.clone();
+            let gcd = self.dense_gcd(f0.into_values().next()?, g0.into_values().next()?);
+            return Some(self.join(BTreeMap::from([(key, gcd)]), 0));
+        }
+
+        let var = active - 1;
+        let (content_f, content_g) = (self.content_in(f, var), self.content_in(g, var));
+        let content = self.dense_gcd(content_f.clone(), content_g.clone());
+        let f = self.divide_in(f, var, &content_f);
+        let g = self.divide_in(g, var, &content_g);
+        let (lc_f, lc_g) = (self.leading_in(&f, var), self.leading_in(&g, var));
+        let gamma = self.dense_gcd(lc_f.clone(), lc_g.clone());
+        let bound = degree(&f, var).min(degree(&g, var)) + gamma.len() - 1;
+
+        let mut newton: Option<Newton> = None;
+        for a in (1..self.p).take(bound + 1 + max_points) {
+            let scale = self.eval(&gamma, a);
+            if scale == 0 || self.eval(&lc_f, a) == 0 || self.eval(&lc_g, a) == 0 {
+                continue;
+            }
+            let image = self.dense_image(
+                &self.evaluate(&f, var, a),
+                &self.evaluate(&g, var, a),
+                var,
+                max_points,
+            )?;
+            let lead = image.keys().next_back()?.clone();
+            if lead.iter().all(|&e| e == 0) {
+                let key = vec![0; lead.len()];
+                return Some(self.join(BTreeMap::from([(key, content)]), var));
+            }
+            let image = self.scale(&image, scale);
+
+            let stable = match &mut newton {
+                Some(state) if lead > state.lead => continue,
+                Some(state) if lead == state.lead => {
+                    let mut residual = image;
+                    for (exps, &c) in &self.evaluate(&state.poly, var, a) {
+                        self.add_term(&mut residual, exps.clone(), self.sub(0, c));
+                    }
+                    let stable = residual.is_empty();
+                    if !stable {
+                        let weight = self.inv(self.eval(&state.modulus, a));
+                        let basis = self.dense_scale(&state.modulus, weight);
+                        for (exps, c) in self.multiply_in(&residual, var, &basis) {
+                            self.add_term(&mut state.poly, exps, c);
+                        }
+                    }
+                    state.modulus = self.dense_mul(&state.modulus, &[self.sub(0, a), 1]);
+                    state.points += 1;
+                    stable
+                }
+                _ => {
+                    newton = Some(Newton {
+                        lead,
+                        poly: image,
+                        modulus: vec![self.sub(0, a), 1],
+                        points: 1,
+                    });
+                    false
+                }
+            };
+
+            let state = newton.as_ref()?;
+            if stable || state.points > bound {
+                let candidate =
+                    self.divide_in(&state.poly, var, &self.content_in(&state.poly, var));
+                if self.divides(&candidate, &f) && self.divides(&candidate, &g) {
+                    return Some(self.monic(&self.multiply_in(&candidate, var, &content)));
+                }
+            }
+        }
+        None
+    }
+
+    /// Monic GCD with the support of an earlier image, by sparse interpolation
+    ///
+    /// Needs a single leading monomial in x₀ to fix the scale of the
+    /// univariate images; returns `None` if that, the evaluation point or the
+    /// final trial division does not work out.
+    fn sparse_image(
+        self,
+        f: &ModPoly,
+        g: &ModPoly,
+        support: &[Vec<usize>],
+        seed: u64,
+    ) -> Option<ModPoly> {
+        let num_vars = support.first()?.len();
+        if num_vars < 2 || self.p < 4 {
+            return None;
+        }
+        let mut groups: BTreeMap<usize, Vec<&Vec<usize>>> = BTreeMap::new();
+        for exps in support {
+            groups.entry(exps[0]).or_default().push(exps);
+        }
+        let (&gcd_degree, leading) = groups.iter().next_back()?;
+        if leading.len() != 1 {
+            return None;
+        }
+
+        let point: Vec<u64> = (1..num_vars)
+            .map(|i| 2 + mix(seed ^ mix(self.p + i as u64)) % (self.p - 3))
+            .collect();
+        let node = |exps: &[usize]| {
+            exps[1..]
+                .iter()
+                .zip(&point)
+                .fold(1, |acc, (&e, &a)| self.mul(acc, self.pow(a, e as u64)))
+        };
+        let nodes: BTreeMap<usize, Vec<u64>> = groups
+            .iter()
+            .map(|(&d, monomials)| (d, monomials.iter().map(|m| node(m)).collect()))
+            .collect();
+        for group in nodes.values() {
+            let mut distinct = group.clone();
+            distinct.sort_unstable();
+            distinct.dedup();
+            if distinct.len() != group.len() {
+                return None;
+            }
+        }
+        let lead_node = node(leading[0]);
+        let unknowns = groups.values().map(Vec::len).max()?;
+
+        let (degree_f, degree_g) = (degree(f, 0), degree(g, 0));
+        let mut values: BTreeMap<usize, Vec<u64>> = BTreeMap::new();
+        for j in 1..=unknowns as u64 {
+            let powers: Vec<u64> = point.iter().map(|&a| self.pow(a, j)).collect();
+            let (f_j, g_j) = (
+                self.univariate_image(f, &powers),
+                self.univariate_image(g, &powers),
+            );
+            if f_j.len() != degree_f + 1 || g_j.len() != degree_g + 1 {
+                return None;
+            }
+            let h = self.dense_gcd(f_j, g_j);
+            if h.len() != gcd_degree + 1 {
+                return None;
+            }
+            let scale = self.pow(lead_node, j);
+            for &d in groups.keys() {
+                values.entry(d).or_default().push(self.mul(h[d], scale));
+            }
+        }
+
+        let mut image = ModPoly::new();
+        for (d, monomials) in &groups {
+            let weights = self.solve_vandermonde(&nodes[d], &values[d][..monomials.len()])?;
+            for ((exps, &k), w) in monomials.iter().zip(&nodes[d]).zip(weights) {
+                self.add_term(&mut image, (*exps).clone(), self.mul(w, self.inv(k)));
+            }
+        }
+        (self.divides(&image, f) && self.divides(&image, g)).then_some(image)
+    }
+
+    /// Substitute `powers` for x₁, x₂, … leaving a polynomial in x₀
+    fn univariate_image(self, poly: &ModPoly, powers: &[u64]) -> Dense {
+        let mut dense = vec![0; degree(poly, 0) + 1];
+        for (exps, &c) in poly {
+            let value = exps[1..]
+                .iter()
+                .zip(powers)
+                .fold(c, |acc, (&e, &a)| self.mul(acc, self.pow(a, e as u64)));
+            dense[exps[0]] = self.add(dense[exps[0]], value);
+        }
+        trim(dense)
+    }
+
+    /// Solve `Σₛ wₛ kₛⁱ = vᵢ` for `i = 0, …, t - 1` with distinct nodes `kₛ`
+    fn solve_vandermonde(self, nodes: &[u64], values: &[u64]) -> Option<Vec<u64>> {
+        let t = nodes.len();
+        let master = nodes.iter().fold(vec![1], |acc, &k| {
+            self.dense_mul(&acc, &[self.sub(0, k), 1])
+        });
+        nodes
+            .iter()
+            .map(|&k| {
+                // master(z) / (z - k) by synthetic division
+                let mut quotient = vec![0; t];
+                quotient[t - 1] = master[t];
+                for i in (0..t - 1).rev() {
+                    quotient[i] = self.add(master[i + 1], self.mul(k, quotient[i + 1]));
+                }
+                let numerator = quotient
+                    .iter()
+                    .zip(values)
+                    .fold(0, |acc, (&q, &v)| self.add(acc, self.mul(q, v)));
+                let denominator = self.eval(&quotient, k);
+                (denominator != 0).then(|| self.mul(numerator, self.inv(denominator)))
+            })
+            .collect()
+    }
+}
+
+/// Newton interpolation state in one variable
+struct Newton {
+    lead: Vec<usize>,
+    poly: ModPoly,
+    modulus: Dense,
+    points: usize,
+}
+
+fn integer_primitive(poly: &MultiPoly) -> (i64, MultiPoly) {
+    let content = poly.values().fold(0, |acc, &c| integer_gcd(acc, c)).max(1);
+    let primitive = poly
+        .iter()
+        .filter(|(_, &c)| c != 0)
+        .map(|(exps, &c)| (exps.clone(), c / content))
+        .collect();
+    (content, primitive)
+}
+
+/// Coefficient of the lexicographically largest monomial
+fn leading_coefficient(poly: &MultiPoly) -> Option<i64> {
+    poly.iter().max_by(|a, b| a.0.cmp(b.0)).map(|(_, &c)| c)
+}
+
+fn degree(poly: &ModPoly, var: usize) -> usize {
+    poly.keys().map(|exps| exps[var]).max().unwrap_or(0)
+}
+
+fn exponent_shift(exps: &[usize], lead: &[usize]) -> Option<Vec<usize>> {
+    exps.iter()
+        .zip(lead)
+        .map(|(&a, &b)| a.checked_sub(b))
+        .collect()
+}
+
+fn shifted(exps: &[usize], shift: &[usize]) -> Vec<usize> {
+    exps.iter().zip(shift).map(|(a, b)| a + b).collect()
+}
+
+fn trim(mut dense: Dense) -> Dense {
+    while dense.last() == Some(&0) {
+        dense.pop();
+    }
+    dense
+}
+
+/// SplitMix64 finalizer, used to pick reproducible evaluation points
+fn mix(mut z: u64) -> u64 {
+    z = z.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn poly(terms: &[(&[usize], i64)]) -> MultiPoly {
+        terms.iter().map(|&(exps, c)| (exps.to_vec(), c)).collect()
+    }
+
+    fn multiply(a: &MultiPoly, b: &MultiPoly) -> MultiPoly {
+        let mut product = MultiPoly::new();
+        for (ea, &ca) in a {
+            for (eb, &cb) in b {
+                *product.entry(shifted(ea, eb)).or_insert(0) += ca * cb;
+            }
+        }
+        product.retain(|_, c| *c != 0);
+        product
+    }
+
+    #[test]
+    fn test_bivariate_gcd_with_content() {
+        // gcd(6(x + y + 1)(x - y), 4(x + y + 1)(x + 2y)) = 2(x + y + 1)
+        let common = poly(&[(&[1, 0], 1), (&[0, 1], 1), (&[0, 0], 1)]);
+        let f = multiply(&common, &poly(&[(&[1, 0], 6), (&[0, 1], -6)]));
+        let g = multiply(&common, &poly(&[(&[1, 0], 4), (&[0, 1], 8)]));
+
+        let gcd = modular_gcd(&f, &g, 2, &MultivariateConfig::default()).unwrap();
+        assert_eq!(gcd, multiply(&common, &poly(&[(&[0, 0], 2)])));
+        assert!(divide_exact(&f, &gcd).is_some());
+        assert!(divide_exact(&f, &poly(&[(&[1, 0], 1), (&[0, 1], 2)])).is_none());
+    }
+
+    #[test]
+    fn test_sparse_trivariate_gcd_needs_several_primes() {
+        // The common factor has coefficients beyond a single 31-bit prime and a
+        // leading coefficient in y, so later primes go through sparse
+        // interpolation and the result through CRT
+        let big = 3_000_000_007;
+        let common = poly(&[
+            (&[3, 1, 0], 5),
+            (&[2, 0, 2], big),
+            (&[1, 1, 1], -7),
+            (&[0, 0, 4], 1),
+            (&[0, 2, 0], -big),
+        ]);
+        let f = multiply(
+            &common,
+            &poly(&[(&[1, 0, 0], 1), (&[0, 1, 1], 3), (&[0, 0, 0], -2)]),
+        );
+        let g = multiply(&common, &poly(&[(&[2, 0, 0], 1), (&[0, 0, 1], -1)]));
+
+        let gcd = modular_gcd(&f, &g, 3, &MultivariateConfig::default()).unwrap();
+        assert_eq!(gcd, common);
+    }
+
+    #[test]
+    fn test_coprime_and_monomial_gcds() {
+        let config = MultivariateConfig::default();
+        let f = poly(&[(&[1, 0], 1), (&[0, 1], 1)]);
+        let g = poly(&[(&[1, 0], 1), (&[0, 1], -1)]);
+        assert_eq!(modular_gcd(&f, &g, 2, &config), Some(poly(&[(&[0, 0], 1)])));
+
+        let xy = poly(&[(&[1, 1], 1)]);
+        let x = poly(&[(&[1, 0], 3)]);
+        assert_eq!(
+            modular_gcd(&xy, &x, 2, &config),
+            Some(poly(&[(&[1, 0], 1)]))
+        );
+    }
+}
@@ -28,7 +28,9 @@
 //! - `poly`: `PolyZp` type (polynomials over Z_p)
 //! - `gcd`: GCD algorithms for PolyZp
 //! - `ntt`: Fast polynomial multiplication via Number Theoretic Transform
-//! - `berlekamp`: Polynomial factorization (Berlekamp's algorithm, Hensel lifting)
+//! - `berlekamp`: Polynomial factorization (Berlekamp's algorithm)
+//! - `cantor_zassenhaus`: Polynomial factorization (distinct- and equal-degree splitting)
+//! - `hensel`: Lifting factorizations from Z_p to Z_{p^k}
 //! - `bridge`: Conversion to/from Expression
 //!
 //! # Performance Considerations
@@ -48,8 +50,10 @@
 
 pub mod berlekamp;
 mod bridge;
+pub mod cantor_zassenhaus;
 mod element;
 mod gcd;
+pub mod hensel;
 mod ntt;
 mod poly;
 
@@ -82,6 +86,9 @@ pub enum FiniteFieldError {
 
     /// Invalid evaluation point
     InvalidEvaluationPoint { reason: String },
+
+    /// Input does not satisfy the preconditions of Hensel lifting
+    HenselPrecondition { reason: String },
 }
 
 impl fmt::Display for FiniteFieldError {
@@ -116,6 +123,9 @@ impl fmt::Display for FiniteFieldError {
             FiniteFieldError::InvalidEvaluationPoint { reason } => {
                 write!(f, "invalid evaluation point: {}", reason)
             }
+            FiniteFieldError::HenselPrecondition { reason } => {
+                write!(f, "Hensel lifting precondition failed: {}", reason)
+            }
         }
     }
 }
@@ -129,5 +139,6 @@ pub type FiniteFieldResult<T> = Result<T, FiniteFieldError>;
 pub use bridge::educational;
 pub use element::{extended_gcd, is_prime, Zp};
 pub use gcd::content;
+pub use hensel::{hensel_lift, hensel_lift_factors};
 pub use ntt::{multiply_auto, ntt_multiply, NTT_PRIME_1, NTT_PRIME_2, NTT_PRIME_3, NTT_THRESHOLD};
 pub use poly::PolyZp;
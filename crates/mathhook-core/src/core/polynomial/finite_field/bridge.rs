@@ -204,11 +204,8 @@ fn extract_terms_recursive(
             }
         }
         Expression::Symbol(s) => {
-            if s == var {
-                *coeffs.entry(1).or_insert(0) += multiplier;
-            } else {
-                *coeffs.entry(0).or_insert(0) += multiplier;
-            }
+            let degree = usize::from(s == var);
+            *coeffs.entry(degree).or_insert(0) += multiplier;
         }
         Expression::Add(terms) => {
             for term in terms.iter() {
@@ -258,6 +255,14 @@ fn number_to_i64(num: &Number) -> Option<i64> {
                 None
             }
         }
+        Number::Decimal(d) => {
+            let value = d.to_rational();
+            if value.is_integer() {
+                value.numer().to_i64()
+            } else {
+                None
+            }
+        }
     }
 }
 /// Convert an Expression to i64 if it's an integer
@@ -280,9 +285,7 @@ fn analyze_monomial(factors: &[Expression], var: &Symbol) -> (i64, usize) {
                 }
             }
             Expression::Symbol(s) => {
-                if s == var {
-                    degree += 1;
-                }
+                degree += usize::from(s == var);
             }
             Expression::Pow(base, exp) => {
                 if let Expression::Symbol(s) = base.as_ref() {
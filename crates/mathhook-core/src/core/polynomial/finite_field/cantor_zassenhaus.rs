@@ -0,0 +1,287 @@
+//! Polynomial Factorization over Finite Fields by Cantor–Zassenhaus
+//!
+//! Factors a square-free polynomial over `Z_p[x]` in two stages.
+//!
+//! # Mathematical Background
+//!
+//! ## Distinct-Degree Factorization
+//!
+//! x^(p^d) - x is the product of all monic irreducible polynomials over Z_p whose
+//! degree divides d. Taking gcd(f, x^(p^d) - x) for d = 1, 2, ... and dividing each
+//! result out of f splits f into products f_d of irreducible factors of degree
+//! exactly d.
+//!
+//! ## Equal-Degree Factorization
+//!
+//! For odd p and a random a ∈ `Z_p[x]`/(f_d), the element a^((p^d - 1)/2) is ±1
+//! modulo each irreducible factor independently, each sign with probability close
+//! to 1/2. So gcd(f_d, a^((p^d - 1)/2) - 1) is a proper factor of f_d with
+//! probability at least 1/2, and repeated splitting separates all factors.
+//!
+//! The exponent (p^d - 1)/2 = ((p - 1)/2)·(1 + p + ⋯ + p^(d-1)) is never formed:
+//! a^((p^d - 1)/2) is computed as (a·a^p⋯a^(p^(d-1)))^((p-1)/2).
+//!
+//! Over Z_2 the equal-degree split needs a trace map instead; factoring there is
+//! delegated to Berlekamp's algorithm.
+//!
+//! # Complexity
+//!
+//! Expected O(n³ log p) field operations for a polynomial of degree n, without
+//! the O(n³) linear algebra of Berlekamp's algorithm.
+//!
+//! ## References
+//!
+//! - `[CZ81]` Cantor, Zassenhaus. "A new algorithm for factoring polynomials over
+//!   finite fields", Mathematics of Computation 36
+//! - `[vzGG13]` von zur Gathen, Gerhard. "Modern Computer Algebra", Section 14.3
+
+use super::berlekamp::berlekamp_factor;
+use super::{FiniteFieldError, FiniteFieldResult, PolyZp};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// Seed of the random splitting polynomials, so factorizations are reproducible
+const SPLITTING_SEED: u64 = 0x5eed;
+
+/// Split a square-free polynomial into products of irreducible factors of equal
+/// degree
+///
+/// # Arguments
+///
+/// * `f` - Square-free polynomial over Z_p
+///
+/// # Returns
+///
+/// Monic `(f_d, d)` pairs, in increasing d, where f_d is the product of the
+/// irreducible factors of degree d
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::algebra::PolyZp;
+/// use mathhook_core::core::polynomial::finite_field::cantor_zassenhaus::distinct_degree_factorization;
+///
+/// // x^3 - x^2 + x - 1 = (x - 1)(x^2 + 1), and x^2 + 1 is irreducible mod 7
+/// let f = PolyZp::from_signed_coeffs(&[-1, 1, -1, 1], 7);
+/// let parts = distinct_degree_factorization(&f).unwrap();
+/// let degrees: Vec<usize> = parts.iter().map(|(_, d)| *d).collect();
+/// assert_eq!(degrees, vec![1, 2]);
+/// ```
+pub fn distinct_degree_factorization(f: &PolyZp) -> FiniteFieldResult<Vec<(PolyZp, usize)>> {
+    if f.is_zero() {
+        return Err(FiniteFieldError::EmptyPolynomial);
+    }
+    let p = f.modulus();
+    let mut rest = f.make_monic()?;
+    let mut parts = Vec::new();
+    let x = PolyZp::x(p);
+    // x^(p^d) mod rest
+    let mut frobenius = x.clone();
+    let mut d = 0;
+
+    while rest.degree().is_some_and(|n| n >= 2 * (d + 1)) {
+        d += 1;
+        frobenius = pow_mod(&frobenius, p, &rest)?;
+        let g = rest.gcd(&frobenius.sub(&x))?;
+        if !g.is_constant() {
+            rest = rest.div_rem(&g)?.0;
+            frobenius = frobenius.div_rem(&rest)?.1;
+            parts.push((g.make_monic()?, d));
+        }
+    }
+    // What is left has no factor of degree below half its own: it is irreducible
+    if let Some(n) = rest.degree().filter(|&n| n > 0) {
+        parts.push((rest, n));
+    }
+    Ok(parts)
+}
+
+/// Split a product of distinct irreducible factors of degree `d` into those
+/// factors
+///
+/// # Arguments
+///
+/// * `f` - Monic square-free polynomial over Z_p, p odd, whose irreducible
+///   factors all have degree `d`
+/// * `d` - Degree of every irreducible factor
+/// * `rng` - Source of the random splitting polynomials
+///
+/// # Returns
+///
+/// The monic irreducible factors of `f`
+pub fn equal_degree_factorization<R: Rng + ?Sized>(
+    f: &PolyZp,
+    d: usize,
+    rng: &mut R,
+) -> FiniteFieldResult<Vec<PolyZp>> {
+    let n = f.degree().ok_or(FiniteFieldError::EmptyPolynomial)?;
+    if d == 0 || n % d != 0 {
+        return Err(FiniteFieldError::DegreeMismatch {
+            expected: d,
+            got: n,
+            operation: "equal-degree factorization",
+        });
+    }
+    if n == d {
+        return Ok(vec![f.make_monic()?]);
+    }
+
+    let p = f.modulus();
+    let one = PolyZp::constant(1, p);
+    loop {
+        let a = PolyZp::from_coeffs((0..n).map(|_| rng.random_range(0..p)).collect(), p);
+        if a.is_constant() {
+            continue;
+        }
+        let g = f.gcd(&a)?;
+        let split = if !g.is_constant() {
+            g
+        } else {
+            f.gcd(&half_power(&a, d, f)?.sub(&one))?
+        };
+        if split.is_constant() || split.degree() == f.degree() {
+            continue;
+        }
+
+        let (cofactor, _) = f.div_rem(&split)?;
+        let mut factors = equal_degree_factorization(&split.make_monic()?, d, rng)?;
+        factors.extend(equal_degree_factorization(&cofactor.make_monic()?, d, rng)?);
+        return Ok(factors);
+    }
+}
+
+/// Factor a square-free polynomial over `Z_p[x]` by Cantor–Zassenhaus
+///
+/// # Arguments
+///
+/// * `f` - Square-free polynomial over Z_p
+///
+/// # Returns
+///
+/// Monic irreducible factors, by increasing degree
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::algebra::PolyZp;
+/// use mathhook_core::core::polynomial::finite_field::cantor_zassenhaus::cantor_zassenhaus;
+///
+/// // x^4 - 1 = (x - 1)(x + 1)(x^2 + 1) over Z_7
+/// let f = PolyZp::from_signed_coeffs(&[-1, 0, 0, 0, 1], 7);
+/// let factors = cantor_zassenhaus(&f).unwrap();
+/// let degrees: Vec<_> = factors.iter().map(|g| g.degree()).collect();
+/// assert_eq!(degrees, vec![Some(1), Some(1), Some(2)]);
+/// ```
+pub fn cantor_zassenhaus(f: &PolyZp) -> FiniteFieldResult<Vec<PolyZp>> {
+    let n = f.degree().ok_or(FiniteFieldError::EmptyPolynomial)?;
+    if n <= 1 {
+        return Ok(vec![f.make_monic()?]);
+    }
+    if f.modulus() == 2 {
+        return berlekamp_factor(f);
+    }
+
+    let mut rng = StdRng::seed_from_u64(SPLITTING_SEED);
+    let mut factors = Vec::with_capacity(n);
+    for (part, d) in distinct_degree_factorization(f)? {
+        let mut split = equal_degree_factorization(&part, d, &mut rng)?;
+        split.sort_by(|a, b| a.coefficients().cmp(b.coefficients()));
+        factors.extend(split);
+    }
+    Ok(factors)
+}
+
+/// base^exp mod m by repeated squaring
+fn pow_mod(base: &PolyZp, mut exp: u64, m: &PolyZp) -> FiniteFieldResult<PolyZp> {
+    let mut result = PolyZp::constant(1, m.modulus()).div_rem(m)?.1;
+    let mut base = base.div_rem(m)?.1;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result.mul_fast(&base).div_rem(m)?.1;
+        }
+        exp >>= 1;
+        if exp > 0 {
+            base = base.mul_fast(&base).div_rem(m)?.1;
+        }
+    }
+    Ok(result)
+}
+
+/// a^((p^d - 1)/2) mod f
+fn half_power(a: &PolyZp, d: usize, f: &PolyZp) -> FiniteFieldResult<PolyZp> {
+    let p = f.modulus();
+    let mut conjugate = a.div_rem(f)?.1;
+    let mut norm = conjugate.clone();
+    for _ in 1..d {
+        conjugate = pow_mod(&conjugate, p, f)?;
+        norm = norm.mul_fast(&conjugate).div_rem(f)?.1;
+    }
+    pow_mod(&norm, (p - 1) / 2, f)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn product(factors: &[PolyZp], p: u64) -> PolyZp {
+        factors
+            .iter()
+            .fold(PolyZp::constant(1, p), |acc, factor| acc.mul(factor))
+    }
+
+    #[test]
+    fn test_distinct_degree_factorization() {
+        // (x - 1)(x - 2)(x^2 + 1)(x^3 + x + 1) over Z_7: x^3 + x + 1 has no roots mod 7
+        let p = 7;
+        let cubic = PolyZp::from_signed_coeffs(&[1, 1, 0, 1], p);
+        let f = product(
+            &[
+                PolyZp::from_signed_coeffs(&[-1, 1], p),
+                PolyZp::from_signed_coeffs(&[-2, 1], p),
+                PolyZp::from_signed_coeffs(&[1, 0, 1], p),
+                cubic.clone(),
+            ],
+            p,
+        );
+        let parts = distinct_degree_factorization(&f).unwrap();
+        let degrees: Vec<(Option<usize>, usize)> =
+            parts.iter().map(|(g, d)| (g.degree(), *d)).collect();
+        assert_eq!(degrees, vec![(Some(2), 1), (Some(2), 2), (Some(3), 3)]);
+        assert_eq!(parts[2].0, cubic);
+    }
+
+    #[test]
+    fn test_cantor_zassenhaus_matches_berlekamp() {
+        // Square-free: p does not divide the discriminant of any of these
+        for (coeffs, p) in [
+            (vec![-1, 0, 0, 0, 0, 0, 1], 11),
+            (vec![1, -1, 0, 0, 0, 1], 13),
+            (vec![-1, 0, 0, 0, 0, 0, 0, 0, 1], 17),
+        ] {
+            let f = PolyZp::from_signed_coeffs(&coeffs, p);
+            let factors = cantor_zassenhaus(&f).unwrap();
+            assert_eq!(product(&factors, p), f.make_monic().unwrap());
+
+            let mut ours: Vec<_> = factors.iter().map(|g| g.coefficients().to_vec()).collect();
+            let mut theirs: Vec<_> = berlekamp_factor(&f.make_monic().unwrap())
+                .unwrap()
+                .iter()
+                .map(|g| g.coefficients().to_vec())
+                .collect();
+            ours.sort();
+            theirs.sort();
+            assert_eq!(ours, theirs);
+        }
+    }
+
+    #[test]
+    fn test_irreducible_and_binary_field() {
+        // x^2 + 1 is irreducible mod 3
+        let f = PolyZp::from_signed_coeffs(&[1, 0, 1], 3);
+        assert_eq!(cantor_zassenhaus(&f).unwrap(), vec![f.clone()]);
+
+        // x^2 + x = x(x + 1) over Z_2
+        let g = PolyZp::from_signed_coeffs(&[0, 1, 1], 2);
+        assert_eq!(cantor_zassenhaus(&g).unwrap().len(), 2);
+    }
+}
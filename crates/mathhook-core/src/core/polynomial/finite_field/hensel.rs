@@ -0,0 +1,371 @@
+//! Hensel Lifting over Z
+//!
+//! Lifts a coprime factorization modulo a prime p to a factorization modulo p^k.
+//!
+//! # Mathematical Background
+//!
+//! Let f ∈ `Z[x]` with p ∤ lc(f) and suppose f ≡ lc(f)·g·h (mod p) where g and h are
+//! monic and coprime in `Z_p[x]`. Hensel's lemma guarantees unique monic G, H with
+//!
+//! - G ≡ g, H ≡ h (mod p)
+//! - f ≡ lc(f)·G·H (mod p^k)
+//!
+//! Each linear lifting step solves σ·g + τ·h ≡ e (mod p) for the error
+//! e = (f - G·H) / p^j using the Bézout coefficients s·g + t·h = 1 from `Z_p[x]`.
+//!
+//! Lifting is the bridge between factorization over `Z_p[x]` (Berlekamp) and
+//! factorization over `Z[x]` (Zassenhaus).
+//!
+//! # References
+//!
+//! - `[GCL92]` Geddes, Czapor, Labahn. "Algorithms for Computer Algebra", Chapter 6
+//! - `[vzGG13]` von zur Gathen, Gerhard. "Modern Computer Algebra", Section 15.4
+
+use super::poly::PolyZp;
+use super::{FiniteFieldError, FiniteFieldResult};
+use crate::core::polynomial::poly::IntPoly;
+
+/// Lift a two-factor coprime factorization from modulus p to modulus p^k
+///
+/// # Arguments
+///
+/// * `f` - Integer polynomial whose leading coefficient is not divisible by p
+/// * `g` - First factor modulo p (made monic if necessary)
+/// * `h` - Second factor modulo p (made monic if necessary), coprime to `g`
+/// * `k` - Target exponent; the result is correct modulo p^k
+///
+/// # Returns
+///
+/// Monic `(G, H)` with coefficients in the symmetric range of p^k such that
+/// f ≡ lc(f)·G·H (mod p^k), G ≡ g and H ≡ h (mod p).
+///
+/// # Errors
+///
+/// Returns `FiniteFieldError::HenselPrecondition` when f ≢ lc(f)·g·h (mod p), when
+/// p divides lc(f), or when g and h share a factor, and `FiniteFieldError::Overflow`
+/// when p^k does not fit in an `i64`.
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::algebra::{hensel_lift, IntPoly, PolyZp};
+///
+/// // x^2 - 2 ≡ (x - 3)(x + 3) (mod 7)
+/// let f = IntPoly::from_coeffs(vec![-2, 0, 1]);
+/// let g = PolyZp::from_signed_coeffs(&[-3, 1], 7);
+/// let h = PolyZp::from_signed_coeffs(&[3, 1], 7);
+///
+/// let (big_g, big_h) = hensel_lift(&f, &g, &h, 4).unwrap();
+/// let product = big_g.mul(&big_h);
+/// for (i, c) in product.coefficients().iter().enumerate() {
+///     assert_eq!((c - f.coeff(i)).rem_euclid(7_i64.pow(4)), 0);
+/// }
+/// ```
+pub fn hensel_lift(
+    f: &IntPoly,
+    g: &PolyZp,
+    h: &PolyZp,
+    k: u32,
+) -> FiniteFieldResult<(IntPoly, IntPoly)> {
+    let p = g.modulus();
+    if h.modulus() != p {
+        return Err(precondition("factors use different moduli"));
+    }
+    if k == 0 {
+        return Err(precondition("lifting exponent must be at least 1"));
+    }
+
+    let lc = f.leading_coeff();
+    if lc == 0 || lc.rem_euclid(p as i64) == 0 {
+        return Err(precondition("prime divides the leading coefficient"));
+    }
+    let pk = modulus_power(p, k)?;
+
+    let g_p = g.make_monic()?;
+    let h_p = h.make_monic()?;
+    let f_p = PolyZp::from_signed_coeffs(f.coefficients(), p);
+    let lc_p = PolyZp::from_signed_coeffs(&[lc], p);
+    if f_p.coefficients() != lc_p.mul(&g_p).mul(&h_p).coefficients() {
+        return Err(precondition("f is not congruent to lc(f)·g·h modulo p"));
+    }
+
+    // Lift g against h̃ = lc(f)·h so that G·H̃ matches f exactly in its leading term.
+    let h_tilde = lc_p.mul(&h_p);
+    let (d, s, t) = g_p.extended_gcd(&h_tilde)?;
+    if !d.is_constant() || d.is_zero() {
+        return Err(precondition("factors are not coprime modulo p"));
+    }
+
+    let f_coeffs: Vec<i128> = f.coefficients().iter().map(|&c| c as i128).collect();
+    let mut big_g = symmetric_coeffs(&g_p);
+    let mut big_h = symmetric_coeffs(&h_tilde);
+    let mut pj = p as i128;
+
+    for _ in 1..k {
+        let m = pj * p as i128;
+        let gh = mul_mod(&big_g, &big_h, m);
+
+        let len = f_coeffs.len().max(gh.len());
+        let error: Vec<u64> = (0..len)
+            .map(|i| {
+                let diff = f_coeffs.get(i).copied().unwrap_or(0) - gh.get(i).copied().unwrap_or(0);
+                (diff.rem_euclid(m) / pj) as u64
+            })
+            .collect();
+        let e = PolyZp::from_coeffs(error, p);
+        if e.is_zero() {
+            pj = m;
+            continue;
+        }
+
+        let (q, sigma) = s.mul(&e).div_rem(&h_tilde)?;
+        let tau = t.mul(&e).add(&q.mul(&g_p));
+
+        add_scaled(&mut big_g, &tau, pj, m);
+        add_scaled(&mut big_h, &sigma, pj, m);
+        pj = m;
+    }
+
+    let lc_inv = inverse_mod(lc as i128, pk).ok_or_else(|| FiniteFieldError::NoInverse {
+        element: lc.rem_euclid(p as i64) as u64,
+        modulus: p,
+    })?;
+    let monic_h: Vec<i128> = big_h.iter().map(|&c| c * lc_inv % pk).collect();
+
+    Ok((to_int_poly(&big_g, pk), to_int_poly(&monic_h, pk)))
+}
+
+/// Lift a multi-factor coprime factorization from modulus p to modulus p^k
+///
+/// Splits off one factor at a time and lifts it against the product of the rest.
+///
+/// # Arguments
+///
+/// * `f` - Integer polynomial whose leading coefficient is not divisible by p
+/// * `factors` - Pairwise coprime factors modulo p with f ≡ lc(f)·∏ factors (mod p)
+/// * `k` - Target exponent; the result is correct modulo p^k
+///
+/// # Returns
+///
+/// Monic lifted factors, in input order, with f ≡ lc(f)·∏ Gᵢ (mod p^k).
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::algebra::{hensel_lift_factors, IntPoly, PolyZp};
+///
+/// // x^3 - x = x(x - 1)(x + 1)
+/// let f = IntPoly::from_coeffs(vec![0, -1, 0, 1]);
+/// let factors = vec![
+///     PolyZp::from_signed_coeffs(&[0, 1], 5),
+///     PolyZp::from_signed_coeffs(&[-1, 1], 5),
+///     PolyZp::from_signed_coeffs(&[1, 1], 5),
+/// ];
+/// let lifted = hensel_lift_factors(&f, &factors, 3).unwrap();
+/// assert_eq!(lifted[1], IntPoly::from_coeffs(vec![-1, 1]));
+/// ```
+pub fn hensel_lift_factors(
+    f: &IntPoly,
+    factors: &[PolyZp],
+    k: u32,
+) -> FiniteFieldResult<Vec<IntPoly>> {
+    let (first, rest) = factors
+        .split_first()
+        .ok_or_else(|| precondition("no factors to lift"))?;
+
+    if rest.is_empty() {
+        let p = first.modulus();
+        let pk = modulus_power(p, k)?;
+        let lc = f.leading_coeff();
+        let lc_inv = inverse_mod(lc as i128, pk)
+            .ok_or_else(|| precondition("prime divides the leading coefficient"))?;
+        let monic: Vec<i128> = f
+            .coefficients()
+            .iter()
+            .map(|&c| c as i128 * lc_inv % pk)
+            .collect();
+        return Ok(vec![to_int_poly(&monic, pk)]);
+    }
+
+    let cofactor = rest[1..]
+        .iter()
+        .fold(rest[0].clone(), |acc, factor| acc.mul(factor));
+    let (lifted_first, lifted_cofactor) = hensel_lift(f, first, &cofactor, k)?;
+
+    let mut result = Vec::with_capacity(factors.len());
+    result.push(lifted_first);
+    result.extend(hensel_lift_factors(&lifted_cofactor, rest, k)?);
+    Ok(result)
+}
+
+fn precondition(reason: &str) -> FiniteFieldError {
+    FiniteFieldError::HenselPrecondition {
+        reason: reason.to_owned(),
+    }
+}
+
+fn modulus_power(p: u64, k: u32) -> FiniteFieldResult<i128> {
+    (p as i64)
+        .checked_pow(k)
+        .map(|pk| pk as i128)
+        .ok_or(FiniteFieldError::Overflow {
+            operation: "Hensel lifting modulus",
+        })
+}
+
+fn symmetric(c: i128, m: i128) -> i128 {
+    let r = c.rem_euclid(m);
+    if r > m / 2 {
+        r - m
+    } else {
+        r
+    }
+}
+
+fn symmetric_coeffs(poly: &PolyZp) -> Vec<i128> {
+    (0..poly.coefficients().len())
+        .map(|i| poly.coeff(i).to_symmetric() as i128)
+        .collect()
+}
+
+fn mul_mod(a: &[i128], b: &[i128], m: i128) -> Vec<i128> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let mut result = vec![0i128; a.len() + b.len() - 1];
+    for (i, &x) in a.iter().enumerate() {
+        for (j, &y) in b.iter().enumerate() {
+            result[i + j] = (result[i + j] + x * y % m) % m;
+        }
+    }
+    result
+}
+
+fn add_scaled(target: &mut Vec<i128>, correction: &PolyZp, scale: i128, m: i128) {
+    let correction = symmetric_coeffs(correction);
+    if target.len() < correction.len() {
+        target.resize(correction.len(), 0);
+    }
+    for (c, delta) in target.iter_mut().zip(correction) {
+        *c = symmetric(*c + delta * scale, m);
+    }
+}
+
+fn inverse_mod(a: i128, m: i128) -> Option<i128> {
+    let (mut old_r, mut r) = (a.rem_euclid(m), m);
+    let (mut old_s, mut s) = (1i128, 0i128);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_s, s) = (s, old_s - q * s);
+    }
+    (old_r == 1).then(|| old_s.rem_euclid(m))
+}
+
+fn to_int_poly(coeffs: &[i128], m: i128) -> IntPoly {
+    IntPoly::from_coeffs(coeffs.iter().map(|&c| symmetric(c, m) as i64).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_congruent(lhs: &IntPoly, rhs: &IntPoly, m: i64) {
+        let diff = lhs.sub(rhs);
+        for c in diff.coefficients() {
+            assert_eq!(c.rem_euclid(m), 0, "{:?} ≢ {:?} (mod {})", lhs, rhs, m);
+        }
+    }
+
+    #[test]
+    fn test_hensel_lift_quadratic() {
+        let f = IntPoly::from_coeffs(vec![-2, 0, 1]);
+        let g = PolyZp::from_signed_coeffs(&[-3, 1], 7);
+        let h = PolyZp::from_signed_coeffs(&[3, 1], 7);
+
+        let (big_g, big_h) = hensel_lift(&f, &g, &h, 5).unwrap();
+
+        assert!(big_g.is_monic());
+        assert!(big_h.is_monic());
+        assert_congruent(&big_g.mul(&big_h), &f, 7_i64.pow(5));
+        assert_congruent(&big_g, &IntPoly::from_coeffs(vec![-3, 1]), 7);
+    }
+
+    #[test]
+    fn test_hensel_lift_non_monic() {
+        // 6x^2 + 5x + 1 = (2x + 1)(3x + 1)
+        let f = IntPoly::from_coeffs(vec![1, 5, 6]);
+        let p = 7;
+        let g = PolyZp::from_signed_coeffs(&[1, 2], p);
+        let h = PolyZp::from_signed_coeffs(&[1, 3], p);
+
+        let (big_g, big_h) = hensel_lift(&f, &g, &h, 6).unwrap();
+        let product = big_g.mul(&big_h).scale(&6);
+
+        assert_congruent(&product, &f, 7_i64.pow(6));
+    }
+
+    #[test]
+    fn test_hensel_lift_exact_factor_recovered() {
+        let f = IntPoly::from_coeffs(vec![-6, 1, 1]); // (x - 2)(x + 3)
+        let g = PolyZp::from_signed_coeffs(&[-2, 1], 11);
+        let h = PolyZp::from_signed_coeffs(&[3, 1], 11);
+
+        let (big_g, big_h) = hensel_lift(&f, &g, &h, 3).unwrap();
+
+        assert_eq!(big_g, IntPoly::from_coeffs(vec![-2, 1]));
+        assert_eq!(big_h, IntPoly::from_coeffs(vec![3, 1]));
+    }
+
+    #[test]
+    fn test_hensel_lift_rejects_bad_input() {
+        let f = IntPoly::from_coeffs(vec![-2, 0, 1]);
+        let g = PolyZp::from_signed_coeffs(&[-1, 1], 7);
+        let h = PolyZp::from_signed_coeffs(&[1, 1], 7);
+        assert!(matches!(
+            hensel_lift(&f, &g, &h, 3),
+            Err(FiniteFieldError::HenselPrecondition { .. })
+        ));
+
+        let square = IntPoly::from_coeffs(vec![1, 2, 1]);
+        let root = PolyZp::from_signed_coeffs(&[1, 1], 7);
+        assert!(matches!(
+            hensel_lift(&square, &root, &root, 3),
+            Err(FiniteFieldError::HenselPrecondition { .. })
+        ));
+    }
+
+    #[test]
+    fn test_hensel_lift_overflow() {
+        let f = IntPoly::from_coeffs(vec![-6, 1, 1]);
+        let g = PolyZp::from_signed_coeffs(&[-2, 1], 11);
+        let h = PolyZp::from_signed_coeffs(&[3, 1], 11);
+        assert!(matches!(
+            hensel_lift(&f, &g, &h, 40),
+            Err(FiniteFieldError::Overflow { .. })
+        ));
+    }
+
+    #[test]
+    fn test_hensel_lift_factors_three_way() {
+        // x^4 - 1 ≡ (x - 1)(x + 1)(x^2 + 1) over Z, and x^2 + 1 = (x - 2)(x + 2) mod 5
+        let f = IntPoly::from_coeffs(vec![-1, 0, 0, 0, 1]);
+        let factors = vec![
+            PolyZp::from_signed_coeffs(&[-1, 1], 5),
+            PolyZp::from_signed_coeffs(&[1, 1], 5),
+            PolyZp::from_signed_coeffs(&[-2, 1], 5),
+            PolyZp::from_signed_coeffs(&[2, 1], 5),
+        ];
+
+        let lifted = hensel_lift_factors(&f, &factors, 4).unwrap();
+        assert_eq!(lifted.len(), 4);
+
+        let product = lifted
+            .iter()
+            .skip(1)
+            .fold(lifted[0].clone(), |acc, factor| acc.mul(factor));
+        assert_congruent(&product, &f, 5_i64.pow(4));
+        assert_eq!(lifted[0], IntPoly::from_coeffs(vec![-1, 1]));
+        assert_eq!(lifted[1], IntPoly::from_coeffs(vec![1, 1]));
+    }
+}
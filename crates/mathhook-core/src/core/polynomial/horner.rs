@@ -0,0 +1,244 @@
+//! Horner Form and Fast Polynomial Evaluation
+//!
+//! Rewrites `cₙxⁿ + ... + c₁x + c₀` as `c₀ + x(c₁ + x(c₂ + ... + x·cₙ))`,
+//! which evaluates with `n` multiplications instead of computing every power.
+//! Missing degrees are skipped with a single power of the gap, so sparse
+//! polynomials such as `x¹⁰⁰ + 1` stay cheap. Coefficients that are
+//! polynomials in other variables are nested in turn (multivariate Horner).
+//!
+//! Compiled expressions (`Expression::compile`) detect polynomial sums and
+//! evaluate them through the same scheme automatically.
+
+use crate::algebra::Expand;
+use crate::core::{Expression, Number, Symbol};
+use std::collections::BTreeMap;
+use std::slice;
+
+impl Expression {
+    /// Rewrite a polynomial in `variable` in nested Horner form
+    ///
+    /// The expression is expanded first. Coefficients involving other
+    /// variables are rewritten in Horner form too, in the alphabetical order
+    /// of their variables. Anything that is not a polynomial in `variable` is
+    /// returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::{expr, symbol, Expression};
+    ///
+    /// let x = symbol!(x);
+    /// let poly = expr!((2 * (x ^ 3)) + (3 * (x ^ 2)) + x + 5);
+    ///
+    /// // 5 + x(1 + x(3 + 2x))
+    /// let nested = Expression::add(vec![
+    ///     expr!(5),
+    ///     Expression::mul(vec![
+    ///         expr!(x),
+    ///         Expression::add(vec![
+    ///             expr!(1),
+    ///             Expression::mul(vec![expr!(x), expr!(3 + (2 * x))]),
+    ///         ]),
+    ///     ]),
+    /// ]);
+    /// assert_eq!(poly.horner(&x), nested);
+    /// ```
+    pub fn horner(&self, variable: &Symbol) -> Expression {
+        let expanded = self.expand();
+        let Some(terms) = polynomial_terms(&expanded, variable) else {
+            return self.clone();
+        };
+
+        let x = Expression::symbol(variable.clone());
+        let power = |degree: u32| match degree {
+            1 => x.clone(),
+            _ => Expression::pow(x.clone(), Expression::integer(degree as i64)),
+        };
+
+        let mut terms = terms.into_iter();
+        let (mut degree, leading) = terms.next().unwrap_or((0, Expression::integer(0)));
+        let mut nested = horner_coefficient(&leading);
+        for (lower, coefficient) in terms {
+            nested = Expression::add(vec![
+                horner_coefficient(&coefficient),
+                Expression::mul(vec![power(degree - lower), nested]),
+            ]);
+            degree = lower;
+        }
+        if degree > 0 {
+            nested = Expression::mul(vec![power(degree), nested]);
+        }
+        nested
+    }
+}
+
+/// Evaluate a polynomial with numeric coefficients at `x` by Horner's scheme
+///
+/// Returns `None` if `expr` is not a sum of monomials `c·varᵏ` with numeric
+/// coefficients. The expression is not expanded, so factored forms are
+/// rejected rather than silently expanded on every call.
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::core::polynomial::eval_polynomial_fast;
+/// use mathhook_core::{expr, symbol};
+///
+/// let x = symbol!(x);
+/// let poly = expr!((3 * (x ^ 4)) - (2 * x) + 7);
+/// assert_eq!(eval_polynomial_fast(&poly, &x, 2.0), Some(51.0));
+/// assert_eq!(eval_polynomial_fast(&expr!(sin(x)), &x, 2.0), None);
+/// ```
+pub fn eval_polynomial_fast(expr: &Expression, var: &Symbol, x: f64) -> Option<f64> {
+    let terms = polynomial_terms(expr, var)?
+        .into_iter()
+        .map(|(degree, coefficient)| Some((degree, numeric_value(&coefficient)?)))
+        .collect::<Option<Vec<_>>>()?;
+    Some(sparse_horner(terms, x))
+}
+
+/// `(degree, coefficient)` pairs of a polynomial in `var`, highest degree first
+///
+/// Like terms are merged. Returns `None` when a term has a negative or
+/// symbolic power of `var`, or `var` appears anywhere else (inside a
+/// function, a base other than `var`, or a nested sum).
+pub(crate) fn polynomial_terms(expr: &Expression, var: &Symbol) -> Option<Vec<(u32, Expression)>> {
+    let terms = match expr {
+        Expression::Add(terms) => terms.as_slice(),
+        other => slice::from_ref(other),
+    };
+
+    let mut by_degree: BTreeMap<u32, Vec<Expression>> = BTreeMap::new();
+    for term in terms {
+        let (degree, coefficient) = monomial(term, var)?;
+        by_degree.entry(degree).or_default().push(coefficient);
+    }
+    Some(
+        by_degree
+            .into_iter()
+            .rev()
+            .map(|(degree, mut coefficients)| {
+                let coefficient = match coefficients.len() {
+                    1 => coefficients.remove(0),
+                    _ => Expression::add(coefficients),
+                };
+                (degree, coefficient)
+            })
+            .collect(),
+    )
+}
+
+/// Evaluate sparse terms given highest degree first
+pub(crate) fn sparse_horner(terms: impl IntoIterator<Item = (u32, f64)>, x: f64) -> f64 {
+    let mut terms = terms.into_iter();
+    let Some((mut degree, mut value)) = terms.next() else {
+        return 0.0;
+    };
+    for (lower, coefficient) in terms {
+        value = value * x.powi((degree - lower) as i32) + coefficient;
+        degree = lower;
+    }
+    value * x.powi(degree as i32)
+}
+
+fn monomial(term: &Expression, var: &Symbol) -> Option<(u32, Expression)> {
+    let factors = match term {
+        Expression::Mul(factors) => factors.as_slice(),
+        other => slice::from_ref(other),
+    };
+
+    let mut degree = 0u32;
+    let mut coefficient = Vec::new();
+    for factor in factors {
+        match factor {
+            Expression::Symbol(s) if s == var => degree += 1,
+            Expression::Pow(base, exp) if matches!(base.as_ref(), Expression::Symbol(s) if s == var) => {
+                match exp.as_ref() {
+                    Expression::Number(Number::Integer(n)) => degree += u32::try_from(*n).ok()?,
+                    _ => return None,
+                }
+            }
+            other if other.contains_variable(var) => return None,
+            other => coefficient.push(other.clone()),
+        }
+    }
+
+    let coefficient = match coefficient.len() {
+        0 => Expression::integer(1),
+        1 => coefficient.remove(0),
+        _ => Expression::mul(coefficient),
+    };
+    Some((degree, coefficient))
+}
+
+/// Nest a coefficient in the first of its variables it is a polynomial in
+fn horner_coefficient(coefficient: &Expression) -> Expression {
+    let mut variables = coefficient.find_variables();
+    variables.sort_by(|a, b| a.name().cmp(b.name()));
+    variables
+        .iter()
+        .find(|v| polynomial_terms(coefficient, v).is_some_and(|terms| terms.len() > 1))
+        .map(|v| coefficient.horner(v))
+        .unwrap_or_else(|| coefficient.clone())
+}
+
+fn numeric_value(expr: &Expression) -> Option<f64> {
+    match expr {
+        Expression::Constant(constant) => Some(constant.to_f64()),
+        _ => Expression::try_extract_numeric_value(expr),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{expr, symbol};
+    use std::collections::HashMap;
+
+    fn value_at(expr: &Expression, values: &[(&str, f64)]) -> f64 {
+        let substitutions: HashMap<String, Expression> = values
+            .iter()
+            .map(|(name, value)| (name.to_string(), Expression::float(*value)))
+            .collect();
+        expr.substitute(&substitutions).evaluate_to_f64().unwrap()
+    }
+
+    #[test]
+    fn test_sparse_horner_matches_direct_evaluation() {
+        let x = symbol!(x);
+        let poly = expr!((x ^ 10) - (4 * (x ^ 3)) + (x ^ 2));
+        let nested = poly.horner(&x);
+        assert_eq!(
+            nested,
+            Expression::mul(vec![
+                expr!(x ^ 2),
+                Expression::add(vec![
+                    expr!(1),
+                    Expression::mul(vec![expr!(x), expr!(-4 + (x ^ 7))]),
+                ]),
+            ])
+        );
+        for value in [-1.5, 0.5, 2.0] {
+            let direct = value_at(&poly, &[("x", value)]);
+            assert!((value_at(&nested, &[("x", value)]) - direct).abs() < 1e-9);
+            assert!((eval_polynomial_fast(&poly, &x, value).unwrap() - direct).abs() < 1e-9);
+        }
+
+        assert_eq!(expr!(sin(x) + x).horner(&x), expr!(sin(x) + x));
+        assert_eq!(eval_polynomial_fast(&expr!((x + 1) ^ 2), &x, 1.0), None);
+    }
+
+    #[test]
+    fn test_multivariate_horner() {
+        let x = symbol!(x);
+        let poly = expr!(((x ^ 2) * (y ^ 2)) + ((x ^ 2) * y) + (x * y) + (y ^ 3) + 2);
+        let nested = poly.horner(&x);
+        assert_ne!(nested, poly);
+        assert_eq!(
+            polynomial_terms(&poly, &x).map(|terms| terms.len()),
+            Some(3)
+        );
+        let point = [("x", 1.25), ("y", -0.75)];
+        assert!((value_at(&nested, &point) - value_at(&poly, &point)).abs() < 1e-9);
+    }
+}
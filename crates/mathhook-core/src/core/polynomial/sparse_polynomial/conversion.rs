@@ -119,6 +119,13 @@ fn extract_monomial_and_coeff(
             Expression::Number(Number::Rational(r)) => {
                 coeff *= &**r;
             }
+            Expression::Mul(inner) => {
+                let (monomial, inner_coeff) = extract_monomial_and_coeff(inner, variables)?;
+                for (exp, inner_exp) in exponents.iter_mut().zip(monomial.exponents) {
+                    *exp += inner_exp;
+                }
+                coeff *= inner_coeff;
+            }
             Expression::Symbol(s) => {
                 if let Some(idx) = variables.iter().position(|v| v == s) {
                     exponents[idx] += 1;
@@ -0,0 +1,128 @@
+//! Which algorithm produced a result, and how long it took
+//!
+//! Solvers and the integrator try several algorithms in turn. Attaching the
+//! one that succeeded lets users audit an answer, compare it with another
+//! system's method, and name the failing component in a bug report.
+
+use crate::calculus::integrals::IntegrationStrategy;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::time::Duration;
+
+/// Algorithm that produced a result
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AlgorithmId {
+    /// Isolating the variable in a linear equation
+    LinearIsolation,
+    /// Rational roots found by factoring a quadratic
+    QuadraticFactoring,
+    /// `a (x - h)² + k = 0`
+    CompletingSquare,
+    /// `x = (-b ± √(b² - 4ac)) / (2a)`
+    QuadraticFormula,
+    /// Rational root search and the cubic and quartic formulas
+    PolynomialRoots,
+    /// Elimination and substitution over a system
+    SystemElimination,
+    /// Left and right division by matrices
+    MatrixDivision,
+    /// Integer bounds of floor and ceiling
+    RoundingBounds,
+    /// Case split on the argument min or max selects
+    MinMaxCases,
+    /// Separable, linear and exact first-order ODE methods
+    OdeMethods,
+    /// Characteristics and separation of variables
+    PdeMethods,
+    /// Numerical root finding after symbolic methods give up
+    NumericFallback,
+    /// Periodic families from the inverse sine, cosine or tangent
+    InverseTrigonometric,
+    /// Exact match in the integral table
+    TableLookup,
+    /// Sifting by `δ(x - a)` or cut-off by `H(x - a)`
+    Distribution,
+    /// Partial fraction decomposition
+    PartialFractions,
+    /// Antiderivative registered for a known function
+    FunctionRegistry,
+    IntegrationByParts,
+    Substitution,
+    TrigSubstitution,
+    /// Tangent half-angle substitution
+    WeierstrassSubstitution,
+    /// Trigonometric identities and power reduction
+    TrigonometricIdentities,
+    /// Risch decision procedure for elementary functions
+    Risch,
+    /// Power rule, linearity and constants
+    BasicRules,
+    /// No algorithm succeeded, so the result is unevaluated or empty
+    Unevaluated,
+}
+
+impl AlgorithmId {
+    /// Stable name for reports
+    pub fn name(&self) -> &'static str {
+        match self {
+            AlgorithmId::LinearIsolation => "linear_isolation",
+            AlgorithmId::QuadraticFactoring => "quadratic_factoring",
+            AlgorithmId::CompletingSquare => "completing_square",
+            AlgorithmId::QuadraticFormula => "quadratic_formula",
+            AlgorithmId::PolynomialRoots => "polynomial_roots",
+            AlgorithmId::SystemElimination => "system_elimination",
+            AlgorithmId::MatrixDivision => "matrix_division",
+            AlgorithmId::RoundingBounds => "rounding_bounds",
+            AlgorithmId::MinMaxCases => "min_max_cases",
+            AlgorithmId::OdeMethods => "ode_methods",
+            AlgorithmId::PdeMethods => "pde_methods",
+            AlgorithmId::NumericFallback => "numeric_fallback",
+            AlgorithmId::InverseTrigonometric => "inverse_trigonometric",
+            AlgorithmId::TableLookup => "table_lookup",
+            AlgorithmId::Distribution => "distribution",
+            AlgorithmId::PartialFractions => "partial_fractions",
+            AlgorithmId::FunctionRegistry => "function_registry",
+            AlgorithmId::IntegrationByParts => "integration_by_parts",
+            AlgorithmId::Substitution => "substitution",
+            AlgorithmId::TrigSubstitution => "trig_substitution",
+            AlgorithmId::WeierstrassSubstitution => "weierstrass_substitution",
+            AlgorithmId::TrigonometricIdentities => "trigonometric_identities",
+            AlgorithmId::Risch => "risch",
+            AlgorithmId::BasicRules => "basic_rules",
+            AlgorithmId::Unevaluated => "unevaluated",
+        }
+    }
+}
+
+impl fmt::Display for AlgorithmId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl From<IntegrationStrategy> for AlgorithmId {
+    fn from(strategy: IntegrationStrategy) -> Self {
+        match strategy {
+            IntegrationStrategy::TableLookup => AlgorithmId::TableLookup,
+            IntegrationStrategy::Distribution => AlgorithmId::Distribution,
+            IntegrationStrategy::RationalFunction => AlgorithmId::PartialFractions,
+            IntegrationStrategy::FunctionRegistry => AlgorithmId::FunctionRegistry,
+            IntegrationStrategy::IntegrationByParts => AlgorithmId::IntegrationByParts,
+            IntegrationStrategy::Substitution => AlgorithmId::Substitution,
+            IntegrationStrategy::TrigSubstitution => AlgorithmId::TrigSubstitution,
+            IntegrationStrategy::WeierstrassSubstitution => AlgorithmId::WeierstrassSubstitution,
+            IntegrationStrategy::Trigonometric => AlgorithmId::TrigonometricIdentities,
+            IntegrationStrategy::Risch => AlgorithmId::Risch,
+            IntegrationStrategy::BasicRules => AlgorithmId::BasicRules,
+        }
+    }
+}
+
+/// A result together with the algorithm that produced it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Provenance<T> {
+    pub result: T,
+    pub algorithm: AlgorithmId,
+    /// Wall-clock time spent producing the result
+    pub elapsed: Duration,
+}
@@ -1,5 +1,12 @@
 //! Symbol type for variables and identifiers
 
+pub mod assumptions;
+pub mod context;
+pub mod names;
+
+pub use assumptions::{Assumption, SymbolAssumptions};
+pub use context::SymbolContext;
+
 use crate::core::commutativity::Commutativity;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::collections::HashMap;
@@ -45,6 +52,9 @@ static SYMBOL_CACHE: Mutex<Option<HashMap<String, Arc<str>>>> = Mutex::new(None)
 pub struct Symbol {
     pub name: Arc<str>,
     symbol_type: SymbolType,
+    assumptions: SymbolAssumptions,
+    /// [`SymbolContext`] the symbol belongs to; 0 for the global context
+    context: u32,
 }
 
 impl Symbol {
@@ -102,6 +112,8 @@ impl Symbol {
         Self {
             name: interned_name,
             symbol_type: SymbolType::Scalar,
+            assumptions: SymbolAssumptions::default(),
+            context: 0,
         }
     }
 
@@ -121,6 +133,8 @@ impl Symbol {
         Self {
             name: Self::intern_symbol(name_str),
             symbol_type: SymbolType::Matrix,
+            assumptions: SymbolAssumptions::default(),
+            context: 0,
         }
     }
 
@@ -140,6 +154,8 @@ impl Symbol {
         Self {
             name: Self::intern_symbol(name_str),
             symbol_type: SymbolType::Operator,
+            assumptions: SymbolAssumptions::default(),
+            context: 0,
         }
     }
 
@@ -159,6 +175,8 @@ impl Symbol {
         Self {
             name: Self::intern_symbol(name_str),
             symbol_type: SymbolType::Quaternion,
+            assumptions: SymbolAssumptions::default(),
+            context: 0,
         }
     }
 
@@ -211,6 +229,62 @@ impl Symbol {
         self.symbol_type
     }
 
+    /// Attach assumptions to this symbol
+    ///
+    /// Symbols with different assumptions are different symbols, as in
+    /// SymPy: `x` declared positive is not equal to a plain `x`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mathhook_core::core::symbol::{Symbol, SymbolAssumptions};
+    ///
+    /// let x = Symbol::scalar("x").with_assumptions(SymbolAssumptions::new().positive());
+    /// assert!(x.assumptions().is_nonzero());
+    /// assert_ne!(x, Symbol::scalar("x"));
+    /// ```
+    pub fn with_assumptions(mut self, assumptions: SymbolAssumptions) -> Self {
+        self.assumptions = assumptions;
+        self
+    }
+
+    /// Create a scalar symbol carrying the given assumptions
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mathhook_core::core::symbol::{Assumption, Symbol};
+    ///
+    /// let n = Symbol::new_with_assumptions("n", &[Assumption::Integer, Assumption::Nonzero]);
+    /// assert!(n.assumptions().is_real());
+    /// assert!(!n.assumptions().is_positive());
+    /// ```
+    pub fn new_with_assumptions(name: &str, assumptions: &[Assumption]) -> Self {
+        Self::scalar(name).with_assumptions(assumptions.iter().copied().collect())
+    }
+
+    /// Get the assumptions declared for this symbol
+    #[inline]
+    pub fn assumptions(&self) -> SymbolAssumptions {
+        self.assumptions
+    }
+
+    /// Identifier of the [`SymbolContext`] this symbol belongs to
+    ///
+    /// Symbols created outside any context share the global context `0`.
+    /// Symbols from different contexts are never equal, even when their
+    /// names match.
+    #[inline]
+    pub fn context_id(&self) -> u32 {
+        self.context
+    }
+
+    /// Move this symbol into the context with the given identifier
+    pub(crate) fn in_context(mut self, context: u32) -> Self {
+        self.context = context;
+        self
+    }
+
     /// Get commutativity of this symbol
     ///
     /// # Examples
@@ -242,9 +316,14 @@ impl Serialize for Symbol {
         S: Serializer,
     {
         use serde::ser::SerializeStruct;
-        let mut state = serializer.serialize_struct("Symbol", 2)?;
+        let mut state = serializer.serialize_struct("Symbol", 3)?;
         state.serialize_field("name", &*self.name)?;
         state.serialize_field("symbol_type", &self.symbol_type)?;
+        if self.assumptions.is_empty() {
+            state.skip_field("assumptions")?;
+        } else {
+            state.serialize_field("assumptions", &self.assumptions)?;
+        }
         state.end()
     }
 }
@@ -278,6 +357,7 @@ impl<'de> Deserialize<'de> for Symbol {
             {
                 let mut name: Option<String> = None;
                 let mut symbol_type: Option<SymbolType> = None;
+                let mut assumptions: Option<SymbolAssumptions> = None;
 
                 while let Some(key) = map.next_key::<String>()? {
                     match key.as_str() {
@@ -287,6 +367,9 @@ impl<'de> Deserialize<'de> for Symbol {
                         "symbol_type" => {
                             symbol_type = Some(map.next_value()?);
                         }
+                        "assumptions" => {
+                            assumptions = Some(map.next_value()?);
+                        }
                         _ => {
                             let _: serde::de::IgnoredAny = map.next_value()?;
                         }
@@ -300,6 +383,8 @@ impl<'de> Deserialize<'de> for Symbol {
                 Ok(Symbol {
                     name: interned_name,
                     symbol_type,
+                    assumptions: assumptions.unwrap_or_default(),
+                    context: 0,
                 })
             }
         }
@@ -363,4 +448,15 @@ mod symbol_type_tests {
         assert_eq!(x.symbol_type(), SymbolType::Scalar);
         assert_eq!(x.commutativity(), Commutativity::Commutative);
     }
+
+    #[test]
+    fn test_assumptions_serde_roundtrip() {
+        let x = Symbol::scalar("x").with_assumptions(SymbolAssumptions::new().positive());
+        let json = serde_json::to_string(&x).unwrap();
+        assert!(json.contains("assumptions"));
+        assert_eq!(serde_json::from_str::<Symbol>(&json).unwrap(), x);
+
+        let plain = serde_json::to_string(&Symbol::scalar("y")).unwrap();
+        assert!(!plain.contains("assumptions"));
+    }
 }
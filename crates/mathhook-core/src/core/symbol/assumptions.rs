@@ -0,0 +1,300 @@
+//! Facts declared about a symbol when it is created
+//!
+//! A symbol created with `positive`, `integer` and similar flags carries them
+//! with it, so every expression built from it can use them: the solver skips
+//! the `a = 0` case for a nonzero coefficient, and
+//! [`Expression::assumptions`](crate::Expression::assumptions) reports what
+//! follows for a whole expression.
+//!
+//! Flags are closed under the obvious implications when set: `positive` adds
+//! `nonnegative`, `nonzero` and `real`, and `nonnegative` together with
+//! `nonzero` is `positive`.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A single fact that can be declared about a symbol
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::core::symbol::{Assumption, Symbol};
+///
+/// let x = Symbol::new_with_assumptions("x", &[Assumption::Positive, Assumption::Real]);
+/// assert!(x.assumptions().is_nonnegative());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Assumption {
+    Positive,
+    Nonnegative,
+    Nonzero,
+    Integer,
+    Real,
+}
+
+impl Assumption {
+    const ALL: [Assumption; 5] = [
+        Assumption::Positive,
+        Assumption::Nonnegative,
+        Assumption::Nonzero,
+        Assumption::Integer,
+        Assumption::Real,
+    ];
+
+    /// Lowercase name, as accepted by [`SymbolAssumptions::with_flag`]
+    pub fn name(self) -> &'static str {
+        match self {
+            Assumption::Positive => "positive",
+            Assumption::Nonnegative => "nonnegative",
+            Assumption::Nonzero => "nonzero",
+            Assumption::Integer => "integer",
+            Assumption::Real => "real",
+        }
+    }
+
+    /// Bit of this flag in [`SymbolAssumptions`]
+    fn bit(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+impl fmt::Display for Assumption {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Assumptions on the value of a symbol or expression
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::core::symbol::SymbolAssumptions;
+///
+/// let facts = SymbolAssumptions::new().positive();
+/// assert!(facts.is_nonzero());
+/// assert!(facts.is_real());
+/// assert!(!facts.is_integer());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(from = "AssumptionFlags", into = "AssumptionFlags")]
+pub struct SymbolAssumptions {
+    /// One bit per [`Assumption`], so a [`Symbol`](super::Symbol) stays small
+    flags: u8,
+}
+
+/// Serialized form of [`SymbolAssumptions`], one field per flag
+#[derive(Serialize, Deserialize)]
+struct AssumptionFlags {
+    #[serde(default)]
+    positive: bool,
+    #[serde(default)]
+    nonnegative: bool,
+    #[serde(default)]
+    nonzero: bool,
+    #[serde(default)]
+    integer: bool,
+    #[serde(default)]
+    real: bool,
+}
+
+impl From<AssumptionFlags> for SymbolAssumptions {
+    fn from(fields: AssumptionFlags) -> Self {
+        let mut facts = Self::new();
+        for (assumption, set) in [
+            (Assumption::Positive, fields.positive),
+            (Assumption::Nonnegative, fields.nonnegative),
+            (Assumption::Nonzero, fields.nonzero),
+            (Assumption::Integer, fields.integer),
+            (Assumption::Real, fields.real),
+        ] {
+            facts.set(assumption, set);
+        }
+        facts
+    }
+}
+
+impl From<SymbolAssumptions> for AssumptionFlags {
+    fn from(facts: SymbolAssumptions) -> Self {
+        Self {
+            positive: facts.is_positive(),
+            nonnegative: facts.is_nonnegative(),
+            nonzero: facts.is_nonzero(),
+            integer: facts.is_integer(),
+            real: facts.is_real(),
+        }
+    }
+}
+
+impl SymbolAssumptions {
+    /// No assumptions: the value may be any complex number
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Assume the value is real and greater than zero
+    pub fn positive(mut self) -> Self {
+        self.set(Assumption::Positive, true);
+        self.closed()
+    }
+
+    /// Assume the value is real and at least zero
+    pub fn nonnegative(mut self) -> Self {
+        self.set(Assumption::Nonnegative, true);
+        self.closed()
+    }
+
+    /// Assume the value is not zero
+    pub fn nonzero(mut self) -> Self {
+        self.set(Assumption::Nonzero, true);
+        self.closed()
+    }
+
+    /// Assume the value is an integer
+    pub fn integer(mut self) -> Self {
+        self.set(Assumption::Integer, true);
+        self.closed()
+    }
+
+    /// Assume the value is real
+    pub fn real(mut self) -> Self {
+        self.set(Assumption::Real, true);
+        self.closed()
+    }
+
+    /// Add a single assumption
+    pub fn with(self, assumption: Assumption) -> Self {
+        match assumption {
+            Assumption::Positive => self.positive(),
+            Assumption::Nonnegative => self.nonnegative(),
+            Assumption::Nonzero => self.nonzero(),
+            Assumption::Integer => self.integer(),
+            Assumption::Real => self.real(),
+        }
+    }
+
+    /// Apply the implications between flags
+    fn closed(mut self) -> Self {
+        if self.is_positive() {
+            self.set(Assumption::Nonnegative, true);
+            self.set(Assumption::Nonzero, true);
+        }
+        if self.is_nonnegative() && self.is_nonzero() {
+            self.set(Assumption::Positive, true);
+        }
+        if self.is_positive() || self.is_nonnegative() || self.is_integer() {
+            self.set(Assumption::Real, true);
+        }
+        self
+    }
+
+    fn has(&self, assumption: Assumption) -> bool {
+        self.flags & assumption.bit() != 0
+    }
+
+    fn set(&mut self, assumption: Assumption, set: bool) {
+        if set {
+            self.flags |= assumption.bit();
+        } else {
+            self.flags &= !assumption.bit();
+        }
+    }
+
+    pub fn is_positive(&self) -> bool {
+        self.has(Assumption::Positive)
+    }
+
+    pub fn is_nonnegative(&self) -> bool {
+        self.has(Assumption::Nonnegative)
+    }
+
+    pub fn is_nonzero(&self) -> bool {
+        self.has(Assumption::Nonzero)
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.has(Assumption::Integer)
+    }
+
+    pub fn is_real(&self) -> bool {
+        self.has(Assumption::Real)
+    }
+
+    /// Whether no flag is set
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Names of the flags that are set, in declaration order
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::core::symbol::SymbolAssumptions;
+    ///
+    /// let facts = SymbolAssumptions::new().integer();
+    /// assert_eq!(facts.names(), vec!["integer", "real"]);
+    /// ```
+    pub fn names(&self) -> Vec<&'static str> {
+        Assumption::ALL
+            .into_iter()
+            .filter(|assumption| self.has(*assumption))
+            .map(Assumption::name)
+            .collect()
+    }
+
+    /// Set a flag by name
+    ///
+    /// # Errors
+    ///
+    /// Returns the unknown name if it is not one of `positive`, `nonnegative`,
+    /// `nonzero`, `integer` or `real`.
+    pub fn with_flag(self, name: &str) -> Result<Self, String> {
+        Assumption::ALL
+            .into_iter()
+            .find(|assumption| assumption.name() == name)
+            .map(|assumption| self.with(assumption))
+            .ok_or_else(|| name.to_owned())
+    }
+}
+
+impl FromIterator<Assumption> for SymbolAssumptions {
+    fn from_iter<I: IntoIterator<Item = Assumption>>(iter: I) -> Self {
+        iter.into_iter().fold(Self::new(), Self::with)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_implications() {
+        let facts = SymbolAssumptions::new().nonnegative().nonzero();
+        assert!(facts.is_positive());
+        assert_eq!(
+            facts.names(),
+            vec!["positive", "nonnegative", "nonzero", "real"]
+        );
+        assert!(SymbolAssumptions::new().nonzero().names() == vec!["nonzero"]);
+    }
+
+    #[test]
+    fn test_with_flag() {
+        let facts = SymbolAssumptions::new().with_flag("integer").unwrap();
+        assert!(facts.is_integer() && facts.is_real());
+        assert_eq!(
+            SymbolAssumptions::new().with_flag("even"),
+            Err("even".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_from_assumption_list() {
+        let facts: SymbolAssumptions = [Assumption::Nonnegative, Assumption::Nonzero]
+            .into_iter()
+            .collect();
+        assert_eq!(facts, SymbolAssumptions::new().positive());
+        assert_eq!(Assumption::Integer.to_string(), "integer");
+    }
+}
@@ -0,0 +1,280 @@
+//! Scoped symbol registries
+//!
+//! Outside a context, every `x` is the same symbol. A server parsing input
+//! for many users at once may not want that: a symbol declared by one
+//! session should not be captured by an expression from another. A
+//! [`SymbolContext`] gives each session its own namespace. Symbols bound in
+//! the same context compare equal by name across parses, while symbols from
+//! different contexts never compare equal.
+//!
+//! A frozen context accepts no new names, so input can be checked against a
+//! fixed set of declared variables.
+//!
+//! The context identifier is stored in every symbol. Identifiers are never
+//! reused, so symbols that outlive their context still differ from those of
+//! every later context.
+
+use super::Symbol;
+use crate::core::Expression;
+use crate::parser::error::ParseError;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::{Mutex, MutexGuard};
+
+/// Identifier of the next context; 0 is the global context
+static NEXT_CONTEXT_ID: AtomicU32 = AtomicU32::new(1);
+
+fn next_context_id() -> u32 {
+    NEXT_CONTEXT_ID
+        .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |id| id.checked_add(1))
+        .expect("symbol context identifiers exhausted")
+}
+
+/// Namespace in which symbols with the same name are the same symbol
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::core::symbol::SymbolContext;
+/// use mathhook_core::Parser;
+///
+/// let parser = Parser::default();
+/// let alice = SymbolContext::new();
+/// let bob = SymbolContext::new();
+///
+/// let first = parser.parse_in("x + 1", &alice).unwrap();
+/// let again = parser.parse_in("x + 1", &alice).unwrap();
+/// let other = parser.parse_in("x + 1", &bob).unwrap();
+///
+/// assert_eq!(first, again);
+/// assert_ne!(first, other);
+/// ```
+#[derive(Debug)]
+pub struct SymbolContext {
+    id: u32,
+    symbols: Mutex<BTreeMap<String, Symbol>>,
+    frozen: AtomicBool,
+}
+
+impl SymbolContext {
+    /// Create an empty context, distinct from every other context
+    ///
+    /// # Panics
+    ///
+    /// If more than `u32::MAX` contexts have been created.
+    pub fn new() -> Self {
+        Self {
+            id: next_context_id(),
+            symbols: Mutex::new(BTreeMap::new()),
+            frozen: AtomicBool::new(false),
+        }
+    }
+
+    /// Identifier carried by every symbol of this context
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// The scalar symbol `name` of this context, registered on first use
+    ///
+    /// Returns `None` when the context is frozen and `name` was never
+    /// registered.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::core::symbol::{Symbol, SymbolContext};
+    ///
+    /// let context = SymbolContext::new();
+    /// let x = context.symbol("x").unwrap();
+    ///
+    /// assert_eq!(context.symbol("x"), Some(x.clone()));
+    /// assert_ne!(x, Symbol::scalar("x"));
+    /// ```
+    pub fn symbol(&self, name: &str) -> Option<Symbol> {
+        self.register(Symbol::scalar(name))
+    }
+
+    /// The registered symbol called `name`, without registering it
+    pub fn get(&self, name: &str) -> Option<Symbol> {
+        self.lock().get(name).cloned()
+    }
+
+    /// Whether a symbol called `name` is registered
+    pub fn contains(&self, name: &str) -> bool {
+        self.lock().contains_key(name)
+    }
+
+    /// Registered symbols, ordered by name
+    pub fn symbols(&self) -> Vec<Symbol> {
+        self.lock().values().cloned().collect()
+    }
+
+    /// Number of registered symbols
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    /// Forget every registered symbol
+    ///
+    /// A frozen context stays frozen, and so accepts no names afterwards.
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    /// Stop registering new names
+    ///
+    /// Freezing cannot be undone; binding input that uses an unregistered
+    /// name fails from then on.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::core::symbol::SymbolContext;
+    /// use mathhook_core::{ParseError, Parser};
+    ///
+    /// let parser = Parser::default();
+    /// let context = SymbolContext::new();
+    /// parser.parse_in("x^2 + y", &context).unwrap();
+    /// context.freeze();
+    ///
+    /// assert!(parser.parse_in("2*x - y", &context).is_ok());
+    /// assert_eq!(
+    ///     parser.parse_in("x + z", &context),
+    ///     Err(ParseError::UnknownSymbol("z".to_owned()))
+    /// );
+    /// ```
+    pub fn freeze(&self) {
+        self.frozen.store(true, Ordering::Release);
+    }
+
+    pub fn is_frozen(&self) -> bool {
+        self.frozen.load(Ordering::Acquire)
+    }
+
+    /// Rewrite `expr` so each of its symbols belongs to this context
+    ///
+    /// Symbol names seen for the first time are registered, keeping their
+    /// type and assumptions.
+    ///
+    /// # Errors
+    ///
+    /// [`ParseError::UnknownSymbol`] when the context is frozen and `expr`
+    /// uses a name that is not registered.
+    pub fn bind(&self, expr: &Expression) -> Result<Expression, ParseError> {
+        let variables = expr.find_variables();
+        if variables.iter().all(|symbol| symbol.context == self.id) {
+            return Ok(expr.clone());
+        }
+
+        let mut substitutions = HashMap::with_capacity(variables.len());
+        for symbol in variables {
+            let name = symbol.name().to_owned();
+            let bound = self
+                .register(symbol)
+                .ok_or_else(|| ParseError::UnknownSymbol(name.clone()))?;
+            substitutions.insert(name, Expression::symbol(bound));
+        }
+        Ok(expr.substitute(&substitutions))
+    }
+
+    /// The registered symbol named like `symbol`, registering `symbol` in
+    /// this context if the name is new and the context is not frozen
+    fn register(&self, symbol: Symbol) -> Option<Symbol> {
+        let mut symbols = self.lock();
+        if let Some(existing) = symbols.get(symbol.name()) {
+            return Some(existing.clone());
+        }
+        if self.is_frozen() {
+            return None;
+        }
+        let symbol = symbol.in_context(self.id);
+        symbols.insert(symbol.name().to_owned(), symbol.clone());
+        Some(symbol)
+    }
+
+    fn lock(&self) -> MutexGuard<'_, BTreeMap<String, Symbol>> {
+        self.symbols.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Default for SymbolContext {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Parser;
+
+    #[test]
+    fn test_symbols_are_shared_within_and_isolated_across_contexts() {
+        let first = SymbolContext::new();
+        let second = SymbolContext::new();
+        assert_ne!(first.id(), second.id());
+
+        let x1 = first.symbol("x").unwrap();
+        assert_eq!(first.symbol("x").unwrap(), x1);
+        assert_eq!(x1.context_id(), first.id());
+        assert_ne!(second.symbol("x").unwrap(), x1);
+        assert_ne!(Symbol::scalar("x"), x1);
+    }
+
+    #[test]
+    fn test_symbols_outliving_their_context_stay_distinct() {
+        let stale = SymbolContext::new().symbol("x").unwrap();
+        for _ in 0..4 {
+            let fresh = SymbolContext::new();
+            assert_ne!(fresh.id(), stale.context_id());
+            assert_ne!(fresh.symbol("x").unwrap(), stale);
+        }
+    }
+
+    #[test]
+    fn test_bind_keeps_symbol_type_and_assumptions() {
+        let context = SymbolContext::new();
+        let a = Symbol::matrix("A");
+        let bound = context.bind(&Expression::symbol(a.clone())).unwrap();
+
+        let Expression::Symbol(symbol) = bound else {
+            panic!("expected a symbol");
+        };
+        assert_eq!(symbol.symbol_type(), a.symbol_type());
+        assert_eq!(symbol.context_id(), context.id());
+        assert_eq!(context.get("A"), Some(symbol));
+    }
+
+    #[test]
+    fn test_list_clear_and_freeze() {
+        let parser = Parser::default();
+        let context = SymbolContext::new();
+        parser.parse_in("y + x*z", &context).unwrap();
+
+        let names: Vec<String> = context
+            .symbols()
+            .iter()
+            .map(|symbol| symbol.name().to_owned())
+            .collect();
+        assert_eq!(names, vec!["x", "y", "z"]);
+
+        context.freeze();
+        assert!(context.is_frozen());
+        assert_eq!(context.symbol("w"), None);
+        assert!(context.symbol("x").is_some());
+
+        context.clear();
+        assert!(context.is_empty());
+        assert_eq!(
+            parser.parse_in("x", &context),
+            Err(ParseError::UnknownSymbol("x".to_owned()))
+        );
+        // Inputs without symbols need no registration
+        assert!(parser.parse_in("1 + 2", &context).is_ok());
+    }
+}
@@ -0,0 +1,644 @@
+//! Symbol name analysis shared by the formatters and the parser
+//!
+//! A name such as `theta_max` is read as a base (`theta`) followed by
+//! underscore-separated subscript parts (`max`). Formatters render those parts
+//! in their own syntax (`\theta_{\max}`, `θₘₐₓ`, `Subscript[\[Theta], max]`)
+//! and [`normalize_symbol_names`] maps the rendered forms back to the plain
+//! name before parsing, so formatted output round-trips.
+
+use std::borrow::Cow;
+
+/// A Greek letter known to every formatter
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GreekLetter {
+    /// Symbol name and LaTeX command (`alpha` -> `\alpha`)
+    pub name: &'static str,
+    pub unicode: char,
+    /// Wolfram named character (`Alpha` -> `\[Alpha]`)
+    pub wolfram: &'static str,
+}
+
+const fn greek(name: &'static str, unicode: char, wolfram: &'static str) -> GreekLetter {
+    GreekLetter {
+        name,
+        unicode,
+        wolfram,
+    }
+}
+
+static GREEK_LETTERS: &[GreekLetter] = &[
+    greek("alpha", 'α', "Alpha"),
+    greek("beta", 'β', "Beta"),
+    greek("gamma", 'γ', "Gamma"),
+    greek("delta", 'δ', "Delta"),
+    greek("epsilon", 'ε', "Epsilon"),
+    greek("varepsilon", 'ϵ', "CurlyEpsilon"),
+    greek("zeta", 'ζ', "Zeta"),
+    greek("eta", 'η', "Eta"),
+    greek("theta", 'θ', "Theta"),
+    greek("vartheta", 'ϑ', "CurlyTheta"),
+    greek("iota", 'ι', "Iota"),
+    greek("kappa", 'κ', "Kappa"),
+    greek("lambda", 'λ', "Lambda"),
+    greek("mu", 'μ', "Mu"),
+    greek("nu", 'ν', "Nu"),
+    greek("xi", 'ξ', "Xi"),
+    greek("omicron", 'ο', "Omicron"),
+    greek("pi", 'π', "Pi"),
+    greek("varpi", 'ϖ', "CurlyPi"),
+    greek("rho", 'ρ', "Rho"),
+    greek("varrho", 'ϱ', "CurlyRho"),
+    greek("sigma", 'σ', "Sigma"),
+    greek("varsigma", 'ς', "FinalSigma"),
+    greek("tau", 'τ', "Tau"),
+    greek("upsilon", 'υ', "Upsilon"),
+    greek("phi", 'φ', "Phi"),
+    greek("varphi", 'ϕ', "CurlyPhi"),
+    greek("chi", 'χ', "Chi"),
+    greek("psi", 'ψ', "Psi"),
+    greek("omega", 'ω', "Omega"),
+    greek("Gamma", 'Γ', "CapitalGamma"),
+    greek("Delta", 'Δ', "CapitalDelta"),
+    greek("Theta", 'Θ', "CapitalTheta"),
+    greek("Lambda", 'Λ', "CapitalLambda"),
+    greek("Xi", 'Ξ', "CapitalXi"),
+    greek("Pi", 'Π', "CapitalPi"),
+    greek("Sigma", 'Σ', "CapitalSigma"),
+    greek("Upsilon", 'Υ', "CapitalUpsilon"),
+    greek("Phi", 'Φ', "CapitalPhi"),
+    greek("Psi", 'Ψ', "CapitalPsi"),
+    greek("Omega", 'Ω', "CapitalOmega"),
+];
+
+/// Words typeset upright with their own LaTeX command when used as subscripts
+const OPERATOR_WORDS: &[&str] = &[
+    "max", "min", "sup", "inf", "lim", "log", "ln", "exp", "det", "deg", "dim", "ker", "arg",
+];
+
+/// Unicode subscript forms of the characters that have one
+const SUBSCRIPT_CHARS: &[(char, char)] = &[
+    ('0', '₀'),
+    ('1', '₁'),
+    ('2', '₂'),
+    ('3', '₃'),
+    ('4', '₄'),
+    ('5', '₅'),
+    ('6', '₆'),
+    ('7', '₇'),
+    ('8', '₈'),
+    ('9', '₉'),
+    ('a', 'ₐ'),
+    ('e', 'ₑ'),
+    ('h', 'ₕ'),
+    ('i', 'ᵢ'),
+    ('j', 'ⱼ'),
+    ('k', 'ₖ'),
+    ('l', 'ₗ'),
+    ('m', 'ₘ'),
+    ('n', 'ₙ'),
+    ('o', 'ₒ'),
+    ('p', 'ₚ'),
+    ('r', 'ᵣ'),
+    ('s', 'ₛ'),
+    ('t', 'ₜ'),
+    ('u', 'ᵤ'),
+    ('v', 'ᵥ'),
+    ('x', 'ₓ'),
+];
+
+/// Look up a Greek letter by symbol name (`alpha`, `Omega`)
+pub fn greek_letter(name: &str) -> Option<&'static GreekLetter> {
+    GREEK_LETTERS.iter().find(|letter| letter.name == name)
+}
+
+/// Look up a Greek letter by its Unicode character
+pub fn greek_letter_for_char(c: char) -> Option<&'static GreekLetter> {
+    GREEK_LETTERS.iter().find(|letter| letter.unicode == c)
+}
+
+/// Look up a Greek letter by its Wolfram named character (`Alpha`, `CapitalOmega`)
+pub fn greek_letter_for_wolfram(name: &str) -> Option<&'static GreekLetter> {
+    GREEK_LETTERS.iter().find(|letter| letter.wolfram == name)
+}
+
+fn to_subscript_char(c: char) -> Option<char> {
+    SUBSCRIPT_CHARS
+        .iter()
+        .find(|(plain, _)| *plain == c)
+        .map(|(_, sub)| *sub)
+}
+
+fn from_subscript_char(c: char) -> Option<char> {
+    SUBSCRIPT_CHARS
+        .iter()
+        .find(|(_, sub)| *sub == c)
+        .map(|(plain, _)| *plain)
+}
+
+/// A symbol name split into base and subscript parts
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::core::symbol::names::SymbolName;
+///
+/// let name = SymbolName::parse("theta_max");
+/// assert_eq!(name.base, "theta");
+/// assert_eq!(name.subscripts, vec!["max"]);
+/// assert_eq!(name.to_latex(), "\\theta_{\\max}");
+/// assert_eq!(name.to_unicode(), "θₘₐₓ");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SymbolName<'a> {
+    pub base: &'a str,
+    pub subscripts: Vec<&'a str>,
+}
+
+impl<'a> SymbolName<'a> {
+    /// Split `name` at underscores; names with empty parts (`x_`, `_x`, `a__b`)
+    /// are kept whole as the base
+    pub fn parse(name: &'a str) -> Self {
+        let mut parts = name.split('_');
+        let base = parts.next().unwrap_or_default();
+        let subscripts: Vec<&str> = parts.collect();
+
+        if base.is_empty() || subscripts.iter().any(|part| part.is_empty()) {
+            return Self {
+                base: name,
+                subscripts: Vec::new(),
+            };
+        }
+
+        Self { base, subscripts }
+    }
+
+    /// LaTeX for the base alone (`alpha` -> `\alpha`, `x` -> `x`)
+    pub fn latex_base(&self) -> String {
+        match greek_letter(self.base) {
+            Some(letter) => format!("\\{}", letter.name),
+            None => self.base.to_owned(),
+        }
+    }
+
+    /// LaTeX subscript including the underscore (`_{1}`), if any
+    pub fn latex_subscript(&self) -> Option<String> {
+        if self.subscripts.is_empty() {
+            return None;
+        }
+
+        let parts: Vec<String> = self
+            .subscripts
+            .iter()
+            .map(|part| latex_subscript_part(part))
+            .collect();
+        Some(format!("_{{{}}}", parts.join(",")))
+    }
+
+    /// Full LaTeX rendering (`x_1` -> `x_{1}`)
+    pub fn to_latex(&self) -> String {
+        let mut result = self.latex_base();
+        if let Some(subscript) = self.latex_subscript() {
+            result.push_str(&subscript);
+        }
+        result
+    }
+
+    /// Unicode rendering (`alpha` -> `α`, `x_1` -> `x₁`)
+    ///
+    /// A single subscript made only of characters with Unicode subscript forms
+    /// is lowered; anything else keeps the underscore (`omega_b` -> `ω_b`).
+    pub fn to_unicode(&self) -> String {
+        let mut result = unicode_part(self.base);
+
+        if let [part] = self.subscripts.as_slice() {
+            let lowered: Option<String> = part.chars().map(to_subscript_char).collect();
+            if let Some(lowered) = lowered {
+                result.push_str(&lowered);
+                return result;
+            }
+        }
+
+        for part in &self.subscripts {
+            result.push('_');
+            result.push_str(&unicode_part(part));
+        }
+        result
+    }
+
+    /// Wolfram rendering (`alpha` -> `\[Alpha]`, `x_1` -> `Subscript[x, 1]`)
+    pub fn to_wolfram(&self) -> String {
+        let base = wolfram_part(self.base);
+        if self.subscripts.is_empty() {
+            return base;
+        }
+
+        let parts: Vec<String> = self
+            .subscripts
+            .iter()
+            .map(|part| wolfram_part(part))
+            .collect();
+        format!("Subscript[{}, {}]", base, parts.join(", "))
+    }
+}
+
+fn latex_subscript_part(part: &str) -> String {
+    if let Some(letter) = greek_letter(part) {
+        format!("\\{}", letter.name)
+    } else if OPERATOR_WORDS.contains(&part) {
+        format!("\\{}", part)
+    } else if part.chars().count() == 1 || part.chars().all(|c| c.is_ascii_digit()) {
+        part.to_owned()
+    } else {
+        format!("\\mathrm{{{}}}", part)
+    }
+}
+
+fn unicode_part(part: &str) -> String {
+    match greek_letter(part) {
+        Some(letter) => letter.unicode.to_string(),
+        None => part.to_owned(),
+    }
+}
+
+fn wolfram_part(part: &str) -> String {
+    match greek_letter(part) {
+        Some(letter) => format!("\\[{}]", letter.wolfram),
+        None => part.to_owned(),
+    }
+}
+
+/// Rewrite formatted subscripted symbols back to plain symbol names
+///
+/// Handles the forms produced by the formatters: LaTeX (`x_{1}`,
+/// `\theta_{\max}`, `v_{\mathrm{init}}`), Unicode (`x₁`, `θₘₐₓ`) and Wolfram
+/// (`Subscript[x, 1]`). A subscripted name directly followed by `(` is left
+/// alone so indexed functions such as `J_{n}(x)` keep their meaning, and any
+/// construct that is not a plain subscripted name is returned unchanged.
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::core::symbol::names::normalize_symbol_names;
+///
+/// assert_eq!(normalize_symbol_names("\\theta_{\\max} + x_{1}"), "theta_max + x_1");
+/// assert_eq!(normalize_symbol_names("x₁ + 1"), "x_1 + 1");
+/// assert_eq!(normalize_symbol_names("\\int_{0}^{1} x dx"), "\\int_{0}^{1} x dx");
+/// ```
+pub fn normalize_symbol_names(input: &str) -> Cow<'_, str> {
+    let needs_work = input
+        .chars()
+        .any(|c| c == '_' || from_subscript_char(c).is_some())
+        || input.contains("Subscript[");
+    if !needs_work {
+        return Cow::Borrowed(input);
+    }
+
+    let chars: Vec<char> = input.chars().collect();
+    let mut output = String::with_capacity(input.len());
+    let mut changed = false;
+    let mut i = 0;
+
+    while i < chars.len() {
+        if let Some((name, end)) = read_subscripted_name(&chars, i) {
+            let original: String = chars[i..end].iter().collect();
+            if name != original {
+                changed = true;
+            }
+            output.push_str(&name);
+            i = end;
+        } else if chars[i].is_ascii_digit() {
+            let end = i + chars[i..].iter().take_while(|c| c.is_ascii_digit()).count();
+            output.extend(&chars[i..end]);
+            i = end;
+        } else if chars[i] == '\\' || chars[i].is_ascii_alphabetic() {
+            // Copy the whole word so a later match never starts mid-identifier
+            let end = word_end(&chars, i);
+            output.extend(&chars[i..end]);
+            i = end;
+        } else {
+            output.push(chars[i]);
+            i += 1;
+        }
+    }
+
+    if changed {
+        Cow::Owned(output)
+    } else {
+        Cow::Borrowed(input)
+    }
+}
+
+/// End of the word starting at `start`: a `\command`, a Wolfram `\[Name]`, or
+/// an ASCII alphanumeric run
+fn word_end(chars: &[char], start: usize) -> usize {
+    if chars[start] == '\\' {
+        if chars.get(start + 1) == Some(&'[') {
+            if let Some(close) = chars[start..].iter().position(|&c| c == ']') {
+                return start + close + 1;
+            }
+        }
+        let mut end = start + 1;
+        while end < chars.len() && chars[end].is_ascii_alphabetic() {
+            end += 1;
+        }
+        return end.max(start + 1);
+    }
+
+    let mut end = start;
+    while end < chars.len() && chars[end].is_ascii_alphanumeric() {
+        end += 1;
+    }
+    end
+}
+
+/// Try to read a subscripted symbol starting at `start`, returning its plain
+/// name and the index just past it
+fn read_subscripted_name(chars: &[char], start: usize) -> Option<(String, usize)> {
+    if let Some(result) = read_wolfram_subscript(chars, start) {
+        return Some(result);
+    }
+
+    let (base, mut i) = read_base(chars, start)?;
+    let mut parts: Vec<String> = Vec::new();
+
+    // Unicode subscript run (x₁, θₘₐₓ)
+    let lowered: String = chars[i..]
+        .iter()
+        .map_while(|&c| from_subscript_char(c))
+        .collect();
+    if !lowered.is_empty() {
+        i += lowered.chars().count();
+        parts.push(lowered);
+    }
+
+    while chars.get(i) == Some(&'_') {
+        let (group, next) = read_subscript_group(chars, i + 1)?;
+        parts.extend(group);
+        i = next;
+    }
+
+    if parts.is_empty() {
+        return None;
+    }
+
+    let mut rest = i;
+    while rest < chars.len() && chars[rest].is_whitespace() {
+        rest += 1;
+    }
+    if chars.get(rest) == Some(&'(') {
+        return None;
+    }
+
+    Some((format!("{}_{}", base, parts.join("_")), i))
+}
+
+/// Read a symbol base: an ASCII identifier, a LaTeX Greek command, a Wolfram
+/// Greek character or a Unicode Greek letter
+fn read_base(chars: &[char], start: usize) -> Option<(String, usize)> {
+    let first = *chars.get(start)?;
+
+    if first == '\\' {
+        let end = word_end(chars, start);
+        let word: String = chars[start + 1..end].iter().collect();
+        let name = match word.strip_prefix('[').and_then(|w| w.strip_suffix(']')) {
+            Some(wolfram) => greek_letter_for_wolfram(wolfram)?.name,
+            None => greek_letter(&word)?.name,
+        };
+        return Some((name.to_owned(), end));
+    }
+
+    if let Some(letter) = greek_letter_for_char(first) {
+        return Some((letter.name.to_owned(), start + 1));
+    }
+
+    if first.is_ascii_alphabetic() {
+        return Some((
+            chars[start..word_end(chars, start)].iter().collect(),
+            word_end(chars, start),
+        ));
+    }
+
+    None
+}
+
+/// Read what follows an underscore: `{a,b}`, `\cmd` or an alphanumeric run
+fn read_subscript_group(chars: &[char], start: usize) -> Option<(Vec<String>, usize)> {
+    match chars.get(start)? {
+        '{' => {
+            let close = start + chars[start..].iter().position(|&c| c == '}')?;
+            // `\mathrm{...}` nests one level of braces
+            let close = if chars[start + 1..close].contains(&'{') {
+                close + 1 + chars[close + 1..].iter().position(|&c| c == '}')?
+            } else {
+                close
+            };
+            let parts = read_subscript_atoms(&chars[start + 1..close])?;
+            Some((parts, close + 1))
+        }
+        '\\' => {
+            let end = word_end(chars, start);
+            let atom = latex_atom_name(&chars[start + 1..end].iter().collect::<String>())?;
+            Some((vec![atom], end))
+        }
+        c if c.is_ascii_alphanumeric() => {
+            let end = word_end(chars, start);
+            Some((vec![chars[start..end].iter().collect()], end))
+        }
+        &c => {
+            let letter = greek_letter_for_char(c)?;
+            Some((vec![letter.name.to_owned()], start + 1))
+        }
+    }
+}
+
+/// Split braced subscript content into name parts, or `None` if it contains
+/// anything other than names
+fn read_subscript_atoms(content: &[char]) -> Option<Vec<String>> {
+    let mut parts = Vec::new();
+    let mut i = 0;
+
+    while i < content.len() {
+        let c = content[i];
+        if c == ',' || c.is_whitespace() {
+            i += 1;
+        } else if c == '\\' {
+            let end = word_end(content, i);
+            let command: String = content[i + 1..end].iter().collect();
+            if (command == "mathrm" || command == "text") && content.get(end) == Some(&'{') {
+                let close = end + content[end..].iter().position(|&c| c == '}')?;
+                let word: String = content[end + 1..close].iter().collect();
+                if word.is_empty() || !word.chars().all(|c| c.is_ascii_alphanumeric()) {
+                    return None;
+                }
+                parts.push(word);
+                i = close + 1;
+            } else {
+                parts.push(latex_atom_name(&command)?);
+                i = end;
+            }
+        } else if let Some(letter) = greek_letter_for_char(c) {
+            parts.push(letter.name.to_owned());
+            i += 1;
+        } else if c.is_ascii_alphanumeric() {
+            let end = word_end(content, i);
+            parts.push(content[i..end].iter().collect());
+            i = end;
+        } else {
+            return None;
+        }
+    }
+
+    if parts.is_empty() {
+        None
+    } else {
+        Some(parts)
+    }
+}
+
+fn latex_atom_name(command: &str) -> Option<String> {
+    if greek_letter(command).is_some() || OPERATOR_WORDS.contains(&command) {
+        Some(command.to_owned())
+    } else {
+        None
+    }
+}
+
+/// Read `Subscript[base, part, ...]` with plain-name arguments
+fn read_wolfram_subscript(chars: &[char], start: usize) -> Option<(String, usize)> {
+    const HEAD: &str = "Subscript[";
+    if start > 0 && chars[start - 1].is_ascii_alphanumeric() {
+        return None;
+    }
+    if !chars[start..]
+        .iter()
+        .take(HEAD.len())
+        .copied()
+        .eq(HEAD.chars())
+    {
+        return None;
+    }
+
+    let open = start + HEAD.len();
+    let close = matching_bracket(chars, open)?;
+
+    let content: String = chars[open..close].iter().collect();
+    let mut names = Vec::new();
+    for argument in content.split(',') {
+        let argument = argument.trim();
+        let name = match argument
+            .strip_prefix("\\[")
+            .and_then(|a| a.strip_suffix(']'))
+        {
+            Some(wolfram) => greek_letter_for_wolfram(wolfram)?.name.to_owned(),
+            None if !argument.is_empty() && argument.chars().all(|c| c.is_ascii_alphanumeric()) => {
+                argument.to_owned()
+            }
+            None => return None,
+        };
+        names.push(name);
+    }
+
+    if names.len() < 2 {
+        return None;
+    }
+    Some((names.join("_"), close + 1))
+}
+
+fn matching_bracket(chars: &[char], open: usize) -> Option<usize> {
+    let mut depth = 0usize;
+    for (offset, &c) in chars[open..].iter().enumerate() {
+        match c {
+            '[' => depth += 1,
+            ']' if depth == 0 => return Some(open + offset),
+            ']' => depth -= 1,
+            _ => {}
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_splits_base_and_subscripts() {
+        let name = SymbolName::parse("x_1");
+        assert_eq!(name.base, "x");
+        assert_eq!(name.subscripts, vec!["1"]);
+
+        assert!(SymbolName::parse("alpha").subscripts.is_empty());
+        assert_eq!(SymbolName::parse("x_").base, "x_");
+        assert_eq!(SymbolName::parse("_x").base, "_x");
+    }
+
+    #[test]
+    fn test_latex_rendering() {
+        assert_eq!(SymbolName::parse("alpha").to_latex(), "\\alpha");
+        assert_eq!(SymbolName::parse("Omega").to_latex(), "\\Omega");
+        assert_eq!(SymbolName::parse("x_1").to_latex(), "x_{1}");
+        assert_eq!(SymbolName::parse("theta_max").to_latex(), "\\theta_{\\max}");
+        assert_eq!(SymbolName::parse("v_init").to_latex(), "v_{\\mathrm{init}}");
+        assert_eq!(
+            SymbolName::parse("eta_mu_nu").to_latex(),
+            "\\eta_{\\mu,\\nu}"
+        );
+        assert_eq!(SymbolName::parse("rate").to_latex(), "rate");
+    }
+
+    #[test]
+    fn test_unicode_rendering() {
+        assert_eq!(SymbolName::parse("alpha").to_unicode(), "α");
+        assert_eq!(SymbolName::parse("x_12").to_unicode(), "x₁₂");
+        assert_eq!(SymbolName::parse("theta_max").to_unicode(), "θₘₐₓ");
+        assert_eq!(SymbolName::parse("omega_b").to_unicode(), "ω_b");
+        assert_eq!(SymbolName::parse("eta_mu_nu").to_unicode(), "η_μ_ν");
+    }
+
+    #[test]
+    fn test_wolfram_rendering() {
+        assert_eq!(SymbolName::parse("alpha").to_wolfram(), "\\[Alpha]");
+        assert_eq!(SymbolName::parse("x_1").to_wolfram(), "Subscript[x, 1]");
+        assert_eq!(
+            SymbolName::parse("Delta_t").to_wolfram(),
+            "Subscript[\\[CapitalDelta], t]"
+        );
+    }
+
+    #[test]
+    fn test_normalize_round_trips_every_rendering() {
+        for name in ["x_1", "theta_max", "v_init", "eta_mu_nu", "Delta_t", "x_12"] {
+            let parsed = SymbolName::parse(name);
+            assert_eq!(normalize_symbol_names(&parsed.to_latex()), name);
+            assert_eq!(normalize_symbol_names(&parsed.to_unicode()), name);
+            assert_eq!(normalize_symbol_names(&parsed.to_wolfram()), name);
+        }
+    }
+
+    #[test]
+    fn test_normalize_leaves_other_constructs_alone() {
+        for input in [
+            "x + y",
+            "\\alpha + 1",
+            "\\int_{0}^{1} x dx",
+            "\\sum_{i=1}^{n} i",
+            "\\lim_{x \\to 0} f",
+            "J_{n}(x)",
+            "P_l(x)",
+            "x_1",
+            "Subscript[x + 1, 2]",
+        ] {
+            assert_eq!(normalize_symbol_names(input), input);
+        }
+    }
+
+    #[test]
+    fn test_normalize_in_context() {
+        assert_eq!(normalize_symbol_names("x_{1}^{2} + 2"), "x_1^{2} + 2");
+        assert_eq!(
+            normalize_symbol_names("\\frac{\\alpha_{0}}{2}"),
+            "\\frac{alpha_0}{2}"
+        );
+        assert_eq!(normalize_symbol_names("2x₁"), "2x_1");
+    }
+}
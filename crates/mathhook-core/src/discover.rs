@@ -0,0 +1,568 @@
+//! Symbolic models discovered from data
+//!
+//! [`discover`] fits `(x, y)` samples with every form of a small template
+//! space and returns the symbolic model that explains them best:
+//!
+//! | form        | model                           | fitted by                              |
+//! |-------------|---------------------------------|----------------------------------------|
+//! | polynomial  | c₀ + c₁x + … + c_d x^d, d ≤ 3   | linear least squares                   |
+//! | exponential | a e^{bx}                        | least squares on ln y                  |
+//! | power law   | a x^b                           | least squares on ln y against ln x     |
+//! | sinusoid    | a sin(ωx) + b cos(ωx) + c       | scan over ω, least squares for a, b, c |
+//!
+//! A form is only tried when there are more samples than parameters, so a
+//! model never matches the data merely by having enough freedom to. Fitted
+//! coefficients within rounding of a fraction with a small denominator are
+//! made exact: samples of `2x + 1` give back exactly `2x + 1`.
+//!
+//! A model is exact when it reproduces every sample. Exact models win, the
+//! one with the fewest parameters first; otherwise models are ranked by the
+//! Akaike information criterion `n ln(RSS/n) + 2k`, which weighs the residual
+//! sum of squares against the number of parameters `k`.
+
+use crate::core::matrix::NumericMatrix;
+use crate::core::{Expression, Symbol};
+use crate::error::MathError;
+use crate::simplify::Simplify;
+use std::f64::consts::PI;
+
+/// Highest polynomial degree tried
+pub const MAX_DEGREE: usize = 3;
+
+/// Largest denominator of a coefficient made exact
+const MAX_DENOMINATOR: i64 = 1000;
+
+/// Relative distance within which a coefficient is taken to be a fraction,
+/// or a residual to be zero
+const TOLERANCE: f64 = 1e-9;
+
+/// Frequencies scanned per unit of π/span when fitting a sinusoid
+const FREQUENCY_STEPS: f64 = 4.0;
+
+/// Largest number of frequencies scanned
+const MAX_FREQUENCIES: usize = 4000;
+
+/// A template of the search space
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ModelForm {
+    /// Polynomial of the given degree
+    Polynomial(usize),
+    /// `a e^{bx}`
+    Exponential,
+    /// `a x^b`
+    PowerLaw,
+    /// `a sin(ωx) + b cos(ωx) + c`
+    Sinusoid,
+}
+
+impl ModelForm {
+    /// Number of fitted parameters
+    pub fn parameters(self) -> usize {
+        match self {
+            ModelForm::Polynomial(degree) => degree + 1,
+            ModelForm::Exponential | ModelForm::PowerLaw => 2,
+            ModelForm::Sinusoid => 4,
+        }
+    }
+
+    /// Every template, simplest first
+    pub fn all() -> Vec<ModelForm> {
+        (0..=MAX_DEGREE)
+            .map(ModelForm::Polynomial)
+            .chain([
+                ModelForm::Exponential,
+                ModelForm::PowerLaw,
+                ModelForm::Sinusoid,
+            ])
+            .collect()
+    }
+}
+
+/// A fitted model with its residuals
+#[derive(Debug, Clone, PartialEq)]
+pub struct Model {
+    pub form: ModelForm,
+    /// The model as an expression in the sample variable
+    pub expression: Expression,
+    /// `yᵢ - model(xᵢ)` for each sample, in sample order
+    pub residuals: Vec<f64>,
+    /// Whether every residual is zero up to rounding
+    pub exact: bool,
+}
+
+impl Model {
+    /// Residual sum of squares
+    pub fn sum_of_squares(&self) -> f64 {
+        self.residuals.iter().map(|r| r * r).sum()
+    }
+
+    /// Root-mean-square residual
+    pub fn rms_error(&self) -> f64 {
+        (self.sum_of_squares() / self.residuals.len() as f64).sqrt()
+    }
+
+    /// Akaike information criterion; lower is better
+    pub fn aic(&self) -> f64 {
+        let n = self.residuals.len() as f64;
+        let rss = self.sum_of_squares().max(f64::MIN_POSITIVE);
+        n * (rss / n).ln() + 2.0 * self.form.parameters() as f64
+    }
+}
+
+/// The best model for `samples` in the variable `x`
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::discover::{discover, ModelForm};
+/// use mathhook_core::{expr, symbol, Simplify};
+///
+/// let x = symbol!(x);
+/// let samples: Vec<(f64, f64)> = (0..6).map(|i| (i as f64, 3.0 * i as f64 - 2.0)).collect();
+///
+/// let model = discover(&x, &samples).unwrap();
+/// assert_eq!(model.form, ModelForm::Polynomial(1));
+/// assert!(model.exact);
+/// assert_eq!(model.expression, expr!((3 * x) - 2).simplify());
+/// ```
+///
+/// # Errors
+///
+/// Returns `MathError::DomainError` for fewer than three samples or samples
+/// that are not finite.
+pub fn discover(x: &Symbol, samples: &[(f64, f64)]) -> Result<Model, MathError> {
+    Ok(discover_all(x, samples)?.remove(0))
+}
+
+/// Every template that could be fitted to `samples`, best first
+///
+/// # Errors
+///
+/// As for [`discover`].
+pub fn discover_all(x: &Symbol, samples: &[(f64, f64)]) -> Result<Vec<Model>, MathError> {
+    check_samples(samples)?;
+    let mut models: Vec<Model> = ModelForm::all()
+        .into_iter()
+        .filter_map(|form| fit(form, x, samples).ok())
+        .collect();
+    // Exact models by parameter count, then the rest by AIC; the sort is
+    // stable, so simpler templates win ties
+    models.sort_by(|a, b| match (a.exact, b.exact) {
+        (true, true) => a.form.parameters().cmp(&b.form.parameters()),
+        (true, false) => std::cmp::Ordering::Less,
+        (false, true) => std::cmp::Ordering::Greater,
+        (false, false) => a.aic().total_cmp(&b.aic()),
+    });
+    Ok(models)
+}
+
+/// Fit one template to `samples` by least squares
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::discover::{fit, ModelForm};
+/// use mathhook_core::{expr, symbol, Expression, Simplify};
+///
+/// let x = symbol!(x);
+/// let samples: Vec<(f64, f64)> = (1..8).map(|i| (i as f64, 5.0 * (i as f64).powf(1.5))).collect();
+///
+/// let model = fit(ModelForm::PowerLaw, &x, &samples).unwrap();
+/// assert!(model.exact);
+/// let expected = Expression::mul(vec![
+///     Expression::integer(5),
+///     Expression::pow(expr!(x), Expression::rational(3, 2)),
+/// ]);
+/// assert_eq!(model.expression, expected.simplify());
+/// ```
+///
+/// # Errors
+///
+/// Returns `MathError::DomainError` when the samples do not suit the
+/// template: no more samples than parameters, a sign change in `y` for the
+/// exponential and power law, or `x ≤ 0` for the power law.
+pub fn fit(form: ModelForm, x: &Symbol, samples: &[(f64, f64)]) -> Result<Model, MathError> {
+    check_samples(samples)?;
+    if samples.len() <= form.parameters() {
+        return Err(unsuitable(form, "needs more samples than parameters"));
+    }
+
+    let xs: Vec<f64> = samples.iter().map(|&(x, _)| x).collect();
+    let ys: Vec<f64> = samples.iter().map(|&(_, y)| y).collect();
+    let var = Expression::symbol(x.clone());
+
+    let (expression, predict): (Expression, Box<dyn Fn(f64) -> f64>) = match form {
+        ModelForm::Polynomial(degree) => {
+            let columns: Vec<Vec<f64>> = (0..=degree)
+                .map(|k| xs.iter().map(|x| x.powi(k as i32)).collect())
+                .collect();
+            let coefficients: Vec<Coefficient> = least_squares(&columns, &ys)
+                .ok_or_else(|| unsuitable(form, "the normal equations are singular"))?
+                .into_iter()
+                .map(Coefficient::new)
+                .collect();
+            let terms = coefficients
+                .iter()
+                .enumerate()
+                .map(|(k, c)| {
+                    Expression::mul(vec![
+                        c.expression(),
+                        Expression::pow(var.clone(), Expression::integer(k as i64)),
+                    ])
+                })
+                .collect();
+            let values: Vec<f64> = coefficients.iter().map(|c| c.value).collect();
+            (
+                Expression::add(terms),
+                Box::new(move |x| values.iter().rev().fold(0.0, |acc, c| acc * x + c)),
+            )
+        }
+        ModelForm::Exponential | ModelForm::PowerLaw => {
+            let sign = ys[0].signum();
+            if ys.iter().any(|y| *y == 0.0 || y.signum() != sign) {
+                return Err(unsuitable(form, "y changes sign or is zero"));
+            }
+            let power_law = form == ModelForm::PowerLaw;
+            if power_law && xs.iter().any(|x| *x <= 0.0) {
+                return Err(unsuitable(form, "x is not positive"));
+            }
+            // ln|y| = ln|a| + b·t with t = x or ln x
+            let ts: Vec<f64> = if power_law {
+                xs.iter().map(|x| x.ln()).collect()
+            } else {
+                xs.clone()
+            };
+            let logs: Vec<f64> = ys.iter().map(|y| y.abs().ln()).collect();
+            let solution = least_squares(&[vec![1.0; ts.len()], ts], &logs)
+                .ok_or_else(|| unsuitable(form, "x takes a single value"))?;
+            let a = Coefficient::new(sign * solution[0].exp());
+            let b = Coefficient::new(solution[1]);
+            let expression = if power_law {
+                Expression::mul(vec![
+                    a.expression(),
+                    Expression::pow(var.clone(), b.expression()),
+                ])
+            } else {
+                Expression::mul(vec![
+                    a.expression(),
+                    Expression::function(
+                        "exp",
+                        vec![Expression::mul(vec![b.expression(), var.clone()])],
+                    ),
+                ])
+            };
+            let (a, b) = (a.value, b.value);
+            let predict: Box<dyn Fn(f64) -> f64> = if power_law {
+                Box::new(move |x| a * x.powf(b))
+            } else {
+                Box::new(move |x| a * (b * x).exp())
+            };
+            (expression, predict)
+        }
+        ModelForm::Sinusoid => {
+            let omega = best_frequency(&xs, &ys)
+                .ok_or_else(|| unsuitable(form, "no frequency fits the samples"))?;
+            let omega = Coefficient::new(omega);
+            let solution = sinusoid_amplitudes(omega.value, &xs, &ys)
+                .ok_or_else(|| unsuitable(form, "the normal equations are singular"))?;
+            let [a, b, c] = [0, 1, 2].map(|i| Coefficient::new(solution[i]));
+            let phase = Expression::mul(vec![omega.expression(), var.clone()]);
+            let expression = Expression::add(vec![
+                Expression::mul(vec![
+                    a.expression(),
+                    Expression::function("sin", vec![phase.clone()]),
+                ]),
+                Expression::mul(vec![
+                    b.expression(),
+                    Expression::function("cos", vec![phase]),
+                ]),
+                c.expression(),
+            ]);
+            let (w, a, b, c) = (omega.value, a.value, b.value, c.value);
+            (
+                expression,
+                Box::new(move |x| a * (w * x).sin() + b * (w * x).cos() + c),
+            )
+        }
+    };
+
+    let residuals: Vec<f64> = xs.iter().zip(&ys).map(|(x, y)| y - predict(*x)).collect();
+    let scale = ys.iter().fold(1.0_f64, |m, y| m.max(y.abs()));
+    let exact = residuals.iter().all(|r| r.abs() <= TOLERANCE * scale);
+    Ok(Model {
+        form,
+        expression: expression.simplify(),
+        residuals,
+        exact,
+    })
+}
+
+/// A fitted value, snapped to a nearby fraction when there is one
+struct Coefficient {
+    value: f64,
+    fraction: Option<(i64, i64)>,
+}
+
+impl Coefficient {
+    fn new(value: f64) -> Self {
+        match nearby_fraction(value) {
+            Some((p, q)) => Self {
+                value: p as f64 / q as f64,
+                fraction: Some((p, q)),
+            },
+            None => Self {
+                value,
+                fraction: None,
+            },
+        }
+    }
+
+    fn expression(&self) -> Expression {
+        match self.fraction {
+            Some((p, 1)) => Expression::integer(p),
+            Some((p, q)) => Expression::rational(p, q),
+            None => Expression::float(self.value),
+        }
+    }
+}
+
+/// `p/q` with `q ≤ MAX_DENOMINATOR` equal to `value` up to rounding, from the
+/// convergents of its continued fraction
+fn nearby_fraction(value: f64) -> Option<(i64, i64)> {
+    if !value.is_finite() || value.abs() >= i64::MAX as f64 / 2.0 {
+        return None;
+    }
+    let close =
+        |p: i64, q: i64| (value - p as f64 / q as f64).abs() <= TOLERANCE * value.abs().max(1.0);
+    let (mut p0, mut q0, mut p1, mut q1) = (0_i64, 1_i64, 1_i64, 0_i64);
+    let mut rest = value;
+    while q1 <= MAX_DENOMINATOR {
+        let whole = rest.floor();
+        let a = whole as i64;
+        let (p2, q2) = (
+            a.checked_mul(p1)?.checked_add(p0)?,
+            a.checked_mul(q1)?.checked_add(q0)?,
+        );
+        if q2 > MAX_DENOMINATOR {
+            break;
+        }
+        if close(p2, q2) {
+            return Some((p2, q2));
+        }
+        (p0, q0, p1, q1) = (p1, q1, p2, q2);
+        let fraction = rest - whole;
+        if fraction == 0.0 {
+            break;
+        }
+        rest = 1.0 / fraction;
+    }
+    None
+}
+
+/// Least-squares coefficients of `y ≈ Σ cⱼ columns[j]`, from the normal
+/// equations
+fn least_squares(columns: &[Vec<f64>], y: &[f64]) -> Option<Vec<f64>> {
+    let k = columns.len();
+    let dot = |u: &[f64], v: &[f64]| u.iter().zip(v).map(|(a, b)| a * b).sum::<f64>();
+    let gram = NumericMatrix::from_fn(k, k, |i, j| dot(&columns[i], &columns[j])).ok()?;
+    let rhs: Vec<f64> = columns.iter().map(|column| dot(column, y)).collect();
+    let solution = gram.solve(&rhs).ok()?;
+    solution.iter().all(|c| c.is_finite()).then_some(solution)
+}
+
+/// `(a, b, c)` of `a sin(ωx) + b cos(ωx) + c` for a fixed ω
+fn sinusoid_amplitudes(omega: f64, xs: &[f64], ys: &[f64]) -> Option<Vec<f64>> {
+    let sin = xs.iter().map(|x| (omega * x).sin()).collect();
+    let cos = xs.iter().map(|x| (omega * x).cos()).collect();
+    least_squares(&[sin, cos, vec![1.0; xs.len()]], ys)
+}
+
+/// The ω minimizing the residual, scanned from one half-period over the
+/// sample span up to the Nyquist frequency of the closest samples, then
+/// refined by golden-section search
+fn best_frequency(xs: &[f64], ys: &[f64]) -> Option<f64> {
+    let rss = |omega: f64| {
+        let [a, b, c] = sinusoid_amplitudes(omega, xs, ys)?[..] else {
+            return None;
+        };
+        Some(
+            xs.iter()
+                .zip(ys)
+                .map(|(x, y)| (y - a * (omega * x).sin() - b * (omega * x).cos() - c).powi(2))
+                .sum::<f64>(),
+        )
+    };
+
+    let mut sorted = xs.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    let span = sorted[sorted.len() - 1] - sorted[0];
+    let spacing = sorted
+        .windows(2)
+        .map(|pair| pair[1] - pair[0])
+        .filter(|gap| *gap > 0.0)
+        .fold(f64::INFINITY, f64::min);
+    if span <= 0.0 || !spacing.is_finite() {
+        return None;
+    }
+    let (low, high) = (PI / span, PI / spacing);
+    let steps =
+        (((high - low) * span / PI * FREQUENCY_STEPS).ceil() as usize).clamp(1, MAX_FREQUENCIES);
+    let step = (high - low) / steps as f64;
+
+    let (best, _) = (0..=steps)
+        .map(|i| low + step * i as f64)
+        .filter_map(|omega| Some((omega, rss(omega)?)))
+        .min_by(|a, b| a.1.total_cmp(&b.1))?;
+
+    // Golden-section search on [best - step, best + step]
+    let ratio = (5.0_f64.sqrt() - 1.0) / 2.0;
+    let (mut a, mut b) = ((best - step).max(low / 2.0), best + step);
+    while b - a > 1e-13 * b.abs().max(1.0) {
+        let (c, d) = (b - ratio * (b - a), a + ratio * (b - a));
+        if rss(c).unwrap_or(f64::INFINITY) < rss(d).unwrap_or(f64::INFINITY) {
+            b = d;
+        } else {
+            a = c;
+        }
+    }
+    Some((a + b) / 2.0)
+}
+
+fn check_samples(samples: &[(f64, f64)]) -> Result<(), MathError> {
+    if samples.len() < 3 {
+        return Err(MathError::DomainError {
+            operation: "discover".to_owned(),
+            value: Expression::integer(samples.len() as i64),
+            reason: "at least three samples are needed".to_owned(),
+        });
+    }
+    if let Some(&(x, y)) = samples
+        .iter()
+        .find(|(x, y)| !x.is_finite() || !y.is_finite())
+    {
+        return Err(MathError::DomainError {
+            operation: "discover".to_owned(),
+            value: Expression::float(if x.is_finite() { y } else { x }),
+            reason: "samples must be finite".to_owned(),
+        });
+    }
+    Ok(())
+}
+
+fn unsuitable(form: ModelForm, reason: &str) -> MathError {
+    MathError::DomainError {
+        operation: format!("fit {:?}", form),
+        value: Expression::integer(form.parameters() as i64),
+        reason: reason.to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{expr, symbol};
+    use std::collections::HashMap;
+
+    fn samples(f: impl Fn(f64) -> f64, xs: impl Iterator<Item = f64>) -> Vec<(f64, f64)> {
+        xs.map(|x| (x, f(x))).collect()
+    }
+
+    fn value_at(model: &Model, x: f64) -> f64 {
+        model
+            .expression
+            .substitute(&HashMap::from([("x".to_owned(), Expression::float(x))]))
+            .evaluate_to_f64()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_exact_polynomial_has_exact_coefficients() {
+        let x = symbol!(x);
+        let data = samples(|x| x * x / 2.0 - 3.0, (-3..4).map(f64::from));
+        let model = discover(&x, &data).unwrap();
+        assert_eq!(model.form, ModelForm::Polynomial(2));
+        assert!(model.exact);
+        assert_eq!(
+            model.expression,
+            Expression::add(vec![
+                Expression::mul(vec![Expression::rational(1, 2), expr!(x ^ 2)]),
+                Expression::integer(-3),
+            ])
+            .simplify()
+        );
+    }
+
+    #[test]
+    fn test_exponential_and_power_law() {
+        let x = symbol!(x);
+        let growth = samples(|x| 3.0 * (0.5 * x).exp(), (0..8).map(f64::from));
+        let model = discover(&x, &growth).unwrap();
+        assert_eq!(model.form, ModelForm::Exponential);
+        assert!(model.exact);
+        assert_eq!(
+            model.expression,
+            Expression::mul(vec![
+                Expression::integer(3),
+                Expression::function(
+                    "exp",
+                    vec![Expression::mul(vec![Expression::rational(1, 2), expr!(x)])]
+                ),
+            ])
+            .simplify()
+        );
+
+        let decay = samples(|x| 2.0 / (x * x * x), (1..7).map(f64::from));
+        let model = discover(&x, &decay).unwrap();
+        assert_eq!(model.form, ModelForm::PowerLaw);
+        assert!((value_at(&model, 2.5) - 2.0 / 15.625).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_sinusoid_frequency_is_recovered() {
+        let x = symbol!(x);
+        let wave = samples(
+            |x| 2.0 * (3.0 * x).sin() - (3.0 * x).cos() + 0.5,
+            (0..40).map(|i| f64::from(i) * 0.1),
+        );
+        let model = discover(&x, &wave).unwrap();
+        assert_eq!(model.form, ModelForm::Sinusoid);
+        assert!(
+            model.exact,
+            "{} with RSS {}",
+            model.expression,
+            model.sum_of_squares()
+        );
+        for x in [0.05_f64, 1.23, 3.3] {
+            let expected = 2.0 * (3.0 * x).sin() - (3.0 * x).cos() + 0.5;
+            assert!((value_at(&model, x) - expected).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_noise_prefers_the_simpler_model() {
+        let x = symbol!(x);
+        // A line with small alternating noise: the cubic fits a little better
+        // but is not worth two more parameters
+        let noisy = samples(|x| 4.0 * x + 1.0, (0..12).map(f64::from))
+            .into_iter()
+            .enumerate()
+            .map(|(i, (x, y))| (x, y + if i % 2 == 0 { 0.01 } else { -0.01 }))
+            .collect::<Vec<_>>();
+        let models = discover_all(&x, &noisy).unwrap();
+        assert_eq!(models[0].form, ModelForm::Polynomial(1));
+        assert!(!models[0].exact);
+        assert!(models[0].rms_error() < 0.02);
+    }
+
+    #[test]
+    fn test_unsuitable_samples() {
+        let x = symbol!(x);
+        assert!(discover(&x, &[(0.0, 1.0), (1.0, 2.0)]).is_err());
+        assert!(discover(&x, &[(0.0, 1.0), (1.0, f64::NAN), (2.0, 3.0)]).is_err());
+
+        let crossing = samples(|x| x, (-2..3).map(f64::from));
+        assert!(fit(ModelForm::Exponential, &x, &crossing).is_err());
+        assert!(fit(ModelForm::PowerLaw, &x, &crossing).is_err());
+        assert!(fit(ModelForm::Polynomial(3), &x, &crossing[..4]).is_err());
+    }
+}
@@ -3,14 +3,28 @@
 //! This module provides educational functionality including step-by-step
 //! explanations, teaching solvers, and difficulty assessment.
 
+pub mod answer_key;
+pub mod curriculum;
 pub mod enhanced_steps;
+pub mod hints;
 pub mod message_registry;
 pub mod step_by_step;
+pub mod template;
 pub mod traits;
+pub mod validation;
+pub mod variants;
 
 // Re-export educational types
+pub use answer_key::{equation_from_solutions, AnswerKeyError, EquationConstraints};
+pub use curriculum::{Curriculum, CurriculumUnit, LearnedTechniques, SolveTechnique};
 pub use enhanced_steps::{
     DifficultyLevel, EducationalResult, EnhancedStep, EnhancedStepExplanation,
 };
+pub use hints::{next_hint, Hint, HintLevel, HintSequence, StudentState};
+pub use message_registry::{ExplanationSkills, SkillUsage};
 pub use step_by_step::{Step, StepByStep, StepByStepExplanation};
+pub use template::{Hole, HoleValues, Problem, Solution, Template, TemplateError};
 pub use traits::{EducationalExt, EducationalOperation, OperationContext};
+pub use validation::{
+    analyze, AnalysisReport, Diagnostic, DiagnosticKind, DiagnosticSeverity, ExpectedInput,
+};
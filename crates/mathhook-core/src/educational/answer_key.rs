@@ -0,0 +1,361 @@
+//! Equations built backwards from their answers
+//!
+//! Teachers writing answer-key-first worksheets choose the solutions and need
+//! an equation that has exactly those. The equation starts as the product
+//! `∏ (qᵢx - pᵢ)` over the solutions `pᵢ/qᵢ`, expanded into a polynomial;
+//! harder difficulties scale it, move terms across the equals sign and
+//! finally disguise each side with [`Expression::random_equivalent`].
+
+use super::variants::MAX_DIFFICULTY;
+use crate::algebra::solvers::completing_square::as_rational;
+use crate::algebra::Expand;
+use crate::core::polynomial::coefficients_list;
+use crate::core::{Expression, Symbol};
+use crate::simplify::Simplify;
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::{One, Zero};
+use rand::Rng;
+use std::collections::HashMap;
+use std::fmt;
+
+/// Largest residual accepted when checking a solution numerically
+const TOLERANCE: f64 = 1e-9;
+
+/// Requirements on a generated equation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EquationConstraints {
+    /// Every coefficient must be an integer, as on most worksheets
+    pub integer_coefficients: bool,
+    /// From 1 to [`MAX_DIFFICULTY`]: 1 and 2 give `polynomial = 0`, 3 and
+    /// up scale it and split its terms between the two sides, and 6 and up
+    /// also rewrite each side into an equivalent but less obvious form
+    pub difficulty: u8,
+}
+
+impl Default for EquationConstraints {
+    fn default() -> Self {
+        Self {
+            integer_coefficients: true,
+            difficulty: 1,
+        }
+    }
+}
+
+/// Why no equation could be built for the requested solutions
+#[derive(Debug, Clone, PartialEq)]
+pub enum AnswerKeyError {
+    /// The solution set is empty
+    NoSolutions,
+    /// A solution depends on the variable being solved for
+    DependsOnVariable { solution: Expression },
+    /// Integer coefficients were requested but the solutions force this
+    /// coefficient, such as `x² - 2√2 x + 2` for a lone `√2` without `-√2`
+    NonIntegerCoefficient { coefficient: Expression },
+    /// A solution does not satisfy the generated equation
+    Unverified { solution: Expression },
+}
+
+impl fmt::Display for AnswerKeyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnswerKeyError::NoSolutions => write!(f, "no solutions were given"),
+            AnswerKeyError::DependsOnVariable { solution } => {
+                write!(f, "solution {} depends on the variable", solution)
+            }
+            AnswerKeyError::NonIntegerCoefficient { coefficient } => write!(
+                f,
+                "coefficient {} cannot be made an integer; include the conjugate solutions",
+                coefficient
+            ),
+            AnswerKeyError::Unverified { solution } => {
+                write!(f, "could not verify solution {}", solution)
+            }
+        }
+    }
+}
+
+impl std::error::Error for AnswerKeyError {}
+
+/// Equation in `variable` whose solutions are exactly `solutions`
+///
+/// Repeated solutions become repeated roots. The result is a relation
+/// `left = right`; solvers take `left - right`. The same seed gives the same
+/// equation.
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::educational::answer_key::{equation_from_solutions, EquationConstraints};
+/// use mathhook_core::{expr, symbol, Expression};
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// let x = symbol!(x);
+/// let solutions = [Expression::integer(2), Expression::rational(-1, 3)];
+/// let mut rng = StdRng::seed_from_u64(1);
+///
+/// let equation =
+///     equation_from_solutions(&solutions, &x, &EquationConstraints::default(), &mut rng)
+///         .unwrap();
+/// assert_eq!(
+///     equation,
+///     Expression::equation(expr!((3 * (x ^ 2)) - (5 * x) - 2), expr!(0))
+/// );
+/// ```
+pub fn equation_from_solutions<R: Rng + ?Sized>(
+    solutions: &[Expression],
+    variable: &Symbol,
+    constraints: &EquationConstraints,
+    rng: &mut R,
+) -> Result<Expression, AnswerKeyError> {
+    if solutions.is_empty() {
+        return Err(AnswerKeyError::NoSolutions);
+    }
+    if let Some(solution) = solutions.iter().find(|s| s.contains_variable(variable)) {
+        return Err(AnswerKeyError::DependsOnVariable {
+            solution: solution.clone(),
+        });
+    }
+
+    let difficulty = constraints.difficulty.clamp(1, MAX_DIFFICULTY);
+    let x = Expression::symbol(variable.clone());
+    let factors = solutions
+        .iter()
+        .map(|solution| match as_rational(solution) {
+            Some(r) => Expression::add(vec![
+                Expression::mul(vec![Expression::big_integer(r.denom().clone()), x.clone()]),
+                Expression::big_integer(-r.numer().clone()),
+            ]),
+            None => Expression::add(vec![
+                x.clone(),
+                Expression::mul(vec![Expression::integer(-1), solution.clone()]),
+            ]),
+        })
+        .collect();
+    let expanded = Expression::mul(factors).expand().simplify();
+
+    let mut coefficients: Vec<(i64, Expression)> = coefficients_list(&expanded, variable)
+        .into_iter()
+        .map(|(degree, coefficient)| (degree, coefficient.simplify()))
+        .filter(|(_, coefficient)| !coefficient.is_zero())
+        .collect();
+    if constraints.integer_coefficients {
+        coefficients = primitive_integer_coefficients(coefficients)?;
+    }
+
+    let mut scale = 1 + rng.random_range(0..=i64::from(difficulty) / 3);
+    if difficulty >= 4 && rng.random_bool(0.5) {
+        scale = -scale;
+    }
+    let mut terms: Vec<Expression> = coefficients
+        .into_iter()
+        .rev()
+        .map(|(degree, coefficient)| {
+            Expression::mul(vec![
+                Expression::integer(scale),
+                coefficient,
+                Expression::pow(x.clone(), Expression::integer(degree)),
+            ])
+            .simplify()
+        })
+        .collect();
+
+    let mut right = Vec::new();
+    if difficulty >= 3 && terms.len() > 1 {
+        let moved = rng.random_range(1..terms.len());
+        for _ in 0..moved {
+            let term = terms.remove(rng.random_range(0..terms.len()));
+            right.push(Expression::mul(vec![Expression::integer(-1), term]).simplify());
+        }
+    }
+    let mut left = Expression::add(terms);
+    let mut right = Expression::add(right);
+
+    for solution in solutions {
+        if !satisfies(&left, &right, variable, solution) {
+            return Err(AnswerKeyError::Unverified {
+                solution: solution.clone(),
+            });
+        }
+    }
+
+    if difficulty >= 6 {
+        left = left.random_equivalent(rng, difficulty - 5);
+        right = right.random_equivalent(rng, difficulty - 5);
+    }
+    Ok(Expression::equation(left, right))
+}
+
+/// Coefficients scaled to coprime integers with a positive leading one
+fn primitive_integer_coefficients(
+    coefficients: Vec<(i64, Expression)>,
+) -> Result<Vec<(i64, Expression)>, AnswerKeyError> {
+    let rationals = coefficients
+        .iter()
+        .map(|(degree, coefficient)| {
+            as_rational(coefficient)
+                .map(|r| (*degree, r))
+                .ok_or_else(|| AnswerKeyError::NonIntegerCoefficient {
+                    coefficient: coefficient.clone(),
+                })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let denominator = rationals
+        .iter()
+        .fold(BigInt::one(), |lcm, (_, r)| lcm.lcm(r.denom()));
+    let numerators: Vec<(i64, BigInt)> = rationals
+        .into_iter()
+        .map(|(degree, r)| (degree, (r * &denominator).to_integer()))
+        .collect();
+    let mut content = numerators
+        .iter()
+        .fold(BigInt::zero(), |gcd, (_, n)| gcd.gcd(n));
+    let leading_negative = numerators
+        .iter()
+        .max_by_key(|(degree, _)| *degree)
+        .is_some_and(|(_, n)| n < &BigInt::zero());
+    if leading_negative {
+        content = -content;
+    }
+
+    Ok(numerators
+        .into_iter()
+        .map(|(degree, n)| (degree, Expression::big_integer(n / &content)))
+        .collect())
+}
+
+/// Whether `left = right` holds at `variable = solution`, exactly or to
+/// within rounding
+fn satisfies(
+    left: &Expression,
+    right: &Expression,
+    variable: &Symbol,
+    solution: &Expression,
+) -> bool {
+    let values = HashMap::from([(variable.name().to_owned(), solution.clone())]);
+    let residual = Expression::add(vec![
+        left.substitute(&values),
+        Expression::mul(vec![Expression::integer(-1), right.substitute(&values)]),
+    ])
+    .simplify();
+    residual.is_zero()
+        || residual
+            .evaluate_to_f64()
+            .is_ok_and(|value| value.abs() <= TOLERANCE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algebra::solvers::SolverResult;
+    use crate::symbol;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    /// Real roots of `equation`, in increasing order
+    fn solve(equation: &Expression, variable: &Symbol) -> Vec<f64> {
+        let Expression::Relation(relation) = equation else {
+            panic!("expected an equation, got {}", equation);
+        };
+        let zero_form = Expression::add(vec![
+            relation.left.clone(),
+            Expression::mul(vec![Expression::integer(-1), relation.right.clone()]),
+        ])
+        .simplify();
+        let roots = match zero_form.solve(variable) {
+            SolverResult::Single(root) => vec![root],
+            SolverResult::Multiple(roots) => roots,
+            other => panic!("unexpected result {:?} for {}", other, zero_form),
+        };
+        let mut values: Vec<f64> = roots
+            .iter()
+            .map(|root| root.evaluate_to_f64().unwrap())
+            .collect();
+        values.sort_by(f64::total_cmp);
+        values
+    }
+
+    #[test]
+    fn test_solutions_round_trip() {
+        let x = symbol!(x);
+        let solutions = [Expression::integer(-4), Expression::rational(3, 2)];
+        for difficulty in 1..=5 {
+            let mut rng = StdRng::seed_from_u64(u64::from(difficulty));
+            let constraints = EquationConstraints {
+                integer_coefficients: true,
+                difficulty,
+            };
+            let equation = equation_from_solutions(&solutions, &x, &constraints, &mut rng).unwrap();
+            assert_eq!(
+                solve(&equation, &x),
+                vec![-4.0, 1.5],
+                "difficulty {}",
+                difficulty
+            );
+        }
+    }
+
+    #[test]
+    fn test_disguised_equation_still_holds() {
+        let x = symbol!(x);
+        let solutions = [Expression::integer(1), Expression::integer(5)];
+        let constraints = EquationConstraints {
+            integer_coefficients: true,
+            difficulty: MAX_DIFFICULTY,
+        };
+        let mut rng = StdRng::seed_from_u64(3);
+        let equation = equation_from_solutions(&solutions, &x, &constraints, &mut rng).unwrap();
+        let Expression::Relation(relation) = &equation else {
+            panic!("expected an equation");
+        };
+        for solution in &solutions {
+            assert!(satisfies(&relation.left, &relation.right, &x, solution));
+        }
+    }
+
+    #[test]
+    fn test_integer_coefficients_need_conjugates() {
+        let x = symbol!(x);
+        let root = Expression::sqrt(Expression::integer(2));
+        let constraints = EquationConstraints::default();
+        let mut rng = StdRng::seed_from_u64(0);
+
+        assert!(matches!(
+            equation_from_solutions(std::slice::from_ref(&root), &x, &constraints, &mut rng),
+            Err(AnswerKeyError::NonIntegerCoefficient { .. })
+        ));
+
+        let conjugates = [
+            root.clone(),
+            Expression::mul(vec![Expression::integer(-1), root]),
+        ];
+        let equation = equation_from_solutions(&conjugates, &x, &constraints, &mut rng).unwrap();
+        assert_eq!(
+            equation,
+            Expression::equation(
+                Expression::add(vec![
+                    Expression::pow(Expression::symbol(x), Expression::integer(2)),
+                    Expression::integer(-2),
+                ]),
+                Expression::integer(0)
+            )
+        );
+    }
+
+    #[test]
+    fn test_rejects_bad_solution_sets() {
+        let x = symbol!(x);
+        let constraints = EquationConstraints::default();
+        let mut rng = StdRng::seed_from_u64(0);
+        assert_eq!(
+            equation_from_solutions(&[], &x, &constraints, &mut rng),
+            Err(AnswerKeyError::NoSolutions)
+        );
+        assert!(matches!(
+            equation_from_solutions(&[Expression::symbol(x.clone())], &x, &constraints, &mut rng),
+            Err(AnswerKeyError::DependsOnVariable { .. })
+        ));
+    }
+}
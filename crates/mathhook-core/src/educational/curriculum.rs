@@ -0,0 +1,339 @@
+//! Course curricula that restrict solving and simplification to what a
+//! student has learned
+//!
+//! A curriculum is a sequence of units, each unlocking solving techniques and
+//! simplification passes. `Curriculum::through_unit` collects everything
+//! unlocked up to a unit; handing the result to
+//! `SmartEquationSolver::with_curriculum` or simplifying with its pipeline
+//! keeps solutions and their explanations within the student's toolbox, so
+//! an Algebra I student in unit 4 factors `x² - 5x + 6 = 0` instead of
+//! reaching for the quadratic formula.
+
+use crate::algebra::equation_analyzer::EquationType;
+use crate::algebra::solvers::QuadraticMethod;
+use crate::simplify::{SimplifyPass, SimplifyPipeline};
+
+/// Equation-solving technique a unit can unlock
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SolveTechnique {
+    /// Isolating the variable in a linear equation
+    LinearIsolation,
+    /// Elimination and substitution for systems of equations
+    Systems,
+    /// Factoring a quadratic with rational roots
+    QuadraticFactoring,
+    /// Rewriting a quadratic as `a (x - h)² + k`
+    CompletingSquare,
+    /// `x = (-b ± √(b² - 4ac)) / (2a)`
+    QuadraticFormula,
+    /// Rational roots and the cubic and quartic formulas
+    PolynomialRoots,
+    /// Equations involving floor, ceiling, min and max
+    PiecewiseEquations,
+    /// Left and right division by matrices
+    MatrixEquations,
+    /// Separable, linear and exact first-order ODEs
+    OrdinaryDifferentialEquations,
+    /// Characteristics and separation of variables
+    PartialDifferentialEquations,
+}
+
+/// One unit of a course
+#[derive(Debug, Clone, PartialEq)]
+pub struct CurriculumUnit {
+    pub name: String,
+    /// Solving techniques first taught in this unit
+    pub techniques: Vec<SolveTechnique>,
+    /// Simplification passes first taught in this unit
+    pub passes: Vec<SimplifyPass>,
+}
+
+impl CurriculumUnit {
+    /// Unit that unlocks nothing yet
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            techniques: Vec::new(),
+            passes: Vec::new(),
+        }
+    }
+
+    /// Unlock a solving technique in this unit
+    pub fn with_technique(mut self, technique: SolveTechnique) -> Self {
+        self.techniques.push(technique);
+        self
+    }
+
+    /// Unlock a simplification pass in this unit
+    pub fn with_pass(mut self, pass: SimplifyPass) -> Self {
+        self.passes.push(pass);
+        self
+    }
+}
+
+/// Ordered units of a course
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::educational::curriculum::{Curriculum, SolveTechnique};
+///
+/// let algebra = Curriculum::algebra_1();
+/// assert!(!algebra.through_unit(4).permits(SolveTechnique::QuadraticFormula));
+/// assert!(algebra.through_unit(5).permits(SolveTechnique::QuadraticFormula));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Curriculum {
+    pub name: String,
+    pub units: Vec<CurriculumUnit>,
+}
+
+impl Curriculum {
+    /// Course without units
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            units: Vec::new(),
+        }
+    }
+
+    /// Append the next unit
+    pub fn with_unit(mut self, unit: CurriculumUnit) -> Self {
+        self.units.push(unit);
+        self
+    }
+
+    /// Algebra I: arithmetic, linear equations and systems, factoring, then
+    /// the quadratic formula in unit 5
+    pub fn algebra_1() -> Self {
+        Self::new("Algebra I")
+            .with_unit(
+                CurriculumUnit::new("Expressions")
+                    .with_pass(SimplifyPass::NumericFold)
+                    .with_pass(SimplifyPass::CollectLikeTerms),
+            )
+            .with_unit(
+                CurriculumUnit::new("Linear Equations")
+                    .with_technique(SolveTechnique::LinearIsolation),
+            )
+            .with_unit(
+                CurriculumUnit::new("Systems of Linear Equations")
+                    .with_technique(SolveTechnique::Systems),
+            )
+            .with_unit(
+                CurriculumUnit::new("Polynomials and Factoring")
+                    .with_technique(SolveTechnique::QuadraticFactoring)
+                    .with_pass(SimplifyPass::CombinePowers)
+                    .with_pass(SimplifyPass::FactorCommon)
+                    .with_pass(SimplifyPass::RationalCancel),
+            )
+            .with_unit(
+                CurriculumUnit::new("Quadratic Equations")
+                    .with_technique(SolveTechnique::CompletingSquare)
+                    .with_technique(SolveTechnique::QuadraticFormula)
+                    .with_pass(SimplifyPass::Radical),
+            )
+            .with_unit(
+                CurriculumUnit::new("Piecewise Functions")
+                    .with_technique(SolveTechnique::PiecewiseEquations),
+            )
+    }
+
+    /// Calculus I, which assumes all of Algebra I
+    pub fn calculus_1() -> Self {
+        let review = Self::algebra_1().units.into_iter().fold(
+            CurriculumUnit::new("Precalculus Review")
+                .with_technique(SolveTechnique::PolynomialRoots),
+            |review, unit| CurriculumUnit {
+                techniques: [review.techniques, unit.techniques].concat(),
+                passes: [review.passes, unit.passes].concat(),
+                ..review
+            },
+        );
+        Self::new("Calculus I")
+            .with_unit(review)
+            .with_unit(
+                CurriculumUnit::new("Trigonometric Functions")
+                    .with_pass(SimplifyPass::Trigonometric),
+            )
+            .with_unit(
+                CurriculumUnit::new("Differential Equations")
+                    .with_technique(SolveTechnique::OrdinaryDifferentialEquations),
+            )
+    }
+
+    /// Linear Algebra: systems, matrix algebra, then eigenvalues through
+    /// characteristic polynomials
+    pub fn linear_algebra() -> Self {
+        Self::new("Linear Algebra")
+            .with_unit(
+                CurriculumUnit::new("Systems of Linear Equations")
+                    .with_technique(SolveTechnique::LinearIsolation)
+                    .with_technique(SolveTechnique::Systems)
+                    .with_pass(SimplifyPass::NumericFold)
+                    .with_pass(SimplifyPass::CollectLikeTerms),
+            )
+            .with_unit(
+                CurriculumUnit::new("Matrix Algebra")
+                    .with_technique(SolveTechnique::MatrixEquations)
+                    .with_pass(SimplifyPass::CombinePowers)
+                    .with_pass(SimplifyPass::MatrixIdentities),
+            )
+            .with_unit(
+                CurriculumUnit::new("Eigenvalues")
+                    .with_technique(SolveTechnique::QuadraticFactoring)
+                    .with_technique(SolveTechnique::QuadraticFormula)
+                    .with_technique(SolveTechnique::PolynomialRoots),
+            )
+    }
+
+    /// Everything unlocked in units `1..=unit`
+    ///
+    /// Units are numbered from 1; numbers past the last unit cover the whole
+    /// course.
+    pub fn through_unit(&self, unit: usize) -> LearnedTechniques {
+        let units = &self.units[..unit.min(self.units.len())];
+        let mut learned = LearnedTechniques {
+            description: match units.last() {
+                Some(last) => format!("{} through unit {} ({})", self.name, units.len(), last.name),
+                None => format!("{} before unit 1", self.name),
+            },
+            techniques: Vec::new(),
+            passes: Vec::new(),
+        };
+        for unit in units {
+            for technique in &unit.techniques {
+                if !learned.techniques.contains(technique) {
+                    learned.techniques.push(*technique);
+                }
+            }
+            for pass in &unit.passes {
+                if !learned.passes.contains(pass) {
+                    learned.passes.push(*pass);
+                }
+            }
+        }
+        learned
+    }
+}
+
+/// Techniques and passes available at a point in a course
+#[derive(Debug, Clone, PartialEq)]
+pub struct LearnedTechniques {
+    /// Course and unit, for explanations
+    pub description: String,
+    pub techniques: Vec<SolveTechnique>,
+    pub passes: Vec<SimplifyPass>,
+}
+
+impl LearnedTechniques {
+    /// Whether `technique` has been taught
+    pub fn permits(&self, technique: SolveTechnique) -> bool {
+        self.techniques.contains(&technique)
+    }
+
+    /// Pipeline of the learned passes in the order they were taught
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::educational::curriculum::Curriculum;
+    /// use mathhook_core::simplify::SimplifyPass;
+    ///
+    /// let pipeline = Curriculum::algebra_1().through_unit(1).simplify_pipeline();
+    /// assert_eq!(pipeline.pass_names(), vec!["numeric_fold", "collect_like_terms"]);
+    /// ```
+    pub fn simplify_pipeline(&self) -> SimplifyPipeline {
+        self.passes
+            .iter()
+            .fold(SimplifyPipeline::new(), |pipeline, pass| {
+                pipeline.with_pass(*pass)
+            })
+    }
+
+    /// Method to solve quadratics with, preferring the most general one taught
+    ///
+    /// `None` when no quadratic technique has been taught.
+    pub fn quadratic_method(&self) -> Option<QuadraticMethod> {
+        [
+            (SolveTechnique::QuadraticFormula, QuadraticMethod::Formula),
+            (
+                SolveTechnique::CompletingSquare,
+                QuadraticMethod::CompletingSquare,
+            ),
+            (
+                SolveTechnique::QuadraticFactoring,
+                QuadraticMethod::Factoring,
+            ),
+        ]
+        .into_iter()
+        .find(|(technique, _)| self.permits(*technique))
+        .map(|(_, method)| method)
+    }
+
+    /// Whether equations of this type may be solved
+    pub fn permits_equation(&self, equation_type: EquationType) -> bool {
+        match equation_type {
+            EquationType::Linear => self.permits(SolveTechnique::LinearIsolation),
+            EquationType::Quadratic => self.quadratic_method().is_some(),
+            EquationType::Cubic | EquationType::Quartic => {
+                self.permits(SolveTechnique::PolynomialRoots)
+            }
+            EquationType::System => self.permits(SolveTechnique::Systems),
+            EquationType::Matrix => self.permits(SolveTechnique::MatrixEquations),
+            EquationType::Rounding | EquationType::MinMax => {
+                self.permits(SolveTechnique::PiecewiseEquations)
+            }
+            EquationType::ODE => self.permits(SolveTechnique::OrdinaryDifferentialEquations),
+            EquationType::PDE => self.permits(SolveTechnique::PartialDifferentialEquations),
+            EquationType::Constant
+            | EquationType::Transcendental
+            | EquationType::Numerical
+            | EquationType::Unknown => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_units_accumulate() {
+        let algebra = Curriculum::algebra_1();
+        let early = algebra.through_unit(2);
+        assert!(early.permits(SolveTechnique::LinearIsolation));
+        assert!(!early.permits(SolveTechnique::Systems));
+        assert_eq!(
+            early.description,
+            "Algebra I through unit 2 (Linear Equations)"
+        );
+
+        let all = algebra.through_unit(usize::MAX);
+        assert!(all.permits(SolveTechnique::PiecewiseEquations));
+        assert_eq!(all, algebra.through_unit(algebra.units.len()));
+    }
+
+    #[test]
+    fn test_quadratic_method_prefers_formula() {
+        let algebra = Curriculum::algebra_1();
+        assert_eq!(algebra.through_unit(3).quadratic_method(), None);
+        assert_eq!(
+            algebra.through_unit(4).quadratic_method(),
+            Some(QuadraticMethod::Factoring)
+        );
+        assert_eq!(
+            algebra.through_unit(5).quadratic_method(),
+            Some(QuadraticMethod::Formula)
+        );
+    }
+
+    #[test]
+    fn test_calculus_includes_algebra() {
+        let review = Curriculum::calculus_1().through_unit(1);
+        let algebra = Curriculum::algebra_1().through_unit(usize::MAX);
+        assert!(algebra.techniques.iter().all(|t| review.permits(*t)));
+        assert!(review.permits(SolveTechnique::PolynomialRoots));
+        assert!(!review.passes.contains(&SimplifyPass::Trigonometric));
+    }
+}
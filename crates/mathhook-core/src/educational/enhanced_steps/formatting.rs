@@ -2,6 +2,7 @@
 
 use crate::core::Expression;
 use crate::formatter::latex::LaTeXFormatter;
+use crate::formatter::mathml::{MathMLContext, MathMLFormatter};
 use crate::formatter::{FormattingError, MathLanguage};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -37,6 +38,7 @@ impl FormatContext {
                 })
             }
             MathLanguage::Markdown => Ok(expr.to_string()),
+            MathLanguage::MathML => expr.to_mathml(&MathMLContext::default()),
         }
     }
 
@@ -0,0 +1,255 @@
+//! Progressive hints derived from step-by-step solutions
+//!
+//! A tutoring app computes the explanation once and asks `next_hint` for help
+//! whenever the student is stuck. The hints for the student's next step go
+//! from general to specific: the overall strategy, the rule to apply, and
+//! finally the step itself, so the app can reveal them one at a time.
+
+use super::step_by_step::{Step, StepByStepExplanation};
+use crate::core::Expression;
+use serde::{Deserialize, Serialize};
+
+/// Rules of steps that frame the problem rather than transform it
+const FRAMING_RULES: &[&str] = &["Initial", "Identification", "Introduction"];
+
+/// Rule name used by `Step::new` when no specific rule is recorded
+const UNNAMED_RULE: &str = "Custom";
+
+/// How much of the next step a hint gives away
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum HintLevel {
+    /// What kind of problem this is and how it is approached
+    Strategy,
+    /// Which rule the next step applies
+    Rule,
+    /// The next step itself
+    NextStep,
+}
+
+/// A single hint
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Hint {
+    pub level: HintLevel,
+    pub text: String,
+    /// Expression the student should reach, only given at `HintLevel::NextStep`
+    pub expression: Option<Expression>,
+}
+
+/// Where the student is in the solution
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StudentState {
+    /// The student's latest intermediate result, if any
+    pub current: Option<Expression>,
+    /// Solution steps already completed, used when `current` matches no step
+    pub steps_completed: usize,
+}
+
+impl StudentState {
+    /// Student who has not started
+    pub fn at_start() -> Self {
+        Self::default()
+    }
+
+    /// Student whose latest intermediate result is `expression`
+    pub fn at(expression: Expression) -> Self {
+        Self {
+            current: Some(expression),
+            steps_completed: 0,
+        }
+    }
+
+    /// Student who has completed `steps` solution steps
+    pub fn after_steps(steps: usize) -> Self {
+        Self {
+            current: None,
+            steps_completed: steps,
+        }
+    }
+}
+
+/// Hints for one solution step, ordered from least to most specific
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HintSequence {
+    /// Index of the step in `StepByStepExplanation::steps`
+    pub step_index: usize,
+    pub hints: Vec<Hint>,
+}
+
+impl HintSequence {
+    /// The first `count` hints, for apps that reveal one hint per request
+    pub fn reveal(&self, count: usize) -> &[Hint] {
+        &self.hints[..count.min(self.hints.len())]
+    }
+}
+
+/// Hints for the student's next step, or `None` once the solution is complete
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::calculus::derivatives::educational::DerivativeWithSteps;
+/// use mathhook_core::educational::hints::{next_hint, HintLevel, StudentState};
+/// use mathhook_core::{expr, symbol};
+///
+/// let x = symbol!(x);
+/// let solution = expr!((x ^ 3) + (2 * x)).derivative_with_steps(&x, 1);
+///
+/// let hints = next_hint(&solution, &StudentState::at_start()).unwrap();
+/// let levels: Vec<HintLevel> = hints.hints.iter().map(|h| h.level).collect();
+/// assert_eq!(levels, vec![HintLevel::Strategy, HintLevel::Rule, HintLevel::NextStep]);
+/// assert!(hints.reveal(2)[1].text.contains("Sum Rule"));
+/// ```
+pub fn next_hint(
+    problem: &StepByStepExplanation,
+    student_state: &StudentState,
+) -> Option<HintSequence> {
+    let actionable: Vec<usize> = problem
+        .steps
+        .iter()
+        .enumerate()
+        .filter(|(_, step)| !is_framing(step))
+        .map(|(index, _)| index)
+        .collect();
+
+    let completed = student_state
+        .current
+        .as_ref()
+        .and_then(|current| {
+            actionable
+                .iter()
+                .rposition(|&index| &problem.steps[index].expression == current)
+                .map(|position| position + 1)
+        })
+        .unwrap_or(student_state.steps_completed);
+
+    let step_index = *actionable.get(completed)?;
+    let step = &problem.steps[step_index];
+
+    Some(HintSequence {
+        step_index,
+        hints: vec![
+            Hint {
+                level: HintLevel::Strategy,
+                text: strategy(problem),
+                expression: None,
+            },
+            Hint {
+                level: HintLevel::Rule,
+                text: format!("Use: {}", rule_name(step)),
+                expression: None,
+            },
+            Hint {
+                level: HintLevel::NextStep,
+                text: format!("{}: {}", step.title, step.description),
+                expression: (!is_placeholder(step)).then(|| step.expression.clone()),
+            },
+        ],
+    })
+}
+
+fn is_framing(step: &Step) -> bool {
+    FRAMING_RULES.contains(&step.rule_applied.as_str())
+}
+
+/// The framing steps describe the problem; without them, name the starting point
+fn strategy(problem: &StepByStepExplanation) -> String {
+    let framing: Vec<&str> = problem
+        .steps
+        .iter()
+        .filter(|step| is_framing(step))
+        .map(|step| step.description.as_str())
+        .collect();
+
+    if framing.is_empty() {
+        format!(
+            "Transform {} step by step toward the result",
+            problem.initial_expression
+        )
+    } else {
+        framing.join(". ")
+    }
+}
+
+fn rule_name(step: &Step) -> &str {
+    if step.rule_applied == UNNAMED_RULE || step.rule_applied.is_empty() {
+        &step.title
+    } else {
+        &step.rule_applied
+    }
+}
+
+/// `Step::new` leaves a zero placeholder instead of a real intermediate result
+fn is_placeholder(step: &Step) -> bool {
+    step.rule_applied == UNNAMED_RULE && step.expression.is_zero()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculus::derivatives::educational::DerivativeWithSteps;
+    use crate::{expr, symbol};
+
+    fn sum_solution() -> StepByStepExplanation {
+        let x = symbol!(x);
+        expr!((x ^ 3) + (2 * x)).derivative_with_steps(&x, 1)
+    }
+
+    #[test]
+    fn test_hints_go_from_general_to_specific() {
+        let solution = sum_solution();
+        let hints = next_hint(&solution, &StudentState::at_start()).unwrap();
+
+        assert!(hints.hints[0].text.contains("sum of 2 terms"));
+        assert_eq!(hints.hints[1].text, "Use: Sum Rule");
+        assert!(hints.hints[0].expression.is_none());
+        assert!(hints.hints[2].expression.is_some());
+        assert!(hints.reveal(0).is_empty());
+        assert_eq!(hints.reveal(10).len(), 3);
+    }
+
+    #[test]
+    fn test_position_from_student_expression() {
+        let solution = sum_solution();
+
+        // Student already differentiated the first term
+        let state = StudentState::at(expr!(3 * (x ^ 2)));
+        let hints = next_hint(&solution, &state).unwrap();
+        assert_eq!(
+            solution.steps[hints.step_index].rule_applied,
+            "Term 2 Derivative"
+        );
+        assert_eq!(hints.hints[2].expression, Some(expr!(2)));
+
+        // Unrecognized work falls back to the completed step count
+        let state = StudentState {
+            current: Some(expr!(y)),
+            steps_completed: 1,
+        };
+        let hints = next_hint(&solution, &state).unwrap();
+        assert_eq!(
+            solution.steps[hints.step_index].rule_applied,
+            "Term 1 Derivative"
+        );
+    }
+
+    #[test]
+    fn test_no_hints_after_final_step() {
+        let solution = sum_solution();
+        let answer = solution.steps.last().unwrap().expression.clone();
+        assert!(next_hint(&solution, &StudentState::at(answer)).is_none());
+        assert!(next_hint(&solution, &StudentState::after_steps(100)).is_none());
+    }
+
+    #[test]
+    fn test_unnamed_rules_use_step_title() {
+        let steps = vec![
+            Step::new("Expand", "Multiply out the brackets"),
+            Step::new("Collect", "Collect like terms"),
+        ];
+        let solution = StepByStepExplanation::new(steps);
+        let hints = next_hint(&solution, &StudentState::after_steps(1)).unwrap();
+        assert_eq!(hints.hints[1].text, "Use: Collect");
+        assert!(hints.hints[2].expression.is_none());
+        assert!(hints.hints[0].text.starts_with("Transform"));
+    }
+}
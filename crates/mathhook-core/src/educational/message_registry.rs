@@ -5,15 +5,18 @@
 pub mod algebra;
 pub mod calculus;
 pub mod core;
+pub mod errors;
 pub mod noncommutative;
 pub mod ode;
 pub mod pde;
+pub mod skills;
 pub mod solvers;
 
 pub use core::{
     MessageBuilder, MessageCategory, MessageHashSystem, MessageKey, MessageTemplate, MessageType,
     MESSAGE_REGISTRY,
 };
+pub use skills::{rule_skills, step_skills, ExplanationSkills, Skill, SkillUsage, SKILLS};
 
 use std::collections::HashMap;
 
@@ -310,6 +310,7 @@ pub static MESSAGE_REGISTRY: Lazy<HashMap<MessageKey, MessageTemplate>> = Lazy::
     super::solvers::initialize_solver_messages(&mut registry);
     super::noncommutative::initialize_noncommutative_messages(&mut registry);
     super::ode::initialize_ode_messages(&mut registry);
+    super::errors::initialize_error_messages(&mut registry);
 
     registry
 });
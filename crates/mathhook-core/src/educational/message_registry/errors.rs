@@ -0,0 +1,160 @@
+//! Student-friendly explanations for `MathError` and its remediation hints
+//!
+//! Explanations are keyed by `MathError::message_variant` under
+//! `MessageType::Error`; hints are keyed by the `Remediation` discriminant
+//! under `MessageType::Insight`.
+
+use super::core::{MessageCategory, MessageKey, MessageTemplate, MessageType};
+use std::collections::HashMap;
+
+/// Initialize all error messages
+pub fn initialize_error_messages(registry: &mut HashMap<MessageKey, MessageTemplate>) {
+    initialize_explanation_messages(registry);
+    initialize_remediation_messages(registry);
+}
+
+/// Initialize explanations, one per `MathError` variant
+fn initialize_explanation_messages(registry: &mut HashMap<MessageKey, MessageTemplate>) {
+    let explanations = [
+        MessageTemplate::new(
+            "Outside the Domain",
+            "{operation} cannot be applied to {expression}: {reason}.",
+            &["operation", "expression", "reason"],
+        ),
+        MessageTemplate::new(
+            "Division by Zero",
+            "This step would divide by zero, and division by zero has no meaning.",
+            &[],
+        ),
+        MessageTemplate::new(
+            "Undefined Expression",
+            "{expression} does not have a value: {reason}.",
+            &["expression", "reason"],
+        ),
+        MessageTemplate::new(
+            "Number Too Large",
+            "The numbers in {operation} grew too large to store.",
+            &["operation"],
+        ),
+        MessageTemplate::new(
+            "Not Supported Yet",
+            "This kind of problem is not supported yet: {feature}.",
+            &["feature"],
+        ),
+        MessageTemplate::new(
+            "Infinite Value",
+            "{operation}({expression}) grows without bound, so it has no finite value.",
+            &["operation", "expression"],
+        ),
+        MessageTemplate::new(
+            "No Real Value",
+            "{operation}({expression}) has no real value; its value is a complex number.",
+            &["operation", "expression"],
+        ),
+        MessageTemplate::new(
+            "Bounds in the Wrong Order",
+            "The lower bound {lower} should be smaller than the upper bound {upper}.",
+            &["lower", "upper"],
+        ),
+        MessageTemplate::new(
+            "Approximation Did Not Settle",
+            "The approximation stopped after {max_iterations} tries without settling on an answer.",
+            &["max_iterations"],
+        ),
+        MessageTemplate::new(
+            "Approximation Did Not Settle",
+            "The approximation did not settle on an answer: {reason}.",
+            &["reason"],
+        ),
+        MessageTemplate::new(
+            "Answer Still Has Unknowns",
+            "{expression} still contains unknowns, so it cannot be turned into a single number.",
+            &["expression"],
+        ),
+        MessageTemplate::new(
+            "Step Could Not Be Completed",
+            "While trying to {operation}, we got stuck at {expression}: {reason}.",
+            &["operation", "expression", "reason"],
+        ),
+        MessageTemplate::new(
+            "Cannot Evaluate",
+            "{expression} has no real value because it involves {reason}.",
+            &["expression", "reason"],
+        ),
+    ];
+
+    for (variant, template) in explanations.into_iter().enumerate() {
+        registry.insert(
+            MessageKey::new(MessageCategory::Error, MessageType::Error, variant as u8),
+            template,
+        );
+    }
+}
+
+/// Initialize hints, one per `Remediation`
+fn initialize_remediation_messages(registry: &mut HashMap<MessageKey, MessageTemplate>) {
+    let hints = [
+        MessageTemplate::new(
+            "Collect Terms",
+            "Move every term with the variable to one side and combine like terms.",
+            &[],
+        ),
+        MessageTemplate::new(
+            "Check the Denominator",
+            "Find the values that make the denominator zero and leave them out.",
+            &[],
+        ),
+        MessageTemplate::new(
+            "Restrict the Input",
+            "Only use inputs inside the function's domain.",
+            &[],
+        ),
+        MessageTemplate::new(
+            "Avoid the Singularity",
+            "Pick a point away from where the function blows up, or take a limit instead.",
+            &[],
+        ),
+        MessageTemplate::new(
+            "Use Complex Numbers",
+            "Work with complex numbers, or choose which branch of the function you want.",
+            &[],
+        ),
+        MessageTemplate::new(
+            "Take a Limit",
+            "Compute a limit to see which value the expression approaches.",
+            &[],
+        ),
+        MessageTemplate::new(
+            "Swap the Bounds",
+            "Swap the bounds so the smaller one comes first, and change the sign of the result.",
+            &[],
+        ),
+        MessageTemplate::new(
+            "Allow More Tries",
+            "Allow more iterations, or start from a guess closer to the answer.",
+            &[],
+        ),
+        MessageTemplate::new(
+            "Try Another Method",
+            "Try a different approximation method or a different starting point.",
+            &[],
+        ),
+        MessageTemplate::new(
+            "Substitute Values",
+            "Replace every variable with a number before evaluating.",
+            &[],
+        ),
+        MessageTemplate::new(
+            "Stay Exact",
+            "Keep the calculation exact with fractions and whole numbers instead of decimals.",
+            &[],
+        ),
+    ];
+
+    for (variant, template) in hints.into_iter().enumerate() {
+        registry.insert(
+            MessageKey::new(MessageCategory::Error, MessageType::Insight, variant as u8),
+            template,
+        );
+    }
+}
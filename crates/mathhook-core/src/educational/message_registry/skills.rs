@@ -0,0 +1,510 @@
+//! Skill tags for registry messages and step rules
+//!
+//! Every message in the registry and every named rule recorded in
+//! `Step::rule_applied` maps to standardized skill identifiers: Common Core
+//! codes (`CCSS.A-REI.B.3`) where a high school standard covers the skill, and
+//! kebab-case identifiers (`chain-rule`) for calculus, series and differential
+//! equations. `ExplanationSkills` collects them over the steps of a solution
+//! for LMS analytics.
+
+use super::core::{MessageCategory, MessageKey, MessageType, MESSAGE_REGISTRY};
+use crate::educational::step_by_step::{Step, StepByStepExplanation};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// A skill a solution step can exercise
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Skill {
+    pub id: &'static str,
+    pub description: &'static str,
+}
+
+impl Skill {
+    const fn new(id: &'static str, description: &'static str) -> Self {
+        Self { id, description }
+    }
+
+    /// Whether the identifier is a Common Core State Standard code
+    pub fn is_common_core(&self) -> bool {
+        self.id.starts_with("CCSS.")
+    }
+}
+
+/// Every skill identifier used by the tags below
+pub static SKILLS: &[Skill] = &[
+    Skill::new(
+        "CCSS.A-SSE.A.2",
+        "Use the structure of an expression to rewrite it",
+    ),
+    Skill::new("CCSS.A-APR.A.1", "Add, subtract, and multiply polynomials"),
+    Skill::new("CCSS.A-APR.B.2", "Apply the remainder theorem"),
+    Skill::new(
+        "CCSS.A-APR.B.3",
+        "Identify zeros of polynomials from a factorization",
+    ),
+    Skill::new(
+        "CCSS.A-APR.C.5",
+        "Expand powers of binomials with the binomial theorem",
+    ),
+    Skill::new("CCSS.A-APR.D.6", "Rewrite simple rational expressions"),
+    Skill::new("CCSS.A-REI.A.1", "Explain each step in solving an equation"),
+    Skill::new("CCSS.A-REI.B.3", "Solve linear equations in one variable"),
+    Skill::new(
+        "CCSS.A-REI.B.4",
+        "Solve quadratic equations in one variable",
+    ),
+    Skill::new(
+        "CCSS.A-REI.C.5",
+        "Solve systems of equations by elimination",
+    ),
+    Skill::new("CCSS.A-REI.C.6", "Solve systems of linear equations"),
+    Skill::new(
+        "CCSS.A-REI.C.9",
+        "Solve systems of linear equations with matrices",
+    ),
+    Skill::new("CCSS.8.EE.A.1", "Apply the properties of integer exponents"),
+    Skill::new("combine-like-terms", "Combine like terms"),
+    Skill::new(
+        "identity-rules",
+        "Simplify with additive and multiplicative identities",
+    ),
+    Skill::new("exponent-rules", "Simplify powers with the exponent rules"),
+    Skill::new(
+        "distributive-property",
+        "Expand with the distributive property",
+    ),
+    Skill::new("foil", "Multiply binomials with FOIL"),
+    Skill::new("binomial-theorem", "Expand powers of binomials"),
+    Skill::new("factor-gcf", "Factor out the greatest common factor"),
+    Skill::new("factor-by-grouping", "Factor by grouping"),
+    Skill::new("factor-quadratics", "Factor quadratic expressions"),
+    Skill::new("rational-expressions", "Simplify rational expressions"),
+    Skill::new(
+        "polynomial-gcd",
+        "Compute polynomial greatest common divisors",
+    ),
+    Skill::new("linear-equations", "Solve linear equations"),
+    Skill::new("quadratic-equations", "Solve quadratic equations"),
+    Skill::new("systems-substitution", "Solve systems by substitution"),
+    Skill::new("systems-elimination", "Solve systems by elimination"),
+    Skill::new("systems-matrices", "Solve systems with matrices"),
+    Skill::new(
+        "rational-root-theorem",
+        "Find candidate roots with the rational root theorem",
+    ),
+    Skill::new("synthetic-division", "Divide polynomials synthetically"),
+    Skill::new(
+        "polynomial-factoring",
+        "Solve polynomial equations by factoring",
+    ),
+    Skill::new("verify-solutions", "Check a solution by substitution"),
+    Skill::new("sum-rule", "Differentiate or integrate term by term"),
+    Skill::new("constant-rule", "Differentiate constants"),
+    Skill::new("power-rule", "Differentiate powers"),
+    Skill::new(
+        "chain-rule",
+        "Differentiate compositions with the chain rule",
+    ),
+    Skill::new("product-rule", "Differentiate products"),
+    Skill::new("quotient-rule", "Differentiate quotients"),
+    Skill::new(
+        "implicit-differentiation",
+        "Differentiate implicitly defined functions",
+    ),
+    Skill::new(
+        "higher-order-derivatives",
+        "Compute higher order derivatives",
+    ),
+    Skill::new("integral-power-rule", "Integrate powers"),
+    Skill::new("integral-constant-rule", "Integrate constants"),
+    Skill::new("u-substitution", "Integrate by substitution"),
+    Skill::new("integration-by-parts", "Integrate by parts"),
+    Skill::new("definite-integrals", "Evaluate definite integrals"),
+    Skill::new(
+        "limits-direct-substitution",
+        "Evaluate limits by direct substitution",
+    ),
+    Skill::new("indeterminate-forms", "Recognize indeterminate forms"),
+    Skill::new("lhopital-rule", "Evaluate limits with L'Hopital's rule"),
+    Skill::new("limit-laws", "Evaluate limits with the limit laws"),
+    Skill::new("one-sided-limits", "Evaluate one-sided limits"),
+    Skill::new(
+        "summation-notation",
+        "Read and manipulate summation notation",
+    ),
+    Skill::new("arithmetic-series", "Sum arithmetic series"),
+    Skill::new("geometric-series", "Sum geometric series"),
+    Skill::new("power-sums", "Sum powers of integers"),
+    Skill::new("series-convergence", "Test series for convergence"),
+    Skill::new(
+        "noncommutative-algebra",
+        "Manipulate noncommuting quantities",
+    ),
+    Skill::new("matrix-inverse", "Solve matrix equations with inverses"),
+    Skill::new("separable-odes", "Solve separable differential equations"),
+    Skill::new(
+        "linear-first-order-odes",
+        "Solve first order linear differential equations",
+    ),
+    Skill::new(
+        "homogeneous-odes",
+        "Solve homogeneous differential equations",
+    ),
+    Skill::new("exact-odes", "Solve exact differential equations"),
+    Skill::new("bernoulli-odes", "Solve Bernoulli differential equations"),
+    Skill::new(
+        "constant-coefficient-odes",
+        "Solve constant coefficient differential equations",
+    ),
+    Skill::new(
+        "cauchy-euler-odes",
+        "Solve Cauchy-Euler differential equations",
+    ),
+    Skill::new(
+        "undetermined-coefficients",
+        "Find particular solutions by undetermined coefficients",
+    ),
+    Skill::new(
+        "variation-of-parameters",
+        "Find particular solutions by variation of parameters",
+    ),
+    Skill::new(
+        "characteristic-equation",
+        "Solve the characteristic equation",
+    ),
+    Skill::new("integrating-factor", "Solve with an integrating factor"),
+    Skill::new(
+        "ode-substitution",
+        "Reduce differential equations by substitution",
+    ),
+    Skill::new(
+        "partial-differential-equations",
+        "Solve partial differential equations",
+    ),
+];
+
+/// Look up a skill by identifier
+pub fn skill(id: &str) -> Option<&'static Skill> {
+    SKILLS.iter().find(|skill| skill.id == id)
+}
+
+impl MessageKey {
+    /// Skills exercised by the message, independent of its variant
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::educational::message_registry::{MessageCategory, MessageKey, MessageType};
+    ///
+    /// let key = MessageKey::new(MessageCategory::Calculus, MessageType::DerivativeChainRule, 0);
+    /// assert_eq!(key.skills(), &["chain-rule"]);
+    ///
+    /// let key = MessageKey::new(MessageCategory::LinearEquation, MessageType::Strategy, 0);
+    /// assert!(key.skills().contains(&"CCSS.A-REI.B.3"));
+    /// ```
+    pub fn skills(&self) -> &'static [&'static str] {
+        use MessageType::*;
+        match self.message_type {
+            DerivativePowerRule | DerivativeVariable => &["power-rule"],
+            DerivativeChainRule => &["chain-rule"],
+            DerivativeProductRule => &["product-rule"],
+            DerivativeQuotientRule => &["quotient-rule"],
+            DerivativeConstant => &["constant-rule"],
+            DerivativeImplicit => &["implicit-differentiation"],
+            DerivativeHigherOrder => &["higher-order-derivatives"],
+            IntegralPowerRule => &["integral-power-rule"],
+            IntegralConstant => &["integral-constant-rule"],
+            IntegralUSubstitution => &["u-substitution"],
+            IntegralByParts => &["integration-by-parts"],
+            IntegralDefinite => &["definite-integrals"],
+            LimitDirect => &["limits-direct-substitution"],
+            LimitIndeterminate => &["indeterminate-forms"],
+            LimitLHopital => &["lhopital-rule"],
+            LimitLaws => &["limit-laws"],
+            LimitOneSided => &["one-sided-limits"],
+            SummationIntroduction | SummationFormula | SummationSubstitution | SummationResult => {
+                &["summation-notation"]
+            }
+            SummationArithmeticSeries => &["arithmetic-series"],
+            SummationGeometricSeries => &["geometric-series"],
+            SummationPowerSum => &["power-sums"],
+            SummationConvergence => &["series-convergence"],
+            SimplifyCombineLike => &["CCSS.A-SSE.A.2", "combine-like-terms"],
+            SimplifyIdentity => &["identity-rules"],
+            ExpandDistributive => &["CCSS.A-APR.A.1", "distributive-property"],
+            ExpandFOIL => &["CCSS.A-APR.A.1", "foil"],
+            ExpandBinomial => &["CCSS.A-APR.C.5", "binomial-theorem"],
+            FactorCommon => &["CCSS.A-SSE.A.2", "factor-gcf"],
+            FactorGrouping => &["CCSS.A-SSE.A.2", "factor-by-grouping"],
+            FactorQuadratic => &["CCSS.A-SSE.A.2", "factor-quadratics"],
+            RationalSimplify => &["CCSS.A-APR.D.6", "rational-expressions"],
+            SystemSubstitution => &["CCSS.A-REI.C.6", "systems-substitution"],
+            SystemElimination => &["CCSS.A-REI.C.5", "systems-elimination"],
+            SystemMatrix => &["CCSS.A-REI.C.9", "systems-matrices"],
+            PolynomialRationalRoot => &["CCSS.A-APR.B.3", "rational-root-theorem"],
+            PolynomialSyntheticDivision => &["CCSS.A-APR.B.2", "synthetic-division"],
+            PolynomialFactorization => &["CCSS.A-APR.B.3", "polynomial-factoring"],
+            LeftMultiplyInverse | RightMultiplyInverse => {
+                &["noncommutative-algebra", "matrix-inverse"]
+            }
+            NoncommutativeWarning | CommutatorExplanation | OrderMatters => {
+                &["noncommutative-algebra"]
+            }
+            ODESeparable => &["separable-odes"],
+            ODELinear => &["linear-first-order-odes"],
+            ODEHomogeneous => &["homogeneous-odes"],
+            ODEExact => &["exact-odes"],
+            ODEBernoulli => &["bernoulli-odes"],
+            ODEConstantCoefficients => &["constant-coefficient-odes"],
+            ODECauchyEuler => &["cauchy-euler-odes"],
+            ODEUndeterminedCoefficients => &["undetermined-coefficients"],
+            ODEVariationParameters => &["variation-of-parameters"],
+            ODECharacteristicEquation => &["characteristic-equation"],
+            ODEIntegratingFactor => &["integrating-factor"],
+            ODESubstitution => &["ode-substitution"],
+            Introduction | Strategy | Step | Calculation | Result | Verification | Insight
+            | Error => self.category.skills(),
+        }
+    }
+}
+
+impl MessageCategory {
+    /// Skills exercised by the generic messages of the category
+    pub fn skills(&self) -> &'static [&'static str] {
+        match self {
+            MessageCategory::LinearEquation => &["CCSS.A-REI.B.3", "linear-equations"],
+            MessageCategory::QuadraticEquation => &["CCSS.A-REI.B.4", "quadratic-equations"],
+            MessageCategory::SystemEquation => &["CCSS.A-REI.C.6"],
+            MessageCategory::PolynomialEquation => &["CCSS.A-APR.B.3"],
+            MessageCategory::Verification => &["CCSS.A-REI.A.1", "verify-solutions"],
+            MessageCategory::NoncommutativeAlgebra => &["noncommutative-algebra"],
+            MessageCategory::PartialDifferentialEquation => &["partial-differential-equations"],
+            MessageCategory::Algebra
+            | MessageCategory::Calculus
+            | MessageCategory::GeneralMath
+            | MessageCategory::Error
+            | MessageCategory::OrdinaryDifferentialEquation => &[],
+        }
+    }
+}
+
+/// Skills exercised by a rule name recorded in `Step::rule_applied`
+///
+/// Unknown rules and rules that only frame or conclude a solution
+/// (`"Initial"`, `"Final"`) have no skills.
+pub fn rule_skills(rule: &str) -> &'static [&'static str] {
+    match rule {
+        "Sum Rule" => &["sum-rule"],
+        "Constant Rule" => &["constant-rule"],
+        "Power Rule" | "General Power Rule" | "Variable Rule" => &["power-rule"],
+        "Chain Rule" | "Chain Rule Application" | "Inner Derivative" | "Outer Derivative" => {
+            &["chain-rule"]
+        }
+        "Product Rule" | "Product Rule Application" | "General Product Rule" => &["product-rule"],
+        "Quotient Rule"
+        | "Quotient Rule Application"
+        | "Numerator Derivative"
+        | "Denominator Derivative" => &["quotient-rule"],
+        "Second Derivative" => &["higher-order-derivatives"],
+        "Combine Like Terms" => &["CCSS.A-SSE.A.2", "combine-like-terms"],
+        "Identity Rules" | "Identity Property" | "Zero Property" | "Zero Rules" => {
+            &["identity-rules"]
+        }
+        "Power Rules" => &["CCSS.8.EE.A.1", "exponent-rules"],
+        "Distributive Property" | "Distribution" | "Distribution Complete" => {
+            &["CCSS.A-APR.A.1", "distributive-property"]
+        }
+        "FOIL Method" => &["CCSS.A-APR.A.1", "foil"],
+        "Binomial Theorem" => &["CCSS.A-APR.C.5", "binomial-theorem"],
+        "GCF Extraction" | "GCF Factored" => &["CCSS.A-SSE.A.2", "factor-gcf"],
+        "Euclidean Algorithm" | "GCD of Coefficients" => &["polynomial-gcd"],
+        "Power Sum Formula" => &["power-sums"],
+        "Convergence Test" => &["series-convergence"],
+        _ => &[],
+    }
+}
+
+/// Skills per registry title, for steps built by `MessageBuilder`
+///
+/// Titles shared by messages with different skills are left out, since the
+/// step alone cannot tell which message produced it.
+static TITLE_SKILLS: Lazy<HashMap<&'static str, &'static [&'static str]>> = Lazy::new(|| {
+    let mut by_title: HashMap<&'static str, Option<&'static [&'static str]>> = HashMap::new();
+    for (key, template) in MESSAGE_REGISTRY.iter() {
+        let skills = key.skills();
+        by_title
+            .entry(template.title)
+            .and_modify(|known| {
+                if *known != Some(skills) {
+                    *known = None;
+                }
+            })
+            .or_insert(Some(skills));
+    }
+    by_title
+        .into_iter()
+        .filter_map(|(title, skills)| Some((title, skills?)))
+        .filter(|(_, skills)| !skills.is_empty())
+        .collect()
+});
+
+/// Skills exercised by a single step, from its rule name and registry title
+pub fn step_skills(step: &Step) -> Vec<&'static str> {
+    let mut skills = rule_skills(&step.rule_applied).to_vec();
+    if let Some(titled) = TITLE_SKILLS.get(step.title.as_str()) {
+        for id in titled.iter() {
+            if !skills.contains(id) {
+                skills.push(id);
+            }
+        }
+    }
+    skills
+}
+
+/// A skill and the steps that exercise it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SkillUsage {
+    pub id: String,
+    /// Indices into `StepByStepExplanation::steps`
+    pub steps: Vec<usize>,
+}
+
+/// Skills exercised by a solution, in order of first use
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExplanationSkills {
+    pub skills: Vec<SkillUsage>,
+}
+
+impl ExplanationSkills {
+    /// Extract the skills of every step
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::calculus::derivatives::educational::DerivativeWithSteps;
+    /// use mathhook_core::educational::message_registry::skills::ExplanationSkills;
+    /// use mathhook_core::{expr, symbol};
+    ///
+    /// let x = symbol!(x);
+    /// let solution = expr!((x ^ 3) + (2 * x)).derivative_with_steps(&x, 1);
+    ///
+    /// let skills = ExplanationSkills::from_explanation(&solution);
+    /// assert!(skills.contains("sum-rule"));
+    /// ```
+    pub fn from_explanation(explanation: &StepByStepExplanation) -> Self {
+        let mut skills: Vec<SkillUsage> = Vec::new();
+        for (index, step) in explanation.steps.iter().enumerate() {
+            for id in step_skills(step) {
+                match skills.iter_mut().find(|usage| usage.id == id) {
+                    Some(usage) => usage.steps.push(index),
+                    None => skills.push(SkillUsage {
+                        id: id.to_owned(),
+                        steps: vec![index],
+                    }),
+                }
+            }
+        }
+        Self { skills }
+    }
+
+    /// Skill identifiers in order of first use
+    pub fn ids(&self) -> Vec<&str> {
+        self.skills.iter().map(|usage| usage.id.as_str()).collect()
+    }
+
+    pub fn contains(&self, id: &str) -> bool {
+        self.skills.iter().any(|usage| usage.id == id)
+    }
+
+    /// Indices of the steps exercising `id`, empty if none do
+    pub fn steps_for(&self, id: &str) -> &[usize] {
+        self.skills
+            .iter()
+            .find(|usage| usage.id == id)
+            .map_or(&[], |usage| usage.steps.as_slice())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.skills.is_empty()
+    }
+}
+
+impl StepByStepExplanation {
+    /// Skills exercised by this explanation
+    pub fn skills(&self) -> ExplanationSkills {
+        ExplanationSkills::from_explanation(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculus::integrals::educational::explain_power_rule;
+    use crate::educational::message_registry::MessageBuilder;
+    use crate::{expr, symbol};
+
+    #[test]
+    fn test_every_tag_is_in_catalog() {
+        let mut ids: Vec<&str> = MESSAGE_REGISTRY
+            .keys()
+            .flat_map(|k| k.skills())
+            .copied()
+            .collect();
+        ids.extend(
+            [
+                "Sum Rule",
+                "Power Rules",
+                "FOIL Method",
+                "GCF Extraction",
+                "Chain Rule",
+                "Quotient Rule",
+            ]
+            .iter()
+            .flat_map(|rule| rule_skills(rule)),
+        );
+        for id in ids {
+            assert!(skill(id).is_some(), "{id} missing from SKILLS");
+        }
+    }
+
+    #[test]
+    fn test_catalog_ids_are_unique() {
+        for (i, skill) in SKILLS.iter().enumerate() {
+            assert!(!SKILLS[i + 1..].iter().any(|other| other.id == skill.id));
+        }
+        assert!(skill("CCSS.A-REI.B.3").unwrap().is_common_core());
+        assert!(!skill("chain-rule").unwrap().is_common_core());
+    }
+
+    #[test]
+    fn test_registry_steps_tagged_by_title() {
+        let step = MessageBuilder::new(
+            MessageCategory::Calculus,
+            MessageType::DerivativeChainRule,
+            0,
+        )
+        .with_substitution("outer_function", "sin(u)")
+        .with_substitution("inner_function", "x^2")
+        .build()
+        .unwrap();
+        assert_eq!(step.rule_applied, "Custom");
+        assert_eq!(step_skills(&step), vec!["chain-rule"]);
+    }
+
+    #[test]
+    fn test_explanation_skills_record_step_indices() {
+        let x = symbol!(x);
+        let solution = explain_power_rule(&expr!(x), &expr!(2), &x);
+        let skills = solution.skills();
+        assert_eq!(skills.ids(), vec!["integral-power-rule"]);
+        assert_eq!(skills.steps_for("integral-power-rule"), &[0, 1]);
+        assert!(skills.steps_for("chain-rule").is_empty());
+    }
+
+    #[test]
+    fn test_untagged_explanation() {
+        let solution = StepByStepExplanation::new(vec![Step::new("Look", "Look at it")]);
+        assert!(solution.skills().is_empty());
+    }
+}
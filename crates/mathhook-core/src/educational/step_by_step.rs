@@ -651,94 +651,90 @@ fn expand_expression(expr: &Expression) -> Option<(Expression, Vec<Step>)> {
     let mut steps = Vec::new();
 
     match expr {
-        Expression::Mul(factors) => {
-            if factors.len() == 2 {
-                if let (Expression::Add(terms1), Expression::Add(terms2)) =
-                    (&factors[0], &factors[1])
-                {
-                    steps.push(Step {
-                        title: "Identify Pattern".to_owned(),
-                        description: format!(
-                            "Two binomials - use FOIL method\n({}) * ({})",
-                            factors[0], factors[1]
-                        ),
-                        expression: expr.clone(),
-                        rule_applied: "FOIL Method".to_owned(),
-                        latex: None,
-                    });
+        Expression::Mul(factors) if factors.len() == 2 => {
+            if let (Expression::Add(terms1), Expression::Add(terms2)) = (&factors[0], &factors[1]) {
+                steps.push(Step {
+                    title: "Identify Pattern".to_owned(),
+                    description: format!(
+                        "Two binomials - use FOIL method\n({}) * ({})",
+                        factors[0], factors[1]
+                    ),
+                    expression: expr.clone(),
+                    rule_applied: "FOIL Method".to_owned(),
+                    latex: None,
+                });
 
-                    let mut all_terms = Vec::new();
-                    for t1 in terms1.iter() {
-                        for t2 in terms2.iter() {
-                            all_terms.push(Expression::Mul(Arc::new(vec![t1.clone(), t2.clone()])));
-                        }
+                let mut all_terms = Vec::new();
+                for t1 in terms1.iter() {
+                    for t2 in terms2.iter() {
+                        all_terms.push(Expression::Mul(Arc::new(vec![t1.clone(), t2.clone()])));
                     }
+                }
 
-                    let intermediate = Expression::Add(Arc::new(all_terms));
-                    steps.push(Step {
-                        title: "Apply FOIL".to_owned(),
-                        description: format!("Multiply each term: {}", intermediate),
-                        expression: intermediate.clone(),
-                        rule_applied: "Distribution".to_owned(),
-                        latex: None,
-                    });
+                let intermediate = Expression::Add(Arc::new(all_terms));
+                steps.push(Step {
+                    title: "Apply FOIL".to_owned(),
+                    description: format!("Multiply each term: {}", intermediate),
+                    expression: intermediate.clone(),
+                    rule_applied: "Distribution".to_owned(),
+                    latex: None,
+                });
 
-                    return Some((intermediate, steps));
-                } else if let Expression::Add(terms) = &factors[0] {
-                    steps.push(Step {
-                        title: "Distribute Factor".to_owned(),
-                        description: format!("Distribute {} over {}", factors[1], factors[0]),
-                        expression: expr.clone(),
-                        rule_applied: "Distributive Property".to_owned(),
-                        latex: None,
-                    });
+                return Some((intermediate, steps));
+            } else if let Expression::Add(terms) = &factors[0] {
+                steps.push(Step {
+                    title: "Distribute Factor".to_owned(),
+                    description: format!("Distribute {} over {}", factors[1], factors[0]),
+                    expression: expr.clone(),
+                    rule_applied: "Distributive Property".to_owned(),
+                    latex: None,
+                });
 
-                    let mut distributed_terms = Vec::new();
-                    for term in terms.iter() {
-                        distributed_terms.push(Expression::Mul(Arc::new(vec![
-                            term.clone(),
-                            factors[1].clone(),
-                        ])));
-                    }
+                let mut distributed_terms = Vec::new();
+                for term in terms.iter() {
+                    distributed_terms.push(Expression::Mul(Arc::new(vec![
+                        term.clone(),
+                        factors[1].clone(),
+                    ])));
+                }
 
-                    let result = Expression::Add(Arc::new(distributed_terms));
-                    steps.push(Step {
-                        title: "Result of Distribution".to_owned(),
-                        description: format!("Expanded form: {}", result),
-                        expression: result.clone(),
-                        rule_applied: "Distribution Complete".to_owned(),
-                        latex: None,
-                    });
+                let result = Expression::Add(Arc::new(distributed_terms));
+                steps.push(Step {
+                    title: "Result of Distribution".to_owned(),
+                    description: format!("Expanded form: {}", result),
+                    expression: result.clone(),
+                    rule_applied: "Distribution Complete".to_owned(),
+                    latex: None,
+                });
 
-                    return Some((result, steps));
-                } else if let Expression::Add(terms) = &factors[1] {
-                    steps.push(Step {
-                        title: "Distribute Factor".to_owned(),
-                        description: format!("Distribute {} over {}", factors[0], factors[1]),
-                        expression: expr.clone(),
-                        rule_applied: "Distributive Property".to_owned(),
-                        latex: None,
-                    });
+                return Some((result, steps));
+            } else if let Expression::Add(terms) = &factors[1] {
+                steps.push(Step {
+                    title: "Distribute Factor".to_owned(),
+                    description: format!("Distribute {} over {}", factors[0], factors[1]),
+                    expression: expr.clone(),
+                    rule_applied: "Distributive Property".to_owned(),
+                    latex: None,
+                });
 
-                    let mut distributed_terms = Vec::new();
-                    for term in terms.iter() {
-                        distributed_terms.push(Expression::Mul(Arc::new(vec![
-                            factors[0].clone(),
-                            term.clone(),
-                        ])));
-                    }
+                let mut distributed_terms = Vec::new();
+                for term in terms.iter() {
+                    distributed_terms.push(Expression::Mul(Arc::new(vec![
+                        factors[0].clone(),
+                        term.clone(),
+                    ])));
+                }
 
-                    let result = Expression::Add(Arc::new(distributed_terms));
-                    steps.push(Step {
-                        title: "Result of Distribution".to_owned(),
-                        description: format!("Expanded form: {}", result),
-                        expression: result.clone(),
-                        rule_applied: "Distribution Complete".to_owned(),
-                        latex: None,
-                    });
+                let result = Expression::Add(Arc::new(distributed_terms));
+                steps.push(Step {
+                    title: "Result of Distribution".to_owned(),
+                    description: format!("Expanded form: {}", result),
+                    expression: result.clone(),
+                    rule_applied: "Distribution Complete".to_owned(),
+                    latex: None,
+                });
 
-                    return Some((result, steps));
-                }
+                return Some((result, steps));
             }
         }
         Expression::Pow(base, exp) => {
@@ -1163,7 +1159,7 @@ impl Expression {
                 };
                 format!("{}^{{{}}}", base_latex, exp.to_latex(None).unwrap_or_else(|_| "exp".to_string()))
             },
-            Expression::Function { name, args } => {
+            Expression::Function { name, args, .. } => {
                 if args.is_empty() {
                     format!("\\{}", name)
                 } else {
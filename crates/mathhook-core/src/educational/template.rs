@@ -0,0 +1,680 @@
+//! Problem templates with placeholders
+//!
+//! Content authors write a problem once with named holes, such as
+//! `{a}x^2 + {b}x + {c} = 0`, say which values each hole may take and which
+//! conditions the values must meet, and then draw as many concrete problems
+//! as a worksheet needs. Each generated [`Problem`] carries its solved
+//! version: the solutions of an equation, or the simplified form of an
+//! expression.
+//!
+//! Unlike a [`Pattern`](crate::pattern::Pattern), which matches existing
+//! expressions, a template only ever produces them. Holes are written
+//! `{name}` wherever a number may appear, in the template and in its
+//! conditions alike; a hole without declared values takes integers from
+//! `-9` to `9`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use mathhook_core::educational::template::{HoleValues, Solution, Template};
+//! use rand::rngs::StdRng;
+//! use rand::SeedableRng;
+//!
+//! let template = Template::parse("{a}x^2 + {b}x + {c} = 0")
+//!     .unwrap()
+//!     .with_values("a", HoleValues::integers(1, 3))
+//!     .unwrap()
+//!     .with_condition("{b}^2 - 4{a}{c} > 0")
+//!     .unwrap();
+//!
+//! let mut rng = StdRng::seed_from_u64(7);
+//! let problem = template.generate(&mut rng).unwrap();
+//! assert!(matches!(problem.solution, Solution::Solved { .. }));
+//! ```
+
+use crate::core::expression::RelationType;
+use crate::core::{Expression, Symbol};
+use crate::parser::config::ParserConfig;
+use crate::parser::error::ParseError;
+use crate::parser::Parser;
+use crate::simplify::Simplify;
+use crate::solvers::{MathSolver, SolverResult};
+use rand::seq::IndexedRandom;
+use rand::Rng;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::sync::Arc;
+
+/// Prefix of the symbols standing for holes in parsed templates
+const HOLE_PREFIX: &str = "hole_";
+
+/// Values of a hole that was not given any
+const DEFAULT_RANGE: (i64, i64) = (-9, 9);
+
+/// Draws tried per requested problem before giving up on the conditions
+const MAX_ATTEMPTS: usize = 1000;
+
+/// Values a hole may take
+#[derive(Debug, Clone, PartialEq)]
+pub enum HoleValues {
+    /// Every integer from `min` to `max`, inclusive
+    Integers { min: i64, max: i64 },
+    /// One of the listed expressions
+    Choices(Vec<Expression>),
+}
+
+impl HoleValues {
+    pub fn integers(min: i64, max: i64) -> Self {
+        HoleValues::Integers { min, max }
+    }
+
+    fn is_empty(&self) -> bool {
+        match self {
+            HoleValues::Integers { min, max } => min > max,
+            HoleValues::Choices(choices) => choices.is_empty(),
+        }
+    }
+
+    fn draw<R: Rng + ?Sized>(&self, rng: &mut R) -> Expression {
+        match self {
+            HoleValues::Integers { min, max } => Expression::integer(rng.random_range(*min..=*max)),
+            HoleValues::Choices(choices) => choices
+                .choose(rng)
+                .cloned()
+                .unwrap_or_else(|| Expression::integer(0)),
+        }
+    }
+}
+
+/// Named placeholder of a template
+#[derive(Debug, Clone, PartialEq)]
+pub struct Hole {
+    pub name: String,
+    pub values: HoleValues,
+    /// Zero is never drawn, as for the leading coefficient of a quadratic
+    pub nonzero: bool,
+}
+
+/// Why a template could not be built or instantiated
+#[derive(Debug, Clone, PartialEq)]
+pub enum TemplateError {
+    /// A `{` without its `}`, or a hole name that is not an identifier
+    MalformedHole { text: String },
+    /// The template or a condition is not a valid expression
+    Parse(ParseError),
+    /// A condition is not a comparison such as `<` or `≠`
+    NotACondition { condition: Expression },
+    /// The name is not a hole of this template
+    UnknownHole { name: String },
+    /// No value was given for this hole
+    MissingValue { name: String },
+    /// The hole has no values to draw from
+    NoValues { name: String },
+    /// No drawn values met the conditions
+    Unsatisfiable { attempts: usize },
+}
+
+impl fmt::Display for TemplateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TemplateError::MalformedHole { text } => write!(f, "malformed hole in '{}'", text),
+            TemplateError::Parse(error) => write!(f, "{}", error),
+            TemplateError::NotACondition { condition } => {
+                write!(f, "{} is not a comparison", condition)
+            }
+            TemplateError::UnknownHole { name } => write!(f, "no hole named {{{}}}", name),
+            TemplateError::MissingValue { name } => write!(f, "no value for hole {{{}}}", name),
+            TemplateError::NoValues { name } => write!(f, "hole {{{}}} has no values", name),
+            TemplateError::Unsatisfiable { attempts } => {
+                write!(f, "no values met the conditions in {} attempts", attempts)
+            }
+        }
+    }
+}
+
+impl std::error::Error for TemplateError {}
+
+impl From<ParseError> for TemplateError {
+    fn from(error: ParseError) -> Self {
+        TemplateError::Parse(error)
+    }
+}
+
+/// Solved version of a generated problem
+#[derive(Debug, Clone, PartialEq)]
+pub enum Solution {
+    /// Solutions of an equation or inequality
+    Solved {
+        variable: Symbol,
+        result: SolverResult,
+    },
+    /// Simplified form of an expression
+    Simplified(Expression),
+}
+
+/// Concrete problem drawn from a template
+#[derive(Debug, Clone, PartialEq)]
+pub struct Problem {
+    /// Value of each hole, in order of first appearance
+    pub values: Vec<(String, Expression)>,
+    pub problem: Expression,
+    pub solution: Solution,
+}
+
+/// Expression with named holes
+#[derive(Debug, Clone, PartialEq)]
+pub struct Template {
+    expression: Expression,
+    holes: Vec<Hole>,
+    conditions: Vec<Expression>,
+    variable: Option<Symbol>,
+}
+
+impl Template {
+    /// Template from text with `{name}` holes
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for an unclosed or empty hole, or text that does not
+    /// parse once the holes are filled in.
+    pub fn parse(text: &str) -> Result<Self, TemplateError> {
+        let (expression, names) = parse_with_holes(text)?;
+        let holes = names
+            .into_iter()
+            .map(|name| Hole {
+                name,
+                values: HoleValues::integers(DEFAULT_RANGE.0, DEFAULT_RANGE.1),
+                nonzero: false,
+            })
+            .collect();
+        Ok(Self {
+            expression,
+            holes,
+            conditions: Vec::new(),
+            variable: None,
+        })
+    }
+
+    pub fn holes(&self) -> &[Hole] {
+        &self.holes
+    }
+
+    /// Draw hole `name` from `values`
+    pub fn with_values(mut self, name: &str, values: HoleValues) -> Result<Self, TemplateError> {
+        if values.is_empty() {
+            return Err(TemplateError::NoValues {
+                name: name.to_owned(),
+            });
+        }
+        self.hole_mut(name)?.values = values;
+        Ok(self)
+    }
+
+    /// Never draw zero for hole `name`
+    pub fn nonzero(mut self, name: &str) -> Result<Self, TemplateError> {
+        self.hole_mut(name)?.nonzero = true;
+        Ok(self)
+    }
+
+    /// Require a comparison between holes, such as `{b}^2 - 4{a}{c} >= 0`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the condition does not parse, is not a
+    /// comparison, or mentions a hole the template does not have.
+    pub fn with_condition(mut self, condition: &str) -> Result<Self, TemplateError> {
+        let (condition, names) = parse_with_holes(condition)?;
+        let comparison = matches!(
+            &condition,
+            Expression::Relation(data) if data.relation_type != RelationType::Approximate
+                && data.relation_type != RelationType::Similar
+                && data.relation_type != RelationType::Proportional
+                && data.relation_type != RelationType::Congruent
+        );
+        if !comparison {
+            return Err(TemplateError::NotACondition { condition });
+        }
+        if let Some(name) = names.into_iter().find(|name| self.hole(name).is_none()) {
+            return Err(TemplateError::UnknownHole { name });
+        }
+        self.conditions.push(condition);
+        Ok(self)
+    }
+
+    /// Solve generated equations for `variable`
+    ///
+    /// By default an equation is solved for its only symbol; with several
+    /// symbols and no variable set, it is simplified instead.
+    pub fn solve_for(mut self, variable: Symbol) -> Self {
+        self.variable = Some(variable);
+        self
+    }
+
+    /// The problem with each hole replaced by its value
+    ///
+    /// Numbers are combined and terms with a zero coefficient dropped, but
+    /// the problem is not otherwise simplified.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::educational::template::Template;
+    /// use mathhook_core::{expr, Expression};
+    /// use std::collections::HashMap;
+    ///
+    /// let template = Template::parse("{a}x + {b}").unwrap();
+    /// let values = HashMap::from([
+    ///     ("a".to_owned(), Expression::integer(3)),
+    ///     ("b".to_owned(), Expression::integer(0)),
+    /// ]);
+    /// assert_eq!(template.instantiate(&values).unwrap(), expr!(3 * x));
+    /// ```
+    pub fn instantiate(
+        &self,
+        values: &HashMap<String, Expression>,
+    ) -> Result<Expression, TemplateError> {
+        substitute_holes(&self.expression, &self.holes, values)
+    }
+
+    /// A random problem whose hole values meet every condition
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TemplateError::Unsatisfiable`] if no draw meets the
+    /// conditions within a fixed number of attempts.
+    pub fn generate<R: Rng + ?Sized>(&self, rng: &mut R) -> Result<Problem, TemplateError> {
+        for _ in 0..MAX_ATTEMPTS {
+            if let Some(problem) = self.try_generate(rng)? {
+                return Ok(problem);
+            }
+        }
+        Err(TemplateError::Unsatisfiable {
+            attempts: MAX_ATTEMPTS,
+        })
+    }
+
+    /// `count` different problems, as for a worksheet
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TemplateError::Unsatisfiable`] if the template cannot
+    /// produce that many different problems meeting the conditions.
+    pub fn generate_many<R: Rng + ?Sized>(
+        &self,
+        count: usize,
+        rng: &mut R,
+    ) -> Result<Vec<Problem>, TemplateError> {
+        let attempts = MAX_ATTEMPTS.saturating_mul(count.max(1));
+        let mut problems: Vec<Problem> = Vec::with_capacity(count);
+        let mut seen = HashSet::new();
+        for _ in 0..attempts {
+            if problems.len() == count {
+                break;
+            }
+            if let Some(problem) = self.try_generate(rng)? {
+                if seen.insert(problem.problem.clone()) {
+                    problems.push(problem);
+                }
+            }
+        }
+        if problems.len() < count {
+            return Err(TemplateError::Unsatisfiable { attempts });
+        }
+        Ok(problems)
+    }
+
+    /// One draw, or `None` if it breaks a condition
+    fn try_generate<R: Rng + ?Sized>(&self, rng: &mut R) -> Result<Option<Problem>, TemplateError> {
+        let mut drawn = Vec::with_capacity(self.holes.len());
+        for hole in &self.holes {
+            let value = hole.values.draw(rng);
+            if hole.nonzero && value.simplify().is_zero() {
+                return Ok(None);
+            }
+            drawn.push((hole.name.clone(), value));
+        }
+
+        let values: HashMap<String, Expression> = drawn.iter().cloned().collect();
+        for condition in &self.conditions {
+            if !holds(&substitute_holes(condition, &self.holes, &values)?) {
+                return Ok(None);
+            }
+        }
+
+        let problem = self.instantiate(&values)?;
+        let solution = self.solve(&problem);
+        Ok(Some(Problem {
+            values: drawn,
+            problem,
+            solution,
+        }))
+    }
+
+    fn solve(&self, problem: &Expression) -> Solution {
+        let Expression::Relation(data) = problem else {
+            return Solution::Simplified(problem.simplify());
+        };
+        let variable = self.variable.clone().or_else(|| {
+            let mut symbols = data.left.find_variables();
+            symbols.extend(data.right.find_variables());
+            symbols.sort_by(|a, b| a.name().cmp(b.name()));
+            symbols.dedup();
+            (symbols.len() == 1).then(|| symbols.remove(0))
+        });
+        match variable {
+            Some(variable) => Solution::Solved {
+                result: MathSolver::new().solve(problem, &variable),
+                variable,
+            },
+            None => Solution::Simplified(problem.simplify()),
+        }
+    }
+
+    fn hole(&self, name: &str) -> Option<&Hole> {
+        self.holes.iter().find(|hole| hole.name == name)
+    }
+
+    fn hole_mut(&mut self, name: &str) -> Result<&mut Hole, TemplateError> {
+        self.holes
+            .iter_mut()
+            .find(|hole| hole.name == name)
+            .ok_or_else(|| TemplateError::UnknownHole {
+                name: name.to_owned(),
+            })
+    }
+}
+
+/// Parse `text` with each `{name}` read as a symbol, returning the hole
+/// names in order of first appearance
+fn parse_with_holes(text: &str) -> Result<(Expression, Vec<String>), TemplateError> {
+    let malformed = || TemplateError::MalformedHole {
+        text: text.to_owned(),
+    };
+    let mut rewritten = String::with_capacity(text.len());
+    let mut names: Vec<String> = Vec::new();
+    let mut rest = text;
+    while let Some(open) = rest.find('{') {
+        let close = rest[open..].find('}').ok_or_else(malformed)? + open;
+        let name = rest[open + 1..close].trim();
+        let identifier = name.chars().next().is_some_and(char::is_alphabetic)
+            && name.chars().all(|c| c.is_alphanumeric() || c == '_');
+        if !identifier {
+            return Err(malformed());
+        }
+        if !names.iter().any(|known| known == name) {
+            names.push(name.to_owned());
+        }
+        rewritten.push_str(&rest[..open]);
+        rewritten.push_str(&format!("({}{})", HOLE_PREFIX, name));
+        rest = &rest[close + 1..];
+    }
+    if rest.contains('}') {
+        return Err(malformed());
+    }
+    rewritten.push_str(rest);
+
+    let expression = Parser::new(&ParserConfig::default()).parse(&rewritten)?;
+    Ok((expression, names))
+}
+
+fn substitute_holes(
+    expression: &Expression,
+    holes: &[Hole],
+    values: &HashMap<String, Expression>,
+) -> Result<Expression, TemplateError> {
+    let mut substitutions = HashMap::with_capacity(holes.len());
+    for hole in holes {
+        let value = values
+            .get(&hole.name)
+            .ok_or_else(|| TemplateError::MissingValue {
+                name: hole.name.clone(),
+            })?;
+        substitutions.insert(format!("{}{}", HOLE_PREFIX, hole.name), value.clone());
+    }
+    Ok(fill(expression, &substitutions))
+}
+
+/// Replace hole symbols without canonicalizing, so the problem keeps the
+/// shape its author wrote
+fn fill(expression: &Expression, substitutions: &HashMap<String, Expression>) -> Expression {
+    match expression {
+        Expression::Symbol(symbol) => substitutions
+            .get(symbol.name())
+            .cloned()
+            .unwrap_or_else(|| expression.clone()),
+        _ => tidy(expression.map_children(|child| fill(child, substitutions))),
+    }
+}
+
+/// Drop the zero terms, unit factors and unit exponents a hole value leaves
+fn tidy(expression: Expression) -> Expression {
+    match expression {
+        Expression::Add(terms) if terms.iter().any(Expression::is_zero_fast) => {
+            let terms: Vec<Expression> = terms
+                .iter()
+                .filter(|term| !term.is_zero_fast())
+                .cloned()
+                .collect();
+            match terms.len() {
+                0 => Expression::integer(0),
+                1 => terms
+                    .into_iter()
+                    .next()
+                    .unwrap_or_else(|| Expression::integer(0)),
+                _ => Expression::Add(Arc::new(terms)),
+            }
+        }
+        Expression::Mul(factors) if factors.iter().any(Expression::is_zero_fast) => {
+            Expression::integer(0)
+        }
+        Expression::Mul(factors) if factors.iter().any(Expression::is_one_fast) => {
+            let factors: Vec<Expression> = factors
+                .iter()
+                .filter(|factor| !factor.is_one_fast())
+                .cloned()
+                .collect();
+            match factors.len() {
+                0 => Expression::integer(1),
+                1 => factors
+                    .into_iter()
+                    .next()
+                    .unwrap_or_else(|| Expression::integer(1)),
+                _ => Expression::Mul(Arc::new(factors)),
+            }
+        }
+        Expression::Pow(base, exp) if exp.is_one_fast() => Arc::unwrap_or_clone(base),
+        Expression::Pow(_, exp) if exp.is_zero_fast() => Expression::integer(1),
+        other => other,
+    }
+}
+
+/// Whether a comparison of numbers holds; `false` if a side is not a number
+fn holds(condition: &Expression) -> bool {
+    let Expression::Relation(data) = condition else {
+        return false;
+    };
+    let difference = Expression::add(vec![
+        data.left.clone(),
+        Expression::mul(vec![Expression::integer(-1), data.right.clone()]),
+    ])
+    .simplify();
+    if difference.is_zero() {
+        return matches!(
+            data.relation_type,
+            RelationType::Equal | RelationType::LessEqual | RelationType::GreaterEqual
+        );
+    }
+    let Ok(value) = difference.evaluate_to_f64() else {
+        return false;
+    };
+    match data.relation_type {
+        RelationType::Equal => false,
+        RelationType::NotEqual => true,
+        RelationType::Less => value < 0.0,
+        RelationType::LessEqual => value <= 0.0,
+        RelationType::Greater => value > 0.0,
+        RelationType::GreaterEqual => value >= 0.0,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{expr, symbol};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn values(pairs: &[(&str, i64)]) -> HashMap<String, Expression> {
+        pairs
+            .iter()
+            .map(|(name, value)| (name.to_string(), Expression::integer(*value)))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_collects_holes_in_order() {
+        let template = Template::parse("{b}x + {a}x^2 + {b}").unwrap();
+        let names: Vec<&str> = template.holes().iter().map(|h| h.name.as_str()).collect();
+        assert_eq!(names, vec!["b", "a"]);
+
+        assert!(matches!(
+            Template::parse("{a x + 1"),
+            Err(TemplateError::MalformedHole { .. })
+        ));
+        assert!(matches!(
+            Template::parse("{} + x"),
+            Err(TemplateError::MalformedHole { .. })
+        ));
+        assert!(matches!(
+            Template::parse("{a} + x }"),
+            Err(TemplateError::MalformedHole { .. })
+        ));
+    }
+
+    #[test]
+    fn test_instantiate() {
+        let template = Template::parse("{a}x^2 + {b}x + {c} = 0").unwrap();
+        let equation = template
+            .instantiate(&values(&[("a", 1), ("b", -3), ("c", 2)]))
+            .unwrap();
+        assert_eq!(
+            equation,
+            Expression::equation(expr!((x ^ 2) + (-3 * x) + 2), expr!(0))
+        );
+
+        assert_eq!(
+            template.instantiate(&values(&[("a", 1)])),
+            Err(TemplateError::MissingValue {
+                name: "b".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn test_expression_template_keeps_problem_unsimplified() {
+        let template = Template::parse("{a}(x + {b}) + {c}x").unwrap();
+        let problem = template
+            .instantiate(&values(&[("a", 2), ("b", 3), ("c", -1)]))
+            .unwrap();
+        assert_ne!(problem, problem.simplify());
+
+        let solution = template.solve(&problem);
+        assert_eq!(solution, Solution::Simplified(expr!(x + 6).simplify()));
+    }
+
+    #[test]
+    fn test_generated_problems_meet_conditions() {
+        let template = Template::parse("{a}x^2 + {b}x + {c} = 0")
+            .unwrap()
+            .with_values("a", HoleValues::integers(-3, 3))
+            .unwrap()
+            .nonzero("a")
+            .unwrap()
+            .with_condition("{b}^2 - 4{a}{c} > 0")
+            .unwrap();
+
+        let mut rng = StdRng::seed_from_u64(42);
+        let problems = template.generate_many(10, &mut rng).unwrap();
+        assert_eq!(problems.len(), 10);
+
+        let distinct: HashSet<_> = problems.iter().map(|p| p.problem.clone()).collect();
+        assert_eq!(distinct.len(), 10);
+
+        let x = symbol!(x);
+        for problem in &problems {
+            let value = |name: &str| match problem.values.iter().find(|(n, _)| n == name) {
+                Some((_, Expression::Number(crate::core::Number::Integer(v)))) => *v,
+                other => panic!("unexpected value {:?}", other),
+            };
+            let (a, b, c) = (value("a"), value("b"), value("c"));
+            assert_ne!(a, 0);
+            assert!(b * b - 4 * a * c > 0);
+            assert!((-3..=3).contains(&a));
+
+            let Solution::Solved { variable, result } = &problem.solution else {
+                panic!("expected solutions for {}", problem.problem);
+            };
+            assert_eq!(variable, &x);
+            let SolverResult::Multiple(roots) = result else {
+                panic!(
+                    "expected two roots for {}, got {:?}",
+                    problem.problem, result
+                );
+            };
+            assert_eq!(roots.len(), 2);
+        }
+    }
+
+    #[test]
+    fn test_choices_and_unsatisfiable_conditions() {
+        let template = Template::parse("x + {k} = 0")
+            .unwrap()
+            .with_values(
+                "k",
+                HoleValues::Choices(vec![Expression::integer(2), Expression::integer(4)]),
+            )
+            .unwrap();
+        let mut rng = StdRng::seed_from_u64(1);
+        let problem = template.generate(&mut rng).unwrap();
+        let Solution::Solved { result, .. } = &problem.solution else {
+            panic!("expected a solved equation");
+        };
+        assert!(matches!(
+            result,
+            SolverResult::Single(root) if *root == expr!(-2) || *root == expr!(-4)
+        ));
+
+        assert!(matches!(
+            template.generate_many(3, &mut rng),
+            Err(TemplateError::Unsatisfiable { .. })
+        ));
+
+        let impossible = template.with_condition("{k} > 10").unwrap();
+        assert!(matches!(
+            impossible.generate(&mut rng),
+            Err(TemplateError::Unsatisfiable { .. })
+        ));
+    }
+
+    #[test]
+    fn test_rejects_bad_conditions() {
+        let template = Template::parse("{a}x").unwrap();
+        assert!(matches!(
+            template.clone().with_condition("{a} + 1"),
+            Err(TemplateError::NotACondition { .. })
+        ));
+        assert_eq!(
+            template.clone().with_condition("{z} > 0"),
+            Err(TemplateError::UnknownHole {
+                name: "z".to_owned()
+            })
+        );
+        assert_eq!(
+            template.with_values("a", HoleValues::integers(3, 1)),
+            Err(TemplateError::NoValues {
+                name: "a".to_owned()
+            })
+        );
+    }
+}
@@ -0,0 +1,596 @@
+//! Validation and linting of student-entered expressions
+//!
+//! Given an already-parsed expression and a description of what the exercise
+//! expects, `analyze` reports likely mistakes (unexpected symbols, malformed
+//! equations, wrong polynomial degree, misspelled function names) as structured
+//! diagnostics that UI layers and bindings can surface next to the input field.
+
+use crate::core::expression::RelationType;
+use crate::core::polynomial::PolynomialProperties;
+use crate::core::{Expression, Symbol};
+use crate::functions::intelligence::get_universal_registry;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// Severity of a single diagnostic
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum DiagnosticSeverity {
+    Info,
+    Warning,
+    Error,
+}
+
+/// Category of issue detected in the input
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DiagnosticKind {
+    /// Symbol not in the expected variable set
+    UndefinedSymbol,
+    /// Relation nested inside arithmetic, chained relations, or missing/unexpected `=`
+    UnbalancedEquation,
+    /// Polynomial degree differs from what the exercise asks for
+    DegreeMismatch,
+    /// Unknown function or symbol name that closely resembles a known function
+    LikelyTypo,
+}
+
+/// A single structured finding about the analyzed expression
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Diagnostic {
+    pub kind: DiagnosticKind,
+    pub severity: DiagnosticSeverity,
+    pub message: String,
+    /// Offending subexpression, when the issue can be localized
+    pub subexpression: Option<Expression>,
+    /// Machine-readable replacement suggestion (e.g. a corrected function name)
+    pub suggestion: Option<String>,
+}
+
+impl Diagnostic {
+    fn new(kind: DiagnosticKind, severity: DiagnosticSeverity, message: String) -> Self {
+        Self {
+            kind,
+            severity,
+            message,
+            subexpression: None,
+            suggestion: None,
+        }
+    }
+
+    fn at(mut self, expr: &Expression) -> Self {
+        self.subexpression = Some(expr.clone());
+        self
+    }
+
+    fn suggest<S: Into<String>>(mut self, suggestion: S) -> Self {
+        self.suggestion = Some(suggestion.into());
+        self
+    }
+}
+
+/// What the exercise expects from the student's input
+///
+/// All checks are opt-in: an empty `ExpectedInput` only runs the structural
+/// and typo checks, which do not depend on the exercise.
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::educational::validation::ExpectedInput;
+/// use mathhook_core::symbol;
+///
+/// let expected = ExpectedInput::new()
+///     .with_variables(vec![symbol!(x)])
+///     .expect_equation(true)
+///     .with_degree(2);
+/// assert_eq!(expected.variables.len(), 1);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ExpectedInput {
+    /// Symbols the student is allowed to use; empty disables the check
+    pub variables: Vec<Symbol>,
+    /// `Some(true)` requires an equation, `Some(false)` forbids one
+    pub equation: Option<bool>,
+    /// Required polynomial degree in the first expected variable
+    pub degree: Option<i64>,
+}
+
+impl ExpectedInput {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_variables(mut self, variables: Vec<Symbol>) -> Self {
+        self.variables = variables;
+        self
+    }
+
+    pub fn expect_equation(mut self, equation: bool) -> Self {
+        self.equation = Some(equation);
+        self
+    }
+
+    pub fn with_degree(mut self, degree: i64) -> Self {
+        self.degree = Some(degree);
+        self
+    }
+}
+
+/// Result of analyzing an expression
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AnalysisReport {
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl AnalysisReport {
+    /// True when no diagnostic has `Error` severity
+    pub fn is_valid(&self) -> bool {
+        !self
+            .diagnostics
+            .iter()
+            .any(|d| d.severity == DiagnosticSeverity::Error)
+    }
+
+    /// True when there are no diagnostics at all
+    pub fn is_clean(&self) -> bool {
+        self.diagnostics.is_empty()
+    }
+
+    /// Diagnostics of a given kind
+    pub fn of_kind(&self, kind: DiagnosticKind) -> Vec<&Diagnostic> {
+        self.diagnostics.iter().filter(|d| d.kind == kind).collect()
+    }
+
+    /// Serialize the report for binding layers
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+}
+
+/// Common long-form or misspelled names students type instead of the canonical function
+const FUNCTION_ALIASES: &[(&str, &str)] = &[
+    ("sine", "sin"),
+    ("cosine", "cos"),
+    ("tangent", "tan"),
+    ("cotangent", "cot"),
+    ("secant", "sec"),
+    ("cosecant", "csc"),
+    ("arcsine", "arcsin"),
+    ("arccosine", "arccos"),
+    ("arctangent", "arctan"),
+    ("asin", "arcsin"),
+    ("acos", "arccos"),
+    ("atan", "arctan"),
+    ("sqr", "sqrt"),
+    ("squareroot", "sqrt"),
+    ("root", "sqrt"),
+    ("absolute", "abs"),
+    ("logarithm", "log"),
+    ("lg", "log"),
+    ("In", "ln"),
+    ("expo", "exp"),
+];
+
+/// Function names students commonly glue to their argument (`sinx`)
+const GLUEABLE_FUNCTIONS: &[&str] = &[
+    "sin", "cos", "tan", "cot", "sec", "csc", "ln", "log", "exp", "sqrt",
+];
+
+/// Analyze a parsed student expression against the exercise expectations
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::educational::validation::{analyze, DiagnosticKind, ExpectedInput};
+/// use mathhook_core::{expr, symbol, Expression};
+///
+/// let input = Expression::function("sine", vec![expr!(x)]);
+/// let report = analyze(&input, &ExpectedInput::new().with_variables(vec![symbol!(x)]));
+///
+/// let typos = report.of_kind(DiagnosticKind::LikelyTypo);
+/// assert_eq!(typos[0].suggestion.as_deref(), Some("sin"));
+/// ```
+pub fn analyze(expr: &Expression, expected: &ExpectedInput) -> AnalysisReport {
+    let mut diagnostics = Vec::new();
+
+    check_equation_structure(expr, expected, &mut diagnostics);
+    check_nested_relations(expr, false, &mut diagnostics);
+    check_names(expr, expected, &mut diagnostics);
+    check_degree(expr, expected, &mut diagnostics);
+
+    diagnostics.sort_by_key(|d| std::cmp::Reverse(d.severity));
+    AnalysisReport { diagnostics }
+}
+
+fn check_equation_structure(
+    expr: &Expression,
+    expected: &ExpectedInput,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let is_equation = matches!(
+        expr,
+        Expression::Relation(data) if data.relation_type == RelationType::Equal
+    );
+
+    match (expected.equation, expr) {
+        (Some(true), Expression::Relation(data)) if !is_equation => diagnostics.push(
+            Diagnostic::new(
+                DiagnosticKind::UnbalancedEquation,
+                DiagnosticSeverity::Error,
+                format!(
+                    "An equation was expected, but the input uses '{}' instead of '='",
+                    data.relation_type.symbol()
+                ),
+            )
+            .at(expr),
+        ),
+        (Some(true), _) if !is_equation => diagnostics.push(
+            Diagnostic::new(
+                DiagnosticKind::UnbalancedEquation,
+                DiagnosticSeverity::Error,
+                "An equation was expected, but the input has no '=' sign".to_owned(),
+            )
+            .at(expr),
+        ),
+        (Some(false), _) if is_equation => diagnostics.push(
+            Diagnostic::new(
+                DiagnosticKind::UnbalancedEquation,
+                DiagnosticSeverity::Error,
+                "An expression was expected, but the input is an equation".to_owned(),
+            )
+            .at(expr),
+        ),
+        _ => {}
+    }
+
+    if let Expression::Relation(data) = expr {
+        if data.left == data.right {
+            diagnostics.push(
+                Diagnostic::new(
+                    DiagnosticKind::UnbalancedEquation,
+                    DiagnosticSeverity::Info,
+                    "Both sides of the relation are identical".to_owned(),
+                )
+                .at(expr),
+            );
+        }
+    }
+}
+
+/// Flag relations that appear anywhere other than the root (`x + (y = 2)`, `a = b = c`)
+fn check_nested_relations(expr: &Expression, nested: bool, diagnostics: &mut Vec<Diagnostic>) {
+    if let Expression::Relation(data) = expr {
+        if nested {
+            diagnostics.push(
+                Diagnostic::new(
+                    DiagnosticKind::UnbalancedEquation,
+                    DiagnosticSeverity::Error,
+                    "A relation appears inside another expression or relation".to_owned(),
+                )
+                .at(expr),
+            );
+        }
+        check_nested_relations(&data.left, true, diagnostics);
+        check_nested_relations(&data.right, true, diagnostics);
+        return;
+    }
+
+    for child in children(expr) {
+        check_nested_relations(child, true, diagnostics);
+    }
+}
+
+fn check_names(expr: &Expression, expected: &ExpectedInput, diagnostics: &mut Vec<Diagnostic>) {
+    let allowed: HashSet<&str> = expected.variables.iter().map(|s| s.name()).collect();
+    let mut reported = HashSet::new();
+    visit_names(expr, &allowed, &mut reported, diagnostics);
+}
+
+fn visit_names<'a>(
+    expr: &'a Expression,
+    allowed: &HashSet<&str>,
+    reported: &mut HashSet<&'a str>,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    match expr {
+        Expression::Symbol(s) => {
+            let name = s.name();
+            if !reported.insert(name) {
+                return;
+            }
+            if let Some((func, arg)) = split_glued_function(name, allowed) {
+                diagnostics.push(
+                    Diagnostic::new(
+                        DiagnosticKind::LikelyTypo,
+                        DiagnosticSeverity::Warning,
+                        format!(
+                            "'{}' looks like {}({}) written without parentheses",
+                            name, func, arg
+                        ),
+                    )
+                    .at(expr)
+                    .suggest(format!("{}({})", func, arg)),
+                );
+            } else if !allowed.is_empty() && !allowed.contains(name) {
+                diagnostics.push(
+                    Diagnostic::new(
+                        DiagnosticKind::UndefinedSymbol,
+                        DiagnosticSeverity::Warning,
+                        format!("Symbol '{}' is not one of the expected variables", name),
+                    )
+                    .at(expr),
+                );
+            }
+        }
+        Expression::Function { name, args, .. } => {
+            if reported.insert(name.as_ref()) {
+                if let Some(suggestion) = suggest_function_name(name) {
+                    diagnostics.push(
+                        Diagnostic::new(
+                            DiagnosticKind::LikelyTypo,
+                            DiagnosticSeverity::Warning,
+                            format!(
+                                "Unknown function '{}'; did you mean '{}'?",
+                                name, suggestion
+                            ),
+                        )
+                        .at(expr)
+                        .suggest(suggestion),
+                    );
+                }
+            }
+            for arg in args.iter() {
+                visit_names(arg, allowed, reported, diagnostics);
+            }
+        }
+        _ => {
+            for child in children(expr) {
+                visit_names(child, allowed, reported, diagnostics);
+            }
+        }
+    }
+}
+
+fn check_degree(expr: &Expression, expected: &ExpectedInput, diagnostics: &mut Vec<Diagnostic>) {
+    let (Some(wanted), Some(var)) = (expected.degree, expected.variables.first()) else {
+        return;
+    };
+
+    let actual = match expr {
+        Expression::Relation(data) => {
+            let left = data.left.degree(var);
+            let right = data.right.degree(var);
+            left.zip(right).map(|(l, r)| l.max(r))
+        }
+        _ => expr.degree(var),
+    };
+
+    if let Some(actual) = actual {
+        if actual != wanted {
+            diagnostics.push(
+                Diagnostic::new(
+                    DiagnosticKind::DegreeMismatch,
+                    DiagnosticSeverity::Warning,
+                    format!(
+                        "Expected degree {} in {}, but the input has degree {}",
+                        wanted,
+                        var.name(),
+                        actual
+                    ),
+                )
+                .at(expr),
+            );
+        }
+    }
+}
+
+/// Suggest the canonical name for an unknown function, if one is close enough
+fn suggest_function_name(name: &str) -> Option<String> {
+    let registry = get_universal_registry();
+    if registry.has_intelligence(name) || name.chars().count() < 2 {
+        return None;
+    }
+
+    if let Some((_, canonical)) = FUNCTION_ALIASES.iter().find(|(alias, _)| *alias == name) {
+        return Some((*canonical).to_owned());
+    }
+
+    if name.chars().count() < 3 {
+        return None;
+    }
+
+    let mut candidates = registry.list_all_functions();
+    candidates.sort();
+    candidates
+        .into_iter()
+        .filter(|candidate| candidate.len() >= 3)
+        .map(|candidate| (edit_distance(name, &candidate), candidate))
+        .filter(|(distance, _)| *distance == 1)
+        .map(|(_, candidate)| candidate)
+        .next()
+}
+
+/// Detect single symbols such as `sinx` or `lnt` that are a function name glued to
+/// one of the expected variables
+///
+/// The rest of the name must be a whole expected variable, so `cost` is only
+/// read as `cos(t)` when `t` is expected, and known functions such as `sinh`
+/// are never split.
+fn split_glued_function<'a>(
+    name: &'a str,
+    allowed: &HashSet<&str>,
+) -> Option<(&'static str, &'a str)> {
+    if allowed.contains(name) || get_universal_registry().has_intelligence(name) {
+        return None;
+    }
+    GLUEABLE_FUNCTIONS.iter().find_map(|func| {
+        let rest = name.strip_prefix(func)?;
+        allowed.contains(rest).then_some((*func, rest))
+    })
+}
+
+/// Levenshtein distance between two short identifiers
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b_chars: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b_chars.len()).collect();
+    let mut curr = vec![0; b_chars.len() + 1];
+
+    for (i, ca) in a.chars().enumerate() {
+        curr[0] = i + 1;
+        for (j, cb) in b_chars.iter().enumerate() {
+            let cost = usize::from(ca != *cb);
+            curr[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(curr[j] + 1);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+    prev[b_chars.len()]
+}
+
+/// Immediate subexpressions of an expression
+fn children(expr: &Expression) -> Vec<&Expression> {
+    match expr {
+        Expression::Add(terms) | Expression::Mul(terms) | Expression::Set(terms) => {
+            terms.iter().collect()
+        }
+        Expression::Pow(base, exp) => vec![base.as_ref(), exp.as_ref()],
+        Expression::Function { args, .. } => args.iter().collect(),
+        Expression::Complex(data) => vec![&data.real, &data.imag],
+        Expression::Relation(data) => vec![&data.left, &data.right],
+        Expression::Piecewise(data) => {
+            let mut out: Vec<&Expression> = data.pieces.iter().flat_map(|(c, v)| [c, v]).collect();
+            out.extend(data.default.iter());
+            out
+        }
+        Expression::Interval(data) => vec![&data.start, &data.end],
+        Expression::MethodCall(data) => {
+            let mut out = vec![&data.object];
+            out.extend(data.args.iter());
+            out
+        }
+        Expression::Number(_)
+        | Expression::Symbol(_)
+        | Expression::Constant(_)
+        | Expression::Matrix(_)
+        | Expression::Calculus(_) => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{expr, symbol};
+
+    #[test]
+    fn test_clean_input_has_no_diagnostics() {
+        let x = symbol!(x);
+        let input = Expression::equation(expr!((x ^ 2) + 1), expr!(5));
+        let expected = ExpectedInput::new()
+            .with_variables(vec![x])
+            .expect_equation(true)
+            .with_degree(2);
+
+        let report = analyze(&input, &expected);
+        assert!(report.is_clean(), "{:?}", report.diagnostics);
+    }
+
+    #[test]
+    fn test_undefined_symbol_reported_once() {
+        let input = expr!(x + y + (2 * y));
+        let report = analyze(
+            &input,
+            &ExpectedInput::new().with_variables(vec![symbol!(x)]),
+        );
+
+        let undefined = report.of_kind(DiagnosticKind::UndefinedSymbol);
+        assert_eq!(undefined.len(), 1);
+        assert!(undefined[0].message.contains("'y'"));
+        assert!(report.is_valid());
+    }
+
+    #[test]
+    fn test_missing_equation_is_error() {
+        let report = analyze(&expr!(x + 1), &ExpectedInput::new().expect_equation(true));
+        assert!(!report.is_valid());
+        assert_eq!(
+            report.diagnostics[0].kind,
+            DiagnosticKind::UnbalancedEquation
+        );
+    }
+
+    #[test]
+    fn test_nested_relation_is_error() {
+        let inner = Expression::equation(expr!(y), expr!(2));
+        let input = Expression::equation(expr!(x), inner);
+
+        let report = analyze(&input, &ExpectedInput::new());
+        assert!(!report.is_valid());
+    }
+
+    #[test]
+    fn test_degree_mismatch() {
+        let x = symbol!(x);
+        let input = Expression::equation(expr!((x ^ 3) + x), expr!(0));
+        let expected = ExpectedInput::new().with_variables(vec![x]).with_degree(2);
+
+        let report = analyze(&input, &expected);
+        let mismatches = report.of_kind(DiagnosticKind::DegreeMismatch);
+        assert_eq!(mismatches.len(), 1);
+        assert!(mismatches[0].message.contains("degree 3"));
+    }
+
+    #[test]
+    fn test_function_typos() {
+        let input = Expression::add(vec![
+            Expression::function("sine", vec![expr!(x)]),
+            Expression::function("coss", vec![expr!(x)]),
+            Expression::function("f", vec![expr!(x)]),
+        ]);
+
+        let report = analyze(&input, &ExpectedInput::new());
+        let suggestions: Vec<_> = report
+            .of_kind(DiagnosticKind::LikelyTypo)
+            .iter()
+            .filter_map(|d| d.suggestion.clone())
+            .collect();
+        assert!(suggestions.contains(&"sin".to_owned()));
+        assert!(suggestions.contains(&"cos".to_owned()));
+        assert_eq!(suggestions.len(), 2);
+    }
+
+    #[test]
+    fn test_glued_function_symbol() {
+        let input = Expression::symbol(symbol!(sinx));
+        let expected = ExpectedInput::new().with_variables(vec![symbol!(x)]);
+        let report = analyze(&input, &expected);
+
+        let typos = report.of_kind(DiagnosticKind::LikelyTypo);
+        assert_eq!(typos[0].suggestion.as_deref(), Some("sin(x)"));
+    }
+
+    #[test]
+    fn test_words_and_functions_are_not_glued() {
+        let input = expr!(cost + sinh);
+        let report = analyze(&input, &ExpectedInput::new());
+        assert!(report.of_kind(DiagnosticKind::LikelyTypo).is_empty());
+
+        let expected = ExpectedInput::new().with_variables(vec![symbol!(h)]);
+        let report = analyze(&Expression::symbol(symbol!(sinh)), &expected);
+        assert!(report.of_kind(DiagnosticKind::LikelyTypo).is_empty());
+    }
+
+    #[test]
+    fn test_inequality_instead_of_equation() {
+        let input = Expression::relation(expr!(x), expr!(3), RelationType::Less);
+        let report = analyze(&input, &ExpectedInput::new().expect_equation(true));
+
+        let errors = report.of_kind(DiagnosticKind::UnbalancedEquation);
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].message.contains("'<'"), "{}", errors[0].message);
+    }
+
+    #[test]
+    fn test_report_serializes() {
+        let report = analyze(&expr!(x + 1), &ExpectedInput::new().expect_equation(true));
+        let json = report.to_json().unwrap();
+        assert!(json.contains("UnbalancedEquation"));
+    }
+}
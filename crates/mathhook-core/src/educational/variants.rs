@@ -0,0 +1,425 @@
+//! Randomized equivalent forms of an expression
+//!
+//! Quiz generators need many surface forms of one expression so that every
+//! variant has the same answer. Each step picks a random subexpression and
+//! rewrites it with an identity: reordering, splitting a number, distributing,
+//! factoring, or introducing a disguised `0` or `1`. Rewritten nodes are built
+//! without canonicalization, which would otherwise undo them.
+//!
+//! A rewrite is kept only if the expression still has the same value at a few
+//! sample points, so a faulty transformation cannot change the answer.
+
+use crate::algebra::{Expand, Factor};
+use crate::core::commutativity::Commutativity;
+use crate::core::expression::FunctionId;
+use crate::core::{Expression, Number, Symbol};
+use rand::seq::{IndexedRandom, SliceRandom};
+use rand::Rng;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Highest difficulty; larger values are clamped
+pub const MAX_DIFFICULTY: u8 = 10;
+
+/// Values given to the variables when checking that a rewrite kept the value
+const SAMPLE_POINTS: [f64; 3] = [0.37, 1.21, 2.53];
+
+const TOLERANCE: f64 = 1e-9;
+
+/// Attempts per requested rewrite; a rewrite can be inapplicable at the
+/// chosen node, such as factoring a symbol
+const ATTEMPTS_PER_REWRITE: usize = 8;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Rewrite {
+    /// `a + b → b + a`
+    Commute,
+    /// `5 → 2 + 3`
+    SplitNumber,
+    /// `a(b + c) → ab + ac`
+    Distribute,
+    /// `ab + ac → a(b + c)`
+    Factor,
+    /// `e → e + 3x - 3x`
+    AddZero,
+    /// `xⁿ → xⁿ⁻¹·x`, `e^{a + b} → eᵃ·eᵇ`
+    SplitPower,
+    /// `e → e + (x + 1)² - x² - 2x - 1`
+    SquareIdentity,
+    /// `e → e·(sin²x + cos²x)`
+    Pythagorean,
+}
+
+impl Rewrite {
+    /// Rewrites allowed at a difficulty; harder levels add identities that
+    /// hide more of the original structure
+    fn available(difficulty: u8) -> &'static [Rewrite] {
+        use Rewrite::*;
+        match difficulty {
+            0..=3 => &[Commute, SplitNumber, Distribute, Factor],
+            4..=6 => &[
+                Commute,
+                SplitNumber,
+                Distribute,
+                Factor,
+                AddZero,
+                SplitPower,
+            ],
+            _ => &[
+                Commute,
+                SplitNumber,
+                Distribute,
+                Factor,
+                AddZero,
+                SplitPower,
+                SquareIdentity,
+                Pythagorean,
+            ],
+        }
+    }
+
+    fn apply<R: Rng + ?Sized>(
+        self,
+        node: &Expression,
+        variables: &[Symbol],
+        rng: &mut R,
+    ) -> Option<Expression> {
+        match self {
+            Rewrite::Commute => {
+                let (terms, is_sum) = match node {
+                    Expression::Add(terms) => (terms, true),
+                    Expression::Mul(factors)
+                        if node.commutativity() == Commutativity::Commutative =>
+                    {
+                        (factors, false)
+                    }
+                    _ => return None,
+                };
+                if terms.len() < 2 {
+                    return None;
+                }
+                let mut shuffled = terms.to_vec();
+                shuffled.shuffle(rng);
+                if shuffled == **terms {
+                    shuffled.rotate_left(1);
+                }
+                Some(if is_sum {
+                    raw_add(shuffled)
+                } else {
+                    raw_mul(shuffled)
+                })
+            }
+            Rewrite::SplitNumber => {
+                let Expression::Number(Number::Integer(n)) = node else {
+                    return None;
+                };
+                let part = rng.random_range(1..=9);
+                if part == *n {
+                    return None;
+                }
+                Some(raw_add(vec![
+                    Expression::integer(part),
+                    Expression::integer(n - part),
+                ]))
+            }
+            Rewrite::Distribute => {
+                if !matches!(node, Expression::Mul(_) | Expression::Pow(..)) {
+                    return None;
+                }
+                Some(node.expand()).filter(|expanded| expanded != node)
+            }
+            Rewrite::Factor => {
+                if !matches!(node, Expression::Add(_)) {
+                    return None;
+                }
+                Some(node.factor()).filter(|factored| factored != node)
+            }
+            Rewrite::AddZero => {
+                let term = random_term(variables, rng);
+                Some(raw_add(vec![
+                    node.clone(),
+                    term.clone(),
+                    raw_mul(vec![Expression::integer(-1), term]),
+                ]))
+            }
+            Rewrite::SplitPower => match node {
+                Expression::Pow(base, exponent) => match exponent.as_ref() {
+                    Expression::Number(Number::Integer(n)) if *n >= 2 => Some(raw_mul(vec![
+                        raw_pow(base.as_ref().clone(), Expression::integer(n - 1)),
+                        base.as_ref().clone(),
+                    ])),
+                    _ => None,
+                },
+                Expression::Function { name, args, .. } if name.as_ref() == "exp" => {
+                    let Expression::Add(terms) = &args[0] else {
+                        return None;
+                    };
+                    let (first, rest) = terms.split_first()?;
+                    Some(raw_mul(vec![
+                        raw_function("exp", vec![first.clone()]),
+                        raw_function("exp", vec![raw_add(rest.to_vec())]),
+                    ]))
+                }
+                _ => None,
+            },
+            Rewrite::SquareIdentity => {
+                let variable = random_variable(variables, rng);
+                Some(raw_add(vec![
+                    node.clone(),
+                    raw_pow(
+                        raw_add(vec![variable.clone(), Expression::integer(1)]),
+                        Expression::integer(2),
+                    ),
+                    raw_mul(vec![
+                        Expression::integer(-1),
+                        raw_pow(variable.clone(), Expression::integer(2)),
+                    ]),
+                    raw_mul(vec![Expression::integer(-2), variable]),
+                    Expression::integer(-1),
+                ]))
+            }
+            Rewrite::Pythagorean => {
+                let angle = random_term(variables, rng);
+                Some(raw_mul(vec![
+                    node.clone(),
+                    raw_add(vec![
+                        raw_pow(
+                            raw_function("sin", vec![angle.clone()]),
+                            Expression::integer(2),
+                        ),
+                        raw_pow(raw_function("cos", vec![angle]), Expression::integer(2)),
+                    ]),
+                ]))
+            }
+        }
+    }
+}
+
+impl Expression {
+    /// A randomly rewritten form with the same value
+    ///
+    /// `difficulty` runs from 1 to [`MAX_DIFFICULTY`] and sets how many
+    /// rewrites are applied and which identities may be used: low levels
+    /// reorder, distribute and factor, higher levels also introduce
+    /// disguised zeros and ones such as `sin²x + cos²x`. The same seed gives
+    /// the same variant.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::expr;
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
+    /// use std::collections::HashMap;
+    ///
+    /// let original = expr!(2 * (x + 3));
+    /// let mut rng = StdRng::seed_from_u64(7);
+    /// let variant = original.random_equivalent(&mut rng, 5);
+    ///
+    /// let at = |e: &mathhook_core::Expression| {
+    ///     e.substitute(&HashMap::from([("x".to_owned(), expr!(4))]))
+    ///         .evaluate_to_f64()
+    ///         .unwrap()
+    /// };
+    /// assert_eq!(at(&variant), at(&original));
+    /// ```
+    pub fn random_equivalent<R: Rng + ?Sized>(&self, rng: &mut R, difficulty: u8) -> Expression {
+        let difficulty = difficulty.clamp(1, MAX_DIFFICULTY);
+        let rewrites = Rewrite::available(difficulty);
+        let mut variables = self.find_variables();
+        variables.sort_by(|a, b| a.name().cmp(b.name()));
+
+        let mut current = self.clone();
+        let mut applied = 0;
+        for _ in 0..usize::from(difficulty) * ATTEMPTS_PER_REWRITE {
+            // Later rewrites can undo earlier ones, such as factoring a sum
+            // that was just distributed
+            if applied >= difficulty && current != *self {
+                break;
+            }
+            let Some(rewrite) = rewrites.choose(rng).copied() else {
+                break;
+            };
+            let mut target = rng.random_range(0..node_count(&current));
+            let rewritten = rewrite_node(&current, &mut target, &mut |node| {
+                rewrite.apply(node, &variables, rng)
+            });
+            if let Some(candidate) = rewritten {
+                if same_value(&candidate, &current, &variables) {
+                    current = candidate;
+                    applied += 1;
+                }
+            }
+        }
+        current
+    }
+}
+
+/// Rewrite the `target`-th node in pre-order, rebuilding its ancestors
+/// without canonicalization
+fn rewrite_node(
+    expr: &Expression,
+    target: &mut usize,
+    rewrite: &mut dyn FnMut(&Expression) -> Option<Expression>,
+) -> Option<Expression> {
+    if *target == 0 {
+        return rewrite(expr);
+    }
+    *target -= 1;
+
+    let rewrite_children =
+        |children: &[Expression],
+         target: &mut usize,
+         rewrite: &mut dyn FnMut(&Expression) -> Option<Expression>| {
+            for (i, child) in children.iter().enumerate() {
+                let size = node_count(child);
+                if *target < size {
+                    let replaced = rewrite_node(child, target, rewrite)?;
+                    let mut updated = children.to_vec();
+                    updated[i] = replaced;
+                    return Some(updated);
+                }
+                *target -= size;
+            }
+            None
+        };
+
+    match expr {
+        Expression::Add(terms) => rewrite_children(terms, target, rewrite).map(raw_add),
+        Expression::Mul(factors) => rewrite_children(factors, target, rewrite).map(raw_mul),
+        Expression::Pow(base, exponent) => {
+            let children = [base.as_ref().clone(), exponent.as_ref().clone()];
+            rewrite_children(&children, target, rewrite)
+                .map(|updated| raw_pow(updated[0].clone(), updated[1].clone()))
+        }
+        Expression::Function { name, args, .. } => {
+            rewrite_children(args, target, rewrite).map(|updated| raw_function(name, updated))
+        }
+        _ => None,
+    }
+}
+
+fn node_count(expr: &Expression) -> usize {
+    1 + match expr {
+        Expression::Add(terms) | Expression::Mul(terms) => terms.iter().map(node_count).sum(),
+        Expression::Pow(base, exponent) => node_count(base) + node_count(exponent),
+        Expression::Function { args, .. } => args.iter().map(node_count).sum(),
+        _ => 0,
+    }
+}
+
+/// Same value at every sample point where both sides evaluate
+fn same_value(a: &Expression, b: &Expression, variables: &[Symbol]) -> bool {
+    SAMPLE_POINTS.iter().all(|point| {
+        let values: HashMap<String, Expression> = variables
+            .iter()
+            .enumerate()
+            .map(|(i, variable)| {
+                (
+                    variable.name().to_owned(),
+                    Expression::float(point + 0.5 * i as f64),
+                )
+            })
+            .collect();
+        match (
+            a.substitute(&values).evaluate_to_f64(),
+            b.substitute(&values).evaluate_to_f64(),
+        ) {
+            (Ok(x), Ok(y)) => (x - y).abs() <= TOLERANCE * (1.0 + y.abs()),
+            (Err(_), Err(_)) => true,
+            _ => false,
+        }
+    })
+}
+
+/// `k·v` for a random variable `v` and small coefficient `k`
+fn random_term<R: Rng + ?Sized>(variables: &[Symbol], rng: &mut R) -> Expression {
+    let coefficient = Expression::integer(rng.random_range(2..=5));
+    match variables.choose(rng) {
+        Some(variable) => raw_mul(vec![coefficient, Expression::symbol(variable.clone())]),
+        None => coefficient,
+    }
+}
+
+fn random_variable<R: Rng + ?Sized>(variables: &[Symbol], rng: &mut R) -> Expression {
+    match variables.choose(rng) {
+        Some(variable) => Expression::symbol(variable.clone()),
+        None => Expression::integer(rng.random_range(2..=5)),
+    }
+}
+
+fn raw_add(terms: Vec<Expression>) -> Expression {
+    Expression::Add(Arc::new(terms))
+}
+
+fn raw_mul(factors: Vec<Expression>) -> Expression {
+    Expression::Mul(Arc::new(factors))
+}
+
+fn raw_pow(base: Expression, exponent: Expression) -> Expression {
+    Expression::Pow(Arc::new(base), Arc::new(exponent))
+}
+
+fn raw_function(name: &str, args: Vec<Expression>) -> Expression {
+    Expression::Function {
+        name: Arc::from(name),
+        args: Arc::new(args),
+        id: FunctionId::of(name),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn variants(original: &Expression, difficulty: u8) -> Vec<Expression> {
+        (0..20)
+            .map(|seed| original.random_equivalent(&mut StdRng::seed_from_u64(seed), difficulty))
+            .collect()
+    }
+
+    #[test]
+    fn test_variants_keep_the_value() {
+        let original = expr!(((x + 1) ^ 2) - (3 * y));
+        let variables = original.find_variables();
+        for difficulty in [1, 5, 10] {
+            for variant in variants(&original, difficulty) {
+                assert!(
+                    same_value(&variant, &original, &variables),
+                    "{} is not equivalent to {}",
+                    variant,
+                    original
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_variants_differ_from_original_and_each_other() {
+        let original = expr!((2 * x) + 6);
+        let forms = variants(&original, 4);
+        assert!(forms.iter().all(|form| *form != original));
+        assert!(forms.iter().any(|form| *form != forms[0]));
+    }
+
+    #[test]
+    fn test_seed_reproducibility() {
+        let original = expr!(x * (y + 2));
+        let first = original.random_equivalent(&mut StdRng::seed_from_u64(42), 8);
+        let second = original.random_equivalent(&mut StdRng::seed_from_u64(42), 8);
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_difficulty_grows_the_expression() {
+        let original = expr!(x + 1);
+        let average_size = |difficulty| {
+            let forms = variants(&original, difficulty);
+            forms.iter().map(node_count).sum::<usize>() as f64 / forms.len() as f64
+        };
+        assert!(average_size(10) > average_size(1));
+    }
+}
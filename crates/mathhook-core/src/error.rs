@@ -3,8 +3,14 @@
 //! This module defines comprehensive error types for mathematical operations
 //! that can fail due to domain restrictions, undefined behavior, or other
 //! mathematical constraints.
+//!
+//! Every error carries a machine-readable [`Remediation`] hint, and
+//! [`MathError::student`] renders a student-friendly explanation from the
+//! educational message registry.
 
+use crate::core::expression::eval_numeric::{EvalDiagnostic, ViolationKind};
 use crate::core::Expression;
+use crate::educational::message_registry::{MessageBuilder, MessageCategory, MessageType};
 use std::fmt;
 
 /// Comprehensive mathematical error type
@@ -87,6 +93,303 @@ pub enum MathError {
     /// - Attempting to convert symbolic expression to f64
     /// - Evaluation resulted in non-numeric expression
     NonNumericalResult { expression: Expression },
+
+    /// Operation stopped at a specific subexpression, with a suggested fix
+    ///
+    /// # Examples
+    ///
+    /// - Solving `x = 2x + 1` for `x` without collecting terms first
+    Diagnostic {
+        operation: String,
+        subexpression: Expression,
+        reason: String,
+        hint: Remediation,
+    },
+
+    /// Numerical evaluation met a domain violation at a known position
+    ///
+    /// # Examples
+    ///
+    /// - ln(x - 1) at x = 1 under `DomainPolicy::Strict`
+    DomainViolation { diagnostic: EvalDiagnostic },
+}
+
+/// Machine-readable suggestion for recovering from a [`MathError`]
+///
+/// `code` is stable and intended for tooling; `Display` gives a short
+/// developer-facing hint, while the student phrasing lives in the message
+/// registry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum Remediation {
+    /// Move terms containing the variable to one side and collect them
+    CollectTerms,
+    /// Exclude values that make a denominator vanish
+    CheckDenominator,
+    /// Restrict the input to the function's domain
+    RestrictDomain,
+    /// Evaluate away from a singularity or take a limit
+    AvoidSingularity,
+    /// Switch to the complex domain or choose a branch
+    UseComplexDomain,
+    /// Resolve an indeterminate form with a limit
+    TakeLimit,
+    /// Reorder integration bounds
+    SwapBounds,
+    /// Allow more iterations or improve the initial guess
+    IncreaseIterations,
+    /// Use a different numerical method
+    TryAnotherMethod,
+    /// Substitute numeric values for the remaining symbols
+    SubstituteValues,
+    /// Keep the computation exact instead of using machine numbers
+    UseExactArithmetic,
+}
+
+impl Remediation {
+    /// Every remediation, in registry order
+    pub const ALL: [Remediation; 11] = [
+        Remediation::CollectTerms,
+        Remediation::CheckDenominator,
+        Remediation::RestrictDomain,
+        Remediation::AvoidSingularity,
+        Remediation::UseComplexDomain,
+        Remediation::TakeLimit,
+        Remediation::SwapBounds,
+        Remediation::IncreaseIterations,
+        Remediation::TryAnotherMethod,
+        Remediation::SubstituteValues,
+        Remediation::UseExactArithmetic,
+    ];
+
+    /// Stable identifier for tooling
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::error::Remediation;
+    ///
+    /// assert_eq!(Remediation::CollectTerms.code(), "collect_terms");
+    /// ```
+    pub fn code(&self) -> &'static str {
+        match self {
+            Remediation::CollectTerms => "collect_terms",
+            Remediation::CheckDenominator => "check_denominator",
+            Remediation::RestrictDomain => "restrict_domain",
+            Remediation::AvoidSingularity => "avoid_singularity",
+            Remediation::UseComplexDomain => "use_complex_domain",
+            Remediation::TakeLimit => "take_limit",
+            Remediation::SwapBounds => "swap_bounds",
+            Remediation::IncreaseIterations => "increase_iterations",
+            Remediation::TryAnotherMethod => "try_another_method",
+            Remediation::SubstituteValues => "substitute_values",
+            Remediation::UseExactArithmetic => "use_exact_arithmetic",
+        }
+    }
+
+    /// Look up a remediation by its `code`
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|hint| hint.code() == code)
+    }
+}
+
+impl fmt::Display for Remediation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let hint = match self {
+            Remediation::CollectTerms => "variable appears on both sides; try collect",
+            Remediation::CheckDenominator => "denominator vanishes; exclude its zeros",
+            Remediation::RestrictDomain => "input outside the domain; restrict the input",
+            Remediation::AvoidSingularity => "evaluated at a singularity; try a limit",
+            Remediation::UseComplexDomain => "no real value; use the complex domain",
+            Remediation::TakeLimit => "indeterminate form; try a limit",
+            Remediation::SwapBounds => "bounds out of order; swap them and negate",
+            Remediation::IncreaseIterations => {
+                "iteration budget exhausted; raise it or improve the guess"
+            }
+            Remediation::TryAnotherMethod => "method did not converge; try another",
+            Remediation::SubstituteValues => "free symbols remain; substitute values",
+            Remediation::UseExactArithmetic => "machine numbers overflowed; stay exact",
+        };
+        write!(f, "{}", hint)
+    }
+}
+
+impl MathError {
+    /// Create a [`MathError::Diagnostic`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::error::{MathError, Remediation};
+    /// use mathhook_core::expr;
+    ///
+    /// let err = MathError::diagnostic(
+    ///     "solve",
+    ///     expr!(x - (2 * x + 1)),
+    ///     "x appears on both sides",
+    ///     Remediation::CollectTerms,
+    /// );
+    /// assert_eq!(err.hint(), Some(Remediation::CollectTerms));
+    /// assert_eq!(err.operation(), Some("solve"));
+    /// ```
+    pub fn diagnostic(
+        operation: impl Into<String>,
+        subexpression: Expression,
+        reason: impl Into<String>,
+        hint: Remediation,
+    ) -> Self {
+        MathError::Diagnostic {
+            operation: operation.into(),
+            subexpression,
+            reason: reason.into(),
+            hint,
+        }
+    }
+
+    /// Suggested fix for this error, if one applies
+    pub fn hint(&self) -> Option<Remediation> {
+        match self {
+            MathError::DomainError { .. } => Some(Remediation::RestrictDomain),
+            MathError::DivisionByZero => Some(Remediation::CheckDenominator),
+            MathError::Undefined { .. } => Some(Remediation::TakeLimit),
+            MathError::NumericOverflow { .. } => Some(Remediation::UseExactArithmetic),
+            MathError::NotImplemented { .. } => None,
+            MathError::Pole { .. } => Some(Remediation::AvoidSingularity),
+            MathError::BranchCut { .. } => Some(Remediation::UseComplexDomain),
+            MathError::InvalidInterval { .. } => Some(Remediation::SwapBounds),
+            MathError::MaxIterationsReached { .. } => Some(Remediation::IncreaseIterations),
+            MathError::ConvergenceFailed { .. } => Some(Remediation::TryAnotherMethod),
+            MathError::NonNumericalResult { .. } => Some(Remediation::SubstituteValues),
+            MathError::Diagnostic { hint, .. } => Some(*hint),
+            MathError::DomainViolation { diagnostic } => Some(match diagnostic.kind {
+                ViolationKind::DivisionByZero => Remediation::CheckDenominator,
+                ViolationKind::LogOfNonPositive => Remediation::RestrictDomain,
+                ViolationKind::EvenRootOfNegative => Remediation::UseComplexDomain,
+            }),
+        }
+    }
+
+    /// Operation or function that failed, if recorded
+    pub fn operation(&self) -> Option<&str> {
+        match self {
+            MathError::DomainError { operation, .. }
+            | MathError::NumericOverflow { operation }
+            | MathError::Diagnostic { operation, .. } => Some(operation),
+            MathError::DomainViolation { .. } => Some("evaluate"),
+            MathError::Pole { function, .. } | MathError::BranchCut { function, .. } => {
+                Some(function)
+            }
+            _ => None,
+        }
+    }
+
+    /// Offending subexpression, if recorded
+    pub fn subexpression(&self) -> Option<&Expression> {
+        match self {
+            MathError::DomainError { value, .. } | MathError::BranchCut { value, .. } => {
+                Some(value)
+            }
+            MathError::Undefined { expression, .. }
+            | MathError::NonNumericalResult { expression } => Some(expression),
+            MathError::Pole { at, .. } => Some(at),
+            MathError::Diagnostic { subexpression, .. } => Some(subexpression),
+            MathError::DomainViolation { diagnostic } => Some(&diagnostic.subexpression),
+            _ => None,
+        }
+    }
+
+    /// Student-friendly rendering of this error
+    ///
+    /// The explanation and the hint are taken from the educational message
+    /// registry, so the wording can be revised without touching solvers.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::MathError;
+    ///
+    /// let message = MathError::DivisionByZero.student().to_string();
+    /// assert!(message.contains("divide by zero"));
+    /// assert!(message.contains("Hint:"));
+    /// ```
+    pub fn student(&self) -> StudentMessage<'_> {
+        StudentMessage { error: self }
+    }
+
+    /// Registry variant of the student explanation under `MessageCategory::Error`
+    pub(crate) fn message_variant(&self) -> u8 {
+        match self {
+            MathError::DomainError { .. } => 0,
+            MathError::DivisionByZero => 1,
+            MathError::Undefined { .. } => 2,
+            MathError::NumericOverflow { .. } => 3,
+            MathError::NotImplemented { .. } => 4,
+            MathError::Pole { .. } => 5,
+            MathError::BranchCut { .. } => 6,
+            MathError::InvalidInterval { .. } => 7,
+            MathError::MaxIterationsReached { .. } => 8,
+            MathError::ConvergenceFailed { .. } => 9,
+            MathError::NonNumericalResult { .. } => 10,
+            MathError::Diagnostic { .. } => 11,
+            MathError::DomainViolation { .. } => 12,
+        }
+    }
+
+    fn student_builder(&self) -> MessageBuilder {
+        let builder = MessageBuilder::new(
+            MessageCategory::Error,
+            MessageType::Error,
+            self.message_variant(),
+        );
+        let builder = match self.operation() {
+            Some(operation) => builder.with_substitution("operation", operation),
+            None => builder,
+        };
+        let builder = match self.subexpression() {
+            Some(expression) => builder.with_substitution("expression", expression.to_string()),
+            None => builder,
+        };
+        match self {
+            MathError::DomainError { reason, .. }
+            | MathError::Undefined { reason, .. }
+            | MathError::ConvergenceFailed { reason }
+            | MathError::Diagnostic { reason, .. } => builder.with_substitution("reason", reason),
+            MathError::NotImplemented { feature } => builder.with_substitution("feature", feature),
+            MathError::InvalidInterval { lower, upper } => builder
+                .with_substitution("lower", lower.to_string())
+                .with_substitution("upper", upper.to_string()),
+            MathError::MaxIterationsReached { max_iterations } => {
+                builder.with_substitution("max_iterations", max_iterations.to_string())
+            }
+            MathError::DomainViolation { diagnostic } => {
+                builder.with_substitution("reason", diagnostic.kind.to_string())
+            }
+            _ => builder,
+        }
+    }
+}
+
+/// Student-facing `Display` adapter returned by [`MathError::student`]
+#[derive(Debug, Clone, Copy)]
+pub struct StudentMessage<'a> {
+    error: &'a MathError,
+}
+
+impl fmt::Display for StudentMessage<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let Some(step) = self.error.student_builder().build() else {
+            return write!(f, "{}", self.error);
+        };
+        write!(f, "{}: {}", step.title, step.description)?;
+
+        let hint = self.error.hint().and_then(|hint| {
+            MessageBuilder::new(MessageCategory::Error, MessageType::Insight, hint as u8).build()
+        });
+        if let Some(hint) = hint {
+            write!(f, "\nHint: {}", hint.description)?;
+        }
+        Ok(())
+    }
 }
 
 impl fmt::Display for MathError {
@@ -141,6 +444,25 @@ impl fmt::Display for MathError {
                     expression
                 )
             }
+            MathError::Diagnostic {
+                operation,
+                subexpression,
+                reason,
+                hint,
+            } => {
+                write!(
+                    f,
+                    "Cannot complete {} at {}: {} (hint: {})",
+                    operation, subexpression, reason, hint
+                )
+            }
+            MathError::DomainViolation { diagnostic } => {
+                write!(
+                    f,
+                    "Domain violation: {} in {} at path {:?}",
+                    diagnostic.kind, diagnostic.subexpression, diagnostic.path
+                )
+            }
         }
     }
 }
@@ -215,4 +537,96 @@ mod tests {
             .to_string()
             .contains("Cannot convert non-numerical expression to number"));
     }
+
+    #[test]
+    fn test_diagnostic_context_and_hint() {
+        let err = MathError::diagnostic(
+            "solve",
+            expr!(x - (2 * x + 1)),
+            "x appears on both sides",
+            Remediation::CollectTerms,
+        );
+        assert_eq!(err.operation(), Some("solve"));
+        assert_eq!(err.subexpression(), Some(&expr!(x - (2 * x + 1))));
+        assert!(err
+            .to_string()
+            .contains("variable appears on both sides; try collect"));
+
+        let err = MathError::Pole {
+            function: "log".to_string(),
+            at: Expression::integer(0),
+        };
+        assert_eq!(err.hint(), Some(Remediation::AvoidSingularity));
+        assert_eq!(err.operation(), Some("log"));
+        assert_eq!(err.subexpression(), Some(&Expression::integer(0)));
+
+        for hint in Remediation::ALL {
+            assert_eq!(Remediation::from_code(hint.code()), Some(hint));
+        }
+    }
+
+    #[test]
+    fn test_student_messages_come_from_registry() {
+        let errors = [
+            MathError::DomainError {
+                operation: "sqrt".to_string(),
+                value: Expression::integer(-1),
+                reason: "negative input".to_string(),
+            },
+            MathError::DivisionByZero,
+            MathError::Undefined {
+                expression: Expression::integer(0),
+                reason: "indeterminate form".to_string(),
+            },
+            MathError::NumericOverflow {
+                operation: "factorial".to_string(),
+            },
+            MathError::NotImplemented {
+                feature: "groebner bases".to_string(),
+            },
+            MathError::Pole {
+                function: "tan".to_string(),
+                at: Expression::pi(),
+            },
+            MathError::BranchCut {
+                function: "log".to_string(),
+                value: Expression::integer(-1),
+            },
+            MathError::InvalidInterval {
+                lower: 1.0,
+                upper: 0.0,
+            },
+            MathError::MaxIterationsReached { max_iterations: 50 },
+            MathError::ConvergenceFailed {
+                reason: "oscillation".to_string(),
+            },
+            MathError::NonNumericalResult {
+                expression: expr!(x),
+            },
+            MathError::diagnostic(
+                "solve",
+                expr!(x - (2 * x + 1)),
+                "x appears on both sides",
+                Remediation::CollectTerms,
+            ),
+            MathError::DomainViolation {
+                diagnostic: EvalDiagnostic {
+                    kind: ViolationKind::LogOfNonPositive,
+                    path: vec![1, 0],
+                    subexpression: Expression::function("ln", vec![Expression::integer(0)]),
+                },
+            },
+        ];
+
+        for err in &errors {
+            let message = err.student().to_string();
+            assert!(!message.contains('{'), "unfilled placeholder: {}", message);
+            assert_ne!(message, err.to_string());
+            assert_eq!(message.contains("Hint:"), err.hint().is_some());
+        }
+
+        let message = errors[11].student().to_string();
+        assert!(message.contains("solve"));
+        assert!(message.contains("x appears on both sides"));
+    }
 }
@@ -1,10 +1,20 @@
 //! Formatting traits for mathematical expressions
 
 pub mod latex;
+pub mod mathml;
+pub mod matrix_format;
+pub mod number_format;
+pub mod pretty;
+pub mod render;
 pub mod simple;
 pub mod wolfram;
 
 pub use latex::LaTeXFormatter;
+pub use mathml::MathMLFormatter;
+pub use matrix_format::MatrixFormat;
+pub use number_format::NumberFormat;
+pub use pretty::PrettyFormatter;
+pub use render::SvgRenderer;
 pub use simple::SimpleFormatter;
 pub use wolfram::WolframFormatter;
 
@@ -20,6 +30,7 @@ pub enum MathLanguage {
     Human,
     Json,
     Markdown,
+    MathML,
 }
 
 impl MathLanguage {
@@ -32,6 +43,7 @@ impl MathLanguage {
             Self::Human => "human",
             Self::Json => "json",
             Self::Markdown => "markdown",
+            Self::MathML => "mathml",
         }
     }
 }
@@ -127,6 +139,7 @@ impl<C: FormattingContext> ExpressionFormatter<C> for Expression {
                 let latex_result = self.to_latex(latex_context)?;
                 Ok(format!("$${}$$", latex_result))
             }
+            MathLanguage::MathML => self.to_mathml(&mathml::MathMLContext::default()),
             // Default to LaTeX for all other cases including MathLanguage::LaTeX
             _ => {
                 let latex_context = latex::LaTeXContext::default();
@@ -157,6 +170,19 @@ impl Expression {
         self.to_latex(latex_context)
     }
 
+    /// Format expression as a multi-line 2D layout with Unicode characters
+    ///
+    /// # Examples
+    /// ```rust
+    /// use mathhook_core::expr;
+    ///
+    /// assert_eq!(expr!(x ^ 2).pretty().unwrap(), " 2\nx");
+    /// assert_eq!(expr!(x / y).pretty().unwrap(), "x\n─\ny");
+    /// ```
+    pub fn pretty(&self) -> Result<String, FormattingError> {
+        self.to_pretty(&pretty::PrettyContext::default())
+    }
+
     /// Format expression with specific language/format
     ///
     /// # Examples
@@ -169,6 +195,8 @@ impl Expression {
     /// let latex = x_expr.format_as(MathLanguage::LaTeX).unwrap();
     /// let simple = x_expr.format_as(MathLanguage::Simple).unwrap();
     /// let wolfram = x_expr.format_as(MathLanguage::Wolfram).unwrap();
+    /// let mathml = x_expr.format_as(MathLanguage::MathML).unwrap();
+    /// assert!(mathml.starts_with("<math"));
     /// ```
     pub fn format_as(&self, language: MathLanguage) -> Result<String, FormattingError> {
         match language {
@@ -190,6 +218,7 @@ impl Expression {
                 let latex_result = self.to_latex(latex_context)?;
                 Ok(format!("$${}$$", latex_result))
             }
+            MathLanguage::MathML => self.to_mathml(&mathml::MathMLContext::default()),
             // Default to LaTeX
             _ => {
                 let latex_context = latex::LaTeXContext::default();
@@ -197,6 +226,52 @@ impl Expression {
             }
         }
     }
+
+    /// Format expression with a specific language and number display options
+    ///
+    /// # Examples
+    /// ```rust
+    /// use mathhook_core::core::Expression;
+    /// use mathhook_core::formatter::{MathLanguage, NumberFormat};
+    ///
+    /// let value = Expression::float(1.23456);
+    /// let simple = value
+    ///     .format_as_with(MathLanguage::Simple, NumberFormat::significant(3))
+    ///     .unwrap();
+    /// assert_eq!(simple, "1.23");
+    /// ```
+    pub fn format_as_with(
+        &self,
+        language: MathLanguage,
+        number_format: NumberFormat,
+    ) -> Result<String, FormattingError> {
+        match language {
+            MathLanguage::Simple | MathLanguage::Human => self.to_simple(&simple::SimpleContext {
+                number_format,
+                ..Default::default()
+            }),
+            MathLanguage::Wolfram => self.to_wolfram(&wolfram::WolframContext {
+                number_format,
+                ..Default::default()
+            }),
+            MathLanguage::Json => self.format_as(MathLanguage::Json),
+            MathLanguage::Markdown => {
+                let latex_result = self.to_latex(latex::LaTeXContext {
+                    number_format,
+                    ..Default::default()
+                })?;
+                Ok(format!("$${}$$", latex_result))
+            }
+            MathLanguage::MathML => self.to_mathml(&mathml::MathMLContext {
+                number_format,
+                ..Default::default()
+            }),
+            MathLanguage::LaTeX => self.to_latex(latex::LaTeXContext {
+                number_format,
+                ..Default::default()
+            }),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -235,6 +310,218 @@ mod tests {
         assert!(simple_result.is_ok());
     }
 
+    #[test]
+    fn test_number_format_shared_across_formatters() {
+        let value = Expression::float(0.000012345);
+        let format = NumberFormat::scientific(3);
+
+        assert_eq!(
+            value.format_as_with(MathLanguage::Simple, format).unwrap(),
+            "1.23e-5"
+        );
+        assert_eq!(
+            value.format_as_with(MathLanguage::LaTeX, format).unwrap(),
+            "1.23 \\times 10^{-5}"
+        );
+        assert_eq!(
+            value.format_as_with(MathLanguage::Wolfram, format).unwrap(),
+            "1.23*^-5"
+        );
+
+        let half = Expression::rational(1, 2);
+        let decimal = NumberFormat::default().with_decimal_rationals();
+        assert_eq!(
+            half.format_as_with(MathLanguage::LaTeX, decimal).unwrap(),
+            "0.5"
+        );
+        assert_eq!(half.format_as(MathLanguage::Simple).unwrap(), "1/2");
+    }
+
+    #[test]
+    fn test_legacy_float_precision_still_applies() {
+        let context = simple::SimpleContext {
+            float_precision: Some(2),
+            ..Default::default()
+        };
+        assert_eq!(
+            Expression::float(2.0 / 3.0).to_simple(&context).unwrap(),
+            "0.67"
+        );
+    }
+
+    #[test]
+    fn test_calculus_notation_across_formatters() {
+        use crate::symbol;
+
+        let x = symbol!(x);
+        let integral = Expression::definite_integral(expr!(x ^ 2), x.clone(), expr!(0), expr!(1));
+        assert_eq!(integral.format().unwrap(), "\\int_{0}^{1} x^2 \\, dx");
+        assert_eq!(
+            integral.format_as(MathLanguage::Simple).unwrap(),
+            "int_0^1 x^2 dx"
+        );
+        assert_eq!(
+            integral.format_as(MathLanguage::Wolfram).unwrap(),
+            "Integrate[Power[x, 2], {x, 0, 1}]"
+        );
+
+        let limit = Expression::limit(expr!(x), x.clone(), expr!(0));
+        assert_eq!(limit.format().unwrap(), "\\lim_{x \\to 0} x");
+        let unicode = simple::SimpleContext {
+            use_unicode: true,
+            ..Default::default()
+        };
+        assert_eq!(limit.to_simple(&unicode).unwrap(), "lim_(x → 0) x");
+
+        let k = symbol!(k);
+        let sum = Expression::sum(expr!(k), k, expr!(1), expr!(n + 1));
+        assert_eq!(
+            sum.format_as(MathLanguage::Simple).unwrap(),
+            "sum_(k=1)^(1 + n) k"
+        );
+
+        let derivative = Expression::derivative(expr!(x + 1), x, 1);
+        assert_eq!(
+            derivative.format_as(MathLanguage::Simple).unwrap(),
+            "d/dx (1 + x)"
+        );
+    }
+
+    #[test]
+    fn test_matrix_formatting() {
+        use matrix_format::MatrixDelimiter;
+
+        let m = Expression::matrix(vec![
+            vec![Expression::integer(1), Expression::integer(-20)],
+            vec![Expression::integer(300), expr!(x)],
+        ]);
+
+        assert_eq!(
+            m.format().unwrap(),
+            "\\begin{pmatrix} 1 & -20 \\\\ 300 & x \\end{pmatrix}"
+        );
+        let brackets = latex::LaTeXContext {
+            matrix_format: MatrixFormat::default().with_delimiter(MatrixDelimiter::Brackets),
+            ..Default::default()
+        };
+        assert!(m
+            .to_latex(brackets)
+            .unwrap()
+            .starts_with("\\begin{bmatrix}"));
+
+        assert_eq!(
+            m.format_as(MathLanguage::Wolfram).unwrap(),
+            "{{1, -20}, {300, x}}"
+        );
+        assert_eq!(
+            m.format_as(MathLanguage::Simple).unwrap(),
+            "[  1  -20]\n[300    x]"
+        );
+
+        let nested = Expression::function("det", vec![m]);
+        assert_eq!(
+            nested.format_as(MathLanguage::Simple).unwrap(),
+            "det([[1, -20], [300, x]])"
+        );
+    }
+
+    #[test]
+    fn test_large_matrix_is_elided() {
+        let rows: Vec<Vec<Expression>> = (0..50)
+            .map(|i| (0..50).map(|j| Expression::integer(i * 50 + j)).collect())
+            .collect();
+        let m = Expression::matrix(rows);
+        let limits = MatrixFormat::default().with_limits(3, 3);
+
+        let latex = m
+            .to_latex(latex::LaTeXContext {
+                matrix_format: limits,
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(
+            latex,
+            "\\begin{pmatrix} 0 & 1 & \\cdots & 49 \\\\ 50 & 51 & \\cdots & 99 \\\\ \\vdots & \\vdots & \\ddots & \\vdots \\\\ 2450 & 2451 & \\cdots & 2499 \\end{pmatrix}"
+        );
+
+        let wolfram = m
+            .to_wolfram(&wolfram::WolframContext {
+                matrix_format: limits,
+                ..Default::default()
+            })
+            .unwrap();
+        assert!(wolfram.contains("\\[DescendingEllipsis]"));
+
+        let simple = m
+            .to_simple(&simple::SimpleContext {
+                matrix_format: limits,
+                use_unicode: true,
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(simple.lines().count(), 4);
+        assert!(simple.contains('⋱'));
+
+        // Elision is opt-in: by default every row is shown
+        let full = m.format_as(MathLanguage::Simple).unwrap();
+        assert_eq!(full.lines().count(), 50);
+        assert!(!full.contains('⋮'));
+    }
+
+    #[test]
+    fn test_greek_and_subscripted_symbols() {
+        use crate::core::Symbol;
+
+        let theta_max = Expression::symbol(Symbol::new("theta_max"));
+        assert_eq!(theta_max.format().unwrap(), "\\theta_{\\max}");
+        assert_eq!(
+            theta_max.format_as(MathLanguage::Wolfram).unwrap(),
+            "Subscript[\\[Theta], max]"
+        );
+
+        let sum = expr!(alpha + x_1);
+        let latex = sum.format().unwrap();
+        assert!(latex.contains("\\alpha"));
+        assert!(latex.contains("x_{1}"));
+
+        let unicode = simple::SimpleContext {
+            use_unicode: true,
+            ..Default::default()
+        };
+        let simple = sum.to_simple(&unicode).unwrap();
+        assert!(simple.contains('α'));
+        assert!(simple.contains("x₁"));
+        assert!(sum
+            .format_as(MathLanguage::Simple)
+            .unwrap()
+            .contains("alpha"));
+
+        let matrix = Expression::symbol(Symbol::matrix("A_1"));
+        assert_eq!(matrix.format().unwrap(), "\\mathbf{A}_{1}");
+    }
+
+    #[test]
+    fn test_degree_mode_annotation() {
+        use crate::core::AngleUnit;
+
+        let sin_30 = Expression::function("sin", vec![expr!(30)]);
+        let shifted = Expression::function("cos", vec![expr!(x + 15)]);
+
+        let simple = simple::SimpleContext {
+            angle_unit: AngleUnit::Degrees,
+            ..Default::default()
+        };
+        assert_eq!(sin_30.to_simple(&simple).unwrap(), "sin(30°)");
+        assert!(shifted.to_simple(&simple).unwrap().ends_with(")°)"));
+
+        let latex = latex::LaTeXContext {
+            angle_unit: AngleUnit::Degrees,
+            ..Default::default()
+        };
+        assert_eq!(sin_30.to_latex(latex).unwrap(), "\\sin(30^\\circ)");
+        assert_eq!(sin_30.format().unwrap(), "\\sin(30)");
+    }
+
     #[test]
     fn test_comprehensive_formatting() {
         use crate::core::expression::RelationType;
@@ -1,8 +1,13 @@
-use super::{FormattingContext, FormattingError};
+use super::{FormattingContext, FormattingError, MatrixFormat, NumberFormat};
+use crate::core::angle_unit::AngleUnit;
 use crate::core::Expression;
 
+mod calculus;
 mod expressions;
 mod functions;
+mod line_breaking;
+
+pub(crate) use functions::latex_function_name;
 
 const MAX_RECURSION_DEPTH: usize = 1000;
 const MAX_TERMS_PER_OPERATION: usize = 10000;
@@ -11,6 +16,77 @@ const MAX_TERMS_PER_OPERATION: usize = 10000;
 #[derive(Debug, Default, Clone)]
 pub struct LaTeXContext {
     pub needs_parentheses: bool,
+    /// Float/rational display options
+    pub number_format: NumberFormat,
+    /// Matrix environment and size limits
+    pub matrix_format: MatrixFormat,
+    /// Break long top-level sums across lines (`None` keeps a single line)
+    pub line_breaking: Option<LineBreaking>,
+    /// In degrees, trigonometric arguments are annotated: `\sin(30^\circ)`
+    pub angle_unit: AngleUnit,
+}
+
+/// Display environment used for broken expressions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BreakEnvironment {
+    /// `aligned`: continuation lines are indented under the first term, or
+    /// aligned on the relation for equations
+    #[default]
+    Aligned,
+    /// `multline`: first line flush left, last line flush right
+    Multline,
+}
+
+/// Line breaking options for long expressions
+///
+/// Sums at the top level (also the right-hand side of a top-level equation)
+/// longer than `max_line_length` LaTeX characters are split between terms.
+///
+/// # Examples
+/// ```
+/// use mathhook_core::formatter::latex::{LaTeXContext, LaTeXFormatter, LineBreaking};
+/// use mathhook_core::{Expression, Symbol};
+///
+/// let terms = ["a", "b", "c", "d"].iter().map(|n| Expression::symbol(Symbol::new(n)));
+/// let sum = Expression::add(terms.collect());
+/// let context = LaTeXContext {
+///     line_breaking: Some(LineBreaking::aligned(6)),
+///     ..Default::default()
+/// };
+/// assert_eq!(
+///     sum.to_latex(context).unwrap(),
+///     "\\begin{aligned} &a + b \\\\ &\\quad + c \\\\ &\\quad + d \\end{aligned}"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LineBreaking {
+    /// Target length of each line in LaTeX source characters
+    pub max_line_length: usize,
+    pub environment: BreakEnvironment,
+}
+
+impl Default for LineBreaking {
+    fn default() -> Self {
+        Self::aligned(80)
+    }
+}
+
+impl LineBreaking {
+    /// Break into an `aligned` environment
+    pub fn aligned(max_line_length: usize) -> Self {
+        Self {
+            max_line_length,
+            environment: BreakEnvironment::Aligned,
+        }
+    }
+
+    /// Break into a `multline` environment
+    pub fn multline(max_line_length: usize) -> Self {
+        Self {
+            max_line_length,
+            environment: BreakEnvironment::Multline,
+        }
+    }
 }
 
 impl FormattingContext for LaTeXContext {}
@@ -27,6 +103,9 @@ pub trait LaTeXFormatter {
     ///
     /// # Context Options
     /// * `needs_parentheses` - Whether to wrap the entire expression in parentheses
+    /// * `number_format` - Significant figures, notation and rational display for numbers
+    /// * `matrix_format` - Matrix environment (`pmatrix`, `bmatrix`, ...) and size limits
+    /// * `line_breaking` - Split long sums into `aligned`/`multline` environments
     ///
     /// # Examples
     /// ```
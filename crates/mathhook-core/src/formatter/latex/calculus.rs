@@ -0,0 +1,315 @@
+//! Textbook notation for derivatives, integrals, limits, sums and products
+//!
+//! Used both for the inert `Expression::Calculus` nodes and for the
+//! function-call forms produced by the parser (`definite_integral`, `limit`, ...).
+
+use super::{LaTeXContext, LaTeXFormatter};
+use crate::core::expression::{CalculusData, LimitDirection};
+use crate::core::symbol::names::SymbolName;
+use crate::core::{Expression, Symbol};
+use crate::formatter::FormattingError;
+
+/// LaTeX for an inert calculus node
+pub(super) fn format_calculus(
+    calculus_data: &CalculusData,
+    context: &LaTeXContext,
+    depth: usize,
+) -> Result<String, FormattingError> {
+    Ok(match calculus_data {
+        CalculusData::Derivative {
+            expression,
+            variable,
+            order,
+        } => derivative(
+            &operand(expression, context, depth)?,
+            &variable_latex(variable),
+            *order,
+        ),
+        CalculusData::Integral {
+            integrand,
+            variable,
+            bounds,
+        } => {
+            let bounds = match bounds {
+                Some((start, end)) => Some((
+                    start.to_latex_with_depth(context, depth + 1)?,
+                    end.to_latex_with_depth(context, depth + 1)?,
+                )),
+                None => None,
+            };
+            integral(
+                &operand(integrand, context, depth)?,
+                &variable_latex(variable),
+                bounds.as_ref().map(|(a, b)| (a.as_str(), b.as_str())),
+            )
+        }
+        CalculusData::Limit {
+            expression,
+            variable,
+            point,
+            direction,
+        } => limit(
+            &operand(expression, context, depth)?,
+            &format!(
+                "{} \\to {}",
+                variable_latex(variable),
+                approach_point(&point.to_latex_with_depth(context, depth + 1)?, *direction)
+            ),
+        ),
+        CalculusData::Sum {
+            expression,
+            variable,
+            start,
+            end,
+        } => big_operator(
+            "\\sum",
+            &operand(expression, context, depth)?,
+            &format!(
+                "{}={}",
+                variable_latex(variable),
+                start.to_latex_with_depth(context, depth + 1)?
+            ),
+            &end.to_latex_with_depth(context, depth + 1)?,
+        ),
+        CalculusData::Product {
+            expression,
+            variable,
+            start,
+            end,
+        } => big_operator(
+            "\\prod",
+            &operand(expression, context, depth)?,
+            &format!(
+                "{}={}",
+                variable_latex(variable),
+                start.to_latex_with_depth(context, depth + 1)?
+            ),
+            &end.to_latex_with_depth(context, depth + 1)?,
+        ),
+    })
+}
+
+/// LaTeX for a calculus function-call form, or `None` if `args` do not match
+/// one of the recognised shapes
+pub(super) fn format_calculus_function(
+    name: &str,
+    args: &[Expression],
+    context: &LaTeXContext,
+    depth: usize,
+) -> Result<Option<String>, FormattingError> {
+    let latex = |expr: &Expression| expr.to_latex_with_depth(context, depth + 1);
+
+    Ok(Some(match (name, args) {
+        ("integrate" | "indefinite_integral", [integrand, variable]) => integral(
+            &operand(integrand, context, depth)?,
+            &latex(variable)?,
+            None,
+        ),
+        ("integrate" | "definite_integral", [integrand, variable, start, end]) => integral(
+            &operand(integrand, context, depth)?,
+            &latex(variable)?,
+            Some((&latex(start)?, &latex(end)?)),
+        ),
+        ("derivative", [expression, variable]) => {
+            derivative(&operand(expression, context, depth)?, &latex(variable)?, 1)
+        }
+        ("derivative", [expression, variable, Expression::Number(order)]) => {
+            match order.to_string().parse::<u32>() {
+                Ok(order) => derivative(
+                    &operand(expression, context, depth)?,
+                    &latex(variable)?,
+                    order,
+                ),
+                Err(_) => return Ok(None),
+            }
+        }
+        ("sum" | "product", [expression, variable, start, end]) => big_operator(
+            big_operator_command(name),
+            &operand(expression, context, depth)?,
+            &format!("{}={}", latex(variable)?, latex(start)?),
+            &latex(end)?,
+        ),
+        // Parser form: the index is an equation such as `i = 1`
+        ("sum" | "product", [expression, index, end]) => big_operator(
+            big_operator_command(name),
+            &operand(expression, context, depth)?,
+            &latex(index)?,
+            &latex(end)?,
+        ),
+        ("limit", [expression, variable, point]) => limit(
+            &operand(expression, context, depth)?,
+            &format!("{} \\to {}", latex(variable)?, latex(point)?),
+        ),
+        // Parser form: the subscript is `x \to a`
+        ("limit", [expression, approach]) => {
+            let subscript = match approach {
+                Expression::Function { name, args, .. } if name.as_ref() == "approaches" => {
+                    match args.as_slice() {
+                        [variable, point] => {
+                            format!("{} \\to {}", latex(variable)?, latex(point)?)
+                        }
+                        _ => latex(approach)?,
+                    }
+                }
+                _ => latex(approach)?,
+            };
+            limit(&operand(expression, context, depth)?, &subscript)
+        }
+        _ => return Ok(None),
+    }))
+}
+
+fn big_operator_command(name: &str) -> &'static str {
+    if name == "product" {
+        "\\prod"
+    } else {
+        "\\sum"
+    }
+}
+
+fn derivative(operand: &str, variable: &str, order: u32) -> String {
+    if order == 1 {
+        format!("\\frac{{d}}{{d{}}} {}", variable, operand)
+    } else {
+        format!(
+            "\\frac{{d^{{{}}}}}{{d{}^{{{}}}}} {}",
+            order, variable, order, operand
+        )
+    }
+}
+
+fn integral(integrand: &str, variable: &str, bounds: Option<(&str, &str)>) -> String {
+    match bounds {
+        Some((start, end)) => format!(
+            "\\int_{{{}}}^{{{}}} {} \\, d{}",
+            start, end, integrand, variable
+        ),
+        None => format!("\\int {} \\, d{}", integrand, variable),
+    }
+}
+
+fn limit(operand: &str, subscript: &str) -> String {
+    format!("\\lim_{{{}}} {}", subscript, operand)
+}
+
+fn big_operator(command: &str, operand: &str, lower: &str, upper: &str) -> String {
+    format!("{}_{{{}}}^{{{}}} {}", command, lower, upper, operand)
+}
+
+/// One-sided limits put the side as a superscript on the point (`0^{+}`)
+fn approach_point(point: &str, direction: LimitDirection) -> String {
+    match direction {
+        LimitDirection::Both => point.to_owned(),
+        LimitDirection::Left => format!("{}^{{-}}", point),
+        LimitDirection::Right => format!("{}^{{+}}", point),
+    }
+}
+
+fn variable_latex(variable: &Symbol) -> String {
+    SymbolName::parse(variable.name()).to_latex()
+}
+
+/// The operand of a calculus operator; sums are parenthesised so the operator
+/// visibly applies to every term
+fn operand(
+    expression: &Expression,
+    context: &LaTeXContext,
+    depth: usize,
+) -> Result<String, FormattingError> {
+    let latex = expression.to_latex_with_depth(context, depth + 1)?;
+    Ok(match expression {
+        Expression::Add(_) => format!("\\left({}\\right)", latex),
+        _ => latex,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{expr, symbol};
+
+    fn latex(expr: &Expression) -> String {
+        expr.to_latex(None).unwrap()
+    }
+
+    #[test]
+    fn test_inert_derivative() {
+        let x = symbol!(x);
+        assert_eq!(
+            latex(&Expression::derivative(expr!(x ^ 2), x.clone(), 1)),
+            "\\frac{d}{dx} x^2"
+        );
+        assert_eq!(
+            latex(&Expression::derivative(expr!(x + 1), x, 2)),
+            "\\frac{d^{2}}{dx^{2}} \\left(1 + x\\right)"
+        );
+    }
+
+    #[test]
+    fn test_inert_integrals() {
+        let x = symbol!(x);
+        assert_eq!(
+            latex(&Expression::integral(expr!(x), x.clone())),
+            "\\int x \\, dx"
+        );
+        assert_eq!(
+            latex(&Expression::definite_integral(
+                expr!(x),
+                x,
+                expr!(0),
+                expr!(10)
+            )),
+            "\\int_{0}^{10} x \\, dx"
+        );
+    }
+
+    #[test]
+    fn test_inert_limit_uses_point_and_direction() {
+        let x = symbol!(x);
+        assert_eq!(
+            latex(&Expression::limit(expr!(x), x.clone(), expr!(2))),
+            "\\lim_{x \\to 2} x"
+        );
+        let one_sided = Expression::Calculus(std::sync::Arc::new(CalculusData::Limit {
+            expression: expr!(x),
+            variable: x,
+            point: expr!(0),
+            direction: LimitDirection::Right,
+        }));
+        assert_eq!(latex(&one_sided), "\\lim_{x \\to 0^{+}} x");
+    }
+
+    #[test]
+    fn test_inert_sum_and_product() {
+        let k = symbol!(k);
+        assert_eq!(
+            latex(&Expression::sum(expr!(k), k.clone(), expr!(0), expr!(n))),
+            "\\sum_{k=0}^{n} k"
+        );
+        assert_eq!(
+            latex(&Expression::product(expr!(k), k, expr!(1), expr!(10))),
+            "\\prod_{k=1}^{10} k"
+        );
+    }
+
+    #[test]
+    fn test_function_forms() {
+        let integral = Expression::function(
+            "definite_integral",
+            vec![expr!(x), expr!(x), expr!(0), expr!(1)],
+        );
+        assert_eq!(latex(&integral), "\\int_{0}^{1} x \\, dx");
+
+        let limit = Expression::function(
+            "limit",
+            vec![
+                expr!(x),
+                Expression::function("approaches", vec![expr!(x), expr!(0)]),
+            ],
+        );
+        assert_eq!(latex(&limit), "\\lim_{x \\to 0} x");
+
+        let product = Expression::function("product", vec![expr!(k), expr!(k), expr!(1), expr!(n)]);
+        assert_eq!(latex(&product), "\\prod_{k=1}^{n} k");
+    }
+}
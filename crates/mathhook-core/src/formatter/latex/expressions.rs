@@ -1,8 +1,14 @@
-use super::{LaTeXContext, LaTeXFormatter, MAX_RECURSION_DEPTH, MAX_TERMS_PER_OPERATION};
+use super::{
+    calculus, line_breaking, LaTeXContext, LaTeXFormatter, MAX_RECURSION_DEPTH,
+    MAX_TERMS_PER_OPERATION,
+};
 use crate::core::expression::smart_display::SmartDisplayFormatter;
-use crate::core::expression::{CalculusData, LimitDirection, Matrix, RelationType};
+use crate::core::expression::{Matrix, RelationType};
+use crate::core::symbol::names::SymbolName;
 use crate::core::symbol::SymbolType;
 use crate::core::{Expression, MathConstant, Number};
+use crate::formatter::matrix_format::Slot;
+use crate::formatter::number_format::ExponentStyle;
 use crate::formatter::FormattingError;
 
 pub(super) fn to_latex_with_depth_impl(
@@ -17,13 +23,21 @@ pub(super) fn to_latex_with_depth_impl(
         });
     }
 
+    if depth == 0 {
+        if let Some(breaking) = &context.line_breaking {
+            if let Some(broken) = line_breaking::format_with_line_breaks(expr, breaking, context)? {
+                return Ok(broken);
+            }
+        }
+    }
+
     Ok(match expr {
-        Expression::Number(num) => format_number(num),
+        Expression::Number(num) => format_number(num, context),
         Expression::Symbol(s) => format_symbol(s),
         Expression::Add(terms) => format_addition(terms, context, depth)?,
         Expression::Mul(factors) => format_multiplication(factors, context, depth)?,
         Expression::Pow(base, exp) => format_power(base, exp, context, depth)?,
-        Expression::Function { name, args } => {
+        Expression::Function { name, args, .. } => {
             expr.function_to_latex_with_depth(name, args, context, depth + 1)?
         }
         Expression::Constant(c) => format_constant(c),
@@ -37,7 +51,9 @@ pub(super) fn to_latex_with_depth_impl(
         Expression::Piecewise(piecewise_data) => format_piecewise(piecewise_data, context, depth)?,
         Expression::Set(elements) => format_set(elements, context, depth)?,
         Expression::Interval(interval_data) => format_interval(interval_data, context, depth)?,
-        Expression::Calculus(calculus_data) => format_calculus(calculus_data, context, depth)?,
+        Expression::Calculus(calculus_data) => {
+            calculus::format_calculus(calculus_data, context, depth)?
+        }
         Expression::MethodCall(method_data) => format!(
             "{}.{}({})",
             method_data.object.to_latex_with_depth(context, depth + 1)?,
@@ -53,7 +69,11 @@ pub(super) fn to_latex_with_depth_impl(
 }
 
 /// Format number in LaTeX notation
-fn format_number(num: &Number) -> String {
+fn format_number(num: &Number, context: &LaTeXContext) -> String {
+    if let Some(decimal) = context.number_format.decimal_for(num) {
+        return decimal.render(ExponentStyle::LaTeX);
+    }
+
     match num {
         Number::Integer(n) => n.to_string(),
         Number::BigInteger(n) => n.to_string(),
@@ -65,6 +85,7 @@ fn format_number(num: &Number) -> String {
             }
         }
         Number::Float(f) => f.to_string(),
+        Number::Decimal(d) => d.to_string(),
     }
 }
 
@@ -79,11 +100,15 @@ fn format_number(num: &Number) -> String {
 /// Note: Type information is cached internally within the symbol itself,
 /// so repeated calls to symbol_type() are O(1).
 fn format_symbol(symbol: &crate::core::Symbol) -> String {
-    match symbol.symbol_type() {
-        SymbolType::Scalar => symbol.name().to_owned(),
-        SymbolType::Matrix => format!("\\mathbf{{{}}}", symbol.name()),
-        SymbolType::Operator => format!("\\hat{{{}}}", symbol.name()),
-        SymbolType::Quaternion => symbol.name().to_owned(),
+    let name = SymbolName::parse(symbol.name());
+    let base = match symbol.symbol_type() {
+        SymbolType::Scalar | SymbolType::Quaternion => name.latex_base(),
+        SymbolType::Matrix => format!("\\mathbf{{{}}}", name.latex_base()),
+        SymbolType::Operator => format!("\\hat{{{}}}", name.latex_base()),
+    };
+    match name.latex_subscript() {
+        Some(subscript) => base + &subscript,
+        None => base,
     }
 }
 
@@ -108,6 +133,20 @@ fn format_addition(
     context: &LaTeXContext,
     depth: usize,
 ) -> Result<String, FormattingError> {
+    let term_strs = addition_pieces(terms, context, depth)?;
+    Ok(if context.needs_parentheses {
+        format!("\\left({}\\right)", term_strs.join(""))
+    } else {
+        term_strs.join("")
+    })
+}
+
+/// Formatted terms of a sum, each after the first carrying its ` + `/` - `
+pub(super) fn addition_pieces(
+    terms: &[Expression],
+    context: &LaTeXContext,
+    depth: usize,
+) -> Result<Vec<String>, FormattingError> {
     if terms.len() > MAX_TERMS_PER_OPERATION {
         return Err(FormattingError::TooManyTerms {
             count: terms.len(),
@@ -138,11 +177,7 @@ fn format_addition(
             ));
         }
     }
-    Ok(if context.needs_parentheses {
-        format!("\\left({}\\right)", term_strs.join(""))
-    } else {
-        term_strs.join("")
-    })
+    Ok(term_strs)
 }
 
 /// Format multiplication with smart division detection
@@ -209,7 +244,7 @@ fn format_power(
         }
     }
 
-    if let Expression::Function { name, args } = base {
+    if let Expression::Function { name, args, .. } = base {
         println!("print function: {}", name);
         return Ok(format!(
             "\\{}^{{{}}}({})",
@@ -251,27 +286,44 @@ fn format_power(
     )
 }
 
-/// Format matrix in LaTeX pmatrix environment
+/// Format matrix in the configured LaTeX matrix environment
+///
+/// Elided rows and columns are shown as `\vdots`, `\cdots` and `\ddots`.
 fn format_matrix(
     matrix: &Matrix,
     context: &LaTeXContext,
     depth: usize,
 ) -> Result<String, FormattingError> {
-    let (rows, cols) = matrix.dimensions();
-    let mut row_strs = Vec::with_capacity(rows);
-
-    for i in 0..rows {
-        let mut col_strs = Vec::with_capacity(cols);
-        for j in 0..cols {
-            let elem = matrix.get_element(i, j);
-            col_strs.push(elem.to_latex_with_depth(context, depth + 1)?);
+    let layout = context.matrix_format.layout(matrix);
+    if layout.cell_count() > MAX_TERMS_PER_OPERATION {
+        return Err(FormattingError::TooManyTerms {
+            count: layout.cell_count(),
+            limit: MAX_TERMS_PER_OPERATION,
+        });
+    }
+
+    let mut row_strs = Vec::with_capacity(layout.rows.len());
+    for row in &layout.rows {
+        let mut col_strs = Vec::with_capacity(layout.cols.len());
+        for col in &layout.cols {
+            col_strs.push(match (row, col) {
+                (Slot::Index(i), Slot::Index(j)) => matrix
+                    .get_element(*i, *j)
+                    .to_latex_with_depth(context, depth + 1)?,
+                (Slot::Index(_), Slot::Ellipsis) => "\\cdots".to_owned(),
+                (Slot::Ellipsis, Slot::Index(_)) => "\\vdots".to_owned(),
+                (Slot::Ellipsis, Slot::Ellipsis) => "\\ddots".to_owned(),
+            });
         }
         row_strs.push(col_strs.join(" & "));
     }
 
+    let environment = context.matrix_format.delimiter.environment();
     Ok(format!(
-        "\\begin{{pmatrix}} {} \\end{{pmatrix}}",
-        row_strs.join(" \\\\ ")
+        "\\begin{{{}}} {} \\end{{{}}}",
+        environment,
+        row_strs.join(" \\\\ "),
+        environment
     ))
 }
 
@@ -285,7 +337,13 @@ fn format_relation(
     let right_latex = relation_data
         .right
         .to_latex_with_depth(context, depth + 1)?;
-    let operator = match relation_data.relation_type {
+    let operator = relation_operator(relation_data.relation_type);
+    Ok(format!("{} {} {}", left_latex, operator, right_latex))
+}
+
+/// LaTeX spelling of a relation operator
+pub(super) fn relation_operator(relation_type: RelationType) -> &'static str {
+    match relation_type {
         RelationType::Equal => "=",
         RelationType::NotEqual => "\\neq",
         RelationType::Less => "<",
@@ -296,8 +354,7 @@ fn format_relation(
         RelationType::Similar => "\\sim",
         RelationType::Proportional => "\\propto",
         RelationType::Congruent => "\\cong",
-    };
-    Ok(format!("{} {} {}", left_latex, operator, right_latex))
+    }
 }
 
 /// Format piecewise function
@@ -370,97 +427,3 @@ fn format_interval(
         start_bracket, start_latex, end_latex, end_bracket
     ))
 }
-
-/// Format calculus expressions (derivatives, integrals, limits, sums, products)
-fn format_calculus(
-    calculus_data: &CalculusData,
-    context: &LaTeXContext,
-    depth: usize,
-) -> Result<String, FormattingError> {
-    Ok(match calculus_data {
-        CalculusData::Derivative {
-            expression,
-            variable,
-            order,
-        } => {
-            if *order == 1 {
-                format!(
-                    "\\frac{{d}}{{d{}}} {}",
-                    variable.name(),
-                    expression.to_latex_with_depth(context, depth + 1)?
-                )
-            } else {
-                format!(
-                    "\\frac{{d^{}}}{{d{}^{}}} {}",
-                    order,
-                    variable.name(),
-                    order,
-                    expression.to_latex_with_depth(context, depth + 1)?
-                )
-            }
-        }
-        CalculusData::Integral {
-            integrand,
-            variable,
-            bounds,
-        } => match bounds {
-            None => format!(
-                "\\int {} d{}",
-                integrand.to_latex_with_depth(context, depth + 1)?,
-                variable.name()
-            ),
-            Some((start, end)) => format!(
-                "\\int_{}^{} {} d{}",
-                start.to_latex_with_depth(context, depth + 1)?,
-                end.to_latex_with_depth(context, depth + 1)?,
-                integrand.to_latex_with_depth(context, depth + 1)?,
-                variable.name()
-            ),
-        },
-        CalculusData::Limit {
-            expression,
-            variable,
-            direction,
-            ..
-        } => {
-            format!(
-                "\\lim_{{{}\\to{}}} {}",
-                variable.name(),
-                match direction {
-                    LimitDirection::Left => "0^-",
-                    LimitDirection::Right => "0^+",
-                    LimitDirection::Both => "0",
-                },
-                expression.to_latex_with_depth(context, depth + 1)?
-            )
-        }
-        CalculusData::Sum {
-            expression,
-            variable,
-            start,
-            end,
-        } => {
-            format!(
-                "\\sum_{{{}={}}}^{} {}",
-                variable.name(),
-                start.to_latex_with_depth(context, depth + 1)?,
-                end.to_latex_with_depth(context, depth + 1)?,
-                expression.to_latex_with_depth(context, depth + 1)?
-            )
-        }
-        CalculusData::Product {
-            expression,
-            variable,
-            start,
-            end,
-        } => {
-            format!(
-                "\\prod_{{{}={}}}^{{{}}} {}",
-                variable.name(),
-                start.to_latex_with_depth(context, depth + 1)?,
-                end.to_latex_with_depth(context, depth + 1)?,
-                expression.to_latex_with_depth(context, depth + 1)?
-            )
-        }
-    })
-}
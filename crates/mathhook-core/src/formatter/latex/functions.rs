@@ -1,6 +1,9 @@
-use super::{LaTeXContext, LaTeXFormatter, MAX_RECURSION_DEPTH, MAX_TERMS_PER_OPERATION};
+use super::{calculus, LaTeXContext, LaTeXFormatter, MAX_RECURSION_DEPTH, MAX_TERMS_PER_OPERATION};
+use crate::core::angle_unit::{AngleUnit, ANGLE_FUNCTIONS};
+use crate::core::expression::FunctionId;
 use crate::core::Expression;
 use crate::formatter::FormattingError;
+use crate::functions::custom::custom_function;
 
 pub(super) fn function_to_latex_with_depth_impl(
     _expr: &Expression,
@@ -23,7 +26,35 @@ pub(super) fn function_to_latex_with_depth_impl(
         });
     }
 
+    if let Some(latex) = calculus::format_calculus_function(name, args, context, depth)? {
+        return Ok(latex);
+    }
+
+    if !FunctionId::of(name).is_builtin() {
+        if let Some(definition) = custom_function(name) {
+            let formatted = args
+                .iter()
+                .map(|arg| arg.to_latex_with_depth(context, depth + 1))
+                .collect::<Result<Vec<_>, _>>()?;
+            if let Some(latex) = definition.latex(&formatted) {
+                return Ok(latex);
+            }
+        }
+    }
+
     Ok(match name {
+        _ if context.angle_unit == AngleUnit::Degrees
+            && ANGLE_FUNCTIONS.contains(&name)
+            && args.len() == 1 =>
+        {
+            let angle = args[0].to_latex_with_depth(context, depth + 1)?;
+            match &args[0] {
+                Expression::Number(_) | Expression::Symbol(_) => {
+                    format!("{}({}^\\circ)", latex_function_name(name), angle)
+                }
+                _ => format!("{}(({})^\\circ)", latex_function_name(name), angle),
+            }
+        }
         "log" => format_log_function(args, context, depth)?,
         "exp" => format!(
             "\\exp({})",
@@ -32,23 +63,15 @@ pub(super) fn function_to_latex_with_depth_impl(
         "sqrt" => format_sqrt_function(args, context, depth)?,
         "factorial" => format_factorial_function(args, context, depth)?,
         "double_factorial" => format_double_factorial_function(args, context, depth)?,
-        "integrate" => format_integrate_function(args, context, depth)?,
-        "derivative" => format_derivative_function(args, context, depth)?,
-        "sum" => format_sum_function(args, context, depth)?,
-        "gamma" => format!(
-            "\\Gamma({})",
-            args[0].to_latex_with_depth(context, depth + 1)?
-        ),
-        "digamma" | "psi" => format!(
-            "\\psi({})",
-            args[0].to_latex_with_depth(context, depth + 1)?
-        ),
-        "riemann_zeta" => format!(
-            "\\zeta({})",
-            args[0].to_latex_with_depth(context, depth + 1)?
+        "integrate" => format!("\\int({})", format_args_list(args, context, depth)?),
+        "derivative" => format!(
+            "\\frac{{d}}{{dx}}({})",
+            format_args_list(args, context, depth)?
         ),
-        "eta" => format!(
-            "\\eta({})",
+        "sum" => format!("\\sum({})", format_args_list(args, context, depth)?),
+        "gamma" | "digamma" | "psi" | "riemann_zeta" | "eta" => format!(
+            "{}({})",
+            latex_function_name(name),
             args[0].to_latex_with_depth(context, depth + 1)?
         ),
         "abs" => format!("|{}|", args[0].to_latex_with_depth(context, depth + 1)?),
@@ -193,70 +216,6 @@ fn format_double_factorial_function(
     }
 }
 
-/// Format integration operator
-fn format_integrate_function(
-    args: &[Expression],
-    context: &LaTeXContext,
-    depth: usize,
-) -> Result<String, FormattingError> {
-    if args.len() == 2 {
-        Ok(format!(
-            "\\int {} \\, d{}",
-            args[0].to_latex_with_depth(context, depth + 1)?,
-            args[1].to_latex_with_depth(context, depth + 1)?
-        ))
-    } else {
-        let arg_strs: Vec<String> = args
-            .iter()
-            .map(|arg| arg.to_latex_with_depth(context, depth + 1))
-            .collect::<Result<Vec<_>, _>>()?;
-        Ok(format!("\\int({})", arg_strs.join(", ")))
-    }
-}
-
-/// Format derivative operator
-fn format_derivative_function(
-    args: &[Expression],
-    context: &LaTeXContext,
-    depth: usize,
-) -> Result<String, FormattingError> {
-    if args.len() == 2 {
-        Ok(format!(
-            "\\frac{{d}}{{d{}}} {}",
-            args[1].to_latex_with_depth(context, depth + 1)?,
-            args[0].to_latex_with_depth(context, depth + 1)?
-        ))
-    } else {
-        Ok(format!(
-            "\\frac{{d}}{{dx}}({})",
-            args[0].to_latex_with_depth(context, depth + 1)?
-        ))
-    }
-}
-
-/// Format summation operator
-fn format_sum_function(
-    args: &[Expression],
-    context: &LaTeXContext,
-    depth: usize,
-) -> Result<String, FormattingError> {
-    if args.len() == 4 {
-        Ok(format!(
-            "\\sum_{{{}={}}}^{{{}}} {}",
-            args[1].to_latex_with_depth(context, depth + 1)?,
-            args[2].to_latex_with_depth(context, depth + 1)?,
-            args[3].to_latex_with_depth(context, depth + 1)?,
-            args[0].to_latex_with_depth(context, depth + 1)?
-        ))
-    } else {
-        let arg_strs: Vec<String> = args
-            .iter()
-            .map(|arg| arg.to_latex_with_depth(context, depth + 1))
-            .collect::<Result<Vec<_>, _>>()?;
-        Ok(format!("\\sum({})", arg_strs.join(", ")))
-    }
-}
-
 /// Format indexed special functions (Bessel, Legendre, etc.) with smart subscript bracing
 fn format_indexed_function(
     base_name: &str,
@@ -291,30 +250,49 @@ fn format_generic_function(
     context: &LaTeXContext,
     depth: usize,
 ) -> Result<String, FormattingError> {
-    const KNOWN_LATEX_FUNCTIONS: &[&str] = &[
-        "sin", "cos", "tan", "cot", "sec", "csc", "arcsin", "arccos", "arctan", "arccot", "arcsec",
-        "arccsc", "sinh", "cosh", "tanh", "coth", "sech", "csch", "arcsinh", "arccosh", "arctanh",
-        "arccoth", "arcsech", "arccsch", "ln", "lg", "erf", "erfc", "min", "max", "gcd", "lcm",
-        "det", "dim", "ker", "hom", "arg", "deg", "lim", "sup", "inf",
-    ];
-
-    let should_add_backslash = KNOWN_LATEX_FUNCTIONS.contains(&name);
+    let latex_name = latex_function_name(name);
 
     if args.is_empty() {
-        Ok(if should_add_backslash {
-            format!("\\{}", name)
-        } else {
-            name.to_owned()
-        })
+        Ok(latex_name)
     } else {
         let arg_strs: Vec<String> = args
             .iter()
             .map(|arg| arg.to_latex_with_depth(context, depth + 1))
             .collect::<Result<Vec<_>, _>>()?;
-        Ok(if should_add_backslash {
-            format!("\\{}({})", name, arg_strs.join(", "))
-        } else {
-            format!("{}({})", name, arg_strs.join(", "))
-        })
+        Ok(format!("{}({})", latex_name, arg_strs.join(", ")))
+    }
+}
+
+/// Functions LaTeX typesets upright with a backslash command of the same name
+const KNOWN_LATEX_FUNCTIONS: &[&str] = &[
+    "sin", "cos", "tan", "cot", "sec", "csc", "arcsin", "arccos", "arctan", "arccot", "arcsec",
+    "arccsc", "sinh", "cosh", "tanh", "coth", "sech", "csch", "arcsinh", "arccosh", "arctanh",
+    "arccoth", "arcsech", "arccsch", "ln", "lg", "log", "exp", "erf", "erfc", "min", "max", "gcd",
+    "lcm", "det", "dim", "ker", "hom", "arg", "deg", "lim", "sup", "inf",
+];
+
+/// LaTeX name the formatter uses for a function call, e.g. `\sin` or `\Gamma`
+pub(crate) fn latex_function_name(name: &str) -> String {
+    match name {
+        "gamma" => "\\Gamma".to_owned(),
+        "digamma" | "psi" => "\\psi".to_owned(),
+        "riemann_zeta" => "\\zeta".to_owned(),
+        "eta" => "\\eta".to_owned(),
+        "sqrt" => "\\sqrt".to_owned(),
+        _ if KNOWN_LATEX_FUNCTIONS.contains(&name) => format!("\\{}", name),
+        _ => name.to_owned(),
     }
 }
+
+/// Comma-separated LaTeX for function arguments
+fn format_args_list(
+    args: &[Expression],
+    context: &LaTeXContext,
+    depth: usize,
+) -> Result<String, FormattingError> {
+    let arg_strs: Vec<String> = args
+        .iter()
+        .map(|arg| arg.to_latex_with_depth(context, depth + 1))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(arg_strs.join(", "))
+}
@@ -0,0 +1,163 @@
+//! Breaking long top-level sums across lines
+
+use super::expressions::{addition_pieces, relation_operator};
+use super::{BreakEnvironment, LaTeXContext, LaTeXFormatter, LineBreaking};
+use crate::core::Expression;
+use crate::formatter::FormattingError;
+
+/// Format `expr` across several lines, or `None` when it is not a sum (or an
+/// equation with a sum on the right) or already fits on one line
+pub(super) fn format_with_line_breaks(
+    expr: &Expression,
+    breaking: &LineBreaking,
+    context: &LaTeXContext,
+) -> Result<Option<String>, FormattingError> {
+    let (lead, terms) = match expr {
+        Expression::Add(terms) => (None, terms),
+        Expression::Relation(relation) => match &relation.right {
+            Expression::Add(terms) => {
+                let left = relation.left.to_latex_with_depth(context, 1)?;
+                (
+                    Some((left, relation_operator(relation.relation_type))),
+                    terms,
+                )
+            }
+            _ => return Ok(None),
+        },
+        _ => return Ok(None),
+    };
+
+    let pieces = addition_pieces(terms, context, 0)?;
+    let lead_length = lead
+        .as_ref()
+        .map_or(0, |(left, operator)| left.len() + operator.len() + 2);
+    let total_length = lead_length + pieces.iter().map(String::len).sum::<usize>();
+    if total_length <= breaking.max_line_length {
+        return Ok(None);
+    }
+
+    let lines = group_into_lines(pieces, breaking.max_line_length, lead_length);
+
+    Ok(Some(match breaking.environment {
+        BreakEnvironment::Aligned => {
+            let first = match &lead {
+                Some((left, operator)) => format!("{} &{} {}", left, operator, lines[0]),
+                None => format!("&{}", lines[0]),
+            };
+            let rest = lines[1..].iter().map(|line| format!("&\\quad {}", line));
+            let all: Vec<String> = std::iter::once(first).chain(rest).collect();
+            format!("\\begin{{aligned}} {} \\end{{aligned}}", all.join(" \\\\ "))
+        }
+        BreakEnvironment::Multline => {
+            let mut all = lines;
+            if let Some((left, operator)) = &lead {
+                all[0] = format!("{} {} {}", left, operator, all[0]);
+            }
+            format!(
+                "\\begin{{multline}} {} \\end{{multline}}",
+                all.join(" \\\\ ")
+            )
+        }
+    }))
+}
+
+/// Greedily pack term pieces into lines of at most `max_length` characters;
+/// an over-long single term gets a line of its own
+fn group_into_lines(pieces: Vec<String>, max_length: usize, first_offset: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    let mut offset = first_offset;
+
+    for piece in pieces {
+        if !current.is_empty() && offset + current.len() + piece.len() > max_length {
+            lines.push(std::mem::take(&mut current));
+            offset = 0;
+        }
+        if current.is_empty() {
+            current.push_str(piece.trim_start());
+        } else {
+            current.push_str(&piece);
+        }
+    }
+    lines.push(current);
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::expression::RelationType;
+    use crate::core::Symbol;
+
+    fn long_sum(count: usize) -> Expression {
+        let terms = (0..count)
+            .map(|i| Expression::symbol(Symbol::new(format!("x_{}", i))))
+            .collect();
+        Expression::add(terms)
+    }
+
+    fn context(breaking: LineBreaking) -> LaTeXContext {
+        LaTeXContext {
+            line_breaking: Some(breaking),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_short_expressions_stay_on_one_line() {
+        let sum = long_sum(3);
+        assert_eq!(
+            sum.to_latex(context(LineBreaking::default())).unwrap(),
+            sum.to_latex(None).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_aligned_breaks_between_terms() {
+        let latex = long_sum(12)
+            .to_latex(context(LineBreaking::aligned(30)))
+            .unwrap();
+        assert!(latex.starts_with("\\begin{aligned} &x_{0} + x_{1}"));
+        assert!(latex.ends_with("\\end{aligned}"));
+        assert!(latex.contains(" \\\\ &\\quad + "));
+
+        let inner = latex
+            .trim_start_matches("\\begin{aligned} ")
+            .trim_end_matches(" \\end{aligned}");
+        for line in inner.split(" \\\\ ") {
+            assert!(line.len() <= 30 + "&\\quad ".len(), "{}", line);
+        }
+    }
+
+    #[test]
+    fn test_equation_aligns_on_relation() {
+        let equation = Expression::relation(
+            Expression::symbol(Symbol::new("y")),
+            long_sum(12),
+            RelationType::Equal,
+        );
+        let latex = equation
+            .to_latex(context(LineBreaking::aligned(30)))
+            .unwrap();
+        assert!(latex.starts_with("\\begin{aligned} y &= x_{0}"));
+    }
+
+    #[test]
+    fn test_multline_environment() {
+        let latex = long_sum(12)
+            .to_latex(context(LineBreaking::multline(30)))
+            .unwrap();
+        assert!(latex.starts_with("\\begin{multline} x_{0} + "));
+        assert!(latex.contains(" \\\\ + x_{"));
+        assert!(latex.ends_with("\\end{multline}"));
+    }
+
+    #[test]
+    fn test_nested_sums_are_not_broken() {
+        let product = Expression::mul(vec![Expression::integer(2), long_sum(12)]);
+        let latex = product
+            .to_latex(context(LineBreaking::aligned(10)))
+            .unwrap();
+        assert!(!latex.contains("aligned"));
+    }
+}
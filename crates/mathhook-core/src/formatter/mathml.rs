@@ -0,0 +1,364 @@
+use super::{FormattingContext, FormattingError, MatrixFormat, NumberFormat};
+use crate::core::Expression;
+use std::borrow::Cow;
+
+mod content;
+mod presentation;
+
+const MAX_RECURSION_DEPTH: usize = 1000;
+const MAX_TERMS_PER_OPERATION: usize = 10000;
+
+const MATHML_NAMESPACE: &str = "http://www.w3.org/1998/Math/MathML";
+
+/// Which MathML vocabulary to produce
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MathMLMarkup {
+    /// Layout markup (`<mi>`, `<mfrac>`, `<msup>`, ...) rendered natively by browsers
+    #[default]
+    Presentation,
+    /// Semantic markup (`<apply><plus/>...</apply>`) for computer algebra interchange
+    Content,
+    /// Presentation markup annotated with the content markup in `<semantics>`
+    Parallel,
+}
+
+/// MathML formatting context
+#[derive(Debug, Default, Clone)]
+pub struct MathMLContext {
+    pub markup: MathMLMarkup,
+    /// Emit `display="block"` on the `<math>` element instead of inline math
+    pub display_block: bool,
+    /// Float/rational display options (presentation markup only; content
+    /// markup always carries exact numbers)
+    pub number_format: NumberFormat,
+    /// Matrix delimiters and size limits (presentation markup only)
+    pub matrix_format: MatrixFormat,
+}
+
+impl MathMLContext {
+    /// Content MathML context
+    pub fn content() -> Self {
+        Self {
+            markup: MathMLMarkup::Content,
+            ..Default::default()
+        }
+    }
+
+    /// Presentation MathML annotated with content MathML
+    pub fn parallel() -> Self {
+        Self {
+            markup: MathMLMarkup::Parallel,
+            ..Default::default()
+        }
+    }
+}
+
+impl FormattingContext for MathMLContext {}
+
+/// Format the expression to MathML
+pub trait MathMLFormatter {
+    /// Format an Expression as a MathML `<math>` element
+    ///
+    /// Presentation MathML renders in browsers without a LaTeX toolchain;
+    /// content MathML describes the meaning of the expression for other
+    /// computer algebra systems.
+    ///
+    /// # Arguments
+    /// * `context` - MathML formatting configuration
+    ///
+    /// # Context Options
+    /// * `markup` - Presentation, content, or both as parallel markup
+    /// * `display_block` - Display (block) instead of inline math
+    /// * `number_format` - Significant figures, notation and rational display for numbers
+    /// * `matrix_format` - Matrix delimiters and size limits
+    ///
+    /// # Examples
+    /// ```
+    /// use mathhook_core::expr;
+    /// use mathhook_core::formatter::mathml::{MathMLContext, MathMLFormatter};
+    ///
+    /// let expression = expr!(x ^ 2);
+    /// assert_eq!(
+    ///     expression.to_mathml(&MathMLContext::default()).unwrap(),
+    ///     "<math xmlns=\"http://www.w3.org/1998/Math/MathML\">\
+    ///      <msup><mi>x</mi><mn>2</mn></msup></math>"
+    /// );
+    /// assert_eq!(
+    ///     expression.to_mathml(&MathMLContext::content()).unwrap(),
+    ///     "<math xmlns=\"http://www.w3.org/1998/Math/MathML\">\
+    ///      <apply><power/><ci>x</ci><cn type=\"integer\">2</cn></apply></math>"
+    /// );
+    /// ```
+    ///
+    /// # Error Handling
+    /// Returns error messages for expressions that exceed safety limits:
+    /// - Maximum recursion depth (1000 levels)
+    /// - Maximum terms per operation (10000 terms)
+    fn to_mathml(&self, context: &MathMLContext) -> Result<String, FormattingError> {
+        let body = match context.markup {
+            MathMLMarkup::Presentation => self.to_presentation_mathml_with_depth(context, 0)?,
+            MathMLMarkup::Content => self.to_content_mathml_with_depth(context, 0)?,
+            MathMLMarkup::Parallel => format!(
+                "<semantics>{}<annotation-xml encoding=\"MathML-Content\">{}</annotation-xml></semantics>",
+                self.to_presentation_mathml_with_depth(context, 0)?,
+                self.to_content_mathml_with_depth(context, 0)?
+            ),
+        };
+        let display = if context.display_block {
+            " display=\"block\""
+        } else {
+            ""
+        };
+        Ok(format!(
+            "<math xmlns=\"{}\"{}>{}</math>",
+            MATHML_NAMESPACE, display, body
+        ))
+    }
+
+    /// Presentation MathML element with recursion depth tracking
+    ///
+    /// Always a single element, so it can be embedded in other markup.
+    fn to_presentation_mathml_with_depth(
+        &self,
+        context: &MathMLContext,
+        depth: usize,
+    ) -> Result<String, FormattingError>;
+
+    /// Content MathML element with recursion depth tracking
+    fn to_content_mathml_with_depth(
+        &self,
+        context: &MathMLContext,
+        depth: usize,
+    ) -> Result<String, FormattingError>;
+}
+
+impl MathMLFormatter for Expression {
+    fn to_presentation_mathml_with_depth(
+        &self,
+        context: &MathMLContext,
+        depth: usize,
+    ) -> Result<String, FormattingError> {
+        presentation::to_presentation_impl(self, context, depth)
+    }
+
+    fn to_content_mathml_with_depth(
+        &self,
+        context: &MathMLContext,
+        depth: usize,
+    ) -> Result<String, FormattingError> {
+        content::to_content_impl(self, context, depth)
+    }
+}
+
+/// Escape `&`, `<` and `>` in element text
+fn escape(text: &str) -> Cow<'_, str> {
+    if !text.contains(['&', '<', '>']) {
+        return Cow::Borrowed(text);
+    }
+    Cow::Owned(
+        text.replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;"),
+    )
+}
+
+fn check_recursion(depth: usize) -> Result<(), FormattingError> {
+    if depth > MAX_RECURSION_DEPTH {
+        return Err(FormattingError::RecursionLimitExceeded {
+            depth,
+            limit: MAX_RECURSION_DEPTH,
+        });
+    }
+    Ok(())
+}
+
+fn check_terms(count: usize) -> Result<(), FormattingError> {
+    if count > MAX_TERMS_PER_OPERATION {
+        return Err(FormattingError::TooManyTerms {
+            count,
+            limit: MAX_TERMS_PER_OPERATION,
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::expression::{CalculusData, LimitDirection, RelationType};
+    use crate::formatter::MathLanguage;
+    use crate::{expr, symbol};
+    use std::sync::Arc;
+
+    fn presentation(expr: &Expression) -> String {
+        expr.to_presentation_mathml_with_depth(&MathMLContext::default(), 0)
+            .unwrap()
+    }
+
+    fn content(expr: &Expression) -> String {
+        expr.to_content_mathml_with_depth(&MathMLContext::default(), 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_presentation_arithmetic() {
+        assert_eq!(
+            presentation(&expr!(x + 1)),
+            "<mrow><mn>1</mn><mo>+</mo><mi>x</mi></mrow>"
+        );
+        let x = Expression::symbol(symbol!(x));
+        let y = Expression::symbol(symbol!(y));
+        let difference = Expression::Add(Arc::new(vec![
+            x.clone(),
+            Expression::Mul(Arc::new(vec![Expression::integer(-2), y.clone()])),
+        ]));
+        assert_eq!(
+            presentation(&difference),
+            "<mrow><mi>x</mi><mo>-</mo><mrow><mn>2</mn><mo>&#x2062;</mo><mi>y</mi></mrow></mrow>"
+        );
+        assert_eq!(
+            presentation(&Expression::div(x.clone(), y)),
+            "<mfrac><mi>x</mi><mi>y</mi></mfrac>"
+        );
+        assert_eq!(
+            presentation(&Expression::rational(-3, 4)),
+            "<mrow><mo>-</mo><mfrac><mn>3</mn><mn>4</mn></mfrac></mrow>"
+        );
+        assert_eq!(
+            presentation(&Expression::sqrt(x.clone())),
+            "<msqrt><mi>x</mi></msqrt>"
+        );
+        assert_eq!(
+            presentation(&Expression::pow(expr!(x + 1), Expression::integer(2))),
+            "<msup><mrow><mo>(</mo><mrow><mn>1</mn><mo>+</mo><mi>x</mi></mrow><mo>)</mo></mrow><mn>2</mn></msup>"
+        );
+    }
+
+    #[test]
+    fn test_presentation_symbols_and_functions() {
+        assert_eq!(
+            presentation(&Expression::symbol(symbol!(theta_1))),
+            "<msub><mi>θ</mi><mn>1</mn></msub>"
+        );
+        assert_eq!(
+            presentation(&Expression::function("sin", vec![expr!(x)])),
+            "<mrow><mi>sin</mi><mo>&#x2061;</mo><mrow><mo>(</mo><mi>x</mi><mo>)</mo></mrow></mrow>"
+        );
+        let squared = Expression::Pow(
+            Arc::new(Expression::function("sin", vec![expr!(x)])),
+            Arc::new(Expression::integer(2)),
+        );
+        assert_eq!(
+            presentation(&squared),
+            "<mrow><msup><mi>sin</mi><mn>2</mn></msup><mo>&#x2061;</mo><mrow><mo>(</mo><mi>x</mi><mo>)</mo></mrow></mrow>"
+        );
+        assert_eq!(
+            presentation(&Expression::function("abs", vec![expr!(x)])),
+            "<mrow><mo>|</mo><mi>x</mi><mo>|</mo></mrow>"
+        );
+        let relation = Expression::relation(expr!(x), Expression::integer(1), RelationType::Less);
+        assert_eq!(
+            presentation(&relation),
+            "<mrow><mi>x</mi><mo>&lt;</mo><mn>1</mn></mrow>"
+        );
+    }
+
+    #[test]
+    fn test_presentation_matrix_and_calculus() {
+        let m = Expression::matrix(vec![
+            vec![Expression::integer(1), Expression::integer(2)],
+            vec![Expression::integer(3), expr!(x)],
+        ]);
+        assert_eq!(
+            presentation(&m),
+            "<mrow><mo>(</mo><mtable><mtr><mtd><mn>1</mn></mtd><mtd><mn>2</mn></mtd></mtr>\
+             <mtr><mtd><mn>3</mn></mtd><mtd><mi>x</mi></mtd></mtr></mtable><mo>)</mo></mrow>"
+        );
+
+        let x = symbol!(x);
+        let integral = Expression::definite_integral(expr!(x ^ 2), x.clone(), expr!(0), expr!(1));
+        assert_eq!(
+            presentation(&integral),
+            "<mrow><msubsup><mo>∫</mo><mn>0</mn><mn>1</mn></msubsup><msup><mi>x</mi><mn>2</mn></msup>\
+             <mspace width=\"0.1667em\"/><mi>d</mi><mi>x</mi></mrow>"
+        );
+        let limit = Expression::Calculus(Arc::new(CalculusData::Limit {
+            expression: expr!(x),
+            variable: x,
+            point: expr!(0),
+            direction: LimitDirection::Right,
+        }));
+        assert_eq!(
+            presentation(&limit),
+            "<mrow><munder><mo>lim</mo><mrow><mi>x</mi><mo>→</mo><msup><mn>0</mn><mo>+</mo></msup></mrow></munder><mi>x</mi></mrow>"
+        );
+    }
+
+    #[test]
+    fn test_content_markup() {
+        assert_eq!(
+            content(&expr!(x + 1)),
+            "<apply><plus/><cn type=\"integer\">1</cn><ci>x</ci></apply>"
+        );
+        assert_eq!(
+            content(&Expression::rational(1, 2)),
+            "<cn type=\"rational\">1<sep/>2</cn>"
+        );
+        assert_eq!(
+            content(&Expression::function("sin", vec![Expression::pi()])),
+            "<apply><sin/><pi/></apply>"
+        );
+        assert_eq!(
+            content(&Expression::function("my_f", vec![expr!(x)])),
+            "<apply><ci type=\"function\">my_f</ci><ci>x</ci></apply>"
+        );
+        let x = symbol!(x);
+        assert_eq!(
+            content(&Expression::derivative(expr!(x ^ 2), x.clone(), 2)),
+            "<apply><diff/><bvar><ci>x</ci><degree><cn type=\"integer\">2</cn></degree></bvar>\
+             <apply><power/><ci>x</ci><cn type=\"integer\">2</cn></apply></apply>"
+        );
+        assert_eq!(
+            content(&Expression::interval(expr!(0), expr!(1), true, false)),
+            "<interval closure=\"closed-open\"><cn type=\"integer\">0</cn><cn type=\"integer\">1</cn></interval>"
+        );
+    }
+
+    #[test]
+    fn test_math_element_and_language_selection() {
+        let x = expr!(x);
+        let parallel = x.to_mathml(&MathMLContext::parallel()).unwrap();
+        assert_eq!(
+            parallel,
+            "<math xmlns=\"http://www.w3.org/1998/Math/MathML\"><semantics><mi>x</mi>\
+             <annotation-xml encoding=\"MathML-Content\"><ci>x</ci></annotation-xml></semantics></math>"
+        );
+
+        let block = MathMLContext {
+            display_block: true,
+            ..Default::default()
+        };
+        assert!(x
+            .to_mathml(&block)
+            .unwrap()
+            .starts_with("<math xmlns=\"http://www.w3.org/1998/Math/MathML\" display=\"block\">"));
+
+        assert_eq!(
+            x.format_as(MathLanguage::MathML).unwrap(),
+            x.to_mathml(&MathMLContext::default()).unwrap()
+        );
+        assert_eq!(
+            Expression::float(0.000012345)
+                .format_as_with(MathLanguage::MathML, NumberFormat::scientific(3))
+                .unwrap(),
+            "<math xmlns=\"http://www.w3.org/1998/Math/MathML\"><mrow><mn>1.23</mn><mo>×</mo>\
+             <msup><mn>10</mn><mrow><mo>-</mo><mn>5</mn></mrow></msup></mrow></math>"
+        );
+    }
+
+    #[test]
+    fn test_names_are_escaped() {
+        assert_eq!(escape("a<b&c"), "a&lt;b&amp;c");
+        assert!(matches!(escape("plain"), Cow::Borrowed(_)));
+    }
+}
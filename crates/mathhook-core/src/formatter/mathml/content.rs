@@ -0,0 +1,356 @@
+use super::{check_recursion, check_terms, escape, MathMLContext, MathMLFormatter};
+use crate::core::expression::{CalculusData, LimitDirection, RelationType};
+use crate::core::symbol::SymbolType;
+use crate::core::{Expression, MathConstant, Number, Symbol};
+use crate::formatter::FormattingError;
+
+pub(super) fn to_content_impl(
+    expr: &Expression,
+    context: &MathMLContext,
+    depth: usize,
+) -> Result<String, FormattingError> {
+    check_recursion(depth)?;
+
+    Ok(match expr {
+        Expression::Number(num) => format_number(num),
+        Expression::Symbol(s) => format_symbol(s),
+        Expression::Constant(c) => format_constant(c).to_owned(),
+        Expression::Add(terms) => apply("<plus/>", terms, context, depth)?,
+        Expression::Mul(factors) => apply("<times/>", factors, context, depth)?,
+        Expression::Pow(base, exp) => format!(
+            "<apply><power/>{}{}</apply>",
+            base.to_content_mathml_with_depth(context, depth + 1)?,
+            exp.to_content_mathml_with_depth(context, depth + 1)?
+        ),
+        Expression::Function { name, args, .. } => {
+            let head = match function_element(name) {
+                Some(element) => element.to_owned(),
+                None => format!("<ci type=\"function\">{}</ci>", escape(name)),
+            };
+            apply(&head, args, context, depth)?
+        }
+        Expression::Complex(complex_data) => format!(
+            "<apply><plus/>{}<apply><times/>{}<imaginaryi/></apply></apply>",
+            complex_data
+                .real
+                .to_content_mathml_with_depth(context, depth + 1)?,
+            complex_data
+                .imag
+                .to_content_mathml_with_depth(context, depth + 1)?
+        ),
+        Expression::Matrix(matrix) => {
+            let (rows, cols) = matrix.dimensions();
+            check_terms(rows * cols)?;
+            let mut result = String::from("<matrix>");
+            for i in 0..rows {
+                result.push_str("<matrixrow>");
+                for j in 0..cols {
+                    result.push_str(
+                        &matrix
+                            .get_element(i, j)
+                            .to_content_mathml_with_depth(context, depth + 1)?,
+                    );
+                }
+                result.push_str("</matrixrow>");
+            }
+            result.push_str("</matrix>");
+            result
+        }
+        Expression::Relation(relation_data) => format!(
+            "<apply>{}{}{}</apply>",
+            relation_element(relation_data.relation_type),
+            relation_data
+                .left
+                .to_content_mathml_with_depth(context, depth + 1)?,
+            relation_data
+                .right
+                .to_content_mathml_with_depth(context, depth + 1)?
+        ),
+        Expression::Piecewise(piecewise_data) => {
+            let mut result = String::from("<piecewise>");
+            for (condition, value) in &piecewise_data.pieces {
+                result.push_str(&format!(
+                    "<piece>{}{}</piece>",
+                    value.to_content_mathml_with_depth(context, depth + 1)?,
+                    condition.to_content_mathml_with_depth(context, depth + 1)?
+                ));
+            }
+            if let Some(default_value) = &piecewise_data.default {
+                result.push_str(&format!(
+                    "<otherwise>{}</otherwise>",
+                    default_value.to_content_mathml_with_depth(context, depth + 1)?
+                ));
+            }
+            result.push_str("</piecewise>");
+            result
+        }
+        Expression::Set(elements) => {
+            format!("<set>{}</set>", format_children(elements, context, depth)?)
+        }
+        Expression::Interval(interval_data) => {
+            let closure = match (interval_data.start_inclusive, interval_data.end_inclusive) {
+                (true, true) => "closed",
+                (true, false) => "closed-open",
+                (false, true) => "open-closed",
+                (false, false) => "open",
+            };
+            format!(
+                "<interval closure=\"{}\">{}{}</interval>",
+                closure,
+                interval_data
+                    .start
+                    .to_content_mathml_with_depth(context, depth + 1)?,
+                interval_data
+                    .end
+                    .to_content_mathml_with_depth(context, depth + 1)?
+            )
+        }
+        Expression::Calculus(calculus_data) => format_calculus(calculus_data, context, depth)?,
+        Expression::MethodCall(method_data) => format!(
+            "<apply><csymbol>{}</csymbol>{}{}</apply>",
+            escape(&method_data.method_name),
+            method_data
+                .object
+                .to_content_mathml_with_depth(context, depth + 1)?,
+            format_children(&method_data.args, context, depth)?
+        ),
+    })
+}
+
+/// Exact `<cn>` element; content markup ignores the display number format
+fn format_number(num: &Number) -> String {
+    match num {
+        Number::Integer(n) => format!("<cn type=\"integer\">{}</cn>", n),
+        Number::BigInteger(n) => format!("<cn type=\"integer\">{}</cn>", n),
+        Number::Rational(r) => {
+            if r.denom() == &num_bigint::BigInt::from(1) {
+                format!("<cn type=\"integer\">{}</cn>", r.numer())
+            } else {
+                format!(
+                    "<cn type=\"rational\">{}<sep/>{}</cn>",
+                    r.numer(),
+                    r.denom()
+                )
+            }
+        }
+        Number::Float(f) if f.is_nan() => "<notanumber/>".to_owned(),
+        Number::Float(f) if f.is_infinite() => {
+            if *f > 0.0 {
+                "<infinity/>".to_owned()
+            } else {
+                "<apply><minus/><infinity/></apply>".to_owned()
+            }
+        }
+        Number::Float(f) => format!("<cn type=\"real\">{}</cn>", f),
+        Number::Decimal(d) => format!("<cn type=\"real\">{}</cn>", d),
+    }
+}
+
+fn format_symbol(symbol: &Symbol) -> String {
+    match symbol.symbol_type() {
+        SymbolType::Matrix => format!("<ci type=\"matrix\">{}</ci>", escape(symbol.name())),
+        _ => format!("<ci>{}</ci>", escape(symbol.name())),
+    }
+}
+
+fn format_constant(c: &MathConstant) -> &'static str {
+    match c {
+        MathConstant::Pi => "<pi/>",
+        MathConstant::E => "<exponentiale/>",
+        MathConstant::I => "<imaginaryi/>",
+        MathConstant::Infinity => "<infinity/>",
+        MathConstant::NegativeInfinity => "<apply><minus/><infinity/></apply>",
+        MathConstant::Undefined => "<notanumber/>",
+        MathConstant::GoldenRatio => "<csymbol>GoldenRatio</csymbol>",
+        MathConstant::EulerGamma => "<eulergamma/>",
+        MathConstant::TribonacciConstant => "<csymbol>TribonacciConstant</csymbol>",
+    }
+}
+
+/// Content MathML element of a built-in function, if it has one
+fn function_element(name: &str) -> Option<&'static str> {
+    Some(match name {
+        "sin" => "<sin/>",
+        "cos" => "<cos/>",
+        "tan" => "<tan/>",
+        "sec" => "<sec/>",
+        "csc" => "<csc/>",
+        "cot" => "<cot/>",
+        "sinh" => "<sinh/>",
+        "cosh" => "<cosh/>",
+        "tanh" => "<tanh/>",
+        "sech" => "<sech/>",
+        "csch" => "<csch/>",
+        "coth" => "<coth/>",
+        "arcsin" | "asin" => "<arcsin/>",
+        "arccos" | "acos" => "<arccos/>",
+        "arctan" | "atan" => "<arctan/>",
+        "arcsec" => "<arcsec/>",
+        "arccsc" => "<arccsc/>",
+        "arccot" => "<arccot/>",
+        "arcsinh" | "asinh" => "<arcsinh/>",
+        "arccosh" | "acosh" => "<arccosh/>",
+        "arctanh" | "atanh" => "<arctanh/>",
+        "exp" => "<exp/>",
+        "ln" => "<ln/>",
+        "log" => "<log/>",
+        "sqrt" => "<root/>",
+        "abs" => "<abs/>",
+        "floor" => "<floor/>",
+        "ceiling" | "ceil" => "<ceiling/>",
+        "factorial" => "<factorial/>",
+        "conjugate" => "<conjugate/>",
+        "arg" => "<arg/>",
+        "re" | "real" => "<real/>",
+        "im" | "imag" => "<imaginary/>",
+        "gcd" => "<gcd/>",
+        "lcm" => "<lcm/>",
+        "max" => "<max/>",
+        "min" => "<min/>",
+        _ => return None,
+    })
+}
+
+fn relation_element(relation_type: RelationType) -> &'static str {
+    match relation_type {
+        RelationType::Equal => "<eq/>",
+        RelationType::NotEqual => "<neq/>",
+        RelationType::Less => "<lt/>",
+        RelationType::LessEqual => "<leq/>",
+        RelationType::Greater => "<gt/>",
+        RelationType::GreaterEqual => "<geq/>",
+        RelationType::Approximate => "<approx/>",
+        RelationType::Similar => "<csymbol>similar</csymbol>",
+        RelationType::Proportional => "<csymbol>proportional</csymbol>",
+        RelationType::Congruent => "<csymbol>congruent</csymbol>",
+    }
+}
+
+/// `<apply>head children</apply>`
+fn apply(
+    head: &str,
+    children: &[Expression],
+    context: &MathMLContext,
+    depth: usize,
+) -> Result<String, FormattingError> {
+    Ok(format!(
+        "<apply>{}{}</apply>",
+        head,
+        format_children(children, context, depth)?
+    ))
+}
+
+fn format_children(
+    children: &[Expression],
+    context: &MathMLContext,
+    depth: usize,
+) -> Result<String, FormattingError> {
+    check_terms(children.len())?;
+    let formatted = children
+        .iter()
+        .map(|child| child.to_content_mathml_with_depth(context, depth + 1))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(formatted.concat())
+}
+
+/// Format calculus operations with bound variables (`<bvar>`) and limits
+fn format_calculus(
+    calculus_data: &CalculusData,
+    context: &MathMLContext,
+    depth: usize,
+) -> Result<String, FormattingError> {
+    Ok(match calculus_data {
+        CalculusData::Derivative {
+            expression,
+            variable,
+            order,
+        } => {
+            let degree = if *order == 1 {
+                String::new()
+            } else {
+                format!("<degree><cn type=\"integer\">{}</cn></degree>", order)
+            };
+            format!(
+                "<apply><diff/><bvar>{}{}</bvar>{}</apply>",
+                format_symbol(variable),
+                degree,
+                expression.to_content_mathml_with_depth(context, depth + 1)?
+            )
+        }
+        CalculusData::Integral {
+            integrand,
+            variable,
+            bounds,
+        } => format!(
+            "<apply><int/><bvar>{}</bvar>{}{}</apply>",
+            format_symbol(variable),
+            limits(bounds.as_ref().map(|(a, b)| (a, b)), context, depth)?,
+            integrand.to_content_mathml_with_depth(context, depth + 1)?
+        ),
+        CalculusData::Limit {
+            expression,
+            variable,
+            point,
+            direction,
+        } => {
+            let point = point.to_content_mathml_with_depth(context, depth + 1)?;
+            let approach = match direction {
+                LimitDirection::Both => format!("<lowlimit>{}</lowlimit>", point),
+                LimitDirection::Left | LimitDirection::Right => format!(
+                    "<condition><apply><tendsto type=\"{}\"/>{}{}</apply></condition>",
+                    if *direction == LimitDirection::Left {
+                        "below"
+                    } else {
+                        "above"
+                    },
+                    format_symbol(variable),
+                    point
+                ),
+            };
+            format!(
+                "<apply><limit/><bvar>{}</bvar>{}{}</apply>",
+                format_symbol(variable),
+                approach,
+                expression.to_content_mathml_with_depth(context, depth + 1)?
+            )
+        }
+        CalculusData::Sum {
+            expression,
+            variable,
+            start,
+            end,
+        } => format!(
+            "<apply><sum/><bvar>{}</bvar>{}{}</apply>",
+            format_symbol(variable),
+            limits(Some((start, end)), context, depth)?,
+            expression.to_content_mathml_with_depth(context, depth + 1)?
+        ),
+        CalculusData::Product {
+            expression,
+            variable,
+            start,
+            end,
+        } => format!(
+            "<apply><product/><bvar>{}</bvar>{}{}</apply>",
+            format_symbol(variable),
+            limits(Some((start, end)), context, depth)?,
+            expression.to_content_mathml_with_depth(context, depth + 1)?
+        ),
+    })
+}
+
+/// `<lowlimit>`/`<uplimit>` pair, or nothing for unbounded operations
+fn limits(
+    bounds: Option<(&Expression, &Expression)>,
+    context: &MathMLContext,
+    depth: usize,
+) -> Result<String, FormattingError> {
+    Ok(match bounds {
+        Some((start, end)) => format!(
+            "<lowlimit>{}</lowlimit><uplimit>{}</uplimit>",
+            start.to_content_mathml_with_depth(context, depth + 1)?,
+            end.to_content_mathml_with_depth(context, depth + 1)?
+        ),
+        None => String::new(),
+    })
+}
@@ -0,0 +1,674 @@
+use super::{check_recursion, check_terms, escape, MathMLContext, MathMLFormatter};
+use crate::core::expression::{CalculusData, LimitDirection, Matrix, PiecewiseData};
+use crate::core::symbol::names::{greek_letter, SymbolName};
+use crate::core::symbol::SymbolType;
+use crate::core::{Expression, MathConstant, Number, Symbol};
+use crate::formatter::matrix_format::{MatrixDelimiter, Slot};
+use crate::formatter::FormattingError;
+use std::sync::Arc;
+
+/// Invisible times between juxtaposed factors (`2x`)
+const INVISIBLE_TIMES: &str = "<mo>&#x2062;</mo>";
+/// Function application between a function name and its arguments
+const APPLY_FUNCTION: &str = "<mo>&#x2061;</mo>";
+
+pub(super) fn to_presentation_impl(
+    expr: &Expression,
+    context: &MathMLContext,
+    depth: usize,
+) -> Result<String, FormattingError> {
+    check_recursion(depth)?;
+
+    Ok(match expr {
+        Expression::Number(num) => format_number(num, context),
+        Expression::Symbol(s) => format_symbol(s),
+        Expression::Constant(c) => format_constant(c).to_owned(),
+        Expression::Add(terms) => format_addition(terms, context, depth)?,
+        Expression::Mul(factors) => format_multiplication(factors, context, depth)?,
+        Expression::Pow(base, exp) => format_power(base, exp, context, depth)?,
+        Expression::Function { name, args, .. } => format_function(name, args, context, depth)?,
+        Expression::Complex(complex_data) => {
+            let real = complex_data
+                .real
+                .to_presentation_mathml_with_depth(context, depth + 1)?;
+            let (sign, imag) = match negated_term(&complex_data.imag) {
+                Some(magnitude) => ("-", operand(&magnitude, context, depth)?),
+                None => ("+", operand(&complex_data.imag, context, depth)?),
+            };
+            format!(
+                "<mrow>{}<mo>{}</mo><mrow>{}{}<mi>i</mi></mrow></mrow>",
+                real, sign, imag, INVISIBLE_TIMES
+            )
+        }
+        Expression::Matrix(matrix) => format_matrix(matrix, context, depth)?,
+        Expression::Relation(relation_data) => format!(
+            "<mrow>{}<mo>{}</mo>{}</mrow>",
+            relation_data
+                .left
+                .to_presentation_mathml_with_depth(context, depth + 1)?,
+            escape(relation_data.relation_type.symbol()),
+            relation_data
+                .right
+                .to_presentation_mathml_with_depth(context, depth + 1)?
+        ),
+        Expression::Piecewise(piecewise_data) => format_piecewise(piecewise_data, context, depth)?,
+        Expression::Set(elements) => {
+            if elements.is_empty() {
+                "<mi>∅</mi>".to_owned()
+            } else {
+                fenced("{", &format_list(elements, context, depth)?, "}")
+            }
+        }
+        Expression::Interval(interval_data) => {
+            let open = if interval_data.start_inclusive {
+                "["
+            } else {
+                "("
+            };
+            let close = if interval_data.end_inclusive {
+                "]"
+            } else {
+                ")"
+            };
+            let bounds = [interval_data.start.clone(), interval_data.end.clone()];
+            fenced(open, &format_list(&bounds, context, depth)?, close)
+        }
+        Expression::Calculus(calculus_data) => format_calculus(calculus_data, context, depth)?,
+        Expression::MethodCall(method_data) => format!(
+            "<mrow>{}<mo>.</mo><mi>{}</mi>{}</mrow>",
+            operand(&method_data.object, context, depth)?,
+            escape(&method_data.method_name),
+            fenced("(", &format_list(&method_data.args, context, depth)?, ")")
+        ),
+    })
+}
+
+/// Format number as `<mn>`, with a separate minus operator for negatives
+fn format_number(num: &Number, context: &MathMLContext) -> String {
+    if let Some(decimal) = context.number_format.decimal_for(num) {
+        let mantissa = signed_mn(&decimal.mantissa);
+        return match decimal.exponent {
+            Some(exponent) => format!(
+                "<mrow>{}<mo>×</mo><msup><mn>10</mn>{}</msup></mrow>",
+                mantissa,
+                signed_mn(&exponent.to_string())
+            ),
+            None => mantissa,
+        };
+    }
+
+    match num {
+        Number::Integer(n) => signed_mn(&n.to_string()),
+        Number::BigInteger(n) => signed_mn(&n.to_string()),
+        Number::Rational(r) => {
+            if r.denom() == &num_bigint::BigInt::from(1) {
+                signed_mn(&r.numer().to_string())
+            } else {
+                let fraction = format!(
+                    "<mfrac><mn>{}</mn><mn>{}</mn></mfrac>",
+                    r.numer().magnitude(),
+                    r.denom()
+                );
+                if r.numer().sign() == num_bigint::Sign::Minus {
+                    format!("<mrow><mo>-</mo>{}</mrow>", fraction)
+                } else {
+                    fraction
+                }
+            }
+        }
+        Number::Float(f) if f.is_nan() => "<mi>NaN</mi>".to_owned(),
+        Number::Float(f) if f.is_infinite() => {
+            if *f > 0.0 {
+                "<mi>∞</mi>".to_owned()
+            } else {
+                "<mrow><mo>-</mo><mi>∞</mi></mrow>".to_owned()
+            }
+        }
+        Number::Float(f) => signed_mn(&f.to_string()),
+        Number::Decimal(d) => signed_mn(&d.to_string()),
+    }
+}
+
+fn signed_mn(text: &str) -> String {
+    match text.strip_prefix('-') {
+        Some(magnitude) => format!("<mrow><mo>-</mo><mn>{}</mn></mrow>", magnitude),
+        None => format!("<mn>{}</mn>", text),
+    }
+}
+
+/// Format symbol with its subscripts and type-aware notation
+///
+/// Matrix symbols are bold and operator symbols get a hat, as in LaTeX.
+fn format_symbol(symbol: &Symbol) -> String {
+    let name = SymbolName::parse(symbol.name());
+    let base = match symbol.symbol_type() {
+        SymbolType::Scalar | SymbolType::Quaternion => name_token(name.base),
+        SymbolType::Matrix => format!("<mi mathvariant=\"bold\">{}</mi>", name_text(name.base)),
+        SymbolType::Operator => format!("<mover>{}<mo>^</mo></mover>", name_token(name.base)),
+    };
+
+    match name.subscripts.as_slice() {
+        [] => base,
+        [part] => format!("<msub>{}{}</msub>", base, name_token(part)),
+        parts => {
+            let tokens: Vec<String> = parts.iter().map(|part| name_token(part)).collect();
+            format!(
+                "<msub>{}<mrow>{}</mrow></msub>",
+                base,
+                tokens.join("<mo>,</mo>")
+            )
+        }
+    }
+}
+
+/// `<mn>` for digit runs, `<mi>` for names (Greek letter names become letters)
+fn name_token(part: &str) -> String {
+    if !part.is_empty() && part.chars().all(|c| c.is_ascii_digit()) {
+        format!("<mn>{}</mn>", part)
+    } else {
+        format!("<mi>{}</mi>", name_text(part))
+    }
+}
+
+fn name_text(part: &str) -> String {
+    match greek_letter(part) {
+        Some(letter) => letter.unicode.to_string(),
+        None => escape(part).into_owned(),
+    }
+}
+
+fn format_constant(c: &MathConstant) -> &'static str {
+    match c {
+        MathConstant::Pi => "<mi>π</mi>",
+        MathConstant::E => "<mi>e</mi>",
+        MathConstant::I => "<mi>i</mi>",
+        MathConstant::Infinity => "<mi>∞</mi>",
+        MathConstant::NegativeInfinity => "<mrow><mo>-</mo><mi>∞</mi></mrow>",
+        MathConstant::Undefined => "<mtext>undefined</mtext>",
+        MathConstant::GoldenRatio => "<mi>φ</mi>",
+        MathConstant::EulerGamma => "<mi>γ</mi>",
+        MathConstant::TribonacciConstant => "<msub><mi>α</mi><mn>3</mn></msub>",
+    }
+}
+
+/// Format addition, writing negative terms after the first as subtractions
+fn format_addition(
+    terms: &[Expression],
+    context: &MathMLContext,
+    depth: usize,
+) -> Result<String, FormattingError> {
+    check_terms(terms.len())?;
+    if terms.len() == 1 {
+        return terms[0].to_presentation_mathml_with_depth(context, depth + 1);
+    }
+
+    let mut pieces = Vec::with_capacity(2 * terms.len());
+    for (i, term) in terms.iter().enumerate() {
+        if i == 0 {
+            pieces.push(term.to_presentation_mathml_with_depth(context, depth + 1)?);
+        } else if let Some(magnitude) = negated_term(term) {
+            pieces.push("<mo>-</mo>".to_owned());
+            pieces.push(summand(&magnitude, context, depth)?);
+        } else {
+            pieces.push("<mo>+</mo>".to_owned());
+            pieces.push(summand(term, context, depth)?);
+        }
+    }
+    Ok(format!("<mrow>{}</mrow>", pieces.concat()))
+}
+
+/// A term after `+`/`-`; nested sums keep their parentheses
+fn summand(
+    term: &Expression,
+    context: &MathMLContext,
+    depth: usize,
+) -> Result<String, FormattingError> {
+    let formatted = term.to_presentation_mathml_with_depth(context, depth + 1)?;
+    Ok(match term {
+        Expression::Add(_) | Expression::Complex(_) | Expression::Relation(_) => {
+            parenthesized(&formatted)
+        }
+        _ => formatted,
+    })
+}
+
+/// Magnitude of a term that reads as a subtraction (`-3`, `-x`, `-2 x`)
+fn negated_term(term: &Expression) -> Option<Expression> {
+    match term {
+        Expression::Number(num) if term.is_negative_number() => {
+            Some(Expression::Number((-num.clone()).ok()?))
+        }
+        Expression::Mul(factors) => negated_product(factors),
+        _ => None,
+    }
+}
+
+/// Magnitude of a product with a negative leading coefficient
+fn negated_product(factors: &[Expression]) -> Option<Expression> {
+    let (Expression::Number(coefficient), rest) = factors.split_first()? else {
+        return None;
+    };
+    if rest.is_empty() || !factors[0].is_negative_number() {
+        return None;
+    }
+
+    let magnitude = (-coefficient.clone()).ok()?;
+    let mut rest = rest.to_vec();
+    if !magnitude.is_one() {
+        rest.insert(0, Expression::Number(magnitude));
+    }
+    Some(product(rest))
+}
+
+/// Format multiplication as a fraction when factors have negative exponents,
+/// otherwise as juxtaposition
+fn format_multiplication(
+    factors: &[Expression],
+    context: &MathMLContext,
+    depth: usize,
+) -> Result<String, FormattingError> {
+    check_terms(factors.len())?;
+    if factors.len() == 1 {
+        return factors[0].to_presentation_mathml_with_depth(context, depth + 1);
+    }
+
+    if let Some(magnitude) = negated_product(factors) {
+        return Ok(format!(
+            "<mrow><mo>-</mo>{}</mrow>",
+            operand(&magnitude, context, depth)?
+        ));
+    }
+
+    let mut numerator = Vec::new();
+    let mut denominator = Vec::new();
+    for factor in factors {
+        match reciprocal(factor) {
+            Some(divisor) => denominator.push(divisor),
+            None => numerator.push(factor.clone()),
+        }
+    }
+    if !denominator.is_empty() {
+        return Ok(format!(
+            "<mfrac>{}{}</mfrac>",
+            product(numerator).to_presentation_mathml_with_depth(context, depth + 1)?,
+            product(denominator).to_presentation_mathml_with_depth(context, depth + 1)?
+        ));
+    }
+
+    let mut pieces = Vec::with_capacity(2 * factors.len());
+    for (i, factor) in factors.iter().enumerate() {
+        if i > 0 {
+            // Adjacent digits would read as one number: 2 ⋅ 3, not 23
+            let separator = if matches!(factor, Expression::Number(_)) {
+                "<mo>⋅</mo>"
+            } else {
+                INVISIBLE_TIMES
+            };
+            pieces.push(separator.to_owned());
+        }
+        let formatted = factor.to_presentation_mathml_with_depth(context, depth + 1)?;
+        pieces.push(match factor {
+            Expression::Add(_) | Expression::Mul(_) | Expression::Complex(_) => {
+                parenthesized(&formatted)
+            }
+            _ if i > 0 && negated_term(factor).is_some() => parenthesized(&formatted),
+            _ => formatted,
+        });
+    }
+    Ok(format!("<mrow>{}</mrow>", pieces.concat()))
+}
+
+/// `b^|n|` for a factor `b^n` with a negative numeric exponent
+fn reciprocal(factor: &Expression) -> Option<Expression> {
+    let Expression::Pow(base, exp) = factor else {
+        return None;
+    };
+    let Expression::Number(exponent) = exp.as_ref() else {
+        return None;
+    };
+    if !exp.is_negative_number() {
+        return None;
+    }
+
+    let magnitude = (-exponent.clone()).ok()?;
+    Some(if magnitude.is_one() {
+        base.as_ref().clone()
+    } else {
+        Expression::Pow(base.clone(), Arc::new(Expression::Number(magnitude)))
+    })
+}
+
+fn product(mut factors: Vec<Expression>) -> Expression {
+    match factors.len() {
+        0 => Expression::integer(1),
+        1 => factors.remove(0),
+        _ => Expression::Mul(Arc::new(factors)),
+    }
+}
+
+/// Format power as a root for unit-fraction exponents, otherwise as `<msup>`
+fn format_power(
+    base: &Expression,
+    exp: &Expression,
+    context: &MathMLContext,
+    depth: usize,
+) -> Result<String, FormattingError> {
+    if let Expression::Number(Number::Rational(r)) = exp {
+        if r.numer() == &num_bigint::BigInt::from(1) {
+            let radicand = base.to_presentation_mathml_with_depth(context, depth + 1)?;
+            return Ok(if r.denom() == &num_bigint::BigInt::from(2) {
+                format!("<msqrt>{}</msqrt>", radicand)
+            } else {
+                format!("<mroot>{}<mn>{}</mn></mroot>", radicand, r.denom())
+            });
+        }
+    }
+
+    let exponent = exp.to_presentation_mathml_with_depth(context, depth + 1)?;
+
+    // sin²(x) rather than sin(x)²; negative exponents would read as inverses
+    if let Expression::Function { name, args, .. } = base {
+        if exp.is_positive_number() && has_generic_notation(name) {
+            let head = format!("<msup><mi>{}</mi>{}</msup>", escape(name), exponent);
+            return applied(&head, args, context, depth);
+        }
+    }
+
+    Ok(format!(
+        "<msup>{}{}</msup>",
+        operand(base, context, depth)?,
+        exponent
+    ))
+}
+
+/// Format an operand of a power, factorial or method call, parenthesized
+/// unless it is atomic
+fn operand(
+    expr: &Expression,
+    context: &MathMLContext,
+    depth: usize,
+) -> Result<String, FormattingError> {
+    let formatted = expr.to_presentation_mathml_with_depth(context, depth + 1)?;
+    let atomic = match expr {
+        Expression::Number(Number::Rational(r)) => {
+            r.denom() == &num_bigint::BigInt::from(1) && !expr.is_negative_number()
+        }
+        Expression::Number(_) => !expr.is_negative_number(),
+        Expression::Symbol(_) | Expression::Function { .. } | Expression::Matrix(_) => true,
+        Expression::Constant(c) => *c != MathConstant::NegativeInfinity,
+        Expression::Set(_) | Expression::Interval(_) => true,
+        _ => false,
+    };
+    Ok(if atomic {
+        formatted
+    } else {
+        parenthesized(&formatted)
+    })
+}
+
+/// Whether `name` is written `name(args)` rather than in its own notation
+fn has_generic_notation(name: &str) -> bool {
+    !matches!(
+        name,
+        "sqrt" | "abs" | "factorial" | "floor" | "ceiling" | "ceil" | "conjugate" | "exp"
+    )
+}
+
+fn format_function(
+    name: &str,
+    args: &[Expression],
+    context: &MathMLContext,
+    depth: usize,
+) -> Result<String, FormattingError> {
+    check_terms(args.len())?;
+
+    if let [arg] = args {
+        let delimited = |open: &str, close: &str| -> Result<String, FormattingError> {
+            Ok(format!(
+                "<mrow><mo>{}</mo>{}<mo>{}</mo></mrow>",
+                open,
+                arg.to_presentation_mathml_with_depth(context, depth + 1)?,
+                close
+            ))
+        };
+        match name {
+            "sqrt" => {
+                return Ok(format!(
+                    "<msqrt>{}</msqrt>",
+                    arg.to_presentation_mathml_with_depth(context, depth + 1)?
+                ))
+            }
+            "abs" => return delimited("|", "|"),
+            "floor" => return delimited("⌊", "⌋"),
+            "ceiling" | "ceil" => return delimited("⌈", "⌉"),
+            "factorial" => {
+                return Ok(format!(
+                    "<mrow>{}<mo>!</mo></mrow>",
+                    operand(arg, context, depth)?
+                ))
+            }
+            "conjugate" => {
+                return Ok(format!(
+                    "<mover>{}<mo>¯</mo></mover>",
+                    arg.to_presentation_mathml_with_depth(context, depth + 1)?
+                ))
+            }
+            "exp" => {
+                return Ok(format!(
+                    "<msup><mi>e</mi>{}</msup>",
+                    arg.to_presentation_mathml_with_depth(context, depth + 1)?
+                ))
+            }
+            _ => {}
+        }
+    }
+
+    let head = format!("<mi>{}</mi>", escape(name));
+    applied(&head, args, context, depth)
+}
+
+/// `head⁡(args)`
+fn applied(
+    head: &str,
+    args: &[Expression],
+    context: &MathMLContext,
+    depth: usize,
+) -> Result<String, FormattingError> {
+    Ok(format!(
+        "<mrow>{}{}{}</mrow>",
+        head,
+        APPLY_FUNCTION,
+        fenced("(", &format_list(args, context, depth)?, ")")
+    ))
+}
+
+/// Comma separated elements
+fn format_list(
+    elements: &[Expression],
+    context: &MathMLContext,
+    depth: usize,
+) -> Result<String, FormattingError> {
+    check_terms(elements.len())?;
+    let formatted = elements
+        .iter()
+        .map(|element| element.to_presentation_mathml_with_depth(context, depth + 1))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(formatted.join("<mo>,</mo>"))
+}
+
+fn fenced(open: &str, inner: &str, close: &str) -> String {
+    format!("<mrow><mo>{}</mo>{}<mo>{}</mo></mrow>", open, inner, close)
+}
+
+fn parenthesized(inner: &str) -> String {
+    fenced("(", inner, ")")
+}
+
+/// Format matrix as an `<mtable>` between the configured delimiters
+///
+/// Elided rows and columns are shown as `⋮`, `⋯` and `⋱`.
+fn format_matrix(
+    matrix: &Matrix,
+    context: &MathMLContext,
+    depth: usize,
+) -> Result<String, FormattingError> {
+    let layout = context.matrix_format.layout(matrix);
+    check_terms(layout.cell_count())?;
+
+    let mut table = String::from("<mtable>");
+    for row in &layout.rows {
+        table.push_str("<mtr>");
+        for col in &layout.cols {
+            let cell = match (row, col) {
+                (Slot::Index(i), Slot::Index(j)) => matrix
+                    .get_element(*i, *j)
+                    .to_presentation_mathml_with_depth(context, depth + 1)?,
+                (Slot::Index(_), Slot::Ellipsis) => "<mo>⋯</mo>".to_owned(),
+                (Slot::Ellipsis, Slot::Index(_)) => "<mo>⋮</mo>".to_owned(),
+                (Slot::Ellipsis, Slot::Ellipsis) => "<mo>⋱</mo>".to_owned(),
+            };
+            table.push_str(&format!("<mtd>{}</mtd>", cell));
+        }
+        table.push_str("</mtr>");
+    }
+    table.push_str("</mtable>");
+
+    Ok(match context.matrix_format.delimiter {
+        MatrixDelimiter::Parentheses => fenced("(", &table, ")"),
+        MatrixDelimiter::Brackets => fenced("[", &table, "]"),
+        MatrixDelimiter::Bars => fenced("|", &table, "|"),
+        MatrixDelimiter::DoubleBars => fenced("‖", &table, "‖"),
+        MatrixDelimiter::Plain => table,
+    })
+}
+
+/// Format piecewise function as a brace followed by value/condition rows
+fn format_piecewise(
+    piecewise_data: &PiecewiseData,
+    context: &MathMLContext,
+    depth: usize,
+) -> Result<String, FormattingError> {
+    let mut rows = String::new();
+    for (condition, value) in &piecewise_data.pieces {
+        rows.push_str(&format!(
+            "<mtr><mtd>{}</mtd><mtd><mtext>if </mtext>{}</mtd></mtr>",
+            value.to_presentation_mathml_with_depth(context, depth + 1)?,
+            condition.to_presentation_mathml_with_depth(context, depth + 1)?
+        ));
+    }
+    if let Some(default_value) = &piecewise_data.default {
+        rows.push_str(&format!(
+            "<mtr><mtd>{}</mtd><mtd><mtext>otherwise</mtext></mtd></mtr>",
+            default_value.to_presentation_mathml_with_depth(context, depth + 1)?
+        ));
+    }
+    Ok(format!(
+        "<mrow><mo>{{</mo><mtable columnalign=\"left left\">{}</mtable></mrow>",
+        rows
+    ))
+}
+
+/// Format derivatives, integrals, limits, sums and products
+fn format_calculus(
+    calculus_data: &CalculusData,
+    context: &MathMLContext,
+    depth: usize,
+) -> Result<String, FormattingError> {
+    Ok(match calculus_data {
+        CalculusData::Derivative {
+            expression,
+            variable,
+            order,
+        } => {
+            let variable = format_symbol(variable);
+            let operator = if *order == 1 {
+                format!(
+                    "<mfrac><mi>d</mi><mrow><mi>d</mi>{}</mrow></mfrac>",
+                    variable
+                )
+            } else {
+                format!(
+                    "<mfrac><msup><mi>d</mi><mn>{order}</mn></msup>\
+                     <mrow><mi>d</mi><msup>{}<mn>{order}</mn></msup></mrow></mfrac>",
+                    variable
+                )
+            };
+            format!(
+                "<mrow>{}{}</mrow>",
+                operator,
+                summand(expression, context, depth)?
+            )
+        }
+        CalculusData::Integral {
+            integrand,
+            variable,
+            bounds,
+        } => {
+            let operator = match bounds {
+                Some((start, end)) => format!(
+                    "<msubsup><mo>∫</mo>{}{}</msubsup>",
+                    start.to_presentation_mathml_with_depth(context, depth + 1)?,
+                    end.to_presentation_mathml_with_depth(context, depth + 1)?
+                ),
+                None => "<mo>∫</mo>".to_owned(),
+            };
+            format!(
+                "<mrow>{}{}<mspace width=\"0.1667em\"/><mi>d</mi>{}</mrow>",
+                operator,
+                summand(integrand, context, depth)?,
+                format_symbol(variable)
+            )
+        }
+        CalculusData::Limit {
+            expression,
+            variable,
+            point,
+            direction,
+        } => {
+            let point = point.to_presentation_mathml_with_depth(context, depth + 1)?;
+            let approach = match direction {
+                LimitDirection::Both => point,
+                LimitDirection::Left => format!("<msup>{}<mo>-</mo></msup>", point),
+                LimitDirection::Right => format!("<msup>{}<mo>+</mo></msup>", point),
+            };
+            format!(
+                "<mrow><munder><mo>lim</mo><mrow>{}<mo>→</mo>{}</mrow></munder>{}</mrow>",
+                format_symbol(variable),
+                approach,
+                summand(expression, context, depth)?
+            )
+        }
+        CalculusData::Sum {
+            expression,
+            variable,
+            start,
+            end,
+        } => big_operator("∑", expression, variable, start, end, context, depth)?,
+        CalculusData::Product {
+            expression,
+            variable,
+            start,
+            end,
+        } => big_operator("∏", expression, variable, start, end, context, depth)?,
+    })
+}
+
+/// `∑`/`∏` with `variable = start` below and `end` above
+fn big_operator(
+    operator: &str,
+    expression: &Expression,
+    variable: &Symbol,
+    start: &Expression,
+    end: &Expression,
+    context: &MathMLContext,
+    depth: usize,
+) -> Result<String, FormattingError> {
+    Ok(format!(
+        "<mrow><munderover><mo>{}</mo><mrow>{}<mo>=</mo>{}</mrow>{}</munderover>{}</mrow>",
+        operator,
+        format_symbol(variable),
+        start.to_presentation_mathml_with_depth(context, depth + 1)?,
+        end.to_presentation_mathml_with_depth(context, depth + 1)?,
+        summand(expression, context, depth)?
+    ))
+}
@@ -0,0 +1,199 @@
+//! Matrix display configuration shared by all formatters
+//!
+//! `MatrixFormat` decides the LaTeX delimiters and how much of a large matrix
+//! is shown; each formatter spells the elided rows and columns in its own
+//! syntax (`\vdots`, `\[VerticalEllipsis]`, `⋮`).
+
+use crate::matrices::Matrix;
+
+/// LaTeX matrix environment (delimiters around the matrix)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MatrixDelimiter {
+    /// `pmatrix`: ( )
+    #[default]
+    Parentheses,
+    /// `bmatrix`: [ ]
+    Brackets,
+    /// `vmatrix`: | | (determinant notation)
+    Bars,
+    /// `Vmatrix`: ‖ ‖
+    DoubleBars,
+    /// `matrix`: no delimiters
+    Plain,
+}
+
+impl MatrixDelimiter {
+    /// Name of the LaTeX environment
+    pub fn environment(self) -> &'static str {
+        match self {
+            Self::Parentheses => "pmatrix",
+            Self::Brackets => "bmatrix",
+            Self::Bars => "vmatrix",
+            Self::DoubleBars => "Vmatrix",
+            Self::Plain => "matrix",
+        }
+    }
+}
+
+/// Matrix formatting options consumed by the Simple, LaTeX, Wolfram, MathML and pretty formatters
+///
+/// Every entry is shown by default. With limits set through
+/// [`MatrixFormat::with_limits`], matrices with more rows or columns keep
+/// their leading entries and the last row/column, with an ellipsis in between.
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::formatter::matrix_format::{MatrixDelimiter, MatrixFormat};
+/// use mathhook_core::formatter::latex::{LaTeXContext, LaTeXFormatter};
+/// use mathhook_core::Expression;
+///
+/// let m = Expression::matrix(vec![
+///     vec![Expression::integer(1), Expression::integer(2)],
+///     vec![Expression::integer(3), Expression::integer(4)],
+/// ]);
+/// let context = LaTeXContext {
+///     matrix_format: MatrixFormat::default().with_delimiter(MatrixDelimiter::Brackets),
+///     ..Default::default()
+/// };
+/// assert_eq!(
+///     m.to_latex(context).unwrap(),
+///     "\\begin{bmatrix} 1 & 2 \\\\ 3 & 4 \\end{bmatrix}"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MatrixFormat {
+    pub delimiter: MatrixDelimiter,
+    /// Largest number of rows shown in full (at least 2)
+    pub max_rows: usize,
+    /// Largest number of columns shown in full (at least 2)
+    pub max_cols: usize,
+}
+
+impl Default for MatrixFormat {
+    fn default() -> Self {
+        Self {
+            delimiter: MatrixDelimiter::default(),
+            max_rows: usize::MAX,
+            max_cols: usize::MAX,
+        }
+    }
+}
+
+impl MatrixFormat {
+    /// Use the given LaTeX delimiters
+    pub fn with_delimiter(mut self, delimiter: MatrixDelimiter) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Show at most `rows` x `cols` entries before eliding
+    pub fn with_limits(mut self, rows: usize, cols: usize) -> Self {
+        self.max_rows = rows;
+        self.max_cols = cols;
+        self
+    }
+
+    /// Never elide entries
+    pub fn unlimited(mut self) -> Self {
+        self.max_rows = usize::MAX;
+        self.max_cols = usize::MAX;
+        self
+    }
+
+    /// Rows and columns to display for `matrix`, in order
+    pub(crate) fn layout(&self, matrix: &Matrix) -> MatrixLayout {
+        let (rows, cols) = matrix.dimensions();
+        MatrixLayout {
+            rows: visible_slots(rows, self.max_rows),
+            cols: visible_slots(cols, self.max_cols),
+        }
+    }
+}
+
+/// A displayed row or column: a real index or the elision marker
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Slot {
+    Index(usize),
+    Ellipsis,
+}
+
+/// Which rows and columns of a matrix are displayed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct MatrixLayout {
+    pub rows: Vec<Slot>,
+    pub cols: Vec<Slot>,
+}
+
+impl MatrixLayout {
+    /// Total number of displayed entries (elision markers included)
+    pub fn cell_count(&self) -> usize {
+        self.rows.len() * self.cols.len()
+    }
+}
+
+/// First `limit - 1` indices, an ellipsis, then the last index
+fn visible_slots(len: usize, limit: usize) -> Vec<Slot> {
+    let limit = limit.max(2);
+    if len <= limit {
+        return (0..len).map(Slot::Index).collect();
+    }
+
+    let mut slots: Vec<Slot> = (0..limit - 1).map(Slot::Index).collect();
+    slots.push(Slot::Ellipsis);
+    slots.push(Slot::Index(len - 1));
+    slots
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_small_dimensions_are_shown_in_full() {
+        assert_eq!(
+            visible_slots(3, 10),
+            vec![Slot::Index(0), Slot::Index(1), Slot::Index(2)]
+        );
+        assert!(visible_slots(0, 10).is_empty());
+    }
+
+    #[test]
+    fn test_large_dimensions_keep_head_and_last() {
+        assert_eq!(
+            visible_slots(100, 3),
+            vec![
+                Slot::Index(0),
+                Slot::Index(1),
+                Slot::Ellipsis,
+                Slot::Index(99)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_limit_is_clamped_to_two() {
+        assert_eq!(
+            visible_slots(5, 0),
+            vec![Slot::Index(0), Slot::Ellipsis, Slot::Index(4)]
+        );
+    }
+
+    #[test]
+    fn test_layout_uses_both_limits() {
+        let matrix = Matrix::identity(20);
+        let layout = MatrixFormat::default().with_limits(4, 2).layout(&matrix);
+        assert_eq!(layout.rows.len(), 5);
+        assert_eq!(layout.cols.len(), 3);
+        assert_eq!(layout.cell_count(), 15);
+        assert_eq!(MatrixFormat::default().layout(&matrix).rows.len(), 20);
+        assert_eq!(
+            MatrixFormat::default()
+                .with_limits(4, 2)
+                .unlimited()
+                .layout(&matrix)
+                .cell_count(),
+            400
+        );
+    }
+}
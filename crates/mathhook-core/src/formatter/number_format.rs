@@ -0,0 +1,343 @@
+//! Number display configuration shared by all formatters
+//!
+//! `NumberFormat` decides *which digits* are shown (significant figures,
+//! fixed decimals, scientific notation, rationals as decimals); each formatter
+//! only decides how an exponent is spelled in its own syntax.
+
+use crate::core::Number;
+use num_traits::ToPrimitive;
+
+/// Float notation selection
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FloatNotation {
+    /// Fixed notation for moderate magnitudes, scientific for very large/small ones
+    #[default]
+    Auto,
+    /// Always positional notation (`12300.0`)
+    Fixed,
+    /// Always mantissa/exponent notation (`1.23 × 10^4`)
+    Scientific,
+}
+
+/// How non-integer rationals are displayed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RationalDisplay {
+    /// Exact fraction (`3/4`, `\frac{3}{4}`)
+    #[default]
+    Fraction,
+    /// Decimal approximation following the float settings (`0.75`)
+    Decimal,
+}
+
+/// Spelling of the power-of-ten part in scientific notation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExponentStyle {
+    /// `1.5e-7`
+    Ascii,
+    /// `1.5×10^-7`
+    Unicode,
+    /// `1.5 \times 10^{-7}`
+    LaTeX,
+    /// `1.5*^-7`
+    Wolfram,
+}
+
+/// Number formatting options consumed by the Simple, LaTeX and Wolfram formatters
+///
+/// The default shows floats with their shortest round-trip digits, switching
+/// to scientific notation for magnitudes below `1e-5` or from `1e16` up, and
+/// rationals as exact fractions.
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::formatter::number_format::{ExponentStyle, NumberFormat};
+///
+/// let format = NumberFormat::significant(3);
+/// let shown = format.format_float(1.23456).render(ExponentStyle::Ascii);
+/// assert_eq!(shown, "1.23");
+///
+/// let format = NumberFormat::scientific(2);
+/// let shown = format.format_float(12345.0).render(ExponentStyle::LaTeX);
+/// assert_eq!(shown, "1.2 \\times 10^{4}");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct NumberFormat {
+    /// Round floats to this many significant figures
+    pub significant_figures: Option<usize>,
+    /// Round floats to this many digits after the decimal point (ignored when
+    /// `significant_figures` is set)
+    pub decimal_places: Option<usize>,
+    pub notation: FloatNotation,
+    pub rationals: RationalDisplay,
+    /// Strip trailing zeros (and a dangling decimal point) from rounded output
+    pub trim_trailing_zeros: bool,
+}
+
+/// Magnitudes outside `[AUTO_SCIENTIFIC_MIN, AUTO_SCIENTIFIC_MAX)` switch to scientific in `Auto`
+const AUTO_SCIENTIFIC_MAX: f64 = 1e16;
+const AUTO_SCIENTIFIC_MIN: f64 = 1e-5;
+
+impl NumberFormat {
+    /// Round to `figures` significant figures in automatic notation
+    pub fn significant(figures: usize) -> Self {
+        Self {
+            significant_figures: Some(figures.max(1)),
+            ..Default::default()
+        }
+    }
+
+    /// Fixed notation with exactly `places` decimals
+    pub fn fixed(places: usize) -> Self {
+        Self {
+            decimal_places: Some(places),
+            notation: FloatNotation::Fixed,
+            ..Default::default()
+        }
+    }
+
+    /// Scientific notation with `figures` significant figures
+    pub fn scientific(figures: usize) -> Self {
+        Self {
+            significant_figures: Some(figures.max(1)),
+            notation: FloatNotation::Scientific,
+            ..Default::default()
+        }
+    }
+
+    /// Show rationals as decimals using the float settings
+    pub fn with_decimal_rationals(mut self) -> Self {
+        self.rationals = RationalDisplay::Decimal;
+        self
+    }
+
+    /// Strip trailing zeros from rounded output
+    pub fn with_trimmed_zeros(mut self) -> Self {
+        self.trim_trailing_zeros = true;
+        self
+    }
+
+    /// Decimal rendering of a number, or `None` when the formatter should keep
+    /// its exact integer/fraction output
+    pub fn decimal_for(&self, number: &Number) -> Option<FormattedNumber> {
+        match number {
+            Number::Float(f) => Some(self.format_float(*f)),
+            Number::Rational(r)
+                if self.rationals == RationalDisplay::Decimal && !r.is_integer() =>
+            {
+                r.to_f64().map(|f| self.format_float(f))
+            }
+            // Rounded exactly, so `fixed(2)` shows amounts of money as written
+            Number::Decimal(d) if self.significant_figures.is_none() => {
+                let places = self.decimal_places?;
+                let formatted = FormattedNumber::plain(d.round_to(places as u32).to_string());
+                Some(if self.trim_trailing_zeros {
+                    formatted.trimmed()
+                } else {
+                    formatted
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// Format a float according to these options
+    pub fn format_float(&self, value: f64) -> FormattedNumber {
+        if !value.is_finite() {
+            return FormattedNumber::plain(value.to_string());
+        }
+
+        let use_scientific = match self.notation {
+            FloatNotation::Scientific => true,
+            FloatNotation::Fixed => false,
+            FloatNotation::Auto => {
+                let magnitude = value.abs();
+                magnitude != 0.0 && !(AUTO_SCIENTIFIC_MIN..AUTO_SCIENTIFIC_MAX).contains(&magnitude)
+            }
+        };
+
+        let formatted = if use_scientific {
+            self.scientific_parts(value)
+        } else {
+            FormattedNumber::plain(self.fixed_digits(value))
+        };
+
+        if self.trim_trailing_zeros {
+            formatted.trimmed()
+        } else {
+            formatted
+        }
+    }
+
+    fn fixed_digits(&self, value: f64) -> String {
+        match (self.significant_figures, self.decimal_places) {
+            (Some(figures), _) => {
+                // Rounding can carry into a new leading digit (9.999 -> 10.0),
+                // so take the exponent of the rounded value
+                let places = figures as i32 - 1 - rounded_exponent(value, figures);
+                if places >= 0 {
+                    format!("{:.*}", places as usize, value)
+                } else {
+                    let scale = 10f64.powi(-places);
+                    format!("{:.0}", (value / scale).round() * scale)
+                }
+            }
+            (None, Some(places)) => format!("{:.*}", places, value),
+            (None, None) => value.to_string(),
+        }
+    }
+
+    fn scientific_parts(&self, value: f64) -> FormattedNumber {
+        let rendered = match (self.significant_figures, self.decimal_places) {
+            (Some(figures), _) => format!("{:.*e}", figures - 1, value),
+            (None, Some(places)) => format!("{:.*e}", places, value),
+            (None, None) => format!("{:e}", value),
+        };
+
+        match rendered.split_once('e') {
+            Some((mantissa, exponent)) => FormattedNumber {
+                mantissa: mantissa.to_owned(),
+                exponent: exponent.parse().ok().filter(|e| *e != 0),
+            },
+            None => FormattedNumber::plain(rendered),
+        }
+    }
+}
+
+/// Base-10 exponent of the leading digit once `value` is rounded to `figures`
+/// significant figures (`1234.5 -> 3`, `0.012 -> -2`, `9.999 -> 1` for 3)
+fn rounded_exponent(value: f64, figures: usize) -> i32 {
+    let rendered = format!("{:.*e}", figures - 1, value);
+    rendered
+        .split_once('e')
+        .and_then(|(_, exponent)| exponent.parse().ok())
+        .unwrap_or(0)
+}
+
+/// A number split into mantissa digits and an optional power of ten
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormattedNumber {
+    pub mantissa: String,
+    pub exponent: Option<i32>,
+}
+
+impl FormattedNumber {
+    fn plain(mantissa: String) -> Self {
+        Self {
+            mantissa,
+            exponent: None,
+        }
+    }
+
+    fn trimmed(mut self) -> Self {
+        if self.mantissa.contains('.') {
+            let trimmed = self.mantissa.trim_end_matches('0').trim_end_matches('.');
+            self.mantissa = trimmed.to_owned();
+        }
+        self
+    }
+
+    /// Spell the number in a formatter's syntax
+    pub fn render(&self, style: ExponentStyle) -> String {
+        let Some(exponent) = self.exponent else {
+            return self.mantissa.clone();
+        };
+
+        match style {
+            ExponentStyle::Ascii => format!("{}e{}", self.mantissa, exponent),
+            ExponentStyle::Unicode => format!("{}×10^{}", self.mantissa, exponent),
+            ExponentStyle::LaTeX => format!("{} \\times 10^{{{}}}", self.mantissa, exponent),
+            ExponentStyle::Wolfram => format!("{}*^{}", self.mantissa, exponent),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::number::Decimal;
+    use num_bigint::BigInt;
+    use num_rational::BigRational;
+
+    fn ascii(format: &NumberFormat, value: f64) -> String {
+        format.format_float(value).render(ExponentStyle::Ascii)
+    }
+
+    #[test]
+    fn test_default_matches_shortest_roundtrip() {
+        let format = NumberFormat::default();
+        assert_eq!(ascii(&format, 2.5), "2.5");
+        assert_eq!(ascii(&format, 0.1), "0.1");
+        assert_eq!(ascii(&format, -3.0), "-3");
+    }
+
+    #[test]
+    fn test_auto_switches_to_scientific_for_extremes() {
+        let format = NumberFormat::default();
+        assert_eq!(ascii(&format, 1.5e20), "1.5e20");
+        assert_eq!(ascii(&format, 2.0e-9), "2e-9");
+    }
+
+    #[test]
+    fn test_significant_figures() {
+        let format = NumberFormat::significant(3);
+        assert_eq!(ascii(&format, 1.23456), "1.23");
+        assert_eq!(ascii(&format, 0.0012345), "0.00123");
+        assert_eq!(ascii(&format, 98765.4), "98800");
+        assert_eq!(ascii(&format, 9.999), "10.0");
+        assert_eq!(ascii(&format, 999.96), "1000");
+        assert_eq!(ascii(&NumberFormat::significant(2), 0.09996), "0.10");
+    }
+
+    #[test]
+    fn test_fixed_and_trailing_zeros() {
+        let format = NumberFormat::fixed(4);
+        assert_eq!(ascii(&format, 2.5), "2.5000");
+        assert_eq!(ascii(&format.with_trimmed_zeros(), 2.5), "2.5");
+        assert_eq!(ascii(&format.with_trimmed_zeros(), 3.0), "3");
+    }
+
+    #[test]
+    fn test_scientific_styles() {
+        let value = NumberFormat::scientific(3).format_float(-0.000123456);
+        assert_eq!(value.render(ExponentStyle::Ascii), "-1.23e-4");
+        assert_eq!(value.render(ExponentStyle::Unicode), "-1.23×10^-4");
+        assert_eq!(value.render(ExponentStyle::LaTeX), "-1.23 \\times 10^{-4}");
+        assert_eq!(value.render(ExponentStyle::Wolfram), "-1.23*^-4");
+    }
+
+    #[test]
+    fn test_rationals_as_decimals() {
+        let three_quarters = Number::rational(BigRational::new(BigInt::from(3), BigInt::from(4)));
+
+        assert!(NumberFormat::default()
+            .decimal_for(&three_quarters)
+            .is_none());
+
+        let format = NumberFormat::default().with_decimal_rationals();
+        let shown = format.decimal_for(&three_quarters).unwrap();
+        assert_eq!(shown.render(ExponentStyle::Ascii), "0.75");
+
+        assert!(format.decimal_for(&Number::integer(7)).is_none());
+    }
+
+    #[test]
+    fn test_decimals_round_exactly() {
+        let price = Number::decimal(Decimal::parse("2.675").unwrap());
+        let shown = NumberFormat::fixed(2).decimal_for(&price).unwrap();
+        assert_eq!(shown.render(ExponentStyle::Ascii), "2.68");
+
+        let whole = Number::decimal(Decimal::parse("4").unwrap());
+        let shown = NumberFormat::fixed(2).decimal_for(&whole).unwrap();
+        assert_eq!(shown.render(ExponentStyle::Ascii), "4.00");
+
+        assert!(NumberFormat::default().decimal_for(&price).is_none());
+    }
+
+    #[test]
+    fn test_non_finite_passthrough() {
+        let format = NumberFormat::scientific(3);
+        assert_eq!(ascii(&format, f64::INFINITY), "inf");
+        assert_eq!(ascii(&format, f64::NAN), "NaN");
+    }
+}
@@ -0,0 +1,204 @@
+//! Two-dimensional terminal rendering
+//!
+//! Lays expressions out over several lines the way they are written by hand:
+//! fractions over a bar, exponents raised, radicals under a root sign and
+//! matrices between tall brackets.
+//!
+//! ```text
+//!      2
+//! 1 + x         ⎡1  2⎤
+//! ──────        ⎢    ⎥
+//!   y           ⎣3  4⎦
+//! ```
+
+use super::matrix_format::MatrixDelimiter;
+use super::{FormattingContext, FormattingError, MatrixFormat, NumberFormat};
+use crate::core::Expression;
+
+mod block;
+mod layout;
+
+const MAX_RECURSION_DEPTH: usize = 1000;
+const MAX_TERMS_PER_OPERATION: usize = 10000;
+
+/// Pretty (2D) formatting context
+#[derive(Debug, Clone)]
+pub struct PrettyContext {
+    /// Draw with Unicode box drawing characters and symbols (`⎛`, `─`, `√`,
+    /// `π`); plain ASCII art otherwise
+    pub use_unicode: bool,
+    /// Float/rational display options
+    pub number_format: NumberFormat,
+    /// Matrix delimiters and size limits
+    pub matrix_format: MatrixFormat,
+}
+
+impl Default for PrettyContext {
+    fn default() -> Self {
+        Self {
+            use_unicode: true,
+            number_format: NumberFormat::default(),
+            matrix_format: MatrixFormat::default().with_delimiter(MatrixDelimiter::Brackets),
+        }
+    }
+}
+
+impl PrettyContext {
+    /// ASCII-only context for terminals without Unicode support
+    pub fn ascii() -> Self {
+        Self {
+            use_unicode: false,
+            ..Default::default()
+        }
+    }
+}
+
+impl FormattingContext for PrettyContext {}
+
+/// Format the expression as multi-line text
+pub trait PrettyFormatter {
+    /// Format an Expression as a 2D layout for monospaced display
+    ///
+    /// Lines are joined with `\n` and carry no trailing spaces.
+    ///
+    /// # Arguments
+    /// * `context` - Pretty formatting configuration
+    ///
+    /// # Context Options
+    /// * `use_unicode` - Unicode box drawing characters or ASCII art
+    /// * `number_format` - Significant figures, notation and rational display for numbers
+    /// * `matrix_format` - Matrix delimiters and size limits
+    ///
+    /// # Examples
+    /// ```
+    /// use mathhook_core::expr;
+    /// use mathhook_core::formatter::pretty::{PrettyContext, PrettyFormatter};
+    ///
+    /// let expression = expr!((x + 1) / y);
+    /// assert_eq!(
+    ///     expression.to_pretty(&PrettyContext::default()).unwrap(),
+    ///     "1 + x\n─────\n  y"
+    /// );
+    /// assert_eq!(
+    ///     expression.to_pretty(&PrettyContext::ascii()).unwrap(),
+    ///     "1 + x\n-----\n  y"
+    /// );
+    /// ```
+    ///
+    /// # Error Handling
+    /// Returns error messages for expressions that exceed safety limits:
+    /// - Maximum recursion depth (1000 levels)
+    /// - Maximum terms per operation (10000 terms)
+    fn to_pretty(&self, context: &PrettyContext) -> Result<String, FormattingError>;
+}
+
+impl PrettyFormatter for Expression {
+    fn to_pretty(&self, context: &PrettyContext) -> Result<String, FormattingError> {
+        Ok(layout::layout(self, context, 0)?.render())
+    }
+}
+
+fn check_recursion(depth: usize) -> Result<(), FormattingError> {
+    if depth > MAX_RECURSION_DEPTH {
+        return Err(FormattingError::RecursionLimitExceeded {
+            depth,
+            limit: MAX_RECURSION_DEPTH,
+        });
+    }
+    Ok(())
+}
+
+fn check_terms(count: usize) -> Result<(), FormattingError> {
+    if count > MAX_TERMS_PER_OPERATION {
+        return Err(FormattingError::TooManyTerms {
+            count,
+            limit: MAX_TERMS_PER_OPERATION,
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::expression::RelationType;
+    use crate::core::MathConstant;
+    use crate::{expr, symbol};
+    use std::sync::Arc;
+
+    fn pretty(expr: &Expression) -> String {
+        expr.to_pretty(&PrettyContext::default()).unwrap()
+    }
+
+    fn ascii(expr: &Expression) -> String {
+        expr.to_pretty(&PrettyContext::ascii()).unwrap()
+    }
+
+    #[test]
+    fn test_powers_and_fractions() {
+        assert_eq!(pretty(&expr!(x ^ 2)), " 2\nx");
+        assert_eq!(pretty(&expr!((x ^ 2) + 1)), "     2\n1 + x");
+        assert_eq!(pretty(&expr!(x / y)), "x\n─\ny");
+        assert_eq!(pretty(&expr!(x ^ (-2))), "1\n──\n 2\nx");
+        assert_eq!(
+            pretty(&Expression::rational(1, 2)),
+            "1\n─\n2",
+            "rationals are stacked"
+        );
+        assert_eq!(pretty(&expr!(2 * x * y)), "2⋅x⋅y");
+        assert_eq!(pretty(&expr!(x - y)), "x - y");
+        assert_eq!(pretty(&expr!((x + 1) ^ 2)), "       2\n(1 + x)");
+    }
+
+    #[test]
+    fn test_tall_parentheses() {
+        let expr = Expression::Pow(Arc::new(expr!(x / y)), Arc::new(expr!(2)));
+        assert_eq!(pretty(&expr), "   2\n⎛x⎞\n⎜─⎟\n⎝y⎠");
+        assert_eq!(ascii(&expr), "   2\n/x\\\n|-|\n\\y/");
+    }
+
+    #[test]
+    fn test_roots() {
+        assert_eq!(pretty(&expr!(sqrt(x))), "√x");
+        assert_eq!(pretty(&expr!(sqrt(x + 1))), "  ______\n╲╱ 1 + x");
+        assert_eq!(ascii(&expr!(sqrt(x))), "  __\n\\/ x");
+        let cube_root = Expression::pow(expr!(x), Expression::rational(1, 3));
+        assert_eq!(pretty(&cube_root), "3 __\n╲╱ x");
+    }
+
+    #[test]
+    fn test_functions() {
+        assert_eq!(pretty(&expr!(sin(x))), "sin(x)");
+        assert_eq!(
+            pretty(&Expression::pow(expr!(sin(x)), expr!(2))),
+            "   2\nsin (x)"
+        );
+        assert_eq!(pretty(&expr!(abs(x))), "│x│");
+        assert_eq!(pretty(&expr!(exp(x))), " x\nℯ");
+        assert_eq!(pretty(&Expression::constant(MathConstant::Pi)), "π");
+        assert_eq!(ascii(&Expression::constant(MathConstant::Pi)), "pi");
+    }
+
+    #[test]
+    fn test_matrix() {
+        let m = Expression::matrix(vec![vec![expr!(1), expr!(2)], vec![expr!(3), expr!(x ^ 2)]]);
+        assert_eq!(pretty(&m), "⎡1  2 ⎤\n⎢     ⎥\n⎢    2⎥\n⎣3  x ⎦");
+        assert_eq!(
+            ascii(&Expression::matrix(vec![vec![expr!(1), expr!(2)]])),
+            "[1  2]"
+        );
+    }
+
+    #[test]
+    fn test_relations_and_calculus() {
+        let x = symbol!(x);
+        let equation = Expression::relation(expr!(x ^ 2), expr!(4), RelationType::Equal);
+        assert_eq!(pretty(&equation), " 2\nx  = 4");
+
+        let integral = Expression::integral(expr!(x ^ 2), x.clone());
+        assert_eq!(pretty(&integral), "⌠\n⎮  2\n⎮ x  dx\n⌡");
+
+        let derivative = Expression::derivative(expr!(sin(x)), x, 1);
+        assert_eq!(pretty(&derivative), "d\n──(sin(x))\ndx");
+    }
+}
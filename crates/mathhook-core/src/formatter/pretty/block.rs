@@ -0,0 +1,227 @@
+//! Rectangular blocks of text that are composed into 2D layouts
+
+/// Lines of equal width, with the row on which the block sits on the
+/// surrounding text line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct Block {
+    lines: Vec<String>,
+    baseline: usize,
+}
+
+/// Glyphs of a stretchable delimiter
+///
+/// A delimiter one line tall uses `single`; taller ones are assembled from
+/// `top`, `extension` and `bottom`, with `middle` (if any) on the central row.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct Delimiter {
+    pub single: &'static str,
+    pub top: &'static str,
+    pub extension: &'static str,
+    pub middle: Option<&'static str>,
+    pub bottom: &'static str,
+}
+
+impl Delimiter {
+    /// A delimiter drawn with one glyph on every row
+    pub const fn uniform(glyph: &'static str) -> Self {
+        Self {
+            single: glyph,
+            top: glyph,
+            extension: glyph,
+            middle: None,
+            bottom: glyph,
+        }
+    }
+
+    /// Column of this delimiter as tall as `height`
+    fn column(&self, height: usize) -> Vec<&'static str> {
+        if height <= 1 {
+            return vec![self.single];
+        }
+        let mut column = vec![self.extension; height];
+        column[0] = self.top;
+        column[height - 1] = self.bottom;
+        if let Some(middle) = self.middle.filter(|_| height >= 3) {
+            column[height / 2] = middle;
+        }
+        column
+    }
+}
+
+impl Block {
+    /// Single-line block
+    pub fn text(text: impl Into<String>) -> Self {
+        Self {
+            lines: vec![text.into()],
+            baseline: 0,
+        }
+    }
+
+    /// Block from rows that may differ in width; shorter rows are padded
+    pub fn from_lines(lines: Vec<String>, baseline: usize) -> Self {
+        let width = lines
+            .iter()
+            .map(|line| display_width(line))
+            .max()
+            .unwrap_or(0);
+        let lines = lines
+            .into_iter()
+            .map(|line| pad_right(line, width))
+            .collect();
+        Self { lines, baseline }
+    }
+
+    pub fn width(&self) -> usize {
+        self.lines.first().map_or(0, |line| display_width(line))
+    }
+
+    pub fn height(&self) -> usize {
+        self.lines.len()
+    }
+
+    pub fn baseline(&self) -> usize {
+        self.baseline
+    }
+
+    pub fn is_single_line(&self) -> bool {
+        self.lines.len() == 1
+    }
+
+    /// Widened to `width` with the content centered
+    pub fn centered(self, width: usize) -> Self {
+        let left = width.saturating_sub(self.width()) / 2;
+        self.padded(left, width)
+    }
+
+    /// Widened to `width` with the content flush left
+    pub fn flush_left(self, width: usize) -> Self {
+        self.padded(0, width)
+    }
+
+    fn padded(self, left: usize, width: usize) -> Self {
+        let lines = self
+            .lines
+            .into_iter()
+            .map(|line| pad_right(format!("{}{}", " ".repeat(left), line), width))
+            .collect();
+        Self {
+            lines,
+            baseline: self.baseline,
+        }
+    }
+
+    /// Blocks side by side, aligned on their baselines
+    pub fn row(blocks: Vec<Block>) -> Self {
+        let above = blocks.iter().map(|b| b.baseline).max().unwrap_or(0);
+        let below = blocks
+            .iter()
+            .map(|b| b.height() - b.baseline - 1)
+            .max()
+            .unwrap_or(0);
+
+        let mut lines = vec![String::new(); above + below + 1];
+        for block in &blocks {
+            let top = above - block.baseline;
+            let blank = " ".repeat(block.width());
+            for (i, line) in lines.iter_mut().enumerate() {
+                match i.checked_sub(top).and_then(|row| block.lines.get(row)) {
+                    Some(row) => line.push_str(row),
+                    None => line.push_str(&blank),
+                }
+            }
+        }
+        Self {
+            lines,
+            baseline: above,
+        }
+    }
+
+    /// Blocks on top of each other, each centered horizontally; the result
+    /// sits on row `baseline`
+    pub fn stack(blocks: Vec<Block>, baseline: usize) -> Self {
+        let width = blocks.iter().map(Block::width).max().unwrap_or(0);
+        let lines = blocks
+            .into_iter()
+            .flat_map(|block| block.centered(width).lines)
+            .collect();
+        Self { lines, baseline }
+    }
+
+    /// Blocks on top of each other, flush left
+    pub fn stack_left(blocks: Vec<Block>, baseline: usize) -> Self {
+        let lines = blocks.into_iter().flat_map(|block| block.lines).collect();
+        Self::from_lines(lines, baseline)
+    }
+
+    /// `numerator` over `denominator` separated by a bar, sitting on the bar
+    pub fn fraction(numerator: Block, denominator: Block, bar: char) -> Self {
+        let width = numerator.width().max(denominator.width());
+        let baseline = numerator.height();
+        let bar = Block::text(bar.to_string().repeat(width));
+        Self::stack(vec![numerator, bar, denominator], baseline)
+    }
+
+    /// `exponent` raised above the top-right corner of `base`
+    pub fn superscript(base: Block, exponent: Block) -> Self {
+        let baseline = exponent.height() + base.baseline;
+        let exponent_width = exponent.width();
+        let base_width = base.width();
+        let mut lines = Vec::with_capacity(exponent.height() + base.height());
+        lines.extend(
+            exponent
+                .lines
+                .into_iter()
+                .map(|line| format!("{}{}", " ".repeat(base_width), line)),
+        );
+        lines.extend(
+            base.lines
+                .into_iter()
+                .map(|line| format!("{}{}", line, " ".repeat(exponent_width))),
+        );
+        Self { lines, baseline }
+    }
+
+    /// The block between two stretchable delimiters
+    pub fn delimited(self, left: &Delimiter, right: &Delimiter) -> Self {
+        let left = Self::delimiter(left, self.height(), self.baseline);
+        let right = Self::delimiter(right, self.height(), self.baseline);
+        Self::row(vec![left, self, right])
+    }
+
+    /// Delimiter as tall as `height`, sitting on row `baseline`
+    pub fn delimiter(delimiter: &Delimiter, height: usize, baseline: usize) -> Self {
+        let lines = delimiter
+            .column(height)
+            .into_iter()
+            .map(str::to_owned)
+            .collect();
+        Self::from_lines(lines, baseline)
+    }
+
+    /// Copy of the block with a line of `fill` above it
+    pub fn overlined(self, fill: char) -> Self {
+        let bar = Block::text(fill.to_string().repeat(self.width()));
+        let baseline = self.baseline + 1;
+        Self::stack(vec![bar, self], baseline)
+    }
+
+    /// Rows joined by newlines, without trailing spaces
+    pub fn render(&self) -> String {
+        self.lines
+            .iter()
+            .map(|line| line.trim_end())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Number of terminal columns; every glyph used is one column wide
+fn display_width(text: &str) -> usize {
+    text.chars().count()
+}
+
+fn pad_right(mut text: String, width: usize) -> String {
+    let missing = width.saturating_sub(display_width(&text));
+    text.extend(std::iter::repeat_n(' ', missing));
+    text
+}
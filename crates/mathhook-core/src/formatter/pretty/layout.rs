@@ -0,0 +1,946 @@
+use super::block::{Block, Delimiter};
+use super::{check_recursion, check_terms, PrettyContext};
+use crate::core::expression::{CalculusData, LimitDirection, Matrix, PiecewiseData, RelationType};
+use crate::core::symbol::names::SymbolName;
+use crate::core::{Expression, MathConstant, Number, Symbol};
+use crate::formatter::matrix_format::{MatrixDelimiter, Slot};
+use crate::formatter::FormattingError;
+use num_bigint::BigInt;
+use std::sync::Arc;
+
+/// Characters used to draw one flavour of output
+struct Glyphs {
+    times: &'static str,
+    /// Mantissa-to-power-of-ten joiner in scientific notation
+    scale: &'static str,
+    fraction_bar: char,
+    overline: char,
+    parentheses: [Delimiter; 2],
+    brackets: [Delimiter; 2],
+    braces: [Delimiter; 2],
+    bars: Delimiter,
+    double_bars: Delimiter,
+    /// Floor and ceiling brackets; spelled as functions when absent
+    floor: Option<[Delimiter; 2]>,
+    ceiling: Option<[Delimiter; 2]>,
+    /// Falling and rising strokes of root and summation signs
+    falling: char,
+    rising: char,
+    /// Top, extension and bottom rows of the integral sign
+    integral: [&'static str; 3],
+    sum_bottom: char,
+    /// Horizontal bar, corner and leg of the product sign
+    product: [char; 3],
+    arrow: &'static str,
+    from_left: &'static str,
+    from_right: &'static str,
+    /// Vertical, horizontal and diagonal ellipses of elided matrix entries
+    ellipses: [&'static str; 3],
+    empty_set: &'static str,
+}
+
+const UNICODE: Glyphs = Glyphs {
+    times: "⋅",
+    scale: "×10",
+    fraction_bar: '─',
+    overline: '_',
+    parentheses: [
+        Delimiter {
+            single: "(",
+            top: "⎛",
+            extension: "⎜",
+            middle: None,
+            bottom: "⎝",
+        },
+        Delimiter {
+            single: ")",
+            top: "⎞",
+            extension: "⎟",
+            middle: None,
+            bottom: "⎠",
+        },
+    ],
+    brackets: [
+        Delimiter {
+            single: "[",
+            top: "⎡",
+            extension: "⎢",
+            middle: None,
+            bottom: "⎣",
+        },
+        Delimiter {
+            single: "]",
+            top: "⎤",
+            extension: "⎥",
+            middle: None,
+            bottom: "⎦",
+        },
+    ],
+    braces: [
+        Delimiter {
+            single: "{",
+            top: "⎧",
+            extension: "⎪",
+            middle: Some("⎨"),
+            bottom: "⎩",
+        },
+        Delimiter {
+            single: "}",
+            top: "⎫",
+            extension: "⎪",
+            middle: Some("⎬"),
+            bottom: "⎭",
+        },
+    ],
+    bars: Delimiter::uniform("│"),
+    double_bars: Delimiter::uniform("║"),
+    floor: Some([
+        Delimiter {
+            single: "⌊",
+            top: "⎢",
+            extension: "⎢",
+            middle: None,
+            bottom: "⎣",
+        },
+        Delimiter {
+            single: "⌋",
+            top: "⎥",
+            extension: "⎥",
+            middle: None,
+            bottom: "⎦",
+        },
+    ]),
+    ceiling: Some([
+        Delimiter {
+            single: "⌈",
+            top: "⎡",
+            extension: "⎢",
+            middle: None,
+            bottom: "⎢",
+        },
+        Delimiter {
+            single: "⌉",
+            top: "⎤",
+            extension: "⎥",
+            middle: None,
+            bottom: "⎥",
+        },
+    ]),
+    falling: '╲',
+    rising: '╱',
+    integral: ["⌠", "⎮", "⌡"],
+    sum_bottom: '‾',
+    product: ['─', '┬', '│'],
+    arrow: "─→",
+    from_left: "⁻",
+    from_right: "⁺",
+    ellipses: ["⋮", "⋯", "⋱"],
+    empty_set: "∅",
+};
+
+const ASCII: Glyphs = Glyphs {
+    times: "*",
+    scale: "*10",
+    fraction_bar: '-',
+    overline: '_',
+    parentheses: [
+        Delimiter {
+            single: "(",
+            top: "/",
+            extension: "|",
+            middle: None,
+            bottom: "\\",
+        },
+        Delimiter {
+            single: ")",
+            top: "\\",
+            extension: "|",
+            middle: None,
+            bottom: "/",
+        },
+    ],
+    brackets: [Delimiter::uniform("["), Delimiter::uniform("]")],
+    braces: [
+        Delimiter {
+            single: "{",
+            top: "/",
+            extension: "|",
+            middle: Some("<"),
+            bottom: "\\",
+        },
+        Delimiter {
+            single: "}",
+            top: "\\",
+            extension: "|",
+            middle: Some(">"),
+            bottom: "/",
+        },
+    ],
+    bars: Delimiter::uniform("|"),
+    double_bars: Delimiter::uniform("||"),
+    floor: None,
+    ceiling: None,
+    falling: '\\',
+    rising: '/',
+    integral: ["  /", " | ", "/  "],
+    sum_bottom: '-',
+    product: ['_', '_', '|'],
+    arrow: "->",
+    from_left: "-",
+    from_right: "+",
+    ellipses: ["...", "...", "..."],
+    empty_set: "{}",
+};
+
+fn glyphs(context: &PrettyContext) -> &'static Glyphs {
+    if context.use_unicode {
+        &UNICODE
+    } else {
+        &ASCII
+    }
+}
+
+pub(super) fn layout(
+    expr: &Expression,
+    context: &PrettyContext,
+    depth: usize,
+) -> Result<Block, FormattingError> {
+    check_recursion(depth)?;
+    let glyphs = glyphs(context);
+
+    Ok(match expr {
+        Expression::Number(num) => format_number(num, context),
+        Expression::Symbol(s) => Block::text(symbol_name(s, context)),
+        Expression::Constant(c) => Block::text(format_constant(c, context)),
+        Expression::Add(terms) => format_addition(terms, context, depth)?,
+        Expression::Mul(factors) => format_multiplication(factors, context, depth)?,
+        Expression::Pow(base, exp) => format_power(expr, base, exp, context, depth)?,
+        Expression::Function { name, args, .. } => format_function(name, args, context, depth)?,
+        Expression::Complex(complex_data) => {
+            let imaginary = Expression::Mul(Arc::new(vec![
+                complex_data.imag.clone(),
+                Expression::Constant(MathConstant::I),
+            ]));
+            if complex_data.real.is_zero() {
+                layout(&imaginary, context, depth + 1)?
+            } else {
+                let sum = [complex_data.real.clone(), imaginary];
+                format_addition(&sum, context, depth)?
+            }
+        }
+        Expression::Matrix(matrix) => format_matrix(matrix, context, depth)?,
+        Expression::Relation(relation_data) => Block::row(vec![
+            layout(&relation_data.left, context, depth + 1)?,
+            Block::text(format!(
+                " {} ",
+                relation_operator(relation_data.relation_type, context)
+            )),
+            layout(&relation_data.right, context, depth + 1)?,
+        ]),
+        Expression::Piecewise(piecewise_data) => format_piecewise(piecewise_data, context, depth)?,
+        Expression::Set(elements) if elements.is_empty() => Block::text(glyphs.empty_set),
+        Expression::Set(elements) => {
+            let [open, close] = &glyphs.braces;
+            format_list(elements, context, depth)?.delimited(open, close)
+        }
+        Expression::Interval(interval_data) => {
+            let bounds = [interval_data.start.clone(), interval_data.end.clone()];
+            let open = if interval_data.start_inclusive {
+                &glyphs.brackets[0]
+            } else {
+                &glyphs.parentheses[0]
+            };
+            let close = if interval_data.end_inclusive {
+                &glyphs.brackets[1]
+            } else {
+                &glyphs.parentheses[1]
+            };
+            format_list(&bounds, context, depth)?.delimited(open, close)
+        }
+        Expression::Calculus(calculus_data) => format_calculus(calculus_data, context, depth)?,
+        Expression::MethodCall(method_data) => Block::row(vec![
+            operand(&method_data.object, context, depth)?,
+            Block::text(format!(".{}", method_data.method_name)),
+            arguments(&method_data.args, context, depth)?,
+        ]),
+    })
+}
+
+fn format_number(num: &Number, context: &PrettyContext) -> Block {
+    let glyphs = glyphs(context);
+    if let Some(decimal) = context.number_format.decimal_for(num) {
+        return match decimal.exponent {
+            Some(exponent) => Block::superscript(
+                Block::text(format!("{}{}", decimal.mantissa, glyphs.scale)),
+                Block::text(exponent.to_string()),
+            ),
+            None => Block::text(decimal.mantissa),
+        };
+    }
+
+    match num {
+        Number::Integer(n) => Block::text(n.to_string()),
+        Number::BigInteger(n) => Block::text(n.to_string()),
+        Number::Rational(r) if r.denom() == &BigInt::from(1) => Block::text(r.numer().to_string()),
+        Number::Rational(r) => {
+            let fraction = Block::fraction(
+                Block::text(r.numer().magnitude().to_string()),
+                Block::text(r.denom().to_string()),
+                glyphs.fraction_bar,
+            );
+            if r.numer() < &BigInt::from(0) {
+                Block::row(vec![Block::text("-"), fraction])
+            } else {
+                fraction
+            }
+        }
+        Number::Float(f) => Block::text(f.to_string()),
+        Number::Decimal(d) => Block::text(d.to_string()),
+    }
+}
+
+fn symbol_name(symbol: &Symbol, context: &PrettyContext) -> String {
+    if context.use_unicode {
+        SymbolName::parse(symbol.name()).to_unicode()
+    } else {
+        symbol.name().to_owned()
+    }
+}
+
+fn format_constant(c: &MathConstant, context: &PrettyContext) -> &'static str {
+    match (c, context.use_unicode) {
+        (MathConstant::Pi, true) => "π",
+        (MathConstant::Pi, false) => "pi",
+        (MathConstant::E, true) => "ℯ",
+        (MathConstant::E, false) => "e",
+        (MathConstant::I, true) => "ⅈ",
+        (MathConstant::I, false) => "i",
+        (MathConstant::Infinity, true) => "∞",
+        (MathConstant::Infinity, false) => "oo",
+        (MathConstant::NegativeInfinity, true) => "-∞",
+        (MathConstant::NegativeInfinity, false) => "-oo",
+        (MathConstant::Undefined, _) => "undefined",
+        (MathConstant::GoldenRatio, true) => "φ",
+        (MathConstant::GoldenRatio, false) => "phi",
+        (MathConstant::EulerGamma, true) => "γ",
+        (MathConstant::EulerGamma, false) => "gamma",
+        (MathConstant::TribonacciConstant, _) => "T",
+    }
+}
+
+fn relation_operator(relation_type: RelationType, context: &PrettyContext) -> &'static str {
+    if context.use_unicode {
+        return relation_type.symbol();
+    }
+    match relation_type {
+        RelationType::Equal => "=",
+        RelationType::NotEqual => "!=",
+        RelationType::Less => "<",
+        RelationType::LessEqual => "<=",
+        RelationType::Greater => ">",
+        RelationType::GreaterEqual => ">=",
+        RelationType::Approximate => "~=",
+        RelationType::Similar => "~",
+        RelationType::Proportional => "prop",
+        RelationType::Congruent => "cong",
+    }
+}
+
+/// Format addition with subtraction for negative terms
+fn format_addition(
+    terms: &[Expression],
+    context: &PrettyContext,
+    depth: usize,
+) -> Result<Block, FormattingError> {
+    check_terms(terms.len())?;
+    if terms.is_empty() {
+        return Ok(Block::text("0"));
+    }
+
+    let mut pieces = Vec::with_capacity(2 * terms.len());
+    for (i, term) in terms.iter().enumerate() {
+        let (negative, magnitude) = match negated_term(term) {
+            Some(magnitude) => (true, magnitude),
+            None => (false, term.clone()),
+        };
+        match (i, negative) {
+            (0, false) => {}
+            (0, true) => pieces.push(Block::text("-")),
+            (_, false) => pieces.push(Block::text(" + ")),
+            (_, true) => pieces.push(Block::text(" - ")),
+        }
+        pieces.push(summand(&magnitude, context, depth)?);
+    }
+    Ok(Block::row(pieces))
+}
+
+/// Magnitude of a term with a negative leading coefficient
+fn negated_term(term: &Expression) -> Option<Expression> {
+    match term {
+        Expression::Number(num) if term.is_negative_number() => {
+            Some(Expression::Number((-num.clone()).ok()?))
+        }
+        Expression::Mul(factors) => negated_product(factors),
+        _ => None,
+    }
+}
+
+/// Magnitude of a product with a negative leading coefficient
+fn negated_product(factors: &[Expression]) -> Option<Expression> {
+    let (Expression::Number(coefficient), rest) = factors.split_first()? else {
+        return None;
+    };
+    if rest.is_empty() || !factors[0].is_negative_number() {
+        return None;
+    }
+
+    let magnitude = (-coefficient.clone()).ok()?;
+    let mut rest = rest.to_vec();
+    if !magnitude.is_one() {
+        rest.insert(0, Expression::Number(magnitude));
+    }
+    Some(product(rest))
+}
+
+/// Format multiplication over a fraction bar when factors have negative
+/// exponents or a fractional coefficient, otherwise joined by a dot
+fn format_multiplication(
+    factors: &[Expression],
+    context: &PrettyContext,
+    depth: usize,
+) -> Result<Block, FormattingError> {
+    check_terms(factors.len())?;
+    if factors.len() == 1 {
+        return layout(&factors[0], context, depth + 1);
+    }
+
+    if let Some(magnitude) = negated_product(factors) {
+        let formatted = layout(&magnitude, context, depth + 1)?;
+        let magnitude = match magnitude {
+            Expression::Add(_) => parenthesized(formatted, context),
+            _ => formatted,
+        };
+        return Ok(Block::row(vec![Block::text("-"), magnitude]));
+    }
+
+    let mut numerator = Vec::new();
+    let mut denominator = Vec::new();
+    for factor in factors {
+        if let Some(divisor) = reciprocal(factor) {
+            denominator.push(divisor);
+            continue;
+        }
+        match factor {
+            Expression::Number(Number::Rational(r)) if !r.is_integer() => {
+                if r.numer() != &BigInt::from(1) {
+                    numerator.push(Expression::Number(Number::big_integer(r.numer().clone())));
+                }
+                denominator.insert(
+                    0,
+                    Expression::Number(Number::big_integer(r.denom().clone())),
+                );
+            }
+            _ => numerator.push(factor.clone()),
+        }
+    }
+    if !denominator.is_empty() {
+        return Ok(Block::fraction(
+            layout(&product(numerator), context, depth + 1)?,
+            layout(&product(denominator), context, depth + 1)?,
+            glyphs(context).fraction_bar,
+        ));
+    }
+
+    let mut pieces = Vec::with_capacity(2 * factors.len());
+    for (i, factor) in factors.iter().enumerate() {
+        if i > 0 {
+            pieces.push(Block::text(glyphs(context).times));
+        }
+        let formatted = layout(factor, context, depth + 1)?;
+        pieces.push(match factor {
+            Expression::Add(_) | Expression::Mul(_) | Expression::Complex(_) => {
+                parenthesized(formatted, context)
+            }
+            _ if i > 0 && negated_term(factor).is_some() => parenthesized(formatted, context),
+            _ => formatted,
+        });
+    }
+    Ok(Block::row(pieces))
+}
+
+/// `b^|n|` for a factor `b^n` with a negative numeric exponent
+fn reciprocal(factor: &Expression) -> Option<Expression> {
+    let Expression::Pow(base, exp) = factor else {
+        return None;
+    };
+    let Expression::Number(exponent) = exp.as_ref() else {
+        return None;
+    };
+    if !exp.is_negative_number() {
+        return None;
+    }
+
+    let magnitude = (-exponent.clone()).ok()?;
+    Some(if magnitude.is_one() {
+        base.as_ref().clone()
+    } else {
+        Expression::Pow(base.clone(), Arc::new(Expression::Number(magnitude)))
+    })
+}
+
+fn product(mut factors: Vec<Expression>) -> Expression {
+    match factors.len() {
+        0 => Expression::integer(1),
+        1 => factors.remove(0),
+        _ => Expression::Mul(Arc::new(factors)),
+    }
+}
+
+/// Format power as a fraction for negative exponents, a root sign for
+/// unit-fraction exponents, otherwise with the exponent raised
+fn format_power(
+    expr: &Expression,
+    base: &Expression,
+    exp: &Expression,
+    context: &PrettyContext,
+    depth: usize,
+) -> Result<Block, FormattingError> {
+    if let Some(divisor) = reciprocal(expr) {
+        return Ok(Block::fraction(
+            Block::text("1"),
+            layout(&divisor, context, depth + 1)?,
+            glyphs(context).fraction_bar,
+        ));
+    }
+
+    let exponent = match exp {
+        Expression::Number(Number::Rational(r)) if !r.is_integer() => {
+            if r.numer() == &BigInt::from(1) {
+                let radicand = layout(base, context, depth + 1)?;
+                let index = (r.denom() != &BigInt::from(2)).then(|| r.denom().to_string());
+                return Ok(root(radicand, index, atomic(base), context));
+            }
+            // Raised fractions stay on one line: x^(3/2) rather than a stacked 3 over 2
+            Block::text(format!("{}/{}", r.numer(), r.denom()))
+        }
+        _ => layout(exp, context, depth + 1)?,
+    };
+
+    // sin²(x) rather than sin(x)²; negative exponents would read as inverses
+    if let Expression::Function { name, args, .. } = base {
+        if exp.is_positive_number() && has_generic_notation(name) {
+            let head = Block::superscript(Block::text(name.as_ref()), exponent);
+            return Ok(Block::row(vec![head, arguments(args, context, depth)?]));
+        }
+    }
+
+    Ok(Block::superscript(operand(base, context, depth)?, exponent))
+}
+
+/// Radical sign over `radicand`, with an optional root index
+///
+/// ```text
+///           ___      3 _______
+/// √x      ╲╱ 2⋅x     ╲╱ x + 1
+/// ```
+fn root(radicand: Block, index: Option<String>, atomic: bool, context: &PrettyContext) -> Block {
+    let glyphs = glyphs(context);
+    if context.use_unicode && atomic && index.is_none() && radicand.is_single_line() {
+        return Block::row(vec![Block::text("√"), radicand]);
+    }
+
+    let height = radicand.height();
+    let body = Block::row(vec![Block::text(" "), radicand]).overlined(glyphs.overline);
+
+    // Row 0 is level with the overline; the stroke rises from the bottom row
+    let mut sign: Vec<Vec<char>> = vec![vec![' '; height + 1]; height + 1];
+    for row in 1..=height {
+        sign[row][height + 1 - row] = glyphs.rising;
+    }
+    sign[height][0] = glyphs.falling;
+
+    let mut sign: Vec<String> = sign.into_iter().map(String::from_iter).collect();
+    if let Some(index) = index {
+        // The index sits just above the falling stroke, left of the rising one
+        // leaving one blank column before the stroke or overline
+        let row = height - 1;
+        let shift = index.chars().count().saturating_sub(1);
+        for line in &mut sign {
+            line.insert_str(0, &" ".repeat(shift));
+        }
+        let rest: String = sign[row].chars().skip(index.chars().count()).collect();
+        sign[row] = format!("{}{}", index, rest);
+    }
+
+    let sign = Block::from_lines(sign, body.baseline());
+    Block::row(vec![sign, body])
+}
+
+/// Format an operand of a power, factorial or method call, parenthesized
+/// unless it is atomic
+fn operand(
+    expr: &Expression,
+    context: &PrettyContext,
+    depth: usize,
+) -> Result<Block, FormattingError> {
+    let formatted = layout(expr, context, depth + 1)?;
+    Ok(if atomic(expr) {
+        formatted
+    } else {
+        parenthesized(formatted, context)
+    })
+}
+
+fn atomic(expr: &Expression) -> bool {
+    match expr {
+        Expression::Number(Number::Rational(r)) => r.is_integer() && !expr.is_negative_number(),
+        Expression::Number(_) => !expr.is_negative_number(),
+        Expression::Symbol(_) | Expression::Function { .. } | Expression::Matrix(_) => true,
+        Expression::Constant(c) => *c != MathConstant::NegativeInfinity,
+        Expression::Set(_) | Expression::Interval(_) => true,
+        _ => false,
+    }
+}
+
+/// Format the body of a sum, integral or limit, parenthesized if it is itself a sum
+fn summand(
+    expr: &Expression,
+    context: &PrettyContext,
+    depth: usize,
+) -> Result<Block, FormattingError> {
+    let formatted = layout(expr, context, depth + 1)?;
+    Ok(match expr {
+        Expression::Add(_) | Expression::Relation(_) => parenthesized(formatted, context),
+        _ => formatted,
+    })
+}
+
+fn parenthesized(block: Block, context: &PrettyContext) -> Block {
+    let [open, close] = &glyphs(context).parentheses;
+    block.delimited(open, close)
+}
+
+/// Whether `name` is written `name(args)` rather than in its own notation
+fn has_generic_notation(name: &str) -> bool {
+    !matches!(
+        name,
+        "sqrt" | "abs" | "factorial" | "floor" | "ceiling" | "ceil" | "conjugate" | "exp"
+    )
+}
+
+fn format_function(
+    name: &str,
+    args: &[Expression],
+    context: &PrettyContext,
+    depth: usize,
+) -> Result<Block, FormattingError> {
+    check_terms(args.len())?;
+    let glyphs = glyphs(context);
+
+    if let [arg] = args {
+        let delimited = |[open, close]: &[Delimiter; 2]| -> Result<Block, FormattingError> {
+            Ok(layout(arg, context, depth + 1)?.delimited(open, close))
+        };
+        match (name, &glyphs.floor, &glyphs.ceiling) {
+            ("sqrt", _, _) => {
+                let radicand = layout(arg, context, depth + 1)?;
+                return Ok(root(radicand, None, atomic(arg), context));
+            }
+            ("abs", _, _) => {
+                return Ok(layout(arg, context, depth + 1)?.delimited(&glyphs.bars, &glyphs.bars))
+            }
+            ("floor", Some(floor), _) => return delimited(floor),
+            ("ceiling" | "ceil", _, Some(ceiling)) => return delimited(ceiling),
+            ("factorial", _, _) => {
+                return Ok(Block::row(vec![
+                    operand(arg, context, depth)?,
+                    Block::text("!"),
+                ]))
+            }
+            ("conjugate", _, _) => {
+                return Ok(layout(arg, context, depth + 1)?.overlined(glyphs.overline))
+            }
+            ("exp", _, _) => {
+                return Ok(Block::superscript(
+                    Block::text(format_constant(&MathConstant::E, context)),
+                    layout(arg, context, depth + 1)?,
+                ))
+            }
+            _ => {}
+        }
+    }
+
+    Ok(Block::row(vec![
+        Block::text(name),
+        arguments(args, context, depth)?,
+    ]))
+}
+
+/// `(a, b, c)`
+fn arguments(
+    args: &[Expression],
+    context: &PrettyContext,
+    depth: usize,
+) -> Result<Block, FormattingError> {
+    Ok(parenthesized(format_list(args, context, depth)?, context))
+}
+
+/// `a, b, c` without delimiters
+fn format_list(
+    items: &[Expression],
+    context: &PrettyContext,
+    depth: usize,
+) -> Result<Block, FormattingError> {
+    check_terms(items.len())?;
+    let mut pieces = Vec::with_capacity(2 * items.len());
+    for (i, item) in items.iter().enumerate() {
+        if i > 0 {
+            pieces.push(Block::text(", "));
+        }
+        pieces.push(layout(item, context, depth + 1)?);
+    }
+    Ok(Block::row(pieces))
+}
+
+/// Format a matrix as centered columns between tall delimiters, with a blank
+/// line between rows
+fn format_matrix(
+    matrix: &Matrix,
+    context: &PrettyContext,
+    depth: usize,
+) -> Result<Block, FormattingError> {
+    let glyphs = glyphs(context);
+    let layout_slots = context.matrix_format.layout(matrix);
+    check_terms(layout_slots.cell_count())?;
+
+    let [vertical, horizontal, diagonal] = glyphs.ellipses;
+    let mut cells = Vec::with_capacity(layout_slots.rows.len());
+    for row in &layout_slots.rows {
+        let mut row_cells = Vec::with_capacity(layout_slots.cols.len());
+        for col in &layout_slots.cols {
+            row_cells.push(match (row, col) {
+                (Slot::Index(i), Slot::Index(j)) => {
+                    layout(&matrix.get_element(*i, *j), context, depth + 1)?
+                }
+                (Slot::Index(_), Slot::Ellipsis) => Block::text(horizontal),
+                (Slot::Ellipsis, Slot::Index(_)) => Block::text(vertical),
+                (Slot::Ellipsis, Slot::Ellipsis) => Block::text(diagonal),
+            });
+        }
+        cells.push(row_cells);
+    }
+
+    let mut widths = vec![0; layout_slots.cols.len()];
+    for row in &cells {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.width());
+        }
+    }
+
+    let mut rows = Vec::with_capacity(2 * cells.len());
+    for (i, row) in cells.into_iter().enumerate() {
+        if i > 0 {
+            rows.push(Block::text(""));
+        }
+        let mut pieces = Vec::with_capacity(2 * row.len());
+        for (j, (cell, width)) in row.into_iter().zip(&widths).enumerate() {
+            if j > 0 {
+                pieces.push(Block::text("  "));
+            }
+            pieces.push(cell.centered(*width));
+        }
+        rows.push(Block::row(pieces));
+    }
+    let height: usize = rows.iter().map(Block::height).sum();
+    let table = Block::stack_left(rows, height.saturating_sub(1) / 2);
+
+    Ok(match context.matrix_format.delimiter {
+        MatrixDelimiter::Parentheses => parenthesized(table, context),
+        MatrixDelimiter::Brackets => {
+            let [open, close] = &glyphs.brackets;
+            table.delimited(open, close)
+        }
+        MatrixDelimiter::Bars => table.delimited(&glyphs.bars, &glyphs.bars),
+        MatrixDelimiter::DoubleBars => table.delimited(&glyphs.double_bars, &glyphs.double_bars),
+        MatrixDelimiter::Plain => table,
+    })
+}
+
+/// Format piecewise function as a brace followed by `value  for condition` rows
+fn format_piecewise(
+    piecewise_data: &PiecewiseData,
+    context: &PrettyContext,
+    depth: usize,
+) -> Result<Block, FormattingError> {
+    let mut rows = Vec::with_capacity(piecewise_data.pieces.len() + 1);
+    for (condition, value) in &piecewise_data.pieces {
+        rows.push((
+            layout(value, context, depth + 1)?,
+            Block::row(vec![
+                Block::text("  for "),
+                layout(condition, context, depth + 1)?,
+            ]),
+        ));
+    }
+    if let Some(default_value) = &piecewise_data.default {
+        rows.push((
+            layout(default_value, context, depth + 1)?,
+            Block::text("  otherwise"),
+        ));
+    }
+
+    let width = rows
+        .iter()
+        .map(|(value, _)| value.width())
+        .max()
+        .unwrap_or(0);
+    let rows: Vec<Block> = rows
+        .into_iter()
+        .map(|(value, condition)| Block::row(vec![value.flush_left(width), condition]))
+        .collect();
+    let height: usize = rows.iter().map(Block::height).sum();
+    let baseline = height.saturating_sub(1) / 2;
+    let body = Block::stack_left(rows, baseline);
+    let brace = Block::delimiter(&glyphs(context).braces[0], height, baseline);
+    Ok(Block::row(vec![brace, body]))
+}
+
+/// Format derivatives, integrals, limits, sums and products
+fn format_calculus(
+    calculus_data: &CalculusData,
+    context: &PrettyContext,
+    depth: usize,
+) -> Result<Block, FormattingError> {
+    let glyphs = glyphs(context);
+    Ok(match calculus_data {
+        CalculusData::Derivative {
+            expression,
+            variable,
+            order,
+        } => {
+            let differential = format!("d{}", symbol_name(variable, context));
+            let (numerator, denominator) = if *order == 1 {
+                (Block::text("d"), Block::text(differential))
+            } else {
+                (
+                    Block::superscript(Block::text("d"), Block::text(order.to_string())),
+                    Block::superscript(Block::text(differential), Block::text(order.to_string())),
+                )
+            };
+            Block::row(vec![
+                Block::fraction(numerator, denominator, glyphs.fraction_bar),
+                parenthesized(layout(expression, context, depth + 1)?, context),
+            ])
+        }
+        CalculusData::Integral {
+            integrand,
+            variable,
+            bounds,
+        } => {
+            let body = summand(integrand, context, depth)?;
+            let [top, extension, bottom] = glyphs.integral;
+            let mut lines = vec![top.to_owned()];
+            lines.extend((0..body.height()).map(|_| extension.to_owned()));
+            lines.push(bottom.to_owned());
+            let sign = Block::from_lines(lines, 1 + body.baseline());
+
+            let sign = match bounds {
+                Some((start, end)) => {
+                    let upper = layout(end, context, depth + 1)?;
+                    let lower = layout(start, context, depth + 1)?;
+                    let baseline = upper.height() + sign.baseline();
+                    Block::stack(vec![upper, sign, lower], baseline)
+                }
+                None => sign,
+            };
+            Block::row(vec![
+                sign,
+                Block::text(" "),
+                body,
+                Block::text(format!(" d{}", symbol_name(variable, context))),
+            ])
+        }
+        CalculusData::Limit {
+            expression,
+            variable,
+            point,
+            direction,
+        } => {
+            let side = match direction {
+                LimitDirection::Both => "",
+                LimitDirection::Left => glyphs.from_left,
+                LimitDirection::Right => glyphs.from_right,
+            };
+            let approach = Block::row(vec![
+                Block::text(format!(
+                    "{}{}",
+                    symbol_name(variable, context),
+                    glyphs.arrow
+                )),
+                layout(point, context, depth + 1)?,
+                Block::text(side),
+            ]);
+            let operator = Block::stack(vec![Block::text("lim"), approach], 0);
+            Block::row(vec![
+                operator,
+                Block::text(" "),
+                summand(expression, context, depth)?,
+            ])
+        }
+        CalculusData::Sum {
+            expression,
+            variable,
+            start,
+            end,
+        } => {
+            let body = summand(expression, context, depth)?;
+            let strokes = body.height().div_ceil(2).max(2);
+            let width = strokes + 1;
+            let mut lines = vec![glyphs.overline.to_string().repeat(width)];
+            lines.extend((0..strokes).map(|k| format!("{}{}", " ".repeat(k), glyphs.falling)));
+            lines.extend(
+                (0..strokes)
+                    .rev()
+                    .map(|k| format!("{}{}", " ".repeat(k), glyphs.rising)),
+            );
+            lines.push(glyphs.sum_bottom.to_string().repeat(width));
+            let sign = Block::from_lines(lines, strokes);
+            big_operator(sign, body, variable, start, end, context, depth)?
+        }
+        CalculusData::Product {
+            expression,
+            variable,
+            start,
+            end,
+        } => {
+            let body = summand(expression, context, depth)?;
+            let legs = (body.height() + 1).max(2);
+            let [bar, corner, leg] = glyphs.product;
+            let bar = bar.to_string();
+            let mut lines = vec![format!("{bar}{corner}{}{corner}{bar}", bar.repeat(legs))];
+            lines.extend((0..legs).map(|_| format!(" {leg}{} {leg} ", " ".repeat(legs - 1))));
+            let sign = Block::from_lines(lines, 1 + body.baseline());
+            big_operator(sign, body, variable, start, end, context, depth)?
+        }
+    })
+}
+
+/// `sign` with `end` above and `variable = start` below, followed by `body`
+fn big_operator(
+    sign: Block,
+    body: Block,
+    variable: &Symbol,
+    start: &Expression,
+    end: &Expression,
+    context: &PrettyContext,
+    depth: usize,
+) -> Result<Block, FormattingError> {
+    let upper = layout(end, context, depth + 1)?;
+    let lower = Block::row(vec![
+        Block::text(format!("{} = ", symbol_name(variable, context))),
+        layout(start, context, depth + 1)?,
+    ]);
+    let baseline = upper.height() + sign.baseline();
+    let operator = Block::stack(vec![upper, sign, lower], baseline);
+    Ok(Block::row(vec![operator, Block::text(" "), body]))
+}
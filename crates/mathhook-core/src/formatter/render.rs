@@ -0,0 +1,1448 @@
+//! Standalone SVG rendering of expressions
+//!
+//! Lays out an expression with a small TeX-style box model (fractions,
+//! scripts, radicals, stretchy delimiters, big operators and matrices) and
+//! writes the result as a self-contained SVG document. No TeX installation,
+//! MathJax or font files are needed, which makes it suitable for server-side
+//! report generation.
+//!
+//! Glyph widths come from approximate metrics of a serif math font; multi-
+//! character runs carry a `textLength` so the layout holds whichever font the
+//! viewer substitutes.
+
+use super::matrix_format::{MatrixDelimiter, Slot};
+use super::simple::{SimpleContext, SimpleFormatter};
+use super::{FormattingContext, FormattingError, MatrixFormat, NumberFormat};
+use crate::core::expression::smart_display::SmartDisplayFormatter;
+use crate::core::expression::{CalculusData, LimitDirection, RelationType};
+use crate::core::symbol::names::{greek_letter, SymbolName};
+use crate::core::symbol::SymbolType;
+use crate::core::{Expression, MathConstant, Number, Symbol};
+use crate::matrices::Matrix;
+use num_traits::{One, Signed};
+use std::fmt::Write;
+use std::sync::Arc;
+
+const MAX_RECURSION_DEPTH: usize = 1000;
+const MAX_TERMS_PER_OPERATION: usize = 10000;
+
+/// Height of glyphs above the baseline, in em
+const ASCENT: f64 = 0.72;
+/// Depth of glyphs below the baseline, in em
+const DESCENT: f64 = 0.22;
+/// Height of the math axis (fraction bars, centered operators), in em
+const AXIS: f64 = 0.25;
+/// Thickness of fraction bars and radical strokes, in em
+const RULE: f64 = 0.05;
+/// Script size relative to the surrounding text
+const SCRIPT_SCALE: f64 = 0.7;
+
+/// SVG rendering context
+#[derive(Debug, Clone)]
+pub struct SvgContext {
+    /// Font size of top-level text in pixels
+    pub font_size: f64,
+    /// Fill and stroke color of the formula
+    pub color: String,
+    /// Background fill; transparent when `None`
+    pub background: Option<String>,
+    /// Empty space around the formula in pixels
+    pub padding: f64,
+    /// CSS font family for glyphs
+    pub font_family: String,
+    /// Float/rational display options
+    pub number_format: NumberFormat,
+    /// Matrix delimiters and size limits
+    pub matrix_format: MatrixFormat,
+}
+
+impl Default for SvgContext {
+    fn default() -> Self {
+        Self {
+            font_size: 20.0,
+            color: "black".to_owned(),
+            background: None,
+            padding: 4.0,
+            font_family: "'STIX Two Math', 'Cambria Math', 'Latin Modern Math', serif".to_owned(),
+            number_format: NumberFormat::default(),
+            matrix_format: MatrixFormat::default(),
+        }
+    }
+}
+
+impl FormattingContext for SvgContext {}
+
+/// Render expressions as standalone SVG images
+pub trait SvgRenderer {
+    /// Render an expression as an SVG document
+    ///
+    /// The document has explicit `width`/`height`, a `<title>` with the plain
+    /// text form for accessibility, and a `vertical-align` style so it lines up
+    /// with surrounding text when embedded inline in HTML.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::expr;
+    /// use mathhook_core::formatter::render::{SvgContext, SvgRenderer};
+    ///
+    /// let svg = expr!((x ^ 2) + 1).to_svg(&SvgContext::default()).unwrap();
+    /// assert!(svg.starts_with("<svg"));
+    /// assert!(svg.contains(">x</text>"));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for expressions deeper than 1000 levels or operations
+    /// with more than 10000 terms.
+    fn to_svg(&self, context: &SvgContext) -> Result<String, FormattingError>;
+}
+
+impl SvgRenderer for Expression {
+    fn to_svg(&self, context: &SvgContext) -> Result<String, FormattingError> {
+        let layout = Layouter { context }.layout(self, context.font_size, 0)?;
+        let title = self
+            .to_simple(&SimpleContext::default())
+            .unwrap_or_default();
+        Ok(layout.to_svg(context, &title))
+    }
+}
+
+/// Text style of a glyph run
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Style {
+    Upright,
+    Italic,
+    Bold,
+}
+
+/// One step of a stroked outline
+#[derive(Debug, Clone, Copy)]
+enum PathStep {
+    Move(f64, f64),
+    Line(f64, f64),
+    Quad(f64, f64, f64, f64),
+}
+
+/// A drawing primitive, positioned relative to the box origin (left end of
+/// the baseline, y pointing down)
+#[derive(Debug, Clone)]
+enum Item {
+    Text {
+        x: f64,
+        y: f64,
+        size: f64,
+        width: f64,
+        style: Style,
+        text: String,
+    },
+    Rule {
+        x: f64,
+        y: f64,
+        width: f64,
+        thickness: f64,
+    },
+    Path {
+        steps: Vec<PathStep>,
+        thickness: f64,
+    },
+}
+
+impl Item {
+    fn shift(&mut self, dx: f64, dy: f64) {
+        match self {
+            Item::Text { x, y, .. } | Item::Rule { x, y, .. } => {
+                *x += dx;
+                *y += dy;
+            }
+            Item::Path { steps, .. } => {
+                for step in steps {
+                    *step = match *step {
+                        PathStep::Move(x, y) => PathStep::Move(x + dx, y + dy),
+                        PathStep::Line(x, y) => PathStep::Line(x + dx, y + dy),
+                        PathStep::Quad(cx, cy, x, y) => {
+                            PathStep::Quad(cx + dx, cy + dy, x + dx, y + dy)
+                        }
+                    };
+                }
+            }
+        }
+    }
+}
+
+/// A laid-out box: extent around its baseline and the items it draws
+#[derive(Debug, Clone, Default)]
+struct Boxed {
+    width: f64,
+    ascent: f64,
+    descent: f64,
+    items: Vec<Item>,
+}
+
+impl Boxed {
+    fn empty() -> Self {
+        Self::default()
+    }
+
+    fn space(width: f64) -> Self {
+        Self {
+            width,
+            ..Self::default()
+        }
+    }
+
+    fn text(text: &str, size: f64, style: Style) -> Self {
+        let width = text.chars().map(char_width).sum::<f64>() * size;
+        Self {
+            width,
+            ascent: ASCENT * size,
+            descent: DESCENT * size,
+            items: vec![Item::Text {
+                x: 0.0,
+                y: 0.0,
+                size,
+                width,
+                style,
+                text: text.to_owned(),
+            }],
+        }
+    }
+
+    /// Place `other` with its origin at (`dx`, `dy`) relative to this box
+    fn place(&mut self, mut other: Boxed, dx: f64, dy: f64) {
+        for item in &mut other.items {
+            item.shift(dx, dy);
+        }
+        self.items.append(&mut other.items);
+        self.width = self.width.max(dx + other.width);
+        self.ascent = self.ascent.max(other.ascent - dy);
+        self.descent = self.descent.max(other.descent + dy);
+    }
+
+    /// Append `other` on the same baseline
+    fn push(&mut self, other: Boxed) {
+        let x = self.width;
+        self.place(other, x, 0.0);
+    }
+
+    fn row(parts: impl IntoIterator<Item = Boxed>) -> Self {
+        let mut result = Self::empty();
+        for part in parts {
+            result.push(part);
+        }
+        result
+    }
+
+    fn height(&self) -> f64 {
+        self.ascent + self.descent
+    }
+
+    fn to_svg(&self, context: &SvgContext, title: &str) -> String {
+        let padding = context.padding;
+        let width = self.width + 2.0 * padding;
+        let height = self.height() + 2.0 * padding;
+        let (dx, dy) = (padding, padding + self.ascent);
+
+        let mut svg = String::new();
+        let _ = write!(
+            svg,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" \
+             viewBox=\"0 0 {w} {h}\" role=\"img\" style=\"vertical-align: -{v}px\">",
+            w = num(width),
+            h = num(height),
+            v = num(self.descent + padding),
+        );
+        let _ = write!(svg, "<title>{}</title>", escape(title));
+        if let Some(background) = &context.background {
+            let _ = write!(
+                svg,
+                "<rect width=\"100%\" height=\"100%\" fill=\"{}\"/>",
+                escape(background)
+            );
+        }
+        let _ = write!(
+            svg,
+            "<g fill=\"{c}\" stroke=\"{c}\" font-family=\"{f}\">",
+            c = escape(&context.color),
+            f = escape(&context.font_family),
+        );
+
+        for item in &self.items {
+            match item {
+                Item::Text {
+                    x,
+                    y,
+                    size,
+                    width,
+                    style,
+                    text,
+                } => {
+                    let _ = write!(
+                        svg,
+                        "<text x=\"{}\" y=\"{}\" font-size=\"{}\" stroke=\"none\"",
+                        num(x + dx),
+                        num(y + dy),
+                        num(*size)
+                    );
+                    match style {
+                        Style::Italic => svg.push_str(" font-style=\"italic\""),
+                        Style::Bold => svg.push_str(" font-weight=\"bold\""),
+                        Style::Upright => {}
+                    }
+                    if text.chars().count() > 1 {
+                        let _ = write!(svg, " textLength=\"{}\"", num(*width));
+                    }
+                    let _ = write!(svg, ">{}</text>", escape(text));
+                }
+                Item::Rule {
+                    x,
+                    y,
+                    width,
+                    thickness,
+                } => {
+                    let _ = write!(
+                        svg,
+                        "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" stroke=\"none\"/>",
+                        num(x + dx),
+                        num(y + dy - thickness / 2.0),
+                        num(*width),
+                        num(*thickness)
+                    );
+                }
+                Item::Path { steps, thickness } => {
+                    let mut d = String::new();
+                    for step in steps {
+                        let _ = match *step {
+                            PathStep::Move(x, y) => {
+                                write!(d, "M{} {}", num(x + dx), num(y + dy))
+                            }
+                            PathStep::Line(x, y) => {
+                                write!(d, "L{} {}", num(x + dx), num(y + dy))
+                            }
+                            PathStep::Quad(cx, cy, x, y) => write!(
+                                d,
+                                "Q{} {} {} {}",
+                                num(cx + dx),
+                                num(cy + dy),
+                                num(x + dx),
+                                num(y + dy)
+                            ),
+                        };
+                    }
+                    let _ = write!(
+                        svg,
+                        "<path d=\"{}\" fill=\"none\" stroke-width=\"{}\" \
+                         stroke-linecap=\"round\" stroke-linejoin=\"round\"/>",
+                        d,
+                        num(*thickness)
+                    );
+                }
+            }
+        }
+
+        svg.push_str("</g></svg>");
+        svg
+    }
+}
+
+/// Approximate advance width of a glyph, in em
+fn char_width(c: char) -> f64 {
+    match c {
+        'i' | 'j' | 'l' | '!' | '|' | '.' | ',' | ':' | ';' | '\'' | ' ' => 0.28,
+        'f' | 't' | 'r' | '(' | ')' | '[' | ']' | '{' | '}' | '⌊' | '⌋' | '⌈' | '⌉' => 0.35,
+        'm' | 'w' => 0.75,
+        'M' | 'W' => 0.9,
+        '+' | '−' | '=' | '<' | '>' | '≤' | '≥' | '≠' | '≈' | '∼' | '∝' | '≡' | '±' | '×' | '→'
+        | '∞' => 0.78,
+        '·' | '⋅' => 0.28,
+        '⋯' | '⋮' | '⋱' => 0.9,
+        '∫' => 0.45,
+        '∑' | '∏' | '∅' => 0.8,
+        'A'..='Z' => 0.68,
+        _ if c.is_ascii_digit() => 0.5,
+        _ if c.is_ascii_lowercase() => 0.5,
+        _ => 0.6,
+    }
+}
+
+/// Number formatted for SVG attributes: two decimals, trailing zeros dropped
+fn num(value: f64) -> String {
+    let rounded = format!("{:.2}", value);
+    let trimmed = rounded.trim_end_matches('0').trim_end_matches('.');
+    if trimmed == "-0" {
+        "0".to_owned()
+    } else {
+        trimmed.to_owned()
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Delimiter shapes drawn as strokes so they stretch to their content
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Fence {
+    Paren,
+    Bracket,
+    Brace,
+    Bar,
+    DoubleBar,
+}
+
+/// Stretchy delimiter enclosing `ascent`/`descent` around the baseline
+fn fence(kind: Fence, left: bool, ascent: f64, descent: f64, size: f64) -> Boxed {
+    let top = -ascent.max(ASCENT * size);
+    let bottom = descent.max(DESCENT * size);
+    let mid = (top + bottom) / 2.0;
+    let thickness = RULE * size * 1.2;
+    let width = match kind {
+        Fence::Paren | Fence::Brace => 0.4 * size,
+        Fence::Bracket => 0.35 * size,
+        Fence::Bar => 0.25 * size,
+        Fence::DoubleBar => 0.4 * size,
+    };
+    // Shapes are drawn as left delimiters, then mirrored
+    let (inner, outer) = if left {
+        (0.8 * width, 0.25 * width)
+    } else {
+        (0.2 * width, 0.75 * width)
+    };
+    let paths = match kind {
+        Fence::Paren => vec![vec![
+            PathStep::Move(inner, top),
+            PathStep::Quad(
+                outer - 0.15 * width * if left { 1.0 } else { -1.0 },
+                mid,
+                inner,
+                bottom,
+            ),
+        ]],
+        Fence::Bracket => vec![vec![
+            PathStep::Move(inner, top),
+            PathStep::Line(outer, top),
+            PathStep::Line(outer, bottom),
+            PathStep::Line(inner, bottom),
+        ]],
+        Fence::Brace => {
+            let center = (inner + outer) / 2.0;
+            let tip = if left {
+                outer - 0.1 * width
+            } else {
+                outer + 0.1 * width
+            };
+            vec![vec![
+                PathStep::Move(inner, top),
+                PathStep::Quad(center, top, center, top + (mid - top) * 0.3),
+                PathStep::Line(center, mid - (mid - top) * 0.3),
+                PathStep::Quad(center, mid, tip, mid),
+                PathStep::Quad(center, mid, center, mid + (bottom - mid) * 0.3),
+                PathStep::Line(center, bottom - (bottom - mid) * 0.3),
+                PathStep::Quad(center, bottom, inner, bottom),
+            ]]
+        }
+        Fence::Bar => vec![vec![
+            PathStep::Move(width / 2.0, top),
+            PathStep::Line(width / 2.0, bottom),
+        ]],
+        Fence::DoubleBar => vec![
+            vec![
+                PathStep::Move(width / 3.0, top),
+                PathStep::Line(width / 3.0, bottom),
+            ],
+            vec![
+                PathStep::Move(2.0 * width / 3.0, top),
+                PathStep::Line(2.0 * width / 3.0, bottom),
+            ],
+        ],
+    };
+
+    Boxed {
+        width,
+        ascent: -top + thickness,
+        descent: bottom + thickness,
+        items: paths
+            .into_iter()
+            .map(|steps| Item::Path { steps, thickness })
+            .collect(),
+    }
+}
+
+/// `inner` between a pair of stretchy delimiters
+fn fenced(inner: Boxed, open: Option<Fence>, close: Option<Fence>, size: f64) -> Boxed {
+    let (ascent, descent) = (inner.ascent, inner.descent);
+    let mut result = Boxed::empty();
+    if let Some(kind) = open {
+        result.push(fence(kind, true, ascent, descent, size));
+    }
+    result.push(inner);
+    if let Some(kind) = close {
+        result.push(fence(kind, false, ascent, descent, size));
+    }
+    result
+}
+
+/// Stacks `over` and `under` centered on `nucleus`, as for limits of ∑
+fn stack(nucleus: Boxed, over: Option<Boxed>, under: Option<Boxed>, gap: f64) -> Boxed {
+    let width = [
+        Some(nucleus.width),
+        over.as_ref().map(|b| b.width),
+        under.as_ref().map(|b| b.width),
+    ]
+    .into_iter()
+    .flatten()
+    .fold(0.0, f64::max);
+
+    let mut result = Boxed::space(width);
+    let (nucleus_ascent, nucleus_descent) = (nucleus.ascent, nucleus.descent);
+    let nucleus_width = nucleus.width;
+    result.place(nucleus, (width - nucleus_width) / 2.0, 0.0);
+    if let Some(over) = over {
+        let dy = -(nucleus_ascent + gap + over.descent);
+        let dx = (width - over.width) / 2.0;
+        result.place(over, dx, dy);
+    }
+    if let Some(under) = under {
+        let dy = nucleus_descent + gap + under.ascent;
+        let dx = (width - under.width) / 2.0;
+        result.place(under, dx, dy);
+    }
+    result
+}
+
+/// Recursive layout of expressions into boxes
+struct Layouter<'a> {
+    context: &'a SvgContext,
+}
+
+impl Layouter<'_> {
+    fn script_size(&self, size: f64) -> f64 {
+        (size * SCRIPT_SCALE).max(self.context.font_size * 0.45)
+    }
+
+    fn check_terms(count: usize) -> Result<(), FormattingError> {
+        if count > MAX_TERMS_PER_OPERATION {
+            return Err(FormattingError::TooManyTerms {
+                count,
+                limit: MAX_TERMS_PER_OPERATION,
+            });
+        }
+        Ok(())
+    }
+
+    /// Binary operator or relation with medium spacing on both sides
+    fn operator(symbol: &str, size: f64) -> Boxed {
+        Boxed::row([
+            Boxed::space(0.22 * size),
+            Boxed::text(symbol, size, Style::Upright),
+            Boxed::space(0.22 * size),
+        ])
+    }
+
+    fn parens(inner: Boxed, size: f64) -> Boxed {
+        fenced(inner, Some(Fence::Paren), Some(Fence::Paren), size)
+    }
+
+    fn fraction(num: Boxed, den: Boxed, size: f64) -> Boxed {
+        let thickness = RULE * size;
+        let gap = 0.15 * size;
+        let width = num.width.max(den.width) + 0.2 * size;
+        let axis = -AXIS * size;
+
+        let mut result = Boxed::space(width);
+        let num_y = axis - thickness / 2.0 - gap - num.descent;
+        let den_y = axis + thickness / 2.0 + gap + den.ascent;
+        let (num_x, den_x) = ((width - num.width) / 2.0, (width - den.width) / 2.0);
+        result.place(num, num_x, num_y);
+        result.place(den, den_x, den_y);
+        result.items.push(Item::Rule {
+            x: 0.0,
+            y: axis,
+            width,
+            thickness,
+        });
+        Boxed::row([Boxed::space(0.08 * size), result, Boxed::space(0.08 * size)])
+    }
+
+    /// `base` with optional superscript and subscript
+    fn scripts(base: Boxed, sup: Option<Boxed>, sub: Option<Boxed>, size: f64) -> Boxed {
+        let (base_ascent, base_descent, base_width) = (base.ascent, base.descent, base.width);
+        let mut result = base;
+        let x = base_width + 0.05 * size;
+        if let Some(sup) = sup {
+            let shift = (base_ascent - 0.35 * size).max(0.0) + 0.4 * size;
+            result.place(sup, x, -shift);
+        }
+        if let Some(sub) = sub {
+            let shift = (base_descent - DESCENT * size).max(0.0) + 0.2 * size;
+            result.place(sub, x, shift);
+        }
+        result
+    }
+
+    /// Radical sign over `inner`, with an optional root index
+    fn radical(inner: Boxed, index: Option<Boxed>, size: f64) -> Boxed {
+        let thickness = RULE * size;
+        let gap = 0.12 * size;
+        let surd = 0.6 * size;
+        let top = -(inner.ascent + gap);
+        let bottom = inner.descent;
+        let height = bottom - top;
+        let lead = index
+            .as_ref()
+            .map_or(0.0, |i| (i.width - 0.35 * surd).max(0.0));
+
+        let mut result = Boxed::empty();
+        if let Some(index) = index {
+            let dy = bottom - 0.55 * height - index.descent;
+            result.place(index, 0.0, dy);
+        }
+        let end = lead + surd + inner.width + 0.1 * size;
+        result.items.push(Item::Path {
+            steps: vec![
+                PathStep::Move(lead, bottom - 0.45 * height),
+                PathStep::Line(lead + 0.2 * surd, bottom - 0.52 * height),
+                PathStep::Line(lead + 0.5 * surd, bottom),
+                PathStep::Line(lead + surd, top),
+                PathStep::Line(end, top),
+            ],
+            thickness,
+        });
+        result.ascent = result.ascent.max(-top + thickness);
+        result.descent = result.descent.max(bottom + thickness);
+        result.place(inner, lead + surd, 0.0);
+        result.width = end + 0.05 * size;
+        result
+    }
+
+    /// Rows of cells in a grid centered on the math axis
+    fn grid(&self, cells: Vec<Vec<Boxed>>, centered: bool, column_gap: f64, size: f64) -> Boxed {
+        let row_gap = 0.3 * size;
+        let cols = cells.iter().map(Vec::len).max().unwrap_or(0);
+        let mut widths = vec![0.0_f64; cols];
+        for row in &cells {
+            for (j, cell) in row.iter().enumerate() {
+                widths[j] = widths[j].max(cell.width);
+            }
+        }
+        let heights: Vec<(f64, f64)> = cells
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .fold((ASCENT * size, DESCENT * size), |(a, d), cell| {
+                        (a.max(cell.ascent), d.max(cell.descent))
+                    })
+            })
+            .collect();
+        let total: f64 = heights.iter().map(|(a, d)| a + d).sum::<f64>()
+            + row_gap * heights.len().saturating_sub(1) as f64;
+
+        let mut result = Boxed::empty();
+        let mut y = -AXIS * size - total / 2.0;
+        for (row, (ascent, descent)) in cells.into_iter().zip(heights) {
+            y += ascent;
+            let mut x = 0.0;
+            for (j, cell) in row.into_iter().enumerate() {
+                let offset = if centered {
+                    (widths[j] - cell.width) / 2.0
+                } else {
+                    0.0
+                };
+                result.place(cell, x + offset, y);
+                x += widths[j] + column_gap;
+            }
+            y += descent + row_gap;
+        }
+        result.width = widths.iter().sum::<f64>() + column_gap * cols.saturating_sub(1) as f64;
+        result.ascent = result.ascent.max(AXIS * size + total / 2.0);
+        result.descent = result.descent.max(total / 2.0 - AXIS * size);
+        result
+    }
+
+    /// Items separated by commas
+    fn list(
+        &self,
+        items: &[Expression],
+        size: f64,
+        depth: usize,
+    ) -> Result<Boxed, FormattingError> {
+        Self::check_terms(items.len())?;
+        let mut result = Boxed::empty();
+        for (i, item) in items.iter().enumerate() {
+            if i > 0 {
+                result.push(Boxed::text(",", size, Style::Upright));
+                result.push(Boxed::space(0.2 * size));
+            }
+            result.push(self.layout(item, size, depth + 1)?);
+        }
+        Ok(result)
+    }
+
+    fn layout(&self, expr: &Expression, size: f64, depth: usize) -> Result<Boxed, FormattingError> {
+        if depth > MAX_RECURSION_DEPTH {
+            return Err(FormattingError::RecursionLimitExceeded {
+                depth,
+                limit: MAX_RECURSION_DEPTH,
+            });
+        }
+
+        match expr {
+            Expression::Number(number) => Ok(self.number(number, size)),
+            Expression::Symbol(symbol) => Ok(self.symbol(symbol, size)),
+            Expression::Constant(constant) => Ok(self.constant(constant, size)),
+            Expression::Add(terms) => self.sum(terms, size, depth),
+            Expression::Mul(factors) => self.product(factors, size, depth),
+            Expression::Pow(base, exponent) => self.power(base, exponent, size, depth),
+            Expression::Function { name, args, .. } => self.function(name, args, size, depth),
+            Expression::Complex(data) => {
+                let mut result = self.layout(&data.real, size, depth + 1)?;
+                result.push(Self::operator("+", size));
+                result.push(self.layout(&data.imag, size, depth + 1)?);
+                result.push(Boxed::text("i", size, Style::Italic));
+                Ok(result)
+            }
+            Expression::Matrix(matrix) => self.matrix(matrix, size, depth),
+            Expression::Relation(data) => {
+                let symbol = match data.relation_type {
+                    RelationType::Equal => "=",
+                    RelationType::NotEqual => "≠",
+                    RelationType::Less => "<",
+                    RelationType::LessEqual => "≤",
+                    RelationType::Greater => ">",
+                    RelationType::GreaterEqual => "≥",
+                    RelationType::Approximate => "≈",
+                    RelationType::Similar => "∼",
+                    RelationType::Proportional => "∝",
+                    RelationType::Congruent => "≡",
+                };
+                Ok(Boxed::row([
+                    self.layout(&data.left, size, depth + 1)?,
+                    Self::operator(symbol, size),
+                    self.layout(&data.right, size, depth + 1)?,
+                ]))
+            }
+            Expression::Piecewise(data) => {
+                Self::check_terms(data.pieces.len())?;
+                let mut rows = Vec::with_capacity(data.pieces.len() + 1);
+                for (condition, value) in &data.pieces {
+                    rows.push(vec![
+                        self.layout(value, size, depth + 1)?,
+                        Boxed::row([
+                            Boxed::text("if", size, Style::Upright),
+                            Boxed::space(0.3 * size),
+                            self.layout(condition, size, depth + 1)?,
+                        ]),
+                    ]);
+                }
+                if let Some(default) = &data.default {
+                    rows.push(vec![
+                        self.layout(default, size, depth + 1)?,
+                        Boxed::text("otherwise", size, Style::Upright),
+                    ]);
+                }
+                let body = self.grid(rows, false, 1.0 * size, size);
+                Ok(fenced(
+                    Boxed::row([Boxed::space(0.1 * size), body]),
+                    Some(Fence::Brace),
+                    None,
+                    size,
+                ))
+            }
+            Expression::Set(elements) => {
+                if elements.is_empty() {
+                    return Ok(Boxed::text("∅", size, Style::Upright));
+                }
+                let inner = self.list(elements, size, depth)?;
+                Ok(fenced(inner, Some(Fence::Brace), Some(Fence::Brace), size))
+            }
+            Expression::Interval(data) => {
+                let inner = Boxed::row([
+                    self.layout(&data.start, size, depth + 1)?,
+                    Boxed::text(",", size, Style::Upright),
+                    Boxed::space(0.2 * size),
+                    self.layout(&data.end, size, depth + 1)?,
+                ]);
+                let side = |inclusive: bool| {
+                    if inclusive {
+                        Fence::Bracket
+                    } else {
+                        Fence::Paren
+                    }
+                };
+                Ok(fenced(
+                    inner,
+                    Some(side(data.start_inclusive)),
+                    Some(side(data.end_inclusive)),
+                    size,
+                ))
+            }
+            Expression::Calculus(data) => self.calculus(data, size, depth),
+            Expression::MethodCall(data) => {
+                let mut result = self.layout(&data.object, size, depth + 1)?;
+                result.push(Boxed::text(
+                    &format!(".{}", data.method_name),
+                    size,
+                    Style::Upright,
+                ));
+                result.push(Self::parens(self.list(&data.args, size, depth)?, size));
+                Ok(result)
+            }
+        }
+    }
+
+    fn number(&self, number: &Number, size: f64) -> Boxed {
+        if let Some(decimal) = self.context.number_format.decimal_for(number) {
+            let mantissa = decimal.mantissa.replace('-', "−");
+            return match decimal.exponent {
+                None => Boxed::text(&mantissa, size, Style::Upright),
+                Some(exponent) => Boxed::row([
+                    Boxed::text(&mantissa, size, Style::Upright),
+                    Self::operator("×", size),
+                    Self::scripts(
+                        Boxed::text("10", size, Style::Upright),
+                        Some(Boxed::text(
+                            &exponent.to_string().replace('-', "−"),
+                            self.script_size(size),
+                            Style::Upright,
+                        )),
+                        None,
+                        size,
+                    ),
+                ]),
+            };
+        }
+
+        match number {
+            Number::Rational(r) if !r.is_integer() => {
+                let fraction = Self::fraction(
+                    Boxed::text(&r.numer().abs().to_string(), size, Style::Upright),
+                    Boxed::text(&r.denom().to_string(), size, Style::Upright),
+                    size,
+                );
+                if r.is_negative() {
+                    Boxed::row([Boxed::text("−", size, Style::Upright), fraction])
+                } else {
+                    fraction
+                }
+            }
+            Number::Rational(r) => Boxed::text(
+                &r.numer().to_string().replace('-', "−"),
+                size,
+                Style::Upright,
+            ),
+            Number::Integer(n) => {
+                Boxed::text(&n.to_string().replace('-', "−"), size, Style::Upright)
+            }
+            Number::BigInteger(n) => {
+                Boxed::text(&n.to_string().replace('-', "−"), size, Style::Upright)
+            }
+            Number::Float(f) => Boxed::text(&f.to_string().replace('-', "−"), size, Style::Upright),
+            Number::Decimal(d) => {
+                Boxed::text(&d.to_string().replace('-', "−"), size, Style::Upright)
+            }
+        }
+    }
+
+    /// Symbol name with Greek letters and subscripts; matrices are bold and
+    /// operators carry a hat
+    fn symbol(&self, symbol: &Symbol, size: f64) -> Boxed {
+        let name = SymbolName::parse(symbol.name());
+        let (base, style) = match greek_letter(name.base) {
+            Some(letter) => (letter.unicode.to_string(), Style::Italic),
+            None => (name.base.to_owned(), Style::Italic),
+        };
+        let style = match symbol.symbol_type() {
+            SymbolType::Matrix => Style::Bold,
+            _ => style,
+        };
+        let mut nucleus = Boxed::text(&base, size, style);
+        if symbol.symbol_type() == SymbolType::Operator {
+            let (width, top) = (nucleus.width, -(ASCENT + 0.1) * size);
+            nucleus.items.push(Item::Path {
+                steps: vec![
+                    PathStep::Move(0.15 * width, top + 0.12 * size),
+                    PathStep::Line(0.5 * width, top),
+                    PathStep::Line(0.85 * width, top + 0.12 * size),
+                ],
+                thickness: RULE * size * 0.8,
+            });
+            nucleus.ascent = (ASCENT + 0.15) * size;
+        }
+        if name.subscripts.is_empty() {
+            return nucleus;
+        }
+        let subscript: Vec<String> = name
+            .subscripts
+            .iter()
+            .map(|part| match greek_letter(part) {
+                Some(letter) => letter.unicode.to_string(),
+                None => (*part).to_owned(),
+            })
+            .collect();
+        Self::scripts(
+            nucleus,
+            None,
+            Some(Boxed::text(
+                &subscript.join(","),
+                self.script_size(size),
+                Style::Upright,
+            )),
+            size,
+        )
+    }
+
+    fn constant(&self, constant: &MathConstant, size: f64) -> Boxed {
+        match constant {
+            MathConstant::Pi => Boxed::text("π", size, Style::Italic),
+            MathConstant::E => Boxed::text("e", size, Style::Italic),
+            MathConstant::I => Boxed::text("i", size, Style::Italic),
+            MathConstant::Infinity => Boxed::text("∞", size, Style::Upright),
+            MathConstant::NegativeInfinity => Boxed::text("−∞", size, Style::Upright),
+            MathConstant::Undefined => Boxed::text("undefined", size, Style::Upright),
+            MathConstant::GoldenRatio => Boxed::text("φ", size, Style::Italic),
+            MathConstant::EulerGamma => Boxed::text("γ", size, Style::Italic),
+            MathConstant::TribonacciConstant => Self::scripts(
+                Boxed::text("α", size, Style::Italic),
+                None,
+                Some(Boxed::text("3", self.script_size(size), Style::Upright)),
+                size,
+            ),
+        }
+    }
+
+    fn sum(&self, terms: &[Expression], size: f64, depth: usize) -> Result<Boxed, FormattingError> {
+        Self::check_terms(terms.len())?;
+        // Lead with a positive term when there is one: `x − 3`, not `−3 + x`
+        let mut ordered: Vec<&Expression> = terms.iter().collect();
+        if let Some(k) = ordered.iter().position(|term| negated(term).is_none()) {
+            let leading = ordered.remove(k);
+            ordered.insert(0, leading);
+        }
+
+        let mut result = Boxed::empty();
+        for (i, term) in ordered.into_iter().enumerate() {
+            match (i, negated(term)) {
+                (0, _) | (_, None) => {
+                    if i > 0 {
+                        result.push(Self::operator("+", size));
+                    }
+                    result.push(self.layout(term, size, depth + 1)?);
+                }
+                (_, Some(positive)) => {
+                    result.push(Self::operator("−", size));
+                    let part = self.layout(&positive, size, depth + 1)?;
+                    result.push(if matches!(positive, Expression::Add(_)) {
+                        Self::parens(part, size)
+                    } else {
+                        part
+                    });
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    fn product(
+        &self,
+        factors: &[Expression],
+        size: f64,
+        depth: usize,
+    ) -> Result<Boxed, FormattingError> {
+        Self::check_terms(factors.len())?;
+        if let Some((dividend, divisor)) = SmartDisplayFormatter::extract_division_parts(factors) {
+            return Ok(Self::fraction(
+                self.layout(dividend, size, depth + 1)?,
+                self.layout(divisor, size, depth + 1)?,
+                size,
+            ));
+        }
+
+        let (sign, numerator, denominator) = split_fraction(factors);
+        let mut result = Boxed::empty();
+        if sign {
+            result.push(Boxed::text("−", size, Style::Upright));
+        }
+        if denominator.is_empty() {
+            result.push(self.juxtapose(&numerator, size, depth)?);
+        } else {
+            result.push(Self::fraction(
+                self.juxtapose(&numerator, size, depth)?,
+                self.juxtapose(&denominator, size, depth)?,
+                size,
+            ));
+        }
+        Ok(result)
+    }
+
+    /// Factors written side by side, with `·` between adjacent numbers
+    fn juxtapose(
+        &self,
+        factors: &[Expression],
+        size: f64,
+        depth: usize,
+    ) -> Result<Boxed, FormattingError> {
+        if factors.is_empty() {
+            return Ok(Boxed::text("1", size, Style::Upright));
+        }
+        let mut result = Boxed::empty();
+        for (i, factor) in factors.iter().enumerate() {
+            if i > 0 {
+                if matches!(factor, Expression::Number(_)) {
+                    result.push(Self::operator("·", size));
+                } else {
+                    result.push(Boxed::space(0.1 * size));
+                }
+            }
+            let part = self.layout(factor, size, depth + 1)?;
+            result.push(if matches!(factor, Expression::Add(_)) {
+                Self::parens(part, size)
+            } else {
+                part
+            });
+        }
+        Ok(result)
+    }
+
+    fn power(
+        &self,
+        base: &Expression,
+        exponent: &Expression,
+        size: f64,
+        depth: usize,
+    ) -> Result<Boxed, FormattingError> {
+        if let Expression::Number(Number::Rational(r)) = exponent {
+            if r.numer().is_one() && r.denom() > &num_bigint::BigInt::one() {
+                let index = (r.denom() != &num_bigint::BigInt::from(2)).then(|| {
+                    Boxed::text(
+                        &r.denom().to_string(),
+                        self.script_size(self.script_size(size)),
+                        Style::Upright,
+                    )
+                });
+                return Ok(Self::radical(
+                    self.layout(base, size, depth + 1)?,
+                    index,
+                    size,
+                ));
+            }
+        }
+        if matches!(exponent, Expression::Number(n) if n.is_negative_one()) {
+            return Ok(Self::fraction(
+                Boxed::text("1", size, Style::Upright),
+                self.layout(base, size, depth + 1)?,
+                size,
+            ));
+        }
+
+        let base_box = self.layout(base, size, depth + 1)?;
+        let base_box = if needs_parens_as_base(base) {
+            Self::parens(base_box, size)
+        } else {
+            base_box
+        };
+        let exponent_box = self.layout(exponent, self.script_size(size), depth + 1)?;
+        Ok(Self::scripts(base_box, Some(exponent_box), None, size))
+    }
+
+    fn function(
+        &self,
+        name: &str,
+        args: &[Expression],
+        size: f64,
+        depth: usize,
+    ) -> Result<Boxed, FormattingError> {
+        Self::check_terms(args.len())?;
+        match (name, args) {
+            ("sqrt", [arg]) => {
+                return Ok(Self::radical(
+                    self.layout(arg, size, depth + 1)?,
+                    None,
+                    size,
+                ));
+            }
+            ("abs", [arg]) => {
+                let inner = self.layout(arg, size, depth + 1)?;
+                return Ok(fenced(inner, Some(Fence::Bar), Some(Fence::Bar), size));
+            }
+            ("factorial", [arg]) => {
+                let inner = self.layout(arg, size, depth + 1)?;
+                let inner = if matches!(arg, Expression::Number(_) | Expression::Symbol(_)) {
+                    inner
+                } else {
+                    Self::parens(inner, size)
+                };
+                return Ok(Boxed::row([inner, Boxed::text("!", size, Style::Upright)]));
+            }
+            ("floor" | "ceil", [arg]) => {
+                let (open, close) = if name == "floor" {
+                    ("⌊", "⌋")
+                } else {
+                    ("⌈", "⌉")
+                };
+                return Ok(Boxed::row([
+                    Boxed::text(open, size, Style::Upright),
+                    self.layout(arg, size, depth + 1)?,
+                    Boxed::text(close, size, Style::Upright),
+                ]));
+            }
+            _ => {}
+        }
+
+        let head = match name {
+            "gamma" => Boxed::text("Γ", size, Style::Upright),
+            "zeta" => Boxed::text("ζ", size, Style::Upright),
+            _ if name.chars().count() == 1 => Boxed::text(name, size, Style::Italic),
+            _ => Boxed::text(name, size, Style::Upright),
+        };
+        Ok(Boxed::row([
+            head,
+            Self::parens(self.list(args, size, depth)?, size),
+        ]))
+    }
+
+    fn matrix(&self, matrix: &Matrix, size: f64, depth: usize) -> Result<Boxed, FormattingError> {
+        let layout = self.context.matrix_format.layout(matrix);
+        Self::check_terms(layout.cell_count())?;
+
+        let mut rows = Vec::with_capacity(layout.rows.len());
+        for row in &layout.rows {
+            let mut cells = Vec::with_capacity(layout.cols.len());
+            for col in &layout.cols {
+                cells.push(match (row, col) {
+                    (Slot::Index(i), Slot::Index(j)) => {
+                        self.layout(&matrix.get_element(*i, *j), size, depth + 1)?
+                    }
+                    (Slot::Index(_), Slot::Ellipsis) => Boxed::text("⋯", size, Style::Upright),
+                    (Slot::Ellipsis, Slot::Index(_)) => Boxed::text("⋮", size, Style::Upright),
+                    (Slot::Ellipsis, Slot::Ellipsis) => Boxed::text("⋱", size, Style::Upright),
+                });
+            }
+            rows.push(cells);
+        }
+
+        let body = self.grid(rows, true, 0.8 * size, size);
+        let body = Boxed::row([Boxed::space(0.1 * size), body, Boxed::space(0.1 * size)]);
+        let fence = match self.context.matrix_format.delimiter {
+            MatrixDelimiter::Parentheses => Some(Fence::Paren),
+            MatrixDelimiter::Brackets => Some(Fence::Bracket),
+            MatrixDelimiter::Bars => Some(Fence::Bar),
+            MatrixDelimiter::DoubleBars => Some(Fence::DoubleBar),
+            MatrixDelimiter::Plain => None,
+        };
+        Ok(fenced(body, fence, fence, size))
+    }
+
+    fn calculus(
+        &self,
+        data: &CalculusData,
+        size: f64,
+        depth: usize,
+    ) -> Result<Boxed, FormattingError> {
+        let script = self.script_size(size);
+        let operand = |expression: &Expression| -> Result<Boxed, FormattingError> {
+            let part = self.layout(expression, size, depth + 1)?;
+            Ok(if matches!(expression, Expression::Add(_)) {
+                Self::parens(part, size)
+            } else {
+                part
+            })
+        };
+
+        match data {
+            CalculusData::Derivative {
+                expression,
+                variable,
+                order,
+            } => {
+                let d = Boxed::text("d", size, Style::Italic);
+                let (num, den) = if *order == 1 {
+                    (
+                        d,
+                        Boxed::row([
+                            Boxed::text("d", size, Style::Italic),
+                            self.symbol(variable, size),
+                        ]),
+                    )
+                } else {
+                    let exponent = || Some(Boxed::text(&order.to_string(), script, Style::Upright));
+                    (
+                        Self::scripts(d, exponent(), None, size),
+                        Boxed::row([
+                            Boxed::text("d", size, Style::Italic),
+                            Self::scripts(self.symbol(variable, size), exponent(), None, size),
+                        ]),
+                    )
+                };
+                Ok(Boxed::row([
+                    Self::fraction(num, den, size),
+                    operand(expression)?,
+                ]))
+            }
+            CalculusData::Integral {
+                integrand,
+                variable,
+                bounds,
+            } => {
+                let sign = Boxed::text("∫", size * 1.6, Style::Upright);
+                let sign = match bounds {
+                    Some((start, end)) => Self::scripts(
+                        sign,
+                        Some(self.layout(end, script, depth + 1)?),
+                        Some(self.layout(start, script, depth + 1)?),
+                        size,
+                    ),
+                    None => sign,
+                };
+                Ok(Boxed::row([
+                    sign,
+                    Boxed::space(0.1 * size),
+                    operand(integrand)?,
+                    Boxed::space(0.2 * size),
+                    Boxed::text("d", size, Style::Italic),
+                    self.symbol(variable, size),
+                ]))
+            }
+            CalculusData::Limit {
+                expression,
+                variable,
+                point,
+                direction,
+            } => {
+                let mut target = self.layout(point, script, depth + 1)?;
+                match direction {
+                    LimitDirection::Both => {}
+                    LimitDirection::Left | LimitDirection::Right => {
+                        let side = if matches!(direction, LimitDirection::Left) {
+                            "−"
+                        } else {
+                            "+"
+                        };
+                        target = Self::scripts(
+                            target,
+                            Some(Boxed::text(side, self.script_size(script), Style::Upright)),
+                            None,
+                            script,
+                        );
+                    }
+                }
+                let under = Boxed::row([
+                    self.symbol(variable, script),
+                    Self::operator("→", script),
+                    target,
+                ]);
+                Ok(Boxed::row([
+                    stack(
+                        Boxed::text("lim", size, Style::Upright),
+                        None,
+                        Some(under),
+                        0.1 * size,
+                    ),
+                    Boxed::space(0.2 * size),
+                    operand(expression)?,
+                ]))
+            }
+            CalculusData::Sum {
+                expression,
+                variable,
+                start,
+                end,
+            }
+            | CalculusData::Product {
+                expression,
+                variable,
+                start,
+                end,
+            } => {
+                let symbol = if matches!(data, CalculusData::Sum { .. }) {
+                    "∑"
+                } else {
+                    "∏"
+                };
+                let under = Boxed::row([
+                    self.symbol(variable, script),
+                    Self::operator("=", script),
+                    self.layout(start, script, depth + 1)?,
+                ]);
+                Ok(Boxed::row([
+                    stack(
+                        Boxed::text(symbol, size * 1.4, Style::Upright),
+                        Some(self.layout(end, script, depth + 1)?),
+                        Some(under),
+                        0.1 * size,
+                    ),
+                    Boxed::space(0.15 * size),
+                    operand(expression)?,
+                ]))
+            }
+        }
+    }
+}
+
+/// Positive form of a term shown after a minus sign, if the term is negative
+fn negated(term: &Expression) -> Option<Expression> {
+    if let Some(positive) = SmartDisplayFormatter::extract_negated_expression(term) {
+        return Some(positive.clone());
+    }
+    match term {
+        Expression::Number(number) if term.is_negative_number() => {
+            (-number.clone()).ok().map(Expression::Number)
+        }
+        Expression::Mul(factors) => match factors.first() {
+            Some(first @ Expression::Number(number)) if first.is_negative_number() => {
+                let mut rest = factors[1..].to_vec();
+                let coefficient = (-number.clone()).ok()?;
+                if !coefficient.is_one() {
+                    rest.insert(0, Expression::Number(coefficient));
+                }
+                Some(match rest.len() {
+                    1 => rest.pop().unwrap_or_else(|| Expression::integer(1)),
+                    _ => Expression::Mul(Arc::new(rest)),
+                })
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Split a product into a leading minus sign, numerator and denominator
+/// factors: negative powers and rational coefficients go below the bar
+fn split_fraction(factors: &[Expression]) -> (bool, Vec<Expression>, Vec<Expression>) {
+    let mut sign = false;
+    let mut numerator = Vec::new();
+    let mut denominator = Vec::new();
+    for (i, factor) in factors.iter().enumerate() {
+        match factor {
+            Expression::Number(number)
+                if i == 0 && number.is_negative_one() && factors.len() > 1 =>
+            {
+                sign = true;
+            }
+            Expression::Number(Number::Rational(r)) if i == 0 && !r.is_integer() => {
+                sign = r.is_negative();
+                if !r.numer().abs().is_one() {
+                    numerator.push(Expression::Number(Number::BigInteger(Box::new(
+                        r.numer().abs(),
+                    ))));
+                }
+                denominator.push(Expression::Number(Number::BigInteger(Box::new(
+                    r.denom().clone(),
+                ))));
+            }
+            Expression::Pow(base, exponent) => match exponent.as_ref() {
+                Expression::Number(Number::Integer(n)) if *n < 0 => {
+                    denominator.push(if *n == -1 {
+                        base.as_ref().clone()
+                    } else {
+                        Expression::Pow(base.clone(), Arc::new(Expression::integer(-n)))
+                    });
+                }
+                _ => numerator.push(factor.clone()),
+            },
+            _ => numerator.push(factor.clone()),
+        }
+    }
+    (sign, numerator, denominator)
+}
+
+fn needs_parens_as_base(base: &Expression) -> bool {
+    match base {
+        Expression::Add(_)
+        | Expression::Mul(_)
+        | Expression::Pow(..)
+        | Expression::Relation(_)
+        | Expression::Complex(_) => true,
+        Expression::Number(Number::Rational(r)) => !r.is_integer() || r.is_negative(),
+        Expression::Number(_) => base.is_negative_number(),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr;
+
+    fn svg(expr: &Expression) -> String {
+        expr.to_svg(&SvgContext::default()).unwrap()
+    }
+
+    #[test]
+    fn test_standalone_document() {
+        let out = svg(&expr!((x ^ 2) + 1));
+        assert!(out.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+        assert!(out.ends_with("</svg>"));
+        assert!(out.contains("<title>"));
+        assert!(out.contains(">x</text>"));
+        assert!(out.contains(">2</text>"));
+        assert!(out.contains(">+</text>"));
+    }
+
+    #[test]
+    fn test_fraction_draws_a_bar_and_stacks_vertically() {
+        let single = Layouter {
+            context: &SvgContext::default(),
+        }
+        .layout(&expr!(x), 20.0, 0)
+        .unwrap();
+        let fraction = Layouter {
+            context: &SvgContext::default(),
+        }
+        .layout(&expr!(x / y), 20.0, 0)
+        .unwrap();
+        assert!(fraction.height() > 1.8 * single.height());
+        assert!(fraction
+            .items
+            .iter()
+            .any(|item| matches!(item, Item::Rule { .. })));
+    }
+
+    #[test]
+    fn test_radical_and_subtraction() {
+        let root = Expression::function("sqrt", vec![expr!(x)]);
+        assert!(svg(&root).contains("<path"));
+
+        let difference = expr!(x - 3);
+        let out = svg(&difference);
+        assert!(out.contains(">−</text>"));
+        assert!(!out.contains(">+</text>"));
+    }
+
+    #[test]
+    fn test_escapes_and_options() {
+        let context = SvgContext {
+            background: Some("white".to_owned()),
+            color: "#333".to_owned(),
+            ..Default::default()
+        };
+        let out = Expression::relation(expr!(x), expr!(1), RelationType::Less)
+            .to_svg(&context)
+            .unwrap();
+        assert!(out.contains("&lt;"));
+        assert!(out.contains("fill=\"white\""));
+        assert!(out.contains("fill=\"#333\""));
+    }
+
+    #[test]
+    fn test_matrix_and_calculus() {
+        let m = Expression::matrix(vec![
+            vec![Expression::integer(1), Expression::integer(2)],
+            vec![Expression::integer(3), Expression::integer(4)],
+        ]);
+        let out = svg(&m);
+        for digit in ["1", "2", "3", "4"] {
+            assert!(out.contains(&format!(">{}</text>", digit)));
+        }
+
+        let integral = Expression::definite_integral(
+            expr!(x),
+            crate::symbol!(x),
+            Expression::integer(0),
+            Expression::integer(1),
+        );
+        assert!(svg(&integral).contains(">∫</text>"));
+    }
+}
@@ -1,7 +1,12 @@
-use super::{FormattingContext, FormattingError};
+use super::matrix_format::{MatrixLayout, Slot};
+use super::number_format::ExponentStyle;
+use super::{FormattingContext, FormattingError, MatrixFormat, NumberFormat};
+use crate::core::angle_unit::{AngleUnit, ANGLE_FUNCTIONS};
 use crate::core::expression::smart_display::SmartDisplayFormatter;
-use crate::core::expression::RelationType;
+use crate::core::expression::{CalculusData, LimitDirection, RelationType};
+use crate::core::symbol::names::SymbolName;
 use crate::core::{Expression, MathConstant, Number};
+use crate::matrices::Matrix;
 
 const MAX_RECURSION_DEPTH: usize = 1000;
 const MAX_TERMS_PER_OPERATION: usize = 10000;
@@ -15,12 +20,38 @@ pub struct SimpleContext {
     pub implicit_multiplication: bool,
     /// Maximum precision for floating point numbers
     pub float_precision: Option<usize>,
-    /// Whether to use Unicode symbols (× instead of *)
+    /// Whether to use Unicode symbols (× instead of *, α and x₁ for `alpha` and `x_1`)
     pub use_unicode: bool,
+    /// Float/rational display options (takes precedence over `float_precision`)
+    pub number_format: NumberFormat,
+    /// Matrix size limits
+    pub matrix_format: MatrixFormat,
+    /// In degrees, trigonometric arguments are annotated: `sin(30°)`
+    pub angle_unit: AngleUnit,
 }
 
 impl FormattingContext for SimpleContext {}
 
+impl SimpleContext {
+    /// Number format with the legacy `float_precision` applied as fixed decimals
+    /// when no explicit rounding is configured
+    fn effective_number_format(&self) -> NumberFormat {
+        let mut format = self.number_format;
+        if format.significant_figures.is_none() && format.decimal_places.is_none() {
+            format.decimal_places = self.float_precision;
+        }
+        format
+    }
+
+    fn exponent_style(&self) -> ExponentStyle {
+        if self.use_unicode {
+            ExponentStyle::Unicode
+        } else {
+            ExponentStyle::Ascii
+        }
+    }
+}
+
 /// Format the expression to Simple
 pub trait SimpleFormatter {
     /// Format an Expression as simple mathematical notation
@@ -34,6 +65,8 @@ pub trait SimpleFormatter {
     ///
     /// # Context Options
     /// * `float_precision` - Number of decimal places for floating point numbers
+    /// * `number_format` - Significant figures, notation and rational display for numbers
+    /// * `matrix_format` - Size limits before rows/columns of large matrices are elided
     /// * `use_unicode` - Whether to use Unicode symbols (× instead of *)
     /// * `parenthesize_negatives` - Whether to wrap negative numbers in parentheses
     /// * `implicit_multiplication` - Whether to use implicit multiplication (2x vs 2*x)
@@ -112,23 +145,31 @@ impl SimpleFormatter for Expression {
             });
         }
         match self {
-            Expression::Number(Number::Integer(n)) => Ok(n.to_string()),
-            Expression::Number(Number::BigInteger(n)) => Ok(n.to_string()),
-            Expression::Number(Number::Rational(r)) => {
-                if r.denom() == &num_bigint::BigInt::from(1) {
-                    Ok(r.numer().to_string())
-                } else {
-                    Ok(format!("{}/{}", r.numer(), r.denom()))
+            Expression::Number(num) => {
+                if let Some(decimal) = context.effective_number_format().decimal_for(num) {
+                    return Ok(decimal.render(context.exponent_style()));
                 }
+                Ok(match num {
+                    Number::Integer(n) => n.to_string(),
+                    Number::BigInteger(n) => n.to_string(),
+                    Number::Rational(r) => {
+                        if r.denom() == &num_bigint::BigInt::from(1) {
+                            r.numer().to_string()
+                        } else {
+                            format!("{}/{}", r.numer(), r.denom())
+                        }
+                    }
+                    Number::Float(f) => f.to_string(),
+                    Number::Decimal(d) => d.to_string(),
+                })
             }
-            Expression::Number(Number::Float(f)) => {
-                if let Some(precision) = context.float_precision {
-                    Ok(format!("{:.1$}", f, precision))
+            Expression::Symbol(s) => {
+                if context.use_unicode {
+                    Ok(SymbolName::parse(s.name()).to_unicode())
                 } else {
-                    Ok(f.to_string())
+                    Ok(s.name().to_owned())
                 }
             }
-            Expression::Symbol(s) => Ok(s.name().to_owned()),
             Expression::Add(terms) => {
                 if terms.len() > MAX_TERMS_PER_OPERATION {
                     return Err(FormattingError::TooManyTerms {
@@ -195,6 +236,13 @@ impl SimpleFormatter for Expression {
             }
             Expression::Pow(base, exp) => {
                 let base_simple = base.to_simple_with_depth(context, depth + 1)?;
+                // (x^2)^(1/2) is not x^(2/2) unless x ≥ 0, so nesting must stay visible
+                let base_simple = match base.as_ref() {
+                    Expression::Add(_) | Expression::Mul(_) | Expression::Pow(..) => {
+                        format!("({})", base_simple)
+                    }
+                    _ => base_simple,
+                };
                 let exp_simple = exp.to_simple_with_depth(context, depth + 1)?;
                 // Add parentheses around negative or complex exponents for clarity
                 if exp_simple.starts_with('-') || exp_simple.contains(' ') {
@@ -203,7 +251,7 @@ impl SimpleFormatter for Expression {
                     Ok(format!("{}^{}", base_simple, exp_simple))
                 }
             }
-            Expression::Function { name, args } => {
+            Expression::Function { name, args, .. } => {
                 if args.is_empty() {
                     Ok(name.to_string())
                 } else {
@@ -218,6 +266,16 @@ impl SimpleFormatter for Expression {
                     for arg in args.iter() {
                         arg_strs.push(arg.to_simple_with_depth(context, depth + 1)?);
                     }
+                    if context.angle_unit == AngleUnit::Degrees
+                        && ANGLE_FUNCTIONS.contains(&name.as_ref())
+                        && args.len() == 1
+                    {
+                        let angle = match &args[0] {
+                            Expression::Number(_) | Expression::Symbol(_) => arg_strs[0].clone(),
+                            _ => format!("({})", arg_strs[0]),
+                        };
+                        return Ok(format!("{}({}°)", name, angle));
+                    }
                     Ok(format!("{}({})", name, arg_strs.join(", ")))
                 }
             }
@@ -354,7 +412,207 @@ impl SimpleFormatter for Expression {
                 MathConstant::EulerGamma => "γ".to_owned(),
                 MathConstant::TribonacciConstant => "T".to_owned(),
             }),
+            Expression::Matrix(matrix) => format_matrix(matrix, context, depth),
+            Expression::Calculus(calculus_data) => format_calculus(calculus_data, context, depth),
             _ => Ok("unknown".to_owned()),
         }
     }
 }
+
+/// Format a matrix as aligned rows at the top level, or as a nested list
+/// (`[[1, 2], [3, 4]]`) inside a larger expression
+fn format_matrix(
+    matrix: &Matrix,
+    context: &SimpleContext,
+    depth: usize,
+) -> Result<String, FormattingError> {
+    let layout = context.matrix_format.layout(matrix);
+    let cells = matrix_cells(matrix, &layout, context, depth)?;
+
+    if depth > 0 || cells.is_empty() {
+        let rows: Vec<String> = cells
+            .iter()
+            .map(|row| format!("[{}]", row.join(", ")))
+            .collect();
+        return Ok(format!("[{}]", rows.join(", ")));
+    }
+
+    let widths: Vec<usize> = (0..layout.cols.len())
+        .map(|j| {
+            cells
+                .iter()
+                .map(|row| row[j].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    let lines: Vec<String> = cells
+        .iter()
+        .map(|row| {
+            let padded: Vec<String> = row
+                .iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{:>width$}", cell, width = width))
+                .collect();
+            format!("[{}]", padded.join("  "))
+        })
+        .collect();
+    Ok(lines.join("\n"))
+}
+
+/// Formatted entries of the displayed part of a matrix, with ellipsis markers
+fn matrix_cells(
+    matrix: &Matrix,
+    layout: &MatrixLayout,
+    context: &SimpleContext,
+    depth: usize,
+) -> Result<Vec<Vec<String>>, FormattingError> {
+    if layout.cell_count() > MAX_TERMS_PER_OPERATION {
+        return Err(FormattingError::TooManyTerms {
+            count: layout.cell_count(),
+            limit: MAX_TERMS_PER_OPERATION,
+        });
+    }
+
+    let (vertical, horizontal, diagonal) = if context.use_unicode {
+        ("⋮", "⋯", "⋱")
+    } else {
+        ("...", "...", "...")
+    };
+
+    let mut cells = Vec::with_capacity(layout.rows.len());
+    for row in &layout.rows {
+        let mut row_cells = Vec::with_capacity(layout.cols.len());
+        for col in &layout.cols {
+            row_cells.push(match (row, col) {
+                (Slot::Index(i), Slot::Index(j)) => matrix
+                    .get_element(*i, *j)
+                    .to_simple_with_depth(context, depth + 1)?,
+                (Slot::Index(_), Slot::Ellipsis) => horizontal.to_owned(),
+                (Slot::Ellipsis, Slot::Index(_)) => vertical.to_owned(),
+                (Slot::Ellipsis, Slot::Ellipsis) => diagonal.to_owned(),
+            });
+        }
+        cells.push(row_cells);
+    }
+    Ok(cells)
+}
+
+/// Format calculus nodes in plain-text textbook notation
+/// (`d/dx f`, `∫_0^1 f dx`, `lim_(x → 0) f`, `∑_(k=0)^n f`)
+fn format_calculus(
+    calculus_data: &CalculusData,
+    context: &SimpleContext,
+    depth: usize,
+) -> Result<String, FormattingError> {
+    let simple = |expr: &Expression| expr.to_simple_with_depth(context, depth + 1);
+    let operand = |expr: &Expression| -> Result<String, FormattingError> {
+        let text = simple(expr)?;
+        Ok(match expr {
+            Expression::Add(_) => format!("({})", text),
+            _ => text,
+        })
+    };
+    let variable = |symbol: &crate::core::Symbol| {
+        Expression::symbol(symbol.clone()).to_simple_with_depth(context, depth + 1)
+    };
+    let arrow = if context.use_unicode { "→" } else { "->" };
+
+    Ok(match calculus_data {
+        CalculusData::Derivative {
+            expression,
+            variable: var,
+            order,
+        } => {
+            if *order == 1 {
+                format!("d/d{} {}", variable(var)?, operand(expression)?)
+            } else {
+                format!(
+                    "d^{}/d{}^{} {}",
+                    order,
+                    variable(var)?,
+                    order,
+                    operand(expression)?
+                )
+            }
+        }
+        CalculusData::Integral {
+            integrand,
+            variable: var,
+            bounds,
+        } => {
+            let sign = if context.use_unicode { "∫" } else { "int" };
+            match bounds {
+                Some((start, end)) => format!(
+                    "{}_{}^{} {} d{}",
+                    sign,
+                    script(&simple(start)?),
+                    script(&simple(end)?),
+                    operand(integrand)?,
+                    variable(var)?
+                ),
+                None => format!("{} {} d{}", sign, operand(integrand)?, variable(var)?),
+            }
+        }
+        CalculusData::Limit {
+            expression,
+            variable: var,
+            point,
+            direction,
+        } => {
+            let side = match direction {
+                LimitDirection::Both => "",
+                LimitDirection::Left => "-",
+                LimitDirection::Right => "+",
+            };
+            format!(
+                "lim_({} {} {}{}) {}",
+                variable(var)?,
+                arrow,
+                simple(point)?,
+                side,
+                operand(expression)?
+            )
+        }
+        CalculusData::Sum {
+            expression,
+            variable: var,
+            start,
+            end,
+        }
+        | CalculusData::Product {
+            expression,
+            variable: var,
+            start,
+            end,
+        } => {
+            let sign = match (calculus_data, context.use_unicode) {
+                (CalculusData::Sum { .. }, true) => "∑",
+                (CalculusData::Sum { .. }, false) => "sum",
+                (_, true) => "∏",
+                (_, false) => "prod",
+            };
+            format!(
+                "{}_({}={})^{} {}",
+                sign,
+                variable(var)?,
+                simple(start)?,
+                script(&simple(end)?),
+                operand(expression)?
+            )
+        }
+    })
+}
+
+/// Parenthesise a sub- or superscript unless it is a single token
+fn script(text: &str) -> String {
+    if text
+        .chars()
+        .all(|c| c.is_alphanumeric() || c == '.' || c == '∞')
+    {
+        text.to_owned()
+    } else {
+        format!("({})", text)
+    }
+}
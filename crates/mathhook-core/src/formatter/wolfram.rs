@@ -1,8 +1,12 @@
-use super::{FormattingContext, FormattingError};
+use super::matrix_format::Slot;
+use super::number_format::ExponentStyle;
+use super::{FormattingContext, FormattingError, MatrixFormat, NumberFormat};
 use crate::core::expression::smart_display::SmartDisplayFormatter;
-use crate::core::expression::{CalculusData, RelationType};
+use crate::core::expression::{CalculusData, LimitDirection, RelationType};
+use crate::core::symbol::names::SymbolName;
 use crate::core::{Expression, Number};
 use crate::functions::intelligence::get_universal_registry;
+use crate::matrices::Matrix;
 
 const MAX_RECURSION_DEPTH: usize = 1000;
 const MAX_TERMS_PER_OPERATION: usize = 10000;
@@ -11,6 +15,10 @@ const MAX_TERMS_PER_OPERATION: usize = 10000;
 #[derive(Debug, Default, Clone)]
 pub struct WolframContext {
     pub needs_parentheses: bool,
+    /// Float/rational display options
+    pub number_format: NumberFormat,
+    /// Matrix size limits
+    pub matrix_format: MatrixFormat,
 }
 
 impl FormattingContext for WolframContext {}
@@ -27,6 +35,7 @@ pub trait WolframFormatter {
     ///
     /// # Context Options
     /// * `needs_parentheses` - Whether to wrap the entire expression in parentheses
+    /// * `number_format` - Significant figures, notation and rational display for numbers
     ///
     /// # Examples
     /// ```
@@ -95,18 +104,26 @@ impl WolframFormatter for Expression {
         }
 
         match self {
-            Expression::Number(Number::Integer(n)) => Ok(n.to_string()),
-            Expression::Number(Number::BigInteger(n)) => Ok(n.to_string()),
-            Expression::Number(Number::Rational(r)) => {
-                if r.denom() == &num_bigint::BigInt::from(1) {
-                    Ok(r.numer().to_string())
-                } else {
-                    // Use Power[denominator, -1] for proper Wolfram syntax
-                    Ok(format!("Times[{}, Power[{}, -1]]", r.numer(), r.denom()))
+            Expression::Number(num) => {
+                if let Some(decimal) = context.number_format.decimal_for(num) {
+                    return Ok(decimal.render(ExponentStyle::Wolfram));
                 }
+                Ok(match num {
+                    Number::Integer(n) => n.to_string(),
+                    Number::BigInteger(n) => n.to_string(),
+                    Number::Rational(r) => {
+                        if r.denom() == &num_bigint::BigInt::from(1) {
+                            r.numer().to_string()
+                        } else {
+                            // Use Power[denominator, -1] for proper Wolfram syntax
+                            format!("Times[{}, Power[{}, -1]]", r.numer(), r.denom())
+                        }
+                    }
+                    Number::Float(f) => f.to_string(),
+                    Number::Decimal(d) => d.to_string(),
+                })
             }
-            Expression::Number(Number::Float(f)) => Ok(f.to_string()),
-            Expression::Symbol(s) => Ok(s.name().to_owned()),
+            Expression::Symbol(s) => Ok(SymbolName::parse(s.name()).to_wolfram()),
             Expression::Add(terms) => {
                 if terms.len() > MAX_TERMS_PER_OPERATION {
                     return Err(FormattingError::TooManyTerms {
@@ -176,7 +193,7 @@ impl WolframFormatter for Expression {
                 base.to_wolfram_with_depth(context, depth + 1)?,
                 exp.to_wolfram_with_depth(context, depth + 1)?
             )),
-            Expression::Function { name, args } => {
+            Expression::Function { name, args, .. } => {
                 self.format_function_with_depth(name, args, context, depth + 1)
             }
             Expression::Complex(complex_data) => Ok(format!(
@@ -188,7 +205,7 @@ impl WolframFormatter for Expression {
                     .imag
                     .to_wolfram_with_depth(context, depth + 1)?
             )),
-            Expression::Matrix(_) => Ok("matrix".to_owned()),
+            Expression::Matrix(matrix) => format_matrix(matrix, context, depth),
             Expression::Constant(c) => Ok(format!("{:?}", c)),
             Expression::Relation(relation_data) => {
                 let left_wolfram = relation_data
@@ -259,13 +276,13 @@ impl WolframFormatter for Expression {
                             format!(
                                 "D[{}, {}]",
                                 expression.to_wolfram_with_depth(context, depth + 1)?,
-                                variable.name()
+                                SymbolName::parse(variable.name()).to_wolfram()
                             )
                         } else {
                             format!(
                                 "D[{}, {{{}, {}}}]",
                                 expression.to_wolfram_with_depth(context, depth + 1)?,
-                                variable.name(),
+                                SymbolName::parse(variable.name()).to_wolfram(),
                                 order
                             )
                         }
@@ -278,12 +295,12 @@ impl WolframFormatter for Expression {
                         None => format!(
                             "Integrate[{}, {}]",
                             integrand.to_wolfram_with_depth(context, depth + 1)?,
-                            variable.name()
+                            SymbolName::parse(variable.name()).to_wolfram()
                         ),
                         Some((start, end)) => format!(
                             "Integrate[{}, {{{}, {}, {}}}]",
                             integrand.to_wolfram_with_depth(context, depth + 1)?,
-                            variable.name(),
+                            SymbolName::parse(variable.name()).to_wolfram(),
                             start.to_wolfram_with_depth(context, depth + 1)?,
                             end.to_wolfram_with_depth(context, depth + 1)?
                         ),
@@ -292,14 +309,20 @@ impl WolframFormatter for Expression {
                         expression,
                         variable,
                         point,
-                        direction: _,
+                        direction,
                     } => {
-                        // Simplified Wolfram limit format for roundtrip consistency
+                        // Two-sided limits keep the short form for roundtrip consistency
+                        let side = match direction {
+                            LimitDirection::Both => String::new(),
+                            LimitDirection::Left => ", Direction -> \"FromBelow\"".to_owned(),
+                            LimitDirection::Right => ", Direction -> \"FromAbove\"".to_owned(),
+                        };
                         format!(
-                            "Limit[{}, {} -> {}]",
+                            "Limit[{}, {} -> {}{}]",
                             expression.to_wolfram_with_depth(context, depth + 1)?,
-                            variable.name(),
-                            point.to_wolfram_with_depth(context, depth + 1)?
+                            SymbolName::parse(variable.name()).to_wolfram(),
+                            point.to_wolfram_with_depth(context, depth + 1)?,
+                            side
                         )
                     }
                     CalculusData::Sum {
@@ -311,7 +334,7 @@ impl WolframFormatter for Expression {
                         format!(
                             "Sum[{}, {{{}, {}, {}}}]",
                             expression.to_wolfram_with_depth(context, depth + 1)?,
-                            variable.name(),
+                            SymbolName::parse(variable.name()).to_wolfram(),
                             start.to_wolfram_with_depth(context, depth + 1)?,
                             end.to_wolfram_with_depth(context, depth + 1)?
                         )
@@ -325,7 +348,7 @@ impl WolframFormatter for Expression {
                         format!(
                             "Product[{}, {{{}, {}, {}}}]",
                             expression.to_wolfram_with_depth(context, depth + 1)?,
-                            variable.name(),
+                            SymbolName::parse(variable.name()).to_wolfram(),
                             start.to_wolfram_with_depth(context, depth + 1)?,
                             end.to_wolfram_with_depth(context, depth + 1)?
                         )
@@ -390,3 +413,39 @@ impl WolframFormatter for Expression {
         }
     }
 }
+
+/// Format a matrix as a nested list (`{{1, 2}, {3, 4}}`)
+///
+/// Elided rows and columns use `\[VerticalEllipsis]`, `\[CenterEllipsis]` and
+/// `\[DescendingEllipsis]`.
+fn format_matrix(
+    matrix: &Matrix,
+    context: &WolframContext,
+    depth: usize,
+) -> Result<String, FormattingError> {
+    let layout = context.matrix_format.layout(matrix);
+    if layout.cell_count() > MAX_TERMS_PER_OPERATION {
+        return Err(FormattingError::TooManyTerms {
+            count: layout.cell_count(),
+            limit: MAX_TERMS_PER_OPERATION,
+        });
+    }
+
+    let mut row_strs = Vec::with_capacity(layout.rows.len());
+    for row in &layout.rows {
+        let mut col_strs = Vec::with_capacity(layout.cols.len());
+        for col in &layout.cols {
+            col_strs.push(match (row, col) {
+                (Slot::Index(i), Slot::Index(j)) => matrix
+                    .get_element(*i, *j)
+                    .to_wolfram_with_depth(context, depth + 1)?,
+                (Slot::Index(_), Slot::Ellipsis) => "\\[CenterEllipsis]".to_owned(),
+                (Slot::Ellipsis, Slot::Index(_)) => "\\[VerticalEllipsis]".to_owned(),
+                (Slot::Ellipsis, Slot::Ellipsis) => "\\[DescendingEllipsis]".to_owned(),
+            });
+        }
+        row_strs.push(format!("{{{}}}", col_strs.join(", ")));
+    }
+
+    Ok(format!("{{{}}}", row_strs.join(", ")))
+}
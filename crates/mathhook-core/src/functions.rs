@@ -14,6 +14,8 @@
 
 // Core function
 pub mod accuracy;
+pub mod catalog;
+pub mod custom;
 pub mod education;
 pub mod evaluation;
 pub mod extensibility;
@@ -29,6 +31,8 @@ pub mod polynomials;
 pub mod special;
 
 pub use accuracy::{AccuracyVerifier, VerifiedConstant, VerifiedRelationship, ACCURACY_VERIFIER};
+pub use catalog::{catalog, constant_catalog, ConstantEntry, FunctionEntry};
+pub use custom::{register_function, unregister_function, FunctionDefinition};
 pub use education::{FunctionEducator, StepGenerator};
 pub use evaluation::EvaluationResult;
 pub use extensibility::{
@@ -0,0 +1,359 @@
+//! Function and Constant Catalog
+//!
+//! Machine-readable description of every registered function and built-in
+//! constant: signature, domains, LaTeX and Wolfram names, and a one-line
+//! docstring. Binding layers and editors use it for autocomplete and signature
+//! help instead of hardcoding their own lists.
+
+use super::intelligence::{get_universal_registry, FunctionFamily};
+use super::properties::{Domain, FunctionProperties};
+use crate::core::{Expression, MathConstant};
+use crate::formatter::latex::{latex_function_name, LaTeXFormatter};
+use crate::formatter::simple::{SimpleContext, SimpleFormatter};
+use crate::formatter::wolfram::{WolframContext, WolframFormatter};
+use once_cell::sync::Lazy;
+
+/// A named function parameter and the values it accepts
+#[derive(Debug, Clone, PartialEq)]
+pub struct Parameter {
+    pub name: &'static str,
+    pub domain: Domain,
+}
+
+/// Number of arguments a function accepts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Arity {
+    pub min: usize,
+    pub max: usize,
+}
+
+impl Arity {
+    /// Whether a call with `count` arguments is well-formed
+    pub fn accepts(&self, count: usize) -> bool {
+        (self.min..=self.max).contains(&count)
+    }
+}
+
+/// Catalog entry for a registered function
+#[derive(Debug, Clone)]
+pub struct FunctionEntry {
+    pub name: String,
+    pub family: FunctionFamily,
+    /// Parameters in call order; trailing ones beyond `arity.min` are optional
+    pub parameters: Vec<Parameter>,
+    pub arity: Arity,
+    /// Domain recorded in the function's registered properties, if any
+    pub domain: Option<Domain>,
+    /// Name used by the LaTeX formatter, e.g. `\sin` or `\Gamma`
+    pub latex_name: String,
+    pub wolfram_name: Option<&'static str>,
+    pub doc: &'static str,
+}
+
+impl FunctionEntry {
+    /// Call signature in plain notation, e.g. `log(x, base?)`
+    pub fn signature(&self) -> String {
+        let parameters: Vec<String> = self
+            .parameters
+            .iter()
+            .enumerate()
+            .map(|(index, parameter)| {
+                if index < self.arity.min {
+                    parameter.name.to_owned()
+                } else {
+                    format!("{}?", parameter.name)
+                }
+            })
+            .collect();
+        format!("{}({})", self.name, parameters.join(", "))
+    }
+}
+
+/// Catalog entry for a built-in constant
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConstantEntry {
+    pub constant: MathConstant,
+    /// Name in plain notation, as accepted by the parser
+    pub name: String,
+    pub latex: String,
+    pub wolfram: String,
+    pub value: f64,
+    pub doc: &'static str,
+}
+
+/// Signature and documentation for a function name
+struct FunctionDoc {
+    name: &'static str,
+    parameters: &'static [Parameter],
+    /// Number of trailing parameters that may be omitted
+    optional: usize,
+    doc: &'static str,
+}
+
+const fn param(name: &'static str, domain: Domain) -> Parameter {
+    Parameter { name, domain }
+}
+
+const X: &[Parameter] = &[param("x", Domain::Real)];
+const Z: &[Parameter] = &[param("z", Domain::Complex)];
+const S: &[Parameter] = &[param("s", Domain::Complex)];
+const N: &[Parameter] = &[param("n", Domain::Integer)];
+const COMPLEX_PAIR: &[Parameter] = &[param("a", Domain::Complex), param("b", Domain::Complex)];
+const LOG_PARAMETERS: &[Parameter] = &[param("x", Domain::Real), param("base", Domain::Real)];
+const INDEX_AND_X: &[Parameter] = &[param("n", Domain::Integer), param("x", Domain::Real)];
+const ORDER_AND_Z: &[Parameter] = &[
+    param("n", Domain::NonNegativeInteger),
+    param("z", Domain::Complex),
+];
+const INTEGER_AND_MODULUS: &[Parameter] = &[
+    param("a", Domain::Integer),
+    param("m", Domain::PositiveInteger),
+];
+const INTEGER_PAIR: &[Parameter] = &[param("a", Domain::Integer), param("b", Domain::Integer)];
+const ORDER_AND_X: &[Parameter] = &[
+    param("n", Domain::NonNegativeInteger),
+    param("x", Domain::Real),
+];
+
+const fn doc(
+    name: &'static str,
+    parameters: &'static [Parameter],
+    doc: &'static str,
+) -> FunctionDoc {
+    FunctionDoc {
+        name,
+        parameters,
+        optional: 0,
+        doc,
+    }
+}
+
+static FUNCTION_DOCS: &[FunctionDoc] = &[
+    doc("sin", Z, "Sine"),
+    doc("cos", Z, "Cosine"),
+    doc("tan", Z, "Tangent"),
+    doc("cot", Z, "Cotangent"),
+    doc("sec", Z, "Secant"),
+    doc("csc", Z, "Cosecant"),
+    doc("arcsin", X, "Inverse sine"),
+    doc("arccos", X, "Inverse cosine"),
+    doc("arctan", X, "Inverse tangent"),
+    doc("arccot", X, "Inverse cotangent"),
+    doc("arcsec", X, "Inverse secant"),
+    doc("arccsc", X, "Inverse cosecant"),
+    doc("sinh", Z, "Hyperbolic sine"),
+    doc("cosh", Z, "Hyperbolic cosine"),
+    doc("tanh", Z, "Hyperbolic tangent"),
+    doc("coth", Z, "Hyperbolic cotangent"),
+    doc("sech", Z, "Hyperbolic secant"),
+    doc("csch", Z, "Hyperbolic cosecant"),
+    doc("asinh", X, "Inverse hyperbolic sine"),
+    doc("acosh", X, "Inverse hyperbolic cosine"),
+    doc("atanh", X, "Inverse hyperbolic tangent"),
+    doc("exp", Z, "Exponential function e^z"),
+    doc("ln", X, "Natural logarithm"),
+    FunctionDoc {
+        name: "log",
+        parameters: LOG_PARAMETERS,
+        optional: 1,
+        doc: "Logarithm of x, base 10 unless a base is given",
+    },
+    doc("sqrt", Z, "Principal square root"),
+    doc("abs", Z, "Absolute value"),
+    doc("gamma", Z, "Gamma function Γ(z), extending (z - 1)!"),
+    doc(
+        "beta",
+        COMPLEX_PAIR,
+        "Beta function B(a, b) = Γ(a)Γ(b)/Γ(a + b)",
+    ),
+    doc("digamma", Z, "Digamma function ψ(z) = Γ'(z)/Γ(z)"),
+    doc(
+        "polygamma",
+        ORDER_AND_Z,
+        "Polygamma function ψ⁽ⁿ⁾(z), the n-th derivative of digamma",
+    ),
+    doc("zeta", S, "Riemann zeta function ζ(s)"),
+    doc(
+        "bessel_j",
+        INDEX_AND_X,
+        "Bessel function of the first kind J_n(x)",
+    ),
+    doc(
+        "bessel_y",
+        INDEX_AND_X,
+        "Bessel function of the second kind Y_n(x)",
+    ),
+    doc("legendre_p", ORDER_AND_X, "Legendre polynomial P_n(x)"),
+    doc("hermite", ORDER_AND_X, "Hermite polynomial H_n(x)"),
+    doc("laguerre", ORDER_AND_X, "Laguerre polynomial L_n(x)"),
+    doc(
+        "chebyshev_first",
+        ORDER_AND_X,
+        "Chebyshev polynomial of the first kind T_n(x)",
+    ),
+    doc(
+        "chebyshev_second",
+        ORDER_AND_X,
+        "Chebyshev polynomial of the second kind U_n(x)",
+    ),
+    doc("gcd", INTEGER_PAIR, "Greatest common divisor"),
+    doc("lcm", INTEGER_PAIR, "Least common multiple"),
+    doc("mod", INTEGER_AND_MODULUS, "Remainder of a modulo m"),
+    doc("is_prime", N, "Whether n is a prime number"),
+];
+
+/// Constants listed in the catalog, with their docstrings
+const CONSTANT_DOCS: &[(MathConstant, &str)] = &[
+    (
+        MathConstant::Pi,
+        "Ratio of a circle's circumference to its diameter",
+    ),
+    (MathConstant::E, "Base of the natural logarithm"),
+    (MathConstant::I, "Imaginary unit, i² = -1"),
+    (MathConstant::Infinity, "Positive infinity"),
+    (MathConstant::GoldenRatio, "Golden ratio (1 + √5)/2"),
+    (MathConstant::EulerGamma, "Euler–Mascheroni constant γ"),
+    (
+        MathConstant::TribonacciConstant,
+        "Real root of x³ = x² + x + 1",
+    ),
+];
+
+static FUNCTION_CATALOG: Lazy<Vec<FunctionEntry>> = Lazy::new(build_function_catalog);
+static CONSTANT_CATALOG: Lazy<Vec<ConstantEntry>> = Lazy::new(build_constant_catalog);
+
+/// Every function in the universal registry, sorted by name
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::functions::catalog;
+///
+/// let log = catalog::catalog().iter().find(|f| f.name == "log").unwrap();
+/// assert_eq!(log.signature(), "log(x, base?)");
+/// assert!(log.arity.accepts(1) && log.arity.accepts(2));
+/// assert_eq!(log.latex_name, "\\log");
+/// assert_eq!(log.wolfram_name, Some("Log"));
+/// ```
+pub fn catalog() -> &'static [FunctionEntry] {
+    &FUNCTION_CATALOG
+}
+
+/// Built-in constants in the order they are usually presented
+pub fn constant_catalog() -> &'static [ConstantEntry] {
+    &CONSTANT_CATALOG
+}
+
+/// Functions whose name starts with `prefix`, for autocomplete
+pub fn complete(prefix: &str) -> impl Iterator<Item = &'static FunctionEntry> + '_ {
+    catalog()
+        .iter()
+        .filter(move |entry| entry.name.starts_with(prefix))
+}
+
+fn build_function_catalog() -> Vec<FunctionEntry> {
+    let registry = get_universal_registry();
+    let mut names = registry.list_all_functions();
+    names.sort();
+
+    names
+        .into_iter()
+        .filter_map(|name| {
+            let properties = registry.get_properties(&name)?;
+            let doc = FUNCTION_DOCS.iter().find(|doc| doc.name == name);
+            let parameters = doc.map_or_else(|| X.to_vec(), |doc| doc.parameters.to_vec());
+            let optional = doc.map_or(0, |doc| doc.optional);
+            Some(FunctionEntry {
+                arity: Arity {
+                    min: parameters.len() - optional,
+                    max: parameters.len(),
+                },
+                parameters,
+                family: properties.family(),
+                domain: registered_domain(properties),
+                latex_name: latex_function_name(&name),
+                wolfram_name: properties.wolfram_name(),
+                doc: doc.map_or("", |doc| doc.doc),
+                name,
+            })
+        })
+        .collect()
+}
+
+fn registered_domain(properties: &FunctionProperties) -> Option<Domain> {
+    match properties {
+        FunctionProperties::Elementary(props) => Some(props.domain_range.domain.clone()),
+        FunctionProperties::UserDefined(props) => props.domain.clone(),
+        FunctionProperties::Special(_) | FunctionProperties::Polynomial(_) => None,
+    }
+}
+
+fn build_constant_catalog() -> Vec<ConstantEntry> {
+    CONSTANT_DOCS
+        .iter()
+        .map(|&(constant, doc)| {
+            let expression = Expression::constant(constant);
+            ConstantEntry {
+                constant,
+                name: expression
+                    .to_simple(&SimpleContext::default())
+                    .unwrap_or_default(),
+                latex: expression.to_latex(None).unwrap_or_default(),
+                wolfram: expression
+                    .to_wolfram(&WolframContext::default())
+                    .unwrap_or_default(),
+                value: constant.to_f64(),
+                doc,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_registered_function_is_documented() {
+        for entry in catalog() {
+            assert!(!entry.doc.is_empty(), "{} has no catalog doc", entry.name);
+        }
+        assert_eq!(catalog().len(), get_universal_registry().registry_size());
+    }
+
+    #[test]
+    fn test_entries_match_formatters() {
+        let gamma = catalog().iter().find(|f| f.name == "gamma").unwrap();
+        assert_eq!(gamma.latex_name, "\\Gamma");
+        assert_eq!(gamma.family, FunctionFamily::Special);
+        assert_eq!(gamma.signature(), "gamma(z)");
+
+        let legendre = catalog().iter().find(|f| f.name == "legendre_p").unwrap();
+        assert_eq!(legendre.arity, Arity { min: 2, max: 2 });
+        assert_eq!(legendre.parameters[0].domain, Domain::NonNegativeInteger);
+        assert!(legendre.domain.is_none());
+
+        let gcd = catalog().iter().find(|f| f.name == "gcd").unwrap();
+        assert_eq!(gcd.domain, Some(Domain::Integer));
+    }
+
+    #[test]
+    fn test_completion_by_prefix() {
+        let names: Vec<&str> = complete("arc").map(|f| f.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec!["arccos", "arccot", "arccsc", "arcsec", "arcsin", "arctan"]
+        );
+        assert_eq!(complete("").count(), catalog().len());
+    }
+
+    #[test]
+    fn test_constant_catalog() {
+        let pi = &constant_catalog()[0];
+        assert_eq!(pi.constant, MathConstant::Pi);
+        assert_eq!(pi.latex, "\\pi");
+        assert_eq!(pi.wolfram, "Pi");
+        assert!((pi.value - std::f64::consts::PI).abs() < 1e-12);
+        assert!(constant_catalog().iter().all(|c| !c.name.is_empty()));
+    }
+}
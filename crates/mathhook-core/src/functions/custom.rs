@@ -0,0 +1,275 @@
+//! Symbolic functions defined outside the core
+//!
+//! A [`FunctionDefinition`] tells differentiation, evaluation and the LaTeX
+//! formatter how to treat `Expression::Function` nodes with a given name.
+//! Definitions are normally produced by the `#[math_function]` attribute, which
+//! submits them at link time, but they can also be registered at runtime with
+//! [`register_function`]. Built-in functions always take precedence.
+
+use crate::calculus::derivatives::Derivative;
+use crate::core::{Expression, Symbol};
+use crate::simplify::Simplify;
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+/// Numeric implementation, called with one value per argument
+pub type EvalFn = fn(&[f64]) -> Option<f64>;
+
+/// Partial derivative with respect to the argument at the given index
+pub type PartialDerivativeFn = fn(&[Expression], usize) -> Option<Expression>;
+
+/// LaTeX rendering, called with the already formatted arguments
+pub type LatexFn = fn(&[String]) -> String;
+
+/// Behaviour of a user-defined symbolic function
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::functions::custom::{register_function, FunctionDefinition};
+/// use mathhook_core::{expr, symbol, Derivative, Expression};
+///
+/// fn eval(args: &[f64]) -> Option<f64> {
+///     Some(args[0].powi(3))
+/// }
+///
+/// fn partial(args: &[Expression], _index: usize) -> Option<Expression> {
+///     Some(Expression::mul(vec![
+///         Expression::integer(3),
+///         Expression::pow(args[0].clone(), Expression::integer(2)),
+///     ]))
+/// }
+///
+/// register_function(
+///     FunctionDefinition::new("cube", 1)
+///         .with_eval(eval)
+///         .with_derivative(partial),
+/// );
+///
+/// let x = symbol!(x);
+/// let cube = Expression::function("cube", vec![expr!(x)]);
+/// assert_eq!(cube.derivative(x), expr!(3 * (x ^ 2)));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct FunctionDefinition {
+    name: &'static str,
+    arity: usize,
+    eval: Option<EvalFn>,
+    derivative: Option<PartialDerivativeFn>,
+    latex: Option<LatexFn>,
+}
+
+impl FunctionDefinition {
+    /// Definition with no behaviour beyond its name and number of arguments
+    pub const fn new(name: &'static str, arity: usize) -> Self {
+        Self {
+            name,
+            arity,
+            eval: None,
+            derivative: None,
+            latex: None,
+        }
+    }
+
+    /// Set the numeric implementation
+    pub const fn with_eval(mut self, eval: EvalFn) -> Self {
+        self.eval = Some(eval);
+        self
+    }
+
+    /// Set the partial derivatives used by the chain rule
+    pub const fn with_derivative(mut self, derivative: PartialDerivativeFn) -> Self {
+        self.derivative = Some(derivative);
+        self
+    }
+
+    /// Set the LaTeX rendering
+    pub const fn with_latex(mut self, latex: LatexFn) -> Self {
+        self.latex = Some(latex);
+        self
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn arity(&self) -> usize {
+        self.arity
+    }
+
+    /// Evaluate numerically when every argument is a number
+    pub fn evaluate(&self, args: &[Expression]) -> Option<Expression> {
+        let eval = self.eval?;
+        if args.len() != self.arity {
+            return None;
+        }
+
+        let values = args
+            .iter()
+            .map(|arg| match arg {
+                Expression::Number(number) => number.to_float().ok(),
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        eval(&values)
+            .filter(|value| value.is_finite())
+            .map(Expression::float)
+    }
+
+    /// Total derivative by the multivariable chain rule
+    ///
+    /// `d/dx f(u₁, …, uₙ) = Σ ∂f/∂uᵢ · duᵢ/dx`. Returns `None` when a needed
+    /// partial derivative is unknown.
+    pub fn differentiate(&self, args: &[Expression], variable: Symbol) -> Option<Expression> {
+        let derivative = self.derivative?;
+        if args.len() != self.arity {
+            return None;
+        }
+
+        let mut terms = Vec::with_capacity(args.len());
+        for (index, arg) in args.iter().enumerate() {
+            let arg_derivative = arg.derivative(variable.clone());
+            if arg_derivative.is_zero() {
+                continue;
+            }
+            terms.push(Expression::mul(vec![
+                derivative(args, index)?,
+                arg_derivative,
+            ]));
+        }
+
+        Some(Expression::add(terms).simplify())
+    }
+
+    /// Render with the custom LaTeX form, if one was given
+    pub fn latex(&self, args: &[String]) -> Option<String> {
+        self.latex.map(|latex| latex(args))
+    }
+}
+
+inventory::collect!(FunctionDefinition);
+
+/// Definitions by name, seeded with every definition submitted at link time
+static CUSTOM_FUNCTIONS: Lazy<RwLock<HashMap<&'static str, FunctionDefinition>>> =
+    Lazy::new(|| {
+        RwLock::new(
+            inventory::iter::<FunctionDefinition>
+                .into_iter()
+                .map(|definition| (definition.name, *definition))
+                .collect(),
+        )
+    });
+
+/// Register a definition, returning the one it replaces
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::functions::custom::{
+///     custom_function, register_function, unregister_function, FunctionDefinition,
+/// };
+///
+/// register_function(FunctionDefinition::new("ramp", 1));
+/// assert_eq!(custom_function("ramp").map(|f| f.arity()), Some(1));
+///
+/// unregister_function("ramp");
+/// assert!(custom_function("ramp").is_none());
+/// ```
+pub fn register_function(definition: FunctionDefinition) -> Option<FunctionDefinition> {
+    CUSTOM_FUNCTIONS
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .insert(definition.name, definition)
+}
+
+/// Remove a definition by name, returning it if present
+pub fn unregister_function(name: &str) -> Option<FunctionDefinition> {
+    CUSTOM_FUNCTIONS
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .remove(name)
+}
+
+/// Look up a definition by name
+pub fn custom_function(name: &str) -> Option<FunctionDefinition> {
+    CUSTOM_FUNCTIONS
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .get(name)
+        .copied()
+}
+
+/// Names of all registered custom functions, sorted
+pub fn custom_function_names() -> Vec<&'static str> {
+    let mut names: Vec<_> = CUSTOM_FUNCTIONS
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .keys()
+        .copied()
+        .collect();
+    names.sort_unstable();
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{expr, symbol};
+
+    fn hypot_eval(args: &[f64]) -> Option<f64> {
+        Some(args[0].hypot(args[1]))
+    }
+
+    fn hypot_partial(args: &[Expression], index: usize) -> Option<Expression> {
+        let hypot = Expression::function("test_hypot", args.to_vec());
+        Some(Expression::div(args[index].clone(), hypot))
+    }
+
+    fn hypot_latex(args: &[String]) -> String {
+        format!("\\sqrt{{{}^2 + {}^2}}", args[0], args[1])
+    }
+
+    const HYPOT: FunctionDefinition = FunctionDefinition::new("test_hypot", 2)
+        .with_eval(hypot_eval)
+        .with_derivative(hypot_partial)
+        .with_latex(hypot_latex);
+
+    #[test]
+    fn test_evaluate_requires_numeric_arguments() {
+        assert_eq!(
+            HYPOT.evaluate(&[Expression::integer(3), Expression::integer(4)]),
+            Some(Expression::float(5.0))
+        );
+        assert_eq!(HYPOT.evaluate(&[expr!(x), Expression::integer(4)]), None);
+        assert_eq!(HYPOT.evaluate(&[Expression::integer(3)]), None);
+    }
+
+    #[test]
+    fn test_differentiate_sums_partials() {
+        let x = symbol!(x);
+        let hypot = Expression::function("test_hypot", vec![expr!(x), expr!(y)]);
+
+        assert_eq!(
+            HYPOT.differentiate(&[expr!(x), expr!(y)], x.clone()),
+            Some(Expression::div(expr!(x), hypot).simplify())
+        );
+        assert_eq!(
+            HYPOT.differentiate(&[expr!(y), expr!(z)], x),
+            Some(Expression::integer(0))
+        );
+    }
+
+    #[test]
+    fn test_registry_round_trip() {
+        assert!(register_function(HYPOT).is_none());
+        assert!(custom_function_names().contains(&"test_hypot"));
+        assert_eq!(
+            HYPOT.latex(&["a".to_owned(), "b".to_owned()]),
+            Some("\\sqrt{a^2 + b^2}".to_owned())
+        );
+        assert!(unregister_function("test_hypot").is_some());
+        assert!(custom_function("test_hypot").is_none());
+    }
+}
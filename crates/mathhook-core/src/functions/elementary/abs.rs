@@ -235,7 +235,7 @@ pub fn simplify_abs(arg: &Expression) -> Expression {
 
         Expression::Mul(terms) => simplify_abs_product(terms),
 
-        Expression::Function { name, args } if name.as_ref() == "abs" && args.len() == 1 => {
+        Expression::Function { name, args, .. } if name.as_ref() == "abs" && args.len() == 1 => {
             Expression::function("abs", vec![args[0].clone()])
         }
 
@@ -256,6 +256,7 @@ fn evaluate_abs_number(n: &Number) -> Expression {
             r.numer().abs(),
             r.denom().clone(),
         ))),
+        Number::Decimal(d) => Expression::Number(Number::decimal(d.abs())),
     }
 }
 
@@ -42,6 +42,7 @@ fn evaluate_abs_number(n: &Number) -> Expression {
         Number::Rational(r) => Expression::Number(Number::rational(
             num_rational::BigRational::new(r.numer().abs(), r.denom().clone()),
         )),
+        Number::Decimal(d) => Expression::Number(Number::decimal(d.abs())),
     }
 }
 
@@ -66,6 +66,7 @@ fn evaluate_sign_number(n: &Number) -> Expression {
                 Expression::integer(0)
             }
         }
+        Number::Decimal(d) => Expression::integer(d.digits().signum().to_i64().unwrap_or(0)),
     }
 }
 
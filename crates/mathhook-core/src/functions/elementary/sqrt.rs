@@ -230,7 +230,7 @@ pub fn simplify_sqrt(arg: &Expression) -> Expression {
 
         Expression::Mul(terms) => simplify_sqrt_product(terms),
 
-        Expression::Function { name, args } if name.as_ref() == "sqrt" && args.len() == 1 => {
+        Expression::Function { name, args, .. } if name.as_ref() == "sqrt" && args.len() == 1 => {
             Expression::function("sqrt", vec![args[0].clone()])
         }
 
@@ -305,6 +305,7 @@ fn evaluate_sqrt_number(n: &Number) -> Expression {
 
             Expression::function("sqrt", vec![Expression::Number(n.clone())])
         }
+        Number::Decimal(d) => evaluate_sqrt_number(&Number::rational(d.to_rational())),
     }
 }
 
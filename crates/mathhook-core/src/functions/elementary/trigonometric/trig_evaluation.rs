@@ -75,14 +75,11 @@ pub fn tan_evaluator(args: &[Expression]) -> Expression {
 }
 
 fn try_exact_sin(arg: &Expression) -> Option<Expression> {
-    if arg.is_zero() {
+    if arg.is_zero_fast() {
         return Some(Expression::integer(0));
     }
 
-    if matches!(
-        arg,
-        Expression::Constant(crate::core::constants::MathConstant::Pi)
-    ) {
+    if pi_multiple(arg).is_some() {
         return Some(Expression::integer(0));
     }
 
@@ -112,15 +109,12 @@ fn try_exact_sin(arg: &Expression) -> Option<Expression> {
 }
 
 fn try_exact_cos(arg: &Expression) -> Option<Expression> {
-    if arg.is_zero() {
+    if arg.is_zero_fast() {
         return Some(Expression::integer(1));
     }
 
-    if matches!(
-        arg,
-        Expression::Constant(crate::core::constants::MathConstant::Pi)
-    ) {
-        return Some(Expression::integer(-1));
+    if let Some(k) = pi_multiple(arg) {
+        return Some(Expression::integer(if k % 2 == 0 { 1 } else { -1 }));
     }
 
     if is_pi_over_2(arg) {
@@ -149,14 +143,11 @@ fn try_exact_cos(arg: &Expression) -> Option<Expression> {
 }
 
 fn try_exact_tan(arg: &Expression) -> Option<Expression> {
-    if arg.is_zero() {
+    if arg.is_zero_fast() {
         return Some(Expression::integer(0));
     }
 
-    if matches!(
-        arg,
-        Expression::Constant(crate::core::constants::MathConstant::Pi)
-    ) {
+    if pi_multiple(arg).is_some() {
         return Some(Expression::integer(0));
     }
 
@@ -221,6 +212,29 @@ fn try_numeric_tan(arg: &Expression) -> Option<Expression> {
     }
 }
 
+/// `k` when `expr` is `kπ` for an integer `k`
+fn pi_multiple(expr: &Expression) -> Option<i64> {
+    let is_pi = |e: &Expression| {
+        matches!(
+            e,
+            Expression::Constant(crate::core::constants::MathConstant::Pi)
+        )
+    };
+    match expr {
+        _ if is_pi(expr) => Some(1),
+        Expression::Mul(terms) => match terms.as_slice() {
+            [Expression::Number(Number::Integer(k)), pi]
+            | [pi, Expression::Number(Number::Integer(k))]
+                if is_pi(pi) =>
+            {
+                Some(*k)
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
 fn is_pi_over_2(expr: &Expression) -> bool {
     if let Expression::Mul(terms) = expr {
         if terms.len() == 2 {
@@ -378,4 +392,15 @@ mod tests {
             result
         );
     }
+
+    #[test]
+    fn test_integer_multiples_of_pi() {
+        let pi = Expression::pi();
+        let times = |k: i64| Expression::mul(vec![Expression::integer(k), pi.clone()]);
+
+        assert_eq!(sin(&times(3)), Expression::integer(0));
+        assert_eq!(cos(&times(-3)), Expression::integer(-1));
+        assert_eq!(cos(&times(4)), Expression::integer(1));
+        assert_eq!(tan(&times(2)), Expression::integer(0));
+    }
 }
@@ -4,6 +4,8 @@
 //! capabilities for ALL functions in MathHook.
 
 use super::properties::FunctionProperties;
+use crate::core::expression::function_id::BUILTIN_FUNCTIONS;
+use crate::core::expression::FunctionId;
 use crate::core::Expression;
 use crate::educational::step_by_step::Step;
 use once_cell::sync::Lazy;
@@ -19,6 +21,9 @@ pub struct UniversalFunctionRegistry {
     /// O(1) lookup for function properties
     properties: HashMap<String, FunctionProperties>,
 
+    /// Properties of the built-in functions indexed by [`FunctionId`]
+    by_id: Vec<Option<FunctionProperties>>,
+
     /// Educational step generators for all functions
     /// Required for step-by-step explanations
     step_generators: HashMap<String, Box<dyn StepGenerator>>,
@@ -69,6 +74,7 @@ impl UniversalFunctionRegistry {
         let mut registry = Self {
             properties: HashMap::with_capacity(64), // Optimized capacity
             step_generators: HashMap::with_capacity(64), // Matching for memory alignment
+            by_id: Vec::new(),
         };
 
         // Initialize built-in functions using modular intelligence
@@ -77,6 +83,11 @@ impl UniversalFunctionRegistry {
         registry.initialize_polynomial_functions();
         registry.initialize_number_theory_functions();
 
+        registry.by_id = BUILTIN_FUNCTIONS
+            .iter()
+            .map(|name| registry.properties.get(*name).cloned())
+            .collect();
+
         registry
     }
 
@@ -86,6 +97,18 @@ impl UniversalFunctionRegistry {
         self.properties.get(name)
     }
 
+    /// Get function properties by interned ID
+    ///
+    /// Indexes the built-in table directly and hashes `name` only for
+    /// user-defined functions.
+    #[inline(always)]
+    pub fn get_properties_by_id(&self, id: FunctionId, name: &str) -> Option<&FunctionProperties> {
+        match id.index() {
+            Some(index) => self.by_id[index].as_ref(),
+            None => self.properties.get(name),
+        }
+    }
+
     /// Check if function has mathematical intelligence
     #[inline(always)]
     pub fn has_intelligence(&self, name: &str) -> bool {
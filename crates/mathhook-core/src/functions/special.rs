@@ -5,12 +5,14 @@
 //! - Bessel functions (J, Y)
 //! - Riemann zeta function
 //! - Error functions (erf, erfc, erfi)
+//! - Heaviside step and Dirac delta
 //! - Hypergeometric functions (1F1, 2F1)
 //! - Elliptic functions (Jacobi sn, cn, dn)
 
 pub mod bessel;
 pub mod beta;
 pub mod digamma;
+pub mod distributions;
 pub mod error_functions;
 pub mod factorial;
 pub mod gamma;
@@ -21,6 +23,7 @@ pub mod zeta;
 pub use bessel::{bessel_j, bessel_y};
 pub use beta::{beta, beta_numerical};
 pub use digamma::{digamma, digamma_numerical};
+pub use distributions::{dirac_delta, heaviside};
 pub use error_functions::{erf, erfc};
 pub use factorial::factorial;
 pub use gamma::{gamma, lanczos_gamma};
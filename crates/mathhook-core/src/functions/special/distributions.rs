@@ -0,0 +1,130 @@
+//! Heaviside step and Dirac delta
+//!
+//! Generalized functions used for step and impulse inputs. The derivative of
+//! `heaviside(x)` is `dirac_delta(x)`; `dirac_delta(x, k)` is the k-th
+//! derivative of the delta. `heaviside(0) = 1/2`, the midpoint convention
+//! that makes `heaviside(x) + heaviside(-x) = 1` hold everywhere.
+
+use crate::core::{Expression, Number};
+
+/// Heaviside step function H(x)
+///
+/// # Mathematical Definition
+///
+/// H(x) = { 0    if x < 0
+///        { 1/2  if x = 0
+///        { 1    if x > 0
+///
+/// # Examples
+///
+/// ```
+/// use mathhook_core::functions::special::distributions::heaviside;
+/// use mathhook_core::{expr, Expression};
+///
+/// assert_eq!(heaviside(&expr!(-3)), expr!(0));
+/// assert_eq!(heaviside(&expr!(0)), Expression::rational(1, 2));
+/// assert_eq!(heaviside(&expr!(x)), Expression::function("heaviside", vec![expr!(x)]));
+/// ```
+pub fn heaviside(arg: &Expression) -> Expression {
+    match arg {
+        Expression::Number(n) => match number_sign(n) {
+            Some(sign) if sign < 0 => Expression::integer(0),
+            Some(0) => Expression::rational(1, 2),
+            Some(_) => Expression::integer(1),
+            None => Expression::function("heaviside", vec![arg.clone()]),
+        },
+        _ => Expression::function("heaviside", vec![arg.clone()]),
+    }
+}
+
+/// Dirac delta δ(x), or its k-th derivative δ⁽ᵏ⁾(x)
+///
+/// # Mathematical Definition
+///
+/// δ(x) = 0 for x ≠ 0 with ∫ δ(x) dx = 1 over any interval containing 0.
+/// The value at 0 is left unevaluated.
+///
+/// # Examples
+///
+/// ```
+/// use mathhook_core::functions::special::distributions::dirac_delta;
+/// use mathhook_core::{expr, Expression};
+///
+/// assert_eq!(dirac_delta(&expr!(2), 0), expr!(0));
+/// assert_eq!(dirac_delta(&expr!(0), 0), Expression::function("dirac_delta", vec![expr!(0)]));
+/// assert_eq!(
+///     dirac_delta(&expr!(x), 1),
+///     Expression::function("dirac_delta", vec![expr!(x), expr!(1)])
+/// );
+/// ```
+pub fn dirac_delta(arg: &Expression, order: i64) -> Expression {
+    if let Expression::Number(n) = arg {
+        if number_sign(n).is_some_and(|sign| sign != 0) {
+            return Expression::integer(0);
+        }
+    }
+    if order == 0 {
+        Expression::function("dirac_delta", vec![arg.clone()])
+    } else {
+        Expression::function("dirac_delta", vec![arg.clone(), Expression::integer(order)])
+    }
+}
+
+/// Order of a `dirac_delta` call: 0 for `δ(x)`, k for `δ(x, k)`
+pub fn delta_order(args: &[Expression]) -> Option<i64> {
+    match args {
+        [_] => Some(0),
+        [_, Expression::Number(Number::Integer(k))] if *k >= 0 => Some(*k),
+        _ => None,
+    }
+}
+
+fn number_sign(n: &Number) -> Option<i64> {
+    match n {
+        Number::Integer(i) => Some(i.signum()),
+        Number::Float(f) if f.is_nan() => None,
+        Number::Float(f) => Some(if *f > 0.0 {
+            1
+        } else if *f < 0.0 {
+            -1
+        } else {
+            0
+        }),
+        Number::BigInteger(bi) => Some(match bi.sign() {
+            num_bigint::Sign::Plus => 1,
+            num_bigint::Sign::Minus => -1,
+            num_bigint::Sign::NoSign => 0,
+        }),
+        Number::Rational(r) => Some(match r.numer().sign() {
+            num_bigint::Sign::Plus => 1,
+            num_bigint::Sign::Minus => -1,
+            num_bigint::Sign::NoSign => 0,
+        }),
+        Number::Decimal(d) => Some(match d.digits().sign() {
+            num_bigint::Sign::Plus => 1,
+            num_bigint::Sign::Minus => -1,
+            num_bigint::Sign::NoSign => 0,
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr;
+
+    #[test]
+    fn test_heaviside_numeric_values() {
+        assert_eq!(heaviside(&Expression::float(0.5)), expr!(1));
+        assert_eq!(heaviside(&Expression::rational(-1, 3)), expr!(0));
+        assert_eq!(heaviside(&expr!(0)), Expression::rational(1, 2));
+    }
+
+    #[test]
+    fn test_delta_order() {
+        assert_eq!(delta_order(&[expr!(x)]), Some(0));
+        assert_eq!(delta_order(&[expr!(x), expr!(2)]), Some(2));
+        assert_eq!(delta_order(&[expr!(x), expr!(y)]), None);
+        assert_eq!(dirac_delta(&Expression::rational(1, 2), 3), expr!(0));
+    }
+}
@@ -351,7 +351,7 @@ mod tests {
         let result = zeta(&Expression::integer(1));
 
         match result {
-            Expression::Function { name, args } => {
+            Expression::Function { name, args, .. } => {
                 assert_eq!(name.as_ref(), "zeta");
                 assert_eq!(args.len(), 1);
             }
@@ -386,7 +386,7 @@ mod tests {
         let result = zeta(&s);
 
         match result {
-            Expression::Function { name, args } => {
+            Expression::Function { name, args, .. } => {
                 assert_eq!(name.as_ref(), "zeta");
                 assert_eq!(args.len(), 1);
             }
@@ -0,0 +1,87 @@
+//! Analytic geometry in the plane with exact coordinates
+//!
+//! Points, lines, segments, circles, ellipses and general conics whose
+//! coordinates and coefficients are expressions, so `√2` stays `√2` and a
+//! circle of radius `r` works as well as one of radius `3`. Intersections are
+//! computed in closed form (lines meet conics in at most two points), and
+//! conic equations can be classified and rewritten in standard form.
+//! [`CoordinateTransform`] changes between Cartesian and polar, cylindrical,
+//! spherical or affine coordinates for expressions, vector fields and
+//! multiple integrals.
+//!
+//! Predicates such as [`Line::contains`] decide exactly when the expressions
+//! simplify to numbers; with free parameters they answer `true` only when the
+//! relation holds identically.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use mathhook_core::geometry::{Circle, Intersect, Line, Point};
+//! use mathhook_core::Expression;
+//!
+//! let unit = Circle::new(Point::origin(), Expression::integer(1));
+//! let diagonal = Line::through(&Point::origin(), &Point::new(Expression::integer(1), Expression::integer(1))).unwrap();
+//! let points = diagonal.intersection(&unit).points().to_vec();
+//! assert_eq!(points.len(), 2);
+//! ```
+
+pub mod circle;
+pub mod conic;
+pub mod coordinates;
+pub mod intersection;
+pub mod line;
+pub mod point;
+
+pub use circle::{Circle, Ellipse};
+pub use conic::{Conic, ConicKind};
+pub use coordinates::{CoordinateSystem, CoordinateTransform};
+pub use intersection::{Intersect, Intersection};
+pub use line::{Line, Segment};
+pub use point::Point;
+
+use crate::core::Expression;
+use crate::simplify::Simplify;
+use std::cmp::Ordering;
+
+/// Relative tolerance for deciding numerically that a constant expression is zero
+const ZERO_TOLERANCE: f64 = 1e-12;
+
+/// Sign of `expr` when it can be decided
+///
+/// Exact when the expression simplifies to a number; otherwise decided
+/// numerically if it has no free symbols (`√2 - 1`), and `None` when it does.
+pub(crate) fn sign_of(expr: &Expression) -> Option<Ordering> {
+    let simplified = expr.simplify();
+    if simplified.is_zero() {
+        return Some(Ordering::Equal);
+    }
+    let value = simplified.evaluate_to_f64().ok()?;
+    if !value.is_finite() {
+        return None;
+    }
+    if value.abs() <= ZERO_TOLERANCE {
+        Some(Ordering::Equal)
+    } else {
+        value.partial_cmp(&0.0)
+    }
+}
+
+/// Whether `expr` is known to be zero
+pub(crate) fn is_zero(expr: &Expression) -> bool {
+    sign_of(expr) == Some(Ordering::Equal)
+}
+
+/// `a - b`, simplified
+pub(crate) fn difference(a: &Expression, b: &Expression) -> Expression {
+    (a - b).simplify()
+}
+
+/// `a / b`, simplified
+pub(crate) fn quotient(a: Expression, b: Expression) -> Expression {
+    Expression::div(a, b).simplify()
+}
+
+/// `expr²`
+pub(crate) fn square(expr: &Expression) -> Expression {
+    Expression::pow(expr.clone(), Expression::integer(2))
+}
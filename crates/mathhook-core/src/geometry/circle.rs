@@ -0,0 +1,339 @@
+//! Circles and axis-aligned ellipses
+
+use super::conic::Conic;
+use super::line::{Line, Segment};
+use super::point::Point;
+use super::{difference, is_zero, quotient, sign_of, square};
+use crate::core::{Expression, Symbol};
+use crate::error::MathError;
+use crate::simplify::Simplify;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+/// A circle given by its center and radius
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::geometry::{Circle, Line, Point};
+/// use mathhook_core::Expression;
+///
+/// let circle = Circle::new(Point::origin(), Expression::integer(5));
+/// let point = Point::new(Expression::integer(3), Expression::integer(4));
+/// assert!(circle.contains(&point));
+///
+/// let tangent = circle.tangent_at(&point).unwrap();
+/// assert!(circle.is_tangent(&tangent));
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Circle {
+    pub center: Point,
+    pub radius: Expression,
+}
+
+impl Circle {
+    pub fn new(center: Point, radius: Expression) -> Self {
+        Self { center, radius }
+    }
+
+    /// Circle through three points (the circumcircle of their triangle)
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError` if the points are collinear.
+    pub fn through_points(a: &Point, b: &Point, c: &Point) -> Result<Self, MathError> {
+        let collinear = || MathError::DomainError {
+            operation: "Circle::through_points".to_owned(),
+            value: Expression::integer(0),
+            reason: "the points are collinear".to_owned(),
+        };
+        if Point::are_collinear(a, b, c) {
+            return Err(collinear());
+        }
+        let first = Segment::new(a.clone(), b.clone()).perpendicular_bisector()?;
+        let second = Segment::new(b.clone(), c.clone()).perpendicular_bisector()?;
+        let center = super::intersection::line_line(&first, &second).ok_or_else(collinear)?;
+        let radius = center.distance(a);
+        Ok(Self { center, radius })
+    }
+
+    /// Circle from an equation such as `x² + y² - 2x = 3`
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError` if the equation is not a circle.
+    pub fn from_equation(equation: &Expression, x: &Symbol, y: &Symbol) -> Result<Self, MathError> {
+        let conic = Conic::from_equation(equation, x, y)?;
+        let not_circle = || MathError::DomainError {
+            operation: "Circle::from_equation".to_owned(),
+            value: equation.clone(),
+            reason: "equation is not a circle".to_owned(),
+        };
+        if is_zero(&conic.a) || !is_zero(&conic.b) || !is_zero(&difference(&conic.a, &conic.c)) {
+            return Err(not_circle());
+        }
+        let two_a = Expression::integer(2) * conic.a.clone();
+        let center = Point::new(
+            quotient(Expression::integer(-1) * conic.d.clone(), two_a.clone()),
+            quotient(Expression::integer(-1) * conic.e.clone(), two_a),
+        );
+        let radius_squared = difference(
+            &Expression::add(vec![square(&center.x), square(&center.y)]),
+            &quotient(conic.f, conic.a),
+        );
+        if sign_of(&radius_squared).is_some_and(|sign| sign != Ordering::Greater) {
+            return Err(not_circle());
+        }
+        Ok(Self {
+            center,
+            radius: Expression::sqrt(radius_squared).simplify(),
+        })
+    }
+
+    /// `(x - h)² + (y - k)² = r²`
+    pub fn equation(&self, x: &Symbol, y: &Symbol) -> Expression {
+        Expression::equation(
+            Expression::add(vec![
+                square(&(Expression::symbol(x.clone()) - self.center.x.clone())),
+                square(&(Expression::symbol(y.clone()) - self.center.y.clone())),
+            ]),
+            square(&self.radius).simplify(),
+        )
+    }
+
+    /// The circle as a general conic
+    pub fn to_conic(&self) -> Conic {
+        let (h, k) = (&self.center.x, &self.center.y);
+        Conic::new(
+            Expression::integer(1),
+            Expression::integer(0),
+            Expression::integer(1),
+            (Expression::integer(-2) * h.clone()).simplify(),
+            (Expression::integer(-2) * k.clone()).simplify(),
+            Expression::add(vec![
+                square(h),
+                square(k),
+                Expression::integer(-1) * square(&self.radius),
+            ])
+            .simplify(),
+        )
+    }
+
+    pub fn area(&self) -> Expression {
+        (Expression::pi() * square(&self.radius)).simplify()
+    }
+
+    pub fn circumference(&self) -> Expression {
+        (Expression::integer(2) * Expression::pi() * self.radius.clone()).simplify()
+    }
+
+    /// Whether `point` is known to lie on the circle
+    pub fn contains(&self, point: &Point) -> bool {
+        is_zero(&difference(
+            &self.center.squared_distance(point),
+            &square(&self.radius),
+        ))
+    }
+
+    /// Tangent line at a point of the circle
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError` if `point` is not known to lie on the circle.
+    pub fn tangent_at(&self, point: &Point) -> Result<Line, MathError> {
+        if !self.contains(point) {
+            return Err(MathError::DomainError {
+                operation: "Circle::tangent_at".to_owned(),
+                value: point.x.clone(),
+                reason: "point is not on the circle".to_owned(),
+            });
+        }
+        // (p - center) · (X - p) = 0
+        let a = difference(&point.x, &self.center.x);
+        let b = difference(&point.y, &self.center.y);
+        let c = Expression::integer(-1)
+            * Expression::add(vec![
+                a.clone() * point.x.clone(),
+                b.clone() * point.y.clone(),
+            ]);
+        Line::new(a, b, c)
+    }
+
+    /// Whether `line` is known to touch the circle in exactly one point
+    pub fn is_tangent(&self, line: &Line) -> bool {
+        // (a h + b k + c)² = r² (a² + b²)
+        is_zero(&difference(
+            &square(&line.value_at(&self.center)),
+            &(square(&self.radius) * Expression::add(vec![square(&line.a), square(&line.b)])),
+        ))
+    }
+}
+
+/// An ellipse with axes parallel to the coordinate axes
+///
+/// `(x - h)²/rx² + (y - k)²/ry² = 1`
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::geometry::{Ellipse, Point};
+/// use mathhook_core::Expression;
+///
+/// let ellipse = Ellipse::new(Point::origin(), Expression::integer(5), Expression::integer(3));
+/// let (_, f2) = ellipse.foci().unwrap();
+/// assert_eq!(f2.x, Expression::integer(4));
+/// assert_eq!(ellipse.eccentricity(), Some(Expression::rational(4, 5)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Ellipse {
+    pub center: Point,
+    /// Semi-axis along x
+    pub rx: Expression,
+    /// Semi-axis along y
+    pub ry: Expression,
+}
+
+impl Ellipse {
+    pub fn new(center: Point, rx: Expression, ry: Expression) -> Self {
+        Self { center, rx, ry }
+    }
+
+    /// `(x - h)²/rx² + (y - k)²/ry² = 1`
+    pub fn equation(&self, x: &Symbol, y: &Symbol) -> Expression {
+        Expression::equation(
+            Expression::add(vec![
+                Expression::div(
+                    square(&(Expression::symbol(x.clone()) - self.center.x.clone())),
+                    square(&self.rx),
+                ),
+                Expression::div(
+                    square(&(Expression::symbol(y.clone()) - self.center.y.clone())),
+                    square(&self.ry),
+                ),
+            ]),
+            Expression::integer(1),
+        )
+    }
+
+    /// The ellipse as a general conic, scaled by `rx² ry²`
+    pub fn to_conic(&self) -> Conic {
+        let (h, k) = (&self.center.x, &self.center.y);
+        let (rx2, ry2) = (square(&self.rx), square(&self.ry));
+        Conic::new(
+            ry2.clone().simplify(),
+            Expression::integer(0),
+            rx2.clone().simplify(),
+            (Expression::integer(-2) * ry2.clone() * h.clone()).simplify(),
+            (Expression::integer(-2) * rx2.clone() * k.clone()).simplify(),
+            Expression::add(vec![
+                ry2.clone() * square(h),
+                rx2.clone() * square(k),
+                Expression::integer(-1) * rx2 * ry2,
+            ])
+            .simplify(),
+        )
+    }
+
+    pub fn area(&self) -> Expression {
+        (Expression::pi() * self.rx.clone() * self.ry.clone()).simplify()
+    }
+
+    /// Whether the x semi-axis is the major one, when decidable
+    fn major_is_x(&self) -> Option<bool> {
+        sign_of(&difference(&square(&self.rx), &square(&self.ry)))
+            .map(|sign| sign != Ordering::Less)
+    }
+
+    /// Distance from the center to each focus, `√(major² - minor²)`
+    fn focal_distance(&self) -> Option<Expression> {
+        let (major, minor) = if self.major_is_x()? {
+            (&self.rx, &self.ry)
+        } else {
+            (&self.ry, &self.rx)
+        };
+        Some(Expression::sqrt(difference(&square(major), &square(minor))).simplify())
+    }
+
+    /// `√(1 - minor²/major²)`, `None` when the major axis is undecided
+    pub fn eccentricity(&self) -> Option<Expression> {
+        let major = if self.major_is_x()? {
+            &self.rx
+        } else {
+            &self.ry
+        };
+        Some(quotient(self.focal_distance()?, major.clone()))
+    }
+
+    /// Foci on the major axis, `None` when the major axis is undecided
+    pub fn foci(&self) -> Option<(Point, Point)> {
+        let c = self.focal_distance()?;
+        let minus = Expression::integer(-1) * c.clone();
+        Some(if self.major_is_x()? {
+            (
+                self.center.translate(&minus, &Expression::integer(0)),
+                self.center.translate(&c, &Expression::integer(0)),
+            )
+        } else {
+            (
+                self.center.translate(&Expression::integer(0), &minus),
+                self.center.translate(&Expression::integer(0), &c),
+            )
+        })
+    }
+
+    /// Whether `point` is known to lie on the ellipse
+    pub fn contains(&self, point: &Point) -> bool {
+        self.to_conic().contains(point)
+    }
+
+    /// Whether `line` is known to touch the ellipse in exactly one point
+    pub fn is_tangent(&self, line: &Line) -> bool {
+        // rx² a² + ry² b² = (a h + b k + c)²
+        is_zero(&difference(
+            &Expression::add(vec![
+                square(&self.rx) * square(&line.a),
+                square(&self.ry) * square(&line.b),
+            ]),
+            &square(&line.value_at(&self.center)),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{expr, symbol};
+
+    fn p(x: i64, y: i64) -> Point {
+        Point::new(Expression::integer(x), Expression::integer(y))
+    }
+
+    #[test]
+    fn test_circle_from_equation_and_three_points() {
+        let x = symbol!(x);
+        let y = symbol!(y);
+        let circle =
+            Circle::from_equation(&expr!((x ^ 2) + (y ^ 2) - (2 * x) - 3), &x, &y).unwrap();
+        assert!(circle.center.coincides(&p(1, 0)));
+        assert_eq!(circle.radius, expr!(2));
+
+        let circumcircle = Circle::through_points(&p(0, 0), &p(2, 0), &p(0, 2)).unwrap();
+        assert!(circumcircle.center.coincides(&p(1, 1)));
+        assert!(circumcircle.contains(&p(2, 2)));
+        assert!(Circle::through_points(&p(0, 0), &p(1, 1), &p(2, 2)).is_err());
+        assert!(Circle::from_equation(&expr!((x ^ 2) + (2 * (y ^ 2)) - 1), &x, &y).is_err());
+    }
+
+    #[test]
+    fn test_tangency() {
+        let circle = Circle::new(Point::origin(), expr!(r));
+        let horizontal = Line::new(expr!(0), expr!(1), expr!(-r)).unwrap();
+        assert!(circle.is_tangent(&horizontal));
+
+        let ellipse = Ellipse::new(Point::origin(), expr!(2), expr!(1));
+        let vertical = Line::new(expr!(1), expr!(0), expr!(-2)).unwrap();
+        assert!(ellipse.is_tangent(&vertical));
+        assert!(ellipse.contains(&p(2, 0)));
+        assert!(!ellipse.is_tangent(&Line::new(expr!(1), expr!(0), expr!(-1)).unwrap()));
+    }
+}
@@ -0,0 +1,380 @@
+//! General conics `A x² + B xy + C y² + D x + E y + F = 0`
+
+use super::point::Point;
+use super::{difference, is_zero, quotient, sign_of, square};
+use crate::algebra::Expand;
+use crate::core::polynomial::extract_coefficient_map;
+use crate::core::{Expression, Symbol};
+use crate::error::MathError;
+use crate::matrices::Matrix;
+use crate::simplify::Simplify;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+/// Shape of the curve described by a conic equation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ConicKind {
+    Circle,
+    Ellipse,
+    Parabola,
+    Hyperbola,
+    /// A point, one or two lines, or no real points at all
+    Degenerate,
+}
+
+/// The conic `a x² + b xy + c y² + d x + e y + f = 0`
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::geometry::{Conic, ConicKind};
+/// use mathhook_core::{expr, symbol};
+///
+/// let x = symbol!(x);
+/// let y = symbol!(y);
+/// let conic = Conic::from_equation(&expr!((x ^ 2) + (4 * (y ^ 2)) - 4), &x, &y).unwrap();
+/// assert_eq!(conic.kind(), Some(ConicKind::Ellipse));
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Conic {
+    pub a: Expression,
+    pub b: Expression,
+    pub c: Expression,
+    pub d: Expression,
+    pub e: Expression,
+    pub f: Expression,
+}
+
+impl Conic {
+    pub fn new(
+        a: Expression,
+        b: Expression,
+        c: Expression,
+        d: Expression,
+        e: Expression,
+        f: Expression,
+    ) -> Self {
+        Self { a, b, c, d, e, f }
+    }
+
+    /// Read the coefficients from an equation of degree at most two in `x`
+    /// and `y`; an expression without a relation is taken as `expr = 0`
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError` if the equation has a term of higher degree or
+    /// is not polynomial in `x` and `y`.
+    pub fn from_equation(equation: &Expression, x: &Symbol, y: &Symbol) -> Result<Self, MathError> {
+        let polynomial = match equation {
+            Expression::Relation(relation) => difference(&relation.left, &relation.right),
+            _ => equation.clone(),
+        }
+        .expand()
+        .simplify();
+
+        let not_quadratic = || MathError::DomainError {
+            operation: "Conic::from_equation".to_owned(),
+            value: equation.clone(),
+            reason: "equation is not a polynomial of degree at most 2 in x and y".to_owned(),
+        };
+
+        let mut coefficients = [
+            [
+                Expression::integer(0),
+                Expression::integer(0),
+                Expression::integer(0),
+            ],
+            [
+                Expression::integer(0),
+                Expression::integer(0),
+                Expression::integer(0),
+            ],
+            [
+                Expression::integer(0),
+                Expression::integer(0),
+                Expression::integer(0),
+            ],
+        ];
+        for (i, x_coefficient) in extract_coefficient_map(&polynomial, x) {
+            for (j, coefficient) in extract_coefficient_map(&x_coefficient, y) {
+                let coefficient = coefficient.simplify();
+                if is_zero(&coefficient) {
+                    continue;
+                }
+                if i < 0
+                    || j < 0
+                    || i + j > 2
+                    || coefficient.contains_variable(x)
+                    || coefficient.contains_variable(y)
+                {
+                    return Err(not_quadratic());
+                }
+                coefficients[i as usize][j as usize] = coefficient;
+            }
+        }
+
+        let [[f, e, c], [d, b, _], [a, _, _]] = coefficients;
+        Ok(Self { a, b, c, d, e, f })
+    }
+
+    /// The equation `a x² + b xy + c y² + d x + e y + f = 0`
+    pub fn equation(&self, x: &Symbol, y: &Symbol) -> Expression {
+        let point = Point::new(Expression::symbol(x.clone()), Expression::symbol(y.clone()));
+        Expression::equation(self.value_at(&point), Expression::integer(0))
+    }
+
+    /// Left-hand side of the equation evaluated at `point`
+    pub fn value_at(&self, point: &Point) -> Expression {
+        Expression::add(vec![
+            self.a.clone() * square(&point.x),
+            self.b.clone() * point.x.clone() * point.y.clone(),
+            self.c.clone() * square(&point.y),
+            self.d.clone() * point.x.clone(),
+            self.e.clone() * point.y.clone(),
+            self.f.clone(),
+        ])
+        .simplify()
+    }
+
+    /// Whether `point` is known to lie on the conic
+    pub fn contains(&self, point: &Point) -> bool {
+        is_zero(&self.value_at(point))
+    }
+
+    /// `b² - 4ac`: negative for ellipses, zero for parabolas, positive for
+    /// hyperbolas
+    pub fn discriminant(&self) -> Expression {
+        difference(
+            &square(&self.b),
+            &(Expression::integer(4) * self.a.clone() * self.c.clone()),
+        )
+    }
+
+    /// Determinant of the 3×3 matrix of the conic; zero for degenerate conics
+    pub fn matrix_determinant(&self) -> Expression {
+        let half = |e: &Expression| quotient(e.clone(), Expression::integer(2));
+        Matrix::dense(vec![
+            vec![self.a.clone(), half(&self.b), half(&self.d)],
+            vec![half(&self.b), self.c.clone(), half(&self.e)],
+            vec![half(&self.d), half(&self.e), self.f.clone()],
+        ])
+        .determinant()
+        .map(|det| det.simplify())
+        .unwrap_or_else(|_| Expression::undefined())
+    }
+
+    /// Classify the conic, or `None` when symbolic coefficients leave the
+    /// sign of the discriminant or determinant undecided
+    pub fn kind(&self) -> Option<ConicKind> {
+        let determinant = self.matrix_determinant();
+        if sign_of(&determinant)? == Ordering::Equal {
+            return Some(ConicKind::Degenerate);
+        }
+        match sign_of(&self.discriminant())? {
+            Ordering::Less => {
+                // Real points exist only when det and a + c have opposite signs
+                let trace = Expression::add(vec![self.a.clone(), self.c.clone()]);
+                if sign_of(&(determinant * trace))? != Ordering::Less {
+                    Some(ConicKind::Degenerate)
+                } else if is_zero(&self.b) && is_zero(&difference(&self.a, &self.c)) {
+                    Some(ConicKind::Circle)
+                } else {
+                    Some(ConicKind::Ellipse)
+                }
+            }
+            Ordering::Equal => Some(ConicKind::Parabola),
+            Ordering::Greater => Some(ConicKind::Hyperbola),
+        }
+    }
+
+    /// Center of an ellipse or hyperbola, `None` for parabolas
+    pub fn center(&self) -> Option<Point> {
+        let denominator = Expression::integer(-1) * self.discriminant();
+        if is_zero(&denominator) {
+            return None;
+        }
+        let h = difference(
+            &(self.b.clone() * self.e.clone()),
+            &(Expression::integer(2) * self.c.clone() * self.d.clone()),
+        );
+        let k = difference(
+            &(self.b.clone() * self.d.clone()),
+            &(Expression::integer(2) * self.a.clone() * self.e.clone()),
+        );
+        Some(Point::new(
+            quotient(h, denominator.clone()),
+            quotient(k, denominator),
+        ))
+    }
+
+    /// Rewrite an axis-aligned conic in standard form by completing squares
+    ///
+    /// - Ellipses: `(x - h)²/p + (y - k)²/q = 1`
+    /// - Hyperbolas: `(x - h)²/p - (y - k)²/q = 1` (or with `y` first)
+    /// - Parabolas: `(y - k)² = p (x - h)` or `(x - h)² = p (y - k)`
+    ///
+    /// # Errors
+    ///
+    /// Returns `NotImplemented` for rotated conics (`b ≠ 0`) and
+    /// `DomainError` for degenerate ones.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::geometry::Conic;
+    /// use mathhook_core::{expr, symbol};
+    ///
+    /// let x = symbol!(x);
+    /// let y = symbol!(y);
+    /// // x² + y² - 2x - 3 = 0  ->  (x - 1)²/4 + y²/4 = 1
+    /// let conic = Conic::from_equation(&expr!((x ^ 2) + (y ^ 2) - (2 * x) - 3), &x, &y).unwrap();
+    /// let standard = conic.standard_form(&x, &y).unwrap();
+    /// assert_eq!(standard.to_string(), expr!((((x - 1) ^ 2) / 4) + ((y ^ 2) / 4)).to_string() + " = 1");
+    /// ```
+    pub fn standard_form(&self, x: &Symbol, y: &Symbol) -> Result<Expression, MathError> {
+        if !is_zero(&self.b) {
+            return Err(MathError::NotImplemented {
+                feature: "standard form of a rotated conic (xy term)".to_owned(),
+            });
+        }
+        let degenerate = || MathError::DomainError {
+            operation: "Conic::standard_form".to_owned(),
+            value: self.equation(x, y),
+            reason: "degenerate conic has no standard form".to_owned(),
+        };
+        let x = Expression::symbol(x.clone());
+        let y = Expression::symbol(y.clone());
+        let shifted = |var: &Expression, linear: &Expression, quadratic: &Expression| {
+            // var + linear/(2 quadratic)
+            let offset = quotient(linear.clone(), Expression::integer(2) * quadratic.clone());
+            Expression::add(vec![var.clone(), offset])
+        };
+
+        match (is_zero(&self.a), is_zero(&self.c)) {
+            (false, false) => {
+                // a (x - h)² + c (y - k)² = r
+                let r = Expression::add(vec![
+                    quotient(square(&self.d), Expression::integer(4) * self.a.clone()),
+                    quotient(square(&self.e), Expression::integer(4) * self.c.clone()),
+                    Expression::integer(-1) * self.f.clone(),
+                ])
+                .simplify();
+                if is_zero(&r) {
+                    return Err(degenerate());
+                }
+                let p = quotient(r.clone(), self.a.clone());
+                let q = quotient(r, self.c.clone());
+                let x_term = |denominator: Expression| {
+                    Expression::div(square(&shifted(&x, &self.d, &self.a)), denominator)
+                };
+                let y_term = |denominator: Expression| {
+                    Expression::div(square(&shifted(&y, &self.e, &self.c)), denominator)
+                };
+                let lhs = match (sign_of(&p), sign_of(&q)) {
+                    (Some(Ordering::Less), Some(Ordering::Less)) => return Err(degenerate()),
+                    (_, Some(Ordering::Less)) => x_term(p) - y_term(Expression::integer(-1) * q),
+                    (Some(Ordering::Less), _) => y_term(q) - x_term(Expression::integer(-1) * p),
+                    _ => Expression::add(vec![x_term(p), y_term(q)]),
+                };
+                Ok(Expression::equation(lhs.simplify(), Expression::integer(1)))
+            }
+            (true, false) => {
+                // c (y - k)² = -d (x - h)
+                if is_zero(&self.d) {
+                    return Err(degenerate());
+                }
+                let p = quotient(Expression::integer(-1) * self.d.clone(), self.c.clone());
+                let h = quotient(
+                    difference(
+                        &quotient(square(&self.e), Expression::integer(4) * self.c.clone()),
+                        &self.f,
+                    ),
+                    self.d.clone(),
+                );
+                Ok(Expression::equation(
+                    square(&shifted(&y, &self.e, &self.c)).simplify(),
+                    Expression::mul(vec![p, difference(&x, &h)]).simplify(),
+                ))
+            }
+            (false, true) => {
+                if is_zero(&self.e) {
+                    return Err(degenerate());
+                }
+                let p = quotient(Expression::integer(-1) * self.e.clone(), self.a.clone());
+                let k = quotient(
+                    difference(
+                        &quotient(square(&self.d), Expression::integer(4) * self.a.clone()),
+                        &self.f,
+                    ),
+                    self.e.clone(),
+                );
+                Ok(Expression::equation(
+                    square(&shifted(&x, &self.d, &self.a)).simplify(),
+                    Expression::mul(vec![p, difference(&y, &k)]).simplify(),
+                ))
+            }
+            (true, true) => Err(degenerate()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{expr, symbol};
+
+    #[test]
+    fn test_classification() {
+        let x = symbol!(x);
+        let y = symbol!(y);
+        let kind = |equation: Expression| Conic::from_equation(&equation, &x, &y).unwrap().kind();
+
+        assert_eq!(kind(expr!((x ^ 2) + (y ^ 2) - 1)), Some(ConicKind::Circle));
+        assert_eq!(
+            kind(expr!((x ^ 2) - (y ^ 2) - 1)),
+            Some(ConicKind::Hyperbola)
+        );
+        assert_eq!(kind(expr!((x ^ 2) - y)), Some(ConicKind::Parabola));
+        assert_eq!(
+            kind(expr!((x ^ 2) + (y ^ 2) + 1)),
+            Some(ConicKind::Degenerate)
+        );
+        assert_eq!(kind(expr!((x ^ 2) - (y ^ 2))), Some(ConicKind::Degenerate));
+        assert_eq!(kind(expr!((x ^ 2) + (y ^ 2) - (r ^ 2))), None);
+    }
+
+    #[test]
+    fn test_from_equation_rejects_higher_degree() {
+        let x = symbol!(x);
+        let y = symbol!(y);
+        assert!(Conic::from_equation(&expr!((x ^ 2) * y), &x, &y).is_err());
+        assert!(Conic::from_equation(&expr!(x ^ 3), &x, &y).is_err());
+    }
+
+    #[test]
+    fn test_parabola_standard_form() {
+        let x = symbol!(x);
+        let y = symbol!(y);
+        // y² - 4x - 2y + 9 = 0  ->  (y - 1)² = 4 (x - 2)
+        let conic = Conic::from_equation(&expr!((y ^ 2) - (4 * x) - (2 * y) + 9), &x, &y).unwrap();
+        let standard = conic.standard_form(&x, &y).unwrap();
+        let Expression::Relation(relation) = &standard else {
+            panic!("expected an equation, got {}", standard);
+        };
+        let vertex = Point::new(expr!(2), expr!(1));
+        let at_vertex = |e: &Expression| {
+            e.substitute(
+                &[
+                    ("x".to_owned(), vertex.x.clone()),
+                    ("y".to_owned(), vertex.y.clone()),
+                ]
+                .into_iter()
+                .collect(),
+            )
+            .simplify()
+        };
+        assert!(at_vertex(&relation.left).is_zero());
+        assert!(at_vertex(&relation.right).is_zero());
+        assert!(conic.contains(&Point::new(expr!(3), expr!(3))));
+    }
+}
@@ -0,0 +1,705 @@
+//! Coordinate transformations between Cartesian and curvilinear systems
+//!
+//! A [`CoordinateTransform`] expresses the Cartesian variables as functions of
+//! new coordinates, `x = f(u)`. From that map it provides the Jacobian, the
+//! scale factors and unit basis vectors, rewrites expressions and vector
+//! fields in either direction, and evaluates gradient, divergence and
+//! Laplacian in orthogonal curvilinear coordinates.
+
+use super::{difference, is_zero, quotient, sign_of, square};
+use crate::algebra::Factor;
+use crate::calculus::derivatives::{Derivative, JacobianOperations, MatrixUtils};
+use crate::core::{Expression, Symbol};
+use crate::error::MathError;
+use crate::simplify::Simplify;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+/// Built-in curvilinear coordinate systems
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CoordinateSystem {
+    /// `(r, θ)`: `x = r cos θ`, `y = r sin θ`
+    Polar,
+    /// `(r, θ, z)`: polar coordinates in the plane plus height `z`
+    Cylindrical,
+    /// `(r, θ, φ)` with polar angle `θ` from the z-axis and azimuth `φ`:
+    /// `x = r sin θ cos φ`, `y = r sin θ sin φ`, `z = r cos θ`
+    Spherical,
+}
+
+impl CoordinateSystem {
+    /// Number of coordinates
+    pub fn dimension(&self) -> usize {
+        match self {
+            CoordinateSystem::Polar => 2,
+            CoordinateSystem::Cylindrical | CoordinateSystem::Spherical => 3,
+        }
+    }
+}
+
+/// Change of variables `x = f(u)` from new coordinates `u` to Cartesian `x`
+///
+/// Vector fields in the new coordinates are given by their components along
+/// the unit basis vectors `eᵢ = (∂x/∂uᵢ) / hᵢ`, the physical components used
+/// in vector calculus.
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::geometry::{CoordinateSystem, CoordinateTransform};
+/// use mathhook_core::{expr, symbol};
+///
+/// let (x, y, r, theta) = (symbol!(x), symbol!(y), symbol!(r), symbol!(theta));
+/// let polar = CoordinateTransform::new(CoordinateSystem::Polar, &[x, y], &[r, theta]).unwrap();
+///
+/// assert_eq!(polar.jacobian_determinant(), expr!(r));
+/// assert_eq!(polar.from_cartesian(&expr!((x ^ 2) + (y ^ 2))), expr!(r ^ 2));
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CoordinateTransform {
+    cartesian: Vec<Symbol>,
+    coordinates: Vec<Symbol>,
+    forward: Vec<Expression>,
+    inverse: Vec<Expression>,
+    scale_factors: Vec<Expression>,
+    /// `unit_basis[i]` holds the Cartesian components of `eᵢ`
+    unit_basis: Vec<Vec<Expression>>,
+    jacobian: Expression,
+    volume_element: Expression,
+    orthogonal: bool,
+}
+
+impl CoordinateTransform {
+    /// Transform for a built-in system
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError` if the number of symbols does not match the
+    /// dimension of the system.
+    pub fn new(
+        system: CoordinateSystem,
+        cartesian: &[Symbol],
+        coordinates: &[Symbol],
+    ) -> Result<Self, MathError> {
+        check_dimension(
+            "CoordinateTransform::new",
+            system.dimension(),
+            cartesian.len(),
+        )?;
+        check_dimension(
+            "CoordinateTransform::new",
+            system.dimension(),
+            coordinates.len(),
+        )?;
+
+        let var = |symbols: &[Symbol], i: usize| Expression::symbol(symbols[i].clone());
+        let cos = |e: Expression| Expression::function("cos", vec![e]);
+        let sin = |e: Expression| Expression::function("sin", vec![e]);
+        let neg = |e: Expression| (Expression::integer(-1) * e).simplify();
+        let (zero, one) = (Expression::integer(0), Expression::integer(1));
+
+        let r = var(coordinates, 0);
+        let theta = var(coordinates, 1);
+        let (x, y) = (var(cartesian, 0), var(cartesian, 1));
+        let planar_radius = Expression::sqrt(Expression::add(vec![square(&x), square(&y)]));
+        let azimuth = Expression::function("arctan", vec![quotient(y.clone(), x.clone())]);
+
+        let (forward, inverse, scale_factors, unit_basis, jacobian) = match system {
+            CoordinateSystem::Polar => (
+                vec![
+                    r.clone() * cos(theta.clone()),
+                    r.clone() * sin(theta.clone()),
+                ],
+                vec![planar_radius, azimuth],
+                vec![one.clone(), r.clone()],
+                vec![
+                    vec![cos(theta.clone()), sin(theta.clone())],
+                    vec![neg(sin(theta.clone())), cos(theta)],
+                ],
+                r,
+            ),
+            CoordinateSystem::Cylindrical => (
+                vec![
+                    r.clone() * cos(theta.clone()),
+                    r.clone() * sin(theta.clone()),
+                    var(coordinates, 2),
+                ],
+                vec![planar_radius, azimuth, var(cartesian, 2)],
+                vec![one.clone(), r.clone(), one.clone()],
+                vec![
+                    vec![cos(theta.clone()), sin(theta.clone()), zero.clone()],
+                    vec![neg(sin(theta.clone())), cos(theta), zero.clone()],
+                    vec![zero.clone(), zero, one],
+                ],
+                r,
+            ),
+            CoordinateSystem::Spherical => {
+                let phi = var(coordinates, 2);
+                let z = var(cartesian, 2);
+                let radius =
+                    Expression::sqrt(Expression::add(vec![square(&x), square(&y), square(&z)]));
+                let polar_angle =
+                    Expression::function("arccos", vec![Expression::div(z, radius.clone())]);
+                (
+                    vec![
+                        r.clone() * sin(theta.clone()) * cos(phi.clone()),
+                        r.clone() * sin(theta.clone()) * sin(phi.clone()),
+                        r.clone() * cos(theta.clone()),
+                    ],
+                    vec![radius, polar_angle, azimuth],
+                    vec![one, r.clone(), r.clone() * sin(theta.clone())],
+                    vec![
+                        vec![
+                            sin(theta.clone()) * cos(phi.clone()),
+                            sin(theta.clone()) * sin(phi.clone()),
+                            cos(theta.clone()),
+                        ],
+                        vec![
+                            cos(theta.clone()) * cos(phi.clone()),
+                            cos(theta.clone()) * sin(phi.clone()),
+                            neg(sin(theta.clone())),
+                        ],
+                        vec![neg(sin(phi.clone())), cos(phi), zero],
+                    ],
+                    square(&r) * sin(theta),
+                )
+            }
+        };
+
+        let simplify_all = |v: Vec<Expression>| v.into_iter().map(|e| e.simplify()).collect();
+        let jacobian = jacobian.simplify();
+        Ok(Self {
+            cartesian: cartesian.to_vec(),
+            coordinates: coordinates.to_vec(),
+            forward: simplify_all(forward),
+            inverse: simplify_all(inverse),
+            scale_factors: simplify_all(scale_factors),
+            unit_basis: unit_basis.into_iter().map(simplify_all).collect(),
+            // Nonnegative on the standard domain r ≥ 0, 0 ≤ θ ≤ π
+            volume_element: jacobian.clone(),
+            jacobian,
+            orthogonal: true,
+        })
+    }
+
+    /// Affine transform `x = A u + b`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::geometry::CoordinateTransform;
+    /// use mathhook_core::{expr, symbol};
+    ///
+    /// let (x, y, u, v) = (symbol!(x), symbol!(y), symbol!(u), symbol!(v));
+    /// // x = u + v, y = u - v
+    /// let shear = CoordinateTransform::affine(
+    ///     vec![vec![expr!(1), expr!(1)], vec![expr!(1), expr!(-1)]],
+    ///     vec![expr!(0), expr!(0)],
+    ///     &[x, y],
+    ///     &[u, v],
+    /// )
+    /// .unwrap();
+    /// assert_eq!(shear.jacobian_determinant(), expr!(-2));
+    /// assert_eq!(shear.change_of_variables(&expr!(1)), expr!(2));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError` if the dimensions disagree or `A` is known to be
+    /// singular.
+    pub fn affine(
+        matrix: Vec<Vec<Expression>>,
+        offset: Vec<Expression>,
+        cartesian: &[Symbol],
+        coordinates: &[Symbol],
+    ) -> Result<Self, MathError> {
+        let n = cartesian.len();
+        check_dimension("CoordinateTransform::affine", n, coordinates.len())?;
+        check_dimension("CoordinateTransform::affine", n, matrix.len())?;
+        check_dimension("CoordinateTransform::affine", n, offset.len())?;
+        for row in &matrix {
+            check_dimension("CoordinateTransform::affine", n, row.len())?;
+        }
+
+        if n == 0 {
+            return Err(MathError::DomainError {
+                operation: "CoordinateTransform::affine".to_owned(),
+                value: Expression::integer(0),
+                reason: "at least one coordinate is required".to_owned(),
+            });
+        }
+        let jacobian = MatrixUtils::determinant(&matrix);
+        if is_zero(&jacobian) {
+            return Err(MathError::DomainError {
+                operation: "CoordinateTransform::affine".to_owned(),
+                value: jacobian,
+                reason: "the linear part is singular".to_owned(),
+            });
+        }
+        let inverse_matrix = inverse_of(&matrix, &jacobian);
+
+        let symbols = |s: &[Symbol]| -> Vec<Expression> {
+            s.iter().map(|v| Expression::symbol(v.clone())).collect()
+        };
+        let (u, x) = (symbols(coordinates), symbols(cartesian));
+
+        // x = A u + b and u = A⁻¹ (x - b)
+        let forward = (0..n)
+            .map(|i| {
+                let mut terms: Vec<Expression> = (0..n)
+                    .map(|j| matrix[i][j].clone() * u[j].clone())
+                    .collect();
+                terms.push(offset[i].clone());
+                Expression::add(terms).simplify()
+            })
+            .collect();
+        let inverse = (0..n)
+            .map(|i| {
+                Expression::add(
+                    (0..n)
+                        .map(|j| inverse_matrix[i][j].clone() * difference(&x[j], &offset[j]))
+                        .collect(),
+                )
+                .simplify()
+            })
+            .collect();
+
+        let scale_factors: Vec<Expression> = (0..n)
+            .map(|j| {
+                Expression::sqrt(Expression::add(
+                    (0..n).map(|i| square(&matrix[i][j])).collect(),
+                ))
+                .simplify()
+            })
+            .collect();
+        let unit_basis = (0..n)
+            .map(|j| {
+                (0..n)
+                    .map(|i| quotient(matrix[i][j].clone(), scale_factors[j].clone()))
+                    .collect()
+            })
+            .collect();
+        let orthogonal = (0..n).all(|j| {
+            (j + 1..n).all(|k| {
+                is_zero(&Expression::add(
+                    (0..n)
+                        .map(|i| matrix[i][j].clone() * matrix[i][k].clone())
+                        .collect(),
+                ))
+            })
+        });
+        let volume_element = match sign_of(&jacobian) {
+            Some(Ordering::Less) => (Expression::integer(-1) * jacobian.clone()).simplify(),
+            Some(_) => jacobian.clone(),
+            None => Expression::function("abs", vec![jacobian.clone()]),
+        };
+
+        Ok(Self {
+            cartesian: cartesian.to_vec(),
+            coordinates: coordinates.to_vec(),
+            forward,
+            inverse,
+            scale_factors,
+            unit_basis,
+            jacobian,
+            volume_element,
+            orthogonal,
+        })
+    }
+
+    /// The Cartesian variables `x`
+    pub fn cartesian(&self) -> &[Symbol] {
+        &self.cartesian
+    }
+
+    /// The new coordinates `u`
+    pub fn coordinates(&self) -> &[Symbol] {
+        &self.coordinates
+    }
+
+    /// Each Cartesian variable as a function of the new coordinates
+    pub fn forward(&self) -> &[Expression] {
+        &self.forward
+    }
+
+    /// Each new coordinate as a function of the Cartesian variables
+    ///
+    /// Angles use the principal branch, e.g. `θ = arctan(y/x)`, which is the
+    /// polar angle for `x > 0`.
+    pub fn inverse(&self) -> &[Expression] {
+        &self.inverse
+    }
+
+    /// Lengths `hᵢ = |∂x/∂uᵢ|` of the coordinate basis vectors
+    pub fn scale_factors(&self) -> &[Expression] {
+        &self.scale_factors
+    }
+
+    /// Whether the coordinate basis vectors are mutually orthogonal
+    pub fn is_orthogonal(&self) -> bool {
+        self.orthogonal
+    }
+
+    /// Rewrite an expression in the Cartesian variables in the new coordinates
+    pub fn from_cartesian(&self, expr: &Expression) -> Expression {
+        let substituted = substitute_all(expr, &self.cartesian, &self.forward);
+        // Factoring exposes sin² + cos² inside terms such as r² cos²θ + r² sin²θ
+        let factored = substituted.factor().simplify();
+        if factored.to_string().len() < substituted.to_string().len() {
+            factored
+        } else {
+            substituted
+        }
+    }
+
+    /// Rewrite an expression in the new coordinates in the Cartesian variables
+    pub fn to_cartesian(&self, expr: &Expression) -> Expression {
+        substitute_all(expr, &self.coordinates, &self.inverse)
+    }
+
+    /// Jacobian matrix `∂xᵢ/∂uⱼ`
+    pub fn jacobian_matrix(&self) -> Vec<Vec<Expression>> {
+        JacobianOperations::compute(&self.forward, &self.coordinates)
+            .into_iter()
+            .map(|row| row.into_iter().map(|e| e.simplify()).collect())
+            .collect()
+    }
+
+    /// Jacobian determinant `det(∂x/∂u)`
+    ///
+    /// Closed form for the built-in systems (`r`, `r`, `r² sin θ`), where the
+    /// general determinant would need trigonometric identities to reduce.
+    pub fn jacobian_determinant(&self) -> Expression {
+        self.jacobian.clone()
+    }
+
+    /// Integrand for a multiple integral after the change of variables:
+    /// `f(x(u)) |det(∂x/∂u)|`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::geometry::{CoordinateSystem, CoordinateTransform};
+    /// use mathhook_core::{expr, symbol};
+    ///
+    /// let (x, y, r, theta) = (symbol!(x), symbol!(y), symbol!(r), symbol!(theta));
+    /// let polar = CoordinateTransform::new(CoordinateSystem::Polar, &[x, y], &[r, theta]).unwrap();
+    /// assert_eq!(polar.change_of_variables(&expr!((x ^ 2) + (y ^ 2))), expr!(r ^ 3));
+    /// ```
+    pub fn change_of_variables(&self, integrand: &Expression) -> Expression {
+        (self.from_cartesian(integrand) * self.volume_element.clone()).simplify()
+    }
+
+    /// Components along the unit basis vectors of a Cartesian vector field,
+    /// expressed in the new coordinates
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError` if the field has the wrong number of components.
+    pub fn vector_field_from_cartesian(
+        &self,
+        field: &[Expression],
+    ) -> Result<Vec<Expression>, MathError> {
+        check_dimension(
+            "CoordinateTransform::vector_field_from_cartesian",
+            self.dimension(),
+            field.len(),
+        )?;
+        let field: Vec<Expression> = field.iter().map(|f| self.from_cartesian(f)).collect();
+
+        // F = E c with the unit basis vectors as the columns of E
+        if self.orthogonal {
+            return Ok(self
+                .unit_basis
+                .iter()
+                .map(|basis| dot(basis, &field))
+                .collect());
+        }
+        let n = self.dimension();
+        let columns: Vec<Vec<Expression>> = (0..n)
+            .map(|i| (0..n).map(|j| self.unit_basis[j][i].clone()).collect())
+            .collect();
+        let inverse = inverse_of(&columns, &MatrixUtils::determinant(&columns));
+        Ok(inverse.iter().map(|row| dot(row, &field)).collect())
+    }
+
+    /// Cartesian components of a field given along the unit basis vectors
+    ///
+    /// The components stay expressed in the new coordinates; apply
+    /// [`to_cartesian`](Self::to_cartesian) to each to change variables too.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError` if the field has the wrong number of components.
+    pub fn vector_field_to_cartesian(
+        &self,
+        components: &[Expression],
+    ) -> Result<Vec<Expression>, MathError> {
+        check_dimension(
+            "CoordinateTransform::vector_field_to_cartesian",
+            self.dimension(),
+            components.len(),
+        )?;
+        let n = self.dimension();
+        Ok((0..n)
+            .map(|i| {
+                let column: Vec<Expression> =
+                    (0..n).map(|j| self.unit_basis[j][i].clone()).collect();
+                dot(&column, components)
+            })
+            .collect())
+    }
+
+    /// Gradient in the new coordinates, `(∇f)ᵢ = (1/hᵢ) ∂f/∂uᵢ`
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError` for non-orthogonal coordinates.
+    pub fn gradient(&self, expr: &Expression) -> Result<Vec<Expression>, MathError> {
+        self.require_orthogonal("CoordinateTransform::gradient")?;
+        Ok(self
+            .coordinates
+            .iter()
+            .zip(&self.scale_factors)
+            .map(|(u, h)| quotient(expr.derivative(u.clone()), h.clone()))
+            .collect())
+    }
+
+    /// Divergence of a field given along the unit basis vectors,
+    /// `∇·F = (1/H) Σ ∂(H Fᵢ / hᵢ)/∂uᵢ` with `H = h₁h₂…hₙ`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::geometry::{CoordinateSystem, CoordinateTransform};
+    /// use mathhook_core::{expr, symbol};
+    ///
+    /// let (x, y, z) = (symbol!(x), symbol!(y), symbol!(z));
+    /// let (r, theta, phi) = (symbol!(r), symbol!(theta), symbol!(phi));
+    /// let spherical =
+    ///     CoordinateTransform::new(CoordinateSystem::Spherical, &[x, y, z], &[r, theta, phi]).unwrap();
+    ///
+    /// // The radial field r e_r has divergence 3
+    /// let field = [expr!(r), expr!(0), expr!(0)];
+    /// assert_eq!(spherical.divergence(&field).unwrap(), expr!(3));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError` for non-orthogonal coordinates or a field with
+    /// the wrong number of components.
+    pub fn divergence(&self, components: &[Expression]) -> Result<Expression, MathError> {
+        self.require_orthogonal("CoordinateTransform::divergence")?;
+        check_dimension(
+            "CoordinateTransform::divergence",
+            self.dimension(),
+            components.len(),
+        )?;
+        let terms = self
+            .coordinates
+            .iter()
+            .zip(components)
+            .enumerate()
+            .map(|(i, (u, f))| (self.volume_without(i) * f.clone()).derivative(u.clone()))
+            .collect();
+        Ok(self.divide_by_volume(Expression::add(terms)))
+    }
+
+    /// Laplacian `∇²f = (1/H) Σ ∂(H/hᵢ² ∂f/∂uᵢ)/∂uᵢ`
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError` for non-orthogonal coordinates.
+    pub fn laplacian(&self, expr: &Expression) -> Result<Expression, MathError> {
+        self.require_orthogonal("CoordinateTransform::laplacian")?;
+        let terms = self
+            .coordinates
+            .iter()
+            .zip(&self.scale_factors)
+            .enumerate()
+            .map(|(i, (u, h))| {
+                let flux = self.volume_without(i) * expr.derivative(u.clone());
+                divide_factorwise(flux, h).derivative(u.clone())
+            })
+            .collect();
+        Ok(self.divide_by_volume(Expression::add(terms)))
+    }
+
+    /// `H / hᵢ`, the product of the other scale factors
+    fn volume_without(&self, index: usize) -> Expression {
+        let others: Vec<Expression> = (self.scale_factors.iter().enumerate())
+            .filter(|(j, _)| *j != index)
+            .map(|(_, h)| h.clone())
+            .collect();
+        Expression::mul(others).simplify()
+    }
+
+    /// `expr / H`, one factor at a time so that common factors cancel
+    fn divide_by_volume(&self, expr: Expression) -> Expression {
+        (self.scale_factors.iter()).fold(expr.simplify(), divide_factorwise)
+    }
+
+    fn dimension(&self) -> usize {
+        self.coordinates.len()
+    }
+
+    fn require_orthogonal(&self, operation: &str) -> Result<(), MathError> {
+        if self.orthogonal {
+            return Ok(());
+        }
+        Err(MathError::DomainError {
+            operation: operation.to_owned(),
+            value: self.jacobian.clone(),
+            reason: "coordinates are not orthogonal".to_owned(),
+        })
+    }
+}
+
+fn check_dimension(operation: &str, expected: usize, actual: usize) -> Result<(), MathError> {
+    if expected == actual {
+        return Ok(());
+    }
+    Err(MathError::DomainError {
+        operation: operation.to_owned(),
+        value: Expression::integer(actual as i64),
+        reason: format!("expected {} components, got {}", expected, actual),
+    })
+}
+
+fn substitute_all(expr: &Expression, symbols: &[Symbol], values: &[Expression]) -> Expression {
+    let substitutions: HashMap<String, Expression> = symbols
+        .iter()
+        .zip(values)
+        .map(|(s, v)| (s.name().to_owned(), v.clone()))
+        .collect();
+    expr.substitute(&substitutions).simplify()
+}
+
+/// Inverse of a square matrix with known determinant, via the adjugate
+fn inverse_of(matrix: &[Vec<Expression>], determinant: &Expression) -> Vec<Vec<Expression>> {
+    let n = matrix.len();
+    if n == 1 {
+        return vec![vec![quotient(Expression::integer(1), determinant.clone())]];
+    }
+    let minor = |row: usize, col: usize| -> Vec<Vec<Expression>> {
+        (0..n)
+            .filter(|&i| i != row)
+            .map(|i| {
+                (0..n)
+                    .filter(|&j| j != col)
+                    .map(|j| matrix[i][j].clone())
+                    .collect()
+            })
+            .collect()
+    };
+    (0..n)
+        .map(|i| {
+            (0..n)
+                .map(|j| {
+                    let sign = if (i + j) % 2 == 0 { 1 } else { -1 };
+                    let cofactor =
+                        Expression::integer(sign) * MatrixUtils::determinant(&minor(j, i));
+                    quotient(cofactor, determinant.clone())
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// `expr / divisor`, dividing by each factor of a product separately
+fn divide_factorwise(expr: Expression, divisor: &Expression) -> Expression {
+    match divisor {
+        Expression::Mul(factors) => (factors.iter()).fold(expr, |acc, f| quotient(acc, f.clone())),
+        _ => quotient(expr, divisor.clone()),
+    }
+}
+
+fn dot(a: &[Expression], b: &[Expression]) -> Expression {
+    Expression::add(
+        a.iter()
+            .zip(b)
+            .map(|(p, q)| p.clone() * q.clone())
+            .collect(),
+    )
+    .simplify()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{expr, symbol};
+
+    fn spherical() -> CoordinateTransform {
+        CoordinateTransform::new(
+            CoordinateSystem::Spherical,
+            &[symbol!(x), symbol!(y), symbol!(z)],
+            &[symbol!(r), symbol!(theta), symbol!(phi)],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_polar_round_trip_and_fields() {
+        let (x, y) = (symbol!(x), symbol!(y));
+        let polar = CoordinateTransform::new(
+            CoordinateSystem::Polar,
+            &[x.clone(), y.clone()],
+            &[symbol!(r), symbol!(theta)],
+        )
+        .unwrap();
+        assert_eq!(polar.to_cartesian(&expr!(r ^ 2)), expr!((x ^ 2) + (y ^ 2)));
+
+        // The position vector is r e_r
+        let position = polar
+            .vector_field_from_cartesian(&[expr!(x), expr!(y)])
+            .unwrap();
+        assert_eq!(position[1], expr!(0));
+        assert_eq!(
+            polar
+                .vector_field_to_cartesian(&[expr!(1), expr!(0)])
+                .unwrap(),
+            vec![expr!(cos(theta)), expr!(sin(theta))]
+        );
+        assert!(CoordinateTransform::new(CoordinateSystem::Polar, &[x], &[symbol!(r)]).is_err());
+    }
+
+    #[test]
+    fn test_spherical_operators() {
+        let spherical = spherical();
+        assert_eq!(spherical.laplacian(&expr!(r ^ 2)).unwrap(), expr!(6));
+        assert_eq!(
+            spherical.gradient(&expr!(r ^ 2)).unwrap(),
+            vec![expr!(2 * r), expr!(0), expr!(0)]
+        );
+        assert_eq!(spherical.jacobian_matrix().len(), 3);
+    }
+
+    #[test]
+    fn test_affine_jacobian_and_inverse() {
+        let (x, y, u, v) = (symbol!(x), symbol!(y), symbol!(u), symbol!(v));
+        // x = 2u + 1, y = v - 3
+        let shift = CoordinateTransform::affine(
+            vec![vec![expr!(2), expr!(0)], vec![expr!(0), expr!(1)]],
+            vec![expr!(1), expr!(-3)],
+            &[x, y],
+            &[u, v],
+        )
+        .unwrap();
+        assert_eq!(shift.jacobian_determinant(), expr!(2));
+        assert!(shift.is_orthogonal());
+        assert_eq!(shift.from_cartesian(&expr!(x)), expr!((2 * u) + 1));
+        assert_eq!(
+            shift.to_cartesian(&shift.from_cartesian(&expr!(x * y))),
+            expr!(x * y).simplify()
+        );
+
+        let singular = CoordinateTransform::affine(
+            vec![vec![expr!(1), expr!(2)], vec![expr!(2), expr!(4)]],
+            vec![expr!(0), expr!(0)],
+            &[symbol!(x), symbol!(y)],
+            &[symbol!(u), symbol!(v)],
+        );
+        assert!(singular.is_err());
+    }
+}
@@ -0,0 +1,377 @@
+//! Intersections between lines, segments and conics
+
+use super::circle::{Circle, Ellipse};
+use super::conic::Conic;
+use super::line::{Line, Segment};
+use super::point::{cross, Point};
+use super::{difference, is_zero, quotient, sign_of, square};
+use crate::core::Expression;
+use crate::simplify::Simplify;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+/// Common points of two figures
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Intersection {
+    /// Finitely many points (possibly none)
+    Points(Vec<Point>),
+    /// The figures share a line, segment or curve
+    Infinite,
+}
+
+impl Intersection {
+    /// The intersection points, empty for `Infinite`
+    pub fn points(&self) -> &[Point] {
+        match self {
+            Intersection::Points(points) => points,
+            Intersection::Infinite => &[],
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        matches!(self, Intersection::Points(points) if points.is_empty())
+    }
+}
+
+/// Figures whose common points can be computed in closed form
+///
+/// When symbolic parameters leave a discriminant's sign undecided, both
+/// candidate points are returned, as in the generic case.
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::geometry::{Circle, Intersect, Point};
+/// use mathhook_core::Expression;
+///
+/// let a = Circle::new(Point::origin(), Expression::integer(1));
+/// let b = Circle::new(Point::new(Expression::integer(2), Expression::integer(0)), Expression::integer(1));
+/// let touching = a.intersection(&b);
+/// assert_eq!(touching.points(), &[Point::new(Expression::integer(1), Expression::integer(0))]);
+/// ```
+pub trait Intersect<Rhs: ?Sized = Self> {
+    fn intersection(&self, other: &Rhs) -> Intersection;
+}
+
+/// Point where two non-parallel lines cross
+pub(crate) fn line_line(first: &Line, second: &Line) -> Option<Point> {
+    let determinant = first.determinant(second);
+    if is_zero(&determinant) {
+        return None;
+    }
+    let x = difference(
+        &(first.b.clone() * second.c.clone()),
+        &(second.b.clone() * first.c.clone()),
+    );
+    let y = difference(
+        &(first.c.clone() * second.a.clone()),
+        &(second.c.clone() * first.a.clone()),
+    );
+    Some(Point::new(
+        quotient(x, determinant.clone()),
+        quotient(y, determinant),
+    ))
+}
+
+/// Intersect `a x + b y + c = 0` with a conic by substituting the line into
+/// the conic equation and solving the resulting quadratic
+fn line_conic(line: &Line, conic: &Conic) -> Intersection {
+    // Solve for y when the line is not vertical, else swap the roles of x and y
+    let swapped = is_zero(&line.b);
+    let (la, lb, lc) = if swapped {
+        (&line.b, &line.a, &line.c)
+    } else {
+        (&line.a, &line.b, &line.c)
+    };
+    let (ca, cb, cc, cd, ce, cf) = if swapped {
+        (&conic.c, &conic.b, &conic.a, &conic.e, &conic.d, &conic.f)
+    } else {
+        (&conic.a, &conic.b, &conic.c, &conic.d, &conic.e, &conic.f)
+    };
+
+    // With y = -(la t + lc)/lb, the conic times lb² is qa t² + qb t + qc = 0
+    let neg = |e: Expression| Expression::integer(-1) * e;
+    let qa = Expression::add(vec![
+        ca.clone() * square(lb),
+        neg(cb.clone() * la.clone() * lb.clone()),
+        cc.clone() * square(la),
+    ])
+    .simplify();
+    let qb = Expression::add(vec![
+        neg(cb.clone() * lc.clone() * lb.clone()),
+        Expression::integer(2) * cc.clone() * la.clone() * lc.clone(),
+        cd.clone() * square(lb),
+        neg(ce.clone() * la.clone() * lb.clone()),
+    ])
+    .simplify();
+    let qc = Expression::add(vec![
+        cc.clone() * square(lc),
+        neg(ce.clone() * lc.clone() * lb.clone()),
+        cf.clone() * square(lb),
+    ])
+    .simplify();
+
+    let roots = if is_zero(&qa) {
+        if is_zero(&qb) {
+            return if is_zero(&qc) {
+                Intersection::Infinite
+            } else {
+                Intersection::Points(Vec::new())
+            };
+        }
+        vec![quotient(neg(qc), qb)]
+    } else {
+        let discriminant = difference(
+            &square(&qb),
+            &(Expression::integer(4) * qa.clone() * qc.clone()),
+        );
+        let two_a = Expression::integer(2) * qa.clone();
+        match sign_of(&discriminant) {
+            Some(Ordering::Less) => Vec::new(),
+            Some(Ordering::Equal) => vec![quotient(neg(qb), two_a)],
+            _ => {
+                let root = Expression::sqrt(discriminant);
+                vec![
+                    quotient(neg(qb.clone()) - root.clone(), two_a.clone()),
+                    quotient(neg(qb) + root, two_a),
+                ]
+            }
+        }
+    };
+
+    Intersection::Points(
+        roots
+            .into_iter()
+            .map(|t| {
+                let other = quotient(neg(la.clone() * t.clone() + lc.clone()), lb.clone());
+                if swapped {
+                    Point::new(other, t)
+                } else {
+                    Point::new(t, other)
+                }
+            })
+            .collect(),
+    )
+}
+
+impl Intersect for Line {
+    fn intersection(&self, other: &Line) -> Intersection {
+        match line_line(self, other) {
+            Some(point) => Intersection::Points(vec![point]),
+            None if self.coincides(other) => Intersection::Infinite,
+            None => Intersection::Points(Vec::new()),
+        }
+    }
+}
+
+impl Intersect<Conic> for Line {
+    fn intersection(&self, other: &Conic) -> Intersection {
+        line_conic(self, other)
+    }
+}
+
+impl Intersect<Circle> for Line {
+    fn intersection(&self, other: &Circle) -> Intersection {
+        line_conic(self, &other.to_conic())
+    }
+}
+
+impl Intersect<Line> for Circle {
+    fn intersection(&self, other: &Line) -> Intersection {
+        other.intersection(self)
+    }
+}
+
+impl Intersect<Ellipse> for Line {
+    fn intersection(&self, other: &Ellipse) -> Intersection {
+        line_conic(self, &other.to_conic())
+    }
+}
+
+impl Intersect<Line> for Ellipse {
+    fn intersection(&self, other: &Line) -> Intersection {
+        other.intersection(self)
+    }
+}
+
+impl Intersect for Circle {
+    /// Two circles meet on their radical line, found by subtracting equations
+    fn intersection(&self, other: &Circle) -> Intersection {
+        if self.center.coincides(&other.center) {
+            return if is_zero(&difference(&square(&self.radius), &square(&other.radius))) {
+                Intersection::Infinite
+            } else {
+                Intersection::Points(Vec::new())
+            };
+        }
+        let (first, second) = (self.to_conic(), other.to_conic());
+        match Line::new(
+            difference(&first.d, &second.d),
+            difference(&first.e, &second.e),
+            difference(&first.f, &second.f),
+        ) {
+            Ok(radical_line) => line_conic(&radical_line, &first),
+            Err(_) => Intersection::Points(Vec::new()),
+        }
+    }
+}
+
+impl Intersect<Line> for Segment {
+    fn intersection(&self, other: &Line) -> Intersection {
+        let Ok(line) = self.line() else {
+            return Intersection::Points(if other.contains(&self.start) {
+                vec![self.start.clone()]
+            } else {
+                Vec::new()
+            });
+        };
+        match line.intersection(other) {
+            Intersection::Infinite => Intersection::Infinite,
+            Intersection::Points(points) => Intersection::Points(
+                points
+                    .into_iter()
+                    .filter(|point| self.may_contain_on_line(point))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+impl Intersect<Segment> for Line {
+    fn intersection(&self, other: &Segment) -> Intersection {
+        other.intersection(self)
+    }
+}
+
+impl Intersect for Segment {
+    /// Collinear segments that overlap in more than one point give
+    /// `Infinite`; with symbolic endpoints an undecided overlap also does
+    fn intersection(&self, other: &Segment) -> Intersection {
+        let Ok(line) = other.line() else {
+            return Intersection::Points(if self.contains(&other.start) {
+                vec![other.start.clone()]
+            } else {
+                Vec::new()
+            });
+        };
+        let collinear = is_zero(&cross(&self.start, &self.end, &other.start))
+            && is_zero(&cross(&self.start, &self.end, &other.end));
+        if !collinear {
+            return match self.intersection(&line) {
+                Intersection::Points(points) => Intersection::Points(
+                    points
+                        .into_iter()
+                        .filter(|point| other.may_contain_on_line(point))
+                        .collect(),
+                ),
+                Intersection::Infinite => Intersection::Infinite,
+            };
+        }
+
+        // Overlap of [0, 1] with the parameters of the other segment's ends
+        let (t1, t2) = (
+            self.parameter_of(&other.start),
+            self.parameter_of(&other.end),
+        );
+        let (low, high) = match sign_of(&difference(&t2, &t1)) {
+            Some(Ordering::Less) => (t2, t1),
+            Some(_) => (t1, t2),
+            None => return Intersection::Infinite,
+        };
+        let (zero, one) = (Expression::integer(0), Expression::integer(1));
+        let start = match sign_of(&low) {
+            Some(Ordering::Less) => zero,
+            Some(_) => low,
+            None => return Intersection::Infinite,
+        };
+        let end = match sign_of(&difference(&high, &one)) {
+            Some(Ordering::Greater) => one,
+            Some(_) => high,
+            None => return Intersection::Infinite,
+        };
+        match sign_of(&difference(&end, &start)) {
+            Some(Ordering::Less) => Intersection::Points(Vec::new()),
+            Some(Ordering::Equal) => Intersection::Points(vec![self.point_at(&start)]),
+            _ => Intersection::Infinite,
+        }
+    }
+}
+
+impl Segment {
+    /// `start + t (end - start)`
+    fn point_at(&self, t: &Expression) -> Point {
+        let along =
+            |a: &Expression, b: &Expression| (a.clone() + t.clone() * difference(b, a)).simplify();
+        Point::new(
+            along(&self.start.x, &self.end.x),
+            along(&self.start.y, &self.end.y),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr;
+
+    fn p(x: i64, y: i64) -> Point {
+        Point::new(Expression::integer(x), Expression::integer(y))
+    }
+
+    #[test]
+    fn test_line_circle_cases() {
+        let circle = Circle::new(p(0, 0), expr!(5));
+        let secant = Line::new(expr!(0), expr!(1), expr!(-3)).unwrap();
+        let points = circle.intersection(&secant);
+        assert_eq!(points.points().len(), 2);
+        assert!(points.points().iter().any(|q| q.coincides(&p(4, 3))));
+        assert!(points.points().iter().any(|q| q.coincides(&p(-4, 3))));
+
+        let tangent = Line::new(expr!(1), expr!(0), expr!(-5)).unwrap();
+        assert_eq!(circle.intersection(&tangent).points(), &[p(5, 0)]);
+
+        let outside = Line::new(expr!(1), expr!(0), expr!(-6)).unwrap();
+        assert!(circle.intersection(&outside).is_empty());
+    }
+
+    #[test]
+    fn test_exact_irrational_points() {
+        let circle = Circle::new(p(0, 0), expr!(1));
+        let diagonal = Line::through(&p(0, 0), &p(1, 1)).unwrap();
+        let points = diagonal.intersection(&circle);
+        for point in points.points() {
+            assert!(circle.contains(point));
+            assert!(diagonal.contains(point));
+        }
+    }
+
+    #[test]
+    fn test_lines_and_segments() {
+        let l1 = Line::through(&p(0, 0), &p(2, 2)).unwrap();
+        let l2 = Line::through(&p(0, 2), &p(2, 0)).unwrap();
+        assert_eq!(l1.intersection(&l2).points(), &[p(1, 1)]);
+        assert_eq!(l1.intersection(&l1.clone()), Intersection::Infinite);
+
+        let s1 = Segment::new(p(0, 0), p(2, 2));
+        let s2 = Segment::new(p(3, 0), p(3, 5));
+        assert!(s1.intersection(&s2).is_empty());
+
+        let s3 = Segment::new(p(2, 2), p(4, 4));
+        assert_eq!(s1.intersection(&s3).points(), &[p(2, 2)]);
+        let s4 = Segment::new(p(1, 1), p(4, 4));
+        assert_eq!(s1.intersection(&s4), Intersection::Infinite);
+    }
+
+    #[test]
+    fn test_circle_circle() {
+        let a = Circle::new(p(0, 0), expr!(5));
+        let b = Circle::new(p(8, 0), expr!(5));
+        let points = a.intersection(&b);
+        assert_eq!(points.points().len(), 2);
+        assert!(points
+            .points()
+            .iter()
+            .all(|q| a.contains(q) && b.contains(q)));
+        assert_eq!(a.intersection(&a.clone()), Intersection::Infinite);
+    }
+}
@@ -0,0 +1,323 @@
+//! Lines and segments
+
+use super::conic::Conic;
+use super::point::{cross, Point};
+use super::{difference, is_zero, quotient, sign_of, square};
+use crate::core::{Expression, Symbol};
+use crate::error::MathError;
+use crate::simplify::Simplify;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+/// The line `a x + b y + c = 0`
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::geometry::{Line, Point};
+/// use mathhook_core::Expression;
+///
+/// let p = |x, y| Point::new(Expression::integer(x), Expression::integer(y));
+/// let line = Line::through(&p(0, 1), &p(2, 5)).unwrap();
+/// assert_eq!(line.slope(), Some(Expression::integer(2)));
+/// assert!(line.contains(&p(1, 3)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Line {
+    pub a: Expression,
+    pub b: Expression,
+    pub c: Expression,
+}
+
+impl Line {
+    /// Line `a x + b y + c = 0`
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError` if `a` and `b` are both zero.
+    ///
+    /// @no-binding - fallible constructor; bindings build lines with `from_point_slope`
+    pub fn new(a: Expression, b: Expression, c: Expression) -> Result<Self, MathError> {
+        if is_zero(&a) && is_zero(&b) {
+            return Err(MathError::DomainError {
+                operation: "Line::new".to_owned(),
+                value: Expression::add(vec![a, b]),
+                reason: "a and b cannot both be zero".to_owned(),
+            });
+        }
+        Ok(Self {
+            a: a.simplify(),
+            b: b.simplify(),
+            c: c.simplify(),
+        })
+    }
+
+    /// Line through two points
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError` if the points coincide.
+    pub fn through(p: &Point, q: &Point) -> Result<Self, MathError> {
+        let a = difference(&q.y, &p.y);
+        let b = difference(&p.x, &q.x);
+        let c = Expression::mul(vec![
+            Expression::integer(-1),
+            Expression::add(vec![a.clone() * p.x.clone(), b.clone() * p.y.clone()]),
+        ]);
+        Self::new(a, b, c).map_err(|_| MathError::DomainError {
+            operation: "Line::through".to_owned(),
+            value: p.x.clone(),
+            reason: "the two points coincide".to_owned(),
+        })
+    }
+
+    /// Line through `point` with the given slope
+    pub fn from_point_slope(point: &Point, slope: &Expression) -> Self {
+        Self {
+            a: slope.clone(),
+            b: Expression::integer(-1),
+            c: difference(&point.y, &(slope.clone() * point.x.clone())),
+        }
+    }
+
+    /// Line from a linear equation in `x` and `y`, e.g. `2x - y = 3`
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError` if the equation is not linear in `x` and `y`.
+    pub fn from_equation(equation: &Expression, x: &Symbol, y: &Symbol) -> Result<Self, MathError> {
+        let conic = Conic::from_equation(equation, x, y)?;
+        if !(is_zero(&conic.a) && is_zero(&conic.b) && is_zero(&conic.c)) {
+            return Err(MathError::DomainError {
+                operation: "Line::from_equation".to_owned(),
+                value: equation.clone(),
+                reason: "equation is not linear".to_owned(),
+            });
+        }
+        Self::new(conic.d, conic.e, conic.f)
+    }
+
+    /// The equation `a x + b y + c = 0`
+    pub fn equation(&self, x: &Symbol, y: &Symbol) -> Expression {
+        Expression::equation(
+            self.value_at_coordinates(Expression::symbol(x.clone()), Expression::symbol(y.clone())),
+            Expression::integer(0),
+        )
+    }
+
+    fn value_at_coordinates(&self, x: Expression, y: Expression) -> Expression {
+        Expression::add(vec![self.a.clone() * x, self.b.clone() * y, self.c.clone()]).simplify()
+    }
+
+    /// `a x + b y + c` at `point`: zero on the line, and its sign tells the side
+    pub fn value_at(&self, point: &Point) -> Expression {
+        self.value_at_coordinates(point.x.clone(), point.y.clone())
+    }
+
+    /// Slope `-a/b`, or `None` for a vertical line
+    pub fn slope(&self) -> Option<Expression> {
+        if is_zero(&self.b) {
+            return None;
+        }
+        Some(quotient(
+            Expression::mul(vec![Expression::integer(-1), self.a.clone()]),
+            self.b.clone(),
+        ))
+    }
+
+    /// Whether `point` is known to lie on the line
+    pub fn contains(&self, point: &Point) -> bool {
+        is_zero(&self.value_at(point))
+    }
+
+    /// Whether the lines are known to be parallel (or equal)
+    pub fn is_parallel(&self, other: &Line) -> bool {
+        is_zero(&self.determinant(other))
+    }
+
+    /// Whether the lines are known to be perpendicular
+    pub fn is_perpendicular(&self, other: &Line) -> bool {
+        is_zero(&Expression::add(vec![
+            self.a.clone() * other.a.clone(),
+            self.b.clone() * other.b.clone(),
+        ]))
+    }
+
+    /// `a₁b₂ - a₂b₁`, zero exactly for parallel lines
+    pub(crate) fn determinant(&self, other: &Line) -> Expression {
+        (self.a.clone() * other.b.clone() - other.a.clone() * self.b.clone()).simplify()
+    }
+
+    /// Whether the lines are known to be the same line
+    pub fn coincides(&self, other: &Line) -> bool {
+        self.is_parallel(other)
+            && is_zero(&(self.a.clone() * other.c.clone() - other.a.clone() * self.c.clone()))
+            && is_zero(&(self.b.clone() * other.c.clone() - other.b.clone() * self.c.clone()))
+    }
+
+    /// Perpendicular distance from `point`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::geometry::{Line, Point};
+    /// use mathhook_core::Expression;
+    ///
+    /// // 3x + 4y - 10 = 0
+    /// let line = Line::new(Expression::integer(3), Expression::integer(4), Expression::integer(-10)).unwrap();
+    /// assert_eq!(line.distance_to(&Point::origin()), Expression::integer(2));
+    /// ```
+    pub fn distance_to(&self, point: &Point) -> Expression {
+        let norm = Expression::sqrt(Expression::add(vec![square(&self.a), square(&self.b)]));
+        quotient(
+            Expression::function("abs", vec![self.value_at(point)]),
+            norm,
+        )
+    }
+
+    /// Line through `point` perpendicular to this one
+    pub fn perpendicular_through(&self, point: &Point) -> Line {
+        let c = difference(
+            &(self.a.clone() * point.y.clone()),
+            &(self.b.clone() * point.x.clone()),
+        );
+        Line {
+            a: self.b.clone(),
+            b: Expression::mul(vec![Expression::integer(-1), self.a.clone()]).simplify(),
+            c,
+        }
+    }
+
+    /// Line through `point` parallel to this one
+    pub fn parallel_through(&self, point: &Point) -> Line {
+        Line {
+            a: self.a.clone(),
+            b: self.b.clone(),
+            c: Expression::mul(vec![
+                Expression::integer(-1),
+                Expression::add(vec![
+                    self.a.clone() * point.x.clone(),
+                    self.b.clone() * point.y.clone(),
+                ]),
+            ])
+            .simplify(),
+        }
+    }
+}
+
+/// The segment from `start` to `end`
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::geometry::{Point, Segment};
+/// use mathhook_core::Expression;
+///
+/// let p = |x, y| Point::new(Expression::integer(x), Expression::integer(y));
+/// let segment = Segment::new(p(0, 0), p(4, 0));
+/// assert!(segment.contains(&p(1, 0)));
+/// assert!(!segment.contains(&p(5, 0)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Segment {
+    pub start: Point,
+    pub end: Point,
+}
+
+impl Segment {
+    pub fn new(start: Point, end: Point) -> Self {
+        Self { start, end }
+    }
+
+    pub fn length(&self) -> Expression {
+        self.start.distance(&self.end)
+    }
+
+    pub fn midpoint(&self) -> Point {
+        self.start.midpoint(&self.end)
+    }
+
+    /// Line through both endpoints
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError` for a degenerate segment.
+    pub fn line(&self) -> Result<Line, MathError> {
+        Line::through(&self.start, &self.end)
+    }
+
+    /// Line of points equidistant from both endpoints
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError` for a degenerate segment.
+    pub fn perpendicular_bisector(&self) -> Result<Line, MathError> {
+        Ok(self.line()?.perpendicular_through(&self.midpoint()))
+    }
+
+    /// Position `t` of `point` along the segment, `start + t (end - start)`,
+    /// for a point on its line
+    pub(crate) fn parameter_of(&self, point: &Point) -> Expression {
+        let dx = difference(&self.end.x, &self.start.x);
+        let dy = difference(&self.end.y, &self.start.y);
+        let along = Expression::add(vec![
+            difference(&point.x, &self.start.x) * dx,
+            difference(&point.y, &self.start.y) * dy,
+        ]);
+        quotient(along, self.start.squared_distance(&self.end))
+    }
+
+    /// Whether a point on the segment's line may lie within the segment:
+    /// `false` only when it is known to lie outside
+    pub(crate) fn may_contain_on_line(&self, point: &Point) -> bool {
+        let t = self.parameter_of(point);
+        let below = sign_of(&t) == Some(Ordering::Less);
+        let above = sign_of(&difference(&Expression::integer(1), &t)) == Some(Ordering::Less);
+        !(below || above)
+    }
+
+    /// Whether `point` is known to lie on the segment
+    pub fn contains(&self, point: &Point) -> bool {
+        if !is_zero(&cross(&self.start, &self.end, point)) {
+            return false;
+        }
+        let t = self.parameter_of(point);
+        matches!(sign_of(&t), Some(Ordering::Greater | Ordering::Equal))
+            && matches!(
+                sign_of(&difference(&Expression::integer(1), &t)),
+                Some(Ordering::Greater | Ordering::Equal)
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{expr, symbol};
+
+    fn p(x: i64, y: i64) -> Point {
+        Point::new(Expression::integer(x), Expression::integer(y))
+    }
+
+    #[test]
+    fn test_line_relations() {
+        let l1 = Line::through(&p(0, 0), &p(1, 1)).unwrap();
+        let l2 = Line::through(&p(0, 1), &p(1, 2)).unwrap();
+        let l3 = Line::through(&p(0, 2), &p(2, 0)).unwrap();
+        assert!(l1.is_parallel(&l2));
+        assert!(!l1.coincides(&l2));
+        assert!(l1.is_perpendicular(&l3));
+        assert!(l1.perpendicular_through(&p(0, 2)).coincides(&l3));
+        assert!(Line::through(&p(1, 1), &p(1, 1)).is_err());
+    }
+
+    #[test]
+    fn test_from_equation() {
+        let x = symbol!(x);
+        let y = symbol!(y);
+        let line = Line::from_equation(&expr!((2 * x) - y - 3), &x, &y).unwrap();
+        assert_eq!(line.slope(), Some(expr!(2)));
+        assert!(line.contains(&p(2, 1)));
+        assert!(Line::from_equation(&expr!((x ^ 2) + y), &x, &y).is_err());
+    }
+}
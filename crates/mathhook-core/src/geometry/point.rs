@@ -0,0 +1,119 @@
+//! Points in the plane
+
+use super::{difference, is_zero, quotient, square};
+use crate::core::Expression;
+use crate::simplify::Simplify;
+use serde::{Deserialize, Serialize};
+
+/// A point with exact (possibly symbolic) coordinates
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::geometry::Point;
+/// use mathhook_core::Expression;
+///
+/// let p = Point::new(Expression::integer(3), Expression::integer(4));
+/// assert_eq!(p.distance(&Point::origin()), Expression::integer(5));
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Point {
+    pub x: Expression,
+    pub y: Expression,
+}
+
+impl Point {
+    pub fn new(x: Expression, y: Expression) -> Self {
+        Self { x, y }
+    }
+
+    /// The point (0, 0)
+    pub fn origin() -> Self {
+        Self::new(Expression::integer(0), Expression::integer(0))
+    }
+
+    /// Point with polar coordinates (`radius`, `angle`)
+    pub fn from_polar(radius: &Expression, angle: &Expression) -> Self {
+        Self::new(
+            (radius.clone() * Expression::function("cos", vec![angle.clone()])).simplify(),
+            (radius.clone() * Expression::function("sin", vec![angle.clone()])).simplify(),
+        )
+    }
+
+    /// Euclidean distance to `other`
+    pub fn distance(&self, other: &Point) -> Expression {
+        Expression::sqrt(self.squared_distance(other)).simplify()
+    }
+
+    /// Squared distance to `other`, which avoids the square root
+    pub fn squared_distance(&self, other: &Point) -> Expression {
+        let dx = difference(&other.x, &self.x);
+        let dy = difference(&other.y, &self.y);
+        Expression::add(vec![square(&dx), square(&dy)]).simplify()
+    }
+
+    /// Point halfway to `other`
+    pub fn midpoint(&self, other: &Point) -> Point {
+        let half = |a: &Expression, b: &Expression| {
+            quotient(
+                Expression::add(vec![a.clone(), b.clone()]),
+                Expression::integer(2),
+            )
+        };
+        Point::new(half(&self.x, &other.x), half(&self.y, &other.y))
+    }
+
+    /// Point moved by (`dx`, `dy`)
+    pub fn translate(&self, dx: &Expression, dy: &Expression) -> Point {
+        Point::new(
+            Expression::add(vec![self.x.clone(), dx.clone()]).simplify(),
+            Expression::add(vec![self.y.clone(), dy.clone()]).simplify(),
+        )
+    }
+
+    /// Whether both coordinates are known to agree with `other`
+    pub fn coincides(&self, other: &Point) -> bool {
+        is_zero(&difference(&self.x, &other.x)) && is_zero(&difference(&self.y, &other.y))
+    }
+
+    /// Whether the three points are known to lie on one line
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::geometry::Point;
+    /// use mathhook_core::Expression;
+    ///
+    /// let p = |x, y| Point::new(Expression::integer(x), Expression::integer(y));
+    /// assert!(Point::are_collinear(&p(0, 0), &p(1, 2), &p(2, 4)));
+    /// assert!(!Point::are_collinear(&p(0, 0), &p(1, 2), &p(2, 5)));
+    /// ```
+    pub fn are_collinear(a: &Point, b: &Point, c: &Point) -> bool {
+        is_zero(&cross(a, b, c))
+    }
+}
+
+/// z-component of `(b - a) × (c - a)`: twice the signed area of the triangle
+pub(crate) fn cross(a: &Point, b: &Point, c: &Point) -> Expression {
+    let abx = difference(&b.x, &a.x);
+    let aby = difference(&b.y, &a.y);
+    let acx = difference(&c.x, &a.x);
+    let acy = difference(&c.y, &a.y);
+    (abx * acy - aby * acx).simplify()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr;
+
+    #[test]
+    fn test_symbolic_distance_and_midpoint() {
+        let p = Point::new(expr!(a), expr!(0));
+        let q = Point::new(expr!(0), expr!(0));
+        assert_eq!(p.squared_distance(&q), expr!(a ^ 2));
+
+        let m = Point::new(expr!(2), expr!(4)).midpoint(&Point::origin());
+        assert!(m.coincides(&Point::new(expr!(1), expr!(2))));
+    }
+}
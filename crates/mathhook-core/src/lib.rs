@@ -7,15 +7,19 @@ extern crate self as mathhook_core;
 
 pub mod algebra;
 pub mod calculus;
+pub mod codegen;
 pub mod core;
+pub mod discover;
 pub mod educational;
 pub mod error;
 pub mod formatter;
 pub mod functions;
+pub mod geometry;
 pub mod macros;
 pub mod matrices;
 pub mod parser;
 pub mod pattern;
+pub mod random;
 pub mod serialize;
 pub mod simplify;
 pub mod solvers;
@@ -23,7 +27,11 @@ pub mod solvers;
 // Curated re-exports (no wildcards for maintainability)
 
 // Macro re-exports (at crate root for backward compatibility)
-pub use mathhook_macros::{expr, function, symbol, symbols};
+pub use mathhook_macros::{expr, function, math_function, matrix, rules, symbol, symbols};
+
+// Used by `#[math_function]` to submit definitions at link time
+#[doc(hidden)]
+pub use inventory;
 
 // Core types (most commonly used)
 pub use core::expression::eval_numeric::{EvalContext, EvalNumeric};
@@ -241,7 +249,7 @@ mod tests {
     #[test]
     fn test_procedural_function_macro() {
         let gamma_call = function!(gamma);
-        if let Expression::Function { name, args } = &gamma_call {
+        if let Expression::Function { name, args, .. } = &gamma_call {
             assert_eq!(name.as_ref(), "gamma");
             assert_eq!(args.len(), 0);
         } else {
@@ -250,7 +258,7 @@ mod tests {
 
         let x = expr!(x);
         let sin_x = function!(sin, x.clone());
-        if let Expression::Function { name, args } = &sin_x {
+        if let Expression::Function { name, args, .. } = &sin_x {
             assert_eq!(name.as_ref(), "sin");
             assert_eq!(args.len(), 1);
         } else {
@@ -259,7 +267,7 @@ mod tests {
 
         let y = expr!(y);
         let log_xy = function!(log, x, y);
-        if let Expression::Function { name, args } = &log_xy {
+        if let Expression::Function { name, args, .. } = &log_xy {
             assert_eq!(name.as_ref(), "log");
             assert_eq!(args.len(), 2);
         } else {
@@ -3,6 +3,7 @@
 //! This module provides declarative macros that make mathematical expression
 //! construction more readable and concise.
 
+pub mod constants;
 pub mod expressions;
 pub mod parsing;
 pub mod symbols;
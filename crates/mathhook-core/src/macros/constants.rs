@@ -0,0 +1,29 @@
+//! Physical constant macros
+//!
+//! Inserts constants from [`crate::core::constants::physical`] into
+//! expressions by their usual symbol.
+
+/// A physical constant as a symbolic expression
+///
+/// The constant stays symbolic until
+/// [`Expression::substitute_physical_constants`](crate::Expression::substitute_physical_constants)
+/// replaces it by its value.
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::{const_expr, Expression};
+///
+/// // E = m c²
+/// let energy = Expression::mul(vec![
+///     Expression::symbol("m"),
+///     Expression::pow(const_expr!(c), Expression::integer(2)),
+/// ]);
+/// assert!(energy.to_string().contains('c'));
+/// ```
+#[macro_export]
+macro_rules! const_expr {
+    ($name:ident) => {
+        $crate::core::constants::physical::by_symbol::$name.expression()
+    };
+}
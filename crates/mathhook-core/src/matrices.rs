@@ -15,10 +15,14 @@
 //! - `operations` - High-level operations for Expression integration
 //! - `decomposition` - Matrix decomposition algorithms (LU, QR, Cholesky, SVD)
 //! - `eigenvalues` - Eigenvalue computation and matrix functions
+//! - `graph` - Adjacency matrices, graph spectra, walks and Laplacians
+//! - `sparse` - Compressed sparse row matrices for large banded systems
 
 pub mod decomposition;
 pub mod eigenvalues;
+pub mod graph;
 pub mod operations;
+pub mod sparse;
 pub mod types;
 pub mod unified;
 
@@ -29,6 +33,8 @@ pub mod inverse_tests;
 // Re-exports for clean API
 pub use decomposition::MatrixDecomposition;
 pub use eigenvalues::EigenOperations;
+pub use graph::Graph;
 pub use operations::MatrixOperations;
+pub use sparse::SparseMatrix;
 pub use types::*;
 pub use unified::{CoreMatrixOps, Matrix};
@@ -2,11 +2,12 @@
 //!
 //! This module provides methods for computing eigenvalues and eigenvectors
 //! of matrices, including both real and complex cases, characteristic polynomials,
-//! and matrix functions using eigendecomposition.
+//! matrix functions using eigendecomposition, and Jordan normal forms.
 
 pub mod characteristic;
 pub mod computation;
 pub mod eigenvalues_tests;
+pub mod jordan;
 pub mod power_methods;
 
 use crate::core::expression::Expression;
@@ -69,7 +70,10 @@ pub trait EigenOperations {
     /// ```
     fn eigenvalues(&self) -> Vec<Expression>;
 
-    /// Compute characteristic polynomial det(A - λI)
+    /// Compute the monic characteristic polynomial det(λI - A)
+    ///
+    /// Computed symbolically with the Berkowitz algorithm, so it works for
+    /// matrices with symbolic entries.
     ///
     /// # Examples
     ///
@@ -88,6 +92,31 @@ pub trait EigenOperations {
     /// ```
     fn characteristic_polynomial(&self) -> CharacteristicPolynomial;
 
+    /// Exact eigenvalues with algebraic multiplicities, for triangular
+    /// matrices and matrices up to 4×4
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mathhook_core::matrices::Matrix;
+    /// use mathhook_core::matrices::eigenvalues::EigenOperations;
+    /// use mathhook_core::{expr, Expression};
+    ///
+    /// let matrix = Matrix::dense(vec![
+    ///     vec![expr!(a), expr!(b)],
+    ///     vec![expr!(b), expr!(a)],
+    /// ]);
+    /// let eigenvalues = EigenOperations::exact_eigenvalues(&matrix).unwrap();
+    /// assert_eq!(eigenvalues.len(), 2);
+    /// ```
+    fn exact_eigenvalues(&self) -> Option<Vec<(Expression, usize)>>;
+
+    /// Basis of the null space of A - λI for the given eigenvalue
+    fn eigenspace(&self, eigenvalue: &Expression) -> Vec<Vec<Expression>>;
+
+    /// Exact eigenvalues with multiplicities and eigenvector bases
+    fn eigenvectors(&self) -> Option<Vec<EigenSpace>>;
+
     /// Get the trace (sum of eigenvalues)
     fn trace(&self) -> Expression;
 
@@ -150,38 +179,20 @@ impl EigenOperations for Matrix {
     }
 
     fn characteristic_polynomial(&self) -> CharacteristicPolynomial {
-        // Construct characteristic polynomial from eigenvalues
-        // For a matrix with eigenvalues λ₁, λ₂, ..., λₙ:
-        // char_poly(λ) = (λ - λ₁)(λ - λ₂)...(λ - λₙ)
-        let eigenvals = Matrix::eigenvalues(self);
-        let lambda = Symbol::scalar("lambda");
+        CharacteristicPolynomial::from_matrix(self, Symbol::scalar("lambda"))
+    }
 
-        if eigenvals.is_empty() {
-            return CharacteristicPolynomial::new(vec![Expression::integer(0)], lambda);
-        }
+    /// @no-binding - returns (eigenvalue, multiplicity) pairs
+    fn exact_eigenvalues(&self) -> Option<Vec<(Expression, usize)>> {
+        Matrix::exact_eigenvalues(self)
+    }
 
-        // Start with (λ - λ₁)
-        let mut poly = CharacteristicPolynomial::new(
-            vec![
-                Expression::mul(vec![Expression::integer(-1), eigenvals[0].clone()]),
-                Expression::integer(1),
-            ],
-            lambda.clone(),
-        );
-
-        // Multiply by (λ - λᵢ) for remaining eigenvalues
-        for eigenval in eigenvals.iter().skip(1) {
-            let factor = CharacteristicPolynomial::new(
-                vec![
-                    Expression::mul(vec![Expression::integer(-1), eigenval.clone()]),
-                    Expression::integer(1),
-                ],
-                lambda.clone(),
-            );
-            poly = poly.multiply(&factor);
-        }
+    fn eigenspace(&self, eigenvalue: &Expression) -> Vec<Vec<Expression>> {
+        Matrix::eigenspace(self, eigenvalue)
+    }
 
-        poly
+    fn eigenvectors(&self) -> Option<Vec<EigenSpace>> {
+        Matrix::eigenvectors(self)
     }
 
     fn trace(&self) -> Expression {
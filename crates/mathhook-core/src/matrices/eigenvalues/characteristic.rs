@@ -1,5 +1,9 @@
+use crate::algebra::solvers::{EquationSolver, PolynomialSolver, SolverResult};
+use crate::algebra::Expand;
 use crate::core::expression::Expression;
 use crate::core::symbol::Symbol;
+use crate::core::Number;
+use crate::matrices::unified::Matrix;
 use crate::simplify::Simplify;
 
 /// Characteristic polynomial of a matrix
@@ -80,6 +84,83 @@ impl CharacteristicPolynomial {
         }
     }
 
+    /// Characteristic polynomial det(λI - A) of a square matrix
+    ///
+    /// Uses the division-free Berkowitz algorithm, so symbolic entries are
+    /// never divided by and the coefficients stay polynomial in the entries.
+    /// The result is monic: its leading coefficient is 1 and its constant
+    /// term is (-1)^n det(A).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::{expr, symbol};
+    /// use mathhook_core::matrices::Matrix;
+    /// use mathhook_core::matrices::eigenvalues::characteristic::CharacteristicPolynomial;
+    ///
+    /// let matrix = Matrix::from_arrays([[2, 1], [1, 2]]);
+    /// let poly = CharacteristicPolynomial::from_matrix(&matrix, symbol!(lambda));
+    ///
+    /// // λ² - 4λ + 3
+    /// assert_eq!(poly.coefficients, vec![expr!(3), expr!(-4), expr!(1)]);
+    /// ```
+    pub fn from_matrix(matrix: &Matrix, variable: Symbol) -> Self {
+        let (n, _) = matrix.dimensions();
+        let entry = |i: usize, j: usize| matrix.get_element(i, j);
+
+        // Coefficients of the trailing principal submatrix, highest power first
+        let mut vector = vec![Expression::integer(1)];
+        for k in (0..n).rev() {
+            let size = n - k;
+
+            // Toeplitz column: 1, -a, -R·C, -R·A·C, ..., -R·A^(size-2)·C
+            let mut diagonals = vec![
+                Expression::integer(1),
+                Expression::mul(vec![Expression::integer(-1), entry(k, k)]),
+            ];
+            let mut column: Vec<Expression> = (k + 1..n).map(|i| entry(i, k)).collect();
+            for step in 0..size.saturating_sub(1) {
+                if step > 0 {
+                    column = (k + 1..n)
+                        .map(|i| {
+                            Expression::add(
+                                (k + 1..n)
+                                    .zip(&column)
+                                    .map(|(j, c)| Expression::mul(vec![entry(i, j), c.clone()]))
+                                    .collect(),
+                            )
+                            .simplify()
+                        })
+                        .collect();
+                }
+                let product = Expression::add(
+                    (k + 1..n)
+                        .zip(&column)
+                        .map(|(j, c)| Expression::mul(vec![entry(k, j), c.clone()]))
+                        .collect(),
+                );
+                diagonals.push(Expression::mul(vec![Expression::integer(-1), product]).simplify());
+            }
+
+            vector = (0..=size)
+                .map(|i| {
+                    Expression::add(
+                        (0..=i.min(size - 1))
+                            .map(|j| {
+                                Expression::mul(vec![diagonals[i - j].clone(), vector[j].clone()])
+                            })
+                            .collect(),
+                    )
+                    .expand()
+                    .simplify()
+                })
+                .collect();
+        }
+
+        vector.reverse();
+        Self::new(vector, variable)
+    }
+
     /// Returns the degree of the polynomial
     ///
     /// # Examples
@@ -316,6 +397,157 @@ impl CharacteristicPolynomial {
         CharacteristicPolynomial::new(result_coeffs, self.variable.clone())
     }
 
+    /// Exact roots with their multiplicities
+    ///
+    /// Zero roots and integer roots are divided out first, trying the
+    /// candidates found by [`PolynomialSolver`] and the divisors of the
+    /// constant term. Once the remaining factor has degree at most two it is
+    /// solved in closed form. Returns `None` when a factor of degree three or
+    /// more is left that has no such root.
+    ///
+    /// @no-binding - returns (root, multiplicity) pairs
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::{expr, symbol};
+    /// use mathhook_core::matrices::eigenvalues::characteristic::CharacteristicPolynomial;
+    ///
+    /// // (λ - 1)²(λ + 4) = λ³ + 2λ² - 7λ + 4
+    /// let poly = CharacteristicPolynomial::new(
+    ///     vec![expr!(4), expr!(-7), expr!(2), expr!(1)],
+    ///     symbol!(lambda),
+    /// );
+    ///
+    /// let roots = poly.roots().unwrap();
+    /// assert!(roots.contains(&(expr!(1), 2)));
+    /// assert!(roots.contains(&(expr!(-4), 1)));
+    /// ```
+    pub fn roots(&self) -> Option<Vec<(Expression, usize)>> {
+        let mut remaining = self.trimmed();
+        let mut roots: Vec<(Expression, usize)> = Vec::new();
+        let mut record = |root: Expression| match roots.iter_mut().find(|(r, _)| *r == root) {
+            Some((_, multiplicity)) => *multiplicity += 1,
+            None => roots.push((root, 1)),
+        };
+
+        while remaining.degree() > 0 && remaining.coefficients[0].is_zero() {
+            remaining.coefficients.remove(0);
+            record(Expression::integer(0));
+        }
+
+        while remaining.degree() > 2 {
+            let root = remaining
+                .root_candidates()
+                .into_iter()
+                .find(|candidate| remaining.evaluate(candidate).simplify().is_zero())?;
+            remaining = remaining.deflate(&root);
+            record(root);
+        }
+
+        let coefficients = &remaining.coefficients;
+        match remaining.degree() {
+            1 => record(
+                Expression::mul(vec![
+                    Expression::integer(-1),
+                    coefficients[0].clone(),
+                    Expression::pow(coefficients[1].clone(), Expression::integer(-1)),
+                ])
+                .simplify(),
+            ),
+            2 => {
+                // λ = (-b ± √(b² - 4ac)) / 2a
+                let (c, b, a) = (&coefficients[0], &coefficients[1], &coefficients[2]);
+                let discriminant = Expression::add(vec![
+                    Expression::pow(b.clone(), Expression::integer(2)),
+                    Expression::mul(vec![Expression::integer(-4), a.clone(), c.clone()]),
+                ])
+                .expand()
+                .simplify();
+                let square_root = Expression::sqrt(discriminant.clone()).simplify();
+                let half = Expression::pow(
+                    Expression::mul(vec![Expression::integer(2), a.clone()]),
+                    Expression::integer(-1),
+                );
+                let root = |sign: i64| {
+                    Expression::mul(vec![
+                        Expression::add(vec![
+                            Expression::mul(vec![Expression::integer(-1), b.clone()]),
+                            Expression::mul(vec![Expression::integer(sign), square_root.clone()]),
+                        ]),
+                        half.clone(),
+                    ])
+                    .simplify()
+                };
+                if discriminant.is_zero() {
+                    let double = root(1);
+                    record(double.clone());
+                    record(double);
+                } else {
+                    record(root(1));
+                    record(root(-1));
+                }
+            }
+            _ => {}
+        }
+
+        Some(roots)
+    }
+
+    /// Copy without vanishing leading coefficients
+    fn trimmed(&self) -> CharacteristicPolynomial {
+        let mut coefficients: Vec<Expression> =
+            self.coefficients.iter().map(|c| c.simplify()).collect();
+        while coefficients.len() > 1 && coefficients.last().is_some_and(Expression::is_zero) {
+            coefficients.pop();
+        }
+        CharacteristicPolynomial::new(coefficients, self.variable.clone())
+    }
+
+    /// Possible roots: the solver's answers, then the integer divisors of
+    /// the constant term when all coefficients are integers
+    fn root_candidates(&self) -> Vec<Expression> {
+        let solver = PolynomialSolver::new();
+        let mut candidates = match solver.solve(&self.to_expression(), &self.variable) {
+            SolverResult::Single(root) => vec![root],
+            SolverResult::Multiple(roots) | SolverResult::Partial(roots) => roots,
+            _ => Vec::new(),
+        };
+
+        let integers: Option<Vec<i64>> = self
+            .coefficients
+            .iter()
+            .map(|c| match c {
+                Expression::Number(Number::Integer(value)) => Some(*value),
+                _ => None,
+            })
+            .collect();
+        if let Some(integers) = integers {
+            for divisor in solver.get_divisors(integers[0]) {
+                candidates.push(Expression::integer(divisor));
+                candidates.push(Expression::integer(-divisor));
+            }
+        }
+        candidates
+    }
+
+    /// Quotient by (λ - root), by synthetic division
+    fn deflate(&self, root: &Expression) -> CharacteristicPolynomial {
+        let n = self.degree();
+        let mut quotient = vec![Expression::integer(0); n];
+        let mut carry = self.coefficients[n].clone();
+        for k in (0..n).rev() {
+            quotient[k] = carry.clone();
+            carry = Expression::add(vec![
+                self.coefficients[k].clone(),
+                Expression::mul(vec![root.clone(), carry]),
+            ])
+            .expand()
+            .simplify();
+        }
+        CharacteristicPolynomial::new(quotient, self.variable.clone())
+    }
+
     /// Formats polynomial as human-readable string
     ///
     /// # Examples
@@ -530,6 +762,56 @@ mod tests {
         assert!(formatted.contains(poly.variable.name.as_ref()));
     }
 
+    #[test]
+    fn test_from_matrix_berkowitz() {
+        let lambda = symbol!(lambda);
+        let matrix = Matrix::from_arrays([[2, 0, 0], [1, 3, 0], [4, 5, 6]]);
+        let poly = CharacteristicPolynomial::from_matrix(&matrix, lambda.clone());
+        // (λ - 2)(λ - 3)(λ - 6) = λ³ - 11λ² + 36λ - 36
+        assert_eq!(
+            poly.coefficients,
+            vec![expr!(-36), expr!(36), expr!(-11), expr!(1)]
+        );
+
+        let symbolic = Matrix::dense(vec![vec![expr!(a), expr!(b)], vec![expr!(c), expr!(d)]]);
+        let poly = CharacteristicPolynomial::from_matrix(&symbolic, lambda);
+        assert_eq!(poly.coefficients[2], expr!(1));
+        let trace = Expression::add(vec![poly.coefficients[1].clone(), expr!(a + d)]);
+        assert!(trace.simplify().is_zero());
+        let det = Expression::add(vec![
+            poly.coefficients[0].clone(),
+            Expression::mul(vec![expr!(-1), expr!(a * d)]),
+            expr!(b * c),
+        ]);
+        assert!(det.simplify().is_zero());
+    }
+
+    #[test]
+    fn test_roots_of_quartic_and_irreducible_cubic() {
+        let lambda = symbol!(lambda);
+        // λ(λ - 5)(λ² - 2) = λ⁴ - 5λ³ - 2λ² + 10λ
+        let poly = CharacteristicPolynomial::new(
+            vec![expr!(0), expr!(10), expr!(-2), expr!(-5), expr!(1)],
+            lambda.clone(),
+        );
+        let roots = poly.roots().unwrap();
+        assert_eq!(roots.len(), 4);
+        assert!(roots.contains(&(expr!(0), 1)));
+        assert!(roots.contains(&(expr!(5), 1)));
+        for (root, _) in &roots {
+            assert!(
+                poly.evaluate(root).expand().simplify().is_zero(),
+                "{}",
+                root
+            );
+        }
+
+        // λ³ - 2 has no rational root
+        let cubic =
+            CharacteristicPolynomial::new(vec![expr!(-2), expr!(0), expr!(0), expr!(1)], lambda);
+        assert!(cubic.roots().is_none());
+    }
+
     #[test]
     fn test_builder_add() {
         let lambda = symbol!(lambda);
@@ -3,11 +3,20 @@
 //! This module provides core algorithms for computing eigenvalues and eigenvectors
 //! of matrices, including both real and complex cases.
 
-use crate::core::Expression;
+use crate::core::expression::eval_numeric::EvalNumeric;
+use crate::core::{Expression, Symbol};
+use crate::matrices::eigenvalues::characteristic::CharacteristicPolynomial;
 use crate::matrices::types::*;
 use crate::matrices::unified::Matrix;
 use crate::simplify::Simplify;
 
+/// Largest dimension for which eigenvalues are found exactly
+const MAX_EXACT_EIGEN_SIZE: usize = 4;
+
+/// Magnitude below which a constant pivot is treated as zero, for entries
+/// like `1 - (5 + √33)/2 - ...` that simplification cannot cancel
+const PIVOT_TOLERANCE: f64 = 1e-10;
+
 impl Matrix {
     /// Compute eigenvalues and eigenvectors
     ///
@@ -134,8 +143,161 @@ impl Matrix {
         }
     }
 
+    /// Exact eigenvalues with their algebraic multiplicities
+    ///
+    /// Triangular matrices read them off the diagonal. Otherwise the roots of
+    /// the characteristic polynomial are found exactly, which is attempted
+    /// for matrices up to 4×4. Returns `None` for larger or non-square
+    /// matrices and when the polynomial has a factor of degree three or more
+    /// without rational roots.
+    ///
+    /// @no-binding - returns (eigenvalue, multiplicity) pairs
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mathhook_core::matrices::Matrix;
+    /// use mathhook_core::Expression;
+    ///
+    /// let matrix = Matrix::from_arrays([[2, 1], [1, 2]]);
+    /// let eigenvalues = matrix.exact_eigenvalues().unwrap();
+    /// assert!(eigenvalues.contains(&(Expression::integer(1), 1)));
+    /// assert!(eigenvalues.contains(&(Expression::integer(3), 1)));
+    /// ```
+    pub fn exact_eigenvalues(&self) -> Option<Vec<(Expression, usize)>> {
+        let (n, cols) = self.dimensions();
+        if n != cols || n == 0 {
+            return None;
+        }
+
+        if self.is_triangular() {
+            let mut eigenvalues: Vec<(Expression, usize)> = Vec::new();
+            for i in 0..n {
+                let value = self.get_element(i, i).simplify();
+                match eigenvalues.iter_mut().find(|(v, _)| *v == value) {
+                    Some((_, multiplicity)) => *multiplicity += 1,
+                    None => eigenvalues.push((value, 1)),
+                }
+            }
+            return Some(eigenvalues);
+        }
+
+        if n > MAX_EXACT_EIGEN_SIZE {
+            return None;
+        }
+        CharacteristicPolynomial::from_matrix(self, Symbol::scalar("lambda")).roots()
+    }
+
+    /// Basis of the eigenspace of `eigenvalue`, the null space of A - λI
+    ///
+    /// Each vector has a 1 in one free coordinate. The basis is empty when
+    /// `eigenvalue` is not an eigenvalue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mathhook_core::matrices::Matrix;
+    /// use mathhook_core::Expression;
+    ///
+    /// let matrix = Matrix::from_arrays([[2, 1], [1, 2]]);
+    /// let basis = matrix.eigenspace(&Expression::integer(3));
+    /// assert_eq!(basis, vec![vec![Expression::integer(1), Expression::integer(1)]]);
+    /// ```
+    pub fn eigenspace(&self, eigenvalue: &Expression) -> Vec<Vec<Expression>> {
+        let (n, _) = self.dimensions();
+        null_space(self.shifted_rows(eigenvalue), n)
+    }
+
+    /// Rows of A - λI
+    pub(crate) fn shifted_rows(&self, eigenvalue: &Expression) -> Vec<Vec<Expression>> {
+        let (n, _) = self.dimensions();
+        (0..n)
+            .map(|i| {
+                (0..n)
+                    .map(|j| {
+                        let entry = self.get_element(i, j);
+                        if i == j {
+                            Expression::add(vec![
+                                entry,
+                                Expression::mul(vec![Expression::integer(-1), eigenvalue.clone()]),
+                            ])
+                            .simplify()
+                        } else {
+                            entry
+                        }
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Exact eigenvalues with multiplicities and eigenspace bases
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mathhook_core::matrices::Matrix;
+    ///
+    /// // Jordan block: eigenvalue 2 twice, one eigenvector
+    /// let matrix = Matrix::from_arrays([[2, 1], [0, 2]]);
+    /// let spaces = matrix.eigenvectors().unwrap();
+    /// assert_eq!(spaces.len(), 1);
+    /// assert_eq!(spaces[0].multiplicity, 2);
+    /// assert_eq!(spaces[0].basis.len(), 1);
+    /// ```
+    pub fn eigenvectors(&self) -> Option<Vec<EigenSpace>> {
+        Some(
+            self.exact_eigenvalues()?
+                .into_iter()
+                .map(|(eigenvalue, multiplicity)| EigenSpace {
+                    basis: self.eigenspace(&eigenvalue),
+                    eigenvalue,
+                    multiplicity,
+                })
+                .collect(),
+        )
+    }
+
+    /// Eigendecomposition from exact eigenvalues and eigenvectors
+    ///
+    /// Eigenvalues are repeated by multiplicity. For a defective matrix the
+    /// eigenvector matrix has fewer columns than rows.
+    pub(crate) fn exact_eigen_decomposition(&self) -> Option<EigenDecomposition> {
+        let spaces = self.eigenvectors()?;
+        let (n, _) = self.dimensions();
+        let eigenvalues = spaces
+            .iter()
+            .flat_map(|space| vec![space.eigenvalue.clone(); space.multiplicity])
+            .collect();
+        let columns: Vec<&Vec<Expression>> = spaces.iter().flat_map(|space| &space.basis).collect();
+        let eigenvectors = (0..n)
+            .map(|i| columns.iter().map(|column| column[i].clone()).collect())
+            .collect();
+        Some(EigenDecomposition {
+            eigenvalues,
+            eigenvectors: Matrix::dense(eigenvectors),
+        })
+    }
+
+    /// Whether all entries on one side of the diagonal are zero
+    fn is_triangular(&self) -> bool {
+        let (n, _) = self.dimensions();
+        match self {
+            Matrix::UpperTriangular(_) | Matrix::LowerTriangular(_) => true,
+            _ => {
+                let below = (0..n).all(|i| (0..i).all(|j| self.get_element(i, j).is_zero()));
+                let above = (0..n).all(|i| (i + 1..n).all(|j| self.get_element(i, j).is_zero()));
+                below || above
+            }
+        }
+    }
+
     /// Compute general eigenvalues for arbitrary matrices
     pub(crate) fn compute_general_eigenvalues(&self) -> Option<EigenDecomposition> {
+        if let Some(exact) = self.exact_eigen_decomposition() {
+            return Some(exact);
+        }
+
         let (n, _) = self.dimensions();
 
         // For small matrices, use direct computation
@@ -230,6 +392,12 @@ impl Matrix {
             Matrix::Identity(_) | Matrix::Zero(_) | Matrix::Scalar(_) | Matrix::Diagonal(_) => true,
             Matrix::Symmetric(_) => true, // Symmetric matrices are always diagonalizable
             _ => {
+                if let Some(spaces) = self.eigenvectors() {
+                    return spaces
+                        .iter()
+                        .all(|space| space.basis.len() == space.multiplicity);
+                }
+
                 // Check if matrix is diagonalizable by examining eigenvalue multiplicities
                 let eigenvals = self.eigenvalues();
                 if eigenvals.len() <= 1 {
@@ -355,3 +523,71 @@ impl Matrix {
         value.is_zero() || *value == *tolerance
     }
 }
+
+/// Zero after simplification, or numerically zero when constant
+fn is_negligible(entry: &Expression) -> bool {
+    entry.is_zero()
+        || (entry.find_variables().is_empty()
+            && entry
+                .eval_complex()
+                .is_ok_and(|value| value.re.abs() + value.im.abs() < PIVOT_TOLERANCE))
+}
+
+/// Reduce `rows` to reduced row echelon form, returning the pivot columns
+pub(crate) fn row_reduce(rows: &mut [Vec<Expression>], cols: usize) -> Vec<usize> {
+    let mut pivots = Vec::new();
+    for col in 0..cols {
+        let rank = pivots.len();
+        let Some(pivot) = (rank..rows.len()).find(|&i| !is_negligible(&rows[i][col])) else {
+            continue;
+        };
+        rows.swap(rank, pivot);
+        let inverse = Expression::pow(rows[rank][col].clone(), Expression::integer(-1));
+        rows[rank] = rows[rank]
+            .iter()
+            .map(|entry| Expression::mul(vec![entry.clone(), inverse.clone()]).simplify())
+            .collect();
+        for i in (0..rows.len()).filter(|&i| i != rank) {
+            if rows[i][col].is_zero() {
+                continue;
+            }
+            let factor = Expression::mul(vec![Expression::integer(-1), rows[i][col].clone()]);
+            rows[i] = rows[i]
+                .iter()
+                .zip(&rows[rank])
+                .map(|(entry, pivot_entry)| {
+                    Expression::add(vec![
+                        entry.clone(),
+                        Expression::mul(vec![factor.clone(), pivot_entry.clone()]),
+                    ])
+                    .simplify()
+                })
+                .collect();
+        }
+        pivots.push(col);
+    }
+    pivots
+}
+
+/// Basis of the vectors `x` with `rows · x = 0`, one per free column
+pub(crate) fn null_space(mut rows: Vec<Vec<Expression>>, cols: usize) -> Vec<Vec<Expression>> {
+    let pivots = row_reduce(&mut rows, cols);
+    (0..cols)
+        .filter(|col| !pivots.contains(col))
+        .map(|free| {
+            let mut vector = vec![Expression::integer(0); cols];
+            vector[free] = Expression::integer(1);
+            for (row, &col) in pivots.iter().enumerate() {
+                vector[col] =
+                    Expression::mul(vec![Expression::integer(-1), rows[row][free].clone()])
+                        .simplify();
+            }
+            vector
+        })
+        .collect()
+}
+
+/// Number of linearly independent rows
+pub(crate) fn rank(rows: &[Vec<Expression>], cols: usize) -> usize {
+    row_reduce(&mut rows.to_vec(), cols).len()
+}
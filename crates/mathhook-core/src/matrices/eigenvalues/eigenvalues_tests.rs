@@ -5,9 +5,12 @@
 
 #[cfg(test)]
 mod tests {
+    use crate::core::expression::eval_numeric::EvalNumeric;
     use crate::core::Expression;
+    use crate::expr;
     use crate::matrices::eigenvalues::EigenOperations;
     use crate::matrices::Matrix;
+    use crate::simplify::Simplify;
 
     /// Test eigenvalue computation for diagonal matrices
     #[test]
@@ -334,4 +337,81 @@ mod tests {
         let complex_eigen = diagonal.complex_eigen_decomposition();
         assert!(complex_eigen.is_none());
     }
+
+    /// Test exact eigenvalues and eigenvectors of general matrices
+    #[test]
+    fn test_exact_eigenvectors() {
+        let matrix = Matrix::from_arrays([[4, 1, 2], [0, 3, 0], [1, 0, 3]]);
+        let spaces = matrix.eigenvectors().unwrap();
+        let total: usize = spaces.iter().map(|space| space.multiplicity).sum();
+        assert_eq!(total, 3);
+
+        for space in &spaces {
+            assert_eq!(space.basis.len(), 1);
+            let vector = &space.basis[0];
+            for i in 0..3 {
+                let residual = Expression::add(
+                    (0..3)
+                        .map(|j| Expression::mul(vec![matrix.get_element(i, j), vector[j].clone()]))
+                        .chain(std::iter::once(Expression::mul(vec![
+                            Expression::integer(-1),
+                            space.eigenvalue.clone(),
+                            vector[i].clone(),
+                        ])))
+                        .collect(),
+                );
+                assert!(
+                    residual.simplify().is_zero(),
+                    "{} for {}",
+                    residual,
+                    space.eigenvalue
+                );
+            }
+        }
+        assert!(matrix.is_diagonalizable());
+    }
+
+    /// Test eigenvalues of a symbolic matrix and an irrational spectrum
+    #[test]
+    fn test_symbolic_eigenvalues() {
+        let symbolic = Matrix::dense(vec![vec![expr!(a), expr!(b)], vec![expr!(b), expr!(a)]]);
+        let eigenvalues = symbolic.exact_eigenvalues().unwrap();
+        assert_eq!(eigenvalues.len(), 2);
+        let sum = Expression::add(eigenvalues.iter().map(|(value, _)| value.clone()).collect());
+        let trace = Expression::add(vec![sum, expr!(-2 * a)]);
+        assert!(trace.simplify().is_zero());
+
+        let irrational = Matrix::from_arrays([[1, 2], [3, 4]]);
+        let eigen = irrational.eigen_decomposition().unwrap();
+        assert_eq!(eigen.eigenvalues.len(), 2);
+        assert_eq!(eigen.eigenvectors.dimensions(), (2, 2));
+        for (k, eigenvalue) in eigen.eigenvalues.iter().enumerate() {
+            for i in 0..2 {
+                let residual = Expression::add(vec![
+                    Expression::mul(vec![
+                        irrational.get_element(i, 0),
+                        eigen.eigenvectors.get_element(0, k),
+                    ]),
+                    Expression::mul(vec![
+                        irrational.get_element(i, 1),
+                        eigen.eigenvectors.get_element(1, k),
+                    ]),
+                    Expression::mul(vec![
+                        Expression::integer(-1),
+                        eigenvalue.clone(),
+                        eigen.eigenvectors.get_element(i, k),
+                    ]),
+                ]);
+                assert!(
+                    residual.eval_complex().unwrap().norm() < 1e-9,
+                    "{}",
+                    residual
+                );
+            }
+        }
+
+        let jordan = Matrix::from_arrays([[5, 1, 0], [0, 5, 1], [0, 0, 5]]);
+        assert!(!jordan.is_diagonalizable());
+        assert_eq!(jordan.eigenvectors().unwrap()[0].basis.len(), 1);
+    }
 }
@@ -0,0 +1,446 @@
+//! Jordan normal form and functions of matrices
+//!
+//! A square matrix whose eigenvalues are known exactly is similar to a block
+//! diagonal matrix J = P⁻¹AP of Jordan blocks. A function f of a Jordan block
+//! of size s at eigenvalue λ is upper triangular with f⁽ʲ⁾(λ)/j! on its j-th
+//! superdiagonal, so f(A) = P f(J) P⁻¹ needs only the derivatives of f at the
+//! eigenvalues. This gives exponentials, powers and logarithms of matrices
+//! that are not diagonalizable.
+
+use super::computation::{null_space, rank, row_reduce};
+use crate::core::Expression;
+use crate::matrices::unified::Matrix;
+use crate::simplify::Simplify;
+
+/// Jordan block J_size(eigenvalue)
+#[derive(Debug, Clone, PartialEq)]
+pub struct JordanBlock {
+    pub eigenvalue: Expression,
+    pub size: usize,
+}
+
+impl Matrix {
+    /// Jordan normal form as (P, J) with A = P J P⁻¹
+    ///
+    /// Blocks for the same eigenvalue are ordered from largest to smallest,
+    /// with ones on the superdiagonal. Returns `None` when the eigenvalues
+    /// cannot be found exactly (see [`Matrix::exact_eigenvalues`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mathhook_core::matrices::Matrix;
+    /// use mathhook_core::Expression;
+    ///
+    /// let matrix = Matrix::from_arrays([[3, 1], [-1, 1]]);
+    /// let (_, j) = matrix.jordan_form().unwrap();
+    ///
+    /// // Eigenvalue 2 twice with a single eigenvector
+    /// assert_eq!(j.get_element(0, 0), Expression::integer(2));
+    /// assert_eq!(j.get_element(0, 1), Expression::integer(1));
+    /// assert_eq!(j.get_element(1, 1), Expression::integer(2));
+    /// ```
+    pub fn jordan_form(&self) -> Option<(Matrix, Matrix)> {
+        let (p, blocks) = self.jordan_blocks()?;
+        let n = blocks.iter().map(|block| block.size).sum();
+        let mut rows = vec![vec![Expression::integer(0); n]; n];
+        let mut offset = 0;
+        for block in &blocks {
+            for i in 0..block.size {
+                rows[offset + i][offset + i] = block.eigenvalue.clone();
+                if i + 1 < block.size {
+                    rows[offset + i][offset + i + 1] = Expression::integer(1);
+                }
+            }
+            offset += block.size;
+        }
+        Some((p, Matrix::dense(rows)))
+    }
+
+    /// Transformation matrix P and the Jordan blocks along the diagonal of J
+    ///
+    /// The columns of P belonging to a block form a Jordan chain
+    /// (A - λI)^(s-1)v, ..., (A - λI)v, v.
+    ///
+    /// @no-binding - use `jordan_form`
+    pub fn jordan_blocks(&self) -> Option<(Matrix, Vec<JordanBlock>)> {
+        let (n, _) = self.dimensions();
+        let mut columns: Vec<Vec<Expression>> = Vec::with_capacity(n);
+        let mut blocks = Vec::new();
+
+        for (eigenvalue, multiplicity) in self.exact_eigenvalues()? {
+            let shifted = self.shifted_rows(&eigenvalue);
+
+            // kernels[k] is a basis of ker (A - λI)^k
+            let mut kernels: Vec<Vec<Vec<Expression>>> = vec![Vec::new()];
+            let mut power = shifted.clone();
+            loop {
+                let kernel = null_space(power.clone(), n);
+                if kernel.len() <= kernels[kernels.len() - 1].len() {
+                    return None;
+                }
+                let complete = kernel.len() >= multiplicity;
+                kernels.push(kernel);
+                if complete {
+                    break;
+                }
+                power = multiply_rows(&power, &shifted);
+            }
+
+            // Chain heads, longest chains first: vectors of ker B^k that are
+            // independent of ker B^(k-1) and of the longer chains
+            let mut heads: Vec<(Vec<Expression>, usize)> = Vec::new();
+            for k in (1..kernels.len()).rev() {
+                let mut span = kernels[k - 1].clone();
+                for (head, size) in &heads {
+                    let mut image = head.clone();
+                    for _ in k..*size {
+                        image = apply_rows(&shifted, &image);
+                    }
+                    span.push(image);
+                }
+                let mut dimension = rank(&span, n);
+                for candidate in &kernels[k] {
+                    span.push(candidate.clone());
+                    let extended = rank(&span, n);
+                    if extended > dimension {
+                        dimension = extended;
+                        heads.push((candidate.clone(), k));
+                    } else {
+                        span.pop();
+                    }
+                }
+            }
+
+            for (head, size) in heads {
+                let mut chain = vec![head];
+                for _ in 1..size {
+                    let next = apply_rows(&shifted, &chain[chain.len() - 1]);
+                    chain.push(next);
+                }
+                chain.reverse();
+                columns.extend(chain);
+                blocks.push(JordanBlock {
+                    eigenvalue: eigenvalue.clone(),
+                    size,
+                });
+            }
+        }
+
+        if columns.len() != n {
+            return None;
+        }
+        let p = (0..n)
+            .map(|i| columns.iter().map(|column| column[i].clone()).collect())
+            .collect();
+        Some((Matrix::dense(p), blocks))
+    }
+
+    /// f(A) = P f(J) P⁻¹ from the derivatives of f at the eigenvalues
+    ///
+    /// `derivative(λ, j)` returns the j-th derivative of f at λ, or `None`
+    /// where f is not defined.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mathhook_core::matrices::Matrix;
+    /// use mathhook_core::Expression;
+    ///
+    /// // f(x) = x² through its derivatives 2x and 2
+    /// let matrix = Matrix::from_arrays([[1, 1], [0, 1]]);
+    /// let square = matrix
+    ///     .apply_function(|lambda, j| match j {
+    ///         0 => Some(Expression::pow(lambda.clone(), Expression::integer(2))),
+    ///         1 => Some(Expression::mul(vec![Expression::integer(2), lambda.clone()])),
+    ///         2 => Some(Expression::integer(2)),
+    ///         _ => Some(Expression::integer(0)),
+    ///     })
+    ///     .unwrap();
+    /// assert_eq!(square.get_element(0, 1), Expression::integer(2));
+    /// ```
+    pub fn apply_function(
+        &self,
+        derivative: impl Fn(&Expression, usize) -> Option<Expression>,
+    ) -> Option<Matrix> {
+        let (p, blocks) = self.jordan_blocks()?;
+        let (n, _) = self.dimensions();
+
+        let mut function_of_j = vec![vec![Expression::integer(0); n]; n];
+        let mut offset = 0;
+        for block in &blocks {
+            let mut factorial = 1i64;
+            for j in 0..block.size {
+                if j > 0 {
+                    factorial *= j as i64;
+                }
+                let value = Expression::mul(vec![
+                    derivative(&block.eigenvalue, j)?,
+                    Expression::rational(1, factorial),
+                ])
+                .simplify();
+                for i in 0..block.size - j {
+                    function_of_j[offset + i][offset + i + j] = value.clone();
+                }
+            }
+            offset += block.size;
+        }
+
+        let p_rows: Vec<Vec<Expression>> = (0..n)
+            .map(|i| (0..n).map(|j| p.get_element(i, j)).collect())
+            .collect();
+        let p_inverse = inverse_rows(&p_rows)?;
+        let result = multiply_rows(&multiply_rows(&p_rows, &function_of_j), &p_inverse);
+        Some(Matrix::dense(result))
+    }
+
+    /// Matrix exponential e^A
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mathhook_core::matrices::Matrix;
+    /// use mathhook_core::Expression;
+    ///
+    /// // A nilpotent matrix: e^A = I + A
+    /// let matrix = Matrix::from_arrays([[0, 1], [0, 0]]);
+    /// let exp = matrix.exp().unwrap();
+    /// assert_eq!(exp.get_element(0, 0), Expression::integer(1));
+    /// assert_eq!(exp.get_element(0, 1), Expression::integer(1));
+    /// assert_eq!(exp.get_element(1, 0), Expression::integer(0));
+    /// ```
+    pub fn exp(&self) -> Option<Matrix> {
+        self.apply_function(|lambda, _| Some(Expression::function("exp", vec![lambda.clone()])))
+    }
+
+    /// Matrix exponential e^(At) for a scalar `t`
+    ///
+    /// Each derivative of e^(λt) with respect to λ brings down a factor t, so
+    /// this is exact for defective matrices as well.
+    pub fn exp_scaled(&self, t: &Expression) -> Option<Matrix> {
+        self.apply_function(|lambda, j| {
+            Some(Expression::mul(vec![
+                Expression::pow(t.clone(), Expression::integer(j as i64)),
+                Expression::function(
+                    "exp",
+                    vec![Expression::mul(vec![lambda.clone(), t.clone()])],
+                ),
+            ]))
+        })
+    }
+
+    /// Matrix power A^k for a symbolic or non-integer exponent
+    ///
+    /// Uses d^j/dλ^j λ^k = k(k-1)...(k-j+1) λ^(k-j). Returns `None` for
+    /// negative integer powers of a singular matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mathhook_core::matrices::Matrix;
+    /// use mathhook_core::{expr, Expression};
+    ///
+    /// let matrix = Matrix::from_arrays([[1, 1], [0, 1]]);
+    /// let power = matrix.pow(&expr!(k)).unwrap();
+    /// assert_eq!(power.get_element(0, 1), expr!(k));
+    /// assert_eq!(power.get_element(1, 1), Expression::integer(1));
+    /// ```
+    pub fn pow(&self, exponent: &Expression) -> Option<Matrix> {
+        let negative = exponent.evaluate_to_f64().is_ok_and(|value| value < 0.0);
+        self.apply_function(|lambda, j| {
+            if lambda.is_zero() && negative {
+                return None;
+            }
+            let falling = (0..j as i64)
+                .map(|i| Expression::add(vec![exponent.clone(), Expression::integer(-i)]))
+                .collect::<Vec<_>>();
+            Some(Expression::mul(
+                falling
+                    .into_iter()
+                    .chain(std::iter::once(Expression::pow(
+                        lambda.clone(),
+                        Expression::add(vec![exponent.clone(), Expression::integer(-(j as i64))]),
+                    )))
+                    .collect(),
+            ))
+        })
+    }
+
+    /// Principal matrix logarithm
+    ///
+    /// Uses d^j/dλ^j ln λ = (-1)^(j-1) (j-1)! / λ^j. Returns `None` for
+    /// singular matrices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mathhook_core::matrices::Matrix;
+    /// use mathhook_core::{expr, Expression};
+    ///
+    /// let matrix = Matrix::from_arrays([[1, 1], [0, 1]]);
+    /// let log = matrix.log().unwrap();
+    /// assert_eq!(log.get_element(0, 0), Expression::integer(0));
+    /// assert_eq!(log.get_element(0, 1), Expression::integer(1));
+    ///
+    /// assert!(Matrix::from_arrays([[0, 1], [0, 0]]).log().is_none());
+    /// ```
+    pub fn log(&self) -> Option<Matrix> {
+        self.apply_function(|lambda, j| {
+            if lambda.is_zero() {
+                return None;
+            }
+            if j == 0 {
+                return Some(Expression::function("ln", vec![lambda.clone()]));
+            }
+            let sign = if j % 2 == 1 { 1 } else { -1 };
+            let factorial: i64 = (1..j as i64).product();
+            Some(Expression::mul(vec![
+                Expression::integer(sign * factorial),
+                Expression::pow(lambda.clone(), Expression::integer(-(j as i64))),
+            ]))
+        })
+    }
+}
+
+/// Product of two matrices given as rows, with simplified entries
+fn multiply_rows(left: &[Vec<Expression>], right: &[Vec<Expression>]) -> Vec<Vec<Expression>> {
+    left.iter()
+        .map(|row| {
+            (0..right.first().map_or(0, Vec::len))
+                .map(|j| {
+                    Expression::add(
+                        row.iter()
+                            .zip(right)
+                            .map(|(entry, right_row)| {
+                                Expression::mul(vec![entry.clone(), right_row[j].clone()])
+                            })
+                            .collect(),
+                    )
+                    .simplify()
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Matrix-vector product
+fn apply_rows(rows: &[Vec<Expression>], vector: &[Expression]) -> Vec<Expression> {
+    rows.iter()
+        .map(|row| {
+            Expression::add(
+                row.iter()
+                    .zip(vector)
+                    .map(|(entry, component)| {
+                        Expression::mul(vec![entry.clone(), component.clone()])
+                    })
+                    .collect(),
+            )
+            .simplify()
+        })
+        .collect()
+}
+
+/// Exact inverse by Gauss-Jordan elimination on [P | I]
+fn inverse_rows(rows: &[Vec<Expression>]) -> Option<Vec<Vec<Expression>>> {
+    let n = rows.len();
+    let mut augmented: Vec<Vec<Expression>> = rows
+        .iter()
+        .enumerate()
+        .map(|(i, row)| {
+            let mut extended = row.clone();
+            extended.extend((0..n).map(|j| Expression::integer(i64::from(i == j))));
+            extended
+        })
+        .collect();
+    let pivots = row_reduce(&mut augmented, n);
+    if pivots.len() != n {
+        return None;
+    }
+    Some(augmented.into_iter().map(|row| row[n..].to_vec()).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::expression::eval_numeric::EvalNumeric;
+    use crate::matrices::unified::CoreMatrixOps;
+    use crate::{expr, symbol};
+    use std::collections::HashMap;
+
+    fn assert_same(left: &Matrix, right: &Matrix) {
+        let (n, m) = left.dimensions();
+        for i in 0..n {
+            for j in 0..m {
+                let difference = Expression::add(vec![
+                    left.get_element(i, j),
+                    Expression::mul(vec![Expression::integer(-1), right.get_element(i, j)]),
+                ]);
+                assert!(
+                    difference.simplify().is_zero(),
+                    "({}, {}): {}",
+                    i,
+                    j,
+                    difference
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_jordan_form_reconstructs_matrix() {
+        let matrix =
+            Matrix::from_arrays([[5, 4, 2, 1], [0, 1, -1, -1], [-1, -1, 3, 0], [1, 1, -1, 2]]);
+        let (p, j) = matrix.jordan_form().unwrap();
+
+        // Eigenvalues 1, 2 and a block of size 2 at 4
+        let diagonal: Vec<Expression> = (0..4).map(|i| j.get_element(i, i)).collect();
+        assert_eq!(diagonal.iter().filter(|v| **v == expr!(4)).count(), 2);
+        let superdiagonal = (0..3)
+            .filter(|&i| j.get_element(i, i + 1) == expr!(1))
+            .count();
+        assert_eq!(superdiagonal, 1);
+
+        let left = matrix.multiply(&p).unwrap();
+        let right = p.multiply(&j).unwrap();
+        assert_same(&left, &right);
+    }
+
+    #[test]
+    fn test_functions_of_jordan_block() {
+        let matrix = Matrix::from_arrays([[2, 1, 0], [0, 2, 1], [0, 0, 2]]);
+
+        let cube = matrix.pow(&expr!(3)).unwrap();
+        let expected = matrix.multiply(&matrix).unwrap().multiply(&matrix).unwrap();
+        assert_same(&cube, &expected);
+
+        let inverse = matrix.pow(&expr!(-1)).unwrap();
+        assert_same(&inverse.multiply(&matrix).unwrap(), &Matrix::identity(3));
+
+        // log then exp round trip, compared numerically
+        let round_trip = matrix.log().unwrap().exp().unwrap();
+        for i in 0..3 {
+            for k in 0..3 {
+                let value = round_trip.get_element(i, k).eval_complex().unwrap();
+                let target = matrix.get_element(i, k).evaluate_to_f64().unwrap();
+                assert!((value.re - target).abs() < 1e-9, "({}, {})", i, k);
+            }
+        }
+    }
+
+    #[test]
+    fn test_exp_scaled_of_defective_matrix() {
+        let t = symbol!(t);
+        let matrix = Matrix::from_arrays([[3, 1], [-1, 1]]);
+        let exp = matrix.exp_scaled(&Expression::symbol(t.clone())).unwrap();
+
+        let at_zero = HashMap::from([(t.name().to_owned(), expr!(0))]);
+        for i in 0..2 {
+            for k in 0..2 {
+                let value = exp.get_element(i, k).substitute(&at_zero).simplify();
+                assert_eq!(value, Expression::integer(i64::from(i == k)));
+            }
+        }
+        assert!(matrix.jordan_form().is_some());
+        assert!(Matrix::from_arrays([[0, 1], [0, 0]]).log().is_none());
+    }
+}
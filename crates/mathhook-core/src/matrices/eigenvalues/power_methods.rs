@@ -48,9 +48,7 @@ impl Matrix {
             ) {
                 Some(d_n)
             } else {
-                // For general matrices, would need to compute P^(-1) and multiply P * D^n * P^(-1)
-                // Return diagonal power for general matrices (P ≈ I approximation)
-                Some(d_n)
+                self.pow(&Expression::integer(n))
             }
         } else {
             None
@@ -147,8 +145,7 @@ impl Matrix {
             ) {
                 Some(exp_d)
             } else {
-                // For general matrices, would need P^(-1)
-                Some(exp_d)
+                self.exp()
             }
         } else {
             None
@@ -197,8 +194,7 @@ impl Matrix {
             ) {
                 Some(log_d)
             } else {
-                // For general matrices, would need P^(-1)
-                Some(log_d)
+                self.log()
             }
         } else {
             None
@@ -242,8 +238,7 @@ impl Matrix {
             ) {
                 Some(sqrt_d)
             } else {
-                // For general matrices, would need P^(-1)
-                Some(sqrt_d)
+                self.pow(&Expression::rational(1, 2))
             }
         } else {
             None
@@ -0,0 +1,542 @@
+//! Spectral and algebraic graph theory on adjacency matrices
+//!
+//! A [`Graph`] wraps a square adjacency matrix whose entries may be integers
+//! (edge multiplicities) or arbitrary expressions (symbolic edge weights).
+//! All computations are exact: products are simplified entry by entry
+//! rather than routed through floating point, so walk counts stay integers
+//! and the characteristic polynomial keeps its symbolic coefficients.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use mathhook_core::matrices::Graph;
+//! use mathhook_core::{expr, symbol, Expression};
+//!
+//! // Triangle K3: characteristic polynomial λ³ - 3λ - 2
+//! let triangle = Graph::from_edges(3, &[(0, 1), (1, 2), (0, 2)]);
+//! let lambda = symbol!(lambda);
+//! let poly = triangle.characteristic_polynomial(&lambda);
+//! assert_eq!(poly.coefficients, vec![expr!(-2), expr!(-3), expr!(0), expr!(1)]);
+//!
+//! // Closed walks of length 3 from a vertex go around the triangle both ways
+//! assert_eq!(triangle.walk_count(0, 0, 3), Expression::integer(2));
+//! ```
+
+use super::eigenvalues::characteristic::CharacteristicPolynomial;
+use super::Matrix;
+use crate::algebra::solvers::completing_square::{as_rational, from_rational};
+use crate::algebra::solvers::{
+    EquationSolver, PolynomialSolver, QuadraticMethod, QuadraticSolver, RootSet, SolverResult,
+};
+use crate::core::{Expression, Symbol};
+use crate::error::MathError;
+use crate::simplify::Simplify;
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_rational::BigRational;
+use num_traits::{One, ToPrimitive, Zero};
+
+/// Graph given by its adjacency matrix
+///
+/// Entry `(i, j)` is the number (or weight) of edges from vertex `i` to
+/// vertex `j`. Undirected graphs have symmetric adjacency matrices.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Graph {
+    pub adjacency: Matrix,
+}
+
+impl Graph {
+    /// Graph with the given adjacency matrix
+    ///
+    /// # Errors
+    ///
+    /// Returns `MathError::DomainError` if the matrix is not square.
+    pub fn from_adjacency(adjacency: Matrix) -> Result<Self, MathError> {
+        if !adjacency.is_square() {
+            let (rows, cols) = adjacency.dimensions();
+            return Err(MathError::DomainError {
+                operation: "graph".to_owned(),
+                value: Expression::function("adjacency_matrix", vec![]),
+                reason: format!("Adjacency matrix must be square, got {}x{}", rows, cols),
+            });
+        }
+        Ok(Self { adjacency })
+    }
+
+    /// Undirected graph on `order` vertices with the given edges
+    ///
+    /// Repeated edges add up, and a loop `(i, i)` adds one to the diagonal.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::matrices::Graph;
+    /// use mathhook_core::Expression;
+    ///
+    /// let path = Graph::from_edges(3, &[(0, 1), (1, 2)]);
+    /// assert_eq!(path.adjacency.get_element(1, 0), Expression::integer(1));
+    /// assert_eq!(path.adjacency.get_element(0, 2), Expression::integer(0));
+    /// ```
+    pub fn from_edges(order: usize, edges: &[(usize, usize)]) -> Self {
+        let mut counts = vec![vec![0i64; order]; order];
+        for &(i, j) in edges {
+            counts[i][j] += 1;
+            if i != j {
+                counts[j][i] += 1;
+            }
+        }
+        Self::from_counts(counts)
+    }
+
+    /// Directed graph on `order` vertices with edges `from -> to`
+    pub fn from_directed_edges(order: usize, edges: &[(usize, usize)]) -> Self {
+        let mut counts = vec![vec![0i64; order]; order];
+        for &(from, to) in edges {
+            counts[from][to] += 1;
+        }
+        Self::from_counts(counts)
+    }
+
+    fn from_counts(counts: Vec<Vec<i64>>) -> Self {
+        let order = counts.len();
+        Self {
+            adjacency: Matrix::from_fn(order, order, |i, j| Expression::integer(counts[i][j])),
+        }
+    }
+
+    /// Number of vertices
+    pub fn order(&self) -> usize {
+        self.adjacency.dimensions().0
+    }
+
+    /// Whether every edge goes both ways with the same weight
+    pub fn is_undirected(&self) -> bool {
+        let n = self.order();
+        (0..n).all(|i| {
+            (0..i).all(|j| {
+                let difference = Expression::add(vec![
+                    self.adjacency.get_element(i, j),
+                    Expression::mul(vec![
+                        Expression::integer(-1),
+                        self.adjacency.get_element(j, i),
+                    ]),
+                ]);
+                difference.simplify().is_zero()
+            })
+        })
+    }
+
+    /// Row sums of the adjacency matrix: the (out-)degree of each vertex
+    pub fn degrees(&self) -> Vec<Expression> {
+        let n = self.order();
+        (0..n)
+            .map(|i| {
+                Expression::add((0..n).map(|j| self.adjacency.get_element(i, j)).collect())
+                    .simplify()
+            })
+            .collect()
+    }
+
+    /// Diagonal matrix of vertex degrees
+    pub fn degree_matrix(&self) -> Matrix {
+        Matrix::diagonal(self.degrees())
+    }
+
+    /// Laplacian `L = D - A` of degree matrix `D` and adjacency matrix `A`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::matrices::Graph;
+    /// use mathhook_core::Expression;
+    ///
+    /// let path = Graph::from_edges(3, &[(0, 1), (1, 2)]);
+    /// let laplacian = path.laplacian();
+    /// assert_eq!(laplacian.get_element(1, 1), Expression::integer(2));
+    /// assert_eq!(laplacian.get_element(0, 1), Expression::integer(-1));
+    /// ```
+    pub fn laplacian(&self) -> Matrix {
+        let degrees = self.degrees();
+        let n = self.order();
+        Matrix::from_fn(n, n, |i, j| {
+            let negated = Expression::mul(vec![
+                Expression::integer(-1),
+                self.adjacency.get_element(i, j),
+            ]);
+            if i == j {
+                Expression::add(vec![degrees[i].clone(), negated]).simplify()
+            } else {
+                negated.simplify()
+            }
+        })
+    }
+
+    /// Characteristic polynomial `det(λI - A)` of the graph
+    ///
+    /// This is the monic convention of spectral graph theory; it differs from
+    /// [`EigenOperations::characteristic_polynomial`](super::EigenOperations::characteristic_polynomial)
+    /// by the sign `(-1)ⁿ`. Coefficients come from the Faddeev-LeVerrier
+    /// recurrence, so they are exact for symbolic weights too. The
+    /// coefficient of `λⁿ⁻²` is minus the number of edges of a simple graph,
+    /// and that of `λⁿ⁻³` is minus twice the number of triangles.
+    pub fn characteristic_polynomial(&self, variable: &Symbol) -> CharacteristicPolynomial {
+        CharacteristicPolynomial::new(
+            characteristic_coefficients(&self.adjacency),
+            variable.clone(),
+        )
+    }
+
+    /// Eigenvalues of the adjacency matrix with their multiplicities
+    ///
+    /// Rational eigenvalues are divided out of the characteristic polynomial
+    /// one at a time; a remaining factor of degree at most two is solved
+    /// exactly by completing the square. Roots of a higher-degree remainder
+    /// that the polynomial solver cannot find are missing from the set;
+    /// compare [`RootSet::total_multiplicity`] with [`Graph::order`] to
+    /// detect this.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::matrices::Graph;
+    /// use mathhook_core::Expression;
+    ///
+    /// let triangle = Graph::from_edges(3, &[(0, 1), (1, 2), (0, 2)]);
+    /// let spectrum = triangle.spectrum();
+    /// assert_eq!(spectrum.total_multiplicity(), 3);
+    /// assert!(spectrum
+    ///     .roots
+    ///     .iter()
+    ///     .any(|root| root.value == Expression::integer(-1) && root.multiplicity == 2));
+    /// ```
+    pub fn spectrum(&self) -> RootSet {
+        let lambda = Symbol::scalar("λ");
+        let polynomial = self.characteristic_polynomial(&lambda);
+
+        let mut eigenvalues = Vec::new();
+        let remainder = match polynomial
+            .coefficients
+            .iter()
+            .map(as_rational)
+            .collect::<Option<Vec<_>>>()
+        {
+            Some(mut coefficients) => {
+                for root in divide_out_rational_roots(&mut coefficients) {
+                    eigenvalues.push(from_rational(root));
+                }
+                coefficients.into_iter().map(from_rational).collect()
+            }
+            None => polynomial.coefficients.clone(),
+        };
+
+        let remainder = CharacteristicPolynomial::new(remainder, lambda.clone());
+        let expression = remainder.to_expression().simplify();
+        let solutions = match remainder.degree() {
+            0 => SolverResult::NoSolution,
+            1 => SolverResult::Single(
+                Expression::mul(vec![
+                    Expression::integer(-1),
+                    remainder.coefficients[0].clone(),
+                    Expression::pow(remainder.coefficients[1].clone(), Expression::integer(-1)),
+                ])
+                .simplify(),
+            ),
+            2 => QuadraticSolver::new()
+                .with_method(QuadraticMethod::CompletingSquare)
+                .solve(&expression, &lambda),
+            _ => PolynomialSolver::new().solve(&expression, &lambda),
+        };
+        match solutions {
+            SolverResult::Single(root) => eigenvalues.push(root),
+            SolverResult::Multiple(roots) | SolverResult::Partial(roots) => {
+                eigenvalues.extend(roots)
+            }
+            _ => {}
+        }
+
+        SolverResult::Multiple(eigenvalues).roots(&polynomial.to_expression(), &lambda)
+    }
+
+    /// `A^length`, whose entry `(i, j)` counts the walks of that length from
+    /// `i` to `j`
+    pub fn walks(&self, length: u32) -> Matrix {
+        let mut result = Matrix::identity(self.order());
+        let mut base = self.adjacency.clone();
+        let mut exponent = length;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = multiply(&result, &base);
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                base = multiply(&base, &base);
+            }
+        }
+        result
+    }
+
+    /// Number of walks of `length` edges from vertex `from` to vertex `to`
+    ///
+    /// With symbolic weights this is the sum over walks of the product of
+    /// their edge weights.
+    pub fn walk_count(&self, from: usize, to: usize, length: u32) -> Expression {
+        self.walks(length).get_element(from, to)
+    }
+
+    /// Total number of closed walks of `length` edges, `tr(A^length)`
+    pub fn closed_walk_count(&self, length: u32) -> Expression {
+        self.walks(length).trace().simplify()
+    }
+
+    /// Number of spanning trees by Kirchhoff's matrix-tree theorem
+    ///
+    /// Any cofactor of the Laplacian; the one deleting the first row and
+    /// column is used, with its determinant computed exactly. The graph with
+    /// no vertices has none.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::matrices::Graph;
+    /// use mathhook_core::Expression;
+    ///
+    /// // Cayley: K4 has 4^2 = 16 spanning trees
+    /// let k4 = Graph::from_edges(4, &[(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]);
+    /// assert_eq!(k4.spanning_tree_count(), Expression::integer(16));
+    /// ```
+    pub fn spanning_tree_count(&self) -> Expression {
+        let n = self.order();
+        if n <= 1 {
+            return Expression::integer(n as i64);
+        }
+        let laplacian = self.laplacian();
+        let reduced = Matrix::from_fn(n - 1, n - 1, |i, j| laplacian.get_element(i + 1, j + 1));
+        // det(R) = (-1)^(n-1) c₀ for the characteristic polynomial of R
+        let constant = characteristic_coefficients(&reduced).swap_remove(0);
+        let sign = if n.is_multiple_of(2) { -1 } else { 1 };
+        Expression::mul(vec![Expression::integer(sign), constant]).simplify()
+    }
+}
+
+/// Coefficients of `det(λI - matrix)`, lowest degree first, by the
+/// Faddeev-LeVerrier recurrence
+fn characteristic_coefficients(matrix: &Matrix) -> Vec<Expression> {
+    let n = matrix.dimensions().0;
+    let mut coefficients = vec![Expression::integer(0); n + 1];
+    coefficients[n] = Expression::integer(1);
+
+    // A₁ = I, c_{n-k} = -tr(M Aₖ)/k, A_{k+1} = M Aₖ + c_{n-k} I
+    let mut a = Matrix::identity(n);
+    for k in 1..=n {
+        let product = multiply(matrix, &a);
+        let coefficient =
+            Expression::mul(vec![Expression::rational(-1, k as i64), product.trace()]).simplify();
+        a = Matrix::from_fn(n, n, |i, j| {
+            let entry = product.get_element(i, j);
+            if i == j {
+                Expression::add(vec![entry, coefficient.clone()]).simplify()
+            } else {
+                entry
+            }
+        });
+        coefficients[n - k] = coefficient;
+    }
+    coefficients
+}
+
+/// Exact product with every entry simplified
+///
+/// `CoreMatrixOps::multiply` goes through floating point for numeric
+/// matrices, which would turn walk counts into approximations.
+fn multiply(left: &Matrix, right: &Matrix) -> Matrix {
+    let n = left.dimensions().0;
+    Matrix::from_fn(n, n, |i, j| {
+        Expression::add(
+            (0..n)
+                .map(|k| Expression::mul(vec![left.get_element(i, k), right.get_element(k, j)]))
+                .collect(),
+        )
+        .simplify()
+    })
+}
+
+/// Divide every rational root out of `coefficients` (ascending, highest
+/// last), returning the roots with repetition
+///
+/// Candidates come from the rational root theorem applied to the polynomial
+/// scaled to integer coefficients.
+fn divide_out_rational_roots(coefficients: &mut Vec<BigRational>) -> Vec<BigRational> {
+    let mut roots = Vec::new();
+    while coefficients.len() > 1 && coefficients[0].is_zero() {
+        coefficients.remove(0);
+        roots.push(BigRational::zero());
+    }
+    if coefficients.len() <= 1 {
+        return roots;
+    }
+
+    let denominators = coefficients
+        .iter()
+        .fold(BigInt::one(), |lcm, c| lcm.lcm(c.denom()));
+    let scaled =
+        |c: &BigRational| (c * BigRational::from_integer(denominators.clone())).to_integer();
+    let (Some(constant), Some(leading)) = (
+        scaled(&coefficients[0]).to_i64(),
+        scaled(&coefficients[coefficients.len() - 1]).to_i64(),
+    ) else {
+        return roots;
+    };
+
+    for p in divisors(constant) {
+        for q in divisors(leading) {
+            for sign in [1, -1] {
+                let candidate = BigRational::new(BigInt::from(sign * p), BigInt::from(q));
+                while coefficients.len() > 1 {
+                    match divide_by_root(coefficients, &candidate) {
+                        Some(quotient) => {
+                            *coefficients = quotient;
+                            roots.push(candidate.clone());
+                        }
+                        None => break,
+                    }
+                }
+            }
+        }
+    }
+    roots
+}
+
+/// Quotient by `(λ - root)` when the division is exact
+fn divide_by_root(coefficients: &[BigRational], root: &BigRational) -> Option<Vec<BigRational>> {
+    // Synthetic division from the leading coefficient down
+    let mut quotient = vec![BigRational::zero(); coefficients.len() - 1];
+    let mut carry = BigRational::zero();
+    for i in (1..coefficients.len()).rev() {
+        carry = &coefficients[i] + &carry * root;
+        quotient[i - 1] = carry.clone();
+    }
+    (&coefficients[0] + &carry * root)
+        .is_zero()
+        .then_some(quotient)
+}
+
+fn divisors(n: i64) -> Vec<i64> {
+    let n = n.unsigned_abs() as i64;
+    let mut divisors: Vec<i64> = (1..=n)
+        .take_while(|d| d * d <= n)
+        .filter(|d| n % d == 0)
+        .flat_map(|d| [d, n / d])
+        .collect();
+    divisors.sort_unstable();
+    divisors.dedup();
+    divisors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{expr, symbol};
+
+    fn cycle(order: usize) -> Graph {
+        let edges: Vec<_> = (0..order).map(|i| (i, (i + 1) % order)).collect();
+        Graph::from_edges(order, &edges)
+    }
+
+    #[test]
+    fn test_rejects_non_square_matrix() {
+        assert!(Graph::from_adjacency(Matrix::zero(2, 3)).is_err());
+        assert!(Graph::from_adjacency(Matrix::zero(3, 3)).is_ok());
+    }
+
+    #[test]
+    fn test_characteristic_polynomial_of_path() {
+        // P3: λ³ - 2λ, spectrum 0, ±√2
+        let path = Graph::from_edges(3, &[(0, 1), (1, 2)]);
+        let lambda = symbol!(lambda);
+        let poly = path.characteristic_polynomial(&lambda);
+        assert_eq!(
+            poly.coefficients,
+            vec![expr!(0), expr!(-2), expr!(0), expr!(1)]
+        );
+
+        let spectrum = path.spectrum();
+        assert_eq!(spectrum.total_multiplicity(), 3);
+        assert!(spectrum.roots.iter().all(|root| root.exact));
+        let mut values: Vec<f64> = spectrum.roots.iter().filter_map(|r| r.numeric).collect();
+        values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let root2 = 2f64.sqrt();
+        assert_eq!(values.len(), 3);
+        for (value, expected) in values.iter().zip([-root2, 0.0, root2]) {
+            assert!((value - expected).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_characteristic_polynomial_counts_edges_and_triangles() {
+        // K4 has 6 edges and 4 triangles: λ⁴ - 6λ² - 8λ - 3
+        let k4 = Graph::from_edges(4, &[(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]);
+        let poly = k4.characteristic_polynomial(&symbol!(lambda));
+        assert_eq!(
+            poly.coefficients,
+            vec![expr!(-3), expr!(-8), expr!(-6), expr!(0), expr!(1)]
+        );
+    }
+
+    #[test]
+    fn test_symbolic_weights() {
+        // Single edge of weight a: λ² - a²
+        let weighted = Graph::from_adjacency(Matrix::dense(vec![
+            vec![expr!(0), expr!(a)],
+            vec![expr!(a), expr!(0)],
+        ]))
+        .unwrap();
+        let poly = weighted.characteristic_polynomial(&symbol!(lambda));
+        assert_eq!(poly.coefficients[0], expr!(-(a ^ 2)).simplify());
+        assert_eq!(poly.coefficients[1], expr!(0));
+        assert_eq!(weighted.walk_count(0, 0, 4), expr!(a ^ 4));
+        assert_eq!(weighted.degrees(), vec![expr!(a), expr!(a)]);
+    }
+
+    #[test]
+    fn test_walk_counts() {
+        // C4 is bipartite: no odd closed walks, 2^(k-1) even walks between opposite vertices
+        let square = cycle(4);
+        assert_eq!(square.walk_count(0, 0, 3), expr!(0));
+        assert_eq!(square.walk_count(0, 2, 2), expr!(2));
+        assert_eq!(square.walk_count(0, 2, 4), expr!(8));
+        assert_eq!(square.walk_count(0, 1, 1), expr!(1));
+        assert_eq!(square.walks(0), Matrix::identity(4));
+
+        // tr(A³) = 6 × number of triangles
+        let triangle = cycle(3);
+        assert_eq!(triangle.closed_walk_count(3), expr!(6));
+    }
+
+    #[test]
+    fn test_directed_walks() {
+        // 0 -> 1 -> 2: a single walk of length 2, none back
+        let chain = Graph::from_directed_edges(3, &[(0, 1), (1, 2)]);
+        assert!(!chain.is_undirected());
+        assert_eq!(chain.walk_count(0, 2, 2), expr!(1));
+        assert_eq!(chain.walk_count(2, 0, 2), expr!(0));
+        assert_eq!(chain.degrees(), vec![expr!(1), expr!(1), expr!(0)]);
+    }
+
+    #[test]
+    fn test_laplacian_and_spanning_trees() {
+        let square = cycle(4);
+        assert!(square.is_undirected());
+        let laplacian = square.laplacian();
+        for i in 0..4 {
+            assert_eq!(laplacian.get_element(i, i), expr!(2));
+            let row: Vec<_> = (0..4).map(|j| laplacian.get_element(i, j)).collect();
+            assert_eq!(Expression::add(row).simplify(), expr!(0));
+        }
+        assert_eq!(square.degree_matrix(), Matrix::diagonal(vec![expr!(2); 4]));
+
+        // A cycle on n vertices has n spanning trees; a tree has exactly one
+        assert_eq!(square.spanning_tree_count(), expr!(4));
+        let path = Graph::from_edges(3, &[(0, 1), (1, 2)]);
+        assert_eq!(path.spanning_tree_count(), expr!(1));
+    }
+}
@@ -8,7 +8,7 @@ use std::sync::Arc;
 use super::{CoreMatrixOps, Matrix};
 use crate::core::Expression;
 use crate::core::Number;
-use crate::simplify::Simplify;
+use crate::simplify::{Simplify, SimplifyDomain};
 
 /// High-level matrix operations trait for Expression
 ///
@@ -70,6 +70,11 @@ pub trait MatrixOperations {
     fn is_diagonal(&self) -> bool;
 
     /// Simplify matrix expression
+    ///
+    /// Optimizes the representation of a concrete matrix; symbolic matrix
+    /// expressions go through [`SimplifyPipeline::matrix`].
+    ///
+    /// [`SimplifyPipeline::matrix`]: crate::simplify::SimplifyPipeline::matrix
     fn simplify_matrix(&self) -> Expression;
 }
 
@@ -252,7 +257,7 @@ impl MatrixOperations for Expression {
                 let optimized = matrix.as_ref().clone().optimize();
                 Expression::Matrix(Arc::new(optimized))
             }
-            _ => self.clone(),
+            _ => self.simplify_for(SimplifyDomain::Matrix),
         }
     }
 }
@@ -0,0 +1,552 @@
+//! Sparse symbolic matrices in compressed sparse row form
+//!
+//! Discretizing a PDE on `n` grid points gives an `n×n` matrix with only a
+//! few nonzero diagonals. The dense [`Matrix`] stores all `n²` entries, which
+//! for a few thousand points means millions of `Expression`s, almost all of
+//! them zero. [`SparseMatrix`] stores only the nonzero entries, row by row
+//! (CSR), and builds from coordinate triplets (COO) or diagonals.
+//!
+//! Entries stay exact: sums and products are simplified entry by entry, and
+//! [`SparseMatrix::solve`] eliminates without leaving the sparse form,
+//! choosing pivots that keep fill-in low, so a banded system keeps its band.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use mathhook_core::matrices::SparseMatrix;
+//! use mathhook_core::Expression;
+//!
+//! // Second-difference matrix of the 1D Laplacian on 1000 points
+//! let laplacian = SparseMatrix::from_diagonals(
+//!     1000,
+//!     &[
+//!         (-1, Expression::integer(1)),
+//!         (0, Expression::integer(-2)),
+//!         (1, Expression::integer(1)),
+//!     ],
+//! );
+//! assert_eq!(laplacian.nnz(), 2998);
+//!
+//! let b = vec![Expression::integer(0); 1000];
+//! let x = laplacian.solve(&b).unwrap();
+//! assert!(x.iter().all(|value| value.is_zero_fast()));
+//! ```
+
+use super::Matrix;
+use crate::core::Expression;
+use crate::error::MathError;
+use crate::simplify::Simplify;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Matrix storing only its nonzero entries
+///
+/// Row `i` holds the entries `values[row_offsets[i]..row_offsets[i + 1]]`
+/// in the columns given by the same range of `col_indices`, in increasing
+/// column order.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SparseMatrix {
+    rows: usize,
+    cols: usize,
+    row_offsets: Vec<usize>,
+    col_indices: Vec<usize>,
+    values: Vec<Expression>,
+}
+
+impl SparseMatrix {
+    /// Matrix of the given size with no nonzero entries
+    pub fn zeros(rows: usize, cols: usize) -> Self {
+        Self {
+            rows,
+            cols,
+            row_offsets: vec![0; rows + 1],
+            col_indices: Vec::new(),
+            values: Vec::new(),
+        }
+    }
+
+    /// Matrix from `(row, column, value)` triplets
+    ///
+    /// Triplets for the same position add up, and entries that simplify to
+    /// zero are dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MathError::DomainError` if a position lies outside the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::matrices::SparseMatrix;
+    /// use mathhook_core::{expr, Expression};
+    ///
+    /// let m = SparseMatrix::from_triplets(
+    ///     2,
+    ///     3,
+    ///     vec![(0, 2, expr!(x)), (1, 0, expr!(2)), (0, 2, expr!(x))],
+    /// )
+    /// .unwrap();
+    /// assert_eq!(m.nnz(), 2);
+    /// assert_eq!(m.get(0, 2), expr!(2 * x));
+    /// assert_eq!(m.get(1, 1), Expression::integer(0));
+    /// ```
+    pub fn from_triplets(
+        rows: usize,
+        cols: usize,
+        triplets: impl IntoIterator<Item = (usize, usize, Expression)>,
+    ) -> Result<Self, MathError> {
+        let mut entries: Vec<BTreeMap<usize, Vec<Expression>>> = vec![BTreeMap::new(); rows];
+        for (i, j, value) in triplets {
+            if i >= rows || j >= cols {
+                return Err(MathError::DomainError {
+                    operation: "sparse_matrix".to_owned(),
+                    value,
+                    reason: format!("Entry ({}, {}) is outside a {}x{} matrix", i, j, rows, cols),
+                });
+            }
+            entries[i].entry(j).or_default().push(value);
+        }
+        Ok(Self::from_rows(
+            cols,
+            entries.into_iter().map(|row| {
+                row.into_iter()
+                    .map(|(j, terms)| (j, Expression::add(terms)))
+                    .collect()
+            }),
+        ))
+    }
+
+    /// Square matrix with constant diagonals
+    ///
+    /// Each `(offset, value)` fills the diagonal `j - i = offset`, so `0` is
+    /// the main diagonal, `1` the one above it and `-1` the one below.
+    pub fn from_diagonals(size: usize, diagonals: &[(isize, Expression)]) -> Self {
+        let rows = (0..size).map(|i| {
+            diagonals
+                .iter()
+                .filter_map(|(offset, value)| {
+                    let j = i as isize + offset;
+                    (0..size as isize)
+                        .contains(&j)
+                        .then(|| (j as usize, value.clone()))
+                })
+                .collect::<BTreeMap<_, _>>()
+        });
+        Self::from_rows(size, rows)
+    }
+
+    /// Nonzero entries of a dense matrix
+    pub fn from_dense(matrix: &Matrix) -> Self {
+        let (rows, cols) = matrix.dimensions();
+        Self::from_rows(
+            cols,
+            (0..rows).map(|i| {
+                (0..cols)
+                    .map(|j| (j, matrix.get_element(i, j)))
+                    .collect::<BTreeMap<_, _>>()
+            }),
+        )
+    }
+
+    /// Dense matrix with the same entries
+    pub fn to_dense(&self) -> Matrix {
+        let rows = (0..self.rows)
+            .map(|i| {
+                let mut row = vec![Expression::integer(0); self.cols];
+                for (j, value) in self.row(i) {
+                    row[j] = value.clone();
+                }
+                row
+            })
+            .collect();
+        Matrix::dense(rows)
+    }
+
+    /// CSR storage from rows given as column-to-value maps, simplifying
+    /// each value and dropping zeros
+    fn from_rows(cols: usize, rows: impl IntoIterator<Item = BTreeMap<usize, Expression>>) -> Self {
+        let mut matrix = Self::zeros(0, cols);
+        for row in rows {
+            for (j, value) in row {
+                let value = value.simplify();
+                if !value.is_zero_fast() {
+                    matrix.col_indices.push(j);
+                    matrix.values.push(value);
+                }
+            }
+            matrix.row_offsets.push(matrix.values.len());
+            matrix.rows += 1;
+        }
+        matrix
+    }
+
+    /// Number of rows and columns
+    pub fn dimensions(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// Number of stored (nonzero) entries
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Entry at row `i` and column `j`, zero if not stored or out of range
+    pub fn get(&self, i: usize, j: usize) -> Expression {
+        if i >= self.rows {
+            return Expression::integer(0);
+        }
+        let range = self.row_offsets[i]..self.row_offsets[i + 1];
+        match self.col_indices[range.clone()].binary_search(&j) {
+            Ok(k) => self.values[range.start + k].clone(),
+            Err(_) => Expression::integer(0),
+        }
+    }
+
+    /// Nonzero entries of row `i` as `(column, value)`, by column
+    pub fn row(&self, i: usize) -> impl Iterator<Item = (usize, &Expression)> {
+        let range = self.row_offsets[i]..self.row_offsets[i + 1];
+        self.col_indices[range.clone()]
+            .iter()
+            .copied()
+            .zip(&self.values[range])
+    }
+
+    /// All nonzero entries as `(row, column, value)`, row by row
+    pub fn triplets(&self) -> impl Iterator<Item = (usize, usize, &Expression)> {
+        (0..self.rows).flat_map(move |i| self.row(i).map(move |(j, value)| (i, j, value)))
+    }
+
+    /// Transposed matrix
+    pub fn transpose(&self) -> Self {
+        let mut rows = vec![BTreeMap::new(); self.cols];
+        for (i, j, value) in self.triplets() {
+            rows[j].insert(i, value.clone());
+        }
+        Self::from_rows(self.rows, rows)
+    }
+
+    /// Every entry multiplied by `scalar`
+    pub fn scalar_multiply(&self, scalar: &Expression) -> Self {
+        Self::from_rows(
+            self.cols,
+            (0..self.rows).map(|i| {
+                self.row(i)
+                    .map(|(j, value)| (j, Expression::mul(vec![scalar.clone(), value.clone()])))
+                    .collect::<BTreeMap<_, _>>()
+            }),
+        )
+    }
+
+    /// Entrywise sum
+    ///
+    /// # Errors
+    ///
+    /// Returns `MathError::DomainError` if the dimensions differ.
+    pub fn add(&self, other: &SparseMatrix) -> Result<Self, MathError> {
+        if self.dimensions() != other.dimensions() {
+            return Err(dimension_error(
+                "sparse_add",
+                format!(
+                    "Cannot add {}x{} and {}x{} matrices",
+                    self.rows, self.cols, other.rows, other.cols
+                ),
+            ));
+        }
+        let triplets = self
+            .triplets()
+            .chain(other.triplets())
+            .map(|(i, j, value)| (i, j, value.clone()));
+        Self::from_triplets(self.rows, self.cols, triplets)
+    }
+
+    /// Matrix product, touching only pairs of nonzero entries
+    ///
+    /// # Errors
+    ///
+    /// Returns `MathError::DomainError` if the inner dimensions differ.
+    pub fn multiply(&self, other: &SparseMatrix) -> Result<Self, MathError> {
+        if self.cols != other.rows {
+            return Err(dimension_error(
+                "sparse_multiply",
+                format!(
+                    "Cannot multiply {}x{} by {}x{} matrix",
+                    self.rows, self.cols, other.rows, other.cols
+                ),
+            ));
+        }
+        let rows = (0..self.rows).map(|i| {
+            let mut terms: BTreeMap<usize, Vec<Expression>> = BTreeMap::new();
+            for (k, left) in self.row(i) {
+                for (j, right) in other.row(k) {
+                    terms
+                        .entry(j)
+                        .or_default()
+                        .push(Expression::mul(vec![left.clone(), right.clone()]));
+                }
+            }
+            terms
+                .into_iter()
+                .map(|(j, terms)| (j, Expression::add(terms)))
+                .collect::<BTreeMap<_, _>>()
+        });
+        Ok(Self::from_rows(other.cols, rows))
+    }
+
+    /// Product with a column vector
+    ///
+    /// # Errors
+    ///
+    /// Returns `MathError::DomainError` if the vector length is not the
+    /// number of columns.
+    pub fn multiply_vector(&self, vector: &[Expression]) -> Result<Vec<Expression>, MathError> {
+        if vector.len() != self.cols {
+            return Err(dimension_error(
+                "sparse_multiply",
+                format!(
+                    "Cannot multiply {}x{} matrix by vector of length {}",
+                    self.rows,
+                    self.cols,
+                    vector.len()
+                ),
+            ));
+        }
+        Ok((0..self.rows)
+            .map(|i| {
+                let terms = self
+                    .row(i)
+                    .map(|(j, value)| Expression::mul(vec![value.clone(), vector[j].clone()]))
+                    .collect();
+                Expression::add(terms).simplify()
+            })
+            .collect())
+    }
+
+    /// Solve `Ax = b` by exact sparse Gaussian elimination
+    ///
+    /// Each step pivots on the row with the fewest entries among those that
+    /// can, which keeps banded and other structured systems sparse.
+    ///
+    /// # Errors
+    ///
+    /// * `DomainError` if the matrix is not square or `b` has the wrong length
+    /// * `DivisionByZero` if the matrix is singular
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::matrices::SparseMatrix;
+    /// use mathhook_core::{expr, Expression};
+    ///
+    /// // [[2, 1], [1, 3]] x = [5, 10]
+    /// let a = SparseMatrix::from_triplets(
+    ///     2,
+    ///     2,
+    ///     vec![(0, 0, expr!(2)), (0, 1, expr!(1)), (1, 0, expr!(1)), (1, 1, expr!(3))],
+    /// )
+    /// .unwrap();
+    /// let x = a.solve(&[expr!(5), expr!(10)]).unwrap();
+    /// assert_eq!(x, vec![Expression::integer(1), Expression::integer(3)]);
+    /// ```
+    pub fn solve(&self, b: &[Expression]) -> Result<Vec<Expression>, MathError> {
+        if self.rows != self.cols {
+            return Err(dimension_error(
+                "solve",
+                format!(
+                    "Solve requires square matrix, got {}x{}",
+                    self.rows, self.cols
+                ),
+            ));
+        }
+        if b.len() != self.rows {
+            return Err(dimension_error(
+                "solve",
+                format!(
+                    "Dimension mismatch: matrix is {}x{} but b has {} elements",
+                    self.rows,
+                    self.cols,
+                    b.len()
+                ),
+            ));
+        }
+
+        let n = self.rows;
+        let mut rows: Vec<BTreeMap<usize, Expression>> = (0..n)
+            .map(|i| self.row(i).map(|(j, value)| (j, value.clone())).collect())
+            .collect();
+        let mut rhs = b.to_vec();
+        let mut rows_in_column: Vec<BTreeSet<usize>> = vec![BTreeSet::new(); n];
+        for (i, j, _) in self.triplets() {
+            rows_in_column[j].insert(i);
+        }
+
+        // pivots[k] is the row that determines unknown k
+        let mut pivots = Vec::with_capacity(n);
+        for k in 0..n {
+            let candidates = std::mem::take(&mut rows_in_column[k]);
+            let pivot = *candidates
+                .iter()
+                .min_by_key(|&&i| rows[i].len())
+                .ok_or(MathError::DivisionByZero)?;
+            let pivot_row = std::mem::take(&mut rows[pivot]);
+            for (j, _) in pivot_row.range(k + 1..) {
+                rows_in_column[*j].remove(&pivot);
+            }
+
+            for &target in candidates.iter().filter(|&&i| i != pivot) {
+                let leading = rows[target]
+                    .remove(&k)
+                    .unwrap_or_else(|| Expression::integer(0));
+                let factor = Expression::div(leading, pivot_row[&k].clone()).simplify();
+                for (j, value) in pivot_row.range(k + 1..) {
+                    let current = rows[target]
+                        .remove(j)
+                        .unwrap_or_else(|| Expression::integer(0));
+                    let updated = subtract_multiple(current, &factor, value);
+                    if updated.is_zero_fast() {
+                        rows_in_column[*j].remove(&target);
+                    } else {
+                        rows[target].insert(*j, updated);
+                        rows_in_column[*j].insert(target);
+                    }
+                }
+                rhs[target] = subtract_multiple(rhs[target].clone(), &factor, &rhs[pivot]);
+            }
+            rows[pivot] = pivot_row;
+            pivots.push(pivot);
+        }
+
+        let mut solution = vec![Expression::integer(0); n];
+        for k in (0..n).rev() {
+            let row = &rows[pivots[k]];
+            let known: Vec<Expression> = row
+                .range(k + 1..)
+                .map(|(j, value)| {
+                    Expression::mul(vec![
+                        Expression::integer(-1),
+                        value.clone(),
+                        solution[*j].clone(),
+                    ])
+                })
+                .chain(std::iter::once(rhs[pivots[k]].clone()))
+                .collect();
+            solution[k] = Expression::div(Expression::add(known), row[&k].clone()).simplify();
+        }
+        Ok(solution)
+    }
+}
+
+/// `current - factor·value`, simplified
+fn subtract_multiple(current: Expression, factor: &Expression, value: &Expression) -> Expression {
+    Expression::add(vec![
+        current,
+        Expression::mul(vec![Expression::integer(-1), factor.clone(), value.clone()]),
+    ])
+    .simplify()
+}
+
+fn dimension_error(operation: &str, reason: String) -> MathError {
+    MathError::DomainError {
+        operation: operation.to_owned(),
+        value: Expression::function("sparse_matrix", vec![]),
+        reason,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrices::CoreMatrixOps;
+    use crate::{expr, symbol};
+
+    fn tridiagonal(size: usize) -> SparseMatrix {
+        SparseMatrix::from_diagonals(
+            size,
+            &[
+                (-1, Expression::integer(-1)),
+                (0, Expression::integer(2)),
+                (1, Expression::integer(-1)),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_dense_round_trip() {
+        let dense = Matrix::dense(vec![
+            vec![expr!(1), expr!(0), expr!(x)],
+            vec![expr!(0), expr!(0), expr!(0)],
+            vec![expr!(y), expr!(3), expr!(0)],
+        ]);
+        let sparse = SparseMatrix::from_dense(&dense);
+        assert_eq!(sparse.nnz(), 4);
+        assert_eq!(sparse.to_dense(), dense);
+        assert_eq!(sparse.transpose().to_dense(), dense.transpose());
+    }
+
+    #[test]
+    fn test_arithmetic_matches_dense() {
+        let a = tridiagonal(4);
+        let b = SparseMatrix::from_diagonals(4, &[(2, expr!(x)), (0, expr!(1))]);
+
+        let product = a.multiply(&b).unwrap();
+        let dense_product = a.to_dense().multiply(&b.to_dense()).unwrap();
+        assert_eq!(product.to_dense(), dense_product);
+
+        let sum = a.add(&b).unwrap();
+        assert_eq!(sum.to_dense(), a.to_dense().add(&b.to_dense()).unwrap());
+
+        let cancelled = a.add(&a.scalar_multiply(&expr!(-1))).unwrap();
+        assert_eq!(cancelled.nnz(), 0);
+
+        assert!(a.multiply(&SparseMatrix::zeros(3, 3)).is_err());
+    }
+
+    #[test]
+    fn test_solve_banded_system() {
+        let size = 200;
+        let a = tridiagonal(size);
+        let expected: Vec<Expression> = (0..size as i64).map(Expression::integer).collect();
+        let b = a.multiply_vector(&expected).unwrap();
+        assert_eq!(a.solve(&b).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_solve_needs_pivoting() {
+        // Zero on the diagonal: [[0, 1], [1, 0]]
+        let swap =
+            SparseMatrix::from_triplets(2, 2, vec![(0, 1, expr!(1)), (1, 0, expr!(1))]).unwrap();
+        let x = swap.solve(&[expr!(a), expr!(b)]).unwrap();
+        assert_eq!(x, vec![expr!(b), expr!(a)]);
+    }
+
+    #[test]
+    fn test_solve_symbolic() {
+        // [[k, 1], [0, k]] x = [1, 1]
+        let k = symbol!(k);
+        let a = SparseMatrix::from_triplets(
+            2,
+            2,
+            vec![
+                (0, 0, Expression::symbol(k.clone())),
+                (0, 1, expr!(1)),
+                (1, 1, Expression::symbol(k.clone())),
+            ],
+        )
+        .unwrap();
+        let b = vec![expr!(1), expr!(1)];
+        let x = a.solve(&b).unwrap();
+        let residual = a.multiply_vector(&x).unwrap();
+        assert_eq!(residual, b);
+    }
+
+    #[test]
+    fn test_singular_and_mismatched() {
+        let singular =
+            SparseMatrix::from_triplets(2, 2, vec![(0, 0, expr!(1)), (1, 0, expr!(2))]).unwrap();
+        assert!(matches!(
+            singular.solve(&[expr!(1), expr!(2)]),
+            Err(MathError::DivisionByZero)
+        ));
+        assert!(tridiagonal(3).solve(&[expr!(1)]).is_err());
+        assert!(SparseMatrix::from_triplets(2, 2, vec![(2, 0, expr!(1))]).is_err());
+    }
+}
@@ -223,6 +223,19 @@ pub struct EigenDecomposition {
     pub eigenvectors: super::unified::Matrix,
 }
 
+/// Eigenvalue with its algebraic multiplicity and a basis of its eigenspace
+///
+/// The matrix is diagonalizable exactly when every basis has as many
+/// vectors as the multiplicity.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EigenSpace {
+    pub eigenvalue: Expression,
+    /// Multiplicity as a root of the characteristic polynomial
+    pub multiplicity: usize,
+    /// Linearly independent eigenvectors
+    pub basis: Vec<Vec<Expression>>,
+}
+
 /// Complex eigenvalue (for matrices with complex eigenvalues)
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ComplexEigenvalue {
@@ -446,6 +446,36 @@ impl Matrix {
         Matrix::dense(rows)
     }
 
+    /// Create matrix whose element at row `i`, column `j` is `f(i, j)`
+    ///
+    /// Elements are generated in row-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::matrices::Matrix;
+    /// use mathhook_core::{expr, Expression};
+    ///
+    /// let hilbert = Matrix::from_fn(2, 2, |i, j| {
+    ///     Expression::rational(1, (i + j + 1) as i64)
+    /// });
+    /// assert_eq!(hilbert.get_element(1, 1), Expression::rational(1, 3));
+    ///
+    /// let vandermonde = Matrix::from_fn(2, 3, |i, j| {
+    ///     Expression::pow(expr!(x), Expression::integer((i + j) as i64))
+    /// });
+    /// assert_eq!(vandermonde.get_element(1, 2), expr!(x ^ 3));
+    /// ```
+    pub fn from_fn<F>(rows: usize, cols: usize, mut f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> Expression,
+    {
+        let matrix_rows: Vec<Vec<Expression>> = (0..rows)
+            .map(|i| (0..cols).map(|j| f(i, j)).collect())
+            .collect();
+        Matrix::dense(matrix_rows)
+    }
+
     /// Create matrix from flat vector (row-major order)
     ///
     /// # Examples
@@ -5,23 +5,40 @@
 pub mod cache;
 pub mod config;
 pub mod constants;
+pub mod detection;
 pub mod error;
 pub mod grammar;
+pub mod incremental;
 pub mod lexer;
-use crate::core::Expression;
+pub mod student_notation;
+use crate::core::number::Decimal;
+use crate::core::symbol::names::normalize_symbol_names;
+use crate::core::symbol::SymbolContext;
+use crate::core::{Expression, Number};
+use crate::formatter::MathLanguage;
 pub use cache::*;
 use config::ParserConfig;
 pub use constants::*;
+use detection::{DetectedParse, LanguageDetection};
 use error::ParseError;
+use std::sync::{Mutex, MutexGuard};
+use student_notation::{normalize_student_notation, StudentNotation};
 /// Fully integrated mathematical expression parser
+///
+/// Remembers its most recent inputs, so parsing the same text again is a
+/// lookup; see [`ParserConfig::cache_capacity`].
 pub struct Parser {
     enable_implicit_multiplication: bool,
+    config: ParserConfig,
+    cache: Mutex<ParseCache>,
 }
 impl Parser {
     /// Create parser with implicit multiplication enabled
     pub fn new(config: &ParserConfig) -> Self {
         Self {
             enable_implicit_multiplication: config.enable_implicit_multiplication,
+            config: *config,
+            cache: Mutex::new(ParseCache::new(config.cache_capacity)),
         }
     }
     /// Parse mathematical expression with full integration
@@ -44,12 +61,198 @@ impl Parser {
     /// let expr = parser.parse("x^2").unwrap();          // -> x^2
     /// ```
     pub fn parse(&self, input: &str) -> Result<Expression, ParseError> {
-        if self.enable_implicit_multiplication {
-            self.parse_with_implicit_multiplication(input)
+        if let Some(expression) = self.lock_cache().get(input) {
+            return Ok(expression);
+        }
+        let expression = self.parse_uncached(input)?;
+        self.lock_cache().insert(input, expression.clone());
+        Ok(expression)
+    }
+    /// Hits, misses and size of this parser's cache of recent inputs
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::parser::Parser;
+    ///
+    /// let parser = Parser::default();
+    /// parser.parse("3x^2 - 7").unwrap();
+    /// parser.parse("3x^2 - 7").unwrap();
+    ///
+    /// let stats = parser.cache_stats();
+    /// assert_eq!((stats.hits, stats.misses, stats.entries), (1, 1, 1));
+    ///
+    /// parser.clear_cache();
+    /// assert_eq!(parser.cache_stats().entries, 0);
+    /// ```
+    pub fn cache_stats(&self) -> ParseCacheStats {
+        self.lock_cache().stats()
+    }
+    /// Forget the recent inputs remembered by this parser
+    pub fn clear_cache(&self) {
+        self.lock_cache().clear();
+    }
+    fn lock_cache(&self) -> MutexGuard<'_, ParseCache> {
+        self.cache.lock().unwrap_or_else(|e| e.into_inner())
+    }
+    fn parse_uncached(&self, input: &str) -> Result<Expression, ParseError> {
+        // Formatted subscripts (`x_{1}`, `θₘₐₓ`, `Subscript[x, 1]`) become plain names
+        let input = normalize_symbol_names(input);
+        let input = self.normalize_notation(&input);
+        let expression = if self.enable_implicit_multiplication {
+            // The dictionary was parsed with implicit multiplication
+            let common = self
+                .config
+                .common_inputs
+                .then(|| common_input(&input))
+                .flatten();
+            match common {
+                Some(expression) => expression,
+                None => self.parse_with_implicit_multiplication(&input)?,
+            }
         } else {
-            self.parse_explicit_only(input)
+            self.parse_explicit_only(&input)?
+        };
+        Ok(self.read_decimals(expression))
+    }
+    /// Parse `input` with its symbols bound to `context`
+    ///
+    /// Names already registered in `context` resolve to the same symbols as
+    /// in earlier parses; new names are registered unless the context is
+    /// frozen, in which case [`ParseError::UnknownSymbol`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::core::symbol::SymbolContext;
+    /// use mathhook_core::Parser;
+    ///
+    /// let parser = Parser::default();
+    /// let context = SymbolContext::new();
+    /// let sum = parser.parse_in("a + b", &context).unwrap();
+    ///
+    /// assert_eq!(context.len(), 2);
+    /// assert_ne!(sum, parser.parse("a + b").unwrap());
+    /// ```
+    pub fn parse_in(&self, input: &str, context: &SymbolContext) -> Result<Expression, ParseError> {
+        context.bind(&self.parse(input)?)
+    }
+    /// Detect the input notation and parse with the matching strategy
+    ///
+    /// Notations are tried from most to least likely. When the detection
+    /// confidence is low every notation is tried, and `ambiguous` is set if
+    /// they disagree on the result.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::formatter::MathLanguage;
+    /// use mathhook_core::parser::Parser;
+    ///
+    /// let parsed = Parser::default().parse_with_detection("Sin[x] + Pi").unwrap();
+    /// assert_eq!(parsed.language, MathLanguage::Wolfram);
+    /// assert!(!parsed.ambiguous);
+    /// ```
+    pub fn parse_with_detection(&self, input: &str) -> Result<DetectedParse, ParseError> {
+        let input = normalize_symbol_names(input);
+        let input = self.normalize_notation(&input);
+        let detection = detection::detect_language(&input);
+        let mut successes: Vec<(MathLanguage, Expression)> = Vec::new();
+        let mut first_error = None;
+
+        for language in detection.ranked() {
+            match self.parse_as(&input, language) {
+                Ok(expression) => {
+                    successes.push((language, expression));
+                    if !detection.is_low_confidence() {
+                        break;
+                    }
+                }
+                Err(error) => {
+                    first_error.get_or_insert(error);
+                }
+            }
+        }
+
+        let mut successes = successes.into_iter();
+        match successes.next() {
+            Some((language, expression)) => {
+                let ambiguous = successes.any(|(_, other)| other != expression);
+                Ok(DetectedParse {
+                    expression: self.read_decimals(expression),
+                    language,
+                    detection,
+                    ambiguous,
+                })
+            }
+            None => Err(first_error.unwrap_or(ParseError::EmptyInput)),
         }
     }
+    /// Student notations in the input, such as mixed numbers or `×`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::parser::student_notation::StudentNotation;
+    /// use mathhook_core::parser::Parser;
+    ///
+    /// let notations = Parser::default().detect_student_notation("1 1/2 × 3");
+    /// assert_eq!(
+    ///     notations,
+    ///     vec![StudentNotation::MixedNumber, StudentNotation::TimesSign]
+    /// );
+    /// ```
+    pub fn detect_student_notation(&self, input: &str) -> Vec<StudentNotation> {
+        normalize_student_notation(input, &self.config).notations
+    }
+    /// Rewrite the student notations enabled in the configuration
+    fn normalize_notation(&self, input: &str) -> String {
+        let config = &self.config;
+        if config.mixed_numbers || config.percent || config.degrees {
+            normalize_student_notation(input, config).text
+        } else {
+            input.to_owned()
+        }
+    }
+    /// Float literals as exact decimals, when the configuration asks for them
+    fn read_decimals(&self, expression: Expression) -> Expression {
+        if !self.config.decimals {
+            return expression;
+        }
+        fn convert(expression: &Expression) -> Expression {
+            match expression {
+                // Literals are written with few enough digits that the
+                // shortest float representation is the text as written
+                Expression::Number(Number::Float(f)) => Decimal::parse(&f.to_string())
+                    .map(|d| Expression::Number(Number::decimal(d)))
+                    .unwrap_or_else(|| expression.clone()),
+                _ => expression.map_children(convert),
+            }
+        }
+        convert(&expression)
+    }
+    /// Score the input against each notation without parsing it
+    pub fn detect_language(&self, input: &str) -> LanguageDetection {
+        detection::detect_language(&normalize_symbol_names(input))
+    }
+    /// Parse normalized input using the conventions of `language`
+    fn parse_as(&self, input: &str, language: MathLanguage) -> Result<Expression, ParseError> {
+        match language {
+            // Wolfram never splits names: `xy` is one symbol, `x y` a product
+            MathLanguage::Wolfram => {
+                Self::parse_grammar(&detection::explicit_wolfram_products(input))
+            }
+            MathLanguage::Simple if !self.enable_implicit_multiplication => {
+                Self::parse_grammar(input)
+            }
+            _ => self.parse_with_implicit_multiplication(input),
+        }
+    }
+    fn parse_grammar(input: &str) -> Result<Expression, ParseError> {
+        grammar::ExpressionParser::new()
+            .parse(input)
+            .map_err(|e| ParseError::SyntaxError(format!("LALRPOP parse error: {:?}", e)))
+    }
     /// Parse with implicit multiplication enabled using comprehensive processor
     fn parse_with_implicit_multiplication(&self, input: &str) -> Result<Expression, ParseError> {
         let enhanced_input =
@@ -80,10 +283,12 @@ mod tests {
     fn test_parser_modes() {
         let config_implicit = ParserConfig {
             enable_implicit_multiplication: true,
+            ..ParserConfig::default()
         };
         let parser_implicit = Parser::new(&config_implicit);
         let config_explicit = ParserConfig {
             enable_implicit_multiplication: false,
+            ..ParserConfig::default()
         };
         let parser_explicit = Parser::new(&config_explicit);
         assert!(parser_implicit.parse("x + y").is_ok());
@@ -93,6 +298,7 @@ mod tests {
     fn test_implicit_multiplication_integration() {
         let config = ParserConfig {
             enable_implicit_multiplication: true,
+            ..ParserConfig::default()
         };
         let parser = Parser::new(&config);
         println!("Testing: 2*x -> 2 * x");
@@ -103,4 +309,151 @@ mod tests {
         assert!(parser.parse("x*y").is_ok());
         assert!(parser.parse("a/b").is_ok());
     }
+    #[test]
+    fn test_radix_literals() {
+        let parser = Parser::default();
+        assert_eq!(parser.parse("0b1010").unwrap(), Expression::integer(10));
+        assert_eq!(parser.parse("0o17").unwrap(), Expression::integer(15));
+        assert_eq!(
+            parser.parse("0x1F + 1").unwrap(),
+            Expression::add(vec![Expression::integer(31), Expression::integer(1)])
+        );
+        // A valid digit must follow the prefix, so 0x is still 0 * x
+        assert_eq!(
+            parser.parse("0b11x").unwrap(),
+            Expression::mul(vec![Expression::integer(3), Expression::symbol("x")])
+        );
+        assert_eq!(
+            parser.parse("0x").unwrap(),
+            Expression::mul(vec![Expression::integer(0), Expression::symbol("x")])
+        );
+    }
+    #[test]
+    fn test_repeated_inputs_hit_the_cache() {
+        let parser = Parser::new(&ParserConfig {
+            cache_capacity: 2,
+            ..ParserConfig::default()
+        });
+        let first = parser.parse("3a + 4b").unwrap();
+        assert_eq!(parser.parse("3a + 4b").unwrap(), first);
+        assert!(parser.parse("3a +").is_err());
+        parser.parse("5c").unwrap();
+        parser.parse("6q").unwrap();
+
+        let stats = parser.cache_stats();
+        assert_eq!((stats.hits, stats.misses), (1, 4));
+        assert_eq!((stats.entries, stats.capacity), (2, 2));
+
+        let uncached = Parser::new(&ParserConfig {
+            cache_capacity: 0,
+            common_inputs: false,
+            ..ParserConfig::default()
+        });
+        assert_eq!(uncached.parse("3a + 4b").unwrap(), first);
+        assert_eq!(uncached.cache_stats().entries, 0);
+    }
+    #[test]
+    fn test_common_inputs_respect_notation_settings() {
+        // `xy` is in the dictionary, but without implicit multiplication it
+        // must still be parsed as written
+        let explicit = Parser::new(&ParserConfig {
+            enable_implicit_multiplication: false,
+            ..ParserConfig::default()
+        });
+        let implicit = Parser::default();
+        assert_eq!(
+            explicit.parse("xy").unwrap(),
+            Expression::symbol(crate::core::Symbol::new("xy"))
+        );
+        assert_ne!(explicit.parse("xy").unwrap(), implicit.parse("xy").unwrap());
+        assert_eq!(
+            implicit.parse("x^2 + 2x + 1").unwrap(),
+            Parser::new(&ParserConfig {
+                common_inputs: false,
+                ..ParserConfig::default()
+            })
+            .parse("x^2 + 2x + 1")
+            .unwrap()
+        );
+    }
+    #[test]
+    fn test_decimal_literals() {
+        let parser = Parser::new(&ParserConfig {
+            decimals: true,
+            ..ParserConfig::default()
+        });
+        assert_eq!(
+            parser.parse("19.99").unwrap(),
+            Expression::Number(Number::decimal(Decimal::parse("19.99").unwrap()))
+        );
+        assert_eq!(
+            parser.parse("2.5x").unwrap(),
+            Expression::mul(vec![
+                Expression::Number(Number::decimal(Decimal::parse("2.5").unwrap())),
+                Expression::symbol("x"),
+            ])
+        );
+        assert_eq!(parser.parse("3").unwrap(), Expression::integer(3));
+        assert_eq!(
+            Parser::default().parse("19.99").unwrap(),
+            Expression::float(19.99)
+        );
+    }
+    #[test]
+    fn test_parse_with_detection_reports_language() {
+        let parser = Parser::default();
+
+        let parsed = parser.parse_with_detection("\\frac{x}{2}").unwrap();
+        assert_eq!(parsed.language, MathLanguage::LaTeX);
+        assert_eq!(parsed.expression, parser.parse("\\frac{x}{2}").unwrap());
+
+        let parsed = parser.parse_with_detection("x + 1").unwrap();
+        assert_eq!(parsed.language, MathLanguage::Simple);
+        assert_eq!(parsed.detection.confidence, 1.0);
+    }
+    #[test]
+    fn test_wolfram_detection_keeps_multiletter_names() {
+        let parser = Parser::default();
+        let parsed = parser.parse_with_detection("Sin[xy]").unwrap();
+        assert_eq!(parsed.language, MathLanguage::Wolfram);
+        assert_eq!(
+            parsed.expression,
+            Expression::function(
+                "sin",
+                vec![Expression::symbol(crate::core::Symbol::new("xy"))]
+            )
+        );
+    }
+    #[test]
+    fn test_mixed_notation_is_flagged_ambiguous() {
+        let parser = Parser::default();
+        let parsed = parser.parse_with_detection("\\sqrt{ab} + Sin[x]").unwrap();
+        assert!(parsed.detection.is_low_confidence());
+        assert!(parsed.ambiguous);
+        assert!(parser.parse_with_detection("").is_err());
+    }
+    #[test]
+    fn test_subscripted_symbols_round_trip() {
+        use crate::formatter::latex::LaTeXFormatter;
+        use crate::formatter::simple::{SimpleContext, SimpleFormatter};
+        use crate::formatter::wolfram::WolframFormatter;
+
+        let parser = Parser::default();
+        let unicode = SimpleContext {
+            use_unicode: true,
+            ..Default::default()
+        };
+
+        for name in ["x_1", "theta_max", "alpha_0", "v_init"] {
+            let original = Expression::symbol(crate::core::Symbol::new(name));
+            let renderings = [
+                original.to_latex(None).unwrap(),
+                original.to_simple(&unicode).unwrap(),
+                original.to_wolfram(&Default::default()).unwrap(),
+            ];
+            for rendered in renderings {
+                assert_eq!(parser.parse(&rendered).unwrap(), original, "{}", rendered);
+            }
+        }
+    }
 }
@@ -3,12 +3,261 @@
 //! This module provides thread-local storage for expensive parser operations
 //! to avoid repeated allocations and computations. Based on Rust Performance
 //! Book recommendations for high-performance parsing.
+//!
+//! Whole inputs are cached too: each [`Parser`](super::Parser) keeps a
+//! [`ParseCache`] of its most recently parsed inputs, and a dictionary of
+//! common classroom inputs is parsed once per process and shared by every
+//! parser. Both are configured through
+//! [`ParserConfig`](super::config::ParserConfig).
 
+use super::config::ParserConfig;
+use super::Parser;
 use crate::core::Expression;
+use once_cell::sync::Lazy;
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::thread_local;
 
+/// Inputs typed often enough in class to parse once per process
+const COMMON_INPUT_TEXTS: &[&str] = &[
+    "x",
+    "y",
+    "z",
+    "t",
+    "n",
+    "a",
+    "b",
+    "c",
+    "0",
+    "1",
+    "2",
+    "-1",
+    "pi",
+    "e",
+    "2x",
+    "3x",
+    "-x",
+    "x^2",
+    "x^3",
+    "x^n",
+    "2x^2",
+    "1/x",
+    "1/x^2",
+    "sqrt(x)",
+    "x+1",
+    "x + 1",
+    "x-1",
+    "x - 1",
+    "x+2",
+    "x + 2",
+    "x-2",
+    "x - 2",
+    "2x+1",
+    "2x + 1",
+    "x^2+1",
+    "x^2 + 1",
+    "x^2-1",
+    "x^2 - 1",
+    "x^2-4",
+    "x^2 - 4",
+    "x^2+x",
+    "x^2 + x",
+    "x^2+2x+1",
+    "x^2 + 2x + 1",
+    "x^2-2x+1",
+    "x^2 - 2x + 1",
+    "x^2+3x+2",
+    "x^2 + 3x + 2",
+    "x^2-5x+6",
+    "x^2 - 5x + 6",
+    "(x+1)^2",
+    "(x + 1)^2",
+    "(x-1)^2",
+    "(x - 1)^2",
+    "x+y",
+    "x + y",
+    "x*y",
+    "xy",
+    "x/y",
+    "x^2+y^2",
+    "x^2 + y^2",
+    "sin(x)",
+    "cos(x)",
+    "tan(x)",
+    "exp(x)",
+    "e^x",
+    "ln(x)",
+    "log(x)",
+    "sin(x)^2",
+    "cos(x)^2",
+    "sin(2x)",
+    "cos(2x)",
+    "x*sin(x)",
+    "x*e^x",
+    "x*ln(x)",
+    "1/(x+1)",
+    "1/(x^2+1)",
+    "x = 1",
+    "x = 2",
+    "2x = 4",
+    "x + 1 = 0",
+    "2x + 1 = 0",
+    "2x + 3 = 7",
+    "x^2 = 4",
+    "x^2 - 4 = 0",
+    "x^2 + 2x + 1 = 0",
+    "x^2 - 5x + 6 = 0",
+];
+
+static COMMON_INPUTS: Lazy<HashMap<&'static str, Expression>> = Lazy::new(|| {
+    let parser = Parser::new(&ParserConfig {
+        enable_implicit_multiplication: true,
+        cache_capacity: 0,
+        common_inputs: false,
+        ..ParserConfig::default()
+    });
+    COMMON_INPUT_TEXTS
+        .iter()
+        .filter_map(|text| Some((*text, parser.parse(text).ok()?)))
+        .collect()
+});
+
+/// Prebuilt parse of a common classroom input, such as `x^2 + 2x + 1`
+///
+/// Inputs are matched exactly, after subscript and student-notation
+/// normalization. The dictionary is parsed with implicit multiplication on
+/// the first lookup.
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::parser::cache::common_input;
+/// use mathhook_core::parser::Parser;
+///
+/// let expr = common_input("x^2 + 2x + 1").unwrap();
+/// assert_eq!(expr, Parser::default().parse("x^2 + 2x + 1").unwrap());
+/// assert!(common_input("x^7 + 13").is_none());
+/// ```
+pub fn common_input(input: &str) -> Option<Expression> {
+    COMMON_INPUTS.get(input).cloned()
+}
+
+/// Least recently used cache of parsed inputs
+///
+/// Keyed by the raw input, so it must only be shared by parsers with the
+/// same configuration. A capacity of zero disables it.
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::parser::cache::ParseCache;
+/// use mathhook_core::Expression;
+///
+/// let mut cache = ParseCache::new(2);
+/// cache.insert("1", Expression::integer(1));
+/// cache.insert("2", Expression::integer(2));
+/// assert!(cache.get("1").is_some());
+///
+/// // "2" is now the least recently used entry
+/// cache.insert("3", Expression::integer(3));
+/// assert!(cache.get("2").is_none());
+/// assert_eq!(cache.len(), 2);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ParseCache {
+    capacity: usize,
+    /// Parsed input and the tick of its last use
+    entries: HashMap<String, (Expression, u64)>,
+    /// Inputs ordered by last use
+    recency: BTreeMap<u64, String>,
+    tick: u64,
+    hits: u64,
+    misses: u64,
+}
+
+/// Usage counters of a [`ParseCache`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ParseCacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    pub entries: usize,
+    pub capacity: usize,
+}
+
+impl ParseCache {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            ..Self::default()
+        }
+    }
+
+    /// Cached parse of `input`, marking it as most recently used
+    pub fn get(&mut self, input: &str) -> Option<Expression> {
+        if self.capacity == 0 {
+            return None;
+        }
+        self.tick += 1;
+        let Some((expression, last_used)) = self.entries.get_mut(input) else {
+            self.misses += 1;
+            return None;
+        };
+        self.hits += 1;
+        let key = self
+            .recency
+            .remove(last_used)
+            .unwrap_or_else(|| input.to_owned());
+        *last_used = self.tick;
+        self.recency.insert(self.tick, key);
+        Some(expression.clone())
+    }
+
+    /// Remember the parse of `input`, evicting the least recently used
+    /// entry when full
+    pub fn insert(&mut self, input: &str, expression: Expression) {
+        if self.capacity == 0 {
+            return;
+        }
+        self.tick += 1;
+        if let Some((_, last_used)) = self.entries.get(input) {
+            self.recency.remove(last_used);
+        } else if self.entries.len() >= self.capacity {
+            if let Some((_, oldest)) = self.recency.pop_first() {
+                self.entries.remove(&oldest);
+            }
+        }
+        self.entries
+            .insert(input.to_owned(), (expression, self.tick));
+        self.recency.insert(self.tick, input.to_owned());
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Forget every entry and reset the counters
+    pub fn clear(&mut self) {
+        *self = Self::new(self.capacity);
+    }
+
+    pub fn stats(&self) -> ParseCacheStats {
+        ParseCacheStats {
+            hits: self.hits,
+            misses: self.misses,
+            entries: self.entries.len(),
+            capacity: self.capacity,
+        }
+    }
+}
+
 thread_local! {
     /// Cache for parsed function names to avoid repeated string allocations
     ///
@@ -232,6 +481,7 @@ mod tests {
             Expression::Function {
                 name,
                 args: func_args,
+                ..
             } => {
                 assert_eq!(name.as_ref(), "bessel_j");
                 assert_eq!(*func_args, args);
@@ -240,6 +490,59 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_cache_evicts_least_recently_used() {
+        let mut cache = ParseCache::new(2);
+        cache.insert("a", Expression::integer(1));
+        cache.insert("b", Expression::integer(2));
+        assert_eq!(cache.get("a"), Some(Expression::integer(1)));
+
+        cache.insert("c", Expression::integer(3));
+        assert_eq!(cache.get("b"), None);
+        assert!(cache.get("a").is_some());
+        assert!(cache.get("c").is_some());
+
+        // Re-inserting refreshes without growing
+        cache.insert("a", Expression::integer(4));
+        cache.insert("d", Expression::integer(5));
+        assert_eq!(cache.get("a"), Some(Expression::integer(4)));
+        assert_eq!(cache.get("c"), None);
+        assert_eq!(
+            cache.stats(),
+            ParseCacheStats {
+                hits: 4,
+                misses: 2,
+                entries: 2,
+                capacity: 2,
+            }
+        );
+
+        cache.clear();
+        assert!(cache.is_empty());
+        assert_eq!(cache.stats().hits, 0);
+    }
+
+    #[test]
+    fn test_disabled_parse_cache() {
+        let mut cache = ParseCache::new(0);
+        cache.insert("a", Expression::integer(1));
+        assert_eq!(cache.get("a"), None);
+        assert_eq!(cache.stats(), ParseCacheStats::default());
+    }
+
+    #[test]
+    fn test_common_inputs_match_parser() {
+        let parser = Parser::new(&ParserConfig {
+            cache_capacity: 0,
+            common_inputs: false,
+            ..ParserConfig::default()
+        });
+        for text in COMMON_INPUT_TEXTS {
+            let expected = parser.parse(text).unwrap();
+            assert_eq!(common_input(text), Some(expected), "{}", text);
+        }
+    }
+
     #[test]
     fn test_cache_clearing() {
         // Populate caches
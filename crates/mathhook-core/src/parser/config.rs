@@ -1,11 +1,64 @@
+/// Recent inputs remembered by a parser with the default configuration
+pub const DEFAULT_CACHE_CAPACITY: usize = 256;
+
+#[derive(Debug, Clone, Copy)]
 pub struct ParserConfig {
     pub enable_implicit_multiplication: bool,
+    /// Read `1 1/2` as the mixed number `1 + 1/2`
+    pub mixed_numbers: bool,
+    /// Read `50%` as `50/100`
+    pub percent: bool,
+    /// Read `30°` as `30π/180`, and `sin 30°` as `sin(30π/180)`
+    pub degrees: bool,
+    /// Number of recent inputs each parser remembers; 0 disables the cache
+    pub cache_capacity: usize,
+    /// Look inputs up in the prebuilt dictionary of common classroom inputs
+    /// before parsing them
+    pub common_inputs: bool,
+    /// Read literals with a point, such as `19.99`, as exact decimals rather
+    /// than floats; see [`Decimal`](crate::core::number::Decimal)
+    pub decimals: bool,
+}
+
+impl ParserConfig {
+    /// Configuration accepting the notations students write by hand
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::parser::config::ParserConfig;
+    /// use mathhook_core::parser::Parser;
+    /// use mathhook_core::simplify::Simplify;
+    /// use mathhook_core::Expression;
+    ///
+    /// let parser = Parser::new(&ParserConfig::student());
+    /// assert_eq!(parser.parse("1 1/2").unwrap(), Expression::rational(3, 2));
+    /// assert_eq!(parser.parse("15%").unwrap(), Expression::rational(3, 20));
+    /// assert_eq!(
+    ///     parser.parse("sin 30°").unwrap().simplify(),
+    ///     Expression::rational(1, 2)
+    /// );
+    /// ```
+    pub fn student() -> Self {
+        Self {
+            mixed_numbers: true,
+            percent: true,
+            degrees: true,
+            ..Self::default()
+        }
+    }
 }
 
 impl Default for ParserConfig {
     fn default() -> Self {
         Self {
             enable_implicit_multiplication: true,
+            mixed_numbers: false,
+            percent: false,
+            degrees: false,
+            cache_capacity: DEFAULT_CACHE_CAPACITY,
+            common_inputs: true,
+            decimals: false,
         }
     }
 }
@@ -0,0 +1,251 @@
+//! Input notation detection for the multi-format parser
+//!
+//! Each notation gets a score from weighted evidence found in the input
+//! (`\frac{..}` for LaTeX, `Sin[..]` for Wolfram, plain `sin(..)` for simple
+//! notation). The confidence is the winning share of the total score, so mixed
+//! notation yields a low confidence and the parser tries every notation.
+
+use crate::core::Expression;
+use crate::formatter::MathLanguage;
+
+/// Notations the parser can be asked to detect
+pub const DETECTABLE_LANGUAGES: [MathLanguage; 3] = [
+    MathLanguage::LaTeX,
+    MathLanguage::Wolfram,
+    MathLanguage::Simple,
+];
+
+/// Below this confidence `Parser::parse_with_detection` tries every notation
+pub const LOW_CONFIDENCE: f64 = 0.6;
+
+/// Prior score for simple notation, so plain input like `x + 1` is confident
+const SIMPLE_PRIOR: f64 = 1.0;
+
+/// LaTeX commands that are strong evidence on their own
+const LATEX_STRUCTURAL_COMMANDS: &[&str] = &[
+    "frac", "sqrt", "int", "sum", "prod", "lim", "left", "right", "begin", "end", "cdot", "times",
+    "mathbf", "mathrm", "text", "partial", "infty",
+];
+
+/// Outcome of notation detection
+#[derive(Debug, Clone, PartialEq)]
+pub struct LanguageDetection {
+    /// Highest scoring notation
+    pub language: MathLanguage,
+    /// Share of the total score held by `language`, in `(0, 1]`
+    pub confidence: f64,
+    /// Raw score per notation, in [`DETECTABLE_LANGUAGES`] order
+    pub scores: [(MathLanguage, f64); 3],
+}
+
+/// Result of `Parser::parse_with_detection`
+#[derive(Debug, Clone, PartialEq)]
+pub struct DetectedParse {
+    pub expression: Expression,
+    /// Notation whose parsing strategy produced `expression`
+    pub language: MathLanguage,
+    pub detection: LanguageDetection,
+    /// Low-confidence input where another notation parsed to a different result
+    pub ambiguous: bool,
+}
+
+impl LanguageDetection {
+    /// Notations ordered from most to least likely
+    pub fn ranked(&self) -> Vec<MathLanguage> {
+        let mut scores = self.scores;
+        // Stable sort keeps DETECTABLE_LANGUAGES order on ties
+        scores.sort_by(|a, b| b.1.total_cmp(&a.1));
+        scores.iter().map(|(language, _)| *language).collect()
+    }
+
+    /// Whether the input mixes notations too much to trust `language` alone
+    pub fn is_low_confidence(&self) -> bool {
+        self.confidence < LOW_CONFIDENCE
+    }
+}
+
+/// Score the input against each notation
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::formatter::MathLanguage;
+/// use mathhook_core::parser::detection::detect_language;
+///
+/// let detection = detect_language("\\frac{x}{2} + \\sqrt{y}");
+/// assert_eq!(detection.language, MathLanguage::LaTeX);
+/// assert!(!detection.is_low_confidence());
+///
+/// let detection = detect_language("Sin[x] + Power[y, 2]");
+/// assert_eq!(detection.language, MathLanguage::Wolfram);
+///
+/// let detection = detect_language("sin(x) + y^2");
+/// assert_eq!(detection.language, MathLanguage::Simple);
+/// ```
+pub fn detect_language(input: &str) -> LanguageDetection {
+    let chars: Vec<char> = input.chars().collect();
+    let mut latex = 0.0;
+    let mut wolfram = 0.0;
+    let mut simple = SIMPLE_PRIOR;
+
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            '\\' if chars.get(i + 1) == Some(&'[') => {
+                // Wolfram named character: \[Alpha]
+                wolfram += 3.0;
+                i += 2;
+                continue;
+            }
+            '\\' => {
+                let word = word_at(&chars, i + 1);
+                if LATEX_STRUCTURAL_COMMANDS.contains(&word.as_str()) {
+                    latex += 3.0;
+                } else if !word.is_empty() {
+                    latex += 2.0;
+                } else {
+                    // Spacing commands such as `\,`
+                    latex += 1.0;
+                }
+                i += 1 + word.chars().count();
+                continue;
+            }
+            '_' | '^' if chars.get(i + 1) == Some(&'{') => latex += 1.0,
+            '{' => latex += 0.5,
+            '-' | ':' if chars.get(i + 1) == Some(&'>') => wolfram += 1.0,
+            '=' if chars.get(i + 1) == Some(&'=') => {
+                wolfram += 1.0;
+                i += 2;
+                continue;
+            }
+            '*' if chars.get(i + 1) == Some(&'^') => wolfram += 2.0,
+            '*' if chars.get(i + 1) == Some(&'*') => simple += 1.0,
+            c if c.is_ascii_alphabetic() && !preceded_by_word_char(&chars, i) => {
+                let word = word_at(&chars, i);
+                let next = chars.get(i + word.chars().count());
+                let capitalized = word.starts_with(|c: char| c.is_ascii_uppercase());
+                match next {
+                    Some('[') if capitalized => wolfram += 3.0,
+                    Some('(') if !capitalized => simple += 1.0,
+                    _ if matches!(word.as_str(), "Pi" | "E" | "I" | "Infinity") => wolfram += 1.0,
+                    _ => {}
+                }
+                i += word.chars().count();
+                continue;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+
+    let scores = [
+        (MathLanguage::LaTeX, latex),
+        (MathLanguage::Wolfram, wolfram),
+        (MathLanguage::Simple, simple),
+    ];
+    let total: f64 = scores.iter().map(|(_, score)| score).sum();
+    let (language, best) = scores.iter().copied().fold(scores[0], |best, candidate| {
+        if candidate.1 > best.1 {
+            candidate
+        } else {
+            best
+        }
+    });
+
+    LanguageDetection {
+        language,
+        confidence: best / total,
+        scores,
+    }
+}
+
+fn word_at(chars: &[char], start: usize) -> String {
+    chars[start.min(chars.len())..]
+        .iter()
+        .take_while(|c| c.is_ascii_alphabetic())
+        .collect()
+}
+
+fn preceded_by_word_char(chars: &[char], i: usize) -> bool {
+    i > 0 && (chars[i - 1].is_ascii_alphanumeric() || chars[i - 1] == '\\')
+}
+
+/// Wolfram multiplies operands separated by whitespace (`2 x`, `a b`) and
+/// never splits a name like `xy`; make those products explicit
+pub(crate) fn explicit_wolfram_products(input: &str) -> String {
+    let chars: Vec<char> = input.chars().collect();
+    let mut output = String::with_capacity(input.len());
+    let mut i = 0;
+
+    while i < chars.len() {
+        if chars[i].is_whitespace() {
+            let start = i;
+            while i < chars.len() && chars[i].is_whitespace() {
+                i += 1;
+            }
+            let previous = output.chars().last();
+            let next = chars.get(i);
+            let ends_operand =
+                previous.is_some_and(|c| c.is_alphanumeric() || c == ')' || c == ']');
+            let starts_operand =
+                next.is_some_and(|c| c.is_alphanumeric() || *c == '(' || *c == '\\');
+            if ends_operand && starts_operand {
+                output.push('*');
+            } else {
+                output.extend(&chars[start..i]);
+            }
+        } else {
+            output.push(chars[i]);
+            i += 1;
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plain_input_is_confident_simple() {
+        let detection = detect_language("x + 1");
+        assert_eq!(detection.language, MathLanguage::Simple);
+        assert_eq!(detection.confidence, 1.0);
+    }
+
+    #[test]
+    fn test_latex_evidence() {
+        let detection = detect_language("x_{1}^{2} + \\alpha");
+        assert_eq!(detection.language, MathLanguage::LaTeX);
+        assert!(detection.confidence > LOW_CONFIDENCE);
+    }
+
+    #[test]
+    fn test_wolfram_evidence() {
+        let detection = detect_language("Limit[Sin[x]/x, x -> 0]");
+        assert_eq!(detection.language, MathLanguage::Wolfram);
+        assert!(detection.confidence > LOW_CONFIDENCE);
+        assert_eq!(
+            detect_language("\\[Alpha] + 1").language,
+            MathLanguage::Wolfram
+        );
+    }
+
+    #[test]
+    fn test_mixed_notation_is_low_confidence() {
+        let detection = detect_language("\\frac{1}{2} + Sin[x]");
+        assert!(detection.is_low_confidence());
+        assert_eq!(detection.ranked().len(), 3);
+        assert_eq!(detection.ranked()[2], MathLanguage::Simple);
+    }
+
+    #[test]
+    fn test_explicit_wolfram_products() {
+        assert_eq!(explicit_wolfram_products("2 x y"), "2*x*y");
+        assert_eq!(explicit_wolfram_products("Sin[x] y"), "Sin[x]*y");
+        assert_eq!(explicit_wolfram_products("x + y"), "x + y");
+        assert_eq!(explicit_wolfram_products("f[x, y]"), "f[x, y]");
+        assert_eq!(explicit_wolfram_products("xy"), "xy");
+    }
+}
@@ -5,6 +5,8 @@ use serde::{Deserialize, Serialize};
 pub enum ParseError {
     InvalidSyntax(String),
     UnknownFunction(String),
+    /// Symbol name not registered in a frozen symbol context
+    UnknownSymbol(String),
     UnbalancedParentheses,
     InvalidNumber(String),
     EmptyInput,
@@ -17,6 +19,7 @@ impl std::fmt::Display for ParseError {
         match self {
             ParseError::InvalidSyntax(msg) => write!(f, "Invalid syntax: {}", msg),
             ParseError::UnknownFunction(name) => write!(f, "Unknown function: {}", name),
+            ParseError::UnknownSymbol(name) => write!(f, "Unknown symbol: {}", name),
             ParseError::UnbalancedParentheses => write!(f, "Unbalanced parentheses"),
             ParseError::InvalidNumber(num) => write!(f, "Invalid number: {}", num),
             ParseError::EmptyInput => write!(f, "Empty input"),
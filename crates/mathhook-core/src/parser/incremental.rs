@@ -0,0 +1,377 @@
+//! Incremental parsing for live-preview editors
+//!
+//! An editor re-parses its buffer on every keystroke, but an edit usually
+//! touches a single term of a long sum. `IncrementalParser` keeps the parsed
+//! top-level terms of the previous text and only re-parses the terms whose
+//! source changed. Input whose top level is not a plain sum (relations,
+//! arrows, big operators like `\sum` that extend to the right) is parsed in
+//! full, so the result always equals `Parser::parse` on the same text.
+
+use super::config::ParserConfig;
+use super::error::ParseError;
+use super::lexer::{tokenize_with_spans, SpannedToken, TokenType, LATEX_TOKEN_MAP};
+use super::Parser;
+use crate::core::symbol::names::normalize_symbol_names;
+use crate::core::Expression;
+use std::collections::HashMap;
+use std::ops::Range;
+
+/// LaTeX commands that cannot change how a top-level sum splits into terms
+const TERM_SAFE_LATEX_COMMANDS: &[&str] = &[
+    "\\frac", "\\dfrac", "\\tfrac", "\\sqrt", "\\left", "\\right", "\\cdot", "\\times", "\\div",
+];
+
+/// LaTeX operators whose operand extends to the right beyond parentheses
+const BIG_OPERATOR_LATEX_COMMANDS: &[&str] = &["\\int", "\\oint", "\\sum", "\\prod", "\\lim"];
+
+/// Replacement of a byte range of the source text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextEdit {
+    pub range: Range<usize>,
+    pub text: String,
+}
+
+impl TextEdit {
+    /// Insert `text` at `offset`
+    pub fn insert(offset: usize, text: impl Into<String>) -> Self {
+        Self {
+            range: offset..offset,
+            text: text.into(),
+        }
+    }
+
+    /// Delete the bytes in `range`
+    pub fn delete(range: Range<usize>) -> Self {
+        Self {
+            range,
+            text: String::new(),
+        }
+    }
+
+    /// Replace the bytes in `range` with `text`
+    pub fn replace(range: Range<usize>, text: impl Into<String>) -> Self {
+        Self {
+            range,
+            text: text.into(),
+        }
+    }
+}
+
+/// A top-level term of a sum and how it joins the previous terms
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Join {
+    First,
+    Plus,
+    Minus,
+}
+
+/// Parser that reuses the previous parse when the source is edited
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::parser::config::ParserConfig;
+/// use mathhook_core::parser::incremental::{IncrementalParser, TextEdit};
+/// use mathhook_core::parser::Parser;
+///
+/// let mut editor = IncrementalParser::new(&ParserConfig::default());
+/// editor.parse("x^2 + \\sin(x) + 1").unwrap();
+///
+/// // The user types a `2` after the final `1`
+/// let expr = editor.edit(TextEdit::insert(17, "2")).unwrap().clone();
+/// assert_eq!(editor.source(), "x^2 + \\sin(x) + 12");
+/// assert_eq!(expr, Parser::default().parse("x^2 + \\sin(x) + 12").unwrap());
+/// assert_eq!(editor.reused_terms(), 2);
+/// ```
+pub struct IncrementalParser {
+    parser: Parser,
+    source: String,
+    tokens: Vec<SpannedToken>,
+    /// Parsed terms of the previous source, keyed by their normalized text
+    terms: HashMap<String, Expression>,
+    result: Result<Expression, ParseError>,
+    reused_terms: usize,
+}
+
+impl IncrementalParser {
+    pub fn new(config: &ParserConfig) -> Self {
+        Self {
+            parser: Parser::new(config),
+            source: String::new(),
+            tokens: Vec::new(),
+            terms: HashMap::new(),
+            result: Err(ParseError::EmptyInput),
+            reused_terms: 0,
+        }
+    }
+
+    /// Replace the whole source and parse it
+    pub fn parse(&mut self, source: &str) -> Result<&Expression, ParseError> {
+        self.source = source.to_owned();
+        self.reparse()
+    }
+
+    /// Apply an edit to the current source and re-parse the changed terms
+    ///
+    /// An edit whose range is out of bounds or not on character boundaries
+    /// leaves the parser unchanged and returns `ParseError::InvalidSyntax`.
+    pub fn edit(&mut self, edit: TextEdit) -> Result<&Expression, ParseError> {
+        let Range { start, end } = edit.range;
+        if start > end
+            || end > self.source.len()
+            || !self.source.is_char_boundary(start)
+            || !self.source.is_char_boundary(end)
+        {
+            return Err(ParseError::InvalidSyntax(format!(
+                "edit range {}..{} does not fit source of length {}",
+                start,
+                end,
+                self.source.len()
+            )));
+        }
+        self.source.replace_range(start..end, &edit.text);
+        self.reparse()
+    }
+
+    /// Current source text
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Result of the latest parse
+    pub fn result(&self) -> Result<&Expression, &ParseError> {
+        self.result.as_ref()
+    }
+
+    /// Tokens of the current source with byte positions
+    pub fn tokens(&self) -> &[SpannedToken] {
+        &self.tokens
+    }
+
+    /// Token containing the byte `offset`, e.g. under the editor cursor
+    pub fn token_at(&self, offset: usize) -> Option<&SpannedToken> {
+        self.tokens
+            .iter()
+            .find(|token| token.span.contains(&offset))
+    }
+
+    /// Number of terms taken from the previous parse during the latest parse
+    pub fn reused_terms(&self) -> usize {
+        self.reused_terms
+    }
+
+    fn reparse(&mut self) -> Result<&Expression, ParseError> {
+        self.tokens = tokenize_with_spans(&self.source);
+        self.reused_terms = 0;
+
+        let normalized = normalize_symbol_names(&self.source).into_owned();
+        self.result = match split_top_level_terms(&normalized) {
+            Some(pieces) if pieces.len() > 1 => self
+                .parse_terms(&pieces)
+                .or_else(|_| self.parser.parse(&normalized)),
+            _ => {
+                self.terms.clear();
+                self.parser.parse(&normalized)
+            }
+        };
+        match &self.result {
+            Ok(expression) => Ok(expression),
+            Err(error) => Err(error.clone()),
+        }
+    }
+
+    /// Parse each term, reusing unchanged ones, and join them the way the
+    /// grammar's left-associative addition rule does
+    fn parse_terms(&mut self, pieces: &[(Join, &str)]) -> Result<Expression, ParseError> {
+        let mut previous = std::mem::take(&mut self.terms);
+        let mut sum: Option<Expression> = None;
+
+        for (join, text) in pieces {
+            let key = text.trim();
+            let term = match previous.remove(key) {
+                Some(term) => {
+                    self.reused_terms += 1;
+                    term
+                }
+                None => self.parser.parse(key)?,
+            };
+            self.terms.insert(key.to_owned(), term.clone());
+
+            sum = Some(match (sum, join) {
+                (None, _) | (_, Join::First) => term,
+                (Some(left), Join::Plus) => Expression::add(vec![left, term]),
+                (Some(left), Join::Minus) => Expression::add(vec![
+                    left,
+                    Expression::mul(vec![Expression::integer(-1), term]),
+                ]),
+            });
+        }
+        sum.ok_or(ParseError::EmptyInput)
+    }
+}
+
+/// Split a plain top-level sum into its terms, or `None` if the top level
+/// contains anything that binds looser than addition or extends to the right
+fn split_top_level_terms(source: &str) -> Option<Vec<(Join, &str)>> {
+    let mut pieces = Vec::new();
+    let mut depth = 0usize;
+    let mut piece_start = 0;
+    let mut join = Join::First;
+    let mut previous: Option<char> = None;
+    let mut chars = source.char_indices().peekable();
+
+    while let Some((offset, c)) = chars.next() {
+        match c {
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth = depth.checked_sub(1)?,
+            '\\' => {
+                let start = offset;
+                let mut end = offset + 1;
+                while let Some(&(next_offset, next)) = chars.peek() {
+                    if !next.is_ascii_alphabetic() {
+                        break;
+                    }
+                    end = next_offset + next.len_utf8();
+                    chars.next();
+                }
+                let command = &source[start..end];
+                // `\[Alpha]` opens a bracket handled above; `\,` and friends are spacing
+                let applied_to_parentheses = chars.peek().is_some_and(|&(_, next)| next == '(');
+                if depth == 0
+                    && command.len() > 1
+                    && !is_term_safe_command(command, applied_to_parentheses)
+                {
+                    return None;
+                }
+                if command.len() > 1 {
+                    previous = command.chars().last();
+                    continue;
+                }
+            }
+            '+' | '-' if depth == 0 => {
+                let binary = previous.is_some_and(|p| {
+                    p.is_alphanumeric() || matches!(p, ')' | ']' | '}' | '!' | '\'')
+                });
+                // `->` is an arrow, which binds looser than addition
+                if c == '-' && chars.peek().is_some_and(|&(_, next)| next == '>') {
+                    return None;
+                }
+                if binary {
+                    pieces.push((join, &source[piece_start..offset]));
+                    join = if c == '+' { Join::Plus } else { Join::Minus };
+                    piece_start = offset + c.len_utf8();
+                }
+            }
+            '=' | '<' | '>' | '|' | ',' | ';' | ':' if depth == 0 => return None,
+            c if depth == 0 && !c.is_ascii() && !c.is_alphanumeric() => return None,
+            _ => {}
+        }
+        if !c.is_whitespace() {
+            previous = Some(c);
+        }
+    }
+
+    if depth != 0 {
+        return None;
+    }
+    pieces.push((join, &source[piece_start..]));
+    Some(pieces)
+}
+
+/// Whether a top-level LaTeX command keeps its operand within one term;
+/// functions such as `\sin(x)` do when their argument is parenthesised
+fn is_term_safe_command(command: &str, applied_to_parentheses: bool) -> bool {
+    TERM_SAFE_LATEX_COMMANDS.contains(&command)
+        || (applied_to_parentheses && !BIG_OPERATOR_LATEX_COMMANDS.contains(&command))
+        || matches!(
+            LATEX_TOKEN_MAP.get(command),
+            Some(TokenType::GreekSymbol | TokenType::Constant)
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn full_parse(source: &str) -> Result<Expression, ParseError> {
+        Parser::default().parse(source)
+    }
+
+    #[test]
+    fn test_split_plain_sums() {
+        let pieces = split_top_level_terms("-x + 2(a - b) - \\frac{1}{y}").unwrap();
+        assert_eq!(
+            pieces,
+            vec![
+                (Join::First, "-x "),
+                (Join::Plus, " 2(a - b) "),
+                (Join::Minus, " \\frac{1}{y}"),
+            ]
+        );
+        assert_eq!(split_top_level_terms("x^-2").unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_split_refuses_looser_operators() {
+        assert!(split_top_level_terms("y = x + 1").is_none());
+        assert!(split_top_level_terms("x -> 0").is_none());
+        assert!(split_top_level_terms("\\sum_{i=1}^{n} i + 1").is_none());
+        assert!(split_top_level_terms("\\sin x + 1").is_none());
+        assert!(split_top_level_terms("\\int(x) + 1 dx").is_none());
+        assert_eq!(split_top_level_terms("\\sin(x) + 1").unwrap().len(), 2);
+        assert!(split_top_level_terms("|a - b|").is_none());
+        assert!(split_top_level_terms("(x + 1").is_none());
+    }
+
+    #[test]
+    fn test_edits_match_full_parse() {
+        let mut editor = IncrementalParser::new(&ParserConfig::default());
+        editor.parse("2x + \\alpha y - 3").unwrap();
+
+        let edits = [
+            TextEdit::replace(16..17, "z^2"),
+            TextEdit::insert(0, "-"),
+            TextEdit::insert(0, "y = "),
+            TextEdit::delete(0..4),
+            TextEdit::replace(1..3, "\\frac{x}{2}"),
+        ];
+        for edit in edits {
+            let incremental = editor.edit(edit).cloned();
+            assert_eq!(
+                incremental,
+                full_parse(editor.source()),
+                "{}",
+                editor.source()
+            );
+        }
+    }
+
+    #[test]
+    fn test_unchanged_terms_are_reused() {
+        let mut editor = IncrementalParser::new(&ParserConfig::default());
+        editor.parse("a + b + c").unwrap();
+        assert_eq!(editor.reused_terms(), 0);
+        editor.edit(TextEdit::replace(4..5, "z")).unwrap();
+        assert_eq!(editor.reused_terms(), 2);
+    }
+
+    #[test]
+    fn test_errors_are_reported_and_recovered() {
+        let mut editor = IncrementalParser::new(&ParserConfig::default());
+        editor.parse("x + 1").unwrap();
+        assert!(editor.edit(TextEdit::delete(4..5)).is_err());
+        assert!(editor.result().is_err());
+        assert!(editor.edit(TextEdit::insert(4, "y")).is_ok());
+        assert!(editor.edit(TextEdit::delete(3..40)).is_err());
+        assert_eq!(editor.source(), "x + y");
+    }
+
+    #[test]
+    fn test_token_at_cursor() {
+        let mut editor = IncrementalParser::new(&ParserConfig::default());
+        editor.parse("\\sin(x) + 1").unwrap();
+        assert_eq!(editor.token_at(2).unwrap().text, "\\sin");
+        assert_eq!(editor.token_at(8).unwrap().text, "+");
+        assert!(editor.token_at(7).is_none());
+    }
+}
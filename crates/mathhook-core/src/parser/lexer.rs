@@ -12,6 +12,7 @@
 pub mod implicit_multiplication;
 pub mod multiplication_rules;
 pub mod rules;
+pub mod spans;
 pub mod standard_tokens;
 pub mod token_maps;
 pub mod wolfram_tokens;
@@ -19,6 +20,7 @@ pub mod wolfram_tokens;
 pub use implicit_multiplication::*;
 pub use multiplication_rules::*;
 pub use rules::*;
+pub use spans::*;
 pub use standard_tokens::*;
 pub use token_maps::*;
 pub use wolfram_tokens::*;
@@ -133,9 +133,19 @@ impl ImplicitMultiplicationProcessor {
     }
 
     /// Extract next token with comprehensive recognition using HashMap lookups
-    fn extract_next_token(chars: &[char], i: &mut usize) -> (String, TokenType) {
+    pub(super) fn extract_next_token(chars: &[char], i: &mut usize) -> (String, TokenType) {
         let start = *i;
 
+        // Binary, octal and hexadecimal literals: 0b1010, 0o17, 0x1F
+        if let Some(radix) = Self::radix_prefix(chars, *i) {
+            *i += 2;
+            while *i < chars.len() && chars[*i].is_digit(radix) {
+                *i += 1;
+            }
+            let token = chars[start..*i].iter().collect();
+            return (token, TokenType::Number);
+        }
+
         // Numbers (including floats)
         if chars[*i].is_ascii_digit() {
             while *i < chars.len() && (chars[*i].is_ascii_digit() || chars[*i] == '.') {
@@ -234,6 +244,15 @@ impl ImplicitMultiplicationProcessor {
 
                     // Return the full token including underscore and subscript
                     chars[identifier_start..*i].iter().collect::<String>()
+                } else if chars.get(*i + 1).is_some_and(|c| c.is_ascii_alphanumeric()) {
+                    // Subscripted symbol like v_init or theta_max: keep the
+                    // subscript word whole instead of splitting it into factors
+                    while *i < chars.len()
+                        && (chars[*i].is_ascii_alphanumeric() || chars[*i] == '_')
+                    {
+                        *i += 1;
+                    }
+                    chars[identifier_start..*i].iter().collect::<String>()
                 } else {
                     base_identifier
                 }
@@ -274,6 +293,23 @@ impl ImplicitMultiplicationProcessor {
         (token, TokenType::Other)
     }
 
+    /// Base of a `0b`, `0o` or `0x` literal starting at `i`
+    ///
+    /// Requires a valid digit after the prefix, so `0x` alone is still zero
+    /// times x.
+    fn radix_prefix(chars: &[char], i: usize) -> Option<u32> {
+        if chars[i] != '0' || chars.get(i + 2).is_none() {
+            return None;
+        }
+        let radix = match chars[i + 1] {
+            'b' | 'B' => 2,
+            'o' | 'O' => 8,
+            'x' | 'X' => 16,
+            _ => return None,
+        };
+        chars[i + 2].is_digit(radix).then_some(radix)
+    }
+
     /// Check if character is an operator
     #[inline]
     fn is_operator_char(ch: char) -> bool {
@@ -0,0 +1,79 @@
+//! Token positions for editor integration
+//!
+//! Uses the same token recognition as implicit multiplication, so an editor
+//! highlights exactly the units the parser multiplies (`2xy` is `2`, `x`, `y`).
+
+use super::implicit_multiplication::ImplicitMultiplicationProcessor;
+use super::token_maps::TokenType;
+use std::ops::Range;
+
+/// A token with its byte range in the source text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpannedToken {
+    pub text: String,
+    pub token_type: TokenType,
+    /// Byte offsets into the tokenized input
+    pub span: Range<usize>,
+}
+
+/// Split input into tokens with byte positions, skipping whitespace
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::parser::lexer::{tokenize_with_spans, TokenType};
+///
+/// let tokens = tokenize_with_spans("\\alpha + 2x");
+/// let texts: Vec<&str> = tokens.iter().map(|t| t.text.as_str()).collect();
+/// assert_eq!(texts, vec!["\\alpha", "+", "2", "x"]);
+/// assert_eq!(tokens[0].token_type, TokenType::GreekSymbol);
+/// assert_eq!(tokens[3].span, 10..11);
+/// ```
+pub fn tokenize_with_spans(input: &str) -> Vec<SpannedToken> {
+    let chars: Vec<char> = input.chars().collect();
+    let byte_offsets: Vec<usize> = input
+        .char_indices()
+        .map(|(offset, _)| offset)
+        .chain(std::iter::once(input.len()))
+        .collect();
+
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        if chars[i].is_whitespace() {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        let (text, token_type) =
+            ImplicitMultiplicationProcessor::extract_next_token(&chars, &mut i);
+        tokens.push(SpannedToken {
+            text,
+            token_type,
+            span: byte_offsets[start]..byte_offsets[i],
+        });
+    }
+    tokens
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spans_cover_token_text() {
+        let input = "θ₁ + \\frac{x}{2}";
+        for token in tokenize_with_spans(input) {
+            assert_eq!(&input[token.span.clone()], token.text);
+        }
+    }
+
+    #[test]
+    fn test_split_identifiers_get_their_own_spans() {
+        let spans: Vec<Range<usize>> = tokenize_with_spans("xy(z)")
+            .into_iter()
+            .map(|t| t.span)
+            .collect();
+        assert_eq!(spans, vec![0..1, 1..2, 2..3, 3..4, 4..5]);
+    }
+}
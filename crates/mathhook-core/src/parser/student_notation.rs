@@ -0,0 +1,244 @@
+//! Normalization of hand-written student notation
+//!
+//! Students write `1 1/2` for a mixed number, `15%` for a percentage and
+//! `sin 30°` for an angle in degrees. None of these are part of the grammar,
+//! so the enabled ones are rewritten into plain syntax (`(1+1/2)`,
+//! `(15/100)`, `sin(30*pi/180)`) before parsing. Every notation found is
+//! reported, including the ones the grammar already accepts such as `×`,
+//! `÷` and products written as `2x(3)`, so a tutor can tell which
+//! conventions a student relies on.
+
+use super::config::ParserConfig;
+use crate::core::angle_unit::ANGLE_FUNCTIONS;
+
+/// A notation that differs from the canonical input syntax
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StudentNotation {
+    /// A factor in parentheses juxtaposed with another, as in `2x(3)`
+    ParenthesizedProduct,
+    /// `×` instead of `*`
+    TimesSign,
+    /// `÷` instead of `/`
+    DivisionSign,
+    /// `1 1/2`
+    MixedNumber,
+    /// `50%`
+    Percent,
+    /// `30°`
+    Degrees,
+}
+
+/// Input with the enabled notations rewritten
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NormalizedInput {
+    pub text: String,
+    /// Every student notation found, in order of first appearance
+    pub notations: Vec<StudentNotation>,
+}
+
+/// Rewrite the notations enabled in `config` and report all of them
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::parser::config::ParserConfig;
+/// use mathhook_core::parser::student_notation::{normalize_student_notation, StudentNotation};
+///
+/// let normalized = normalize_student_notation("2 1/4 × 20%", &ParserConfig::student());
+/// assert_eq!(normalized.text, "(2+1/4) × (20/100)");
+/// assert_eq!(
+///     normalized.notations,
+///     vec![StudentNotation::MixedNumber, StudentNotation::TimesSign, StudentNotation::Percent]
+/// );
+/// ```
+pub fn normalize_student_notation(input: &str, config: &ParserConfig) -> NormalizedInput {
+    let chars: Vec<char> = input.chars().collect();
+    let mut notations = Vec::new();
+    let mut note = |notation: StudentNotation| {
+        if !notations.contains(&notation) {
+            notations.push(notation);
+        }
+    };
+    let mut out: Vec<char> = Vec::with_capacity(chars.len());
+
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if let Some((whole, numerator, denominator, end)) = mixed_number_at(&chars, i) {
+            note(StudentNotation::MixedNumber);
+            if config.mixed_numbers {
+                out.extend(format!("({}+{}/{})", whole, numerator, denominator).chars());
+                i = end;
+                continue;
+            }
+        }
+        match c {
+            '×' => note(StudentNotation::TimesSign),
+            '÷' => note(StudentNotation::DivisionSign),
+            '(' if follows_factor(&out) => note(StudentNotation::ParenthesizedProduct),
+            '%' => {
+                note(StudentNotation::Percent);
+                if config.percent {
+                    if let Some(start) = operand_start(&out) {
+                        let operand: String = out.drain(start..).collect();
+                        out.extend(format!("({}/100)", operand).chars());
+                        i += 1;
+                        continue;
+                    }
+                }
+            }
+            '°' => {
+                note(StudentNotation::Degrees);
+                if config.degrees {
+                    if let Some(start) = operand_start(&out) {
+                        let operand: String = out.drain(start..).collect();
+                        let radians = format!("{}*pi/180", operand);
+                        let spaces = out.iter().rev().take_while(|c| **c == ' ').count();
+                        let name_end = out.len() - spaces;
+                        let applied = spaces > 0
+                            && ANGLE_FUNCTIONS.iter().any(|name| {
+                                out[..name_end].ends_with(&name.chars().collect::<Vec<_>>())
+                            });
+                        if applied {
+                            out.truncate(name_end);
+                        }
+                        out.extend(format!("({})", radians).chars());
+                        i += 1;
+                        continue;
+                    }
+                }
+            }
+            _ => {}
+        }
+        out.push(c);
+        i += 1;
+    }
+
+    NormalizedInput {
+        text: out.into_iter().collect(),
+        notations,
+    }
+}
+
+/// Whether `text` ends in a factor that an opening parenthesis would
+/// multiply: `)`, a number, or a coefficient and variable such as `2x`
+fn follows_factor(text: &[char]) -> bool {
+    match text {
+        [.., ')'] => true,
+        [.., last] if last.is_ascii_digit() => true,
+        [.., coefficient, variable] => coefficient.is_ascii_digit() && variable.is_alphabetic(),
+        _ => false,
+    }
+}
+
+/// Start of the number or parenthesized group that ends `text`
+fn operand_start(text: &[char]) -> Option<usize> {
+    match text.last()? {
+        ')' => {
+            let mut depth = 0usize;
+            for (index, c) in text.iter().enumerate().rev() {
+                match c {
+                    ')' => depth += 1,
+                    '(' => {
+                        depth -= 1;
+                        if depth == 0 {
+                            return Some(index);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            None
+        }
+        c if c.is_ascii_digit() => {
+            let digits = text
+                .iter()
+                .rev()
+                .take_while(|c| c.is_ascii_digit() || **c == '.')
+                .count();
+            Some(text.len() - digits)
+        }
+        _ => None,
+    }
+}
+
+/// The mixed number `whole numerator/denominator` starting at `start`, with
+/// the index just past it
+///
+/// Only proper fractions count, and the number must stand alone: `x2 1/2`
+/// and `2 1/2.5` are left as they are.
+fn mixed_number_at(chars: &[char], start: usize) -> Option<(String, String, String, usize)> {
+    let is_number_char = |c: &char| c.is_alphanumeric() || matches!(c, '.' | '_' | '^' | '/');
+    if start > 0 && is_number_char(&chars[start - 1]) {
+        return None;
+    }
+    let digits_at = |from: usize| -> (String, usize) {
+        let digits: String = chars[from..]
+            .iter()
+            .take_while(|c| c.is_ascii_digit())
+            .collect();
+        let end = from + digits.len();
+        (digits, end)
+    };
+
+    let (whole, mut i) = digits_at(start);
+    if whole.is_empty() || chars.get(i) != Some(&' ') {
+        return None;
+    }
+    while chars.get(i) == Some(&' ') {
+        i += 1;
+    }
+    let (numerator, i) = digits_at(i);
+    if numerator.is_empty() || chars.get(i) != Some(&'/') {
+        return None;
+    }
+    let (denominator, end) = digits_at(i + 1);
+    if denominator.is_empty() || chars.get(end).is_some_and(is_number_char) {
+        return None;
+    }
+
+    let proper = match (numerator.parse::<u64>(), denominator.parse::<u64>()) {
+        (Ok(n), Ok(d)) => n < d,
+        _ => false,
+    };
+    proper.then_some((whole, numerator, denominator, end))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn normalize(input: &str) -> String {
+        normalize_student_notation(input, &ParserConfig::student()).text
+    }
+
+    #[test]
+    fn test_rewrites() {
+        assert_eq!(normalize("3 3/4 - 1"), "(3+3/4) - 1");
+        assert_eq!(normalize("x + 12.5%"), "x + (12.5/100)");
+        assert_eq!(normalize("(x+1)%"), "((x+1)/100)");
+        assert_eq!(normalize("cos 60°"), "cos(60*pi/180)");
+        assert_eq!(normalize("2 * 45°"), "2 * (45*pi/180)");
+    }
+
+    #[test]
+    fn test_leaves_ordinary_input_alone() {
+        for input in ["2 3/2", "x 1/2", "2 1/4x", "a/b", "sin(x)"] {
+            assert_eq!(normalize(input), input);
+        }
+    }
+
+    #[test]
+    fn test_detection_without_rewriting() {
+        let normalized = normalize_student_notation("2x(3) ÷ 50%", &ParserConfig::default());
+        assert_eq!(normalized.text, "2x(3) ÷ 50%");
+        assert_eq!(
+            normalized.notations,
+            vec![
+                StudentNotation::ParenthesizedProduct,
+                StudentNotation::DivisionSign,
+                StudentNotation::Percent
+            ]
+        );
+    }
+}
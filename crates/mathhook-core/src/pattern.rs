@@ -9,6 +9,7 @@
 //! 2. **Multiple Substitution**: Apply several substitutions simultaneously
 //! 3. **Pattern Matching**: Match structural patterns with wildcards
 //! 4. **Pattern Replacement**: Apply transformation rules
+//! 5. **Rewrite Rules**: Conditional rule tables, written with the `rules!` macro
 //!
 //! # Examples
 //!
@@ -25,7 +26,9 @@
 //! ```
 
 pub mod matching;
+pub mod rules;
 pub mod substitution;
 
 pub use matching::{Matchable, Pattern, PatternMatches};
+pub use rules::{RewriteRule, RuleSet};
 pub use substitution::Substitutable;
@@ -143,7 +143,7 @@ impl Matchable for Expression {
                     Expression::Pow(Arc::new(new_base), Arc::new(new_exp))
                 }
 
-                Expression::Function { name, args } => {
+                Expression::Function { name, args, id } => {
                     let new_args: Vec<Expression> = args
                         .iter()
                         .map(|a| a.replace(pattern, replacement))
@@ -151,6 +151,7 @@ impl Matchable for Expression {
                     Expression::Function {
                         name: name.clone(),
                         args: Arc::new(new_args),
+                        id: *id,
                     }
                 }
 
@@ -216,6 +217,7 @@ pub(super) fn match_recursive(
             if let Expression::Function {
                 name: expr_name,
                 args: expr_args,
+                ..
             } = expr
             {
                 if expr_name.as_ref() != name.as_str() {
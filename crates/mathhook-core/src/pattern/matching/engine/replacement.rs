@@ -3,6 +3,7 @@
 //! Applies replacement patterns with wildcard bindings from matches.
 
 use super::PatternMatches;
+use crate::core::expression::FunctionId;
 use crate::core::Expression;
 use crate::pattern::matching::patterns::Pattern;
 use std::sync::Arc;
@@ -47,6 +48,7 @@ pub fn apply_replacement(replacement: &Pattern, bindings: &PatternMatches) -> Ex
             Expression::Function {
                 name: Arc::from(name.as_str()),
                 args: Arc::new(new_args),
+                id: FunctionId::of(name),
             }
         }
     }
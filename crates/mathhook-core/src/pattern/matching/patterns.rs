@@ -3,7 +3,8 @@
 //! Provides the core pattern types for structural matching, including
 //! wildcards with constraints and exact matching patterns.
 
-use crate::core::Expression;
+use crate::core::{Expression, Number};
+use std::fmt;
 
 /// Constraints for wildcard pattern matching
 ///
@@ -143,6 +144,66 @@ impl Pattern {
     }
 }
 
+impl Pattern {
+    /// Binding strength in displayed form: sums 1, products 2, powers 3, atoms 4
+    fn precedence(&self) -> u8 {
+        match self {
+            Pattern::Add(_) => 1,
+            Pattern::Mul(_) => 2,
+            Pattern::Pow(..) => 3,
+            Pattern::Exact(expr) => match expr {
+                Expression::Add(_) => 1,
+                Expression::Mul(_) | Expression::Number(Number::Rational(_)) => 2,
+                Expression::Number(_) if expr.is_negative_number() => 2,
+                Expression::Pow(..) => 3,
+                _ => 4,
+            },
+            Pattern::Wildcard { .. } | Pattern::Function { .. } => 4,
+        }
+    }
+
+    /// Display an operand, in parentheses when it binds looser than `min`
+    fn fmt_operand(&self, f: &mut fmt::Formatter<'_>, min: u8) -> fmt::Result {
+        if self.precedence() < min {
+            write!(f, "({})", self)
+        } else {
+            write!(f, "{}", self)
+        }
+    }
+}
+
+/// Rule notation: wildcards carry a trailing underscore, as in `sin(x_)^2`
+impl fmt::Display for Pattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let write_joined = |f: &mut fmt::Formatter<'_>, items: &[Pattern], sep: &str, min| {
+            for (index, item) in items.iter().enumerate() {
+                if index > 0 {
+                    f.write_str(sep)?;
+                }
+                item.fmt_operand(f, min)?;
+            }
+            Ok(())
+        };
+
+        match self {
+            Pattern::Wildcard { name, .. } => write!(f, "{}_", name),
+            Pattern::Exact(expr) => write!(f, "{}", expr),
+            Pattern::Add(terms) => write_joined(f, terms, " + ", 1),
+            Pattern::Mul(factors) => write_joined(f, factors, "*", 2),
+            Pattern::Pow(base, exp) => {
+                base.fmt_operand(f, 4)?;
+                f.write_str("^")?;
+                exp.fmt_operand(f, 4)
+            }
+            Pattern::Function { name, args } => {
+                write!(f, "{}(", name)?;
+                write_joined(f, args, ", ", 1)?;
+                f.write_str(")")
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -224,6 +285,30 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_pattern_display() {
+        let square = |p| {
+            Pattern::Pow(
+                Box::new(p),
+                Box::new(Pattern::Exact(Expression::integer(2))),
+            )
+        };
+        let pattern = Pattern::Add(vec![
+            square(Pattern::Function {
+                name: "sin".to_owned(),
+                args: vec![Pattern::wildcard("x")],
+            }),
+            Pattern::Mul(vec![
+                Pattern::Exact(Expression::integer(-1)),
+                square(Pattern::Add(vec![
+                    Pattern::wildcard("y"),
+                    Pattern::Exact(Expression::integer(1)),
+                ])),
+            ]),
+        ]);
+        assert_eq!(pattern.to_string(), "sin(x_)^2 + -1*(y_ + 1)^2");
+    }
+
     #[test]
     fn test_contains_subexpression_direct() {
         let x = symbol!(x);
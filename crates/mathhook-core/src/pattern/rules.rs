@@ -0,0 +1,448 @@
+//! Declarative rewrite rules
+//!
+//! A [`RewriteRule`] pairs a pattern with a replacement template and optional
+//! conditions on the wildcard bindings. A [`RuleSet`] applies its rules
+//! bottom-up until the expression stops changing. The `rules!` macro builds
+//! rule sets from source such as
+//! `log(x_*y_) => log(x_) + log(y_) if positive(x_, y_)`, and every rule
+//! displays in that same notation so a table can be audited as written.
+
+use super::{Matchable, Pattern, PatternMatches};
+use crate::algebra::solvers::Assumptions;
+use crate::core::expression::ExpressionInterner;
+use crate::core::{Expression, Symbol};
+use std::fmt;
+use std::sync::Arc;
+
+/// Passes after which [`RuleSet::apply`] stops even if rules still fire
+const MAX_PASSES: usize = 32;
+
+/// Sums with more terms are not searched for a matching subset of terms
+const MAX_PARTIAL_TERMS: usize = 12;
+
+/// Property a wildcard binding must have for a rule to fire
+///
+/// Properties are decided conservatively, from the binding itself and the
+/// assumptions declared on its symbols, as in
+/// [`Assumptions`](crate::algebra::solvers::Assumptions).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Property {
+    Positive,
+    Nonnegative,
+    Nonzero,
+    Integer,
+    Real,
+    /// A numeric literal
+    Numeric,
+}
+
+impl Property {
+    /// Name used in rule source, e.g. `positive`
+    pub fn name(&self) -> &'static str {
+        match self {
+            Property::Positive => "positive",
+            Property::Nonnegative => "nonnegative",
+            Property::Nonzero => "nonzero",
+            Property::Integer => "integer",
+            Property::Real => "real",
+            Property::Numeric => "numeric",
+        }
+    }
+
+    /// Whether `expr` is known to have this property
+    pub fn holds(&self, expr: &Expression) -> bool {
+        let known = Assumptions::new();
+        match self {
+            Property::Positive => known.is_positive(expr),
+            Property::Nonnegative => known.is_nonnegative(expr),
+            Property::Nonzero => known.is_nonzero(expr),
+            Property::Integer => known.is_integer(expr),
+            Property::Real => known.is_real(expr),
+            Property::Numeric => matches!(expr, Expression::Number(_)),
+        }
+    }
+}
+
+/// Requirement that the expression bound to a wildcard has a property
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Condition {
+    pub property: Property,
+    pub wildcard: String,
+}
+
+impl Condition {
+    pub fn new(property: Property, wildcard: impl Into<String>) -> Self {
+        Self {
+            property,
+            wildcard: wildcard.into(),
+        }
+    }
+
+    /// Check the condition against the bindings of a successful match
+    pub fn holds(&self, bindings: &PatternMatches) -> bool {
+        bindings
+            .get(&self.wildcard)
+            .is_some_and(|binding| self.property.holds(binding))
+    }
+}
+
+impl fmt::Display for Condition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}({}_)", self.property.name(), self.wildcard)
+    }
+}
+
+/// `pattern => replacement`, provided all conditions hold
+///
+/// Wildcards in the replacement are filled in from the match and the result
+/// is rebuilt in canonical form. A pattern that is a sum also matches any
+/// subset of the terms of a larger sum, so `sin(x_)^2 + cos(x_)^2 => 1` turns
+/// `sin(t)^2 + cos(t)^2 + 1` into `2`.
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::pattern::rules::{Condition, Property, RewriteRule};
+/// use mathhook_core::pattern::Pattern;
+/// use mathhook_core::core::symbol::SymbolAssumptions;
+/// use mathhook_core::{expr, symbol, Expression};
+///
+/// // sqrt(x_^2) => x_ if nonnegative(x_)
+/// let rule = RewriteRule::new(
+///     Pattern::Function {
+///         name: "sqrt".to_owned(),
+///         args: vec![Pattern::Pow(
+///             Box::new(Pattern::wildcard("x")),
+///             Box::new(Pattern::Exact(Expression::integer(2))),
+///         )],
+///     },
+///     Pattern::wildcard("x"),
+/// )
+/// .when(Condition::new(Property::Nonnegative, "x"));
+///
+/// let r = Expression::symbol(symbol!(r).with_assumptions(SymbolAssumptions::new().nonnegative()));
+/// let sqrt_of_square = |base| Expression::function("sqrt", vec![Expression::pow(base, expr!(2))]);
+/// assert_eq!(rule.apply(&sqrt_of_square(r.clone())), Some(r));
+/// assert_eq!(rule.apply(&sqrt_of_square(expr!(t))), None);
+/// assert_eq!(rule.to_string(), "sqrt(x_^2) => x_ if nonnegative(x_)");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RewriteRule {
+    pattern: Pattern,
+    replacement: Pattern,
+    conditions: Vec<Condition>,
+}
+
+impl RewriteRule {
+    /// Unconditional rule
+    pub fn new(pattern: Pattern, replacement: Pattern) -> Self {
+        Self {
+            pattern,
+            replacement,
+            conditions: Vec::new(),
+        }
+    }
+
+    /// Add a condition that must hold for the rule to fire
+    pub fn when(mut self, condition: Condition) -> Self {
+        self.conditions.push(condition);
+        self
+    }
+
+    pub fn pattern(&self) -> &Pattern {
+        &self.pattern
+    }
+
+    pub fn replacement(&self) -> &Pattern {
+        &self.replacement
+    }
+
+    pub fn conditions(&self) -> &[Condition] {
+        &self.conditions
+    }
+
+    /// Rewrite `expr` itself, not its subexpressions, if the rule applies
+    pub fn apply(&self, expr: &Expression) -> Option<Expression> {
+        if let Some(bindings) = self.bindings(expr) {
+            return Some(instantiate(&self.replacement, &bindings));
+        }
+        self.apply_to_terms(expr)
+    }
+
+    /// Bindings of a match whose conditions hold
+    fn bindings(&self, expr: &Expression) -> Option<PatternMatches> {
+        expr.matches(&self.pattern).filter(|bindings| {
+            self.conditions
+                .iter()
+                .all(|condition| condition.holds(bindings))
+        })
+    }
+
+    /// Rewrite a subset of the terms of a sum when the pattern is a shorter sum
+    fn apply_to_terms(&self, expr: &Expression) -> Option<Expression> {
+        let (Pattern::Add(pattern_terms), Expression::Add(terms)) = (&self.pattern, expr) else {
+            return None;
+        };
+        let wanted = pattern_terms.len();
+        if terms.len() <= wanted || terms.len() > MAX_PARTIAL_TERMS {
+            return None;
+        }
+
+        (0u32..1 << terms.len())
+            .filter(|mask| mask.count_ones() as usize == wanted)
+            .find_map(|mask| {
+                let (selected, rest): (Vec<_>, Vec<_>) = terms
+                    .iter()
+                    .enumerate()
+                    .partition(|(index, _)| mask & (1 << index) != 0);
+                let subsum = Expression::Add(Arc::new(
+                    selected.into_iter().map(|(_, term)| term.clone()).collect(),
+                ));
+                let bindings = self.bindings(&subsum)?;
+
+                let mut result: Vec<Expression> =
+                    rest.into_iter().map(|(_, term)| term.clone()).collect();
+                result.push(instantiate(&self.replacement, &bindings));
+                Some(Expression::add(result))
+            })
+    }
+}
+
+impl fmt::Display for RewriteRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} => {}", self.pattern, self.replacement)?;
+        for (index, condition) in self.conditions.iter().enumerate() {
+            let joiner = if index == 0 { " if " } else { " && " };
+            write!(f, "{}{}", joiner, condition)?;
+        }
+        Ok(())
+    }
+}
+
+/// Ordered table of rewrite rules
+///
+/// At every node the first applicable rule wins. Subexpressions are
+/// rewritten before the expressions containing them.
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::pattern::rules::{RewriteRule, RuleSet};
+/// use mathhook_core::pattern::Pattern;
+/// use mathhook_core::{expr, Expression};
+///
+/// // exp(ln(x_)) => x_
+/// let rules = RuleSet::new(vec![RewriteRule::new(
+///     Pattern::Function {
+///         name: "exp".to_owned(),
+///         args: vec![Pattern::Function {
+///             name: "ln".to_owned(),
+///             args: vec![Pattern::wildcard("x")],
+///         }],
+///     },
+///     Pattern::wildcard("x"),
+/// )]);
+///
+/// let nested = Expression::function("exp", vec![Expression::function("ln", vec![expr!(y + 1)])]);
+/// assert_eq!(rules.apply(&expr!(2 * z)), expr!(2 * z));
+/// assert_eq!(rules.apply(&Expression::mul(vec![expr!(2), nested])), expr!(2 * (y + 1)));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RuleSet {
+    rules: Vec<RewriteRule>,
+}
+
+impl RuleSet {
+    pub fn new(rules: Vec<RewriteRule>) -> Self {
+        Self { rules }
+    }
+
+    /// Append a rule, tried after all existing ones
+    pub fn push(&mut self, rule: RewriteRule) {
+        self.rules.push(rule);
+    }
+
+    pub fn rules(&self) -> &[RewriteRule] {
+        &self.rules
+    }
+
+    pub fn len(&self) -> usize {
+        self.rules.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Rewrite until no rule applies anywhere in the expression
+    ///
+    /// Each pass is interned, so a wildcard bound twice and the check for a
+    /// fixed point compare shared subtrees by pointer.
+    pub fn apply(&self, expr: &Expression) -> Expression {
+        let mut interner = ExpressionInterner::new();
+        let mut current = interner.intern(expr);
+        for _ in 0..MAX_PASSES {
+            let next = interner.intern(&self.apply_once(&current));
+            if next.ptr_eq(&current) {
+                break;
+            }
+            current = next;
+        }
+        current
+    }
+
+    /// One bottom-up pass over the expression
+    pub fn apply_once(&self, expr: &Expression) -> Expression {
+        let rebuilt = match expr {
+            Expression::Add(terms) => {
+                Expression::add(terms.iter().map(|t| self.apply_once(t)).collect())
+            }
+            Expression::Mul(factors) => {
+                Expression::mul(factors.iter().map(|f| self.apply_once(f)).collect())
+            }
+            Expression::Pow(base, exp) => {
+                Expression::pow(self.apply_once(base), self.apply_once(exp))
+            }
+            Expression::Function { name, args, .. } => Expression::function(
+                name.as_ref(),
+                args.iter().map(|a| self.apply_once(a)).collect(),
+            ),
+            _ => expr.clone(),
+        };
+
+        self.rules
+            .iter()
+            .find_map(|rule| rule.apply(&rebuilt))
+            .unwrap_or(rebuilt)
+    }
+}
+
+impl fmt::Display for RuleSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for rule in &self.rules {
+            writeln!(f, "{};", rule)?;
+        }
+        Ok(())
+    }
+}
+
+/// Fill a replacement template from the bindings, in canonical form
+///
+/// A wildcard without a binding stays a symbol of the same name.
+fn instantiate(template: &Pattern, bindings: &PatternMatches) -> Expression {
+    match template {
+        Pattern::Wildcard { name, .. } => bindings
+            .get(name)
+            .cloned()
+            .unwrap_or_else(|| Expression::symbol(Symbol::scalar(name))),
+        Pattern::Exact(expr) => expr.clone(),
+        Pattern::Add(terms) => {
+            Expression::add(terms.iter().map(|t| instantiate(t, bindings)).collect())
+        }
+        Pattern::Mul(factors) => {
+            Expression::mul(factors.iter().map(|f| instantiate(f, bindings)).collect())
+        }
+        Pattern::Pow(base, exp) => {
+            Expression::pow(instantiate(base, bindings), instantiate(exp, bindings))
+        }
+        Pattern::Function { name, args } => Expression::function(
+            name.as_str(),
+            args.iter().map(|a| instantiate(a, bindings)).collect(),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::symbol::SymbolAssumptions;
+    use crate::expr;
+
+    fn sin_cos_squares() -> RewriteRule {
+        let square = |name: &str| {
+            Pattern::Pow(
+                Box::new(Pattern::Function {
+                    name: name.to_owned(),
+                    args: vec![Pattern::wildcard("x")],
+                }),
+                Box::new(Pattern::Exact(Expression::integer(2))),
+            )
+        };
+        RewriteRule::new(
+            Pattern::Add(vec![square("sin"), square("cos")]),
+            Pattern::Exact(Expression::integer(1)),
+        )
+    }
+
+    #[test]
+    fn test_rule_matches_subset_of_terms() {
+        let rules = RuleSet::new(vec![sin_cos_squares()]);
+        assert_eq!(rules.apply(&expr!(sin(t) ^ 2 + cos(t) ^ 2)), expr!(1));
+        assert_eq!(rules.apply(&expr!(sin(t) ^ 2 + cos(t) ^ 2 + 1)), expr!(2));
+        assert_eq!(
+            rules.apply(&expr!(sin(t) ^ 2 + cos(u) ^ 2)),
+            expr!(sin(t) ^ 2 + cos(u) ^ 2)
+        );
+    }
+
+    #[test]
+    fn test_conditions_gate_rule() {
+        let log_product = RewriteRule::new(
+            Pattern::Function {
+                name: "log".to_owned(),
+                args: vec![Pattern::Mul(vec![
+                    Pattern::wildcard("x"),
+                    Pattern::wildcard("y"),
+                ])],
+            },
+            Pattern::Add(vec![
+                Pattern::Function {
+                    name: "log".to_owned(),
+                    args: vec![Pattern::wildcard("x")],
+                },
+                Pattern::Function {
+                    name: "log".to_owned(),
+                    args: vec![Pattern::wildcard("y")],
+                },
+            ]),
+        )
+        .when(Condition::new(Property::Positive, "x"))
+        .when(Condition::new(Property::Positive, "y"));
+
+        assert_eq!(
+            log_product.to_string(),
+            "log(x_*y_) => log(x_) + log(y_) if positive(x_) && positive(y_)"
+        );
+        assert_eq!(log_product.apply(&expr!(log(a * b))), None);
+
+        let positive = |name| {
+            Expression::symbol(
+                Symbol::scalar(name).with_assumptions(SymbolAssumptions::new().positive()),
+            )
+        };
+        let (a, b) = (positive("a"), positive("b"));
+        let product =
+            Expression::function("log", vec![Expression::mul(vec![a.clone(), b.clone()])]);
+        assert_eq!(
+            log_product.apply(&product),
+            Some(Expression::add(vec![
+                Expression::function("log", vec![a]),
+                Expression::function("log", vec![b]),
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_rewrite_reaches_fixpoint() {
+        // f(x_) => x_ peels nested calls in one apply
+        let rules = RuleSet::new(vec![RewriteRule::new(
+            Pattern::Function {
+                name: "f".to_owned(),
+                args: vec![Pattern::wildcard("x")],
+            },
+            Pattern::wildcard("x"),
+        )]);
+        assert_eq!(rules.apply(&expr!(f(f(f(y))) + 1)), expr!(y + 1));
+        assert_eq!(rules.to_string(), "f(x_) => x_;\n");
+    }
+}
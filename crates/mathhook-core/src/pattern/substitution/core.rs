@@ -1,7 +1,9 @@
 //! Core substitution trait and single-expression substitution implementation
 
+use super::rewrite::Replacements;
 use crate::core::Expression;
 use crate::simplify::Simplify;
+use std::collections::HashMap;
 use std::sync::Arc;
 
 /// Trait for types that support substitution operations
@@ -74,6 +76,34 @@ pub trait Substitutable {
     /// assert_eq!(result, expected);
     /// ```
     fn subs_multiple(&self, substitutions: &[(Expression, Expression)]) -> Expression;
+
+    /// Apply every substitution in a map simultaneously
+    ///
+    /// Each node is looked up by its structural hash, so the cost of one
+    /// traversal does not grow with the number of replacement pairs.
+    /// Replacements are not themselves rewritten.
+    ///
+    /// # Arguments
+    ///
+    /// * `substitutions` - Map from expressions to their replacements
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mathhook_core::prelude::*;
+    /// use mathhook_core::pattern::Substitutable;
+    /// use std::collections::HashMap;
+    ///
+    /// let substitutions = HashMap::from([
+    ///     (expr!(x), expr!(y)),
+    ///     (expr!(y), expr!(x)),
+    ///     (expr!(sin(z)), Expression::integer(0)),
+    /// ]);
+    ///
+    /// let result = expr!((x ^ 2) + y + sin(z)).subs_map(&substitutions);
+    /// assert_eq!(result, expr!(x + (y ^ 2)).simplify());
+    /// ```
+    fn subs_map(&self, substitutions: &HashMap<Expression, Expression>) -> Expression;
 }
 
 impl Substitutable for Expression {
@@ -104,11 +134,12 @@ impl Substitutable for Expression {
                 Expression::Pow(Arc::new(new_base), Arc::new(new_exp))
             }
 
-            Expression::Function { name, args } => {
+            Expression::Function { name, args, id } => {
                 let new_args: Vec<Expression> = args.iter().map(|a| a.subs(old, new)).collect();
                 Expression::Function {
                     name: name.clone(),
                     args: Arc::new(new_args),
+                    id: *id,
                 }
             }
 
@@ -264,6 +295,10 @@ impl Substitutable for Expression {
     fn subs_multiple(&self, substitutions: &[(Expression, Expression)]) -> Expression {
         super::rewrite::subs_multiple_impl(self, substitutions)
     }
+
+    fn subs_map(&self, substitutions: &HashMap<Expression, Expression>) -> Expression {
+        super::rewrite::rewrite(self, &Replacements::new(substitutions))
+    }
 }
 
 #[cfg(test)]
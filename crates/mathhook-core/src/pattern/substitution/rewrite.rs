@@ -2,70 +2,108 @@
 
 use crate::core::Expression;
 use crate::simplify::Simplify;
+use std::collections::HashMap;
+use std::mem::{self, Discriminant};
 use std::sync::Arc;
 
+/// Replacement table keyed by structural hash
+///
+/// When several pairs share an `old` expression, the first one wins.
+pub(super) struct Replacements<'a> {
+    /// Variants that occur among the keys, checked before a node is hashed
+    kinds: Vec<Discriminant<Expression>>,
+    table: HashMap<&'a Expression, &'a Expression>,
+}
+
+impl<'a> Replacements<'a> {
+    pub(super) fn new<I>(pairs: I) -> Self
+    where
+        I: IntoIterator<Item = (&'a Expression, &'a Expression)>,
+    {
+        let pairs = pairs.into_iter();
+        let mut kinds = Vec::new();
+        let mut table = HashMap::with_capacity(pairs.size_hint().0);
+        for (old, new) in pairs {
+            let kind = mem::discriminant(old);
+            if !kinds.contains(&kind) {
+                kinds.push(kind);
+            }
+            table.entry(old).or_insert(new);
+        }
+        Self { kinds, table }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.table.is_empty()
+    }
+
+    fn get(&self, expr: &Expression) -> Option<&'a Expression> {
+        if !self.kinds.contains(&mem::discriminant(expr)) {
+            return None;
+        }
+        self.table.get(expr).copied()
+    }
+}
+
 /// Implementation for multiple simultaneous substitutions
 pub(super) fn subs_multiple_impl(
     expr: &Expression,
     substitutions: &[(Expression, Expression)],
 ) -> Expression {
-    if substitutions.is_empty() {
+    rewrite(
+        expr,
+        &Replacements::new(substitutions.iter().map(|(old, new)| (old, new))),
+    )
+}
+
+/// Rewrite `expr` in one traversal, replacing every node found in the table
+pub(super) fn rewrite(expr: &Expression, replacements: &Replacements) -> Expression {
+    if replacements.is_empty() {
         return expr.clone();
     }
-
-    for (old, new) in substitutions {
-        if expr == old {
-            return new.clone();
-        }
+    if let Some(new) = replacements.get(expr) {
+        return new.clone();
     }
 
     match expr {
         Expression::Number(_) | Expression::Constant(_) | Expression::Symbol(_) => expr.clone(),
 
         Expression::Add(terms) => {
-            let new_terms: Vec<Expression> = terms
-                .iter()
-                .map(|t| subs_multiple_impl(t, substitutions))
-                .collect();
+            let new_terms: Vec<Expression> =
+                terms.iter().map(|t| rewrite(t, replacements)).collect();
             Expression::Add(Arc::new(new_terms))
         }
 
         Expression::Mul(factors) => {
-            let new_factors: Vec<Expression> = factors
-                .iter()
-                .map(|f| subs_multiple_impl(f, substitutions))
-                .collect();
+            let new_factors: Vec<Expression> =
+                factors.iter().map(|f| rewrite(f, replacements)).collect();
             Expression::Mul(Arc::new(new_factors))
         }
 
         Expression::Pow(base, exp) => {
-            let new_base = subs_multiple_impl(base, substitutions);
-            let new_exp = subs_multiple_impl(exp, substitutions);
+            let new_base = rewrite(base, replacements);
+            let new_exp = rewrite(exp, replacements);
             Expression::Pow(Arc::new(new_base), Arc::new(new_exp))
         }
 
-        Expression::Function { name, args } => {
-            let new_args: Vec<Expression> = args
-                .iter()
-                .map(|a| subs_multiple_impl(a, substitutions))
-                .collect();
+        Expression::Function { name, args, id } => {
+            let new_args: Vec<Expression> = args.iter().map(|a| rewrite(a, replacements)).collect();
             Expression::Function {
                 name: name.clone(),
                 args: Arc::new(new_args),
+                id: *id,
             }
         }
 
         Expression::Set(elements) => {
-            let new_elements: Vec<Expression> = elements
-                .iter()
-                .map(|e| subs_multiple_impl(e, substitutions))
-                .collect();
+            let new_elements: Vec<Expression> =
+                elements.iter().map(|e| rewrite(e, replacements)).collect();
             Expression::Set(Arc::new(new_elements))
         }
 
         Expression::Complex(data) => {
-            let new_real = subs_multiple_impl(&data.real, substitutions);
-            let new_imag = subs_multiple_impl(&data.imag, substitutions);
+            let new_real = rewrite(&data.real, replacements);
+            let new_imag = rewrite(&data.imag, replacements);
             Expression::Complex(Arc::new(crate::core::expression::ComplexData {
                 real: new_real,
                 imag: new_imag,
@@ -80,7 +118,7 @@ pub(super) fn subs_multiple_impl(
                 let mut row: Vec<Expression> = Vec::with_capacity(cols);
                 for j in 0..cols {
                     let elem = matrix.get_element(i, j);
-                    row.push(subs_multiple_impl(&elem, substitutions));
+                    row.push(rewrite(&elem, replacements));
                 }
                 new_data.push(row);
             }
@@ -89,8 +127,8 @@ pub(super) fn subs_multiple_impl(
         }
 
         Expression::Relation(data) => {
-            let new_left = subs_multiple_impl(&data.left, substitutions);
-            let new_right = subs_multiple_impl(&data.right, substitutions);
+            let new_left = rewrite(&data.left, replacements);
+            let new_right = rewrite(&data.right, replacements);
             Expression::Relation(Arc::new(crate::core::expression::RelationData {
                 left: new_left,
                 right: new_right,
@@ -102,18 +140,10 @@ pub(super) fn subs_multiple_impl(
             let new_pieces: Vec<(Expression, Expression)> = data
                 .pieces
                 .iter()
-                .map(|(expr, cond)| {
-                    (
-                        subs_multiple_impl(expr, substitutions),
-                        subs_multiple_impl(cond, substitutions),
-                    )
-                })
+                .map(|(expr, cond)| (rewrite(expr, replacements), rewrite(cond, replacements)))
                 .collect();
 
-            let new_default = data
-                .default
-                .as_ref()
-                .map(|d| subs_multiple_impl(d, substitutions));
+            let new_default = data.default.as_ref().map(|d| rewrite(d, replacements));
 
             Expression::Piecewise(Arc::new(crate::core::expression::PiecewiseData {
                 pieces: new_pieces,
@@ -122,8 +152,8 @@ pub(super) fn subs_multiple_impl(
         }
 
         Expression::Interval(data) => {
-            let new_start = subs_multiple_impl(&data.start, substitutions);
-            let new_end = subs_multiple_impl(&data.end, substitutions);
+            let new_start = rewrite(&data.start, replacements);
+            let new_end = rewrite(&data.end, replacements);
             Expression::Interval(Arc::new(crate::core::expression::IntervalData {
                 start: new_start,
                 end: new_end,
@@ -141,7 +171,7 @@ pub(super) fn subs_multiple_impl(
                     variable,
                     order,
                 } => CalculusData::Derivative {
-                    expression: subs_multiple_impl(expression, substitutions),
+                    expression: rewrite(expression, replacements),
                     variable: variable.clone(),
                     order: *order,
                 },
@@ -151,14 +181,11 @@ pub(super) fn subs_multiple_impl(
                     variable,
                     bounds,
                 } => CalculusData::Integral {
-                    integrand: subs_multiple_impl(integrand, substitutions),
+                    integrand: rewrite(integrand, replacements),
                     variable: variable.clone(),
-                    bounds: bounds.as_ref().map(|(a, b)| {
-                        (
-                            subs_multiple_impl(a, substitutions),
-                            subs_multiple_impl(b, substitutions),
-                        )
-                    }),
+                    bounds: bounds
+                        .as_ref()
+                        .map(|(a, b)| (rewrite(a, replacements), rewrite(b, replacements))),
                 },
 
                 CalculusData::Limit {
@@ -167,9 +194,9 @@ pub(super) fn subs_multiple_impl(
                     point,
                     direction,
                 } => CalculusData::Limit {
-                    expression: subs_multiple_impl(expression, substitutions),
+                    expression: rewrite(expression, replacements),
                     variable: variable.clone(),
-                    point: subs_multiple_impl(point, substitutions),
+                    point: rewrite(point, replacements),
                     direction: *direction,
                 },
 
@@ -179,10 +206,10 @@ pub(super) fn subs_multiple_impl(
                     start,
                     end,
                 } => CalculusData::Sum {
-                    expression: subs_multiple_impl(expression, substitutions),
+                    expression: rewrite(expression, replacements),
                     variable: variable.clone(),
-                    start: subs_multiple_impl(start, substitutions),
-                    end: subs_multiple_impl(end, substitutions),
+                    start: rewrite(start, replacements),
+                    end: rewrite(end, replacements),
                 },
 
                 CalculusData::Product {
@@ -191,10 +218,10 @@ pub(super) fn subs_multiple_impl(
                     start,
                     end,
                 } => CalculusData::Product {
-                    expression: subs_multiple_impl(expression, substitutions),
+                    expression: rewrite(expression, replacements),
                     variable: variable.clone(),
-                    start: subs_multiple_impl(start, substitutions),
-                    end: subs_multiple_impl(end, substitutions),
+                    start: rewrite(start, replacements),
+                    end: rewrite(end, replacements),
                 },
             };
 
@@ -202,12 +229,9 @@ pub(super) fn subs_multiple_impl(
         }
 
         Expression::MethodCall(data) => {
-            let new_object = subs_multiple_impl(&data.object, substitutions);
-            let new_args: Vec<Expression> = data
-                .args
-                .iter()
-                .map(|a| subs_multiple_impl(a, substitutions))
-                .collect();
+            let new_object = rewrite(&data.object, replacements);
+            let new_args: Vec<Expression> =
+                data.args.iter().map(|a| rewrite(a, replacements)).collect();
 
             Expression::MethodCall(Arc::new(crate::core::expression::MethodCallData {
                 object: new_object,
@@ -263,4 +287,36 @@ mod tests {
 
         assert_eq!(result, Expression::integer(49));
     }
+
+    #[test]
+    fn test_subs_map_swaps_in_one_pass() {
+        let substitutions: HashMap<Expression, Expression> = (0..50)
+            .map(|i| {
+                (
+                    Expression::symbol(format!("a{}", i).as_str()),
+                    Expression::integer(i),
+                )
+            })
+            .chain([(expr!(x), expr!(y)), (expr!(y), expr!(x))])
+            .collect();
+        let expr = Expression::add(vec![
+            Expression::pow(Expression::symbol("a7"), Expression::integer(2)),
+            Expression::mul(vec![Expression::symbol("a3"), expr!(x)]),
+            expr!(y),
+        ]);
+
+        let result = expr.subs_map(&substitutions);
+
+        assert_eq!(result, expr!(49 + (3 * y) + x).simplify());
+    }
+
+    #[test]
+    fn test_multiple_substitution_first_pair_wins() {
+        let result = expr!(x + 1).subs_multiple(&[
+            (expr!(x), Expression::integer(1)),
+            (expr!(x), Expression::integer(2)),
+        ]);
+
+        assert_eq!(result, Expression::integer(2));
+    }
 }
@@ -164,7 +164,7 @@ impl MathSerializer {
                 exponent: Box::new(Self::expression_to_data(exp)),
             },
 
-            Expression::Function { name, args } => ExpressionData::Function {
+            Expression::Function { name, args, .. } => ExpressionData::Function {
                 name: name.to_string(),
                 args: args.iter().map(Self::expression_to_data).collect(),
             },
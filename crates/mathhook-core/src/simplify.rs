@@ -5,12 +5,23 @@
 
 use std::sync::Arc;
 
+use crate::algebra::solvers::Assumptions;
+use crate::core::expression::eval_scope::EvalSettings;
 use crate::core::{Expression, Number};
 use crate::matrices::operations::MatrixOperations;
 use num_traits::ToPrimitive;
 pub mod arithmetic;
+pub mod complex_exponential;
 mod constants;
+mod distributions;
 mod functions;
+pub mod min_max;
+pub mod pipeline;
+pub(crate) mod radicals;
+pub mod rounding;
+pub mod verification;
+
+pub use pipeline::{SimplifyDomain, SimplifyPass, SimplifyPipeline};
 
 /// Trait for simplifying expressions
 ///
@@ -87,152 +98,162 @@ pub trait Simplify {
 impl Simplify for Expression {
     #[inline(always)]
     fn simplify(&self) -> Self {
-        match self {
-            Expression::Number(num) => Self::normalize_number(num),
-            Expression::Symbol(_) => self.clone(),
+        EvalSettings::simplify_in_scope(self, |expr| {
+            if verification::is_enabled() {
+                return verification::checked(expr, &Assumptions::new(), simplify_expression);
+            }
+            simplify_expression(expr)
+        })
+    }
+}
 
-            // Delegate arithmetic operations to specialized arithmetic module
-            Expression::Add(terms) => arithmetic::simplify_addition(terms),
-            Expression::Mul(factors) => arithmetic::simplify_multiplication(factors),
-            Expression::Pow(base, exp) => arithmetic::simplify_power(base, exp),
+#[inline(always)]
+fn simplify_expression(expr: &Expression) -> Expression {
+    match expr {
+        Expression::Number(num) => Expression::normalize_number(num),
+        Expression::Symbol(_) => expr.clone(),
 
-            // Delegate function simplification to functions module
-            Expression::Function { name, args } => functions::simplify_function(name, args),
+        // Delegate arithmetic operations to specialized arithmetic module
+        Expression::Add(terms) => arithmetic::simplify_addition(terms),
+        Expression::Mul(factors) => arithmetic::simplify_multiplication(factors),
+        Expression::Pow(base, exp) => arithmetic::simplify_power(base, exp),
 
-            // Delegate constant simplification to constants module
-            Expression::Constant(constant) => constants::simplify_constant(constant),
+        // Delegate function simplification to functions module
+        Expression::Function { name, args, .. } => functions::simplify_function(name, args),
 
-            // Delegate complex and matrix operations to specialized modules
-            Expression::Complex(_) => Expression::simplify_complex(self),
-            Expression::Matrix(_) => self.simplify_matrix(),
+        // Delegate constant simplification to constants module
+        Expression::Constant(constant) => constants::simplify_constant(constant),
 
-            // Handle remaining expression types with proper simplification
-            Expression::Relation(relation) => {
-                // Simplify both sides of relations (equations, inequalities)
-                let simplified_left = relation.left.simplify();
-                let simplified_right = relation.right.simplify();
-                Expression::relation(simplified_left, simplified_right, relation.relation_type)
-            }
-            Expression::Piecewise(piecewise) => {
-                // Simplify each piece's expression and condition
-                let simplified_pieces: Vec<_> = piecewise
-                    .pieces
-                    .iter()
-                    .map(|piece| (piece.0.simplify(), piece.1.simplify()))
-                    .collect();
-                let simplified_default = piecewise.default.as_ref().map(|expr| expr.simplify());
-                Expression::piecewise(simplified_pieces, simplified_default)
-            }
-            Expression::Set(set_elements) => {
-                // Simplify each element in the set
-                let simplified_elements: Vec<_> =
-                    set_elements.iter().map(|elem| elem.simplify()).collect();
-                Expression::set(simplified_elements)
-            }
-            Expression::Interval(interval) => {
-                // Simplify interval bounds
-                let simplified_start = interval.start.simplify();
-                let simplified_end = interval.end.simplify();
-                Expression::interval(
-                    simplified_start,
-                    simplified_end,
-                    interval.start_inclusive,
-                    interval.end_inclusive,
-                )
-            }
-            Expression::Calculus(calc_op) => {
-                // Simplify calculus operations by simplifying their sub-expressions
-                use crate::core::expression::data_types::CalculusData;
-                match calc_op.as_ref() {
-                    CalculusData::Derivative {
-                        expression,
-                        variable,
-                        order,
-                    } => {
-                        let simplified_expr = expression.simplify();
-                        Expression::Calculus(Arc::new(CalculusData::Derivative {
-                            expression: simplified_expr,
-                            variable: variable.clone(),
-                            order: *order,
-                        }))
-                    }
-                    CalculusData::Integral {
-                        integrand,
-                        variable,
-                        bounds,
-                    } => {
-                        let simplified_integrand = integrand.simplify();
-                        let simplified_bounds = bounds
-                            .as_ref()
-                            .map(|(start, end)| (start.simplify(), end.simplify()));
-                        Expression::Calculus(Arc::new(CalculusData::Integral {
-                            integrand: simplified_integrand,
-                            variable: variable.clone(),
-                            bounds: simplified_bounds,
-                        }))
-                    }
-                    CalculusData::Limit {
-                        expression,
-                        variable,
-                        point,
-                        direction,
-                    } => {
-                        let simplified_expr = expression.simplify();
-                        let simplified_point = point.simplify();
-                        Expression::Calculus(Arc::new(CalculusData::Limit {
-                            expression: simplified_expr,
-                            variable: variable.clone(),
-                            point: simplified_point,
-                            direction: *direction,
-                        }))
-                    }
-                    CalculusData::Sum {
-                        expression,
-                        variable,
-                        start,
-                        end,
-                    } => {
-                        let simplified_expr = expression.simplify();
-                        let simplified_start = start.simplify();
-                        let simplified_end = end.simplify();
-                        Expression::Calculus(Arc::new(CalculusData::Sum {
-                            expression: simplified_expr,
-                            variable: variable.clone(),
-                            start: simplified_start,
-                            end: simplified_end,
-                        }))
-                    }
-                    CalculusData::Product {
-                        expression,
-                        variable,
-                        start,
-                        end,
-                    } => {
-                        let simplified_expr = expression.simplify();
-                        let simplified_start = start.simplify();
-                        let simplified_end = end.simplify();
-                        Expression::Calculus(Arc::new(CalculusData::Product {
-                            expression: simplified_expr,
-                            variable: variable.clone(),
-                            start: simplified_start,
-                            end: simplified_end,
-                        }))
-                    }
+        // Delegate complex and matrix operations to specialized modules
+        Expression::Complex(_) => Expression::simplify_complex(expr),
+        Expression::Matrix(_) => expr.simplify_matrix(),
+
+        // Handle remaining expression types with proper simplification
+        Expression::Relation(relation) => {
+            // Simplify both sides of relations (equations, inequalities)
+            let simplified_left = relation.left.simplify();
+            let simplified_right = relation.right.simplify();
+            Expression::relation(simplified_left, simplified_right, relation.relation_type)
+        }
+        Expression::Piecewise(piecewise) => {
+            // Simplify each piece's expression and condition
+            let simplified_pieces: Vec<_> = piecewise
+                .pieces
+                .iter()
+                .map(|piece| (piece.0.simplify(), piece.1.simplify()))
+                .collect();
+            let simplified_default = piecewise.default.as_ref().map(|expr| expr.simplify());
+            Expression::piecewise(simplified_pieces, simplified_default)
+        }
+        Expression::Set(set_elements) => {
+            // Simplify each element in the set
+            let simplified_elements: Vec<_> =
+                set_elements.iter().map(|elem| elem.simplify()).collect();
+            Expression::set(simplified_elements)
+        }
+        Expression::Interval(interval) => {
+            // Simplify interval bounds
+            let simplified_start = interval.start.simplify();
+            let simplified_end = interval.end.simplify();
+            Expression::interval(
+                simplified_start,
+                simplified_end,
+                interval.start_inclusive,
+                interval.end_inclusive,
+            )
+        }
+        Expression::Calculus(calc_op) => {
+            // Simplify calculus operations by simplifying their sub-expressions
+            use crate::core::expression::data_types::CalculusData;
+            match calc_op.as_ref() {
+                CalculusData::Derivative {
+                    expression,
+                    variable,
+                    order,
+                } => {
+                    let simplified_expr = expression.simplify();
+                    Expression::Calculus(Arc::new(CalculusData::Derivative {
+                        expression: simplified_expr,
+                        variable: variable.clone(),
+                        order: *order,
+                    }))
+                }
+                CalculusData::Integral {
+                    integrand,
+                    variable,
+                    bounds,
+                } => {
+                    let simplified_integrand = integrand.simplify();
+                    let simplified_bounds = bounds
+                        .as_ref()
+                        .map(|(start, end)| (start.simplify(), end.simplify()));
+                    Expression::Calculus(Arc::new(CalculusData::Integral {
+                        integrand: simplified_integrand,
+                        variable: variable.clone(),
+                        bounds: simplified_bounds,
+                    }))
+                }
+                CalculusData::Limit {
+                    expression,
+                    variable,
+                    point,
+                    direction,
+                } => {
+                    let simplified_expr = expression.simplify();
+                    let simplified_point = point.simplify();
+                    Expression::Calculus(Arc::new(CalculusData::Limit {
+                        expression: simplified_expr,
+                        variable: variable.clone(),
+                        point: simplified_point,
+                        direction: *direction,
+                    }))
+                }
+                CalculusData::Sum {
+                    expression,
+                    variable,
+                    start,
+                    end,
+                } => {
+                    let simplified_expr = expression.simplify();
+                    let simplified_start = start.simplify();
+                    let simplified_end = end.simplify();
+                    Expression::Calculus(Arc::new(CalculusData::Sum {
+                        expression: simplified_expr,
+                        variable: variable.clone(),
+                        start: simplified_start,
+                        end: simplified_end,
+                    }))
+                }
+                CalculusData::Product {
+                    expression,
+                    variable,
+                    start,
+                    end,
+                } => {
+                    let simplified_expr = expression.simplify();
+                    let simplified_start = start.simplify();
+                    let simplified_end = end.simplify();
+                    Expression::Calculus(Arc::new(CalculusData::Product {
+                        expression: simplified_expr,
+                        variable: variable.clone(),
+                        start: simplified_start,
+                        end: simplified_end,
+                    }))
                 }
             }
-            Expression::MethodCall(method_data) => {
-                let simplified_object = method_data.object.simplify();
-                let simplified_args: Vec<Expression> =
-                    method_data.args.iter().map(|arg| arg.simplify()).collect();
+        }
+        Expression::MethodCall(method_data) => {
+            let simplified_object = method_data.object.simplify();
+            let simplified_args: Vec<Expression> =
+                method_data.args.iter().map(|arg| arg.simplify()).collect();
 
-                // Try to evaluate the method call if possible
-                let method_call = Expression::method_call(
-                    simplified_object,
-                    &method_data.method_name,
-                    simplified_args,
-                );
-                method_call.evaluate_method_call()
-            }
+            // Try to evaluate the method call if possible
+            let method_call = Expression::method_call(
+                simplified_object,
+                &method_data.method_name,
+                simplified_args,
+            );
+            method_call.evaluate_method_call()
         }
     }
 }
@@ -9,7 +9,9 @@ mod matrix_ops;
 mod multiplication;
 mod power;
 
+pub(super) use addition::check_pythagorean;
 pub use addition::simplify_addition;
+pub(crate) use helpers::expression_order;
 pub use matrix_ops::{try_matrix_add, try_matrix_multiply};
 pub use multiplication::simplify_multiplication;
 pub use power::simplify_power;
@@ -1,22 +1,24 @@
 //! Addition simplification operations
 
-use super::helpers::{expression_order, extract_arithmetic_coefficient_and_base};
+use super::helpers::{expression_order, extract_arithmetic_coefficient_and_base, merge_decimals};
 use super::multiplication::simplify_multiplication;
 use super::power::simplify_power;
 use super::Simplify;
 use crate::core::commutativity::Commutativity;
 use crate::core::constants::EPSILON;
+use crate::core::number::Decimal;
 use crate::core::{Expression, Number};
 use num_bigint::BigInt;
 use num_rational::BigRational;
 use num_traits::{ToPrimitive, Zero};
-use std::collections::VecDeque;
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, VecDeque};
 use std::sync::Arc;
 
 fn extract_trig_squared(expr: &Expression, func: &str) -> Option<Expression> {
     if let Expression::Pow(base, exp) = expr {
         if let Expression::Number(Number::Integer(2)) = exp.as_ref() {
-            if let Expression::Function { name, args } = base.as_ref() {
+            if let Expression::Function { name, args, .. } = base.as_ref() {
                 if name.as_ref() == func && args.len() == 1 {
                     return Some(args[0].clone());
                 }
@@ -26,9 +28,17 @@ fn extract_trig_squared(expr: &Expression, func: &str) -> Option<Expression> {
     None
 }
 
-fn check_pythagorean(terms: &[Expression]) -> Option<Vec<Expression>> {
-    for (i, t1) in terms.iter().enumerate() {
-        for (j, t2) in terms.iter().enumerate() {
+pub(in crate::simplify) fn check_pythagorean(terms: &[Expression]) -> Option<Vec<Expression>> {
+    // Only squared sines and cosines can pair up, so long sums stay linear
+    let candidates: Vec<(usize, &Expression)> = terms
+        .iter()
+        .enumerate()
+        .filter(|(_, t)| {
+            extract_trig_squared(t, "sin").is_some() || extract_trig_squared(t, "cos").is_some()
+        })
+        .collect();
+    for &(i, t1) in &candidates {
+        for &(j, t2) in &candidates {
             if i >= j {
                 continue;
             }
@@ -124,6 +134,7 @@ pub fn simplify_addition(terms: &[Expression]) -> Expression {
     let mut float_sum = 0.0;
     let mut has_float = false;
     let mut rational_sum: Option<BigRational> = None;
+    let mut decimal_sum: Option<Decimal> = None;
     let mut non_numeric_count = 0;
     let mut first_non_numeric: Option<Expression> = None;
     let mut numeric_result = None;
@@ -136,8 +147,23 @@ pub fn simplify_addition(terms: &[Expression]) -> Expression {
             _ => term.simplify(),
         };
         match simplified_term {
-            Expression::Number(Number::Integer(n)) => {
-                int_sum = int_sum.saturating_add(n);
+            Expression::Number(Number::Integer(n)) => match int_sum.checked_add(n) {
+                Some(sum) => int_sum = sum,
+                // Past i64, continue exactly in the rational sum
+                None => {
+                    let n = BigRational::from(BigInt::from(n));
+                    rational_sum = Some(match rational_sum {
+                        Some(current_sum) => current_sum + n,
+                        None => n,
+                    });
+                }
+            },
+            Expression::Number(Number::BigInteger(n)) => {
+                let n = BigRational::from(*n);
+                rational_sum = Some(match rational_sum {
+                    Some(current_sum) => current_sum + n,
+                    None => n,
+                });
             }
             Expression::Number(Number::Float(f)) => {
                 float_sum += f;
@@ -150,6 +176,12 @@ pub fn simplify_addition(terms: &[Expression]) -> Expression {
                     rational_sum = Some(r.as_ref().clone());
                 }
             }
+            Expression::Number(Number::Decimal(d)) => {
+                decimal_sum = Some(match decimal_sum {
+                    Some(current_sum) => current_sum.add(&d),
+                    None => *d,
+                });
+            }
             _ => {
                 non_numeric_count += 1;
                 if first_non_numeric.is_none() {
@@ -169,7 +201,11 @@ pub fn simplify_addition(terms: &[Expression]) -> Expression {
             if float_val.abs() >= EPSILON {
                 numeric_result = Some(Expression::Number(Number::float(float_val)));
             }
-        } else if !final_rational.is_zero() {
+        } else if final_rational.is_integer() {
+            if !final_rational.is_zero() {
+                numeric_result = Some(Expression::big_integer(final_rational.to_integer()));
+            }
+        } else {
             numeric_result = Some(Expression::Number(Number::rational(final_rational)));
         }
     } else if has_float {
@@ -180,6 +216,7 @@ pub fn simplify_addition(terms: &[Expression]) -> Expression {
     } else if int_sum != 0 {
         numeric_result = Some(Expression::integer(int_sum));
     }
+    let numeric_result = merge_decimals(numeric_result, decimal_sum, |a, b| a + b, Number::is_zero);
 
     match (numeric_result.as_ref(), non_numeric_count) {
         (None, 0) => Expression::integer(0),
@@ -206,7 +243,8 @@ pub fn simplify_addition(terms: &[Expression]) -> Expression {
                 }
             }
 
-            let mut like_terms: Vec<(String, Expression, Vec<Expression>)> = Vec::new();
+            let mut like_terms: Vec<(Expression, Vec<Expression>)> = Vec::new();
+            let mut like_term_index: HashMap<String, usize> = HashMap::new();
 
             for term in terms {
                 if !matches!(term, Expression::Number(_)) {
@@ -217,27 +255,25 @@ pub fn simplify_addition(terms: &[Expression]) -> Expression {
                         _ => term.simplify(),
                     };
                     match simplified_term {
-                        Expression::Number(Number::Integer(0)) => {}
-                        Expression::Number(Number::Float(0.0)) => {}
+                        // Already counted in the numeric sum
+                        Expression::Number(_) => {}
                         _ => {
                             let (coeff, base) =
                                 extract_arithmetic_coefficient_and_base(&simplified_term);
 
-                            let base_key = format!("{:?}", base);
-
-                            if let Some(entry) =
-                                like_terms.iter_mut().find(|(key, _, _)| key == &base_key)
-                            {
-                                entry.2.push(coeff);
-                            } else {
-                                like_terms.push((base_key, base.clone(), vec![coeff]));
+                            match like_term_index.entry(format!("{:?}", base)) {
+                                Entry::Occupied(entry) => like_terms[*entry.get()].1.push(coeff),
+                                Entry::Vacant(entry) => {
+                                    entry.insert(like_terms.len());
+                                    like_terms.push((base, vec![coeff]));
+                                }
                             }
                         }
                     }
                 }
             }
 
-            for (_, base, coeffs) in like_terms {
+            for (base, coeffs) in like_terms {
                 if coeffs.len() == 1 {
                     let coeff = &coeffs[0];
                     match coeff {
@@ -1,24 +1,49 @@
 //! Helper functions for arithmetic simplification
 
+use crate::core::number::Decimal;
 use crate::core::{Expression, Number};
+use crate::error::MathError;
 use num_traits::ToPrimitive;
 use std::cmp::Ordering;
 use std::sync::Arc;
 
+/// Fold the exact decimals of a sum or product into its other numbers
+///
+/// Decimals are accumulated apart from the integer, rational and float
+/// totals, then combined with them once, so the result follows the decimal
+/// conversion rules. `None` when the result is `identity`.
+pub(super) fn merge_decimals(
+    numeric: Option<Expression>,
+    decimals: Option<Decimal>,
+    combine: fn(Number, Number) -> Result<Number, MathError>,
+    identity: fn(&Number) -> bool,
+) -> Option<Expression> {
+    let Some(decimals) = decimals.map(Number::decimal) else {
+        return numeric;
+    };
+    let merged = match numeric {
+        Some(Expression::Number(other)) => combine(decimals.clone(), other).unwrap_or(decimals),
+        _ => decimals,
+    };
+    (!identity(&merged)).then_some(Expression::Number(merged))
+}
+
 /// Canonical ordering for expressions to ensure consistent output
-pub(super) fn expression_order(a: &Expression, b: &Expression) -> Ordering {
+pub(crate) fn expression_order(a: &Expression, b: &Expression) -> Ordering {
     match (a, b) {
         (Expression::Number(n1), Expression::Number(n2)) => {
             let val1 = match n1 {
                 Number::Integer(i) => *i as f64,
                 Number::Float(f) => *f,
                 Number::Rational(r) => r.to_f64().unwrap_or(0.0),
+                Number::Decimal(d) => d.to_f64().unwrap_or(0.0),
                 _ => 0.0,
             };
             let val2 = match n2 {
                 Number::Integer(i) => *i as f64,
                 Number::Float(f) => *f,
                 Number::Rational(r) => r.to_f64().unwrap_or(0.0),
+                Number::Decimal(d) => d.to_f64().unwrap_or(0.0),
                 _ => 0.0,
             };
             val1.partial_cmp(&val2).unwrap_or(Ordering::Equal)
@@ -54,10 +79,12 @@ pub(super) fn expression_order(a: &Expression, b: &Expression) -> Ordering {
             Expression::Function {
                 name: name1,
                 args: args1,
+                ..
             },
             Expression::Function {
                 name: name2,
                 args: args2,
+                ..
             },
         ) => {
             let name_cmp = name1.cmp(name2);
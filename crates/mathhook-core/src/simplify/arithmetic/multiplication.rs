@@ -7,11 +7,12 @@ pub use binary_numeric::try_simplify_binary;
 pub use power_combining::combine_like_powers;
 
 use super::addition::simplify_addition;
-use super::helpers::expression_order;
+use super::helpers::{expression_order, merge_decimals};
 use super::power::simplify_power;
 use super::Simplify;
 use crate::core::commutativity::Commutativity;
 use crate::core::constants::EPSILON;
+use crate::core::number::Decimal;
 use crate::core::{Expression, Number};
 use num_bigint::BigInt;
 use num_rational::BigRational;
@@ -77,22 +78,17 @@ pub fn simplify_multiplication(factors: &[Expression]) -> Expression {
         }
     }
 
-    let mut all_integers = true;
-    let mut integer_product = 1i64;
-    for factor in factors {
-        match factor {
-            Expression::Number(Number::Integer(n)) => {
-                integer_product = integer_product.saturating_mul(*n);
-            }
-            _ => {
-                all_integers = false;
-                break;
-            }
+    if factors.len() > 2 {
+        let integer_product = factors
+            .iter()
+            .try_fold(1i64, |product, factor| match factor {
+                Expression::Number(Number::Integer(n)) => product.checked_mul(*n),
+                _ => None,
+            });
+        if let Some(product) = integer_product {
+            return Expression::integer(product);
         }
     }
-    if all_integers && factors.len() > 2 {
-        return Expression::integer(integer_product);
-    }
 
     let mut int_product = 1i64;
     let mut float_product = 1.0;
@@ -102,6 +98,7 @@ pub fn simplify_multiplication(factors: &[Expression]) -> Expression {
     let mut numeric_result = None;
 
     let mut rational_product: Option<BigRational> = None;
+    let mut decimal_product: Option<Decimal> = None;
 
     let has_undefined = factors
         .iter()
@@ -109,11 +106,28 @@ pub fn simplify_multiplication(factors: &[Expression]) -> Expression {
 
     for factor in factors {
         match factor {
-            Expression::Number(Number::Integer(n)) => {
-                int_product = int_product.saturating_mul(*n);
-                if int_product == 0 && !has_undefined {
-                    return Expression::integer(0);
+            Expression::Number(Number::Integer(n)) => match int_product.checked_mul(*n) {
+                Some(product) => {
+                    int_product = product;
+                    if int_product == 0 && !has_undefined {
+                        return Expression::integer(0);
+                    }
+                }
+                // Past i64, continue exactly in the rational product
+                None => {
+                    let n = BigInt::from(*n);
+                    rational_product = Some(match rational_product {
+                        Some(current_rational) => current_rational * n,
+                        None => BigRational::from(n),
+                    });
                 }
+            },
+            Expression::Number(Number::BigInteger(n)) => {
+                let n = n.as_ref().clone();
+                rational_product = Some(match rational_product {
+                    Some(current_rational) => current_rational * n,
+                    None => BigRational::from(n),
+                });
             }
             Expression::Number(Number::Float(f)) => {
                 float_product *= f;
@@ -137,6 +151,15 @@ pub fn simplify_multiplication(factors: &[Expression]) -> Expression {
                     return Expression::integer(0);
                 }
             }
+            Expression::Number(Number::Decimal(d)) => {
+                if d.is_zero() && !has_undefined {
+                    return Expression::integer(0);
+                }
+                decimal_product = Some(match decimal_product {
+                    Some(product) => product.mul(d),
+                    None => d.as_ref().clone(),
+                });
+            }
             _ => {
                 non_numeric_count += 1;
                 if first_non_numeric.is_none() {
@@ -156,13 +179,9 @@ pub fn simplify_multiplication(factors: &[Expression]) -> Expression {
             if (float_val - 1.0).abs() >= EPSILON {
                 numeric_result = Some(Expression::Number(Number::float(float_val)));
             }
-        } else if final_rational.denom() == &BigInt::from(1) {
-            if let Some(int_val) = final_rational.numer().to_i64() {
-                if int_val != 1 {
-                    numeric_result = Some(Expression::integer(int_val));
-                }
-            } else if !final_rational.is_one() {
-                numeric_result = Some(Expression::Number(Number::rational(final_rational)));
+        } else if final_rational.is_integer() {
+            if !final_rational.is_one() {
+                numeric_result = Some(Expression::big_integer(final_rational.to_integer()));
             }
         } else if !final_rational.is_one() {
             numeric_result = Some(Expression::Number(Number::rational(final_rational)));
@@ -175,6 +194,12 @@ pub fn simplify_multiplication(factors: &[Expression]) -> Expression {
     } else if int_product != 1 {
         numeric_result = Some(Expression::integer(int_product));
     }
+    let numeric_result = merge_decimals(
+        numeric_result,
+        decimal_product,
+        |a, b| a * b,
+        Number::is_one,
+    );
 
     match (numeric_result.as_ref(), non_numeric_count) {
         (None, 0) => Expression::integer(1),
@@ -22,26 +22,18 @@ use num_traits::ToPrimitive;
 /// Some(simplified) if numeric simplification possible, None otherwise
 pub fn try_simplify_binary(factor1: &Expression, factor2: &Expression) -> Option<Expression> {
     match (factor1, factor2) {
-        (Expression::Number(Number::Integer(a)), Expression::Number(Number::Integer(b))) => {
-            if let Some(result) = a.checked_mul(*b) {
-                Some(Expression::integer(result))
-            } else {
-                Some(Expression::Number(Number::rational(BigRational::new(
-                    BigInt::from(*a) * BigInt::from(*b),
-                    BigInt::from(1),
-                ))))
-            }
-        }
+        (Expression::Number(Number::Integer(a)), Expression::Number(Number::Integer(b))) => Some(
+            Expression::Number(Number::promote_on_overflow(a.checked_mul(*b), || {
+                BigInt::from(*a) * BigInt::from(*b)
+            })),
+        ),
         (Expression::Number(Number::Rational(r)), Expression::Number(Number::Integer(b))) => {
             if r.denom() == &BigInt::from(1) {
                 if let Some(a) = r.numer().to_i64() {
                     if let Some(result) = a.checked_mul(*b) {
                         return Some(Expression::integer(result));
                     } else {
-                        return Some(Expression::Number(Number::rational(BigRational::new(
-                            r.numer() * BigInt::from(*b),
-                            BigInt::from(1),
-                        ))));
+                        return Some(Expression::big_integer(r.numer() * BigInt::from(*b)));
                     }
                 }
             }
@@ -53,10 +45,7 @@ pub fn try_simplify_binary(factor1: &Expression, factor2: &Expression) -> Option
                     if let Some(result) = a.checked_mul(b) {
                         return Some(Expression::integer(result));
                     } else {
-                        return Some(Expression::Number(Number::rational(BigRational::new(
-                            BigInt::from(*a) * r.numer(),
-                            BigInt::from(1),
-                        ))));
+                        return Some(Expression::big_integer(BigInt::from(*a) * r.numer()));
                     }
                 }
             }
@@ -68,10 +57,7 @@ pub fn try_simplify_binary(factor1: &Expression, factor2: &Expression) -> Option
                     if let Some(result) = a.checked_mul(b) {
                         return Some(Expression::integer(result));
                     } else {
-                        return Some(Expression::Number(Number::rational(BigRational::new(
-                            r1.numer() * r2.numer(),
-                            BigInt::from(1),
-                        ))));
+                        return Some(Expression::big_integer(r1.numer() * r2.numer()));
                     }
                 }
             }
@@ -2,8 +2,11 @@
 
 use super::multiplication::simplify_multiplication;
 use super::Simplify;
+use crate::algebra::solvers::Assumptions;
 use crate::core::commutativity::Commutativity;
-use crate::core::{Expression, Number};
+use crate::core::{Expression, MathConstant, Number};
+use crate::simplify::complex_exponential::simplify_complex_exponential;
+use crate::simplify::radicals::combine_powers;
 use num_bigint::BigInt;
 use num_rational::BigRational;
 use std::sync::Arc;
@@ -13,6 +16,14 @@ pub fn simplify_power(base: &Expression, exp: &Expression) -> Expression {
     let simplified_base = base.simplify();
     let simplified_exp = exp.simplify();
 
+    // e^(πi) = -1, e^(x + πi/2) = i·e^x
+    if matches!(simplified_base, Expression::Constant(MathConstant::E)) {
+        let power = |rest| Expression::pow(Expression::e(), rest);
+        if let Some(result) = simplify_complex_exponential(&simplified_exp, power) {
+            return result;
+        }
+    }
+
     match (&simplified_base, &simplified_exp) {
         // x^0 = 1
         (_, Expression::Number(Number::Integer(0))) => Expression::integer(1),
@@ -34,17 +45,10 @@ pub fn simplify_power(base: &Expression, exp: &Expression) -> Expression {
         (Expression::Number(Number::Integer(a)), Expression::Number(Number::Integer(n)))
             if *n > 0 && *a != 0 =>
         {
-            // Use checked_pow to prevent overflow, promote to BigInt on overflow
-            if let Some(result) = (*a).checked_pow(*n as u32) {
-                Expression::integer(result)
-            } else {
-                // Overflow - use BigInt for arbitrary precision
-                let base_big = BigInt::from(*a);
-                let result_big = base_big.pow(*n as u32);
-                Expression::Number(Number::rational(BigRational::new(
-                    result_big,
-                    BigInt::from(1),
-                )))
+            // Promotes to BigInt on overflow; results too large to hold stay unevaluated
+            match Number::Integer(*a).pow(&Number::Integer(*n)) {
+                Ok(result) => Expression::Number(result),
+                Err(_) => Expression::Pow(Arc::new(simplified_base), Arc::new(simplified_exp)),
             }
         }
         // a^(-1) = 1/a (convert to rational for integers)
@@ -67,31 +71,60 @@ pub fn simplify_power(base: &Expression, exp: &Expression) -> Expression {
         (Expression::Number(Number::Rational(r)), Expression::Number(Number::Integer(n)))
             if *n > 0 =>
         {
-            let exp = *n as u32;
-            let numerator = r.numer().pow(exp);
-            let denominator = r.denom().pow(exp);
-            Expression::Number(Number::rational(BigRational::new(numerator, denominator)))
+            let power =
+                |value: &BigInt| Number::big_integer(value.clone()).pow(&Number::Integer(*n));
+            let quotient = power(r.numer()).and_then(|numerator| {
+                power(r.denom()).and_then(|denominator| numerator / denominator)
+            });
+            match quotient {
+                Ok(result) => Expression::Number(result),
+                Err(_) => Expression::Pow(Arc::new(simplified_base), Arc::new(simplified_exp)),
+            }
         }
         // a^(-n) = 1/(a^n) for positive integers a and n
         (Expression::Number(Number::Integer(a)), Expression::Number(Number::Integer(n)))
             if *n < 0 && *a != 0 =>
         {
-            let positive_exp = (-n) as u32;
-            let numerator = BigInt::from(1);
-            let denominator = BigInt::from(*a).pow(positive_exp);
-            Expression::Number(Number::rational(BigRational::new(numerator, denominator)))
+            let reciprocal = n
+                .checked_neg()
+                .and_then(|m| Number::Integer(*a).pow(&Number::Integer(m)).ok())
+                .and_then(|power| (Number::Integer(1) / power).ok());
+            match reciprocal {
+                Some(result) => Expression::Number(result),
+                None => Expression::Pow(Arc::new(simplified_base), Arc::new(simplified_exp)),
+            }
+        }
+        // Exact decimals keep their places: 1.5^2 = 2.25, 0.5^(-2) = 4
+        (
+            Expression::Number(decimal @ Number::Decimal(_)),
+            Expression::Number(Number::Integer(n)),
+        ) if !decimal.is_zero() => {
+            let power = decimal.pow(&Number::Integer(n.unsigned_abs() as i64));
+            let power = if *n < 0 {
+                power.and_then(|p| Number::Integer(1) / p)
+            } else {
+                power
+            };
+            match power {
+                Ok(result) => Expression::Number(result),
+                Err(_) => Expression::Pow(Arc::new(simplified_base), Arc::new(simplified_exp)),
+            }
         }
         // sqrt(x)^2 = x (inverse function)
-        (Expression::Function { name, args }, Expression::Number(Number::Integer(2)))
+        (Expression::Function { name, args, .. }, Expression::Number(Number::Integer(2)))
             if name.as_ref() == "sqrt" && args.len() == 1 =>
         {
             args[0].clone()
         }
-        // (a^b)^c = a^(b*c)
-        (Expression::Pow(b, e), c) => {
-            let new_exp = simplify_multiplication(&[e.as_ref().clone(), c.clone()]);
-            Expression::Pow(Arc::new(b.as_ref().clone()), Arc::new(new_exp))
-        }
+        // (a^b)^c = a^(b*c) for integer c or nonnegative a, (a^2)^(1/2) = |a| for real a
+        (Expression::Pow(b, e), c) => combine_powers(b, e, c, &Assumptions::new())
+            .map(|combined| combined.simplify())
+            .unwrap_or_else(|| {
+                Expression::Pow(
+                    Arc::new(simplified_base.clone()),
+                    Arc::new(simplified_exp.clone()),
+                )
+            }),
         // (a*b)^n = a^n * b^n ONLY if commutative
         (Expression::Mul(factors), Expression::Number(Number::Integer(n))) if *n > 0 => {
             let commutativity = Commutativity::combine(factors.iter().map(|f| f.commutativity()));
@@ -0,0 +1,355 @@
+//! Complex exponentials and Euler's formula
+//!
+//! - `exp(kπi/2)` simplifies to `1`, `i`, `-1` or `-i` for an integer `k`,
+//!   also as a term of a sum: `exp(x + πi) = -exp(x)`
+//! - [`Expression::rewrite_as_trig`] applies Euler's formula,
+//!   `exp(a + bi) = exp(a)(cos b + i sin b)`
+//! - [`Expression::rewrite_as_exp`] goes the other way, writing the
+//!   trigonometric and hyperbolic functions with `exp`, e.g.
+//!   `sin z = (exp(iz) - exp(-iz))/(2i)`
+//!
+//! Rewrites are not simplified, so their shape follows the identity used.
+
+use super::Simplify;
+use crate::core::{Expression, MathConstant, Number};
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::{One, ToPrimitive};
+use std::sync::Arc;
+
+/// Rewrite rule for the already simplified exponent of `exp` or `e^x`
+///
+/// `exponential` builds the exponential of what remains of the exponent
+/// after the `kπi/2` terms are taken out.
+pub(super) fn simplify_complex_exponential(
+    exponent: &Expression,
+    exponential: impl Fn(Expression) -> Expression,
+) -> Option<Expression> {
+    let terms = match exponent {
+        Expression::Add(terms) => terms.as_ref().clone(),
+        _ => vec![exponent.clone()],
+    };
+    let term_count = terms.len();
+
+    // Sum of the k in each kπi/2 term
+    let mut quarter_turns = 0i64;
+    let mut rest = Vec::with_capacity(terms.len());
+    for term in terms {
+        match half_pi_multiple(&term) {
+            Some(k) => quarter_turns = quarter_turns.checked_add(k.rem_euclid(4))?,
+            None => rest.push(term),
+        }
+    }
+    if rest.len() == term_count {
+        return None;
+    }
+
+    let phase = match quarter_turns.rem_euclid(4) {
+        0 => Expression::integer(1),
+        1 => Expression::i(),
+        2 => Expression::integer(-1),
+        _ => Expression::mul(vec![Expression::integer(-1), Expression::i()]),
+    };
+    let remaining = if rest.is_empty() {
+        Expression::integer(1)
+    } else {
+        exponential(Expression::add(rest))
+    };
+    Some(Expression::mul(vec![phase, remaining]).simplify())
+}
+
+/// The integer `k` when `term` is `kπi/2`
+fn half_pi_multiple(term: &Expression) -> Option<i64> {
+    fn flatten<'a>(term: &'a Expression, factors: &mut Vec<&'a Expression>) {
+        match term {
+            Expression::Mul(nested) => nested.iter().for_each(|f| flatten(f, factors)),
+            _ => factors.push(term),
+        }
+    }
+    let mut factors = Vec::new();
+    flatten(term, &mut factors);
+
+    let mut coefficient = BigRational::one();
+    let (mut pi, mut i) = (false, false);
+    for factor in factors {
+        match factor {
+            Expression::Constant(MathConstant::Pi) if !pi => pi = true,
+            Expression::Constant(MathConstant::I) if !i => i = true,
+            Expression::Number(Number::Integer(n)) => coefficient *= BigInt::from(*n),
+            Expression::Number(Number::BigInteger(n)) => coefficient *= n.as_ref().clone(),
+            Expression::Number(Number::Rational(r)) => coefficient *= r.as_ref(),
+            _ => return None,
+        }
+    }
+    let doubled = coefficient * BigInt::from(2);
+    if pi && i && doubled.is_integer() {
+        doubled.to_integer().to_i64()
+    } else {
+        None
+    }
+}
+
+impl Expression {
+    /// Rewrite exponentials with an imaginary exponent by Euler's formula
+    ///
+    /// `exp(a + bi) = exp(a)(cos b + i sin b)`, where the imaginary part `b`
+    /// collects the terms of the exponent carrying a factor `i`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::{expr, Expression};
+    ///
+    /// let exp_ix = Expression::function("exp", vec![Expression::mul(vec![Expression::i(), expr!(x)])]);
+    /// assert_eq!(
+    ///     exp_ix.rewrite_as_trig(),
+    ///     Expression::add(vec![
+    ///         expr!(cos(x)),
+    ///         Expression::mul(vec![Expression::i(), expr!(sin(x))]),
+    ///     ])
+    /// );
+    /// ```
+    pub fn rewrite_as_trig(&self) -> Expression {
+        self.map_functions(&|name, args| match (name, args) {
+            ("exp", [exponent]) => euler(exponent),
+            _ => None,
+        })
+    }
+
+    /// Rewrite trigonometric and hyperbolic functions in terms of `exp`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::{expr, EvalNumeric, Expression};
+    ///
+    /// let sin = Expression::function("sin", vec![expr!(2)]);
+    /// let value = sin.rewrite_as_exp().eval_complex().unwrap();
+    /// assert!((value.re - 2f64.sin()).abs() < 1e-12 && value.im.abs() < 1e-12);
+    /// ```
+    pub fn rewrite_as_exp(&self) -> Expression {
+        self.map_functions(&|name, args| match args {
+            [z] => trig_as_exp(name, z),
+            _ => None,
+        })
+    }
+
+    /// Replace, bottom up, each function call for which `rule` gives a value
+    fn map_functions(
+        &self,
+        rule: &dyn Fn(&str, &[Expression]) -> Option<Expression>,
+    ) -> Expression {
+        match self {
+            Expression::Function { name, args, .. } => {
+                let args: Vec<Expression> = args.iter().map(|a| a.map_functions(rule)).collect();
+                rule(name, &args).unwrap_or_else(|| Expression::function(name.as_ref(), args))
+            }
+            Expression::Add(terms) => {
+                Expression::add(terms.iter().map(|t| t.map_functions(rule)).collect())
+            }
+            Expression::Mul(factors) => {
+                Expression::mul(factors.iter().map(|f| f.map_functions(rule)).collect())
+            }
+            Expression::Pow(base, exponent) => match base.as_ref() {
+                Expression::Constant(MathConstant::E) => {
+                    let exponent = exponent.map_functions(rule);
+                    rule("exp", std::slice::from_ref(&exponent))
+                        .unwrap_or_else(|| Expression::pow(base.as_ref().clone(), exponent))
+                }
+                _ => Expression::pow(base.map_functions(rule), exponent.map_functions(rule)),
+            },
+            Expression::Relation(relation) => {
+                let mut relation = relation.as_ref().clone();
+                relation.left = relation.left.map_functions(rule);
+                relation.right = relation.right.map_functions(rule);
+                Expression::Relation(Arc::new(relation))
+            }
+            _ => self.clone(),
+        }
+    }
+}
+
+/// `exp(a)(cos b + i sin b)` for the exponent `a + bi`, when `b` is nonzero
+fn euler(exponent: &Expression) -> Option<Expression> {
+    let terms = match exponent {
+        Expression::Add(terms) => terms.as_ref().clone(),
+        _ => vec![exponent.clone()],
+    };
+    let (mut real, mut imaginary) = (Vec::new(), Vec::new());
+    for term in terms {
+        match without_i(&term) {
+            Some(coefficient) => imaginary.push(coefficient),
+            None => real.push(term),
+        }
+    }
+    if imaginary.is_empty() {
+        return None;
+    }
+
+    let b = Expression::add(imaginary);
+    let rotation = Expression::add(vec![
+        Expression::function("cos", vec![b.clone()]),
+        Expression::mul(vec![Expression::i(), Expression::function("sin", vec![b])]),
+    ]);
+    Some(if real.is_empty() {
+        rotation
+    } else {
+        Expression::mul(vec![
+            Expression::function("exp", vec![Expression::add(real)]),
+            rotation,
+        ])
+    })
+}
+
+/// `b` when `term` is `b·i` with `b` free of `i`
+fn without_i(term: &Expression) -> Option<Expression> {
+    let is_i = |e: &Expression| matches!(e, Expression::Constant(MathConstant::I));
+    match term {
+        _ if is_i(term) => Some(Expression::integer(1)),
+        Expression::Mul(factors) if factors.iter().filter(|f| is_i(f)).count() == 1 => {
+            let rest: Vec<Expression> = factors.iter().filter(|f| !is_i(f)).cloned().collect();
+            Some(Expression::mul(rest))
+        }
+        _ => None,
+    }
+}
+
+fn trig_as_exp(name: &str, z: &Expression) -> Option<Expression> {
+    let exp = |sign: i64, imaginary: bool| {
+        let mut factors = vec![Expression::integer(sign), z.clone()];
+        if imaginary {
+            factors.push(Expression::i());
+        }
+        Expression::function("exp", vec![Expression::mul(factors)])
+    };
+    let difference = |imaginary: bool| {
+        Expression::add(vec![
+            exp(1, imaginary),
+            Expression::mul(vec![Expression::integer(-1), exp(-1, imaginary)]),
+        ])
+    };
+    let sum = |imaginary: bool| Expression::add(vec![exp(1, imaginary), exp(-1, imaginary)]);
+    let over = |numerator: Expression, denominator: Expression| {
+        Expression::mul(vec![
+            numerator,
+            Expression::pow(denominator, Expression::integer(-1)),
+        ])
+    };
+    // sin z = -i/2 (e^{iz} - e^{-iz}), cos z = (e^{iz} + e^{-iz})/2
+    let sin = || {
+        Expression::mul(vec![
+            Expression::rational(-1, 2),
+            Expression::i(),
+            difference(true),
+        ])
+    };
+    let cos = || Expression::mul(vec![Expression::rational(1, 2), sum(true)]);
+    let tan = || {
+        Expression::mul(vec![
+            Expression::integer(-1),
+            Expression::i(),
+            over(difference(true), sum(true)),
+        ])
+    };
+    let reciprocal = |e: Expression| Expression::pow(e, Expression::integer(-1));
+
+    Some(match name {
+        "sin" => sin(),
+        "cos" => cos(),
+        "tan" => tan(),
+        "csc" => reciprocal(sin()),
+        "sec" => reciprocal(cos()),
+        "cot" => reciprocal(tan()),
+        "sinh" => Expression::mul(vec![Expression::rational(1, 2), difference(false)]),
+        "cosh" => Expression::mul(vec![Expression::rational(1, 2), sum(false)]),
+        "tanh" => over(difference(false), sum(false)),
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{expr, EvalNumeric};
+
+    fn exp(arg: Expression) -> Expression {
+        Expression::function("exp", vec![arg])
+    }
+
+    fn i_pi(coefficient: Expression) -> Expression {
+        Expression::mul(vec![coefficient, Expression::i(), Expression::pi()])
+    }
+
+    #[test]
+    fn test_exp_of_half_pi_multiples() {
+        assert_eq!(exp(i_pi(expr!(1))).simplify(), expr!(-1));
+        assert_eq!(exp(i_pi(expr!(2))).simplify(), expr!(1));
+        assert_eq!(
+            exp(i_pi(Expression::rational(1, 2))).simplify(),
+            Expression::i()
+        );
+        assert_eq!(
+            exp(i_pi(Expression::rational(-1, 2))).simplify(),
+            Expression::mul(vec![expr!(-1), Expression::i()]).simplify()
+        );
+        assert_eq!(
+            exp(Expression::add(vec![expr!(x), i_pi(expr!(1))])).simplify(),
+            Expression::mul(vec![expr!(-1), exp(expr!(x))]).simplify()
+        );
+
+        let third = exp(i_pi(Expression::rational(1, 3)));
+        assert_eq!(third.simplify(), third);
+
+        let power = Expression::pow(Expression::e(), i_pi(expr!(3)));
+        assert_eq!(power.simplify(), expr!(-1));
+    }
+
+    #[test]
+    fn test_rewrites_preserve_value() {
+        let z = Expression::add(vec![
+            Expression::rational(1, 3),
+            Expression::mul(vec![Expression::rational(1, 2), Expression::i()]),
+        ]);
+        for name in [
+            "sin", "cos", "tan", "csc", "sec", "cot", "sinh", "cosh", "tanh",
+        ] {
+            let f = Expression::function(name, vec![z.clone()]);
+            let expected = f.eval_complex().unwrap();
+            let as_exp = f.rewrite_as_exp();
+            assert!(
+                (as_exp.eval_complex().unwrap() - expected).norm() < 1e-12,
+                "{name}: {as_exp}"
+            );
+            let back = as_exp.rewrite_as_trig();
+            assert!(
+                (back.eval_complex().unwrap() - expected).norm() < 1e-12,
+                "{name}: {back}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_euler_with_real_part() {
+        let exponent = Expression::add(vec![
+            expr!(2),
+            Expression::mul(vec![expr!(3), Expression::i()]),
+        ]);
+        assert_eq!(
+            exp(exponent.clone()).rewrite_as_trig(),
+            Expression::mul(vec![
+                exp(expr!(2)),
+                Expression::add(vec![
+                    Expression::function("cos", vec![expr!(3)]),
+                    Expression::mul(vec![
+                        Expression::i(),
+                        Expression::function("sin", vec![expr!(3)])
+                    ]),
+                ]),
+            ])
+        );
+
+        let power = Expression::pow(Expression::e(), exponent.clone());
+        assert_eq!(power.rewrite_as_trig(), exp(exponent).rewrite_as_trig());
+        assert_eq!(exp(expr!(x)).rewrite_as_trig(), exp(expr!(x)));
+    }
+}
@@ -0,0 +1,129 @@
+//! Simplification of Heaviside and Dirac delta expressions
+//!
+//! - `heaviside(u) = 1` when `u` is known positive, `0` when known negative
+//! - `dirac_delta(u) = 0` when `u` is known nonzero
+//! - `dirac_delta(c·u) = dirac_delta(u)/|c|` for a numeric `c`, which includes
+//!   evenness, `dirac_delta(-u) = dirac_delta(u)`; the k-th derivative picks up
+//!   a further `1/cᵏ`
+
+use super::Simplify;
+use crate::algebra::solvers::Assumptions;
+use crate::core::Expression;
+use crate::functions::special::distributions::delta_order;
+
+/// Rewrite rule for already simplified arguments, without assumptions
+pub(super) fn simplify_distribution(name: &str, args: &[Expression]) -> Option<Expression> {
+    rewrite_distribution(name, args, &Assumptions::new()).map(|rewritten| rewritten.simplify())
+}
+
+pub(super) fn rewrite_distribution(
+    name: &str,
+    args: &[Expression],
+    assumptions: &Assumptions,
+) -> Option<Expression> {
+    match (name, args) {
+        ("heaviside", [arg]) => {
+            if is_positive(arg, assumptions) {
+                Some(Expression::integer(1))
+            } else if is_positive(&negate(arg), assumptions) {
+                Some(Expression::integer(0))
+            } else {
+                None
+            }
+        }
+        ("dirac_delta", [arg, ..]) => {
+            let order = delta_order(args)?;
+            if assumptions.is_nonzero(arg) && !matches!(arg, Expression::Number(_)) {
+                return Some(Expression::integer(0));
+            }
+            rescale_delta(arg, order)
+        }
+        _ => None,
+    }
+}
+
+/// `δ⁽ᵏ⁾(c·u) = δ⁽ᵏ⁾(u) / (|c|·cᵏ)`
+fn rescale_delta(arg: &Expression, order: i64) -> Option<Expression> {
+    let Expression::Mul(factors) = arg else {
+        return None;
+    };
+    let [coefficient @ Expression::Number(_), rest @ ..] = factors.as_slice() else {
+        return None;
+    };
+    if coefficient.is_zero() || *coefficient == Expression::integer(1) {
+        return None;
+    }
+
+    let inner = Expression::mul(rest.to_vec());
+    let mut delta_args = vec![inner];
+    if order > 0 {
+        delta_args.push(Expression::integer(order));
+    }
+    let scale = Expression::mul(vec![
+        Expression::function("abs", vec![coefficient.clone()]),
+        Expression::pow(coefficient.clone(), Expression::integer(order)),
+    ]);
+    Some(Expression::mul(vec![
+        Expression::function("dirac_delta", delta_args),
+        Expression::pow(scale, Expression::integer(-1)),
+    ]))
+}
+
+fn is_positive(expr: &Expression, assumptions: &Assumptions) -> bool {
+    assumptions.is_nonnegative(expr) && assumptions.is_nonzero(expr)
+}
+
+fn negate(expr: &Expression) -> Expression {
+    Expression::mul(vec![Expression::integer(-1), expr.clone()]).simplify()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::rounding::simplify_with_assumptions;
+    use super::*;
+    use crate::expr;
+
+    fn heaviside(arg: Expression) -> Expression {
+        Expression::function("heaviside", vec![arg])
+    }
+
+    fn delta(args: Vec<Expression>) -> Expression {
+        Expression::function("dirac_delta", args)
+    }
+
+    #[test]
+    fn test_heaviside_of_signed_expressions() {
+        assert_eq!(heaviside(expr!(exp(x))).simplify(), expr!(1));
+        assert_eq!(heaviside(expr!(-exp(x))).simplify(), expr!(0));
+        assert_eq!(heaviside(expr!(5)).simplify(), expr!(1));
+        assert_eq!(heaviside(expr!(x)).simplify(), heaviside(expr!(x)));
+
+        let assumptions = Assumptions::new().nonnegative(expr!(t)).nonzero(expr!(t));
+        assert_eq!(
+            simplify_with_assumptions(&heaviside(expr!(t)), &assumptions),
+            expr!(1)
+        );
+    }
+
+    #[test]
+    fn test_delta_scaling_and_evenness() {
+        assert_eq!(delta(vec![expr!(-x)]).simplify(), delta(vec![expr!(x)]));
+        assert_eq!(
+            delta(vec![expr!(2 * x)]).simplify(),
+            Expression::mul(vec![Expression::rational(1, 2), delta(vec![expr!(x)])]).simplify()
+        );
+        // δ'(-x) = -δ'(x)
+        assert_eq!(
+            delta(vec![expr!(-x), expr!(1)]).simplify(),
+            Expression::mul(vec![expr!(-1), delta(vec![expr!(x), expr!(1)])]).simplify()
+        );
+    }
+
+    #[test]
+    fn test_delta_vanishes_away_from_zero() {
+        assert_eq!(delta(vec![expr!(3)]).simplify(), expr!(0));
+        assert_eq!(delta(vec![expr!(exp(x))]).simplify(), expr!(0));
+        let delta_at_zero = delta(vec![expr!(0)]);
+        assert_eq!(delta_at_zero.simplify(), delta_at_zero);
+    }
+}
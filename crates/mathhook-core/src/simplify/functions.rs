@@ -35,12 +35,23 @@ pub fn simplify_function(name: &str, args: &[Expression]) -> Expression {
     // First simplify arguments
     let simplified_args: Vec<Expression> = args.iter().map(|arg| arg.simplify()).collect();
 
+    // exp(πi) = -1, exp(x + πi/2) = i·exp(x)
+    if let ("exp", [exponent]) = (name, simplified_args.as_slice()) {
+        let exp = |rest| Expression::function("exp", vec![rest]);
+        if let Some(result) =
+            super::complex_exponential::simplify_complex_exponential(exponent, exp)
+        {
+            return result;
+        }
+    }
+
     // Apply mathematical identities before evaluation
     // exp(log(x)) = x and exp(ln(x)) = x
     if name == "exp" && simplified_args.len() == 1 {
         if let Expression::Function {
             name: inner_name,
             args: inner_args,
+            ..
         } = &simplified_args[0]
         {
             if (inner_name.as_ref() == "log" || inner_name.as_ref() == "ln")
@@ -56,6 +67,7 @@ pub fn simplify_function(name: &str, args: &[Expression]) -> Expression {
         if let Expression::Function {
             name: inner_name,
             args: inner_args,
+            ..
         } = &simplified_args[0]
         {
             if inner_name.as_ref() == "exp" && inner_args.len() == 1 {
@@ -64,6 +76,26 @@ pub fn simplify_function(name: &str, args: &[Expression]) -> Expression {
         }
     }
 
+    // floor(x + n) = floor(x) + n, mod(k*m + r, m) = mod(r, m) for integers n, k
+    if let Some(result) = super::rounding::simplify_rounding(name, &simplified_args) {
+        return result;
+    }
+
+    // max(a, max(b, c)) = max(a, b, c), max(x, x + 1) = x + 1
+    if let Some(result) = super::min_max::simplify_min_max(name, &simplified_args) {
+        return result;
+    }
+
+    // sqrt(x^2) = x and abs(x) = x for nonnegative x
+    if let Some(result) = super::radicals::simplify_radical(name, &simplified_args) {
+        return result;
+    }
+
+    // heaviside(exp(x)) = 1, dirac_delta(-2x) = dirac_delta(x)/2
+    if let Some(result) = super::distributions::simplify_distribution(name, &simplified_args) {
+        return result;
+    }
+
     // Determine if we should keep the expression symbolic
     // Keep transcendental functions (sin, cos, tan, etc.) symbolic when applied to non-zero integers
     // This prevents sin(1) → 0.8414... (numeric), keeping sin(1) → sin(1) (symbolic)
@@ -94,6 +126,16 @@ pub fn simplify_function(name: &str, args: &[Expression]) -> Expression {
             Expression::Number(_) if should_keep_symbolic => {
                 Expression::function(name, simplified_args)
             }
+            // Keep surds such as sqrt(2) exact instead of rounding them to floats
+            Expression::Number(Number::Float(_))
+                if name == "sqrt"
+                    && matches!(
+                        &simplified_args[0],
+                        Expression::Number(Number::Integer(_) | Number::Rational(_))
+                    ) =>
+            {
+                Expression::function(name, simplified_args)
+            }
             // Return non-Function evaluated results (Symbol, Add, Mul, Number, etc.)
             _ => result,
         }
@@ -142,6 +184,10 @@ mod tests {
         // sqrt(4) = 2
         let result = simplify_function("sqrt", &[expr!(4)]);
         assert_eq!(result, expr!(2));
+
+        // sqrt(2) stays exact
+        let result = simplify_function("sqrt", &[expr!(2)]);
+        assert_eq!(result, Expression::function("sqrt", vec![expr!(2)]));
     }
 
     #[test]
@@ -244,7 +290,7 @@ mod tests {
 
         // Should be sin(1) (symbolic), not a float
         match result {
-            Expression::Function { name, args } => {
+            Expression::Function { name, args, .. } => {
                 assert_eq!(name.as_ref(), "sin");
                 assert_eq!(args.len(), 1);
                 assert_eq!(args[0], expr!(1));
@@ -0,0 +1,227 @@
+//! Simplification of min and max expressions
+//!
+//! - Nested calls flatten: `max(a, max(b, c)) = max(a, b, c)`
+//! - An argument dominated by another is dropped: `max(x, x + 1) = x + 1`,
+//!   `min(3, 5, y) = min(3, y)`
+//! - A single remaining argument replaces the call
+//!
+//! `a` dominates `b` in a max when `a - b` is known to be nonnegative, which
+//! `Assumptions` decides; without assumptions only structurally nonnegative
+//! differences (numbers, squares, `abs`) are used.
+
+use super::Simplify;
+use crate::algebra::solvers::Assumptions;
+use crate::core::expression::RelationType;
+use crate::core::Expression;
+
+/// Rewrite rule for already simplified arguments, without assumptions
+pub(super) fn simplify_min_max(name: &str, args: &[Expression]) -> Option<Expression> {
+    rewrite_min_max(name, args, &Assumptions::new()).map(|rewritten| rewritten.simplify())
+}
+
+/// Flatten, drop dominated arguments, and unwrap single-argument calls
+pub(super) fn rewrite_min_max(
+    name: &str,
+    args: &[Expression],
+    assumptions: &Assumptions,
+) -> Option<Expression> {
+    if !matches!(name, "min" | "max") || args.is_empty() {
+        return None;
+    }
+
+    let mut flattened: Vec<Expression> = Vec::with_capacity(args.len());
+    for arg in args {
+        let nested = match arg {
+            Expression::Function {
+                name: inner,
+                args: inner_args,
+                ..
+            } if inner.as_ref() == name => inner_args.to_vec(),
+            other => vec![other.clone()],
+        };
+        for candidate in nested {
+            if !flattened.contains(&candidate) {
+                flattened.push(candidate);
+            }
+        }
+    }
+
+    // For max keep arguments no other argument is known to be ≥; for min ≤
+    let dominates = |a: &Expression, b: &Expression| {
+        let difference = if name == "max" {
+            subtract(a, b)
+        } else {
+            subtract(b, a)
+        };
+        assumptions.is_nonnegative(&difference)
+    };
+    let mut kept: Vec<Expression> = Vec::with_capacity(flattened.len());
+    for (i, candidate) in flattened.iter().enumerate() {
+        let dominated = flattened.iter().enumerate().any(|(j, other)| {
+            // Ties keep the earlier argument
+            i != j && dominates(other, candidate) && (j < i || !dominates(candidate, other))
+        });
+        if !dominated {
+            kept.push(candidate.clone());
+        }
+    }
+
+    if kept.len() == 1 {
+        return kept.pop();
+    }
+    if kept.as_slice() == args {
+        return None;
+    }
+    Some(Expression::function(name, kept))
+}
+
+/// Rewrite every min and max in `expr` as a piecewise expression
+///
+/// `max(a, b)` becomes `a` where `a ≥ b` and `b` otherwise; calls with more
+/// arguments nest, `max(a, b, c) = max(a, max(b, c))`.
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::core::expression::RelationType;
+/// use mathhook_core::simplify::min_max::rewrite_as_piecewise;
+/// use mathhook_core::{expr, Expression};
+///
+/// let max = Expression::function("max", vec![expr!(x), expr!(0)]);
+/// assert_eq!(
+///     rewrite_as_piecewise(&max),
+///     Expression::piecewise(
+///         vec![(
+///             expr!(x),
+///             Expression::relation(expr!(x), expr!(0), RelationType::GreaterEqual),
+///         )],
+///         Some(expr!(0)),
+///     )
+/// );
+/// ```
+pub fn rewrite_as_piecewise(expr: &Expression) -> Expression {
+    match expr {
+        Expression::Function { name, args, .. } => {
+            let args: Vec<Expression> = args.iter().map(rewrite_as_piecewise).collect();
+            match (name.as_ref(), args.as_slice()) {
+                ("min" | "max", [only]) => only.clone(),
+                ("min" | "max", [first, rest @ ..]) => {
+                    let other = if rest.len() == 1 {
+                        rest[0].clone()
+                    } else {
+                        rewrite_as_piecewise(&Expression::function(name.as_ref(), rest.to_vec()))
+                    };
+                    let relation = if name.as_ref() == "max" {
+                        RelationType::GreaterEqual
+                    } else {
+                        RelationType::LessEqual
+                    };
+                    Expression::piecewise(
+                        vec![(
+                            first.clone(),
+                            Expression::relation(first.clone(), other.clone(), relation),
+                        )],
+                        Some(other),
+                    )
+                }
+                _ => Expression::function(name.as_ref(), args),
+            }
+        }
+        Expression::Add(terms) => Expression::add(terms.iter().map(rewrite_as_piecewise).collect()),
+        Expression::Mul(factors) => {
+            Expression::mul(factors.iter().map(rewrite_as_piecewise).collect())
+        }
+        Expression::Pow(base, exponent) => {
+            Expression::pow(rewrite_as_piecewise(base), rewrite_as_piecewise(exponent))
+        }
+        Expression::Relation(relation) => Expression::relation(
+            rewrite_as_piecewise(&relation.left),
+            rewrite_as_piecewise(&relation.right),
+            relation.relation_type,
+        ),
+        _ => expr.clone(),
+    }
+}
+
+fn subtract(a: &Expression, b: &Expression) -> Expression {
+    Expression::add(vec![
+        a.clone(),
+        Expression::mul(vec![Expression::integer(-1), b.clone()]),
+    ])
+    .simplify()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::rounding::simplify_with_assumptions;
+    use super::*;
+    use crate::expr;
+
+    fn max(args: Vec<Expression>) -> Expression {
+        Expression::function("max", args)
+    }
+
+    fn min(args: Vec<Expression>) -> Expression {
+        Expression::function("min", args)
+    }
+
+    #[test]
+    fn test_numeric_arguments_fold() {
+        assert_eq!(
+            max(vec![expr!(3), expr!(7), expr!(-2)]).simplify(),
+            expr!(7)
+        );
+        assert_eq!(
+            min(vec![Expression::rational(1, 2), expr!(1)]).simplify(),
+            Expression::rational(1, 2)
+        );
+        assert_eq!(
+            min(vec![expr!(5), expr!(y), expr!(3)]).simplify(),
+            min(vec![expr!(y), expr!(3)])
+        );
+    }
+
+    #[test]
+    fn test_flattening_and_structural_dominance() {
+        let nested = max(vec![expr!(x), max(vec![expr!(y), expr!(x)])]);
+        assert_eq!(nested.simplify(), max(vec![expr!(x), expr!(y)]));
+        assert_eq!(max(vec![expr!(x), expr!(x + 1)]).simplify(), expr!(x + 1));
+        assert_eq!(min(vec![expr!(x), expr!(x)]).simplify(), expr!(x));
+        assert_eq!(max(vec![expr!(x ^ 2), expr!(0)]).simplify(), expr!(x ^ 2));
+    }
+
+    #[test]
+    fn test_assumptions_decide_order() {
+        let unknown = max(vec![expr!(a), expr!(b)]);
+        assert_eq!(unknown.simplify(), unknown);
+
+        let assumptions = Assumptions::new().nonnegative(expr!(a - b));
+        assert_eq!(simplify_with_assumptions(&unknown, &assumptions), expr!(a));
+        assert_eq!(
+            simplify_with_assumptions(&min(vec![expr!(a), expr!(b)]), &assumptions),
+            expr!(b)
+        );
+    }
+
+    #[test]
+    fn test_piecewise_nests_extra_arguments() {
+        let rewritten = rewrite_as_piecewise(&min(vec![expr!(x), expr!(y), expr!(z)]));
+        let inner = Expression::piecewise(
+            vec![(
+                expr!(y),
+                Expression::relation(expr!(y), expr!(z), RelationType::LessEqual),
+            )],
+            Some(expr!(z)),
+        );
+        assert_eq!(
+            rewritten,
+            Expression::piecewise(
+                vec![(
+                    expr!(x),
+                    Expression::relation(expr!(x), inner.clone(), RelationType::LessEqual),
+                )],
+                Some(inner),
+            )
+        );
+    }
+}
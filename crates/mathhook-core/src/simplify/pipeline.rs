@@ -0,0 +1,673 @@
+//! Configurable simplification pipelines
+//!
+//! [`Simplify::simplify`](super::Simplify::simplify) applies every rule it
+//! knows in one fixed traversal. A [`SimplifyPipeline`] instead runs an
+//! explicit, ordered list of named passes, repeating the list until the
+//! expression stops changing:
+//!
+//! | Pass | Rewrites |
+//! |------|----------|
+//! | [`SimplifyPass::NumericFold`] | `2 + 3 → 5`, `x·1 → x`, `x^0 → 1` |
+//! | [`SimplifyPass::CollectLikeTerms`] | `2x + 3x → 5x` |
+//! | [`SimplifyPass::CombinePowers`] | `x²·x³ → x⁵`, `(x²)³ → x⁶` |
+//! | [`SimplifyPass::Trigonometric`] | `sin(0) → 0`, `sin²x + cos²x → 1` |
+//! | [`SimplifyPass::Radical`] | `√12 → 2√3`, `1/√2 → √2/2` |
+//! | [`SimplifyPass::RationalCancel`] | `(x² - 1)/(x - 1) → x + 1` |
+//! | [`SimplifyPass::FactorCommon`] | `2ħω + 4ħk → 2ħ(ω + 2k)` |
+//! | [`SimplifyPass::MatrixIdentities`] | `(AB)ᵀ → BᵀAᵀ`, `A·A⁻¹ → 1`, `tr(BCA) → tr(ABC)` |
+//! | [`SimplifyPass::BooleanAlgebra`] | `¬¬p → p`, `p ∧ (p ∨ q) → p` |
+//!
+//! The first six make up [`SimplifyPipeline::standard`]. Presets for other
+//! domains, selectable per call through [`SimplifyDomain`], pick their own:
+//!
+//! - [`SimplifyPipeline::physics`] never cancels quotients, so units such as
+//!   `m/s` keep their denominators, and factors shared constants out of sums
+//! - [`SimplifyPipeline::matrix`] keeps only the passes that are sound for
+//!   noncommutative products and adds the `transpose`, `adjoint`, `inverse`
+//!   and `trace` identities
+//! - [`SimplifyPipeline::boolean`] applies the laws of `and`, `or` and `not`
+//!
+//! Passes build raw nodes instead of going through the canonicalizing
+//! constructors, so a pipeline with a pass removed really leaves that rewrite
+//! out. With simplification verification on (see
+//! [`verification`](super::verification)) each pass is checked on its own,
+//! so a reported mismatch points at the pass that introduced it.
+
+mod passes;
+
+use super::verification;
+use crate::algebra::solvers::Assumptions;
+use crate::core::expression::ExpressionInterner;
+use crate::core::Expression;
+use std::fmt;
+use std::sync::Arc;
+
+/// Rounds of the whole pipeline run before giving up on a fixed point
+const DEFAULT_MAX_ROUNDS: usize = 8;
+
+/// Built-in simplification pass
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SimplifyPass {
+    /// Fold numeric operands and drop additive and multiplicative identities
+    NumericFold,
+    /// Add up coefficients of terms that differ only by a numeric factor
+    CollectLikeTerms,
+    /// Merge powers of a common base; noncommutative factors merge only with
+    /// their neighbours
+    CombinePowers,
+    /// Trigonometric values at special points and the Pythagorean identity
+    Trigonometric,
+    /// Square factors out of integer roots, radicals out of denominators
+    Radical,
+    /// Cancel common factors of numerator and denominator
+    RationalCancel,
+    /// Pull the integer content and factors shared by every term out of a
+    /// commutative sum
+    FactorCommon,
+    /// Involution and order reversal of `transpose`, `adjoint` and
+    /// `inverse`, linearity and cyclicity of `trace`, and cancellation of
+    /// adjacent `A·A⁻¹`
+    MatrixIdentities,
+    /// Double negation, associativity, idempotence and absorption of `and`,
+    /// `or` and `not`
+    BooleanAlgebra,
+}
+
+impl SimplifyPass {
+    /// Passes of the standard pipeline, in order
+    pub const STANDARD: [SimplifyPass; 6] = [
+        SimplifyPass::NumericFold,
+        SimplifyPass::CollectLikeTerms,
+        SimplifyPass::CombinePowers,
+        SimplifyPass::Trigonometric,
+        SimplifyPass::Radical,
+        SimplifyPass::RationalCancel,
+    ];
+
+    /// Name of the pass
+    pub fn name(self) -> &'static str {
+        match self {
+            SimplifyPass::NumericFold => "numeric_fold",
+            SimplifyPass::CollectLikeTerms => "collect_like_terms",
+            SimplifyPass::CombinePowers => "combine_powers",
+            SimplifyPass::Trigonometric => "trigonometric",
+            SimplifyPass::Radical => "radical",
+            SimplifyPass::RationalCancel => "rational_cancel",
+            SimplifyPass::FactorCommon => "factor_common",
+            SimplifyPass::MatrixIdentities => "matrix_identities",
+            SimplifyPass::BooleanAlgebra => "boolean_algebra",
+        }
+    }
+
+    /// Apply the pass once to every node of `expr`, bottom-up
+    pub fn apply(self, expr: &Expression) -> Expression {
+        let rule = match self {
+            SimplifyPass::NumericFold => passes::numeric_fold,
+            SimplifyPass::CollectLikeTerms => passes::collect_like_terms,
+            SimplifyPass::CombinePowers => passes::combine_powers,
+            SimplifyPass::Trigonometric => passes::trigonometric,
+            SimplifyPass::Radical => passes::radical,
+            SimplifyPass::RationalCancel => passes::rational_cancel,
+            SimplifyPass::FactorCommon => passes::factor_common,
+            SimplifyPass::MatrixIdentities => passes::matrix_identities,
+            SimplifyPass::BooleanAlgebra => passes::boolean_algebra,
+        };
+        passes::bottom_up(expr, rule)
+    }
+}
+
+/// Domain whose preset pipeline a call simplifies with
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SimplifyDomain {
+    /// [`SimplifyPipeline::standard`]
+    #[default]
+    Standard,
+    /// [`SimplifyPipeline::physics`]
+    Physics,
+    /// [`SimplifyPipeline::matrix`]
+    Matrix,
+    /// [`SimplifyPipeline::boolean`]
+    Boolean,
+}
+
+type Rewrite = Arc<dyn Fn(&Expression) -> Expression + Send + Sync>;
+
+/// A step of a pipeline
+#[derive(Clone)]
+enum Pass {
+    Builtin(SimplifyPass),
+    Custom { name: String, rewrite: Rewrite },
+}
+
+impl Pass {
+    fn name(&self) -> &str {
+        match self {
+            Pass::Builtin(pass) => pass.name(),
+            Pass::Custom { name, .. } => name,
+        }
+    }
+
+    fn apply(&self, expr: &Expression) -> Expression {
+        match self {
+            Pass::Builtin(pass) => pass.apply(expr),
+            Pass::Custom { rewrite, .. } => rewrite(expr),
+        }
+    }
+}
+
+/// Ordered list of simplification passes
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::simplify::{SimplifyPass, SimplifyPipeline};
+/// use mathhook_core::{expr, Expression};
+/// use std::sync::Arc;
+///
+/// let x = expr!(x);
+/// let sum = Expression::Add(Arc::new(vec![expr!(2), expr!(3), x.clone(), x.clone()]));
+///
+/// // Fold numbers but leave the like terms alone
+/// let pipeline = SimplifyPipeline::standard().without_pass(SimplifyPass::CollectLikeTerms);
+/// assert_eq!(
+///     pipeline.run(&sum),
+///     Expression::Add(Arc::new(vec![expr!(5), x.clone(), x.clone()]))
+/// );
+///
+/// let doubled = Expression::Mul(Arc::new(vec![expr!(2), x]));
+/// assert_eq!(
+///     SimplifyPipeline::standard().run(&sum),
+///     Expression::Add(Arc::new(vec![expr!(5), doubled]))
+/// );
+/// ```
+#[derive(Clone)]
+pub struct SimplifyPipeline {
+    passes: Vec<Pass>,
+    max_rounds: usize,
+}
+
+impl SimplifyPipeline {
+    /// Pipeline without any passes
+    pub fn new() -> Self {
+        Self {
+            passes: Vec::new(),
+            max_rounds: DEFAULT_MAX_ROUNDS,
+        }
+    }
+
+    /// Pipeline of the [`SimplifyPass::STANDARD`] passes, in order
+    pub fn standard() -> Self {
+        Self::of(&SimplifyPass::STANDARD)
+    }
+
+    /// Pipeline for physical quantities
+    ///
+    /// Leaves quotients uncancelled so units keep their denominators, and
+    /// factors shared constants out of sums.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::simplify::SimplifyPipeline;
+    /// use mathhook_core::{expr, Expression};
+    /// use std::sync::Arc;
+    ///
+    /// let (hbar, omega, k) = (expr!(hbar), expr!(omega), expr!(k));
+    /// let energy = Expression::Add(Arc::new(vec![
+    ///     Expression::Mul(Arc::new(vec![expr!(2), hbar.clone(), omega.clone()])),
+    ///     Expression::Mul(Arc::new(vec![expr!(4), hbar.clone(), k.clone()])),
+    /// ]));
+    /// assert_eq!(
+    ///     SimplifyPipeline::physics().run(&energy),
+    ///     Expression::Mul(Arc::new(vec![
+    ///         expr!(2),
+    ///         hbar,
+    ///         Expression::Add(Arc::new(vec![omega, Expression::Mul(Arc::new(vec![expr!(2), k]))])),
+    ///     ]))
+    /// );
+    /// ```
+    pub fn physics() -> Self {
+        Self::of(&[
+            SimplifyPass::NumericFold,
+            SimplifyPass::CollectLikeTerms,
+            SimplifyPass::CombinePowers,
+            SimplifyPass::Trigonometric,
+            SimplifyPass::Radical,
+            SimplifyPass::FactorCommon,
+        ])
+    }
+
+    /// Pipeline for products of matrices and other noncommuting symbols
+    ///
+    /// Drops the passes that rely on commuting factors and adds
+    /// [`SimplifyPass::MatrixIdentities`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::simplify::SimplifyPipeline;
+    /// use mathhook_core::{symbol, Expression};
+    /// use std::sync::Arc;
+    ///
+    /// let a = Expression::symbol(symbol!(A; matrix));
+    /// let b = Expression::symbol(symbol!(B; matrix));
+    /// let product = Expression::Mul(Arc::new(vec![a.clone(), b.clone()]));
+    /// assert_eq!(
+    ///     SimplifyPipeline::matrix().run(&Expression::function("transpose", vec![product])),
+    ///     Expression::Mul(Arc::new(vec![
+    ///         Expression::function("transpose", vec![b]),
+    ///         Expression::function("transpose", vec![a]),
+    ///     ]))
+    /// );
+    /// ```
+    pub fn matrix() -> Self {
+        Self::of(&[
+            SimplifyPass::NumericFold,
+            SimplifyPass::CollectLikeTerms,
+            SimplifyPass::CombinePowers,
+            SimplifyPass::MatrixIdentities,
+        ])
+    }
+
+    /// Pipeline for propositions built from `and`, `or` and `not`
+    pub fn boolean() -> Self {
+        Self::of(&[SimplifyPass::BooleanAlgebra])
+    }
+
+    /// Preset pipeline of a domain
+    pub fn for_domain(domain: SimplifyDomain) -> Self {
+        match domain {
+            SimplifyDomain::Standard => Self::standard(),
+            SimplifyDomain::Physics => Self::physics(),
+            SimplifyDomain::Matrix => Self::matrix(),
+            SimplifyDomain::Boolean => Self::boolean(),
+        }
+    }
+
+    fn of(passes: &[SimplifyPass]) -> Self {
+        passes.iter().copied().fold(Self::new(), Self::with_pass)
+    }
+
+    /// Append a built-in pass
+    pub fn with_pass(mut self, pass: SimplifyPass) -> Self {
+        self.passes.push(Pass::Builtin(pass));
+        self
+    }
+
+    /// Append a custom pass, which rewrites the whole expression at once
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::simplify::{Simplify, SimplifyPipeline};
+    /// use mathhook_core::expr;
+    ///
+    /// let pipeline = SimplifyPipeline::new().with_custom_pass("full", |e| e.simplify());
+    /// assert_eq!(pipeline.pass_names(), vec!["full"]);
+    /// assert_eq!(pipeline.run(&expr!(x + x)), expr!(2 * x));
+    /// ```
+    pub fn with_custom_pass<F>(mut self, name: &str, rewrite: F) -> Self
+    where
+        F: Fn(&Expression) -> Expression + Send + Sync + 'static,
+    {
+        self.passes.push(Pass::Custom {
+            name: name.to_owned(),
+            rewrite: Arc::new(rewrite),
+        });
+        self
+    }
+
+    /// Remove every occurrence of a built-in pass
+    pub fn without_pass(mut self, pass: SimplifyPass) -> Self {
+        self.passes
+            .retain(|p| !matches!(p, Pass::Builtin(builtin) if *builtin == pass));
+        self
+    }
+
+    /// Remove every pass, built-in or custom, with the given name
+    pub fn without_named_pass(mut self, name: &str) -> Self {
+        self.passes.retain(|p| p.name() != name);
+        self
+    }
+
+    /// Limit how often the pass list is repeated while the expression changes
+    pub fn with_max_rounds(mut self, max_rounds: usize) -> Self {
+        self.max_rounds = max_rounds;
+        self
+    }
+
+    /// Names of the passes, in the order they run
+    pub fn pass_names(&self) -> Vec<&str> {
+        self.passes.iter().map(Pass::name).collect()
+    }
+
+    /// Run the passes in order, repeating until nothing changes or the round
+    /// limit is reached
+    ///
+    /// Rounds are interned, so subtrees a round leaves unchanged share
+    /// storage with the previous round and the fixed-point check compares
+    /// pointers instead of walking the whole expression.
+    pub fn run(&self, expr: &Expression) -> Expression {
+        let mut interner = ExpressionInterner::new();
+        let mut current = interner.intern(expr);
+        for _ in 0..self.max_rounds {
+            let before = current.clone();
+            for pass in &self.passes {
+                current = verification::checked(&current, &Assumptions::new(), |e| pass.apply(e));
+            }
+            current = interner.intern(&current);
+            if current.ptr_eq(&before) {
+                break;
+            }
+        }
+        current
+    }
+}
+
+impl Default for SimplifyPipeline {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+impl fmt::Debug for SimplifyPipeline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SimplifyPipeline")
+            .field("passes", &self.pass_names())
+            .field("max_rounds", &self.max_rounds)
+            .finish()
+    }
+}
+
+impl Expression {
+    /// Simplify with a custom pipeline of passes
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::simplify::{SimplifyPass, SimplifyPipeline};
+    /// use mathhook_core::{expr, Expression};
+    ///
+    /// let powers = expr!((x ^ 2) * (x ^ 3));
+    /// let pipeline = SimplifyPipeline::new().with_pass(SimplifyPass::CombinePowers);
+    /// assert_eq!(powers.simplify_with(&pipeline), expr!(x ^ 5));
+    /// ```
+    pub fn simplify_with(&self, pipeline: &SimplifyPipeline) -> Expression {
+        pipeline.run(self)
+    }
+
+    /// Simplify with the preset pipeline of `domain`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::simplify::SimplifyDomain;
+    /// use mathhook_core::{expr, Expression};
+    ///
+    /// let p = expr!(p);
+    /// let double_negation = Expression::function("not", vec![Expression::function("not", vec![p.clone()])]);
+    /// assert_eq!(double_negation.simplify_for(SimplifyDomain::Boolean), p);
+    /// ```
+    pub fn simplify_for(&self, domain: SimplifyDomain) -> Expression {
+        self.simplify_with(&SimplifyPipeline::for_domain(domain))
+    }
+
+    /// Simplify with [`SimplifyPipeline::physics`]
+    pub fn simplify_physics(&self) -> Expression {
+        self.simplify_for(SimplifyDomain::Physics)
+    }
+
+    /// Simplify with [`SimplifyPipeline::boolean`]
+    pub fn simplify_boolean(&self) -> Expression {
+        self.simplify_for(SimplifyDomain::Boolean)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Symbol;
+    use crate::expr;
+    use crate::simplify::Simplify;
+
+    fn raw_add(terms: Vec<Expression>) -> Expression {
+        Expression::Add(Arc::new(terms))
+    }
+
+    fn raw_mul(factors: Vec<Expression>) -> Expression {
+        Expression::Mul(Arc::new(factors))
+    }
+
+    fn raw_pow(base: Expression, exponent: Expression) -> Expression {
+        Expression::Pow(Arc::new(base), Arc::new(exponent))
+    }
+
+    #[test]
+    fn test_empty_pipeline_is_identity() {
+        let input = raw_add(vec![expr!(1), expr!(2), expr!(x)]);
+        assert_eq!(SimplifyPipeline::new().run(&input), input);
+    }
+
+    #[test]
+    fn test_each_pass_in_isolation() {
+        let x = expr!(x);
+        let cases = [
+            (
+                SimplifyPass::NumericFold,
+                raw_mul(vec![expr!(2), expr!(3), x.clone(), expr!(1)]),
+                raw_mul(vec![expr!(6), x.clone()]),
+            ),
+            (
+                SimplifyPass::CollectLikeTerms,
+                raw_add(vec![raw_mul(vec![expr!(2), x.clone()]), x.clone()]),
+                raw_mul(vec![expr!(3), x.clone()]),
+            ),
+            (
+                SimplifyPass::CombinePowers,
+                raw_pow(raw_pow(x.clone(), expr!(2)), expr!(3)),
+                raw_pow(x.clone(), expr!(6)),
+            ),
+            (
+                SimplifyPass::Trigonometric,
+                raw_add(vec![
+                    raw_pow(expr!(sin(x)), expr!(2)),
+                    raw_pow(expr!(cos(x)), expr!(2)),
+                ]),
+                expr!(1),
+            ),
+            (
+                SimplifyPass::Radical,
+                Expression::sqrt(expr!(12)),
+                raw_mul(vec![expr!(2), Expression::sqrt(expr!(3))]),
+            ),
+        ];
+        for (pass, input, expected) in cases {
+            let pipeline = SimplifyPipeline::new().with_pass(pass);
+            assert_eq!(pipeline.run(&input), expected, "{}", pass.name());
+        }
+    }
+
+    #[test]
+    fn test_rational_cancel() {
+        let quotient = raw_mul(vec![expr!((x ^ 2) - 1), raw_pow(expr!(x - 1), expr!(-1))]);
+        let cancelled = SimplifyPipeline::new()
+            .with_pass(SimplifyPass::RationalCancel)
+            .run(&quotient);
+        assert_eq!(cancelled.simplify(), expr!(x + 1));
+    }
+
+    #[test]
+    fn test_noncommutative_powers_merge_only_when_adjacent() {
+        let a = Expression::symbol(Symbol::matrix("A"));
+        let b = Expression::symbol(Symbol::matrix("B"));
+        let product = raw_mul(vec![a.clone(), b.clone(), a.clone()]);
+        let pipeline = SimplifyPipeline::new().with_pass(SimplifyPass::CombinePowers);
+        assert_eq!(pipeline.run(&product), product);
+
+        let adjacent = raw_mul(vec![a.clone(), a.clone(), b.clone()]);
+        assert_eq!(
+            pipeline.run(&adjacent),
+            raw_mul(vec![raw_pow(a, expr!(2)), b])
+        );
+    }
+
+    #[test]
+    fn test_standard_pipeline_reaches_fixed_point() {
+        let x = expr!(x);
+        let input = raw_add(vec![
+            raw_mul(vec![raw_pow(x.clone(), expr!(2)), x.clone()]),
+            raw_pow(x.clone(), expr!(3)),
+            raw_pow(expr!(sin(x)), expr!(2)),
+            raw_pow(expr!(cos(x)), expr!(2)),
+        ]);
+        let result = SimplifyPipeline::standard().run(&input);
+        assert_eq!(
+            result,
+            raw_add(vec![
+                raw_mul(vec![expr!(2), raw_pow(x, expr!(3))]),
+                expr!(1)
+            ])
+        );
+    }
+
+    #[test]
+    fn test_builder_composition() {
+        let pipeline = SimplifyPipeline::standard()
+            .without_pass(SimplifyPass::Trigonometric)
+            .with_custom_pass("expand", |e| e.clone())
+            .without_named_pass("radical");
+        assert_eq!(
+            pipeline.pass_names(),
+            vec![
+                "numeric_fold",
+                "collect_like_terms",
+                "combine_powers",
+                "rational_cancel",
+                "expand"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_physics_keeps_quotients() {
+        let (m, s) = (expr!(m), expr!(s));
+        let speed = raw_mul(vec![
+            expr!(3),
+            m.clone(),
+            raw_pow(s.clone(), expr!(-1)),
+            s.clone(),
+            raw_pow(s.clone(), expr!(-1)),
+        ]);
+        // s·s⁻¹ combines, but the remaining s⁻¹ unit stays
+        assert_eq!(
+            speed.simplify_physics(),
+            raw_mul(vec![expr!(3), m, raw_pow(s, expr!(-1))])
+        );
+    }
+
+    #[test]
+    fn test_physics_factors_common_constants() {
+        let (c, v, u) = (expr!(c), expr!(v), expr!(u));
+        let sum = raw_add(vec![
+            raw_mul(vec![expr!(6), c.clone(), v.clone()]),
+            raw_mul(vec![expr!(-9), c.clone(), u.clone()]),
+        ]);
+        assert_eq!(
+            sum.simplify_physics(),
+            raw_mul(vec![
+                expr!(3),
+                c,
+                raw_add(vec![
+                    raw_mul(vec![expr!(2), v]),
+                    raw_mul(vec![expr!(-3), u])
+                ]),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_matrix_identities() {
+        use crate::matrices::operations::MatrixOperations;
+
+        let a = Expression::symbol(Symbol::matrix("A"));
+        let b = Expression::symbol(Symbol::matrix("B"));
+        let transpose = |e: Expression| Expression::function("transpose", vec![e]);
+        let inverse = |e: Expression| Expression::function("inverse", vec![e]);
+
+        assert_eq!(transpose(transpose(a.clone())).simplify_matrix(), a);
+
+        let product = raw_mul(vec![a.clone(), inverse(a.clone()), b.clone()]);
+        assert_eq!(product.simplify_matrix(), b);
+
+        let scaled = transpose(raw_mul(vec![expr!(2), a.clone(), b.clone()]));
+        assert_eq!(
+            scaled.simplify_matrix(),
+            raw_mul(vec![expr!(2), transpose(b.clone()), transpose(a.clone())])
+        );
+
+        // A·B·A⁻¹ does not cancel
+        let conjugated = raw_mul(vec![a.clone(), b.clone(), inverse(a.clone())]);
+        assert_eq!(conjugated.simplify_matrix(), conjugated);
+
+        let adjoint = |e: Expression| Expression::function("adjoint", vec![e]);
+        assert_eq!(adjoint(adjoint(a.clone())).simplify_matrix(), a);
+        assert_eq!(
+            adjoint(raw_mul(vec![a.clone(), b.clone()])).simplify_matrix(),
+            raw_mul(vec![adjoint(b.clone()), adjoint(a.clone())])
+        );
+    }
+
+    #[test]
+    fn test_trace_identities() {
+        use crate::matrices::operations::MatrixOperations;
+
+        let a = Expression::symbol(Symbol::matrix("A"));
+        let b = Expression::symbol(Symbol::matrix("B"));
+        let c = Expression::symbol(Symbol::matrix("C"));
+        let trace = |e: Expression| Expression::function("trace", vec![e]);
+        let transpose = |e: Expression| Expression::function("transpose", vec![e]);
+        let abc = raw_mul(vec![a.clone(), b.clone(), c.clone()]);
+
+        let rotated = trace(raw_mul(vec![c.clone(), a.clone(), b.clone()]));
+        assert_eq!(rotated.simplify_matrix(), trace(abc.clone()));
+
+        // (ABC)ᵀ = CᵀBᵀAᵀ, whose trace is that of ABC
+        assert_eq!(trace(transpose(abc.clone())).simplify_matrix(), trace(abc));
+
+        let linear = trace(Expression::Add(Arc::new(vec![
+            raw_mul(vec![expr!(3), a.clone()]),
+            b.clone(),
+        ])));
+        assert_eq!(
+            linear.simplify_matrix(),
+            Expression::Add(Arc::new(vec![raw_mul(vec![expr!(3), trace(a)]), trace(b)]))
+        );
+    }
+
+    #[test]
+    fn test_boolean_laws() {
+        let (p, q, r) = (expr!(p), expr!(q), expr!(r));
+        let and = |args: Vec<Expression>| Expression::function("and", args);
+        let or = |args: Vec<Expression>| Expression::function("or", args);
+
+        let absorbed = and(vec![p.clone(), or(vec![p.clone(), q.clone()])]);
+        assert_eq!(absorbed.simplify_boolean(), p);
+
+        let nested = or(vec![p.clone(), or(vec![q.clone(), p.clone()]), r.clone()]);
+        assert_eq!(nested.simplify_boolean(), or(vec![p, q, r]));
+    }
+
+    #[test]
+    fn test_domain_selection() {
+        let double_negation =
+            Expression::function("not", vec![Expression::function("not", vec![expr!(p)])]);
+        assert_eq!(
+            double_negation.simplify_for(SimplifyDomain::Standard),
+            double_negation
+        );
+        assert_eq!(
+            double_negation.simplify_for(SimplifyDomain::Boolean),
+            expr!(p)
+        );
+    }
+}
@@ -0,0 +1,561 @@
+//! Rewrite rules of the built-in simplification passes
+//!
+//! Each pass is a rule for a single node, applied bottom-up. Rules build their
+//! results from raw nodes rather than the canonicalizing constructors, so a
+//! pass does its own rewrite and nothing else.
+
+use crate::algebra::simplification::get_simplification_registry;
+use crate::algebra::{RationalSimplify, RationalizeDenominator};
+use crate::core::commutativity::Commutativity;
+use crate::core::expression::matrix_methods::canonical_rotation;
+use crate::core::{Expression, Number};
+use crate::simplify::arithmetic::check_pythagorean;
+use num_integer::Integer;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Largest trial divisor when pulling square factors out of a radicand
+const MAX_SQUARE_FACTOR: i64 = 1_000_000;
+
+/// Rebuild `expr` with every child rewritten, then apply `rule` to the node
+pub(super) fn bottom_up(
+    expr: &Expression,
+    rule: fn(&Expression) -> Option<Expression>,
+) -> Expression {
+    let rebuilt = match expr {
+        Expression::Add(terms) => {
+            Expression::Add(Arc::new(terms.iter().map(|t| bottom_up(t, rule)).collect()))
+        }
+        Expression::Mul(factors) => Expression::Mul(Arc::new(
+            factors.iter().map(|f| bottom_up(f, rule)).collect(),
+        )),
+        Expression::Pow(base, exponent) => Expression::Pow(
+            Arc::new(bottom_up(base, rule)),
+            Arc::new(bottom_up(exponent, rule)),
+        ),
+        Expression::Function { name, args, id } => Expression::Function {
+            name: name.clone(),
+            args: Arc::new(args.iter().map(|a| bottom_up(a, rule)).collect()),
+            id: *id,
+        },
+        _ => return rule(expr).unwrap_or_else(|| expr.clone()),
+    };
+    rule(&rebuilt).unwrap_or(rebuilt)
+}
+
+fn raw_add(mut terms: Vec<Expression>) -> Expression {
+    match terms.len() {
+        0 => Expression::integer(0),
+        1 => terms.pop().expect("BUG: one term"),
+        _ => Expression::Add(Arc::new(terms)),
+    }
+}
+
+fn raw_mul(mut factors: Vec<Expression>) -> Expression {
+    match factors.len() {
+        0 => Expression::integer(1),
+        1 => factors.pop().expect("BUG: one factor"),
+        _ => Expression::Mul(Arc::new(factors)),
+    }
+}
+
+/// Fold numeric operands of sums, products and powers, and drop identities
+pub(super) fn numeric_fold(expr: &Expression) -> Option<Expression> {
+    match expr {
+        Expression::Add(terms) => {
+            let (numbers, rest): (Vec<_>, Vec<_>) = terms
+                .iter()
+                .cloned()
+                .partition(|t| matches!(t, Expression::Number(_)));
+            if numbers.len() < 2 && !numbers.iter().any(Expression::is_zero) {
+                return None;
+            }
+            let sum = Expression::add(numbers);
+            let mut folded = Vec::with_capacity(rest.len() + 1);
+            if !sum.is_zero() {
+                folded.push(sum);
+            }
+            folded.extend(rest);
+            Some(raw_add(folded))
+        }
+        Expression::Mul(factors) => {
+            let (numbers, rest): (Vec<_>, Vec<_>) = factors
+                .iter()
+                .cloned()
+                .partition(|f| matches!(f, Expression::Number(_)));
+            if numbers.len() < 2 && !numbers.iter().any(|n| n.is_zero() || n.is_one()) {
+                return None;
+            }
+            let product = Expression::mul(numbers);
+            if product.is_zero() {
+                return Some(Expression::integer(0));
+            }
+            let mut folded = Vec::with_capacity(rest.len() + 1);
+            if !product.is_one() {
+                folded.push(product);
+            }
+            folded.extend(rest);
+            Some(raw_mul(folded))
+        }
+        Expression::Pow(base, exponent) => match (base.as_ref(), exponent.as_ref()) {
+            (_, exponent) if exponent.is_zero() => Some(Expression::integer(1)),
+            (base, exponent) if exponent.is_one() => Some(base.clone()),
+            (Expression::Number(_), Expression::Number(_)) => {
+                let power = Expression::pow(base.as_ref().clone(), exponent.as_ref().clone());
+                matches!(power, Expression::Number(_)).then_some(power)
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Split a term into its numeric coefficient and the rest
+fn coefficient_and_term(term: &Expression) -> (Expression, Expression) {
+    match term {
+        Expression::Number(_) => (term.clone(), Expression::integer(1)),
+        Expression::Mul(factors) => {
+            let (numbers, rest): (Vec<_>, Vec<_>) = factors
+                .iter()
+                .cloned()
+                .partition(|f| matches!(f, Expression::Number(_)));
+            (Expression::mul(numbers), raw_mul(rest))
+        }
+        _ => (Expression::integer(1), term.clone()),
+    }
+}
+
+/// Add up the coefficients of terms that differ only by a numeric factor
+pub(super) fn collect_like_terms(expr: &Expression) -> Option<Expression> {
+    let Expression::Add(terms) = expr else {
+        return None;
+    };
+    let mut groups: Vec<(Expression, Vec<Expression>)> = Vec::new();
+    let mut index: HashMap<Expression, usize> = HashMap::new();
+    for term in terms.iter() {
+        let (coefficient, rest) = coefficient_and_term(term);
+        match index.get(&rest) {
+            Some(&i) => groups[i].1.push(coefficient),
+            None => {
+                index.insert(rest.clone(), groups.len());
+                groups.push((rest, vec![coefficient]));
+            }
+        }
+    }
+    if groups.len() == terms.len() {
+        return None;
+    }
+    let collected = groups
+        .into_iter()
+        .filter_map(|(rest, coefficients)| {
+            let coefficient = Expression::add(coefficients);
+            if coefficient.is_zero() {
+                None
+            } else if rest.is_one() {
+                Some(coefficient)
+            } else if coefficient.is_one() {
+                Some(rest)
+            } else {
+                Some(raw_mul(vec![coefficient, rest]))
+            }
+        })
+        .collect();
+    Some(raw_add(collected))
+}
+
+fn base_and_exponent(factor: &Expression) -> (&Expression, Expression) {
+    match factor {
+        Expression::Pow(base, exponent) => (base.as_ref(), exponent.as_ref().clone()),
+        _ => (factor, Expression::integer(1)),
+    }
+}
+
+fn power(base: Expression, exponents: Vec<Expression>) -> Expression {
+    let exponent = raw_add(exponents);
+    let exponent = numeric_fold(&exponent).unwrap_or(exponent);
+    Expression::Pow(Arc::new(base), Arc::new(exponent))
+}
+
+/// Merge powers of a common base in products, and `(b^m)^n` for integer `n`
+///
+/// Factors of a noncommutative product merge only with their neighbours.
+pub(super) fn combine_powers(expr: &Expression) -> Option<Expression> {
+    match expr {
+        Expression::Mul(factors) => {
+            let commutative = expr.commutativity() == Commutativity::Commutative;
+            let mut merged: Vec<(Expression, Vec<Expression>)> = Vec::new();
+            let mut numbers = Vec::new();
+            for factor in factors.iter() {
+                if matches!(factor, Expression::Number(_)) {
+                    numbers.push(factor.clone());
+                    continue;
+                }
+                let (base, exponent) = base_and_exponent(factor);
+                let existing = if commutative {
+                    merged.iter_mut().find(|(b, _)| b == base)
+                } else {
+                    merged.last_mut().filter(|(b, _)| b == base)
+                };
+                match existing {
+                    Some((_, exponents)) => exponents.push(exponent),
+                    None => merged.push((base.clone(), vec![exponent])),
+                }
+            }
+            if numbers.len() + merged.len() == factors.len() {
+                return None;
+            }
+            numbers.extend(merged.into_iter().map(|(base, exponents)| {
+                if exponents.len() == 1 {
+                    match exponents[0].is_one() {
+                        true => base,
+                        false => Expression::Pow(Arc::new(base), Arc::new(exponents[0].clone())),
+                    }
+                } else {
+                    power(base, exponents)
+                }
+            }));
+            Some(raw_mul(numbers))
+        }
+        Expression::Pow(inner, outer) => match (inner.as_ref(), outer.as_ref()) {
+            (Expression::Pow(base, exponent), Expression::Number(Number::Integer(_))) => {
+                let product = raw_mul(vec![exponent.as_ref().clone(), outer.as_ref().clone()]);
+                let product = numeric_fold(&product).unwrap_or(product);
+                Some(Expression::Pow(base.clone(), Arc::new(product)))
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+const TRIGONOMETRIC: &[&str] = &[
+    "sin", "cos", "tan", "csc", "sec", "cot", "asin", "acos", "atan", "sinh", "cosh", "tanh",
+];
+
+/// Values of trigonometric functions at special points and `sin² + cos² = 1`
+pub(super) fn trigonometric(expr: &Expression) -> Option<Expression> {
+    match expr {
+        Expression::Function { name, args, .. } if TRIGONOMETRIC.contains(&name.as_ref()) => {
+            let rewritten = get_simplification_registry().simplify_function(name, args);
+            (rewritten != *expr).then_some(rewritten)
+        }
+        Expression::Add(terms) => check_pythagorean(terms).map(raw_add),
+        _ => None,
+    }
+}
+
+/// Largest `k` with `k² | n`, for `n > 0`
+fn square_factor(mut n: i64) -> i64 {
+    let mut factor = 1;
+    let mut d = 2;
+    while d * d <= n && d <= MAX_SQUARE_FACTOR {
+        while n % (d * d) == 0 {
+            n /= d * d;
+            factor *= d;
+        }
+        d += 1;
+    }
+    factor
+}
+
+/// Pull square factors out of integer square roots and clear radicals from
+/// denominators
+pub(super) fn radical(expr: &Expression) -> Option<Expression> {
+    match expr {
+        Expression::Function { name, args, .. } if name.as_ref() == "sqrt" && args.len() == 1 => {
+            let Expression::Number(Number::Integer(n)) = &args[0] else {
+                return None;
+            };
+            if *n <= 0 {
+                return None;
+            }
+            let root = square_factor(*n);
+            if root == 1 {
+                return None;
+            }
+            let rest = n / (root * root);
+            Some(match rest {
+                1 => Expression::integer(root),
+                _ => raw_mul(vec![
+                    Expression::integer(root),
+                    Expression::sqrt(Expression::integer(rest)),
+                ]),
+            })
+        }
+        Expression::Mul(_) => {
+            let rationalized = expr.rationalize_denominator();
+            (rationalized != *expr).then_some(rationalized)
+        }
+        _ => None,
+    }
+}
+
+/// Cancel common factors of a quotient's numerator and denominator
+pub(super) fn rational_cancel(expr: &Expression) -> Option<Expression> {
+    let Expression::Mul(factors) = expr else {
+        return None;
+    };
+    let has_denominator = factors.iter().any(|factor| {
+        matches!(factor, Expression::Pow(_, exponent)
+            if matches!(exponent.as_ref(), Expression::Number(Number::Integer(n)) if *n < 0))
+    });
+    if !has_denominator {
+        return None;
+    }
+    let cancelled = expr.simplify_rational();
+    (cancelled != *expr).then_some(cancelled)
+}
+
+fn factors_of(term: &Expression) -> Vec<Expression> {
+    match term {
+        Expression::Mul(factors) => factors.as_ref().clone(),
+        _ => vec![term.clone()],
+    }
+}
+
+/// Pull the integer content and the factors shared by every term out of a
+/// commutative sum
+pub(super) fn factor_common(expr: &Expression) -> Option<Expression> {
+    let Expression::Add(terms) = expr else {
+        return None;
+    };
+    if expr.commutativity() != Commutativity::Commutative {
+        return None;
+    }
+    let mut remaining: Vec<Vec<Expression>> = terms.iter().map(factors_of).collect();
+
+    let mut content = 0i64;
+    for factors in &remaining {
+        let coefficient = match factors.first() {
+            Some(Expression::Number(Number::Integer(n))) => n.abs(),
+            _ => 1,
+        };
+        content = content.gcd(&coefficient);
+    }
+
+    let mut common = Vec::new();
+    for candidate in remaining[0].clone() {
+        if matches!(candidate, Expression::Number(_)) {
+            continue;
+        }
+        if remaining.iter().all(|factors| factors.contains(&candidate)) {
+            for factors in remaining.iter_mut() {
+                let position = factors.iter().position(|f| *f == candidate);
+                factors.remove(position.expect("BUG: checked above"));
+            }
+            common.push(candidate);
+        }
+    }
+    if content <= 1 && common.is_empty() {
+        return None;
+    }
+
+    let inner = remaining
+        .into_iter()
+        .map(|mut factors| {
+            if content > 1 {
+                if let Some(Expression::Number(Number::Integer(n))) = factors.first_mut() {
+                    *n /= content;
+                }
+            }
+            let term = raw_mul(factors);
+            numeric_fold(&term).unwrap_or(term)
+        })
+        .collect();
+    let mut product = Vec::with_capacity(common.len() + 2);
+    if content > 1 {
+        product.push(Expression::integer(content));
+    }
+    product.extend(common);
+    product.push(raw_add(inner));
+    Some(raw_mul(product))
+}
+
+fn is_matrix(expr: &Expression) -> bool {
+    expr.commutativity() == Commutativity::Noncommutative
+}
+
+fn matrix_function(name: &str, arg: Expression) -> Expression {
+    Expression::function(name, vec![arg])
+}
+
+/// Split a product into its scalar factors and its matrix factors in order
+fn scalars_and_matrices(factors: &[Expression]) -> (Vec<Expression>, Vec<Expression>) {
+    factors.iter().cloned().partition(|f| !is_matrix(f))
+}
+
+/// Involutions and order reversal of `transpose`, `adjoint` and `inverse`,
+/// linearity and cyclicity of `trace`, and cancellation of adjacent `A·A⁻¹`
+pub(super) fn matrix_identities(expr: &Expression) -> Option<Expression> {
+    match expr {
+        Expression::Function { name, args, .. } if name.as_ref() == "trace" && args.len() == 1 => {
+            trace_identities(&args[0])
+        }
+        Expression::Function { name, args, .. } if args.len() == 1 => {
+            let name = name.as_ref();
+            if !matches!(name, "transpose" | "adjoint" | "inverse") {
+                return None;
+            }
+            match &args[0] {
+                Expression::Function {
+                    name: inner,
+                    args: inner_args,
+                    ..
+                } if inner.as_ref() == name && inner_args.len() == 1 => Some(inner_args[0].clone()),
+                arg if !is_matrix(arg) => match name {
+                    "transpose" => Some(arg.clone()),
+                    "adjoint" => Some(arg.conjugate()),
+                    _ => Some(Expression::Pow(
+                        Arc::new(arg.clone()),
+                        Arc::new(Expression::integer(-1)),
+                    )),
+                },
+                Expression::Add(terms) if name != "inverse" => Some(raw_add(
+                    terms
+                        .iter()
+                        .map(|t| matrix_function(name, t.clone()))
+                        .collect(),
+                )),
+                Expression::Mul(factors) => {
+                    let (scalars, matrices) = scalars_and_matrices(factors);
+                    let scalars = match name {
+                        "transpose" => scalars,
+                        "adjoint" => scalars.iter().map(|s| s.conjugate()).collect(),
+                        _ => scalars
+                            .into_iter()
+                            .map(|s| {
+                                Expression::Pow(Arc::new(s), Arc::new(Expression::integer(-1)))
+                            })
+                            .collect(),
+                    };
+                    let reversed = matrices.into_iter().rev().map(|m| matrix_function(name, m));
+                    Some(raw_mul(scalars.into_iter().chain(reversed).collect()))
+                }
+                _ => None,
+            }
+        }
+        Expression::Mul(factors) => {
+            let is_inverse_of = |a: &Expression, b: &Expression| {
+                matches!(b, Expression::Function { name, args, .. }
+                    if name.as_ref() == "inverse" && args.len() == 1 && args[0] == *a)
+            };
+            let mut kept: Vec<Expression> = Vec::with_capacity(factors.len());
+            let mut cancelled = false;
+            for factor in factors.iter() {
+                match kept.last() {
+                    Some(last)
+                        if is_matrix(factor)
+                            && (is_inverse_of(last, factor) || is_inverse_of(factor, last)) =>
+                    {
+                        kept.pop();
+                        cancelled = true;
+                    }
+                    _ => kept.push(factor.clone()),
+                }
+            }
+            cancelled.then(|| raw_mul(kept))
+        }
+        _ => None,
+    }
+}
+
+/// Linearity, transpose invariance and cyclicity of `trace(arg)`
+fn trace_identities(arg: &Expression) -> Option<Expression> {
+    match arg {
+        Expression::Add(terms) if is_matrix(arg) => Some(raw_add(
+            terms
+                .iter()
+                .map(|t| matrix_function("trace", t.clone()))
+                .collect(),
+        )),
+        Expression::Function { name, args, .. }
+            if name.as_ref() == "transpose" && args.len() == 1 =>
+        {
+            Some(matrix_function("trace", args[0].clone()))
+        }
+        Expression::Mul(factors) if is_matrix(arg) => {
+            let (mut scalars, matrices) = scalars_and_matrices(factors);
+            if !scalars.is_empty() {
+                let product = match matrices.len() {
+                    1 => matrices[0].clone(),
+                    _ => raw_mul(matrices),
+                };
+                scalars.push(matrix_function("trace", product));
+                return Some(raw_mul(scalars));
+            }
+            // tr(XᵀYᵀ) = tr((YX)ᵀ) = tr(YX)
+            let untransposed: Option<Vec<Expression>> = matrices
+                .iter()
+                .rev()
+                .map(|m| match m {
+                    Expression::Function { name, args, .. }
+                        if name.as_ref() == "transpose" && args.len() == 1 =>
+                    {
+                        Some(args[0].clone())
+                    }
+                    _ => None,
+                })
+                .collect();
+            if let Some(product) = untransposed {
+                return Some(matrix_function("trace", raw_mul(product)));
+            }
+            let rotated = canonical_rotation(&matrices);
+            (rotated != matrices).then(|| matrix_function("trace", raw_mul(rotated)))
+        }
+        _ => None,
+    }
+}
+
+/// Arguments of `op` with nested applications of `op` flattened and
+/// duplicates removed
+fn flatten_connective(op: &str, args: &[Expression]) -> Vec<Expression> {
+    let mut flat: Vec<Expression> = Vec::with_capacity(args.len());
+    for arg in args {
+        match arg {
+            Expression::Function { name, args, .. } if name.as_ref() == op => {
+                for inner in flatten_connective(op, args) {
+                    if !flat.contains(&inner) {
+                        flat.push(inner);
+                    }
+                }
+            }
+            _ if flat.contains(arg) => {}
+            _ => flat.push(arg.clone()),
+        }
+    }
+    flat
+}
+
+/// Double negation, associativity, idempotence and absorption of `and`,
+/// `or` and `not`
+pub(super) fn boolean_algebra(expr: &Expression) -> Option<Expression> {
+    let Expression::Function { name, args, .. } = expr else {
+        return None;
+    };
+    match (name.as_ref(), args.as_slice()) {
+        ("not", [Expression::Function { name, args, .. }])
+            if name.as_ref() == "not" && args.len() == 1 =>
+        {
+            Some(args[0].clone())
+        }
+        (op @ ("and" | "or"), _) if !args.is_empty() => {
+            let dual = if op == "and" { "or" } else { "and" };
+            let flat = flatten_connective(op, args);
+            // p ∧ (p ∨ q) = p and p ∨ (p ∧ q) = p
+            let absorbed: Vec<Expression> = flat
+                .iter()
+                .filter(|arg| {
+                    !matches!(arg, Expression::Function { name, args, .. }
+                        if name.as_ref() == dual
+                            && args.iter().any(|inner| flat.contains(inner)))
+                })
+                .cloned()
+                .collect();
+            if absorbed.len() == 1 {
+                return absorbed.into_iter().next();
+            }
+            (absorbed.as_slice() != args.as_slice()).then(|| Expression::function(op, absorbed))
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,219 @@
+//! Simplification of square roots, absolute values and nested powers
+//!
+//! These rules are only valid for some values of their arguments:
+//! - `sqrt(a²) = a` and `|a| = a` for `a ≥ 0`
+//! - `sqrt(a²) = |a|` for real `a`
+//! - `(a^b)^c = a^(bc)` for integer `c`, for `a ≥ 0`, or for `-1 < b ≤ 1`
+//!
+//! Plain `simplify` decides them from the assumptions declared on symbols
+//! (see [`SymbolAssumptions`](crate::core::symbol::SymbolAssumptions));
+//! `simplify_with_assumptions` also uses the facts in an `Assumptions`.
+//! Without either, `sqrt(x²)` stays as it is, since it equals `-x` for
+//! negative `x`.
+
+use super::arithmetic::simplify_multiplication;
+use super::Simplify;
+use crate::algebra::solvers::Assumptions;
+use crate::core::{Expression, Number};
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use std::sync::Arc;
+
+/// Rewrite rule for already simplified arguments, without assumptions
+pub(super) fn simplify_radical(name: &str, args: &[Expression]) -> Option<Expression> {
+    rewrite_radical(name, args, &Assumptions::new()).map(|rewritten| rewritten.simplify())
+}
+
+/// Remove `sqrt` of an even power and `abs` of a known sign
+///
+/// Square factors of a product leave the root: `sqrt(4x²y) = 2x·sqrt(y)`
+/// for nonnegative `x`.
+pub(crate) fn rewrite_radical(
+    name: &str,
+    args: &[Expression],
+    assumptions: &Assumptions,
+) -> Option<Expression> {
+    match (name, args) {
+        ("abs", [arg]) if assumptions.is_nonnegative(arg) => Some(arg.clone()),
+        ("sqrt", [Expression::Pow(base, exponent)]) => sqrt_of_power(base, exponent, assumptions),
+        ("sqrt", [Expression::Mul(factors)]) => sqrt_of_product(factors, assumptions),
+        _ => None,
+    }
+}
+
+/// `sqrt(a·b) = sqrt(a)·sqrt(b)`, valid whenever `a ≥ 0`, for the factors
+/// `a` whose root is known
+fn sqrt_of_product(factors: &[Expression], assumptions: &Assumptions) -> Option<Expression> {
+    let mut outside = Vec::new();
+    let mut inside = Vec::new();
+    for factor in factors {
+        let root = match factor {
+            Expression::Pow(base, exponent) => sqrt_of_power(base, exponent, assumptions),
+            Expression::Number(Number::Integer(n)) if *n > 0 => {
+                let root = (*n as f64).sqrt().round() as i64;
+                (root * root == *n).then(|| Expression::integer(root))
+            }
+            _ => None,
+        };
+        match root {
+            Some(root) => outside.push(root),
+            None => inside.push(factor.clone()),
+        }
+    }
+    if outside.is_empty() {
+        return None;
+    }
+    if !inside.is_empty() {
+        outside.push(Expression::function("sqrt", vec![Expression::mul(inside)]));
+    }
+    Some(Expression::mul(outside))
+}
+
+/// `(base^inner)^outer` as a single power when that is valid
+///
+/// Returns `None` when the powers must stay nested.
+pub(super) fn combine_powers(
+    base: &Expression,
+    inner: &Expression,
+    outer: &Expression,
+    assumptions: &Assumptions,
+) -> Option<Expression> {
+    let unit_inner = match inner {
+        Expression::Number(Number::Integer(n)) => *n == 1,
+        Expression::Number(Number::Rational(r)) => {
+            let one = BigRational::from_integer(BigInt::from(1));
+            -one.clone() < **r && **r <= one
+        }
+        _ => false,
+    };
+    if matches!(outer, Expression::Number(Number::Integer(_)))
+        || unit_inner
+        || assumptions.is_nonnegative(base)
+    {
+        let exponent = simplify_multiplication(&[inner.clone(), outer.clone()]);
+        return Some(Expression::Pow(Arc::new(base.clone()), Arc::new(exponent)));
+    }
+
+    match outer {
+        Expression::Number(Number::Rational(r))
+            if **r == BigRational::new(BigInt::from(1), BigInt::from(2)) =>
+        {
+            sqrt_of_power(base, inner, assumptions)
+        }
+        _ => None,
+    }
+}
+
+/// `sqrt(base^exponent)` for a positive even integer exponent `2k`: `base^k`
+/// for nonnegative `base`, `|base|^k` for real `base`
+fn sqrt_of_power(
+    base: &Expression,
+    exponent: &Expression,
+    assumptions: &Assumptions,
+) -> Option<Expression> {
+    let half = match exponent {
+        Expression::Number(Number::Integer(n)) if *n > 0 && n % 2 == 0 => n / 2,
+        _ => return None,
+    };
+    let root = if assumptions.is_nonnegative(base) || half % 2 == 0 && assumptions.is_real(base) {
+        base.clone()
+    } else if assumptions.is_real(base) {
+        Expression::function("abs", vec![base.clone()])
+    } else {
+        return None;
+    };
+    Some(Expression::pow(root, Expression::integer(half)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::rounding::simplify_with_assumptions;
+    use super::*;
+    use crate::core::symbol::{Assumption, Symbol};
+    use crate::expr;
+
+    fn sqrt(arg: Expression) -> Expression {
+        Expression::function("sqrt", vec![arg])
+    }
+
+    fn symbol(assumptions: &[Assumption]) -> Expression {
+        Expression::symbol(Symbol::new_with_assumptions("x", assumptions))
+    }
+
+    #[test]
+    fn test_sqrt_of_square_depends_on_assumptions() {
+        let positive = symbol(&[Assumption::Positive]);
+        let real = symbol(&[Assumption::Real]);
+
+        let square = |x: &Expression| Expression::pow(x.clone(), expr!(2));
+        assert_eq!(sqrt(square(&positive)).simplify(), positive);
+        assert_eq!(
+            sqrt(square(&real)).simplify(),
+            Expression::function("abs", vec![real.clone()])
+        );
+        assert_eq!(sqrt(expr!(x ^ 2)).simplify(), sqrt(expr!(x ^ 2)));
+
+        // x⁴ is a square of x² whatever the sign of a real x
+        assert_eq!(
+            sqrt(Expression::pow(real.clone(), expr!(4))).simplify(),
+            square(&real)
+        );
+    }
+
+    #[test]
+    fn test_nested_powers() {
+        let nonnegative = symbol(&[Assumption::Nonnegative]);
+        let half = Expression::rational(1, 2);
+
+        let nested = |x: Expression| Expression::pow(Expression::pow(x, expr!(2)), half.clone());
+        assert_eq!(nested(nonnegative.clone()).simplify(), nonnegative);
+        assert_eq!(nested(expr!(x)).simplify(), nested(expr!(x)));
+
+        assert_eq!(
+            Expression::pow(expr!(x ^ 2), expr!(3)).simplify(),
+            expr!(x ^ 6)
+        );
+        assert_eq!(
+            Expression::pow(Expression::pow(expr!(x), half), expr!(2)).simplify(),
+            expr!(x)
+        );
+    }
+
+    #[test]
+    fn test_square_factors_leave_the_root() {
+        let positive = symbol(&[Assumption::Positive]);
+        let product = Expression::mul(vec![
+            expr!(4),
+            Expression::pow(positive.clone(), expr!(2)),
+            expr!(y),
+        ]);
+        assert_eq!(
+            sqrt(product).simplify(),
+            Expression::mul(vec![expr!(2), positive, sqrt(expr!(y))]).simplify()
+        );
+        assert_eq!(
+            sqrt(expr!((x ^ 2) * y)).simplify(),
+            sqrt(expr!((x ^ 2) * y))
+        );
+    }
+
+    #[test]
+    fn test_abs() {
+        let positive = symbol(&[Assumption::Positive]);
+        let abs = |x: Expression| Expression::function("abs", vec![x]);
+        assert_eq!(abs(positive.clone()).simplify(), positive);
+        assert_eq!(abs(expr!(x)).simplify(), abs(expr!(x)));
+    }
+
+    #[test]
+    fn test_with_assumptions() {
+        let assumptions = Assumptions::new().nonnegative(expr!(a));
+        assert_eq!(
+            simplify_with_assumptions(&sqrt(expr!(a ^ 2)), &assumptions),
+            expr!(a)
+        );
+
+        let nested = Expression::pow(expr!(a ^ 2), Expression::rational(1, 2));
+        assert_eq!(simplify_with_assumptions(&nested, &assumptions), expr!(a));
+    }
+}
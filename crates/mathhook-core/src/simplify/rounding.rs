@@ -0,0 +1,240 @@
+//! Simplification of floor, ceiling and modulo expressions
+//!
+//! Rules that need to know which parts of an argument are integers:
+//! - `floor(n) = n`, `ceil(n) = n` for integer-valued `n`
+//! - `floor(x + n) = floor(x) + n`, `ceil(x + n) = ceil(x) + n`
+//! - `mod(k·m + r, m) = mod(r, m)` for integer `k`, which gives the parity
+//!   rules `mod(2k, 2) = 0` and `mod(2k + 1, 2) = 1`
+//!
+//! `Simplify::simplify` only knows structural integers (numbers, results of
+//! rounding functions); `simplify_with_assumptions` adds the integer symbols
+//! declared in an `Assumptions`, and also applies the orderings and signs it
+//! implies to `min`, `max`, `heaviside`, `dirac_delta`, `sqrt`, `abs` and
+//! nested powers (see `simplify::min_max`, `simplify::distributions` and
+//! `simplify::radicals`).
+
+use super::{verification, Simplify};
+use crate::algebra::solvers::Assumptions;
+use crate::core::{Expression, Number};
+
+/// Simplify with knowledge of which symbols take integer values
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::algebra::solvers::Assumptions;
+/// use mathhook_core::simplify::rounding::simplify_with_assumptions;
+/// use mathhook_core::simplify::Simplify;
+/// use mathhook_core::{expr, Expression};
+///
+/// let odd = Expression::function("mod", vec![expr!((2 * k) + 1), expr!(2)]);
+/// let assumptions = Assumptions::new().integer(expr!(k));
+/// assert_eq!(simplify_with_assumptions(&odd, &assumptions), expr!(1));
+///
+/// let shifted = Expression::function("floor", vec![expr!(x + k)]);
+/// assert_eq!(
+///     simplify_with_assumptions(&shifted, &assumptions),
+///     Expression::add(vec![Expression::function("floor", vec![expr!(x)]), expr!(k)]).simplify()
+/// );
+/// ```
+pub fn simplify_with_assumptions(expr: &Expression, assumptions: &Assumptions) -> Expression {
+    verification::checked(expr, assumptions, |e| simplify_assuming(e, assumptions))
+}
+
+fn simplify_assuming(expr: &Expression, assumptions: &Assumptions) -> Expression {
+    let recurse = |e: &Expression| simplify_assuming(e, assumptions);
+    match expr {
+        Expression::Function { name, args, .. } => {
+            let args: Vec<Expression> = args.iter().map(recurse).collect();
+            match rewrite(name, &args, assumptions) {
+                Some(rewritten) => recurse(&rewritten),
+                None => Expression::function(name.as_ref(), args).simplify(),
+            }
+        }
+        Expression::Add(terms) => Expression::add(terms.iter().map(recurse).collect()).simplify(),
+        Expression::Mul(factors) => {
+            Expression::mul(factors.iter().map(recurse).collect()).simplify()
+        }
+        Expression::Pow(base, exponent) => {
+            let (base, exponent) = (recurse(base), recurse(exponent));
+            match &base {
+                Expression::Pow(inner_base, inner_exponent) => super::radicals::combine_powers(
+                    inner_base,
+                    inner_exponent,
+                    &exponent,
+                    assumptions,
+                )
+                .map(|combined| recurse(&combined))
+                .unwrap_or_else(|| Expression::pow(base.clone(), exponent).simplify()),
+                _ => Expression::pow(base, exponent).simplify(),
+            }
+        }
+        _ => expr.simplify(),
+    }
+}
+
+/// Rewrite rule for already simplified arguments, without assumptions
+pub(super) fn simplify_rounding(name: &str, args: &[Expression]) -> Option<Expression> {
+    rewrite(name, args, &Assumptions::new()).map(|rewritten| rewritten.simplify())
+}
+
+fn rewrite(name: &str, args: &[Expression], assumptions: &Assumptions) -> Option<Expression> {
+    match (name, args) {
+        ("floor" | "ceil", [arg]) => rewrite_rounding(name, arg, assumptions),
+        ("mod", [value, Expression::Number(Number::Integer(modulus))]) if *modulus != 0 => {
+            rewrite_modulo(value, *modulus, assumptions)
+        }
+        ("min" | "max", _) => super::min_max::rewrite_min_max(name, args, assumptions),
+        ("heaviside" | "dirac_delta", _) => {
+            super::distributions::rewrite_distribution(name, args, assumptions)
+        }
+        ("sqrt" | "abs", _) => super::radicals::rewrite_radical(name, args, assumptions),
+        _ => None,
+    }
+}
+
+fn rewrite_rounding(name: &str, arg: &Expression, assumptions: &Assumptions) -> Option<Expression> {
+    if let Expression::Number(Number::Rational(r)) = arg {
+        // Exact, unlike the f64 conversion used by numeric evaluation
+        let rounded = if name == "floor" { r.floor() } else { r.ceil() };
+        return Some(Expression::big_integer(rounded.to_integer()));
+    }
+    if assumptions.is_integer(arg) {
+        return Some(arg.clone());
+    }
+
+    let Expression::Add(terms) = arg else {
+        return None;
+    };
+    let (integers, rest): (Vec<Expression>, Vec<Expression>) = terms
+        .iter()
+        .cloned()
+        .partition(|term| assumptions.is_integer(term));
+    if integers.is_empty() {
+        return None;
+    }
+
+    let mut shifted = vec![Expression::function(name, vec![Expression::add(rest)])];
+    shifted.extend(integers);
+    Some(Expression::add(shifted))
+}
+
+fn rewrite_modulo(
+    value: &Expression,
+    modulus: i64,
+    assumptions: &Assumptions,
+) -> Option<Expression> {
+    let terms: Vec<Expression> = match value {
+        Expression::Add(terms) => terms.to_vec(),
+        single => vec![single.clone()],
+    };
+    let remaining: Vec<Expression> = terms
+        .iter()
+        .filter(|term| !is_multiple_of(term, modulus, assumptions))
+        .cloned()
+        .collect();
+    if remaining.len() == terms.len() {
+        return None;
+    }
+    if remaining.is_empty() {
+        return Some(Expression::integer(0));
+    }
+    Some(Expression::function(
+        "mod",
+        vec![Expression::add(remaining), Expression::integer(modulus)],
+    ))
+}
+
+/// `term` is an integer multiple of `modulus`
+fn is_multiple_of(term: &Expression, modulus: i64, assumptions: &Assumptions) -> bool {
+    match term {
+        Expression::Number(Number::Integer(n)) => n % modulus == 0,
+        Expression::Mul(factors) => factors.iter().enumerate().any(|(i, factor)| {
+            matches!(factor, Expression::Number(Number::Integer(n)) if n % modulus == 0)
+                && factors
+                    .iter()
+                    .enumerate()
+                    .all(|(j, other)| i == j || assumptions.is_integer(other))
+        }),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr;
+
+    fn floor(arg: Expression) -> Expression {
+        Expression::function("floor", vec![arg])
+    }
+
+    fn modulo(value: Expression, modulus: i64) -> Expression {
+        Expression::function("mod", vec![value, Expression::integer(modulus)])
+    }
+
+    #[test]
+    fn test_exact_rational_folding() {
+        let seven_halves = Expression::rational(7, 2);
+        assert_eq!(floor(seven_halves.clone()).simplify(), expr!(3));
+        assert_eq!(
+            Expression::function("ceil", vec![seven_halves]).simplify(),
+            expr!(4)
+        );
+        assert_eq!(floor(Expression::rational(-7, 2)).simplify(), expr!(-4));
+    }
+
+    #[test]
+    fn test_integer_shift_leaves_floor() {
+        assert_eq!(
+            floor(expr!(x + 3)).simplify(),
+            Expression::add(vec![floor(expr!(x)), expr!(3)]).simplify()
+        );
+        // A nested floor is an integer
+        let nested = floor(Expression::add(vec![floor(expr!(y)), expr!(x)]));
+        assert_eq!(
+            nested.simplify(),
+            Expression::add(vec![floor(expr!(x)), floor(expr!(y))]).simplify()
+        );
+        assert_eq!(floor(floor(expr!(x))).simplify(), floor(expr!(x)));
+    }
+
+    #[test]
+    fn test_parity_needs_integer_assumption() {
+        let even = modulo(expr!(2 * k), 2);
+        assert_eq!(even.simplify(), even);
+
+        let assumptions = Assumptions::new().integer(expr!(k));
+        assert_eq!(simplify_with_assumptions(&even, &assumptions), expr!(0));
+        assert_eq!(
+            simplify_with_assumptions(&modulo(expr!((6 * k) + 7), 3), &assumptions),
+            expr!(1)
+        );
+        assert_eq!(
+            simplify_with_assumptions(&modulo(expr!(k + 1), 2), &assumptions),
+            modulo(expr!(k + 1), 2)
+        );
+    }
+
+    #[test]
+    fn test_integer_functions_need_integer_arguments() {
+        let factorial = |arg| Expression::function("factorial", vec![arg]);
+        let half = factorial(Expression::rational(1, 2));
+        assert_ne!(floor(half.clone()).simplify(), half.simplify());
+
+        let assumptions = Assumptions::new().integer(expr!(n));
+        assert_eq!(
+            simplify_with_assumptions(&floor(factorial(expr!(n))), &assumptions),
+            factorial(expr!(n))
+        );
+    }
+
+    #[test]
+    fn test_structural_integers_reduce_without_assumptions() {
+        let value = Expression::add(vec![
+            Expression::mul(vec![expr!(4), floor(expr!(x))]),
+            expr!(3),
+        ]);
+        assert_eq!(modulo(value, 2).simplify(), expr!(1));
+    }
+}
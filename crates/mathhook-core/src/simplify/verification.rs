@@ -0,0 +1,322 @@
+//! Numeric self-checking of simplification
+//!
+//! With verification on, every top-level `simplify()` (and
+//! `simplify_with_assumptions`) result is compared with its input at a few
+//! sample points. A point where the two evaluate to different numbers is a
+//! simplification bug; it is recorded as a [`Mismatch`] and reported on
+//! stderr.
+//!
+//! - Points where the input cannot be evaluated are outside its domain and
+//!   are skipped, as are points where the result cannot be evaluated
+//! - Variables assumed integer are sampled at integers, nonnegative ones at
+//!   nonnegative values and nonzero ones away from 0
+//! - Points come from a fixed seed, so a reported mismatch reproduces
+//!
+//! Verification is off by default. Set the `MATHHOOK_VERIFY_SIMPLIFY`
+//! environment variable to turn it on for a whole test run, call
+//! [`set_enabled`], or wrap code in [`collect_mismatches`] to check it on the
+//! current thread only.
+
+use crate::algebra::solvers::Assumptions;
+use crate::core::commutativity::Commutativity;
+use crate::core::{Expression, Symbol};
+use once_cell::sync::Lazy;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::cell::{Cell, RefCell};
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+/// Environment variable that turns verification on at startup
+pub const ENV_VAR: &str = "MATHHOOK_VERIFY_SIMPLIFY";
+
+/// Points at which both forms must evaluate for a check
+const SAMPLE_COUNT: usize = 5;
+
+/// Points tried before giving up on finding ones inside the domain
+const MAX_ATTEMPTS: usize = 40;
+
+const SEED: u64 = 0x5eed;
+
+/// Relative tolerance between the two values
+const TOLERANCE: f64 = 1e-6;
+
+/// Most recent global mismatches kept for [`take_mismatches`]; older ones
+/// are still reported on stderr
+pub const MAX_RECORDED: usize = 1000;
+
+static ENABLED: Lazy<AtomicBool> =
+    Lazy::new(|| AtomicBool::new(std::env::var_os(ENV_VAR).is_some()));
+
+static MISMATCHES: Mutex<VecDeque<Mismatch>> = Mutex::new(VecDeque::new());
+
+/// Open `collect_mismatches` scopes on any thread, so the common case of no
+/// verification skips the thread-local lookup
+static SCOPES: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    /// Set while a check runs, so the simplifications it triggers are not
+    /// themselves checked
+    static CHECKING: Cell<bool> = const { Cell::new(false) };
+
+    /// Mismatches of a `collect_mismatches` scope on this thread
+    static SCOPE: RefCell<Option<Vec<Mismatch>>> = const { RefCell::new(None) };
+}
+
+/// A point where simplification changed the value
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mismatch {
+    pub original: Expression,
+    pub simplified: Expression,
+    /// Value of each variable at the point
+    pub point: HashMap<String, f64>,
+    pub expected: f64,
+    pub actual: f64,
+}
+
+/// Turn verification on or off for all threads
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Whether results are verified on the current thread
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+        || (SCOPES.load(Ordering::Relaxed) > 0 && SCOPE.with(|scope| scope.borrow().is_some()))
+}
+
+/// Mismatches recorded while verification was on globally, clearing the log
+///
+/// Only the last [`MAX_RECORDED`] are kept between calls.
+pub fn take_mismatches() -> Vec<Mismatch> {
+    std::mem::take(&mut *MISMATCHES.lock().unwrap_or_else(|e| e.into_inner())).into()
+}
+
+/// Run `f` with verification on for the current thread and return the
+/// mismatches found in its simplifications
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::simplify::verification::collect_mismatches;
+/// use mathhook_core::simplify::Simplify;
+/// use mathhook_core::expr;
+///
+/// let (result, mismatches) = collect_mismatches(|| expr!((x + x) * y).simplify());
+/// assert_eq!(result, expr!(2 * x * y));
+/// assert!(mismatches.is_empty());
+/// ```
+pub fn collect_mismatches<T>(f: impl FnOnce() -> T) -> (T, Vec<Mismatch>) {
+    let outer = SCOPE.with(|scope| scope.borrow_mut().replace(Vec::new()));
+    SCOPES.fetch_add(1, Ordering::Relaxed);
+    let result = f();
+    SCOPES.fetch_sub(1, Ordering::Relaxed);
+    let mismatches = SCOPE.with(|scope| std::mem::replace(&mut *scope.borrow_mut(), outer));
+    (result, mismatches.unwrap_or_default())
+}
+
+/// First sample point where `simplified` and `original` differ
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::algebra::solvers::Assumptions;
+/// use mathhook_core::simplify::verification::find_mismatch;
+/// use mathhook_core::expr;
+///
+/// assert!(find_mismatch(&expr!(x * x), &expr!(x ^ 2), &Assumptions::new()).is_none());
+/// assert!(find_mismatch(&expr!(x * x), &expr!(2 * x), &Assumptions::new()).is_some());
+/// ```
+pub fn find_mismatch(
+    original: &Expression,
+    simplified: &Expression,
+    assumptions: &Assumptions,
+) -> Option<Mismatch> {
+    if original == simplified {
+        return None;
+    }
+    let mut variables = original.find_variables();
+    for variable in simplified.find_variables() {
+        if !variables.contains(&variable) {
+            variables.push(variable);
+        }
+    }
+    if variables
+        .iter()
+        .any(|variable| variable.commutativity() != Commutativity::Commutative)
+    {
+        return None;
+    }
+    variables.sort_by(|a, b| a.name().cmp(b.name()));
+
+    let mut rng = StdRng::seed_from_u64(SEED);
+    let mut compared = 0;
+    for _ in 0..MAX_ATTEMPTS {
+        if compared == SAMPLE_COUNT {
+            break;
+        }
+        let point: HashMap<String, f64> = variables
+            .iter()
+            .map(|variable| {
+                (
+                    variable.name().to_owned(),
+                    sample(variable, assumptions, &mut rng),
+                )
+            })
+            .collect();
+        let values: HashMap<String, Expression> = point
+            .iter()
+            .map(|(name, value)| (name.clone(), Expression::float(*value)))
+            .collect();
+        let value_of = |expr: &Expression| {
+            expr.substitute(&values)
+                .evaluate_to_f64()
+                .ok()
+                .filter(|value| value.is_finite())
+        };
+        let (Some(expected), Some(actual)) = (value_of(original), value_of(simplified)) else {
+            continue;
+        };
+        compared += 1;
+        if (expected - actual).abs() > TOLERANCE * expected.abs().max(1.0) {
+            return Some(Mismatch {
+                original: original.clone(),
+                simplified: simplified.clone(),
+                point,
+                expected,
+                actual,
+            });
+        }
+    }
+    None
+}
+
+/// Simplify with `simplify`, checking the result when verification is on
+pub(super) fn checked(
+    original: &Expression,
+    assumptions: &Assumptions,
+    simplify: impl FnOnce(&Expression) -> Expression,
+) -> Expression {
+    if CHECKING.with(Cell::get) || !is_enabled() {
+        return simplify(original);
+    }
+    CHECKING.with(|checking| checking.set(true));
+    let _reset = ResetOnDrop;
+    let simplified = simplify(original);
+    if let Some(mismatch) = find_mismatch(original, &simplified, assumptions) {
+        record(mismatch);
+    }
+    simplified
+}
+
+struct ResetOnDrop;
+
+impl Drop for ResetOnDrop {
+    fn drop(&mut self) {
+        CHECKING.with(|checking| checking.set(false));
+    }
+}
+
+fn record(mismatch: Mismatch) {
+    let unscoped = SCOPE.with(|scope| match scope.borrow_mut().as_mut() {
+        Some(mismatches) => {
+            mismatches.push(mismatch.clone());
+            false
+        }
+        None => true,
+    });
+    if unscoped {
+        eprintln!(
+            "WARNING: simplify changed the value of {} to {} at {:?}: {} != {}",
+            mismatch.original,
+            mismatch.simplified,
+            mismatch.point,
+            mismatch.expected,
+            mismatch.actual
+        );
+        let mut recorded = MISMATCHES.lock().unwrap_or_else(|e| e.into_inner());
+        if recorded.len() == MAX_RECORDED {
+            recorded.pop_front();
+        }
+        recorded.push_back(mismatch);
+    }
+}
+
+fn sample(variable: &Symbol, assumptions: &Assumptions, rng: &mut StdRng) -> f64 {
+    let symbol = Expression::symbol(variable.clone());
+    let nonnegative = assumptions.is_nonnegative(&symbol);
+    let nonzero = assumptions.is_nonzero(&symbol);
+    loop {
+        let mut value = if assumptions.is_integer(&symbol) {
+            f64::from(rng.random_range(-6..=6))
+        } else {
+            rng.random_range(-3.0..3.0)
+        };
+        if nonnegative {
+            value = value.abs();
+        }
+        if !(nonzero && value == 0.0) {
+            return value;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr;
+    use crate::simplify::rounding::simplify_with_assumptions;
+    use crate::simplify::Simplify;
+
+    #[test]
+    fn test_wrong_result_is_reported_with_point() {
+        let mismatch = find_mismatch(
+            &expr!((x + 1) ^ 2),
+            &expr!((x ^ 2) + 1),
+            &Assumptions::new(),
+        )
+        .unwrap();
+        let x = mismatch.point["x"];
+        assert!((mismatch.expected - (x + 1.0).powi(2)).abs() < 1e-9);
+        assert!((mismatch.actual - (x * x + 1.0)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_assumptions_restrict_sample_points() {
+        let floor = Expression::function("floor", vec![expr!(n)]);
+        assert!(find_mismatch(&floor, &expr!(n), &Assumptions::new()).is_some());
+        let integer = Assumptions::new().integer(expr!(n));
+        assert!(find_mismatch(&floor, &expr!(n), &integer).is_none());
+
+        let root = Expression::pow(expr!(y ^ 2), Expression::rational(1, 2));
+        let nonnegative = Assumptions::new().nonnegative(expr!(y));
+        assert!(find_mismatch(&root, &expr!(y), &nonnegative).is_none());
+    }
+
+    #[test]
+    fn test_points_outside_the_domain_are_skipped() {
+        // ln(x) only evaluates for x > 0, where ln(x²)/2 agrees with it
+        let half_log_square = Expression::mul(vec![
+            Expression::rational(1, 2),
+            Expression::function("ln", vec![expr!(x ^ 2)]),
+        ]);
+        assert!(find_mismatch(&expr!(ln(x)), &half_log_square, &Assumptions::new()).is_none());
+    }
+
+    #[test]
+    fn test_scoped_collection_checks_outermost_calls() {
+        let (results, mismatches) = collect_mismatches(|| {
+            vec![
+                expr!((x * y) + (x * y) - (2 * x)).simplify(),
+                expr!(sin(x) ^ 2).simplify(),
+                simplify_with_assumptions(
+                    &Expression::function("floor", vec![expr!(n + 1)]),
+                    &Assumptions::new().integer(expr!(n)),
+                ),
+            ]
+        });
+        assert_eq!(results[2], expr!(n + 1));
+        assert!(mismatches.is_empty(), "{:?}", mismatches);
+    }
+}
@@ -3,10 +3,20 @@
 //! This module provides stateful solver objects that complement the Expression-centric API.
 //! These are separate objects that maintain state and configuration for complex solving operations.
 
-use crate::algebra::equation_analyzer::SmartEquationSolver;
+use crate::algebra::equation_analyzer::{EquationAnalyzer, EquationType, SmartEquationSolver};
+use crate::algebra::solvers::{
+    general_solutions, solve_set, ConditionSet, ImageSet, SolutionCase, SolutionSet,
+};
+use crate::core::provenance::{AlgorithmId, Provenance};
 use crate::core::{Expression, Symbol};
+use crate::error::{MathError, MathResult, Remediation};
 use crate::simplify::Simplify;
 use serde::{Deserialize, Serialize};
+use std::time::Instant;
+
+pub mod verification;
+
+pub use verification::{verify_solution, SolutionCheck, VerificationReport, VerificationStatus};
 
 /// Result of a solving operation
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -19,6 +29,14 @@ pub enum SolverResult {
     NoSolution,
     /// Infinite solutions exist
     InfiniteSolutions,
+    /// Solutions that depend on conditions on the equation's parameters
+    Cases(Vec<SolutionCase>),
+    /// Infinitely many isolated solutions in periodic families, such as
+    /// `{π·n | n ∈ ℤ}` for `sin(x) = 0`
+    ImageSets(Vec<ImageSet>),
+    /// Solutions of an equation with no closed-form solution, described by
+    /// the equation itself
+    ConditionSet(ConditionSet),
 }
 
 /// Configuration for solving operations
@@ -30,6 +48,8 @@ pub struct SolverConfig {
     pub tolerance: f64,
     pub use_numeric: bool,
     pub simplify_results: bool,
+    /// Drop solutions refuted by substituting them back into the equation
+    pub strict_verification: bool,
 }
 
 impl Default for SolverConfig {
@@ -39,6 +59,7 @@ impl Default for SolverConfig {
             tolerance: 1e-10,
             use_numeric: false,
             simplify_results: true,
+            strict_verification: false,
         }
     }
 }
@@ -100,6 +121,7 @@ impl MathSolver {
     ///     tolerance: 1e-8,
     ///     use_numeric: true,
     ///     simplify_results: false,
+    ///     strict_verification: true,
     /// };
     /// let solver = MathSolver::with_config(config);
     /// ```
@@ -125,53 +147,125 @@ impl MathSolver {
     /// let result = solver.solve(&equation, &symbol!(x));
     /// ```
     pub fn solve(&self, equation: &Expression, variable: &Symbol) -> SolverResult {
-        match equation {
-            Expression::Relation(relation_data) => {
-                let left = &relation_data.left;
-                let right = &relation_data.right;
-
-                let standard_form = Expression::add(vec![
-                    left.clone(),
-                    Expression::mul(vec![Expression::integer(-1), right.clone()]),
-                ]);
-
-                let (algebra_result, _explanation) = self
-                    .smart_solver
-                    .solve_with_equation(&standard_form, variable);
-
-                let result = self.convert_solver_result(algebra_result);
-
-                if self.config.simplify_results {
-                    match result {
-                        SolverResult::Single(expr) => SolverResult::Single(expr.simplify()),
-                        SolverResult::Multiple(exprs) => {
-                            SolverResult::Multiple(exprs.iter().map(|e| e.simplify()).collect())
-                        }
-                        other => other,
-                    }
-                } else {
-                    result
-                }
+        self.solve_verified(equation, variable).0
+    }
+
+    /// Solve an equation and report the residual of every solution
+    ///
+    /// Each solution is substituted back into `equation` before it is
+    /// returned. With `strict_verification`, refuted (extraneous) solutions
+    /// are removed from the result; their residuals remain in the report.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::solvers::VerificationStatus;
+    /// use mathhook_core::{expr, symbol, Expression, MathSolver};
+    ///
+    /// let solver = MathSolver::new();
+    /// let x = symbol!(x);
+    /// let equation = Expression::equation(expr!((2 * x) + 3), expr!(7));
+    ///
+    /// let (_result, report) = solver.solve_verified(&equation, &x);
+    /// assert!(report.is_consistent());
+    /// assert_eq!(report.checks[0].status, VerificationStatus::Verified);
+    /// ```
+    pub fn solve_verified(
+        &self,
+        equation: &Expression,
+        variable: &Symbol,
+    ) -> (SolverResult, VerificationReport) {
+        let (result, report, _) = self.solve_attributed(equation, variable);
+        (result, report)
+    }
+
+    /// Solve an equation, reporting the algorithm used and the time taken
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::core::AlgorithmId;
+    /// use mathhook_core::{expr, symbol, MathSolver, SolverResult};
+    ///
+    /// let solver = MathSolver::new();
+    /// let x = symbol!(x);
+    ///
+    /// let solved = solver.solve_with_provenance(&expr!((x ^ 2) - (3 * x) + 1), &x);
+    /// assert!(matches!(solved.result, SolverResult::Multiple(_)));
+    /// assert_eq!(solved.algorithm, AlgorithmId::QuadraticFormula);
+    /// ```
+    pub fn solve_with_provenance(
+        &self,
+        equation: &Expression,
+        variable: &Symbol,
+    ) -> Provenance<SolverResult> {
+        let start = Instant::now();
+        let (result, _, algorithm) = self.solve_attributed(equation, variable);
+        Provenance {
+            result,
+            algorithm,
+            elapsed: start.elapsed(),
+        }
+    }
+
+    fn solve_attributed(
+        &self,
+        equation: &Expression,
+        variable: &Symbol,
+    ) -> (SolverResult, VerificationReport, AlgorithmId) {
+        let target = match equation {
+            Expression::Relation(relation_data) => Expression::add(vec![
+                relation_data.left.clone(),
+                Expression::mul(vec![Expression::integer(-1), relation_data.right.clone()]),
+            ]),
+            _ => equation.clone(),
+        };
+
+        if let Some(families) = general_solutions(&target, variable) {
+            let result = if families.is_empty() {
+                SolverResult::NoSolution
+            } else {
+                SolverResult::ImageSets(families)
+            };
+            return (
+                result,
+                VerificationReport::default(),
+                AlgorithmId::InverseTrigonometric,
+            );
+        }
+
+        let (algebra_result, _explanation, algorithm) =
+            self.smart_solver.solve_with_algorithm(&target, variable);
+
+        let result = match self
+            .convert_solver_result(algebra_result.respecting_assumptions(variable))
+        {
+            // Equations without a closed form are unsolved, not unsolvable
+            SolverResult::NoSolution
+                if matches!(
+                    EquationAnalyzer::analyze(&target, variable),
+                    EquationType::Transcendental | EquationType::Numerical | EquationType::Unknown
+                ) =>
+            {
+                SolverResult::ConditionSet(ConditionSet::new(variable.clone(), equation.clone()))
             }
-            _ => {
-                let (algebra_result, _explanation) =
-                    self.smart_solver.solve_with_equation(equation, variable);
-
-                let result = self.convert_solver_result(algebra_result);
-
-                if self.config.simplify_results {
-                    match result {
-                        SolverResult::Single(expr) => SolverResult::Single(expr.simplify()),
-                        SolverResult::Multiple(exprs) => {
-                            SolverResult::Multiple(exprs.iter().map(|e| e.simplify()).collect())
-                        }
-                        other => other,
-                    }
-                } else {
-                    result
+            other => other,
+        };
+
+        let result = if self.config.simplify_results {
+            match result {
+                SolverResult::Single(expr) => SolverResult::Single(expr.simplify()),
+                SolverResult::Multiple(exprs) => {
+                    SolverResult::Multiple(exprs.iter().map(|e| e.simplify()).collect())
                 }
+                other => other,
             }
-        }
+        } else {
+            result
+        };
+
+        let (result, report) = self.verify(equation, variable, result);
+        (result, report, algorithm)
     }
 
     /// Solve a system of equations
@@ -201,6 +295,57 @@ impl MathSolver {
             .collect()
     }
 
+    /// Solve an inequality for a given variable
+    ///
+    /// Handles linear, polynomial and rational inequalities by sign analysis
+    /// between the real roots and poles; see
+    /// [`solve_set`](crate::algebra::solvers::solve_set). Equations and
+    /// `≠` relations are accepted too.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MathError::Diagnostic` when `inequality` is not a relation,
+    /// is not a rational function of `variable`, involves other free
+    /// variables, or has roots that cannot be found exactly.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mathhook_core::core::expression::RelationType;
+    /// use mathhook_core::{expr, symbol, Expression, MathSolver};
+    ///
+    /// let solver = MathSolver::new();
+    /// let x = symbol!(x);
+    /// let inequality = Expression::relation(expr!((2 * x) - 3), expr!(5), RelationType::Less);
+    ///
+    /// let set = solver.solve_inequality(&inequality, &x).unwrap();
+    /// assert_eq!(set.to_string(), "(-∞, 4)");
+    /// assert!(set.contains(0.0) && !set.contains(4.0));
+    /// ```
+    pub fn solve_inequality(
+        &self,
+        inequality: &Expression,
+        variable: &Symbol,
+    ) -> MathResult<SolutionSet> {
+        let unsupported = |reason: &str| {
+            MathError::diagnostic(
+                "solve_inequality",
+                inequality.clone(),
+                reason,
+                Remediation::TryAnotherMethod,
+            )
+        };
+        if !matches!(inequality, Expression::Relation(_)) {
+            return Err(unsupported("expected a relation such as x < 1"));
+        }
+        solve_set(inequality, variable).ok_or_else(|| {
+            unsupported(&format!(
+                "not a rational function of {} with exactly solvable roots",
+                variable.name()
+            ))
+        })
+    }
+
     /// Update solver configuration
     ///
     /// # Examples
@@ -219,6 +364,51 @@ impl MathSolver {
         self.config = config;
     }
 
+    fn verify(
+        &self,
+        equation: &Expression,
+        variable: &Symbol,
+        result: SolverResult,
+    ) -> (SolverResult, VerificationReport) {
+        let solutions = match &result {
+            SolverResult::Single(expr) => std::slice::from_ref(expr),
+            SolverResult::Multiple(exprs) => exprs.as_slice(),
+            SolverResult::NoSolution
+            | SolverResult::InfiniteSolutions
+            | SolverResult::Cases(_)
+            | SolverResult::ImageSets(_)
+            | SolverResult::ConditionSet(_) => &[],
+        };
+        let report = VerificationReport {
+            checks: solutions
+                .iter()
+                .map(|solution| {
+                    verify_solution(equation, variable, solution, self.config.tolerance)
+                })
+                .collect(),
+        };
+
+        if !self.config.strict_verification || report.is_consistent() {
+            return (result, report);
+        }
+
+        let mut kept: Vec<Expression> = report
+            .checks
+            .iter()
+            .filter(|check| check.status != VerificationStatus::Failed)
+            .map(|check| check.solution.clone())
+            .collect();
+        let result = match result {
+            SolverResult::Single(_) => match kept.pop() {
+                Some(expr) => SolverResult::Single(expr),
+                None => SolverResult::NoSolution,
+            },
+            _ if kept.is_empty() => SolverResult::NoSolution,
+            _ => SolverResult::Multiple(kept),
+        };
+        (result, report)
+    }
+
     fn convert_solver_result(
         &self,
         algebra_result: crate::algebra::solvers::SolverResult,
@@ -234,6 +424,7 @@ impl MathSolver {
                 SolverResult::Multiple(exprs)
             }
             crate::algebra::solvers::SolverResult::Partial(exprs) => SolverResult::Multiple(exprs),
+            crate::algebra::solvers::SolverResult::Cases(cases) => SolverResult::Cases(cases),
         }
     }
 }
@@ -243,3 +434,104 @@ impl Default for MathSolver {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{expr, symbol};
+
+    #[test]
+    fn test_strict_verification_drops_extraneous_roots() {
+        let x = symbol!(x);
+        let equation = Expression::equation(expr!(x ^ 2), expr!(4));
+        let candidates = SolverResult::Multiple(vec![expr!(2), expr!(3)]);
+
+        let lenient = MathSolver::new();
+        let (result, report) = lenient.verify(&equation, &x, candidates.clone());
+        assert_eq!(result, candidates);
+        assert_eq!(report.extraneous(), vec![&expr!(3)]);
+
+        let strict = MathSolver::with_config(SolverConfig {
+            strict_verification: true,
+            ..Default::default()
+        });
+        let (result, report) = strict.verify(&equation, &x, candidates);
+        assert_eq!(result, SolverResult::Multiple(vec![expr!(2)]));
+        assert_eq!(report.checks.len(), 2);
+
+        let (result, _) = strict.verify(&equation, &x, SolverResult::Single(expr!(3)));
+        assert_eq!(result, SolverResult::NoSolution);
+    }
+
+    #[test]
+    fn test_provenance() {
+        let x = symbol!(x);
+        let solver = MathSolver::new();
+        let linear = solver.solve_with_provenance(&expr!((2 * x) + 3), &x);
+        assert_eq!(linear.result, solver.solve(&expr!((2 * x) + 3), &x));
+        assert_eq!(linear.algorithm, AlgorithmId::LinearIsolation);
+
+        let unsolved = solver.solve_with_provenance(&expr!(y + 1), &x);
+        assert_eq!(unsolved.algorithm, AlgorithmId::Unevaluated);
+    }
+
+    #[test]
+    fn test_solve_inequality() {
+        use crate::core::expression::RelationType;
+
+        let solver = MathSolver::new();
+        let x = symbol!(x);
+        let inequality = |left, right, relation_type| {
+            solver.solve_inequality(&Expression::relation(left, right, relation_type), &x)
+        };
+
+        let linear = inequality(expr!(3 - x), expr!(1), RelationType::LessEqual).unwrap();
+        assert_eq!(linear.to_string(), "[2, ∞)");
+
+        let cubic = inequality(expr!((x ^ 3) - x), expr!(0), RelationType::Greater).unwrap();
+        assert_eq!(cubic.to_string(), "(-1, 0) ∪ (1, ∞)");
+
+        let rational = inequality(
+            Expression::div(expr!(x - 3), expr!(x + 1)),
+            expr!(0),
+            RelationType::LessEqual,
+        )
+        .unwrap();
+        assert_eq!(rational.to_string(), "(-1, 3]");
+
+        let not_relation = solver.solve_inequality(&expr!(x - 1), &x).unwrap_err();
+        assert_eq!(not_relation.hint(), Some(Remediation::TryAnotherMethod));
+        let transcendental = inequality(expr!(sin(x)), expr!(0), RelationType::Less).unwrap_err();
+        assert_eq!(transcendental.operation(), Some("solve_inequality"));
+    }
+
+    #[test]
+    fn test_solutions_respect_symbol_assumptions() {
+        use crate::core::symbol::Assumption;
+
+        let solver = MathSolver::new();
+        let quadratic = |x: &Symbol| {
+            Expression::add(vec![
+                Expression::pow(Expression::symbol(x.clone()), Expression::integer(2)),
+                Expression::integer(-4),
+            ])
+        };
+
+        let positive = Symbol::new_with_assumptions("x", &[Assumption::Positive]);
+        assert_eq!(
+            solver.solve(&quadratic(&positive), &positive),
+            SolverResult::Single(expr!(2))
+        );
+        assert!(matches!(
+            solver.solve(&quadratic(&symbol!(x)), &symbol!(x)),
+            SolverResult::Multiple(roots) if roots.len() == 2
+        ));
+
+        let n = Symbol::new_with_assumptions("n", &[Assumption::Integer]);
+        let linear = Expression::add(vec![
+            Expression::mul(vec![Expression::integer(2), Expression::symbol(n.clone())]),
+            Expression::integer(-3),
+        ]);
+        assert_eq!(solver.solve(&linear, &n), SolverResult::NoSolution);
+    }
+}
@@ -0,0 +1,205 @@
+//! Substitution check of solver results
+//!
+//! Each candidate solution is substituted back into the equation's standard
+//! form `left - right`. A residual that simplifies to zero, or evaluates to a
+//! number within the tolerance, verifies the solution. A residual that is a
+//! nonzero number, or a solution that leaves the equation's domain (a vanishing
+//! denominator or logarithm argument), is refuted; these are the extraneous
+//! roots introduced by squaring or clearing denominators.
+
+use crate::core::{Expression, Symbol};
+use crate::error::MathError;
+use crate::simplify::Simplify;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Outcome of substituting one solution back into the equation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VerificationStatus {
+    /// The residual is zero, exactly or within the tolerance
+    Verified,
+    /// The residual is nonzero or undefined: the solution is extraneous
+    Failed,
+    /// The residual stays symbolic, e.g. when the equation has parameters
+    Inconclusive,
+}
+
+/// Residual of one solution
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SolutionCheck {
+    pub solution: Expression,
+    /// Simplified `left - right` with the solution substituted
+    pub residual: Expression,
+    /// Numeric value of `residual`, when it evaluates to a real number
+    pub numeric_residual: Option<f64>,
+    pub status: VerificationStatus,
+}
+
+/// Residuals of every solution in a `SolverResult`
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct VerificationReport {
+    pub checks: Vec<SolutionCheck>,
+}
+
+impl VerificationReport {
+    /// No solution was refuted
+    pub fn is_consistent(&self) -> bool {
+        self.checks
+            .iter()
+            .all(|check| check.status != VerificationStatus::Failed)
+    }
+
+    /// Solutions refuted by substitution
+    pub fn extraneous(&self) -> Vec<&Expression> {
+        self.checks
+            .iter()
+            .filter(|check| check.status == VerificationStatus::Failed)
+            .map(|check| &check.solution)
+            .collect()
+    }
+}
+
+/// Substitute `solution` for `variable` in `equation` and check the residual
+///
+/// # Examples
+///
+/// ```rust
+/// use mathhook_core::solvers::{verify_solution, VerificationStatus};
+/// use mathhook_core::{expr, symbol, Expression};
+///
+/// let x = symbol!(x);
+/// let equation = Expression::equation(expr!(x ^ 2), expr!(4));
+///
+/// let check = verify_solution(&equation, &x, &expr!(-2), 1e-10);
+/// assert_eq!(check.status, VerificationStatus::Verified);
+///
+/// let check = verify_solution(&equation, &x, &expr!(3), 1e-10);
+/// assert_eq!(check.status, VerificationStatus::Failed);
+/// assert_eq!(check.numeric_residual, Some(5.0));
+/// ```
+pub fn verify_solution(
+    equation: &Expression,
+    variable: &Symbol,
+    solution: &Expression,
+    tolerance: f64,
+) -> SolutionCheck {
+    let substitutions = HashMap::from([(variable.name().to_owned(), solution.clone())]);
+    let substituted = standard_form(equation).substitute(&substitutions);
+    let residual = substituted.simplify();
+
+    let (numeric_residual, status) = if leaves_domain(equation, &substitutions) {
+        (None, VerificationStatus::Failed)
+    } else if residual.is_zero() {
+        (Some(0.0), VerificationStatus::Verified)
+    } else {
+        match substituted.evaluate_to_f64() {
+            Ok(value) if value.is_finite() && value.abs() <= tolerance => {
+                (Some(value), VerificationStatus::Verified)
+            }
+            Ok(value) => (Some(value), VerificationStatus::Failed),
+            Err(
+                MathError::DivisionByZero
+                | MathError::DomainError { .. }
+                | MathError::Pole { .. }
+                | MathError::Undefined { .. },
+            ) => (None, VerificationStatus::Failed),
+            Err(_) => (None, VerificationStatus::Inconclusive),
+        }
+    };
+
+    SolutionCheck {
+        solution: solution.clone(),
+        residual,
+        numeric_residual,
+        status,
+    }
+}
+
+/// A denominator or logarithm argument of the original equation vanishes
+///
+/// Checked before the residual because building the substituted residual
+/// already cancels `1/0 - 1/0` to zero.
+fn leaves_domain(expr: &Expression, substitutions: &HashMap<String, Expression>) -> bool {
+    let vanishes = |inner: &Expression| inner.substitute(substitutions).simplify().is_zero();
+    match expr {
+        Expression::Pow(base, exponent) => {
+            (exponent.is_negative_number() && vanishes(base))
+                || leaves_domain(base, substitutions)
+                || leaves_domain(exponent, substitutions)
+        }
+        Expression::Function { name, args, .. } => {
+            (matches!(name.as_ref(), "ln" | "log") && args.first().is_some_and(vanishes))
+                || args.iter().any(|arg| leaves_domain(arg, substitutions))
+        }
+        Expression::Add(terms) | Expression::Mul(terms) => {
+            terms.iter().any(|term| leaves_domain(term, substitutions))
+        }
+        Expression::Relation(relation) => {
+            leaves_domain(&relation.left, substitutions)
+                || leaves_domain(&relation.right, substitutions)
+        }
+        _ => false,
+    }
+}
+
+/// `left - right` for equations, the expression itself otherwise
+fn standard_form(equation: &Expression) -> Expression {
+    match equation {
+        Expression::Relation(relation) => Expression::add(vec![
+            relation.left.clone(),
+            Expression::mul(vec![Expression::integer(-1), relation.right.clone()]),
+        ]),
+        other => other.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{expr, symbol};
+
+    #[test]
+    fn test_expression_form_is_checked_against_zero() {
+        let x = symbol!(x);
+        let check = verify_solution(&expr!((2 * x) - 6), &x, &expr!(3), 1e-10);
+        assert_eq!(check.status, VerificationStatus::Verified);
+        assert_eq!(check.residual, expr!(0));
+    }
+
+    #[test]
+    fn test_solution_outside_domain_is_extraneous() {
+        let x = symbol!(x);
+        // x/(x - 1) = 1/(x - 1) clears to x = 1, which is not in the domain
+        let denominator = Expression::pow(expr!(x - 1), expr!(-1));
+        let equation = Expression::equation(
+            Expression::mul(vec![expr!(x), denominator.clone()]),
+            denominator,
+        );
+        let check = verify_solution(&equation, &x, &expr!(1), 1e-10);
+        assert_eq!(check.status, VerificationStatus::Failed);
+
+        let report = VerificationReport {
+            checks: vec![check],
+        };
+        assert!(!report.is_consistent());
+        assert_eq!(report.extraneous(), vec![&expr!(1)]);
+    }
+
+    #[test]
+    fn test_symbolic_residual_is_inconclusive() {
+        let x = symbol!(x);
+        let equation = Expression::equation(expr!(x ^ 2), expr!(a));
+        let check = verify_solution(&equation, &x, &expr!(b), 1e-10);
+        assert_eq!(check.status, VerificationStatus::Inconclusive);
+        assert_eq!(check.numeric_residual, None);
+    }
+
+    #[test]
+    fn test_float_roots_within_tolerance() {
+        let x = symbol!(x);
+        let equation = Expression::equation(expr!(x ^ 2), expr!(2));
+        let root = Expression::float(std::f64::consts::SQRT_2);
+        let check = verify_solution(&equation, &x, &root, 1e-10);
+        assert_eq!(check.status, VerificationStatus::Verified);
+    }
+}
@@ -186,6 +186,108 @@ mod large_numbers {
             "20! should be computed correctly"
         );
     }
+
+    #[test]
+    fn test_integer_overflow_promotes_to_big_integer() {
+        let big = |digits: &[u8]| BigInt::parse_bytes(digits, 10).unwrap();
+        let max = Number::integer(i64::MAX);
+        let min = Number::integer(i64::MIN);
+
+        assert_eq!(
+            (max.clone() + Number::integer(1)).unwrap(),
+            Number::BigInteger(Box::new(big(b"9223372036854775808")))
+        );
+        assert_eq!(
+            (max * Number::integer(2)).unwrap(),
+            Number::BigInteger(Box::new(big(b"18446744073709551614")))
+        );
+        assert_eq!(
+            (min.clone() / Number::integer(-1)).unwrap(),
+            Number::BigInteger(Box::new(big(b"9223372036854775808")))
+        );
+        assert_eq!(
+            (-min).unwrap(),
+            Number::BigInteger(Box::new(big(b"9223372036854775808")))
+        );
+
+        // The simplifier promotes instead of saturating
+        let sum = Expression::add(vec![
+            Expression::integer(i64::MAX),
+            Expression::integer(i64::MAX),
+            expr!(x),
+        ]);
+        assert_eq!(
+            sum.simplify(),
+            Expression::add(vec![
+                Expression::big_integer(big(b"18446744073709551614")),
+                expr!(x)
+            ])
+        );
+    }
+
+    #[test]
+    fn test_power_overflow() {
+        let power = expr!(2 ^ 100).simplify();
+        assert_eq!(
+            power,
+            Expression::big_integer(BigInt::from(1) << 100),
+            "2^100 should be exact"
+        );
+
+        let reciprocal = expr!(3 ^ (-50)).simplify();
+        assert_eq!(
+            reciprocal,
+            Expression::number(Number::rational(BigRational::new(
+                BigInt::from(1),
+                BigInt::from(3).pow(50)
+            )))
+        );
+
+        // Too large to expand, so left as a power
+        let huge = Expression::pow(expr!(2), Expression::integer(1_000_000_000)).simplify();
+        assert!(
+            matches!(huge, Expression::Pow(_, _)),
+            "2^(10^9) should stay unevaluated, got: {huge}"
+        );
+    }
+
+    #[test]
+    fn test_factorial_beyond_i64() {
+        let factorial_25 = Expression::function("factorial", vec![expr!(25)]).simplify();
+        assert_eq!(
+            factorial_25,
+            Expression::big_integer((1..=25).map(BigInt::from).product()),
+            "25! should be exact"
+        );
+
+        // A big coefficient must survive next to a non-numeric factor
+        let scaled = Expression::mul(vec![factorial_25.clone(), expr!(x)]).simplify();
+        assert_eq!(
+            scaled,
+            Expression::mul(vec![factorial_25.clone(), expr!(x)]),
+            "got: {scaled}"
+        );
+        let doubled = Expression::add(vec![factorial_25.clone(), factorial_25, expr!(x)]);
+        assert_eq!(
+            doubled.simplify(),
+            Expression::add(vec![
+                Expression::big_integer(
+                    BigInt::from(2) * (1..=25).map(BigInt::from).product::<BigInt>()
+                ),
+                expr!(x)
+            ])
+        );
+    }
+
+    #[test]
+    fn test_lcm_beyond_i64() {
+        let a = Expression::integer(i64::MAX);
+        let b = Expression::integer(i64::MAX - 1);
+        assert_eq!(
+            a.lcm(&b),
+            Expression::big_integer(BigInt::from(i64::MAX) * BigInt::from(i64::MAX - 1))
+        );
+    }
 }
 
 // Rational number edge cases
@@ -5,6 +5,7 @@
 //! Each test validates both solver correctness AND mathematical accuracy.
 
 use mathhook_core::prelude::*;
+use mathhook_core::solvers::VerificationStatus;
 
 // Helper: Verify solution satisfies equation through substitution
 // Tests that substituting the solution into the equation makes both sides equal.
@@ -501,6 +502,10 @@ fn test_complete_solving_workflow() {
             SolverResult::Multiple(sols) => sols,
             SolverResult::NoSolution => vec![],
             SolverResult::InfiniteSolutions => continue, // Skip verification for infinite
+            SolverResult::Cases(_) => panic!("Numeric equations need no case split"),
+            SolverResult::ImageSets(_) | SolverResult::ConditionSet(_) => {
+                panic!("Polynomial equations have finitely many closed-form roots")
+            }
         };
 
         // Verify we got the expected number of solutions
@@ -524,3 +529,38 @@ fn test_complete_solving_workflow() {
         }
     }
 }
+
+#[test]
+fn test_solve_verified_reports_residuals() {
+    // Equation: x² = 4, both roots substitute to a zero residual
+    let x = symbol!(x);
+    let left = Expression::pow(Expression::symbol(x.clone()), Expression::integer(2));
+    let equation = Expression::equation(left, Expression::integer(4));
+
+    let solver = MathSolver::new();
+    let (result, report) = solver.solve_verified(&equation, &x);
+
+    assert!(matches!(result, SolverResult::Multiple(_)));
+    assert_eq!(report.checks.len(), 2);
+    assert!(report.is_consistent());
+    for check in &report.checks {
+        assert_eq!(check.status, VerificationStatus::Verified);
+        assert!(check.residual.is_zero());
+    }
+}
+
+#[test]
+fn test_strict_verification_keeps_valid_solutions() {
+    // Equation: 3x - 6 = 0, solution x = 2 passes the strict check
+    let x = symbol!(x);
+    let equation = Expression::equation(expr!((3 * x) - 6), Expression::integer(0));
+
+    let solver = MathSolver::with_config(SolverConfig {
+        strict_verification: true,
+        ..Default::default()
+    });
+    let (result, report) = solver.solve_verified(&equation, &x);
+
+    assert_eq!(result, SolverResult::Single(Expression::integer(2)));
+    assert!(report.extraneous().is_empty());
+}
@@ -21,6 +21,7 @@ fn number_to_f64(n: &Number) -> f64 {
             let denom = r.denom().to_string().parse::<f64>().unwrap_or(f64::NAN);
             numer / denom
         }
+        Number::Decimal(d) => d.to_f64().unwrap_or(f64::NAN),
     }
 }
 
@@ -50,7 +51,9 @@ fn assert_is_zero_or_undefined(expr: &Expression) {
             let val = number_to_f64(n);
             assert!(val.abs() < EPSILON, "Expected zero, got {}", val);
         }
-        Expression::Function { name, args } if name.as_ref() == "undefined" && args.is_empty() => {
+        Expression::Function { name, args, .. }
+            if name.as_ref() == "undefined" && args.is_empty() =>
+        {
             // undefined is acceptable for singular matrix determinant
         }
         _ => panic!("Expected zero or undefined, got {:?}", expr),
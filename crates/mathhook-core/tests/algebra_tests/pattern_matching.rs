@@ -421,7 +421,7 @@ fn test_function_expression_creation() {
     let x = symbol!(x);
     let sin_x = Expression::function("sin", vec![Expression::symbol(x)]);
     match sin_x {
-        Expression::Function { name, args } => {
+        Expression::Function { name, args, .. } => {
             assert_eq!(name.as_ref(), "sin");
             assert_eq!(args.len(), 1);
         }
@@ -26,6 +26,7 @@ fn analyze_all_parsing_cases() {
         total += 1;
         let parser = Parser::new(&ParserConfig {
             enable_implicit_multiplication: true,
+            ..ParserConfig::default()
         });
         match parser.parse(&case.input) {
             Ok(_expr) => {
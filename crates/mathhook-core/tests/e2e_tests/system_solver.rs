@@ -8,7 +8,8 @@
 //! - Edge cases (zero rows, identity matrix, diagonal systems)
 
 use mathhook_core::algebra::solvers::{SolverResult, SystemEquationSolver, SystemSolver};
-use mathhook_core::{symbol, Expression};
+use mathhook_core::core::expression::RelationType;
+use mathhook_core::{expr, symbol, Expression};
 
 #[test]
 fn test_2x2_unique_solution() {
@@ -471,6 +472,32 @@ fn test_non_square_system() {
     );
 }
 
+#[test]
+fn test_one_variable_intersects_solution_sets() {
+    let solver = SystemSolver::new();
+    let x = symbol!(x);
+    let variables = [x.clone()];
+
+    // x² - 1 = 0, x² + x - 2 = 0: only x = 1 satisfies both
+    let result = solver.solve_system(&[expr!((x ^ 2) - 1), expr!((x ^ 2) + x - 2)], &variables);
+    assert_eq!(result, SolverResult::Single(Expression::integer(1)));
+
+    // x² = 4 and x > 0
+    let positive = Expression::relation(expr!(x), expr!(0), RelationType::Greater);
+    let result = solver.solve_system(&[expr!((x ^ 2) - 4), positive], &variables);
+    assert_eq!(result, SolverResult::Single(Expression::integer(2)));
+
+    // x² < 1 and x > 0 leaves the interval (0, 1)
+    let inside = Expression::relation(expr!(x ^ 2), expr!(1), RelationType::Less);
+    let positive = Expression::relation(expr!(x), expr!(0), RelationType::Greater);
+    let result = solver.solve_system(&[inside, positive], &variables);
+    assert_eq!(result, SolverResult::InfiniteSolutions);
+
+    // x = 1 and x = 2 are inconsistent
+    let result = solver.solve_system(&[expr!(x - 1), expr!(x - 2)], &variables);
+    assert_eq!(result, SolverResult::NoSolution);
+}
+
 #[test]
 fn test_3x3_requires_pivoting() {
     let solver = SystemSolver::new();
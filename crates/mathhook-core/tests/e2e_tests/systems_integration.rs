@@ -7,7 +7,7 @@ use mathhook_core::algebra::equation_analyzer::{
     EquationAnalyzer, EquationType, SmartEquationSolver,
 };
 use mathhook_core::algebra::solvers::{SolverResult, SystemEquationSolver, SystemSolver};
-use mathhook_core::{symbol, Expression};
+use mathhook_core::{expr, symbol, Expression};
 
 #[test]
 fn test_system_detection_multiple_variables() {
@@ -161,15 +161,12 @@ fn test_polynomial_system_routes_to_groebner() {
 }
 
 #[test]
-#[ignore = "FIXME: Lets find out why"]
-fn test_groebner_stub_mathematical_honesty() {
-    // Test: Verify Fix 3 - stub returns Partial (honest) not NoSolution (false negative)
+fn test_groebner_circle_and_line() {
     let x = symbol!(x);
     let y = symbol!(y);
 
     let solver = SystemSolver::new();
 
-    // Complex polynomial system that requires univariate solving + back-substitution
     // Circle: x² + y² = 1
     // Line: x - y = 0
     // Solutions: (√2/2, √2/2) and (-√2/2, -√2/2)
@@ -185,24 +182,29 @@ fn test_groebner_stub_mathematical_honesty() {
 
     let result = solver.solve_system(&[eq1, eq2], &[x, y]);
 
-    // Should NOT return NoSolution (that would be false negative)
-    // Should return either:
-    // - Multiple (if full extraction implemented)
-    // - Partial (if extraction incomplete - acceptable per Fix 3)
-    assert!(
-        !matches!(result, SolverResult::NoSolution),
-        "Should NOT return NoSolution for solvable system - this is mathematically incorrect. \
-         Expected Multiple or Partial. Got: {:?}",
-        result
-    );
+    let SolverResult::Multiple(points) = result else {
+        panic!("Expected two solution points, got {:?}", result);
+    };
+    assert_eq!(points.len(), 2);
+    for point in &points {
+        let Expression::Set(values) = point else {
+            panic!("Expected a point, got {:?}", point);
+        };
+        let x_value = values[0].evaluate_to_f64().unwrap();
+        let y_value = values[1].evaluate_to_f64().unwrap();
+        assert!((x_value - y_value).abs() < 1e-10);
+        assert!((x_value.abs() - std::f64::consts::FRAC_1_SQRT_2).abs() < 1e-10);
+    }
+}
 
-    // Partial is acceptable (Gröbner basis computed, extraction incomplete)
-    // Full implementation deferred to Phase 4: WAVE-CLEANUP
-    assert!(
-        matches!(result, SolverResult::Partial(_) | SolverResult::Multiple(_)),
-        "Expected Partial or Multiple, got {:?}",
-        result
-    );
+#[test]
+fn test_groebner_inconsistent_system() {
+    let x = symbol!(x);
+    let y = symbol!(y);
+
+    // xy = 1 and x = 0 have no common solution
+    let result = SystemSolver::new().solve_system(&[expr!((x * y) - 1), expr!(x)], &[x, y]);
+    assert_eq!(result, SolverResult::NoSolution);
 }
 
 #[test]
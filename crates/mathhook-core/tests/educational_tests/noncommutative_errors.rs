@@ -213,6 +213,7 @@ fn test_formatter_context_variations() {
     let context1 = LaTeXContext::default();
     let context2 = LaTeXContext {
         needs_parentheses: true,
+        ..Default::default()
     };
 
     let latex1 = expr.to_latex(context1);
@@ -10,6 +10,7 @@ use mathhook_core::{symbol, symbols, Expression, Symbol};
 fn parse_latex(input: &str) -> Result<Expression, String> {
     let parser = Parser::new(&ParserConfig {
         enable_implicit_multiplication: true,
+        ..ParserConfig::default()
     });
     parser.parse(input).map_err(|e| e.to_string())
 }
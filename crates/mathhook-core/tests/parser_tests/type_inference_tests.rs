@@ -6,6 +6,7 @@ use mathhook_core::parser::Parser;
 fn parse_latex(input: &str) -> Result<Expression, String> {
     let parser = Parser::new(&ParserConfig {
         enable_implicit_multiplication: true,
+        ..ParserConfig::default()
     });
     parser.parse(input).map_err(|e| e.to_string())
 }
@@ -321,6 +322,7 @@ fn test_nested_expression_commutativity() {
 fn test_malformed_latex_mathbf() {
     let parser = Parser::new(&ParserConfig {
         enable_implicit_multiplication: true,
+        ..ParserConfig::default()
     });
     let result1 = parser.parse(r"\mathbf{");
     let result2 = parser.parse(r"\mathbf}A");
@@ -342,6 +344,7 @@ fn test_malformed_latex_mathbf() {
 fn test_malformed_latex_hat() {
     let parser = Parser::new(&ParserConfig {
         enable_implicit_multiplication: true,
+        ..ParserConfig::default()
     });
     let result1 = parser.parse(r"\hat{");
     let result2 = parser.parse(r"\hat}p");
@@ -9,6 +9,7 @@
 //! - Identities: sin^2(x) + cos^2(x) = 1, e^(ln x) = x
 //! - Inverse operations: d/dx(integral(f)) = f, expand(factor(x)) = x
 //! - Domain correctness: sqrt(-1) handling, log(0) errors
+//! - Random expressions: simplify and expand keep values on seeded inputs
 
 pub mod associativity;
 pub mod commutativity;
@@ -16,3 +17,4 @@ pub mod domain_correctness;
 pub mod identities;
 pub mod inverse_operations;
 pub mod numerical_stability;
+pub mod random_expressions;
@@ -3,22 +3,22 @@
 //! This module provides the implementation for the procedural expr!() macro.
 //! All exports are internal to the crate only.
 use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
 mod codegen;
 mod errors;
 mod parser;
-mod preprocessor;
+pub(crate) use codegen::CodeGenerator;
 use parser::ExprParser;
-use preprocessor::PowerOperatorParser;
 /// Implementation function for the expr!() procedural macro (crate-internal)
 ///
-/// Includes ** power operator support via token-level preprocessing.
+/// Parses the tokens with mathematical precedence, including `^` and `**` power.
 pub(crate) fn expr_impl(input: TokenStream) -> TokenStream {
-    let syn_expr = match PowerOperatorParser::parse_with_power(input) {
-        Ok(expr) => expr,
-        Err(err) => return err.to_compile_error().into(),
-    };
-    match ExprParser::parse(&syn_expr) {
+    match ExprParser::parse(input.into()) {
         Ok(tokens) => tokens.into(),
         Err(err) => err.to_compile_error().into(),
     }
 }
+/// Matrix constructor code for comma-separated bracketed rows (crate-internal)
+pub(crate) fn matrix_tokens(rows: TokenStream2) -> syn::Result<TokenStream2> {
+    ExprParser::parse_matrix(rows, Span::call_site())
+}
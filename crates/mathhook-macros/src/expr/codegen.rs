@@ -105,6 +105,13 @@ impl CodeGenerator {
             mathhook_core::core::expression::RelationType::Equal)
         }
     }
+    /// Generate code for inequality: a != b
+    pub fn generate_relation_not_equal(left: &TokenStream, right: &TokenStream) -> TokenStream {
+        quote! {
+            mathhook_core::Expression::relation(# left, # right,
+            mathhook_core::core::expression::RelationType::NotEqual)
+        }
+    }
     /// Generate code for less than: a < b
     pub fn generate_relation_less(left: &TokenStream, right: &TokenStream) -> TokenStream {
         quote! {
@@ -151,4 +158,80 @@ impl CodeGenerator {
             (# receiver).simplify()
         }
     }
+    /// Generate code for a relational chain: a < b <= c is (a < b) and (b <= c)
+    pub fn generate_conjunction(relations: &[TokenStream]) -> TokenStream {
+        quote! {
+            mathhook_core::Expression::function("and", vec![# (# relations),*])
+        }
+    }
+    /// Generate code for a list of expressions: [a, b, c]
+    pub fn generate_list(items: &[TokenStream]) -> TokenStream {
+        quote! {
+            vec![# (# items),*]
+        }
+    }
+    /// Generate code for a matrix: [[a, b], [c, d]]
+    pub fn generate_matrix(rows: &[Vec<TokenStream>]) -> TokenStream {
+        let rows = rows.iter().map(|row| quote! { vec![# (# row),*] });
+        quote! {
+            mathhook_core::Expression::matrix(vec![# (# rows),*])
+        }
+    }
+    /// Generate code for derivative: diff(f, x) or diff(f, x, n)
+    pub fn generate_derivative(expr: &TokenStream, variable: &str, order: u32) -> TokenStream {
+        quote! {
+            mathhook_core::Expression::derivative(# expr,
+            mathhook_core::Symbol::scalar(# variable), # order)
+        }
+    }
+    /// Generate code for indefinite integral: integrate(f, x)
+    pub fn generate_integral(integrand: &TokenStream, variable: &str) -> TokenStream {
+        quote! {
+            mathhook_core::Expression::integral(# integrand,
+            mathhook_core::Symbol::scalar(# variable))
+        }
+    }
+    /// Generate code for definite integral: integrate(f, x, a, b)
+    pub fn generate_definite_integral(
+        integrand: &TokenStream,
+        variable: &str,
+        lower: &TokenStream,
+        upper: &TokenStream,
+    ) -> TokenStream {
+        quote! {
+            mathhook_core::Expression::definite_integral(# integrand,
+            mathhook_core::Symbol::scalar(# variable), # lower, # upper)
+        }
+    }
+    /// Generate code for limit: limit(f, x, a)
+    pub fn generate_limit(expr: &TokenStream, variable: &str, point: &TokenStream) -> TokenStream {
+        quote! {
+            mathhook_core::Expression::limit(# expr,
+            mathhook_core::Symbol::scalar(# variable), # point)
+        }
+    }
+    /// Generate code for summation: sum(f, i, a, b)
+    pub fn generate_sum(
+        expr: &TokenStream,
+        variable: &str,
+        lower: &TokenStream,
+        upper: &TokenStream,
+    ) -> TokenStream {
+        quote! {
+            mathhook_core::Expression::sum(# expr,
+            mathhook_core::Symbol::scalar(# variable), # lower, # upper)
+        }
+    }
+    /// Generate code for product: product(f, i, a, b)
+    pub fn generate_product(
+        expr: &TokenStream,
+        variable: &str,
+        lower: &TokenStream,
+        upper: &TokenStream,
+    ) -> TokenStream {
+        quote! {
+            mathhook_core::Expression::product(# expr,
+            mathhook_core::Symbol::scalar(# variable), # lower, # upper)
+        }
+    }
 }
@@ -2,7 +2,7 @@
 //!
 //! Provides helpful, actionable error messages that guide users to correct syntax.
 
-use proc_macro2::Span;
+use proc_macro2::{Span, TokenTree};
 use syn::Error;
 
 /// Create error for unsupported binary operator
@@ -11,10 +11,10 @@ pub fn unsupported_operator(op: &str, span: Span) -> Error {
         span,
         format!(
             "Unsupported operator '{}'\n\
-             = help: Supported operators: +, -, *, /, ** (power), ==, <, >, <=, >=\n\
-             = note: Use ** for exponentiation (e.g., x**2)\n\
+             = help: Supported operators: +, -, *, /, ^ or ** (power), ==, !=, <, >, <=, >=\n\
+             = note: Use ^ or ** for exponentiation (e.g., x^2)\n\
              = note: Or use .pow() method (e.g., x.pow(2))\n\
-             = note: Comparison operators: ==, <, >, <=, >= return boolean expressions",
+             = note: Comparison operators: ==, !=, <, >, <=, >= return relations",
             op
         ),
     )
@@ -71,3 +71,51 @@ pub fn unsupported_method_call(method: &str, span: Span) -> Error {
         ),
     )
 }
+
+/// Create error for a token that cannot appear at this position
+pub fn unexpected_token(token: &TokenTree) -> Error {
+    let hint = match token {
+        TokenTree::Punct(punct) if punct.as_char() == '=' => {
+            "\n= help: Use == to write an equation (e.g., x + 1 == 3)"
+        }
+        _ => "",
+    };
+    Error::new(
+        token.span(),
+        format!("Unexpected token '{}' in expression{}", token, hint),
+    )
+}
+
+/// Create error for input that ends before the expression is complete
+pub fn unexpected_end(span: Span) -> Error {
+    Error::new(
+        span,
+        "Expected an expression\n\
+         = help: An operator needs an operand on each side (e.g., x + 1)",
+    )
+}
+
+/// Create error for a calculus operator called with the wrong arguments
+pub fn calculus_usage(name: &str, usage: &str, span: Span) -> Error {
+    Error::new(
+        span,
+        format!(
+            "Wrong number of arguments to {}\n\
+             = help: Write {}",
+            name, usage
+        ),
+    )
+}
+
+/// Create error for a calculus variable that is not a plain identifier
+pub fn invalid_variable(usage: &str, span: Span) -> Error {
+    Error::new(
+        span,
+        format!(
+            "Expected a variable name\n\
+             = help: Write {}\n\
+             = note: The variable must be a single identifier such as x",
+            usage
+        ),
+    )
+}
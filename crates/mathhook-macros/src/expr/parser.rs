@@ -1,63 +1,259 @@
 //! Expression parser for procedural expr!() macro
 //!
-//! Parses syn::Expr into an intermediate representation that can be converted
-//! to mathhook Expression constructor calls.
+//! Parses the macro's tokens directly with mathematical precedence and emits
+//! mathhook Expression constructor calls. Rust's expression grammar is not
+//! involved, so `^` and `**` both mean power and bind tighter than unary minus.
 //!
-//! "**" power operator support via ^ (BitXor) preprocessing.
+//! Precedence (high to low):
+//! 1. Method calls: `.pow()`, `.abs()`, `.sqrt()`, `.simplify()`
+//! 2. Power (`^`, `**`) - right-associative
+//! 3. Unary negation
+//! 4. Multiplication, Division (`*`, `/`) - left-associative
+//! 5. Addition, Subtraction (`+`, `-`) - left-associative
+//! 6. Relations (`==`, `!=`, `<`, `>`, `<=`, `>=`) - a chain such as
+//!    `0 < x <= 1` becomes the conjunction of its links
 use super::codegen::CodeGenerator;
 use super::errors;
-use proc_macro2::TokenStream;
+use proc_macro2::{Delimiter, Group, Ident, Punct, Spacing, Span, TokenStream, TokenTree};
 use syn::spanned::Spanned;
-use syn::{BinOp, Expr, ExprBinary, ExprLit, ExprParen, ExprUnary, Lit, UnOp};
+use syn::Lit;
+
+/// Relational operator between two operands
+#[derive(Debug, Clone, Copy)]
+enum Relation {
+    Equal,
+    NotEqual,
+    Less,
+    Greater,
+    LessEqual,
+    GreaterEqual,
+}
+
+impl Relation {
+    fn generate(self, left: &TokenStream, right: &TokenStream) -> TokenStream {
+        match self {
+            Relation::Equal => CodeGenerator::generate_relation_equal(left, right),
+            Relation::NotEqual => CodeGenerator::generate_relation_not_equal(left, right),
+            Relation::Less => CodeGenerator::generate_relation_less(left, right),
+            Relation::Greater => CodeGenerator::generate_relation_greater(left, right),
+            Relation::LessEqual => CodeGenerator::generate_relation_less_equal(left, right),
+            Relation::GreaterEqual => CodeGenerator::generate_relation_greater_equal(left, right),
+        }
+    }
+}
+
 /// Parser for mathematical expressions
-pub struct ExprParser;
+pub struct ExprParser {
+    tokens: Vec<TokenTree>,
+    pos: usize,
+    /// Span reported when the input ends early
+    end: Span,
+}
+
 impl ExprParser {
-    /// Parse a syn::Expr into TokenStream of Expression constructors
-    pub fn parse(expr: &Expr) -> syn::Result<TokenStream> {
-        Self::parse_expr(expr)
-    }
-    /// Recursively parse expression tree
-    fn parse_expr(expr: &Expr) -> syn::Result<TokenStream> {
-        match expr {
-            Expr::Lit(ExprLit { lit, .. }) => Self::parse_literal(lit),
-            Expr::Path(path) => {
-                if path.path.segments.len() == 1 {
-                    let ident = &path.path.segments[0].ident;
-                    let name = ident.to_string();
-                    Ok(CodeGenerator::generate_symbol(&name))
-                } else {
-                    Err(errors::unsupported_expression(
-                        "qualified path",
-                        path.span(),
+    /// Parse macro input into TokenStream of Expression constructors
+    ///
+    /// A bracketed list of expressions, such as a system of equations,
+    /// becomes a `Vec<Expression>`; a bracketed list of rows becomes a matrix.
+    pub fn parse(input: TokenStream) -> syn::Result<TokenStream> {
+        let tokens: Vec<TokenTree> = input.into_iter().collect();
+        if let [TokenTree::Group(group)] = tokens.as_slice() {
+            if group.delimiter() == Delimiter::Bracket && !is_matrix(group) {
+                let items = split_on_commas(group.stream())
+                    .into_iter()
+                    .map(|item| Self::parse_tokens(item, group.span_close()))
+                    .collect::<syn::Result<Vec<_>>>()?;
+                return Ok(CodeGenerator::generate_list(&items));
+            }
+        }
+        Self::parse_tokens(tokens.into_iter().collect(), Span::call_site())
+    }
+
+    /// Parse comma-separated bracketed rows into a matrix constructor
+    pub fn parse_matrix(rows: TokenStream, span: Span) -> syn::Result<TokenStream> {
+        let mut elements: Vec<Vec<TokenStream>> = Vec::new();
+        for row in split_on_commas(rows) {
+            let group = match row.clone().into_iter().collect::<Vec<_>>().as_slice() {
+                [TokenTree::Group(group)] if group.delimiter() == Delimiter::Bracket => {
+                    group.clone()
+                }
+                _ => {
+                    return Err(syn::Error::new(
+                        row.span(),
+                        "Each matrix row must be written in brackets, as in [1, x]",
                     ))
                 }
+            };
+            let row_elements = split_on_commas(group.stream())
+                .into_iter()
+                .map(|element| Self::parse_tokens(element, group.span_close()))
+                .collect::<syn::Result<Vec<_>>>()?;
+            match elements.first() {
+                None if row_elements.is_empty() => {
+                    return Err(syn::Error::new(group.span(), "Matrix rows cannot be empty"))
+                }
+                Some(first) if first.len() != row_elements.len() => {
+                    return Err(syn::Error::new(
+                        group.span(),
+                        format!(
+                            "Matrix row has {} elements but the first row has {}",
+                            row_elements.len(),
+                            first.len()
+                        ),
+                    ))
+                }
+                _ => {}
             }
-            Expr::Binary(binary) => Self::parse_binary(binary),
-            Expr::Unary(unary) => Self::parse_unary(unary),
-            Expr::Paren(ExprParen { expr, .. }) => Self::parse_expr(expr),
-            Expr::Call(call) => {
-                if let Expr::Path(path) = call.func.as_ref() {
-                    if path.path.segments.len() == 1 {
-                        let func_name = path.path.segments[0].ident.to_string();
-                        let mut args = Vec::new();
-                        for arg in &call.args {
-                            args.push(Self::parse_expr(arg)?);
-                        }
-                        return Ok(CodeGenerator::generate_function(&func_name, &args));
-                    }
+            elements.push(row_elements);
+        }
+        if elements.is_empty() {
+            return Err(syn::Error::new(span, "A matrix needs at least one row"));
+        }
+        Ok(CodeGenerator::generate_matrix(&elements))
+    }
+
+    /// Parse one complete expression, rejecting leftover tokens
+    fn parse_tokens(input: TokenStream, end: Span) -> syn::Result<TokenStream> {
+        let mut parser = ExprParser {
+            tokens: input.into_iter().collect(),
+            pos: 0,
+            end,
+        };
+        let expr = parser.parse_relation()?;
+        match parser.tokens.get(parser.pos) {
+            Some(TokenTree::Punct(punct)) if !matches!(punct.as_char(), '=' | ',' | ';') => Err(
+                errors::unsupported_operator(&punct.to_string(), punct.span()),
+            ),
+            Some(token) => Err(errors::unexpected_token(token)),
+            None => Ok(expr),
+        }
+    }
+
+    /// Parse a relation or a chain of relations
+    fn parse_relation(&mut self) -> syn::Result<TokenStream> {
+        let mut operands = vec![self.parse_sum()?];
+        let mut relations = Vec::new();
+        while let Some(relation) = self.eat_relation() {
+            relations.push(relation);
+            operands.push(self.parse_sum()?);
+        }
+        let mut links: Vec<TokenStream> = relations
+            .iter()
+            .zip(operands.windows(2))
+            .map(|(relation, pair)| relation.generate(&pair[0], &pair[1]))
+            .collect();
+        Ok(match links.len() {
+            0 => operands.swap_remove(0),
+            1 => links.swap_remove(0),
+            _ => CodeGenerator::generate_conjunction(&links),
+        })
+    }
+
+    /// Parse addition and subtraction, left-associative
+    fn parse_sum(&mut self) -> syn::Result<TokenStream> {
+        let mut left = self.parse_product()?;
+        loop {
+            if self.eat_punct('+') {
+                let right = self.parse_product()?;
+                left = CodeGenerator::generate_add(&left, &right);
+            } else if self.eat_punct('-') {
+                let right = self.parse_product()?;
+                left = CodeGenerator::generate_sub(&left, &right);
+            } else {
+                return Ok(left);
+            }
+        }
+    }
+
+    /// Parse multiplication and division, left-associative
+    fn parse_product(&mut self) -> syn::Result<TokenStream> {
+        let mut left = self.parse_unary()?;
+        loop {
+            if self.eat_punct('*') {
+                let right = self.parse_unary()?;
+                left = CodeGenerator::generate_mul(&left, &right);
+            } else if self.eat_punct('/') {
+                let right = self.parse_unary()?;
+                left = CodeGenerator::generate_div(&left, &right);
+            } else {
+                return Ok(left);
+            }
+        }
+    }
+
+    /// Parse unary negation, which binds looser than power: `-x^2` is `-(x^2)`
+    fn parse_unary(&mut self) -> syn::Result<TokenStream> {
+        if self.eat_punct('-') {
+            let operand = self.parse_unary()?;
+            return Ok(CodeGenerator::generate_neg(&operand));
+        }
+        self.parse_power()
+    }
+
+    /// Parse power, right-associative; the exponent may be negated
+    fn parse_power(&mut self) -> syn::Result<TokenStream> {
+        let base = self.parse_postfix()?;
+        if self.eat_power() {
+            let exponent = self.parse_unary()?;
+            return Ok(CodeGenerator::generate_pow(&base, &exponent));
+        }
+        Ok(base)
+    }
+
+    /// Parse an atom followed by any method calls
+    fn parse_postfix(&mut self) -> syn::Result<TokenStream> {
+        let mut receiver = self.parse_atom()?;
+        while self.peek_punct(0).is_some_and(|p| p.as_char() == '.') {
+            let Some(TokenTree::Ident(method)) = self.tokens.get(self.pos + 1).cloned() else {
+                break;
+            };
+            self.pos += 2;
+            let args = match self.tokens.get(self.pos) {
+                Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis => {
+                    let group = group.clone();
+                    self.pos += 1;
+                    Self::parse_arguments(&group)?
                 }
-                Err(errors::unsupported_expression(
-                    "complex function call",
-                    call.span(),
-                ))
+                _ => Vec::new(),
+            };
+            receiver = Self::parse_method_call(&method, &receiver, &args)?;
+        }
+        Ok(receiver)
+    }
+
+    /// Parse a literal, symbol, function call, parenthesized group or matrix
+    fn parse_atom(&mut self) -> syn::Result<TokenStream> {
+        let Some(token) = self.tokens.get(self.pos).cloned() else {
+            return Err(errors::unexpected_end(self.end));
+        };
+        self.pos += 1;
+        match token {
+            TokenTree::Literal(literal) => {
+                let lit: Lit = syn::parse2(TokenTree::Literal(literal).into())?;
+                Self::parse_literal(&lit)
             }
-            Expr::MethodCall(method) => Self::parse_method_call(method),
-            _ => Err(errors::unsupported_expression(
-                "this expression type",
-                expr.span(),
+            TokenTree::Ident(ident) => match self.tokens.get(self.pos) {
+                Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis => {
+                    let group = group.clone();
+                    self.pos += 1;
+                    Self::parse_call(&ident, &group)
+                }
+                _ => Ok(CodeGenerator::generate_symbol(&ident.to_string())),
+            },
+            TokenTree::Group(group) => match group.delimiter() {
+                Delimiter::Parenthesis | Delimiter::None => {
+                    Self::parse_tokens(group.stream(), group.span_close())
+                }
+                Delimiter::Bracket => Self::parse_matrix(group.stream(), group.span()),
+                Delimiter::Brace => Err(errors::unexpected_token(&TokenTree::Group(group))),
+            },
+            TokenTree::Punct(punct) => Err(errors::unsupported_unary_operator(
+                &punct.to_string(),
+                punct.span(),
             )),
         }
     }
+
     /// Parse literal values
     fn parse_literal(lit: &Lit) -> syn::Result<TokenStream> {
         match lit {
@@ -79,104 +275,257 @@ impl ExprParser {
             )),
         }
     }
-    /// Parse binary operations with correct precedence
+
+    /// Parse a function call, recognizing the calculus operators
     ///
-    /// Precedence (high to low):
-    /// 1. Power (**) - right-associative
-    /// 2. Multiplication, Division (*, /) - left-associative
-    /// 3. Addition, Subtraction (+, -) - left-associative
-    /// 4. Comparisons (==, <, >, <=, >=) - non-associative
-    ///
-    /// Note: ** is preprocessed to ^ (BitXor) for parsing.
-    fn parse_binary(binary: &ExprBinary) -> syn::Result<TokenStream> {
-        match &binary.op {
-            BinOp::BitXor(_) => {
-                let left = Self::parse_expr(&binary.left)?;
-                let right = Self::parse_expr(&binary.right)?;
-                Ok(CodeGenerator::generate_pow(&left, &right))
-            }
-            BinOp::Mul(_) => {
-                let left = Self::parse_expr(&binary.left)?;
-                let right = Self::parse_expr(&binary.right)?;
-                Ok(CodeGenerator::generate_mul(&left, &right))
-            }
-            BinOp::Div(_) => {
-                let left = Self::parse_expr(&binary.left)?;
-                let right = Self::parse_expr(&binary.right)?;
-                Ok(CodeGenerator::generate_div(&left, &right))
-            }
-            BinOp::Add(_) => {
-                let left = Self::parse_expr(&binary.left)?;
-                let right = Self::parse_expr(&binary.right)?;
-                Ok(CodeGenerator::generate_add(&left, &right))
-            }
-            BinOp::Sub(_) => {
-                let left = Self::parse_expr(&binary.left)?;
-                let right = Self::parse_expr(&binary.right)?;
-                Ok(CodeGenerator::generate_sub(&left, &right))
+    /// - `diff(f, x)`, `diff(f, x, n)`: derivative of order 1 or `n`
+    /// - `integrate(f, x)`, `integrate(f, x, a, b)`: indefinite or definite integral
+    /// - `sum(f, i, a, b)`, `product(f, i, a, b)`: sum or product over `i` from `a` to `b`
+    /// - `limit(f, x, a)`: limit as `x` approaches `a`
+    fn parse_call(name: &Ident, group: &Group) -> syn::Result<TokenStream> {
+        let raw_args = split_on_commas(group.stream());
+        let func_name = name.to_string();
+        let usage = match func_name.as_str() {
+            "diff" => "diff(f, x) or diff(f, x, order)",
+            "integrate" => "integrate(f, x) or integrate(f, x, lower, upper)",
+            "sum" => "sum(f, i, lower, upper)",
+            "product" => "product(f, i, lower, upper)",
+            "limit" => "limit(f, x, point)",
+            _ => {
+                let args = Self::parse_arguments(group)?;
+                return Ok(CodeGenerator::generate_function(&func_name, &args));
             }
-            BinOp::Eq(_) => {
-                let left = Self::parse_expr(&binary.left)?;
-                let right = Self::parse_expr(&binary.right)?;
-                Ok(CodeGenerator::generate_relation_equal(&left, &right))
-            }
-            BinOp::Lt(_) => {
-                let left = Self::parse_expr(&binary.left)?;
-                let right = Self::parse_expr(&binary.right)?;
-                Ok(CodeGenerator::generate_relation_less(&left, &right))
+        };
+        let arity_matches = match func_name.as_str() {
+            "diff" => matches!(raw_args.len(), 2 | 3),
+            "integrate" => matches!(raw_args.len(), 2 | 4),
+            "limit" => raw_args.len() == 3,
+            _ => raw_args.len() == 4,
+        };
+        if !arity_matches {
+            return Err(errors::calculus_usage(&func_name, usage, group.span()));
+        }
+
+        let body = Self::parse_tokens(raw_args[0].clone(), group.span_close())?;
+        let variable = Self::parse_variable(&raw_args[1], usage)?;
+        let rest = raw_args[2..]
+            .iter()
+            .map(|arg| Self::parse_tokens(arg.clone(), group.span_close()))
+            .collect::<syn::Result<Vec<_>>>();
+        Ok(match (func_name.as_str(), raw_args.len()) {
+            ("diff", 2) => CodeGenerator::generate_derivative(&body, &variable, 1),
+            ("diff", _) => {
+                let order = Self::parse_order(&raw_args[2])?;
+                CodeGenerator::generate_derivative(&body, &variable, order)
             }
-            BinOp::Gt(_) => {
-                let left = Self::parse_expr(&binary.left)?;
-                let right = Self::parse_expr(&binary.right)?;
-                Ok(CodeGenerator::generate_relation_greater(&left, &right))
+            ("integrate", 2) => CodeGenerator::generate_integral(&body, &variable),
+            ("integrate", _) => {
+                let rest = rest?;
+                CodeGenerator::generate_definite_integral(&body, &variable, &rest[0], &rest[1])
             }
-            BinOp::Le(_) => {
-                let left = Self::parse_expr(&binary.left)?;
-                let right = Self::parse_expr(&binary.right)?;
-                Ok(CodeGenerator::generate_relation_less_equal(&left, &right))
+            ("limit", _) => CodeGenerator::generate_limit(&body, &variable, &rest?[0]),
+            ("sum", _) => {
+                let rest = rest?;
+                CodeGenerator::generate_sum(&body, &variable, &rest[0], &rest[1])
             }
-            BinOp::Ge(_) => {
-                let left = Self::parse_expr(&binary.left)?;
-                let right = Self::parse_expr(&binary.right)?;
-                Ok(CodeGenerator::generate_relation_greater_equal(
-                    &left, &right,
-                ))
+            _ => {
+                let rest = rest?;
+                CodeGenerator::generate_product(&body, &variable, &rest[0], &rest[1])
             }
-            _ => Err(errors::unsupported_operator("this operator", binary.span())),
+        })
+    }
+
+    /// Parse the variable of a calculus operator, which must be an identifier
+    fn parse_variable(arg: &TokenStream, usage: &str) -> syn::Result<String> {
+        match arg.clone().into_iter().collect::<Vec<_>>().as_slice() {
+            [TokenTree::Ident(ident)] => Ok(ident.to_string()),
+            _ => Err(errors::invalid_variable(usage, arg.span())),
         }
     }
-    /// Parse unary operations
-    fn parse_unary(unary: &ExprUnary) -> syn::Result<TokenStream> {
-        match unary.op {
-            UnOp::Neg(_) => {
-                let expr = Self::parse_expr(&unary.expr)?;
-                Ok(CodeGenerator::generate_neg(&expr))
-            }
-            UnOp::Deref(_) => Err(errors::unsupported_unary_operator("*", unary.span())),
-            _ => Err(errors::unsupported_unary_operator(
-                "this unary operator",
-                unary.span(),
+
+    /// Parse the order of a derivative, which must be an integer literal
+    fn parse_order(arg: &TokenStream) -> syn::Result<u32> {
+        match syn::parse2::<syn::LitInt>(arg.clone()) {
+            Ok(order) => order.base10_parse::<u32>(),
+            Err(_) => Err(syn::Error::new(
+                arg.span(),
+                "The order of diff(f, x, order) must be an integer literal",
             )),
         }
     }
+
+    /// Parse comma-separated arguments of a call
+    fn parse_arguments(group: &Group) -> syn::Result<Vec<TokenStream>> {
+        split_on_commas(group.stream())
+            .into_iter()
+            .map(|arg| Self::parse_tokens(arg, group.span_close()))
+            .collect()
+    }
+
     /// Parse method calls
-    fn parse_method_call(method: &syn::ExprMethodCall) -> syn::Result<TokenStream> {
-        let method_name = method.method.to_string();
-        let receiver = Self::parse_expr(&method.receiver)?;
-        match method_name.as_str() {
-            "pow" if method.args.len() == 1 => {
-                let arg = Self::parse_expr(&method.args[0])?;
-                Ok(CodeGenerator::generate_pow(&receiver, &arg))
-            }
-            "abs" if method.args.is_empty() => Ok(CodeGenerator::generate_method_abs(&receiver)),
-            "sqrt" if method.args.is_empty() => Ok(CodeGenerator::generate_method_sqrt(&receiver)),
-            "simplify" if method.args.is_empty() => {
-                Ok(CodeGenerator::generate_method_simplify(&receiver))
+    fn parse_method_call(
+        method: &Ident,
+        receiver: &TokenStream,
+        args: &[TokenStream],
+    ) -> syn::Result<TokenStream> {
+        let method_name = method.to_string();
+        match (method_name.as_str(), args) {
+            ("pow", [arg]) => Ok(CodeGenerator::generate_pow(receiver, arg)),
+            ("abs", []) => Ok(CodeGenerator::generate_method_abs(receiver)),
+            ("sqrt", []) => Ok(CodeGenerator::generate_method_sqrt(receiver)),
+            ("simplify", []) => Ok(CodeGenerator::generate_method_simplify(receiver)),
+            _ => Err(errors::unsupported_method_call(&method_name, method.span())),
+        }
+    }
+
+    fn peek_punct(&self, offset: usize) -> Option<&Punct> {
+        match self.tokens.get(self.pos + offset) {
+            Some(TokenTree::Punct(punct)) => Some(punct),
+            _ => None,
+        }
+    }
+
+    /// Consume a single-character operator, leaving `**` for the power level
+    fn eat_punct(&mut self, op: char) -> bool {
+        let Some(punct) = self.peek_punct(0) else {
+            return false;
+        };
+        let joined_to_equals = punct.spacing() == Spacing::Joint
+            && self.peek_punct(1).is_some_and(|next| next.as_char() == '=');
+        if punct.as_char() != op || joined_to_equals || (op == '*' && self.at_double_star()) {
+            return false;
+        }
+        self.pos += 1;
+        true
+    }
+
+    /// Consume `^` or `**`
+    ///
+    /// The two stars of `**` may be separated by a space, since rustfmt
+    /// formats `x ** 2` inside macros as `x * *2`.
+    fn eat_power(&mut self) -> bool {
+        if self.at_double_star() {
+            self.pos += 2;
+            return true;
+        }
+        self.eat_punct('^')
+    }
+
+    fn at_double_star(&self) -> bool {
+        self.peek_punct(0).is_some_and(|p| p.as_char() == '*')
+            && self.peek_punct(1).is_some_and(|p| p.as_char() == '*')
+    }
+
+    /// Consume a relational operator
+    fn eat_relation(&mut self) -> Option<Relation> {
+        let first = self.peek_punct(0)?;
+        let followed_by_equals = first.spacing() == Spacing::Joint
+            && self.peek_punct(1).is_some_and(|next| next.as_char() == '=');
+        let (relation, len) = match (first.as_char(), followed_by_equals) {
+            ('=', true) => (Relation::Equal, 2),
+            ('!', true) => (Relation::NotEqual, 2),
+            ('<', true) => (Relation::LessEqual, 2),
+            ('>', true) => (Relation::GreaterEqual, 2),
+            ('<', false) => (Relation::Less, 1),
+            ('>', false) => (Relation::Greater, 1),
+            _ => return None,
+        };
+        self.pos += len;
+        Some(relation)
+    }
+}
+
+/// Whether a bracket group is a list of bracketed rows
+fn is_matrix(group: &Group) -> bool {
+    let items = split_on_commas(group.stream());
+    !items.is_empty()
+        && items.iter().all(|item| {
+            matches!(
+                item.clone().into_iter().collect::<Vec<_>>().as_slice(),
+                [TokenTree::Group(row)] if row.delimiter() == Delimiter::Bracket
+            )
+        })
+}
+
+/// Split tokens at top-level commas, dropping a trailing comma
+fn split_on_commas(input: TokenStream) -> Vec<TokenStream> {
+    let mut parts = Vec::new();
+    let mut current = TokenStream::new();
+    for token in input {
+        match &token {
+            TokenTree::Punct(punct) if punct.as_char() == ',' => {
+                parts.push(std::mem::take(&mut current));
             }
-            _ => Err(errors::unsupported_method_call(
-                &method_name,
-                method.method.span(),
-            )),
+            _ => current.extend([token]),
+        }
+    }
+    if !current.is_empty() {
+        parts.push(current);
+    }
+    parts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_to_string(input: &str) -> String {
+        let tokens: TokenStream = input.parse().unwrap();
+        ExprParser::parse(tokens).unwrap().to_string()
+    }
+
+    #[test]
+    fn test_power_binds_tighter_than_negation() {
+        assert_eq!(parse_to_string("-x ^ 2"), parse_to_string("-(x ^ 2)"));
+        assert_eq!(parse_to_string("-x ** 2"), parse_to_string("-(x ** 2)"));
+    }
+
+    #[test]
+    fn test_power_right_associative() {
+        assert_eq!(parse_to_string("2 ^ 3 ^ 4"), parse_to_string("2 ^ (3 ^ 4)"));
+        assert_eq!(
+            parse_to_string("x ^ -y ^ 2"),
+            parse_to_string("x ^ (-(y ^ 2))")
+        );
+    }
+
+    #[test]
+    fn test_double_star_with_space() {
+        assert_eq!(parse_to_string("x * *2"), parse_to_string("x ^ 2"));
+        assert_eq!(
+            parse_to_string("2 * x ** 2"),
+            parse_to_string("2 * (x ^ 2)")
+        );
+    }
+
+    #[test]
+    fn test_relation_chain() {
+        let chain = parse_to_string("0 < x <= 1");
+        assert!(
+            chain.contains("\"and\""),
+            "Expected conjunction in: {}",
+            chain
+        );
+        assert!(chain.contains("LessEqual"), "Expected <= in: {}", chain);
+    }
+
+    #[test]
+    fn test_errors() {
+        for input in [
+            "",
+            "x +",
+            "x = 1",
+            "(x +)",
+            "diff(x)",
+            "diff(x, 2 * y)",
+            "x.foo()",
+        ] {
+            let tokens: TokenStream = input.parse().unwrap();
+            assert!(
+                ExprParser::parse(tokens).is_err(),
+                "Expected error for {}",
+                input
+            );
         }
     }
 }
@@ -19,16 +19,23 @@ use syn::{parse_macro_input, Ident};
 
 mod expr;
 mod function;
+mod math_function;
+mod matrix;
+mod rules;
 mod symbol;
 
 /// Procedural macro for creating mathematical expressions with full syntax support
 ///
+/// The macro parses its input with mathematical precedence itself rather than
+/// relying on Rust's expression grammar, so `^` means power and `-x^2` is
+/// `-(x^2)`.
+///
 /// # Supported Operators
 ///
 /// - **Basic arithmetic**: `+`, `-`, `*`, `/`
 /// - **Unary negation**: `-x`
-/// - **Power operations**: Both `**` and `.pow()` syntax
-/// - **Comparison operators**: `==`, `<`, `>`, `<=`, `>=`
+/// - **Power operations**: `^`, `**` and `.pow()` syntax
+/// - **Comparison operators**: `==`, `!=`, `<`, `>`, `<=`, `>=`
 ///
 /// # Supported Literals
 ///
@@ -42,96 +49,71 @@ mod symbol;
 /// - **Function calls**: `sin(x)`, `log(x, y)`, `f(a, b, c)`
 /// - **Parenthesized expressions**: `(2*x + 3)`, `((x+y))`
 /// - **Method calls**: `x.pow(2)`, `x.abs()`, `x.sqrt()`, `x.simplify()`
+/// - **Matrices**: `[[1, x], [y, x^2]]`, as with [`matrix!`]
+/// - **Lists**: `[x + y == 3, x - y == 1]` gives a `Vec<Expression>`, for
+///   example a system of equations
 ///
-/// # Power Operator Syntax
-///
-/// The macro supports **two syntaxes** for exponentiation:
+/// # Calculus Operators
 ///
-/// ## Method Syntax: `.pow()`
-///
-/// The `.pow()` method works everywhere without restrictions:
+/// These names build unevaluated calculus expressions instead of plain
+/// function calls. The variable must be an identifier.
 ///
 /// ```rust,ignore
-/// expr!(x.pow(2))              // x squared
-/// expr!(x.pow(y))              // x to the y
-/// expr!((x + 1).pow(3))        // (x+1) cubed
-/// expr!(2 * x.pow(3) + 5)      // Complex expression
+/// expr!(diff(sin(x), x))               // d/dx sin(x)
+/// expr!(diff(x^3, x, 2))               // Second derivative
+/// expr!(integrate(x^2, x))             // Indefinite integral
+/// expr!(integrate(x^2, x, 0, 1))       // Definite integral
+/// expr!(sum(k^2, k, 1, n))             // Sum over k from 1 to n
+/// expr!(product(k, k, 1, n))           // Product over k from 1 to n
+/// expr!(limit(sin(x) / x, x, 0))       // Limit as x approaches 0
 /// ```
 ///
-/// **Advantages:**
-/// - Mirrors Rust's standard library API
-/// - Works seamlessly in any context
-/// - Clear, unambiguous precedence
-/// - No parentheses required
-///
-/// ## Infix Syntax: `**`
+/// # Relational Chains
 ///
-/// The `**` operator provides mathematical notation:
+/// A chain of comparisons is the conjunction of its links:
 ///
 /// ```rust,ignore
-/// expr!(x ** 2)                // x squared
-/// expr!(x ** y)                // x to the y
-/// expr!((x + 1) ** 3)          // (x+1) cubed
+/// expr!(0 < x <= 1)                    // and(0 < x, x <= 1)
 /// ```
 ///
-/// **IMPORTANT - When to use parentheses with `**`:**
-///
-/// Due to token-level preprocessing, complex expressions with `**` should use
-/// parentheses for clarity when mixing with other operators:
-///
-/// ```rust,ignore
-/// // ✅ RECOMMENDED (clear precedence):
-/// expr!(2 * (x ** 3) + 5)      // Parentheses make precedence explicit
-/// expr!((x ** 2) + (y ** 2))   // Clear grouping
-///
-/// // ⚠️  WORKS BUT LESS CLEAR:
-/// expr!(x ** 2 + y ** 2)       // Relies on implicit precedence
-/// ```
-///
-/// **Right-associativity:**
+/// # Power Operator Syntax
 ///
-/// Power operations are right-associative (like standard mathematical notation):
+/// `^`, `**` and `.pow()` are interchangeable. Power binds tighter than
+/// negation, multiplication and division, and is right-associative:
 ///
 /// ```rust,ignore
-/// expr!(x ** 2 ** 3)           // Parsed as: x ** (2 ** 3) = x ** 8
-/// expr!(2 ** 3 ** 2)           // Parsed as: 2 ** (3 ** 2) = 2 ** 9 = 512
+/// expr!(2 * x^3 + 5)           // 2*(x^3) + 5
+/// expr!(x ** 2 + y ** 2)       // (x^2) + (y^2)
+/// expr!(-x^2)                  // -(x^2); write (-x)^2 for the square of -x
+/// expr!(x^-1)                  // x^(-1)
+/// expr!(2 ^ 3 ^ 2)             // 2^(3^2) = 2^9
 /// ```
 ///
-/// **Which syntax to use?**
-///
-/// - Use `.pow()` for guaranteed clarity in complex expressions
-/// - Use `**` when writing simple mathematical formulas that match notation
-/// - When in doubt, add parentheses: `expr!(2 * (x ** 3) + 5)`
-///
 /// # Examples
 ///
 /// ```rust,ignore
 /// use mathhook_macros::expr;
 /// use mathhook_core::{Expression, symbol};
 ///
-/// let x = symbol!(x);
-/// let y = symbol!(y);
-///
 /// // Basic operations
 /// let sum = expr!(x + 2);
 /// let product = expr!(2 * x);
 ///
-/// // Power operations (both syntaxes work)
-/// let power1 = expr!(x ** 2);
-/// let power2 = expr!(x.pow(2));
-///
-/// // Complex expressions (use parentheses with ** for clarity)
-/// let quadratic = expr!((x ** 2) + 2*x + 1);
-/// let nested = expr!((x + 1) * (x - 1));
+/// // Power operations
+/// let quadratic = expr!(x^2 + 2*x + 1);
 /// let functions = expr!(sin(x ** 2));
 ///
 /// // Comparison operators
-/// let equation = expr!(x ** 2 == 4);
+/// let equation = expr!(x^2 == 4);
 /// let inequality = expr!(x + 1 > y);
+/// let system = expr!([x + y == 3, x - y == 1]);
+///
+/// // Calculus
+/// let slope = expr!(diff(x^2, x));
+/// let area = expr!(integrate(x^2, x, 0, 1));
 ///
 /// // Method calls
 /// let abs_val = expr!(x.abs());
-/// let sqrt_expr = expr!(x.sqrt());
 /// let simplified = expr!((x + x).simplify());
 /// ```
 ///
@@ -140,19 +122,18 @@ mod symbol;
 /// Operator precedence from highest to lowest:
 ///
 /// 1. **Method calls** (highest): `.pow()`, `.abs()`, `.sqrt()`, `.simplify()`
-/// 2. **Power** (right-associative): `**`
+/// 2. **Power** (right-associative): `^`, `**`
 /// 3. **Unary negation**: `-x`
 /// 4. **Multiplication/division**: `*`, `/`
 /// 5. **Addition/subtraction**: `+`, `-`
-/// 6. **Comparison operators** (lowest): `==`, `<`, `>`, `<=`, `>=`
+/// 6. **Comparison operators** (lowest): `==`, `!=`, `<`, `>`, `<=`, `>=`
 ///
 /// Use parentheses to override precedence:
 ///
 /// ```rust,ignore
 /// expr!(2 * x + 3)             // Parsed as: (2*x) + 3
 /// expr!((2 + 3) * x)           // Parentheses override: 5*x
-/// expr!(2 * (x ** 3))          // Recommended for clarity with **
-/// expr!((x + 1) ** 2)          // Power of sum
+/// expr!((x + 1) ^ 2)           // Power of sum
 /// ```
 #[proc_macro]
 pub fn expr(input: TokenStream) -> TokenStream {
@@ -213,11 +194,21 @@ pub fn symbol(input: TokenStream) -> TokenStream {
 /// symbols![A, B, C => matrix]    // All matrices
 /// symbols![p, x, H => operator]  // All operators
 /// symbols![i, j, k => quaternion] // All quaternions
+/// symbols![x1..x4]               // x1, x2, x3
+/// symbols![x1..=x4]              // x1, x2, x3, x4
+/// symbols![a:e]                  // a, b, c, d
+/// symbols![n, m => integer, positive; x, y => real]  // Assumptions per group
+/// symbols!((x, y, z))            // Tuple instead of Vec
 /// ```
 ///
+/// Ranges exclude their end, as in Rust and SymPy's `symbols('x1:10')`,
+/// unless written with `..=`. Assumptions are `positive`, `nonnegative`,
+/// `nonzero`, `integer` and `real`, and may be mixed with a symbol type.
+///
 /// # Returns
 ///
-/// Returns `Vec<Symbol>` containing all created symbols.
+/// Returns `Vec<Symbol>` containing all created symbols, or a tuple of
+/// symbols when the list is wrapped in parentheses, for destructuring.
 ///
 /// # Examples
 ///
@@ -239,6 +230,12 @@ pub fn symbol(input: TokenStream) -> TokenStream {
 /// // Quaternion symbols (noncommutative)
 /// let quats = symbols![i, j, k => quaternion];
 /// assert_eq!(quats.len(), 3);
+///
+/// // Ranges and assumptions
+/// let coefficients = symbols![a0..=a5];
+/// assert_eq!(coefficients.len(), 6);
+/// let (n, m) = symbols!((n, m => integer, positive));
+/// assert!(n.assumptions().is_integer());
 /// ```
 #[proc_macro]
 pub fn symbols(input: TokenStream) -> TokenStream {
@@ -277,6 +274,145 @@ pub fn function(input: TokenStream) -> TokenStream {
     function::function_impl(input)
 }
 
+/// Procedural macro for creating matrix expressions
+///
+/// Each row is a bracketed list of elements, and each element accepts the
+/// same syntax as [`expr!`]. All rows must have the same length, which is
+/// checked at compile time.
+///
+/// # Syntax
+///
+/// ```rust,ignore
+/// matrix![[1, 2], [3, 4]]             // Numeric 2×2
+/// matrix![[1, x], [y, x^2]]           // Symbolic elements
+/// matrix![[sin(t), cos(t)]]           // Single row
+/// ```
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use mathhook_macros::{expr, matrix};
+/// use mathhook_core::Expression;
+///
+/// let m = matrix![[1, x], [y, x^2]];
+/// assert_eq!(
+///     m,
+///     Expression::matrix(vec![vec![expr!(1), expr!(x)], vec![expr!(y), expr!(x ^ 2)]])
+/// );
+/// ```
+#[proc_macro]
+pub fn matrix(input: TokenStream) -> TokenStream {
+    matrix::matrix_impl(input)
+}
+
+/// Attribute macro for defining a new symbolic function
+///
+/// Place it on the inherent impl of a type that describes the function. The
+/// impl may provide any of these associated functions, each taking one
+/// parameter per argument of the symbolic function:
+///
+/// - `eval(x: f64, ...) -> f64` (or `Option<f64>`) - numeric evaluation
+/// - `derivative(x: &Expression) -> Expression` for one argument, or
+///   `derivative(x: &Expression, y: &Expression, ...) -> [Expression; N]`
+///   returning every partial derivative
+/// - `latex(x: &str, ...) -> String` - LaTeX rendering of formatted arguments
+///
+/// The macro registers a `FunctionDefinition` so that differentiation,
+/// evaluation and LaTeX output pick the function up, exposes it as the
+/// associated constant `DEFINITION`, and generates a free constructor
+/// function whose name is the snake_case type name. The binding generator
+/// picks up that constructor like any other public function.
+///
+/// # Options
+///
+/// - `name = "..."` - function name, instead of the snake_case type name
+/// - `arity = N` - number of arguments, required only without any of the
+///   associated functions above
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use mathhook_core::{expr, math_function, symbol, Derivative, Expression};
+///
+/// pub struct Softplus;
+///
+/// #[math_function]
+/// impl Softplus {
+///     fn eval(x: f64) -> f64 {
+///         x.exp().ln_1p()
+///     }
+///
+///     fn derivative(x: &Expression) -> Expression {
+///         // σ(x) = 1 / (1 + e^(-x))
+///         let decay = Expression::function("exp", vec![Expression::mul(vec![
+///             Expression::integer(-1),
+///             x.clone(),
+///         ])]);
+///         Expression::pow(
+///             Expression::add(vec![Expression::integer(1), decay]),
+///             Expression::integer(-1),
+///         )
+///     }
+///
+///     fn latex(x: &str) -> String {
+///         format!("\\operatorname{{softplus}}({})", x)
+///     }
+/// }
+///
+/// let x = symbol!(x);
+/// let f = softplus(expr!(x));
+/// let slope = f.derivative(x);
+/// ```
+#[proc_macro_attribute]
+pub fn math_function(attr: TokenStream, item: TokenStream) -> TokenStream {
+    math_function::math_function_impl(attr, item)
+}
+
+/// Procedural macro for building a table of rewrite rules
+///
+/// Each rule is `pattern => replacement`, optionally followed by `if` and
+/// conditions joined with `&&`. Rules are separated by `;`. Identifiers
+/// ending in `_` are wildcards, bound by the pattern and substituted into
+/// the replacement; other identifiers are symbols. Rules are parsed at
+/// compile time, and a wildcard used in the replacement or a condition but
+/// not in the pattern is a compile error.
+///
+/// # Syntax
+///
+/// ```rust,ignore
+/// rules! {
+///     sin(x_)^2 + cos(x_)^2 => 1;                          // Plain rule
+///     log(x_*y_) => log(x_) + log(y_) if positive(x_, y_); // Conditional
+///     sqrt(x_^2) => x_ if nonnegative(x_) && real(x_);     // Several conditions
+/// }
+/// ```
+///
+/// Conditions are `positive`, `nonnegative`, `nonzero`, `integer`, `real`
+/// and `numeric`, each taking one or more wildcards. Subtraction and
+/// division match their canonical forms, so `x_ - y_` is `x_ + (-1)*y_`.
+///
+/// # Returns
+///
+/// Returns a `RuleSet`, whose `apply` rewrites an expression bottom-up until
+/// no rule changes it. A sum pattern also matches a subset of the terms of
+/// a larger sum.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use mathhook_core::{expr, rules};
+///
+/// let trig = rules! {
+///     sin(x_)^2 + cos(x_)^2 => 1;
+/// };
+/// assert_eq!(trig.apply(&expr!((sin(t) ^ 2) + (cos(t) ^ 2) + 3)), expr!(4));
+/// println!("{}", trig); // sin(x_)^2 + cos(x_)^2 => 1;
+/// ```
+#[proc_macro]
+pub fn rules(input: TokenStream) -> TokenStream {
+    rules::rules_impl(input)
+}
+
 /// Generate Python (PyO3) binding for a unary mathematical function
 ///
 /// # Arguments
@@ -585,7 +721,7 @@ pub fn generate_nodejs_variadic_binding(input: TokenStream) -> TokenStream {
                 .map(|x| match x {
                     napi::bindgen_prelude::Either::A(e) => e.inner.clone(),
                     napi::bindgen_prelude::Either::B(num) => {
-                        if num.fract() == 0.0 && num.is_finite() {
+                        if num.fract() == 0.0 && num.is_finite() && num.abs() < i64::MAX as f64 {
                             Expression::integer(num as i64)
                         } else {
                             Expression::float(num)
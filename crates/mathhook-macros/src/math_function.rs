@@ -0,0 +1,237 @@
+//! Procedural #[math_function] attribute implementation
+//!
+//! Reads the `eval`, `derivative` and `latex` associated functions of the
+//! annotated impl block, wraps them in the slice-based signatures expected by
+//! `FunctionDefinition`, and submits the definition to the core registry.
+use convert_case::{Case, Casing};
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{
+    Expr, ExprLit, FnArg, Ident, ImplItem, ImplItemFn, ItemImpl, Lit, Meta, Pat, ReturnType, Token,
+    Type,
+};
+
+const METHODS: [&str; 3] = ["eval", "derivative", "latex"];
+
+struct Config {
+    name: Option<String>,
+    arity: Option<usize>,
+}
+
+impl Config {
+    fn parse(attr: TokenStream2) -> syn::Result<Self> {
+        let mut config = Config {
+            name: None,
+            arity: None,
+        };
+        if attr.is_empty() {
+            return Ok(config);
+        }
+
+        let metas =
+            syn::parse::Parser::parse2(Punctuated::<Meta, Token![,]>::parse_terminated, attr)?;
+        for meta in metas {
+            let Meta::NameValue(pair) = &meta else {
+                return Err(syn::Error::new(
+                    meta.span(),
+                    "Expected `name = \"...\"` or `arity = N`",
+                ));
+            };
+            let Expr::Lit(ExprLit { lit, .. }) = &pair.value else {
+                return Err(syn::Error::new(pair.value.span(), "Expected a literal"));
+            };
+            match (pair.path.get_ident().map(Ident::to_string).as_deref(), lit) {
+                (Some("name"), Lit::Str(name)) => config.name = Some(name.value()),
+                (Some("arity"), Lit::Int(arity)) => config.arity = Some(arity.base10_parse()?),
+                _ => {
+                    return Err(syn::Error::new(
+                        meta.span(),
+                        "Expected `name = \"...\"` or `arity = N`",
+                    ))
+                }
+            }
+        }
+        Ok(config)
+    }
+}
+
+/// Argument names of an associated function, rejecting methods with a receiver
+fn argument_names(method: &ImplItemFn) -> syn::Result<Vec<Ident>> {
+    method
+        .sig
+        .inputs
+        .iter()
+        .enumerate()
+        .map(|(index, input)| match input {
+            FnArg::Receiver(receiver) => Err(syn::Error::new(
+                receiver.span(),
+                format!(
+                    "`{}` must be an associated function without `self`",
+                    method.sig.ident
+                ),
+            )),
+            FnArg::Typed(typed) => Ok(match &*typed.pat {
+                Pat::Ident(pat) => pat.ident.clone(),
+                _ => format_ident!("arg{}", index),
+            }),
+        })
+        .collect()
+}
+
+/// Whether `eval` returns `Option<f64>` rather than `f64`
+fn returns_option(method: &ImplItemFn) -> bool {
+    match &method.sig.output {
+        ReturnType::Type(_, ty) => matches!(
+            &**ty,
+            Type::Path(path) if path.path.segments.last().is_some_and(|s| s.ident == "Option")
+        ),
+        ReturnType::Default => false,
+    }
+}
+
+fn expand(attr: TokenStream2, item: ItemImpl) -> syn::Result<TokenStream2> {
+    let config = Config::parse(attr)?;
+
+    if item.trait_.is_some() || !item.generics.params.is_empty() {
+        return Err(syn::Error::new(
+            item.impl_token.span,
+            "#[math_function] must be placed on an inherent impl of a non-generic type",
+        ));
+    }
+    let self_ty = &item.self_ty;
+    let type_name = match &**self_ty {
+        Type::Path(path) => path.path.segments.last().map(|s| s.ident.to_string()),
+        _ => None,
+    }
+    .ok_or_else(|| syn::Error::new(self_ty.span(), "Expected a named type"))?;
+
+    let methods: Vec<&ImplItemFn> = item
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            ImplItem::Fn(method) if METHODS.contains(&method.sig.ident.to_string().as_str()) => {
+                Some(method)
+            }
+            _ => None,
+        })
+        .collect();
+    let find = |name: &str| methods.iter().copied().find(|m| m.sig.ident == name);
+
+    let mut params: Option<Vec<Ident>> = None;
+    for method in &methods {
+        let names = argument_names(method)?;
+        if let Some(expected) = params.as_ref().map(Vec::len).or(config.arity) {
+            if names.len() != expected {
+                return Err(syn::Error::new(
+                    method.sig.inputs.span(),
+                    format!(
+                        "`{}` takes {} arguments but the function has arity {}",
+                        method.sig.ident,
+                        names.len(),
+                        expected
+                    ),
+                ));
+            }
+        }
+        params.get_or_insert(names);
+    }
+    let params =
+        match (params, config.arity) {
+            (Some(params), _) => params,
+            (None, Some(arity)) => (0..arity).map(|i| format_ident!("arg{}", i)).collect(),
+            (None, None) => return Err(syn::Error::new(
+                Span::call_site(),
+                "#[math_function] needs `eval`, `derivative` or `latex`, or an explicit `arity`",
+            )),
+        };
+    let arity = params.len();
+    let indices: Vec<usize> = (0..arity).collect();
+
+    let name = config
+        .name
+        .unwrap_or_else(|| type_name.to_case(Case::Snake));
+    let function_ident = Ident::new(&name, Span::call_site());
+
+    let mut definition = quote! {
+        mathhook_core::functions::custom::FunctionDefinition::new(#name, #arity)
+    };
+    if let Some(eval) = find("eval") {
+        let call = quote! { <#self_ty>::eval(#(args[#indices]),*) };
+        let result = if returns_option(eval) {
+            call
+        } else {
+            quote! { Some(#call) }
+        };
+        definition = quote! {
+            #definition.with_eval({
+                fn eval(args: &[f64]) -> Option<f64> {
+                    #result
+                }
+                eval
+            })
+        };
+    }
+    if find("derivative").is_some() {
+        let partial = if arity == 1 {
+            quote! { Some(<#self_ty>::derivative(&args[0])).filter(|_| index == 0) }
+        } else {
+            quote! { <#self_ty>::derivative(#(&args[#indices]),*).into_iter().nth(index) }
+        };
+        definition = quote! {
+            #definition.with_derivative({
+                fn derivative(
+                    args: &[mathhook_core::Expression],
+                    index: usize,
+                ) -> Option<mathhook_core::Expression> {
+                    #partial
+                }
+                derivative
+            })
+        };
+    }
+    if find("latex").is_some() {
+        definition = quote! {
+            #definition.with_latex({
+                fn latex(args: &[String]) -> String {
+                    <#self_ty>::latex(#(&args[#indices]),*)
+                }
+                latex
+            })
+        };
+    }
+
+    let doc = format!("Symbolic `{}` function, defined by [`{}`]", name, type_name);
+
+    Ok(quote! {
+        #item
+
+        impl #self_ty {
+            /// Definition registered for this function
+            pub const DEFINITION: mathhook_core::functions::custom::FunctionDefinition =
+                #definition;
+        }
+
+        mathhook_core::inventory::submit! { <#self_ty>::DEFINITION }
+
+        #[doc = #doc]
+        pub fn #function_ident(
+            #(#params: mathhook_core::Expression),*
+        ) -> mathhook_core::Expression {
+            mathhook_core::Expression::function(#name, vec![#(#params),*])
+        }
+    })
+}
+
+pub(crate) fn math_function_impl(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let item = match syn::parse::<ItemImpl>(item) {
+        Ok(item) => item,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    match expand(attr.into(), item) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
@@ -0,0 +1,12 @@
+//! Procedural matrix!() macro implementation
+//!
+//! Rows are parsed by the expr!() parser, so elements accept the same syntax.
+use crate::expr::matrix_tokens;
+use proc_macro::TokenStream;
+
+pub(crate) fn matrix_impl(input: TokenStream) -> TokenStream {
+    match matrix_tokens(input.into()) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
@@ -0,0 +1,16 @@
+//! Procedural rules!{} macro implementation
+//!
+//! Each rule is parsed into a pattern tree at compile time and emitted as
+//! `Pattern` constructors, so building a rule table never parses text.
+use proc_macro::TokenStream;
+mod codegen;
+mod parser;
+use parser::RulesParser;
+
+/// Implementation function for rules!{} procedural macro
+pub(crate) fn rules_impl(input: TokenStream) -> TokenStream {
+    match RulesParser::parse(input.into()) {
+        Ok(rules) => codegen::generate_rule_set(&rules).into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
@@ -0,0 +1,71 @@
+//! Code generation for rules!{} rule tables
+use super::parser::{Node, Rule};
+use crate::expr::CodeGenerator;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+
+/// Generate a `RuleSet` holding every rule in order
+pub fn generate_rule_set(rules: &[Rule]) -> TokenStream {
+    let rules = rules.iter().map(generate_rule);
+    quote! {
+        mathhook_core::pattern::RuleSet::new(vec![#(#rules),*])
+    }
+}
+
+fn generate_rule(rule: &Rule) -> TokenStream {
+    let pattern = generate_pattern(&rule.pattern);
+    let replacement = generate_pattern(&rule.replacement);
+    let conditions = rule.conditions.iter().map(|condition| {
+        let property = Ident::new(condition.property, Span::call_site());
+        let wildcard = &condition.wildcard;
+        quote! {
+            .when(mathhook_core::pattern::rules::Condition::new(
+                mathhook_core::pattern::rules::Property::#property,
+                #wildcard,
+            ))
+        }
+    });
+    quote! {
+        mathhook_core::pattern::RewriteRule::new(#pattern, #replacement)#(#conditions)*
+    }
+}
+
+fn generate_pattern(node: &Node) -> TokenStream {
+    match node {
+        Node::Wildcard(name) => quote! { mathhook_core::pattern::Pattern::wildcard(#name) },
+        Node::Integer(value) => exact(CodeGenerator::generate_integer(*value)),
+        Node::Float(value) => exact(CodeGenerator::generate_float(*value)),
+        Node::Symbol(name) => exact(CodeGenerator::generate_symbol(name)),
+        Node::Add(terms) => {
+            let terms = terms.iter().map(generate_pattern);
+            quote! { mathhook_core::pattern::Pattern::Add(vec![#(#terms),*]) }
+        }
+        Node::Mul(factors) => {
+            let factors = factors.iter().map(generate_pattern);
+            quote! { mathhook_core::pattern::Pattern::Mul(vec![#(#factors),*]) }
+        }
+        Node::Pow(base, exp) => {
+            let base = generate_pattern(base);
+            let exp = generate_pattern(exp);
+            quote! {
+                mathhook_core::pattern::Pattern::Pow(
+                    std::boxed::Box::new(#base),
+                    std::boxed::Box::new(#exp),
+                )
+            }
+        }
+        Node::Function(name, args) => {
+            let args = args.iter().map(generate_pattern);
+            quote! {
+                mathhook_core::pattern::Pattern::Function {
+                    name: #name.to_owned(),
+                    args: vec![#(#args),*],
+                }
+            }
+        }
+    }
+}
+
+fn exact(expr: TokenStream) -> TokenStream {
+    quote! { mathhook_core::pattern::Pattern::Exact(#expr) }
+}
@@ -0,0 +1,483 @@
+//! Parser for rules!{} rule tables
+//!
+//! A table is a `;`-separated list of `pattern => replacement` rules, each
+//! optionally followed by `if` and `&&`-joined conditions such as
+//! `positive(x_, y_)`. Identifiers ending in `_` are wildcards; other
+//! identifiers are symbols. Operators follow expr!() precedence, and
+//! subtraction and division are written in the canonical form they match:
+//! `a - b` is `a + (-1)*b` and `a / b` is `a * b^(-1)`.
+use proc_macro2::{Delimiter, Group, Ident, Punct, Spacing, Span, TokenStream, TokenTree};
+use syn::spanned::Spanned;
+use syn::Lit;
+
+/// Properties accepted in conditions, by source name and `Property` variant
+pub const PROPERTIES: [(&str, &str); 6] = [
+    ("positive", "Positive"),
+    ("nonnegative", "Nonnegative"),
+    ("nonzero", "Nonzero"),
+    ("integer", "Integer"),
+    ("real", "Real"),
+    ("numeric", "Numeric"),
+];
+
+/// Pattern tree, with sums and products flattened
+#[derive(Debug, Clone, PartialEq)]
+pub enum Node {
+    /// Wildcard name without the trailing underscore
+    Wildcard(String),
+    Integer(i64),
+    Float(f64),
+    Symbol(String),
+    Add(Vec<Node>),
+    Mul(Vec<Node>),
+    Pow(Box<Node>, Box<Node>),
+    Function(String, Vec<Node>),
+}
+
+impl Node {
+    fn add(left: Node, right: Node) -> Node {
+        let mut terms = match left {
+            Node::Add(terms) => terms,
+            other => vec![other],
+        };
+        match right {
+            Node::Add(more) => terms.extend(more),
+            other => terms.push(other),
+        }
+        Node::Add(terms)
+    }
+
+    fn mul(left: Node, right: Node) -> Node {
+        let mut factors = match left {
+            Node::Mul(factors) => factors,
+            other => vec![other],
+        };
+        match right {
+            Node::Mul(more) => factors.extend(more),
+            other => factors.push(other),
+        }
+        Node::Mul(factors)
+    }
+
+    fn neg(operand: Node) -> Node {
+        match operand {
+            Node::Integer(value) => Node::Integer(-value),
+            Node::Float(value) => Node::Float(-value),
+            other => Node::mul(Node::Integer(-1), other),
+        }
+    }
+
+    fn div(left: Node, right: Node) -> Node {
+        Node::mul(
+            left,
+            Node::Pow(Box::new(right), Box::new(Node::Integer(-1))),
+        )
+    }
+
+    /// Collect wildcard names in order of first appearance
+    fn wildcards(&self, names: &mut Vec<String>) {
+        match self {
+            Node::Wildcard(name) => {
+                if !names.contains(name) {
+                    names.push(name.clone());
+                }
+            }
+            Node::Integer(_) | Node::Float(_) | Node::Symbol(_) => {}
+            Node::Add(items) | Node::Mul(items) | Node::Function(_, items) => {
+                items.iter().for_each(|item| item.wildcards(names))
+            }
+            Node::Pow(base, exp) => {
+                base.wildcards(names);
+                exp.wildcards(names);
+            }
+        }
+    }
+}
+
+/// Condition `property(wildcard)`, with the `Property` variant name
+#[derive(Debug, Clone, PartialEq)]
+pub struct Condition {
+    pub property: &'static str,
+    pub wildcard: String,
+}
+
+/// One parsed rule
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rule {
+    pub pattern: Node,
+    pub replacement: Node,
+    pub conditions: Vec<Condition>,
+}
+
+/// Parser for a table of rewrite rules
+pub struct RulesParser {
+    tokens: Vec<TokenTree>,
+    pos: usize,
+    /// Span reported when the input ends early
+    end: Span,
+}
+
+impl RulesParser {
+    /// Parse every rule of the table
+    pub fn parse(input: TokenStream) -> syn::Result<Vec<Rule>> {
+        split_on_punct(input, ';')
+            .into_iter()
+            .filter(|rule| !rule.is_empty())
+            .map(Self::parse_rule)
+            .collect()
+    }
+
+    /// Parse `pattern => replacement [if conditions]`
+    fn parse_rule(tokens: TokenStream) -> syn::Result<Rule> {
+        let span = tokens.span();
+        let tokens: Vec<TokenTree> = tokens.into_iter().collect();
+        let arrow = (0..tokens.len().saturating_sub(1))
+            .find(|&i| is_punct(&tokens[i], '=', Spacing::Joint) && is_char(&tokens[i + 1], '>'))
+            .ok_or_else(|| {
+                syn::Error::new(
+                    span,
+                    "Expected `=>` between the pattern and the replacement",
+                )
+            })?;
+        let condition_start = tokens[arrow + 2..]
+            .iter()
+            .position(|token| matches!(token, TokenTree::Ident(ident) if ident == "if"))
+            .map(|offset| arrow + 2 + offset);
+
+        let replacement_end = condition_start.unwrap_or(tokens.len());
+        let arrow_span = tokens[arrow + 1].span();
+        let pattern = Self::parse_expression(&tokens[..arrow], tokens[arrow].span())?;
+        let replacement = Self::parse_expression(&tokens[arrow + 2..replacement_end], arrow_span)?;
+        let conditions = match condition_start {
+            Some(start) => Self::parse_conditions(&tokens[start + 1..], tokens[start].span())?,
+            None => Vec::new(),
+        };
+
+        let mut bound = Vec::new();
+        pattern.wildcards(&mut bound);
+        let mut used = Vec::new();
+        replacement.wildcards(&mut used);
+        used.extend(conditions.iter().map(|c| c.wildcard.clone()));
+        if let Some(unbound) = used.iter().find(|name| !bound.contains(name)) {
+            return Err(syn::Error::new(
+                span,
+                format!(
+                    "Wildcard `{}_` does not appear in the pattern, so it is never bound",
+                    unbound
+                ),
+            ));
+        }
+
+        Ok(Rule {
+            pattern,
+            replacement,
+            conditions,
+        })
+    }
+
+    /// Parse a complete pattern or replacement, rejecting leftover tokens
+    fn parse_expression(tokens: &[TokenTree], end: Span) -> syn::Result<Node> {
+        let mut parser = RulesParser {
+            tokens: tokens.to_vec(),
+            pos: 0,
+            end,
+        };
+        let node = parser.parse_sum()?;
+        match parser.tokens.get(parser.pos) {
+            Some(token) => Err(syn::Error::new(
+                token.span(),
+                format!("Unexpected `{}` in rule", token),
+            )),
+            None => Ok(node),
+        }
+    }
+
+    /// Parse `property(x_, ...) && ...` into one condition per wildcard
+    fn parse_conditions(tokens: &[TokenTree], end: Span) -> syn::Result<Vec<Condition>> {
+        let mut conditions = Vec::new();
+        let mut rest = tokens;
+        loop {
+            let (name, group) = match rest {
+                [TokenTree::Ident(name), TokenTree::Group(group), ..]
+                    if group.delimiter() == Delimiter::Parenthesis =>
+                {
+                    (name, group)
+                }
+                [token, ..] => {
+                    return Err(syn::Error::new(
+                        token.span(),
+                        "Expected a condition such as `positive(x_)`",
+                    ))
+                }
+                [] => return Err(syn::Error::new(end, "Expected a condition after `if`")),
+            };
+            let property = PROPERTIES
+                .iter()
+                .find(|(source, _)| name == source)
+                .map(|(_, variant)| *variant)
+                .ok_or_else(|| {
+                    syn::Error::new(
+                        name.span(),
+                        format!(
+                            "Unknown condition `{}`. Valid conditions: {}",
+                            name,
+                            PROPERTIES.map(|(source, _)| source).join(", ")
+                        ),
+                    )
+                })?;
+            for arg in split_on_punct(group.stream(), ',') {
+                let wildcard = match arg.clone().into_iter().collect::<Vec<_>>().as_slice() {
+                    [TokenTree::Ident(ident)] => wildcard_name(ident),
+                    _ => None,
+                }
+                .ok_or_else(|| {
+                    syn::Error::new(
+                        arg.span(),
+                        "Condition arguments must be wildcards like `x_`",
+                    )
+                })?;
+                conditions.push(Condition { property, wildcard });
+            }
+
+            rest = &rest[2..];
+            match rest {
+                [] => return Ok(conditions),
+                [first, second, tail @ ..]
+                    if is_punct(first, '&', Spacing::Joint) && is_char(second, '&') =>
+                {
+                    rest = tail
+                }
+                [token, ..] => {
+                    return Err(syn::Error::new(token.span(), "Join conditions with `&&`"))
+                }
+            }
+        }
+    }
+
+    /// Parse addition and subtraction, left-associative
+    fn parse_sum(&mut self) -> syn::Result<Node> {
+        let mut left = self.parse_product()?;
+        loop {
+            if self.eat_punct('+') {
+                left = Node::add(left, self.parse_product()?);
+            } else if self.eat_punct('-') {
+                left = Node::add(left, Node::neg(self.parse_product()?));
+            } else {
+                return Ok(left);
+            }
+        }
+    }
+
+    /// Parse multiplication and division, left-associative
+    fn parse_product(&mut self) -> syn::Result<Node> {
+        let mut left = self.parse_unary()?;
+        loop {
+            if self.eat_punct('*') {
+                left = Node::mul(left, self.parse_unary()?);
+            } else if self.eat_punct('/') {
+                left = Node::div(left, self.parse_unary()?);
+            } else {
+                return Ok(left);
+            }
+        }
+    }
+
+    /// Parse unary negation, which binds looser than power: `-x_^2` is `-(x_^2)`
+    fn parse_unary(&mut self) -> syn::Result<Node> {
+        if self.eat_punct('-') {
+            return Ok(Node::neg(self.parse_unary()?));
+        }
+        self.parse_power()
+    }
+
+    /// Parse power, right-associative; the exponent may be negated
+    fn parse_power(&mut self) -> syn::Result<Node> {
+        let base = self.parse_atom()?;
+        if self.eat_power() {
+            let exponent = self.parse_unary()?;
+            return Ok(Node::Pow(Box::new(base), Box::new(exponent)));
+        }
+        Ok(base)
+    }
+
+    /// Parse a literal, wildcard, symbol, function call or parenthesized group
+    fn parse_atom(&mut self) -> syn::Result<Node> {
+        let Some(token) = self.tokens.get(self.pos).cloned() else {
+            return Err(syn::Error::new(self.end, "Rule ends unexpectedly"));
+        };
+        self.pos += 1;
+        match token {
+            TokenTree::Literal(literal) => match Lit::new(literal) {
+                Lit::Int(int) => Ok(Node::Integer(int.base10_parse()?)),
+                Lit::Float(float) => Ok(Node::Float(float.base10_parse()?)),
+                lit => Err(syn::Error::new(
+                    lit.span(),
+                    "Only integer and float literals are allowed in rules",
+                )),
+            },
+            TokenTree::Ident(ident) => match self.tokens.get(self.pos) {
+                Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis => {
+                    let group = group.clone();
+                    self.pos += 1;
+                    Self::parse_call(&ident, &group)
+                }
+                _ => Ok(wildcard_name(&ident)
+                    .map(Node::Wildcard)
+                    .unwrap_or_else(|| Node::Symbol(ident.to_string()))),
+            },
+            TokenTree::Group(group)
+                if matches!(group.delimiter(), Delimiter::Parenthesis | Delimiter::None) =>
+            {
+                let tokens: Vec<TokenTree> = group.stream().into_iter().collect();
+                Self::parse_expression(&tokens, group.span_close())
+            }
+            token => Err(syn::Error::new(
+                token.span(),
+                format!("Unexpected `{}` in rule", token),
+            )),
+        }
+    }
+
+    /// Parse the comma-separated arguments of a function call
+    fn parse_call(name: &Ident, group: &Group) -> syn::Result<Node> {
+        let args = split_on_punct(group.stream(), ',')
+            .into_iter()
+            .map(|arg| {
+                let tokens: Vec<TokenTree> = arg.into_iter().collect();
+                Self::parse_expression(&tokens, group.span_close())
+            })
+            .collect::<syn::Result<Vec<_>>>()?;
+        Ok(Node::Function(name.to_string(), args))
+    }
+
+    fn peek_punct(&self, offset: usize) -> Option<&Punct> {
+        match self.tokens.get(self.pos + offset) {
+            Some(TokenTree::Punct(punct)) => Some(punct),
+            _ => None,
+        }
+    }
+
+    /// Consume a single-character operator, leaving `**` for the power level
+    fn eat_punct(&mut self, op: char) -> bool {
+        if self.peek_punct(0).is_none_or(|p| p.as_char() != op) {
+            return false;
+        }
+        if op == '*' && self.at_double_star() {
+            return false;
+        }
+        self.pos += 1;
+        true
+    }
+
+    /// Consume `^` or `**`
+    fn eat_power(&mut self) -> bool {
+        if self.at_double_star() {
+            self.pos += 2;
+            return true;
+        }
+        self.eat_punct('^')
+    }
+
+    fn at_double_star(&self) -> bool {
+        self.peek_punct(0).is_some_and(|p| p.as_char() == '*')
+            && self.peek_punct(1).is_some_and(|p| p.as_char() == '*')
+    }
+}
+
+/// Wildcard name of an identifier written with a trailing underscore
+fn wildcard_name(ident: &Ident) -> Option<String> {
+    let name = ident.to_string();
+    let stripped = name.strip_suffix('_')?;
+    (!stripped.is_empty()).then(|| stripped.to_owned())
+}
+
+fn is_char(token: &TokenTree, ch: char) -> bool {
+    matches!(token, TokenTree::Punct(punct) if punct.as_char() == ch)
+}
+
+fn is_punct(token: &TokenTree, ch: char, spacing: Spacing) -> bool {
+    matches!(token, TokenTree::Punct(punct) if punct.as_char() == ch && punct.spacing() == spacing)
+}
+
+/// Split tokens at top-level occurrences of `separator`, dropping a trailing one
+fn split_on_punct(input: TokenStream, separator: char) -> Vec<TokenStream> {
+    let mut parts = Vec::new();
+    let mut current = TokenStream::new();
+    for token in input {
+        if is_char(&token, separator) {
+            parts.push(std::mem::take(&mut current));
+        } else {
+            current.extend([token]);
+        }
+    }
+    if !current.is_empty() {
+        parts.push(current);
+    }
+    parts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(input: &str) -> syn::Result<Vec<Rule>> {
+        RulesParser::parse(input.parse().unwrap())
+    }
+
+    #[test]
+    fn test_wildcards_and_canonical_operators() {
+        let rules = parse("x_ - y_ / 2 => z; -x_^2 => 0").unwrap();
+        assert_eq!(
+            rules[0].pattern,
+            Node::Add(vec![
+                Node::Wildcard("x".to_owned()),
+                Node::Mul(vec![
+                    Node::Integer(-1),
+                    Node::Wildcard("y".to_owned()),
+                    Node::Pow(Box::new(Node::Integer(2)), Box::new(Node::Integer(-1))),
+                ]),
+            ])
+        );
+        assert_eq!(rules[0].replacement, Node::Symbol("z".to_owned()));
+        assert_eq!(
+            rules[1].pattern,
+            Node::Mul(vec![
+                Node::Integer(-1),
+                Node::Pow(
+                    Box::new(Node::Wildcard("x".to_owned())),
+                    Box::new(Node::Integer(2))
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_conditions() {
+        let rules =
+            parse("log(x_*y_) => log(x_) + log(y_) if positive(x_, y_) && real(x_);").unwrap();
+        let conditions: Vec<_> = rules[0]
+            .conditions
+            .iter()
+            .map(|c| (c.property, c.wildcard.as_str()))
+            .collect();
+        assert_eq!(
+            conditions,
+            vec![("Positive", "x"), ("Positive", "y"), ("Real", "x")]
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        for input in [
+            "x_ + 1",
+            "x_ => y_",
+            "f(x_) => x_ if large(x_)",
+            "f(x_) => x_ if positive(x)",
+            "f(x_) => x_ if positive(x_) positive(x_)",
+            "f(x_) => ",
+            "f(x_) => x_ +",
+        ] {
+            assert!(parse(input).is_err(), "Expected error for {}", input);
+        }
+    }
+}
@@ -12,7 +12,7 @@ pub enum SymbolType {
 pub struct CodeGenerator;
 
 impl CodeGenerator {
-    pub fn generate_single(name: &str, symbol_type: SymbolType) -> TokenStream {
+    pub fn generate_single(name: &str, symbol_type: &SymbolType) -> TokenStream {
         match symbol_type {
             SymbolType::Scalar => quote! {
                 mathhook_core::Symbol::scalar(#name)
@@ -29,24 +29,36 @@ impl CodeGenerator {
         }
     }
 
-    pub fn generate_multiple(names: &[String], symbol_type: SymbolType) -> TokenStream {
-        let symbols: Vec<TokenStream> = names
+    /// Symbol constructor followed by `with_assumptions` when any are given
+    pub fn generate_with_assumptions(
+        name: &str,
+        symbol_type: &SymbolType,
+        assumptions: &[String],
+    ) -> TokenStream {
+        let symbol = Self::generate_single(name, symbol_type);
+        if assumptions.is_empty() {
+            return symbol;
+        }
+        let flags = assumptions
             .iter()
-            .map(|name| {
-                let name_str = name.as_str();
-                match symbol_type {
-                    SymbolType::Scalar => quote! { mathhook_core::Symbol::scalar(#name_str) },
-                    SymbolType::Matrix => quote! { mathhook_core::Symbol::matrix(#name_str) },
-                    SymbolType::Operator => quote! { mathhook_core::Symbol::operator(#name_str) },
-                    SymbolType::Quaternion => {
-                        quote! { mathhook_core::Symbol::quaternion(#name_str) }
-                    }
-                }
-            })
-            .collect();
-
+            .map(|flag| syn::Ident::new(flag, proc_macro2::Span::call_site()));
         quote! {
-            vec![#(#symbols),*]
+            #symbol.with_assumptions(
+                mathhook_core::core::symbol::SymbolAssumptions::new()#(.#flags())*
+            )
+        }
+    }
+
+    /// `Vec<Symbol>`, or a tuple when `tuple` is set
+    pub fn generate_multiple(symbols: &[TokenStream], tuple: bool) -> TokenStream {
+        if tuple {
+            quote! {
+                (#(#symbols,)*)
+            }
+        } else {
+            quote! {
+                vec![#(#symbols),*]
+            }
         }
     }
 }
@@ -18,3 +18,18 @@ pub fn unsupported_type(ty: &str, span: Span) -> syn::Error {
 pub fn empty_symbols_list(span: Span) -> syn::Error {
     syn::Error::new(span, "symbols!() requires at least one symbol name")
 }
+
+pub fn unsupported_flag(flag: &str, span: Span) -> syn::Error {
+    syn::Error::new(
+        span,
+        format!(
+            "Unsupported symbol type or assumption: {}. Valid types: scalar, matrix, operator, \
+             quaternion. Valid assumptions: positive, nonnegative, nonzero, integer, real",
+            flag
+        ),
+    )
+}
+
+pub fn invalid_range(msg: &str, span: Span) -> syn::Error {
+    syn::Error::new(span, format!("Invalid symbol range: {}", msg))
+}
@@ -51,32 +51,178 @@ impl Parse for SingleSymbol {
     }
 }
 
-struct MultipleSymbols {
+/// Names sharing one `=> type, assumptions` clause
+struct SymbolGroup {
     names: Vec<String>,
     symbol_type: SymbolType,
+    assumptions: Vec<String>,
+}
+
+struct MultipleSymbols {
+    groups: Vec<SymbolGroup>,
+    tuple: bool,
 }
 
 impl Parse for MultipleSymbols {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        let idents = Punctuated::<Ident, Token![,]>::parse_separated_nonempty(input)?;
+        if input.peek(syn::token::Paren) {
+            let content;
+            syn::parenthesized!(content in input);
+            let groups = parse_groups(&content)?;
+            return Ok(MultipleSymbols {
+                groups,
+                tuple: true,
+            });
+        }
+        let groups = parse_groups(input)?;
+        Ok(MultipleSymbols {
+            groups,
+            tuple: false,
+        })
+    }
+}
 
-        if idents.is_empty() {
-            return Err(errors::empty_symbols_list(input.span()));
+/// Parse groups separated by `;`
+fn parse_groups(input: ParseStream) -> syn::Result<Vec<SymbolGroup>> {
+    if input.is_empty() {
+        return Err(errors::empty_symbols_list(input.span()));
+    }
+    let mut groups = Vec::new();
+    loop {
+        groups.push(parse_group(input)?);
+        if input.is_empty() {
+            return Ok(groups);
+        }
+        input.parse::<Token![;]>()?;
+        if input.is_empty() {
+            return Ok(groups);
         }
+    }
+}
 
-        let names: Vec<String> = idents.iter().map(|i| i.to_string()).collect();
+/// Parse `names [=> flags]`, where each name may be a range
+fn parse_group(input: ParseStream) -> syn::Result<SymbolGroup> {
+    let mut names = Vec::new();
+    loop {
+        names.extend(parse_names(input)?);
+        if !input.peek(Token![,]) {
+            break;
+        }
+        input.parse::<Token![,]>()?;
+        if input.is_empty() || input.peek(Token![=>]) || input.peek(Token![;]) {
+            break;
+        }
+    }
 
-        let symbol_type = if input.peek(Token![=>]) {
-            input.parse::<Token![=>]>()?;
-            parse_symbol_type(input)?
-        } else {
-            SymbolType::Scalar
-        };
+    let mut symbol_type = SymbolType::Scalar;
+    let mut assumptions = Vec::new();
+    if input.peek(Token![=>]) {
+        input.parse::<Token![=>]>()?;
+        let flags = Punctuated::<Ident, Token![,]>::parse_separated_nonempty(input)?;
+        for flag in flags {
+            match flag.to_string().as_str() {
+                "scalar" => symbol_type = SymbolType::Scalar,
+                "matrix" => symbol_type = SymbolType::Matrix,
+                "operator" => symbol_type = SymbolType::Operator,
+                "quaternion" => symbol_type = SymbolType::Quaternion,
+                name if ASSUMPTIONS.contains(&name) => assumptions.push(name.to_owned()),
+                other => return Err(errors::unsupported_flag(other, flag.span())),
+            }
+        }
+    }
 
-        Ok(MultipleSymbols { names, symbol_type })
+    Ok(SymbolGroup {
+        names,
+        symbol_type,
+        assumptions,
+    })
+}
+
+/// Assumption flags accepted after `=>`
+const ASSUMPTIONS: [&str; 5] = ["positive", "nonnegative", "nonzero", "integer", "real"];
+
+/// Parse a name, or a range `x1..x10`, `x1..=x10` or `a:e`
+fn parse_names(input: ParseStream) -> syn::Result<Vec<String>> {
+    let start: Ident = input.parse()?;
+    let inclusive = if input.peek(Token![..=]) {
+        input.parse::<Token![..=]>()?;
+        true
+    } else if input.peek(Token![..]) {
+        input.parse::<Token![..]>()?;
+        false
+    } else if input.peek(Token![:]) && !input.peek(Token![::]) {
+        input.parse::<Token![:]>()?;
+        false
+    } else {
+        return Ok(vec![start.to_string()]);
+    };
+    let end: Ident = input.parse()?;
+    expand_range(&start, &end, inclusive)
+}
+
+/// Names from `start` up to `end`, excluding `end` unless `inclusive`
+///
+/// Both ends are single letters (`a:e` is a, b, c, d), or share a prefix
+/// followed by a number (`x1..x4` is x1, x2, x3).
+fn expand_range(start: &Ident, end: &Ident, inclusive: bool) -> syn::Result<Vec<String>> {
+    let (first, last) = (start.to_string(), end.to_string());
+    let span = start
+        .span()
+        .join(end.span())
+        .unwrap_or_else(|| start.span());
+
+    let names: Vec<String> = match (single_letter(&first), single_letter(&last)) {
+        (Some(a), Some(b)) if a.is_lowercase() == b.is_lowercase() => {
+            let upper = if inclusive { b as u32 + 1 } else { b as u32 };
+            (a as u32..upper)
+                .filter_map(char::from_u32)
+                .map(String::from)
+                .collect()
+        }
+        _ => {
+            let (Some((prefix, low)), Some((end_prefix, high))) =
+                (split_index(&first), split_index(&last))
+            else {
+                return Err(errors::invalid_range(
+                    "both ends must be single letters or share a prefix followed by a number",
+                    span,
+                ));
+            };
+            if prefix != end_prefix {
+                return Err(errors::invalid_range(
+                    "both ends must share the same prefix",
+                    span,
+                ));
+            }
+            let upper = if inclusive { high + 1 } else { high };
+            (low..upper).map(|n| format!("{}{}", prefix, n)).collect()
+        }
+    };
+
+    if names.is_empty() {
+        return Err(errors::invalid_range("the range is empty", span));
+    }
+    Ok(names)
+}
+
+fn single_letter(name: &str) -> Option<char> {
+    let mut chars = name.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) if c.is_ascii_alphabetic() => Some(c),
+        _ => None,
     }
 }
 
+/// Split `x10` into `("x", 10)`
+fn split_index(name: &str) -> Option<(&str, u64)> {
+    let digits = name.len() - name.trim_end_matches(|c: char| c.is_ascii_digit()).len();
+    if digits == 0 || digits == name.len() {
+        return None;
+    }
+    let (prefix, index) = name.split_at(name.len() - digits);
+    Some((prefix, index.parse().ok()?))
+}
+
 fn parse_symbol_type(input: ParseStream) -> syn::Result<SymbolType> {
     let type_ident: Ident = input.parse()?;
     match type_ident.to_string().as_str() {
@@ -93,15 +239,25 @@ impl SymbolParser {
         let parsed: SingleSymbol = syn::parse(input)?;
         Ok(CodeGenerator::generate_single(
             &parsed.name,
-            parsed.symbol_type,
+            &parsed.symbol_type,
         ))
     }
 
     pub fn parse_multiple(input: TokenStream) -> syn::Result<proc_macro2::TokenStream> {
         let parsed: MultipleSymbols = syn::parse(input)?;
-        Ok(CodeGenerator::generate_multiple(
-            &parsed.names,
-            parsed.symbol_type,
-        ))
+        let symbols: Vec<proc_macro2::TokenStream> = parsed
+            .groups
+            .iter()
+            .flat_map(|group| {
+                group.names.iter().map(|name| {
+                    CodeGenerator::generate_with_assumptions(
+                        name,
+                        &group.symbol_type,
+                        &group.assumptions,
+                    )
+                })
+            })
+            .collect();
+        Ok(CodeGenerator::generate_multiple(&symbols, parsed.tuple))
     }
 }
@@ -711,3 +711,114 @@ fn test_math_precedence_product_of_powers() {
     let result = expr!(x ^ 2 * y ^ 3 * z ^ 4);
     let _ = result;
 }
+
+// ============================================================================
+// Calculus Operators, Relational Chains, Lists and Matrices
+// ============================================================================
+
+#[test]
+fn test_calculus_diff() {
+    let x = Symbol::scalar("x");
+    assert_eq!(
+        expr!(diff(sin(x), x)),
+        Expression::derivative(expr!(sin(x)), x.clone(), 1)
+    );
+    assert_eq!(
+        expr!(diff(x ^ 3, x, 2)),
+        Expression::derivative(expr!(x ^ 3), x, 2)
+    );
+}
+
+#[test]
+fn test_calculus_integrate() {
+    let x = Symbol::scalar("x");
+    assert_eq!(
+        expr!(integrate(x ^ 2, x)),
+        Expression::integral(expr!(x ^ 2), x.clone())
+    );
+    assert_eq!(
+        expr!(integrate(x ^ 2, x, 0, 1)),
+        Expression::definite_integral(expr!(x ^ 2), x, expr!(0), expr!(1))
+    );
+}
+
+#[test]
+fn test_calculus_sum_product_limit() {
+    let k = Symbol::scalar("k");
+    assert_eq!(
+        expr!(sum(k ^ 2, k, 1, n)),
+        Expression::sum(expr!(k ^ 2), k.clone(), expr!(1), expr!(n))
+    );
+    assert_eq!(
+        expr!(product(k, k, 1, n + 1)),
+        Expression::product(expr!(k), k, expr!(1), expr!(n + 1))
+    );
+    assert_eq!(
+        expr!(limit(sin(x) / x, x, 0)),
+        Expression::limit(expr!(sin(x) / x), Symbol::scalar("x"), expr!(0))
+    );
+}
+
+#[test]
+fn test_calculus_inside_expression() {
+    let result = expr!(2 * diff(x ^ 2, x) + 1);
+    let expected = Expression::add(vec![
+        Expression::mul(vec![
+            expr!(2),
+            Expression::derivative(expr!(x ^ 2), Symbol::scalar("x"), 1),
+        ]),
+        expr!(1),
+    ]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_relation_not_equal() {
+    let result = expr!(x != 0);
+    assert_eq!(
+        result,
+        Expression::relation(
+            expr!(x),
+            expr!(0),
+            mathhook_core::core::expression::RelationType::NotEqual
+        )
+    );
+}
+
+#[test]
+fn test_relational_chain() {
+    assert_eq!(
+        expr!(0 < x <= 1),
+        Expression::function("and", vec![expr!(0 < x), expr!(x <= 1)])
+    );
+    assert_eq!(
+        expr!(a < b < c < d),
+        Expression::function("and", vec![expr!(a < b), expr!(b < c), expr!(c < d)])
+    );
+}
+
+#[test]
+fn test_equation_list() {
+    let system: Vec<Expression> = expr!([x + y == 3, x - y == 1]);
+    assert_eq!(system, vec![expr!(x + y == 3), expr!(x - y == 1)]);
+}
+
+#[test]
+fn test_matrix_literal() {
+    assert_eq!(
+        expr!([[1, x], [y, x ^ 2]]),
+        Expression::matrix(vec![vec![expr!(1), expr!(x)], vec![expr!(y), expr!(x ^ 2)]])
+    );
+    assert!(matches!(expr!(2 * [[1, 0], [0, 1]]), Expression::Mul(..)));
+}
+
+#[test]
+fn test_power_precedence_without_parentheses() {
+    assert_eq!(expr!(2 * x * *3 + 5), expr!(2 * (x ^ 3) + 5));
+    assert_eq!(expr!(-x ^ 2), expr!(-(x ^ 2)));
+    assert_eq!(
+        expr!(x ^ -1),
+        Expression::pow(expr!(x), Expression::mul(vec![expr!(-1), expr!(1)]))
+    );
+    assert_eq!(expr!(x / y ^ 2), expr!(x / (y ^ 2)));
+}
@@ -740,10 +740,8 @@ fn test_power_operator_with_constants() {
 
 #[test]
 fn test_power_operator_complex_expression() {
-    // Due to token-level preprocessing limitations, complex expressions with **
-    // should use parentheses for clarity: 2 * (x ** 2) + 3 * x + 1
-    // This test validates that parentheses work correctly
-    let result = expr!(2 * (x * *2) + 3 * x + 1);
+    let result = expr!(2 * x * *2 + 3 * x + 1);
+    assert_eq!(result, expr!(2 * (x * *2) + 3 * x + 1));
     assert_eq!(
         result,
         Expression::add(vec![
@@ -774,17 +772,17 @@ fn test_power_both_syntaxes_equivalent() {
 
 #[test]
 fn test_power_operator_mixed_with_unary() {
-    // Note: Due to token-level preprocessing, -x ** 2 is parsed as (-x) ** 2
-    // This is acceptable behavior. For -(x ** 2), use explicit parentheses.
+    // Power binds tighter than negation: -x ** 2 is -(x ** 2)
     let result = expr!(-x * *2);
     assert_eq!(
         result,
-        Expression::pow(
-            Expression::mul(vec![
-                Expression::integer(-1),
-                Expression::symbol(Symbol::scalar("x"))
-            ]),
-            Expression::integer(2)
-        )
+        Expression::mul(vec![
+            Expression::integer(-1),
+            Expression::pow(
+                Expression::symbol(Symbol::scalar("x")),
+                Expression::integer(2)
+            )
+        ])
     );
+    assert_eq!(expr!((-x) * *2), expr!((-x).pow(2)));
 }
@@ -0,0 +1,116 @@
+//! Tests for the #[math_function] attribute macro
+
+use mathhook_core::functions::custom::custom_function;
+use mathhook_core::simplify::Simplify;
+use mathhook_core::{expr, symbol, Derivative, Expression, LaTeXFormatter};
+use mathhook_macros::math_function;
+
+/// `sinc(x) = sin(x) / x`
+pub struct Sinc;
+
+#[math_function]
+impl Sinc {
+    fn eval(x: f64) -> f64 {
+        if x == 0.0 {
+            1.0
+        } else {
+            x.sin() / x
+        }
+    }
+
+    fn derivative(x: &Expression) -> Expression {
+        // (x cos x - sin x) / x²
+        Expression::div(
+            Expression::add(vec![
+                Expression::mul(vec![
+                    x.clone(),
+                    Expression::function("cos", vec![x.clone()]),
+                ]),
+                Expression::mul(vec![
+                    Expression::integer(-1),
+                    Expression::function("sin", vec![x.clone()]),
+                ]),
+            ]),
+            Expression::pow(x.clone(), Expression::integer(2)),
+        )
+    }
+
+    fn latex(x: &str) -> String {
+        format!("\\operatorname{{sinc}}({})", x)
+    }
+}
+
+pub struct WeightedMean;
+
+#[math_function(name = "wmean")]
+impl WeightedMean {
+    fn eval(a: f64, b: f64) -> Option<f64> {
+        Some((2.0 * a + b) / 3.0)
+    }
+
+    fn derivative(_a: &Expression, _b: &Expression) -> [Expression; 2] {
+        [Expression::rational(2, 3), Expression::rational(1, 3)]
+    }
+}
+
+#[test]
+fn test_constructor_builds_function_call() {
+    assert_eq!(sinc(expr!(x)), Expression::function("sinc", vec![expr!(x)]));
+    assert_eq!(
+        wmean(expr!(x), expr!(y)),
+        Expression::function("wmean", vec![expr!(x), expr!(y)])
+    );
+}
+
+#[test]
+fn test_definition_is_registered() {
+    let definition = custom_function("sinc").expect("sinc should be registered");
+    assert_eq!(definition.arity(), 1);
+    assert_eq!(Sinc::DEFINITION.name(), "sinc");
+    assert_eq!(custom_function("wmean").map(|f| f.arity()), Some(2));
+}
+
+#[test]
+fn test_derivative_applies_chain_rule() {
+    let x = symbol!(x);
+    let result = sinc(expr!(x ^ 2)).derivative(x);
+    let expected = Expression::mul(vec![Sinc::derivative(&expr!(x ^ 2)), expr!(2 * x)]).simplify();
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_derivative_sums_partials() {
+    let x = symbol!(x);
+    assert_eq!(
+        wmean(expr!(x), expr!(3 * x)).derivative(x.clone()),
+        Expression::rational(5, 3)
+    );
+    assert_eq!(
+        wmean(expr!(y), expr!(x)).derivative(x),
+        Expression::rational(1, 3)
+    );
+}
+
+#[test]
+fn test_numeric_evaluation() {
+    assert_eq!(
+        sinc(Expression::integer(0)).evaluate().unwrap(),
+        Expression::float(1.0)
+    );
+
+    assert_eq!(
+        wmean(Expression::integer(3), Expression::integer(6))
+            .evaluate()
+            .unwrap(),
+        Expression::float(4.0)
+    );
+    assert_eq!(sinc(expr!(x)).simplify(), sinc(expr!(x)));
+}
+
+#[test]
+fn test_latex_rendering() {
+    assert_eq!(
+        sinc(expr!(x)).to_latex(None).unwrap(),
+        "\\operatorname{sinc}(x)"
+    );
+}
@@ -0,0 +1,57 @@
+//! Tests for matrix![] macro
+
+use mathhook_core::matrices::Matrix;
+use mathhook_core::{expr, Expression};
+use mathhook_macros::matrix;
+
+#[test]
+fn test_matrix_numeric() {
+    let result = matrix![[1, 2], [3, 4]];
+    let expected = Expression::matrix(vec![
+        vec![Expression::integer(1), Expression::integer(2)],
+        vec![Expression::integer(3), Expression::integer(4)],
+    ]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_matrix_symbolic() {
+    let result = matrix![[1, x], [y, x ^ 2]];
+    let expected = Expression::matrix(vec![vec![expr!(1), expr!(x)], vec![expr!(y), expr!(x ^ 2)]]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_matrix_elements_use_expr_syntax() {
+    let result = matrix![[sin(t), -cos(t)], [x * *2 + 1, 2 * y]];
+    let expected = Expression::matrix(vec![
+        vec![expr!(sin(t)), expr!(-cos(t))],
+        vec![expr!((x ^ 2) + 1), expr!(2 * y)],
+    ]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_matrix_single_row_and_column() {
+    assert_eq!(
+        matrix![[a, b, c]],
+        Expression::matrix(vec![vec![expr!(a), expr!(b), expr!(c)]])
+    );
+    assert_eq!(
+        matrix![[a], [b]],
+        Expression::matrix(vec![vec![expr!(a)], vec![expr!(b)]])
+    );
+}
+
+#[test]
+fn test_matrix_trailing_commas() {
+    assert_eq!(matrix![[1, 2,], [3, 4,],], matrix![[1, 2], [3, 4]]);
+}
+
+#[test]
+fn test_matrix_matches_from_fn() {
+    let from_fn = Expression::Matrix(std::sync::Arc::new(Matrix::from_fn(2, 2, |i, j| {
+        Expression::integer((2 * i + j + 1) as i64)
+    })));
+    assert_eq!(matrix![[1, 2], [3, 4]], from_fn);
+}
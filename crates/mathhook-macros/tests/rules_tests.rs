@@ -0,0 +1,85 @@
+//! Tests for the rules!{} macro
+
+use mathhook_core::core::symbol::SymbolAssumptions;
+use mathhook_core::pattern::rules::{Condition, Property, RewriteRule};
+use mathhook_core::pattern::Pattern;
+use mathhook_core::{expr, Expression, Symbol};
+use mathhook_macros::rules;
+
+fn positive(name: &str) -> Expression {
+    Expression::symbol(Symbol::scalar(name).with_assumptions(SymbolAssumptions::new().positive()))
+}
+
+#[test]
+fn test_rules_build_patterns() {
+    let table = rules! {
+        exp(ln(x_)) => x_;
+        f(x_, 2) => x_ - 1 if integer(x_);
+    };
+    assert_eq!(table.len(), 2);
+    assert_eq!(
+        table.rules()[0],
+        RewriteRule::new(
+            Pattern::Function {
+                name: "exp".to_owned(),
+                args: vec![Pattern::Function {
+                    name: "ln".to_owned(),
+                    args: vec![Pattern::wildcard("x")],
+                }],
+            },
+            Pattern::wildcard("x"),
+        )
+    );
+    assert_eq!(
+        table.rules()[1].conditions(),
+        &[Condition::new(Property::Integer, "x")]
+    );
+}
+
+#[test]
+fn test_pythagorean_identity() {
+    let table = rules! {
+        sin(x_)^2 + cos(x_)^2 => 1;
+    };
+    assert_eq!(table.apply(&expr!((sin(t) ^ 2) + (cos(t) ^ 2))), expr!(1));
+    assert_eq!(
+        table.apply(&expr!((sin(t) ^ 2) + (cos(t) ^ 2) + 3)),
+        expr!(4)
+    );
+    assert_eq!(
+        table.apply(&expr!(y * ((sin(t) ^ 2) + (cos(t) ^ 2)))),
+        expr!(y)
+    );
+}
+
+#[test]
+fn test_conditions_gate_rewrite() {
+    let table = rules! {
+        log(x_*y_) => log(x_) + log(y_) if positive(x_, y_);
+    };
+    let unknown = expr!(log(a * b));
+    assert_eq!(table.apply(&unknown), unknown);
+
+    let (a, b) = (positive("a"), positive("b"));
+    let product = Expression::function("log", vec![Expression::mul(vec![a.clone(), b.clone()])]);
+    assert_eq!(
+        table.apply(&product),
+        Expression::add(vec![
+            Expression::function("log", vec![a]),
+            Expression::function("log", vec![b]),
+        ])
+    );
+}
+
+#[test]
+fn test_display_matches_source_notation() {
+    let table = rules! {
+        sin(x_)^2 + cos(x_)^2 => 1;
+        log(x_*y_) => log(x_) + log(y_) if positive(x_, y_);
+    };
+    assert_eq!(
+        table.to_string(),
+        "sin(x_)^2 + cos(x_)^2 => 1;\n\
+         log(x_*y_) => log(x_) + log(y_) if positive(x_) && positive(y_);\n"
+    );
+}
@@ -1,5 +1,6 @@
 //! Comprehensive tests for symbol!() and symbols!() macros
 
+use mathhook_core::core::symbol::SymbolAssumptions;
 use mathhook_core::Symbol;
 use mathhook_macros::{symbol, symbols};
 
@@ -244,3 +245,66 @@ fn test_symbols_mixed_case() {
         ]
     );
 }
+
+// ============================================================================
+// symbols!() Ranges, Assumptions and Tuples
+// ============================================================================
+
+#[test]
+fn test_symbols_numbered_range() {
+    assert_eq!(
+        symbols![x1..x4],
+        vec![
+            Symbol::scalar("x1"),
+            Symbol::scalar("x2"),
+            Symbol::scalar("x3")
+        ]
+    );
+    assert_eq!(symbols![x1..=x10].len(), 10);
+    assert_eq!(symbols![c0:c3], symbols![c0, c1, c2]);
+}
+
+#[test]
+fn test_symbols_letter_range() {
+    assert_eq!(symbols![a:e], symbols![a, b, c, d]);
+    assert_eq!(symbols![A..=C => matrix], symbols![A, B, C => matrix]);
+}
+
+#[test]
+fn test_symbols_ranges_mixed_with_names() {
+    let syms = symbols![t, x0..x2, y];
+    assert_eq!(syms, symbols![t, x0, x1, y]);
+}
+
+#[test]
+fn test_symbols_assumptions() {
+    let facts = SymbolAssumptions::new().integer().positive();
+    assert_eq!(
+        symbols![n, m => integer, positive],
+        vec![
+            Symbol::scalar("n").with_assumptions(facts),
+            Symbol::scalar("m").with_assumptions(facts)
+        ]
+    );
+}
+
+#[test]
+fn test_symbols_assumption_groups() {
+    let syms = symbols![n => integer; x, y => real; A => matrix];
+    assert!(syms[0].assumptions().is_integer());
+    assert!(syms[1].assumptions().is_real() && !syms[1].assumptions().is_integer());
+    assert!(syms[2].assumptions().is_real());
+    assert_eq!(syms[3], Symbol::matrix("A"));
+}
+
+#[test]
+fn test_symbols_tuple() {
+    let (x, y) = symbols!((x, y));
+    assert_eq!((x, y), (Symbol::scalar("x"), Symbol::scalar("y")));
+
+    let (k,) = symbols!((k => nonzero));
+    assert!(k.assumptions().is_nonzero());
+
+    let (p0, p1, p2) = symbols!((p0..=p2));
+    assert_eq!(vec![p0, p1, p2], symbols![p0, p1, p2]);
+}
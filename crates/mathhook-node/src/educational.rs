@@ -0,0 +1,199 @@
+//! Educational step classes for MathHook Node.js bindings
+//!
+//! `EnhancedStep` and `EnhancedStepExplanation` live in private core modules and
+//! are only re-exported, so binding-codegen does not discover them. These
+//! hand-written classes give JavaScript tutoring frontends the same surface the
+//! generated `Step` and `StepByStepExplanation` classes have: field getters,
+//! `toJSON()` for `JSON.stringify`, and `fromJSON()` to restore a saved value.
+use crate::generated::step::JsStep;
+use crate::generated::step_by_step_explanation::JsStepByStepExplanation;
+use mathhook_core::educational::enhanced_steps::EnhancedStepBuilder;
+use mathhook_core::educational::step_by_step::{Step, StepByStepExplanation};
+use mathhook_core::educational::{EnhancedStep, EnhancedStepExplanation};
+use napi::bindgen_prelude::*;
+use napi_derive::napi;
+
+fn json_error(e: serde_json::Error) -> Error {
+    Error::new(Status::InvalidArg, format!("JSON error: {}", e))
+}
+
+/// Step with a human-readable message and structured API data
+///
+/// # Examples
+///
+/// ```javascript
+/// const { EnhancedStep } = require('mathhook');
+///
+/// const step = new EnhancedStep('isolate', 'Isolate x', 'Subtract 2 from both sides');
+/// console.log(step.title, step.humanMessage);
+/// console.log(JSON.stringify(step));  // full structured data
+/// ```
+#[napi(js_name = "EnhancedStep")]
+#[derive(Clone)]
+pub struct JsEnhancedStep {
+    pub(crate) inner: EnhancedStep,
+}
+
+#[napi]
+impl JsEnhancedStep {
+    /// Create a step with a title and a message for the student
+    #[napi(constructor)]
+    pub fn new(step_id: String, title: String, human_message: String) -> Self {
+        Self {
+            inner: EnhancedStepBuilder::new(&step_id)
+                .with_human_message(&title, &human_message)
+                .build(),
+        }
+    }
+
+    /// Stable identifier of the step
+    #[napi(getter)]
+    pub fn step_id(&self) -> String {
+        self.inner.step_id.clone()
+    }
+
+    /// Short title of the step
+    #[napi(getter)]
+    pub fn title(&self) -> String {
+        self.inner.title.clone()
+    }
+
+    /// Explanation written for the student
+    #[napi(getter)]
+    pub fn human_message(&self) -> String {
+        self.inner.human_message.clone()
+    }
+
+    /// Category of the operation, e.g. "linear_equation"
+    #[napi(getter)]
+    pub fn category(&self) -> String {
+        self.inner.api_data.category.clone()
+    }
+
+    /// Kind of step within the category, e.g. "introduction"
+    #[napi(getter)]
+    pub fn step_type(&self) -> String {
+        self.inner.api_data.step_type.clone()
+    }
+
+    /// Convert to a plain `Step`
+    #[napi]
+    pub fn to_step(&self) -> JsStep {
+        JsStep {
+            inner: Step::from(self.inner.clone()),
+        }
+    }
+
+    /// Structured data of the step, also used by `JSON.stringify`
+    #[napi(js_name = "toJSON")]
+    pub fn to_json_value(&self) -> Result<serde_json::Value> {
+        serde_json::to_value(&self.inner).map_err(json_error)
+    }
+
+    /// Restore a step from the output of `toJSON()`
+    #[napi(factory, js_name = "fromJSON")]
+    pub fn from_json_value(value: serde_json::Value) -> Result<JsEnhancedStep> {
+        serde_json::from_value(value)
+            .map(|inner| JsEnhancedStep { inner })
+            .map_err(json_error)
+    }
+}
+
+impl From<EnhancedStep> for JsEnhancedStep {
+    fn from(core: EnhancedStep) -> Self {
+        Self { inner: core }
+    }
+}
+
+/// Explanation made of enhanced steps, with metadata and a summary
+///
+/// # Examples
+///
+/// ```javascript
+/// const { EnhancedStepExplanation } = require('mathhook');
+///
+/// const explanation = new EnhancedStepExplanation(steps);
+/// console.log(explanation.toHumanText());
+/// const legacy = explanation.toStepByStep();  // StepByStepExplanation
+/// ```
+#[napi(js_name = "EnhancedStepExplanation")]
+#[derive(Clone)]
+pub struct JsEnhancedStepExplanation {
+    pub(crate) inner: EnhancedStepExplanation,
+}
+
+#[napi]
+impl JsEnhancedStepExplanation {
+    /// Create an explanation from steps, deriving metadata and summary
+    #[napi(constructor)]
+    pub fn new(steps: Vec<&JsEnhancedStep>) -> Self {
+        Self {
+            inner: EnhancedStepExplanation::new(
+                steps.into_iter().map(|s| s.inner.clone()).collect(),
+            ),
+        }
+    }
+
+    /// Steps in order
+    #[napi(getter)]
+    pub fn steps(&self) -> Vec<JsEnhancedStep> {
+        self.inner
+            .steps
+            .iter()
+            .cloned()
+            .map(JsEnhancedStep::from)
+            .collect()
+    }
+
+    /// Number of steps
+    #[napi(getter)]
+    pub fn step_count(&self) -> u32 {
+        self.inner.steps.len() as u32
+    }
+
+    /// Estimated difficulty from 1 to 10
+    #[napi(getter)]
+    pub fn difficulty_level(&self) -> u32 {
+        u32::from(self.inner.metadata.difficulty_level)
+    }
+
+    /// Topic of the explanation, e.g. "linear equations"
+    #[napi(getter)]
+    pub fn topic(&self) -> String {
+        self.inner.metadata.topic.clone()
+    }
+
+    /// Plain text rendering: problem, numbered steps and answer
+    #[napi]
+    pub fn to_human_text(&self) -> String {
+        self.inner.to_human_text()
+    }
+
+    /// Convert to a plain `StepByStepExplanation`
+    #[napi]
+    pub fn to_step_by_step(&self) -> JsStepByStepExplanation {
+        JsStepByStepExplanation {
+            inner: StepByStepExplanation::from(self.inner.clone()),
+        }
+    }
+
+    /// Structured data of the explanation, also used by `JSON.stringify`
+    #[napi(js_name = "toJSON")]
+    pub fn to_json_value(&self) -> Result<serde_json::Value> {
+        serde_json::to_value(&self.inner).map_err(json_error)
+    }
+
+    /// Restore an explanation from the output of `toJSON()`
+    #[napi(factory, js_name = "fromJSON")]
+    pub fn from_json_value(value: serde_json::Value) -> Result<JsEnhancedStepExplanation> {
+        serde_json::from_value(value)
+            .map(|inner| JsEnhancedStepExplanation { inner })
+            .map_err(json_error)
+    }
+}
+
+impl From<EnhancedStepExplanation> for JsEnhancedStepExplanation {
+    fn from(core: EnhancedStepExplanation) -> Self {
+        Self { inner: core }
+    }
+}
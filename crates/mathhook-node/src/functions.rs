@@ -4,6 +4,7 @@
 use crate::generated::symbol::JsSymbol;
 use crate::JsExpression;
 use mathhook_core::algebra::groebner::{GroebnerBasis, MonomialOrder};
+use mathhook_core::core::symbol::SymbolAssumptions;
 use mathhook_core::parser::config::ParserConfig;
 use mathhook_core::parser::Parser;
 use mathhook_core::{Expression, Symbol};
@@ -132,6 +133,22 @@ impl FromNapiValue for SymbolOrExpression {
     }
 }
 
+/// Integer result for JavaScript: a `number` when it is exactly
+/// representable, otherwise a `bigint`
+///
+/// Generated bindings widen every Rust integer to `i128` (a lossless cast)
+/// and return it through this, so large counts and sizes never wrap or
+/// lose precision.
+pub fn js_integer(value: i128) -> Either<i64, BigInt> {
+    /// Largest integer a JavaScript `number` holds exactly, 2^53 - 1
+    const MAX_SAFE_INTEGER: i128 = (1 << 53) - 1;
+    if (-MAX_SAFE_INTEGER..=MAX_SAFE_INTEGER).contains(&value) {
+        Either::A(value as i64)
+    } else {
+        Either::B(BigInt::from(value))
+    }
+}
+
 /// Compute Gröbner basis for a system of polynomials
 ///
 /// A Gröbner basis is a special generating set for a polynomial ideal that
@@ -220,16 +237,55 @@ pub fn symbol(name: String) -> JsExpression {
     }
 }
 
-/// Create multiple symbols at once from a string specification
+/// Assumptions declared on symbols created by `symbols()`
+///
+/// Mirrors the keyword arguments of the Python `symbols()`; omitted flags are
+/// not assumed.
+#[napi(object)]
+#[derive(Default)]
+pub struct SymbolOptions {
+    pub positive: Option<bool>,
+    pub nonnegative: Option<bool>,
+    pub nonzero: Option<bool>,
+    pub integer: Option<bool>,
+    pub real: Option<bool>,
+}
+
+impl SymbolOptions {
+    fn assumptions(&self) -> SymbolAssumptions {
+        let mut assumptions = SymbolAssumptions::new();
+        if self.positive == Some(true) {
+            assumptions = assumptions.positive();
+        }
+        if self.nonnegative == Some(true) {
+            assumptions = assumptions.nonnegative();
+        }
+        if self.nonzero == Some(true) {
+            assumptions = assumptions.nonzero();
+        }
+        if self.integer == Some(true) {
+            assumptions = assumptions.integer();
+        }
+        if self.real == Some(true) {
+            assumptions = assumptions.real();
+        }
+        assumptions
+    }
+}
+
+/// Create multiple symbols at once from a string specification or an array
 ///
-/// Supports three input formats:
+/// Supports four input formats:
 /// - Space-separated: `"x y z"` → [x, y, z]
 /// - Comma-separated: `"a, b, c"` or `"a,b,c"` → [a, b, c]
 /// - Range syntax: `"x0:3"` → [x0, x1, x2]
+/// - Array of names: `['x', 'y']` → [x, y]
 ///
 /// # Arguments
 ///
-/// * `names` - String containing symbol names in one of the supported formats
+/// * `names` - String containing symbol names in one of the supported formats,
+///   or an array of names
+/// * `options` - Assumptions declared on every created symbol
 ///
 /// # Returns
 ///
@@ -247,11 +303,31 @@ pub fn symbol(name: String) -> JsExpression {
 /// // Range syntax
 /// const [x0, x1, x2] = symbols('x0:3');
 ///
+/// // With assumptions
+/// const [p, q] = symbols(['p', 'q'], { positive: true });
+/// p.assumptions().isNonzero();  // true
+///
 /// // Use in expressions
 /// const expr = x.add(y).multiply(z);
 /// ```
 #[napi]
-pub fn symbols(names: String) -> Result<Vec<JsExpression>> {
+pub fn symbols(
+    names: Either<String, Vec<String>>,
+    options: Option<SymbolOptions>,
+) -> Result<Vec<JsExpression>> {
+    let assumptions = options.unwrap_or_default().assumptions();
+    let make = |name: &str| JsExpression {
+        inner: Expression::symbol(Symbol::new(name).with_assumptions(assumptions)),
+    };
+    let names = match names {
+        Either::A(names) => names,
+        Either::B(list) => {
+            if list.is_empty() {
+                return Err(Error::new(Status::InvalidArg, "No symbol names provided"));
+            }
+            return Ok(list.iter().map(|name| make(name)).collect());
+        }
+    };
     if names.contains(':') {
         let parts: Vec<&str> = names.split(':').collect();
         if parts.len() != 2 {
@@ -279,10 +355,7 @@ pub fn symbols(names: String) -> Result<Vec<JsExpression>> {
         };
         let mut result = Vec::new();
         for i in start..end {
-            let name = format!("{}{}", base, i);
-            result.push(JsExpression {
-                inner: Expression::symbol(Symbol::new(&name)),
-            });
+            result.push(make(&format!("{}{}", base, i)));
         }
         return Ok(result);
     }
@@ -298,12 +371,7 @@ pub fn symbols(names: String) -> Result<Vec<JsExpression>> {
             "No symbol names provided - input string is empty or contains only whitespace",
         ));
     }
-    Ok(symbol_names
-        .iter()
-        .map(|name| JsExpression {
-            inner: Expression::symbol(Symbol::new(name)),
-        })
-        .collect())
+    Ok(symbol_names.into_iter().map(make).collect())
 }
 mathhook_macros::generate_nodejs_binding!(sin);
 mathhook_macros::generate_nodejs_binding!(cos);
@@ -470,3 +538,65 @@ pub fn parse(expression: String) -> Result<JsExpression> {
         )),
     }
 }
+/// Parse many expressions at once
+///
+/// Inputs are parsed in parallel. Entries that fail to parse are `null`,
+/// so one bad submission does not fail the whole batch.
+///
+/// # Examples
+/// ```javascript
+/// const { parseMany } = require('mathhook');
+///
+/// const parsed = parseMany(['x^2 + 1', '2 *', 'sin(x)']);  // [x^2 + 1, null, sin(x)]
+/// ```
+#[napi]
+pub fn parse_many(expressions: Vec<String>) -> Vec<Option<JsExpression>> {
+    mathhook_core::core::performance::parse_many(&expressions, &ParserConfig::default())
+        .into_iter()
+        .map(|result| result.ok().map(|inner| JsExpression { inner }))
+        .collect()
+}
+/// Simplify many expressions at once, in parallel
+///
+/// # Examples
+/// ```javascript
+/// const { parse, simplifyMany } = require('mathhook');
+///
+/// const simplified = simplifyMany([parse('x + x'), parse('2 * 3')]);  // [2*x, 6]
+/// ```
+#[napi]
+pub fn simplify_many(expressions: Vec<&JsExpression>) -> Vec<JsExpression> {
+    let expressions: Vec<Expression> = expressions.iter().map(|e| e.inner.clone()).collect();
+    mathhook_core::core::performance::simplify_many(&expressions)
+        .into_iter()
+        .map(|inner| JsExpression { inner })
+        .collect()
+}
+/// Solve many equations for the same variable at once, in parallel
+///
+/// Each equation is treated as `equation = 0`. Returns one array of
+/// solutions per equation; unsolved equations give an empty array.
+///
+/// # Examples
+/// ```javascript
+/// const { parse, solveMany } = require('mathhook');
+///
+/// const solutions = solveMany([parse('x - 3'), parse('x^2 - 4')], 'x');  // [[3], [2, -2]]
+/// ```
+#[napi]
+pub fn solve_many(expressions: Vec<&JsExpression>, variable: String) -> Vec<Vec<JsExpression>> {
+    use mathhook_core::solvers::SolverResult;
+    let expressions: Vec<Expression> = expressions.iter().map(|e| e.inner.clone()).collect();
+    let sym = Symbol::new(&variable);
+    mathhook_core::core::performance::solve_many(&expressions, &sym)
+        .into_iter()
+        .map(|result| match result {
+            SolverResult::Single(inner) => vec![JsExpression { inner }],
+            SolverResult::Multiple(roots) => roots
+                .into_iter()
+                .map(|inner| JsExpression { inner })
+                .collect(),
+            _ => vec![],
+        })
+        .collect()
+}
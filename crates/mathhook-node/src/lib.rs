@@ -7,11 +7,15 @@
 mod generated;
 
 // Hand-written wrappers and API convenience functions
+mod educational;
 mod functions;
+mod matrix;
 
 // Public API re-exports
+pub use educational::{JsEnhancedStep, JsEnhancedStepExplanation};
 pub use functions::*;
+pub use matrix::{matrix, matrix_to_array, numeric_matrix, numeric_matrix_to_array};
 pub use generated::JsExpression;
 
-// Re-export SymbolOrExpression for use by generated code
-pub use functions::SymbolOrExpression;
+// Re-export helpers for use by generated code
+pub use functions::{js_integer, SymbolOrExpression};
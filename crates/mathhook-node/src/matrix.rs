@@ -0,0 +1,126 @@
+//! Matrix conversions for MathHook Node.js bindings
+//!
+//! The generated `Matrix` and `NumericMatrix` classes cover element access,
+//! determinant, inverse and eigen decompositions, but can only be built from flat
+//! arrays. These functions convert between both classes and nested JavaScript
+//! arrays (one inner array per row).
+use crate::functions::ExpressionOrNumber;
+use crate::generated::matrix::JsMatrix;
+use crate::generated::numeric_matrix::JsNumericMatrix;
+use crate::JsExpression;
+use mathhook_core::core::matrix::NumericMatrix;
+use mathhook_core::matrices::Matrix;
+use napi::bindgen_prelude::*;
+use napi_derive::napi;
+
+/// Column count of a rectangular array of rows
+fn column_count<T>(rows: &[Vec<T>]) -> Result<usize> {
+    let cols = rows.first().map(Vec::len).unwrap_or(0);
+    if let Some(i) = rows.iter().position(|row| row.len() != cols) {
+        return Err(Error::new(
+            Status::InvalidArg,
+            format!("Row {} has {} entries, expected {}", i, rows[i].len(), cols),
+        ));
+    }
+    Ok(cols)
+}
+
+/// Create a symbolic matrix from nested arrays
+///
+/// Entries may be Expressions, numbers, or strings (taken as symbols).
+///
+/// # Arguments
+///
+/// * `rows` - One array per row; all rows must have the same length
+///
+/// # Errors
+///
+/// Returns error if the rows have different lengths
+///
+/// # Examples
+///
+/// ```javascript
+/// const { matrix, symbol } = require('mathhook');
+///
+/// const x = symbol('x');
+/// const m = matrix([[1, x], [x, 1]]);
+/// m.determinant().toString();  // "1 - x^2"
+/// m.getElement(0, 1);          // x
+/// ```
+#[napi]
+pub fn matrix(rows: Vec<Vec<ExpressionOrNumber>>) -> Result<JsMatrix> {
+    column_count(&rows)?;
+    let rows = rows
+        .into_iter()
+        .map(|row| row.into_iter().map(|entry| entry.0).collect())
+        .collect();
+    Ok(JsMatrix {
+        inner: Matrix::dense(rows),
+    })
+}
+
+/// Convert a symbolic matrix to nested arrays of Expressions
+///
+/// # Examples
+///
+/// ```javascript
+/// const { matrix, matrixToArray } = require('mathhook');
+///
+/// const rows = matrixToArray(matrix([[1, 2], [3, 4]]).transpose());
+/// rows[0].map(e => e.toString());  // ["1", "3"]
+/// ```
+#[napi]
+pub fn matrix_to_array(matrix: &JsMatrix) -> Vec<Vec<JsExpression>> {
+    let (rows, cols) = matrix.inner.dimensions();
+    (0..rows)
+        .map(|i| {
+            (0..cols)
+                .map(|j| JsExpression {
+                    inner: matrix.inner.get_element(i, j),
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Create a numeric (f64) matrix from nested arrays of numbers
+///
+/// # Errors
+///
+/// Returns error if the rows have different lengths or the matrix is empty
+///
+/// # Examples
+///
+/// ```javascript
+/// const { numericMatrix, numericMatrixToArray } = require('mathhook');
+///
+/// const m = numericMatrix([[2, 0], [0, 4]]);
+/// m.determinant();                         // 8
+/// numericMatrixToArray(m.inverse());       // [[0.5, 0], [0, 0.25]]
+/// ```
+#[napi]
+pub fn numeric_matrix(rows: Vec<Vec<f64>>) -> Result<JsNumericMatrix> {
+    let cols = column_count(&rows)?;
+    let row_count = rows.len();
+    NumericMatrix::from_flat(row_count, cols, rows.into_iter().flatten().collect())
+        .map(|inner| JsNumericMatrix { inner })
+        .map_err(|e| Error::new(Status::InvalidArg, format!("{}", e)))
+}
+
+/// Convert a numeric matrix to nested arrays of numbers
+#[napi]
+pub fn numeric_matrix_to_array(matrix: &JsNumericMatrix) -> Result<Vec<Vec<f64>>> {
+    let (rows, cols) = matrix.inner.dimensions();
+    (0..rows)
+        .map(|i| {
+            (0..cols)
+                .map(|j| {
+                    matrix
+                        .inner
+                        .get(i, j)
+                        .map_err(|e| Error::new(Status::GenericFailure, format!("{}", e)))
+                })
+                .collect()
+        })
+        .collect()
+}
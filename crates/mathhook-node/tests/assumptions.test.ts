@@ -0,0 +1,47 @@
+import test from "ava";
+import { Expression, symbols } from "../index.js";
+
+const add = (...args: Expression[]): Expression => Expression.add(args);
+const pow = (base: Expression, exponent: Expression): Expression =>
+  Expression.pow(base, exponent);
+const int = (n: number): Expression => Expression.integer(n);
+
+// =============================================================================
+// SYMBOL OPTIONS
+// =============================================================================
+
+test("symbols accepts an array of names with options", (t) => {
+  const [x, y] = symbols(["x", "y"], { positive: true });
+
+  t.is(x.toString(), "x");
+  t.true(x.assumptions().isPositive());
+  t.true(y.assumptions().isNonzero());
+});
+
+test("symbols string syntax accepts options", (t) => {
+  const [n0, n1] = symbols("n0:2", { integer: true });
+
+  t.deepEqual(n0.assumptions().names(), ["integer", "real"]);
+  t.true(n1.assumptions().isInteger());
+});
+
+test("symbols without options carry no assumptions", (t) => {
+  const [x] = symbols("x");
+  t.true(x.assumptions().isEmpty());
+});
+
+test("symbols rejects an empty array", (t) => {
+  t.throws(() => symbols([]));
+});
+
+// =============================================================================
+// EXPRESSION INTROSPECTION
+// =============================================================================
+
+test("assumptions propagate through expressions", (t) => {
+  const [x] = symbols(["x"], { positive: true });
+  const facts = add(pow(x, int(2)), int(1)).assumptions();
+
+  t.true(facts.isPositive());
+  t.false(facts.isInteger());
+});
@@ -0,0 +1,86 @@
+import test from "ava";
+import {
+  EnhancedStep,
+  EnhancedStepExplanation,
+  Expression,
+  Step,
+  StepByStepExplanation,
+  symbols,
+} from "../index.js";
+
+const add = (...args: Expression[]): Expression => Expression.add(args);
+const mul = (...args: Expression[]): Expression => Expression.mul(args);
+const int = (n: number): Expression => Expression.integer(n);
+
+// =============================================================================
+// STEP-BY-STEP EXPLANATIONS
+// =============================================================================
+
+test("solver explanation serializes to JSON", (t) => {
+  const [x] = symbols("x");
+  const equation = add(mul(int(3), x), int(9));
+  const [, explanation] = equation.solveLinearWithSteps(x.asSymbol()!);
+
+  const json = explanation.toJSON();
+  t.true(Array.isArray(json.steps));
+  t.is(json.steps.length, json.total_steps);
+
+  const parsed = JSON.parse(JSON.stringify(explanation));
+  t.deepEqual(parsed, json);
+});
+
+test("explanation round-trips through fromJSON", (t) => {
+  const [x] = symbols("x");
+  const equation = add(x, int(5));
+  const [, explanation] = equation.solveLinearWithSteps(x.asSymbol()!);
+
+  const restored = StepByStepExplanation.fromJSON(explanation.toJSON());
+  t.true(restored.equals(explanation));
+});
+
+test("step round-trips through fromJSON", (t) => {
+  const step = Step.fromStrings("Isolate x", "Subtract 5 from both sides");
+  const restored = Step.fromJSON(step.toJSON());
+
+  t.true(restored.equals(step));
+  t.is(step.toJSON().title, "Isolate x");
+});
+
+test("fromJSON rejects malformed data", (t) => {
+  t.throws(() => StepByStepExplanation.fromJSON({ steps: "nope" }));
+});
+
+// =============================================================================
+// ENHANCED STEPS
+// =============================================================================
+
+test("enhanced step exposes its fields", (t) => {
+  const step = new EnhancedStep(
+    "isolate",
+    "Isolate x",
+    "Subtract 5 from both sides",
+  );
+
+  t.is(step.stepId, "isolate");
+  t.is(step.title, "Isolate x");
+  t.is(step.humanMessage, "Subtract 5 from both sides");
+  t.is(JSON.parse(JSON.stringify(step)).human_message, step.humanMessage);
+});
+
+test("enhanced explanation renders text and converts to legacy steps", (t) => {
+  const steps = [
+    new EnhancedStep("intro", "Introduction", "Solve x + 5 = 0"),
+    new EnhancedStep("isolate", "Isolate x", "Subtract 5 from both sides"),
+  ];
+  const explanation = new EnhancedStepExplanation(steps);
+
+  t.is(explanation.stepCount, 2);
+  t.is(explanation.steps[1].title, "Isolate x");
+  t.true(explanation.toHumanText().includes("Step 2: Isolate x"));
+
+  const legacy = explanation.toStepByStep();
+  t.is(legacy.toJSON().steps.length, 2);
+
+  const restored = EnhancedStepExplanation.fromJSON(explanation.toJSON());
+  t.is(restored.toHumanText(), explanation.toHumanText());
+});
@@ -0,0 +1,70 @@
+import test from "ava";
+import { Expression, parse, sin, symbols } from "../index.js";
+
+// =============================================================================
+// TREE INSPECTION
+// =============================================================================
+
+test("kind names the node type", (t) => {
+  const [x] = symbols("x");
+
+  t.is(x.kind(), "symbol");
+  t.is(Expression.integer(3).kind(), "number");
+  t.is(Expression.add([x, Expression.integer(1)]).kind(), "add");
+  t.is(Expression.pow(x, Expression.integer(2)).kind(), "pow");
+  t.is(sin(x).kind(), "function");
+});
+
+test("args lists the children", (t) => {
+  const [x] = symbols("x");
+  const power = Expression.pow(x, Expression.integer(3));
+
+  t.deepEqual(
+    power.args().map((arg) => arg.toString()),
+    ["x", "3"],
+  );
+  t.deepEqual(x.args(), []);
+});
+
+test("names of symbols and functions", (t) => {
+  const [theta] = symbols("theta");
+
+  t.is(theta.symbolName(), "theta");
+  t.is(sin(theta).functionName(), "sin");
+  t.is(sin(theta).symbolName(), null);
+  t.is(theta.functionName(), null);
+});
+
+test("tree can be walked without string parsing", (t) => {
+  const expr = parse("sin(x)^2 + 3*y");
+
+  const leaves: string[] = [];
+  const walk = (node: Expression) => {
+    const name = node.symbolName();
+    if (name !== null) {
+      leaves.push(name);
+    }
+    node.args().forEach(walk);
+  };
+  walk(expr);
+
+  t.deepEqual(leaves.sort(), ["x", "y"]);
+});
+
+// =============================================================================
+// JSON
+// =============================================================================
+
+test("expression round-trips through JSON", (t) => {
+  const expr = parse("sin(x)^2 + 2/3*y");
+  const restored = Expression.fromJSON(
+    JSON.parse(JSON.stringify(expr.toJSON())),
+  );
+
+  t.true(restored.equals(expr));
+  t.is(restored.toString(), expr.toString());
+});
+
+test("fromJSON rejects malformed data", (t) => {
+  t.throws(() => Expression.fromJSON({ Unknown: [] }));
+});
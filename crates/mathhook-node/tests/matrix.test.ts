@@ -0,0 +1,78 @@
+import test from "ava";
+import {
+  matrix,
+  matrixToArray,
+  numericMatrix,
+  numericMatrixToArray,
+  symbol,
+} from "../index.js";
+
+// =============================================================================
+// SYMBOLIC MATRICES
+// =============================================================================
+
+test("matrix from nested arrays", (t) => {
+  const x = symbol("x");
+  const m = matrix([
+    [1, x],
+    [2, 3],
+  ]);
+
+  t.deepEqual(m.dimensions(), [2, 2]);
+  t.is(m.getElement(0, 1).toString(), "x");
+  t.is(m.getElement(1, 0).toString(), "2");
+});
+
+test("matrix round-trips through nested arrays", (t) => {
+  const m = matrix([
+    [1, 2, 3],
+    [4, 5, 6],
+  ]);
+  const rows = matrixToArray(m.transpose());
+
+  t.is(rows.length, 3);
+  t.deepEqual(
+    rows.map((row) => row.map((e) => e.toString())),
+    [
+      ["1", "4"],
+      ["2", "5"],
+      ["3", "6"],
+    ],
+  );
+});
+
+test("matrix determinant and inverse", (t) => {
+  const m = matrix([
+    [2, 0],
+    [0, 4],
+  ]);
+
+  t.is(m.determinant().toString(), "8");
+  const product = m.multiply(m.inverse());
+  t.true(product.isIdentity());
+});
+
+test("matrix rejects ragged rows", (t) => {
+  t.throws(() => matrix([[1, 2], [3]]));
+});
+
+// =============================================================================
+// NUMERIC MATRICES
+// =============================================================================
+
+test("numeric matrix round-trips through nested arrays", (t) => {
+  const m = numericMatrix([
+    [2, 0],
+    [0, 4],
+  ]);
+
+  t.is(m.determinant(), 8);
+  t.deepEqual(numericMatrixToArray(m.inverse()), [
+    [0.5, 0],
+    [0, 0.25],
+  ]);
+});
+
+test("numeric matrix rejects empty input", (t) => {
+  t.throws(() => numericMatrix([]));
+});
@@ -4,6 +4,8 @@ import {
   SmartEquationSolver,
   symbols,
   parse,
+  parseMany,
+  solveMany,
 } from "../index.js";
 
 // Helper functions for cleaner test code
@@ -399,3 +401,18 @@ test("solve equation with rational coefficients via parse", (t) => {
 
   t.truthy(result);
 });
+
+// =============================================================================
+// BATCHES
+// =============================================================================
+
+test("parse and solve a batch of submissions", (t) => {
+  const parsed = parseMany(["x - 3", "2 *", "x^2 - 4"]);
+  t.is(parsed.length, 3);
+  t.is(parsed[1], null);
+
+  const solutions = solveMany([parsed[0]!, parsed[2]!], "x");
+  t.is(solutions.length, 2);
+  t.is(solutions[0].length, 1);
+  t.is(solutions[1].length, 2);
+});
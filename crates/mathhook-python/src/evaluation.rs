@@ -0,0 +1,176 @@
+//! `with mathhook.evaluate(...)` blocks
+//!
+//! The block pushes evaluation settings for the current thread on entry and
+//! restores the previous ones on exit, so `evaluate()`, `evaluate_with_context()`
+//! and every `EvalContext` created inside it use them, and `simplify()` uses
+//! the assumptions.
+use crate::PyExpression;
+use mathhook_core::core::expression::eval_scope::{EvalScope, EvalSettings};
+use mathhook_core::core::symbol::SymbolAssumptions;
+use mathhook_core::core::AngleUnit;
+use mathhook_core::Expression;
+use pyo3::exceptions::{PyRuntimeError, PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+/// Most significant digits a 64-bit float carries
+const MAX_PRECISION_DIGITS: u32 = f64::DIGITS;
+
+/// Settings for a `with` block, returned by `evaluate()`
+///
+/// The settings only apply between `__enter__` and `__exit__`, on the
+/// thread that entered the block.
+#[pyclass(name = "EvaluationScope", unsendable)]
+pub struct PyEvaluationScope {
+    settings: EvalSettings,
+    scope: Option<EvalScope>,
+}
+
+#[pymethods]
+impl PyEvaluationScope {
+    fn __enter__(mut slf: PyRefMut<'_, Self>) -> PyResult<PyRefMut<'_, Self>> {
+        if slf.scope.is_some() {
+            return Err(PyRuntimeError::new_err(
+                "evaluation scope is already active",
+            ));
+        }
+        slf.scope = Some(slf.settings.clone().enter());
+        Ok(slf)
+    }
+
+    #[pyo3(signature = (_exc_type = None, _exc_value = None, _traceback = None))]
+    fn __exit__(
+        &mut self,
+        _exc_type: Option<&Bound<'_, PyAny>>,
+        _exc_value: Option<&Bound<'_, PyAny>>,
+        _traceback: Option<&Bound<'_, PyAny>>,
+    ) -> bool {
+        self.scope = None;
+        false
+    }
+
+    fn __repr__(&self) -> String {
+        let mut parts = Vec::new();
+        if let Some(bits) = self.settings.precision {
+            parts.push(format!("precision_bits={bits}"));
+        }
+        if let Some(unit) = self.settings.angle_unit {
+            let name = match unit {
+                AngleUnit::Radians => "radians",
+                AngleUnit::Degrees => "degrees",
+            };
+            parts.push(format!("angle='{name}'"));
+        }
+        let mut names: Vec<&String> = self.settings.assumptions.keys().collect();
+        names.sort();
+        if !names.is_empty() {
+            let names: Vec<&str> = names.iter().map(|name| name.as_str()).collect();
+            parts.push(format!("assumptions=[{}]", names.join(", ")));
+        }
+        format!("EvaluationScope({})", parts.join(", "))
+    }
+}
+
+#[doc = " Temporarily change how expressions are evaluated"]
+#[doc = ""]
+#[doc = " Returns a context manager. Inside the `with` block, `evaluate()`,"]
+#[doc = " `evaluate_with_context()` and new `EvalContext` objects use the given"]
+#[doc = " settings, and `simplify()` uses the assumptions; leaving the block,"]
+#[doc = " normally or by an exception, restores the previous ones. Blocks nest."]
+#[doc = ""]
+#[doc = " # Arguments"]
+#[doc = ""]
+#[doc = " * `precision` - Significant decimal digits of numerical results, at most"]
+#[doc = "   15 since evaluation uses 64-bit floats"]
+#[doc = " * `angle` - Unit of trigonometric arguments: 'radians' or 'degrees'"]
+#[doc = " * `assumptions` - Dict from a symbol or symbol name to an assumption"]
+#[doc = "   ('positive', 'nonnegative', 'nonzero', 'integer', 'real') or a list of them"]
+#[doc = ""]
+#[doc = " # Examples"]
+#[doc = ""]
+#[doc = " ```python"]
+#[doc = " import mathhook"]
+#[doc = " from mathhook import EvalContext, sin, sqrt, symbols"]
+#[doc = ""]
+#[doc = " x, = symbols('x')"]
+#[doc = " with mathhook.evaluate(angle='degrees'):"]
+#[doc = "     sin(30).evaluate()  # 1/2"]
+#[doc = ""]
+#[doc = " with mathhook.evaluate(precision=5, assumptions={x: 'positive'}):"]
+#[doc = "     sqrt(x**2).simplify()  # x"]
+#[doc = "     sqrt(2).evaluate_with_context(EvalContext.numeric({}))  # 1.41421..."]
+#[doc = " ```"]
+#[pyfunction]
+#[pyo3(signature = (precision = None, angle = None, assumptions = None))]
+pub fn evaluate(
+    precision: Option<u32>,
+    angle: Option<&str>,
+    assumptions: Option<&Bound<'_, PyDict>>,
+) -> PyResult<PyEvaluationScope> {
+    let mut settings = EvalSettings::new();
+    if let Some(digits) = precision {
+        if digits == 0 {
+            return Err(PyValueError::new_err("precision must be at least 1 digit"));
+        }
+        if digits > MAX_PRECISION_DIGITS {
+            return Err(PyValueError::new_err(format!(
+                "precision above {MAX_PRECISION_DIGITS} digits is not supported; \
+                 numerical evaluation uses 64-bit floats"
+            )));
+        }
+        let bits = (f64::from(digits) * std::f64::consts::LOG2_10).ceil() as u32;
+        settings = settings.with_precision(bits);
+    }
+    if let Some(angle) = angle {
+        let unit = match angle {
+            "radians" => AngleUnit::Radians,
+            "degrees" => AngleUnit::Degrees,
+            _ => {
+                return Err(PyValueError::new_err(
+                    "angle must be 'radians' or 'degrees'",
+                ))
+            }
+        };
+        settings = settings.with_angle_unit(unit);
+    }
+    if let Some(assumptions) = assumptions {
+        for (key, value) in assumptions.iter() {
+            settings = settings.assume(symbol_name(&key)?, parse_assumptions(&value)?);
+        }
+    }
+    Ok(PyEvaluationScope {
+        settings,
+        scope: None,
+    })
+}
+
+/// Name of a symbol given as a symbol expression or a string
+fn symbol_name(key: &Bound<'_, PyAny>) -> PyResult<String> {
+    if let Ok(name) = key.extract::<String>() {
+        return Ok(name);
+    }
+    if let Ok(expr) = key.extract::<PyExpression>() {
+        if let Expression::Symbol(symbol) = &expr.inner {
+            return Ok(symbol.name().to_owned());
+        }
+    }
+    Err(PyTypeError::new_err(
+        "assumption keys must be symbols or symbol names",
+    ))
+}
+
+/// A single assumption name or a list of them
+fn parse_assumptions(value: &Bound<'_, PyAny>) -> PyResult<SymbolAssumptions> {
+    let names = match value.extract::<String>() {
+        Ok(name) => vec![name],
+        Err(_) => value
+            .extract::<Vec<String>>()
+            .map_err(|_| PyTypeError::new_err("assumptions must be a name or a list of names"))?,
+    };
+    names
+        .iter()
+        .try_fold(SymbolAssumptions::new(), |facts, name| {
+            facts.with_flag(name)
+        })
+        .map_err(|unknown| PyValueError::new_err(format!("unknown assumption '{unknown}'")))
+}
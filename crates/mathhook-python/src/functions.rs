@@ -5,6 +5,7 @@
 use crate::PyExpression;
 
 use crate::helpers::{sympify_python, LatexMode, PRINT_CONFIG};
+use mathhook_core::core::symbol::SymbolAssumptions;
 use mathhook_core::{Expression, Symbol};
 use pyo3::prelude::*;
 
@@ -18,6 +19,8 @@ use pyo3::prelude::*;
 #[doc = " # Arguments"]
 #[doc = ""]
 #[doc = " * `names` - String with symbol names"]
+#[doc = " * `positive`, `nonnegative`, `nonzero`, `integer`, `real` - Assumptions"]
+#[doc = "   declared on every created symbol"]
 #[doc = ""]
 #[doc = " # Examples"]
 #[doc = ""]
@@ -25,9 +28,38 @@ use pyo3::prelude::*;
 #[doc = " x, y, z = symbols('x y z')"]
 #[doc = " a, b = symbols('a,b')"]
 #[doc = " x0, x1, x2 = symbols('x0:3')"]
+#[doc = " n, m = symbols('n m', integer=True)"]
+#[doc = " n.assumptions().is_real()  # True"]
 #[doc = " ```"]
 #[pyfunction]
-pub fn symbols(names: &str) -> PyResult<Vec<PyExpression>> {
+#[pyo3(signature = (names, *, positive = false, nonnegative = false, nonzero = false, integer = false, real = false))]
+pub fn symbols(
+    names: &str,
+    positive: bool,
+    nonnegative: bool,
+    nonzero: bool,
+    integer: bool,
+    real: bool,
+) -> PyResult<Vec<PyExpression>> {
+    let mut assumptions = SymbolAssumptions::new();
+    if positive {
+        assumptions = assumptions.positive();
+    }
+    if nonnegative {
+        assumptions = assumptions.nonnegative();
+    }
+    if nonzero {
+        assumptions = assumptions.nonzero();
+    }
+    if integer {
+        assumptions = assumptions.integer();
+    }
+    if real {
+        assumptions = assumptions.real();
+    }
+    let make = |name: &str| PyExpression {
+        inner: Expression::symbol(Symbol::new(name).with_assumptions(assumptions)),
+    };
     if names.contains(':') {
         let parts: Vec<&str> = names.split(':').collect();
         if parts.len() != 2 {
@@ -51,10 +83,7 @@ pub fn symbols(names: &str) -> PyResult<Vec<PyExpression>> {
         };
         let mut result = Vec::new();
         for i in start..end {
-            let name = format!("{}{}", base, i);
-            result.push(PyExpression {
-                inner: Expression::symbol(Symbol::new(&name)),
-            });
+            result.push(make(&format!("{}{}", base, i)));
         }
         return Ok(result);
     }
@@ -69,12 +98,7 @@ pub fn symbols(names: &str) -> PyResult<Vec<PyExpression>> {
             "No symbol names provided",
         ));
     }
-    Ok(symbol_names
-        .iter()
-        .map(|name| PyExpression {
-            inner: Expression::symbol(Symbol::new(name)),
-        })
-        .collect())
+    Ok(symbol_names.into_iter().map(make).collect())
 }
 
 /// Create a single symbol
@@ -134,6 +158,79 @@ pub fn solve(equation: &PyExpression, variable: &str) -> Vec<PyExpression> {
     }
 }
 
+/// Parse many expressions at once
+///
+/// Inputs are parsed in parallel. Entries that fail to parse are `None`,
+/// so one bad submission does not fail the whole batch.
+///
+/// # Examples
+///
+/// ```python
+/// from mathhook import parse_many
+///
+/// parsed = parse_many(['x^2 + 1', '2 *', 'sin(x)'])  # [x^2 + 1, None, sin(x)]
+/// ```
+#[pyfunction]
+pub fn parse_many(py: Python<'_>, inputs: Vec<String>) -> Vec<Option<PyExpression>> {
+    use mathhook_core::ParserConfig;
+    py.detach(|| mathhook_core::core::performance::parse_many(&inputs, &ParserConfig::default()))
+        .into_iter()
+        .map(|result| result.ok().map(|inner| PyExpression { inner }))
+        .collect()
+}
+
+/// Simplify many expressions at once, in parallel
+///
+/// # Examples
+///
+/// ```python
+/// from mathhook import parse, simplify_many
+///
+/// simplified = simplify_many([parse('x + x'), parse('2 * 3')])  # [2*x, 6]
+/// ```
+#[pyfunction]
+pub fn simplify_many(py: Python<'_>, expressions: Vec<PyExpression>) -> Vec<PyExpression> {
+    let expressions: Vec<Expression> = expressions.into_iter().map(|e| e.inner).collect();
+    py.detach(|| mathhook_core::core::performance::simplify_many(&expressions))
+        .into_iter()
+        .map(|inner| PyExpression { inner })
+        .collect()
+}
+
+/// Solve many equations for the same variable at once, in parallel
+///
+/// Each equation is treated as `equation = 0`. Returns one list of
+/// solutions per equation, as `solve` would.
+///
+/// # Examples
+///
+/// ```python
+/// from mathhook import parse, solve_many
+///
+/// solutions = solve_many([parse('x - 3'), parse('x^2 - 4')], 'x')  # [[3], [2, -2]]
+/// ```
+#[pyfunction]
+pub fn solve_many(
+    py: Python<'_>,
+    equations: Vec<PyExpression>,
+    variable: &str,
+) -> Vec<Vec<PyExpression>> {
+    use mathhook_core::solvers::SolverResult;
+    let equations: Vec<Expression> = equations.into_iter().map(|e| e.inner).collect();
+    let sym = Symbol::new(variable);
+    py.detach(|| mathhook_core::core::performance::solve_many(&equations, &sym))
+        .into_iter()
+        .map(|result| match result {
+            SolverResult::Single(expr) => vec![PyExpression { inner: expr }],
+            SolverResult::Multiple(exprs) => exprs
+                .into_iter()
+                .map(|expr| PyExpression { inner: expr })
+                .collect(),
+            _ => vec![],
+        })
+        .collect()
+}
+
 #[doc = " Parse a mathematical expression from a string"]
 #[doc = ""]
 #[doc = " Parses mathematical expressions in standard notation, LaTeX, or Wolfram format."]
@@ -174,6 +271,27 @@ pub fn parse(input: &str) -> PyResult<PyExpression> {
     }
 }
 
+/// Evaluate an expression to a Python `complex`
+///
+/// Works where real evaluation cannot: `i`, roots of negative numbers and
+/// `exp(i*x)`. Raises `ValueError` for free symbols or non-finite results.
+///
+/// # Examples
+///
+/// ```python
+/// from mathhook import parse, eval_complex
+///
+/// eval_complex(parse('exp(i*pi)'))  # (-1+1.2246467991473532e-16j)
+/// eval_complex(parse('sqrt(-4)'))   # 2j
+/// ```
+#[pyfunction]
+pub fn eval_complex(x: &Bound<'_, PyAny>) -> PyResult<num_complex::Complex64> {
+    use mathhook_core::EvalNumeric;
+    sympify_python(x)?
+        .eval_complex()
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
+}
+
 mathhook_macros::generate_python_binding!(sin);
 
 mathhook_macros::generate_python_binding!(cos);
@@ -330,7 +448,7 @@ pub fn roots(poly: &PyExpression, variable: String) -> PyExpression {
 #[doc = ""]
 #[doc = " * `use_latex` - Enable LaTeX rendering (default: True)"]
 #[doc = " * `latex_mode` - LaTeX rendering mode: 'mathjax', 'png', 'svg' (default: 'mathjax')"]
-#[doc = " * `unicode` - Use Unicode characters in text output (default: True)"]
+#[doc = " * `unicode` - Draw `pprint` output with Unicode characters rather than ASCII art (default: True)"]
 #[doc = ""]
 #[doc = " # Examples"]
 #[doc = ""]
@@ -366,17 +484,17 @@ pub fn init_printing(use_latex: bool, latex_mode: &str, unicode: bool) -> PyResu
     Ok(())
 }
 
-#[doc = " Pretty print expression with Unicode"]
+#[doc = " Pretty print expression in 2D"]
 #[doc = ""]
-#[doc = " Prints a formatted representation of the expression to stdout, optionally"]
-#[doc = " using Unicode characters for mathematical symbols. This is useful for"]
-#[doc = " terminal display and debugging."]
+#[doc = " Prints the expression to stdout laid out over several lines: fractions"]
+#[doc = " over a bar, raised exponents, root signs and matrices between tall"]
+#[doc = " brackets. This is useful for terminal display and debugging."]
 #[doc = ""]
 #[doc = " # Arguments"]
 #[doc = ""]
 #[doc = " * `expr` - The expression to print"]
-#[doc = " * `use_unicode` - Use Unicode characters (superscripts, Greek letters, etc.)"]
-#[doc = "                   Default: True"]
+#[doc = " * `use_unicode` - Draw with Unicode box drawing characters instead of ASCII art"]
+#[doc = "                   Default: the `unicode` setting of `init_printing()` (True)"]
 #[doc = ""]
 #[doc = " # Examples"]
 #[doc = ""]
@@ -385,25 +503,24 @@ pub fn init_printing(use_latex: bool, latex_mode: &str, unicode: bool) -> PyResu
 #[doc = ""]
 #[doc = " x, y = symbols('x y')"]
 #[doc = ""]
-#[doc = " # Print with Unicode (default)"]
-#[doc = " pprint(x**2)  # Shows: x²"]
-#[doc = ""]
-#[doc = " # Print without Unicode"]
-#[doc = " pprint(x**2, use_unicode=False)  # Shows: x^2"]
+#[doc = " pprint(x**2)"]
+#[doc = " #  2"]
+#[doc = " # x"]
 #[doc = ""]
-#[doc = " # Complex expression"]
-#[doc = " pprint((x + y)**2)"]
+#[doc = " pprint((x + 1)/y, use_unicode=False)"]
+#[doc = " # 1 + x"]
+#[doc = " # -----"]
+#[doc = " #   y"]
 #[doc = " ```"]
 #[pyfunction]
-# [pyo3 (signature = (expr , use_unicode = true))]
-pub fn pprint(py: Python, expr: &PyExpression, use_unicode: bool) -> PyResult<()> {
-    use mathhook_core::formatter::simple::{SimpleContext, SimpleFormatter};
-    let context = SimpleContext {
-        use_unicode,
-        implicit_multiplication: true,
+# [pyo3 (signature = (expr , use_unicode = None))]
+pub fn pprint(py: Python, expr: &PyExpression, use_unicode: Option<bool>) -> PyResult<()> {
+    use mathhook_core::formatter::pretty::{PrettyContext, PrettyFormatter};
+    let context = PrettyContext {
+        use_unicode: use_unicode.unwrap_or_else(|| PRINT_CONFIG.read().unwrap().unicode),
         ..Default::default()
     };
-    match expr.inner.to_simple(&context) {
+    match expr.inner.to_pretty(&context) {
         Ok(output) => {
             let sys = py.import("sys")?;
             let stdout = sys.getattr("stdout")?;
@@ -4,7 +4,9 @@
 
 use crate::generated::PyExpression;
 use mathhook_core::{Expression, Symbol};
+use num_bigint::BigInt;
 use pyo3::prelude::*;
+use pyo3::types::PyInt;
 use std::sync::RwLock;
 
 #[derive(Clone, Copy)]
@@ -39,6 +41,13 @@ pub(crate) fn sympify_python(obj: &Bound<'_, PyAny>) -> PyResult<Expression> {
     if let Ok(value) = obj.extract::<i64>() {
         return Ok(Expression::integer(value));
     }
+    if obj.is_instance_of::<PyInt>() {
+        // Python ints are unbounded; keep the ones beyond i64 exact
+        let digits = obj.str()?;
+        if let Ok(value) = digits.to_str()?.parse::<BigInt>() {
+            return Ok(Expression::big_integer(value));
+        }
+    }
     if let Ok(value) = obj.extract::<f64>() {
         return Ok(Expression::float(value));
     }
@@ -10,12 +10,14 @@ mod generated;
 pyo3_stub_gen::define_stub_info_gatherer!(stub_info);
 
 // Hand-written wrappers and API convenience functions
+mod evaluation;
 mod functions;
 mod functions_generated;
 mod helpers;
 mod polyzp;
 
 // Public API re-exports
+pub use evaluation::{evaluate, PyEvaluationScope};
 pub use functions::*;
 pub use generated::PyExpression;
 pub use polyzp::{poly_gcd, poly_mul_fast, poly_zp, PyPolyZp};
@@ -30,12 +32,16 @@ fn mathhook(m: &Bound<'_, PyModule>) -> PyResult<()> {
 
     // Register hand-written classes (not in generated)
     m.add_class::<PyPolyZp>()?;
+    m.add_class::<PyEvaluationScope>()?;
 
     // Register functions from functions module
     m.add_function(wrap_pyfunction!(functions::parse, m)?)?;
     m.add_function(wrap_pyfunction!(functions::symbols, m)?)?;
     m.add_function(wrap_pyfunction!(functions::symbol, m)?)?;
     m.add_function(wrap_pyfunction!(functions::solve, m)?)?;
+    m.add_function(wrap_pyfunction!(functions::parse_many, m)?)?;
+    m.add_function(wrap_pyfunction!(functions::simplify_many, m)?)?;
+    m.add_function(wrap_pyfunction!(functions::solve_many, m)?)?;
     m.add_function(wrap_pyfunction!(functions::init_printing, m)?)?;
     m.add_function(wrap_pyfunction!(functions::pprint, m)?)?;
     m.add_function(wrap_pyfunction!(functions::sin, m)?)?;
@@ -72,6 +78,8 @@ fn mathhook(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(functions::beta, m)?)?;
     m.add_function(wrap_pyfunction!(functions::degree, m)?)?;
     m.add_function(wrap_pyfunction!(functions::roots, m)?)?;
+    m.add_function(wrap_pyfunction!(functions::eval_complex, m)?)?;
+    m.add_function(wrap_pyfunction!(evaluation::evaluate, m)?)?;
 
     // Register macro-generated functions for benchmarking
     m.add_function(wrap_pyfunction!(
@@ -0,0 +1,28 @@
+"""Test batch operations
+
+Batches are processed in one call and must agree with the single-item
+functions, with unparseable entries reported as None.
+"""
+from mathhook import parse, parse_many, simplify_many, solve, solve_many
+
+
+def test_parse_many_reports_failures_per_entry():
+    """Test that a bad input does not fail the batch"""
+    parsed = parse_many(['x^2 + 1', '2 *', 'sin(x)'])
+    assert len(parsed) == 3
+    assert parsed[1] is None
+    assert str(parsed[0]) == str(parse('x^2 + 1'))
+
+
+def test_simplify_many():
+    """Test that simplify_many matches simplify"""
+    expressions = [parse('x + x'), parse('2 * 3')]
+    simplified = simplify_many(expressions)
+    assert [str(e) for e in simplified] == [str(e.simplify()) for e in expressions]
+
+
+def test_solve_many():
+    """Test that solve_many matches solve"""
+    equations = [parse('x - 3'), parse('x^2 - 4')]
+    results = solve_many(equations, 'x')
+    assert [len(r) for r in results] == [len(solve(e, 'x')) for e in equations]
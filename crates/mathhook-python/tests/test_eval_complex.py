@@ -0,0 +1,40 @@
+"""Test complex numeric evaluation
+
+eval_complex returns Python complex values, so expressions that have no
+real value can still be evaluated numerically.
+"""
+import cmath
+
+import pytest
+from mathhook import eval_complex, parse, symbols
+
+
+def test_returns_python_complex():
+    """Test that results are complex objects"""
+    result = eval_complex(parse('3 + 4*i'))
+    assert isinstance(result, complex)
+    assert result == 3 + 4j
+
+
+def test_euler_formula():
+    """Test exp(i*x) = cos(x) + i*sin(x)"""
+    result = eval_complex(parse('exp(i*pi/3)'))
+    assert cmath.isclose(result, cmath.exp(1j * cmath.pi / 3))
+
+
+def test_root_of_negative_number():
+    """Test that roots take the principal branch"""
+    assert cmath.isclose(eval_complex(parse('sqrt(-4)')), 2j)
+    assert cmath.isclose(eval_complex(parse('(-8)^(1/3)')), (-8 + 0j) ** (1 / 3))
+
+
+def test_real_input():
+    """Test that plain numbers evaluate with zero imaginary part"""
+    assert eval_complex(2) == 2 + 0j
+
+
+def test_free_symbol_raises():
+    """Test that unevaluable expressions raise ValueError"""
+    x, = symbols('x')
+    with pytest.raises(ValueError):
+        eval_complex(x + 1)
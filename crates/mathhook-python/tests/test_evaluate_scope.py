@@ -0,0 +1,92 @@
+"""Tests for the mathhook.evaluate() context manager
+
+Verifies that settings apply inside the with block only, nest, and are
+restored when the block raises.
+"""
+
+import pytest
+import mathhook
+from mathhook import PyExpression as Expression, EvalContext, symbols
+
+
+def sin_of(value):
+    return Expression.function("sin", [Expression.integer(value)])
+
+
+class TestAngleUnit:
+    def test_degrees_inside_block(self):
+        with mathhook.evaluate(angle='degrees'):
+            assert sin_of(30).evaluate() == Expression.rational(1, 2)
+        assert sin_of(30).evaluate() != Expression.rational(1, 2)
+
+    def test_contexts_created_inside_block(self):
+        with mathhook.evaluate(angle='degrees'):
+            ctx = EvalContext.symbolic()
+        assert sin_of(90).evaluate_with_context(ctx) == Expression.integer(1)
+
+    def test_invalid_angle(self):
+        with pytest.raises(ValueError):
+            mathhook.evaluate(angle='gradians')
+
+
+class TestAssumptions:
+    def test_positive_symbol(self):
+        x, = symbols('x')
+        root = mathhook.sqrt(x**2)
+        ctx = EvalContext(numeric=False, simplify_first=True)
+        with mathhook.evaluate(assumptions={x: 'positive'}):
+            assert root.evaluate_with_context(ctx) == x
+        assert root.evaluate_with_context(ctx) != x
+
+    def test_names_and_lists(self):
+        x, = symbols('x')
+        root = mathhook.sqrt(x**2)
+        ctx = EvalContext(numeric=False, simplify_first=True)
+        with mathhook.evaluate(assumptions={'x': ['real', 'nonnegative']}):
+            assert root.evaluate_with_context(ctx) == x
+
+    def test_simplify_uses_assumptions(self):
+        x, = symbols('x')
+        root = mathhook.sqrt(x**2)
+        with mathhook.evaluate(assumptions={x: 'positive'}):
+            assert root.simplify() == x
+        assert root.simplify() != x
+
+    def test_unknown_assumption(self):
+        with pytest.raises(ValueError):
+            mathhook.evaluate(assumptions={'x': 'prime'})
+
+
+class TestPrecision:
+    def test_rounds_numeric_results(self):
+        ctx = EvalContext(numeric=True)
+        root = mathhook.sqrt(Expression.integer(2))
+        exact = root.evaluate_with_context(ctx)
+        with mathhook.evaluate(precision=3):
+            assert root.evaluate_with_context(ctx) != exact
+        assert root.evaluate_with_context(ctx) == exact
+
+    def test_unsupported_precision(self):
+        with pytest.raises(ValueError):
+            mathhook.evaluate(precision=50)
+
+
+class TestScoping:
+    def test_nested_blocks(self):
+        with mathhook.evaluate(angle='degrees'):
+            with mathhook.evaluate(precision=10):
+                assert sin_of(30).evaluate() == Expression.rational(1, 2)
+            assert sin_of(30).evaluate() == Expression.rational(1, 2)
+        assert sin_of(30).evaluate() != Expression.rational(1, 2)
+
+    def test_restored_after_exception(self):
+        with pytest.raises(RuntimeError):
+            with mathhook.evaluate(angle='degrees'):
+                raise RuntimeError("boom")
+        assert sin_of(30).evaluate() != Expression.rational(1, 2)
+
+    def test_scope_cannot_be_entered_twice(self):
+        scope = mathhook.evaluate(angle='degrees')
+        with scope:
+            with pytest.raises(RuntimeError):
+                scope.__enter__()
@@ -301,3 +301,16 @@ def test_function_chain_evaluation():
 
     # Should not simplify to just x or numeric value
     assert str(expr1) and str(expr2)
+
+
+def test_factorial_beyond_int64():
+    """Test that factorials past 20! stay exact instead of overflowing"""
+    result = mathhook.factorial(25).simplify()
+    assert str(result) == '15511210043330985984000000'
+
+
+def test_large_int_stays_exact():
+    """Test that Python ints beyond 64 bits are not rounded to floats"""
+    x, = symbols('x')
+    result = x + 2**70
+    assert '1180591620717411303424' in str(result)
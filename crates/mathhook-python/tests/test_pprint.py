@@ -169,3 +169,40 @@ def test_pprint_output_format(capsys):
     assert captured.out.strip()
     # Should contain x
     assert 'x' in captured.out
+
+
+def test_pprint_fraction_bar(capsys):
+    """Test that fractions are drawn over a bar"""
+    x, y = symbols('x y')
+    expr = (x + 1) / y
+
+    pprint(expr)
+    captured = capsys.readouterr()
+
+    lines = captured.out.rstrip('\n').split('\n')
+    assert len(lines) == 3
+    assert set(lines[1]) == {'─'}
+    assert 'y' in lines[2]
+
+
+def test_pprint_raised_exponent(capsys):
+    """Test that exponents are raised above the base"""
+    x, = symbols('x')
+
+    pprint(x**2, use_unicode=False)
+    captured = capsys.readouterr()
+
+    assert captured.out == ' 2\nx\n'
+
+
+def test_pprint_follows_init_printing(capsys):
+    """Test that init_printing(unicode=False) switches pprint to ASCII"""
+    x, y = symbols('x y')
+
+    mathhook.init_printing(unicode=False)
+    try:
+        pprint(x / y)
+        captured = capsys.readouterr()
+        assert captured.out == 'x\n-\ny\n'
+    finally:
+        mathhook.init_printing()
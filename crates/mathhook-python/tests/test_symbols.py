@@ -230,3 +230,24 @@ def test_trailing_comma_comma_sep():
     result = symbols('x,y,z,')
     assert len(result) == 3
     assert str(result[2]) == 'z'
+
+
+def test_assumptions_keyword():
+    """Test assumptions declared with keyword arguments"""
+    p, q = symbols('p q', positive=True)
+    assert p.assumptions().is_positive()
+    assert q.assumptions().is_nonzero()
+    assert q.assumptions().is_real()
+
+
+def test_assumptions_on_range():
+    """Test assumptions apply to range syntax as well"""
+    n0, n1 = symbols('n0:2', integer=True)
+    assert n0.assumptions().names() == ['integer', 'real']
+    assert n1.assumptions().is_integer()
+
+
+def test_no_assumptions_by_default():
+    """Test symbols without keywords carry no assumptions"""
+    x, = symbols('x')
+    assert x.assumptions().is_empty()